@@ -0,0 +1,639 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// BAI2 <-> FedNow camt.052.001.08 (BankToCustomerAccountReportV08). The
+// BAI2 reader and writer live in `open_payments_common::bai2`.
+
+use open_payments_common::bai2::type_codes::{self, TypeCodeClass};
+use open_payments_common::bai2::{to_decimal, to_minor_units, Account, Bai2File, FileHeader, FundsType, Group, Summary, Transaction, ERR_CONTROL_TOTAL, ERR_UNMAPPABLE};
+use open_payments_common::datetime::date_part;
+use open_payments_common::swift::fields::is_bic;
+use open_payments_common::ValidationError;
+
+use crate::common::*;
+use crate::iso::camt_052_001_08::BankToCustomerAccountReportV08;
+use crate::swift::cash_account;
+
+/// Issuer of proprietary bank transaction codes holding BAI2 type codes.
+pub const BAI_ISSUER: &str = "BAI";
+
+fn unmappable(message: String) -> ValidationError {
+    ValidationError::new(ERR_UNMAPPABLE, message)
+}
+
+fn credit_debit(credit: bool) -> CreditDebitCode {
+    if credit { CreditDebitCode::CodeCRDT } else { CreditDebitCode::CodeDBIT }
+}
+
+fn is_credit(code: &CreditDebitCode) -> bool {
+    matches!(code, CreditDebitCode::CodeCRDT)
+}
+
+fn date_choice(date: &str) -> DateAndDateTime2Choice {
+    DateAndDateTime2Choice { dt: Some(date.to_string()), ..Default::default() }
+}
+
+fn date_time(date: &str, time: Option<&str>) -> String {
+    format!("{}T{}:00", date, time.unwrap_or("00:00"))
+}
+
+/// `hh:mm` of an ISODateTime, if it carries a time.
+fn time_part(value: &str) -> Option<String> {
+    value.get(11..16).map(|t| t.to_string())
+}
+
+fn bai_code(code: &str) -> BankTransactionCodeStructure4 {
+    let domain = type_codes::type_code(code).and_then(|t| t.bank_transaction_code).map(|(domain, family, sub_family)| BankTransactionCodeStructure5 {
+        cd: domain.to_string(),
+        fmly: BankTransactionCodeStructure6 { cd: family.to_string(), sub_fmly_cd: sub_family.to_string() },
+    });
+    BankTransactionCodeStructure4 {
+        domn: domain,
+        prtry: Some(ProprietaryBankTransactionCodeStructure1 { cd: code.to_string(), issr: Some(BAI_ISSUER.to_string()) }),
+    }
+}
+
+fn proprietary_bai_code(code: &BankTransactionCodeStructure4) -> Option<&str> {
+    code.prtry.as_ref().filter(|p| p.issr.as_deref() == Some(BAI_ISSUER)).map(|p| p.cd.as_str())
+}
+
+/// Party identified by a BAI2 sender, receiver or originator identification.
+fn identification_party(id: &str) -> PartyIdentification135 {
+    PartyIdentification135 {
+        id: Some(Party38Choice {
+            org_id: Some(OrganisationIdentification29 {
+                othr: Some(vec![GenericOrganisationIdentification1 { id: id.to_string(), ..Default::default() }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn party_identification(party: &PartyIdentification135) -> Option<String> {
+    let org_id = party.id.as_ref().and_then(|id| id.org_id.as_ref());
+    org_id
+        .and_then(|o| o.any_bic.clone().or_else(|| o.othr.as_ref().and_then(|o| o.first()).map(|o| o.id.clone())))
+        .or_else(|| party.nm.clone())
+}
+
+/// Account servicer for a BAI2 originator: nine digits are an ABA routing
+/// number, a BIC is taken as such and anything else is a proprietary identifier.
+fn originator_agent(id: &str) -> BranchAndFinancialInstitutionIdentification6 {
+    let mut institution = FinancialInstitutionIdentification18::default();
+    if id.len() == 9 && id.chars().all(|c| c.is_ascii_digit()) {
+        institution.clr_sys_mmb_id = Some(ClearingSystemMemberIdentification2 {
+            clr_sys_id: Some(ClearingSystemIdentification2Choice { cd: Some("USABA".to_string()), ..Default::default() }),
+            mmb_id: id.to_string(),
+        });
+    } else if is_bic(id) {
+        institution.bicfi = Some(id.to_string());
+    } else {
+        institution.othr = Some(GenericFinancialIdentification1 { id: id.to_string(), ..Default::default() });
+    }
+    BranchAndFinancialInstitutionIdentification6 { fin_instn_id: institution, ..Default::default() }
+}
+
+fn agent_identification(agent: &BranchAndFinancialInstitutionIdentification6) -> Option<String> {
+    let id = &agent.fin_instn_id;
+    id.clr_sys_mmb_id
+        .as_ref()
+        .map(|m| m.mmb_id.clone())
+        .or_else(|| id.bicfi.clone())
+        .or_else(|| id.othr.as_ref().map(|o| o.id.clone()))
+}
+
+/// Availability and value date of an amount. Funds types 0, 1 and 2 make
+/// the whole amount available after that many days, S and D distribute it
+/// over days and V gives the value date.
+fn funds_type_to_iso(funds_type: &FundsType, amount: f64, credit: bool, currency: &str) -> (Option<Vec<CashAvailability1>>, Option<DateAndDateTime2Choice>) {
+    let available = |days: u32, value: f64| CashAvailability1 {
+        dt: CashAvailabilityDate1Choice { nb_of_days: Some(days.to_string()), ..Default::default() },
+        amt: ActiveOrHistoricCurrencyAndAmount { ccy: currency.to_string(), value },
+        cdt_dbt_ind: credit_debit(credit),
+    };
+    match funds_type {
+        FundsType::Unknown => (None, None),
+        FundsType::Immediate => (Some(vec![available(0, amount)]), None),
+        FundsType::OneDay => (Some(vec![available(1, amount)]), None),
+        FundsType::TwoOrMoreDays => (Some(vec![available(2, amount)]), None),
+        FundsType::Distributed { immediate, one_day, two_or_more_days } => (
+            Some(vec![
+                available(0, to_decimal(*immediate, currency)),
+                available(1, to_decimal(*one_day, currency)),
+                available(2, to_decimal(*two_or_more_days, currency)),
+            ]),
+            None,
+        ),
+        FundsType::DistributedByDays(distributions) => {
+            (Some(distributions.iter().map(|(days, value)| available(*days, to_decimal(*value, currency))).collect()), None)
+        }
+        FundsType::ValueDated { date, time } => {
+            let value_date = match time {
+                Some(time) => DateAndDateTime2Choice { dt_tm: Some(date_time(date, Some(time))), ..Default::default() },
+                None => date_choice(date),
+            };
+            (None, Some(value_date))
+        }
+    }
+}
+
+/// Inverse of `funds_type_to_iso`. Availability by actual date has no BAI2
+/// counterpart.
+fn funds_type_from_iso(availability: Option<&[CashAvailability1]>, value_date: Option<&DateAndDateTime2Choice>, amount: f64, currency: &str) -> Result<FundsType, ValidationError> {
+    if let Some(availability) = availability.filter(|a| !a.is_empty()) {
+        let mut distributions = Vec::with_capacity(availability.len());
+        for item in availability {
+            let days = item
+                .dt
+                .nb_of_days
+                .as_deref()
+                .and_then(|d| d.trim_start_matches('+').parse::<u32>().ok())
+                .ok_or_else(|| unmappable("availability by actual date cannot be expressed as a BAI2 funds type".to_string()))?;
+            distributions.push((days, to_minor_units(item.amt.value, currency)));
+        }
+        let total = to_minor_units(amount, currency);
+        return Ok(match distributions.as_slice() {
+            [(0, value)] if *value == total => FundsType::Immediate,
+            [(1, value)] if *value == total => FundsType::OneDay,
+            [(2, value)] if *value == total => FundsType::TwoOrMoreDays,
+            [(0, immediate), (1, one_day), (2, two_or_more_days)] => {
+                FundsType::Distributed { immediate: *immediate, one_day: *one_day, two_or_more_days: *two_or_more_days }
+            }
+            _ => FundsType::DistributedByDays(distributions),
+        });
+    }
+    Ok(match value_date {
+        Some(choice) => match (&choice.dt, &choice.dt_tm) {
+            (Some(date), _) => FundsType::ValueDated { date: date.clone(), time: None },
+            (None, Some(date_time)) => FundsType::ValueDated { date: date_part(date_time).to_string(), time: time_part(date_time) },
+            _ => FundsType::Unknown,
+        },
+        None => FundsType::Unknown,
+    })
+}
+
+/// Content shared by the account reports and statements.
+struct AccountContent {
+    acct: CashAccount39,
+    bal: Vec<CashBalance8>,
+    txs_summry: Option<TotalTransactions6>,
+    ntry: Option<Vec<ReportEntry10>>,
+}
+
+fn balance_to_iso(summary: &Summary, currency: &str, as_of_date: &str) -> CashBalance8 {
+    let amount = summary.amount.unwrap_or_default();
+    let value = to_decimal(amount.abs(), currency);
+    let (avlbty, _) = funds_type_to_iso(&summary.funds_type, value, amount >= 0, currency);
+    let tp = match type_codes::type_code(&summary.type_code).and_then(|t| t.balance_type) {
+        Some(code) => BalanceType10Choice { cd: Some(code.to_string()), ..Default::default() },
+        None => BalanceType10Choice { prtry: Some(summary.type_code.clone()), ..Default::default() },
+    };
+    CashBalance8 {
+        tp: BalanceType13 { cd_or_prtry: tp, ..Default::default() },
+        amt: ActiveOrHistoricCurrencyAndAmount { ccy: currency.to_string(), value },
+        cdt_dbt_ind: credit_debit(amount >= 0),
+        dt: date_choice(as_of_date),
+        avlbty,
+        ..Default::default()
+    }
+}
+
+fn summary_to_iso(summary: &Summary, class: TypeCodeClass, currency: &str) -> TotalsPerBankTransactionCode5 {
+    let sum = summary.amount.map(|a| to_decimal(a.abs(), currency));
+    TotalsPerBankTransactionCode5 {
+        nb_of_ntries: summary.item_count.map(|c| c.to_string()),
+        sum,
+        bk_tx_cd: bai_code(&summary.type_code),
+        avlbty: funds_type_to_iso(&summary.funds_type, sum.unwrap_or_default(), class.is_credit(), currency).0,
+        ..Default::default()
+    }
+}
+
+fn transaction_to_iso(transaction: &Transaction, class: TypeCodeClass, currency: &str, as_of_date: &str) -> ReportEntry10 {
+    // A negative detail amount reverses an entry of the opposite direction.
+    let reversal = transaction.amount < 0;
+    let credit = class.is_credit() != reversal;
+    let value = to_decimal(transaction.amount.abs(), currency);
+    let (avlbty, val_dt) = funds_type_to_iso(&transaction.funds_type, value, credit, currency);
+    let details = transaction.customer_reference.as_ref().map(|reference| {
+        vec![EntryDetails9 {
+            tx_dtls: Some(vec![EntryTransaction10 {
+                refs: Some(TransactionReferences6 { end_to_end_id: Some(reference.clone()), ..Default::default() }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }]
+    });
+    ReportEntry10 {
+        amt: ActiveOrHistoricCurrencyAndAmount { ccy: currency.to_string(), value },
+        cdt_dbt_ind: credit_debit(credit),
+        rvsl_ind: if reversal { Some(true) } else { None },
+        sts: EntryStatus1Choice { cd: Some("BOOK".to_string()), ..Default::default() },
+        bookg_dt: Some(date_choice(as_of_date)),
+        val_dt,
+        acct_svcr_ref: transaction.bank_reference.clone(),
+        avlbty,
+        bk_tx_cd: bai_code(&transaction.type_code),
+        ntry_dtls: details,
+        addtl_ntry_inf: transaction.text.as_ref().map(|t| t.chars().take(500).collect()),
+        ..Default::default()
+    }
+}
+
+fn entry_totals<'a>(entries: impl Iterator<Item = &'a ReportEntry10>) -> NumberAndSumOfTransactions1 {
+    let (count, sum) = entries.fold((0, 0.0), |(count, sum), e| (count + 1, sum + e.amt.value));
+    NumberAndSumOfTransactions1 { nb_of_ntries: Some(count.to_string()), sum: Some(sum) }
+}
+
+/// Transaction summary computed from the entries, with the BAI2 summary
+/// amounts (100 total credits, 400 total debits and any other summary code)
+/// as totals per bank transaction code.
+fn transaction_summary(entries: &[ReportEntry10], per_code: Vec<TotalsPerBankTransactionCode5>, currency: &str) -> Option<TotalTransactions6> {
+    if entries.is_empty() && per_code.is_empty() {
+        return None;
+    }
+    let credits = entry_totals(entries.iter().filter(|e| is_credit(&e.cdt_dbt_ind)));
+    let debits = entry_totals(entries.iter().filter(|e| !is_credit(&e.cdt_dbt_ind)));
+    let net = to_minor_units(credits.sum.unwrap_or_default(), currency) - to_minor_units(debits.sum.unwrap_or_default(), currency);
+    Some(TotalTransactions6 {
+        ttl_ntries: Some(NumberAndSumOfTransactions4 {
+            nb_of_ntries: Some(entries.len().to_string()),
+            sum: Some(entries.iter().map(|e| e.amt.value).sum()),
+            ttl_net_ntry: Some(AmountAndDirection35 { amt: to_decimal(net.abs(), currency), cdt_dbt_ind: credit_debit(net >= 0) }),
+        }),
+        ttl_cdt_ntries: Some(credits),
+        ttl_dbt_ntries: Some(debits),
+        ttl_ntries_per_bk_tx_cd: if per_code.is_empty() { None } else { Some(per_code) },
+    })
+}
+
+fn account_to_iso(group: &Group, account: &Account) -> Result<AccountContent, ValidationError> {
+    let currency = group.account_currency(account);
+    let mut balances = Vec::new();
+    let mut per_code = Vec::new();
+    for summary in &account.summaries {
+        match type_codes::classify(&summary.type_code) {
+            Some(TypeCodeClass::Status) => balances.push(balance_to_iso(summary, &currency, &group.as_of_date)),
+            Some(class) => per_code.push(summary_to_iso(summary, class, &currency)),
+            None => return Err(unmappable(format!("account {}: unknown type code {}", account.account_number, summary.type_code))),
+        }
+    }
+    let mut entries = Vec::with_capacity(account.transactions.len());
+    for transaction in &account.transactions {
+        match type_codes::classify(&transaction.type_code) {
+            Some(class) if class != TypeCodeClass::Status => entries.push(transaction_to_iso(transaction, class, &currency, &group.as_of_date)),
+            _ => {
+                return Err(unmappable(format!("account {}: {} is not a transaction detail type code", account.account_number, transaction.type_code)))
+            }
+        }
+    }
+    Ok(AccountContent {
+        acct: CashAccount39 {
+            id: cash_account(&account.account_number).id,
+            ccy: Some(currency.clone()),
+            svcr: Some(originator_agent(&group.originator_id)),
+            ..Default::default()
+        },
+        bal: balances,
+        txs_summry: transaction_summary(&entries, per_code, &currency),
+        ntry: if entries.is_empty() { None } else { Some(entries) },
+    })
+}
+
+fn group_header(file: &Bai2File, group: &Group, index: usize) -> GroupHeader81 {
+    let header = &file.header;
+    GroupHeader81 {
+        msg_id: if file.groups.len() > 1 { format!("{}-{}", header.file_id, index + 1) } else { header.file_id.clone() },
+        cre_dt_tm: date_time(&header.creation_date, header.creation_time.as_deref()),
+        msg_rcpt: Some(identification_party(&group.ultimate_receiver_id)),
+        ..Default::default()
+    }
+}
+
+fn statement_id(header: &GroupHeader81, index: usize) -> String {
+    format!("{}-{}", header.msg_id, index + 1)
+}
+
+/// Parses a BAI2 file and translates it, see `file_to_camt052`.
+pub fn bai2_to_camt052(input: &str) -> Result<Vec<BankToCustomerAccountReportV08>, ValidationError> {
+    file_to_camt052(&Bai2File::parse(input)?)
+}
+
+/// Translates a BAI2 file into one camt.052 per group with one report per
+/// account. Status codes become balances, transaction details booked
+/// entries and summary codes totals per bank transaction code; the BAI2
+/// type code is kept as proprietary bank transaction code issued by `BAI`.
+pub fn file_to_camt052(file: &Bai2File) -> Result<Vec<BankToCustomerAccountReportV08>, ValidationError> {
+    let mut documents = Vec::with_capacity(file.groups.len());
+    for (index, group) in file.groups.iter().enumerate() {
+        let grp_hdr = group_header(file, group, index);
+        let mut reports = Vec::with_capacity(group.accounts.len());
+        for (number, account) in group.accounts.iter().enumerate() {
+            let content = account_to_iso(group, account)?;
+            reports.push(AccountReport25 {
+                id: statement_id(&grp_hdr, number),
+                cre_dt_tm: Some(date_time(&group.as_of_date, group.as_of_time.as_deref())),
+                acct: content.acct,
+                bal: if content.bal.is_empty() { None } else { Some(content.bal) },
+                txs_summry: content.txs_summry,
+                ntry: content.ntry,
+                ..Default::default()
+            });
+        }
+        documents.push(BankToCustomerAccountReportV08 { grp_hdr, rpt: reports, splmtry_data: None });
+    }
+    Ok(documents)
+}
+
+fn control_total_error(id: &str, what: &str, declared: &str, computed: &str) -> ValidationError {
+    ValidationError::new(ERR_CONTROL_TOTAL, format!("{}: {} is {} but the entries add up to {}", id, what, declared, computed))
+}
+
+fn check_totals(id: &str, what: &str, count: Option<&str>, sum: Option<f64>, entries: &[&ReportEntry10], currency: &str) -> Result<(), ValidationError> {
+    if let Some(count) = count {
+        if count.parse::<usize>().ok() != Some(entries.len()) {
+            return Err(control_total_error(id, &format!("{} number of entries", what), count, &entries.len().to_string()));
+        }
+    }
+    if let Some(sum) = sum {
+        let computed: i64 = entries.iter().map(|e| to_minor_units(e.amt.value, currency)).sum();
+        if to_minor_units(sum, currency) != computed {
+            return Err(control_total_error(id, &format!("{} sum", what), &sum.to_string(), &to_decimal(computed, currency).to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Checks the transaction summary of a report or statement (TtlNtries,
+/// TtlCdtNtries and TtlDbtNtries) against its entries.
+pub fn check_transaction_summary(id: &str, summary: &TotalTransactions6, entries: &[ReportEntry10], currency: &str) -> Result<(), ValidationError> {
+    let all: Vec<&ReportEntry10> = entries.iter().collect();
+    let credits: Vec<&ReportEntry10> = entries.iter().filter(|e| is_credit(&e.cdt_dbt_ind)).collect();
+    let debits: Vec<&ReportEntry10> = entries.iter().filter(|e| !is_credit(&e.cdt_dbt_ind)).collect();
+    if let Some(ref totals) = summary.ttl_ntries {
+        check_totals(id, "TtlNtries", totals.nb_of_ntries.as_deref(), totals.sum, &all, currency)?;
+        if let Some(ref net) = totals.ttl_net_ntry {
+            let computed: i64 = credits.iter().map(|e| to_minor_units(e.amt.value, currency)).sum::<i64>()
+                - debits.iter().map(|e| to_minor_units(e.amt.value, currency)).sum::<i64>();
+            let declared = to_minor_units(net.amt, currency) * if is_credit(&net.cdt_dbt_ind) { 1 } else { -1 };
+            if declared != computed {
+                return Err(control_total_error(id, "TtlNetNtry", &to_decimal(declared, currency).to_string(), &to_decimal(computed, currency).to_string()));
+            }
+        }
+    }
+    if let Some(ref totals) = summary.ttl_cdt_ntries {
+        check_totals(id, "TtlCdtNtries", totals.nb_of_ntries.as_deref(), totals.sum, &credits, currency)?;
+    }
+    if let Some(ref totals) = summary.ttl_dbt_ntries {
+        check_totals(id, "TtlDbtNtries", totals.nb_of_ntries.as_deref(), totals.sum, &debits, currency)?;
+    }
+    Ok(())
+}
+
+/// Removes the BAI2 field and record delimiters from a reference.
+fn bai_reference(value: &str) -> Option<String> {
+    let cleaned: String = value.chars().filter(|c| *c != ',' && *c != '/' && !c.is_control()).collect();
+    let cleaned = cleaned.trim().to_string();
+    if cleaned.is_empty() { None } else { Some(cleaned) }
+}
+
+fn balance_type_code(balance: &CashBalance8) -> Result<String, ValidationError> {
+    let tp = &balance.tp.cd_or_prtry;
+    if let Some(code) = tp.cd.as_deref() {
+        return type_codes::status_code_for_balance(code)
+            .map(|c| c.to_string())
+            .ok_or_else(|| unmappable(format!("balance type {} has no BAI2 status code", code)));
+    }
+    match tp.prtry.as_deref() {
+        Some(code) if type_codes::classify(code) == Some(TypeCodeClass::Status) => Ok(code.to_string()),
+        other => Err(unmappable(format!("balance type {} has no BAI2 status code", other.unwrap_or_default()))),
+    }
+}
+
+fn balance_from_iso(balance: &CashBalance8, currency: &str) -> Result<Summary, ValidationError> {
+    let sign = if is_credit(&balance.cdt_dbt_ind) { 1 } else { -1 };
+    Ok(Summary {
+        type_code: balance_type_code(balance)?,
+        amount: Some(sign * to_minor_units(balance.amt.value, currency)),
+        item_count: None,
+        funds_type: funds_type_from_iso(balance.avlbty.as_deref(), None, balance.amt.value, currency)?,
+    })
+}
+
+fn summary_from_iso(totals: &TotalsPerBankTransactionCode5, code: &str, currency: &str) -> Result<Summary, ValidationError> {
+    Ok(Summary {
+        type_code: code.to_string(),
+        amount: totals.sum.map(|s| to_minor_units(s, currency)),
+        item_count: totals.nb_of_ntries.as_deref().and_then(|n| n.parse().ok()),
+        funds_type: funds_type_from_iso(totals.avlbty.as_deref(), None, totals.sum.unwrap_or_default(), currency)?,
+    })
+}
+
+/// 100 total credits and 400 total debits, from the totals per BAI2 code
+/// when present and from the credit and debit totals otherwise.
+fn summaries_from_iso(summary: Option<&TotalTransactions6>, entries: &[ReportEntry10], currency: &str) -> Result<Vec<Summary>, ValidationError> {
+    let mut summaries = Vec::new();
+    let per_code: Vec<(&str, &TotalsPerBankTransactionCode5)> = summary
+        .and_then(|s| s.ttl_ntries_per_bk_tx_cd.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(|t| proprietary_bai_code(&t.bk_tx_cd).map(|code| (code, t)))
+        .collect();
+    for (code, credit) in [("100", true), ("400", false)] {
+        if per_code.iter().any(|(c, _)| *c == code) {
+            continue;
+        }
+        let totals = summary.and_then(|s| if credit { s.ttl_cdt_ntries.as_ref() } else { s.ttl_dbt_ntries.as_ref() });
+        let computed;
+        let totals = match totals {
+            Some(totals) => totals,
+            None if entries.is_empty() => continue,
+            None => {
+                computed = entry_totals(entries.iter().filter(|e| is_credit(&e.cdt_dbt_ind) == credit));
+                &computed
+            }
+        };
+        summaries.push(Summary {
+            type_code: code.to_string(),
+            amount: totals.sum.map(|s| to_minor_units(s, currency)),
+            item_count: totals.nb_of_ntries.as_deref().and_then(|n| n.parse().ok()),
+            funds_type: FundsType::Unknown,
+        });
+    }
+    for (code, totals) in per_code {
+        match type_codes::classify(code) {
+            Some(class) if class != TypeCodeClass::Status => summaries.push(summary_from_iso(totals, code, currency)?),
+            _ => return Err(unmappable(format!("{} is not a BAI2 summary type code", code))),
+        }
+    }
+    summaries.sort_by(|a, b| a.type_code.cmp(&b.type_code));
+    Ok(summaries)
+}
+
+/// Detail type code of an entry: the proprietary BAI2 code when its
+/// direction fits, otherwise the first code of the table with the same ISO
+/// bank transaction code and direction, otherwise 399 or 699 (miscellaneous).
+fn entry_type_code(entry: &ReportEntry10) -> String {
+    let credit = is_credit(&entry.cdt_dbt_ind);
+    let reversal = entry.rvsl_ind == Some(true);
+    let fits = |class: Option<TypeCodeClass>| match class {
+        Some(TypeCodeClass::CreditDetail) => credit != reversal,
+        Some(TypeCodeClass::DebitDetail) => credit == reversal,
+        _ => false,
+    };
+    if let Some(code) = proprietary_bai_code(&entry.bk_tx_cd).filter(|c| fits(type_codes::classify(c))) {
+        return code.to_string();
+    }
+    if let Some(ref domain) = entry.bk_tx_cd.domn {
+        let key = (domain.cd.as_str(), domain.fmly.cd.as_str(), domain.fmly.sub_fmly_cd.as_str());
+        if let Some(code) = type_codes::TYPE_CODES.iter().find(|t| t.bank_transaction_code == Some(key) && fits(Some(t.class))) {
+            return code.code.to_string();
+        }
+    }
+    if credit != reversal { "399".to_string() } else { "699".to_string() }
+}
+
+fn transaction_from_iso(entry: &ReportEntry10, currency: &str) -> Result<Transaction, ValidationError> {
+    let type_code = entry_type_code(entry);
+    let amount = to_minor_units(entry.amt.value, currency);
+    let reversal = entry.rvsl_ind == Some(true);
+    let first = entry.ntry_dtls.iter().flatten().flat_map(|d| d.tx_dtls.iter().flatten()).next();
+    let customer_reference = first.and_then(|t| t.refs.as_ref()).and_then(|r| {
+        r.end_to_end_id.as_deref().filter(|id| *id != "NOTPROVIDED").or(r.acct_ownr_tx_id.as_deref()).or(r.chq_nb.as_deref()).or(r.instr_id.as_deref())
+    });
+    let text = entry.addtl_ntry_inf.as_deref().or_else(|| first.and_then(|t| t.addtl_tx_inf.as_deref()));
+    Ok(Transaction {
+        type_code,
+        amount: if reversal { -amount } else { amount },
+        funds_type: funds_type_from_iso(entry.avlbty.as_deref(), entry.val_dt.as_ref(), entry.amt.value, currency)?,
+        bank_reference: entry.acct_svcr_ref.as_deref().and_then(bai_reference),
+        customer_reference: customer_reference.and_then(bai_reference),
+        text: text.map(|t| t.replace(['\r', '\n'], " ").trim_end_matches('/').trim().to_string()).filter(|t| !t.is_empty()),
+    })
+}
+
+fn account_from_iso(id: &str, acct: &CashAccount39, balances: &[CashBalance8], summary: Option<&TotalTransactions6>, entries: &[ReportEntry10]) -> Result<(Account, String), ValidationError> {
+    let account_number = acct
+        .id
+        .iban
+        .clone()
+        .or_else(|| acct.id.othr.as_ref().map(|o| o.id.clone()))
+        .ok_or_else(|| unmappable(format!("{}: account identification is required", id)))?;
+    let currency = acct
+        .ccy
+        .clone()
+        .or_else(|| balances.first().map(|b| b.amt.ccy.clone()))
+        .or_else(|| entries.first().map(|e| e.amt.ccy.clone()))
+        .unwrap_or_else(|| "USD".to_string());
+    if let Some(summary) = summary {
+        check_transaction_summary(id, summary, entries, &currency)?;
+    }
+    let mut summaries = balances.iter().map(|b| balance_from_iso(b, &currency)).collect::<Result<Vec<_>, _>>()?;
+    summaries.extend(summaries_from_iso(summary, entries, &currency)?);
+    // Only booked and pending entries are part of the balance report.
+    let transactions = entries
+        .iter()
+        .filter(|e| matches!(e.sts.cd.as_deref(), Some("BOOK") | Some("PDNG")))
+        .map(|e| transaction_from_iso(e, &currency))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((Account { account_number, currency: Some(currency.clone()), summaries, transactions, ..Default::default() }, currency))
+}
+
+/// Adds an account to the group of its originator and as-of date, opening
+/// a new group when there is none yet.
+fn add_account(groups: &mut Vec<Group>, template: Group, mut account: Account, currency: String) {
+    let index = match groups.iter().position(|g| g.originator_id == template.originator_id && g.as_of_date == template.as_of_date) {
+        Some(index) => index,
+        None => {
+            groups.push(Group { currency: Some(currency.clone()), ..template });
+            groups.len() - 1
+        }
+    };
+    let group = &mut groups[index];
+    if group.currency.as_deref() == Some(currency.as_str()) {
+        account.currency = None;
+    }
+    group.accounts.push(account);
+}
+
+fn file_header(header: &GroupHeader81, sender_id: &str) -> FileHeader {
+    FileHeader {
+        sender_id: sender_id.to_string(),
+        receiver_id: header.msg_rcpt.as_ref().and_then(party_identification).unwrap_or_default(),
+        creation_date: date_part(&header.cre_dt_tm).to_string(),
+        creation_time: time_part(&header.cre_dt_tm),
+        file_id: bai_reference(&header.msg_id).unwrap_or_else(|| "1".to_string()),
+        physical_record_length: None,
+        block_size: None,
+        version: "2".to_string(),
+    }
+}
+
+fn group_template(header: &GroupHeader81, acct: &CashAccount39, cre_dt_tm: Option<&str>, modifier: u8) -> Result<Group, ValidationError> {
+    let originator_id = acct
+        .svcr
+        .as_ref()
+        .and_then(agent_identification)
+        .ok_or_else(|| unmappable("account servicer is required as BAI2 originator".to_string()))?;
+    let as_of = cre_dt_tm.unwrap_or(&header.cre_dt_tm);
+    Ok(Group {
+        ultimate_receiver_id: header.msg_rcpt.as_ref().and_then(party_identification).unwrap_or_default(),
+        originator_id,
+        status: 1,
+        as_of_date: date_part(as_of).to_string(),
+        as_of_time: time_part(as_of),
+        as_of_date_modifier: Some(modifier),
+        ..Default::default()
+    })
+}
+
+fn finish_file(header: &GroupHeader81, groups: Vec<Group>) -> Bai2File {
+    let sender_id = groups.first().map(|g| g.originator_id.clone()).unwrap_or_default();
+    let mut file = Bai2File { header: file_header(header, &sender_id), groups, ..Default::default() };
+    file.update_control_totals();
+    file
+}
+
+/// Translates a camt.052 into BAI2 text, see `camt052_to_file`.
+pub fn camt052_to_bai2(document: &BankToCustomerAccountReportV08) -> Result<String, ValidationError> {
+    Ok(camt052_to_file(document)?.to_bai2())
+}
+
+/// Translates a camt.052 into a BAI2 file with one group per account
+/// servicer and as-of date, reported as interim same-day (modifier 3). The
+/// transaction summary of every report is checked against its entries
+/// first; the control totals of the file are computed.
+pub fn camt052_to_file(document: &BankToCustomerAccountReportV08) -> Result<Bai2File, ValidationError> {
+    let header = &document.grp_hdr;
+    let mut groups = Vec::new();
+    for report in &document.rpt {
+        let template = group_template(header, &report.acct, report.cre_dt_tm.as_deref(), 3)?;
+        let entries = report.ntry.as_deref().unwrap_or_default();
+        let (account, currency) = account_from_iso(&report.id, &report.acct, report.bal.as_deref().unwrap_or_default(), report.txs_summry.as_ref(), entries)?;
+        add_account(&mut groups, template, account, currency);
+    }
+    Ok(finish_file(header, groups))
+}
@@ -910,11 +910,11 @@ pub struct AccountNotification17 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "NtfctnPgntn", skip_serializing_if = "Option::is_none") )]
 	pub ntfctn_pgntn: Option<Pagination1>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "ElctrncSeqNb", skip_serializing_if = "Option::is_none") )]
-	pub elctrnc_seq_nb: Option<f64>,
+	pub elctrnc_seq_nb: Option<u64>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "RptgSeq", skip_serializing_if = "Option::is_none") )]
 	pub rptg_seq: Option<SequenceRange1Choice>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "LglSeqNb", skip_serializing_if = "Option::is_none") )]
-	pub lgl_seq_nb: Option<f64>,
+	pub lgl_seq_nb: Option<u64>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "CreDtTm", skip_serializing_if = "Option::is_none") )]
 	pub cre_dt_tm: Option<String>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "FrToDt", skip_serializing_if = "Option::is_none") )]
@@ -980,11 +980,11 @@ pub struct AccountReport25 {
 	#[cfg_attr( feature = "derive_serde", serde(rename = "RptPgntn", skip_serializing_if = "Option::is_none") )]
 	pub rpt_pgntn: Option<Pagination1>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "ElctrncSeqNb", skip_serializing_if = "Option::is_none") )]
-	pub elctrnc_seq_nb: Option<f64>,
+	pub elctrnc_seq_nb: Option<u64>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "RptgSeq", skip_serializing_if = "Option::is_none") )]
 	pub rptg_seq: Option<SequenceRange1Choice>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "LglSeqNb", skip_serializing_if = "Option::is_none") )]
-	pub lgl_seq_nb: Option<f64>,
+	pub lgl_seq_nb: Option<u64>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "CreDtTm", skip_serializing_if = "Option::is_none") )]
 	pub cre_dt_tm: Option<String>,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "FrToDt", skip_serializing_if = "Option::is_none") )]
@@ -1084,7 +1084,7 @@ impl AccountSchemeName1Choice {
 #[cfg_attr(feature = "derive_clone", derive(Clone))]
 #[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
 pub struct ActiveCurrencyAndAmount {
-	#[cfg_attr( feature = "derive_serde", serde(rename = "@Ccy") )]
+	#[cfg_attr( feature = "derive_serde", serde(rename = "@Ccy", alias = "Ccy") )]
 	pub ccy: String,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "$value") )]
 	pub value: f64,
@@ -1104,7 +1104,7 @@ impl ActiveCurrencyAndAmount {
 #[cfg_attr(feature = "derive_clone", derive(Clone))]
 #[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
 pub struct ActiveOrHistoricCurrencyAnd13DecimalAmount {
-	#[cfg_attr( feature = "derive_serde", serde(rename = "@Ccy") )]
+	#[cfg_attr( feature = "derive_serde", serde(rename = "@Ccy", alias = "Ccy") )]
 	pub ccy: String,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "$value") )]
 	pub value: f64,
@@ -1124,7 +1124,7 @@ impl ActiveOrHistoricCurrencyAnd13DecimalAmount {
 #[cfg_attr(feature = "derive_clone", derive(Clone))]
 #[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
 pub struct ActiveOrHistoricCurrencyAndAmount {
-	#[cfg_attr( feature = "derive_serde", serde(rename = "@Ccy") )]
+	#[cfg_attr( feature = "derive_serde", serde(rename = "@Ccy", alias = "Ccy") )]
 	pub ccy: String,
 	#[cfg_attr( feature = "derive_serde", serde(rename = "$value") )]
 	pub value: f64,
@@ -25,6 +25,12 @@
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 use open_payments_common::ValidationError;
+use crate::visitor::{Visitor, VisitorMut};
+use open_payments_common::example::Example;
+#[cfg(feature = "derive_default")]
+use open_payments_common::tag_path::{Step, TagPaths};
+#[cfg(feature = "derive_serde")]
+use open_payments_common::fingerprint::{fingerprint, Fingerprint, FingerprintOptions};
 
 use crate::iso::admi_002_001_01::*;
 use crate::iso::admi_004_001_02::*;
@@ -153,4 +159,173 @@ impl Document {
             }
         }
     }
-}
\ No newline at end of file
+
+    /// Message definition identifier (`MsgDefIdr`) of the message, e.g.
+    /// `pacs.008.001.08`.
+    pub fn message_definition_identifier(&self) -> Option<&'static str> {
+        match self {
+			Document::Admi00200101(_) => Some("admi.002.001.01"),
+			Document::SystemEventNotificationV02(_) => Some("admi.004.001.02"),
+			Document::ResendRequestV01(_) => Some("admi.006.001.01"),
+			Document::ReceiptAcknowledgementV01(_) => Some("admi.007.001.01"),
+			Document::FIToFIPaymentStatusReportV10(_) => Some("pacs.002.001.10"),
+			Document::PaymentReturnV10(_) => Some("pacs.004.001.10"),
+			Document::FIToFICustomerCreditTransferV08(_) => Some("pacs.008.001.08"),
+			Document::FinancialInstitutionCreditTransferV08(_) => Some("pacs.009.001.08"),
+			Document::FIToFIPaymentStatusRequestV03(_) => Some("pacs.028.001.03"),
+			Document::CreditorPaymentActivationRequestV07(_) => Some("pain.013.001.07"),
+			Document::CreditorPaymentActivationRequestStatusReportV07(_) => Some("pain.014.001.07"),
+			Document::UnableToApplyV07(_) => Some("camt.026.001.07"),
+			Document::AdditionalPaymentInformationV09(_) => Some("camt.028.001.09"),
+			Document::ResolutionOfInvestigationV09(_) => Some("camt.029.001.09"),
+			Document::CustomerPaymentCancellationRequestV09(_) => Some("camt.055.001.09"),
+			Document::FIToFIPaymentCancellationRequestV08(_) => Some("camt.056.001.08"),
+			Document::AccountReportingRequestV05(_) => Some("camt.060.001.05"),
+			Document::SystemEventAcknowledgementV01(_) => Some("admi.011.001.01"),
+			Document::AdministrationProprietaryMessageV02(_) => Some("admi.998.001.02"),
+			Document::BankToCustomerAccountReportV08(_) => Some("camt.052.001.08"),
+			Document::BankToCustomerDebitCreditNotificationV08(_) => Some("camt.054.001.08"),
+			Document::UNKNOWN => None,
+        }
+    }
+
+    /// Canonical fingerprint of the message
+    /// (`open_payments_common::fingerprint`), `None` for an unknown document.
+    #[cfg(feature = "derive_serde")]
+    pub fn fingerprint(&self, options: &FingerprintOptions) -> Option<Fingerprint> {
+        let msg_def_idr = self.message_definition_identifier()?;
+        let value = serde_json::to_value(self).ok()?;
+        Some(fingerprint(msg_def_idr, &value, options))
+    }
+
+    /// Walks the message with a visitor (`crate::visitor`).
+    pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+			Document::Admi00200101(value) => value.walk(visitor),
+			Document::SystemEventNotificationV02(value) => value.walk(visitor),
+			Document::ResendRequestV01(value) => value.walk(visitor),
+			Document::ReceiptAcknowledgementV01(value) => value.walk(visitor),
+			Document::FIToFIPaymentStatusReportV10(value) => value.walk(visitor),
+			Document::PaymentReturnV10(value) => value.walk(visitor),
+			Document::FIToFICustomerCreditTransferV08(value) => value.walk(visitor),
+			Document::FinancialInstitutionCreditTransferV08(value) => value.walk(visitor),
+			Document::FIToFIPaymentStatusRequestV03(value) => value.walk(visitor),
+			Document::CreditorPaymentActivationRequestV07(value) => value.walk(visitor),
+			Document::CreditorPaymentActivationRequestStatusReportV07(value) => value.walk(visitor),
+			Document::UnableToApplyV07(value) => value.walk(visitor),
+			Document::AdditionalPaymentInformationV09(value) => value.walk(visitor),
+			Document::ResolutionOfInvestigationV09(value) => value.walk(visitor),
+			Document::CustomerPaymentCancellationRequestV09(value) => value.walk(visitor),
+			Document::FIToFIPaymentCancellationRequestV08(value) => value.walk(visitor),
+			Document::AccountReportingRequestV05(value) => value.walk(visitor),
+			Document::SystemEventAcknowledgementV01(value) => value.walk(visitor),
+			Document::AdministrationProprietaryMessageV02(value) => value.walk(visitor),
+			Document::BankToCustomerAccountReportV08(value) => value.walk(visitor),
+			Document::BankToCustomerDebitCreditNotificationV08(value) => value.walk(visitor),
+			Document::UNKNOWN => {}
+        }
+    }
+
+    pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+			Document::Admi00200101(value) => value.walk_mut(visitor),
+			Document::SystemEventNotificationV02(value) => value.walk_mut(visitor),
+			Document::ResendRequestV01(value) => value.walk_mut(visitor),
+			Document::ReceiptAcknowledgementV01(value) => value.walk_mut(visitor),
+			Document::FIToFIPaymentStatusReportV10(value) => value.walk_mut(visitor),
+			Document::PaymentReturnV10(value) => value.walk_mut(visitor),
+			Document::FIToFICustomerCreditTransferV08(value) => value.walk_mut(visitor),
+			Document::FinancialInstitutionCreditTransferV08(value) => value.walk_mut(visitor),
+			Document::FIToFIPaymentStatusRequestV03(value) => value.walk_mut(visitor),
+			Document::CreditorPaymentActivationRequestV07(value) => value.walk_mut(visitor),
+			Document::CreditorPaymentActivationRequestStatusReportV07(value) => value.walk_mut(visitor),
+			Document::UnableToApplyV07(value) => value.walk_mut(visitor),
+			Document::AdditionalPaymentInformationV09(value) => value.walk_mut(visitor),
+			Document::ResolutionOfInvestigationV09(value) => value.walk_mut(visitor),
+			Document::CustomerPaymentCancellationRequestV09(value) => value.walk_mut(visitor),
+			Document::FIToFIPaymentCancellationRequestV08(value) => value.walk_mut(visitor),
+			Document::AccountReportingRequestV05(value) => value.walk_mut(visitor),
+			Document::SystemEventAcknowledgementV01(value) => value.walk_mut(visitor),
+			Document::AdministrationProprietaryMessageV02(value) => value.walk_mut(visitor),
+			Document::BankToCustomerAccountReportV08(value) => value.walk_mut(visitor),
+			Document::BankToCustomerDebitCreditNotificationV08(value) => value.walk_mut(visitor),
+			Document::UNKNOWN => {}
+        }
+    }
+}
+
+impl Example for Document {
+    /// A customer credit transfer (pacs.008), which is also the message in
+    /// the examples of the envelopes of other messages.
+    fn example() -> Self {
+        Document::FIToFICustomerCreditTransferV08(Box::new(Example::example()))
+    }
+}
+
+/// Paths of a document start at its message element.
+#[cfg(feature = "derive_default")]
+impl TagPaths for Document {
+    fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+        let message: Option<(&str, &'a dyn TagPaths)> = match self {
+			Document::Admi00200101(value) => Some(("admi.002.001.01", &**value)),
+			Document::SystemEventNotificationV02(value) => Some(("SysEvtNtfctn", &**value)),
+			Document::ResendRequestV01(value) => Some(("RsndReq", &**value)),
+			Document::ReceiptAcknowledgementV01(value) => Some(("RctAck", &**value)),
+			Document::FIToFIPaymentStatusReportV10(value) => Some(("FIToFIPmtStsRpt", &**value)),
+			Document::PaymentReturnV10(value) => Some(("PmtRtr", &**value)),
+			Document::FIToFICustomerCreditTransferV08(value) => Some(("FIToFICstmrCdtTrf", &**value)),
+			Document::FinancialInstitutionCreditTransferV08(value) => Some(("FICdtTrf", &**value)),
+			Document::FIToFIPaymentStatusRequestV03(value) => Some(("FIToFIPmtStsReq", &**value)),
+			Document::CreditorPaymentActivationRequestV07(value) => Some(("CdtrPmtActvtnReq", &**value)),
+			Document::CreditorPaymentActivationRequestStatusReportV07(value) => Some(("CdtrPmtActvtnReqStsRpt", &**value)),
+			Document::UnableToApplyV07(value) => Some(("UblToApply", &**value)),
+			Document::AdditionalPaymentInformationV09(value) => Some(("AddtlPmtInf", &**value)),
+			Document::ResolutionOfInvestigationV09(value) => Some(("RsltnOfInvstgtn", &**value)),
+			Document::CustomerPaymentCancellationRequestV09(value) => Some(("CstmrPmtCxlReq", &**value)),
+			Document::FIToFIPaymentCancellationRequestV08(value) => Some(("FIToFIPmtCxlReq", &**value)),
+			Document::AccountReportingRequestV05(value) => Some(("AcctRptgReq", &**value)),
+			Document::SystemEventAcknowledgementV01(value) => Some(("SysEvtAck", &**value)),
+			Document::AdministrationProprietaryMessageV02(value) => Some(("AdmstnPrtryMsg", &**value)),
+			Document::BankToCustomerAccountReportV08(value) => Some(("BkToCstmrAcctRpt", &**value)),
+			Document::BankToCustomerDebitCreditNotificationV08(value) => Some(("BkToCstmrDbtCdtNtfctn", &**value)),
+			Document::UNKNOWN => None,
+        };
+        if let Some((tag, message)) = message {
+            if step.matches(tag) && step.first() {
+                out.push(message);
+            }
+        }
+    }
+
+    fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+        let message: Option<(&str, &'a mut dyn TagPaths)> = match self {
+			Document::Admi00200101(value) => Some(("admi.002.001.01", &mut **value)),
+			Document::SystemEventNotificationV02(value) => Some(("SysEvtNtfctn", &mut **value)),
+			Document::ResendRequestV01(value) => Some(("RsndReq", &mut **value)),
+			Document::ReceiptAcknowledgementV01(value) => Some(("RctAck", &mut **value)),
+			Document::FIToFIPaymentStatusReportV10(value) => Some(("FIToFIPmtStsRpt", &mut **value)),
+			Document::PaymentReturnV10(value) => Some(("PmtRtr", &mut **value)),
+			Document::FIToFICustomerCreditTransferV08(value) => Some(("FIToFICstmrCdtTrf", &mut **value)),
+			Document::FinancialInstitutionCreditTransferV08(value) => Some(("FICdtTrf", &mut **value)),
+			Document::FIToFIPaymentStatusRequestV03(value) => Some(("FIToFIPmtStsReq", &mut **value)),
+			Document::CreditorPaymentActivationRequestV07(value) => Some(("CdtrPmtActvtnReq", &mut **value)),
+			Document::CreditorPaymentActivationRequestStatusReportV07(value) => Some(("CdtrPmtActvtnReqStsRpt", &mut **value)),
+			Document::UnableToApplyV07(value) => Some(("UblToApply", &mut **value)),
+			Document::AdditionalPaymentInformationV09(value) => Some(("AddtlPmtInf", &mut **value)),
+			Document::ResolutionOfInvestigationV09(value) => Some(("RsltnOfInvstgtn", &mut **value)),
+			Document::CustomerPaymentCancellationRequestV09(value) => Some(("CstmrPmtCxlReq", &mut **value)),
+			Document::FIToFIPaymentCancellationRequestV08(value) => Some(("FIToFIPmtCxlReq", &mut **value)),
+			Document::AccountReportingRequestV05(value) => Some(("AcctRptgReq", &mut **value)),
+			Document::SystemEventAcknowledgementV01(value) => Some(("SysEvtAck", &mut **value)),
+			Document::AdministrationProprietaryMessageV02(value) => Some(("AdmstnPrtryMsg", &mut **value)),
+			Document::BankToCustomerAccountReportV08(value) => Some(("BkToCstmrAcctRpt", &mut **value)),
+			Document::BankToCustomerDebitCreditNotificationV08(value) => Some(("BkToCstmrDbtCdtNtfctn", &mut **value)),
+			Document::UNKNOWN => None,
+        };
+        if let Some((tag, message)) = message {
+            if step.matches(tag) && step.first() {
+                out.push(message);
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Duplicate detection for a FedNow message (`open_payments_common::duplicate`):
+// the keys of the business application header and the Document.
+
+use crate::common::*;
+use crate::document::Document;
+use open_payments_common::duplicate::{document_keys, DuplicateDetector, DuplicateStore, MessageKeys, Verdict};
+
+/// Whether a header says the message may be a duplicate: `PssblDplct`,
+/// or `CpyDplct` DUPL or CODU.
+pub fn is_possible_duplicate(app_hdr: &BusinessApplicationHeaderV02) -> bool {
+    app_hdr.pssbl_dplct == Some(true) || matches!(app_hdr.cpy_dplct, Some(CopyDuplicate1Code::CodeDUPL | CopyDuplicate1Code::CodeCODU))
+}
+
+pub fn message_keys(app_hdr: Option<&BusinessApplicationHeaderV02>, document: &Document) -> MessageKeys {
+    let mut keys = serde_json::to_value(document).map(|value| document_keys(&value)).unwrap_or_default();
+    keys.msg_def_idr = document.message_definition_identifier().map(str::to_string);
+    if let Some(app_hdr) = app_hdr {
+        keys.biz_msg_idr = Some(app_hdr.biz_msg_idr.clone());
+        keys.possible_duplicate = is_possible_duplicate(app_hdr);
+    }
+    keys
+}
+
+/// Classifies a message and remembers it when it is new. `resent` marks
+/// messages delivered in answer to a resend or retrieval request, which
+/// repeat earlier messages whatever their header says.
+pub fn check_message<S: DuplicateStore>(
+    detector: &mut DuplicateDetector<S>,
+    app_hdr: Option<&BusinessApplicationHeaderV02>,
+    document: &Document,
+    resent: bool,
+) -> Verdict {
+    let mut keys = message_keys(app_hdr, document);
+    keys.possible_duplicate |= resent;
+    detector.check(&keys)
+}
@@ -0,0 +1,4843 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `Example` for the generated types of this crate, generated by
+// generate-example.py from their facets (see `open_payments_common::example`).
+
+use open_payments_common::example::Example;
+
+impl Example for crate::common::AccountDebitCreditNotification {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c54_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowAccountActivityDetailsReport {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c52_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowAccountActivityTotalsReport {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c52_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowAccountBalanceReport {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c52_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowAccountReportingRequest {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c60_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowAdditionalPaymentInformation {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c28_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowBroadcast {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			a4_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowCustomerCreditTransfer {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			p8_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowIncomingMessage {
+	fn example() -> Self {
+		Self {
+			fed_now_message_reject: Some(Example::example()),
+			fed_now_participant_broadcast: None,
+			fed_now_retrieval_request: None,
+			fed_now_receipt_acknowledgement: None,
+			fed_now_payment_status: None,
+			fed_now_payment_return: None,
+			fed_now_customer_credit_transfer: None,
+			fed_now_institution_credit_transfer: None,
+			fed_now_payment_status_request: None,
+			fed_now_request_for_payment: None,
+			fed_now_request_for_payment_response: None,
+			fed_now_information_request: None,
+			fed_now_additional_payment_information: None,
+			fed_now_information_request_response: None,
+			fed_now_request_for_payment_cancellation_request_response: None,
+			fed_now_return_request_response: None,
+			fed_now_request_for_payment_cancellation_request: None,
+			fed_now_return_request: None,
+			fed_now_account_reporting_request: None,
+			fed_now_incoming_message_signature_management: None,
+		}
+	}
+}
+
+impl Example for crate::common::FedNowIncomingMessageSignatureManagement {
+	fn example() -> Self {
+		Self {
+			sender_id: "SenderId".to_string(),
+			ke_get_all_fed_now_active_public_keys: Some(Example::example()),
+			ke_get_all_customer_public_keys: None,
+			ke_fed_now_message_signature_key_exchange: None,
+		}
+	}
+}
+
+impl Example for crate::common::FedNowInformationRequest {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c26_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowInformationRequestResponse {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c29_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowInstitutionCreditTransfer {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			p9_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowMessageReject {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			a2_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowOutgoingMessage {
+	fn example() -> Self {
+		Self {
+			fed_now_message_reject: Some(Example::example()),
+			fed_now_broadcast: None,
+			fed_now_receipt_acknowledgement: None,
+			fed_now_system_response: None,
+			fed_now_participant_file: None,
+			fed_now_payment_status: None,
+			fed_now_payment_return: None,
+			fed_now_customer_credit_transfer: None,
+			fed_now_institution_credit_transfer: None,
+			fed_now_payment_status_request: None,
+			fed_now_request_for_payment: None,
+			fed_now_request_for_payment_response: None,
+			fed_now_information_request: None,
+			fed_now_additional_payment_information: None,
+			fed_now_return_request_response: None,
+			fed_now_information_request_response: None,
+			fed_now_account_activity_details_report: None,
+			fed_now_account_activity_totals_report: None,
+			fed_now_account_balance_report: None,
+			account_debit_credit_notification: None,
+			fed_now_request_for_payment_cancellation_request: None,
+			fed_now_request_for_payment_cancellation_request_response: None,
+			fed_now_return_request: None,
+			fed_now_outgoing_message_signature_management: None,
+		}
+	}
+}
+
+impl Example for crate::common::FedNowOutgoingMessageSignatureManagement {
+	fn example() -> Self {
+		Self {
+			ke_fed_now_public_key_responses: Some(Example::example()),
+			ke_fed_now_customer_message_signature_key_operation_response: None,
+		}
+	}
+}
+
+impl Example for crate::common::FedNowParticipantBroadcast {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			a4_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowParticipantFile {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			a998_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowPaymentReturn {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			p4_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowPaymentStatus {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			p2_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowPaymentStatusRequest {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			p28_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowReceiptAcknowledgement {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			a7_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowRequestForPayment {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			pain13_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowRequestForPaymentCancellationRequest {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c55_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowRequestForPaymentCancellationRequestResponse {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c29_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowRequestForPaymentResponse {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			pain14_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowRetrievalRequest {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			a6_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowReturnRequest {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c56_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowReturnRequestResponse {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			c29_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowSystemResponse {
+	fn example() -> Self {
+		Self {
+			bah_app_hdr: Example::example(),
+			a11_document: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowTechnicalHeader {
+	fn example() -> Self {
+		Self {}
+	}
+}
+
+impl Example for crate::common::AccountIdentification4Choice {
+	fn example() -> Self {
+		Self {
+			iban: Some("GB29NWBK60161331926819".to_string()),
+			othr: None,
+		}
+	}
+}
+
+impl Example for crate::common::AccountInterest4 {
+	fn example() -> Self {
+		Self {
+			tp: Some(Example::example()),
+			rate: None,
+			fr_to_dt: None,
+			rsn: None,
+			tax: None,
+		}
+	}
+}
+
+impl Example for crate::common::AccountNotification17 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			ntfctn_pgntn: None,
+			elctrnc_seq_nb: None,
+			rptg_seq: None,
+			lgl_seq_nb: None,
+			cre_dt_tm: None,
+			fr_to_dt: None,
+			cpy_dplct_ind: None,
+			rptg_src: None,
+			acct: Example::example(),
+			rltd_acct: None,
+			intrst: None,
+			txs_summry: None,
+			ntry: None,
+			addtl_ntfctn_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::AccountReport25 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			rpt_pgntn: None,
+			elctrnc_seq_nb: None,
+			rptg_seq: None,
+			lgl_seq_nb: None,
+			cre_dt_tm: None,
+			fr_to_dt: None,
+			cpy_dplct_ind: None,
+			rptg_src: None,
+			acct: Example::example(),
+			rltd_acct: None,
+			intrst: None,
+			bal: None,
+			txs_summry: None,
+			ntry: None,
+			addtl_rpt_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::AccountSchemeName1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ActiveCurrencyAndAmount {
+	fn example() -> Self {
+		Self {
+			ccy: "USD".to_string(),
+			value: 1.0,
+		}
+	}
+}
+
+impl Example for crate::common::ActiveOrHistoricCurrencyAnd13DecimalAmount {
+	fn example() -> Self {
+		Self {
+			ccy: "USD".to_string(),
+			value: 1.0,
+		}
+	}
+}
+
+impl Example for crate::common::ActiveOrHistoricCurrencyAndAmount {
+	fn example() -> Self {
+		Self {
+			ccy: "USD".to_string(),
+			value: 1.0,
+		}
+	}
+}
+
+impl Example for crate::common::ActiveOrHistoricCurrencyAndAmountRange2 {
+	fn example() -> Self {
+		Self {
+			amt: Example::example(),
+			cdt_dbt_ind: None,
+			ccy: "USD".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::AddressType2Code {
+	fn example() -> Self {
+		Self::CodeADDR
+	}
+}
+
+impl Example for crate::common::AddressType3Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::AmendmentInformationDetails13 {
+	fn example() -> Self {
+		Self {
+			orgnl_mndt_id: Some("OrgnlMndtId".to_string()),
+			orgnl_cdtr_schme_id: None,
+			orgnl_cdtr_agt: None,
+			orgnl_cdtr_agt_acct: None,
+			orgnl_dbtr: None,
+			orgnl_dbtr_acct: None,
+			orgnl_dbtr_agt: None,
+			orgnl_dbtr_agt_acct: None,
+			orgnl_fnl_colltn_dt: None,
+			orgnl_frqcy: None,
+			orgnl_rsn: None,
+			orgnl_trckg_days: None,
+		}
+	}
+}
+
+impl Example for crate::common::AmountAndCurrencyExchange3 {
+	fn example() -> Self {
+		Self {
+			instd_amt: Some(Example::example()),
+			tx_amt: None,
+			cntr_val_amt: None,
+			anncd_pstng_amt: None,
+			prtry_amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::AmountAndCurrencyExchangeDetails3 {
+	fn example() -> Self {
+		Self {
+			amt: Example::example(),
+			ccy_xchg: None,
+		}
+	}
+}
+
+impl Example for crate::common::AmountAndCurrencyExchangeDetails4 {
+	fn example() -> Self {
+		Self {
+			tp: "Tp".to_string(),
+			amt: Example::example(),
+			ccy_xchg: None,
+		}
+	}
+}
+
+impl Example for crate::common::AmountAndDirection35 {
+	fn example() -> Self {
+		Self {
+			amt: 1.0,
+			cdt_dbt_ind: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::AmountOrRate1Choice {
+	fn example() -> Self {
+		Self {
+			amt: Some(Example::example()),
+			rate: None,
+		}
+	}
+}
+
+impl Example for crate::common::AmountRangeBoundary1 {
+	fn example() -> Self {
+		Self {
+			bdry_amt: 1.0,
+			incl: false,
+		}
+	}
+}
+
+impl Example for crate::common::AmountType4Choice {
+	fn example() -> Self {
+		Self {
+			instd_amt: Some(Example::example()),
+			eqvt_amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::AttendanceContext1Code {
+	fn example() -> Self {
+		Self::CodeATTD
+	}
+}
+
+impl Example for crate::common::AuthenticationEntity1Code {
+	fn example() -> Self {
+		Self::CodeICCD
+	}
+}
+
+impl Example for crate::common::AuthenticationMethod1Code {
+	fn example() -> Self {
+		Self::CodeUKNW
+	}
+}
+
+impl Example for crate::common::Authorisation1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::Authorisation1Code {
+	fn example() -> Self {
+		Self::CodeAUTH
+	}
+}
+
+impl Example for crate::common::BalanceSubType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::BalanceType10Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::BalanceType13 {
+	fn example() -> Self {
+		Self {
+			cd_or_prtry: Example::example(),
+			sub_tp: None,
+		}
+	}
+}
+
+impl Example for crate::common::BankTransactionCodeStructure4 {
+	fn example() -> Self {
+		Self {
+			domn: Some(Example::example()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::BankTransactionCodeStructure5 {
+	fn example() -> Self {
+		Self {
+			cd: "Cd".to_string(),
+			fmly: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::BankTransactionCodeStructure6 {
+	fn example() -> Self {
+		Self {
+			cd: "Cd".to_string(),
+			sub_fmly_cd: "SubF".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::BatchInformation2 {
+	fn example() -> Self {
+		Self {
+			msg_id: Some("MsgId".to_string()),
+			pmt_inf_id: None,
+			nb_of_txs: None,
+			ttl_amt: None,
+			cdt_dbt_ind: None,
+		}
+	}
+}
+
+impl Example for crate::common::BranchAndFinancialInstitutionIdentification6 {
+	fn example() -> Self {
+		Self {
+			fin_instn_id: Example::example(),
+			brnch_id: None,
+		}
+	}
+}
+
+impl Example for crate::common::BranchData3 {
+	fn example() -> Self {
+		Self {
+			id: Some("Id".to_string()),
+			lei: None,
+			nm: None,
+			pstl_adr: None,
+		}
+	}
+}
+
+impl Example for crate::common::BusinessApplicationHeader5 {
+	fn example() -> Self {
+		Self {
+			char_set: None,
+			fr: Example::example(),
+			to: Example::example(),
+			biz_msg_idr: "BizMsgIdr".to_string(),
+			msg_def_idr: "MsgDefIdr".to_string(),
+			biz_svc: None,
+			cre_dt: "2024-01-01T00:00:00".to_string(),
+			cpy_dplct: None,
+			pssbl_dplct: None,
+			prty: None,
+			sgntr: None,
+		}
+	}
+}
+
+impl Example for crate::common::BusinessApplicationHeaderV02 {
+	fn example() -> Self {
+		Self {
+			char_set: None,
+			fr: Example::example(),
+			to: Example::example(),
+			biz_msg_idr: "BizMsgIdr".to_string(),
+			msg_def_idr: "MsgDefIdr".to_string(),
+			biz_svc: None,
+			mkt_prctc: None,
+			cre_dt: "2024-01-01T00:00:00".to_string(),
+			biz_prcg_dt: None,
+			cpy_dplct: None,
+			pssbl_dplct: None,
+			prty: None,
+			sgntr: None,
+			rltd: None,
+		}
+	}
+}
+
+impl Example for crate::common::CSCManagement1Code {
+	fn example() -> Self {
+		Self::CodePRST
+	}
+}
+
+impl Example for crate::common::CancellationIndividualStatus1Code {
+	fn example() -> Self {
+		Self::CodeRJCR
+	}
+}
+
+impl Example for crate::common::CancellationReason33Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::CancellationStatusReason3Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::CancellationStatusReason4 {
+	fn example() -> Self {
+		Self {
+			orgtr: Some(Example::example()),
+			rsn: None,
+			addtl_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::CardAggregated2 {
+	fn example() -> Self {
+		Self {
+			addtl_svc: Some(Example::example()),
+			tx_ctgy: None,
+			sale_rcncltn_id: None,
+			seq_nb_rg: None,
+			tx_dt_rg: None,
+		}
+	}
+}
+
+impl Example for crate::common::CardDataReading1Code {
+	fn example() -> Self {
+		Self::CodeTAGC
+	}
+}
+
+impl Example for crate::common::CardEntry4 {
+	fn example() -> Self {
+		Self {
+			card: Some(Example::example()),
+			poi: None,
+			aggtd_ntry: None,
+			pre_pd_acct: None,
+		}
+	}
+}
+
+impl Example for crate::common::CardIndividualTransaction2 {
+	fn example() -> Self {
+		Self {
+			icc_rltd_data: Some("ICCRltdData".to_string()),
+			pmt_cntxt: None,
+			addtl_svc: None,
+			tx_ctgy: None,
+			sale_rcncltn_id: None,
+			sale_ref_nb: None,
+			re_presntmnt_rsn: None,
+			seq_nb: None,
+			tx_id: None,
+			pdct: None,
+			vldtn_dt: None,
+			vldtn_seq_nb: None,
+		}
+	}
+}
+
+impl Example for crate::common::CardPaymentServiceType2Code {
+	fn example() -> Self {
+		Self::CodeAGGR
+	}
+}
+
+impl Example for crate::common::CardSecurityInformation1 {
+	fn example() -> Self {
+		Self {
+			csc_mgmt: Example::example(),
+			csc_val: None,
+		}
+	}
+}
+
+impl Example for crate::common::CardSequenceNumberRange1 {
+	fn example() -> Self {
+		Self {
+			frst_tx: Some("FrstTx".to_string()),
+			last_tx: None,
+		}
+	}
+}
+
+impl Example for crate::common::CardTransaction17 {
+	fn example() -> Self {
+		Self {
+			card: Some(Example::example()),
+			poi: None,
+			tx: None,
+			pre_pd_acct: None,
+		}
+	}
+}
+
+impl Example for crate::common::CardTransaction3Choice {
+	fn example() -> Self {
+		Self {
+			aggtd: Some(Example::example()),
+			indv: None,
+		}
+	}
+}
+
+impl Example for crate::common::CardholderAuthentication2 {
+	fn example() -> Self {
+		Self {
+			authntcn_mtd: Example::example(),
+			authntcn_ntty: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::CardholderVerificationCapability1Code {
+	fn example() -> Self {
+		Self::CodeMNSG
+	}
+}
+
+impl Example for crate::common::Case5 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			cretr: Example::example(),
+			reop_case_indctn: None,
+		}
+	}
+}
+
+impl Example for crate::common::CaseAssignment5 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			assgnr: Example::example(),
+			assgne: Example::example(),
+			cre_dt_tm: "2024-01-01T00:00:00".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::CashAccount38 {
+	fn example() -> Self {
+		Self {
+			id: Example::example(),
+			tp: None,
+			ccy: None,
+			nm: None,
+			prxy: None,
+		}
+	}
+}
+
+impl Example for crate::common::CashAccount39 {
+	fn example() -> Self {
+		Self {
+			id: Example::example(),
+			tp: None,
+			ccy: None,
+			nm: None,
+			prxy: None,
+			ownr: None,
+			svcr: None,
+		}
+	}
+}
+
+impl Example for crate::common::CashAccountType2Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::CashAvailability1 {
+	fn example() -> Self {
+		Self {
+			dt: Example::example(),
+			amt: Example::example(),
+			cdt_dbt_ind: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::CashAvailabilityDate1Choice {
+	fn example() -> Self {
+		Self {
+			nb_of_days: Some("0".to_string()),
+			actl_dt: None,
+		}
+	}
+}
+
+impl Example for crate::common::CashBalance8 {
+	fn example() -> Self {
+		Self {
+			tp: Example::example(),
+			cdt_line: None,
+			amt: Example::example(),
+			cdt_dbt_ind: Example::example(),
+			dt: Example::example(),
+			avlbty: None,
+		}
+	}
+}
+
+impl Example for crate::common::CashDeposit1 {
+	fn example() -> Self {
+		Self {
+			note_dnmtn: Example::example(),
+			nb_of_notes: "1".to_string(),
+			amt: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::CategoryPurpose1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ChargeBearerType1Code {
+	fn example() -> Self {
+		Self::CodeDEBT
+	}
+}
+
+impl Example for crate::common::ChargeType3Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::Charges6 {
+	fn example() -> Self {
+		Self {
+			ttl_chrgs_and_tax_amt: Some(Example::example()),
+			rcrd: None,
+		}
+	}
+}
+
+impl Example for crate::common::Charges7 {
+	fn example() -> Self {
+		Self {
+			amt: Example::example(),
+			agt: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::ChargesRecord3 {
+	fn example() -> Self {
+		Self {
+			amt: Example::example(),
+			cdt_dbt_ind: None,
+			chrg_incl_ind: None,
+			tp: None,
+			rate: None,
+			br: None,
+			agt: None,
+			tax: None,
+		}
+	}
+}
+
+impl Example for crate::common::Cheque11 {
+	fn example() -> Self {
+		Self {
+			chq_tp: Some(Example::example()),
+			chq_nb: None,
+			chq_fr: None,
+			dlvry_mtd: None,
+			dlvr_to: None,
+			instr_prty: None,
+			chq_mtrty_dt: None,
+			frms_cd: None,
+			memo_fld: None,
+			rgnl_clr_zone: None,
+			prt_lctn: None,
+			sgntr: None,
+		}
+	}
+}
+
+impl Example for crate::common::ChequeDelivery1Code {
+	fn example() -> Self {
+		Self::CodeMLDB
+	}
+}
+
+impl Example for crate::common::ChequeDeliveryMethod1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ChequeType2Code {
+	fn example() -> Self {
+		Self::CodeCCHQ
+	}
+}
+
+impl Example for crate::common::ClaimNonReceipt2 {
+	fn example() -> Self {
+		Self {
+			dt_prcd: "DtPrcd".to_string(),
+			orgnl_nxt_agt: None,
+		}
+	}
+}
+
+impl Example for crate::common::ClaimNonReceipt2Choice {
+	fn example() -> Self {
+		Self {
+			accptd: Some(Example::example()),
+			rjctd: None,
+		}
+	}
+}
+
+impl Example for crate::common::ClaimNonReceiptRejectReason1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ClearingChannel2Code {
+	fn example() -> Self {
+		Self::CodeRTGS
+	}
+}
+
+impl Example for crate::common::ClearingSystemIdentification2Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ClearingSystemIdentification3Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ClearingSystemMemberIdentification2 {
+	fn example() -> Self {
+		Self {
+			clr_sys_id: None,
+			mmb_id: "MmbId".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::Compensation2 {
+	fn example() -> Self {
+		Self {
+			amt: Example::example(),
+			dbtr_agt: Example::example(),
+			cdtr_agt: Example::example(),
+			rsn: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::CompensationReason1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::Contact4 {
+	fn example() -> Self {
+		Self {
+			nm_prfx: Some(Example::example()),
+			nm: None,
+			phne_nb: None,
+			mob_nb: None,
+			fax_nb: None,
+			email_adr: None,
+			email_purp: None,
+			job_titl: None,
+			rspnsblty: None,
+			dept: None,
+			othr: None,
+			prefrd_mtd: None,
+		}
+	}
+}
+
+impl Example for crate::common::ControlData1 {
+	fn example() -> Self {
+		Self {
+			nb_of_txs: "1".to_string(),
+			ctrl_sum: None,
+		}
+	}
+}
+
+impl Example for crate::common::CopyDuplicate1Code {
+	fn example() -> Self {
+		Self::CodeCODU
+	}
+}
+
+impl Example for crate::common::CorporateAction9 {
+	fn example() -> Self {
+		Self {
+			evt_tp: "EvtTp".to_string(),
+			evt_id: "EvtId".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::CorrectiveGroupInformation1 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			msg_nm_id: "MsgNmId".to_string(),
+			cre_dt_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::CorrectiveInterbankTransaction2 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: None,
+			instr_id: None,
+			end_to_end_id: None,
+			tx_id: None,
+			uetr: None,
+			intr_bk_sttlm_amt: Example::example(),
+			intr_bk_sttlm_dt: "2024-01-01".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::CorrectivePaymentInitiation4 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: None,
+			pmt_inf_id: None,
+			instr_id: None,
+			end_to_end_id: None,
+			uetr: None,
+			instd_amt: Example::example(),
+			reqd_exctn_dt: None,
+			reqd_colltn_dt: None,
+		}
+	}
+}
+
+impl Example for crate::common::CorrectiveTransaction4Choice {
+	fn example() -> Self {
+		Self {
+			initn: Some(Example::example()),
+			intr_bk: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditDebitCode {
+	fn example() -> Self {
+		Self::CodeCRDT
+	}
+}
+
+impl Example for crate::common::CreditLine3 {
+	fn example() -> Self {
+		Self {
+			incl: false,
+			tp: None,
+			amt: None,
+			dt: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditLineType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditTransferMandateData1 {
+	fn example() -> Self {
+		Self {
+			mndt_id: Some("MndtId".to_string()),
+			tp: None,
+			dt_of_sgntr: None,
+			dt_of_vrfctn: None,
+			elctrnc_sgntr: None,
+			frst_pmt_dt: None,
+			fnl_pmt_dt: None,
+			frqcy: None,
+			rsn: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditTransferTransaction35 {
+	fn example() -> Self {
+		Self {
+			pmt_id: Example::example(),
+			pmt_tp_inf: None,
+			pmt_cond: None,
+			amt: Example::example(),
+			chrg_br: Example::example(),
+			chq_instr: None,
+			ultmt_dbtr: None,
+			intrmy_agt1: None,
+			intrmy_agt2: None,
+			intrmy_agt3: None,
+			cdtr_agt: Example::example(),
+			cdtr: Example::example(),
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			instr_for_cdtr_agt: None,
+			purp: None,
+			rgltry_rptg: None,
+			tax: None,
+			rltd_rmt_inf: None,
+			rmt_inf: None,
+			nclsd_file: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditTransferTransaction36 {
+	fn example() -> Self {
+		Self {
+			pmt_id: Example::example(),
+			pmt_tp_inf: None,
+			intr_bk_sttlm_amt: Example::example(),
+			intr_bk_sttlm_dt: None,
+			sttlm_prty: None,
+			sttlm_tm_indctn: None,
+			sttlm_tm_req: None,
+			prvs_instg_agt1: None,
+			prvs_instg_agt1_acct: None,
+			prvs_instg_agt2: None,
+			prvs_instg_agt2_acct: None,
+			prvs_instg_agt3: None,
+			prvs_instg_agt3_acct: None,
+			instg_agt: None,
+			instd_agt: None,
+			intrmy_agt1: None,
+			intrmy_agt1_acct: None,
+			intrmy_agt2: None,
+			intrmy_agt2_acct: None,
+			intrmy_agt3: None,
+			intrmy_agt3_acct: None,
+			ultmt_dbtr: None,
+			dbtr: Example::example(),
+			dbtr_acct: None,
+			dbtr_agt: None,
+			dbtr_agt_acct: None,
+			cdtr_agt: None,
+			cdtr_agt_acct: None,
+			cdtr: Example::example(),
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			instr_for_cdtr_agt: None,
+			instr_for_nxt_agt: None,
+			purp: None,
+			rmt_inf: None,
+			undrlyg_cstmr_cdt_trf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditTransferTransaction37 {
+	fn example() -> Self {
+		Self {
+			ultmt_dbtr: None,
+			initg_pty: None,
+			dbtr: Example::example(),
+			dbtr_acct: None,
+			dbtr_agt: Example::example(),
+			dbtr_agt_acct: None,
+			prvs_instg_agt1: None,
+			prvs_instg_agt1_acct: None,
+			prvs_instg_agt2: None,
+			prvs_instg_agt2_acct: None,
+			prvs_instg_agt3: None,
+			prvs_instg_agt3_acct: None,
+			intrmy_agt1: None,
+			intrmy_agt1_acct: None,
+			intrmy_agt2: None,
+			intrmy_agt2_acct: None,
+			intrmy_agt3: None,
+			intrmy_agt3_acct: None,
+			cdtr_agt: Example::example(),
+			cdtr_agt_acct: None,
+			cdtr: Example::example(),
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			instr_for_cdtr_agt: None,
+			instr_for_nxt_agt: None,
+			tax: None,
+			rmt_inf: None,
+			instd_amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditTransferTransaction39 {
+	fn example() -> Self {
+		Self {
+			pmt_id: Example::example(),
+			pmt_tp_inf: None,
+			intr_bk_sttlm_amt: Example::example(),
+			intr_bk_sttlm_dt: None,
+			sttlm_prty: None,
+			sttlm_tm_indctn: None,
+			sttlm_tm_req: None,
+			accptnc_dt_tm: None,
+			poolg_adjstmnt_dt: None,
+			instd_amt: None,
+			xchg_rate: None,
+			chrg_br: Example::example(),
+			chrgs_inf: None,
+			prvs_instg_agt1: None,
+			prvs_instg_agt1_acct: None,
+			prvs_instg_agt2: None,
+			prvs_instg_agt2_acct: None,
+			prvs_instg_agt3: None,
+			prvs_instg_agt3_acct: None,
+			instg_agt: None,
+			instd_agt: None,
+			intrmy_agt1: None,
+			intrmy_agt1_acct: None,
+			intrmy_agt2: None,
+			intrmy_agt2_acct: None,
+			intrmy_agt3: None,
+			intrmy_agt3_acct: None,
+			ultmt_dbtr: None,
+			initg_pty: None,
+			dbtr: Example::example(),
+			dbtr_acct: None,
+			dbtr_agt: Example::example(),
+			dbtr_agt_acct: None,
+			cdtr_agt: Example::example(),
+			cdtr_agt_acct: None,
+			cdtr: Example::example(),
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			instr_for_cdtr_agt: None,
+			instr_for_nxt_agt: None,
+			purp: None,
+			rgltry_rptg: None,
+			tax: None,
+			rltd_rmt_inf: None,
+			rmt_inf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditTransferTransaction45 {
+	fn example() -> Self {
+		Self {
+			ultmt_dbtr: None,
+			initg_pty: None,
+			dbtr: Example::example(),
+			dbtr_acct: None,
+			dbtr_agt: Example::example(),
+			dbtr_agt_acct: None,
+			prvs_instg_agt1: None,
+			prvs_instg_agt1_acct: None,
+			prvs_instg_agt2: None,
+			prvs_instg_agt2_acct: None,
+			prvs_instg_agt3: None,
+			prvs_instg_agt3_acct: None,
+			intrmy_agt1: None,
+			intrmy_agt1_acct: None,
+			intrmy_agt2: None,
+			intrmy_agt2_acct: None,
+			intrmy_agt3: None,
+			intrmy_agt3_acct: None,
+			cdtr_agt: Example::example(),
+			cdtr_agt_acct: None,
+			cdtr: Example::example(),
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			instr_for_cdtr_agt: None,
+			instr_for_nxt_agt: None,
+			tax: None,
+			rmt_inf: None,
+			instd_amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditorReferenceInformation2 {
+	fn example() -> Self {
+		Self {
+			tp: Some(Example::example()),
+			ref_attr: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditorReferenceType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::CreditorReferenceType2 {
+	fn example() -> Self {
+		Self {
+			cd_or_prtry: Example::example(),
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::CurrencyExchange5 {
+	fn example() -> Self {
+		Self {
+			src_ccy: "USD".to_string(),
+			trgt_ccy: None,
+			unit_ccy: None,
+			xchg_rate: 1.0,
+			ctrct_id: None,
+			qtn_dt: None,
+		}
+	}
+}
+
+impl Example for crate::common::DateAndDateTime2Choice {
+	fn example() -> Self {
+		Self {
+			dt: Some("2024-01-01".to_string()),
+			dt_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::DateAndPlaceOfBirth1 {
+	fn example() -> Self {
+		Self {
+			birth_dt: "2024-01-01".to_string(),
+			prvc_of_birth: None,
+			city_of_birth: "CityOfBirth".to_string(),
+			ctry_of_birth: "US".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::DateOrDateTimePeriod1Choice {
+	fn example() -> Self {
+		Self {
+			dt: Some(Example::example()),
+			dt_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::DatePeriod2 {
+	fn example() -> Self {
+		Self {
+			fr_dt: "2024-01-01".to_string(),
+			to_dt: "2024-01-01".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::DatePeriodDetails1 {
+	fn example() -> Self {
+		Self {
+			fr_dt: "2024-01-01".to_string(),
+			to_dt: None,
+		}
+	}
+}
+
+impl Example for crate::common::DateTimePeriod1 {
+	fn example() -> Self {
+		Self {
+			fr_dt_tm: "2024-01-01T00:00:00".to_string(),
+			to_dt_tm: "2024-01-01T00:00:00".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::DiscountAmountAndType1 {
+	fn example() -> Self {
+		Self {
+			tp: None,
+			amt: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::DiscountAmountType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::DisplayCapabilities1 {
+	fn example() -> Self {
+		Self {
+			disp_tp: Example::example(),
+			nb_of_lines: "0".to_string(),
+			line_width: "0".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::Document12 {
+	fn example() -> Self {
+		Self {
+			tp: Example::example(),
+			id: "Id".to_string(),
+			isse_dt: Example::example(),
+			nm: None,
+			lang_cd: None,
+			frmt: Example::example(),
+			file_nm: None,
+			dgtl_sgntr: None,
+			nclsr: "Nclsr".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::DocumentAdjustment1 {
+	fn example() -> Self {
+		Self {
+			amt: Example::example(),
+			cdt_dbt_ind: None,
+			rsn: None,
+			addtl_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::DocumentFormat1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::DocumentLineIdentification1 {
+	fn example() -> Self {
+		Self {
+			tp: Some(Example::example()),
+			nb: None,
+			rltd_dt: None,
+		}
+	}
+}
+
+impl Example for crate::common::DocumentLineInformation1 {
+	fn example() -> Self {
+		Self {
+			id: vec![Example::example()],
+			desc: None,
+			amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::DocumentLineType1 {
+	fn example() -> Self {
+		Self {
+			cd_or_prtry: Example::example(),
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::DocumentLineType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::DocumentType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::DocumentType3Code {
+	fn example() -> Self {
+		Self::CodeRADM
+	}
+}
+
+impl Example for crate::common::DocumentType6Code {
+	fn example() -> Self {
+		Self::CodeMSIN
+	}
+}
+
+impl Example for crate::common::EntryDetails9 {
+	fn example() -> Self {
+		Self {
+			btch: Some(Example::example()),
+			tx_dtls: None,
+		}
+	}
+}
+
+impl Example for crate::common::EntryStatus1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::EntryTransaction10 {
+	fn example() -> Self {
+		Self {
+			refs: Some(Example::example()),
+			amt: None,
+			cdt_dbt_ind: None,
+			amt_dtls: None,
+			avlbty: None,
+			bk_tx_cd: None,
+			chrgs: None,
+			intrst: None,
+			rltd_pties: None,
+			rltd_agts: None,
+			lcl_instrm: None,
+			purp: None,
+			rltd_rmt_inf: None,
+			rmt_inf: None,
+			rltd_dts: None,
+			rltd_pric: None,
+			rltd_qties: None,
+			fin_instrm_id: None,
+			tax: None,
+			rtr_inf: None,
+			corp_actn: None,
+			sfkpg_acct: None,
+			csh_dpst: None,
+			card_tx: None,
+			addtl_tx_inf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::EquivalentAmount2 {
+	fn example() -> Self {
+		Self {
+			amt: Example::example(),
+			ccy_of_trf: "USD".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::Event1 {
+	fn example() -> Self {
+		Self {
+			evt_cd: "a".to_string(),
+			evt_param: None,
+			evt_desc: None,
+			evt_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::Event2 {
+	fn example() -> Self {
+		Self {
+			evt_cd: "a".to_string(),
+			evt_param: None,
+			evt_desc: None,
+			evt_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::FinancialIdentificationSchemeName1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::FinancialInstitutionIdentification18 {
+	fn example() -> Self {
+		Self {
+			bicfi: Some("BANKUS33XXX".to_string()),
+			clr_sys_mmb_id: None,
+			lei: None,
+			nm: None,
+			pstl_adr: None,
+			othr: None,
+		}
+	}
+}
+
+impl Example for crate::common::FinancialInstrumentQuantity1Choice {
+	fn example() -> Self {
+		Self {
+			unit: Some(1.0),
+			face_amt: None,
+			amtsd_val: None,
+		}
+	}
+}
+
+impl Example for crate::common::FloorLimitType1Code {
+	fn example() -> Self {
+		Self::CodeCRED
+	}
+}
+
+impl Example for crate::common::Frequency36Choice {
+	fn example() -> Self {
+		Self {
+			tp: Some(Example::example()),
+			prd: None,
+			pt_in_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::Frequency6Code {
+	fn example() -> Self {
+		Self::CodeYEAR
+	}
+}
+
+impl Example for crate::common::FrequencyAndMoment1 {
+	fn example() -> Self {
+		Self {
+			tp: Example::example(),
+			pt_in_tm: "00".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::FrequencyPeriod1 {
+	fn example() -> Self {
+		Self {
+			tp: Example::example(),
+			cnt_per_prd: 1.0,
+		}
+	}
+}
+
+impl Example for crate::common::FromToAmountRange1 {
+	fn example() -> Self {
+		Self {
+			fr_amt: Example::example(),
+			to_amt: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::Garnishment3 {
+	fn example() -> Self {
+		Self {
+			tp: Example::example(),
+			grnshee: None,
+			grnshmt_admstr: None,
+			ref_nb: None,
+			dt: None,
+			rmtd_amt: None,
+			fmly_mdcl_insrnc_ind: None,
+			mplyee_termntn_ind: None,
+		}
+	}
+}
+
+impl Example for crate::common::GarnishmentType1 {
+	fn example() -> Self {
+		Self {
+			cd_or_prtry: Example::example(),
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::GarnishmentType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::GenericAccountIdentification1 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			schme_nm: None,
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::GenericFinancialIdentification1 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			schme_nm: None,
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::GenericIdentification1 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			schme_nm: None,
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::GenericIdentification3 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::GenericIdentification30 {
+	fn example() -> Self {
+		Self {
+			id: "aaaa".to_string(),
+			issr: "Issr".to_string(),
+			schme_nm: None,
+		}
+	}
+}
+
+impl Example for crate::common::GenericIdentification32 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			tp: None,
+			issr: None,
+			shrt_nm: None,
+		}
+	}
+}
+
+impl Example for crate::common::GenericIdentification36 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			issr: "Issr".to_string(),
+			schme_nm: None,
+		}
+	}
+}
+
+impl Example for crate::common::GenericOrganisationIdentification1 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			schme_nm: None,
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::GenericPersonIdentification1 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			schme_nm: None,
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::GroupCancellationStatus1Code {
+	fn example() -> Self {
+		Self::CodePACR
+	}
+}
+
+impl Example for crate::common::GroupHeader77 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			cre_dt_tm: "2024-01-01T00:00:00".to_string(),
+			msg_sndr: None,
+		}
+	}
+}
+
+impl Example for crate::common::GroupHeader78 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			cre_dt_tm: "2024-01-01T00:00:00".to_string(),
+			nb_of_txs: "1".to_string(),
+			ctrl_sum: None,
+			initg_pty: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::GroupHeader81 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			cre_dt_tm: "2024-01-01T00:00:00".to_string(),
+			msg_rcpt: None,
+			msg_pgntn: None,
+			orgnl_biz_qry: None,
+			addtl_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::GroupHeader87 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			cre_dt_tm: "2024-01-01T00:00:00".to_string(),
+			initg_pty: Example::example(),
+			dbtr_agt: None,
+			cdtr_agt: None,
+		}
+	}
+}
+
+impl Example for crate::common::GroupHeader90 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			cre_dt_tm: "2024-01-01T00:00:00".to_string(),
+			authstn: None,
+			btch_bookg: None,
+			nb_of_txs: "1".to_string(),
+			ctrl_sum: None,
+			grp_rtr: None,
+			ttl_rtrd_intr_bk_sttlm_amt: None,
+			intr_bk_sttlm_dt: None,
+			sttlm_inf: Example::example(),
+			instg_agt: None,
+			instd_agt: None,
+		}
+	}
+}
+
+impl Example for crate::common::GroupHeader91 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			cre_dt_tm: "2024-01-01T00:00:00".to_string(),
+			instg_agt: None,
+			instd_agt: None,
+		}
+	}
+}
+
+impl Example for crate::common::GroupHeader93 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			cre_dt_tm: "2024-01-01T00:00:00".to_string(),
+			btch_bookg: None,
+			nb_of_txs: "1".to_string(),
+			ctrl_sum: None,
+			ttl_intr_bk_sttlm_amt: None,
+			intr_bk_sttlm_dt: None,
+			sttlm_inf: Example::example(),
+			pmt_tp_inf: None,
+			instg_agt: None,
+			instd_agt: None,
+		}
+	}
+}
+
+impl Example for crate::common::IdentificationSource3Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ImplementationSpecification1 {
+	fn example() -> Self {
+		Self {
+			regy: "Regy".to_string(),
+			id: "Id".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::ImpliedCurrencyAmountRange1Choice {
+	fn example() -> Self {
+		Self {
+			fr_amt: Some(Example::example()),
+			to_amt: None,
+			fr_to_amt: None,
+			eq_amt: None,
+			neq_amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::Instruction3Code {
+	fn example() -> Self {
+		Self::CodeCHQB
+	}
+}
+
+impl Example for crate::common::Instruction4Code {
+	fn example() -> Self {
+		Self::CodePHOA
+	}
+}
+
+impl Example for crate::common::Instruction5Code {
+	fn example() -> Self {
+		Self::CodePHOB
+	}
+}
+
+impl Example for crate::common::InstructionForCreditorAgent1 {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			instr_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::InstructionForCreditorAgent2 {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			instr_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::InstructionForCreditorAgent3 {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			instr_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::InstructionForNextAgent1 {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			instr_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::InterestRecord2 {
+	fn example() -> Self {
+		Self {
+			amt: Example::example(),
+			cdt_dbt_ind: Example::example(),
+			tp: None,
+			rate: None,
+			fr_to_dt: None,
+			rsn: None,
+			tax: None,
+		}
+	}
+}
+
+impl Example for crate::common::InterestType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::InterestType1Code {
+	fn example() -> Self {
+		Self::CodeINDY
+	}
+}
+
+impl Example for crate::common::InvestigationStatus5Choice {
+	fn example() -> Self {
+		Self {
+			conf: Some("Conf".to_string()),
+			rjctd_mod: None,
+			dplct_of: None,
+			assgnmt_cxl_conf: None,
+		}
+	}
+}
+
+impl Example for crate::common::Limit2 {
+	fn example() -> Self {
+		Self {
+			amt: Example::example(),
+			cdt_dbt_ind: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::LocalInstrument2Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::MandateClassification1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::MandateClassification1Code {
+	fn example() -> Self {
+		Self::CodeFIXE
+	}
+}
+
+impl Example for crate::common::MandateRelatedData1Choice {
+	fn example() -> Self {
+		Self {
+			drct_dbt_mndt: Some(Example::example()),
+			cdt_trf_mndt: None,
+		}
+	}
+}
+
+impl Example for crate::common::MandateRelatedInformation14 {
+	fn example() -> Self {
+		Self {
+			mndt_id: Some("MndtId".to_string()),
+			dt_of_sgntr: None,
+			amdmnt_ind: None,
+			amdmnt_inf_dtls: None,
+			elctrnc_sgntr: None,
+			frst_colltn_dt: None,
+			fnl_colltn_dt: None,
+			frqcy: None,
+			rsn: None,
+			trckg_days: None,
+		}
+	}
+}
+
+impl Example for crate::common::MandateSetupReason1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::MandateTypeInformation2 {
+	fn example() -> Self {
+		Self {
+			svc_lvl: Some(Example::example()),
+			lcl_instrm: None,
+			ctgy_purp: None,
+			clssfctn: None,
+		}
+	}
+}
+
+impl Example for crate::common::MessageHeader10 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			cre_dt_tm: None,
+			qry_nm: None,
+		}
+	}
+}
+
+impl Example for crate::common::MessageHeader7 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			cre_dt_tm: None,
+			req_tp: None,
+			orgnl_biz_qry: None,
+			qry_nm: None,
+		}
+	}
+}
+
+impl Example for crate::common::MessageIdentification2 {
+	fn example() -> Self {
+		Self {
+			msg_nm_id: Some("MsgNmId".to_string()),
+			msg_id: None,
+		}
+	}
+}
+
+impl Example for crate::common::MessageReference {
+	fn example() -> Self {
+		Self {
+			ref_attr: "Ref".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::MessageReference1 {
+	fn example() -> Self {
+		Self {
+			ref_attr: "Ref".to_string(),
+			msg_nm: None,
+			ref_issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::MissingOrIncorrectInformation3 {
+	fn example() -> Self {
+		Self {
+			aml_req: Some(false),
+			mssng_inf: None,
+			incrrct_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::ModificationStatusReason1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ModificationStatusReason2 {
+	fn example() -> Self {
+		Self {
+			orgtr: Some(Example::example()),
+			rsn: None,
+			addtl_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::NameAndAddress16 {
+	fn example() -> Self {
+		Self {
+			nm: "Nm".to_string(),
+			adr: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::NameAndAddress5 {
+	fn example() -> Self {
+		Self {
+			nm: "Nm".to_string(),
+			adr: None,
+		}
+	}
+}
+
+impl Example for crate::common::NamePrefix2Code {
+	fn example() -> Self {
+		Self::CodeDOCT
+	}
+}
+
+impl Example for crate::common::NumberAndSumOfTransactions1 {
+	fn example() -> Self {
+		Self {
+			nb_of_ntries: Some("1".to_string()),
+			sum: None,
+		}
+	}
+}
+
+impl Example for crate::common::NumberAndSumOfTransactions4 {
+	fn example() -> Self {
+		Self {
+			nb_of_ntries: Some("1".to_string()),
+			sum: None,
+			ttl_net_ntry: None,
+		}
+	}
+}
+
+impl Example for crate::common::NumberOfCancellationsPerStatus1 {
+	fn example() -> Self {
+		Self {
+			dtld_nb_of_txs: "1".to_string(),
+			dtld_sts: Example::example(),
+			dtld_ctrl_sum: None,
+		}
+	}
+}
+
+impl Example for crate::common::NumberOfTransactionsPerStatus1 {
+	fn example() -> Self {
+		Self {
+			dtld_nb_of_txs: "1".to_string(),
+			dtld_sts: Example::example(),
+			dtld_ctrl_sum: None,
+		}
+	}
+}
+
+impl Example for crate::common::NumberOfTransactionsPerStatus5 {
+	fn example() -> Self {
+		Self {
+			dtld_nb_of_txs: "1".to_string(),
+			dtld_sts: "Dtld".to_string(),
+			dtld_ctrl_sum: None,
+		}
+	}
+}
+
+impl Example for crate::common::OnLineCapability1Code {
+	fn example() -> Self {
+		Self::CodeOFLN
+	}
+}
+
+impl Example for crate::common::OrganisationIdentification29 {
+	fn example() -> Self {
+		Self {
+			any_bic: Some("BANKUS33XXX".to_string()),
+			lei: None,
+			othr: None,
+		}
+	}
+}
+
+impl Example for crate::common::OrganisationIdentificationSchemeName1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalAndCurrentQuantities1 {
+	fn example() -> Self {
+		Self {
+			face_amt: 1.0,
+			amtsd_val: 1.0,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalBusinessQuery1 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			msg_nm_id: None,
+			cre_dt_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalGroupHeader14 {
+	fn example() -> Self {
+		Self {
+			orgnl_grp_cxl_id: None,
+			rslvd_case: None,
+			orgnl_msg_id: "OrgnlMsgId".to_string(),
+			orgnl_msg_nm_id: "OrgnlMsgNmId".to_string(),
+			orgnl_cre_dt_tm: None,
+			orgnl_nb_of_txs: None,
+			orgnl_ctrl_sum: None,
+			grp_cxl_sts: None,
+			cxl_sts_rsn_inf: None,
+			nb_of_txs_per_cxl_sts: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalGroupHeader15 {
+	fn example() -> Self {
+		Self {
+			grp_cxl_id: None,
+			case: None,
+			orgnl_msg_id: "OrgnlMsgId".to_string(),
+			orgnl_msg_nm_id: "OrgnlMsgNmId".to_string(),
+			orgnl_cre_dt_tm: None,
+			nb_of_txs: None,
+			ctrl_sum: None,
+			grp_cxl: None,
+			cxl_rsn_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalGroupHeader17 {
+	fn example() -> Self {
+		Self {
+			orgnl_msg_id: "OrgnlMsgId".to_string(),
+			orgnl_msg_nm_id: "OrgnlMsgNmId".to_string(),
+			orgnl_cre_dt_tm: None,
+			orgnl_nb_of_txs: None,
+			orgnl_ctrl_sum: None,
+			grp_sts: None,
+			sts_rsn_inf: None,
+			nb_of_txs_per_sts: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalGroupHeader18 {
+	fn example() -> Self {
+		Self {
+			orgnl_msg_id: "OrgnlMsgId".to_string(),
+			orgnl_msg_nm_id: "OrgnlMsgNmId".to_string(),
+			orgnl_cre_dt_tm: None,
+			rtr_rsn_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalGroupInformation27 {
+	fn example() -> Self {
+		Self {
+			orgnl_msg_id: "OrgnlMsgId".to_string(),
+			orgnl_msg_nm_id: "OrgnlMsgNmId".to_string(),
+			orgnl_cre_dt_tm: None,
+			orgnl_nb_of_txs: None,
+			orgnl_ctrl_sum: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalGroupInformation29 {
+	fn example() -> Self {
+		Self {
+			orgnl_msg_id: "OrgnlMsgId".to_string(),
+			orgnl_msg_nm_id: "OrgnlMsgNmId".to_string(),
+			orgnl_cre_dt_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalGroupInformation30 {
+	fn example() -> Self {
+		Self {
+			orgnl_msg_id: "OrgnlMsgId".to_string(),
+			orgnl_msg_nm_id: "OrgnlMsgNmId".to_string(),
+			orgnl_cre_dt_tm: None,
+			orgnl_nb_of_txs: None,
+			orgnl_ctrl_sum: None,
+			grp_sts: None,
+			sts_rsn_inf: None,
+			nb_of_txs_per_sts: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalPaymentInstruction30 {
+	fn example() -> Self {
+		Self {
+			orgnl_pmt_inf_cxl_id: None,
+			rslvd_case: None,
+			orgnl_pmt_inf_id: "OrgnlPmtInfId".to_string(),
+			orgnl_grp_inf: None,
+			orgnl_nb_of_txs: None,
+			orgnl_ctrl_sum: None,
+			pmt_inf_cxl_sts: None,
+			cxl_sts_rsn_inf: None,
+			nb_of_txs_per_cxl_sts: None,
+			tx_inf_and_sts: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalPaymentInstruction31 {
+	fn example() -> Self {
+		Self {
+			orgnl_pmt_inf_id: "OrgnlPmtInfId".to_string(),
+			orgnl_nb_of_txs: None,
+			orgnl_ctrl_sum: None,
+			pmt_inf_sts: None,
+			sts_rsn_inf: None,
+			nb_of_txs_per_sts: None,
+			tx_inf_and_sts: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalPaymentInstruction36 {
+	fn example() -> Self {
+		Self {
+			pmt_cxl_id: None,
+			case: None,
+			orgnl_pmt_inf_id: "OrgnlPmtInfId".to_string(),
+			orgnl_grp_inf: None,
+			nb_of_txs: None,
+			ctrl_sum: None,
+			pmt_inf_cxl: None,
+			cxl_rsn_inf: None,
+			tx_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalTransactionReference28 {
+	fn example() -> Self {
+		Self {
+			intr_bk_sttlm_amt: Some(Example::example()),
+			amt: None,
+			intr_bk_sttlm_dt: None,
+			reqd_colltn_dt: None,
+			reqd_exctn_dt: None,
+			cdtr_schme_id: None,
+			sttlm_inf: None,
+			pmt_tp_inf: None,
+			pmt_mtd: None,
+			mndt_rltd_inf: None,
+			rmt_inf: None,
+			ultmt_dbtr: None,
+			dbtr: None,
+			dbtr_acct: None,
+			dbtr_agt: None,
+			dbtr_agt_acct: None,
+			cdtr_agt: None,
+			cdtr_agt_acct: None,
+			cdtr: None,
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			purp: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalTransactionReference29 {
+	fn example() -> Self {
+		Self {
+			amt: None,
+			reqd_exctn_dt: None,
+			xpry_dt: None,
+			pmt_cond: None,
+			pmt_tp_inf: None,
+			pmt_mtd: None,
+			rmt_inf: None,
+			nclsd_file: None,
+			ultmt_dbtr: None,
+			dbtr: None,
+			dbtr_acct: None,
+			dbtr_agt: None,
+			cdtr_agt: Example::example(),
+			cdtr: Example::example(),
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalTransactionReference31 {
+	fn example() -> Self {
+		Self {
+			intr_bk_sttlm_amt: Some(Example::example()),
+			amt: None,
+			intr_bk_sttlm_dt: None,
+			reqd_colltn_dt: None,
+			reqd_exctn_dt: None,
+			cdtr_schme_id: None,
+			sttlm_inf: None,
+			pmt_tp_inf: None,
+			pmt_mtd: None,
+			mndt_rltd_inf: None,
+			rmt_inf: None,
+			ultmt_dbtr: None,
+			dbtr: None,
+			dbtr_acct: None,
+			dbtr_agt: None,
+			dbtr_agt_acct: None,
+			cdtr_agt: None,
+			cdtr_agt_acct: None,
+			cdtr: None,
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			purp: None,
+		}
+	}
+}
+
+impl Example for crate::common::OriginalTransactionReference32 {
+	fn example() -> Self {
+		Self {
+			intr_bk_sttlm_amt: Some(Example::example()),
+			amt: None,
+			intr_bk_sttlm_dt: None,
+			reqd_colltn_dt: None,
+			reqd_exctn_dt: None,
+			cdtr_schme_id: None,
+			sttlm_inf: None,
+			pmt_tp_inf: None,
+			pmt_mtd: None,
+			mndt_rltd_inf: None,
+			rmt_inf: None,
+			ultmt_dbtr: None,
+			dbtr: None,
+			dbtr_acct: None,
+			dbtr_agt: None,
+			dbtr_agt_acct: None,
+			cdtr_agt: None,
+			cdtr_agt_acct: None,
+			cdtr: None,
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			purp: None,
+			undrlyg_cstmr_cdt_trf: None,
+		}
+	}
+}
+
+impl Example for crate::common::OtherContact1 {
+	fn example() -> Self {
+		Self {
+			chanl_tp: "Chan".to_string(),
+			id: None,
+		}
+	}
+}
+
+impl Example for crate::common::OtherIdentification1 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			sfx: None,
+			tp: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::POIComponentType1Code {
+	fn example() -> Self {
+		Self::CodeSOFT
+	}
+}
+
+impl Example for crate::common::Pagination1 {
+	fn example() -> Self {
+		Self {
+			pg_nb: "0".to_string(),
+			last_pg_ind: false,
+		}
+	}
+}
+
+impl Example for crate::common::Party38Choice {
+	fn example() -> Self {
+		Self {
+			org_id: Some(Example::example()),
+			prvt_id: None,
+		}
+	}
+}
+
+impl Example for crate::common::Party40Choice {
+	fn example() -> Self {
+		Self {
+			pty: Some(Example::example()),
+			agt: None,
+		}
+	}
+}
+
+impl Example for crate::common::Party44Choice {
+	fn example() -> Self {
+		Self {
+			org_id: Some(Example::example()),
+			fi_id: None,
+		}
+	}
+}
+
+impl Example for crate::common::PartyAndSignature3 {
+	fn example() -> Self {
+		Self {
+			pty: Example::example(),
+			sgntr: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::PartyIdentification120Choice {
+	fn example() -> Self {
+		Self {
+			any_bic: Some("BANKUS33XXX".to_string()),
+			prtry_id: None,
+			nm_and_adr: None,
+		}
+	}
+}
+
+impl Example for crate::common::PartyIdentification135 {
+	fn example() -> Self {
+		Self {
+			nm: Some("Nm".to_string()),
+			pstl_adr: None,
+			id: None,
+			ctry_of_res: None,
+			ctct_dtls: None,
+		}
+	}
+}
+
+impl Example for crate::common::PartyIdentification136 {
+	fn example() -> Self {
+		Self {
+			id: Example::example(),
+			lei: None,
+		}
+	}
+}
+
+impl Example for crate::common::PartyType3Code {
+	fn example() -> Self {
+		Self::CodeOPOI
+	}
+}
+
+impl Example for crate::common::PartyType4Code {
+	fn example() -> Self {
+		Self::CodeMERC
+	}
+}
+
+impl Example for crate::common::PaymentCancellationReason5 {
+	fn example() -> Self {
+		Self {
+			orgtr: Some(Example::example()),
+			rsn: None,
+			addtl_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentCard4 {
+	fn example() -> Self {
+		Self {
+			plain_card_data: Some(Example::example()),
+			card_ctry_cd: None,
+			card_brnd: None,
+			addtl_card_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentComplementaryInformation8 {
+	fn example() -> Self {
+		Self {
+			instr_id: Some("InstrId".to_string()),
+			end_to_end_id: None,
+			tx_id: None,
+			pmt_tp_inf: None,
+			reqd_exctn_dt: None,
+			reqd_colltn_dt: None,
+			intr_bk_sttlm_dt: None,
+			amt: None,
+			intr_bk_sttlm_amt: None,
+			chrg_br: None,
+			ultmt_dbtr: None,
+			dbtr: None,
+			dbtr_acct: None,
+			dbtr_agt: None,
+			dbtr_agt_acct: None,
+			sttlm_inf: None,
+			intrmy_agt1: None,
+			intrmy_agt1_acct: None,
+			intrmy_agt2: None,
+			intrmy_agt2_acct: None,
+			intrmy_agt3: None,
+			intrmy_agt3_acct: None,
+			cdtr_agt: None,
+			cdtr_agt_acct: None,
+			cdtr: None,
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			purp: None,
+			instr_for_dbtr_agt: None,
+			prvs_instg_agt1: None,
+			prvs_instg_agt1_acct: None,
+			prvs_instg_agt2: None,
+			prvs_instg_agt2_acct: None,
+			prvs_instg_agt3: None,
+			prvs_instg_agt3_acct: None,
+			instr_for_nxt_agt: None,
+			instr_for_cdtr_agt: None,
+			rmt_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentCondition1 {
+	fn example() -> Self {
+		Self {
+			amt_mod_allwd: false,
+			early_pmt_allwd: false,
+			dely_pnlty: None,
+			imdt_pmt_rbt: None,
+			grnted_pmt_reqd: false,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentConditionStatus1 {
+	fn example() -> Self {
+		Self {
+			accptd_amt: None,
+			grnted_pmt: false,
+			early_pmt: false,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentContext3 {
+	fn example() -> Self {
+		Self {
+			card_pres: None,
+			crdhldr_pres: None,
+			on_line_cntxt: None,
+			attndnc_cntxt: None,
+			tx_envt: None,
+			tx_chanl: None,
+			attndnt_msg_cpbl: None,
+			attndnt_lang: None,
+			card_data_ntry_md: Example::example(),
+			fllbck_ind: None,
+			authntcn_mtd: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentIdentification6 {
+	fn example() -> Self {
+		Self {
+			instr_id: None,
+			end_to_end_id: "EndToEndId".to_string(),
+			uetr: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentIdentification7 {
+	fn example() -> Self {
+		Self {
+			instr_id: None,
+			end_to_end_id: "EndToEndId".to_string(),
+			tx_id: None,
+			uetr: None,
+			clr_sys_ref: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentInstruction31 {
+	fn example() -> Self {
+		Self {
+			pmt_inf_id: None,
+			pmt_mtd: Example::example(),
+			pmt_tp_inf: None,
+			reqd_exctn_dt: Example::example(),
+			xpry_dt: None,
+			pmt_cond: None,
+			dbtr: Example::example(),
+			dbtr_acct: None,
+			dbtr_agt: Example::example(),
+			ultmt_dbtr: None,
+			chrg_br: None,
+			cdt_trf_tx: vec![Example::example()],
+		}
+	}
+}
+
+impl Example for crate::common::PaymentMethod4Code {
+	fn example() -> Self {
+		Self::CodeCHK
+	}
+}
+
+impl Example for crate::common::PaymentMethod7Code {
+	fn example() -> Self {
+		Self::CodeCHK
+	}
+}
+
+impl Example for crate::common::PaymentReturnReason5 {
+	fn example() -> Self {
+		Self {
+			orgnl_bk_tx_cd: Some(Example::example()),
+			orgtr: None,
+			rsn: None,
+			addtl_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentReturnReason6 {
+	fn example() -> Self {
+		Self {
+			orgtr: Some(Example::example()),
+			rsn: None,
+			addtl_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTransaction102 {
+	fn example() -> Self {
+		Self {
+			cxl_sts_id: Some("CxlStsId".to_string()),
+			rslvd_case: None,
+			orgnl_grp_inf: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_tx_id: None,
+			orgnl_clr_sys_ref: None,
+			orgnl_uetr: None,
+			tx_cxl_sts: None,
+			cxl_sts_rsn_inf: None,
+			rsltn_rltd_inf: None,
+			orgnl_intr_bk_sttlm_amt: None,
+			orgnl_intr_bk_sttlm_dt: None,
+			assgnr: None,
+			assgne: None,
+			orgnl_tx_ref: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTransaction103 {
+	fn example() -> Self {
+		Self {
+			cxl_sts_id: Some("CxlStsId".to_string()),
+			rslvd_case: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			uetr: None,
+			tx_cxl_sts: None,
+			cxl_sts_rsn_inf: None,
+			orgnl_instd_amt: None,
+			orgnl_reqd_exctn_dt: None,
+			orgnl_reqd_colltn_dt: None,
+			orgnl_tx_ref: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTransaction104 {
+	fn example() -> Self {
+		Self {
+			sts_id: Some("StsId".to_string()),
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_uetr: None,
+			tx_sts: None,
+			sts_rsn_inf: None,
+			pmt_cond_sts: None,
+			chrgs_inf: None,
+			dbtr_dcsn_dt_tm: None,
+			accptnc_dt_tm: None,
+			acct_svcr_ref: None,
+			clr_sys_ref: None,
+			orgnl_tx_ref: None,
+			nclsd_file: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTransaction106 {
+	fn example() -> Self {
+		Self {
+			cxl_id: Some("CxlId".to_string()),
+			case: None,
+			orgnl_grp_inf: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_tx_id: None,
+			orgnl_uetr: None,
+			orgnl_clr_sys_ref: None,
+			orgnl_intr_bk_sttlm_amt: None,
+			orgnl_intr_bk_sttlm_dt: None,
+			assgnr: None,
+			assgne: None,
+			cxl_rsn_inf: None,
+			orgnl_tx_ref: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTransaction107 {
+	fn example() -> Self {
+		Self {
+			mod_sts_id: None,
+			rslvd_case: None,
+			orgnl_grp_inf: Example::example(),
+			orgnl_pmt_inf_id: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_tx_id: None,
+			orgnl_clr_sys_ref: None,
+			orgnl_uetr: None,
+			mod_sts_rsn_inf: None,
+			rsltn_rltd_inf: None,
+			orgnl_intr_bk_sttlm_amt: None,
+			orgnl_intr_bk_sttlm_dt: None,
+			assgnr: None,
+			assgne: None,
+			orgnl_tx_ref: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTransaction110 {
+	fn example() -> Self {
+		Self {
+			sts_id: Some("StsId".to_string()),
+			orgnl_grp_inf: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_tx_id: None,
+			orgnl_uetr: None,
+			tx_sts: None,
+			sts_rsn_inf: None,
+			chrgs_inf: None,
+			accptnc_dt_tm: None,
+			fctv_intr_bk_sttlm_dt: None,
+			acct_svcr_ref: None,
+			clr_sys_ref: None,
+			instg_agt: None,
+			instd_agt: None,
+			orgnl_tx_ref: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTransaction113 {
+	fn example() -> Self {
+		Self {
+			sts_req_id: Some("StsReqId".to_string()),
+			orgnl_grp_inf: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_tx_id: None,
+			orgnl_uetr: None,
+			accptnc_dt_tm: None,
+			clr_sys_ref: None,
+			instg_agt: None,
+			instd_agt: None,
+			orgnl_tx_ref: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTransaction118 {
+	fn example() -> Self {
+		Self {
+			rtr_id: None,
+			orgnl_grp_inf: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_tx_id: None,
+			orgnl_uetr: None,
+			orgnl_clr_sys_ref: None,
+			orgnl_intr_bk_sttlm_amt: None,
+			orgnl_intr_bk_sttlm_dt: None,
+			rtrd_intr_bk_sttlm_amt: Example::example(),
+			intr_bk_sttlm_dt: None,
+			sttlm_prty: None,
+			sttlm_tm_indctn: None,
+			rtrd_instd_amt: None,
+			xchg_rate: None,
+			compstn_amt: None,
+			chrg_br: None,
+			chrgs_inf: None,
+			clr_sys_ref: None,
+			instg_agt: None,
+			instd_agt: None,
+			rtr_chain: None,
+			rtr_rsn_inf: None,
+			orgnl_tx_ref: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTransaction124 {
+	fn example() -> Self {
+		Self {
+			cxl_id: Some("CxlId".to_string()),
+			case: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_uetr: None,
+			orgnl_instd_amt: None,
+			orgnl_reqd_exctn_dt: None,
+			orgnl_reqd_colltn_dt: None,
+			cxl_rsn_inf: None,
+			orgnl_tx_ref: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTypeInformation26 {
+	fn example() -> Self {
+		Self {
+			instr_prty: Some(Example::example()),
+			svc_lvl: None,
+			lcl_instrm: None,
+			ctgy_purp: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTypeInformation27 {
+	fn example() -> Self {
+		Self {
+			instr_prty: Some(Example::example()),
+			clr_chanl: None,
+			svc_lvl: None,
+			lcl_instrm: None,
+			seq_tp: None,
+			ctgy_purp: None,
+		}
+	}
+}
+
+impl Example for crate::common::PaymentTypeInformation28 {
+	fn example() -> Self {
+		Self {
+			instr_prty: Some(Example::example()),
+			clr_chanl: None,
+			svc_lvl: None,
+			lcl_instrm: None,
+			ctgy_purp: None,
+		}
+	}
+}
+
+impl Example for crate::common::PersonIdentification13 {
+	fn example() -> Self {
+		Self {
+			dt_and_plc_of_birth: Some(Example::example()),
+			othr: None,
+		}
+	}
+}
+
+impl Example for crate::common::PersonIdentificationSchemeName1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::PlainCardData1 {
+	fn example() -> Self {
+		Self {
+			pan: "00000000".to_string(),
+			card_seq_nb: None,
+			fctv_dt: None,
+			xpry_dt: "2024-01-01".to_string(),
+			svc_cd: None,
+			trck_data: None,
+			card_scty_cd: None,
+		}
+	}
+}
+
+impl Example for crate::common::PointOfInteraction1 {
+	fn example() -> Self {
+		Self {
+			id: Example::example(),
+			sys_nm: None,
+			grp_id: None,
+			cpblties: None,
+			cmpnt: None,
+		}
+	}
+}
+
+impl Example for crate::common::PointOfInteractionCapabilities1 {
+	fn example() -> Self {
+		Self {
+			card_rdng_cpblties: Some(vec![Example::example()]),
+			crdhldr_vrfctn_cpblties: None,
+			on_line_cpblties: None,
+			disp_cpblties: None,
+			prt_line_width: None,
+		}
+	}
+}
+
+impl Example for crate::common::PointOfInteractionComponent1 {
+	fn example() -> Self {
+		Self {
+			poi_cmpnt_tp: Example::example(),
+			manfctr_id: None,
+			mdl: None,
+			vrsn_nb: None,
+			srl_nb: None,
+			apprvl_nb: None,
+		}
+	}
+}
+
+impl Example for crate::common::PostalAddress1 {
+	fn example() -> Self {
+		Self {
+			adr_tp: None,
+			adr_line: None,
+			strt_nm: None,
+			bldg_nb: None,
+			pst_cd: None,
+			twn_nm: None,
+			ctry_sub_dvsn: None,
+			ctry: "US".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::PostalAddress24 {
+	fn example() -> Self {
+		Self {
+			adr_tp: Some(Example::example()),
+			dept: None,
+			sub_dept: None,
+			strt_nm: None,
+			bldg_nb: None,
+			bldg_nm: None,
+			flr: None,
+			pst_bx: None,
+			room: None,
+			pst_cd: None,
+			twn_nm: None,
+			twn_lctn_nm: None,
+			dstrct_nm: None,
+			ctry_sub_dvsn: None,
+			ctry: None,
+			adr_line: None,
+		}
+	}
+}
+
+impl Example for crate::common::PreferredContactMethod1Code {
+	fn example() -> Self {
+		Self::CodeLETT
+	}
+}
+
+impl Example for crate::common::Price7 {
+	fn example() -> Self {
+		Self {
+			tp: Example::example(),
+			val: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::PriceRateOrAmount3Choice {
+	fn example() -> Self {
+		Self {
+			rate: Some(1.0),
+			amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::PriceValueType1Code {
+	fn example() -> Self {
+		Self::CodeDISC
+	}
+}
+
+impl Example for crate::common::Priority2Code {
+	fn example() -> Self {
+		Self::CodeHIGH
+	}
+}
+
+impl Example for crate::common::Priority3Code {
+	fn example() -> Self {
+		Self::CodeURGT
+	}
+}
+
+impl Example for crate::common::Product2 {
+	fn example() -> Self {
+		Self {
+			pdct_cd: "PdctCd".to_string(),
+			unit_of_measr: None,
+			pdct_qty: None,
+			unit_pric: None,
+			pdct_amt: None,
+			tax_tp: None,
+			addtl_pdct_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::ProprietaryAgent4 {
+	fn example() -> Self {
+		Self {
+			tp: "Tp".to_string(),
+			agt: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::ProprietaryBankTransactionCodeStructure1 {
+	fn example() -> Self {
+		Self {
+			cd: "Cd".to_string(),
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::ProprietaryData5 {
+	fn example() -> Self {
+		Self {
+			tp: "Tp".to_string(),
+			data: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::ProprietaryDate3 {
+	fn example() -> Self {
+		Self {
+			tp: "Tp".to_string(),
+			dt: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::ProprietaryParty5 {
+	fn example() -> Self {
+		Self {
+			tp: "Tp".to_string(),
+			pty: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::ProprietaryPrice2 {
+	fn example() -> Self {
+		Self {
+			tp: "Tp".to_string(),
+			pric: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::ProprietaryQuantity1 {
+	fn example() -> Self {
+		Self {
+			tp: "Tp".to_string(),
+			qty: "Qty".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::ProprietaryReference1 {
+	fn example() -> Self {
+		Self {
+			tp: "Tp".to_string(),
+			ref_attr: "Ref".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::ProxyAccountIdentification1 {
+	fn example() -> Self {
+		Self {
+			tp: None,
+			id: "Id".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::ProxyAccountType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::Purpose2Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::QueryType3Code {
+	fn example() -> Self {
+		Self::CodeALLL
+	}
+}
+
+impl Example for crate::common::Rate4 {
+	fn example() -> Self {
+		Self {
+			tp: Example::example(),
+			vldty_rg: None,
+		}
+	}
+}
+
+impl Example for crate::common::RateType4Choice {
+	fn example() -> Self {
+		Self {
+			pctg: Some(1.0),
+			othr: None,
+		}
+	}
+}
+
+impl Example for crate::common::ReceiptAcknowledgementReport2 {
+	fn example() -> Self {
+		Self {
+			rltd_ref: Example::example(),
+			req_hdlg: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::ReferredDocumentInformation7 {
+	fn example() -> Self {
+		Self {
+			tp: Some(Example::example()),
+			nb: None,
+			rltd_dt: None,
+			line_dtls: None,
+		}
+	}
+}
+
+impl Example for crate::common::ReferredDocumentType3Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some(Example::example()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ReferredDocumentType4 {
+	fn example() -> Self {
+		Self {
+			cd_or_prtry: Example::example(),
+			issr: None,
+		}
+	}
+}
+
+impl Example for crate::common::RegulatoryAuthority2 {
+	fn example() -> Self {
+		Self {
+			nm: Some("Nm".to_string()),
+			ctry: None,
+		}
+	}
+}
+
+impl Example for crate::common::RegulatoryReporting3 {
+	fn example() -> Self {
+		Self {
+			dbt_cdt_rptg_ind: Some(Example::example()),
+			authrty: None,
+			dtls: None,
+		}
+	}
+}
+
+impl Example for crate::common::RegulatoryReportingType1Code {
+	fn example() -> Self {
+		Self::CodeCRED
+	}
+}
+
+impl Example for crate::common::RejectionReason2 {
+	fn example() -> Self {
+		Self {
+			rjctg_pty_rsn: "RjctgPtyRsn".to_string(),
+			rjctn_dt_tm: None,
+			err_lctn: None,
+			rsn_desc: None,
+			addtl_data: None,
+		}
+	}
+}
+
+impl Example for crate::common::RemittanceAmount2 {
+	fn example() -> Self {
+		Self {
+			due_pybl_amt: Some(Example::example()),
+			dscnt_apld_amt: None,
+			cdt_note_amt: None,
+			tax_amt: None,
+			adjstmnt_amt_and_rsn: None,
+			rmtd_amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::RemittanceAmount3 {
+	fn example() -> Self {
+		Self {
+			due_pybl_amt: Some(Example::example()),
+			dscnt_apld_amt: None,
+			cdt_note_amt: None,
+			tax_amt: None,
+			adjstmnt_amt_and_rsn: None,
+			rmtd_amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::RemittanceInformation16 {
+	fn example() -> Self {
+		Self {
+			ustrd: Some(vec!["Ustrd".to_string()]),
+			strd: None,
+		}
+	}
+}
+
+impl Example for crate::common::RemittanceInformation2 {
+	fn example() -> Self {
+		Self {
+			ustrd: Some(vec!["Ustrd".to_string()]),
+		}
+	}
+}
+
+impl Example for crate::common::RemittanceLocation7 {
+	fn example() -> Self {
+		Self {
+			rmt_id: Some("RmtId".to_string()),
+			rmt_lctn_dtls: None,
+		}
+	}
+}
+
+impl Example for crate::common::RemittanceLocationData1 {
+	fn example() -> Self {
+		Self {
+			mtd: Example::example(),
+			elctrnc_adr: None,
+			pstl_adr: None,
+		}
+	}
+}
+
+impl Example for crate::common::RemittanceLocationMethod2Code {
+	fn example() -> Self {
+		Self::CodeFAXI
+	}
+}
+
+impl Example for crate::common::ReportEntry10 {
+	fn example() -> Self {
+		Self {
+			ntry_ref: None,
+			amt: Example::example(),
+			cdt_dbt_ind: Example::example(),
+			rvsl_ind: None,
+			sts: Example::example(),
+			bookg_dt: None,
+			val_dt: None,
+			acct_svcr_ref: None,
+			avlbty: None,
+			bk_tx_cd: Example::example(),
+			comssn_wvr_ind: None,
+			addtl_inf_ind: None,
+			amt_dtls: None,
+			chrgs: None,
+			tech_inpt_chanl: None,
+			intrst: None,
+			card_tx: None,
+			ntry_dtls: None,
+			addtl_ntry_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::ReportingPeriod2 {
+	fn example() -> Self {
+		Self {
+			fr_to_dt: Example::example(),
+			fr_to_tm: None,
+			tp: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::ReportingRequest5 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			reqd_msg_nm_id: "pacs.008.001.08".to_string(),
+			acct: None,
+			acct_ownr: Example::example(),
+			acct_svcr: None,
+			rptg_prd: None,
+			rptg_seq: None,
+			reqd_tx_tp: None,
+			reqd_bal_tp: None,
+		}
+	}
+}
+
+impl Example for crate::common::ReportingSource1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::RequestHandling2 {
+	fn example() -> Self {
+		Self {
+			sts_cd: "a".to_string(),
+			sts_dt_tm: None,
+			desc: None,
+		}
+	}
+}
+
+impl Example for crate::common::RequestType4Choice {
+	fn example() -> Self {
+		Self {
+			pmt_ctrl: Some("PmtC".to_string()),
+			enqry: None,
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::ResendSearchCriteria2 {
+	fn example() -> Self {
+		Self {
+			biz_dt: None,
+			seq_nb: None,
+			seq_rg: None,
+			orgnl_msg_nm_id: None,
+			file_ref: None,
+			rcpt: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::ResolutionData1 {
+	fn example() -> Self {
+		Self {
+			end_to_end_id: Some("EndToEndId".to_string()),
+			tx_id: None,
+			uetr: None,
+			intr_bk_sttlm_amt: None,
+			intr_bk_sttlm_dt: None,
+			clr_chanl: None,
+			compstn: None,
+			chrgs: None,
+		}
+	}
+}
+
+impl Example for crate::common::ReturnReason5Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::SecuritiesAccount19 {
+	fn example() -> Self {
+		Self {
+			id: "Id".to_string(),
+			tp: None,
+			nm: None,
+		}
+	}
+}
+
+impl Example for crate::common::SecurityIdentification19 {
+	fn example() -> Self {
+		Self {
+			isin: Some("US0378331005".to_string()),
+			othr_id: None,
+			desc: None,
+		}
+	}
+}
+
+impl Example for crate::common::SequenceRange1 {
+	fn example() -> Self {
+		Self {
+			fr_seq: "FrSeq".to_string(),
+			to_seq: "ToSeq".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::SequenceRange1Choice {
+	fn example() -> Self {
+		Self {
+			fr_seq: Some("FrSeq".to_string()),
+			to_seq: None,
+			fr_to_seq: None,
+			eq_seq: None,
+			neq_seq: None,
+		}
+	}
+}
+
+impl Example for crate::common::SequenceType3Code {
+	fn example() -> Self {
+		Self::CodeFRST
+	}
+}
+
+impl Example for crate::common::ServiceLevel8Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::SettlementDateTimeIndication1 {
+	fn example() -> Self {
+		Self {
+			dbt_dt_tm: Some("2024-01-01T00:00:00".to_string()),
+			cdt_dt_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::SettlementInstruction7 {
+	fn example() -> Self {
+		Self {
+			sttlm_mtd: Example::example(),
+			sttlm_acct: None,
+			clr_sys: None,
+			instg_rmbrsmnt_agt: None,
+			instg_rmbrsmnt_agt_acct: None,
+			instd_rmbrsmnt_agt: None,
+			instd_rmbrsmnt_agt_acct: None,
+			thrd_rmbrsmnt_agt: None,
+			thrd_rmbrsmnt_agt_acct: None,
+		}
+	}
+}
+
+impl Example for crate::common::SettlementMethod1Code {
+	fn example() -> Self {
+		Self::CodeINDA
+	}
+}
+
+impl Example for crate::common::SettlementTimeRequest2 {
+	fn example() -> Self {
+		Self {
+			cls_tm: Some("00:00:00".to_string()),
+			till_tm: None,
+			fr_tm: None,
+			rjct_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::SignatureEnvelope {
+	fn example() -> Self {
+		Self {}
+	}
+}
+
+impl Example for crate::common::SkipPayload {
+	fn example() -> Self {
+		Self {}
+	}
+}
+
+impl Example for crate::common::StatementResolutionEntry4 {
+	fn example() -> Self {
+		Self {
+			orgnl_grp_inf: Some(Example::example()),
+			orgnl_stmt_id: None,
+			uetr: None,
+			acct_svcr_ref: None,
+			crrctd_amt: None,
+			chrgs: None,
+			purp: None,
+		}
+	}
+}
+
+impl Example for crate::common::StatusReason6Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::StatusReasonInformation12 {
+	fn example() -> Self {
+		Self {
+			orgtr: Some(Example::example()),
+			rsn: None,
+			addtl_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::StructuredRegulatoryReporting3 {
+	fn example() -> Self {
+		Self {
+			tp: Some("Tp".to_string()),
+			dt: None,
+			ctry: None,
+			cd: None,
+			amt: None,
+			inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::StructuredRemittanceInformation16 {
+	fn example() -> Self {
+		Self {
+			rfrd_doc_inf: Some(vec![Example::example()]),
+			rfrd_doc_amt: None,
+			cdtr_ref_inf: None,
+			invcr: None,
+			invcee: None,
+			tax_rmt: None,
+			grnshmt_rmt: None,
+			addtl_rmt_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::SupplementaryData1 {
+	fn example() -> Self {
+		Self {
+			plc_and_nm: None,
+			envlp: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::SupplementaryDataEnvelope1 {
+	fn example() -> Self {
+		Self {}
+	}
+}
+
+impl Example for crate::common::TaxAmount2 {
+	fn example() -> Self {
+		Self {
+			rate: Some(1.0),
+			taxbl_base_amt: None,
+			ttl_amt: None,
+			dtls: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxAmountAndType1 {
+	fn example() -> Self {
+		Self {
+			tp: None,
+			amt: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::TaxAmountType1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxAuthorisation1 {
+	fn example() -> Self {
+		Self {
+			titl: Some("Titl".to_string()),
+			nm: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxCharges2 {
+	fn example() -> Self {
+		Self {
+			id: Some("Id".to_string()),
+			rate: None,
+			amt: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxInformation7 {
+	fn example() -> Self {
+		Self {
+			cdtr: Some(Example::example()),
+			dbtr: None,
+			ultmt_dbtr: None,
+			admstn_zone: None,
+			ref_nb: None,
+			mtd: None,
+			ttl_taxbl_base_amt: None,
+			ttl_tax_amt: None,
+			dt: None,
+			seq_nb: None,
+			rcrd: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxInformation8 {
+	fn example() -> Self {
+		Self {
+			cdtr: Some(Example::example()),
+			dbtr: None,
+			admstn_zone: None,
+			ref_nb: None,
+			mtd: None,
+			ttl_taxbl_base_amt: None,
+			ttl_tax_amt: None,
+			dt: None,
+			seq_nb: None,
+			rcrd: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxParty1 {
+	fn example() -> Self {
+		Self {
+			tax_id: Some("TaxId".to_string()),
+			regn_id: None,
+			tax_tp: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxParty2 {
+	fn example() -> Self {
+		Self {
+			tax_id: Some("TaxId".to_string()),
+			regn_id: None,
+			tax_tp: None,
+			authstn: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxPeriod2 {
+	fn example() -> Self {
+		Self {
+			yr: Some("Yr".to_string()),
+			tp: None,
+			fr_to_dt: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxRecord2 {
+	fn example() -> Self {
+		Self {
+			tp: Some("Tp".to_string()),
+			ctgy: None,
+			ctgy_dtls: None,
+			dbtr_sts: None,
+			cert_id: None,
+			frms_cd: None,
+			prd: None,
+			tax_amt: None,
+			addtl_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::TaxRecordDetails2 {
+	fn example() -> Self {
+		Self {
+			prd: None,
+			amt: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::TaxRecordPeriod1Code {
+	fn example() -> Self {
+		Self::CodeMM01
+	}
+}
+
+impl Example for crate::common::TechnicalInputChannel1Choice {
+	fn example() -> Self {
+		Self {
+			cd: Some("Cd".to_string()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::TimePeriodDetails1 {
+	fn example() -> Self {
+		Self {
+			fr_tm: "00:00:00".to_string(),
+			to_tm: None,
+		}
+	}
+}
+
+impl Example for crate::common::TotalTransactions6 {
+	fn example() -> Self {
+		Self {
+			ttl_ntries: Some(Example::example()),
+			ttl_cdt_ntries: None,
+			ttl_dbt_ntries: None,
+			ttl_ntries_per_bk_tx_cd: None,
+		}
+	}
+}
+
+impl Example for crate::common::TotalsPerBankTransactionCode5 {
+	fn example() -> Self {
+		Self {
+			nb_of_ntries: None,
+			sum: None,
+			ttl_net_ntry: None,
+			cdt_ntries: None,
+			dbt_ntries: None,
+			fcst_ind: None,
+			bk_tx_cd: Example::example(),
+			avlbty: None,
+			dt: None,
+		}
+	}
+}
+
+impl Example for crate::common::TrackData1 {
+	fn example() -> Self {
+		Self {
+			trck_nb: None,
+			trck_val: "TrckVal".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::TransactionAgents5 {
+	fn example() -> Self {
+		Self {
+			instg_agt: Some(Example::example()),
+			instd_agt: None,
+			dbtr_agt: None,
+			cdtr_agt: None,
+			intrmy_agt1: None,
+			intrmy_agt2: None,
+			intrmy_agt3: None,
+			rcvg_agt: None,
+			dlvrg_agt: None,
+			issg_agt: None,
+			sttlm_plc: None,
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::TransactionChannel1Code {
+	fn example() -> Self {
+		Self::CodeMAIL
+	}
+}
+
+impl Example for crate::common::TransactionDates3 {
+	fn example() -> Self {
+		Self {
+			accptnc_dt_tm: Some("2024-01-01T00:00:00".to_string()),
+			trad_actvty_ctrctl_sttlm_dt: None,
+			trad_dt: None,
+			intr_bk_sttlm_dt: None,
+			start_dt: None,
+			end_dt: None,
+			tx_dt_tm: None,
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::TransactionEnvironment1Code {
+	fn example() -> Self {
+		Self::CodeMERC
+	}
+}
+
+impl Example for crate::common::TransactionIdentifier1 {
+	fn example() -> Self {
+		Self {
+			tx_dt_tm: "2024-01-01T00:00:00".to_string(),
+			tx_ref: "TxRef".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::TransactionIndividualStatus1Code {
+	fn example() -> Self {
+		Self::CodeACTC
+	}
+}
+
+impl Example for crate::common::TransactionInterest4 {
+	fn example() -> Self {
+		Self {
+			ttl_intrst_and_tax_amt: Some(Example::example()),
+			rcrd: None,
+		}
+	}
+}
+
+impl Example for crate::common::TransactionParties6 {
+	fn example() -> Self {
+		Self {
+			initg_pty: Some(Example::example()),
+			dbtr: None,
+			dbtr_acct: None,
+			ultmt_dbtr: None,
+			cdtr: None,
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+			tradg_pty: None,
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::TransactionParties8 {
+	fn example() -> Self {
+		Self {
+			ultmt_dbtr: None,
+			dbtr: Example::example(),
+			dbtr_acct: None,
+			initg_pty: None,
+			dbtr_agt: None,
+			dbtr_agt_acct: None,
+			prvs_instg_agt1: None,
+			prvs_instg_agt1_acct: None,
+			prvs_instg_agt2: None,
+			prvs_instg_agt2_acct: None,
+			prvs_instg_agt3: None,
+			prvs_instg_agt3_acct: None,
+			intrmy_agt1: None,
+			intrmy_agt1_acct: None,
+			intrmy_agt2: None,
+			intrmy_agt2_acct: None,
+			intrmy_agt3: None,
+			intrmy_agt3_acct: None,
+			cdtr_agt: None,
+			cdtr_agt_acct: None,
+			cdtr: Example::example(),
+			cdtr_acct: None,
+			ultmt_cdtr: None,
+		}
+	}
+}
+
+impl Example for crate::common::TransactionPrice4Choice {
+	fn example() -> Self {
+		Self {
+			deal_pric: Some(Example::example()),
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::TransactionQuantities3Choice {
+	fn example() -> Self {
+		Self {
+			qty: Some(Example::example()),
+			orgnl_and_cur_face_amt: None,
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::TransactionReferences6 {
+	fn example() -> Self {
+		Self {
+			msg_id: Some("MsgId".to_string()),
+			acct_svcr_ref: None,
+			pmt_inf_id: None,
+			instr_id: None,
+			end_to_end_id: None,
+			uetr: None,
+			tx_id: None,
+			mndt_id: None,
+			chq_nb: None,
+			clr_sys_ref: None,
+			acct_ownr_tx_id: None,
+			acct_svcr_tx_id: None,
+			mkt_infrstrctr_tx_id: None,
+			prcg_id: None,
+			prtry: None,
+		}
+	}
+}
+
+impl Example for crate::common::TransactionType2 {
+	fn example() -> Self {
+		Self {
+			sts: Example::example(),
+			cdt_dbt_ind: Example::example(),
+			flr_lmt: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnableToApplyIncorrect1 {
+	fn example() -> Self {
+		Self {
+			cd: Example::example(),
+			addtl_incrrct_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnableToApplyIncorrectInformation4Code {
+	fn example() -> Self {
+		Self::CodeIN01
+	}
+}
+
+impl Example for crate::common::UnableToApplyJustification3Choice {
+	fn example() -> Self {
+		Self {
+			any_inf: Some(false),
+			mssng_or_incrrct_inf: None,
+			pssbl_dplct_instr: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnableToApplyMissing1 {
+	fn example() -> Self {
+		Self {
+			cd: Example::example(),
+			addtl_mssng_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnableToApplyMissingInformation3Code {
+	fn example() -> Self {
+		Self::CodeMS01
+	}
+}
+
+impl Example for crate::common::UnderlyingGroupInformation1 {
+	fn example() -> Self {
+		Self {
+			orgnl_msg_id: "OrgnlMsgId".to_string(),
+			orgnl_msg_nm_id: "OrgnlMsgNmId".to_string(),
+			orgnl_cre_dt_tm: None,
+			orgnl_msg_dlvry_chanl: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnderlyingPaymentInstruction5 {
+	fn example() -> Self {
+		Self {
+			orgnl_grp_inf: None,
+			orgnl_pmt_inf_id: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_uetr: None,
+			orgnl_instd_amt: Example::example(),
+			reqd_exctn_dt: None,
+			reqd_colltn_dt: None,
+			orgnl_tx_ref: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnderlyingPaymentTransaction4 {
+	fn example() -> Self {
+		Self {
+			orgnl_grp_inf: None,
+			orgnl_instr_id: None,
+			orgnl_end_to_end_id: None,
+			orgnl_tx_id: None,
+			orgnl_uetr: None,
+			orgnl_intr_bk_sttlm_amt: Example::example(),
+			orgnl_intr_bk_sttlm_dt: "2024-01-01".to_string(),
+			orgnl_tx_ref: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnderlyingStatementEntry3 {
+	fn example() -> Self {
+		Self {
+			orgnl_grp_inf: Some(Example::example()),
+			orgnl_stmt_id: None,
+			orgnl_ntry_id: None,
+			orgnl_uetr: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnderlyingTransaction22 {
+	fn example() -> Self {
+		Self {
+			orgnl_grp_inf_and_sts: Some(Example::example()),
+			orgnl_pmt_inf_and_sts: None,
+			tx_inf_and_sts: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnderlyingTransaction23 {
+	fn example() -> Self {
+		Self {
+			orgnl_grp_inf_and_cxl: Some(Example::example()),
+			tx_inf: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnderlyingTransaction27 {
+	fn example() -> Self {
+		Self {
+			orgnl_grp_inf_and_cxl: Some(Example::example()),
+			orgnl_pmt_inf_and_cxl: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnderlyingTransaction5Choice {
+	fn example() -> Self {
+		Self {
+			initn: Some(Example::example()),
+			intr_bk: None,
+			stmt_ntry: None,
+		}
+	}
+}
+
+impl Example for crate::common::UnitOfMeasure1Code {
+	fn example() -> Self {
+		Self::CodePIEC
+	}
+}
+
+impl Example for crate::common::UserInterface2Code {
+	fn example() -> Self {
+		Self::CodeMDSP
+	}
+}
+
+impl Example for crate::common::YieldedOrValueType1Choice {
+	fn example() -> Self {
+		Self {
+			yldd: Some(false),
+			val_tp: None,
+		}
+	}
+}
+
+impl Example for crate::common::FedNowMessageSignatureKey {
+	fn example() -> Self {
+		Self {
+			fed_now_key_id: "A".to_string(),
+			name: "A".to_string(),
+			encoded_public_key: "EncodedPublicKey".to_string(),
+			encoding: "A".to_string(),
+			algorithm: None,
+			key_creation_date_time: None,
+		}
+	}
+}
+
+impl Example for crate::common::FedNowMessageSignatureKeyStatus {
+	fn example() -> Self {
+		Self {
+			key_status: "KeyStatus".to_string(),
+			status_date_time: "2024-01-01T00:00:00".to_string(),
+		}
+	}
+}
+
+impl Example for crate::common::FedNowPublicKeyResponse {
+	fn example() -> Self {
+		Self {
+			fed_now_message_signature_key_status: Example::example(),
+			fed_now_message_signature_key: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::common::KeyAddition {
+	fn example() -> Self {
+		Self {
+			key: Some(Example::example()),
+		}
+	}
+}
+
+impl Example for crate::fednow_extra::key_exchange::KeyRevocation {
+	fn example() -> Self {
+		Self {
+			key_revocation: Some("KeyRevocation".to_string()),
+			fed_now_status_description: None,
+			fed_now_key_id: None,
+		}
+	}
+}
+
+impl Example for crate::fednow_extra::key_exchange::FedNowMessageSignatureKeyExchange {
+	fn example() -> Self {
+		Self {
+			key_addition: Some(Example::example()),
+			key_revocation: None,
+		}
+	}
+}
+
+impl Example for crate::fednow_extra::key_exchange::FedNowCustomerMessageSignatureKeyOperationResponse {
+	fn example() -> Self {
+		Self {
+			fed_now_key_id: "A".to_string(),
+			status: "Status".to_string(),
+			error_code: None,
+		}
+	}
+}
+
+impl Example for crate::fednow_extra::key_exchange::GetAllFedNowActivePublicKeys {
+	fn example() -> Self {
+		Self {}
+	}
+}
+
+impl Example for crate::fednow_extra::key_exchange::GetAllCustomerPublicKeys {
+	fn example() -> Self {
+		Self {}
+	}
+}
+
+impl Example for crate::fednow_extra::key_exchange::FedNowPublicKeyResponses {
+	fn example() -> Self {
+		Self {
+			public_keys: vec![Example::example()],
+		}
+	}
+}
+
+impl Example for crate::fednow_incoming_external::FedNowIncoming {
+	fn example() -> Self {
+		Self {
+			fed_now_technical_header: None,
+			fed_now_incoming_message: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::fednow_outgoing_external::FedNowOutgoing {
+	fn example() -> Self {
+		Self {
+			fed_now_technical_header: None,
+			fed_now_outgoing_message: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::iso::admi_002_001_01::Admi00200101 {
+	fn example() -> Self {
+		Self {
+			rltd_ref: Example::example(),
+			rsn: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::iso::admi_004_001_02::SystemEventNotificationV02 {
+	fn example() -> Self {
+		Self {
+			evt_inf: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::iso::admi_006_001_01::ResendRequestV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rsnd_sch_crit: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::admi_007_001_01::ReceiptAcknowledgementV01 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			rpt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::admi_011_001_01::SystemEventAcknowledgementV01 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			orgtr_ref: None,
+			sttlm_ssn_idr: None,
+			ack_dtls: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::admi_998_001_02::AdministrationProprietaryMessageV02 {
+	fn example() -> Self {
+		Self {
+			msg_id: None,
+			rltd: None,
+			prvs: None,
+			othr: None,
+			prtry_data: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::iso::camt_026_001_07::UnableToApplyV07 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			undrlyg: Example::example(),
+			justfn: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::camt_028_001_09::AdditionalPaymentInformationV09 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			undrlyg: Example::example(),
+			inf: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::camt_029_001_09::ResolutionOfInvestigationV09 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			rslvd_case: None,
+			sts: Example::example(),
+			cxl_dtls: None,
+			mod_dtls: None,
+			clm_non_rct_dtls: None,
+			stmt_dtls: None,
+			crrctn_tx: None,
+			rsltn_rltd_inf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::camt_052_001_08::BankToCustomerAccountReportV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			rpt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::camt_054_001_08::BankToCustomerDebitCreditNotificationV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			ntfctn: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::camt_055_001_09::CustomerPaymentCancellationRequestV09 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			ctrl_data: None,
+			undrlyg: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::camt_056_001_08::FIToFIPaymentCancellationRequestV08 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			ctrl_data: None,
+			undrlyg: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::camt_060_001_05::AccountReportingRequestV05 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			rptg_req: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::pacs_002_001_10::FIToFIPaymentStatusReportV10 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf_and_sts: None,
+			tx_inf_and_sts: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::pacs_004_001_10::PaymentReturnV10 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf: None,
+			tx_inf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			cdt_trf_tx_inf: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::pacs_009_001_08::FinancialInstitutionCreditTransferV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			cdt_trf_tx_inf: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::pacs_028_001_03::FIToFIPaymentStatusRequestV03 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf: None,
+			tx_inf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::pain_013_001_07::CreditorPaymentActivationRequestV07 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			pmt_inf: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::iso::pain_014_001_07::CreditorPaymentActivationRequestStatusReportV07 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf_and_sts: Example::example(),
+			orgnl_pmt_inf_and_sts: None,
+			splmtry_data: None,
+		}
+	}
+}
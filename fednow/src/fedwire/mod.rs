@@ -0,0 +1,410 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+// Fedwire Funds FAIM messages <-> FedNow pacs.008.001.08 and pacs.009.001.08.
+//
+// Customer transfers (CTR) become FIToFICustomerCreditTransferV08 and bank
+// transfers (BTR) FinancialInstitutionCreditTransferV08. Tags and message
+// elements without a counterpart are left out and listed in the
+// `unrepresentable` part of the translation, errors are only raised when a
+// mandatory part of the target message cannot be filled.
+
+pub mod pacs008;
+pub mod pacs009;
+
+use crate::common::*;
+use crate::swift::{account_identifier, cash_account};
+use open_payments_common::fedwire::{faim_text, DepositoryInstitution, FaimParty, FundsTransfer, Imad, Unrepresentable, ERR_UNMAPPABLE};
+use open_payments_common::ValidationError;
+
+/// Clearing system code of the Fedwire Funds Service.
+pub const FEDWIRE_CLEARING_SYSTEM: &str = "FDW";
+/// Clearing system code of the FedNow Service (`ClrSys/Cd`).
+pub const FEDNOW_CLEARING_SYSTEM: &str = "FDN";
+pub const ABA_CLEARING_SYSTEM: &str = "USABA";
+pub const CHIPS_CLEARING_SYSTEM: &str = "USPID";
+
+fn unmappable(message: &str) -> ValidationError {
+    ValidationError::new(ERR_UNMAPPABLE, message.to_string())
+}
+
+fn tag_name(tag: &str) -> String {
+    format!("{{{}}}", tag)
+}
+
+/// Records an ISO 20022 element that is present but has no FAIM tag.
+pub fn report_element<T>(value: &Option<T>, element: &str, report: &mut Vec<Unrepresentable>) {
+    if value.is_some() {
+        report.push(Unrepresentable::new(element, "no FAIM tag"));
+    }
+}
+
+/// Records the parts of a transfer that no ISO 20022 message element
+/// carries: unmodelled tags, reversal subtypes, the previous IMAD and the
+/// duplicate and test flags of {1500}.
+pub fn report_transfer(transfer: &FundsTransfer, report: &mut Vec<Unrepresentable>) {
+    if transfer.is_possible_duplicate() {
+        report.push(Unrepresentable::new("{1500}", "possible duplicate flag belongs to the business application header"));
+    }
+    if transfer.test_production_code == 'T' {
+        report.push(Unrepresentable::new("{1500}", "test message flag"));
+    }
+    if transfer.subtype_code != "00" {
+        report.push(Unrepresentable::new("{1510}", &format!("subtype {} is translated as a basic funds transfer", transfer.subtype_code)));
+    }
+    if transfer.previous_imad.is_some() {
+        report.push(Unrepresentable::new("{3500}", "previous message IMAD"));
+    }
+    if transfer.transaction_type_code.is_some() {
+        report.push(Unrepresentable::new("{3600}", "transaction type code"));
+    }
+    for tag in &transfer.other_tags {
+        report.push(Unrepresentable::new(&tag_name(&tag.tag), "no ISO 20022 counterpart"));
+    }
+}
+
+/// Agent identified by an ABA routing number.
+pub fn routing_agent(routing_number: &str, name: Option<&str>) -> BranchAndFinancialInstitutionIdentification6 {
+    BranchAndFinancialInstitutionIdentification6 {
+        fin_instn_id: FinancialInstitutionIdentification18 {
+            clr_sys_mmb_id: Some(clearing_member(ABA_CLEARING_SYSTEM, routing_number)),
+            nm: name.map(|n| n.to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn clearing_member(system: &str, member: &str) -> ClearingSystemMemberIdentification2 {
+    ClearingSystemMemberIdentification2 {
+        clr_sys_id: Some(ClearingSystemIdentification2Choice { cd: Some(system.to_string()), ..Default::default() }),
+        mmb_id: member.to_string(),
+    }
+}
+
+/// Agent for the sender {3100} or receiver {3400}.
+pub fn depository_agent(institution: &DepositoryInstitution) -> BranchAndFinancialInstitutionIdentification6 {
+    routing_agent(&institution.routing_number, institution.short_name.as_deref())
+}
+
+fn clearing_member_id<'a>(agent: &'a BranchAndFinancialInstitutionIdentification6, system: &str) -> Option<&'a str> {
+    let member = agent.fin_instn_id.clr_sys_mmb_id.as_ref()?;
+    let code = member.clr_sys_id.as_ref().and_then(|s| s.cd.as_deref());
+    if code == Some(system) { Some(member.mmb_id.as_str()) } else { None }
+}
+
+/// ABA routing number of an agent.
+pub fn agent_routing_number(agent: &BranchAndFinancialInstitutionIdentification6) -> Option<&str> {
+    clearing_member_id(agent, ABA_CLEARING_SYSTEM)
+}
+
+/// Sender or receiver depository institution: the instructing/instructed
+/// agent, or the debtor/creditor agent when it is not given.
+pub fn depository_institution(
+    element: &str,
+    agent: Option<&BranchAndFinancialInstitutionIdentification6>,
+    fallback: Option<&BranchAndFinancialInstitutionIdentification6>,
+) -> Result<DepositoryInstitution, ValidationError> {
+    let agent = agent
+        .filter(|a| agent_routing_number(a).is_some())
+        .or(fallback.filter(|a| agent_routing_number(a).is_some()))
+        .ok_or_else(|| unmappable(&format!("{} has no ABA routing number", element)))?;
+    Ok(DepositoryInstitution {
+        routing_number: agent_routing_number(agent).unwrap_or_default().to_string(),
+        short_name: agent.fin_instn_id.nm.as_deref().map(|n| faim_text(n, 18)),
+    })
+}
+
+/// Whether an agent says no more than the depository institution, so that
+/// the party tag can be left out.
+pub fn is_depository_institution(agent: &BranchAndFinancialInstitutionIdentification6, institution: &DepositoryInstitution) -> bool {
+    let id = &agent.fin_instn_id;
+    agent_routing_number(agent) == Some(institution.routing_number.as_str())
+        && id.bicfi.is_none()
+        && id.lei.is_none()
+        && id.pstl_adr.is_none()
+        && id.othr.is_none()
+        && agent.brnch_id.is_none()
+        && (id.nm.is_none() || id.nm.as_deref().map(|n| faim_text(n, 18)) == institution.short_name)
+}
+
+/// IMAD of a message: the clearing system reference, else the message identification.
+pub fn message_imad(clr_sys_ref: Option<&str>, msg_id: &str) -> Result<Imad, ValidationError> {
+    clr_sys_ref
+        .and_then(Imad::parse)
+        .or_else(|| Imad::parse(msg_id))
+        .ok_or_else(|| unmappable("no IMAD in ClrSysRef or MsgId"))
+}
+
+pub fn settlement_instruction() -> SettlementInstruction7 {
+    SettlementInstruction7 {
+        sttlm_mtd: SettlementMethod1Code::CodeCLRG,
+        clr_sys: Some(ClearingSystemIdentification3Choice { cd: Some(FEDWIRE_CLEARING_SYSTEM.to_string()), ..Default::default() }),
+        ..Default::default()
+    }
+}
+
+/// Amount of {2000} from an interbank settlement amount, USD only.
+pub fn amount_in_cents(amount: &ActiveCurrencyAndAmount) -> Result<i64, ValidationError> {
+    if amount.ccy != "USD" {
+        return Err(unmappable(&format!("Fedwire Funds settles in USD, not {}", amount.ccy)));
+    }
+    Ok((amount.value * 100.0).round() as i64)
+}
+
+pub fn interbank_amount(cents: i64) -> ActiveCurrencyAndAmount {
+    ActiveCurrencyAndAmount { ccy: "USD".to_string(), value: cents as f64 / 100.0 }
+}
+
+/// {3610} local instrument as `LclInstrm/Prtry`; the proprietary code of
+/// `PROP` stands for itself.
+pub fn local_instrument(transfer: &FundsTransfer) -> Option<LocalInstrument2Choice> {
+    let (code, proprietary) = transfer.local_instrument.as_ref()?;
+    let value = if code == "PROP" { proprietary.clone().unwrap_or_else(|| code.clone()) } else { code.clone() };
+    Some(LocalInstrument2Choice { prtry: Some(value), ..Default::default() })
+}
+
+const LOCAL_INSTRUMENT_CODES: &[&str] = &["ANSI", "COVS", "GXML", "IXML", "NARR", "PROP", "RMTS", "RRMT", "S820", "SWIF", "UEXML"];
+
+pub fn faim_local_instrument(instrument: &LocalInstrument2Choice) -> Option<(String, Option<String>)> {
+    let value = instrument.prtry.as_deref().or(instrument.cd.as_deref())?;
+    if LOCAL_INSTRUMENT_CODES.contains(&value) {
+        Some((value.to_string(), None))
+    } else {
+        Some(("PROP".to_string(), Some(faim_text(value, 35))))
+    }
+}
+
+fn postal_address(lines: &[String]) -> Option<PostalAddress24> {
+    if lines.is_empty() {
+        return None;
+    }
+    Some(PostalAddress24 { adr_line: Some(lines.to_vec()), ..Default::default() })
+}
+
+fn address_lines(address: &PostalAddress24) -> Vec<String> {
+    if let Some(ref lines) = address.adr_line {
+        return lines.iter().take(3).cloned().collect();
+    }
+    let street = [address.strt_nm.as_deref(), address.bldg_nb.as_deref()].iter().flatten().cloned().collect::<Vec<_>>().join(" ");
+    let town = [address.pst_cd.as_deref(), address.twn_nm.as_deref(), address.ctry_sub_dvsn.as_deref()].iter().flatten().cloned().collect::<Vec<_>>().join(" ");
+    [street, town, address.ctry.clone().unwrap_or_default()].into_iter().filter(|l| !l.is_empty()).collect()
+}
+
+/// Institution party tag ({4000}, {4100}, {5100}, {5200}) as an agent and
+/// its account. CHIPS identifiers (`U`) and `T` identifiers are reported.
+pub fn party_to_agent(tag: &str, party: &FaimParty, report: &mut Vec<Unrepresentable>) -> (BranchAndFinancialInstitutionIdentification6, Option<CashAccount38>) {
+    let mut agent = BranchAndFinancialInstitutionIdentification6::default();
+    let mut account = None;
+    let id = &mut agent.fin_instn_id;
+    id.nm = party.name.clone();
+    id.pstl_adr = postal_address(&party.address);
+    if let Some(ref identifier) = party.identifier {
+        match party.id_code {
+            Some('B') => id.bicfi = Some(identifier.clone()),
+            Some('F') => id.clr_sys_mmb_id = Some(clearing_member(ABA_CLEARING_SYSTEM, identifier)),
+            Some('C') => id.clr_sys_mmb_id = Some(clearing_member(CHIPS_CLEARING_SYSTEM, identifier)),
+            Some('D') => account = Some(cash_account(identifier)),
+            code => report.push(Unrepresentable::new(&tag_name(tag), &format!("identification code {}", code.map(String::from).unwrap_or_default()))),
+        }
+    }
+    (agent, account)
+}
+
+fn customer_scheme(code: char) -> Option<(bool, &'static str)> {
+    match code {
+        '1' => Some((false, "CCPT")),
+        '2' => Some((false, "TXID")),
+        '3' => Some((false, "DRLC")),
+        '4' => Some((false, "ARNU")),
+        '5' => Some((true, "CINC")),
+        _ => None,
+    }
+}
+
+fn customer_code(scheme: Option<&str>, organisation: bool) -> Option<char> {
+    match (scheme, organisation) {
+        (Some("CCPT"), false) => Some('1'),
+        (Some("TXID"), _) => Some('2'),
+        (Some("DRLC"), false) => Some('3'),
+        (Some("ARNU"), false) => Some('4'),
+        (Some("CINC"), true) => Some('5'),
+        (None, false) => Some('9'),
+        _ => None,
+    }
+}
+
+/// Originator {5000} or beneficiary {4200} as a customer and its account.
+pub fn party_to_customer(tag: &str, party: &FaimParty, report: &mut Vec<Unrepresentable>) -> (PartyIdentification135, Option<CashAccount38>) {
+    let mut customer = PartyIdentification135 { nm: party.name.clone(), pstl_adr: postal_address(&party.address), ..Default::default() };
+    let mut account = None;
+    if let Some(ref identifier) = party.identifier {
+        match party.id_code {
+            Some('D') => account = Some(cash_account(identifier)),
+            Some('B') => {
+                customer.id = Some(Party38Choice {
+                    org_id: Some(OrganisationIdentification29 { any_bic: Some(identifier.clone()), ..Default::default() }),
+                    ..Default::default()
+                })
+            }
+            Some('9') => {
+                let other = GenericPersonIdentification1 { id: identifier.clone(), ..Default::default() };
+                customer.id = Some(Party38Choice {
+                    prvt_id: Some(PersonIdentification13 { othr: Some(vec![other]), ..Default::default() }),
+                    ..Default::default()
+                });
+            }
+            code => match code.and_then(customer_scheme) {
+                Some((true, scheme)) => {
+                    let other = GenericOrganisationIdentification1 {
+                        id: identifier.clone(),
+                        schme_nm: Some(OrganisationIdentificationSchemeName1Choice { cd: Some(scheme.to_string()), ..Default::default() }),
+                        ..Default::default()
+                    };
+                    customer.id = Some(Party38Choice {
+                        org_id: Some(OrganisationIdentification29 { othr: Some(vec![other]), ..Default::default() }),
+                        ..Default::default()
+                    });
+                }
+                Some((false, scheme)) => {
+                    let other = GenericPersonIdentification1 {
+                        id: identifier.clone(),
+                        schme_nm: Some(PersonIdentificationSchemeName1Choice { cd: Some(scheme.to_string()), ..Default::default() }),
+                        ..Default::default()
+                    };
+                    customer.id = Some(Party38Choice {
+                        prvt_id: Some(PersonIdentification13 { othr: Some(vec![other]), ..Default::default() }),
+                        ..Default::default()
+                    });
+                }
+                None => report.push(Unrepresentable::new(&tag_name(tag), &format!("identification code {}", code.map(String::from).unwrap_or_default()))),
+            },
+        }
+    }
+    (customer, account)
+}
+
+/// Party tag for an agent: BIC (`B`), ABA (`F`) or CHIPS participant (`C`)
+/// identification, the account (`D`) otherwise.
+pub fn agent_to_party(
+    element: &str,
+    agent: &BranchAndFinancialInstitutionIdentification6,
+    account: Option<&CashAccount38>,
+    report: &mut Vec<Unrepresentable>,
+) -> FaimParty {
+    let id = &agent.fin_instn_id;
+    let account = account.and_then(account_identifier);
+    let (id_code, identifier) = if let Some(ref bic) = id.bicfi {
+        (Some('B'), Some(bic.clone()))
+    } else if let Some(routing) = agent_routing_number(agent) {
+        (Some('F'), Some(routing.to_string()))
+    } else if let Some(participant) = clearing_member_id(agent, CHIPS_CLEARING_SYSTEM) {
+        (Some('C'), Some(participant.to_string()))
+    } else if let Some(ref account) = account {
+        (Some('D'), Some(account.clone()))
+    } else {
+        (None, None)
+    };
+    if account.is_some() && id_code != Some('D') {
+        report.push(Unrepresentable::new(&format!("{}Acct", element), "the party tag carries a single identifier"));
+    }
+    if id.clr_sys_mmb_id.is_some() && !matches!(id_code, Some('F') | Some('C')) {
+        report.push(Unrepresentable::new(&format!("{}/FinInstnId/ClrSysMmbId", element), "clearing system other than USABA or USPID"));
+    }
+    report_element(&id.lei, &format!("{}/FinInstnId/LEI", element), report);
+    report_element(&id.othr, &format!("{}/FinInstnId/Othr", element), report);
+    report_element(&agent.brnch_id, &format!("{}/BrnchId", element), report);
+    FaimParty {
+        id_code,
+        identifier,
+        name: id.nm.clone(),
+        address: id.pstl_adr.as_ref().map(address_lines).unwrap_or_default(),
+    }
+}
+
+/// Party tag for a customer: the account (`D`), else the BIC (`B`) or a
+/// personal or organisation identification.
+pub fn customer_to_party(element: &str, party: &PartyIdentification135, account: Option<&CashAccount38>, report: &mut Vec<Unrepresentable>) -> FaimParty {
+    let account = account.and_then(account_identifier);
+    let organisation = party.id.as_ref().and_then(|id| id.org_id.as_ref());
+    let private = party.id.as_ref().and_then(|id| id.prvt_id.as_ref());
+    let mut identification: Option<(char, String)> = None;
+    if let Some(org) = organisation {
+        if let Some(ref bic) = org.any_bic {
+            identification = Some(('B', bic.clone()));
+        } else if let Some(other) = org.othr.iter().flatten().next() {
+            let scheme = other.schme_nm.as_ref().and_then(|s| s.cd.as_deref());
+            identification = customer_code(scheme, true).map(|code| (code, other.id.clone()));
+        }
+    } else if let Some(other) = private.and_then(|p| p.othr.iter().flatten().next()) {
+        let scheme = other.schme_nm.as_ref().and_then(|s| s.cd.as_deref());
+        identification = customer_code(scheme, false).map(|code| (code, other.id.clone()));
+    }
+    let (id_code, identifier) = match (account, identification) {
+        (Some(account), identification) => {
+            if identification.is_some() {
+                report.push(Unrepresentable::new(&format!("{}/Id", element), "the party tag carries a single identifier"));
+            }
+            (Some('D'), Some(account))
+        }
+        (None, Some((code, identifier))) => (Some(code), Some(identifier)),
+        (None, None) => {
+            if party.id.is_some() {
+                report.push(Unrepresentable::new(&format!("{}/Id", element), "identification without a FAIM identification code"));
+            }
+            (None, None)
+        }
+    };
+    report_element(&organisation.and_then(|o| o.lei.as_ref()), &format!("{}/Id/OrgId/LEI", element), report);
+    report_element(&party.ctry_of_res, &format!("{}/CtryOfRes", element), report);
+    report_element(&party.ctct_dtls, &format!("{}/CtctDtls", element), report);
+    FaimParty {
+        id_code,
+        identifier,
+        name: party.nm.clone(),
+        address: party.pstl_adr.as_ref().map(address_lines).unwrap_or_default(),
+    }
+}
+
+/// References are 16 characters in FAIM.
+pub fn faim_reference(element: &str, value: &str, report: &mut Vec<Unrepresentable>) -> String {
+    let written = faim_text(value, 16);
+    if written != value {
+        report.push(Unrepresentable::new(element, "reference longer than 16 characters or with characters outside the FAIM set"));
+    }
+    written
+}
+
+/// Free text lines: each entry wrapped at 35 characters, at most `max_lines`.
+pub fn faim_lines<'a>(element: &str, texts: impl Iterator<Item = &'a str>, max_lines: usize, report: &mut Vec<Unrepresentable>) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for text in texts {
+        let chars: Vec<char> = text.chars().collect();
+        lines.extend(chars.chunks(35).map(|c| faim_text(&c.iter().collect::<String>(), 35)));
+    }
+    if lines.len() > max_lines {
+        report.push(Unrepresentable::new(element, &format!("text beyond {} lines of 35 characters", max_lines)));
+        lines.truncate(max_lines);
+    }
+    lines
+}
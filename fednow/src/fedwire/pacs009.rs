@@ -0,0 +1,228 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+// Fedwire bank transfers (BTR) <-> pacs.009.001.08
+// (FinancialInstitutionCreditTransferV08).
+
+use crate::common::*;
+use crate::iso::pacs_009_001_08::FinancialInstitutionCreditTransferV08;
+use open_payments_common::datetime;
+use open_payments_common::fedwire::{FundsTransfer, Translation, Unrepresentable, BUSINESS_FUNCTION_BANK_TRANSFER, ERR_UNMAPPABLE};
+use open_payments_common::ValidationError;
+
+use super::*;
+
+/// Parses a FAIM message and translates it, see `transfer_to_pacs009`.
+pub fn faim_to_pacs009(input: &str) -> Result<Translation<FinancialInstitutionCreditTransferV08>, ValidationError> {
+    transfer_to_pacs009(&FundsTransfer::parse(input)?)
+}
+
+/// Translates a bank transfer into a pacs.009 with one transaction. The
+/// originator {5000} is the debtor and the beneficiary {4200} the creditor,
+/// the sender and receiver taking their place when absent; {5100} and
+/// {4100} are the debtor and creditor agents. Charges, the instructed
+/// amount and the exchange rate have no place in a pacs.009 and are
+/// reported.
+pub fn transfer_to_pacs009(transfer: &FundsTransfer) -> Result<Translation<FinancialInstitutionCreditTransferV08>, ValidationError> {
+    if transfer.business_function_code != BUSINESS_FUNCTION_BANK_TRANSFER {
+        return Err(ValidationError::new(ERR_UNMAPPABLE, format!("business function {} is not a bank transfer", transfer.business_function_code)));
+    }
+    let mut report = Vec::new();
+    report_transfer(transfer, &mut report);
+    if transfer.charges.is_some() {
+        report.push(Unrepresentable::new("{3700}", "no charges in a pacs.009"));
+    }
+    if transfer.instructed_amount.is_some() {
+        report.push(Unrepresentable::new("{3710}", "no instructed amount in a pacs.009"));
+    }
+    if transfer.exchange_rate.is_some() {
+        report.push(Unrepresentable::new("{3720}", "no exchange rate in a pacs.009"));
+    }
+    let imad = transfer.imad.value();
+
+    let (dbtr, dbtr_acct) = match transfer.originator {
+        Some(ref party) => party_to_agent("5000", party, &mut report),
+        None => (depository_agent(&transfer.sender), None),
+    };
+    let (cdtr, cdtr_acct) = match transfer.beneficiary {
+        Some(ref party) => party_to_agent("4200", party, &mut report),
+        None => (depository_agent(&transfer.receiver), None),
+    };
+    let mut tx = CreditTransferTransaction36 {
+        pmt_id: PaymentIdentification7 {
+            instr_id: transfer.sender_reference.clone(),
+            end_to_end_id: transfer.reference_for_beneficiary.clone().or_else(|| transfer.sender_reference.clone()).unwrap_or_else(|| imad.clone()),
+            clr_sys_ref: Some(imad.clone()),
+            ..Default::default()
+        },
+        pmt_tp_inf: local_instrument(transfer).map(|l| PaymentTypeInformation28 { lcl_instrm: Some(l), ..Default::default() }),
+        intr_bk_sttlm_amt: interbank_amount(transfer.amount),
+        intr_bk_sttlm_dt: Some(transfer.imad.cycle_date.clone()),
+        instg_agt: Some(depository_agent(&transfer.sender)),
+        instd_agt: Some(depository_agent(&transfer.receiver)),
+        dbtr,
+        dbtr_acct,
+        cdtr,
+        cdtr_acct,
+        ..Default::default()
+    };
+    if let Some(ref party) = transfer.originator_fi {
+        let (agent, account) = party_to_agent("5100", party, &mut report);
+        tx.dbtr_agt = Some(agent);
+        tx.dbtr_agt_acct = account;
+    }
+    if let Some(ref party) = transfer.beneficiary_fi {
+        let (agent, account) = party_to_agent("4100", party, &mut report);
+        tx.cdtr_agt = Some(agent);
+        tx.cdtr_agt_acct = account;
+    }
+    if let Some(ref party) = transfer.intermediary_fi {
+        let (agent, account) = party_to_agent("4000", party, &mut report);
+        tx.intrmy_agt1 = Some(agent);
+        tx.intrmy_agt1_acct = account;
+    }
+    if let Some(ref party) = transfer.instructing_fi {
+        let (agent, account) = party_to_agent("5200", party, &mut report);
+        tx.prvs_instg_agt1 = Some(agent);
+        tx.prvs_instg_agt1_acct = account;
+    }
+    if !transfer.originator_to_beneficiary.is_empty() {
+        tx.rmt_inf = Some(RemittanceInformation2 { ustrd: Some(transfer.originator_to_beneficiary.clone()) });
+    }
+    if !transfer.receiver_fi_information.is_empty() {
+        tx.instr_for_nxt_agt = Some(transfer.receiver_fi_information.iter().map(|l| InstructionForNextAgent1 { instr_inf: Some(l.clone()), ..Default::default() }).collect());
+    }
+    if !transfer.beneficiary_fi_information.is_empty() {
+        tx.instr_for_cdtr_agt = Some(transfer.beneficiary_fi_information.iter().map(|l| InstructionForCreditorAgent2 { instr_inf: Some(l.clone()), ..Default::default() }).collect());
+    }
+
+    let document = FinancialInstitutionCreditTransferV08 {
+        grp_hdr: GroupHeader93 {
+            msg_id: imad,
+            cre_dt_tm: datetime::now_iso_datetime(),
+            nb_of_txs: "1".to_string(),
+            sttlm_inf: settlement_instruction(),
+            ..Default::default()
+        },
+        cdt_trf_tx_inf: vec![tx],
+        splmtry_data: None,
+    };
+    Ok(Translation { message: document, unrepresentable: report })
+}
+
+/// Translates a pacs.009 and writes it as a FAIM message, see `pacs009_to_transfer`.
+pub fn pacs009_to_faim(document: &FinancialInstitutionCreditTransferV08) -> Result<Translation<String>, ValidationError> {
+    let translation = pacs009_to_transfer(document)?;
+    Ok(Translation { message: translation.message.to_faim(), unrepresentable: translation.unrepresentable })
+}
+
+/// Translates a pacs.009 with a single USD transaction into a bank
+/// transfer. The underlying customer credit transfer of a pacs.009 COV is
+/// reported, Fedwire carries covers as CTP messages.
+pub fn pacs009_to_transfer(document: &FinancialInstitutionCreditTransferV08) -> Result<Translation<FundsTransfer>, ValidationError> {
+    if document.cdt_trf_tx_inf.len() != 1 {
+        return Err(ValidationError::new(ERR_UNMAPPABLE, format!("a Fedwire message carries one transaction, not {}", document.cdt_trf_tx_inf.len())));
+    }
+    let header = &document.grp_hdr;
+    let tx = &document.cdt_trf_tx_inf[0];
+    let mut report = Vec::new();
+
+    let sender = depository_institution("InstgAgt", tx.instg_agt.as_ref().or(header.instg_agt.as_ref()), Some(&tx.dbtr))?;
+    let receiver = depository_institution("InstdAgt", tx.instd_agt.as_ref().or(header.instd_agt.as_ref()), Some(&tx.cdtr))?;
+    let mut transfer = FundsTransfer {
+        test_production_code: 'P',
+        message_duplication_code: ' ',
+        type_code: "10".to_string(),
+        subtype_code: "00".to_string(),
+        imad: message_imad(tx.pmt_id.clr_sys_ref.as_deref(), &header.msg_id)?,
+        amount: amount_in_cents(&tx.intr_bk_sttlm_amt)?,
+        business_function_code: BUSINESS_FUNCTION_BANK_TRANSFER.to_string(),
+        ..Default::default()
+    };
+
+    transfer.sender_reference = tx.pmt_id.instr_id.as_deref().map(|r| faim_reference("CdtTrfTxInf/PmtId/InstrId", r, &mut report));
+    let end_to_end_id = tx.pmt_id.end_to_end_id.as_str();
+    if Some(end_to_end_id) != tx.pmt_id.instr_id.as_deref() && Some(end_to_end_id) != tx.pmt_id.clr_sys_ref.as_deref() {
+        transfer.reference_for_beneficiary = Some(faim_reference("CdtTrfTxInf/PmtId/EndToEndId", end_to_end_id, &mut report));
+    }
+    report_element(&tx.pmt_id.tx_id, "CdtTrfTxInf/PmtId/TxId", &mut report);
+    report_element(&tx.pmt_id.uetr, "CdtTrfTxInf/PmtId/UETR", &mut report);
+    if let Some(ref payment_type) = tx.pmt_tp_inf {
+        transfer.local_instrument = payment_type.lcl_instrm.as_ref().and_then(faim_local_instrument);
+        report_element(&payment_type.instr_prty, "CdtTrfTxInf/PmtTpInf/InstrPrty", &mut report);
+        report_element(&payment_type.clr_chanl, "CdtTrfTxInf/PmtTpInf/ClrChanl", &mut report);
+        report_element(&payment_type.svc_lvl, "CdtTrfTxInf/PmtTpInf/SvcLvl", &mut report);
+        report_element(&payment_type.ctgy_purp, "CdtTrfTxInf/PmtTpInf/CtgyPurp", &mut report);
+    }
+
+    if let Some(ref agent) = tx.intrmy_agt1 {
+        transfer.intermediary_fi = Some(agent_to_party("CdtTrfTxInf/IntrmyAgt1", agent, tx.intrmy_agt1_acct.as_ref(), &mut report));
+    }
+    if let Some(ref agent) = tx.cdtr_agt {
+        transfer.beneficiary_fi = Some(agent_to_party("CdtTrfTxInf/CdtrAgt", agent, tx.cdtr_agt_acct.as_ref(), &mut report));
+    }
+    if !is_depository_institution(&tx.cdtr, &receiver) || tx.cdtr_acct.is_some() {
+        transfer.beneficiary = Some(agent_to_party("CdtTrfTxInf/Cdtr", &tx.cdtr, tx.cdtr_acct.as_ref(), &mut report));
+    }
+    if !is_depository_institution(&tx.dbtr, &sender) || tx.dbtr_acct.is_some() {
+        transfer.originator = Some(agent_to_party("CdtTrfTxInf/Dbtr", &tx.dbtr, tx.dbtr_acct.as_ref(), &mut report));
+    }
+    if let Some(ref agent) = tx.dbtr_agt {
+        transfer.originator_fi = Some(agent_to_party("CdtTrfTxInf/DbtrAgt", agent, tx.dbtr_agt_acct.as_ref(), &mut report));
+    }
+    if let Some(ref agent) = tx.prvs_instg_agt1 {
+        transfer.instructing_fi = Some(agent_to_party("CdtTrfTxInf/PrvsInstgAgt1", agent, tx.prvs_instg_agt1_acct.as_ref(), &mut report));
+    }
+
+    if let Some(ref remittance) = tx.rmt_inf {
+        transfer.originator_to_beneficiary = faim_lines("CdtTrfTxInf/RmtInf/Ustrd", remittance.ustrd.iter().flatten().map(|l| l.as_str()), 4, &mut report);
+    }
+    let next_agent = tx.instr_for_nxt_agt.iter().flatten();
+    if next_agent.clone().any(|i| i.cd.is_some()) {
+        report.push(Unrepresentable::new("CdtTrfTxInf/InstrForNxtAgt/Cd", "instruction codes"));
+    }
+    transfer.receiver_fi_information = faim_lines("CdtTrfTxInf/InstrForNxtAgt", next_agent.filter_map(|i| i.instr_inf.as_deref()), 6, &mut report);
+    let creditor_agent = tx.instr_for_cdtr_agt.iter().flatten();
+    if creditor_agent.clone().any(|i| i.cd.is_some()) {
+        report.push(Unrepresentable::new("CdtTrfTxInf/InstrForCdtrAgt/Cd", "instruction codes"));
+    }
+    transfer.beneficiary_fi_information = faim_lines("CdtTrfTxInf/InstrForCdtrAgt", creditor_agent.filter_map(|i| i.instr_inf.as_deref()), 6, &mut report);
+
+    report_element(&tx.sttlm_prty, "CdtTrfTxInf/SttlmPrty", &mut report);
+    report_element(&tx.sttlm_tm_indctn, "CdtTrfTxInf/SttlmTmIndctn", &mut report);
+    report_element(&tx.sttlm_tm_req, "CdtTrfTxInf/SttlmTmReq", &mut report);
+    report_element(&tx.prvs_instg_agt2, "CdtTrfTxInf/PrvsInstgAgt2", &mut report);
+    report_element(&tx.prvs_instg_agt3, "CdtTrfTxInf/PrvsInstgAgt3", &mut report);
+    report_element(&tx.intrmy_agt2, "CdtTrfTxInf/IntrmyAgt2", &mut report);
+    report_element(&tx.intrmy_agt3, "CdtTrfTxInf/IntrmyAgt3", &mut report);
+    report_element(&tx.ultmt_dbtr, "CdtTrfTxInf/UltmtDbtr", &mut report);
+    report_element(&tx.ultmt_cdtr, "CdtTrfTxInf/UltmtCdtr", &mut report);
+    report_element(&tx.purp, "CdtTrfTxInf/Purp", &mut report);
+    report_element(&tx.undrlyg_cstmr_cdt_trf, "CdtTrfTxInf/UndrlygCstmrCdtTrf", &mut report);
+    report_element(&tx.splmtry_data, "CdtTrfTxInf/SplmtryData", &mut report);
+    report_element(&document.splmtry_data, "SplmtryData", &mut report);
+
+    transfer.sender = sender;
+    transfer.receiver = receiver;
+    Ok(Translation { message: transfer, unrepresentable: report })
+}
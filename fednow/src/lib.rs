@@ -28,6 +28,29 @@ pub mod fednow_incoming_external;
 pub mod fednow_outgoing_external;
 pub mod document;
 pub mod common;
+#[cfg(feature = "derive_default")]
+pub mod swift;
+#[cfg(feature = "derive_default")]
+pub mod bai2;
+#[cfg(feature = "derive_default")]
+pub mod fedwire;
+pub mod reconciliation;
+pub mod statement_check;
+pub mod pagination;
+pub mod participant_directory;
+pub mod system_event;
+#[cfg(feature = "derive_default")]
+pub mod rfp;
+#[cfg(feature = "derive_serde")]
+pub mod header_check;
+#[cfg(feature = "derive_serde")]
+pub mod duplicate;
+#[cfg(all(feature = "derive_serde", feature = "derive_default"))]
+pub mod message_reject;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
 
 use crate::fednow_incoming_external::*;
 use crate::fednow_outgoing_external::*;
@@ -50,3 +73,22 @@ pub enum FednowMessage {
     #[cfg_attr(feature = "derive_default", default)]
     UNKNOWN
 }
+
+impl FednowMessage {
+    /// Walks the message with a visitor (`crate::visitor`).
+    pub fn walk<V: visitor::Visitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            FednowMessage::FedNowIncoming(message) => message.walk(visitor),
+            FednowMessage::FedNowOutgoing(message) => message.walk(visitor),
+            FednowMessage::UNKNOWN => {}
+        }
+    }
+
+    pub fn walk_mut<V: visitor::VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+            FednowMessage::FedNowIncoming(message) => message.walk_mut(visitor),
+            FednowMessage::FedNowOutgoing(message) => message.walk_mut(visitor),
+            FednowMessage::UNKNOWN => {}
+        }
+    }
+}
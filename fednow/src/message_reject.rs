@@ -0,0 +1,160 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Message rejects (admi.002.001.01) for inbound FedNow messages that cannot
+// be parsed or do not validate (`open_payments_common::reject`). The
+// reject goes back to the sender of the message in a `FedNowMessageReject`
+// with its own business application header.
+
+use crate::common::*;
+use crate::document::Document;
+use crate::header_check::AppHdrBuilder;
+use crate::iso::admi_002_001_01::Admi00200101;
+use crate::fednow_outgoing_external::FedNowOutgoing;
+use crate::FednowMessage;
+use open_payments_common::header_check::{AgentId, ERR_TO_AGENT};
+use open_payments_common::reject::{error_location, message_references, Rejection};
+use open_payments_common::schema::{SchemaSet, SchemaViolation};
+use open_payments_common::ValidationError;
+use serde_path_to_error::Segment;
+
+/// `RltdRef/Ref` when the rejected message has no readable `BizMsgIdr`.
+pub const NO_REFERENCE: &str = "NONREF";
+
+pub type ParseError = serde_path_to_error::Error<serde_xml_rs::Error>;
+
+/// Element path of a parse error, with 1-based indexes for repeated
+/// elements.
+pub fn parse_error_location(error: &ParseError) -> Option<String> {
+    let mut location = String::new();
+    for segment in error.path().iter() {
+        match segment {
+            Segment::Map { key } => location.push_str(&format!("/{}", key)),
+            Segment::Enum { variant } => location.push_str(&format!("/{}", variant)),
+            Segment::Seq { index } => location.push_str(&format!("[{}]", index + 1)),
+            Segment::Unknown => {}
+        }
+    }
+    if location.is_empty() { None } else { Some(location) }
+}
+
+/// Rejection of a message that could not be parsed. The references come
+/// from the raw header.
+pub fn parse_rejection(error: &ParseError, xml: &str) -> Rejection {
+    Rejection::syntax(&error.inner().to_string(), parse_error_location(error)).with_header_references(xml)
+}
+
+/// Rejection of a parsed message that failed validation.
+pub fn validation_rejection(error: &ValidationError, message: &FednowMessage) -> Rejection {
+    let value = serde_json::to_value(message).unwrap_or_default();
+    let (biz_msg_idr, sender) = message_references(&value);
+    Rejection {
+        location: error_location(error, &value),
+        biz_msg_idr,
+        sender,
+        ..Rejection::from_validation_error(error)
+    }
+}
+
+/// Parses and validates an inbound message; a message that fails either
+/// step comes back as the rejection to answer it with.
+pub fn parse_message(xml: &str) -> Result<FednowMessage, Box<Rejection>> {
+    let mut deserializer = serde_xml_rs::Deserializer::new_from_reader(xml.as_bytes());
+    let message: FednowMessage = serde_path_to_error::deserialize(&mut deserializer).map_err(|e| Box::new(parse_rejection(&e, xml)))?;
+    let result = match &message {
+        FednowMessage::FedNowIncoming(incoming) => incoming.validate(),
+        FednowMessage::FedNowOutgoing(outgoing) => outgoing.validate(),
+        FednowMessage::UNKNOWN => return Err(Box::new(Rejection::syntax("not a FedNow message", None).with_header_references(xml))),
+    };
+    match result {
+        Ok(()) => Ok(message),
+        Err(error) => Err(Box::new(validation_rejection(&error, &message))),
+    }
+}
+
+/// Rejection of a message that does not follow its schema. The references
+/// come from the raw header.
+pub fn schema_rejection(violation: &SchemaViolation, xml: &str) -> Rejection {
+    Rejection::from_schema_violation(violation).with_header_references(xml)
+}
+
+/// `parse_message` of a message that is first validated against the XSDs,
+/// which also rejects elements the generated types have no field for and
+/// the cardinalities and facets `validate` does not check. The first
+/// violation is the rejection.
+pub fn parse_message_with_schemas(xml: &str, schemas: &SchemaSet) -> Result<FednowMessage, Box<Rejection>> {
+    if let Some(violation) = schemas.validate(xml).first() {
+        return Err(Box::new(schema_rejection(violation, xml)));
+    }
+    parse_message(xml)
+}
+
+/// Keeps the first `max` characters of `text`, or the last ones for
+/// element paths where the innermost element matters most.
+fn limit(text: &str, max: usize, keep_end: bool) -> String {
+    let count = text.chars().count();
+    if count <= max {
+        text.to_string()
+    } else if keep_end {
+        text.chars().skip(count - max).collect()
+    } else {
+        text.chars().take(max).collect()
+    }
+}
+
+/// The admi.002 answering a rejection, from `from` (the rejecting party)
+/// to the sender of the rejected message, with `reject_id` as its
+/// `BizMsgIdr`.
+pub fn message_reject(rejection: &Rejection, reject_id: &str, from: AgentId, cre_dt_tm: &str) -> Result<FedNowMessageReject, ValidationError> {
+    let sender = rejection.sender.clone().ok_or_else(|| ValidationError::new(ERR_TO_AGENT, format!(
+        "the sender of message {} is not known, the reject has no receiver", rejection.biz_msg_idr.as_deref().unwrap_or(NO_REFERENCE)
+    )))?;
+    let document = Document::Admi00200101(Box::new(Admi00200101 {
+        rltd_ref: MessageReference { ref_attr: limit(rejection.biz_msg_idr.as_deref().unwrap_or(NO_REFERENCE), 35, false) },
+        rsn: RejectionReason2 {
+            rjctg_pty_rsn: rejection.reason.clone(),
+            rjctn_dt_tm: Some(cre_dt_tm.to_string()),
+            err_lctn: rejection.location.as_deref().map(|l| limit(l, 350, true)),
+            rsn_desc: Some(limit(&rejection.description, 350, false)).filter(|d| !d.is_empty()),
+            addtl_data: rejection.error_code.map(|code| format!("ValidationError {}", code)),
+        },
+    }));
+    let app_hdr = AppHdrBuilder::from_document(&document)?
+        .business_message_id(reject_id)
+        .creation_date(cre_dt_tm)
+        .from(from)
+        .to(sender)
+        .build()?;
+    let reject = FedNowMessageReject { bah_app_hdr: app_hdr, a2_document: document };
+    reject.validate()?;
+    Ok(reject)
+}
+
+/// A message reject as an outgoing FedNow message.
+pub fn outgoing_reject(reject: FedNowMessageReject) -> FednowMessage {
+    FednowMessage::FedNowOutgoing(Box::new(FedNowOutgoing {
+        fed_now_outgoing_message: FedNowOutgoingMessage { fed_now_message_reject: Some(reject), ..Default::default() },
+        ..Default::default()
+    }))
+}
@@ -0,0 +1,186 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Reassembly and splitting of camt.052.001.08 account reports paginated
+// with `GrpHdr/MsgPgntn` (`open_payments_common::pagination`), such as the
+// responses to an account reporting request (camt.060.001.05).
+//
+// Pages are matched by the `Id` of their first report. Merging appends the
+// entries and interest records of the reports with the same `Id` and keeps
+// each balance (type and date) once; the merged message is page 1 and the
+// last page.
+
+use crate::common::*;
+use crate::iso::camt_052_001_08::BankToCustomerAccountReportV08;
+use open_payments_common::pagination::{page_number, PageAssembler};
+#[cfg(feature = "derive_clone")]
+use open_payments_common::pagination::{page_ranges, PageLimits};
+use open_payments_common::ValidationError;
+
+fn balance_key(balance: &CashBalance8) -> (Option<&str>, Option<&str>, Option<&str>) {
+    let tp = &balance.tp.cd_or_prtry;
+    (tp.cd.as_deref().or(tp.prtry.as_deref()), balance.dt.dt.as_deref(), balance.dt.dt_tm.as_deref())
+}
+
+fn extend<T>(target: &mut Option<Vec<T>>, source: Option<Vec<T>>) {
+    if let Some(items) = source {
+        target.get_or_insert_with(Vec::new).extend(items);
+    }
+}
+
+fn merge_report(target: &mut AccountReport25, report: AccountReport25) {
+    if let Some(balances) = report.bal {
+        let target = target.bal.get_or_insert_with(Vec::new);
+        for balance in balances {
+            if !target.iter().any(|b| balance_key(b) == balance_key(&balance)) {
+                target.push(balance);
+            }
+        }
+    }
+    if target.txs_summry.is_none() {
+        target.txs_summry = report.txs_summry;
+    }
+    extend(&mut target.intrst, report.intrst);
+    extend(&mut target.ntry, report.ntry);
+    target.rpt_pgntn = None;
+}
+
+/// Merges the pages of a report, in page order, into one message.
+pub fn merge_reports(pages: Vec<BankToCustomerAccountReportV08>) -> Option<BankToCustomerAccountReportV08> {
+    let mut pages = pages.into_iter();
+    let mut merged = pages.next()?;
+    for page in pages {
+        for report in page.rpt {
+            match merged.rpt.iter_mut().find(|r| r.id == report.id) {
+                Some(target) => merge_report(target, report),
+                None => merged.rpt.push(report),
+            }
+        }
+        extend(&mut merged.splmtry_data, page.splmtry_data);
+    }
+    merged.grp_hdr.msg_pgntn = Some(Pagination1 { pg_nb: "1".to_string(), last_pg_ind: true });
+    Some(merged)
+}
+
+/// Collects camt.052 pages and yields the merged report once every page
+/// has arrived.
+#[derive(Default)]
+pub struct ReportAssembler {
+    pages: PageAssembler<BankToCustomerAccountReportV08>,
+}
+
+impl ReportAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a page, keyed by the `Id` of its first report. A message without
+    /// `MsgPgntn` is complete on its own.
+    pub fn add(&mut self, page: BankToCustomerAccountReportV08) -> Result<Option<BankToCustomerAccountReportV08>, ValidationError> {
+        let key = page.rpt.first().map(|r| r.id.clone()).unwrap_or_else(|| page.grp_hdr.msg_id.clone());
+        self.add_with_key(&key, page)
+    }
+
+    /// Adds a page under a key chosen by the caller, e.g. the `MsgId` of the
+    /// account reporting request in `OrgnlBizQry`.
+    pub fn add_with_key(&mut self, key: &str, page: BankToCustomerAccountReportV08) -> Result<Option<BankToCustomerAccountReportV08>, ValidationError> {
+        let (number, last) = match page.grp_hdr.msg_pgntn {
+            Some(ref p) => (page_number(&p.pg_nb)?, p.last_pg_ind),
+            None => (1, true),
+        };
+        Ok(self.pages.add(key, number, last, page)?.and_then(merge_reports))
+    }
+
+    pub fn pages(&self) -> &PageAssembler<BankToCustomerAccountReportV08> {
+        &self.pages
+    }
+
+    pub fn pages_mut(&mut self) -> &mut PageAssembler<BankToCustomerAccountReportV08> {
+        &mut self.pages
+    }
+}
+
+/// Splits a camt.052 report into pages under the limits. `entry_size`
+/// measures an entry for `PageLimits::max_size`, e.g. its serialized length.
+/// Entries stay in order; every page repeats the report headers and
+/// balances, `TxsSummry` and `Intrst` are only on the first page of each
+/// report, and every page starts with the first report so that the pages
+/// can be matched by its `Id`.
+#[cfg(feature = "derive_clone")]
+pub fn split_report(
+    report: BankToCustomerAccountReportV08,
+    limits: &PageLimits,
+    entry_size: impl Fn(&ReportEntry10) -> usize,
+) -> Result<Vec<BankToCustomerAccountReportV08>, ValidationError> {
+    let BankToCustomerAccountReportV08 { grp_hdr, rpt, mut splmtry_data } = report;
+    let mut headers = Vec::new();
+    let mut items = Vec::new();
+    for mut r in rpt {
+        let entries = r.ntry.take().unwrap_or_default();
+        if entries.is_empty() {
+            items.push((headers.len(), None));
+        }
+        items.extend(entries.into_iter().map(|e| (headers.len(), Some(e))));
+        r.rpt_pgntn = None;
+        headers.push(r);
+    }
+    let continued = |index: usize| {
+        let mut header: AccountReport25 = headers[index].clone();
+        header.txs_summry = None;
+        header.intrst = None;
+        header
+    };
+    let sizes: Vec<usize> = items.iter().map(|(_, e)| e.as_ref().map(&entry_size).unwrap_or(0)).collect();
+    let ranges = page_ranges(&sizes, limits)?;
+    let total = ranges.len();
+    let mut items = items.into_iter();
+    let mut started = vec![false; headers.len()];
+    let mut pages = Vec::new();
+    for (page_index, range) in ranges.into_iter().enumerate() {
+        let mut page: Vec<(usize, AccountReport25)> = Vec::new();
+        for (index, entry) in items.by_ref().take(range.len()) {
+            if page.last().is_none_or(|(i, _)| *i != index) {
+                let header = if started[index] { continued(index) } else { headers[index].clone() };
+                started[index] = true;
+                page.push((index, header));
+            }
+            if let (Some(entry), Some((_, header))) = (entry, page.last_mut()) {
+                header.ntry.get_or_insert_with(Vec::new).push(entry);
+            }
+        }
+        if !headers.is_empty() && page.first().is_none_or(|(i, _)| *i != 0) {
+            page.insert(0, (0, continued(0)));
+        }
+        let mut grp_hdr = grp_hdr.clone();
+        if total > 1 {
+            let suffix = format!("-{}", page_index + 1);
+            let base: String = grp_hdr.msg_id.chars().take(35 - suffix.len()).collect();
+            grp_hdr.msg_id = format!("{}{}", base, suffix);
+        }
+        grp_hdr.msg_pgntn = Some(Pagination1 { pg_nb: (page_index + 1).to_string(), last_pg_ind: page_index + 1 == total });
+        let rpt = page.into_iter().map(|(_, r)| r).collect();
+        pages.push(BankToCustomerAccountReportV08 { grp_hdr, rpt, splmtry_data: splmtry_data.take() });
+    }
+    Ok(pages)
+}
@@ -0,0 +1,266 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// FedNow participant directory built from the participant files
+// (`FedNowParticipantFile1`) distributed with admi.998, one file per
+// business day. Participants are looked up by routing number to find out
+// whether they can receive credit transfers or requests for payment before
+// a message is built, and the files of two business days can be compared.
+
+use std::collections::BTreeMap;
+#[cfg(feature = "derive_serde")]
+use std::sync::LazyLock;
+
+#[cfg(feature = "derive_serde")]
+use regex::Regex;
+
+use crate::fednow_extra::participant_file::FedNowParticipantFile1;
+use crate::fednow_extra::participant_file::ServicesFedNow1;
+use open_payments_common::nacha::is_routing_number;
+use open_payments_common::ValidationError;
+
+pub const ERR_NOT_A_PARTICIPANT: u32 = 2901;
+pub const ERR_SERVICE_NOT_ENABLED: u32 = 2902;
+pub const ERR_UNKNOWN_BUSINESS_DAY: u32 = 2903;
+pub const ERR_PARTICIPANT_FILE: u32 = 2904;
+
+#[cfg(feature = "derive_serde")]
+static PARTICIPANT_FILE_START: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<(?:[A-Za-z0-9_]+:)?PtcptFile[\s>]").unwrap());
+#[cfg(feature = "derive_serde")]
+static PARTICIPANT_FILE_END: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"</(?:[A-Za-z0-9_]+:)?PtcptFile\s*>").unwrap());
+
+/// FedNow services a participant has enrolled for (`Svcs`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FedNowService {
+    /// CTSR: sends and receives credit transfers.
+    CreditTransferSendReceive,
+    /// CTRO: receives credit transfers only.
+    CreditTransferReceiveOnly,
+    /// RFPR: receives requests for payment.
+    RequestForPaymentReceive,
+}
+
+impl FedNowService {
+    pub fn code(&self) -> &'static str {
+        match self {
+            FedNowService::CreditTransferSendReceive => "CTSR",
+            FedNowService::CreditTransferReceiveOnly => "CTRO",
+            FedNowService::RequestForPaymentReceive => "RFPR",
+        }
+    }
+}
+
+impl From<&ServicesFedNow1> for FedNowService {
+    fn from(service: &ServicesFedNow1) -> Self {
+        match service {
+            ServicesFedNow1::CodeCTSR => FedNowService::CreditTransferSendReceive,
+            ServicesFedNow1::CodeCTRO => FedNowService::CreditTransferReceiveOnly,
+            ServicesFedNow1::CodeRFPR => FedNowService::RequestForPaymentReceive,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Participant {
+    pub routing_number: String,
+    pub name: String,
+    pub services: Vec<FedNowService>,
+}
+
+impl Participant {
+    pub fn has_service(&self, service: FedNowService) -> bool {
+        self.services.contains(&service)
+    }
+
+    pub fn can_send_credit_transfers(&self) -> bool {
+        self.has_service(FedNowService::CreditTransferSendReceive)
+    }
+
+    pub fn can_receive_credit_transfers(&self) -> bool {
+        self.has_service(FedNowService::CreditTransferSendReceive) || self.has_service(FedNowService::CreditTransferReceiveOnly)
+    }
+
+    pub fn can_receive_requests_for_payment(&self) -> bool {
+        self.has_service(FedNowService::RequestForPaymentReceive)
+    }
+}
+
+/// A participant present on both days with a different name or services.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ParticipantChange {
+    pub before: Participant,
+    pub after: Participant,
+}
+
+impl ParticipantChange {
+    pub fn services_added(&self) -> Vec<FedNowService> {
+        self.after.services.iter().filter(|s| !self.before.services.contains(s)).copied().collect()
+    }
+
+    pub fn services_removed(&self) -> Vec<FedNowService> {
+        self.before.services.iter().filter(|s| !self.after.services.contains(s)).copied().collect()
+    }
+}
+
+/// Differences between the participant files of two business days.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DirectoryDiff {
+    pub from_business_day: String,
+    pub to_business_day: String,
+    pub added: Vec<Participant>,
+    pub removed: Vec<Participant>,
+    pub changed: Vec<ParticipantChange>,
+}
+
+impl DirectoryDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Participants per business day, by routing number.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ParticipantDirectory {
+    days: BTreeMap<String, BTreeMap<String, Participant>>,
+}
+
+impl ParticipantDirectory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the participant file of a business day, replacing a file
+    /// loaded earlier for the same day. Routing numbers must be valid ABA
+    /// routing numbers and appear once.
+    pub fn load(&mut self, file: &FedNowParticipantFile1) -> Result<(), ValidationError> {
+        if file.biz_day.is_empty() {
+            return Err(ValidationError::new(ERR_PARTICIPANT_FILE, "participant file without BizDay".to_string()));
+        }
+        let mut participants = BTreeMap::new();
+        for profile in &file.ptcpt_prfl {
+            if !is_routing_number(&profile.id) {
+                return Err(ValidationError::new(ERR_PARTICIPANT_FILE, format!("{}: invalid routing number {}", file.biz_day, profile.id)));
+            }
+            let mut services: Vec<FedNowService> = profile.svcs.iter().map(FedNowService::from).collect();
+            services.sort();
+            services.dedup();
+            let participant = Participant { routing_number: profile.id.clone(), name: profile.nm.clone(), services };
+            if participants.insert(profile.id.clone(), participant).is_some() {
+                return Err(ValidationError::new(ERR_PARTICIPANT_FILE, format!("{}: routing number {} listed twice", file.biz_day, profile.id)));
+            }
+        }
+        self.days.insert(file.biz_day.clone(), participants);
+        Ok(())
+    }
+
+    /// Loads the participant file of an admi.998 message (or of a bare
+    /// `PtcptFile` element). The `PrtryData/Data` envelope of the generated
+    /// message is open content, so the file is read from the XML directly.
+    /// Returns the business day.
+    #[cfg(feature = "derive_serde")]
+    pub fn load_xml(&mut self, xml: &str) -> Result<String, ValidationError> {
+        let from = PARTICIPANT_FILE_START.find(xml).ok_or_else(|| ValidationError::new(ERR_PARTICIPANT_FILE, "no PtcptFile element".to_string()))?.start();
+        let to = PARTICIPANT_FILE_END.find_at(xml, from).ok_or_else(|| ValidationError::new(ERR_PARTICIPANT_FILE, "unterminated PtcptFile element".to_string()))?.end();
+        let file: FedNowParticipantFile1 = serde_xml_rs::from_str(&xml[from..to]).map_err(|e| ValidationError::new(ERR_PARTICIPANT_FILE, e.to_string()))?;
+        self.load(&file)?;
+        Ok(file.biz_day)
+    }
+
+    /// Loaded business days, oldest first.
+    pub fn business_days(&self) -> Vec<&str> {
+        self.days.keys().map(String::as_str).collect()
+    }
+
+    pub fn latest_business_day(&self) -> Option<&str> {
+        self.days.keys().next_back().map(String::as_str)
+    }
+
+    /// Removes the files of the business days before the given one.
+    pub fn retain_from(&mut self, business_day: &str) {
+        self.days.retain(|day, _| day.as_str() >= business_day);
+    }
+
+    fn day(&self, business_day: &str) -> Result<&BTreeMap<String, Participant>, ValidationError> {
+        self.days.get(business_day).ok_or_else(|| ValidationError::new(ERR_UNKNOWN_BUSINESS_DAY, format!("no participant file for business day {}", business_day)))
+    }
+
+    pub fn participants(&self, business_day: &str) -> Result<Vec<&Participant>, ValidationError> {
+        Ok(self.day(business_day)?.values().collect())
+    }
+
+    pub fn participant(&self, business_day: &str, routing_number: &str) -> Result<&Participant, ValidationError> {
+        self.day(business_day)?
+            .get(routing_number)
+            .ok_or_else(|| ValidationError::new(ERR_NOT_A_PARTICIPANT, format!("{} is not a FedNow participant on {}", routing_number, business_day)))
+    }
+
+    /// The participant in the latest participant file.
+    pub fn lookup(&self, routing_number: &str) -> Result<&Participant, ValidationError> {
+        let business_day = self.latest_business_day().ok_or_else(|| ValidationError::new(ERR_UNKNOWN_BUSINESS_DAY, "no participant file loaded".to_string()))?;
+        self.participant(business_day, routing_number)
+    }
+
+    fn require(&self, business_day: &str, routing_number: &str, enabled: fn(&Participant) -> bool, what: &str) -> Result<(), ValidationError> {
+        let participant = self.participant(business_day, routing_number)?;
+        if enabled(participant) {
+            Ok(())
+        } else {
+            Err(ValidationError::new(ERR_SERVICE_NOT_ENABLED, format!("{} ({}) cannot {} on {}", routing_number, participant.name, what, business_day)))
+        }
+    }
+
+    /// Whether the routing number can send credit transfers (CTSR) on the
+    /// business day.
+    pub fn check_can_send_credit_transfers(&self, business_day: &str, routing_number: &str) -> Result<(), ValidationError> {
+        self.require(business_day, routing_number, Participant::can_send_credit_transfers, "send credit transfers")
+    }
+
+    /// Whether the routing number can receive credit transfers (CTSR or
+    /// CTRO) on the business day.
+    pub fn check_can_receive_credit_transfers(&self, business_day: &str, routing_number: &str) -> Result<(), ValidationError> {
+        self.require(business_day, routing_number, Participant::can_receive_credit_transfers, "receive credit transfers")
+    }
+
+    /// Whether the routing number can receive requests for payment (RFPR)
+    /// on the business day.
+    pub fn check_can_receive_requests_for_payment(&self, business_day: &str, routing_number: &str) -> Result<(), ValidationError> {
+        self.require(business_day, routing_number, Participant::can_receive_requests_for_payment, "receive requests for payment")
+    }
+
+    /// Participants added, removed and changed between two business days.
+    pub fn diff(&self, from_business_day: &str, to_business_day: &str) -> Result<DirectoryDiff, ValidationError> {
+        let before = self.day(from_business_day)?;
+        let after = self.day(to_business_day)?;
+        let mut diff = DirectoryDiff { from_business_day: from_business_day.to_string(), to_business_day: to_business_day.to_string(), ..Default::default() };
+        for (routing_number, participant) in after {
+            match before.get(routing_number) {
+                None => diff.added.push(participant.clone()),
+                Some(previous) if previous != participant => diff.changed.push(ParticipantChange { before: previous.clone(), after: participant.clone() }),
+                Some(_) => {}
+            }
+        }
+        diff.removed = before.iter().filter(|(r, _)| !after.contains_key(*r)).map(|(_, p)| p.clone()).collect();
+        Ok(diff)
+    }
+}
@@ -0,0 +1,120 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+// Statement entries and instructions for `open_payments_common::reconciliation`:
+// camt.054.001.08 entries and pacs.008.001.08 instructions.
+
+use crate::common::*;
+use crate::iso::camt_054_001_08::BankToCustomerDebitCreditNotificationV08;
+use crate::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+use open_payments_common::datetime::date_part;
+use open_payments_common::reconciliation::{EntryBatch, EntryTransaction, Instruction, References, StatementEntry};
+
+fn choice_date(choice: &Option<DateAndDateTime2Choice>) -> Option<String> {
+    let choice = choice.as_ref()?;
+    choice.dt.as_deref().or(choice.dt_tm.as_deref()).map(|d| date_part(d).to_string())
+}
+
+fn account_id(account: &CashAccount39) -> Option<String> {
+    account.id.iban.clone().or_else(|| account.id.othr.as_ref().map(|o| o.id.clone()))
+}
+
+fn transaction_references(refs: &TransactionReferences6) -> References {
+    References {
+        message_id: refs.msg_id.clone(),
+        payment_information_id: refs.pmt_inf_id.clone(),
+        instruction_id: refs.instr_id.clone(),
+        end_to_end_id: refs.end_to_end_id.clone(),
+        uetr: refs.uetr.clone(),
+        account_servicer_reference: refs.acct_svcr_ref.clone(),
+    }
+}
+
+/// Reduces an entry to its amounts, dates and references. The first
+/// `NtryDtls/Btch` found describes the batch of a batch booked entry.
+pub fn report_entry(entry: &ReportEntry10, account: Option<String>) -> StatementEntry {
+    let details = entry.ntry_dtls.iter().flatten();
+    let batch = details.clone().find_map(|d| d.btch.as_ref()).map(|b| EntryBatch {
+        message_id: b.msg_id.clone(),
+        payment_information_id: b.pmt_inf_id.clone(),
+        number_of_transactions: b.nb_of_txs.as_deref().and_then(|n| n.parse().ok()),
+        total_amount: b.ttl_amt.as_ref().map(|a| a.value),
+    });
+    let transactions = details
+        .flat_map(|d| d.tx_dtls.iter().flatten())
+        .map(|tx| EntryTransaction {
+            references: tx.refs.as_ref().map(transaction_references).unwrap_or_default(),
+            amount: tx.amt.as_ref().map(|a| a.value),
+            currency: tx.amt.as_ref().map(|a| a.ccy.clone()),
+        })
+        .collect();
+    StatementEntry {
+        account,
+        entry_reference: entry.ntry_ref.clone(),
+        references: References { account_servicer_reference: entry.acct_svcr_ref.clone(), ..Default::default() },
+        amount: entry.amt.value,
+        currency: entry.amt.ccy.clone(),
+        credit: matches!(entry.cdt_dbt_ind, CreditDebitCode::CodeCRDT),
+        booking_date: choice_date(&entry.bookg_dt),
+        value_date: choice_date(&entry.val_dt),
+        batch,
+        transactions,
+    }
+}
+
+/// Booked entries of all notifications, in document order.
+pub fn notification_entries(document: &BankToCustomerDebitCreditNotificationV08) -> Vec<StatementEntry> {
+    document
+        .ntfctn
+        .iter()
+        .flat_map(|n| {
+            n.ntry
+                .iter()
+                .flatten()
+                .filter(|e| e.sts.cd.as_deref().is_none_or(|c| c == "BOOK"))
+                .map(|e| report_entry(e, account_id(&n.acct)))
+        })
+        .collect()
+}
+
+/// One debit instruction per transaction, settling on the interbank
+/// settlement date.
+pub fn pacs008_instructions(document: &FIToFICustomerCreditTransferV08) -> Vec<Instruction> {
+    document
+        .cdt_trf_tx_inf
+        .iter()
+        .map(|tx| Instruction {
+            references: References {
+                message_id: Some(document.grp_hdr.msg_id.clone()),
+                instruction_id: tx.pmt_id.instr_id.clone(),
+                end_to_end_id: Some(tx.pmt_id.end_to_end_id.clone()),
+                uetr: tx.pmt_id.uetr.clone(),
+                ..Default::default()
+            },
+            amount: tx.intr_bk_sttlm_amt.value,
+            currency: tx.intr_bk_sttlm_amt.ccy.clone(),
+            credit: false,
+            date: tx.intr_bk_sttlm_dt.clone().or_else(|| document.grp_hdr.intr_bk_sttlm_dt.clone()),
+        })
+        .collect()
+}
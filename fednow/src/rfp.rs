@@ -0,0 +1,590 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Request-for-payment workflow for FedNow (`open_payments_common::rfp`):
+// pain.013.001.07 requests, pain.014.001.07 status reports, camt.055.001.09
+// cancellation requests with their camt.029.001.09 resolutions, and the
+// pacs.008.001.08 that pays an accepted request. The payment is built from
+// the request itself so that its references always match.
+
+use crate::common::*;
+use crate::fedwire::{agent_routing_number, routing_agent, FEDNOW_CLEARING_SYSTEM};
+use crate::iso::camt_029_001_09::ResolutionOfInvestigationV09;
+use crate::iso::camt_055_001_09::CustomerPaymentCancellationRequestV09;
+use crate::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+use crate::iso::pain_013_001_07::CreditorPaymentActivationRequestV07;
+use crate::iso::pain_014_001_07::CreditorPaymentActivationRequestStatusReportV07;
+use open_payments_common::datetime::{date_part, now_iso_datetime, today_iso_date};
+use open_payments_common::nacha::is_routing_number;
+use open_payments_common::rfp::{
+    cents, new_uetr, PaymentReferences, RequestForPayment, RfpStatus, RfpTracker,
+    ERR_RFP_AMOUNT, ERR_RFP_EXPIRED, ERR_RFP_LINKAGE, ERR_RFP_REQUEST, ERR_RFP_STATE, ERR_RFP_UNKNOWN,
+};
+use open_payments_common::ValidationError;
+
+pub const PAIN_013_MSG_DEF_IDR: &str = "pain.013.001.07";
+
+fn date_or_datetime(value: &str) -> DateAndDateTime2Choice {
+    if value.len() == 10 {
+        DateAndDateTime2Choice { dt: Some(value.to_string()), ..Default::default() }
+    } else {
+        DateAndDateTime2Choice { dt_tm: Some(value.to_string()), ..Default::default() }
+    }
+}
+
+fn choice_value(choice: &DateAndDateTime2Choice) -> Option<String> {
+    choice.dt.clone().or_else(|| choice.dt_tm.clone())
+}
+
+fn unknown(end_to_end_id: &str) -> ValidationError {
+    ValidationError::new(ERR_RFP_UNKNOWN, format!("no request for payment with EndToEndId {}", end_to_end_id))
+}
+
+/// Builds a pain.013 with a single request for payment in USD.
+#[derive(Default)]
+pub struct RfpBuilder {
+    msg_id: String,
+    cre_dt_tm: Option<String>,
+    pmt_inf_id: String,
+    end_to_end_id: String,
+    uetr: Option<String>,
+    amount: f64,
+    creditor: PartyIdentification135,
+    creditor_account: Option<CashAccount38>,
+    creditor_agent: String,
+    debtor: PartyIdentification135,
+    debtor_account: Option<CashAccount38>,
+    debtor_agent: String,
+    requested_execution_date: Option<String>,
+    expiry: Option<String>,
+    amount_modification_allowed: bool,
+    remittance: Vec<String>,
+}
+
+impl RfpBuilder {
+    pub fn new(msg_id: &str, pmt_inf_id: &str, end_to_end_id: &str) -> Self {
+        RfpBuilder {
+            msg_id: msg_id.to_string(),
+            pmt_inf_id: pmt_inf_id.to_string(),
+            end_to_end_id: end_to_end_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// `CreDtTm`, the current time by default.
+    pub fn creation_date_time(mut self, cre_dt_tm: &str) -> Self {
+        self.cre_dt_tm = Some(cre_dt_tm.to_string());
+        self
+    }
+
+    /// `PmtId/UETR`, generated when not given.
+    pub fn uetr(mut self, uetr: &str) -> Self {
+        self.uetr = Some(uetr.to_string());
+        self
+    }
+
+    pub fn amount(mut self, amount: f64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// The creditor, its account and the routing number of its agent.
+    pub fn creditor(mut self, party: PartyIdentification135, account: Option<CashAccount38>, routing_number: &str) -> Self {
+        self.creditor = party;
+        self.creditor_account = account;
+        self.creditor_agent = routing_number.to_string();
+        self
+    }
+
+    /// The debtor, its account and the routing number of its agent.
+    pub fn debtor(mut self, party: PartyIdentification135, account: Option<CashAccount38>, routing_number: &str) -> Self {
+        self.debtor = party;
+        self.debtor_account = account;
+        self.debtor_agent = routing_number.to_string();
+        self
+    }
+
+    /// `ReqdExctnDt`, ISODate or ISODateTime; today by default.
+    pub fn requested_execution_date(mut self, date: &str) -> Self {
+        self.requested_execution_date = Some(date.to_string());
+        self
+    }
+
+    /// `XpryDt`, ISODate or ISODateTime.
+    pub fn expiry(mut self, expiry: &str) -> Self {
+        self.expiry = Some(expiry.to_string());
+        self
+    }
+
+    /// `PmtCond/AmtModAllwd`.
+    pub fn amount_modification_allowed(mut self, allowed: bool) -> Self {
+        self.amount_modification_allowed = allowed;
+        self
+    }
+
+    /// Adds a line of unstructured remittance information.
+    pub fn remittance(mut self, line: &str) -> Self {
+        self.remittance.push(line.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<CreditorPaymentActivationRequestV07, ValidationError> {
+        for (role, routing) in [("creditor", &self.creditor_agent), ("debtor", &self.debtor_agent)] {
+            if !is_routing_number(routing) {
+                return Err(ValidationError::new(ERR_RFP_REQUEST, format!("{} agent {:?} is not a routing number", role, routing)));
+            }
+        }
+        if self.end_to_end_id.is_empty() || self.pmt_inf_id.is_empty() {
+            return Err(ValidationError::new(ERR_RFP_REQUEST, "a request for payment needs a PmtInfId and an EndToEndId".to_string()));
+        }
+        if cents(self.amount) <= 0 {
+            return Err(ValidationError::new(ERR_RFP_AMOUNT, format!("request for payment {} has no amount", self.end_to_end_id)));
+        }
+        let requested = self.requested_execution_date.unwrap_or_else(today_iso_date);
+        if let Some(expiry) = &self.expiry {
+            if date_part(expiry) < date_part(&requested) {
+                return Err(ValidationError::new(ERR_RFP_EXPIRED, format!(
+                    "request for payment {} expires on {} before its execution date {}", self.end_to_end_id, expiry, requested
+                )));
+            }
+        }
+        let condition = PaymentCondition1 {
+            amt_mod_allwd: self.amount_modification_allowed,
+            early_pmt_allwd: true,
+            ..Default::default()
+        };
+        let transaction = CreditTransferTransaction35 {
+            pmt_id: PaymentIdentification6 {
+                end_to_end_id: self.end_to_end_id,
+                uetr: Some(self.uetr.unwrap_or_else(new_uetr)),
+                ..Default::default()
+            },
+            amt: AmountType4Choice {
+                instd_amt: Some(ActiveOrHistoricCurrencyAndAmount { ccy: "USD".to_string(), value: self.amount }),
+                ..Default::default()
+            },
+            chrg_br: ChargeBearerType1Code::CodeSLEV,
+            cdtr_agt: routing_agent(&self.creditor_agent, None),
+            cdtr: self.creditor,
+            cdtr_acct: self.creditor_account,
+            rmt_inf: if self.remittance.is_empty() {
+                None
+            } else {
+                Some(RemittanceInformation16 { ustrd: Some(self.remittance), ..Default::default() })
+            },
+            ..Default::default()
+        };
+        let initiating_party = PartyIdentification135 { nm: transaction.cdtr.nm.clone(), ..Default::default() };
+        Ok(CreditorPaymentActivationRequestV07 {
+            grp_hdr: GroupHeader78 {
+                msg_id: self.msg_id,
+                cre_dt_tm: self.cre_dt_tm.unwrap_or_else(now_iso_datetime),
+                nb_of_txs: "1".to_string(),
+                ctrl_sum: Some(self.amount),
+                initg_pty: initiating_party,
+            },
+            pmt_inf: vec![PaymentInstruction31 {
+                pmt_inf_id: Some(self.pmt_inf_id),
+                pmt_mtd: PaymentMethod7Code::CodeTRF,
+                reqd_exctn_dt: date_or_datetime(&requested),
+                xpry_dt: self.expiry.as_deref().map(date_or_datetime),
+                pmt_cond: Some(condition),
+                dbtr: self.debtor,
+                dbtr_acct: self.debtor_account,
+                dbtr_agt: routing_agent(&self.debtor_agent, None),
+                cdt_trf_tx: vec![transaction],
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+    }
+}
+
+/// The requests for payment of a pain.013, one per transaction.
+pub fn request_records(request: &CreditorPaymentActivationRequestV07) -> Result<Vec<RequestForPayment>, ValidationError> {
+    let mut records = vec![];
+    for pmt_inf in &request.pmt_inf {
+        let pmt_inf_id = pmt_inf.pmt_inf_id.clone().ok_or_else(|| ValidationError::new(ERR_RFP_LINKAGE, format!(
+            "payment information of request {} has no PmtInfId to link the payment to", request.grp_hdr.msg_id
+        )))?;
+        for tx in &pmt_inf.cdt_trf_tx {
+            let amount = tx.amt.instd_amt.as_ref().ok_or_else(|| ValidationError::new(ERR_RFP_AMOUNT, format!(
+                "request for payment {} has no InstdAmt", tx.pmt_id.end_to_end_id
+            )))?;
+            let condition = tx.pmt_cond.as_ref().or(pmt_inf.pmt_cond.as_ref());
+            records.push(RequestForPayment {
+                msg_id: request.grp_hdr.msg_id.clone(),
+                cre_dt_tm: request.grp_hdr.cre_dt_tm.clone(),
+                pmt_inf_id: pmt_inf_id.clone(),
+                end_to_end_id: tx.pmt_id.end_to_end_id.clone(),
+                uetr: tx.pmt_id.uetr.clone(),
+                amount: amount.value,
+                currency: amount.ccy.clone(),
+                amount_modification_allowed: condition.is_some_and(|c| c.amt_mod_allwd),
+                requested_execution_date: choice_value(&pmt_inf.reqd_exctn_dt),
+                expiry: pmt_inf.xpry_dt.as_ref().and_then(choice_value),
+                creditor_agent: agent_routing_number(&tx.cdtr_agt).map(|r| r.to_string()),
+                debtor_agent: agent_routing_number(&pmt_inf.dbtr_agt).map(|r| r.to_string()),
+            });
+        }
+    }
+    Ok(records)
+}
+
+/// Starts tracking every request of a pain.013.
+pub fn track_request(tracker: &mut RfpTracker, request: &CreditorPaymentActivationRequestV07) -> Result<(), ValidationError> {
+    for record in request_records(request)? {
+        tracker.track(record)?;
+    }
+    Ok(())
+}
+
+/// The pain.014 answering a request with a `TxSts` code (ACTC, ACCP,
+/// RJCT, ...) and an optional reason code.
+pub fn status_report(
+    request: &RequestForPayment,
+    code: &str,
+    reason: Option<&str>,
+    msg_id: &str,
+    cre_dt_tm: &str,
+    initiating_party: PartyIdentification135,
+) -> Result<CreditorPaymentActivationRequestStatusReportV07, ValidationError> {
+    let status = RfpStatus::from_status_code(code).ok_or_else(|| ValidationError::new(ERR_RFP_STATE, format!(
+        "{} is not a status of a request for payment", code
+    )))?;
+    let transaction = PaymentTransaction104 {
+        orgnl_end_to_end_id: Some(request.end_to_end_id.clone()),
+        orgnl_uetr: request.uetr.clone(),
+        tx_sts: Some(code.to_string()),
+        sts_rsn_inf: reason.map(|r| vec![StatusReasonInformation12 {
+            rsn: Some(StatusReason6Choice { cd: Some(r.to_string()), ..Default::default() }),
+            ..Default::default()
+        }]),
+        accptnc_dt_tm: (status == RfpStatus::Accepted).then(|| cre_dt_tm.to_string()),
+        ..Default::default()
+    };
+    Ok(CreditorPaymentActivationRequestStatusReportV07 {
+        grp_hdr: GroupHeader87 {
+            msg_id: msg_id.to_string(),
+            cre_dt_tm: cre_dt_tm.to_string(),
+            initg_pty: initiating_party,
+            dbtr_agt: request.debtor_agent.as_deref().map(|r| routing_agent(r, None)),
+            cdtr_agt: request.creditor_agent.as_deref().map(|r| routing_agent(r, None)),
+        },
+        orgnl_grp_inf_and_sts: OriginalGroupInformation30 {
+            orgnl_msg_id: request.msg_id.clone(),
+            orgnl_msg_nm_id: PAIN_013_MSG_DEF_IDR.to_string(),
+            orgnl_cre_dt_tm: Some(request.cre_dt_tm.clone()),
+            ..Default::default()
+        },
+        orgnl_pmt_inf_and_sts: Some(vec![OriginalPaymentInstruction31 {
+            orgnl_pmt_inf_id: request.pmt_inf_id.clone(),
+            tx_inf_and_sts: Some(vec![transaction]),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    })
+}
+
+fn status_reason(reasons: &Option<Vec<StatusReasonInformation12>>) -> Option<&str> {
+    reasons.as_ref()?.iter()
+        .filter_map(|r| r.rsn.as_ref())
+        .find_map(|r| r.cd.as_deref().or(r.prtry.as_deref()))
+}
+
+/// Applies a pain.014 to the tracked requests. Transactions without a
+/// status take the status of their payment information, and payment
+/// information without transactions applies to every request it carries.
+pub fn apply_status_report(tracker: &mut RfpTracker, report: &CreditorPaymentActivationRequestStatusReportV07) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    let group = &report.orgnl_grp_inf_and_sts;
+    let tracked_ids = |pmt_inf_id: Option<&str>| -> Vec<String> {
+        tracker.requests().iter()
+            .filter(|t| t.request.msg_id == group.orgnl_msg_id && pmt_inf_id.is_none_or(|id| t.request.pmt_inf_id == id))
+            .map(|t| t.request.end_to_end_id.clone())
+            .collect()
+    };
+    let mut updates: Vec<(String, &str, Option<&str>)> = vec![];
+    match &report.orgnl_pmt_inf_and_sts {
+        None => if let Some(code) = &group.grp_sts {
+            let reason = status_reason(&group.sts_rsn_inf);
+            updates.extend(tracked_ids(None).into_iter().map(|id| (id, code.as_str(), reason)));
+        },
+        Some(payments) => for payment in payments {
+            let code = payment.pmt_inf_sts.as_ref().or(group.grp_sts.as_ref());
+            let reason = status_reason(&payment.sts_rsn_inf).or(status_reason(&group.sts_rsn_inf));
+            match &payment.tx_inf_and_sts {
+                Some(transactions) => for tx in transactions {
+                    let Some(code) = tx.tx_sts.as_ref().or(code) else { continue };
+                    match &tx.orgnl_end_to_end_id {
+                        Some(id) => updates.push((id.clone(), code, status_reason(&tx.sts_rsn_inf).or(reason))),
+                        None => errors.push(ValidationError::new(ERR_RFP_UNKNOWN, format!(
+                            "status report {} has a transaction without OrgnlEndToEndId", report.grp_hdr.msg_id
+                        ))),
+                    }
+                },
+                None => if let Some(code) = code {
+                    updates.extend(tracked_ids(Some(&payment.orgnl_pmt_inf_id)).into_iter().map(|id| (id, code.as_str(), reason)));
+                },
+            }
+        },
+    }
+    for (id, code, reason) in updates {
+        if let Err(e) = tracker.apply_status(&id, code, reason) {
+            errors.push(e);
+        }
+    }
+    errors
+}
+
+/// The pacs.008 paying the request with the given `EndToEndId`, which the
+/// tracker must hold as accepted and unexpired at `cre_dt_tm`. The request
+/// is consumed: parties, accounts, purpose and remittance information move
+/// into the payment. `EndToEndId`, `InstrId` (the `PmtInfId`) and `UETR`
+/// are taken from the request; a UETR is generated when the request has
+/// none. `amount` overrides the requested amount when the request allows
+/// modification.
+pub fn payment_for_request(
+    tracker: &RfpTracker,
+    request: CreditorPaymentActivationRequestV07,
+    end_to_end_id: &str,
+    msg_id: &str,
+    cre_dt_tm: &str,
+    amount: Option<f64>,
+) -> Result<FIToFICustomerCreditTransferV08, ValidationError> {
+    let tracked = tracker.get(end_to_end_id).ok_or_else(|| unknown(end_to_end_id))?;
+    if tracked.status != RfpStatus::Accepted {
+        return Err(ValidationError::new(ERR_RFP_STATE, format!(
+            "request for payment {} cannot be paid, it is {:?}", end_to_end_id, tracked.status
+        )));
+    }
+    if tracked.request.is_expired(cre_dt_tm) {
+        return Err(ValidationError::new(ERR_RFP_EXPIRED, format!(
+            "request for payment {} expired at {}", end_to_end_id, tracked.request.expiry.as_deref().unwrap_or_default()
+        )));
+    }
+    for pmt_inf in request.pmt_inf {
+        let PaymentInstruction31 { pmt_inf_id, pmt_cond, dbtr, dbtr_acct, dbtr_agt, ultmt_dbtr, cdt_trf_tx, .. } = pmt_inf;
+        let Some(tx) = cdt_trf_tx.into_iter().find(|tx| tx.pmt_id.end_to_end_id == end_to_end_id) else {
+            continue;
+        };
+        let pmt_inf_id = pmt_inf_id.ok_or_else(|| ValidationError::new(ERR_RFP_LINKAGE, format!(
+            "request for payment {} has no PmtInfId to link the payment to", end_to_end_id
+        )))?;
+        let requested = tx.amt.instd_amt.ok_or_else(|| ValidationError::new(ERR_RFP_AMOUNT, format!(
+            "request for payment {} has no InstdAmt", end_to_end_id
+        )))?;
+        let modification_allowed = tx.pmt_cond.as_ref().or(pmt_cond.as_ref()).is_some_and(|c| c.amt_mod_allwd);
+        let value = match amount {
+            Some(value) if !modification_allowed && cents(value) != cents(requested.value) => {
+                return Err(ValidationError::new(ERR_RFP_AMOUNT, format!(
+                    "request for payment {} does not allow paying {:.2} instead of {:.2}", end_to_end_id, value, requested.value
+                )));
+            }
+            Some(value) if cents(value) <= 0 => {
+                return Err(ValidationError::new(ERR_RFP_AMOUNT, format!("no amount to pay request for payment {}", end_to_end_id)));
+            }
+            Some(value) => value,
+            None => requested.value,
+        };
+        let instructing = agent_routing_number(&dbtr_agt).map(|r| routing_agent(r, None));
+        let instructed = agent_routing_number(&tx.cdtr_agt).map(|r| routing_agent(r, None));
+        let transaction = CreditTransferTransaction39 {
+            pmt_id: PaymentIdentification7 {
+                instr_id: Some(pmt_inf_id),
+                end_to_end_id: tx.pmt_id.end_to_end_id,
+                uetr: Some(tx.pmt_id.uetr.unwrap_or_else(new_uetr)),
+                ..Default::default()
+            },
+            intr_bk_sttlm_amt: ActiveCurrencyAndAmount { ccy: requested.ccy, value },
+            intr_bk_sttlm_dt: Some(date_part(cre_dt_tm).to_string()),
+            chrg_br: ChargeBearerType1Code::CodeSLEV,
+            instg_agt: instructing,
+            instd_agt: instructed,
+            ultmt_dbtr,
+            dbtr,
+            dbtr_acct,
+            dbtr_agt,
+            cdtr_agt: tx.cdtr_agt,
+            cdtr: tx.cdtr,
+            cdtr_acct: tx.cdtr_acct,
+            ultmt_cdtr: tx.ultmt_cdtr,
+            purp: tx.purp,
+            rltd_rmt_inf: tx.rltd_rmt_inf,
+            rmt_inf: tx.rmt_inf,
+            ..Default::default()
+        };
+        return Ok(FIToFICustomerCreditTransferV08 {
+            grp_hdr: GroupHeader93 {
+                msg_id: msg_id.to_string(),
+                cre_dt_tm: cre_dt_tm.to_string(),
+                nb_of_txs: "1".to_string(),
+                sttlm_inf: SettlementInstruction7 {
+                    sttlm_mtd: SettlementMethod1Code::CodeCLRG,
+                    clr_sys: Some(ClearingSystemIdentification3Choice { cd: Some(FEDNOW_CLEARING_SYSTEM.to_string()), ..Default::default() }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            cdt_trf_tx_inf: vec![transaction],
+            ..Default::default()
+        });
+    }
+    Err(unknown(end_to_end_id))
+}
+
+/// References and amounts of the transactions of a pacs.008.
+pub fn payment_references(payment: &FIToFICustomerCreditTransferV08) -> Vec<PaymentReferences> {
+    payment.cdt_trf_tx_inf.iter().map(|tx| PaymentReferences {
+        msg_id: payment.grp_hdr.msg_id.clone(),
+        instr_id: tx.pmt_id.instr_id.clone(),
+        end_to_end_id: tx.pmt_id.end_to_end_id.clone(),
+        uetr: tx.pmt_id.uetr.clone(),
+        amount: tx.intr_bk_sttlm_amt.value,
+        currency: tx.intr_bk_sttlm_amt.ccy.clone(),
+    }).collect()
+}
+
+/// Checks a pacs.008 against the tracked requests it pays: each
+/// transaction must match an open, unexpired request at `now`.
+pub fn check_payment_linkage(tracker: &RfpTracker, payment: &FIToFICustomerCreditTransferV08, now: &str) -> Vec<ValidationError> {
+    payment_references(payment).iter()
+        .filter_map(|references| tracker.check_payment(references, now).err())
+        .flatten()
+        .collect()
+}
+
+/// Pays a tracked request: builds the pacs.008 with `payment_for_request`
+/// and marks the request paid.
+pub fn pay_request(
+    tracker: &mut RfpTracker,
+    request: CreditorPaymentActivationRequestV07,
+    end_to_end_id: &str,
+    msg_id: &str,
+    cre_dt_tm: &str,
+    amount: Option<f64>,
+) -> Result<FIToFICustomerCreditTransferV08, Vec<ValidationError>> {
+    let payment = payment_for_request(tracker, request, end_to_end_id, msg_id, cre_dt_tm, amount).map_err(|e| vec![e])?;
+    for references in payment_references(&payment) {
+        tracker.mark_paid(&references, cre_dt_tm)?;
+    }
+    Ok(payment)
+}
+
+/// The camt.055 withdrawing a request, sent by the creditor agent to the
+/// debtor agent, with a cancellation reason code.
+pub fn cancellation_request(
+    request: &RequestForPayment,
+    case_id: &str,
+    reason: &str,
+    cre_dt_tm: &str,
+) -> Result<CustomerPaymentCancellationRequestV09, ValidationError> {
+    let (Some(creditor_agent), Some(debtor_agent)) = (&request.creditor_agent, &request.debtor_agent) else {
+        return Err(ValidationError::new(ERR_RFP_REQUEST, format!(
+            "request for payment {} does not identify both agents", request.end_to_end_id
+        )));
+    };
+    let transaction = PaymentTransaction124 {
+        orgnl_end_to_end_id: Some(request.end_to_end_id.clone()),
+        orgnl_uetr: request.uetr.clone(),
+        orgnl_instd_amt: Some(ActiveOrHistoricCurrencyAndAmount { ccy: request.currency.clone(), value: request.amount }),
+        orgnl_reqd_exctn_dt: request.requested_execution_date.as_deref().map(date_or_datetime),
+        cxl_rsn_inf: Some(vec![PaymentCancellationReason5 {
+            rsn: Some(CancellationReason33Choice { cd: Some(reason.to_string()), ..Default::default() }),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    Ok(CustomerPaymentCancellationRequestV09 {
+        assgnmt: CaseAssignment5 {
+            id: case_id.to_string(),
+            assgnr: Party40Choice { agt: Some(routing_agent(creditor_agent, None)), ..Default::default() },
+            assgne: Party40Choice { agt: Some(routing_agent(debtor_agent, None)), ..Default::default() },
+            cre_dt_tm: cre_dt_tm.to_string(),
+        },
+        case: Some(Case5 {
+            id: case_id.to_string(),
+            cretr: Party40Choice { agt: Some(routing_agent(creditor_agent, None)), ..Default::default() },
+            ..Default::default()
+        }),
+        undrlyg: vec![UnderlyingTransaction27 {
+            orgnl_pmt_inf_and_cxl: Some(vec![OriginalPaymentInstruction36 {
+                orgnl_pmt_inf_id: request.pmt_inf_id.clone(),
+                orgnl_grp_inf: Some(OriginalGroupInformation29 {
+                    orgnl_msg_id: request.msg_id.clone(),
+                    orgnl_msg_nm_id: PAIN_013_MSG_DEF_IDR.to_string(),
+                    orgnl_cre_dt_tm: Some(request.cre_dt_tm.clone()),
+                }),
+                tx_inf: Some(vec![transaction]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }],
+        ..Default::default()
+    })
+}
+
+/// Sends a camt.055 for a tracked request and records the cancellation.
+pub fn cancel_request(
+    tracker: &mut RfpTracker,
+    end_to_end_id: &str,
+    case_id: &str,
+    reason: &str,
+    cre_dt_tm: &str,
+) -> Result<CustomerPaymentCancellationRequestV09, ValidationError> {
+    let tracked = tracker.get(end_to_end_id).ok_or_else(|| unknown(end_to_end_id))?;
+    let message = cancellation_request(&tracked.request, case_id, reason, cre_dt_tm)?;
+    tracker.request_cancellation(end_to_end_id, Some(reason))?;
+    Ok(message)
+}
+
+fn cancellation_code(code: &CancellationIndividualStatus1Code) -> &'static str {
+    match code {
+        CancellationIndividualStatus1Code::CodeRJCR => "RJCR",
+        CancellationIndividualStatus1Code::CodeACCR => "ACCR",
+        CancellationIndividualStatus1Code::CodePDCR => "PDCR",
+    }
+}
+
+/// Applies the cancellation statuses of a camt.029 to the tracked
+/// requests. Transactions without `TxCxlSts` take the `Sts/Conf` code of
+/// the resolution.
+pub fn apply_resolution(tracker: &mut RfpTracker, resolution: &ResolutionOfInvestigationV09) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    for details in resolution.cxl_dtls.iter().flatten() {
+        let transactions = details.tx_inf_and_sts.iter().flatten()
+            .map(|tx| (tx.orgnl_end_to_end_id.as_deref(), tx.tx_cxl_sts.as_ref()))
+            .chain(details.orgnl_pmt_inf_and_sts.iter().flatten()
+                .flat_map(|p| p.tx_inf_and_sts.iter().flatten())
+                .map(|tx| (tx.orgnl_end_to_end_id.as_deref(), tx.tx_cxl_sts.as_ref())));
+        for (end_to_end_id, status) in transactions {
+            let Some(end_to_end_id) = end_to_end_id else { continue };
+            let Some(code) = status.map(cancellation_code).or(resolution.sts.conf.as_deref()) else { continue };
+            if let Err(e) = tracker.apply_cancellation_status(end_to_end_id, code) {
+                errors.push(e);
+            }
+        }
+    }
+    errors
+}
@@ -0,0 +1,126 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+// Balance, summary and sequence checks (`open_payments_common::statement_check`)
+// for camt.052.001.08 and camt.054.001.08.
+
+use crate::common::*;
+use crate::iso::camt_052_001_08::BankToCustomerAccountReportV08;
+use crate::iso::camt_054_001_08::BankToCustomerDebitCreditNotificationV08;
+use open_payments_common::statement_check::{check_statements, CheckedStatement, DeclaredTotals, CLOSING_BOOKED, OPENING_BOOKED};
+use open_payments_common::ValidationError;
+
+fn is_credit(code: &CreditDebitCode) -> bool {
+    matches!(code, CreditDebitCode::CodeCRDT)
+}
+
+fn signed(amount: f64, code: &CreditDebitCode) -> f64 {
+    if is_credit(code) { amount } else { -amount }
+}
+
+/// First balance of the given types, in the order of the types.
+fn booked_balance(balances: &[CashBalance8], codes: &[&str]) -> Option<f64> {
+    codes
+        .iter()
+        .find_map(|code| balances.iter().find(|b| b.tp.cd_or_prtry.cd.as_deref() == Some(*code)))
+        .map(|b| signed(b.amt.value, &b.cdt_dbt_ind))
+}
+
+fn page(pagination: &Option<Pagination1>) -> Option<(u64, bool)> {
+    pagination.as_ref().and_then(|p| p.pg_nb.parse().ok().map(|n| (n, p.last_pg_ind)))
+}
+
+fn account_id(account: &CashAccount39) -> Option<String> {
+    account.id.iban.clone().or_else(|| account.id.othr.as_ref().map(|o| o.id.clone()))
+}
+
+fn number(value: &Option<String>) -> Option<u64> {
+    value.as_deref().and_then(|n| n.parse().ok())
+}
+
+/// Fills the entries, summary and currency of a checked statement. Only
+/// booked entries (`Sts/Cd` BOOK) count.
+fn add_entries(checked: &mut CheckedStatement, account: &CashAccount39, entries: &Option<Vec<ReportEntry10>>, summary: &Option<TotalTransactions6>) {
+    for entry in entries.iter().flatten().filter(|e| e.sts.cd.as_deref().is_none_or(|c| c == "BOOK")) {
+        if checked.currency.is_empty() {
+            checked.currency = entry.amt.ccy.clone();
+        }
+        if is_credit(&entry.cdt_dbt_ind) { checked.credits.push(entry.amt.value) } else { checked.debits.push(entry.amt.value) }
+    }
+    if let Some(ref currency) = account.ccy {
+        checked.currency = currency.clone();
+    }
+    if let Some(ref summary) = summary {
+        checked.total_entries = summary.ttl_ntries.as_ref().map(|t| DeclaredTotals {
+            number_of_entries: number(&t.nb_of_ntries),
+            sum: t.sum,
+            net_amount: t.ttl_net_ntry.as_ref().map(|n| signed(n.amt, &n.cdt_dbt_ind)),
+        });
+        checked.total_credit_entries = summary.ttl_cdt_ntries.as_ref().map(|t| DeclaredTotals { number_of_entries: number(&t.nb_of_ntries), sum: t.sum, net_amount: None });
+        checked.total_debit_entries = summary.ttl_dbt_ntries.as_ref().map(|t| DeclaredTotals { number_of_entries: number(&t.nb_of_ntries), sum: t.sum, net_amount: None });
+    }
+}
+
+pub fn report(rpt: &AccountReport25) -> CheckedStatement {
+    let balances = rpt.bal.as_deref().unwrap_or_default();
+    let mut checked = CheckedStatement {
+        id: rpt.id.clone(),
+        account: account_id(&rpt.acct),
+        electronic_sequence_number: rpt.elctrnc_seq_nb,
+        legal_sequence_number: rpt.lgl_seq_nb,
+        page: page(&rpt.rpt_pgntn),
+        opening_booked: booked_balance(balances, OPENING_BOOKED),
+        closing_booked: booked_balance(balances, &[CLOSING_BOOKED]),
+        ..Default::default()
+    };
+    add_entries(&mut checked, &rpt.acct, &rpt.ntry, &rpt.txs_summry);
+    if let Some(balance) = balances.first().filter(|_| checked.currency.is_empty()) {
+        checked.currency = balance.amt.ccy.clone();
+    }
+    checked
+}
+
+pub fn notification(ntfctn: &AccountNotification17) -> CheckedStatement {
+    let mut checked = CheckedStatement {
+        id: ntfctn.id.clone(),
+        account: account_id(&ntfctn.acct),
+        electronic_sequence_number: ntfctn.elctrnc_seq_nb,
+        legal_sequence_number: ntfctn.lgl_seq_nb,
+        page: page(&ntfctn.ntfctn_pgntn),
+        ..Default::default()
+    };
+    add_entries(&mut checked, &ntfctn.acct, &ntfctn.ntry, &ntfctn.txs_summry);
+    checked
+}
+
+/// Checks the reports of consecutive camt.052 messages, in the order given.
+pub fn check_camt052(documents: &[BankToCustomerAccountReportV08]) -> Vec<ValidationError> {
+    let reports: Vec<CheckedStatement> = documents.iter().flat_map(|d| d.rpt.iter().map(report)).collect();
+    check_statements(&reports)
+}
+
+/// Checks the notifications of consecutive camt.054 messages, in the order given.
+pub fn check_camt054(documents: &[BankToCustomerDebitCreditNotificationV08]) -> Vec<ValidationError> {
+    let notifications: Vec<CheckedStatement> = documents.iter().flat_map(|d| d.ntfctn.iter().map(notification)).collect();
+    check_statements(&notifications)
+}
@@ -0,0 +1,216 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Translation between SWIFT FIN (MT) messages and the FedNow messages of this
+// crate. The FIN parser itself lives in `open_payments_common::swift` and the
+// party mapping in `open_payments_common::swift::mapping`; this module copies
+// the mapped parties into the generated types and builds the FedNow header,
+// in which participants are identified by routing number rather than BIC.
+
+pub mod mt202;
+
+use crate::common::*;
+use open_payments_common::swift::fields::PartyField;
+use open_payments_common::swift::mapping::{self, AccountId, Address, ClearingMember, Customer, Institution};
+use open_payments_common::swift::FinMessage;
+
+pub use open_payments_common::swift::mapping::{clearing_system_code, clearing_system_prefix, creation_date_time, mt_reference, text_lines, ERR_UNMAPPABLE};
+
+/// Financial institution identified by BIC.
+pub fn bic_agent(bic: &str) -> BranchAndFinancialInstitutionIdentification6 {
+    agent(&Institution::with_bic(bic))
+}
+
+/// FedNow participant identified by routing number (`ClrSysMmbId`, `USABA`).
+pub fn routing_number_agent(routing_number: &str) -> BranchAndFinancialInstitutionIdentification6 {
+    agent(&Institution::with_routing_number(routing_number))
+}
+
+fn account(account: AccountId) -> CashAccount38 {
+    let id = match account {
+        AccountId::Iban(iban) => AccountIdentification4Choice { iban: Some(iban), ..Default::default() },
+        AccountId::Other(id) => AccountIdentification4Choice { othr: Some(GenericAccountIdentification1 { id, ..Default::default() }), ..Default::default() },
+    };
+    CashAccount38 { id, ..Default::default() }
+}
+
+/// Account from an MT party identifier, see `AccountId::parse`.
+pub fn cash_account(account_id: &str) -> CashAccount38 {
+    account(AccountId::parse(account_id))
+}
+
+fn postal_address(address: &Address) -> PostalAddress24 {
+    PostalAddress24 {
+        adr_line: if address.lines.is_empty() { None } else { Some(address.lines.clone()) },
+        strt_nm: address.street_name.clone(),
+        bldg_nb: address.building_number.clone(),
+        pst_cd: address.post_code.clone(),
+        twn_nm: address.town_name.clone(),
+        ctry: address.country.clone(),
+        ..Default::default()
+    }
+}
+
+fn address(address: &PostalAddress24) -> Address {
+    Address {
+        lines: address.adr_line.clone().unwrap_or_default(),
+        street_name: address.strt_nm.clone(),
+        building_number: address.bldg_nb.clone(),
+        post_code: address.pst_cd.clone(),
+        town_name: address.twn_nm.clone(),
+        country: address.ctry.clone(),
+    }
+}
+
+/// Agent for a version neutral institution.
+pub fn agent(institution: &Institution) -> BranchAndFinancialInstitutionIdentification6 {
+    BranchAndFinancialInstitutionIdentification6 {
+        fin_instn_id: FinancialInstitutionIdentification18 {
+            bicfi: institution.bic.clone(),
+            clr_sys_mmb_id: institution.clearing_member.as_ref().map(|m| ClearingSystemMemberIdentification2 {
+                clr_sys_id: m.system.as_ref().map(|cd| ClearingSystemIdentification2Choice { cd: Some(cd.clone()), ..Default::default() }),
+                mmb_id: m.member_id.clone(),
+            }),
+            lei: institution.lei.clone(),
+            nm: institution.name.clone(),
+            pstl_adr: institution.address.as_ref().map(postal_address),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Version neutral institution of an agent.
+pub fn institution(agent: &BranchAndFinancialInstitutionIdentification6) -> Institution {
+    let id = &agent.fin_instn_id;
+    Institution {
+        bic: id.bicfi.clone(),
+        clearing_member: id.clr_sys_mmb_id.as_ref().map(|m| ClearingMember {
+            system: m.clr_sys_id.as_ref().and_then(|s| s.cd.clone()),
+            member_id: m.mmb_id.clone(),
+        }),
+        lei: id.lei.clone(),
+        name: id.nm.clone(),
+        address: id.pstl_adr.as_ref().map(address),
+    }
+}
+
+/// Institution fields (52a to 58a), see `mapping::party_to_institution`.
+pub fn party_to_agent(party: &PartyField) -> BranchAndFinancialInstitutionIdentification6 {
+    agent(&mapping::party_to_institution(party))
+}
+
+/// Account of an institution field, see `mapping::party_to_institution_account`.
+pub fn party_to_agent_account(party: &PartyField) -> Option<CashAccount38> {
+    mapping::party_to_institution_account(party).map(account)
+}
+
+/// Customer fields (50a, 59a), see `mapping::party_to_customer`.
+pub fn party_to_customer(party: &PartyField) -> PartyIdentification135 {
+    let customer = mapping::party_to_customer(party);
+    let org_id = match (customer.any_bic, customer.other_id) {
+        (Some(bic), _) => Some(OrganisationIdentification29 { any_bic: Some(bic), ..Default::default() }),
+        (None, Some(other)) => Some(OrganisationIdentification29 {
+            othr: Some(vec![GenericOrganisationIdentification1 {
+                id: other.id,
+                schme_nm: other.scheme_code.map(|cd| OrganisationIdentificationSchemeName1Choice { cd: Some(cd), ..Default::default() }),
+                issr: other.issuer,
+            }]),
+            ..Default::default()
+        }),
+        (None, None) => None,
+    };
+    PartyIdentification135 {
+        nm: customer.name,
+        pstl_adr: customer.address.as_ref().map(postal_address),
+        id: org_id.map(|org_id| Party38Choice { org_id: Some(org_id), ..Default::default() }),
+        ..Default::default()
+    }
+}
+
+/// Account of a customer field, see `mapping::party_to_customer_account`.
+pub fn party_to_customer_account(party: &PartyField) -> Option<CashAccount38> {
+    mapping::party_to_customer_account(party).map(account)
+}
+
+/// Business application header (head.001.001.02) for a message translated
+/// from FIN. FedNow addresses participants by routing number, so sender and
+/// receiver are the routing numbers of the FIN sender and receiver, given by
+/// the caller, in `ClrSysMmbId/MmbId`. The MT reference is the business
+/// message identifier and the PDE/PDM trailers set the possible duplicate flag.
+pub fn business_application_header(
+    message: &FinMessage,
+    sender_routing_number: &str,
+    receiver_routing_number: &str,
+    reference: &str,
+    msg_def_idr: &str,
+    cre_dt: &str,
+) -> BusinessApplicationHeaderV02 {
+    let party = |routing_number: &str| Party44Choice {
+        fi_id: Some(BranchAndFinancialInstitutionIdentification6 {
+            fin_instn_id: FinancialInstitutionIdentification18 {
+                clr_sys_mmb_id: Some(ClearingSystemMemberIdentification2 { mmb_id: routing_number.to_string(), ..Default::default() }),
+                ..Default::default()
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    BusinessApplicationHeaderV02 {
+        fr: party(sender_routing_number),
+        to: party(receiver_routing_number),
+        biz_msg_idr: reference.to_string(),
+        msg_def_idr: msg_def_idr.to_string(),
+        cre_dt: cre_dt.to_string(),
+        pssbl_dplct: if mapping::possible_duplicate(message) { Some(true) } else { None },
+        ..Default::default()
+    }
+}
+
+/// Account identification as written in an MT party identifier line.
+pub fn account_identifier(account: &CashAccount38) -> Option<String> {
+    let id = &account.id;
+    id.iban.clone().or_else(|| id.othr.as_ref().map(|o| o.id.clone()))
+}
+
+/// BIC of an agent, if identified by BIC.
+pub fn agent_bic(agent: &BranchAndFinancialInstitutionIdentification6) -> Option<&str> {
+    agent.fin_instn_id.bicfi.as_deref()
+}
+
+/// Institution field for an agent, see `mapping::institution_to_party`.
+pub fn agent_to_party(agent: &BranchAndFinancialInstitutionIdentification6, account: Option<&CashAccount38>) -> PartyField {
+    mapping::institution_to_party(&institution(agent), account.and_then(account_identifier).as_deref())
+}
+
+/// Customer field for a party, see `mapping::customer_to_party`.
+pub fn customer_to_party(party: &PartyIdentification135, account: Option<&CashAccount38>, letterless_option: Option<char>) -> PartyField {
+    let customer = Customer {
+        name: party.nm.clone(),
+        any_bic: party.id.as_ref().and_then(|id| id.org_id.as_ref()).and_then(|org| org.any_bic.clone()),
+        address: party.pstl_adr.as_ref().map(address),
+        ..Default::default()
+    };
+    mapping::customer_to_party(&customer, account.and_then(account_identifier).as_deref(), letterless_option)
+}
@@ -0,0 +1,233 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// MT202 / MT202 COV <-> pacs.009.001.08 (FinancialInstitutionCreditTransferV08).
+//
+// FedNow settles every transfer through the FedNow Service, so the settlement
+// method is always CLRG and the MT correspondent fields (53a, 54a) have no
+// counterpart in the FedNow message.
+
+use crate::common::*;
+use crate::iso::pacs_009_001_08::FinancialInstitutionCreditTransferV08;
+use open_payments_common::swift::fields::{CurrencyAmount, DateCurrencyAmount};
+use open_payments_common::swift::mt202::{Mt202, UnderlyingCustomerCreditTransfer};
+use open_payments_common::swift::mapping::{self, Cover};
+use open_payments_common::swift::FinMessage;
+use open_payments_common::ValidationError;
+
+use crate::swift::*;
+
+pub const PACS_009_MSG_DEF_IDR: &str = "pacs.009.001.08";
+
+pub use crate::fedwire::FEDNOW_CLEARING_SYSTEM;
+
+pub use open_payments_common::swift::mapping::ERR_COVER_MISMATCH;
+
+fn next_agent_instructions(lines: &[String]) -> Option<Vec<InstructionForNextAgent1>> {
+    if lines.is_empty() {
+        return None;
+    }
+    Some(lines.iter().map(|l| InstructionForNextAgent1 { instr_inf: Some(l.clone()), ..Default::default() }).collect())
+}
+
+fn instruction_lines(instructions: &Option<Vec<InstructionForNextAgent1>>) -> Vec<String> {
+    mapping::instruction_lines(instructions.iter().flatten().filter_map(|i| i.instr_inf.as_deref()))
+}
+
+/// Parses MT202/MT202 COV FIN text and translates it, see `mt202_to_pacs009`.
+pub fn fin_to_pacs009(
+    input: &str,
+    sender_routing_number: &str,
+    receiver_routing_number: &str,
+) -> Result<(BusinessApplicationHeaderV02, FinancialInstitutionCreditTransferV08), ValidationError> {
+    mt202_to_pacs009(&FinMessage::parse(input)?, sender_routing_number, receiver_routing_number)
+}
+
+/// Translates an MT202 into a FedNow pacs.009 (core) and an MT202 COV into a
+/// pacs.009 COV, with sequence B mapped to `UndrlygCstmrCdtTrf`. The
+/// resulting cover is checked with `check_cover`. FedNow participants are
+/// identified by routing number: the routing numbers of the FIN sender and
+/// receiver identify the instructing and instructed agents and the header.
+pub fn mt202_to_pacs009(
+    message: &FinMessage,
+    sender_routing_number: &str,
+    receiver_routing_number: &str,
+) -> Result<(BusinessApplicationHeaderV02, FinancialInstitutionCreditTransferV08), ValidationError> {
+    let mt = Mt202::from_fin(message)?;
+    let cre_dt_tm = creation_date_time(message);
+
+    let mut tx = CreditTransferTransaction36 {
+        pmt_id: PaymentIdentification7 {
+            instr_id: Some(mt.transaction_reference.clone()),
+            end_to_end_id: mt.related_reference.clone(),
+            tx_id: Some(mt.transaction_reference.clone()),
+            uetr: message.uetr().map(|u| u.to_string()),
+            ..Default::default()
+        },
+        intr_bk_sttlm_amt: ActiveCurrencyAndAmount { ccy: mt.value_date_amount.currency.clone(), value: mt.value_date_amount.amount },
+        intr_bk_sttlm_dt: Some(mt.value_date_amount.date.clone()),
+        instg_agt: Some(routing_number_agent(sender_routing_number)),
+        instd_agt: Some(routing_number_agent(receiver_routing_number)),
+        dbtr: mt.ordering_institution.as_ref().map(party_to_agent).unwrap_or_else(|| routing_number_agent(sender_routing_number)),
+        dbtr_acct: mt.ordering_institution.as_ref().and_then(party_to_agent_account),
+        cdtr_agt: mt.account_with_institution.as_ref().map(party_to_agent),
+        cdtr_agt_acct: mt.account_with_institution.as_ref().and_then(party_to_agent_account),
+        cdtr: party_to_agent(&mt.beneficiary_institution),
+        cdtr_acct: party_to_agent_account(&mt.beneficiary_institution),
+        instr_for_nxt_agt: next_agent_instructions(&mt.sender_to_receiver_information),
+        ..Default::default()
+    };
+    if let Some(ref intermediary) = mt.intermediary {
+        tx.intrmy_agt1 = Some(party_to_agent(intermediary));
+        tx.intrmy_agt1_acct = party_to_agent_account(intermediary);
+    }
+    if let Some(ref underlying) = mt.underlying {
+        tx.undrlyg_cstmr_cdt_trf = Some(underlying_to_iso(underlying, &tx));
+    }
+
+    if mt.senders_correspondent.is_some() || mt.receivers_correspondent.is_some() {
+        return Err(ValidationError::new(ERR_UNMAPPABLE, "fields 53a and 54a cannot be settled through the FedNow Service".to_string()));
+    }
+    let settlement = SettlementInstruction7 {
+        sttlm_mtd: SettlementMethod1Code::CodeCLRG,
+        clr_sys: Some(ClearingSystemIdentification3Choice { cd: Some(FEDNOW_CLEARING_SYSTEM.to_string()), ..Default::default() }),
+        ..Default::default()
+    };
+
+    check_cover(&tx)?;
+    let document = FinancialInstitutionCreditTransferV08 {
+        grp_hdr: GroupHeader93 {
+            msg_id: mt.transaction_reference.clone(),
+            cre_dt_tm: cre_dt_tm.clone(),
+            nb_of_txs: "1".to_string(),
+            sttlm_inf: settlement,
+            ..Default::default()
+        },
+        cdt_trf_tx_inf: vec![tx],
+        splmtry_data: None,
+    };
+    let header = business_application_header(message, sender_routing_number, receiver_routing_number, &mt.transaction_reference, PACS_009_MSG_DEF_IDR, &cre_dt_tm);
+    Ok((header, document))
+}
+
+fn underlying_to_iso(underlying: &UnderlyingCustomerCreditTransfer, cover: &CreditTransferTransaction36) -> CreditTransferTransaction37 {
+    CreditTransferTransaction37 {
+        dbtr: party_to_customer(&underlying.ordering_customer),
+        dbtr_acct: party_to_customer_account(&underlying.ordering_customer),
+        // Without 52a in sequence B the cover debtor ordered the customer transfer.
+        dbtr_agt: underlying.ordering_institution.as_ref().map(party_to_agent).unwrap_or_else(|| agent(&institution(&cover.dbtr))),
+        dbtr_agt_acct: underlying.ordering_institution.as_ref().and_then(party_to_agent_account),
+        intrmy_agt1: underlying.intermediary_institution.as_ref().map(party_to_agent),
+        intrmy_agt1_acct: underlying.intermediary_institution.as_ref().and_then(party_to_agent_account),
+        // Without 57a in sequence B the beneficiary institution of the cover services the creditor.
+        cdtr_agt: underlying.account_with_institution.as_ref().map(party_to_agent).unwrap_or_else(|| agent(&institution(&cover.cdtr))),
+        cdtr_agt_acct: underlying.account_with_institution.as_ref().and_then(party_to_agent_account),
+        cdtr: party_to_customer(&underlying.beneficiary_customer),
+        cdtr_acct: party_to_customer_account(&underlying.beneficiary_customer),
+        instr_for_nxt_agt: next_agent_instructions(&underlying.sender_to_receiver_information),
+        rmt_inf: mapping::remittance_text(&underlying.remittance_information).map(|text| RemittanceInformation16 { ustrd: Some(vec![text]), ..Default::default() }),
+        instd_amt: underlying.instructed_amount.as_ref().map(|a| ActiveOrHistoricCurrencyAndAmount { ccy: a.currency.clone(), value: a.amount }),
+        ..Default::default()
+    }
+}
+
+/// Checks a cover against its underlying customer credit transfer, see
+/// `mapping::check_cover`.
+pub fn check_cover(cover: &CreditTransferTransaction36) -> Result<(), ValidationError> {
+    let underlying = match cover.undrlyg_cstmr_cdt_trf {
+        Some(ref underlying) => underlying,
+        None => return Ok(()),
+    };
+    mapping::check_cover(&Cover {
+        debtor: institution(&cover.dbtr),
+        creditor: institution(&cover.cdtr),
+        amount: CurrencyAmount { currency: cover.intr_bk_sttlm_amt.ccy.clone(), amount: cover.intr_bk_sttlm_amt.value },
+        underlying_debtor_agent: institution(&underlying.dbtr_agt),
+        underlying_intermediary: underlying.intrmy_agt1.as_ref().map(institution),
+        underlying_creditor_agent: institution(&underlying.cdtr_agt),
+        underlying_instructed_amount: underlying.instd_amt.as_ref().map(|a| CurrencyAmount { currency: a.ccy.clone(), amount: a.value }),
+        underlying_debtor_identified: underlying.dbtr.nm.is_some() || underlying.dbtr.id.is_some(),
+        underlying_creditor_identified: underlying.cdtr.nm.is_some() || underlying.cdtr.id.is_some(),
+    })
+}
+
+/// Translates every transaction of a pacs.009 into an MT202, or an MT202 COV
+/// when the transaction carries an underlying customer credit transfer.
+/// FedNow identifies the instructing and instructed agents by routing
+/// number, so the caller gives the BICs that address the FIN messages.
+pub fn pacs009_to_mt202(document: &FinancialInstitutionCreditTransferV08, sender_bic: &str, receiver_bic: &str) -> Result<Vec<FinMessage>, ValidationError> {
+    let header = &document.grp_hdr;
+    let mut messages = Vec::new();
+    for tx in &document.cdt_trf_tx_inf {
+        check_cover(tx)?;
+        let instructing = tx.instg_agt.as_ref().or(header.instg_agt.as_ref());
+        let date = tx
+            .intr_bk_sttlm_dt
+            .clone()
+            .or_else(|| header.intr_bk_sttlm_dt.clone())
+            .ok_or_else(|| ValidationError::new(ERR_UNMAPPABLE, "interbank settlement date is required for field 32A".to_string()))?;
+        let reference = tx.pmt_id.instr_id.as_deref().or(tx.pmt_id.tx_id.as_deref()).unwrap_or(&header.msg_id);
+        let related = if tx.pmt_id.end_to_end_id == "NOTPROVIDED" { "NONREF" } else { tx.pmt_id.end_to_end_id.as_str() };
+
+        // The instructing agent is the sender, 52a only names another debtor.
+        let debtor = if instructing.and_then(|agent| institution(&tx.dbtr).same_as(&institution(agent))) == Some(true) && tx.dbtr_acct.is_none() {
+            None
+        } else {
+            Some(agent_to_party(&tx.dbtr, tx.dbtr_acct.as_ref()))
+        };
+
+        let mt = Mt202 {
+            transaction_reference: mt_reference(reference),
+            related_reference: mt_reference(related),
+            value_date_amount: DateCurrencyAmount {
+                date,
+                currency: tx.intr_bk_sttlm_amt.ccy.clone(),
+                amount: tx.intr_bk_sttlm_amt.value,
+            },
+            ordering_institution: debtor,
+            intermediary: tx.intrmy_agt1.as_ref().map(|a| agent_to_party(a, tx.intrmy_agt1_acct.as_ref())),
+            account_with_institution: tx.cdtr_agt.as_ref().map(|a| agent_to_party(a, tx.cdtr_agt_acct.as_ref())),
+            beneficiary_institution: agent_to_party(&tx.cdtr, tx.cdtr_acct.as_ref()),
+            sender_to_receiver_information: instruction_lines(&tx.instr_for_nxt_agt),
+            underlying: tx.undrlyg_cstmr_cdt_trf.as_ref().map(underlying_to_mt),
+            ..Default::default()
+        };
+        messages.push(mt.to_fin(sender_bic, receiver_bic, tx.pmt_id.uetr.as_deref()));
+    }
+    Ok(messages)
+}
+
+fn underlying_to_mt(underlying: &CreditTransferTransaction37) -> UnderlyingCustomerCreditTransfer {
+    let remittance = underlying.rmt_inf.as_ref().and_then(|r| r.ustrd.as_deref()).map(mapping::remittance_lines).unwrap_or_default();
+    UnderlyingCustomerCreditTransfer {
+        ordering_customer: customer_to_party(&underlying.dbtr, underlying.dbtr_acct.as_ref(), Some('K')),
+        ordering_institution: Some(agent_to_party(&underlying.dbtr_agt, underlying.dbtr_agt_acct.as_ref())),
+        intermediary_institution: underlying.intrmy_agt1.as_ref().map(|a| agent_to_party(a, underlying.intrmy_agt1_acct.as_ref())),
+        account_with_institution: Some(agent_to_party(&underlying.cdtr_agt, underlying.cdtr_agt_acct.as_ref())),
+        beneficiary_customer: customer_to_party(&underlying.cdtr, underlying.cdtr_acct.as_ref(), None),
+        remittance_information: remittance,
+        sender_to_receiver_information: instruction_lines(&underlying.instr_for_nxt_agt),
+        instructed_amount: underlying.instd_amt.as_ref().map(|a| CurrencyAmount { currency: a.ccy.clone(), amount: a.value }),
+    }
+}
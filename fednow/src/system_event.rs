@@ -0,0 +1,103 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// FedNow system event broadcasts (admi.004.001.02 in `FedNowBroadcast` and
+// `FedNowParticipantBroadcast`) fed into a `BusinessDayTracker`
+// (`open_payments_common::system_event`), and the system event
+// acknowledgements (admi.011.001.01) answering them.
+
+use crate::common::*;
+use crate::document::Document;
+use crate::iso::admi_004_001_02::SystemEventNotificationV02;
+use crate::iso::admi_011_001_01::SystemEventAcknowledgementV01;
+#[cfg(all(feature = "derive_serde", feature = "derive_default"))]
+use crate::header_check::{agent_id, AppHdrBuilder};
+#[cfg(all(feature = "derive_serde", feature = "derive_default"))]
+use open_payments_common::header_check::AgentId;
+use open_payments_common::system_event::{BusinessDayTracker, EventRecord, SystemEvent, ERR_EVENT_PARAMETER};
+use open_payments_common::ValidationError;
+
+pub fn event_record(event: &Event2) -> EventRecord {
+    EventRecord {
+        code: event.evt_cd.clone(),
+        parameters: event.evt_param.clone().unwrap_or_default(),
+        description: event.evt_desc.clone(),
+        time: event.evt_tm.clone(),
+    }
+}
+
+/// The system event notification a Document carries.
+pub fn notification(document: &Document) -> Result<&SystemEventNotificationV02, ValidationError> {
+    match document {
+        Document::SystemEventNotificationV02(notification) => Ok(notification),
+        other => Err(ValidationError::new(ERR_EVENT_PARAMETER, format!(
+            "{} is not a system event notification", other.message_definition_identifier().unwrap_or("unknown document")
+        ))),
+    }
+}
+
+/// Applies the event of a FedNow Service broadcast.
+pub fn apply_broadcast(tracker: &mut BusinessDayTracker, broadcast: &FedNowBroadcast) -> Result<SystemEvent, ValidationError> {
+    tracker.apply(&event_record(&notification(&broadcast.a4_document)?.evt_inf))
+}
+
+/// Applies the event of a participant broadcast.
+pub fn apply_participant_broadcast(tracker: &mut BusinessDayTracker, broadcast: &FedNowParticipantBroadcast) -> Result<SystemEvent, ValidationError> {
+    tracker.apply(&event_record(&notification(&broadcast.a4_document)?.evt_inf))
+}
+
+/// The admi.011 acknowledging a notification. `orgtr_ref` is the
+/// reference of the acknowledged message, usually its `BizMsgIdr`.
+pub fn acknowledgement(notification: &SystemEventNotificationV02, msg_id: &str, orgtr_ref: Option<&str>) -> SystemEventAcknowledgementV01 {
+    let event = &notification.evt_inf;
+    SystemEventAcknowledgementV01 {
+        msg_id: msg_id.to_string(),
+        orgtr_ref: orgtr_ref.map(|r| r.to_string()),
+        sttlm_ssn_idr: None,
+        ack_dtls: Some(Event1 {
+            evt_cd: event.evt_cd.clone(),
+            evt_param: event.evt_param.clone(),
+            evt_desc: event.evt_desc.clone(),
+            evt_tm: event.evt_tm.clone(),
+        }),
+        splmtry_data: None,
+    }
+}
+
+/// The `FedNowSystemResponse` acknowledging a participant broadcast, from
+/// `from` back to the participant that sent it.
+#[cfg(all(feature = "derive_serde", feature = "derive_default"))]
+pub fn system_response(broadcast: &FedNowParticipantBroadcast, msg_id: &str, from: AgentId, cre_dt_tm: &str) -> Result<FedNowSystemResponse, ValidationError> {
+    let received = &broadcast.bah_app_hdr;
+    let acknowledgement = acknowledgement(notification(&broadcast.a4_document)?, msg_id, Some(&received.biz_msg_idr));
+    let document = Document::SystemEventAcknowledgementV01(Box::new(acknowledgement));
+    let mut builder = AppHdrBuilder::from_document(&document)?
+        .business_message_id(msg_id)
+        .creation_date(cre_dt_tm)
+        .from(from);
+    if let Some(sender) = received.fr.fi_id.as_ref().and_then(agent_id) {
+        builder = builder.to(sender);
+    }
+    Ok(FedNowSystemResponse { bah_app_hdr: builder.build()?, a11_document: document })
+}
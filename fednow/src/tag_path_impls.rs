@@ -0,0 +1,8123 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `TagPaths` for the generated types of this crate, generated by
+// generate-tag-path.py from the tags of their fields (see
+// `open_payments_common::tag_path`).
+
+use open_payments_common::tag_path::*;
+
+impl TagPaths for crate::common::AccountDebitCreditNotification {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c54_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c54_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowAccountActivityDetailsReport {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c52_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c52_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowAccountActivityTotalsReport {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c52_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c52_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowAccountBalanceReport {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c52_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c52_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowAccountReportingRequest {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c60_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c60_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowAdditionalPaymentInformation {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c28_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c28_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowBroadcast {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.a4_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.a4_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowCustomerCreditTransfer {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.p8_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.p8_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowIncomingMessage {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FedNowMessageReject") { optional(&self.fed_now_message_reject, step, out) }
+		if step.matches("FedNowParticipantBroadcast") { optional(&self.fed_now_participant_broadcast, step, out) }
+		if step.matches("FedNowRetrievalRequest") { optional(&self.fed_now_retrieval_request, step, out) }
+		if step.matches("FedNowReceiptAcknowledgement") { optional(&self.fed_now_receipt_acknowledgement, step, out) }
+		if step.matches("FedNowPaymentStatus") { optional(&self.fed_now_payment_status, step, out) }
+		if step.matches("FedNowPaymentReturn") { optional(&self.fed_now_payment_return, step, out) }
+		if step.matches("FedNowCustomerCreditTransfer") { optional(&self.fed_now_customer_credit_transfer, step, out) }
+		if step.matches("FedNowInstitutionCreditTransfer") { optional(&self.fed_now_institution_credit_transfer, step, out) }
+		if step.matches("FedNowPaymentStatusRequest") { optional(&self.fed_now_payment_status_request, step, out) }
+		if step.matches("FedNowRequestForPayment") { optional(&self.fed_now_request_for_payment, step, out) }
+		if step.matches("FedNowRequestForPaymentResponse") { optional(&self.fed_now_request_for_payment_response, step, out) }
+		if step.matches("FedNowInformationRequest") { optional(&self.fed_now_information_request, step, out) }
+		if step.matches("FedNowAdditionalPaymentInformation") { optional(&self.fed_now_additional_payment_information, step, out) }
+		if step.matches("FedNowInformationRequestResponse") { optional(&self.fed_now_information_request_response, step, out) }
+		if step.matches("FedNowRequestForPaymentCancellationRequestResponse") { optional(&self.fed_now_request_for_payment_cancellation_request_response, step, out) }
+		if step.matches("FedNowReturnRequestResponse") { optional(&self.fed_now_return_request_response, step, out) }
+		if step.matches("FedNowRequestForPaymentCancellationRequest") { optional(&self.fed_now_request_for_payment_cancellation_request, step, out) }
+		if step.matches("FedNowReturnRequest") { optional(&self.fed_now_return_request, step, out) }
+		if step.matches("FedNowAccountReportingRequest") { optional(&self.fed_now_account_reporting_request, step, out) }
+		if step.matches("FedNowIncomingMessageSignatureManagement") { optional(&self.fed_now_incoming_message_signature_management, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FedNowMessageReject") { optional_mut(&mut self.fed_now_message_reject, step, create, out) }
+		if step.matches("FedNowParticipantBroadcast") { optional_mut(&mut self.fed_now_participant_broadcast, step, create, out) }
+		if step.matches("FedNowRetrievalRequest") { optional_mut(&mut self.fed_now_retrieval_request, step, create, out) }
+		if step.matches("FedNowReceiptAcknowledgement") { optional_mut(&mut self.fed_now_receipt_acknowledgement, step, create, out) }
+		if step.matches("FedNowPaymentStatus") { optional_mut(&mut self.fed_now_payment_status, step, create, out) }
+		if step.matches("FedNowPaymentReturn") { optional_mut(&mut self.fed_now_payment_return, step, create, out) }
+		if step.matches("FedNowCustomerCreditTransfer") { optional_mut(&mut self.fed_now_customer_credit_transfer, step, create, out) }
+		if step.matches("FedNowInstitutionCreditTransfer") { optional_mut(&mut self.fed_now_institution_credit_transfer, step, create, out) }
+		if step.matches("FedNowPaymentStatusRequest") { optional_mut(&mut self.fed_now_payment_status_request, step, create, out) }
+		if step.matches("FedNowRequestForPayment") { optional_mut(&mut self.fed_now_request_for_payment, step, create, out) }
+		if step.matches("FedNowRequestForPaymentResponse") { optional_mut(&mut self.fed_now_request_for_payment_response, step, create, out) }
+		if step.matches("FedNowInformationRequest") { optional_mut(&mut self.fed_now_information_request, step, create, out) }
+		if step.matches("FedNowAdditionalPaymentInformation") { optional_mut(&mut self.fed_now_additional_payment_information, step, create, out) }
+		if step.matches("FedNowInformationRequestResponse") { optional_mut(&mut self.fed_now_information_request_response, step, create, out) }
+		if step.matches("FedNowRequestForPaymentCancellationRequestResponse") { optional_mut(&mut self.fed_now_request_for_payment_cancellation_request_response, step, create, out) }
+		if step.matches("FedNowReturnRequestResponse") { optional_mut(&mut self.fed_now_return_request_response, step, create, out) }
+		if step.matches("FedNowRequestForPaymentCancellationRequest") { optional_mut(&mut self.fed_now_request_for_payment_cancellation_request, step, create, out) }
+		if step.matches("FedNowReturnRequest") { optional_mut(&mut self.fed_now_return_request, step, create, out) }
+		if step.matches("FedNowAccountReportingRequest") { optional_mut(&mut self.fed_now_account_reporting_request, step, create, out) }
+		if step.matches("FedNowIncomingMessageSignatureManagement") { optional_mut(&mut self.fed_now_incoming_message_signature_management, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowIncomingMessageSignatureManagement {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("SenderId") { one(&self.sender_id, step, out) }
+		if step.matches("GetAllFedNowActivePublicKeys") { optional(&self.ke_get_all_fed_now_active_public_keys, step, out) }
+		if step.matches("GetAllCustomerPublicKeys") { optional(&self.ke_get_all_customer_public_keys, step, out) }
+		if step.matches("FedNowMessageSignatureKeyExchange") { optional(&self.ke_fed_now_message_signature_key_exchange, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("SenderId") { one_mut(&mut self.sender_id, step, out) }
+		if step.matches("GetAllFedNowActivePublicKeys") { optional_mut(&mut self.ke_get_all_fed_now_active_public_keys, step, create, out) }
+		if step.matches("GetAllCustomerPublicKeys") { optional_mut(&mut self.ke_get_all_customer_public_keys, step, create, out) }
+		if step.matches("FedNowMessageSignatureKeyExchange") { optional_mut(&mut self.ke_fed_now_message_signature_key_exchange, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowInformationRequest {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c26_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c26_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowInformationRequestResponse {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c29_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c29_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowInstitutionCreditTransfer {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.p9_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.p9_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowMessageReject {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.a2_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.a2_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowOutgoingMessage {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FedNowMessageReject") { optional(&self.fed_now_message_reject, step, out) }
+		if step.matches("FedNowBroadcast") { optional(&self.fed_now_broadcast, step, out) }
+		if step.matches("FedNowReceiptAcknowledgement") { optional(&self.fed_now_receipt_acknowledgement, step, out) }
+		if step.matches("FedNowSystemResponse") { optional(&self.fed_now_system_response, step, out) }
+		if step.matches("FedNowParticipantFile") { optional(&self.fed_now_participant_file, step, out) }
+		if step.matches("FedNowPaymentStatus") { optional(&self.fed_now_payment_status, step, out) }
+		if step.matches("FedNowPaymentReturn") { optional(&self.fed_now_payment_return, step, out) }
+		if step.matches("FedNowCustomerCreditTransfer") { optional(&self.fed_now_customer_credit_transfer, step, out) }
+		if step.matches("FedNowInstitutionCreditTransfer") { optional(&self.fed_now_institution_credit_transfer, step, out) }
+		if step.matches("FedNowPaymentStatusRequest") { optional(&self.fed_now_payment_status_request, step, out) }
+		if step.matches("FedNowRequestForPayment") { optional(&self.fed_now_request_for_payment, step, out) }
+		if step.matches("FedNowRequestForPaymentResponse") { optional(&self.fed_now_request_for_payment_response, step, out) }
+		if step.matches("FedNowInformationRequest") { optional(&self.fed_now_information_request, step, out) }
+		if step.matches("FedNowAdditionalPaymentInformation") { optional(&self.fed_now_additional_payment_information, step, out) }
+		if step.matches("FedNowReturnRequestResponse") { optional(&self.fed_now_return_request_response, step, out) }
+		if step.matches("FedNowInformationRequestResponse") { optional(&self.fed_now_information_request_response, step, out) }
+		if step.matches("FedNowAccountActivityDetailsReport") { optional(&self.fed_now_account_activity_details_report, step, out) }
+		if step.matches("FedNowAccountActivityTotalsReport") { optional(&self.fed_now_account_activity_totals_report, step, out) }
+		if step.matches("FedNowAccountBalanceReport") { optional(&self.fed_now_account_balance_report, step, out) }
+		if step.matches("AccountDebitCreditNotification") { optional(&self.account_debit_credit_notification, step, out) }
+		if step.matches("FedNowRequestForPaymentCancellationRequest") { optional(&self.fed_now_request_for_payment_cancellation_request, step, out) }
+		if step.matches("FedNowRequestForPaymentCancellationRequestResponse") { optional(&self.fed_now_request_for_payment_cancellation_request_response, step, out) }
+		if step.matches("FedNowReturnRequest") { optional(&self.fed_now_return_request, step, out) }
+		if step.matches("FedNowOutgoingMessageSignatureManagement") { optional(&self.fed_now_outgoing_message_signature_management, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FedNowMessageReject") { optional_mut(&mut self.fed_now_message_reject, step, create, out) }
+		if step.matches("FedNowBroadcast") { optional_mut(&mut self.fed_now_broadcast, step, create, out) }
+		if step.matches("FedNowReceiptAcknowledgement") { optional_mut(&mut self.fed_now_receipt_acknowledgement, step, create, out) }
+		if step.matches("FedNowSystemResponse") { optional_mut(&mut self.fed_now_system_response, step, create, out) }
+		if step.matches("FedNowParticipantFile") { optional_mut(&mut self.fed_now_participant_file, step, create, out) }
+		if step.matches("FedNowPaymentStatus") { optional_mut(&mut self.fed_now_payment_status, step, create, out) }
+		if step.matches("FedNowPaymentReturn") { optional_mut(&mut self.fed_now_payment_return, step, create, out) }
+		if step.matches("FedNowCustomerCreditTransfer") { optional_mut(&mut self.fed_now_customer_credit_transfer, step, create, out) }
+		if step.matches("FedNowInstitutionCreditTransfer") { optional_mut(&mut self.fed_now_institution_credit_transfer, step, create, out) }
+		if step.matches("FedNowPaymentStatusRequest") { optional_mut(&mut self.fed_now_payment_status_request, step, create, out) }
+		if step.matches("FedNowRequestForPayment") { optional_mut(&mut self.fed_now_request_for_payment, step, create, out) }
+		if step.matches("FedNowRequestForPaymentResponse") { optional_mut(&mut self.fed_now_request_for_payment_response, step, create, out) }
+		if step.matches("FedNowInformationRequest") { optional_mut(&mut self.fed_now_information_request, step, create, out) }
+		if step.matches("FedNowAdditionalPaymentInformation") { optional_mut(&mut self.fed_now_additional_payment_information, step, create, out) }
+		if step.matches("FedNowReturnRequestResponse") { optional_mut(&mut self.fed_now_return_request_response, step, create, out) }
+		if step.matches("FedNowInformationRequestResponse") { optional_mut(&mut self.fed_now_information_request_response, step, create, out) }
+		if step.matches("FedNowAccountActivityDetailsReport") { optional_mut(&mut self.fed_now_account_activity_details_report, step, create, out) }
+		if step.matches("FedNowAccountActivityTotalsReport") { optional_mut(&mut self.fed_now_account_activity_totals_report, step, create, out) }
+		if step.matches("FedNowAccountBalanceReport") { optional_mut(&mut self.fed_now_account_balance_report, step, create, out) }
+		if step.matches("AccountDebitCreditNotification") { optional_mut(&mut self.account_debit_credit_notification, step, create, out) }
+		if step.matches("FedNowRequestForPaymentCancellationRequest") { optional_mut(&mut self.fed_now_request_for_payment_cancellation_request, step, create, out) }
+		if step.matches("FedNowRequestForPaymentCancellationRequestResponse") { optional_mut(&mut self.fed_now_request_for_payment_cancellation_request_response, step, create, out) }
+		if step.matches("FedNowReturnRequest") { optional_mut(&mut self.fed_now_return_request, step, create, out) }
+		if step.matches("FedNowOutgoingMessageSignatureManagement") { optional_mut(&mut self.fed_now_outgoing_message_signature_management, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowOutgoingMessageSignatureManagement {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FedNowPublicKeyResponses") { optional(&self.ke_fed_now_public_key_responses, step, out) }
+		if step.matches("FedNowCustomerMessageSignatureKeyOperationResponse") { optional(&self.ke_fed_now_customer_message_signature_key_operation_response, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FedNowPublicKeyResponses") { optional_mut(&mut self.ke_fed_now_public_key_responses, step, create, out) }
+		if step.matches("FedNowCustomerMessageSignatureKeyOperationResponse") { optional_mut(&mut self.ke_fed_now_customer_message_signature_key_operation_response, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowParticipantBroadcast {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.a4_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.a4_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowParticipantFile {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.a998_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.a998_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowPaymentReturn {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.p4_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.p4_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowPaymentStatus {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.p2_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.p2_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowPaymentStatusRequest {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.p28_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.p28_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowReceiptAcknowledgement {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.a7_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.a7_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowRequestForPayment {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.pain13_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.pain13_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowRequestForPaymentCancellationRequest {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c55_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c55_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowRequestForPaymentCancellationRequestResponse {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c29_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c29_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowRequestForPaymentResponse {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.pain14_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.pain14_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowRetrievalRequest {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.a6_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.a6_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowReturnRequest {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c56_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c56_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowReturnRequestResponse {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.c29_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.c29_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowSystemResponse {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AppHdr") { one(&self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one(&self.a11_document, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AppHdr") { one_mut(&mut self.bah_app_hdr, step, out) }
+		if step.matches("Document") { one_mut(&mut self.a11_document, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowTechnicalHeader {}
+
+impl TagPaths for crate::common::AccountIdentification4Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("IBAN") { optional(&self.iban, step, out) }
+		if step.matches("Othr") { optional(&self.othr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("IBAN") { optional_mut(&mut self.iban, step, create, out) }
+		if step.matches("Othr") { optional_mut(&mut self.othr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AccountInterest4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Rate") { optional_repeated(&self.rate, step, out) }
+		if step.matches("FrToDt") { optional(&self.fr_to_dt, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("Tax") { optional(&self.tax, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Rate") { optional_repeated_mut(&mut self.rate, step, create, out) }
+		if step.matches("FrToDt") { optional_mut(&mut self.fr_to_dt, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("Tax") { optional_mut(&mut self.tax, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AccountNotification17 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("NtfctnPgntn") { optional(&self.ntfctn_pgntn, step, out) }
+		if step.matches("ElctrncSeqNb") { optional(&self.elctrnc_seq_nb, step, out) }
+		if step.matches("RptgSeq") { optional(&self.rptg_seq, step, out) }
+		if step.matches("LglSeqNb") { optional(&self.lgl_seq_nb, step, out) }
+		if step.matches("CreDtTm") { optional(&self.cre_dt_tm, step, out) }
+		if step.matches("FrToDt") { optional(&self.fr_to_dt, step, out) }
+		if step.matches("CpyDplctInd") { optional(&self.cpy_dplct_ind, step, out) }
+		if step.matches("RptgSrc") { optional(&self.rptg_src, step, out) }
+		if step.matches("Acct") { one(&self.acct, step, out) }
+		if step.matches("RltdAcct") { optional(&self.rltd_acct, step, out) }
+		if step.matches("Intrst") { optional_repeated(&self.intrst, step, out) }
+		if step.matches("TxsSummry") { optional(&self.txs_summry, step, out) }
+		if step.matches("Ntry") { optional_repeated(&self.ntry, step, out) }
+		if step.matches("AddtlNtfctnInf") { optional(&self.addtl_ntfctn_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("NtfctnPgntn") { optional_mut(&mut self.ntfctn_pgntn, step, create, out) }
+		if step.matches("ElctrncSeqNb") { optional_mut(&mut self.elctrnc_seq_nb, step, create, out) }
+		if step.matches("RptgSeq") { optional_mut(&mut self.rptg_seq, step, create, out) }
+		if step.matches("LglSeqNb") { optional_mut(&mut self.lgl_seq_nb, step, create, out) }
+		if step.matches("CreDtTm") { optional_mut(&mut self.cre_dt_tm, step, create, out) }
+		if step.matches("FrToDt") { optional_mut(&mut self.fr_to_dt, step, create, out) }
+		if step.matches("CpyDplctInd") { optional_mut(&mut self.cpy_dplct_ind, step, create, out) }
+		if step.matches("RptgSrc") { optional_mut(&mut self.rptg_src, step, create, out) }
+		if step.matches("Acct") { one_mut(&mut self.acct, step, out) }
+		if step.matches("RltdAcct") { optional_mut(&mut self.rltd_acct, step, create, out) }
+		if step.matches("Intrst") { optional_repeated_mut(&mut self.intrst, step, create, out) }
+		if step.matches("TxsSummry") { optional_mut(&mut self.txs_summry, step, create, out) }
+		if step.matches("Ntry") { optional_repeated_mut(&mut self.ntry, step, create, out) }
+		if step.matches("AddtlNtfctnInf") { optional_mut(&mut self.addtl_ntfctn_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AccountReport25 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("RptPgntn") { optional(&self.rpt_pgntn, step, out) }
+		if step.matches("ElctrncSeqNb") { optional(&self.elctrnc_seq_nb, step, out) }
+		if step.matches("RptgSeq") { optional(&self.rptg_seq, step, out) }
+		if step.matches("LglSeqNb") { optional(&self.lgl_seq_nb, step, out) }
+		if step.matches("CreDtTm") { optional(&self.cre_dt_tm, step, out) }
+		if step.matches("FrToDt") { optional(&self.fr_to_dt, step, out) }
+		if step.matches("CpyDplctInd") { optional(&self.cpy_dplct_ind, step, out) }
+		if step.matches("RptgSrc") { optional(&self.rptg_src, step, out) }
+		if step.matches("Acct") { one(&self.acct, step, out) }
+		if step.matches("RltdAcct") { optional(&self.rltd_acct, step, out) }
+		if step.matches("Intrst") { optional_repeated(&self.intrst, step, out) }
+		if step.matches("Bal") { optional_repeated(&self.bal, step, out) }
+		if step.matches("TxsSummry") { optional(&self.txs_summry, step, out) }
+		if step.matches("Ntry") { optional_repeated(&self.ntry, step, out) }
+		if step.matches("AddtlRptInf") { optional(&self.addtl_rpt_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("RptPgntn") { optional_mut(&mut self.rpt_pgntn, step, create, out) }
+		if step.matches("ElctrncSeqNb") { optional_mut(&mut self.elctrnc_seq_nb, step, create, out) }
+		if step.matches("RptgSeq") { optional_mut(&mut self.rptg_seq, step, create, out) }
+		if step.matches("LglSeqNb") { optional_mut(&mut self.lgl_seq_nb, step, create, out) }
+		if step.matches("CreDtTm") { optional_mut(&mut self.cre_dt_tm, step, create, out) }
+		if step.matches("FrToDt") { optional_mut(&mut self.fr_to_dt, step, create, out) }
+		if step.matches("CpyDplctInd") { optional_mut(&mut self.cpy_dplct_ind, step, create, out) }
+		if step.matches("RptgSrc") { optional_mut(&mut self.rptg_src, step, create, out) }
+		if step.matches("Acct") { one_mut(&mut self.acct, step, out) }
+		if step.matches("RltdAcct") { optional_mut(&mut self.rltd_acct, step, create, out) }
+		if step.matches("Intrst") { optional_repeated_mut(&mut self.intrst, step, create, out) }
+		if step.matches("Bal") { optional_repeated_mut(&mut self.bal, step, create, out) }
+		if step.matches("TxsSummry") { optional_mut(&mut self.txs_summry, step, create, out) }
+		if step.matches("Ntry") { optional_repeated_mut(&mut self.ntry, step, create, out) }
+		if step.matches("AddtlRptInf") { optional_mut(&mut self.addtl_rpt_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AccountSchemeName1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ActiveCurrencyAndAmount {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("@Ccy") { one(&self.ccy, step, out) }
+		if step.matches("$value") { one(&self.value, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("@Ccy") { one_mut(&mut self.ccy, step, out) }
+		if step.matches("$value") { one_mut(&mut self.value, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ActiveOrHistoricCurrencyAnd13DecimalAmount {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("@Ccy") { one(&self.ccy, step, out) }
+		if step.matches("$value") { one(&self.value, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("@Ccy") { one_mut(&mut self.ccy, step, out) }
+		if step.matches("$value") { one_mut(&mut self.value, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ActiveOrHistoricCurrencyAndAmount {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("@Ccy") { one(&self.ccy, step, out) }
+		if step.matches("$value") { one(&self.value, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("@Ccy") { one_mut(&mut self.ccy, step, out) }
+		if step.matches("$value") { one_mut(&mut self.value, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ActiveOrHistoricCurrencyAndAmountRange2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { optional(&self.cdt_dbt_ind, step, out) }
+		if step.matches("Ccy") { one(&self.ccy, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CdtDbtInd") { optional_mut(&mut self.cdt_dbt_ind, step, create, out) }
+		if step.matches("Ccy") { one_mut(&mut self.ccy, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::AddressType2Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeADDR => "ADDR",
+			Self::CodePBOX => "PBOX",
+			Self::CodeHOME => "HOME",
+			Self::CodeBIZZ => "BIZZ",
+			Self::CodeMLTO => "MLTO",
+			Self::CodeDLVY => "DLVY",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"ADDR" => Self::CodeADDR,
+			"PBOX" => Self::CodePBOX,
+			"HOME" => Self::CodeHOME,
+			"BIZZ" => Self::CodeBIZZ,
+			"MLTO" => Self::CodeMLTO,
+			"DLVY" => Self::CodeDLVY,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::AddressType3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AmendmentInformationDetails13 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlMndtId") { optional(&self.orgnl_mndt_id, step, out) }
+		if step.matches("OrgnlCdtrSchmeId") { optional(&self.orgnl_cdtr_schme_id, step, out) }
+		if step.matches("OrgnlCdtrAgt") { optional(&self.orgnl_cdtr_agt, step, out) }
+		if step.matches("OrgnlCdtrAgtAcct") { optional(&self.orgnl_cdtr_agt_acct, step, out) }
+		if step.matches("OrgnlDbtr") { optional(&self.orgnl_dbtr, step, out) }
+		if step.matches("OrgnlDbtrAcct") { optional(&self.orgnl_dbtr_acct, step, out) }
+		if step.matches("OrgnlDbtrAgt") { optional(&self.orgnl_dbtr_agt, step, out) }
+		if step.matches("OrgnlDbtrAgtAcct") { optional(&self.orgnl_dbtr_agt_acct, step, out) }
+		if step.matches("OrgnlFnlColltnDt") { optional(&self.orgnl_fnl_colltn_dt, step, out) }
+		if step.matches("OrgnlFrqcy") { optional(&self.orgnl_frqcy, step, out) }
+		if step.matches("OrgnlRsn") { optional(&self.orgnl_rsn, step, out) }
+		if step.matches("OrgnlTrckgDays") { optional(&self.orgnl_trckg_days, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlMndtId") { optional_mut(&mut self.orgnl_mndt_id, step, create, out) }
+		if step.matches("OrgnlCdtrSchmeId") { optional_mut(&mut self.orgnl_cdtr_schme_id, step, create, out) }
+		if step.matches("OrgnlCdtrAgt") { optional_mut(&mut self.orgnl_cdtr_agt, step, create, out) }
+		if step.matches("OrgnlCdtrAgtAcct") { optional_mut(&mut self.orgnl_cdtr_agt_acct, step, create, out) }
+		if step.matches("OrgnlDbtr") { optional_mut(&mut self.orgnl_dbtr, step, create, out) }
+		if step.matches("OrgnlDbtrAcct") { optional_mut(&mut self.orgnl_dbtr_acct, step, create, out) }
+		if step.matches("OrgnlDbtrAgt") { optional_mut(&mut self.orgnl_dbtr_agt, step, create, out) }
+		if step.matches("OrgnlDbtrAgtAcct") { optional_mut(&mut self.orgnl_dbtr_agt_acct, step, create, out) }
+		if step.matches("OrgnlFnlColltnDt") { optional_mut(&mut self.orgnl_fnl_colltn_dt, step, create, out) }
+		if step.matches("OrgnlFrqcy") { optional_mut(&mut self.orgnl_frqcy, step, create, out) }
+		if step.matches("OrgnlRsn") { optional_mut(&mut self.orgnl_rsn, step, create, out) }
+		if step.matches("OrgnlTrckgDays") { optional_mut(&mut self.orgnl_trckg_days, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AmountAndCurrencyExchange3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstdAmt") { optional(&self.instd_amt, step, out) }
+		if step.matches("TxAmt") { optional(&self.tx_amt, step, out) }
+		if step.matches("CntrValAmt") { optional(&self.cntr_val_amt, step, out) }
+		if step.matches("AnncdPstngAmt") { optional(&self.anncd_pstng_amt, step, out) }
+		if step.matches("PrtryAmt") { optional_repeated(&self.prtry_amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstdAmt") { optional_mut(&mut self.instd_amt, step, create, out) }
+		if step.matches("TxAmt") { optional_mut(&mut self.tx_amt, step, create, out) }
+		if step.matches("CntrValAmt") { optional_mut(&mut self.cntr_val_amt, step, create, out) }
+		if step.matches("AnncdPstngAmt") { optional_mut(&mut self.anncd_pstng_amt, step, create, out) }
+		if step.matches("PrtryAmt") { optional_repeated_mut(&mut self.prtry_amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AmountAndCurrencyExchangeDetails3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CcyXchg") { optional(&self.ccy_xchg, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CcyXchg") { optional_mut(&mut self.ccy_xchg, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AmountAndCurrencyExchangeDetails4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CcyXchg") { optional(&self.ccy_xchg, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CcyXchg") { optional_mut(&mut self.ccy_xchg, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AmountAndDirection35 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one(&self.cdt_dbt_ind, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one_mut(&mut self.cdt_dbt_ind, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::AmountOrRate1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+		if step.matches("Rate") { optional(&self.rate, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+		if step.matches("Rate") { optional_mut(&mut self.rate, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AmountRangeBoundary1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("BdryAmt") { one(&self.bdry_amt, step, out) }
+		if step.matches("Incl") { one(&self.incl, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("BdryAmt") { one_mut(&mut self.bdry_amt, step, out) }
+		if step.matches("Incl") { one_mut(&mut self.incl, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::AmountType4Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstdAmt") { optional(&self.instd_amt, step, out) }
+		if step.matches("EqvtAmt") { optional(&self.eqvt_amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstdAmt") { optional_mut(&mut self.instd_amt, step, create, out) }
+		if step.matches("EqvtAmt") { optional_mut(&mut self.eqvt_amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::AttendanceContext1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeATTD => "ATTD",
+			Self::CodeSATT => "SATT",
+			Self::CodeUATT => "UATT",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"ATTD" => Self::CodeATTD,
+			"SATT" => Self::CodeSATT,
+			"UATT" => Self::CodeUATT,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::AuthenticationEntity1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeICCD => "ICCD",
+			Self::CodeAGNT => "AGNT",
+			Self::CodeMERC => "MERC",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"ICCD" => Self::CodeICCD,
+			"AGNT" => Self::CodeAGNT,
+			"MERC" => Self::CodeMERC,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::AuthenticationMethod1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeUKNW => "UKNW",
+			Self::CodeBYPS => "BYPS",
+			Self::CodeNPIN => "NPIN",
+			Self::CodeFPIN => "FPIN",
+			Self::CodeCPSG => "CPSG",
+			Self::CodePPSG => "PPSG",
+			Self::CodeMANU => "MANU",
+			Self::CodeMERC => "MERC",
+			Self::CodeSCRT => "SCRT",
+			Self::CodeSNCT => "SNCT",
+			Self::CodeSCNL => "SCNL",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"UKNW" => Self::CodeUKNW,
+			"BYPS" => Self::CodeBYPS,
+			"NPIN" => Self::CodeNPIN,
+			"FPIN" => Self::CodeFPIN,
+			"CPSG" => Self::CodeCPSG,
+			"PPSG" => Self::CodePPSG,
+			"MANU" => Self::CodeMANU,
+			"MERC" => Self::CodeMERC,
+			"SCRT" => Self::CodeSCRT,
+			"SNCT" => Self::CodeSNCT,
+			"SCNL" => Self::CodeSCNL,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Authorisation1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Authorisation1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeAUTH => "AUTH",
+			Self::CodeFDET => "FDET",
+			Self::CodeFSUM => "FSUM",
+			Self::CodeILEV => "ILEV",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"AUTH" => Self::CodeAUTH,
+			"FDET" => Self::CodeFDET,
+			"FSUM" => Self::CodeFSUM,
+			"ILEV" => Self::CodeILEV,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::BalanceSubType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::BalanceType10Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::BalanceType13 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one(&self.cd_or_prtry, step, out) }
+		if step.matches("SubTp") { optional(&self.sub_tp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one_mut(&mut self.cd_or_prtry, step, out) }
+		if step.matches("SubTp") { optional_mut(&mut self.sub_tp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::BankTransactionCodeStructure4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Domn") { optional(&self.domn, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Domn") { optional_mut(&mut self.domn, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::BankTransactionCodeStructure5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { one(&self.cd, step, out) }
+		if step.matches("Fmly") { one(&self.fmly, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { one_mut(&mut self.cd, step, out) }
+		if step.matches("Fmly") { one_mut(&mut self.fmly, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::BankTransactionCodeStructure6 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { one(&self.cd, step, out) }
+		if step.matches("SubFmlyCd") { one(&self.sub_fmly_cd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { one_mut(&mut self.cd, step, out) }
+		if step.matches("SubFmlyCd") { one_mut(&mut self.sub_fmly_cd, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::BatchInformation2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { optional(&self.msg_id, step, out) }
+		if step.matches("PmtInfId") { optional(&self.pmt_inf_id, step, out) }
+		if step.matches("NbOfTxs") { optional(&self.nb_of_txs, step, out) }
+		if step.matches("TtlAmt") { optional(&self.ttl_amt, step, out) }
+		if step.matches("CdtDbtInd") { optional(&self.cdt_dbt_ind, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { optional_mut(&mut self.msg_id, step, create, out) }
+		if step.matches("PmtInfId") { optional_mut(&mut self.pmt_inf_id, step, create, out) }
+		if step.matches("NbOfTxs") { optional_mut(&mut self.nb_of_txs, step, create, out) }
+		if step.matches("TtlAmt") { optional_mut(&mut self.ttl_amt, step, create, out) }
+		if step.matches("CdtDbtInd") { optional_mut(&mut self.cdt_dbt_ind, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::BranchAndFinancialInstitutionIdentification6 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FinInstnId") { one(&self.fin_instn_id, step, out) }
+		if step.matches("BrnchId") { optional(&self.brnch_id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FinInstnId") { one_mut(&mut self.fin_instn_id, step, out) }
+		if step.matches("BrnchId") { optional_mut(&mut self.brnch_id, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::BranchData3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("LEI") { optional(&self.lei, step, out) }
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+		if step.matches("PstlAdr") { optional(&self.pstl_adr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("LEI") { optional_mut(&mut self.lei, step, create, out) }
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+		if step.matches("PstlAdr") { optional_mut(&mut self.pstl_adr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::BusinessApplicationHeader5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CharSet") { optional(&self.char_set, step, out) }
+		if step.matches("Fr") { one(&self.fr, step, out) }
+		if step.matches("To") { one(&self.to, step, out) }
+		if step.matches("BizMsgIdr") { one(&self.biz_msg_idr, step, out) }
+		if step.matches("MsgDefIdr") { one(&self.msg_def_idr, step, out) }
+		if step.matches("BizSvc") { optional(&self.biz_svc, step, out) }
+		if step.matches("CreDt") { one(&self.cre_dt, step, out) }
+		if step.matches("CpyDplct") { optional(&self.cpy_dplct, step, out) }
+		if step.matches("PssblDplct") { optional(&self.pssbl_dplct, step, out) }
+		if step.matches("Prty") { optional(&self.prty, step, out) }
+		if step.matches("Sgntr") { optional(&self.sgntr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CharSet") { optional_mut(&mut self.char_set, step, create, out) }
+		if step.matches("Fr") { one_mut(&mut self.fr, step, out) }
+		if step.matches("To") { one_mut(&mut self.to, step, out) }
+		if step.matches("BizMsgIdr") { one_mut(&mut self.biz_msg_idr, step, out) }
+		if step.matches("MsgDefIdr") { one_mut(&mut self.msg_def_idr, step, out) }
+		if step.matches("BizSvc") { optional_mut(&mut self.biz_svc, step, create, out) }
+		if step.matches("CreDt") { one_mut(&mut self.cre_dt, step, out) }
+		if step.matches("CpyDplct") { optional_mut(&mut self.cpy_dplct, step, create, out) }
+		if step.matches("PssblDplct") { optional_mut(&mut self.pssbl_dplct, step, create, out) }
+		if step.matches("Prty") { optional_mut(&mut self.prty, step, create, out) }
+		if step.matches("Sgntr") { optional_mut(&mut self.sgntr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::BusinessApplicationHeaderV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CharSet") { optional(&self.char_set, step, out) }
+		if step.matches("Fr") { one(&self.fr, step, out) }
+		if step.matches("To") { one(&self.to, step, out) }
+		if step.matches("BizMsgIdr") { one(&self.biz_msg_idr, step, out) }
+		if step.matches("MsgDefIdr") { one(&self.msg_def_idr, step, out) }
+		if step.matches("BizSvc") { optional(&self.biz_svc, step, out) }
+		if step.matches("MktPrctc") { optional(&self.mkt_prctc, step, out) }
+		if step.matches("CreDt") { one(&self.cre_dt, step, out) }
+		if step.matches("BizPrcgDt") { optional(&self.biz_prcg_dt, step, out) }
+		if step.matches("CpyDplct") { optional(&self.cpy_dplct, step, out) }
+		if step.matches("PssblDplct") { optional(&self.pssbl_dplct, step, out) }
+		if step.matches("Prty") { optional(&self.prty, step, out) }
+		if step.matches("Sgntr") { optional(&self.sgntr, step, out) }
+		if step.matches("Rltd") { optional_repeated(&self.rltd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CharSet") { optional_mut(&mut self.char_set, step, create, out) }
+		if step.matches("Fr") { one_mut(&mut self.fr, step, out) }
+		if step.matches("To") { one_mut(&mut self.to, step, out) }
+		if step.matches("BizMsgIdr") { one_mut(&mut self.biz_msg_idr, step, out) }
+		if step.matches("MsgDefIdr") { one_mut(&mut self.msg_def_idr, step, out) }
+		if step.matches("BizSvc") { optional_mut(&mut self.biz_svc, step, create, out) }
+		if step.matches("MktPrctc") { optional_mut(&mut self.mkt_prctc, step, create, out) }
+		if step.matches("CreDt") { one_mut(&mut self.cre_dt, step, out) }
+		if step.matches("BizPrcgDt") { optional_mut(&mut self.biz_prcg_dt, step, create, out) }
+		if step.matches("CpyDplct") { optional_mut(&mut self.cpy_dplct, step, create, out) }
+		if step.matches("PssblDplct") { optional_mut(&mut self.pssbl_dplct, step, create, out) }
+		if step.matches("Prty") { optional_mut(&mut self.prty, step, create, out) }
+		if step.matches("Sgntr") { optional_mut(&mut self.sgntr, step, create, out) }
+		if step.matches("Rltd") { optional_repeated_mut(&mut self.rltd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CSCManagement1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodePRST => "PRST",
+			Self::CodeBYPS => "BYPS",
+			Self::CodeUNRD => "UNRD",
+			Self::CodeNCSC => "NCSC",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"PRST" => Self::CodePRST,
+			"BYPS" => Self::CodeBYPS,
+			"UNRD" => Self::CodeUNRD,
+			"NCSC" => Self::CodeNCSC,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::CancellationIndividualStatus1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeRJCR => "RJCR",
+			Self::CodeACCR => "ACCR",
+			Self::CodePDCR => "PDCR",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"RJCR" => Self::CodeRJCR,
+			"ACCR" => Self::CodeACCR,
+			"PDCR" => Self::CodePDCR,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::CancellationReason33Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CancellationStatusReason3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CancellationStatusReason4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Orgtr") { optional(&self.orgtr, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("AddtlInf") { optional_repeated(&self.addtl_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Orgtr") { optional_mut(&mut self.orgtr, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("AddtlInf") { optional_repeated_mut(&mut self.addtl_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CardAggregated2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AddtlSvc") { optional(&self.addtl_svc, step, out) }
+		if step.matches("TxCtgy") { optional(&self.tx_ctgy, step, out) }
+		if step.matches("SaleRcncltnId") { optional(&self.sale_rcncltn_id, step, out) }
+		if step.matches("SeqNbRg") { optional(&self.seq_nb_rg, step, out) }
+		if step.matches("TxDtRg") { optional(&self.tx_dt_rg, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AddtlSvc") { optional_mut(&mut self.addtl_svc, step, create, out) }
+		if step.matches("TxCtgy") { optional_mut(&mut self.tx_ctgy, step, create, out) }
+		if step.matches("SaleRcncltnId") { optional_mut(&mut self.sale_rcncltn_id, step, create, out) }
+		if step.matches("SeqNbRg") { optional_mut(&mut self.seq_nb_rg, step, create, out) }
+		if step.matches("TxDtRg") { optional_mut(&mut self.tx_dt_rg, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CardDataReading1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeTAGC => "TAGC",
+			Self::CodePHYS => "PHYS",
+			Self::CodeBRCD => "BRCD",
+			Self::CodeMGST => "MGST",
+			Self::CodeCICC => "CICC",
+			Self::CodeDFLE => "DFLE",
+			Self::CodeCTLS => "CTLS",
+			Self::CodeECTL => "ECTL",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"TAGC" => Self::CodeTAGC,
+			"PHYS" => Self::CodePHYS,
+			"BRCD" => Self::CodeBRCD,
+			"MGST" => Self::CodeMGST,
+			"CICC" => Self::CodeCICC,
+			"DFLE" => Self::CodeDFLE,
+			"CTLS" => Self::CodeCTLS,
+			"ECTL" => Self::CodeECTL,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::CardEntry4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Card") { optional(&self.card, step, out) }
+		if step.matches("POI") { optional(&self.poi, step, out) }
+		if step.matches("AggtdNtry") { optional(&self.aggtd_ntry, step, out) }
+		if step.matches("PrePdAcct") { optional(&self.pre_pd_acct, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Card") { optional_mut(&mut self.card, step, create, out) }
+		if step.matches("POI") { optional_mut(&mut self.poi, step, create, out) }
+		if step.matches("AggtdNtry") { optional_mut(&mut self.aggtd_ntry, step, create, out) }
+		if step.matches("PrePdAcct") { optional_mut(&mut self.pre_pd_acct, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CardIndividualTransaction2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ICCRltdData") { optional(&self.icc_rltd_data, step, out) }
+		if step.matches("PmtCntxt") { optional(&self.pmt_cntxt, step, out) }
+		if step.matches("AddtlSvc") { optional(&self.addtl_svc, step, out) }
+		if step.matches("TxCtgy") { optional(&self.tx_ctgy, step, out) }
+		if step.matches("SaleRcncltnId") { optional(&self.sale_rcncltn_id, step, out) }
+		if step.matches("SaleRefNb") { optional(&self.sale_ref_nb, step, out) }
+		if step.matches("RePresntmntRsn") { optional(&self.re_presntmnt_rsn, step, out) }
+		if step.matches("SeqNb") { optional(&self.seq_nb, step, out) }
+		if step.matches("TxId") { optional(&self.tx_id, step, out) }
+		if step.matches("Pdct") { optional(&self.pdct, step, out) }
+		if step.matches("VldtnDt") { optional(&self.vldtn_dt, step, out) }
+		if step.matches("VldtnSeqNb") { optional(&self.vldtn_seq_nb, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ICCRltdData") { optional_mut(&mut self.icc_rltd_data, step, create, out) }
+		if step.matches("PmtCntxt") { optional_mut(&mut self.pmt_cntxt, step, create, out) }
+		if step.matches("AddtlSvc") { optional_mut(&mut self.addtl_svc, step, create, out) }
+		if step.matches("TxCtgy") { optional_mut(&mut self.tx_ctgy, step, create, out) }
+		if step.matches("SaleRcncltnId") { optional_mut(&mut self.sale_rcncltn_id, step, create, out) }
+		if step.matches("SaleRefNb") { optional_mut(&mut self.sale_ref_nb, step, create, out) }
+		if step.matches("RePresntmntRsn") { optional_mut(&mut self.re_presntmnt_rsn, step, create, out) }
+		if step.matches("SeqNb") { optional_mut(&mut self.seq_nb, step, create, out) }
+		if step.matches("TxId") { optional_mut(&mut self.tx_id, step, create, out) }
+		if step.matches("Pdct") { optional_mut(&mut self.pdct, step, create, out) }
+		if step.matches("VldtnDt") { optional_mut(&mut self.vldtn_dt, step, create, out) }
+		if step.matches("VldtnSeqNb") { optional_mut(&mut self.vldtn_seq_nb, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CardPaymentServiceType2Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeAGGR => "AGGR",
+			Self::CodeDCCV => "DCCV",
+			Self::CodeGRTT => "GRTT",
+			Self::CodeINSP => "INSP",
+			Self::CodeLOYT => "LOYT",
+			Self::CodeNRES => "NRES",
+			Self::CodePUCO => "PUCO",
+			Self::CodeRECP => "RECP",
+			Self::CodeSOAF => "SOAF",
+			Self::CodeUNAF => "UNAF",
+			Self::CodeVCAU => "VCAU",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"AGGR" => Self::CodeAGGR,
+			"DCCV" => Self::CodeDCCV,
+			"GRTT" => Self::CodeGRTT,
+			"INSP" => Self::CodeINSP,
+			"LOYT" => Self::CodeLOYT,
+			"NRES" => Self::CodeNRES,
+			"PUCO" => Self::CodePUCO,
+			"RECP" => Self::CodeRECP,
+			"SOAF" => Self::CodeSOAF,
+			"UNAF" => Self::CodeUNAF,
+			"VCAU" => Self::CodeVCAU,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::CardSecurityInformation1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CSCMgmt") { one(&self.csc_mgmt, step, out) }
+		if step.matches("CSCVal") { optional(&self.csc_val, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CSCMgmt") { one_mut(&mut self.csc_mgmt, step, out) }
+		if step.matches("CSCVal") { optional_mut(&mut self.csc_val, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CardSequenceNumberRange1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrstTx") { optional(&self.frst_tx, step, out) }
+		if step.matches("LastTx") { optional(&self.last_tx, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrstTx") { optional_mut(&mut self.frst_tx, step, create, out) }
+		if step.matches("LastTx") { optional_mut(&mut self.last_tx, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CardTransaction17 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Card") { optional(&self.card, step, out) }
+		if step.matches("POI") { optional(&self.poi, step, out) }
+		if step.matches("Tx") { optional(&self.tx, step, out) }
+		if step.matches("PrePdAcct") { optional(&self.pre_pd_acct, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Card") { optional_mut(&mut self.card, step, create, out) }
+		if step.matches("POI") { optional_mut(&mut self.poi, step, create, out) }
+		if step.matches("Tx") { optional_mut(&mut self.tx, step, create, out) }
+		if step.matches("PrePdAcct") { optional_mut(&mut self.pre_pd_acct, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CardTransaction3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Aggtd") { optional(&self.aggtd, step, out) }
+		if step.matches("Indv") { optional(&self.indv, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Aggtd") { optional_mut(&mut self.aggtd, step, create, out) }
+		if step.matches("Indv") { optional_mut(&mut self.indv, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CardholderAuthentication2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AuthntcnMtd") { one(&self.authntcn_mtd, step, out) }
+		if step.matches("AuthntcnNtty") { one(&self.authntcn_ntty, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AuthntcnMtd") { one_mut(&mut self.authntcn_mtd, step, out) }
+		if step.matches("AuthntcnNtty") { one_mut(&mut self.authntcn_ntty, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::CardholderVerificationCapability1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeMNSG => "MNSG",
+			Self::CodeNPIN => "NPIN",
+			Self::CodeFCPN => "FCPN",
+			Self::CodeFEPN => "FEPN",
+			Self::CodeFDSG => "FDSG",
+			Self::CodeFBIO => "FBIO",
+			Self::CodeMNVR => "MNVR",
+			Self::CodeFBIG => "FBIG",
+			Self::CodeAPKI => "APKI",
+			Self::CodePKIS => "PKIS",
+			Self::CodeCHDT => "CHDT",
+			Self::CodeSCEC => "SCEC",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"MNSG" => Self::CodeMNSG,
+			"NPIN" => Self::CodeNPIN,
+			"FCPN" => Self::CodeFCPN,
+			"FEPN" => Self::CodeFEPN,
+			"FDSG" => Self::CodeFDSG,
+			"FBIO" => Self::CodeFBIO,
+			"MNVR" => Self::CodeMNVR,
+			"FBIG" => Self::CodeFBIG,
+			"APKI" => Self::CodeAPKI,
+			"PKIS" => Self::CodePKIS,
+			"CHDT" => Self::CodeCHDT,
+			"SCEC" => Self::CodeSCEC,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Case5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Cretr") { one(&self.cretr, step, out) }
+		if step.matches("ReopCaseIndctn") { optional(&self.reop_case_indctn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Cretr") { one_mut(&mut self.cretr, step, out) }
+		if step.matches("ReopCaseIndctn") { optional_mut(&mut self.reop_case_indctn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CaseAssignment5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Assgnr") { one(&self.assgnr, step, out) }
+		if step.matches("Assgne") { one(&self.assgne, step, out) }
+		if step.matches("CreDtTm") { one(&self.cre_dt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Assgnr") { one_mut(&mut self.assgnr, step, out) }
+		if step.matches("Assgne") { one_mut(&mut self.assgne, step, out) }
+		if step.matches("CreDtTm") { one_mut(&mut self.cre_dt_tm, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::CashAccount38 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Ccy") { optional(&self.ccy, step, out) }
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+		if step.matches("Prxy") { optional(&self.prxy, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Ccy") { optional_mut(&mut self.ccy, step, create, out) }
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+		if step.matches("Prxy") { optional_mut(&mut self.prxy, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CashAccount39 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Ccy") { optional(&self.ccy, step, out) }
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+		if step.matches("Prxy") { optional(&self.prxy, step, out) }
+		if step.matches("Ownr") { optional(&self.ownr, step, out) }
+		if step.matches("Svcr") { optional(&self.svcr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Ccy") { optional_mut(&mut self.ccy, step, create, out) }
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+		if step.matches("Prxy") { optional_mut(&mut self.prxy, step, create, out) }
+		if step.matches("Ownr") { optional_mut(&mut self.ownr, step, create, out) }
+		if step.matches("Svcr") { optional_mut(&mut self.svcr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CashAccountType2Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CashAvailability1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Dt") { one(&self.dt, step, out) }
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one(&self.cdt_dbt_ind, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Dt") { one_mut(&mut self.dt, step, out) }
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one_mut(&mut self.cdt_dbt_ind, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::CashAvailabilityDate1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("NbOfDays") { optional(&self.nb_of_days, step, out) }
+		if step.matches("ActlDt") { optional(&self.actl_dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("NbOfDays") { optional_mut(&mut self.nb_of_days, step, create, out) }
+		if step.matches("ActlDt") { optional_mut(&mut self.actl_dt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CashBalance8 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("CdtLine") { optional_repeated(&self.cdt_line, step, out) }
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one(&self.cdt_dbt_ind, step, out) }
+		if step.matches("Dt") { one(&self.dt, step, out) }
+		if step.matches("Avlbty") { optional_repeated(&self.avlbty, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("CdtLine") { optional_repeated_mut(&mut self.cdt_line, step, create, out) }
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one_mut(&mut self.cdt_dbt_ind, step, out) }
+		if step.matches("Dt") { one_mut(&mut self.dt, step, out) }
+		if step.matches("Avlbty") { optional_repeated_mut(&mut self.avlbty, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CashDeposit1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("NoteDnmtn") { one(&self.note_dnmtn, step, out) }
+		if step.matches("NbOfNotes") { one(&self.nb_of_notes, step, out) }
+		if step.matches("Amt") { one(&self.amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("NoteDnmtn") { one_mut(&mut self.note_dnmtn, step, out) }
+		if step.matches("NbOfNotes") { one_mut(&mut self.nb_of_notes, step, out) }
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::CategoryPurpose1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ChargeBearerType1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeDEBT => "DEBT",
+			Self::CodeCRED => "CRED",
+			Self::CodeSHAR => "SHAR",
+			Self::CodeSLEV => "SLEV",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"DEBT" => Self::CodeDEBT,
+			"CRED" => Self::CodeCRED,
+			"SHAR" => Self::CodeSHAR,
+			"SLEV" => Self::CodeSLEV,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::ChargeType3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Charges6 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TtlChrgsAndTaxAmt") { optional(&self.ttl_chrgs_and_tax_amt, step, out) }
+		if step.matches("Rcrd") { optional_repeated(&self.rcrd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TtlChrgsAndTaxAmt") { optional_mut(&mut self.ttl_chrgs_and_tax_amt, step, create, out) }
+		if step.matches("Rcrd") { optional_repeated_mut(&mut self.rcrd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Charges7 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("Agt") { one(&self.agt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("Agt") { one_mut(&mut self.agt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ChargesRecord3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { optional(&self.cdt_dbt_ind, step, out) }
+		if step.matches("ChrgInclInd") { optional(&self.chrg_incl_ind, step, out) }
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Rate") { optional(&self.rate, step, out) }
+		if step.matches("Br") { optional(&self.br, step, out) }
+		if step.matches("Agt") { optional(&self.agt, step, out) }
+		if step.matches("Tax") { optional(&self.tax, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CdtDbtInd") { optional_mut(&mut self.cdt_dbt_ind, step, create, out) }
+		if step.matches("ChrgInclInd") { optional_mut(&mut self.chrg_incl_ind, step, create, out) }
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Rate") { optional_mut(&mut self.rate, step, create, out) }
+		if step.matches("Br") { optional_mut(&mut self.br, step, create, out) }
+		if step.matches("Agt") { optional_mut(&mut self.agt, step, create, out) }
+		if step.matches("Tax") { optional_mut(&mut self.tax, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Cheque11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ChqTp") { optional(&self.chq_tp, step, out) }
+		if step.matches("ChqNb") { optional(&self.chq_nb, step, out) }
+		if step.matches("ChqFr") { optional(&self.chq_fr, step, out) }
+		if step.matches("DlvryMtd") { optional(&self.dlvry_mtd, step, out) }
+		if step.matches("DlvrTo") { optional(&self.dlvr_to, step, out) }
+		if step.matches("InstrPrty") { optional(&self.instr_prty, step, out) }
+		if step.matches("ChqMtrtyDt") { optional(&self.chq_mtrty_dt, step, out) }
+		if step.matches("FrmsCd") { optional(&self.frms_cd, step, out) }
+		if step.matches("MemoFld") { optional_repeated(&self.memo_fld, step, out) }
+		if step.matches("RgnlClrZone") { optional(&self.rgnl_clr_zone, step, out) }
+		if step.matches("PrtLctn") { optional(&self.prt_lctn, step, out) }
+		if step.matches("Sgntr") { optional_repeated(&self.sgntr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ChqTp") { optional_mut(&mut self.chq_tp, step, create, out) }
+		if step.matches("ChqNb") { optional_mut(&mut self.chq_nb, step, create, out) }
+		if step.matches("ChqFr") { optional_mut(&mut self.chq_fr, step, create, out) }
+		if step.matches("DlvryMtd") { optional_mut(&mut self.dlvry_mtd, step, create, out) }
+		if step.matches("DlvrTo") { optional_mut(&mut self.dlvr_to, step, create, out) }
+		if step.matches("InstrPrty") { optional_mut(&mut self.instr_prty, step, create, out) }
+		if step.matches("ChqMtrtyDt") { optional_mut(&mut self.chq_mtrty_dt, step, create, out) }
+		if step.matches("FrmsCd") { optional_mut(&mut self.frms_cd, step, create, out) }
+		if step.matches("MemoFld") { optional_repeated_mut(&mut self.memo_fld, step, create, out) }
+		if step.matches("RgnlClrZone") { optional_mut(&mut self.rgnl_clr_zone, step, create, out) }
+		if step.matches("PrtLctn") { optional_mut(&mut self.prt_lctn, step, create, out) }
+		if step.matches("Sgntr") { optional_repeated_mut(&mut self.sgntr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ChequeDelivery1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeMLDB => "MLDB",
+			Self::CodeMLCD => "MLCD",
+			Self::CodeMLFA => "MLFA",
+			Self::CodeCRDB => "CRDB",
+			Self::CodeCRCD => "CRCD",
+			Self::CodeCRFA => "CRFA",
+			Self::CodePUDB => "PUDB",
+			Self::CodePUCD => "PUCD",
+			Self::CodePUFA => "PUFA",
+			Self::CodeRGDB => "RGDB",
+			Self::CodeRGCD => "RGCD",
+			Self::CodeRGFA => "RGFA",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"MLDB" => Self::CodeMLDB,
+			"MLCD" => Self::CodeMLCD,
+			"MLFA" => Self::CodeMLFA,
+			"CRDB" => Self::CodeCRDB,
+			"CRCD" => Self::CodeCRCD,
+			"CRFA" => Self::CodeCRFA,
+			"PUDB" => Self::CodePUDB,
+			"PUCD" => Self::CodePUCD,
+			"PUFA" => Self::CodePUFA,
+			"RGDB" => Self::CodeRGDB,
+			"RGCD" => Self::CodeRGCD,
+			"RGFA" => Self::CodeRGFA,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::ChequeDeliveryMethod1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ChequeType2Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeCCHQ => "CCHQ",
+			Self::CodeCCCH => "CCCH",
+			Self::CodeBCHQ => "BCHQ",
+			Self::CodeDRFT => "DRFT",
+			Self::CodeELDR => "ELDR",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"CCHQ" => Self::CodeCCHQ,
+			"CCCH" => Self::CodeCCCH,
+			"BCHQ" => Self::CodeBCHQ,
+			"DRFT" => Self::CodeDRFT,
+			"ELDR" => Self::CodeELDR,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::ClaimNonReceipt2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DtPrcd") { one(&self.dt_prcd, step, out) }
+		if step.matches("OrgnlNxtAgt") { optional(&self.orgnl_nxt_agt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DtPrcd") { one_mut(&mut self.dt_prcd, step, out) }
+		if step.matches("OrgnlNxtAgt") { optional_mut(&mut self.orgnl_nxt_agt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ClaimNonReceipt2Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Accptd") { optional(&self.accptd, step, out) }
+		if step.matches("Rjctd") { optional(&self.rjctd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Accptd") { optional_mut(&mut self.accptd, step, create, out) }
+		if step.matches("Rjctd") { optional_mut(&mut self.rjctd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ClaimNonReceiptRejectReason1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ClearingChannel2Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeRTGS => "RTGS",
+			Self::CodeRTNS => "RTNS",
+			Self::CodeMPNS => "MPNS",
+			Self::CodeBOOK => "BOOK",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"RTGS" => Self::CodeRTGS,
+			"RTNS" => Self::CodeRTNS,
+			"MPNS" => Self::CodeMPNS,
+			"BOOK" => Self::CodeBOOK,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::ClearingSystemIdentification2Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ClearingSystemIdentification3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ClearingSystemMemberIdentification2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ClrSysId") { optional(&self.clr_sys_id, step, out) }
+		if step.matches("MmbId") { one(&self.mmb_id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ClrSysId") { optional_mut(&mut self.clr_sys_id, step, create, out) }
+		if step.matches("MmbId") { one_mut(&mut self.mmb_id, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::Compensation2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("DbtrAgt") { one(&self.dbtr_agt, step, out) }
+		if step.matches("CdtrAgt") { one(&self.cdtr_agt, step, out) }
+		if step.matches("Rsn") { one(&self.rsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("DbtrAgt") { one_mut(&mut self.dbtr_agt, step, out) }
+		if step.matches("CdtrAgt") { one_mut(&mut self.cdtr_agt, step, out) }
+		if step.matches("Rsn") { one_mut(&mut self.rsn, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::CompensationReason1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Contact4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("NmPrfx") { optional(&self.nm_prfx, step, out) }
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+		if step.matches("PhneNb") { optional(&self.phne_nb, step, out) }
+		if step.matches("MobNb") { optional(&self.mob_nb, step, out) }
+		if step.matches("FaxNb") { optional(&self.fax_nb, step, out) }
+		if step.matches("EmailAdr") { optional(&self.email_adr, step, out) }
+		if step.matches("EmailPurp") { optional(&self.email_purp, step, out) }
+		if step.matches("JobTitl") { optional(&self.job_titl, step, out) }
+		if step.matches("Rspnsblty") { optional(&self.rspnsblty, step, out) }
+		if step.matches("Dept") { optional(&self.dept, step, out) }
+		if step.matches("Othr") { optional_repeated(&self.othr, step, out) }
+		if step.matches("PrefrdMtd") { optional(&self.prefrd_mtd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("NmPrfx") { optional_mut(&mut self.nm_prfx, step, create, out) }
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+		if step.matches("PhneNb") { optional_mut(&mut self.phne_nb, step, create, out) }
+		if step.matches("MobNb") { optional_mut(&mut self.mob_nb, step, create, out) }
+		if step.matches("FaxNb") { optional_mut(&mut self.fax_nb, step, create, out) }
+		if step.matches("EmailAdr") { optional_mut(&mut self.email_adr, step, create, out) }
+		if step.matches("EmailPurp") { optional_mut(&mut self.email_purp, step, create, out) }
+		if step.matches("JobTitl") { optional_mut(&mut self.job_titl, step, create, out) }
+		if step.matches("Rspnsblty") { optional_mut(&mut self.rspnsblty, step, create, out) }
+		if step.matches("Dept") { optional_mut(&mut self.dept, step, create, out) }
+		if step.matches("Othr") { optional_repeated_mut(&mut self.othr, step, create, out) }
+		if step.matches("PrefrdMtd") { optional_mut(&mut self.prefrd_mtd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ControlData1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("NbOfTxs") { one(&self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional(&self.ctrl_sum, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("NbOfTxs") { one_mut(&mut self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional_mut(&mut self.ctrl_sum, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CopyDuplicate1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeCODU => "CODU",
+			Self::CodeCOPY => "COPY",
+			Self::CodeDUPL => "DUPL",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"CODU" => Self::CodeCODU,
+			"COPY" => Self::CodeCOPY,
+			"DUPL" => Self::CodeDUPL,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::CorporateAction9 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("EvtTp") { one(&self.evt_tp, step, out) }
+		if step.matches("EvtId") { one(&self.evt_id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("EvtTp") { one_mut(&mut self.evt_tp, step, out) }
+		if step.matches("EvtId") { one_mut(&mut self.evt_id, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::CorrectiveGroupInformation1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("MsgNmId") { one(&self.msg_nm_id, step, out) }
+		if step.matches("CreDtTm") { optional(&self.cre_dt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("MsgNmId") { one_mut(&mut self.msg_nm_id, step, out) }
+		if step.matches("CreDtTm") { optional_mut(&mut self.cre_dt_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CorrectiveInterbankTransaction2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { optional(&self.grp_hdr, step, out) }
+		if step.matches("InstrId") { optional(&self.instr_id, step, out) }
+		if step.matches("EndToEndId") { optional(&self.end_to_end_id, step, out) }
+		if step.matches("TxId") { optional(&self.tx_id, step, out) }
+		if step.matches("UETR") { optional(&self.uetr, step, out) }
+		if step.matches("IntrBkSttlmAmt") { one(&self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { one(&self.intr_bk_sttlm_dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { optional_mut(&mut self.grp_hdr, step, create, out) }
+		if step.matches("InstrId") { optional_mut(&mut self.instr_id, step, create, out) }
+		if step.matches("EndToEndId") { optional_mut(&mut self.end_to_end_id, step, create, out) }
+		if step.matches("TxId") { optional_mut(&mut self.tx_id, step, create, out) }
+		if step.matches("UETR") { optional_mut(&mut self.uetr, step, create, out) }
+		if step.matches("IntrBkSttlmAmt") { one_mut(&mut self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { one_mut(&mut self.intr_bk_sttlm_dt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::CorrectivePaymentInitiation4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { optional(&self.grp_hdr, step, out) }
+		if step.matches("PmtInfId") { optional(&self.pmt_inf_id, step, out) }
+		if step.matches("InstrId") { optional(&self.instr_id, step, out) }
+		if step.matches("EndToEndId") { optional(&self.end_to_end_id, step, out) }
+		if step.matches("UETR") { optional(&self.uetr, step, out) }
+		if step.matches("InstdAmt") { one(&self.instd_amt, step, out) }
+		if step.matches("ReqdExctnDt") { optional(&self.reqd_exctn_dt, step, out) }
+		if step.matches("ReqdColltnDt") { optional(&self.reqd_colltn_dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { optional_mut(&mut self.grp_hdr, step, create, out) }
+		if step.matches("PmtInfId") { optional_mut(&mut self.pmt_inf_id, step, create, out) }
+		if step.matches("InstrId") { optional_mut(&mut self.instr_id, step, create, out) }
+		if step.matches("EndToEndId") { optional_mut(&mut self.end_to_end_id, step, create, out) }
+		if step.matches("UETR") { optional_mut(&mut self.uetr, step, create, out) }
+		if step.matches("InstdAmt") { one_mut(&mut self.instd_amt, step, out) }
+		if step.matches("ReqdExctnDt") { optional_mut(&mut self.reqd_exctn_dt, step, create, out) }
+		if step.matches("ReqdColltnDt") { optional_mut(&mut self.reqd_colltn_dt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CorrectiveTransaction4Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Initn") { optional(&self.initn, step, out) }
+		if step.matches("IntrBk") { optional(&self.intr_bk, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Initn") { optional_mut(&mut self.initn, step, create, out) }
+		if step.matches("IntrBk") { optional_mut(&mut self.intr_bk, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditDebitCode {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeCRDT => "CRDT",
+			Self::CodeDBIT => "DBIT",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"CRDT" => Self::CodeCRDT,
+			"DBIT" => Self::CodeDBIT,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::CreditLine3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Incl") { one(&self.incl, step, out) }
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+		if step.matches("Dt") { optional(&self.dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Incl") { one_mut(&mut self.incl, step, out) }
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+		if step.matches("Dt") { optional_mut(&mut self.dt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditLineType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditTransferMandateData1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MndtId") { optional(&self.mndt_id, step, out) }
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("DtOfSgntr") { optional(&self.dt_of_sgntr, step, out) }
+		if step.matches("DtOfVrfctn") { optional(&self.dt_of_vrfctn, step, out) }
+		if step.matches("ElctrncSgntr") { optional(&self.elctrnc_sgntr, step, out) }
+		if step.matches("FrstPmtDt") { optional(&self.frst_pmt_dt, step, out) }
+		if step.matches("FnlPmtDt") { optional(&self.fnl_pmt_dt, step, out) }
+		if step.matches("Frqcy") { optional(&self.frqcy, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MndtId") { optional_mut(&mut self.mndt_id, step, create, out) }
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("DtOfSgntr") { optional_mut(&mut self.dt_of_sgntr, step, create, out) }
+		if step.matches("DtOfVrfctn") { optional_mut(&mut self.dt_of_vrfctn, step, create, out) }
+		if step.matches("ElctrncSgntr") { optional_mut(&mut self.elctrnc_sgntr, step, create, out) }
+		if step.matches("FrstPmtDt") { optional_mut(&mut self.frst_pmt_dt, step, create, out) }
+		if step.matches("FnlPmtDt") { optional_mut(&mut self.fnl_pmt_dt, step, create, out) }
+		if step.matches("Frqcy") { optional_mut(&mut self.frqcy, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditTransferTransaction35 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PmtId") { one(&self.pmt_id, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("PmtCond") { optional(&self.pmt_cond, step, out) }
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("ChrgBr") { one(&self.chrg_br, step, out) }
+		if step.matches("ChqInstr") { optional(&self.chq_instr, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("IntrmyAgt1") { optional(&self.intrmy_agt1, step, out) }
+		if step.matches("IntrmyAgt2") { optional(&self.intrmy_agt2, step, out) }
+		if step.matches("IntrmyAgt3") { optional(&self.intrmy_agt3, step, out) }
+		if step.matches("CdtrAgt") { one(&self.cdtr_agt, step, out) }
+		if step.matches("Cdtr") { one(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated(&self.instr_for_cdtr_agt, step, out) }
+		if step.matches("Purp") { optional(&self.purp, step, out) }
+		if step.matches("RgltryRptg") { optional_repeated(&self.rgltry_rptg, step, out) }
+		if step.matches("Tax") { optional(&self.tax, step, out) }
+		if step.matches("RltdRmtInf") { optional_repeated(&self.rltd_rmt_inf, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("NclsdFile") { optional_repeated(&self.nclsd_file, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PmtId") { one_mut(&mut self.pmt_id, step, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("PmtCond") { optional_mut(&mut self.pmt_cond, step, create, out) }
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("ChrgBr") { one_mut(&mut self.chrg_br, step, out) }
+		if step.matches("ChqInstr") { optional_mut(&mut self.chq_instr, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("IntrmyAgt1") { optional_mut(&mut self.intrmy_agt1, step, create, out) }
+		if step.matches("IntrmyAgt2") { optional_mut(&mut self.intrmy_agt2, step, create, out) }
+		if step.matches("IntrmyAgt3") { optional_mut(&mut self.intrmy_agt3, step, create, out) }
+		if step.matches("CdtrAgt") { one_mut(&mut self.cdtr_agt, step, out) }
+		if step.matches("Cdtr") { one_mut(&mut self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated_mut(&mut self.instr_for_cdtr_agt, step, create, out) }
+		if step.matches("Purp") { optional_mut(&mut self.purp, step, create, out) }
+		if step.matches("RgltryRptg") { optional_repeated_mut(&mut self.rgltry_rptg, step, create, out) }
+		if step.matches("Tax") { optional_mut(&mut self.tax, step, create, out) }
+		if step.matches("RltdRmtInf") { optional_repeated_mut(&mut self.rltd_rmt_inf, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("NclsdFile") { optional_repeated_mut(&mut self.nclsd_file, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditTransferTransaction36 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PmtId") { one(&self.pmt_id, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("IntrBkSttlmAmt") { one(&self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("SttlmPrty") { optional(&self.sttlm_prty, step, out) }
+		if step.matches("SttlmTmIndctn") { optional(&self.sttlm_tm_indctn, step, out) }
+		if step.matches("SttlmTmReq") { optional(&self.sttlm_tm_req, step, out) }
+		if step.matches("PrvsInstgAgt1") { optional(&self.prvs_instg_agt1, step, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional(&self.prvs_instg_agt1_acct, step, out) }
+		if step.matches("PrvsInstgAgt2") { optional(&self.prvs_instg_agt2, step, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional(&self.prvs_instg_agt2_acct, step, out) }
+		if step.matches("PrvsInstgAgt3") { optional(&self.prvs_instg_agt3, step, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional(&self.prvs_instg_agt3_acct, step, out) }
+		if step.matches("InstgAgt") { optional(&self.instg_agt, step, out) }
+		if step.matches("InstdAgt") { optional(&self.instd_agt, step, out) }
+		if step.matches("IntrmyAgt1") { optional(&self.intrmy_agt1, step, out) }
+		if step.matches("IntrmyAgt1Acct") { optional(&self.intrmy_agt1_acct, step, out) }
+		if step.matches("IntrmyAgt2") { optional(&self.intrmy_agt2, step, out) }
+		if step.matches("IntrmyAgt2Acct") { optional(&self.intrmy_agt2_acct, step, out) }
+		if step.matches("IntrmyAgt3") { optional(&self.intrmy_agt3, step, out) }
+		if step.matches("IntrmyAgt3Acct") { optional(&self.intrmy_agt3_acct, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("Dbtr") { one(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional(&self.dbtr_agt_acct, step, out) }
+		if step.matches("CdtrAgt") { optional(&self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional(&self.cdtr_agt_acct, step, out) }
+		if step.matches("Cdtr") { one(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated(&self.instr_for_cdtr_agt, step, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated(&self.instr_for_nxt_agt, step, out) }
+		if step.matches("Purp") { optional(&self.purp, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("UndrlygCstmrCdtTrf") { optional(&self.undrlyg_cstmr_cdt_trf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PmtId") { one_mut(&mut self.pmt_id, step, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("IntrBkSttlmAmt") { one_mut(&mut self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("SttlmPrty") { optional_mut(&mut self.sttlm_prty, step, create, out) }
+		if step.matches("SttlmTmIndctn") { optional_mut(&mut self.sttlm_tm_indctn, step, create, out) }
+		if step.matches("SttlmTmReq") { optional_mut(&mut self.sttlm_tm_req, step, create, out) }
+		if step.matches("PrvsInstgAgt1") { optional_mut(&mut self.prvs_instg_agt1, step, create, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional_mut(&mut self.prvs_instg_agt1_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt2") { optional_mut(&mut self.prvs_instg_agt2, step, create, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional_mut(&mut self.prvs_instg_agt2_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt3") { optional_mut(&mut self.prvs_instg_agt3, step, create, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional_mut(&mut self.prvs_instg_agt3_acct, step, create, out) }
+		if step.matches("InstgAgt") { optional_mut(&mut self.instg_agt, step, create, out) }
+		if step.matches("InstdAgt") { optional_mut(&mut self.instd_agt, step, create, out) }
+		if step.matches("IntrmyAgt1") { optional_mut(&mut self.intrmy_agt1, step, create, out) }
+		if step.matches("IntrmyAgt1Acct") { optional_mut(&mut self.intrmy_agt1_acct, step, create, out) }
+		if step.matches("IntrmyAgt2") { optional_mut(&mut self.intrmy_agt2, step, create, out) }
+		if step.matches("IntrmyAgt2Acct") { optional_mut(&mut self.intrmy_agt2_acct, step, create, out) }
+		if step.matches("IntrmyAgt3") { optional_mut(&mut self.intrmy_agt3, step, create, out) }
+		if step.matches("IntrmyAgt3Acct") { optional_mut(&mut self.intrmy_agt3_acct, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("Dbtr") { one_mut(&mut self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("DbtrAgtAcct") { optional_mut(&mut self.dbtr_agt_acct, step, create, out) }
+		if step.matches("CdtrAgt") { optional_mut(&mut self.cdtr_agt, step, create, out) }
+		if step.matches("CdtrAgtAcct") { optional_mut(&mut self.cdtr_agt_acct, step, create, out) }
+		if step.matches("Cdtr") { one_mut(&mut self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated_mut(&mut self.instr_for_cdtr_agt, step, create, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated_mut(&mut self.instr_for_nxt_agt, step, create, out) }
+		if step.matches("Purp") { optional_mut(&mut self.purp, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("UndrlygCstmrCdtTrf") { optional_mut(&mut self.undrlyg_cstmr_cdt_trf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditTransferTransaction37 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("InitgPty") { optional(&self.initg_pty, step, out) }
+		if step.matches("Dbtr") { one(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { one(&self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional(&self.dbtr_agt_acct, step, out) }
+		if step.matches("PrvsInstgAgt1") { optional(&self.prvs_instg_agt1, step, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional(&self.prvs_instg_agt1_acct, step, out) }
+		if step.matches("PrvsInstgAgt2") { optional(&self.prvs_instg_agt2, step, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional(&self.prvs_instg_agt2_acct, step, out) }
+		if step.matches("PrvsInstgAgt3") { optional(&self.prvs_instg_agt3, step, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional(&self.prvs_instg_agt3_acct, step, out) }
+		if step.matches("IntrmyAgt1") { optional(&self.intrmy_agt1, step, out) }
+		if step.matches("IntrmyAgt1Acct") { optional(&self.intrmy_agt1_acct, step, out) }
+		if step.matches("IntrmyAgt2") { optional(&self.intrmy_agt2, step, out) }
+		if step.matches("IntrmyAgt2Acct") { optional(&self.intrmy_agt2_acct, step, out) }
+		if step.matches("IntrmyAgt3") { optional(&self.intrmy_agt3, step, out) }
+		if step.matches("IntrmyAgt3Acct") { optional(&self.intrmy_agt3_acct, step, out) }
+		if step.matches("CdtrAgt") { one(&self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional(&self.cdtr_agt_acct, step, out) }
+		if step.matches("Cdtr") { one(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated(&self.instr_for_cdtr_agt, step, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated(&self.instr_for_nxt_agt, step, out) }
+		if step.matches("Tax") { optional(&self.tax, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("InstdAmt") { optional(&self.instd_amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("InitgPty") { optional_mut(&mut self.initg_pty, step, create, out) }
+		if step.matches("Dbtr") { one_mut(&mut self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { one_mut(&mut self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional_mut(&mut self.dbtr_agt_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt1") { optional_mut(&mut self.prvs_instg_agt1, step, create, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional_mut(&mut self.prvs_instg_agt1_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt2") { optional_mut(&mut self.prvs_instg_agt2, step, create, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional_mut(&mut self.prvs_instg_agt2_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt3") { optional_mut(&mut self.prvs_instg_agt3, step, create, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional_mut(&mut self.prvs_instg_agt3_acct, step, create, out) }
+		if step.matches("IntrmyAgt1") { optional_mut(&mut self.intrmy_agt1, step, create, out) }
+		if step.matches("IntrmyAgt1Acct") { optional_mut(&mut self.intrmy_agt1_acct, step, create, out) }
+		if step.matches("IntrmyAgt2") { optional_mut(&mut self.intrmy_agt2, step, create, out) }
+		if step.matches("IntrmyAgt2Acct") { optional_mut(&mut self.intrmy_agt2_acct, step, create, out) }
+		if step.matches("IntrmyAgt3") { optional_mut(&mut self.intrmy_agt3, step, create, out) }
+		if step.matches("IntrmyAgt3Acct") { optional_mut(&mut self.intrmy_agt3_acct, step, create, out) }
+		if step.matches("CdtrAgt") { one_mut(&mut self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional_mut(&mut self.cdtr_agt_acct, step, create, out) }
+		if step.matches("Cdtr") { one_mut(&mut self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated_mut(&mut self.instr_for_cdtr_agt, step, create, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated_mut(&mut self.instr_for_nxt_agt, step, create, out) }
+		if step.matches("Tax") { optional_mut(&mut self.tax, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("InstdAmt") { optional_mut(&mut self.instd_amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditTransferTransaction39 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PmtId") { one(&self.pmt_id, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("IntrBkSttlmAmt") { one(&self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("SttlmPrty") { optional(&self.sttlm_prty, step, out) }
+		if step.matches("SttlmTmIndctn") { optional(&self.sttlm_tm_indctn, step, out) }
+		if step.matches("SttlmTmReq") { optional(&self.sttlm_tm_req, step, out) }
+		if step.matches("AccptncDtTm") { optional(&self.accptnc_dt_tm, step, out) }
+		if step.matches("PoolgAdjstmntDt") { optional(&self.poolg_adjstmnt_dt, step, out) }
+		if step.matches("InstdAmt") { optional(&self.instd_amt, step, out) }
+		if step.matches("XchgRate") { optional(&self.xchg_rate, step, out) }
+		if step.matches("ChrgBr") { one(&self.chrg_br, step, out) }
+		if step.matches("ChrgsInf") { optional_repeated(&self.chrgs_inf, step, out) }
+		if step.matches("PrvsInstgAgt1") { optional(&self.prvs_instg_agt1, step, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional(&self.prvs_instg_agt1_acct, step, out) }
+		if step.matches("PrvsInstgAgt2") { optional(&self.prvs_instg_agt2, step, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional(&self.prvs_instg_agt2_acct, step, out) }
+		if step.matches("PrvsInstgAgt3") { optional(&self.prvs_instg_agt3, step, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional(&self.prvs_instg_agt3_acct, step, out) }
+		if step.matches("InstgAgt") { optional(&self.instg_agt, step, out) }
+		if step.matches("InstdAgt") { optional(&self.instd_agt, step, out) }
+		if step.matches("IntrmyAgt1") { optional(&self.intrmy_agt1, step, out) }
+		if step.matches("IntrmyAgt1Acct") { optional(&self.intrmy_agt1_acct, step, out) }
+		if step.matches("IntrmyAgt2") { optional(&self.intrmy_agt2, step, out) }
+		if step.matches("IntrmyAgt2Acct") { optional(&self.intrmy_agt2_acct, step, out) }
+		if step.matches("IntrmyAgt3") { optional(&self.intrmy_agt3, step, out) }
+		if step.matches("IntrmyAgt3Acct") { optional(&self.intrmy_agt3_acct, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("InitgPty") { optional(&self.initg_pty, step, out) }
+		if step.matches("Dbtr") { one(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { one(&self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional(&self.dbtr_agt_acct, step, out) }
+		if step.matches("CdtrAgt") { one(&self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional(&self.cdtr_agt_acct, step, out) }
+		if step.matches("Cdtr") { one(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated(&self.instr_for_cdtr_agt, step, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated(&self.instr_for_nxt_agt, step, out) }
+		if step.matches("Purp") { optional(&self.purp, step, out) }
+		if step.matches("RgltryRptg") { optional_repeated(&self.rgltry_rptg, step, out) }
+		if step.matches("Tax") { optional(&self.tax, step, out) }
+		if step.matches("RltdRmtInf") { optional_repeated(&self.rltd_rmt_inf, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PmtId") { one_mut(&mut self.pmt_id, step, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("IntrBkSttlmAmt") { one_mut(&mut self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("SttlmPrty") { optional_mut(&mut self.sttlm_prty, step, create, out) }
+		if step.matches("SttlmTmIndctn") { optional_mut(&mut self.sttlm_tm_indctn, step, create, out) }
+		if step.matches("SttlmTmReq") { optional_mut(&mut self.sttlm_tm_req, step, create, out) }
+		if step.matches("AccptncDtTm") { optional_mut(&mut self.accptnc_dt_tm, step, create, out) }
+		if step.matches("PoolgAdjstmntDt") { optional_mut(&mut self.poolg_adjstmnt_dt, step, create, out) }
+		if step.matches("InstdAmt") { optional_mut(&mut self.instd_amt, step, create, out) }
+		if step.matches("XchgRate") { optional_mut(&mut self.xchg_rate, step, create, out) }
+		if step.matches("ChrgBr") { one_mut(&mut self.chrg_br, step, out) }
+		if step.matches("ChrgsInf") { optional_repeated_mut(&mut self.chrgs_inf, step, create, out) }
+		if step.matches("PrvsInstgAgt1") { optional_mut(&mut self.prvs_instg_agt1, step, create, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional_mut(&mut self.prvs_instg_agt1_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt2") { optional_mut(&mut self.prvs_instg_agt2, step, create, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional_mut(&mut self.prvs_instg_agt2_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt3") { optional_mut(&mut self.prvs_instg_agt3, step, create, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional_mut(&mut self.prvs_instg_agt3_acct, step, create, out) }
+		if step.matches("InstgAgt") { optional_mut(&mut self.instg_agt, step, create, out) }
+		if step.matches("InstdAgt") { optional_mut(&mut self.instd_agt, step, create, out) }
+		if step.matches("IntrmyAgt1") { optional_mut(&mut self.intrmy_agt1, step, create, out) }
+		if step.matches("IntrmyAgt1Acct") { optional_mut(&mut self.intrmy_agt1_acct, step, create, out) }
+		if step.matches("IntrmyAgt2") { optional_mut(&mut self.intrmy_agt2, step, create, out) }
+		if step.matches("IntrmyAgt2Acct") { optional_mut(&mut self.intrmy_agt2_acct, step, create, out) }
+		if step.matches("IntrmyAgt3") { optional_mut(&mut self.intrmy_agt3, step, create, out) }
+		if step.matches("IntrmyAgt3Acct") { optional_mut(&mut self.intrmy_agt3_acct, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("InitgPty") { optional_mut(&mut self.initg_pty, step, create, out) }
+		if step.matches("Dbtr") { one_mut(&mut self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { one_mut(&mut self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional_mut(&mut self.dbtr_agt_acct, step, create, out) }
+		if step.matches("CdtrAgt") { one_mut(&mut self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional_mut(&mut self.cdtr_agt_acct, step, create, out) }
+		if step.matches("Cdtr") { one_mut(&mut self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated_mut(&mut self.instr_for_cdtr_agt, step, create, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated_mut(&mut self.instr_for_nxt_agt, step, create, out) }
+		if step.matches("Purp") { optional_mut(&mut self.purp, step, create, out) }
+		if step.matches("RgltryRptg") { optional_repeated_mut(&mut self.rgltry_rptg, step, create, out) }
+		if step.matches("Tax") { optional_mut(&mut self.tax, step, create, out) }
+		if step.matches("RltdRmtInf") { optional_repeated_mut(&mut self.rltd_rmt_inf, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditTransferTransaction45 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("InitgPty") { optional(&self.initg_pty, step, out) }
+		if step.matches("Dbtr") { one(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { one(&self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional(&self.dbtr_agt_acct, step, out) }
+		if step.matches("PrvsInstgAgt1") { optional(&self.prvs_instg_agt1, step, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional(&self.prvs_instg_agt1_acct, step, out) }
+		if step.matches("PrvsInstgAgt2") { optional(&self.prvs_instg_agt2, step, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional(&self.prvs_instg_agt2_acct, step, out) }
+		if step.matches("PrvsInstgAgt3") { optional(&self.prvs_instg_agt3, step, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional(&self.prvs_instg_agt3_acct, step, out) }
+		if step.matches("IntrmyAgt1") { optional(&self.intrmy_agt1, step, out) }
+		if step.matches("IntrmyAgt1Acct") { optional(&self.intrmy_agt1_acct, step, out) }
+		if step.matches("IntrmyAgt2") { optional(&self.intrmy_agt2, step, out) }
+		if step.matches("IntrmyAgt2Acct") { optional(&self.intrmy_agt2_acct, step, out) }
+		if step.matches("IntrmyAgt3") { optional(&self.intrmy_agt3, step, out) }
+		if step.matches("IntrmyAgt3Acct") { optional(&self.intrmy_agt3_acct, step, out) }
+		if step.matches("CdtrAgt") { one(&self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional(&self.cdtr_agt_acct, step, out) }
+		if step.matches("Cdtr") { one(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated(&self.instr_for_cdtr_agt, step, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated(&self.instr_for_nxt_agt, step, out) }
+		if step.matches("Tax") { optional(&self.tax, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("InstdAmt") { optional(&self.instd_amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("InitgPty") { optional_mut(&mut self.initg_pty, step, create, out) }
+		if step.matches("Dbtr") { one_mut(&mut self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { one_mut(&mut self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional_mut(&mut self.dbtr_agt_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt1") { optional_mut(&mut self.prvs_instg_agt1, step, create, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional_mut(&mut self.prvs_instg_agt1_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt2") { optional_mut(&mut self.prvs_instg_agt2, step, create, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional_mut(&mut self.prvs_instg_agt2_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt3") { optional_mut(&mut self.prvs_instg_agt3, step, create, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional_mut(&mut self.prvs_instg_agt3_acct, step, create, out) }
+		if step.matches("IntrmyAgt1") { optional_mut(&mut self.intrmy_agt1, step, create, out) }
+		if step.matches("IntrmyAgt1Acct") { optional_mut(&mut self.intrmy_agt1_acct, step, create, out) }
+		if step.matches("IntrmyAgt2") { optional_mut(&mut self.intrmy_agt2, step, create, out) }
+		if step.matches("IntrmyAgt2Acct") { optional_mut(&mut self.intrmy_agt2_acct, step, create, out) }
+		if step.matches("IntrmyAgt3") { optional_mut(&mut self.intrmy_agt3, step, create, out) }
+		if step.matches("IntrmyAgt3Acct") { optional_mut(&mut self.intrmy_agt3_acct, step, create, out) }
+		if step.matches("CdtrAgt") { one_mut(&mut self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional_mut(&mut self.cdtr_agt_acct, step, create, out) }
+		if step.matches("Cdtr") { one_mut(&mut self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated_mut(&mut self.instr_for_cdtr_agt, step, create, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated_mut(&mut self.instr_for_nxt_agt, step, create, out) }
+		if step.matches("Tax") { optional_mut(&mut self.tax, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("InstdAmt") { optional_mut(&mut self.instd_amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditorReferenceInformation2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Ref") { optional(&self.ref_attr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Ref") { optional_mut(&mut self.ref_attr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditorReferenceType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CreditorReferenceType2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one(&self.cd_or_prtry, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one_mut(&mut self.cd_or_prtry, step, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::CurrencyExchange5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("SrcCcy") { one(&self.src_ccy, step, out) }
+		if step.matches("TrgtCcy") { optional(&self.trgt_ccy, step, out) }
+		if step.matches("UnitCcy") { optional(&self.unit_ccy, step, out) }
+		if step.matches("XchgRate") { one(&self.xchg_rate, step, out) }
+		if step.matches("CtrctId") { optional(&self.ctrct_id, step, out) }
+		if step.matches("QtnDt") { optional(&self.qtn_dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("SrcCcy") { one_mut(&mut self.src_ccy, step, out) }
+		if step.matches("TrgtCcy") { optional_mut(&mut self.trgt_ccy, step, create, out) }
+		if step.matches("UnitCcy") { optional_mut(&mut self.unit_ccy, step, create, out) }
+		if step.matches("XchgRate") { one_mut(&mut self.xchg_rate, step, out) }
+		if step.matches("CtrctId") { optional_mut(&mut self.ctrct_id, step, create, out) }
+		if step.matches("QtnDt") { optional_mut(&mut self.qtn_dt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DateAndDateTime2Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Dt") { optional(&self.dt, step, out) }
+		if step.matches("DtTm") { optional(&self.dt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Dt") { optional_mut(&mut self.dt, step, create, out) }
+		if step.matches("DtTm") { optional_mut(&mut self.dt_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DateAndPlaceOfBirth1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("BirthDt") { one(&self.birth_dt, step, out) }
+		if step.matches("PrvcOfBirth") { optional(&self.prvc_of_birth, step, out) }
+		if step.matches("CityOfBirth") { one(&self.city_of_birth, step, out) }
+		if step.matches("CtryOfBirth") { one(&self.ctry_of_birth, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("BirthDt") { one_mut(&mut self.birth_dt, step, out) }
+		if step.matches("PrvcOfBirth") { optional_mut(&mut self.prvc_of_birth, step, create, out) }
+		if step.matches("CityOfBirth") { one_mut(&mut self.city_of_birth, step, out) }
+		if step.matches("CtryOfBirth") { one_mut(&mut self.ctry_of_birth, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::DateOrDateTimePeriod1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Dt") { optional(&self.dt, step, out) }
+		if step.matches("DtTm") { optional(&self.dt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Dt") { optional_mut(&mut self.dt, step, create, out) }
+		if step.matches("DtTm") { optional_mut(&mut self.dt_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DatePeriod2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrDt") { one(&self.fr_dt, step, out) }
+		if step.matches("ToDt") { one(&self.to_dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrDt") { one_mut(&mut self.fr_dt, step, out) }
+		if step.matches("ToDt") { one_mut(&mut self.to_dt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::DatePeriodDetails1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrDt") { one(&self.fr_dt, step, out) }
+		if step.matches("ToDt") { optional(&self.to_dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrDt") { one_mut(&mut self.fr_dt, step, out) }
+		if step.matches("ToDt") { optional_mut(&mut self.to_dt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DateTimePeriod1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrDtTm") { one(&self.fr_dt_tm, step, out) }
+		if step.matches("ToDtTm") { one(&self.to_dt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrDtTm") { one_mut(&mut self.fr_dt_tm, step, out) }
+		if step.matches("ToDtTm") { one_mut(&mut self.to_dt_tm, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::DiscountAmountAndType1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Amt") { one(&self.amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::DiscountAmountType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DisplayCapabilities1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DispTp") { one(&self.disp_tp, step, out) }
+		if step.matches("NbOfLines") { one(&self.nb_of_lines, step, out) }
+		if step.matches("LineWidth") { one(&self.line_width, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DispTp") { one_mut(&mut self.disp_tp, step, out) }
+		if step.matches("NbOfLines") { one_mut(&mut self.nb_of_lines, step, out) }
+		if step.matches("LineWidth") { one_mut(&mut self.line_width, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::Document12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("IsseDt") { one(&self.isse_dt, step, out) }
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+		if step.matches("LangCd") { optional(&self.lang_cd, step, out) }
+		if step.matches("Frmt") { one(&self.frmt, step, out) }
+		if step.matches("FileNm") { optional(&self.file_nm, step, out) }
+		if step.matches("DgtlSgntr") { optional(&self.dgtl_sgntr, step, out) }
+		if step.matches("Nclsr") { one(&self.nclsr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("IsseDt") { one_mut(&mut self.isse_dt, step, out) }
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+		if step.matches("LangCd") { optional_mut(&mut self.lang_cd, step, create, out) }
+		if step.matches("Frmt") { one_mut(&mut self.frmt, step, out) }
+		if step.matches("FileNm") { optional_mut(&mut self.file_nm, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("Nclsr") { one_mut(&mut self.nclsr, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::DocumentAdjustment1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { optional(&self.cdt_dbt_ind, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("AddtlInf") { optional(&self.addtl_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CdtDbtInd") { optional_mut(&mut self.cdt_dbt_ind, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("AddtlInf") { optional_mut(&mut self.addtl_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DocumentFormat1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DocumentLineIdentification1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Nb") { optional(&self.nb, step, out) }
+		if step.matches("RltdDt") { optional(&self.rltd_dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Nb") { optional_mut(&mut self.nb, step, create, out) }
+		if step.matches("RltdDt") { optional_mut(&mut self.rltd_dt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DocumentLineInformation1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { repeated(&self.id, step, out) }
+		if step.matches("Desc") { optional(&self.desc, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { repeated_mut(&mut self.id, step, out) }
+		if step.matches("Desc") { optional_mut(&mut self.desc, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DocumentLineType1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one(&self.cd_or_prtry, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one_mut(&mut self.cd_or_prtry, step, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DocumentLineType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DocumentType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::DocumentType3Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeRADM => "RADM",
+			Self::CodeRPIN => "RPIN",
+			Self::CodeFXDR => "FXDR",
+			Self::CodeDISP => "DISP",
+			Self::CodePUOR => "PUOR",
+			Self::CodeSCOR => "SCOR",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"RADM" => Self::CodeRADM,
+			"RPIN" => Self::CodeRPIN,
+			"FXDR" => Self::CodeFXDR,
+			"DISP" => Self::CodeDISP,
+			"PUOR" => Self::CodePUOR,
+			"SCOR" => Self::CodeSCOR,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::DocumentType6Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeMSIN => "MSIN",
+			Self::CodeCNFA => "CNFA",
+			Self::CodeDNFA => "DNFA",
+			Self::CodeCINV => "CINV",
+			Self::CodeCREN => "CREN",
+			Self::CodeDEBN => "DEBN",
+			Self::CodeHIRI => "HIRI",
+			Self::CodeSBIN => "SBIN",
+			Self::CodeCMCN => "CMCN",
+			Self::CodeSOAC => "SOAC",
+			Self::CodeDISP => "DISP",
+			Self::CodeBOLD => "BOLD",
+			Self::CodeVCHR => "VCHR",
+			Self::CodeAROI => "AROI",
+			Self::CodeTSUT => "TSUT",
+			Self::CodePUOR => "PUOR",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"MSIN" => Self::CodeMSIN,
+			"CNFA" => Self::CodeCNFA,
+			"DNFA" => Self::CodeDNFA,
+			"CINV" => Self::CodeCINV,
+			"CREN" => Self::CodeCREN,
+			"DEBN" => Self::CodeDEBN,
+			"HIRI" => Self::CodeHIRI,
+			"SBIN" => Self::CodeSBIN,
+			"CMCN" => Self::CodeCMCN,
+			"SOAC" => Self::CodeSOAC,
+			"DISP" => Self::CodeDISP,
+			"BOLD" => Self::CodeBOLD,
+			"VCHR" => Self::CodeVCHR,
+			"AROI" => Self::CodeAROI,
+			"TSUT" => Self::CodeTSUT,
+			"PUOR" => Self::CodePUOR,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::EntryDetails9 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Btch") { optional(&self.btch, step, out) }
+		if step.matches("TxDtls") { optional_repeated(&self.tx_dtls, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Btch") { optional_mut(&mut self.btch, step, create, out) }
+		if step.matches("TxDtls") { optional_repeated_mut(&mut self.tx_dtls, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::EntryStatus1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::EntryTransaction10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { optional(&self.refs, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { optional(&self.cdt_dbt_ind, step, out) }
+		if step.matches("AmtDtls") { optional(&self.amt_dtls, step, out) }
+		if step.matches("Avlbty") { optional_repeated(&self.avlbty, step, out) }
+		if step.matches("BkTxCd") { optional(&self.bk_tx_cd, step, out) }
+		if step.matches("Chrgs") { optional(&self.chrgs, step, out) }
+		if step.matches("Intrst") { optional(&self.intrst, step, out) }
+		if step.matches("RltdPties") { optional(&self.rltd_pties, step, out) }
+		if step.matches("RltdAgts") { optional(&self.rltd_agts, step, out) }
+		if step.matches("LclInstrm") { optional(&self.lcl_instrm, step, out) }
+		if step.matches("Purp") { optional(&self.purp, step, out) }
+		if step.matches("RltdRmtInf") { optional_repeated(&self.rltd_rmt_inf, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("RltdDts") { optional(&self.rltd_dts, step, out) }
+		if step.matches("RltdPric") { optional(&self.rltd_pric, step, out) }
+		if step.matches("RltdQties") { optional_repeated(&self.rltd_qties, step, out) }
+		if step.matches("FinInstrmId") { optional(&self.fin_instrm_id, step, out) }
+		if step.matches("Tax") { optional(&self.tax, step, out) }
+		if step.matches("RtrInf") { optional(&self.rtr_inf, step, out) }
+		if step.matches("CorpActn") { optional(&self.corp_actn, step, out) }
+		if step.matches("SfkpgAcct") { optional(&self.sfkpg_acct, step, out) }
+		if step.matches("CshDpst") { optional_repeated(&self.csh_dpst, step, out) }
+		if step.matches("CardTx") { optional(&self.card_tx, step, out) }
+		if step.matches("AddtlTxInf") { optional(&self.addtl_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { optional_mut(&mut self.refs, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+		if step.matches("CdtDbtInd") { optional_mut(&mut self.cdt_dbt_ind, step, create, out) }
+		if step.matches("AmtDtls") { optional_mut(&mut self.amt_dtls, step, create, out) }
+		if step.matches("Avlbty") { optional_repeated_mut(&mut self.avlbty, step, create, out) }
+		if step.matches("BkTxCd") { optional_mut(&mut self.bk_tx_cd, step, create, out) }
+		if step.matches("Chrgs") { optional_mut(&mut self.chrgs, step, create, out) }
+		if step.matches("Intrst") { optional_mut(&mut self.intrst, step, create, out) }
+		if step.matches("RltdPties") { optional_mut(&mut self.rltd_pties, step, create, out) }
+		if step.matches("RltdAgts") { optional_mut(&mut self.rltd_agts, step, create, out) }
+		if step.matches("LclInstrm") { optional_mut(&mut self.lcl_instrm, step, create, out) }
+		if step.matches("Purp") { optional_mut(&mut self.purp, step, create, out) }
+		if step.matches("RltdRmtInf") { optional_repeated_mut(&mut self.rltd_rmt_inf, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("RltdDts") { optional_mut(&mut self.rltd_dts, step, create, out) }
+		if step.matches("RltdPric") { optional_mut(&mut self.rltd_pric, step, create, out) }
+		if step.matches("RltdQties") { optional_repeated_mut(&mut self.rltd_qties, step, create, out) }
+		if step.matches("FinInstrmId") { optional_mut(&mut self.fin_instrm_id, step, create, out) }
+		if step.matches("Tax") { optional_mut(&mut self.tax, step, create, out) }
+		if step.matches("RtrInf") { optional_mut(&mut self.rtr_inf, step, create, out) }
+		if step.matches("CorpActn") { optional_mut(&mut self.corp_actn, step, create, out) }
+		if step.matches("SfkpgAcct") { optional_mut(&mut self.sfkpg_acct, step, create, out) }
+		if step.matches("CshDpst") { optional_repeated_mut(&mut self.csh_dpst, step, create, out) }
+		if step.matches("CardTx") { optional_mut(&mut self.card_tx, step, create, out) }
+		if step.matches("AddtlTxInf") { optional_mut(&mut self.addtl_tx_inf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::EquivalentAmount2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CcyOfTrf") { one(&self.ccy_of_trf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CcyOfTrf") { one_mut(&mut self.ccy_of_trf, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::Event1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("EvtCd") { one(&self.evt_cd, step, out) }
+		if step.matches("EvtParam") { optional_repeated(&self.evt_param, step, out) }
+		if step.matches("EvtDesc") { optional(&self.evt_desc, step, out) }
+		if step.matches("EvtTm") { optional(&self.evt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("EvtCd") { one_mut(&mut self.evt_cd, step, out) }
+		if step.matches("EvtParam") { optional_repeated_mut(&mut self.evt_param, step, create, out) }
+		if step.matches("EvtDesc") { optional_mut(&mut self.evt_desc, step, create, out) }
+		if step.matches("EvtTm") { optional_mut(&mut self.evt_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Event2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("EvtCd") { one(&self.evt_cd, step, out) }
+		if step.matches("EvtParam") { optional_repeated(&self.evt_param, step, out) }
+		if step.matches("EvtDesc") { optional(&self.evt_desc, step, out) }
+		if step.matches("EvtTm") { optional(&self.evt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("EvtCd") { one_mut(&mut self.evt_cd, step, out) }
+		if step.matches("EvtParam") { optional_repeated_mut(&mut self.evt_param, step, create, out) }
+		if step.matches("EvtDesc") { optional_mut(&mut self.evt_desc, step, create, out) }
+		if step.matches("EvtTm") { optional_mut(&mut self.evt_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FinancialIdentificationSchemeName1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FinancialInstitutionIdentification18 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("BICFI") { optional(&self.bicfi, step, out) }
+		if step.matches("ClrSysMmbId") { optional(&self.clr_sys_mmb_id, step, out) }
+		if step.matches("LEI") { optional(&self.lei, step, out) }
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+		if step.matches("PstlAdr") { optional(&self.pstl_adr, step, out) }
+		if step.matches("Othr") { optional(&self.othr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("BICFI") { optional_mut(&mut self.bicfi, step, create, out) }
+		if step.matches("ClrSysMmbId") { optional_mut(&mut self.clr_sys_mmb_id, step, create, out) }
+		if step.matches("LEI") { optional_mut(&mut self.lei, step, create, out) }
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+		if step.matches("PstlAdr") { optional_mut(&mut self.pstl_adr, step, create, out) }
+		if step.matches("Othr") { optional_mut(&mut self.othr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FinancialInstrumentQuantity1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Unit") { optional(&self.unit, step, out) }
+		if step.matches("FaceAmt") { optional(&self.face_amt, step, out) }
+		if step.matches("AmtsdVal") { optional(&self.amtsd_val, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Unit") { optional_mut(&mut self.unit, step, create, out) }
+		if step.matches("FaceAmt") { optional_mut(&mut self.face_amt, step, create, out) }
+		if step.matches("AmtsdVal") { optional_mut(&mut self.amtsd_val, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FloorLimitType1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeCRED => "CRED",
+			Self::CodeDEBT => "DEBT",
+			Self::CodeBOTH => "BOTH",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"CRED" => Self::CodeCRED,
+			"DEBT" => Self::CodeDEBT,
+			"BOTH" => Self::CodeBOTH,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Frequency36Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Prd") { optional(&self.prd, step, out) }
+		if step.matches("PtInTm") { optional(&self.pt_in_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Prd") { optional_mut(&mut self.prd, step, create, out) }
+		if step.matches("PtInTm") { optional_mut(&mut self.pt_in_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Frequency6Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeYEAR => "YEAR",
+			Self::CodeMNTH => "MNTH",
+			Self::CodeQURT => "QURT",
+			Self::CodeMIAN => "MIAN",
+			Self::CodeWEEK => "WEEK",
+			Self::CodeDAIL => "DAIL",
+			Self::CodeADHO => "ADHO",
+			Self::CodeINDA => "INDA",
+			Self::CodeFRTN => "FRTN",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"YEAR" => Self::CodeYEAR,
+			"MNTH" => Self::CodeMNTH,
+			"QURT" => Self::CodeQURT,
+			"MIAN" => Self::CodeMIAN,
+			"WEEK" => Self::CodeWEEK,
+			"DAIL" => Self::CodeDAIL,
+			"ADHO" => Self::CodeADHO,
+			"INDA" => Self::CodeINDA,
+			"FRTN" => Self::CodeFRTN,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::FrequencyAndMoment1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("PtInTm") { one(&self.pt_in_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("PtInTm") { one_mut(&mut self.pt_in_tm, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FrequencyPeriod1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("CntPerPrd") { one(&self.cnt_per_prd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("CntPerPrd") { one_mut(&mut self.cnt_per_prd, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FromToAmountRange1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrAmt") { one(&self.fr_amt, step, out) }
+		if step.matches("ToAmt") { one(&self.to_amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrAmt") { one_mut(&mut self.fr_amt, step, out) }
+		if step.matches("ToAmt") { one_mut(&mut self.to_amt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::Garnishment3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Grnshee") { optional(&self.grnshee, step, out) }
+		if step.matches("GrnshmtAdmstr") { optional(&self.grnshmt_admstr, step, out) }
+		if step.matches("RefNb") { optional(&self.ref_nb, step, out) }
+		if step.matches("Dt") { optional(&self.dt, step, out) }
+		if step.matches("RmtdAmt") { optional(&self.rmtd_amt, step, out) }
+		if step.matches("FmlyMdclInsrncInd") { optional(&self.fmly_mdcl_insrnc_ind, step, out) }
+		if step.matches("MplyeeTermntnInd") { optional(&self.mplyee_termntn_ind, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Grnshee") { optional_mut(&mut self.grnshee, step, create, out) }
+		if step.matches("GrnshmtAdmstr") { optional_mut(&mut self.grnshmt_admstr, step, create, out) }
+		if step.matches("RefNb") { optional_mut(&mut self.ref_nb, step, create, out) }
+		if step.matches("Dt") { optional_mut(&mut self.dt, step, create, out) }
+		if step.matches("RmtdAmt") { optional_mut(&mut self.rmtd_amt, step, create, out) }
+		if step.matches("FmlyMdclInsrncInd") { optional_mut(&mut self.fmly_mdcl_insrnc_ind, step, create, out) }
+		if step.matches("MplyeeTermntnInd") { optional_mut(&mut self.mplyee_termntn_ind, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GarnishmentType1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one(&self.cd_or_prtry, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one_mut(&mut self.cd_or_prtry, step, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GarnishmentType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GenericAccountIdentification1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("SchmeNm") { optional(&self.schme_nm, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("SchmeNm") { optional_mut(&mut self.schme_nm, step, create, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GenericFinancialIdentification1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("SchmeNm") { optional(&self.schme_nm, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("SchmeNm") { optional_mut(&mut self.schme_nm, step, create, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GenericIdentification1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("SchmeNm") { optional(&self.schme_nm, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("SchmeNm") { optional_mut(&mut self.schme_nm, step, create, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GenericIdentification3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GenericIdentification30 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Issr") { one(&self.issr, step, out) }
+		if step.matches("SchmeNm") { optional(&self.schme_nm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Issr") { one_mut(&mut self.issr, step, out) }
+		if step.matches("SchmeNm") { optional_mut(&mut self.schme_nm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GenericIdentification32 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+		if step.matches("ShrtNm") { optional(&self.shrt_nm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+		if step.matches("ShrtNm") { optional_mut(&mut self.shrt_nm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GenericIdentification36 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Issr") { one(&self.issr, step, out) }
+		if step.matches("SchmeNm") { optional(&self.schme_nm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Issr") { one_mut(&mut self.issr, step, out) }
+		if step.matches("SchmeNm") { optional_mut(&mut self.schme_nm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GenericOrganisationIdentification1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("SchmeNm") { optional(&self.schme_nm, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("SchmeNm") { optional_mut(&mut self.schme_nm, step, create, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GenericPersonIdentification1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("SchmeNm") { optional(&self.schme_nm, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("SchmeNm") { optional_mut(&mut self.schme_nm, step, create, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GroupCancellationStatus1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodePACR => "PACR",
+			Self::CodeRJCR => "RJCR",
+			Self::CodeACCR => "ACCR",
+			Self::CodePDCR => "PDCR",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"PACR" => Self::CodePACR,
+			"RJCR" => Self::CodeRJCR,
+			"ACCR" => Self::CodeACCR,
+			"PDCR" => Self::CodePDCR,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::GroupHeader77 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one(&self.cre_dt_tm, step, out) }
+		if step.matches("MsgSndr") { optional(&self.msg_sndr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one_mut(&mut self.cre_dt_tm, step, out) }
+		if step.matches("MsgSndr") { optional_mut(&mut self.msg_sndr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GroupHeader78 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one(&self.cre_dt_tm, step, out) }
+		if step.matches("NbOfTxs") { one(&self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional(&self.ctrl_sum, step, out) }
+		if step.matches("InitgPty") { one(&self.initg_pty, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one_mut(&mut self.cre_dt_tm, step, out) }
+		if step.matches("NbOfTxs") { one_mut(&mut self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional_mut(&mut self.ctrl_sum, step, create, out) }
+		if step.matches("InitgPty") { one_mut(&mut self.initg_pty, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::GroupHeader81 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one(&self.cre_dt_tm, step, out) }
+		if step.matches("MsgRcpt") { optional(&self.msg_rcpt, step, out) }
+		if step.matches("MsgPgntn") { optional(&self.msg_pgntn, step, out) }
+		if step.matches("OrgnlBizQry") { optional(&self.orgnl_biz_qry, step, out) }
+		if step.matches("AddtlInf") { optional(&self.addtl_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one_mut(&mut self.cre_dt_tm, step, out) }
+		if step.matches("MsgRcpt") { optional_mut(&mut self.msg_rcpt, step, create, out) }
+		if step.matches("MsgPgntn") { optional_mut(&mut self.msg_pgntn, step, create, out) }
+		if step.matches("OrgnlBizQry") { optional_mut(&mut self.orgnl_biz_qry, step, create, out) }
+		if step.matches("AddtlInf") { optional_mut(&mut self.addtl_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GroupHeader87 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one(&self.cre_dt_tm, step, out) }
+		if step.matches("InitgPty") { one(&self.initg_pty, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("CdtrAgt") { optional(&self.cdtr_agt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one_mut(&mut self.cre_dt_tm, step, out) }
+		if step.matches("InitgPty") { one_mut(&mut self.initg_pty, step, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("CdtrAgt") { optional_mut(&mut self.cdtr_agt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GroupHeader90 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one(&self.cre_dt_tm, step, out) }
+		if step.matches("Authstn") { optional_repeated(&self.authstn, step, out) }
+		if step.matches("BtchBookg") { optional(&self.btch_bookg, step, out) }
+		if step.matches("NbOfTxs") { one(&self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional(&self.ctrl_sum, step, out) }
+		if step.matches("GrpRtr") { optional(&self.grp_rtr, step, out) }
+		if step.matches("TtlRtrdIntrBkSttlmAmt") { optional(&self.ttl_rtrd_intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("SttlmInf") { one(&self.sttlm_inf, step, out) }
+		if step.matches("InstgAgt") { optional(&self.instg_agt, step, out) }
+		if step.matches("InstdAgt") { optional(&self.instd_agt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one_mut(&mut self.cre_dt_tm, step, out) }
+		if step.matches("Authstn") { optional_repeated_mut(&mut self.authstn, step, create, out) }
+		if step.matches("BtchBookg") { optional_mut(&mut self.btch_bookg, step, create, out) }
+		if step.matches("NbOfTxs") { one_mut(&mut self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional_mut(&mut self.ctrl_sum, step, create, out) }
+		if step.matches("GrpRtr") { optional_mut(&mut self.grp_rtr, step, create, out) }
+		if step.matches("TtlRtrdIntrBkSttlmAmt") { optional_mut(&mut self.ttl_rtrd_intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("SttlmInf") { one_mut(&mut self.sttlm_inf, step, out) }
+		if step.matches("InstgAgt") { optional_mut(&mut self.instg_agt, step, create, out) }
+		if step.matches("InstdAgt") { optional_mut(&mut self.instd_agt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GroupHeader91 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one(&self.cre_dt_tm, step, out) }
+		if step.matches("InstgAgt") { optional(&self.instg_agt, step, out) }
+		if step.matches("InstdAgt") { optional(&self.instd_agt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one_mut(&mut self.cre_dt_tm, step, out) }
+		if step.matches("InstgAgt") { optional_mut(&mut self.instg_agt, step, create, out) }
+		if step.matches("InstdAgt") { optional_mut(&mut self.instd_agt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::GroupHeader93 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one(&self.cre_dt_tm, step, out) }
+		if step.matches("BtchBookg") { optional(&self.btch_bookg, step, out) }
+		if step.matches("NbOfTxs") { one(&self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional(&self.ctrl_sum, step, out) }
+		if step.matches("TtlIntrBkSttlmAmt") { optional(&self.ttl_intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("SttlmInf") { one(&self.sttlm_inf, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("InstgAgt") { optional(&self.instg_agt, step, out) }
+		if step.matches("InstdAgt") { optional(&self.instd_agt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("CreDtTm") { one_mut(&mut self.cre_dt_tm, step, out) }
+		if step.matches("BtchBookg") { optional_mut(&mut self.btch_bookg, step, create, out) }
+		if step.matches("NbOfTxs") { one_mut(&mut self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional_mut(&mut self.ctrl_sum, step, create, out) }
+		if step.matches("TtlIntrBkSttlmAmt") { optional_mut(&mut self.ttl_intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("SttlmInf") { one_mut(&mut self.sttlm_inf, step, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("InstgAgt") { optional_mut(&mut self.instg_agt, step, create, out) }
+		if step.matches("InstdAgt") { optional_mut(&mut self.instd_agt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::IdentificationSource3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ImplementationSpecification1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Regy") { one(&self.regy, step, out) }
+		if step.matches("Id") { one(&self.id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Regy") { one_mut(&mut self.regy, step, out) }
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ImpliedCurrencyAmountRange1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrAmt") { optional(&self.fr_amt, step, out) }
+		if step.matches("ToAmt") { optional(&self.to_amt, step, out) }
+		if step.matches("FrToAmt") { optional(&self.fr_to_amt, step, out) }
+		if step.matches("EQAmt") { optional(&self.eq_amt, step, out) }
+		if step.matches("NEQAmt") { optional(&self.neq_amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrAmt") { optional_mut(&mut self.fr_amt, step, create, out) }
+		if step.matches("ToAmt") { optional_mut(&mut self.to_amt, step, create, out) }
+		if step.matches("FrToAmt") { optional_mut(&mut self.fr_to_amt, step, create, out) }
+		if step.matches("EQAmt") { optional_mut(&mut self.eq_amt, step, create, out) }
+		if step.matches("NEQAmt") { optional_mut(&mut self.neq_amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Instruction3Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeCHQB => "CHQB",
+			Self::CodeHOLD => "HOLD",
+			Self::CodePHOB => "PHOB",
+			Self::CodeTELB => "TELB",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"CHQB" => Self::CodeCHQB,
+			"HOLD" => Self::CodeHOLD,
+			"PHOB" => Self::CodePHOB,
+			"TELB" => Self::CodeTELB,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Instruction4Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodePHOA => "PHOA",
+			Self::CodeTELA => "TELA",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"PHOA" => Self::CodePHOA,
+			"TELA" => Self::CodeTELA,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Instruction5Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodePHOB => "PHOB",
+			Self::CodeTELB => "TELB",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"PHOB" => Self::CodePHOB,
+			"TELB" => Self::CodeTELB,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::InstructionForCreditorAgent1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("InstrInf") { optional(&self.instr_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("InstrInf") { optional_mut(&mut self.instr_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::InstructionForCreditorAgent2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("InstrInf") { optional(&self.instr_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("InstrInf") { optional_mut(&mut self.instr_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::InstructionForCreditorAgent3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("InstrInf") { optional(&self.instr_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("InstrInf") { optional_mut(&mut self.instr_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::InstructionForNextAgent1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("InstrInf") { optional(&self.instr_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("InstrInf") { optional_mut(&mut self.instr_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::InterestRecord2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one(&self.cdt_dbt_ind, step, out) }
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Rate") { optional(&self.rate, step, out) }
+		if step.matches("FrToDt") { optional(&self.fr_to_dt, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("Tax") { optional(&self.tax, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one_mut(&mut self.cdt_dbt_ind, step, out) }
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Rate") { optional_mut(&mut self.rate, step, create, out) }
+		if step.matches("FrToDt") { optional_mut(&mut self.fr_to_dt, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("Tax") { optional_mut(&mut self.tax, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::InterestType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::InterestType1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeINDY => "INDY",
+			Self::CodeOVRN => "OVRN",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"INDY" => Self::CodeINDY,
+			"OVRN" => Self::CodeOVRN,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::InvestigationStatus5Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Conf") { optional(&self.conf, step, out) }
+		if step.matches("RjctdMod") { optional_repeated(&self.rjctd_mod, step, out) }
+		if step.matches("DplctOf") { optional(&self.dplct_of, step, out) }
+		if step.matches("AssgnmtCxlConf") { optional(&self.assgnmt_cxl_conf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Conf") { optional_mut(&mut self.conf, step, create, out) }
+		if step.matches("RjctdMod") { optional_repeated_mut(&mut self.rjctd_mod, step, create, out) }
+		if step.matches("DplctOf") { optional_mut(&mut self.dplct_of, step, create, out) }
+		if step.matches("AssgnmtCxlConf") { optional_mut(&mut self.assgnmt_cxl_conf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Limit2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one(&self.cdt_dbt_ind, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one_mut(&mut self.cdt_dbt_ind, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::LocalInstrument2Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MandateClassification1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MandateClassification1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeFIXE => "FIXE",
+			Self::CodeUSGB => "USGB",
+			Self::CodeVARI => "VARI",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"FIXE" => Self::CodeFIXE,
+			"USGB" => Self::CodeUSGB,
+			"VARI" => Self::CodeVARI,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::MandateRelatedData1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DrctDbtMndt") { optional(&self.drct_dbt_mndt, step, out) }
+		if step.matches("CdtTrfMndt") { optional(&self.cdt_trf_mndt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DrctDbtMndt") { optional_mut(&mut self.drct_dbt_mndt, step, create, out) }
+		if step.matches("CdtTrfMndt") { optional_mut(&mut self.cdt_trf_mndt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MandateRelatedInformation14 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MndtId") { optional(&self.mndt_id, step, out) }
+		if step.matches("DtOfSgntr") { optional(&self.dt_of_sgntr, step, out) }
+		if step.matches("AmdmntInd") { optional(&self.amdmnt_ind, step, out) }
+		if step.matches("AmdmntInfDtls") { optional(&self.amdmnt_inf_dtls, step, out) }
+		if step.matches("ElctrncSgntr") { optional(&self.elctrnc_sgntr, step, out) }
+		if step.matches("FrstColltnDt") { optional(&self.frst_colltn_dt, step, out) }
+		if step.matches("FnlColltnDt") { optional(&self.fnl_colltn_dt, step, out) }
+		if step.matches("Frqcy") { optional(&self.frqcy, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("TrckgDays") { optional(&self.trckg_days, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MndtId") { optional_mut(&mut self.mndt_id, step, create, out) }
+		if step.matches("DtOfSgntr") { optional_mut(&mut self.dt_of_sgntr, step, create, out) }
+		if step.matches("AmdmntInd") { optional_mut(&mut self.amdmnt_ind, step, create, out) }
+		if step.matches("AmdmntInfDtls") { optional_mut(&mut self.amdmnt_inf_dtls, step, create, out) }
+		if step.matches("ElctrncSgntr") { optional_mut(&mut self.elctrnc_sgntr, step, create, out) }
+		if step.matches("FrstColltnDt") { optional_mut(&mut self.frst_colltn_dt, step, create, out) }
+		if step.matches("FnlColltnDt") { optional_mut(&mut self.fnl_colltn_dt, step, create, out) }
+		if step.matches("Frqcy") { optional_mut(&mut self.frqcy, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("TrckgDays") { optional_mut(&mut self.trckg_days, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MandateSetupReason1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MandateTypeInformation2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("SvcLvl") { optional(&self.svc_lvl, step, out) }
+		if step.matches("LclInstrm") { optional(&self.lcl_instrm, step, out) }
+		if step.matches("CtgyPurp") { optional(&self.ctgy_purp, step, out) }
+		if step.matches("Clssfctn") { optional(&self.clssfctn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("SvcLvl") { optional_mut(&mut self.svc_lvl, step, create, out) }
+		if step.matches("LclInstrm") { optional_mut(&mut self.lcl_instrm, step, create, out) }
+		if step.matches("CtgyPurp") { optional_mut(&mut self.ctgy_purp, step, create, out) }
+		if step.matches("Clssfctn") { optional_mut(&mut self.clssfctn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MessageHeader10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("CreDtTm") { optional(&self.cre_dt_tm, step, out) }
+		if step.matches("QryNm") { optional(&self.qry_nm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("CreDtTm") { optional_mut(&mut self.cre_dt_tm, step, create, out) }
+		if step.matches("QryNm") { optional_mut(&mut self.qry_nm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MessageHeader7 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("CreDtTm") { optional(&self.cre_dt_tm, step, out) }
+		if step.matches("ReqTp") { optional(&self.req_tp, step, out) }
+		if step.matches("OrgnlBizQry") { optional(&self.orgnl_biz_qry, step, out) }
+		if step.matches("QryNm") { optional(&self.qry_nm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("CreDtTm") { optional_mut(&mut self.cre_dt_tm, step, create, out) }
+		if step.matches("ReqTp") { optional_mut(&mut self.req_tp, step, create, out) }
+		if step.matches("OrgnlBizQry") { optional_mut(&mut self.orgnl_biz_qry, step, create, out) }
+		if step.matches("QryNm") { optional_mut(&mut self.qry_nm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MessageIdentification2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgNmId") { optional(&self.msg_nm_id, step, out) }
+		if step.matches("MsgId") { optional(&self.msg_id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgNmId") { optional_mut(&mut self.msg_nm_id, step, create, out) }
+		if step.matches("MsgId") { optional_mut(&mut self.msg_id, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MessageReference {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Ref") { one(&self.ref_attr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Ref") { one_mut(&mut self.ref_attr, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::MessageReference1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Ref") { one(&self.ref_attr, step, out) }
+		if step.matches("MsgNm") { optional(&self.msg_nm, step, out) }
+		if step.matches("RefIssr") { optional(&self.ref_issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Ref") { one_mut(&mut self.ref_attr, step, out) }
+		if step.matches("MsgNm") { optional_mut(&mut self.msg_nm, step, create, out) }
+		if step.matches("RefIssr") { optional_mut(&mut self.ref_issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::MissingOrIncorrectInformation3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AMLReq") { optional(&self.aml_req, step, out) }
+		if step.matches("MssngInf") { optional_repeated(&self.mssng_inf, step, out) }
+		if step.matches("IncrrctInf") { optional_repeated(&self.incrrct_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AMLReq") { optional_mut(&mut self.aml_req, step, create, out) }
+		if step.matches("MssngInf") { optional_repeated_mut(&mut self.mssng_inf, step, create, out) }
+		if step.matches("IncrrctInf") { optional_repeated_mut(&mut self.incrrct_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ModificationStatusReason1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ModificationStatusReason2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Orgtr") { optional(&self.orgtr, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("AddtlInf") { optional_repeated(&self.addtl_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Orgtr") { optional_mut(&mut self.orgtr, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("AddtlInf") { optional_repeated_mut(&mut self.addtl_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::NameAndAddress16 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Nm") { one(&self.nm, step, out) }
+		if step.matches("Adr") { one(&self.adr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Nm") { one_mut(&mut self.nm, step, out) }
+		if step.matches("Adr") { one_mut(&mut self.adr, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::NameAndAddress5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Nm") { one(&self.nm, step, out) }
+		if step.matches("Adr") { optional(&self.adr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Nm") { one_mut(&mut self.nm, step, out) }
+		if step.matches("Adr") { optional_mut(&mut self.adr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::NamePrefix2Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeDOCT => "DOCT",
+			Self::CodeMADM => "MADM",
+			Self::CodeMISS => "MISS",
+			Self::CodeMIST => "MIST",
+			Self::CodeMIKS => "MIKS",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"DOCT" => Self::CodeDOCT,
+			"MADM" => Self::CodeMADM,
+			"MISS" => Self::CodeMISS,
+			"MIST" => Self::CodeMIST,
+			"MIKS" => Self::CodeMIKS,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::NumberAndSumOfTransactions1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("NbOfNtries") { optional(&self.nb_of_ntries, step, out) }
+		if step.matches("Sum") { optional(&self.sum, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("NbOfNtries") { optional_mut(&mut self.nb_of_ntries, step, create, out) }
+		if step.matches("Sum") { optional_mut(&mut self.sum, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::NumberAndSumOfTransactions4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("NbOfNtries") { optional(&self.nb_of_ntries, step, out) }
+		if step.matches("Sum") { optional(&self.sum, step, out) }
+		if step.matches("TtlNetNtry") { optional(&self.ttl_net_ntry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("NbOfNtries") { optional_mut(&mut self.nb_of_ntries, step, create, out) }
+		if step.matches("Sum") { optional_mut(&mut self.sum, step, create, out) }
+		if step.matches("TtlNetNtry") { optional_mut(&mut self.ttl_net_ntry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::NumberOfCancellationsPerStatus1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DtldNbOfTxs") { one(&self.dtld_nb_of_txs, step, out) }
+		if step.matches("DtldSts") { one(&self.dtld_sts, step, out) }
+		if step.matches("DtldCtrlSum") { optional(&self.dtld_ctrl_sum, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DtldNbOfTxs") { one_mut(&mut self.dtld_nb_of_txs, step, out) }
+		if step.matches("DtldSts") { one_mut(&mut self.dtld_sts, step, out) }
+		if step.matches("DtldCtrlSum") { optional_mut(&mut self.dtld_ctrl_sum, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::NumberOfTransactionsPerStatus1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DtldNbOfTxs") { one(&self.dtld_nb_of_txs, step, out) }
+		if step.matches("DtldSts") { one(&self.dtld_sts, step, out) }
+		if step.matches("DtldCtrlSum") { optional(&self.dtld_ctrl_sum, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DtldNbOfTxs") { one_mut(&mut self.dtld_nb_of_txs, step, out) }
+		if step.matches("DtldSts") { one_mut(&mut self.dtld_sts, step, out) }
+		if step.matches("DtldCtrlSum") { optional_mut(&mut self.dtld_ctrl_sum, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::NumberOfTransactionsPerStatus5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DtldNbOfTxs") { one(&self.dtld_nb_of_txs, step, out) }
+		if step.matches("DtldSts") { one(&self.dtld_sts, step, out) }
+		if step.matches("DtldCtrlSum") { optional(&self.dtld_ctrl_sum, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DtldNbOfTxs") { one_mut(&mut self.dtld_nb_of_txs, step, out) }
+		if step.matches("DtldSts") { one_mut(&mut self.dtld_sts, step, out) }
+		if step.matches("DtldCtrlSum") { optional_mut(&mut self.dtld_ctrl_sum, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OnLineCapability1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeOFLN => "OFLN",
+			Self::CodeONLN => "ONLN",
+			Self::CodeSMON => "SMON",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"OFLN" => Self::CodeOFLN,
+			"ONLN" => Self::CodeONLN,
+			"SMON" => Self::CodeSMON,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::OrganisationIdentification29 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AnyBIC") { optional(&self.any_bic, step, out) }
+		if step.matches("LEI") { optional(&self.lei, step, out) }
+		if step.matches("Othr") { optional_repeated(&self.othr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AnyBIC") { optional_mut(&mut self.any_bic, step, create, out) }
+		if step.matches("LEI") { optional_mut(&mut self.lei, step, create, out) }
+		if step.matches("Othr") { optional_repeated_mut(&mut self.othr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OrganisationIdentificationSchemeName1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalAndCurrentQuantities1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FaceAmt") { one(&self.face_amt, step, out) }
+		if step.matches("AmtsdVal") { one(&self.amtsd_val, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FaceAmt") { one_mut(&mut self.face_amt, step, out) }
+		if step.matches("AmtsdVal") { one_mut(&mut self.amtsd_val, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalBusinessQuery1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("MsgNmId") { optional(&self.msg_nm_id, step, out) }
+		if step.matches("CreDtTm") { optional(&self.cre_dt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("MsgNmId") { optional_mut(&mut self.msg_nm_id, step, create, out) }
+		if step.matches("CreDtTm") { optional_mut(&mut self.cre_dt_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalGroupHeader14 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlGrpCxlId") { optional(&self.orgnl_grp_cxl_id, step, out) }
+		if step.matches("RslvdCase") { optional(&self.rslvd_case, step, out) }
+		if step.matches("OrgnlMsgId") { one(&self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one(&self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional(&self.orgnl_cre_dt_tm, step, out) }
+		if step.matches("OrgnlNbOfTxs") { optional(&self.orgnl_nb_of_txs, step, out) }
+		if step.matches("OrgnlCtrlSum") { optional(&self.orgnl_ctrl_sum, step, out) }
+		if step.matches("GrpCxlSts") { optional(&self.grp_cxl_sts, step, out) }
+		if step.matches("CxlStsRsnInf") { optional_repeated(&self.cxl_sts_rsn_inf, step, out) }
+		if step.matches("NbOfTxsPerCxlSts") { optional_repeated(&self.nb_of_txs_per_cxl_sts, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlGrpCxlId") { optional_mut(&mut self.orgnl_grp_cxl_id, step, create, out) }
+		if step.matches("RslvdCase") { optional_mut(&mut self.rslvd_case, step, create, out) }
+		if step.matches("OrgnlMsgId") { one_mut(&mut self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one_mut(&mut self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional_mut(&mut self.orgnl_cre_dt_tm, step, create, out) }
+		if step.matches("OrgnlNbOfTxs") { optional_mut(&mut self.orgnl_nb_of_txs, step, create, out) }
+		if step.matches("OrgnlCtrlSum") { optional_mut(&mut self.orgnl_ctrl_sum, step, create, out) }
+		if step.matches("GrpCxlSts") { optional_mut(&mut self.grp_cxl_sts, step, create, out) }
+		if step.matches("CxlStsRsnInf") { optional_repeated_mut(&mut self.cxl_sts_rsn_inf, step, create, out) }
+		if step.matches("NbOfTxsPerCxlSts") { optional_repeated_mut(&mut self.nb_of_txs_per_cxl_sts, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalGroupHeader15 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpCxlId") { optional(&self.grp_cxl_id, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("OrgnlMsgId") { one(&self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one(&self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional(&self.orgnl_cre_dt_tm, step, out) }
+		if step.matches("NbOfTxs") { optional(&self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional(&self.ctrl_sum, step, out) }
+		if step.matches("GrpCxl") { optional(&self.grp_cxl, step, out) }
+		if step.matches("CxlRsnInf") { optional_repeated(&self.cxl_rsn_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpCxlId") { optional_mut(&mut self.grp_cxl_id, step, create, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("OrgnlMsgId") { one_mut(&mut self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one_mut(&mut self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional_mut(&mut self.orgnl_cre_dt_tm, step, create, out) }
+		if step.matches("NbOfTxs") { optional_mut(&mut self.nb_of_txs, step, create, out) }
+		if step.matches("CtrlSum") { optional_mut(&mut self.ctrl_sum, step, create, out) }
+		if step.matches("GrpCxl") { optional_mut(&mut self.grp_cxl, step, create, out) }
+		if step.matches("CxlRsnInf") { optional_repeated_mut(&mut self.cxl_rsn_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalGroupHeader17 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one(&self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one(&self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional(&self.orgnl_cre_dt_tm, step, out) }
+		if step.matches("OrgnlNbOfTxs") { optional(&self.orgnl_nb_of_txs, step, out) }
+		if step.matches("OrgnlCtrlSum") { optional(&self.orgnl_ctrl_sum, step, out) }
+		if step.matches("GrpSts") { optional(&self.grp_sts, step, out) }
+		if step.matches("StsRsnInf") { optional_repeated(&self.sts_rsn_inf, step, out) }
+		if step.matches("NbOfTxsPerSts") { optional_repeated(&self.nb_of_txs_per_sts, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one_mut(&mut self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one_mut(&mut self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional_mut(&mut self.orgnl_cre_dt_tm, step, create, out) }
+		if step.matches("OrgnlNbOfTxs") { optional_mut(&mut self.orgnl_nb_of_txs, step, create, out) }
+		if step.matches("OrgnlCtrlSum") { optional_mut(&mut self.orgnl_ctrl_sum, step, create, out) }
+		if step.matches("GrpSts") { optional_mut(&mut self.grp_sts, step, create, out) }
+		if step.matches("StsRsnInf") { optional_repeated_mut(&mut self.sts_rsn_inf, step, create, out) }
+		if step.matches("NbOfTxsPerSts") { optional_repeated_mut(&mut self.nb_of_txs_per_sts, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalGroupHeader18 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one(&self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one(&self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional(&self.orgnl_cre_dt_tm, step, out) }
+		if step.matches("RtrRsnInf") { optional_repeated(&self.rtr_rsn_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one_mut(&mut self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one_mut(&mut self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional_mut(&mut self.orgnl_cre_dt_tm, step, create, out) }
+		if step.matches("RtrRsnInf") { optional_repeated_mut(&mut self.rtr_rsn_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalGroupInformation27 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one(&self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one(&self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional(&self.orgnl_cre_dt_tm, step, out) }
+		if step.matches("OrgnlNbOfTxs") { optional(&self.orgnl_nb_of_txs, step, out) }
+		if step.matches("OrgnlCtrlSum") { optional(&self.orgnl_ctrl_sum, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one_mut(&mut self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one_mut(&mut self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional_mut(&mut self.orgnl_cre_dt_tm, step, create, out) }
+		if step.matches("OrgnlNbOfTxs") { optional_mut(&mut self.orgnl_nb_of_txs, step, create, out) }
+		if step.matches("OrgnlCtrlSum") { optional_mut(&mut self.orgnl_ctrl_sum, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalGroupInformation29 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one(&self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one(&self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional(&self.orgnl_cre_dt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one_mut(&mut self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one_mut(&mut self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional_mut(&mut self.orgnl_cre_dt_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalGroupInformation30 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one(&self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one(&self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional(&self.orgnl_cre_dt_tm, step, out) }
+		if step.matches("OrgnlNbOfTxs") { optional(&self.orgnl_nb_of_txs, step, out) }
+		if step.matches("OrgnlCtrlSum") { optional(&self.orgnl_ctrl_sum, step, out) }
+		if step.matches("GrpSts") { optional(&self.grp_sts, step, out) }
+		if step.matches("StsRsnInf") { optional_repeated(&self.sts_rsn_inf, step, out) }
+		if step.matches("NbOfTxsPerSts") { optional_repeated(&self.nb_of_txs_per_sts, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one_mut(&mut self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one_mut(&mut self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional_mut(&mut self.orgnl_cre_dt_tm, step, create, out) }
+		if step.matches("OrgnlNbOfTxs") { optional_mut(&mut self.orgnl_nb_of_txs, step, create, out) }
+		if step.matches("OrgnlCtrlSum") { optional_mut(&mut self.orgnl_ctrl_sum, step, create, out) }
+		if step.matches("GrpSts") { optional_mut(&mut self.grp_sts, step, create, out) }
+		if step.matches("StsRsnInf") { optional_repeated_mut(&mut self.sts_rsn_inf, step, create, out) }
+		if step.matches("NbOfTxsPerSts") { optional_repeated_mut(&mut self.nb_of_txs_per_sts, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalPaymentInstruction30 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlPmtInfCxlId") { optional(&self.orgnl_pmt_inf_cxl_id, step, out) }
+		if step.matches("RslvdCase") { optional(&self.rslvd_case, step, out) }
+		if step.matches("OrgnlPmtInfId") { one(&self.orgnl_pmt_inf_id, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlNbOfTxs") { optional(&self.orgnl_nb_of_txs, step, out) }
+		if step.matches("OrgnlCtrlSum") { optional(&self.orgnl_ctrl_sum, step, out) }
+		if step.matches("PmtInfCxlSts") { optional(&self.pmt_inf_cxl_sts, step, out) }
+		if step.matches("CxlStsRsnInf") { optional_repeated(&self.cxl_sts_rsn_inf, step, out) }
+		if step.matches("NbOfTxsPerCxlSts") { optional_repeated(&self.nb_of_txs_per_cxl_sts, step, out) }
+		if step.matches("TxInfAndSts") { optional_repeated(&self.tx_inf_and_sts, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlPmtInfCxlId") { optional_mut(&mut self.orgnl_pmt_inf_cxl_id, step, create, out) }
+		if step.matches("RslvdCase") { optional_mut(&mut self.rslvd_case, step, create, out) }
+		if step.matches("OrgnlPmtInfId") { one_mut(&mut self.orgnl_pmt_inf_id, step, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlNbOfTxs") { optional_mut(&mut self.orgnl_nb_of_txs, step, create, out) }
+		if step.matches("OrgnlCtrlSum") { optional_mut(&mut self.orgnl_ctrl_sum, step, create, out) }
+		if step.matches("PmtInfCxlSts") { optional_mut(&mut self.pmt_inf_cxl_sts, step, create, out) }
+		if step.matches("CxlStsRsnInf") { optional_repeated_mut(&mut self.cxl_sts_rsn_inf, step, create, out) }
+		if step.matches("NbOfTxsPerCxlSts") { optional_repeated_mut(&mut self.nb_of_txs_per_cxl_sts, step, create, out) }
+		if step.matches("TxInfAndSts") { optional_repeated_mut(&mut self.tx_inf_and_sts, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalPaymentInstruction31 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlPmtInfId") { one(&self.orgnl_pmt_inf_id, step, out) }
+		if step.matches("OrgnlNbOfTxs") { optional(&self.orgnl_nb_of_txs, step, out) }
+		if step.matches("OrgnlCtrlSum") { optional(&self.orgnl_ctrl_sum, step, out) }
+		if step.matches("PmtInfSts") { optional(&self.pmt_inf_sts, step, out) }
+		if step.matches("StsRsnInf") { optional_repeated(&self.sts_rsn_inf, step, out) }
+		if step.matches("NbOfTxsPerSts") { optional_repeated(&self.nb_of_txs_per_sts, step, out) }
+		if step.matches("TxInfAndSts") { optional_repeated(&self.tx_inf_and_sts, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlPmtInfId") { one_mut(&mut self.orgnl_pmt_inf_id, step, out) }
+		if step.matches("OrgnlNbOfTxs") { optional_mut(&mut self.orgnl_nb_of_txs, step, create, out) }
+		if step.matches("OrgnlCtrlSum") { optional_mut(&mut self.orgnl_ctrl_sum, step, create, out) }
+		if step.matches("PmtInfSts") { optional_mut(&mut self.pmt_inf_sts, step, create, out) }
+		if step.matches("StsRsnInf") { optional_repeated_mut(&mut self.sts_rsn_inf, step, create, out) }
+		if step.matches("NbOfTxsPerSts") { optional_repeated_mut(&mut self.nb_of_txs_per_sts, step, create, out) }
+		if step.matches("TxInfAndSts") { optional_repeated_mut(&mut self.tx_inf_and_sts, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalPaymentInstruction36 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PmtCxlId") { optional(&self.pmt_cxl_id, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("OrgnlPmtInfId") { one(&self.orgnl_pmt_inf_id, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("NbOfTxs") { optional(&self.nb_of_txs, step, out) }
+		if step.matches("CtrlSum") { optional(&self.ctrl_sum, step, out) }
+		if step.matches("PmtInfCxl") { optional(&self.pmt_inf_cxl, step, out) }
+		if step.matches("CxlRsnInf") { optional_repeated(&self.cxl_rsn_inf, step, out) }
+		if step.matches("TxInf") { optional_repeated(&self.tx_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PmtCxlId") { optional_mut(&mut self.pmt_cxl_id, step, create, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("OrgnlPmtInfId") { one_mut(&mut self.orgnl_pmt_inf_id, step, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("NbOfTxs") { optional_mut(&mut self.nb_of_txs, step, create, out) }
+		if step.matches("CtrlSum") { optional_mut(&mut self.ctrl_sum, step, create, out) }
+		if step.matches("PmtInfCxl") { optional_mut(&mut self.pmt_inf_cxl, step, create, out) }
+		if step.matches("CxlRsnInf") { optional_repeated_mut(&mut self.cxl_rsn_inf, step, create, out) }
+		if step.matches("TxInf") { optional_repeated_mut(&mut self.tx_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalTransactionReference28 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("IntrBkSttlmAmt") { optional(&self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("ReqdColltnDt") { optional(&self.reqd_colltn_dt, step, out) }
+		if step.matches("ReqdExctnDt") { optional(&self.reqd_exctn_dt, step, out) }
+		if step.matches("CdtrSchmeId") { optional(&self.cdtr_schme_id, step, out) }
+		if step.matches("SttlmInf") { optional(&self.sttlm_inf, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("PmtMtd") { optional(&self.pmt_mtd, step, out) }
+		if step.matches("MndtRltdInf") { optional(&self.mndt_rltd_inf, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("Dbtr") { optional(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional(&self.dbtr_agt_acct, step, out) }
+		if step.matches("CdtrAgt") { optional(&self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional(&self.cdtr_agt_acct, step, out) }
+		if step.matches("Cdtr") { optional(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("Purp") { optional(&self.purp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("IntrBkSttlmAmt") { optional_mut(&mut self.intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("ReqdColltnDt") { optional_mut(&mut self.reqd_colltn_dt, step, create, out) }
+		if step.matches("ReqdExctnDt") { optional_mut(&mut self.reqd_exctn_dt, step, create, out) }
+		if step.matches("CdtrSchmeId") { optional_mut(&mut self.cdtr_schme_id, step, create, out) }
+		if step.matches("SttlmInf") { optional_mut(&mut self.sttlm_inf, step, create, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("PmtMtd") { optional_mut(&mut self.pmt_mtd, step, create, out) }
+		if step.matches("MndtRltdInf") { optional_mut(&mut self.mndt_rltd_inf, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("Dbtr") { optional_mut(&mut self.dbtr, step, create, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("DbtrAgtAcct") { optional_mut(&mut self.dbtr_agt_acct, step, create, out) }
+		if step.matches("CdtrAgt") { optional_mut(&mut self.cdtr_agt, step, create, out) }
+		if step.matches("CdtrAgtAcct") { optional_mut(&mut self.cdtr_agt_acct, step, create, out) }
+		if step.matches("Cdtr") { optional_mut(&mut self.cdtr, step, create, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("Purp") { optional_mut(&mut self.purp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalTransactionReference29 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+		if step.matches("ReqdExctnDt") { optional(&self.reqd_exctn_dt, step, out) }
+		if step.matches("XpryDt") { optional(&self.xpry_dt, step, out) }
+		if step.matches("PmtCond") { optional(&self.pmt_cond, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("PmtMtd") { optional(&self.pmt_mtd, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("NclsdFile") { optional_repeated(&self.nclsd_file, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("Dbtr") { optional(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("CdtrAgt") { one(&self.cdtr_agt, step, out) }
+		if step.matches("Cdtr") { one(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+		if step.matches("ReqdExctnDt") { optional_mut(&mut self.reqd_exctn_dt, step, create, out) }
+		if step.matches("XpryDt") { optional_mut(&mut self.xpry_dt, step, create, out) }
+		if step.matches("PmtCond") { optional_mut(&mut self.pmt_cond, step, create, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("PmtMtd") { optional_mut(&mut self.pmt_mtd, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("NclsdFile") { optional_repeated_mut(&mut self.nclsd_file, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("Dbtr") { optional_mut(&mut self.dbtr, step, create, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("CdtrAgt") { one_mut(&mut self.cdtr_agt, step, out) }
+		if step.matches("Cdtr") { one_mut(&mut self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalTransactionReference31 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("IntrBkSttlmAmt") { optional(&self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("ReqdColltnDt") { optional(&self.reqd_colltn_dt, step, out) }
+		if step.matches("ReqdExctnDt") { optional(&self.reqd_exctn_dt, step, out) }
+		if step.matches("CdtrSchmeId") { optional(&self.cdtr_schme_id, step, out) }
+		if step.matches("SttlmInf") { optional(&self.sttlm_inf, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("PmtMtd") { optional(&self.pmt_mtd, step, out) }
+		if step.matches("MndtRltdInf") { optional(&self.mndt_rltd_inf, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("Dbtr") { optional(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional(&self.dbtr_agt_acct, step, out) }
+		if step.matches("CdtrAgt") { optional(&self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional(&self.cdtr_agt_acct, step, out) }
+		if step.matches("Cdtr") { optional(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("Purp") { optional(&self.purp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("IntrBkSttlmAmt") { optional_mut(&mut self.intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("ReqdColltnDt") { optional_mut(&mut self.reqd_colltn_dt, step, create, out) }
+		if step.matches("ReqdExctnDt") { optional_mut(&mut self.reqd_exctn_dt, step, create, out) }
+		if step.matches("CdtrSchmeId") { optional_mut(&mut self.cdtr_schme_id, step, create, out) }
+		if step.matches("SttlmInf") { optional_mut(&mut self.sttlm_inf, step, create, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("PmtMtd") { optional_mut(&mut self.pmt_mtd, step, create, out) }
+		if step.matches("MndtRltdInf") { optional_mut(&mut self.mndt_rltd_inf, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("Dbtr") { optional_mut(&mut self.dbtr, step, create, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("DbtrAgtAcct") { optional_mut(&mut self.dbtr_agt_acct, step, create, out) }
+		if step.matches("CdtrAgt") { optional_mut(&mut self.cdtr_agt, step, create, out) }
+		if step.matches("CdtrAgtAcct") { optional_mut(&mut self.cdtr_agt_acct, step, create, out) }
+		if step.matches("Cdtr") { optional_mut(&mut self.cdtr, step, create, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("Purp") { optional_mut(&mut self.purp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OriginalTransactionReference32 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("IntrBkSttlmAmt") { optional(&self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("ReqdColltnDt") { optional(&self.reqd_colltn_dt, step, out) }
+		if step.matches("ReqdExctnDt") { optional(&self.reqd_exctn_dt, step, out) }
+		if step.matches("CdtrSchmeId") { optional(&self.cdtr_schme_id, step, out) }
+		if step.matches("SttlmInf") { optional(&self.sttlm_inf, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("PmtMtd") { optional(&self.pmt_mtd, step, out) }
+		if step.matches("MndtRltdInf") { optional(&self.mndt_rltd_inf, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("Dbtr") { optional(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional(&self.dbtr_agt_acct, step, out) }
+		if step.matches("CdtrAgt") { optional(&self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional(&self.cdtr_agt_acct, step, out) }
+		if step.matches("Cdtr") { optional(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("Purp") { optional(&self.purp, step, out) }
+		if step.matches("UndrlygCstmrCdtTrf") { optional(&self.undrlyg_cstmr_cdt_trf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("IntrBkSttlmAmt") { optional_mut(&mut self.intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("ReqdColltnDt") { optional_mut(&mut self.reqd_colltn_dt, step, create, out) }
+		if step.matches("ReqdExctnDt") { optional_mut(&mut self.reqd_exctn_dt, step, create, out) }
+		if step.matches("CdtrSchmeId") { optional_mut(&mut self.cdtr_schme_id, step, create, out) }
+		if step.matches("SttlmInf") { optional_mut(&mut self.sttlm_inf, step, create, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("PmtMtd") { optional_mut(&mut self.pmt_mtd, step, create, out) }
+		if step.matches("MndtRltdInf") { optional_mut(&mut self.mndt_rltd_inf, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("Dbtr") { optional_mut(&mut self.dbtr, step, create, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("DbtrAgtAcct") { optional_mut(&mut self.dbtr_agt_acct, step, create, out) }
+		if step.matches("CdtrAgt") { optional_mut(&mut self.cdtr_agt, step, create, out) }
+		if step.matches("CdtrAgtAcct") { optional_mut(&mut self.cdtr_agt_acct, step, create, out) }
+		if step.matches("Cdtr") { optional_mut(&mut self.cdtr, step, create, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("Purp") { optional_mut(&mut self.purp, step, create, out) }
+		if step.matches("UndrlygCstmrCdtTrf") { optional_mut(&mut self.undrlyg_cstmr_cdt_trf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OtherContact1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ChanlTp") { one(&self.chanl_tp, step, out) }
+		if step.matches("Id") { optional(&self.id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ChanlTp") { one_mut(&mut self.chanl_tp, step, out) }
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::OtherIdentification1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Sfx") { optional(&self.sfx, step, out) }
+		if step.matches("Tp") { one(&self.tp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Sfx") { optional_mut(&mut self.sfx, step, create, out) }
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::POIComponentType1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeSOFT => "SOFT",
+			Self::CodeEMVK => "EMVK",
+			Self::CodeEMVO => "EMVO",
+			Self::CodeMRIT => "MRIT",
+			Self::CodeCHIT => "CHIT",
+			Self::CodeSECM => "SECM",
+			Self::CodePEDV => "PEDV",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"SOFT" => Self::CodeSOFT,
+			"EMVK" => Self::CodeEMVK,
+			"EMVO" => Self::CodeEMVO,
+			"MRIT" => Self::CodeMRIT,
+			"CHIT" => Self::CodeCHIT,
+			"SECM" => Self::CodeSECM,
+			"PEDV" => Self::CodePEDV,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Pagination1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PgNb") { one(&self.pg_nb, step, out) }
+		if step.matches("LastPgInd") { one(&self.last_pg_ind, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PgNb") { one_mut(&mut self.pg_nb, step, out) }
+		if step.matches("LastPgInd") { one_mut(&mut self.last_pg_ind, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::Party38Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgId") { optional(&self.org_id, step, out) }
+		if step.matches("PrvtId") { optional(&self.prvt_id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgId") { optional_mut(&mut self.org_id, step, create, out) }
+		if step.matches("PrvtId") { optional_mut(&mut self.prvt_id, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Party40Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Pty") { optional(&self.pty, step, out) }
+		if step.matches("Agt") { optional(&self.agt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Pty") { optional_mut(&mut self.pty, step, create, out) }
+		if step.matches("Agt") { optional_mut(&mut self.agt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Party44Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgId") { optional(&self.org_id, step, out) }
+		if step.matches("FIId") { optional(&self.fi_id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgId") { optional_mut(&mut self.org_id, step, create, out) }
+		if step.matches("FIId") { optional_mut(&mut self.fi_id, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PartyAndSignature3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Pty") { one(&self.pty, step, out) }
+		if step.matches("Sgntr") { one(&self.sgntr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Pty") { one_mut(&mut self.pty, step, out) }
+		if step.matches("Sgntr") { one_mut(&mut self.sgntr, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::PartyIdentification120Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AnyBIC") { optional(&self.any_bic, step, out) }
+		if step.matches("PrtryId") { optional(&self.prtry_id, step, out) }
+		if step.matches("NmAndAdr") { optional(&self.nm_and_adr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AnyBIC") { optional_mut(&mut self.any_bic, step, create, out) }
+		if step.matches("PrtryId") { optional_mut(&mut self.prtry_id, step, create, out) }
+		if step.matches("NmAndAdr") { optional_mut(&mut self.nm_and_adr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PartyIdentification135 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+		if step.matches("PstlAdr") { optional(&self.pstl_adr, step, out) }
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("CtryOfRes") { optional(&self.ctry_of_res, step, out) }
+		if step.matches("CtctDtls") { optional(&self.ctct_dtls, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+		if step.matches("PstlAdr") { optional_mut(&mut self.pstl_adr, step, create, out) }
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("CtryOfRes") { optional_mut(&mut self.ctry_of_res, step, create, out) }
+		if step.matches("CtctDtls") { optional_mut(&mut self.ctct_dtls, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PartyIdentification136 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("LEI") { optional(&self.lei, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("LEI") { optional_mut(&mut self.lei, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PartyType3Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeOPOI => "OPOI",
+			Self::CodeMERC => "MERC",
+			Self::CodeACCP => "ACCP",
+			Self::CodeITAG => "ITAG",
+			Self::CodeACQR => "ACQR",
+			Self::CodeCISS => "CISS",
+			Self::CodeDLIS => "DLIS",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"OPOI" => Self::CodeOPOI,
+			"MERC" => Self::CodeMERC,
+			"ACCP" => Self::CodeACCP,
+			"ITAG" => Self::CodeITAG,
+			"ACQR" => Self::CodeACQR,
+			"CISS" => Self::CodeCISS,
+			"DLIS" => Self::CodeDLIS,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::PartyType4Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeMERC => "MERC",
+			Self::CodeACCP => "ACCP",
+			Self::CodeITAG => "ITAG",
+			Self::CodeACQR => "ACQR",
+			Self::CodeCISS => "CISS",
+			Self::CodeTAXH => "TAXH",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"MERC" => Self::CodeMERC,
+			"ACCP" => Self::CodeACCP,
+			"ITAG" => Self::CodeITAG,
+			"ACQR" => Self::CodeACQR,
+			"CISS" => Self::CodeCISS,
+			"TAXH" => Self::CodeTAXH,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::PaymentCancellationReason5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Orgtr") { optional(&self.orgtr, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("AddtlInf") { optional_repeated(&self.addtl_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Orgtr") { optional_mut(&mut self.orgtr, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("AddtlInf") { optional_repeated_mut(&mut self.addtl_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentCard4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PlainCardData") { optional(&self.plain_card_data, step, out) }
+		if step.matches("CardCtryCd") { optional(&self.card_ctry_cd, step, out) }
+		if step.matches("CardBrnd") { optional(&self.card_brnd, step, out) }
+		if step.matches("AddtlCardData") { optional(&self.addtl_card_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PlainCardData") { optional_mut(&mut self.plain_card_data, step, create, out) }
+		if step.matches("CardCtryCd") { optional_mut(&mut self.card_ctry_cd, step, create, out) }
+		if step.matches("CardBrnd") { optional_mut(&mut self.card_brnd, step, create, out) }
+		if step.matches("AddtlCardData") { optional_mut(&mut self.addtl_card_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentComplementaryInformation8 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstrId") { optional(&self.instr_id, step, out) }
+		if step.matches("EndToEndId") { optional(&self.end_to_end_id, step, out) }
+		if step.matches("TxId") { optional(&self.tx_id, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("ReqdExctnDt") { optional(&self.reqd_exctn_dt, step, out) }
+		if step.matches("ReqdColltnDt") { optional(&self.reqd_colltn_dt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+		if step.matches("IntrBkSttlmAmt") { optional(&self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("ChrgBr") { optional(&self.chrg_br, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("Dbtr") { optional(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional(&self.dbtr_agt_acct, step, out) }
+		if step.matches("SttlmInf") { optional(&self.sttlm_inf, step, out) }
+		if step.matches("IntrmyAgt1") { optional(&self.intrmy_agt1, step, out) }
+		if step.matches("IntrmyAgt1Acct") { optional(&self.intrmy_agt1_acct, step, out) }
+		if step.matches("IntrmyAgt2") { optional(&self.intrmy_agt2, step, out) }
+		if step.matches("IntrmyAgt2Acct") { optional(&self.intrmy_agt2_acct, step, out) }
+		if step.matches("IntrmyAgt3") { optional(&self.intrmy_agt3, step, out) }
+		if step.matches("IntrmyAgt3Acct") { optional(&self.intrmy_agt3_acct, step, out) }
+		if step.matches("CdtrAgt") { optional(&self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional(&self.cdtr_agt_acct, step, out) }
+		if step.matches("Cdtr") { optional(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("Purp") { optional(&self.purp, step, out) }
+		if step.matches("InstrForDbtrAgt") { optional(&self.instr_for_dbtr_agt, step, out) }
+		if step.matches("PrvsInstgAgt1") { optional(&self.prvs_instg_agt1, step, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional(&self.prvs_instg_agt1_acct, step, out) }
+		if step.matches("PrvsInstgAgt2") { optional(&self.prvs_instg_agt2, step, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional(&self.prvs_instg_agt2_acct, step, out) }
+		if step.matches("PrvsInstgAgt3") { optional(&self.prvs_instg_agt3, step, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional(&self.prvs_instg_agt3_acct, step, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated(&self.instr_for_nxt_agt, step, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated(&self.instr_for_cdtr_agt, step, out) }
+		if step.matches("RmtInf") { optional(&self.rmt_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstrId") { optional_mut(&mut self.instr_id, step, create, out) }
+		if step.matches("EndToEndId") { optional_mut(&mut self.end_to_end_id, step, create, out) }
+		if step.matches("TxId") { optional_mut(&mut self.tx_id, step, create, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("ReqdExctnDt") { optional_mut(&mut self.reqd_exctn_dt, step, create, out) }
+		if step.matches("ReqdColltnDt") { optional_mut(&mut self.reqd_colltn_dt, step, create, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+		if step.matches("IntrBkSttlmAmt") { optional_mut(&mut self.intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("ChrgBr") { optional_mut(&mut self.chrg_br, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("Dbtr") { optional_mut(&mut self.dbtr, step, create, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("DbtrAgtAcct") { optional_mut(&mut self.dbtr_agt_acct, step, create, out) }
+		if step.matches("SttlmInf") { optional_mut(&mut self.sttlm_inf, step, create, out) }
+		if step.matches("IntrmyAgt1") { optional_mut(&mut self.intrmy_agt1, step, create, out) }
+		if step.matches("IntrmyAgt1Acct") { optional_mut(&mut self.intrmy_agt1_acct, step, create, out) }
+		if step.matches("IntrmyAgt2") { optional_mut(&mut self.intrmy_agt2, step, create, out) }
+		if step.matches("IntrmyAgt2Acct") { optional_mut(&mut self.intrmy_agt2_acct, step, create, out) }
+		if step.matches("IntrmyAgt3") { optional_mut(&mut self.intrmy_agt3, step, create, out) }
+		if step.matches("IntrmyAgt3Acct") { optional_mut(&mut self.intrmy_agt3_acct, step, create, out) }
+		if step.matches("CdtrAgt") { optional_mut(&mut self.cdtr_agt, step, create, out) }
+		if step.matches("CdtrAgtAcct") { optional_mut(&mut self.cdtr_agt_acct, step, create, out) }
+		if step.matches("Cdtr") { optional_mut(&mut self.cdtr, step, create, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("Purp") { optional_mut(&mut self.purp, step, create, out) }
+		if step.matches("InstrForDbtrAgt") { optional_mut(&mut self.instr_for_dbtr_agt, step, create, out) }
+		if step.matches("PrvsInstgAgt1") { optional_mut(&mut self.prvs_instg_agt1, step, create, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional_mut(&mut self.prvs_instg_agt1_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt2") { optional_mut(&mut self.prvs_instg_agt2, step, create, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional_mut(&mut self.prvs_instg_agt2_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt3") { optional_mut(&mut self.prvs_instg_agt3, step, create, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional_mut(&mut self.prvs_instg_agt3_acct, step, create, out) }
+		if step.matches("InstrForNxtAgt") { optional_repeated_mut(&mut self.instr_for_nxt_agt, step, create, out) }
+		if step.matches("InstrForCdtrAgt") { optional_repeated_mut(&mut self.instr_for_cdtr_agt, step, create, out) }
+		if step.matches("RmtInf") { optional_mut(&mut self.rmt_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentCondition1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AmtModAllwd") { one(&self.amt_mod_allwd, step, out) }
+		if step.matches("EarlyPmtAllwd") { one(&self.early_pmt_allwd, step, out) }
+		if step.matches("DelyPnlty") { optional(&self.dely_pnlty, step, out) }
+		if step.matches("ImdtPmtRbt") { optional(&self.imdt_pmt_rbt, step, out) }
+		if step.matches("GrntedPmtReqd") { one(&self.grnted_pmt_reqd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AmtModAllwd") { one_mut(&mut self.amt_mod_allwd, step, out) }
+		if step.matches("EarlyPmtAllwd") { one_mut(&mut self.early_pmt_allwd, step, out) }
+		if step.matches("DelyPnlty") { optional_mut(&mut self.dely_pnlty, step, create, out) }
+		if step.matches("ImdtPmtRbt") { optional_mut(&mut self.imdt_pmt_rbt, step, create, out) }
+		if step.matches("GrntedPmtReqd") { one_mut(&mut self.grnted_pmt_reqd, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentConditionStatus1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AccptdAmt") { optional(&self.accptd_amt, step, out) }
+		if step.matches("GrntedPmt") { one(&self.grnted_pmt, step, out) }
+		if step.matches("EarlyPmt") { one(&self.early_pmt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AccptdAmt") { optional_mut(&mut self.accptd_amt, step, create, out) }
+		if step.matches("GrntedPmt") { one_mut(&mut self.grnted_pmt, step, out) }
+		if step.matches("EarlyPmt") { one_mut(&mut self.early_pmt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentContext3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CardPres") { optional(&self.card_pres, step, out) }
+		if step.matches("CrdhldrPres") { optional(&self.crdhldr_pres, step, out) }
+		if step.matches("OnLineCntxt") { optional(&self.on_line_cntxt, step, out) }
+		if step.matches("AttndncCntxt") { optional(&self.attndnc_cntxt, step, out) }
+		if step.matches("TxEnvt") { optional(&self.tx_envt, step, out) }
+		if step.matches("TxChanl") { optional(&self.tx_chanl, step, out) }
+		if step.matches("AttndntMsgCpbl") { optional(&self.attndnt_msg_cpbl, step, out) }
+		if step.matches("AttndntLang") { optional(&self.attndnt_lang, step, out) }
+		if step.matches("CardDataNtryMd") { one(&self.card_data_ntry_md, step, out) }
+		if step.matches("FllbckInd") { optional(&self.fllbck_ind, step, out) }
+		if step.matches("AuthntcnMtd") { optional(&self.authntcn_mtd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CardPres") { optional_mut(&mut self.card_pres, step, create, out) }
+		if step.matches("CrdhldrPres") { optional_mut(&mut self.crdhldr_pres, step, create, out) }
+		if step.matches("OnLineCntxt") { optional_mut(&mut self.on_line_cntxt, step, create, out) }
+		if step.matches("AttndncCntxt") { optional_mut(&mut self.attndnc_cntxt, step, create, out) }
+		if step.matches("TxEnvt") { optional_mut(&mut self.tx_envt, step, create, out) }
+		if step.matches("TxChanl") { optional_mut(&mut self.tx_chanl, step, create, out) }
+		if step.matches("AttndntMsgCpbl") { optional_mut(&mut self.attndnt_msg_cpbl, step, create, out) }
+		if step.matches("AttndntLang") { optional_mut(&mut self.attndnt_lang, step, create, out) }
+		if step.matches("CardDataNtryMd") { one_mut(&mut self.card_data_ntry_md, step, out) }
+		if step.matches("FllbckInd") { optional_mut(&mut self.fllbck_ind, step, create, out) }
+		if step.matches("AuthntcnMtd") { optional_mut(&mut self.authntcn_mtd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentIdentification6 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstrId") { optional(&self.instr_id, step, out) }
+		if step.matches("EndToEndId") { one(&self.end_to_end_id, step, out) }
+		if step.matches("UETR") { optional(&self.uetr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstrId") { optional_mut(&mut self.instr_id, step, create, out) }
+		if step.matches("EndToEndId") { one_mut(&mut self.end_to_end_id, step, out) }
+		if step.matches("UETR") { optional_mut(&mut self.uetr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentIdentification7 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstrId") { optional(&self.instr_id, step, out) }
+		if step.matches("EndToEndId") { one(&self.end_to_end_id, step, out) }
+		if step.matches("TxId") { optional(&self.tx_id, step, out) }
+		if step.matches("UETR") { optional(&self.uetr, step, out) }
+		if step.matches("ClrSysRef") { optional(&self.clr_sys_ref, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstrId") { optional_mut(&mut self.instr_id, step, create, out) }
+		if step.matches("EndToEndId") { one_mut(&mut self.end_to_end_id, step, out) }
+		if step.matches("TxId") { optional_mut(&mut self.tx_id, step, create, out) }
+		if step.matches("UETR") { optional_mut(&mut self.uetr, step, create, out) }
+		if step.matches("ClrSysRef") { optional_mut(&mut self.clr_sys_ref, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentInstruction31 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PmtInfId") { optional(&self.pmt_inf_id, step, out) }
+		if step.matches("PmtMtd") { one(&self.pmt_mtd, step, out) }
+		if step.matches("PmtTpInf") { optional(&self.pmt_tp_inf, step, out) }
+		if step.matches("ReqdExctnDt") { one(&self.reqd_exctn_dt, step, out) }
+		if step.matches("XpryDt") { optional(&self.xpry_dt, step, out) }
+		if step.matches("PmtCond") { optional(&self.pmt_cond, step, out) }
+		if step.matches("Dbtr") { one(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("DbtrAgt") { one(&self.dbtr_agt, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("ChrgBr") { optional(&self.chrg_br, step, out) }
+		if step.matches("CdtTrfTx") { repeated(&self.cdt_trf_tx, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PmtInfId") { optional_mut(&mut self.pmt_inf_id, step, create, out) }
+		if step.matches("PmtMtd") { one_mut(&mut self.pmt_mtd, step, out) }
+		if step.matches("PmtTpInf") { optional_mut(&mut self.pmt_tp_inf, step, create, out) }
+		if step.matches("ReqdExctnDt") { one_mut(&mut self.reqd_exctn_dt, step, out) }
+		if step.matches("XpryDt") { optional_mut(&mut self.xpry_dt, step, create, out) }
+		if step.matches("PmtCond") { optional_mut(&mut self.pmt_cond, step, create, out) }
+		if step.matches("Dbtr") { one_mut(&mut self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("DbtrAgt") { one_mut(&mut self.dbtr_agt, step, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("ChrgBr") { optional_mut(&mut self.chrg_br, step, create, out) }
+		if step.matches("CdtTrfTx") { repeated_mut(&mut self.cdt_trf_tx, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentMethod4Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeCHK => "CHK",
+			Self::CodeTRF => "TRF",
+			Self::CodeDD => "DD",
+			Self::CodeTRA => "TRA",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"CHK" => Self::CodeCHK,
+			"TRF" => Self::CodeTRF,
+			"DD" => Self::CodeDD,
+			"TRA" => Self::CodeTRA,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::PaymentMethod7Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeCHK => "CHK",
+			Self::CodeTRF => "TRF",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"CHK" => Self::CodeCHK,
+			"TRF" => Self::CodeTRF,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::PaymentReturnReason5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlBkTxCd") { optional(&self.orgnl_bk_tx_cd, step, out) }
+		if step.matches("Orgtr") { optional(&self.orgtr, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("AddtlInf") { optional_repeated(&self.addtl_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlBkTxCd") { optional_mut(&mut self.orgnl_bk_tx_cd, step, create, out) }
+		if step.matches("Orgtr") { optional_mut(&mut self.orgtr, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("AddtlInf") { optional_repeated_mut(&mut self.addtl_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentReturnReason6 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Orgtr") { optional(&self.orgtr, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("AddtlInf") { optional_repeated(&self.addtl_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Orgtr") { optional_mut(&mut self.orgtr, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("AddtlInf") { optional_repeated_mut(&mut self.addtl_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTransaction102 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CxlStsId") { optional(&self.cxl_sts_id, step, out) }
+		if step.matches("RslvdCase") { optional(&self.rslvd_case, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlTxId") { optional(&self.orgnl_tx_id, step, out) }
+		if step.matches("OrgnlClrSysRef") { optional(&self.orgnl_clr_sys_ref, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("TxCxlSts") { optional(&self.tx_cxl_sts, step, out) }
+		if step.matches("CxlStsRsnInf") { optional_repeated(&self.cxl_sts_rsn_inf, step, out) }
+		if step.matches("RsltnRltdInf") { optional(&self.rsltn_rltd_inf, step, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { optional(&self.orgnl_intr_bk_sttlm_amt, step, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { optional(&self.orgnl_intr_bk_sttlm_dt, step, out) }
+		if step.matches("Assgnr") { optional(&self.assgnr, step, out) }
+		if step.matches("Assgne") { optional(&self.assgne, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CxlStsId") { optional_mut(&mut self.cxl_sts_id, step, create, out) }
+		if step.matches("RslvdCase") { optional_mut(&mut self.rslvd_case, step, create, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlTxId") { optional_mut(&mut self.orgnl_tx_id, step, create, out) }
+		if step.matches("OrgnlClrSysRef") { optional_mut(&mut self.orgnl_clr_sys_ref, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("TxCxlSts") { optional_mut(&mut self.tx_cxl_sts, step, create, out) }
+		if step.matches("CxlStsRsnInf") { optional_repeated_mut(&mut self.cxl_sts_rsn_inf, step, create, out) }
+		if step.matches("RsltnRltdInf") { optional_mut(&mut self.rsltn_rltd_inf, step, create, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { optional_mut(&mut self.orgnl_intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { optional_mut(&mut self.orgnl_intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("Assgnr") { optional_mut(&mut self.assgnr, step, create, out) }
+		if step.matches("Assgne") { optional_mut(&mut self.assgne, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTransaction103 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CxlStsId") { optional(&self.cxl_sts_id, step, out) }
+		if step.matches("RslvdCase") { optional(&self.rslvd_case, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("UETR") { optional(&self.uetr, step, out) }
+		if step.matches("TxCxlSts") { optional(&self.tx_cxl_sts, step, out) }
+		if step.matches("CxlStsRsnInf") { optional_repeated(&self.cxl_sts_rsn_inf, step, out) }
+		if step.matches("OrgnlInstdAmt") { optional(&self.orgnl_instd_amt, step, out) }
+		if step.matches("OrgnlReqdExctnDt") { optional(&self.orgnl_reqd_exctn_dt, step, out) }
+		if step.matches("OrgnlReqdColltnDt") { optional(&self.orgnl_reqd_colltn_dt, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CxlStsId") { optional_mut(&mut self.cxl_sts_id, step, create, out) }
+		if step.matches("RslvdCase") { optional_mut(&mut self.rslvd_case, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("UETR") { optional_mut(&mut self.uetr, step, create, out) }
+		if step.matches("TxCxlSts") { optional_mut(&mut self.tx_cxl_sts, step, create, out) }
+		if step.matches("CxlStsRsnInf") { optional_repeated_mut(&mut self.cxl_sts_rsn_inf, step, create, out) }
+		if step.matches("OrgnlInstdAmt") { optional_mut(&mut self.orgnl_instd_amt, step, create, out) }
+		if step.matches("OrgnlReqdExctnDt") { optional_mut(&mut self.orgnl_reqd_exctn_dt, step, create, out) }
+		if step.matches("OrgnlReqdColltnDt") { optional_mut(&mut self.orgnl_reqd_colltn_dt, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTransaction104 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("StsId") { optional(&self.sts_id, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("TxSts") { optional(&self.tx_sts, step, out) }
+		if step.matches("StsRsnInf") { optional_repeated(&self.sts_rsn_inf, step, out) }
+		if step.matches("PmtCondSts") { optional(&self.pmt_cond_sts, step, out) }
+		if step.matches("ChrgsInf") { optional_repeated(&self.chrgs_inf, step, out) }
+		if step.matches("DbtrDcsnDtTm") { optional(&self.dbtr_dcsn_dt_tm, step, out) }
+		if step.matches("AccptncDtTm") { optional(&self.accptnc_dt_tm, step, out) }
+		if step.matches("AcctSvcrRef") { optional(&self.acct_svcr_ref, step, out) }
+		if step.matches("ClrSysRef") { optional(&self.clr_sys_ref, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+		if step.matches("NclsdFile") { optional_repeated(&self.nclsd_file, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("StsId") { optional_mut(&mut self.sts_id, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("TxSts") { optional_mut(&mut self.tx_sts, step, create, out) }
+		if step.matches("StsRsnInf") { optional_repeated_mut(&mut self.sts_rsn_inf, step, create, out) }
+		if step.matches("PmtCondSts") { optional_mut(&mut self.pmt_cond_sts, step, create, out) }
+		if step.matches("ChrgsInf") { optional_repeated_mut(&mut self.chrgs_inf, step, create, out) }
+		if step.matches("DbtrDcsnDtTm") { optional_mut(&mut self.dbtr_dcsn_dt_tm, step, create, out) }
+		if step.matches("AccptncDtTm") { optional_mut(&mut self.accptnc_dt_tm, step, create, out) }
+		if step.matches("AcctSvcrRef") { optional_mut(&mut self.acct_svcr_ref, step, create, out) }
+		if step.matches("ClrSysRef") { optional_mut(&mut self.clr_sys_ref, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+		if step.matches("NclsdFile") { optional_repeated_mut(&mut self.nclsd_file, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTransaction106 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CxlId") { optional(&self.cxl_id, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlTxId") { optional(&self.orgnl_tx_id, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("OrgnlClrSysRef") { optional(&self.orgnl_clr_sys_ref, step, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { optional(&self.orgnl_intr_bk_sttlm_amt, step, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { optional(&self.orgnl_intr_bk_sttlm_dt, step, out) }
+		if step.matches("Assgnr") { optional(&self.assgnr, step, out) }
+		if step.matches("Assgne") { optional(&self.assgne, step, out) }
+		if step.matches("CxlRsnInf") { optional_repeated(&self.cxl_rsn_inf, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CxlId") { optional_mut(&mut self.cxl_id, step, create, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlTxId") { optional_mut(&mut self.orgnl_tx_id, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("OrgnlClrSysRef") { optional_mut(&mut self.orgnl_clr_sys_ref, step, create, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { optional_mut(&mut self.orgnl_intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { optional_mut(&mut self.orgnl_intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("Assgnr") { optional_mut(&mut self.assgnr, step, create, out) }
+		if step.matches("Assgne") { optional_mut(&mut self.assgne, step, create, out) }
+		if step.matches("CxlRsnInf") { optional_repeated_mut(&mut self.cxl_rsn_inf, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTransaction107 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ModStsId") { optional(&self.mod_sts_id, step, out) }
+		if step.matches("RslvdCase") { optional(&self.rslvd_case, step, out) }
+		if step.matches("OrgnlGrpInf") { one(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlPmtInfId") { optional(&self.orgnl_pmt_inf_id, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlTxId") { optional(&self.orgnl_tx_id, step, out) }
+		if step.matches("OrgnlClrSysRef") { optional(&self.orgnl_clr_sys_ref, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("ModStsRsnInf") { optional_repeated(&self.mod_sts_rsn_inf, step, out) }
+		if step.matches("RsltnRltdInf") { optional(&self.rsltn_rltd_inf, step, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { optional(&self.orgnl_intr_bk_sttlm_amt, step, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { optional(&self.orgnl_intr_bk_sttlm_dt, step, out) }
+		if step.matches("Assgnr") { optional(&self.assgnr, step, out) }
+		if step.matches("Assgne") { optional(&self.assgne, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ModStsId") { optional_mut(&mut self.mod_sts_id, step, create, out) }
+		if step.matches("RslvdCase") { optional_mut(&mut self.rslvd_case, step, create, out) }
+		if step.matches("OrgnlGrpInf") { one_mut(&mut self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlPmtInfId") { optional_mut(&mut self.orgnl_pmt_inf_id, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlTxId") { optional_mut(&mut self.orgnl_tx_id, step, create, out) }
+		if step.matches("OrgnlClrSysRef") { optional_mut(&mut self.orgnl_clr_sys_ref, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("ModStsRsnInf") { optional_repeated_mut(&mut self.mod_sts_rsn_inf, step, create, out) }
+		if step.matches("RsltnRltdInf") { optional_mut(&mut self.rsltn_rltd_inf, step, create, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { optional_mut(&mut self.orgnl_intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { optional_mut(&mut self.orgnl_intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("Assgnr") { optional_mut(&mut self.assgnr, step, create, out) }
+		if step.matches("Assgne") { optional_mut(&mut self.assgne, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTransaction110 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("StsId") { optional(&self.sts_id, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlTxId") { optional(&self.orgnl_tx_id, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("TxSts") { optional(&self.tx_sts, step, out) }
+		if step.matches("StsRsnInf") { optional_repeated(&self.sts_rsn_inf, step, out) }
+		if step.matches("ChrgsInf") { optional_repeated(&self.chrgs_inf, step, out) }
+		if step.matches("AccptncDtTm") { optional(&self.accptnc_dt_tm, step, out) }
+		if step.matches("FctvIntrBkSttlmDt") { optional(&self.fctv_intr_bk_sttlm_dt, step, out) }
+		if step.matches("AcctSvcrRef") { optional(&self.acct_svcr_ref, step, out) }
+		if step.matches("ClrSysRef") { optional(&self.clr_sys_ref, step, out) }
+		if step.matches("InstgAgt") { optional(&self.instg_agt, step, out) }
+		if step.matches("InstdAgt") { optional(&self.instd_agt, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("StsId") { optional_mut(&mut self.sts_id, step, create, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlTxId") { optional_mut(&mut self.orgnl_tx_id, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("TxSts") { optional_mut(&mut self.tx_sts, step, create, out) }
+		if step.matches("StsRsnInf") { optional_repeated_mut(&mut self.sts_rsn_inf, step, create, out) }
+		if step.matches("ChrgsInf") { optional_repeated_mut(&mut self.chrgs_inf, step, create, out) }
+		if step.matches("AccptncDtTm") { optional_mut(&mut self.accptnc_dt_tm, step, create, out) }
+		if step.matches("FctvIntrBkSttlmDt") { optional_mut(&mut self.fctv_intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("AcctSvcrRef") { optional_mut(&mut self.acct_svcr_ref, step, create, out) }
+		if step.matches("ClrSysRef") { optional_mut(&mut self.clr_sys_ref, step, create, out) }
+		if step.matches("InstgAgt") { optional_mut(&mut self.instg_agt, step, create, out) }
+		if step.matches("InstdAgt") { optional_mut(&mut self.instd_agt, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTransaction113 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("StsReqId") { optional(&self.sts_req_id, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlTxId") { optional(&self.orgnl_tx_id, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("AccptncDtTm") { optional(&self.accptnc_dt_tm, step, out) }
+		if step.matches("ClrSysRef") { optional(&self.clr_sys_ref, step, out) }
+		if step.matches("InstgAgt") { optional(&self.instg_agt, step, out) }
+		if step.matches("InstdAgt") { optional(&self.instd_agt, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("StsReqId") { optional_mut(&mut self.sts_req_id, step, create, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlTxId") { optional_mut(&mut self.orgnl_tx_id, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("AccptncDtTm") { optional_mut(&mut self.accptnc_dt_tm, step, create, out) }
+		if step.matches("ClrSysRef") { optional_mut(&mut self.clr_sys_ref, step, create, out) }
+		if step.matches("InstgAgt") { optional_mut(&mut self.instg_agt, step, create, out) }
+		if step.matches("InstdAgt") { optional_mut(&mut self.instd_agt, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTransaction118 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RtrId") { optional(&self.rtr_id, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlTxId") { optional(&self.orgnl_tx_id, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("OrgnlClrSysRef") { optional(&self.orgnl_clr_sys_ref, step, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { optional(&self.orgnl_intr_bk_sttlm_amt, step, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { optional(&self.orgnl_intr_bk_sttlm_dt, step, out) }
+		if step.matches("RtrdIntrBkSttlmAmt") { one(&self.rtrd_intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("SttlmPrty") { optional(&self.sttlm_prty, step, out) }
+		if step.matches("SttlmTmIndctn") { optional(&self.sttlm_tm_indctn, step, out) }
+		if step.matches("RtrdInstdAmt") { optional(&self.rtrd_instd_amt, step, out) }
+		if step.matches("XchgRate") { optional(&self.xchg_rate, step, out) }
+		if step.matches("CompstnAmt") { optional(&self.compstn_amt, step, out) }
+		if step.matches("ChrgBr") { optional(&self.chrg_br, step, out) }
+		if step.matches("ChrgsInf") { optional_repeated(&self.chrgs_inf, step, out) }
+		if step.matches("ClrSysRef") { optional(&self.clr_sys_ref, step, out) }
+		if step.matches("InstgAgt") { optional(&self.instg_agt, step, out) }
+		if step.matches("InstdAgt") { optional(&self.instd_agt, step, out) }
+		if step.matches("RtrChain") { optional(&self.rtr_chain, step, out) }
+		if step.matches("RtrRsnInf") { optional_repeated(&self.rtr_rsn_inf, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RtrId") { optional_mut(&mut self.rtr_id, step, create, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlTxId") { optional_mut(&mut self.orgnl_tx_id, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("OrgnlClrSysRef") { optional_mut(&mut self.orgnl_clr_sys_ref, step, create, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { optional_mut(&mut self.orgnl_intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { optional_mut(&mut self.orgnl_intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("RtrdIntrBkSttlmAmt") { one_mut(&mut self.rtrd_intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("SttlmPrty") { optional_mut(&mut self.sttlm_prty, step, create, out) }
+		if step.matches("SttlmTmIndctn") { optional_mut(&mut self.sttlm_tm_indctn, step, create, out) }
+		if step.matches("RtrdInstdAmt") { optional_mut(&mut self.rtrd_instd_amt, step, create, out) }
+		if step.matches("XchgRate") { optional_mut(&mut self.xchg_rate, step, create, out) }
+		if step.matches("CompstnAmt") { optional_mut(&mut self.compstn_amt, step, create, out) }
+		if step.matches("ChrgBr") { optional_mut(&mut self.chrg_br, step, create, out) }
+		if step.matches("ChrgsInf") { optional_repeated_mut(&mut self.chrgs_inf, step, create, out) }
+		if step.matches("ClrSysRef") { optional_mut(&mut self.clr_sys_ref, step, create, out) }
+		if step.matches("InstgAgt") { optional_mut(&mut self.instg_agt, step, create, out) }
+		if step.matches("InstdAgt") { optional_mut(&mut self.instd_agt, step, create, out) }
+		if step.matches("RtrChain") { optional_mut(&mut self.rtr_chain, step, create, out) }
+		if step.matches("RtrRsnInf") { optional_repeated_mut(&mut self.rtr_rsn_inf, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTransaction124 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CxlId") { optional(&self.cxl_id, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("OrgnlInstdAmt") { optional(&self.orgnl_instd_amt, step, out) }
+		if step.matches("OrgnlReqdExctnDt") { optional(&self.orgnl_reqd_exctn_dt, step, out) }
+		if step.matches("OrgnlReqdColltnDt") { optional(&self.orgnl_reqd_colltn_dt, step, out) }
+		if step.matches("CxlRsnInf") { optional_repeated(&self.cxl_rsn_inf, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CxlId") { optional_mut(&mut self.cxl_id, step, create, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("OrgnlInstdAmt") { optional_mut(&mut self.orgnl_instd_amt, step, create, out) }
+		if step.matches("OrgnlReqdExctnDt") { optional_mut(&mut self.orgnl_reqd_exctn_dt, step, create, out) }
+		if step.matches("OrgnlReqdColltnDt") { optional_mut(&mut self.orgnl_reqd_colltn_dt, step, create, out) }
+		if step.matches("CxlRsnInf") { optional_repeated_mut(&mut self.cxl_rsn_inf, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTypeInformation26 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstrPrty") { optional(&self.instr_prty, step, out) }
+		if step.matches("SvcLvl") { optional_repeated(&self.svc_lvl, step, out) }
+		if step.matches("LclInstrm") { optional(&self.lcl_instrm, step, out) }
+		if step.matches("CtgyPurp") { optional(&self.ctgy_purp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstrPrty") { optional_mut(&mut self.instr_prty, step, create, out) }
+		if step.matches("SvcLvl") { optional_repeated_mut(&mut self.svc_lvl, step, create, out) }
+		if step.matches("LclInstrm") { optional_mut(&mut self.lcl_instrm, step, create, out) }
+		if step.matches("CtgyPurp") { optional_mut(&mut self.ctgy_purp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTypeInformation27 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstrPrty") { optional(&self.instr_prty, step, out) }
+		if step.matches("ClrChanl") { optional(&self.clr_chanl, step, out) }
+		if step.matches("SvcLvl") { optional_repeated(&self.svc_lvl, step, out) }
+		if step.matches("LclInstrm") { optional(&self.lcl_instrm, step, out) }
+		if step.matches("SeqTp") { optional(&self.seq_tp, step, out) }
+		if step.matches("CtgyPurp") { optional(&self.ctgy_purp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstrPrty") { optional_mut(&mut self.instr_prty, step, create, out) }
+		if step.matches("ClrChanl") { optional_mut(&mut self.clr_chanl, step, create, out) }
+		if step.matches("SvcLvl") { optional_repeated_mut(&mut self.svc_lvl, step, create, out) }
+		if step.matches("LclInstrm") { optional_mut(&mut self.lcl_instrm, step, create, out) }
+		if step.matches("SeqTp") { optional_mut(&mut self.seq_tp, step, create, out) }
+		if step.matches("CtgyPurp") { optional_mut(&mut self.ctgy_purp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PaymentTypeInformation28 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstrPrty") { optional(&self.instr_prty, step, out) }
+		if step.matches("ClrChanl") { optional(&self.clr_chanl, step, out) }
+		if step.matches("SvcLvl") { optional_repeated(&self.svc_lvl, step, out) }
+		if step.matches("LclInstrm") { optional(&self.lcl_instrm, step, out) }
+		if step.matches("CtgyPurp") { optional(&self.ctgy_purp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstrPrty") { optional_mut(&mut self.instr_prty, step, create, out) }
+		if step.matches("ClrChanl") { optional_mut(&mut self.clr_chanl, step, create, out) }
+		if step.matches("SvcLvl") { optional_repeated_mut(&mut self.svc_lvl, step, create, out) }
+		if step.matches("LclInstrm") { optional_mut(&mut self.lcl_instrm, step, create, out) }
+		if step.matches("CtgyPurp") { optional_mut(&mut self.ctgy_purp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PersonIdentification13 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DtAndPlcOfBirth") { optional(&self.dt_and_plc_of_birth, step, out) }
+		if step.matches("Othr") { optional_repeated(&self.othr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DtAndPlcOfBirth") { optional_mut(&mut self.dt_and_plc_of_birth, step, create, out) }
+		if step.matches("Othr") { optional_repeated_mut(&mut self.othr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PersonIdentificationSchemeName1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PlainCardData1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PAN") { one(&self.pan, step, out) }
+		if step.matches("CardSeqNb") { optional(&self.card_seq_nb, step, out) }
+		if step.matches("FctvDt") { optional(&self.fctv_dt, step, out) }
+		if step.matches("XpryDt") { one(&self.xpry_dt, step, out) }
+		if step.matches("SvcCd") { optional(&self.svc_cd, step, out) }
+		if step.matches("TrckData") { optional_repeated(&self.trck_data, step, out) }
+		if step.matches("CardSctyCd") { optional(&self.card_scty_cd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PAN") { one_mut(&mut self.pan, step, out) }
+		if step.matches("CardSeqNb") { optional_mut(&mut self.card_seq_nb, step, create, out) }
+		if step.matches("FctvDt") { optional_mut(&mut self.fctv_dt, step, create, out) }
+		if step.matches("XpryDt") { one_mut(&mut self.xpry_dt, step, out) }
+		if step.matches("SvcCd") { optional_mut(&mut self.svc_cd, step, create, out) }
+		if step.matches("TrckData") { optional_repeated_mut(&mut self.trck_data, step, create, out) }
+		if step.matches("CardSctyCd") { optional_mut(&mut self.card_scty_cd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PointOfInteraction1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("SysNm") { optional(&self.sys_nm, step, out) }
+		if step.matches("GrpId") { optional(&self.grp_id, step, out) }
+		if step.matches("Cpblties") { optional(&self.cpblties, step, out) }
+		if step.matches("Cmpnt") { optional_repeated(&self.cmpnt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("SysNm") { optional_mut(&mut self.sys_nm, step, create, out) }
+		if step.matches("GrpId") { optional_mut(&mut self.grp_id, step, create, out) }
+		if step.matches("Cpblties") { optional_mut(&mut self.cpblties, step, create, out) }
+		if step.matches("Cmpnt") { optional_repeated_mut(&mut self.cmpnt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PointOfInteractionCapabilities1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CardRdngCpblties") { optional_repeated(&self.card_rdng_cpblties, step, out) }
+		if step.matches("CrdhldrVrfctnCpblties") { optional_repeated(&self.crdhldr_vrfctn_cpblties, step, out) }
+		if step.matches("OnLineCpblties") { optional(&self.on_line_cpblties, step, out) }
+		if step.matches("DispCpblties") { optional_repeated(&self.disp_cpblties, step, out) }
+		if step.matches("PrtLineWidth") { optional(&self.prt_line_width, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CardRdngCpblties") { optional_repeated_mut(&mut self.card_rdng_cpblties, step, create, out) }
+		if step.matches("CrdhldrVrfctnCpblties") { optional_repeated_mut(&mut self.crdhldr_vrfctn_cpblties, step, create, out) }
+		if step.matches("OnLineCpblties") { optional_mut(&mut self.on_line_cpblties, step, create, out) }
+		if step.matches("DispCpblties") { optional_repeated_mut(&mut self.disp_cpblties, step, create, out) }
+		if step.matches("PrtLineWidth") { optional_mut(&mut self.prt_line_width, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PointOfInteractionComponent1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("POICmpntTp") { one(&self.poi_cmpnt_tp, step, out) }
+		if step.matches("ManfctrId") { optional(&self.manfctr_id, step, out) }
+		if step.matches("Mdl") { optional(&self.mdl, step, out) }
+		if step.matches("VrsnNb") { optional(&self.vrsn_nb, step, out) }
+		if step.matches("SrlNb") { optional(&self.srl_nb, step, out) }
+		if step.matches("ApprvlNb") { optional_repeated(&self.apprvl_nb, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("POICmpntTp") { one_mut(&mut self.poi_cmpnt_tp, step, out) }
+		if step.matches("ManfctrId") { optional_mut(&mut self.manfctr_id, step, create, out) }
+		if step.matches("Mdl") { optional_mut(&mut self.mdl, step, create, out) }
+		if step.matches("VrsnNb") { optional_mut(&mut self.vrsn_nb, step, create, out) }
+		if step.matches("SrlNb") { optional_mut(&mut self.srl_nb, step, create, out) }
+		if step.matches("ApprvlNb") { optional_repeated_mut(&mut self.apprvl_nb, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PostalAddress1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AdrTp") { optional(&self.adr_tp, step, out) }
+		if step.matches("AdrLine") { optional_repeated(&self.adr_line, step, out) }
+		if step.matches("StrtNm") { optional(&self.strt_nm, step, out) }
+		if step.matches("BldgNb") { optional(&self.bldg_nb, step, out) }
+		if step.matches("PstCd") { optional(&self.pst_cd, step, out) }
+		if step.matches("TwnNm") { optional(&self.twn_nm, step, out) }
+		if step.matches("CtrySubDvsn") { optional(&self.ctry_sub_dvsn, step, out) }
+		if step.matches("Ctry") { one(&self.ctry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AdrTp") { optional_mut(&mut self.adr_tp, step, create, out) }
+		if step.matches("AdrLine") { optional_repeated_mut(&mut self.adr_line, step, create, out) }
+		if step.matches("StrtNm") { optional_mut(&mut self.strt_nm, step, create, out) }
+		if step.matches("BldgNb") { optional_mut(&mut self.bldg_nb, step, create, out) }
+		if step.matches("PstCd") { optional_mut(&mut self.pst_cd, step, create, out) }
+		if step.matches("TwnNm") { optional_mut(&mut self.twn_nm, step, create, out) }
+		if step.matches("CtrySubDvsn") { optional_mut(&mut self.ctry_sub_dvsn, step, create, out) }
+		if step.matches("Ctry") { one_mut(&mut self.ctry, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::PostalAddress24 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AdrTp") { optional(&self.adr_tp, step, out) }
+		if step.matches("Dept") { optional(&self.dept, step, out) }
+		if step.matches("SubDept") { optional(&self.sub_dept, step, out) }
+		if step.matches("StrtNm") { optional(&self.strt_nm, step, out) }
+		if step.matches("BldgNb") { optional(&self.bldg_nb, step, out) }
+		if step.matches("BldgNm") { optional(&self.bldg_nm, step, out) }
+		if step.matches("Flr") { optional(&self.flr, step, out) }
+		if step.matches("PstBx") { optional(&self.pst_bx, step, out) }
+		if step.matches("Room") { optional(&self.room, step, out) }
+		if step.matches("PstCd") { optional(&self.pst_cd, step, out) }
+		if step.matches("TwnNm") { optional(&self.twn_nm, step, out) }
+		if step.matches("TwnLctnNm") { optional(&self.twn_lctn_nm, step, out) }
+		if step.matches("DstrctNm") { optional(&self.dstrct_nm, step, out) }
+		if step.matches("CtrySubDvsn") { optional(&self.ctry_sub_dvsn, step, out) }
+		if step.matches("Ctry") { optional(&self.ctry, step, out) }
+		if step.matches("AdrLine") { optional_repeated(&self.adr_line, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AdrTp") { optional_mut(&mut self.adr_tp, step, create, out) }
+		if step.matches("Dept") { optional_mut(&mut self.dept, step, create, out) }
+		if step.matches("SubDept") { optional_mut(&mut self.sub_dept, step, create, out) }
+		if step.matches("StrtNm") { optional_mut(&mut self.strt_nm, step, create, out) }
+		if step.matches("BldgNb") { optional_mut(&mut self.bldg_nb, step, create, out) }
+		if step.matches("BldgNm") { optional_mut(&mut self.bldg_nm, step, create, out) }
+		if step.matches("Flr") { optional_mut(&mut self.flr, step, create, out) }
+		if step.matches("PstBx") { optional_mut(&mut self.pst_bx, step, create, out) }
+		if step.matches("Room") { optional_mut(&mut self.room, step, create, out) }
+		if step.matches("PstCd") { optional_mut(&mut self.pst_cd, step, create, out) }
+		if step.matches("TwnNm") { optional_mut(&mut self.twn_nm, step, create, out) }
+		if step.matches("TwnLctnNm") { optional_mut(&mut self.twn_lctn_nm, step, create, out) }
+		if step.matches("DstrctNm") { optional_mut(&mut self.dstrct_nm, step, create, out) }
+		if step.matches("CtrySubDvsn") { optional_mut(&mut self.ctry_sub_dvsn, step, create, out) }
+		if step.matches("Ctry") { optional_mut(&mut self.ctry, step, create, out) }
+		if step.matches("AdrLine") { optional_repeated_mut(&mut self.adr_line, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PreferredContactMethod1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeLETT => "LETT",
+			Self::CodeMAIL => "MAIL",
+			Self::CodePHON => "PHON",
+			Self::CodeFAXX => "FAXX",
+			Self::CodeCELL => "CELL",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"LETT" => Self::CodeLETT,
+			"MAIL" => Self::CodeMAIL,
+			"PHON" => Self::CodePHON,
+			"FAXX" => Self::CodeFAXX,
+			"CELL" => Self::CodeCELL,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Price7 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Val") { one(&self.val, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Val") { one_mut(&mut self.val, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::PriceRateOrAmount3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Rate") { optional(&self.rate, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Rate") { optional_mut(&mut self.rate, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::PriceValueType1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeDISC => "DISC",
+			Self::CodePREM => "PREM",
+			Self::CodePARV => "PARV",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"DISC" => Self::CodeDISC,
+			"PREM" => Self::CodePREM,
+			"PARV" => Self::CodePARV,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Priority2Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeHIGH => "HIGH",
+			Self::CodeNORM => "NORM",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"HIGH" => Self::CodeHIGH,
+			"NORM" => Self::CodeNORM,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Priority3Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeURGT => "URGT",
+			Self::CodeHIGH => "HIGH",
+			Self::CodeNORM => "NORM",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"URGT" => Self::CodeURGT,
+			"HIGH" => Self::CodeHIGH,
+			"NORM" => Self::CodeNORM,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Product2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PdctCd") { one(&self.pdct_cd, step, out) }
+		if step.matches("UnitOfMeasr") { optional(&self.unit_of_measr, step, out) }
+		if step.matches("PdctQty") { optional(&self.pdct_qty, step, out) }
+		if step.matches("UnitPric") { optional(&self.unit_pric, step, out) }
+		if step.matches("PdctAmt") { optional(&self.pdct_amt, step, out) }
+		if step.matches("TaxTp") { optional(&self.tax_tp, step, out) }
+		if step.matches("AddtlPdctInf") { optional(&self.addtl_pdct_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PdctCd") { one_mut(&mut self.pdct_cd, step, out) }
+		if step.matches("UnitOfMeasr") { optional_mut(&mut self.unit_of_measr, step, create, out) }
+		if step.matches("PdctQty") { optional_mut(&mut self.pdct_qty, step, create, out) }
+		if step.matches("UnitPric") { optional_mut(&mut self.unit_pric, step, create, out) }
+		if step.matches("PdctAmt") { optional_mut(&mut self.pdct_amt, step, create, out) }
+		if step.matches("TaxTp") { optional_mut(&mut self.tax_tp, step, create, out) }
+		if step.matches("AddtlPdctInf") { optional_mut(&mut self.addtl_pdct_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProprietaryAgent4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Agt") { one(&self.agt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Agt") { one_mut(&mut self.agt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProprietaryBankTransactionCodeStructure1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { one(&self.cd, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { one_mut(&mut self.cd, step, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProprietaryData5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Data") { one(&self.data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Data") { one_mut(&mut self.data, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProprietaryDate3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Dt") { one(&self.dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Dt") { one_mut(&mut self.dt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProprietaryParty5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Pty") { one(&self.pty, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Pty") { one_mut(&mut self.pty, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProprietaryPrice2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Pric") { one(&self.pric, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Pric") { one_mut(&mut self.pric, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProprietaryQuantity1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Qty") { one(&self.qty, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Qty") { one_mut(&mut self.qty, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProprietaryReference1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("Ref") { one(&self.ref_attr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("Ref") { one_mut(&mut self.ref_attr, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProxyAccountIdentification1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Id") { one(&self.id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ProxyAccountType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::Purpose2Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::QueryType3Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeALLL => "ALLL",
+			Self::CodeCHNG => "CHNG",
+			Self::CodeMODF => "MODF",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"ALLL" => Self::CodeALLL,
+			"CHNG" => Self::CodeCHNG,
+			"MODF" => Self::CodeMODF,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::Rate4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { one(&self.tp, step, out) }
+		if step.matches("VldtyRg") { optional(&self.vldty_rg, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+		if step.matches("VldtyRg") { optional_mut(&mut self.vldty_rg, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RateType4Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Pctg") { optional(&self.pctg, step, out) }
+		if step.matches("Othr") { optional(&self.othr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Pctg") { optional_mut(&mut self.pctg, step, create, out) }
+		if step.matches("Othr") { optional_mut(&mut self.othr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ReceiptAcknowledgementReport2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RltdRef") { one(&self.rltd_ref, step, out) }
+		if step.matches("ReqHdlg") { one(&self.req_hdlg, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RltdRef") { one_mut(&mut self.rltd_ref, step, out) }
+		if step.matches("ReqHdlg") { one_mut(&mut self.req_hdlg, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ReferredDocumentInformation7 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Nb") { optional(&self.nb, step, out) }
+		if step.matches("RltdDt") { optional(&self.rltd_dt, step, out) }
+		if step.matches("LineDtls") { optional_repeated(&self.line_dtls, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Nb") { optional_mut(&mut self.nb, step, create, out) }
+		if step.matches("RltdDt") { optional_mut(&mut self.rltd_dt, step, create, out) }
+		if step.matches("LineDtls") { optional_repeated_mut(&mut self.line_dtls, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ReferredDocumentType3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ReferredDocumentType4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one(&self.cd_or_prtry, step, out) }
+		if step.matches("Issr") { optional(&self.issr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CdOrPrtry") { one_mut(&mut self.cd_or_prtry, step, out) }
+		if step.matches("Issr") { optional_mut(&mut self.issr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RegulatoryAuthority2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+		if step.matches("Ctry") { optional(&self.ctry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+		if step.matches("Ctry") { optional_mut(&mut self.ctry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RegulatoryReporting3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DbtCdtRptgInd") { optional(&self.dbt_cdt_rptg_ind, step, out) }
+		if step.matches("Authrty") { optional(&self.authrty, step, out) }
+		if step.matches("Dtls") { optional_repeated(&self.dtls, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DbtCdtRptgInd") { optional_mut(&mut self.dbt_cdt_rptg_ind, step, create, out) }
+		if step.matches("Authrty") { optional_mut(&mut self.authrty, step, create, out) }
+		if step.matches("Dtls") { optional_repeated_mut(&mut self.dtls, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RegulatoryReportingType1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeCRED => "CRED",
+			Self::CodeDEBT => "DEBT",
+			Self::CodeBOTH => "BOTH",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"CRED" => Self::CodeCRED,
+			"DEBT" => Self::CodeDEBT,
+			"BOTH" => Self::CodeBOTH,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::RejectionReason2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RjctgPtyRsn") { one(&self.rjctg_pty_rsn, step, out) }
+		if step.matches("RjctnDtTm") { optional(&self.rjctn_dt_tm, step, out) }
+		if step.matches("ErrLctn") { optional(&self.err_lctn, step, out) }
+		if step.matches("RsnDesc") { optional(&self.rsn_desc, step, out) }
+		if step.matches("AddtlData") { optional(&self.addtl_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RjctgPtyRsn") { one_mut(&mut self.rjctg_pty_rsn, step, out) }
+		if step.matches("RjctnDtTm") { optional_mut(&mut self.rjctn_dt_tm, step, create, out) }
+		if step.matches("ErrLctn") { optional_mut(&mut self.err_lctn, step, create, out) }
+		if step.matches("RsnDesc") { optional_mut(&mut self.rsn_desc, step, create, out) }
+		if step.matches("AddtlData") { optional_mut(&mut self.addtl_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RemittanceAmount2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DuePyblAmt") { optional(&self.due_pybl_amt, step, out) }
+		if step.matches("DscntApldAmt") { optional_repeated(&self.dscnt_apld_amt, step, out) }
+		if step.matches("CdtNoteAmt") { optional(&self.cdt_note_amt, step, out) }
+		if step.matches("TaxAmt") { optional_repeated(&self.tax_amt, step, out) }
+		if step.matches("AdjstmntAmtAndRsn") { optional_repeated(&self.adjstmnt_amt_and_rsn, step, out) }
+		if step.matches("RmtdAmt") { optional(&self.rmtd_amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DuePyblAmt") { optional_mut(&mut self.due_pybl_amt, step, create, out) }
+		if step.matches("DscntApldAmt") { optional_repeated_mut(&mut self.dscnt_apld_amt, step, create, out) }
+		if step.matches("CdtNoteAmt") { optional_mut(&mut self.cdt_note_amt, step, create, out) }
+		if step.matches("TaxAmt") { optional_repeated_mut(&mut self.tax_amt, step, create, out) }
+		if step.matches("AdjstmntAmtAndRsn") { optional_repeated_mut(&mut self.adjstmnt_amt_and_rsn, step, create, out) }
+		if step.matches("RmtdAmt") { optional_mut(&mut self.rmtd_amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RemittanceAmount3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DuePyblAmt") { optional(&self.due_pybl_amt, step, out) }
+		if step.matches("DscntApldAmt") { optional_repeated(&self.dscnt_apld_amt, step, out) }
+		if step.matches("CdtNoteAmt") { optional(&self.cdt_note_amt, step, out) }
+		if step.matches("TaxAmt") { optional_repeated(&self.tax_amt, step, out) }
+		if step.matches("AdjstmntAmtAndRsn") { optional_repeated(&self.adjstmnt_amt_and_rsn, step, out) }
+		if step.matches("RmtdAmt") { optional(&self.rmtd_amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DuePyblAmt") { optional_mut(&mut self.due_pybl_amt, step, create, out) }
+		if step.matches("DscntApldAmt") { optional_repeated_mut(&mut self.dscnt_apld_amt, step, create, out) }
+		if step.matches("CdtNoteAmt") { optional_mut(&mut self.cdt_note_amt, step, create, out) }
+		if step.matches("TaxAmt") { optional_repeated_mut(&mut self.tax_amt, step, create, out) }
+		if step.matches("AdjstmntAmtAndRsn") { optional_repeated_mut(&mut self.adjstmnt_amt_and_rsn, step, create, out) }
+		if step.matches("RmtdAmt") { optional_mut(&mut self.rmtd_amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RemittanceInformation16 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Ustrd") { optional_repeated(&self.ustrd, step, out) }
+		if step.matches("Strd") { optional_repeated(&self.strd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Ustrd") { optional_repeated_mut(&mut self.ustrd, step, create, out) }
+		if step.matches("Strd") { optional_repeated_mut(&mut self.strd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RemittanceInformation2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Ustrd") { optional_repeated(&self.ustrd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Ustrd") { optional_repeated_mut(&mut self.ustrd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RemittanceLocation7 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RmtId") { optional(&self.rmt_id, step, out) }
+		if step.matches("RmtLctnDtls") { optional_repeated(&self.rmt_lctn_dtls, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RmtId") { optional_mut(&mut self.rmt_id, step, create, out) }
+		if step.matches("RmtLctnDtls") { optional_repeated_mut(&mut self.rmt_lctn_dtls, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RemittanceLocationData1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Mtd") { one(&self.mtd, step, out) }
+		if step.matches("ElctrncAdr") { optional(&self.elctrnc_adr, step, out) }
+		if step.matches("PstlAdr") { optional(&self.pstl_adr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Mtd") { one_mut(&mut self.mtd, step, out) }
+		if step.matches("ElctrncAdr") { optional_mut(&mut self.elctrnc_adr, step, create, out) }
+		if step.matches("PstlAdr") { optional_mut(&mut self.pstl_adr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RemittanceLocationMethod2Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeFAXI => "FAXI",
+			Self::CodeEDIC => "EDIC",
+			Self::CodeURID => "URID",
+			Self::CodeEMAL => "EMAL",
+			Self::CodePOST => "POST",
+			Self::CodeSMSM => "SMSM",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"FAXI" => Self::CodeFAXI,
+			"EDIC" => Self::CodeEDIC,
+			"URID" => Self::CodeURID,
+			"EMAL" => Self::CodeEMAL,
+			"POST" => Self::CodePOST,
+			"SMSM" => Self::CodeSMSM,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::ReportEntry10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("NtryRef") { optional(&self.ntry_ref, step, out) }
+		if step.matches("Amt") { one(&self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one(&self.cdt_dbt_ind, step, out) }
+		if step.matches("RvslInd") { optional(&self.rvsl_ind, step, out) }
+		if step.matches("Sts") { one(&self.sts, step, out) }
+		if step.matches("BookgDt") { optional(&self.bookg_dt, step, out) }
+		if step.matches("ValDt") { optional(&self.val_dt, step, out) }
+		if step.matches("AcctSvcrRef") { optional(&self.acct_svcr_ref, step, out) }
+		if step.matches("Avlbty") { optional_repeated(&self.avlbty, step, out) }
+		if step.matches("BkTxCd") { one(&self.bk_tx_cd, step, out) }
+		if step.matches("ComssnWvrInd") { optional(&self.comssn_wvr_ind, step, out) }
+		if step.matches("AddtlInfInd") { optional(&self.addtl_inf_ind, step, out) }
+		if step.matches("AmtDtls") { optional(&self.amt_dtls, step, out) }
+		if step.matches("Chrgs") { optional(&self.chrgs, step, out) }
+		if step.matches("TechInptChanl") { optional(&self.tech_inpt_chanl, step, out) }
+		if step.matches("Intrst") { optional(&self.intrst, step, out) }
+		if step.matches("CardTx") { optional(&self.card_tx, step, out) }
+		if step.matches("NtryDtls") { optional_repeated(&self.ntry_dtls, step, out) }
+		if step.matches("AddtlNtryInf") { optional(&self.addtl_ntry_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("NtryRef") { optional_mut(&mut self.ntry_ref, step, create, out) }
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+		if step.matches("CdtDbtInd") { one_mut(&mut self.cdt_dbt_ind, step, out) }
+		if step.matches("RvslInd") { optional_mut(&mut self.rvsl_ind, step, create, out) }
+		if step.matches("Sts") { one_mut(&mut self.sts, step, out) }
+		if step.matches("BookgDt") { optional_mut(&mut self.bookg_dt, step, create, out) }
+		if step.matches("ValDt") { optional_mut(&mut self.val_dt, step, create, out) }
+		if step.matches("AcctSvcrRef") { optional_mut(&mut self.acct_svcr_ref, step, create, out) }
+		if step.matches("Avlbty") { optional_repeated_mut(&mut self.avlbty, step, create, out) }
+		if step.matches("BkTxCd") { one_mut(&mut self.bk_tx_cd, step, out) }
+		if step.matches("ComssnWvrInd") { optional_mut(&mut self.comssn_wvr_ind, step, create, out) }
+		if step.matches("AddtlInfInd") { optional_mut(&mut self.addtl_inf_ind, step, create, out) }
+		if step.matches("AmtDtls") { optional_mut(&mut self.amt_dtls, step, create, out) }
+		if step.matches("Chrgs") { optional_mut(&mut self.chrgs, step, create, out) }
+		if step.matches("TechInptChanl") { optional_mut(&mut self.tech_inpt_chanl, step, create, out) }
+		if step.matches("Intrst") { optional_mut(&mut self.intrst, step, create, out) }
+		if step.matches("CardTx") { optional_mut(&mut self.card_tx, step, create, out) }
+		if step.matches("NtryDtls") { optional_repeated_mut(&mut self.ntry_dtls, step, create, out) }
+		if step.matches("AddtlNtryInf") { optional_mut(&mut self.addtl_ntry_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ReportingPeriod2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrToDt") { one(&self.fr_to_dt, step, out) }
+		if step.matches("FrToTm") { optional(&self.fr_to_tm, step, out) }
+		if step.matches("Tp") { one(&self.tp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrToDt") { one_mut(&mut self.fr_to_dt, step, out) }
+		if step.matches("FrToTm") { optional_mut(&mut self.fr_to_tm, step, create, out) }
+		if step.matches("Tp") { one_mut(&mut self.tp, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ReportingRequest5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("ReqdMsgNmId") { one(&self.reqd_msg_nm_id, step, out) }
+		if step.matches("Acct") { optional(&self.acct, step, out) }
+		if step.matches("AcctOwnr") { one(&self.acct_ownr, step, out) }
+		if step.matches("AcctSvcr") { optional(&self.acct_svcr, step, out) }
+		if step.matches("RptgPrd") { optional(&self.rptg_prd, step, out) }
+		if step.matches("RptgSeq") { optional(&self.rptg_seq, step, out) }
+		if step.matches("ReqdTxTp") { optional(&self.reqd_tx_tp, step, out) }
+		if step.matches("ReqdBalTp") { optional_repeated(&self.reqd_bal_tp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("ReqdMsgNmId") { one_mut(&mut self.reqd_msg_nm_id, step, out) }
+		if step.matches("Acct") { optional_mut(&mut self.acct, step, create, out) }
+		if step.matches("AcctOwnr") { one_mut(&mut self.acct_ownr, step, out) }
+		if step.matches("AcctSvcr") { optional_mut(&mut self.acct_svcr, step, create, out) }
+		if step.matches("RptgPrd") { optional_mut(&mut self.rptg_prd, step, create, out) }
+		if step.matches("RptgSeq") { optional_mut(&mut self.rptg_seq, step, create, out) }
+		if step.matches("ReqdTxTp") { optional_mut(&mut self.reqd_tx_tp, step, create, out) }
+		if step.matches("ReqdBalTp") { optional_repeated_mut(&mut self.reqd_bal_tp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ReportingSource1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RequestHandling2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("StsCd") { one(&self.sts_cd, step, out) }
+		if step.matches("StsDtTm") { optional(&self.sts_dt_tm, step, out) }
+		if step.matches("Desc") { optional(&self.desc, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("StsCd") { one_mut(&mut self.sts_cd, step, out) }
+		if step.matches("StsDtTm") { optional_mut(&mut self.sts_dt_tm, step, create, out) }
+		if step.matches("Desc") { optional_mut(&mut self.desc, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::RequestType4Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PmtCtrl") { optional(&self.pmt_ctrl, step, out) }
+		if step.matches("Enqry") { optional(&self.enqry, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PmtCtrl") { optional_mut(&mut self.pmt_ctrl, step, create, out) }
+		if step.matches("Enqry") { optional_mut(&mut self.enqry, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ResendSearchCriteria2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("BizDt") { optional(&self.biz_dt, step, out) }
+		if step.matches("SeqNb") { optional(&self.seq_nb, step, out) }
+		if step.matches("SeqRg") { optional(&self.seq_rg, step, out) }
+		if step.matches("OrgnlMsgNmId") { optional(&self.orgnl_msg_nm_id, step, out) }
+		if step.matches("FileRef") { optional(&self.file_ref, step, out) }
+		if step.matches("Rcpt") { one(&self.rcpt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("BizDt") { optional_mut(&mut self.biz_dt, step, create, out) }
+		if step.matches("SeqNb") { optional_mut(&mut self.seq_nb, step, create, out) }
+		if step.matches("SeqRg") { optional_mut(&mut self.seq_rg, step, create, out) }
+		if step.matches("OrgnlMsgNmId") { optional_mut(&mut self.orgnl_msg_nm_id, step, create, out) }
+		if step.matches("FileRef") { optional_mut(&mut self.file_ref, step, create, out) }
+		if step.matches("Rcpt") { one_mut(&mut self.rcpt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::ResolutionData1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("EndToEndId") { optional(&self.end_to_end_id, step, out) }
+		if step.matches("TxId") { optional(&self.tx_id, step, out) }
+		if step.matches("UETR") { optional(&self.uetr, step, out) }
+		if step.matches("IntrBkSttlmAmt") { optional(&self.intr_bk_sttlm_amt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("ClrChanl") { optional(&self.clr_chanl, step, out) }
+		if step.matches("Compstn") { optional(&self.compstn, step, out) }
+		if step.matches("Chrgs") { optional_repeated(&self.chrgs, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("EndToEndId") { optional_mut(&mut self.end_to_end_id, step, create, out) }
+		if step.matches("TxId") { optional_mut(&mut self.tx_id, step, create, out) }
+		if step.matches("UETR") { optional_mut(&mut self.uetr, step, create, out) }
+		if step.matches("IntrBkSttlmAmt") { optional_mut(&mut self.intr_bk_sttlm_amt, step, create, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("ClrChanl") { optional_mut(&mut self.clr_chanl, step, create, out) }
+		if step.matches("Compstn") { optional_mut(&mut self.compstn, step, create, out) }
+		if step.matches("Chrgs") { optional_repeated_mut(&mut self.chrgs, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::ReturnReason5Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::SecuritiesAccount19 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { one(&self.id, step, out) }
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { one_mut(&mut self.id, step, out) }
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::SecurityIdentification19 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ISIN") { optional(&self.isin, step, out) }
+		if step.matches("OthrId") { optional_repeated(&self.othr_id, step, out) }
+		if step.matches("Desc") { optional(&self.desc, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ISIN") { optional_mut(&mut self.isin, step, create, out) }
+		if step.matches("OthrId") { optional_repeated_mut(&mut self.othr_id, step, create, out) }
+		if step.matches("Desc") { optional_mut(&mut self.desc, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::SequenceRange1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrSeq") { one(&self.fr_seq, step, out) }
+		if step.matches("ToSeq") { one(&self.to_seq, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrSeq") { one_mut(&mut self.fr_seq, step, out) }
+		if step.matches("ToSeq") { one_mut(&mut self.to_seq, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::SequenceRange1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrSeq") { optional(&self.fr_seq, step, out) }
+		if step.matches("ToSeq") { optional(&self.to_seq, step, out) }
+		if step.matches("FrToSeq") { optional_repeated(&self.fr_to_seq, step, out) }
+		if step.matches("EQSeq") { optional_repeated(&self.eq_seq, step, out) }
+		if step.matches("NEQSeq") { optional_repeated(&self.neq_seq, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrSeq") { optional_mut(&mut self.fr_seq, step, create, out) }
+		if step.matches("ToSeq") { optional_mut(&mut self.to_seq, step, create, out) }
+		if step.matches("FrToSeq") { optional_repeated_mut(&mut self.fr_to_seq, step, create, out) }
+		if step.matches("EQSeq") { optional_repeated_mut(&mut self.eq_seq, step, create, out) }
+		if step.matches("NEQSeq") { optional_repeated_mut(&mut self.neq_seq, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::SequenceType3Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeFRST => "FRST",
+			Self::CodeRCUR => "RCUR",
+			Self::CodeFNAL => "FNAL",
+			Self::CodeOOFF => "OOFF",
+			Self::CodeRPRE => "RPRE",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"FRST" => Self::CodeFRST,
+			"RCUR" => Self::CodeRCUR,
+			"FNAL" => Self::CodeFNAL,
+			"OOFF" => Self::CodeOOFF,
+			"RPRE" => Self::CodeRPRE,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::ServiceLevel8Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::SettlementDateTimeIndication1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DbtDtTm") { optional(&self.dbt_dt_tm, step, out) }
+		if step.matches("CdtDtTm") { optional(&self.cdt_dt_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DbtDtTm") { optional_mut(&mut self.dbt_dt_tm, step, create, out) }
+		if step.matches("CdtDtTm") { optional_mut(&mut self.cdt_dt_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::SettlementInstruction7 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("SttlmMtd") { one(&self.sttlm_mtd, step, out) }
+		if step.matches("SttlmAcct") { optional(&self.sttlm_acct, step, out) }
+		if step.matches("ClrSys") { optional(&self.clr_sys, step, out) }
+		if step.matches("InstgRmbrsmntAgt") { optional(&self.instg_rmbrsmnt_agt, step, out) }
+		if step.matches("InstgRmbrsmntAgtAcct") { optional(&self.instg_rmbrsmnt_agt_acct, step, out) }
+		if step.matches("InstdRmbrsmntAgt") { optional(&self.instd_rmbrsmnt_agt, step, out) }
+		if step.matches("InstdRmbrsmntAgtAcct") { optional(&self.instd_rmbrsmnt_agt_acct, step, out) }
+		if step.matches("ThrdRmbrsmntAgt") { optional(&self.thrd_rmbrsmnt_agt, step, out) }
+		if step.matches("ThrdRmbrsmntAgtAcct") { optional(&self.thrd_rmbrsmnt_agt_acct, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("SttlmMtd") { one_mut(&mut self.sttlm_mtd, step, out) }
+		if step.matches("SttlmAcct") { optional_mut(&mut self.sttlm_acct, step, create, out) }
+		if step.matches("ClrSys") { optional_mut(&mut self.clr_sys, step, create, out) }
+		if step.matches("InstgRmbrsmntAgt") { optional_mut(&mut self.instg_rmbrsmnt_agt, step, create, out) }
+		if step.matches("InstgRmbrsmntAgtAcct") { optional_mut(&mut self.instg_rmbrsmnt_agt_acct, step, create, out) }
+		if step.matches("InstdRmbrsmntAgt") { optional_mut(&mut self.instd_rmbrsmnt_agt, step, create, out) }
+		if step.matches("InstdRmbrsmntAgtAcct") { optional_mut(&mut self.instd_rmbrsmnt_agt_acct, step, create, out) }
+		if step.matches("ThrdRmbrsmntAgt") { optional_mut(&mut self.thrd_rmbrsmnt_agt, step, create, out) }
+		if step.matches("ThrdRmbrsmntAgtAcct") { optional_mut(&mut self.thrd_rmbrsmnt_agt_acct, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::SettlementMethod1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeINDA => "INDA",
+			Self::CodeINGA => "INGA",
+			Self::CodeCOVE => "COVE",
+			Self::CodeCLRG => "CLRG",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"INDA" => Self::CodeINDA,
+			"INGA" => Self::CodeINGA,
+			"COVE" => Self::CodeCOVE,
+			"CLRG" => Self::CodeCLRG,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::SettlementTimeRequest2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CLSTm") { optional(&self.cls_tm, step, out) }
+		if step.matches("TillTm") { optional(&self.till_tm, step, out) }
+		if step.matches("FrTm") { optional(&self.fr_tm, step, out) }
+		if step.matches("RjctTm") { optional(&self.rjct_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CLSTm") { optional_mut(&mut self.cls_tm, step, create, out) }
+		if step.matches("TillTm") { optional_mut(&mut self.till_tm, step, create, out) }
+		if step.matches("FrTm") { optional_mut(&mut self.fr_tm, step, create, out) }
+		if step.matches("RjctTm") { optional_mut(&mut self.rjct_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::SignatureEnvelope {}
+
+impl TagPaths for crate::common::SkipPayload {}
+
+impl TagPaths for crate::common::StatementResolutionEntry4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlStmtId") { optional(&self.orgnl_stmt_id, step, out) }
+		if step.matches("UETR") { optional(&self.uetr, step, out) }
+		if step.matches("AcctSvcrRef") { optional(&self.acct_svcr_ref, step, out) }
+		if step.matches("CrrctdAmt") { optional(&self.crrctd_amt, step, out) }
+		if step.matches("Chrgs") { optional_repeated(&self.chrgs, step, out) }
+		if step.matches("Purp") { optional(&self.purp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlStmtId") { optional_mut(&mut self.orgnl_stmt_id, step, create, out) }
+		if step.matches("UETR") { optional_mut(&mut self.uetr, step, create, out) }
+		if step.matches("AcctSvcrRef") { optional_mut(&mut self.acct_svcr_ref, step, create, out) }
+		if step.matches("CrrctdAmt") { optional_mut(&mut self.crrctd_amt, step, create, out) }
+		if step.matches("Chrgs") { optional_repeated_mut(&mut self.chrgs, step, create, out) }
+		if step.matches("Purp") { optional_mut(&mut self.purp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::StatusReason6Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::StatusReasonInformation12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Orgtr") { optional(&self.orgtr, step, out) }
+		if step.matches("Rsn") { optional(&self.rsn, step, out) }
+		if step.matches("AddtlInf") { optional_repeated(&self.addtl_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Orgtr") { optional_mut(&mut self.orgtr, step, create, out) }
+		if step.matches("Rsn") { optional_mut(&mut self.rsn, step, create, out) }
+		if step.matches("AddtlInf") { optional_repeated_mut(&mut self.addtl_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::StructuredRegulatoryReporting3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Dt") { optional(&self.dt, step, out) }
+		if step.matches("Ctry") { optional(&self.ctry, step, out) }
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+		if step.matches("Inf") { optional_repeated(&self.inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Dt") { optional_mut(&mut self.dt, step, create, out) }
+		if step.matches("Ctry") { optional_mut(&mut self.ctry, step, create, out) }
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+		if step.matches("Inf") { optional_repeated_mut(&mut self.inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::StructuredRemittanceInformation16 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RfrdDocInf") { optional_repeated(&self.rfrd_doc_inf, step, out) }
+		if step.matches("RfrdDocAmt") { optional(&self.rfrd_doc_amt, step, out) }
+		if step.matches("CdtrRefInf") { optional(&self.cdtr_ref_inf, step, out) }
+		if step.matches("Invcr") { optional(&self.invcr, step, out) }
+		if step.matches("Invcee") { optional(&self.invcee, step, out) }
+		if step.matches("TaxRmt") { optional(&self.tax_rmt, step, out) }
+		if step.matches("GrnshmtRmt") { optional(&self.grnshmt_rmt, step, out) }
+		if step.matches("AddtlRmtInf") { optional_repeated(&self.addtl_rmt_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RfrdDocInf") { optional_repeated_mut(&mut self.rfrd_doc_inf, step, create, out) }
+		if step.matches("RfrdDocAmt") { optional_mut(&mut self.rfrd_doc_amt, step, create, out) }
+		if step.matches("CdtrRefInf") { optional_mut(&mut self.cdtr_ref_inf, step, create, out) }
+		if step.matches("Invcr") { optional_mut(&mut self.invcr, step, create, out) }
+		if step.matches("Invcee") { optional_mut(&mut self.invcee, step, create, out) }
+		if step.matches("TaxRmt") { optional_mut(&mut self.tax_rmt, step, create, out) }
+		if step.matches("GrnshmtRmt") { optional_mut(&mut self.grnshmt_rmt, step, create, out) }
+		if step.matches("AddtlRmtInf") { optional_repeated_mut(&mut self.addtl_rmt_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::SupplementaryData1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PlcAndNm") { optional(&self.plc_and_nm, step, out) }
+		if step.matches("Envlp") { one(&self.envlp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PlcAndNm") { optional_mut(&mut self.plc_and_nm, step, create, out) }
+		if step.matches("Envlp") { one_mut(&mut self.envlp, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::SupplementaryDataEnvelope1 {}
+
+impl TagPaths for crate::common::TaxAmount2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Rate") { optional(&self.rate, step, out) }
+		if step.matches("TaxblBaseAmt") { optional(&self.taxbl_base_amt, step, out) }
+		if step.matches("TtlAmt") { optional(&self.ttl_amt, step, out) }
+		if step.matches("Dtls") { optional_repeated(&self.dtls, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Rate") { optional_mut(&mut self.rate, step, create, out) }
+		if step.matches("TaxblBaseAmt") { optional_mut(&mut self.taxbl_base_amt, step, create, out) }
+		if step.matches("TtlAmt") { optional_mut(&mut self.ttl_amt, step, create, out) }
+		if step.matches("Dtls") { optional_repeated_mut(&mut self.dtls, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxAmountAndType1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Amt") { one(&self.amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxAmountType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxAuthorisation1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Titl") { optional(&self.titl, step, out) }
+		if step.matches("Nm") { optional(&self.nm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Titl") { optional_mut(&mut self.titl, step, create, out) }
+		if step.matches("Nm") { optional_mut(&mut self.nm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxCharges2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("Rate") { optional(&self.rate, step, out) }
+		if step.matches("Amt") { optional(&self.amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("Rate") { optional_mut(&mut self.rate, step, create, out) }
+		if step.matches("Amt") { optional_mut(&mut self.amt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxInformation7 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cdtr") { optional(&self.cdtr, step, out) }
+		if step.matches("Dbtr") { optional(&self.dbtr, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("AdmstnZone") { optional(&self.admstn_zone, step, out) }
+		if step.matches("RefNb") { optional(&self.ref_nb, step, out) }
+		if step.matches("Mtd") { optional(&self.mtd, step, out) }
+		if step.matches("TtlTaxblBaseAmt") { optional(&self.ttl_taxbl_base_amt, step, out) }
+		if step.matches("TtlTaxAmt") { optional(&self.ttl_tax_amt, step, out) }
+		if step.matches("Dt") { optional(&self.dt, step, out) }
+		if step.matches("SeqNb") { optional(&self.seq_nb, step, out) }
+		if step.matches("Rcrd") { optional_repeated(&self.rcrd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cdtr") { optional_mut(&mut self.cdtr, step, create, out) }
+		if step.matches("Dbtr") { optional_mut(&mut self.dbtr, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("AdmstnZone") { optional_mut(&mut self.admstn_zone, step, create, out) }
+		if step.matches("RefNb") { optional_mut(&mut self.ref_nb, step, create, out) }
+		if step.matches("Mtd") { optional_mut(&mut self.mtd, step, create, out) }
+		if step.matches("TtlTaxblBaseAmt") { optional_mut(&mut self.ttl_taxbl_base_amt, step, create, out) }
+		if step.matches("TtlTaxAmt") { optional_mut(&mut self.ttl_tax_amt, step, create, out) }
+		if step.matches("Dt") { optional_mut(&mut self.dt, step, create, out) }
+		if step.matches("SeqNb") { optional_mut(&mut self.seq_nb, step, create, out) }
+		if step.matches("Rcrd") { optional_repeated_mut(&mut self.rcrd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxInformation8 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cdtr") { optional(&self.cdtr, step, out) }
+		if step.matches("Dbtr") { optional(&self.dbtr, step, out) }
+		if step.matches("AdmstnZone") { optional(&self.admstn_zone, step, out) }
+		if step.matches("RefNb") { optional(&self.ref_nb, step, out) }
+		if step.matches("Mtd") { optional(&self.mtd, step, out) }
+		if step.matches("TtlTaxblBaseAmt") { optional(&self.ttl_taxbl_base_amt, step, out) }
+		if step.matches("TtlTaxAmt") { optional(&self.ttl_tax_amt, step, out) }
+		if step.matches("Dt") { optional(&self.dt, step, out) }
+		if step.matches("SeqNb") { optional(&self.seq_nb, step, out) }
+		if step.matches("Rcrd") { optional_repeated(&self.rcrd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cdtr") { optional_mut(&mut self.cdtr, step, create, out) }
+		if step.matches("Dbtr") { optional_mut(&mut self.dbtr, step, create, out) }
+		if step.matches("AdmstnZone") { optional_mut(&mut self.admstn_zone, step, create, out) }
+		if step.matches("RefNb") { optional_mut(&mut self.ref_nb, step, create, out) }
+		if step.matches("Mtd") { optional_mut(&mut self.mtd, step, create, out) }
+		if step.matches("TtlTaxblBaseAmt") { optional_mut(&mut self.ttl_taxbl_base_amt, step, create, out) }
+		if step.matches("TtlTaxAmt") { optional_mut(&mut self.ttl_tax_amt, step, create, out) }
+		if step.matches("Dt") { optional_mut(&mut self.dt, step, create, out) }
+		if step.matches("SeqNb") { optional_mut(&mut self.seq_nb, step, create, out) }
+		if step.matches("Rcrd") { optional_repeated_mut(&mut self.rcrd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxParty1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TaxId") { optional(&self.tax_id, step, out) }
+		if step.matches("RegnId") { optional(&self.regn_id, step, out) }
+		if step.matches("TaxTp") { optional(&self.tax_tp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TaxId") { optional_mut(&mut self.tax_id, step, create, out) }
+		if step.matches("RegnId") { optional_mut(&mut self.regn_id, step, create, out) }
+		if step.matches("TaxTp") { optional_mut(&mut self.tax_tp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxParty2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TaxId") { optional(&self.tax_id, step, out) }
+		if step.matches("RegnId") { optional(&self.regn_id, step, out) }
+		if step.matches("TaxTp") { optional(&self.tax_tp, step, out) }
+		if step.matches("Authstn") { optional(&self.authstn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TaxId") { optional_mut(&mut self.tax_id, step, create, out) }
+		if step.matches("RegnId") { optional_mut(&mut self.regn_id, step, create, out) }
+		if step.matches("TaxTp") { optional_mut(&mut self.tax_tp, step, create, out) }
+		if step.matches("Authstn") { optional_mut(&mut self.authstn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxPeriod2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Yr") { optional(&self.yr, step, out) }
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("FrToDt") { optional(&self.fr_to_dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Yr") { optional_mut(&mut self.yr, step, create, out) }
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("FrToDt") { optional_mut(&mut self.fr_to_dt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxRecord2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tp") { optional(&self.tp, step, out) }
+		if step.matches("Ctgy") { optional(&self.ctgy, step, out) }
+		if step.matches("CtgyDtls") { optional(&self.ctgy_dtls, step, out) }
+		if step.matches("DbtrSts") { optional(&self.dbtr_sts, step, out) }
+		if step.matches("CertId") { optional(&self.cert_id, step, out) }
+		if step.matches("FrmsCd") { optional(&self.frms_cd, step, out) }
+		if step.matches("Prd") { optional(&self.prd, step, out) }
+		if step.matches("TaxAmt") { optional(&self.tax_amt, step, out) }
+		if step.matches("AddtlInf") { optional(&self.addtl_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tp") { optional_mut(&mut self.tp, step, create, out) }
+		if step.matches("Ctgy") { optional_mut(&mut self.ctgy, step, create, out) }
+		if step.matches("CtgyDtls") { optional_mut(&mut self.ctgy_dtls, step, create, out) }
+		if step.matches("DbtrSts") { optional_mut(&mut self.dbtr_sts, step, create, out) }
+		if step.matches("CertId") { optional_mut(&mut self.cert_id, step, create, out) }
+		if step.matches("FrmsCd") { optional_mut(&mut self.frms_cd, step, create, out) }
+		if step.matches("Prd") { optional_mut(&mut self.prd, step, create, out) }
+		if step.matches("TaxAmt") { optional_mut(&mut self.tax_amt, step, create, out) }
+		if step.matches("AddtlInf") { optional_mut(&mut self.addtl_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxRecordDetails2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Prd") { optional(&self.prd, step, out) }
+		if step.matches("Amt") { one(&self.amt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Prd") { optional_mut(&mut self.prd, step, create, out) }
+		if step.matches("Amt") { one_mut(&mut self.amt, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::TaxRecordPeriod1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeMM01 => "MM01",
+			Self::CodeMM02 => "MM02",
+			Self::CodeMM03 => "MM03",
+			Self::CodeMM04 => "MM04",
+			Self::CodeMM05 => "MM05",
+			Self::CodeMM06 => "MM06",
+			Self::CodeMM07 => "MM07",
+			Self::CodeMM08 => "MM08",
+			Self::CodeMM09 => "MM09",
+			Self::CodeMM10 => "MM10",
+			Self::CodeMM11 => "MM11",
+			Self::CodeMM12 => "MM12",
+			Self::CodeQTR1 => "QTR1",
+			Self::CodeQTR2 => "QTR2",
+			Self::CodeQTR3 => "QTR3",
+			Self::CodeQTR4 => "QTR4",
+			Self::CodeHLF1 => "HLF1",
+			Self::CodeHLF2 => "HLF2",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"MM01" => Self::CodeMM01,
+			"MM02" => Self::CodeMM02,
+			"MM03" => Self::CodeMM03,
+			"MM04" => Self::CodeMM04,
+			"MM05" => Self::CodeMM05,
+			"MM06" => Self::CodeMM06,
+			"MM07" => Self::CodeMM07,
+			"MM08" => Self::CodeMM08,
+			"MM09" => Self::CodeMM09,
+			"MM10" => Self::CodeMM10,
+			"MM11" => Self::CodeMM11,
+			"MM12" => Self::CodeMM12,
+			"QTR1" => Self::CodeQTR1,
+			"QTR2" => Self::CodeQTR2,
+			"QTR3" => Self::CodeQTR3,
+			"QTR4" => Self::CodeQTR4,
+			"HLF1" => Self::CodeHLF1,
+			"HLF2" => Self::CodeHLF2,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::TechnicalInputChannel1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { optional(&self.cd, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { optional_mut(&mut self.cd, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TimePeriodDetails1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FrTm") { one(&self.fr_tm, step, out) }
+		if step.matches("ToTm") { optional(&self.to_tm, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FrTm") { one_mut(&mut self.fr_tm, step, out) }
+		if step.matches("ToTm") { optional_mut(&mut self.to_tm, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TotalTransactions6 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TtlNtries") { optional(&self.ttl_ntries, step, out) }
+		if step.matches("TtlCdtNtries") { optional(&self.ttl_cdt_ntries, step, out) }
+		if step.matches("TtlDbtNtries") { optional(&self.ttl_dbt_ntries, step, out) }
+		if step.matches("TtlNtriesPerBkTxCd") { optional_repeated(&self.ttl_ntries_per_bk_tx_cd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TtlNtries") { optional_mut(&mut self.ttl_ntries, step, create, out) }
+		if step.matches("TtlCdtNtries") { optional_mut(&mut self.ttl_cdt_ntries, step, create, out) }
+		if step.matches("TtlDbtNtries") { optional_mut(&mut self.ttl_dbt_ntries, step, create, out) }
+		if step.matches("TtlNtriesPerBkTxCd") { optional_repeated_mut(&mut self.ttl_ntries_per_bk_tx_cd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TotalsPerBankTransactionCode5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("NbOfNtries") { optional(&self.nb_of_ntries, step, out) }
+		if step.matches("Sum") { optional(&self.sum, step, out) }
+		if step.matches("TtlNetNtry") { optional(&self.ttl_net_ntry, step, out) }
+		if step.matches("CdtNtries") { optional(&self.cdt_ntries, step, out) }
+		if step.matches("DbtNtries") { optional(&self.dbt_ntries, step, out) }
+		if step.matches("FcstInd") { optional(&self.fcst_ind, step, out) }
+		if step.matches("BkTxCd") { one(&self.bk_tx_cd, step, out) }
+		if step.matches("Avlbty") { optional_repeated(&self.avlbty, step, out) }
+		if step.matches("Dt") { optional(&self.dt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("NbOfNtries") { optional_mut(&mut self.nb_of_ntries, step, create, out) }
+		if step.matches("Sum") { optional_mut(&mut self.sum, step, create, out) }
+		if step.matches("TtlNetNtry") { optional_mut(&mut self.ttl_net_ntry, step, create, out) }
+		if step.matches("CdtNtries") { optional_mut(&mut self.cdt_ntries, step, create, out) }
+		if step.matches("DbtNtries") { optional_mut(&mut self.dbt_ntries, step, create, out) }
+		if step.matches("FcstInd") { optional_mut(&mut self.fcst_ind, step, create, out) }
+		if step.matches("BkTxCd") { one_mut(&mut self.bk_tx_cd, step, out) }
+		if step.matches("Avlbty") { optional_repeated_mut(&mut self.avlbty, step, create, out) }
+		if step.matches("Dt") { optional_mut(&mut self.dt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TrackData1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TrckNb") { optional(&self.trck_nb, step, out) }
+		if step.matches("TrckVal") { one(&self.trck_val, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TrckNb") { optional_mut(&mut self.trck_nb, step, create, out) }
+		if step.matches("TrckVal") { one_mut(&mut self.trck_val, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionAgents5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstgAgt") { optional(&self.instg_agt, step, out) }
+		if step.matches("InstdAgt") { optional(&self.instd_agt, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("CdtrAgt") { optional(&self.cdtr_agt, step, out) }
+		if step.matches("IntrmyAgt1") { optional(&self.intrmy_agt1, step, out) }
+		if step.matches("IntrmyAgt2") { optional(&self.intrmy_agt2, step, out) }
+		if step.matches("IntrmyAgt3") { optional(&self.intrmy_agt3, step, out) }
+		if step.matches("RcvgAgt") { optional(&self.rcvg_agt, step, out) }
+		if step.matches("DlvrgAgt") { optional(&self.dlvrg_agt, step, out) }
+		if step.matches("IssgAgt") { optional(&self.issg_agt, step, out) }
+		if step.matches("SttlmPlc") { optional(&self.sttlm_plc, step, out) }
+		if step.matches("Prtry") { optional_repeated(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstgAgt") { optional_mut(&mut self.instg_agt, step, create, out) }
+		if step.matches("InstdAgt") { optional_mut(&mut self.instd_agt, step, create, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("CdtrAgt") { optional_mut(&mut self.cdtr_agt, step, create, out) }
+		if step.matches("IntrmyAgt1") { optional_mut(&mut self.intrmy_agt1, step, create, out) }
+		if step.matches("IntrmyAgt2") { optional_mut(&mut self.intrmy_agt2, step, create, out) }
+		if step.matches("IntrmyAgt3") { optional_mut(&mut self.intrmy_agt3, step, create, out) }
+		if step.matches("RcvgAgt") { optional_mut(&mut self.rcvg_agt, step, create, out) }
+		if step.matches("DlvrgAgt") { optional_mut(&mut self.dlvrg_agt, step, create, out) }
+		if step.matches("IssgAgt") { optional_mut(&mut self.issg_agt, step, create, out) }
+		if step.matches("SttlmPlc") { optional_mut(&mut self.sttlm_plc, step, create, out) }
+		if step.matches("Prtry") { optional_repeated_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionChannel1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeMAIL => "MAIL",
+			Self::CodeTLPH => "TLPH",
+			Self::CodeECOM => "ECOM",
+			Self::CodeTVPY => "TVPY",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"MAIL" => Self::CodeMAIL,
+			"TLPH" => Self::CodeTLPH,
+			"ECOM" => Self::CodeECOM,
+			"TVPY" => Self::CodeTVPY,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::TransactionDates3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AccptncDtTm") { optional(&self.accptnc_dt_tm, step, out) }
+		if step.matches("TradActvtyCtrctlSttlmDt") { optional(&self.trad_actvty_ctrctl_sttlm_dt, step, out) }
+		if step.matches("TradDt") { optional(&self.trad_dt, step, out) }
+		if step.matches("IntrBkSttlmDt") { optional(&self.intr_bk_sttlm_dt, step, out) }
+		if step.matches("StartDt") { optional(&self.start_dt, step, out) }
+		if step.matches("EndDt") { optional(&self.end_dt, step, out) }
+		if step.matches("TxDtTm") { optional(&self.tx_dt_tm, step, out) }
+		if step.matches("Prtry") { optional_repeated(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AccptncDtTm") { optional_mut(&mut self.accptnc_dt_tm, step, create, out) }
+		if step.matches("TradActvtyCtrctlSttlmDt") { optional_mut(&mut self.trad_actvty_ctrctl_sttlm_dt, step, create, out) }
+		if step.matches("TradDt") { optional_mut(&mut self.trad_dt, step, create, out) }
+		if step.matches("IntrBkSttlmDt") { optional_mut(&mut self.intr_bk_sttlm_dt, step, create, out) }
+		if step.matches("StartDt") { optional_mut(&mut self.start_dt, step, create, out) }
+		if step.matches("EndDt") { optional_mut(&mut self.end_dt, step, create, out) }
+		if step.matches("TxDtTm") { optional_mut(&mut self.tx_dt_tm, step, create, out) }
+		if step.matches("Prtry") { optional_repeated_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionEnvironment1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeMERC => "MERC",
+			Self::CodePRIV => "PRIV",
+			Self::CodePUBL => "PUBL",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"MERC" => Self::CodeMERC,
+			"PRIV" => Self::CodePRIV,
+			"PUBL" => Self::CodePUBL,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::TransactionIdentifier1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TxDtTm") { one(&self.tx_dt_tm, step, out) }
+		if step.matches("TxRef") { one(&self.tx_ref, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TxDtTm") { one_mut(&mut self.tx_dt_tm, step, out) }
+		if step.matches("TxRef") { one_mut(&mut self.tx_ref, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionIndividualStatus1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeACTC => "ACTC",
+			Self::CodeRJCT => "RJCT",
+			Self::CodePDNG => "PDNG",
+			Self::CodeACCP => "ACCP",
+			Self::CodeACSP => "ACSP",
+			Self::CodeACSC => "ACSC",
+			Self::CodeACCR => "ACCR",
+			Self::CodeACWC => "ACWC",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"ACTC" => Self::CodeACTC,
+			"RJCT" => Self::CodeRJCT,
+			"PDNG" => Self::CodePDNG,
+			"ACCP" => Self::CodeACCP,
+			"ACSP" => Self::CodeACSP,
+			"ACSC" => Self::CodeACSC,
+			"ACCR" => Self::CodeACCR,
+			"ACWC" => Self::CodeACWC,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::TransactionInterest4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TtlIntrstAndTaxAmt") { optional(&self.ttl_intrst_and_tax_amt, step, out) }
+		if step.matches("Rcrd") { optional_repeated(&self.rcrd, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TtlIntrstAndTaxAmt") { optional_mut(&mut self.ttl_intrst_and_tax_amt, step, create, out) }
+		if step.matches("Rcrd") { optional_repeated_mut(&mut self.rcrd, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionParties6 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InitgPty") { optional(&self.initg_pty, step, out) }
+		if step.matches("Dbtr") { optional(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("Cdtr") { optional(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+		if step.matches("TradgPty") { optional(&self.tradg_pty, step, out) }
+		if step.matches("Prtry") { optional_repeated(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InitgPty") { optional_mut(&mut self.initg_pty, step, create, out) }
+		if step.matches("Dbtr") { optional_mut(&mut self.dbtr, step, create, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("Cdtr") { optional_mut(&mut self.cdtr, step, create, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+		if step.matches("TradgPty") { optional_mut(&mut self.tradg_pty, step, create, out) }
+		if step.matches("Prtry") { optional_repeated_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionParties8 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("UltmtDbtr") { optional(&self.ultmt_dbtr, step, out) }
+		if step.matches("Dbtr") { one(&self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional(&self.dbtr_acct, step, out) }
+		if step.matches("InitgPty") { optional(&self.initg_pty, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("DbtrAgtAcct") { optional(&self.dbtr_agt_acct, step, out) }
+		if step.matches("PrvsInstgAgt1") { optional(&self.prvs_instg_agt1, step, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional(&self.prvs_instg_agt1_acct, step, out) }
+		if step.matches("PrvsInstgAgt2") { optional(&self.prvs_instg_agt2, step, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional(&self.prvs_instg_agt2_acct, step, out) }
+		if step.matches("PrvsInstgAgt3") { optional(&self.prvs_instg_agt3, step, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional(&self.prvs_instg_agt3_acct, step, out) }
+		if step.matches("IntrmyAgt1") { optional(&self.intrmy_agt1, step, out) }
+		if step.matches("IntrmyAgt1Acct") { optional(&self.intrmy_agt1_acct, step, out) }
+		if step.matches("IntrmyAgt2") { optional(&self.intrmy_agt2, step, out) }
+		if step.matches("IntrmyAgt2Acct") { optional(&self.intrmy_agt2_acct, step, out) }
+		if step.matches("IntrmyAgt3") { optional(&self.intrmy_agt3, step, out) }
+		if step.matches("IntrmyAgt3Acct") { optional(&self.intrmy_agt3_acct, step, out) }
+		if step.matches("CdtrAgt") { optional(&self.cdtr_agt, step, out) }
+		if step.matches("CdtrAgtAcct") { optional(&self.cdtr_agt_acct, step, out) }
+		if step.matches("Cdtr") { one(&self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional(&self.cdtr_acct, step, out) }
+		if step.matches("UltmtCdtr") { optional(&self.ultmt_cdtr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("UltmtDbtr") { optional_mut(&mut self.ultmt_dbtr, step, create, out) }
+		if step.matches("Dbtr") { one_mut(&mut self.dbtr, step, out) }
+		if step.matches("DbtrAcct") { optional_mut(&mut self.dbtr_acct, step, create, out) }
+		if step.matches("InitgPty") { optional_mut(&mut self.initg_pty, step, create, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("DbtrAgtAcct") { optional_mut(&mut self.dbtr_agt_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt1") { optional_mut(&mut self.prvs_instg_agt1, step, create, out) }
+		if step.matches("PrvsInstgAgt1Acct") { optional_mut(&mut self.prvs_instg_agt1_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt2") { optional_mut(&mut self.prvs_instg_agt2, step, create, out) }
+		if step.matches("PrvsInstgAgt2Acct") { optional_mut(&mut self.prvs_instg_agt2_acct, step, create, out) }
+		if step.matches("PrvsInstgAgt3") { optional_mut(&mut self.prvs_instg_agt3, step, create, out) }
+		if step.matches("PrvsInstgAgt3Acct") { optional_mut(&mut self.prvs_instg_agt3_acct, step, create, out) }
+		if step.matches("IntrmyAgt1") { optional_mut(&mut self.intrmy_agt1, step, create, out) }
+		if step.matches("IntrmyAgt1Acct") { optional_mut(&mut self.intrmy_agt1_acct, step, create, out) }
+		if step.matches("IntrmyAgt2") { optional_mut(&mut self.intrmy_agt2, step, create, out) }
+		if step.matches("IntrmyAgt2Acct") { optional_mut(&mut self.intrmy_agt2_acct, step, create, out) }
+		if step.matches("IntrmyAgt3") { optional_mut(&mut self.intrmy_agt3, step, create, out) }
+		if step.matches("IntrmyAgt3Acct") { optional_mut(&mut self.intrmy_agt3_acct, step, create, out) }
+		if step.matches("CdtrAgt") { optional_mut(&mut self.cdtr_agt, step, create, out) }
+		if step.matches("CdtrAgtAcct") { optional_mut(&mut self.cdtr_agt_acct, step, create, out) }
+		if step.matches("Cdtr") { one_mut(&mut self.cdtr, step, out) }
+		if step.matches("CdtrAcct") { optional_mut(&mut self.cdtr_acct, step, create, out) }
+		if step.matches("UltmtCdtr") { optional_mut(&mut self.ultmt_cdtr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionPrice4Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DealPric") { optional(&self.deal_pric, step, out) }
+		if step.matches("Prtry") { optional_repeated(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DealPric") { optional_mut(&mut self.deal_pric, step, create, out) }
+		if step.matches("Prtry") { optional_repeated_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionQuantities3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Qty") { optional(&self.qty, step, out) }
+		if step.matches("OrgnlAndCurFaceAmt") { optional(&self.orgnl_and_cur_face_amt, step, out) }
+		if step.matches("Prtry") { optional(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Qty") { optional_mut(&mut self.qty, step, create, out) }
+		if step.matches("OrgnlAndCurFaceAmt") { optional_mut(&mut self.orgnl_and_cur_face_amt, step, create, out) }
+		if step.matches("Prtry") { optional_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionReferences6 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { optional(&self.msg_id, step, out) }
+		if step.matches("AcctSvcrRef") { optional(&self.acct_svcr_ref, step, out) }
+		if step.matches("PmtInfId") { optional(&self.pmt_inf_id, step, out) }
+		if step.matches("InstrId") { optional(&self.instr_id, step, out) }
+		if step.matches("EndToEndId") { optional(&self.end_to_end_id, step, out) }
+		if step.matches("UETR") { optional(&self.uetr, step, out) }
+		if step.matches("TxId") { optional(&self.tx_id, step, out) }
+		if step.matches("MndtId") { optional(&self.mndt_id, step, out) }
+		if step.matches("ChqNb") { optional(&self.chq_nb, step, out) }
+		if step.matches("ClrSysRef") { optional(&self.clr_sys_ref, step, out) }
+		if step.matches("AcctOwnrTxId") { optional(&self.acct_ownr_tx_id, step, out) }
+		if step.matches("AcctSvcrTxId") { optional(&self.acct_svcr_tx_id, step, out) }
+		if step.matches("MktInfrstrctrTxId") { optional(&self.mkt_infrstrctr_tx_id, step, out) }
+		if step.matches("PrcgId") { optional(&self.prcg_id, step, out) }
+		if step.matches("Prtry") { optional_repeated(&self.prtry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { optional_mut(&mut self.msg_id, step, create, out) }
+		if step.matches("AcctSvcrRef") { optional_mut(&mut self.acct_svcr_ref, step, create, out) }
+		if step.matches("PmtInfId") { optional_mut(&mut self.pmt_inf_id, step, create, out) }
+		if step.matches("InstrId") { optional_mut(&mut self.instr_id, step, create, out) }
+		if step.matches("EndToEndId") { optional_mut(&mut self.end_to_end_id, step, create, out) }
+		if step.matches("UETR") { optional_mut(&mut self.uetr, step, create, out) }
+		if step.matches("TxId") { optional_mut(&mut self.tx_id, step, create, out) }
+		if step.matches("MndtId") { optional_mut(&mut self.mndt_id, step, create, out) }
+		if step.matches("ChqNb") { optional_mut(&mut self.chq_nb, step, create, out) }
+		if step.matches("ClrSysRef") { optional_mut(&mut self.clr_sys_ref, step, create, out) }
+		if step.matches("AcctOwnrTxId") { optional_mut(&mut self.acct_ownr_tx_id, step, create, out) }
+		if step.matches("AcctSvcrTxId") { optional_mut(&mut self.acct_svcr_tx_id, step, create, out) }
+		if step.matches("MktInfrstrctrTxId") { optional_mut(&mut self.mkt_infrstrctr_tx_id, step, create, out) }
+		if step.matches("PrcgId") { optional_mut(&mut self.prcg_id, step, create, out) }
+		if step.matches("Prtry") { optional_repeated_mut(&mut self.prtry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::TransactionType2 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Sts") { one(&self.sts, step, out) }
+		if step.matches("CdtDbtInd") { one(&self.cdt_dbt_ind, step, out) }
+		if step.matches("FlrLmt") { optional_repeated(&self.flr_lmt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Sts") { one_mut(&mut self.sts, step, out) }
+		if step.matches("CdtDbtInd") { one_mut(&mut self.cdt_dbt_ind, step, out) }
+		if step.matches("FlrLmt") { optional_repeated_mut(&mut self.flr_lmt, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnableToApplyIncorrect1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { one(&self.cd, step, out) }
+		if step.matches("AddtlIncrrctInf") { optional(&self.addtl_incrrct_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { one_mut(&mut self.cd, step, out) }
+		if step.matches("AddtlIncrrctInf") { optional_mut(&mut self.addtl_incrrct_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnableToApplyIncorrectInformation4Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeIN01 => "IN01",
+			Self::CodeIN02 => "IN02",
+			Self::CodeIN03 => "IN03",
+			Self::CodeIN04 => "IN04",
+			Self::CodeIN05 => "IN05",
+			Self::CodeIN06 => "IN06",
+			Self::CodeIN07 => "IN07",
+			Self::CodeIN08 => "IN08",
+			Self::CodeIN09 => "IN09",
+			Self::CodeIN10 => "IN10",
+			Self::CodeIN11 => "IN11",
+			Self::CodeIN12 => "IN12",
+			Self::CodeIN13 => "IN13",
+			Self::CodeIN14 => "IN14",
+			Self::CodeIN15 => "IN15",
+			Self::CodeIN16 => "IN16",
+			Self::CodeIN17 => "IN17",
+			Self::CodeIN18 => "IN18",
+			Self::CodeIN19 => "IN19",
+			Self::CodeMM20 => "MM20",
+			Self::CodeMM21 => "MM21",
+			Self::CodeMM22 => "MM22",
+			Self::CodeMM25 => "MM25",
+			Self::CodeMM26 => "MM26",
+			Self::CodeMM27 => "MM27",
+			Self::CodeMM28 => "MM28",
+			Self::CodeMM29 => "MM29",
+			Self::CodeMM30 => "MM30",
+			Self::CodeMM31 => "MM31",
+			Self::CodeMM32 => "MM32",
+			Self::CodeIN33 => "IN33",
+			Self::CodeMM34 => "MM34",
+			Self::CodeMM35 => "MM35",
+			Self::CodeIN36 => "IN36",
+			Self::CodeIN37 => "IN37",
+			Self::CodeIN38 => "IN38",
+			Self::CodeIN39 => "IN39",
+			Self::CodeNARR => "NARR",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"IN01" => Self::CodeIN01,
+			"IN02" => Self::CodeIN02,
+			"IN03" => Self::CodeIN03,
+			"IN04" => Self::CodeIN04,
+			"IN05" => Self::CodeIN05,
+			"IN06" => Self::CodeIN06,
+			"IN07" => Self::CodeIN07,
+			"IN08" => Self::CodeIN08,
+			"IN09" => Self::CodeIN09,
+			"IN10" => Self::CodeIN10,
+			"IN11" => Self::CodeIN11,
+			"IN12" => Self::CodeIN12,
+			"IN13" => Self::CodeIN13,
+			"IN14" => Self::CodeIN14,
+			"IN15" => Self::CodeIN15,
+			"IN16" => Self::CodeIN16,
+			"IN17" => Self::CodeIN17,
+			"IN18" => Self::CodeIN18,
+			"IN19" => Self::CodeIN19,
+			"MM20" => Self::CodeMM20,
+			"MM21" => Self::CodeMM21,
+			"MM22" => Self::CodeMM22,
+			"MM25" => Self::CodeMM25,
+			"MM26" => Self::CodeMM26,
+			"MM27" => Self::CodeMM27,
+			"MM28" => Self::CodeMM28,
+			"MM29" => Self::CodeMM29,
+			"MM30" => Self::CodeMM30,
+			"MM31" => Self::CodeMM31,
+			"MM32" => Self::CodeMM32,
+			"IN33" => Self::CodeIN33,
+			"MM34" => Self::CodeMM34,
+			"MM35" => Self::CodeMM35,
+			"IN36" => Self::CodeIN36,
+			"IN37" => Self::CodeIN37,
+			"IN38" => Self::CodeIN38,
+			"IN39" => Self::CodeIN39,
+			"NARR" => Self::CodeNARR,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::UnableToApplyJustification3Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AnyInf") { optional(&self.any_inf, step, out) }
+		if step.matches("MssngOrIncrrctInf") { optional(&self.mssng_or_incrrct_inf, step, out) }
+		if step.matches("PssblDplctInstr") { optional(&self.pssbl_dplct_instr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AnyInf") { optional_mut(&mut self.any_inf, step, create, out) }
+		if step.matches("MssngOrIncrrctInf") { optional_mut(&mut self.mssng_or_incrrct_inf, step, create, out) }
+		if step.matches("PssblDplctInstr") { optional_mut(&mut self.pssbl_dplct_instr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnableToApplyMissing1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Cd") { one(&self.cd, step, out) }
+		if step.matches("AddtlMssngInf") { optional(&self.addtl_mssng_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Cd") { one_mut(&mut self.cd, step, out) }
+		if step.matches("AddtlMssngInf") { optional_mut(&mut self.addtl_mssng_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnableToApplyMissingInformation3Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeMS01 => "MS01",
+			Self::CodeMS02 => "MS02",
+			Self::CodeMS03 => "MS03",
+			Self::CodeMS04 => "MS04",
+			Self::CodeMS05 => "MS05",
+			Self::CodeMS06 => "MS06",
+			Self::CodeMS07 => "MS07",
+			Self::CodeMS08 => "MS08",
+			Self::CodeMS09 => "MS09",
+			Self::CodeMS10 => "MS10",
+			Self::CodeMS11 => "MS11",
+			Self::CodeMS12 => "MS12",
+			Self::CodeMS13 => "MS13",
+			Self::CodeMS14 => "MS14",
+			Self::CodeMS15 => "MS15",
+			Self::CodeMS16 => "MS16",
+			Self::CodeMS17 => "MS17",
+			Self::CodeNARR => "NARR",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"MS01" => Self::CodeMS01,
+			"MS02" => Self::CodeMS02,
+			"MS03" => Self::CodeMS03,
+			"MS04" => Self::CodeMS04,
+			"MS05" => Self::CodeMS05,
+			"MS06" => Self::CodeMS06,
+			"MS07" => Self::CodeMS07,
+			"MS08" => Self::CodeMS08,
+			"MS09" => Self::CodeMS09,
+			"MS10" => Self::CodeMS10,
+			"MS11" => Self::CodeMS11,
+			"MS12" => Self::CodeMS12,
+			"MS13" => Self::CodeMS13,
+			"MS14" => Self::CodeMS14,
+			"MS15" => Self::CodeMS15,
+			"MS16" => Self::CodeMS16,
+			"MS17" => Self::CodeMS17,
+			"NARR" => Self::CodeNARR,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::UnderlyingGroupInformation1 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one(&self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one(&self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional(&self.orgnl_cre_dt_tm, step, out) }
+		if step.matches("OrgnlMsgDlvryChanl") { optional(&self.orgnl_msg_dlvry_chanl, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlMsgId") { one_mut(&mut self.orgnl_msg_id, step, out) }
+		if step.matches("OrgnlMsgNmId") { one_mut(&mut self.orgnl_msg_nm_id, step, out) }
+		if step.matches("OrgnlCreDtTm") { optional_mut(&mut self.orgnl_cre_dt_tm, step, create, out) }
+		if step.matches("OrgnlMsgDlvryChanl") { optional_mut(&mut self.orgnl_msg_dlvry_chanl, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnderlyingPaymentInstruction5 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlPmtInfId") { optional(&self.orgnl_pmt_inf_id, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("OrgnlInstdAmt") { one(&self.orgnl_instd_amt, step, out) }
+		if step.matches("ReqdExctnDt") { optional(&self.reqd_exctn_dt, step, out) }
+		if step.matches("ReqdColltnDt") { optional(&self.reqd_colltn_dt, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlPmtInfId") { optional_mut(&mut self.orgnl_pmt_inf_id, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("OrgnlInstdAmt") { one_mut(&mut self.orgnl_instd_amt, step, out) }
+		if step.matches("ReqdExctnDt") { optional_mut(&mut self.reqd_exctn_dt, step, create, out) }
+		if step.matches("ReqdColltnDt") { optional_mut(&mut self.reqd_colltn_dt, step, create, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnderlyingPaymentTransaction4 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlInstrId") { optional(&self.orgnl_instr_id, step, out) }
+		if step.matches("OrgnlEndToEndId") { optional(&self.orgnl_end_to_end_id, step, out) }
+		if step.matches("OrgnlTxId") { optional(&self.orgnl_tx_id, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { one(&self.orgnl_intr_bk_sttlm_amt, step, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { one(&self.orgnl_intr_bk_sttlm_dt, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlInstrId") { optional_mut(&mut self.orgnl_instr_id, step, create, out) }
+		if step.matches("OrgnlEndToEndId") { optional_mut(&mut self.orgnl_end_to_end_id, step, create, out) }
+		if step.matches("OrgnlTxId") { optional_mut(&mut self.orgnl_tx_id, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+		if step.matches("OrgnlIntrBkSttlmAmt") { one_mut(&mut self.orgnl_intr_bk_sttlm_amt, step, out) }
+		if step.matches("OrgnlIntrBkSttlmDt") { one_mut(&mut self.orgnl_intr_bk_sttlm_dt, step, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnderlyingStatementEntry3 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlStmtId") { optional(&self.orgnl_stmt_id, step, out) }
+		if step.matches("OrgnlNtryId") { optional(&self.orgnl_ntry_id, step, out) }
+		if step.matches("OrgnlUETR") { optional(&self.orgnl_uetr, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("OrgnlStmtId") { optional_mut(&mut self.orgnl_stmt_id, step, create, out) }
+		if step.matches("OrgnlNtryId") { optional_mut(&mut self.orgnl_ntry_id, step, create, out) }
+		if step.matches("OrgnlUETR") { optional_mut(&mut self.orgnl_uetr, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnderlyingTransaction22 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlGrpInfAndSts") { optional(&self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("OrgnlPmtInfAndSts") { optional_repeated(&self.orgnl_pmt_inf_and_sts, step, out) }
+		if step.matches("TxInfAndSts") { optional_repeated(&self.tx_inf_and_sts, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlGrpInfAndSts") { optional_mut(&mut self.orgnl_grp_inf_and_sts, step, create, out) }
+		if step.matches("OrgnlPmtInfAndSts") { optional_repeated_mut(&mut self.orgnl_pmt_inf_and_sts, step, create, out) }
+		if step.matches("TxInfAndSts") { optional_repeated_mut(&mut self.tx_inf_and_sts, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnderlyingTransaction23 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlGrpInfAndCxl") { optional(&self.orgnl_grp_inf_and_cxl, step, out) }
+		if step.matches("TxInf") { optional_repeated(&self.tx_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlGrpInfAndCxl") { optional_mut(&mut self.orgnl_grp_inf_and_cxl, step, create, out) }
+		if step.matches("TxInf") { optional_repeated_mut(&mut self.tx_inf, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnderlyingTransaction27 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlGrpInfAndCxl") { optional(&self.orgnl_grp_inf_and_cxl, step, out) }
+		if step.matches("OrgnlPmtInfAndCxl") { optional_repeated(&self.orgnl_pmt_inf_and_cxl, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlGrpInfAndCxl") { optional_mut(&mut self.orgnl_grp_inf_and_cxl, step, create, out) }
+		if step.matches("OrgnlPmtInfAndCxl") { optional_repeated_mut(&mut self.orgnl_pmt_inf_and_cxl, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnderlyingTransaction5Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Initn") { optional(&self.initn, step, out) }
+		if step.matches("IntrBk") { optional(&self.intr_bk, step, out) }
+		if step.matches("StmtNtry") { optional(&self.stmt_ntry, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Initn") { optional_mut(&mut self.initn, step, create, out) }
+		if step.matches("IntrBk") { optional_mut(&mut self.intr_bk, step, create, out) }
+		if step.matches("StmtNtry") { optional_mut(&mut self.stmt_ntry, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::UnitOfMeasure1Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodePIEC => "PIEC",
+			Self::CodeTONS => "TONS",
+			Self::CodeFOOT => "FOOT",
+			Self::CodeGBGA => "GBGA",
+			Self::CodeUSGA => "USGA",
+			Self::CodeGRAM => "GRAM",
+			Self::CodeINCH => "INCH",
+			Self::CodeKILO => "KILO",
+			Self::CodePUND => "PUND",
+			Self::CodeMETR => "METR",
+			Self::CodeCMET => "CMET",
+			Self::CodeMMET => "MMET",
+			Self::CodeLITR => "LITR",
+			Self::CodeCELI => "CELI",
+			Self::CodeMILI => "MILI",
+			Self::CodeGBOU => "GBOU",
+			Self::CodeUSOU => "USOU",
+			Self::CodeGBQA => "GBQA",
+			Self::CodeUSQA => "USQA",
+			Self::CodeGBPI => "GBPI",
+			Self::CodeUSPI => "USPI",
+			Self::CodeMILE => "MILE",
+			Self::CodeKMET => "KMET",
+			Self::CodeYARD => "YARD",
+			Self::CodeSQKI => "SQKI",
+			Self::CodeHECT => "HECT",
+			Self::CodeARES => "ARES",
+			Self::CodeSMET => "SMET",
+			Self::CodeSCMT => "SCMT",
+			Self::CodeSMIL => "SMIL",
+			Self::CodeSQMI => "SQMI",
+			Self::CodeSQYA => "SQYA",
+			Self::CodeSQFO => "SQFO",
+			Self::CodeSQIN => "SQIN",
+			Self::CodeACRE => "ACRE",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"PIEC" => Self::CodePIEC,
+			"TONS" => Self::CodeTONS,
+			"FOOT" => Self::CodeFOOT,
+			"GBGA" => Self::CodeGBGA,
+			"USGA" => Self::CodeUSGA,
+			"GRAM" => Self::CodeGRAM,
+			"INCH" => Self::CodeINCH,
+			"KILO" => Self::CodeKILO,
+			"PUND" => Self::CodePUND,
+			"METR" => Self::CodeMETR,
+			"CMET" => Self::CodeCMET,
+			"MMET" => Self::CodeMMET,
+			"LITR" => Self::CodeLITR,
+			"CELI" => Self::CodeCELI,
+			"MILI" => Self::CodeMILI,
+			"GBOU" => Self::CodeGBOU,
+			"USOU" => Self::CodeUSOU,
+			"GBQA" => Self::CodeGBQA,
+			"USQA" => Self::CodeUSQA,
+			"GBPI" => Self::CodeGBPI,
+			"USPI" => Self::CodeUSPI,
+			"MILE" => Self::CodeMILE,
+			"KMET" => Self::CodeKMET,
+			"YARD" => Self::CodeYARD,
+			"SQKI" => Self::CodeSQKI,
+			"HECT" => Self::CodeHECT,
+			"ARES" => Self::CodeARES,
+			"SMET" => Self::CodeSMET,
+			"SCMT" => Self::CodeSCMT,
+			"SMIL" => Self::CodeSMIL,
+			"SQMI" => Self::CodeSQMI,
+			"SQYA" => Self::CodeSQYA,
+			"SQFO" => Self::CodeSQFO,
+			"SQIN" => Self::CodeSQIN,
+			"ACRE" => Self::CodeACRE,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::UserInterface2Code {
+	fn code(&self) -> Option<&'static str> {
+		Some(match self {
+			Self::CodeMDSP => "MDSP",
+			Self::CodeCDSP => "CDSP",
+		})
+	}
+
+	fn set_code(&mut self, code: &str) -> bool {
+		*self = match code {
+			"MDSP" => Self::CodeMDSP,
+			"CDSP" => Self::CodeCDSP,
+			_ => return false,
+		};
+		true
+	}
+}
+
+impl TagPaths for crate::common::YieldedOrValueType1Choice {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Yldd") { optional(&self.yldd, step, out) }
+		if step.matches("ValTp") { optional(&self.val_tp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Yldd") { optional_mut(&mut self.yldd, step, create, out) }
+		if step.matches("ValTp") { optional_mut(&mut self.val_tp, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowMessageSignatureKey {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FedNowKeyID") { one(&self.fed_now_key_id, step, out) }
+		if step.matches("Name") { one(&self.name, step, out) }
+		if step.matches("EncodedPublicKey") { one(&self.encoded_public_key, step, out) }
+		if step.matches("Encoding") { one(&self.encoding, step, out) }
+		if step.matches("Algorithm") { optional(&self.algorithm, step, out) }
+		if step.matches("KeyCreationDateTime") { optional(&self.key_creation_date_time, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FedNowKeyID") { one_mut(&mut self.fed_now_key_id, step, out) }
+		if step.matches("Name") { one_mut(&mut self.name, step, out) }
+		if step.matches("EncodedPublicKey") { one_mut(&mut self.encoded_public_key, step, out) }
+		if step.matches("Encoding") { one_mut(&mut self.encoding, step, out) }
+		if step.matches("Algorithm") { optional_mut(&mut self.algorithm, step, create, out) }
+		if step.matches("KeyCreationDateTime") { optional_mut(&mut self.key_creation_date_time, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowMessageSignatureKeyStatus {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("KeyStatus") { one(&self.key_status, step, out) }
+		if step.matches("StatusDateTime") { one(&self.status_date_time, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("KeyStatus") { one_mut(&mut self.key_status, step, out) }
+		if step.matches("StatusDateTime") { one_mut(&mut self.status_date_time, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::FedNowPublicKeyResponse {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FedNowMessageSignatureKeyStatus") { one(&self.fed_now_message_signature_key_status, step, out) }
+		if step.matches("FedNowMessageSignatureKey") { one(&self.fed_now_message_signature_key, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FedNowMessageSignatureKeyStatus") { one_mut(&mut self.fed_now_message_signature_key_status, step, out) }
+		if step.matches("FedNowMessageSignatureKey") { one_mut(&mut self.fed_now_message_signature_key, step, out) }
+	}
+}
+
+impl TagPaths for crate::common::KeyAddition {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Key") { optional(&self.key, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Key") { optional_mut(&mut self.key, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::fednow_extra::key_exchange::KeyRevocation {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("KeyRevocation") { optional(&self.key_revocation, step, out) }
+		if step.matches("FedNowStatusDescription") { optional(&self.fed_now_status_description, step, out) }
+		if step.matches("FedNowKeyID") { optional(&self.fed_now_key_id, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("KeyRevocation") { optional_mut(&mut self.key_revocation, step, create, out) }
+		if step.matches("FedNowStatusDescription") { optional_mut(&mut self.fed_now_status_description, step, create, out) }
+		if step.matches("FedNowKeyID") { optional_mut(&mut self.fed_now_key_id, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::fednow_extra::key_exchange::FedNowMessageSignatureKeyExchange {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("KeyAddition") { optional(&self.key_addition, step, out) }
+		if step.matches("KeyRevocation") { optional(&self.key_revocation, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("KeyAddition") { optional_mut(&mut self.key_addition, step, create, out) }
+		if step.matches("KeyRevocation") { optional_mut(&mut self.key_revocation, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::fednow_extra::key_exchange::FedNowCustomerMessageSignatureKeyOperationResponse {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FedNowKeyID") { one(&self.fed_now_key_id, step, out) }
+		if step.matches("Status") { one(&self.status, step, out) }
+		if step.matches("ErrorCode") { optional(&self.error_code, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FedNowKeyID") { one_mut(&mut self.fed_now_key_id, step, out) }
+		if step.matches("Status") { one_mut(&mut self.status, step, out) }
+		if step.matches("ErrorCode") { optional_mut(&mut self.error_code, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::fednow_extra::key_exchange::GetAllFedNowActivePublicKeys {}
+
+impl TagPaths for crate::fednow_extra::key_exchange::GetAllCustomerPublicKeys {}
+
+impl TagPaths for crate::fednow_extra::key_exchange::FedNowPublicKeyResponses {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PublicKeys") { repeated(&self.public_keys, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PublicKeys") { repeated_mut(&mut self.public_keys, step, out) }
+	}
+}
+
+impl TagPaths for crate::fednow_incoming_external::FedNowIncoming {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FedNowTechnicalHeader") { optional(&self.fed_now_technical_header, step, out) }
+		if step.matches("FedNowIncomingMessage") { one(&self.fed_now_incoming_message, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FedNowTechnicalHeader") { optional_mut(&mut self.fed_now_technical_header, step, create, out) }
+		if step.matches("FedNowIncomingMessage") { one_mut(&mut self.fed_now_incoming_message, step, out) }
+	}
+}
+
+impl TagPaths for crate::fednow_outgoing_external::FedNowOutgoing {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FedNowTechnicalHeader") { optional(&self.fed_now_technical_header, step, out) }
+		if step.matches("FedNowOutgoingMessage") { one(&self.fed_now_outgoing_message, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FedNowTechnicalHeader") { optional_mut(&mut self.fed_now_technical_header, step, create, out) }
+		if step.matches("FedNowOutgoingMessage") { one_mut(&mut self.fed_now_outgoing_message, step, out) }
+	}
+}
+
+impl TagPaths for crate::iso::admi_002_001_01::Admi00200101 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RltdRef") { one(&self.rltd_ref, step, out) }
+		if step.matches("Rsn") { one(&self.rsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RltdRef") { one_mut(&mut self.rltd_ref, step, out) }
+		if step.matches("Rsn") { one_mut(&mut self.rsn, step, out) }
+	}
+}
+
+impl TagPaths for crate::iso::admi_004_001_02::SystemEventNotificationV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("EvtInf") { one(&self.evt_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("EvtInf") { one_mut(&mut self.evt_inf, step, out) }
+	}
+}
+
+impl TagPaths for crate::iso::admi_006_001_01::ResendRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RsndSchCrit") { repeated(&self.rsnd_sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RsndSchCrit") { repeated_mut(&mut self.rsnd_sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::admi_007_001_01::ReceiptAcknowledgementV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("Rpt") { repeated(&self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("Rpt") { repeated_mut(&mut self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::admi_011_001_01::SystemEventAcknowledgementV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("OrgtrRef") { optional(&self.orgtr_ref, step, out) }
+		if step.matches("SttlmSsnIdr") { optional(&self.sttlm_ssn_idr, step, out) }
+		if step.matches("AckDtls") { optional(&self.ack_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("OrgtrRef") { optional_mut(&mut self.orgtr_ref, step, create, out) }
+		if step.matches("SttlmSsnIdr") { optional_mut(&mut self.sttlm_ssn_idr, step, create, out) }
+		if step.matches("AckDtls") { optional_mut(&mut self.ack_dtls, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::admi_998_001_02::AdministrationProprietaryMessageV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { optional(&self.msg_id, step, out) }
+		if step.matches("Rltd") { optional(&self.rltd, step, out) }
+		if step.matches("Prvs") { optional(&self.prvs, step, out) }
+		if step.matches("Othr") { optional(&self.othr, step, out) }
+		if step.matches("PrtryData") { one(&self.prtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { optional_mut(&mut self.msg_id, step, create, out) }
+		if step.matches("Rltd") { optional_mut(&mut self.rltd, step, create, out) }
+		if step.matches("Prvs") { optional_mut(&mut self.prvs, step, create, out) }
+		if step.matches("Othr") { optional_mut(&mut self.othr, step, create, out) }
+		if step.matches("PrtryData") { one_mut(&mut self.prtry_data, step, out) }
+	}
+}
+
+impl TagPaths for crate::iso::camt_026_001_07::UnableToApplyV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Undrlyg") { one(&self.undrlyg, step, out) }
+		if step.matches("Justfn") { one(&self.justfn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Undrlyg") { one_mut(&mut self.undrlyg, step, out) }
+		if step.matches("Justfn") { one_mut(&mut self.justfn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::camt_028_001_09::AdditionalPaymentInformationV09 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Undrlyg") { one(&self.undrlyg, step, out) }
+		if step.matches("Inf") { one(&self.inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Undrlyg") { one_mut(&mut self.undrlyg, step, out) }
+		if step.matches("Inf") { one_mut(&mut self.inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::camt_029_001_09::ResolutionOfInvestigationV09 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("RslvdCase") { optional(&self.rslvd_case, step, out) }
+		if step.matches("Sts") { one(&self.sts, step, out) }
+		if step.matches("CxlDtls") { optional_repeated(&self.cxl_dtls, step, out) }
+		if step.matches("ModDtls") { optional(&self.mod_dtls, step, out) }
+		if step.matches("ClmNonRctDtls") { optional(&self.clm_non_rct_dtls, step, out) }
+		if step.matches("StmtDtls") { optional(&self.stmt_dtls, step, out) }
+		if step.matches("CrrctnTx") { optional(&self.crrctn_tx, step, out) }
+		if step.matches("RsltnRltdInf") { optional(&self.rsltn_rltd_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("RslvdCase") { optional_mut(&mut self.rslvd_case, step, create, out) }
+		if step.matches("Sts") { one_mut(&mut self.sts, step, out) }
+		if step.matches("CxlDtls") { optional_repeated_mut(&mut self.cxl_dtls, step, create, out) }
+		if step.matches("ModDtls") { optional_mut(&mut self.mod_dtls, step, create, out) }
+		if step.matches("ClmNonRctDtls") { optional_mut(&mut self.clm_non_rct_dtls, step, create, out) }
+		if step.matches("StmtDtls") { optional_mut(&mut self.stmt_dtls, step, create, out) }
+		if step.matches("CrrctnTx") { optional_mut(&mut self.crrctn_tx, step, create, out) }
+		if step.matches("RsltnRltdInf") { optional_mut(&mut self.rsltn_rltd_inf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::camt_052_001_08::BankToCustomerAccountReportV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Rpt") { repeated(&self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Rpt") { repeated_mut(&mut self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::camt_054_001_08::BankToCustomerDebitCreditNotificationV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Ntfctn") { repeated(&self.ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Ntfctn") { repeated_mut(&mut self.ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::camt_055_001_09::CustomerPaymentCancellationRequestV09 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("CtrlData") { optional(&self.ctrl_data, step, out) }
+		if step.matches("Undrlyg") { repeated(&self.undrlyg, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("CtrlData") { optional_mut(&mut self.ctrl_data, step, create, out) }
+		if step.matches("Undrlyg") { repeated_mut(&mut self.undrlyg, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::camt_056_001_08::FIToFIPaymentCancellationRequestV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("CtrlData") { optional(&self.ctrl_data, step, out) }
+		if step.matches("Undrlyg") { repeated(&self.undrlyg, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("CtrlData") { optional_mut(&mut self.ctrl_data, step, create, out) }
+		if step.matches("Undrlyg") { repeated_mut(&mut self.undrlyg, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::camt_060_001_05::AccountReportingRequestV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("RptgReq") { repeated(&self.rptg_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("RptgReq") { repeated_mut(&mut self.rptg_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::pacs_002_001_10::FIToFIPaymentStatusReportV10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { optional_repeated(&self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("TxInfAndSts") { optional_repeated(&self.tx_inf_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { optional_repeated_mut(&mut self.orgnl_grp_inf_and_sts, step, create, out) }
+		if step.matches("TxInfAndSts") { optional_repeated_mut(&mut self.tx_inf_and_sts, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::pacs_004_001_10::PaymentReturnV10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("TxInf") { optional_repeated(&self.tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("TxInf") { optional_repeated_mut(&mut self.tx_inf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("CdtTrfTxInf") { repeated(&self.cdt_trf_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("CdtTrfTxInf") { repeated_mut(&mut self.cdt_trf_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::pacs_009_001_08::FinancialInstitutionCreditTransferV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("CdtTrfTxInf") { repeated(&self.cdt_trf_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("CdtTrfTxInf") { repeated_mut(&mut self.cdt_trf_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::pacs_028_001_03::FIToFIPaymentStatusRequestV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional_repeated(&self.orgnl_grp_inf, step, out) }
+		if step.matches("TxInf") { optional_repeated(&self.tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional_repeated_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("TxInf") { optional_repeated_mut(&mut self.tx_inf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::pain_013_001_07::CreditorPaymentActivationRequestV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("PmtInf") { repeated(&self.pmt_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("PmtInf") { repeated_mut(&mut self.pmt_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::iso::pain_014_001_07::CreditorPaymentActivationRequestStatusReportV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { one(&self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("OrgnlPmtInfAndSts") { optional_repeated(&self.orgnl_pmt_inf_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { one_mut(&mut self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("OrgnlPmtInfAndSts") { optional_repeated_mut(&mut self.orgnl_pmt_inf_and_sts, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
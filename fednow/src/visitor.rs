@@ -0,0 +1,7881 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over the generated types of this crate, generated by
+// generate-visitor.py. `Visitor` has a `visit_` method per type that
+// walks into its fields by default; override the methods of the types of
+// interest and call the matching `walk_` function to keep going below
+// them. `VisitorMut` does the same with mutable references.
+
+pub trait Visitor {
+	fn visit_account_debit_credit_notification(&mut self, node: &crate::common::AccountDebitCreditNotification) { walk_account_debit_credit_notification(self, node) }
+	fn visit_fed_now_account_activity_details_report(&mut self, node: &crate::common::FedNowAccountActivityDetailsReport) { walk_fed_now_account_activity_details_report(self, node) }
+	fn visit_fed_now_account_activity_totals_report(&mut self, node: &crate::common::FedNowAccountActivityTotalsReport) { walk_fed_now_account_activity_totals_report(self, node) }
+	fn visit_fed_now_account_balance_report(&mut self, node: &crate::common::FedNowAccountBalanceReport) { walk_fed_now_account_balance_report(self, node) }
+	fn visit_fed_now_account_reporting_request(&mut self, node: &crate::common::FedNowAccountReportingRequest) { walk_fed_now_account_reporting_request(self, node) }
+	fn visit_fed_now_additional_payment_information(&mut self, node: &crate::common::FedNowAdditionalPaymentInformation) { walk_fed_now_additional_payment_information(self, node) }
+	fn visit_fed_now_broadcast(&mut self, node: &crate::common::FedNowBroadcast) { walk_fed_now_broadcast(self, node) }
+	fn visit_fed_now_customer_credit_transfer(&mut self, node: &crate::common::FedNowCustomerCreditTransfer) { walk_fed_now_customer_credit_transfer(self, node) }
+	fn visit_fed_now_incoming_message(&mut self, node: &crate::common::FedNowIncomingMessage) { walk_fed_now_incoming_message(self, node) }
+	fn visit_fed_now_incoming_message_signature_management(&mut self, node: &crate::common::FedNowIncomingMessageSignatureManagement) { walk_fed_now_incoming_message_signature_management(self, node) }
+	fn visit_fed_now_information_request(&mut self, node: &crate::common::FedNowInformationRequest) { walk_fed_now_information_request(self, node) }
+	fn visit_fed_now_information_request_response(&mut self, node: &crate::common::FedNowInformationRequestResponse) { walk_fed_now_information_request_response(self, node) }
+	fn visit_fed_now_institution_credit_transfer(&mut self, node: &crate::common::FedNowInstitutionCreditTransfer) { walk_fed_now_institution_credit_transfer(self, node) }
+	fn visit_fed_now_message_reject(&mut self, node: &crate::common::FedNowMessageReject) { walk_fed_now_message_reject(self, node) }
+	fn visit_fed_now_outgoing_message(&mut self, node: &crate::common::FedNowOutgoingMessage) { walk_fed_now_outgoing_message(self, node) }
+	fn visit_fed_now_outgoing_message_signature_management(&mut self, node: &crate::common::FedNowOutgoingMessageSignatureManagement) { walk_fed_now_outgoing_message_signature_management(self, node) }
+	fn visit_fed_now_participant_broadcast(&mut self, node: &crate::common::FedNowParticipantBroadcast) { walk_fed_now_participant_broadcast(self, node) }
+	fn visit_fed_now_participant_file(&mut self, node: &crate::common::FedNowParticipantFile) { walk_fed_now_participant_file(self, node) }
+	fn visit_fed_now_payment_return(&mut self, node: &crate::common::FedNowPaymentReturn) { walk_fed_now_payment_return(self, node) }
+	fn visit_fed_now_payment_status(&mut self, node: &crate::common::FedNowPaymentStatus) { walk_fed_now_payment_status(self, node) }
+	fn visit_fed_now_payment_status_request(&mut self, node: &crate::common::FedNowPaymentStatusRequest) { walk_fed_now_payment_status_request(self, node) }
+	fn visit_fed_now_receipt_acknowledgement(&mut self, node: &crate::common::FedNowReceiptAcknowledgement) { walk_fed_now_receipt_acknowledgement(self, node) }
+	fn visit_fed_now_request_for_payment(&mut self, node: &crate::common::FedNowRequestForPayment) { walk_fed_now_request_for_payment(self, node) }
+	fn visit_fed_now_request_for_payment_cancellation_request(&mut self, node: &crate::common::FedNowRequestForPaymentCancellationRequest) { walk_fed_now_request_for_payment_cancellation_request(self, node) }
+	fn visit_fed_now_request_for_payment_cancellation_request_response(&mut self, node: &crate::common::FedNowRequestForPaymentCancellationRequestResponse) { walk_fed_now_request_for_payment_cancellation_request_response(self, node) }
+	fn visit_fed_now_request_for_payment_response(&mut self, node: &crate::common::FedNowRequestForPaymentResponse) { walk_fed_now_request_for_payment_response(self, node) }
+	fn visit_fed_now_retrieval_request(&mut self, node: &crate::common::FedNowRetrievalRequest) { walk_fed_now_retrieval_request(self, node) }
+	fn visit_fed_now_return_request(&mut self, node: &crate::common::FedNowReturnRequest) { walk_fed_now_return_request(self, node) }
+	fn visit_fed_now_return_request_response(&mut self, node: &crate::common::FedNowReturnRequestResponse) { walk_fed_now_return_request_response(self, node) }
+	fn visit_fed_now_system_response(&mut self, node: &crate::common::FedNowSystemResponse) { walk_fed_now_system_response(self, node) }
+	fn visit_fed_now_technical_header(&mut self, node: &crate::common::FedNowTechnicalHeader) { walk_fed_now_technical_header(self, node) }
+	fn visit_account_identification4_choice(&mut self, node: &crate::common::AccountIdentification4Choice) { walk_account_identification4_choice(self, node) }
+	fn visit_account_interest4(&mut self, node: &crate::common::AccountInterest4) { walk_account_interest4(self, node) }
+	fn visit_account_notification17(&mut self, node: &crate::common::AccountNotification17) { walk_account_notification17(self, node) }
+	fn visit_account_report25(&mut self, node: &crate::common::AccountReport25) { walk_account_report25(self, node) }
+	fn visit_account_scheme_name1_choice(&mut self, node: &crate::common::AccountSchemeName1Choice) { walk_account_scheme_name1_choice(self, node) }
+	fn visit_active_currency_and_amount(&mut self, node: &crate::common::ActiveCurrencyAndAmount) { walk_active_currency_and_amount(self, node) }
+	fn visit_active_or_historic_currency_and13_decimal_amount(&mut self, node: &crate::common::ActiveOrHistoricCurrencyAnd13DecimalAmount) { walk_active_or_historic_currency_and13_decimal_amount(self, node) }
+	fn visit_active_or_historic_currency_and_amount(&mut self, node: &crate::common::ActiveOrHistoricCurrencyAndAmount) { walk_active_or_historic_currency_and_amount(self, node) }
+	fn visit_active_or_historic_currency_and_amount_range2(&mut self, node: &crate::common::ActiveOrHistoricCurrencyAndAmountRange2) { walk_active_or_historic_currency_and_amount_range2(self, node) }
+	fn visit_address_type2_code(&mut self, node: &crate::common::AddressType2Code) { walk_address_type2_code(self, node) }
+	fn visit_address_type3_choice(&mut self, node: &crate::common::AddressType3Choice) { walk_address_type3_choice(self, node) }
+	fn visit_amendment_information_details13(&mut self, node: &crate::common::AmendmentInformationDetails13) { walk_amendment_information_details13(self, node) }
+	fn visit_amount_and_currency_exchange3(&mut self, node: &crate::common::AmountAndCurrencyExchange3) { walk_amount_and_currency_exchange3(self, node) }
+	fn visit_amount_and_currency_exchange_details3(&mut self, node: &crate::common::AmountAndCurrencyExchangeDetails3) { walk_amount_and_currency_exchange_details3(self, node) }
+	fn visit_amount_and_currency_exchange_details4(&mut self, node: &crate::common::AmountAndCurrencyExchangeDetails4) { walk_amount_and_currency_exchange_details4(self, node) }
+	fn visit_amount_and_direction35(&mut self, node: &crate::common::AmountAndDirection35) { walk_amount_and_direction35(self, node) }
+	fn visit_amount_or_rate1_choice(&mut self, node: &crate::common::AmountOrRate1Choice) { walk_amount_or_rate1_choice(self, node) }
+	fn visit_amount_range_boundary1(&mut self, node: &crate::common::AmountRangeBoundary1) { walk_amount_range_boundary1(self, node) }
+	fn visit_amount_type4_choice(&mut self, node: &crate::common::AmountType4Choice) { walk_amount_type4_choice(self, node) }
+	fn visit_attendance_context1_code(&mut self, node: &crate::common::AttendanceContext1Code) { walk_attendance_context1_code(self, node) }
+	fn visit_authentication_entity1_code(&mut self, node: &crate::common::AuthenticationEntity1Code) { walk_authentication_entity1_code(self, node) }
+	fn visit_authentication_method1_code(&mut self, node: &crate::common::AuthenticationMethod1Code) { walk_authentication_method1_code(self, node) }
+	fn visit_authorisation1_choice(&mut self, node: &crate::common::Authorisation1Choice) { walk_authorisation1_choice(self, node) }
+	fn visit_authorisation1_code(&mut self, node: &crate::common::Authorisation1Code) { walk_authorisation1_code(self, node) }
+	fn visit_balance_sub_type1_choice(&mut self, node: &crate::common::BalanceSubType1Choice) { walk_balance_sub_type1_choice(self, node) }
+	fn visit_balance_type10_choice(&mut self, node: &crate::common::BalanceType10Choice) { walk_balance_type10_choice(self, node) }
+	fn visit_balance_type13(&mut self, node: &crate::common::BalanceType13) { walk_balance_type13(self, node) }
+	fn visit_bank_transaction_code_structure4(&mut self, node: &crate::common::BankTransactionCodeStructure4) { walk_bank_transaction_code_structure4(self, node) }
+	fn visit_bank_transaction_code_structure5(&mut self, node: &crate::common::BankTransactionCodeStructure5) { walk_bank_transaction_code_structure5(self, node) }
+	fn visit_bank_transaction_code_structure6(&mut self, node: &crate::common::BankTransactionCodeStructure6) { walk_bank_transaction_code_structure6(self, node) }
+	fn visit_batch_information2(&mut self, node: &crate::common::BatchInformation2) { walk_batch_information2(self, node) }
+	fn visit_branch_and_financial_institution_identification6(&mut self, node: &crate::common::BranchAndFinancialInstitutionIdentification6) { walk_branch_and_financial_institution_identification6(self, node) }
+	fn visit_branch_data3(&mut self, node: &crate::common::BranchData3) { walk_branch_data3(self, node) }
+	fn visit_business_application_header5(&mut self, node: &crate::common::BusinessApplicationHeader5) { walk_business_application_header5(self, node) }
+	fn visit_business_application_header_v02(&mut self, node: &crate::common::BusinessApplicationHeaderV02) { walk_business_application_header_v02(self, node) }
+	fn visit_csc_management1_code(&mut self, node: &crate::common::CSCManagement1Code) { walk_csc_management1_code(self, node) }
+	fn visit_cancellation_individual_status1_code(&mut self, node: &crate::common::CancellationIndividualStatus1Code) { walk_cancellation_individual_status1_code(self, node) }
+	fn visit_cancellation_reason33_choice(&mut self, node: &crate::common::CancellationReason33Choice) { walk_cancellation_reason33_choice(self, node) }
+	fn visit_cancellation_status_reason3_choice(&mut self, node: &crate::common::CancellationStatusReason3Choice) { walk_cancellation_status_reason3_choice(self, node) }
+	fn visit_cancellation_status_reason4(&mut self, node: &crate::common::CancellationStatusReason4) { walk_cancellation_status_reason4(self, node) }
+	fn visit_card_aggregated2(&mut self, node: &crate::common::CardAggregated2) { walk_card_aggregated2(self, node) }
+	fn visit_card_data_reading1_code(&mut self, node: &crate::common::CardDataReading1Code) { walk_card_data_reading1_code(self, node) }
+	fn visit_card_entry4(&mut self, node: &crate::common::CardEntry4) { walk_card_entry4(self, node) }
+	fn visit_card_individual_transaction2(&mut self, node: &crate::common::CardIndividualTransaction2) { walk_card_individual_transaction2(self, node) }
+	fn visit_card_payment_service_type2_code(&mut self, node: &crate::common::CardPaymentServiceType2Code) { walk_card_payment_service_type2_code(self, node) }
+	fn visit_card_security_information1(&mut self, node: &crate::common::CardSecurityInformation1) { walk_card_security_information1(self, node) }
+	fn visit_card_sequence_number_range1(&mut self, node: &crate::common::CardSequenceNumberRange1) { walk_card_sequence_number_range1(self, node) }
+	fn visit_card_transaction17(&mut self, node: &crate::common::CardTransaction17) { walk_card_transaction17(self, node) }
+	fn visit_card_transaction3_choice(&mut self, node: &crate::common::CardTransaction3Choice) { walk_card_transaction3_choice(self, node) }
+	fn visit_cardholder_authentication2(&mut self, node: &crate::common::CardholderAuthentication2) { walk_cardholder_authentication2(self, node) }
+	fn visit_cardholder_verification_capability1_code(&mut self, node: &crate::common::CardholderVerificationCapability1Code) { walk_cardholder_verification_capability1_code(self, node) }
+	fn visit_case5(&mut self, node: &crate::common::Case5) { walk_case5(self, node) }
+	fn visit_case_assignment5(&mut self, node: &crate::common::CaseAssignment5) { walk_case_assignment5(self, node) }
+	fn visit_cash_account38(&mut self, node: &crate::common::CashAccount38) { walk_cash_account38(self, node) }
+	fn visit_cash_account39(&mut self, node: &crate::common::CashAccount39) { walk_cash_account39(self, node) }
+	fn visit_cash_account_type2_choice(&mut self, node: &crate::common::CashAccountType2Choice) { walk_cash_account_type2_choice(self, node) }
+	fn visit_cash_availability1(&mut self, node: &crate::common::CashAvailability1) { walk_cash_availability1(self, node) }
+	fn visit_cash_availability_date1_choice(&mut self, node: &crate::common::CashAvailabilityDate1Choice) { walk_cash_availability_date1_choice(self, node) }
+	fn visit_cash_balance8(&mut self, node: &crate::common::CashBalance8) { walk_cash_balance8(self, node) }
+	fn visit_cash_deposit1(&mut self, node: &crate::common::CashDeposit1) { walk_cash_deposit1(self, node) }
+	fn visit_category_purpose1_choice(&mut self, node: &crate::common::CategoryPurpose1Choice) { walk_category_purpose1_choice(self, node) }
+	fn visit_charge_bearer_type1_code(&mut self, node: &crate::common::ChargeBearerType1Code) { walk_charge_bearer_type1_code(self, node) }
+	fn visit_charge_type3_choice(&mut self, node: &crate::common::ChargeType3Choice) { walk_charge_type3_choice(self, node) }
+	fn visit_charges6(&mut self, node: &crate::common::Charges6) { walk_charges6(self, node) }
+	fn visit_charges7(&mut self, node: &crate::common::Charges7) { walk_charges7(self, node) }
+	fn visit_charges_record3(&mut self, node: &crate::common::ChargesRecord3) { walk_charges_record3(self, node) }
+	fn visit_cheque11(&mut self, node: &crate::common::Cheque11) { walk_cheque11(self, node) }
+	fn visit_cheque_delivery1_code(&mut self, node: &crate::common::ChequeDelivery1Code) { walk_cheque_delivery1_code(self, node) }
+	fn visit_cheque_delivery_method1_choice(&mut self, node: &crate::common::ChequeDeliveryMethod1Choice) { walk_cheque_delivery_method1_choice(self, node) }
+	fn visit_cheque_type2_code(&mut self, node: &crate::common::ChequeType2Code) { walk_cheque_type2_code(self, node) }
+	fn visit_claim_non_receipt2(&mut self, node: &crate::common::ClaimNonReceipt2) { walk_claim_non_receipt2(self, node) }
+	fn visit_claim_non_receipt2_choice(&mut self, node: &crate::common::ClaimNonReceipt2Choice) { walk_claim_non_receipt2_choice(self, node) }
+	fn visit_claim_non_receipt_reject_reason1_choice(&mut self, node: &crate::common::ClaimNonReceiptRejectReason1Choice) { walk_claim_non_receipt_reject_reason1_choice(self, node) }
+	fn visit_clearing_channel2_code(&mut self, node: &crate::common::ClearingChannel2Code) { walk_clearing_channel2_code(self, node) }
+	fn visit_clearing_system_identification2_choice(&mut self, node: &crate::common::ClearingSystemIdentification2Choice) { walk_clearing_system_identification2_choice(self, node) }
+	fn visit_clearing_system_identification3_choice(&mut self, node: &crate::common::ClearingSystemIdentification3Choice) { walk_clearing_system_identification3_choice(self, node) }
+	fn visit_clearing_system_member_identification2(&mut self, node: &crate::common::ClearingSystemMemberIdentification2) { walk_clearing_system_member_identification2(self, node) }
+	fn visit_compensation2(&mut self, node: &crate::common::Compensation2) { walk_compensation2(self, node) }
+	fn visit_compensation_reason1_choice(&mut self, node: &crate::common::CompensationReason1Choice) { walk_compensation_reason1_choice(self, node) }
+	fn visit_contact4(&mut self, node: &crate::common::Contact4) { walk_contact4(self, node) }
+	fn visit_control_data1(&mut self, node: &crate::common::ControlData1) { walk_control_data1(self, node) }
+	fn visit_copy_duplicate1_code(&mut self, node: &crate::common::CopyDuplicate1Code) { walk_copy_duplicate1_code(self, node) }
+	fn visit_corporate_action9(&mut self, node: &crate::common::CorporateAction9) { walk_corporate_action9(self, node) }
+	fn visit_corrective_group_information1(&mut self, node: &crate::common::CorrectiveGroupInformation1) { walk_corrective_group_information1(self, node) }
+	fn visit_corrective_interbank_transaction2(&mut self, node: &crate::common::CorrectiveInterbankTransaction2) { walk_corrective_interbank_transaction2(self, node) }
+	fn visit_corrective_payment_initiation4(&mut self, node: &crate::common::CorrectivePaymentInitiation4) { walk_corrective_payment_initiation4(self, node) }
+	fn visit_corrective_transaction4_choice(&mut self, node: &crate::common::CorrectiveTransaction4Choice) { walk_corrective_transaction4_choice(self, node) }
+	fn visit_credit_debit_code(&mut self, node: &crate::common::CreditDebitCode) { walk_credit_debit_code(self, node) }
+	fn visit_credit_line3(&mut self, node: &crate::common::CreditLine3) { walk_credit_line3(self, node) }
+	fn visit_credit_line_type1_choice(&mut self, node: &crate::common::CreditLineType1Choice) { walk_credit_line_type1_choice(self, node) }
+	fn visit_credit_transfer_mandate_data1(&mut self, node: &crate::common::CreditTransferMandateData1) { walk_credit_transfer_mandate_data1(self, node) }
+	fn visit_credit_transfer_transaction35(&mut self, node: &crate::common::CreditTransferTransaction35) { walk_credit_transfer_transaction35(self, node) }
+	fn visit_credit_transfer_transaction36(&mut self, node: &crate::common::CreditTransferTransaction36) { walk_credit_transfer_transaction36(self, node) }
+	fn visit_credit_transfer_transaction37(&mut self, node: &crate::common::CreditTransferTransaction37) { walk_credit_transfer_transaction37(self, node) }
+	fn visit_credit_transfer_transaction39(&mut self, node: &crate::common::CreditTransferTransaction39) { walk_credit_transfer_transaction39(self, node) }
+	fn visit_credit_transfer_transaction45(&mut self, node: &crate::common::CreditTransferTransaction45) { walk_credit_transfer_transaction45(self, node) }
+	fn visit_creditor_reference_information2(&mut self, node: &crate::common::CreditorReferenceInformation2) { walk_creditor_reference_information2(self, node) }
+	fn visit_creditor_reference_type1_choice(&mut self, node: &crate::common::CreditorReferenceType1Choice) { walk_creditor_reference_type1_choice(self, node) }
+	fn visit_creditor_reference_type2(&mut self, node: &crate::common::CreditorReferenceType2) { walk_creditor_reference_type2(self, node) }
+	fn visit_currency_exchange5(&mut self, node: &crate::common::CurrencyExchange5) { walk_currency_exchange5(self, node) }
+	fn visit_date_and_date_time2_choice(&mut self, node: &crate::common::DateAndDateTime2Choice) { walk_date_and_date_time2_choice(self, node) }
+	fn visit_date_and_place_of_birth1(&mut self, node: &crate::common::DateAndPlaceOfBirth1) { walk_date_and_place_of_birth1(self, node) }
+	fn visit_date_or_date_time_period1_choice(&mut self, node: &crate::common::DateOrDateTimePeriod1Choice) { walk_date_or_date_time_period1_choice(self, node) }
+	fn visit_date_period2(&mut self, node: &crate::common::DatePeriod2) { walk_date_period2(self, node) }
+	fn visit_date_period_details1(&mut self, node: &crate::common::DatePeriodDetails1) { walk_date_period_details1(self, node) }
+	fn visit_date_time_period1(&mut self, node: &crate::common::DateTimePeriod1) { walk_date_time_period1(self, node) }
+	fn visit_discount_amount_and_type1(&mut self, node: &crate::common::DiscountAmountAndType1) { walk_discount_amount_and_type1(self, node) }
+	fn visit_discount_amount_type1_choice(&mut self, node: &crate::common::DiscountAmountType1Choice) { walk_discount_amount_type1_choice(self, node) }
+	fn visit_display_capabilities1(&mut self, node: &crate::common::DisplayCapabilities1) { walk_display_capabilities1(self, node) }
+	fn visit_document12(&mut self, node: &crate::common::Document12) { walk_document12(self, node) }
+	fn visit_document_adjustment1(&mut self, node: &crate::common::DocumentAdjustment1) { walk_document_adjustment1(self, node) }
+	fn visit_document_format1_choice(&mut self, node: &crate::common::DocumentFormat1Choice) { walk_document_format1_choice(self, node) }
+	fn visit_document_line_identification1(&mut self, node: &crate::common::DocumentLineIdentification1) { walk_document_line_identification1(self, node) }
+	fn visit_document_line_information1(&mut self, node: &crate::common::DocumentLineInformation1) { walk_document_line_information1(self, node) }
+	fn visit_document_line_type1(&mut self, node: &crate::common::DocumentLineType1) { walk_document_line_type1(self, node) }
+	fn visit_document_line_type1_choice(&mut self, node: &crate::common::DocumentLineType1Choice) { walk_document_line_type1_choice(self, node) }
+	fn visit_document_type1_choice(&mut self, node: &crate::common::DocumentType1Choice) { walk_document_type1_choice(self, node) }
+	fn visit_document_type3_code(&mut self, node: &crate::common::DocumentType3Code) { walk_document_type3_code(self, node) }
+	fn visit_document_type6_code(&mut self, node: &crate::common::DocumentType6Code) { walk_document_type6_code(self, node) }
+	fn visit_entry_details9(&mut self, node: &crate::common::EntryDetails9) { walk_entry_details9(self, node) }
+	fn visit_entry_status1_choice(&mut self, node: &crate::common::EntryStatus1Choice) { walk_entry_status1_choice(self, node) }
+	fn visit_entry_transaction10(&mut self, node: &crate::common::EntryTransaction10) { walk_entry_transaction10(self, node) }
+	fn visit_equivalent_amount2(&mut self, node: &crate::common::EquivalentAmount2) { walk_equivalent_amount2(self, node) }
+	fn visit_event1(&mut self, node: &crate::common::Event1) { walk_event1(self, node) }
+	fn visit_event2(&mut self, node: &crate::common::Event2) { walk_event2(self, node) }
+	fn visit_financial_identification_scheme_name1_choice(&mut self, node: &crate::common::FinancialIdentificationSchemeName1Choice) { walk_financial_identification_scheme_name1_choice(self, node) }
+	fn visit_financial_institution_identification18(&mut self, node: &crate::common::FinancialInstitutionIdentification18) { walk_financial_institution_identification18(self, node) }
+	fn visit_financial_instrument_quantity1_choice(&mut self, node: &crate::common::FinancialInstrumentQuantity1Choice) { walk_financial_instrument_quantity1_choice(self, node) }
+	fn visit_floor_limit_type1_code(&mut self, node: &crate::common::FloorLimitType1Code) { walk_floor_limit_type1_code(self, node) }
+	fn visit_frequency36_choice(&mut self, node: &crate::common::Frequency36Choice) { walk_frequency36_choice(self, node) }
+	fn visit_frequency6_code(&mut self, node: &crate::common::Frequency6Code) { walk_frequency6_code(self, node) }
+	fn visit_frequency_and_moment1(&mut self, node: &crate::common::FrequencyAndMoment1) { walk_frequency_and_moment1(self, node) }
+	fn visit_frequency_period1(&mut self, node: &crate::common::FrequencyPeriod1) { walk_frequency_period1(self, node) }
+	fn visit_from_to_amount_range1(&mut self, node: &crate::common::FromToAmountRange1) { walk_from_to_amount_range1(self, node) }
+	fn visit_garnishment3(&mut self, node: &crate::common::Garnishment3) { walk_garnishment3(self, node) }
+	fn visit_garnishment_type1(&mut self, node: &crate::common::GarnishmentType1) { walk_garnishment_type1(self, node) }
+	fn visit_garnishment_type1_choice(&mut self, node: &crate::common::GarnishmentType1Choice) { walk_garnishment_type1_choice(self, node) }
+	fn visit_generic_account_identification1(&mut self, node: &crate::common::GenericAccountIdentification1) { walk_generic_account_identification1(self, node) }
+	fn visit_generic_financial_identification1(&mut self, node: &crate::common::GenericFinancialIdentification1) { walk_generic_financial_identification1(self, node) }
+	fn visit_generic_identification1(&mut self, node: &crate::common::GenericIdentification1) { walk_generic_identification1(self, node) }
+	fn visit_generic_identification3(&mut self, node: &crate::common::GenericIdentification3) { walk_generic_identification3(self, node) }
+	fn visit_generic_identification30(&mut self, node: &crate::common::GenericIdentification30) { walk_generic_identification30(self, node) }
+	fn visit_generic_identification32(&mut self, node: &crate::common::GenericIdentification32) { walk_generic_identification32(self, node) }
+	fn visit_generic_identification36(&mut self, node: &crate::common::GenericIdentification36) { walk_generic_identification36(self, node) }
+	fn visit_generic_organisation_identification1(&mut self, node: &crate::common::GenericOrganisationIdentification1) { walk_generic_organisation_identification1(self, node) }
+	fn visit_generic_person_identification1(&mut self, node: &crate::common::GenericPersonIdentification1) { walk_generic_person_identification1(self, node) }
+	fn visit_group_cancellation_status1_code(&mut self, node: &crate::common::GroupCancellationStatus1Code) { walk_group_cancellation_status1_code(self, node) }
+	fn visit_group_header77(&mut self, node: &crate::common::GroupHeader77) { walk_group_header77(self, node) }
+	fn visit_group_header78(&mut self, node: &crate::common::GroupHeader78) { walk_group_header78(self, node) }
+	fn visit_group_header81(&mut self, node: &crate::common::GroupHeader81) { walk_group_header81(self, node) }
+	fn visit_group_header87(&mut self, node: &crate::common::GroupHeader87) { walk_group_header87(self, node) }
+	fn visit_group_header90(&mut self, node: &crate::common::GroupHeader90) { walk_group_header90(self, node) }
+	fn visit_group_header91(&mut self, node: &crate::common::GroupHeader91) { walk_group_header91(self, node) }
+	fn visit_group_header93(&mut self, node: &crate::common::GroupHeader93) { walk_group_header93(self, node) }
+	fn visit_identification_source3_choice(&mut self, node: &crate::common::IdentificationSource3Choice) { walk_identification_source3_choice(self, node) }
+	fn visit_implementation_specification1(&mut self, node: &crate::common::ImplementationSpecification1) { walk_implementation_specification1(self, node) }
+	fn visit_implied_currency_amount_range1_choice(&mut self, node: &crate::common::ImpliedCurrencyAmountRange1Choice) { walk_implied_currency_amount_range1_choice(self, node) }
+	fn visit_instruction3_code(&mut self, node: &crate::common::Instruction3Code) { walk_instruction3_code(self, node) }
+	fn visit_instruction4_code(&mut self, node: &crate::common::Instruction4Code) { walk_instruction4_code(self, node) }
+	fn visit_instruction5_code(&mut self, node: &crate::common::Instruction5Code) { walk_instruction5_code(self, node) }
+	fn visit_instruction_for_creditor_agent1(&mut self, node: &crate::common::InstructionForCreditorAgent1) { walk_instruction_for_creditor_agent1(self, node) }
+	fn visit_instruction_for_creditor_agent2(&mut self, node: &crate::common::InstructionForCreditorAgent2) { walk_instruction_for_creditor_agent2(self, node) }
+	fn visit_instruction_for_creditor_agent3(&mut self, node: &crate::common::InstructionForCreditorAgent3) { walk_instruction_for_creditor_agent3(self, node) }
+	fn visit_instruction_for_next_agent1(&mut self, node: &crate::common::InstructionForNextAgent1) { walk_instruction_for_next_agent1(self, node) }
+	fn visit_interest_record2(&mut self, node: &crate::common::InterestRecord2) { walk_interest_record2(self, node) }
+	fn visit_interest_type1_choice(&mut self, node: &crate::common::InterestType1Choice) { walk_interest_type1_choice(self, node) }
+	fn visit_interest_type1_code(&mut self, node: &crate::common::InterestType1Code) { walk_interest_type1_code(self, node) }
+	fn visit_investigation_status5_choice(&mut self, node: &crate::common::InvestigationStatus5Choice) { walk_investigation_status5_choice(self, node) }
+	fn visit_limit2(&mut self, node: &crate::common::Limit2) { walk_limit2(self, node) }
+	fn visit_local_instrument2_choice(&mut self, node: &crate::common::LocalInstrument2Choice) { walk_local_instrument2_choice(self, node) }
+	fn visit_mandate_classification1_choice(&mut self, node: &crate::common::MandateClassification1Choice) { walk_mandate_classification1_choice(self, node) }
+	fn visit_mandate_classification1_code(&mut self, node: &crate::common::MandateClassification1Code) { walk_mandate_classification1_code(self, node) }
+	fn visit_mandate_related_data1_choice(&mut self, node: &crate::common::MandateRelatedData1Choice) { walk_mandate_related_data1_choice(self, node) }
+	fn visit_mandate_related_information14(&mut self, node: &crate::common::MandateRelatedInformation14) { walk_mandate_related_information14(self, node) }
+	fn visit_mandate_setup_reason1_choice(&mut self, node: &crate::common::MandateSetupReason1Choice) { walk_mandate_setup_reason1_choice(self, node) }
+	fn visit_mandate_type_information2(&mut self, node: &crate::common::MandateTypeInformation2) { walk_mandate_type_information2(self, node) }
+	fn visit_message_header10(&mut self, node: &crate::common::MessageHeader10) { walk_message_header10(self, node) }
+	fn visit_message_header7(&mut self, node: &crate::common::MessageHeader7) { walk_message_header7(self, node) }
+	fn visit_message_identification2(&mut self, node: &crate::common::MessageIdentification2) { walk_message_identification2(self, node) }
+	fn visit_message_reference(&mut self, node: &crate::common::MessageReference) { walk_message_reference(self, node) }
+	fn visit_message_reference1(&mut self, node: &crate::common::MessageReference1) { walk_message_reference1(self, node) }
+	fn visit_missing_or_incorrect_information3(&mut self, node: &crate::common::MissingOrIncorrectInformation3) { walk_missing_or_incorrect_information3(self, node) }
+	fn visit_modification_status_reason1_choice(&mut self, node: &crate::common::ModificationStatusReason1Choice) { walk_modification_status_reason1_choice(self, node) }
+	fn visit_modification_status_reason2(&mut self, node: &crate::common::ModificationStatusReason2) { walk_modification_status_reason2(self, node) }
+	fn visit_name_and_address16(&mut self, node: &crate::common::NameAndAddress16) { walk_name_and_address16(self, node) }
+	fn visit_name_and_address5(&mut self, node: &crate::common::NameAndAddress5) { walk_name_and_address5(self, node) }
+	fn visit_name_prefix2_code(&mut self, node: &crate::common::NamePrefix2Code) { walk_name_prefix2_code(self, node) }
+	fn visit_number_and_sum_of_transactions1(&mut self, node: &crate::common::NumberAndSumOfTransactions1) { walk_number_and_sum_of_transactions1(self, node) }
+	fn visit_number_and_sum_of_transactions4(&mut self, node: &crate::common::NumberAndSumOfTransactions4) { walk_number_and_sum_of_transactions4(self, node) }
+	fn visit_number_of_cancellations_per_status1(&mut self, node: &crate::common::NumberOfCancellationsPerStatus1) { walk_number_of_cancellations_per_status1(self, node) }
+	fn visit_number_of_transactions_per_status1(&mut self, node: &crate::common::NumberOfTransactionsPerStatus1) { walk_number_of_transactions_per_status1(self, node) }
+	fn visit_number_of_transactions_per_status5(&mut self, node: &crate::common::NumberOfTransactionsPerStatus5) { walk_number_of_transactions_per_status5(self, node) }
+	fn visit_on_line_capability1_code(&mut self, node: &crate::common::OnLineCapability1Code) { walk_on_line_capability1_code(self, node) }
+	fn visit_organisation_identification29(&mut self, node: &crate::common::OrganisationIdentification29) { walk_organisation_identification29(self, node) }
+	fn visit_organisation_identification_scheme_name1_choice(&mut self, node: &crate::common::OrganisationIdentificationSchemeName1Choice) { walk_organisation_identification_scheme_name1_choice(self, node) }
+	fn visit_original_and_current_quantities1(&mut self, node: &crate::common::OriginalAndCurrentQuantities1) { walk_original_and_current_quantities1(self, node) }
+	fn visit_original_business_query1(&mut self, node: &crate::common::OriginalBusinessQuery1) { walk_original_business_query1(self, node) }
+	fn visit_original_group_header14(&mut self, node: &crate::common::OriginalGroupHeader14) { walk_original_group_header14(self, node) }
+	fn visit_original_group_header15(&mut self, node: &crate::common::OriginalGroupHeader15) { walk_original_group_header15(self, node) }
+	fn visit_original_group_header17(&mut self, node: &crate::common::OriginalGroupHeader17) { walk_original_group_header17(self, node) }
+	fn visit_original_group_header18(&mut self, node: &crate::common::OriginalGroupHeader18) { walk_original_group_header18(self, node) }
+	fn visit_original_group_information27(&mut self, node: &crate::common::OriginalGroupInformation27) { walk_original_group_information27(self, node) }
+	fn visit_original_group_information29(&mut self, node: &crate::common::OriginalGroupInformation29) { walk_original_group_information29(self, node) }
+	fn visit_original_group_information30(&mut self, node: &crate::common::OriginalGroupInformation30) { walk_original_group_information30(self, node) }
+	fn visit_original_payment_instruction30(&mut self, node: &crate::common::OriginalPaymentInstruction30) { walk_original_payment_instruction30(self, node) }
+	fn visit_original_payment_instruction31(&mut self, node: &crate::common::OriginalPaymentInstruction31) { walk_original_payment_instruction31(self, node) }
+	fn visit_original_payment_instruction36(&mut self, node: &crate::common::OriginalPaymentInstruction36) { walk_original_payment_instruction36(self, node) }
+	fn visit_original_transaction_reference28(&mut self, node: &crate::common::OriginalTransactionReference28) { walk_original_transaction_reference28(self, node) }
+	fn visit_original_transaction_reference29(&mut self, node: &crate::common::OriginalTransactionReference29) { walk_original_transaction_reference29(self, node) }
+	fn visit_original_transaction_reference31(&mut self, node: &crate::common::OriginalTransactionReference31) { walk_original_transaction_reference31(self, node) }
+	fn visit_original_transaction_reference32(&mut self, node: &crate::common::OriginalTransactionReference32) { walk_original_transaction_reference32(self, node) }
+	fn visit_other_contact1(&mut self, node: &crate::common::OtherContact1) { walk_other_contact1(self, node) }
+	fn visit_other_identification1(&mut self, node: &crate::common::OtherIdentification1) { walk_other_identification1(self, node) }
+	fn visit_poi_component_type1_code(&mut self, node: &crate::common::POIComponentType1Code) { walk_poi_component_type1_code(self, node) }
+	fn visit_pagination1(&mut self, node: &crate::common::Pagination1) { walk_pagination1(self, node) }
+	fn visit_party38_choice(&mut self, node: &crate::common::Party38Choice) { walk_party38_choice(self, node) }
+	fn visit_party40_choice(&mut self, node: &crate::common::Party40Choice) { walk_party40_choice(self, node) }
+	fn visit_party44_choice(&mut self, node: &crate::common::Party44Choice) { walk_party44_choice(self, node) }
+	fn visit_party_and_signature3(&mut self, node: &crate::common::PartyAndSignature3) { walk_party_and_signature3(self, node) }
+	fn visit_party_identification120_choice(&mut self, node: &crate::common::PartyIdentification120Choice) { walk_party_identification120_choice(self, node) }
+	fn visit_party_identification135(&mut self, node: &crate::common::PartyIdentification135) { walk_party_identification135(self, node) }
+	fn visit_party_identification136(&mut self, node: &crate::common::PartyIdentification136) { walk_party_identification136(self, node) }
+	fn visit_party_type3_code(&mut self, node: &crate::common::PartyType3Code) { walk_party_type3_code(self, node) }
+	fn visit_party_type4_code(&mut self, node: &crate::common::PartyType4Code) { walk_party_type4_code(self, node) }
+	fn visit_payment_cancellation_reason5(&mut self, node: &crate::common::PaymentCancellationReason5) { walk_payment_cancellation_reason5(self, node) }
+	fn visit_payment_card4(&mut self, node: &crate::common::PaymentCard4) { walk_payment_card4(self, node) }
+	fn visit_payment_complementary_information8(&mut self, node: &crate::common::PaymentComplementaryInformation8) { walk_payment_complementary_information8(self, node) }
+	fn visit_payment_condition1(&mut self, node: &crate::common::PaymentCondition1) { walk_payment_condition1(self, node) }
+	fn visit_payment_condition_status1(&mut self, node: &crate::common::PaymentConditionStatus1) { walk_payment_condition_status1(self, node) }
+	fn visit_payment_context3(&mut self, node: &crate::common::PaymentContext3) { walk_payment_context3(self, node) }
+	fn visit_payment_identification6(&mut self, node: &crate::common::PaymentIdentification6) { walk_payment_identification6(self, node) }
+	fn visit_payment_identification7(&mut self, node: &crate::common::PaymentIdentification7) { walk_payment_identification7(self, node) }
+	fn visit_payment_instruction31(&mut self, node: &crate::common::PaymentInstruction31) { walk_payment_instruction31(self, node) }
+	fn visit_payment_method4_code(&mut self, node: &crate::common::PaymentMethod4Code) { walk_payment_method4_code(self, node) }
+	fn visit_payment_method7_code(&mut self, node: &crate::common::PaymentMethod7Code) { walk_payment_method7_code(self, node) }
+	fn visit_payment_return_reason5(&mut self, node: &crate::common::PaymentReturnReason5) { walk_payment_return_reason5(self, node) }
+	fn visit_payment_return_reason6(&mut self, node: &crate::common::PaymentReturnReason6) { walk_payment_return_reason6(self, node) }
+	fn visit_payment_transaction102(&mut self, node: &crate::common::PaymentTransaction102) { walk_payment_transaction102(self, node) }
+	fn visit_payment_transaction103(&mut self, node: &crate::common::PaymentTransaction103) { walk_payment_transaction103(self, node) }
+	fn visit_payment_transaction104(&mut self, node: &crate::common::PaymentTransaction104) { walk_payment_transaction104(self, node) }
+	fn visit_payment_transaction106(&mut self, node: &crate::common::PaymentTransaction106) { walk_payment_transaction106(self, node) }
+	fn visit_payment_transaction107(&mut self, node: &crate::common::PaymentTransaction107) { walk_payment_transaction107(self, node) }
+	fn visit_payment_transaction110(&mut self, node: &crate::common::PaymentTransaction110) { walk_payment_transaction110(self, node) }
+	fn visit_payment_transaction113(&mut self, node: &crate::common::PaymentTransaction113) { walk_payment_transaction113(self, node) }
+	fn visit_payment_transaction118(&mut self, node: &crate::common::PaymentTransaction118) { walk_payment_transaction118(self, node) }
+	fn visit_payment_transaction124(&mut self, node: &crate::common::PaymentTransaction124) { walk_payment_transaction124(self, node) }
+	fn visit_payment_type_information26(&mut self, node: &crate::common::PaymentTypeInformation26) { walk_payment_type_information26(self, node) }
+	fn visit_payment_type_information27(&mut self, node: &crate::common::PaymentTypeInformation27) { walk_payment_type_information27(self, node) }
+	fn visit_payment_type_information28(&mut self, node: &crate::common::PaymentTypeInformation28) { walk_payment_type_information28(self, node) }
+	fn visit_person_identification13(&mut self, node: &crate::common::PersonIdentification13) { walk_person_identification13(self, node) }
+	fn visit_person_identification_scheme_name1_choice(&mut self, node: &crate::common::PersonIdentificationSchemeName1Choice) { walk_person_identification_scheme_name1_choice(self, node) }
+	fn visit_plain_card_data1(&mut self, node: &crate::common::PlainCardData1) { walk_plain_card_data1(self, node) }
+	fn visit_point_of_interaction1(&mut self, node: &crate::common::PointOfInteraction1) { walk_point_of_interaction1(self, node) }
+	fn visit_point_of_interaction_capabilities1(&mut self, node: &crate::common::PointOfInteractionCapabilities1) { walk_point_of_interaction_capabilities1(self, node) }
+	fn visit_point_of_interaction_component1(&mut self, node: &crate::common::PointOfInteractionComponent1) { walk_point_of_interaction_component1(self, node) }
+	fn visit_postal_address1(&mut self, node: &crate::common::PostalAddress1) { walk_postal_address1(self, node) }
+	fn visit_postal_address24(&mut self, node: &crate::common::PostalAddress24) { walk_postal_address24(self, node) }
+	fn visit_preferred_contact_method1_code(&mut self, node: &crate::common::PreferredContactMethod1Code) { walk_preferred_contact_method1_code(self, node) }
+	fn visit_price7(&mut self, node: &crate::common::Price7) { walk_price7(self, node) }
+	fn visit_price_rate_or_amount3_choice(&mut self, node: &crate::common::PriceRateOrAmount3Choice) { walk_price_rate_or_amount3_choice(self, node) }
+	fn visit_price_value_type1_code(&mut self, node: &crate::common::PriceValueType1Code) { walk_price_value_type1_code(self, node) }
+	fn visit_priority2_code(&mut self, node: &crate::common::Priority2Code) { walk_priority2_code(self, node) }
+	fn visit_priority3_code(&mut self, node: &crate::common::Priority3Code) { walk_priority3_code(self, node) }
+	fn visit_product2(&mut self, node: &crate::common::Product2) { walk_product2(self, node) }
+	fn visit_proprietary_agent4(&mut self, node: &crate::common::ProprietaryAgent4) { walk_proprietary_agent4(self, node) }
+	fn visit_proprietary_bank_transaction_code_structure1(&mut self, node: &crate::common::ProprietaryBankTransactionCodeStructure1) { walk_proprietary_bank_transaction_code_structure1(self, node) }
+	fn visit_proprietary_data5(&mut self, node: &crate::common::ProprietaryData5) { walk_proprietary_data5(self, node) }
+	fn visit_proprietary_date3(&mut self, node: &crate::common::ProprietaryDate3) { walk_proprietary_date3(self, node) }
+	fn visit_proprietary_party5(&mut self, node: &crate::common::ProprietaryParty5) { walk_proprietary_party5(self, node) }
+	fn visit_proprietary_price2(&mut self, node: &crate::common::ProprietaryPrice2) { walk_proprietary_price2(self, node) }
+	fn visit_proprietary_quantity1(&mut self, node: &crate::common::ProprietaryQuantity1) { walk_proprietary_quantity1(self, node) }
+	fn visit_proprietary_reference1(&mut self, node: &crate::common::ProprietaryReference1) { walk_proprietary_reference1(self, node) }
+	fn visit_proxy_account_identification1(&mut self, node: &crate::common::ProxyAccountIdentification1) { walk_proxy_account_identification1(self, node) }
+	fn visit_proxy_account_type1_choice(&mut self, node: &crate::common::ProxyAccountType1Choice) { walk_proxy_account_type1_choice(self, node) }
+	fn visit_purpose2_choice(&mut self, node: &crate::common::Purpose2Choice) { walk_purpose2_choice(self, node) }
+	fn visit_query_type3_code(&mut self, node: &crate::common::QueryType3Code) { walk_query_type3_code(self, node) }
+	fn visit_rate4(&mut self, node: &crate::common::Rate4) { walk_rate4(self, node) }
+	fn visit_rate_type4_choice(&mut self, node: &crate::common::RateType4Choice) { walk_rate_type4_choice(self, node) }
+	fn visit_receipt_acknowledgement_report2(&mut self, node: &crate::common::ReceiptAcknowledgementReport2) { walk_receipt_acknowledgement_report2(self, node) }
+	fn visit_referred_document_information7(&mut self, node: &crate::common::ReferredDocumentInformation7) { walk_referred_document_information7(self, node) }
+	fn visit_referred_document_type3_choice(&mut self, node: &crate::common::ReferredDocumentType3Choice) { walk_referred_document_type3_choice(self, node) }
+	fn visit_referred_document_type4(&mut self, node: &crate::common::ReferredDocumentType4) { walk_referred_document_type4(self, node) }
+	fn visit_regulatory_authority2(&mut self, node: &crate::common::RegulatoryAuthority2) { walk_regulatory_authority2(self, node) }
+	fn visit_regulatory_reporting3(&mut self, node: &crate::common::RegulatoryReporting3) { walk_regulatory_reporting3(self, node) }
+	fn visit_regulatory_reporting_type1_code(&mut self, node: &crate::common::RegulatoryReportingType1Code) { walk_regulatory_reporting_type1_code(self, node) }
+	fn visit_rejection_reason2(&mut self, node: &crate::common::RejectionReason2) { walk_rejection_reason2(self, node) }
+	fn visit_remittance_amount2(&mut self, node: &crate::common::RemittanceAmount2) { walk_remittance_amount2(self, node) }
+	fn visit_remittance_amount3(&mut self, node: &crate::common::RemittanceAmount3) { walk_remittance_amount3(self, node) }
+	fn visit_remittance_information16(&mut self, node: &crate::common::RemittanceInformation16) { walk_remittance_information16(self, node) }
+	fn visit_remittance_information2(&mut self, node: &crate::common::RemittanceInformation2) { walk_remittance_information2(self, node) }
+	fn visit_remittance_location7(&mut self, node: &crate::common::RemittanceLocation7) { walk_remittance_location7(self, node) }
+	fn visit_remittance_location_data1(&mut self, node: &crate::common::RemittanceLocationData1) { walk_remittance_location_data1(self, node) }
+	fn visit_remittance_location_method2_code(&mut self, node: &crate::common::RemittanceLocationMethod2Code) { walk_remittance_location_method2_code(self, node) }
+	fn visit_report_entry10(&mut self, node: &crate::common::ReportEntry10) { walk_report_entry10(self, node) }
+	fn visit_reporting_period2(&mut self, node: &crate::common::ReportingPeriod2) { walk_reporting_period2(self, node) }
+	fn visit_reporting_request5(&mut self, node: &crate::common::ReportingRequest5) { walk_reporting_request5(self, node) }
+	fn visit_reporting_source1_choice(&mut self, node: &crate::common::ReportingSource1Choice) { walk_reporting_source1_choice(self, node) }
+	fn visit_request_handling2(&mut self, node: &crate::common::RequestHandling2) { walk_request_handling2(self, node) }
+	fn visit_request_type4_choice(&mut self, node: &crate::common::RequestType4Choice) { walk_request_type4_choice(self, node) }
+	fn visit_resend_search_criteria2(&mut self, node: &crate::common::ResendSearchCriteria2) { walk_resend_search_criteria2(self, node) }
+	fn visit_resolution_data1(&mut self, node: &crate::common::ResolutionData1) { walk_resolution_data1(self, node) }
+	fn visit_return_reason5_choice(&mut self, node: &crate::common::ReturnReason5Choice) { walk_return_reason5_choice(self, node) }
+	fn visit_securities_account19(&mut self, node: &crate::common::SecuritiesAccount19) { walk_securities_account19(self, node) }
+	fn visit_security_identification19(&mut self, node: &crate::common::SecurityIdentification19) { walk_security_identification19(self, node) }
+	fn visit_sequence_range1(&mut self, node: &crate::common::SequenceRange1) { walk_sequence_range1(self, node) }
+	fn visit_sequence_range1_choice(&mut self, node: &crate::common::SequenceRange1Choice) { walk_sequence_range1_choice(self, node) }
+	fn visit_sequence_type3_code(&mut self, node: &crate::common::SequenceType3Code) { walk_sequence_type3_code(self, node) }
+	fn visit_service_level8_choice(&mut self, node: &crate::common::ServiceLevel8Choice) { walk_service_level8_choice(self, node) }
+	fn visit_settlement_date_time_indication1(&mut self, node: &crate::common::SettlementDateTimeIndication1) { walk_settlement_date_time_indication1(self, node) }
+	fn visit_settlement_instruction7(&mut self, node: &crate::common::SettlementInstruction7) { walk_settlement_instruction7(self, node) }
+	fn visit_settlement_method1_code(&mut self, node: &crate::common::SettlementMethod1Code) { walk_settlement_method1_code(self, node) }
+	fn visit_settlement_time_request2(&mut self, node: &crate::common::SettlementTimeRequest2) { walk_settlement_time_request2(self, node) }
+	fn visit_signature_envelope(&mut self, node: &crate::common::SignatureEnvelope) { walk_signature_envelope(self, node) }
+	fn visit_skip_payload(&mut self, node: &crate::common::SkipPayload) { walk_skip_payload(self, node) }
+	fn visit_statement_resolution_entry4(&mut self, node: &crate::common::StatementResolutionEntry4) { walk_statement_resolution_entry4(self, node) }
+	fn visit_status_reason6_choice(&mut self, node: &crate::common::StatusReason6Choice) { walk_status_reason6_choice(self, node) }
+	fn visit_status_reason_information12(&mut self, node: &crate::common::StatusReasonInformation12) { walk_status_reason_information12(self, node) }
+	fn visit_structured_regulatory_reporting3(&mut self, node: &crate::common::StructuredRegulatoryReporting3) { walk_structured_regulatory_reporting3(self, node) }
+	fn visit_structured_remittance_information16(&mut self, node: &crate::common::StructuredRemittanceInformation16) { walk_structured_remittance_information16(self, node) }
+	fn visit_supplementary_data1(&mut self, node: &crate::common::SupplementaryData1) { walk_supplementary_data1(self, node) }
+	fn visit_supplementary_data_envelope1(&mut self, node: &crate::common::SupplementaryDataEnvelope1) { walk_supplementary_data_envelope1(self, node) }
+	fn visit_tax_amount2(&mut self, node: &crate::common::TaxAmount2) { walk_tax_amount2(self, node) }
+	fn visit_tax_amount_and_type1(&mut self, node: &crate::common::TaxAmountAndType1) { walk_tax_amount_and_type1(self, node) }
+	fn visit_tax_amount_type1_choice(&mut self, node: &crate::common::TaxAmountType1Choice) { walk_tax_amount_type1_choice(self, node) }
+	fn visit_tax_authorisation1(&mut self, node: &crate::common::TaxAuthorisation1) { walk_tax_authorisation1(self, node) }
+	fn visit_tax_charges2(&mut self, node: &crate::common::TaxCharges2) { walk_tax_charges2(self, node) }
+	fn visit_tax_information7(&mut self, node: &crate::common::TaxInformation7) { walk_tax_information7(self, node) }
+	fn visit_tax_information8(&mut self, node: &crate::common::TaxInformation8) { walk_tax_information8(self, node) }
+	fn visit_tax_party1(&mut self, node: &crate::common::TaxParty1) { walk_tax_party1(self, node) }
+	fn visit_tax_party2(&mut self, node: &crate::common::TaxParty2) { walk_tax_party2(self, node) }
+	fn visit_tax_period2(&mut self, node: &crate::common::TaxPeriod2) { walk_tax_period2(self, node) }
+	fn visit_tax_record2(&mut self, node: &crate::common::TaxRecord2) { walk_tax_record2(self, node) }
+	fn visit_tax_record_details2(&mut self, node: &crate::common::TaxRecordDetails2) { walk_tax_record_details2(self, node) }
+	fn visit_tax_record_period1_code(&mut self, node: &crate::common::TaxRecordPeriod1Code) { walk_tax_record_period1_code(self, node) }
+	fn visit_technical_input_channel1_choice(&mut self, node: &crate::common::TechnicalInputChannel1Choice) { walk_technical_input_channel1_choice(self, node) }
+	fn visit_time_period_details1(&mut self, node: &crate::common::TimePeriodDetails1) { walk_time_period_details1(self, node) }
+	fn visit_total_transactions6(&mut self, node: &crate::common::TotalTransactions6) { walk_total_transactions6(self, node) }
+	fn visit_totals_per_bank_transaction_code5(&mut self, node: &crate::common::TotalsPerBankTransactionCode5) { walk_totals_per_bank_transaction_code5(self, node) }
+	fn visit_track_data1(&mut self, node: &crate::common::TrackData1) { walk_track_data1(self, node) }
+	fn visit_transaction_agents5(&mut self, node: &crate::common::TransactionAgents5) { walk_transaction_agents5(self, node) }
+	fn visit_transaction_channel1_code(&mut self, node: &crate::common::TransactionChannel1Code) { walk_transaction_channel1_code(self, node) }
+	fn visit_transaction_dates3(&mut self, node: &crate::common::TransactionDates3) { walk_transaction_dates3(self, node) }
+	fn visit_transaction_environment1_code(&mut self, node: &crate::common::TransactionEnvironment1Code) { walk_transaction_environment1_code(self, node) }
+	fn visit_transaction_identifier1(&mut self, node: &crate::common::TransactionIdentifier1) { walk_transaction_identifier1(self, node) }
+	fn visit_transaction_individual_status1_code(&mut self, node: &crate::common::TransactionIndividualStatus1Code) { walk_transaction_individual_status1_code(self, node) }
+	fn visit_transaction_interest4(&mut self, node: &crate::common::TransactionInterest4) { walk_transaction_interest4(self, node) }
+	fn visit_transaction_parties6(&mut self, node: &crate::common::TransactionParties6) { walk_transaction_parties6(self, node) }
+	fn visit_transaction_parties8(&mut self, node: &crate::common::TransactionParties8) { walk_transaction_parties8(self, node) }
+	fn visit_transaction_price4_choice(&mut self, node: &crate::common::TransactionPrice4Choice) { walk_transaction_price4_choice(self, node) }
+	fn visit_transaction_quantities3_choice(&mut self, node: &crate::common::TransactionQuantities3Choice) { walk_transaction_quantities3_choice(self, node) }
+	fn visit_transaction_references6(&mut self, node: &crate::common::TransactionReferences6) { walk_transaction_references6(self, node) }
+	fn visit_transaction_type2(&mut self, node: &crate::common::TransactionType2) { walk_transaction_type2(self, node) }
+	fn visit_unable_to_apply_incorrect1(&mut self, node: &crate::common::UnableToApplyIncorrect1) { walk_unable_to_apply_incorrect1(self, node) }
+	fn visit_unable_to_apply_incorrect_information4_code(&mut self, node: &crate::common::UnableToApplyIncorrectInformation4Code) { walk_unable_to_apply_incorrect_information4_code(self, node) }
+	fn visit_unable_to_apply_justification3_choice(&mut self, node: &crate::common::UnableToApplyJustification3Choice) { walk_unable_to_apply_justification3_choice(self, node) }
+	fn visit_unable_to_apply_missing1(&mut self, node: &crate::common::UnableToApplyMissing1) { walk_unable_to_apply_missing1(self, node) }
+	fn visit_unable_to_apply_missing_information3_code(&mut self, node: &crate::common::UnableToApplyMissingInformation3Code) { walk_unable_to_apply_missing_information3_code(self, node) }
+	fn visit_underlying_group_information1(&mut self, node: &crate::common::UnderlyingGroupInformation1) { walk_underlying_group_information1(self, node) }
+	fn visit_underlying_payment_instruction5(&mut self, node: &crate::common::UnderlyingPaymentInstruction5) { walk_underlying_payment_instruction5(self, node) }
+	fn visit_underlying_payment_transaction4(&mut self, node: &crate::common::UnderlyingPaymentTransaction4) { walk_underlying_payment_transaction4(self, node) }
+	fn visit_underlying_statement_entry3(&mut self, node: &crate::common::UnderlyingStatementEntry3) { walk_underlying_statement_entry3(self, node) }
+	fn visit_underlying_transaction22(&mut self, node: &crate::common::UnderlyingTransaction22) { walk_underlying_transaction22(self, node) }
+	fn visit_underlying_transaction23(&mut self, node: &crate::common::UnderlyingTransaction23) { walk_underlying_transaction23(self, node) }
+	fn visit_underlying_transaction27(&mut self, node: &crate::common::UnderlyingTransaction27) { walk_underlying_transaction27(self, node) }
+	fn visit_underlying_transaction5_choice(&mut self, node: &crate::common::UnderlyingTransaction5Choice) { walk_underlying_transaction5_choice(self, node) }
+	fn visit_unit_of_measure1_code(&mut self, node: &crate::common::UnitOfMeasure1Code) { walk_unit_of_measure1_code(self, node) }
+	fn visit_user_interface2_code(&mut self, node: &crate::common::UserInterface2Code) { walk_user_interface2_code(self, node) }
+	fn visit_yielded_or_value_type1_choice(&mut self, node: &crate::common::YieldedOrValueType1Choice) { walk_yielded_or_value_type1_choice(self, node) }
+	fn visit_fed_now_message_signature_key(&mut self, node: &crate::common::FedNowMessageSignatureKey) { walk_fed_now_message_signature_key(self, node) }
+	fn visit_fed_now_message_signature_key_status(&mut self, node: &crate::common::FedNowMessageSignatureKeyStatus) { walk_fed_now_message_signature_key_status(self, node) }
+	fn visit_fed_now_public_key_response(&mut self, node: &crate::common::FedNowPublicKeyResponse) { walk_fed_now_public_key_response(self, node) }
+	fn visit_key_addition(&mut self, node: &crate::common::KeyAddition) { walk_key_addition(self, node) }
+	fn visit_key_revocation(&mut self, node: &crate::fednow_extra::key_exchange::KeyRevocation) { walk_key_revocation(self, node) }
+	fn visit_fed_now_message_signature_key_exchange(&mut self, node: &crate::fednow_extra::key_exchange::FedNowMessageSignatureKeyExchange) { walk_fed_now_message_signature_key_exchange(self, node) }
+	fn visit_fed_now_customer_message_signature_key_operation_response(&mut self, node: &crate::fednow_extra::key_exchange::FedNowCustomerMessageSignatureKeyOperationResponse) { walk_fed_now_customer_message_signature_key_operation_response(self, node) }
+	fn visit_get_all_fed_now_active_public_keys(&mut self, node: &crate::fednow_extra::key_exchange::GetAllFedNowActivePublicKeys) { walk_get_all_fed_now_active_public_keys(self, node) }
+	fn visit_get_all_customer_public_keys(&mut self, node: &crate::fednow_extra::key_exchange::GetAllCustomerPublicKeys) { walk_get_all_customer_public_keys(self, node) }
+	fn visit_fed_now_public_key_responses(&mut self, node: &crate::fednow_extra::key_exchange::FedNowPublicKeyResponses) { walk_fed_now_public_key_responses(self, node) }
+	fn visit_fed_now_incoming(&mut self, node: &crate::fednow_incoming_external::FedNowIncoming) { walk_fed_now_incoming(self, node) }
+	fn visit_fed_now_outgoing(&mut self, node: &crate::fednow_outgoing_external::FedNowOutgoing) { walk_fed_now_outgoing(self, node) }
+	fn visit_admi00200101(&mut self, node: &crate::iso::admi_002_001_01::Admi00200101) { walk_admi00200101(self, node) }
+	fn visit_system_event_notification_v02(&mut self, node: &crate::iso::admi_004_001_02::SystemEventNotificationV02) { walk_system_event_notification_v02(self, node) }
+	fn visit_resend_request_v01(&mut self, node: &crate::iso::admi_006_001_01::ResendRequestV01) { walk_resend_request_v01(self, node) }
+	fn visit_receipt_acknowledgement_v01(&mut self, node: &crate::iso::admi_007_001_01::ReceiptAcknowledgementV01) { walk_receipt_acknowledgement_v01(self, node) }
+	fn visit_system_event_acknowledgement_v01(&mut self, node: &crate::iso::admi_011_001_01::SystemEventAcknowledgementV01) { walk_system_event_acknowledgement_v01(self, node) }
+	fn visit_administration_proprietary_message_v02(&mut self, node: &crate::iso::admi_998_001_02::AdministrationProprietaryMessageV02) { walk_administration_proprietary_message_v02(self, node) }
+	fn visit_unable_to_apply_v07(&mut self, node: &crate::iso::camt_026_001_07::UnableToApplyV07) { walk_unable_to_apply_v07(self, node) }
+	fn visit_additional_payment_information_v09(&mut self, node: &crate::iso::camt_028_001_09::AdditionalPaymentInformationV09) { walk_additional_payment_information_v09(self, node) }
+	fn visit_resolution_of_investigation_v09(&mut self, node: &crate::iso::camt_029_001_09::ResolutionOfInvestigationV09) { walk_resolution_of_investigation_v09(self, node) }
+	fn visit_bank_to_customer_account_report_v08(&mut self, node: &crate::iso::camt_052_001_08::BankToCustomerAccountReportV08) { walk_bank_to_customer_account_report_v08(self, node) }
+	fn visit_bank_to_customer_debit_credit_notification_v08(&mut self, node: &crate::iso::camt_054_001_08::BankToCustomerDebitCreditNotificationV08) { walk_bank_to_customer_debit_credit_notification_v08(self, node) }
+	fn visit_customer_payment_cancellation_request_v09(&mut self, node: &crate::iso::camt_055_001_09::CustomerPaymentCancellationRequestV09) { walk_customer_payment_cancellation_request_v09(self, node) }
+	fn visit_fi_to_fi_payment_cancellation_request_v08(&mut self, node: &crate::iso::camt_056_001_08::FIToFIPaymentCancellationRequestV08) { walk_fi_to_fi_payment_cancellation_request_v08(self, node) }
+	fn visit_account_reporting_request_v05(&mut self, node: &crate::iso::camt_060_001_05::AccountReportingRequestV05) { walk_account_reporting_request_v05(self, node) }
+	fn visit_fi_to_fi_payment_status_report_v10(&mut self, node: &crate::iso::pacs_002_001_10::FIToFIPaymentStatusReportV10) { walk_fi_to_fi_payment_status_report_v10(self, node) }
+	fn visit_payment_return_v10(&mut self, node: &crate::iso::pacs_004_001_10::PaymentReturnV10) { walk_payment_return_v10(self, node) }
+	fn visit_fi_to_fi_customer_credit_transfer_v08(&mut self, node: &crate::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08) { walk_fi_to_fi_customer_credit_transfer_v08(self, node) }
+	fn visit_financial_institution_credit_transfer_v08(&mut self, node: &crate::iso::pacs_009_001_08::FinancialInstitutionCreditTransferV08) { walk_financial_institution_credit_transfer_v08(self, node) }
+	fn visit_fi_to_fi_payment_status_request_v03(&mut self, node: &crate::iso::pacs_028_001_03::FIToFIPaymentStatusRequestV03) { walk_fi_to_fi_payment_status_request_v03(self, node) }
+	fn visit_creditor_payment_activation_request_v07(&mut self, node: &crate::iso::pain_013_001_07::CreditorPaymentActivationRequestV07) { walk_creditor_payment_activation_request_v07(self, node) }
+	fn visit_creditor_payment_activation_request_status_report_v07(&mut self, node: &crate::iso::pain_014_001_07::CreditorPaymentActivationRequestStatusReportV07) { walk_creditor_payment_activation_request_status_report_v07(self, node) }
+}
+
+pub trait VisitorMut {
+	fn visit_account_debit_credit_notification_mut(&mut self, node: &mut crate::common::AccountDebitCreditNotification) { walk_account_debit_credit_notification_mut(self, node) }
+	fn visit_fed_now_account_activity_details_report_mut(&mut self, node: &mut crate::common::FedNowAccountActivityDetailsReport) { walk_fed_now_account_activity_details_report_mut(self, node) }
+	fn visit_fed_now_account_activity_totals_report_mut(&mut self, node: &mut crate::common::FedNowAccountActivityTotalsReport) { walk_fed_now_account_activity_totals_report_mut(self, node) }
+	fn visit_fed_now_account_balance_report_mut(&mut self, node: &mut crate::common::FedNowAccountBalanceReport) { walk_fed_now_account_balance_report_mut(self, node) }
+	fn visit_fed_now_account_reporting_request_mut(&mut self, node: &mut crate::common::FedNowAccountReportingRequest) { walk_fed_now_account_reporting_request_mut(self, node) }
+	fn visit_fed_now_additional_payment_information_mut(&mut self, node: &mut crate::common::FedNowAdditionalPaymentInformation) { walk_fed_now_additional_payment_information_mut(self, node) }
+	fn visit_fed_now_broadcast_mut(&mut self, node: &mut crate::common::FedNowBroadcast) { walk_fed_now_broadcast_mut(self, node) }
+	fn visit_fed_now_customer_credit_transfer_mut(&mut self, node: &mut crate::common::FedNowCustomerCreditTransfer) { walk_fed_now_customer_credit_transfer_mut(self, node) }
+	fn visit_fed_now_incoming_message_mut(&mut self, node: &mut crate::common::FedNowIncomingMessage) { walk_fed_now_incoming_message_mut(self, node) }
+	fn visit_fed_now_incoming_message_signature_management_mut(&mut self, node: &mut crate::common::FedNowIncomingMessageSignatureManagement) { walk_fed_now_incoming_message_signature_management_mut(self, node) }
+	fn visit_fed_now_information_request_mut(&mut self, node: &mut crate::common::FedNowInformationRequest) { walk_fed_now_information_request_mut(self, node) }
+	fn visit_fed_now_information_request_response_mut(&mut self, node: &mut crate::common::FedNowInformationRequestResponse) { walk_fed_now_information_request_response_mut(self, node) }
+	fn visit_fed_now_institution_credit_transfer_mut(&mut self, node: &mut crate::common::FedNowInstitutionCreditTransfer) { walk_fed_now_institution_credit_transfer_mut(self, node) }
+	fn visit_fed_now_message_reject_mut(&mut self, node: &mut crate::common::FedNowMessageReject) { walk_fed_now_message_reject_mut(self, node) }
+	fn visit_fed_now_outgoing_message_mut(&mut self, node: &mut crate::common::FedNowOutgoingMessage) { walk_fed_now_outgoing_message_mut(self, node) }
+	fn visit_fed_now_outgoing_message_signature_management_mut(&mut self, node: &mut crate::common::FedNowOutgoingMessageSignatureManagement) { walk_fed_now_outgoing_message_signature_management_mut(self, node) }
+	fn visit_fed_now_participant_broadcast_mut(&mut self, node: &mut crate::common::FedNowParticipantBroadcast) { walk_fed_now_participant_broadcast_mut(self, node) }
+	fn visit_fed_now_participant_file_mut(&mut self, node: &mut crate::common::FedNowParticipantFile) { walk_fed_now_participant_file_mut(self, node) }
+	fn visit_fed_now_payment_return_mut(&mut self, node: &mut crate::common::FedNowPaymentReturn) { walk_fed_now_payment_return_mut(self, node) }
+	fn visit_fed_now_payment_status_mut(&mut self, node: &mut crate::common::FedNowPaymentStatus) { walk_fed_now_payment_status_mut(self, node) }
+	fn visit_fed_now_payment_status_request_mut(&mut self, node: &mut crate::common::FedNowPaymentStatusRequest) { walk_fed_now_payment_status_request_mut(self, node) }
+	fn visit_fed_now_receipt_acknowledgement_mut(&mut self, node: &mut crate::common::FedNowReceiptAcknowledgement) { walk_fed_now_receipt_acknowledgement_mut(self, node) }
+	fn visit_fed_now_request_for_payment_mut(&mut self, node: &mut crate::common::FedNowRequestForPayment) { walk_fed_now_request_for_payment_mut(self, node) }
+	fn visit_fed_now_request_for_payment_cancellation_request_mut(&mut self, node: &mut crate::common::FedNowRequestForPaymentCancellationRequest) { walk_fed_now_request_for_payment_cancellation_request_mut(self, node) }
+	fn visit_fed_now_request_for_payment_cancellation_request_response_mut(&mut self, node: &mut crate::common::FedNowRequestForPaymentCancellationRequestResponse) { walk_fed_now_request_for_payment_cancellation_request_response_mut(self, node) }
+	fn visit_fed_now_request_for_payment_response_mut(&mut self, node: &mut crate::common::FedNowRequestForPaymentResponse) { walk_fed_now_request_for_payment_response_mut(self, node) }
+	fn visit_fed_now_retrieval_request_mut(&mut self, node: &mut crate::common::FedNowRetrievalRequest) { walk_fed_now_retrieval_request_mut(self, node) }
+	fn visit_fed_now_return_request_mut(&mut self, node: &mut crate::common::FedNowReturnRequest) { walk_fed_now_return_request_mut(self, node) }
+	fn visit_fed_now_return_request_response_mut(&mut self, node: &mut crate::common::FedNowReturnRequestResponse) { walk_fed_now_return_request_response_mut(self, node) }
+	fn visit_fed_now_system_response_mut(&mut self, node: &mut crate::common::FedNowSystemResponse) { walk_fed_now_system_response_mut(self, node) }
+	fn visit_fed_now_technical_header_mut(&mut self, node: &mut crate::common::FedNowTechnicalHeader) { walk_fed_now_technical_header_mut(self, node) }
+	fn visit_account_identification4_choice_mut(&mut self, node: &mut crate::common::AccountIdentification4Choice) { walk_account_identification4_choice_mut(self, node) }
+	fn visit_account_interest4_mut(&mut self, node: &mut crate::common::AccountInterest4) { walk_account_interest4_mut(self, node) }
+	fn visit_account_notification17_mut(&mut self, node: &mut crate::common::AccountNotification17) { walk_account_notification17_mut(self, node) }
+	fn visit_account_report25_mut(&mut self, node: &mut crate::common::AccountReport25) { walk_account_report25_mut(self, node) }
+	fn visit_account_scheme_name1_choice_mut(&mut self, node: &mut crate::common::AccountSchemeName1Choice) { walk_account_scheme_name1_choice_mut(self, node) }
+	fn visit_active_currency_and_amount_mut(&mut self, node: &mut crate::common::ActiveCurrencyAndAmount) { walk_active_currency_and_amount_mut(self, node) }
+	fn visit_active_or_historic_currency_and13_decimal_amount_mut(&mut self, node: &mut crate::common::ActiveOrHistoricCurrencyAnd13DecimalAmount) { walk_active_or_historic_currency_and13_decimal_amount_mut(self, node) }
+	fn visit_active_or_historic_currency_and_amount_mut(&mut self, node: &mut crate::common::ActiveOrHistoricCurrencyAndAmount) { walk_active_or_historic_currency_and_amount_mut(self, node) }
+	fn visit_active_or_historic_currency_and_amount_range2_mut(&mut self, node: &mut crate::common::ActiveOrHistoricCurrencyAndAmountRange2) { walk_active_or_historic_currency_and_amount_range2_mut(self, node) }
+	fn visit_address_type2_code_mut(&mut self, node: &mut crate::common::AddressType2Code) { walk_address_type2_code_mut(self, node) }
+	fn visit_address_type3_choice_mut(&mut self, node: &mut crate::common::AddressType3Choice) { walk_address_type3_choice_mut(self, node) }
+	fn visit_amendment_information_details13_mut(&mut self, node: &mut crate::common::AmendmentInformationDetails13) { walk_amendment_information_details13_mut(self, node) }
+	fn visit_amount_and_currency_exchange3_mut(&mut self, node: &mut crate::common::AmountAndCurrencyExchange3) { walk_amount_and_currency_exchange3_mut(self, node) }
+	fn visit_amount_and_currency_exchange_details3_mut(&mut self, node: &mut crate::common::AmountAndCurrencyExchangeDetails3) { walk_amount_and_currency_exchange_details3_mut(self, node) }
+	fn visit_amount_and_currency_exchange_details4_mut(&mut self, node: &mut crate::common::AmountAndCurrencyExchangeDetails4) { walk_amount_and_currency_exchange_details4_mut(self, node) }
+	fn visit_amount_and_direction35_mut(&mut self, node: &mut crate::common::AmountAndDirection35) { walk_amount_and_direction35_mut(self, node) }
+	fn visit_amount_or_rate1_choice_mut(&mut self, node: &mut crate::common::AmountOrRate1Choice) { walk_amount_or_rate1_choice_mut(self, node) }
+	fn visit_amount_range_boundary1_mut(&mut self, node: &mut crate::common::AmountRangeBoundary1) { walk_amount_range_boundary1_mut(self, node) }
+	fn visit_amount_type4_choice_mut(&mut self, node: &mut crate::common::AmountType4Choice) { walk_amount_type4_choice_mut(self, node) }
+	fn visit_attendance_context1_code_mut(&mut self, node: &mut crate::common::AttendanceContext1Code) { walk_attendance_context1_code_mut(self, node) }
+	fn visit_authentication_entity1_code_mut(&mut self, node: &mut crate::common::AuthenticationEntity1Code) { walk_authentication_entity1_code_mut(self, node) }
+	fn visit_authentication_method1_code_mut(&mut self, node: &mut crate::common::AuthenticationMethod1Code) { walk_authentication_method1_code_mut(self, node) }
+	fn visit_authorisation1_choice_mut(&mut self, node: &mut crate::common::Authorisation1Choice) { walk_authorisation1_choice_mut(self, node) }
+	fn visit_authorisation1_code_mut(&mut self, node: &mut crate::common::Authorisation1Code) { walk_authorisation1_code_mut(self, node) }
+	fn visit_balance_sub_type1_choice_mut(&mut self, node: &mut crate::common::BalanceSubType1Choice) { walk_balance_sub_type1_choice_mut(self, node) }
+	fn visit_balance_type10_choice_mut(&mut self, node: &mut crate::common::BalanceType10Choice) { walk_balance_type10_choice_mut(self, node) }
+	fn visit_balance_type13_mut(&mut self, node: &mut crate::common::BalanceType13) { walk_balance_type13_mut(self, node) }
+	fn visit_bank_transaction_code_structure4_mut(&mut self, node: &mut crate::common::BankTransactionCodeStructure4) { walk_bank_transaction_code_structure4_mut(self, node) }
+	fn visit_bank_transaction_code_structure5_mut(&mut self, node: &mut crate::common::BankTransactionCodeStructure5) { walk_bank_transaction_code_structure5_mut(self, node) }
+	fn visit_bank_transaction_code_structure6_mut(&mut self, node: &mut crate::common::BankTransactionCodeStructure6) { walk_bank_transaction_code_structure6_mut(self, node) }
+	fn visit_batch_information2_mut(&mut self, node: &mut crate::common::BatchInformation2) { walk_batch_information2_mut(self, node) }
+	fn visit_branch_and_financial_institution_identification6_mut(&mut self, node: &mut crate::common::BranchAndFinancialInstitutionIdentification6) { walk_branch_and_financial_institution_identification6_mut(self, node) }
+	fn visit_branch_data3_mut(&mut self, node: &mut crate::common::BranchData3) { walk_branch_data3_mut(self, node) }
+	fn visit_business_application_header5_mut(&mut self, node: &mut crate::common::BusinessApplicationHeader5) { walk_business_application_header5_mut(self, node) }
+	fn visit_business_application_header_v02_mut(&mut self, node: &mut crate::common::BusinessApplicationHeaderV02) { walk_business_application_header_v02_mut(self, node) }
+	fn visit_csc_management1_code_mut(&mut self, node: &mut crate::common::CSCManagement1Code) { walk_csc_management1_code_mut(self, node) }
+	fn visit_cancellation_individual_status1_code_mut(&mut self, node: &mut crate::common::CancellationIndividualStatus1Code) { walk_cancellation_individual_status1_code_mut(self, node) }
+	fn visit_cancellation_reason33_choice_mut(&mut self, node: &mut crate::common::CancellationReason33Choice) { walk_cancellation_reason33_choice_mut(self, node) }
+	fn visit_cancellation_status_reason3_choice_mut(&mut self, node: &mut crate::common::CancellationStatusReason3Choice) { walk_cancellation_status_reason3_choice_mut(self, node) }
+	fn visit_cancellation_status_reason4_mut(&mut self, node: &mut crate::common::CancellationStatusReason4) { walk_cancellation_status_reason4_mut(self, node) }
+	fn visit_card_aggregated2_mut(&mut self, node: &mut crate::common::CardAggregated2) { walk_card_aggregated2_mut(self, node) }
+	fn visit_card_data_reading1_code_mut(&mut self, node: &mut crate::common::CardDataReading1Code) { walk_card_data_reading1_code_mut(self, node) }
+	fn visit_card_entry4_mut(&mut self, node: &mut crate::common::CardEntry4) { walk_card_entry4_mut(self, node) }
+	fn visit_card_individual_transaction2_mut(&mut self, node: &mut crate::common::CardIndividualTransaction2) { walk_card_individual_transaction2_mut(self, node) }
+	fn visit_card_payment_service_type2_code_mut(&mut self, node: &mut crate::common::CardPaymentServiceType2Code) { walk_card_payment_service_type2_code_mut(self, node) }
+	fn visit_card_security_information1_mut(&mut self, node: &mut crate::common::CardSecurityInformation1) { walk_card_security_information1_mut(self, node) }
+	fn visit_card_sequence_number_range1_mut(&mut self, node: &mut crate::common::CardSequenceNumberRange1) { walk_card_sequence_number_range1_mut(self, node) }
+	fn visit_card_transaction17_mut(&mut self, node: &mut crate::common::CardTransaction17) { walk_card_transaction17_mut(self, node) }
+	fn visit_card_transaction3_choice_mut(&mut self, node: &mut crate::common::CardTransaction3Choice) { walk_card_transaction3_choice_mut(self, node) }
+	fn visit_cardholder_authentication2_mut(&mut self, node: &mut crate::common::CardholderAuthentication2) { walk_cardholder_authentication2_mut(self, node) }
+	fn visit_cardholder_verification_capability1_code_mut(&mut self, node: &mut crate::common::CardholderVerificationCapability1Code) { walk_cardholder_verification_capability1_code_mut(self, node) }
+	fn visit_case5_mut(&mut self, node: &mut crate::common::Case5) { walk_case5_mut(self, node) }
+	fn visit_case_assignment5_mut(&mut self, node: &mut crate::common::CaseAssignment5) { walk_case_assignment5_mut(self, node) }
+	fn visit_cash_account38_mut(&mut self, node: &mut crate::common::CashAccount38) { walk_cash_account38_mut(self, node) }
+	fn visit_cash_account39_mut(&mut self, node: &mut crate::common::CashAccount39) { walk_cash_account39_mut(self, node) }
+	fn visit_cash_account_type2_choice_mut(&mut self, node: &mut crate::common::CashAccountType2Choice) { walk_cash_account_type2_choice_mut(self, node) }
+	fn visit_cash_availability1_mut(&mut self, node: &mut crate::common::CashAvailability1) { walk_cash_availability1_mut(self, node) }
+	fn visit_cash_availability_date1_choice_mut(&mut self, node: &mut crate::common::CashAvailabilityDate1Choice) { walk_cash_availability_date1_choice_mut(self, node) }
+	fn visit_cash_balance8_mut(&mut self, node: &mut crate::common::CashBalance8) { walk_cash_balance8_mut(self, node) }
+	fn visit_cash_deposit1_mut(&mut self, node: &mut crate::common::CashDeposit1) { walk_cash_deposit1_mut(self, node) }
+	fn visit_category_purpose1_choice_mut(&mut self, node: &mut crate::common::CategoryPurpose1Choice) { walk_category_purpose1_choice_mut(self, node) }
+	fn visit_charge_bearer_type1_code_mut(&mut self, node: &mut crate::common::ChargeBearerType1Code) { walk_charge_bearer_type1_code_mut(self, node) }
+	fn visit_charge_type3_choice_mut(&mut self, node: &mut crate::common::ChargeType3Choice) { walk_charge_type3_choice_mut(self, node) }
+	fn visit_charges6_mut(&mut self, node: &mut crate::common::Charges6) { walk_charges6_mut(self, node) }
+	fn visit_charges7_mut(&mut self, node: &mut crate::common::Charges7) { walk_charges7_mut(self, node) }
+	fn visit_charges_record3_mut(&mut self, node: &mut crate::common::ChargesRecord3) { walk_charges_record3_mut(self, node) }
+	fn visit_cheque11_mut(&mut self, node: &mut crate::common::Cheque11) { walk_cheque11_mut(self, node) }
+	fn visit_cheque_delivery1_code_mut(&mut self, node: &mut crate::common::ChequeDelivery1Code) { walk_cheque_delivery1_code_mut(self, node) }
+	fn visit_cheque_delivery_method1_choice_mut(&mut self, node: &mut crate::common::ChequeDeliveryMethod1Choice) { walk_cheque_delivery_method1_choice_mut(self, node) }
+	fn visit_cheque_type2_code_mut(&mut self, node: &mut crate::common::ChequeType2Code) { walk_cheque_type2_code_mut(self, node) }
+	fn visit_claim_non_receipt2_mut(&mut self, node: &mut crate::common::ClaimNonReceipt2) { walk_claim_non_receipt2_mut(self, node) }
+	fn visit_claim_non_receipt2_choice_mut(&mut self, node: &mut crate::common::ClaimNonReceipt2Choice) { walk_claim_non_receipt2_choice_mut(self, node) }
+	fn visit_claim_non_receipt_reject_reason1_choice_mut(&mut self, node: &mut crate::common::ClaimNonReceiptRejectReason1Choice) { walk_claim_non_receipt_reject_reason1_choice_mut(self, node) }
+	fn visit_clearing_channel2_code_mut(&mut self, node: &mut crate::common::ClearingChannel2Code) { walk_clearing_channel2_code_mut(self, node) }
+	fn visit_clearing_system_identification2_choice_mut(&mut self, node: &mut crate::common::ClearingSystemIdentification2Choice) { walk_clearing_system_identification2_choice_mut(self, node) }
+	fn visit_clearing_system_identification3_choice_mut(&mut self, node: &mut crate::common::ClearingSystemIdentification3Choice) { walk_clearing_system_identification3_choice_mut(self, node) }
+	fn visit_clearing_system_member_identification2_mut(&mut self, node: &mut crate::common::ClearingSystemMemberIdentification2) { walk_clearing_system_member_identification2_mut(self, node) }
+	fn visit_compensation2_mut(&mut self, node: &mut crate::common::Compensation2) { walk_compensation2_mut(self, node) }
+	fn visit_compensation_reason1_choice_mut(&mut self, node: &mut crate::common::CompensationReason1Choice) { walk_compensation_reason1_choice_mut(self, node) }
+	fn visit_contact4_mut(&mut self, node: &mut crate::common::Contact4) { walk_contact4_mut(self, node) }
+	fn visit_control_data1_mut(&mut self, node: &mut crate::common::ControlData1) { walk_control_data1_mut(self, node) }
+	fn visit_copy_duplicate1_code_mut(&mut self, node: &mut crate::common::CopyDuplicate1Code) { walk_copy_duplicate1_code_mut(self, node) }
+	fn visit_corporate_action9_mut(&mut self, node: &mut crate::common::CorporateAction9) { walk_corporate_action9_mut(self, node) }
+	fn visit_corrective_group_information1_mut(&mut self, node: &mut crate::common::CorrectiveGroupInformation1) { walk_corrective_group_information1_mut(self, node) }
+	fn visit_corrective_interbank_transaction2_mut(&mut self, node: &mut crate::common::CorrectiveInterbankTransaction2) { walk_corrective_interbank_transaction2_mut(self, node) }
+	fn visit_corrective_payment_initiation4_mut(&mut self, node: &mut crate::common::CorrectivePaymentInitiation4) { walk_corrective_payment_initiation4_mut(self, node) }
+	fn visit_corrective_transaction4_choice_mut(&mut self, node: &mut crate::common::CorrectiveTransaction4Choice) { walk_corrective_transaction4_choice_mut(self, node) }
+	fn visit_credit_debit_code_mut(&mut self, node: &mut crate::common::CreditDebitCode) { walk_credit_debit_code_mut(self, node) }
+	fn visit_credit_line3_mut(&mut self, node: &mut crate::common::CreditLine3) { walk_credit_line3_mut(self, node) }
+	fn visit_credit_line_type1_choice_mut(&mut self, node: &mut crate::common::CreditLineType1Choice) { walk_credit_line_type1_choice_mut(self, node) }
+	fn visit_credit_transfer_mandate_data1_mut(&mut self, node: &mut crate::common::CreditTransferMandateData1) { walk_credit_transfer_mandate_data1_mut(self, node) }
+	fn visit_credit_transfer_transaction35_mut(&mut self, node: &mut crate::common::CreditTransferTransaction35) { walk_credit_transfer_transaction35_mut(self, node) }
+	fn visit_credit_transfer_transaction36_mut(&mut self, node: &mut crate::common::CreditTransferTransaction36) { walk_credit_transfer_transaction36_mut(self, node) }
+	fn visit_credit_transfer_transaction37_mut(&mut self, node: &mut crate::common::CreditTransferTransaction37) { walk_credit_transfer_transaction37_mut(self, node) }
+	fn visit_credit_transfer_transaction39_mut(&mut self, node: &mut crate::common::CreditTransferTransaction39) { walk_credit_transfer_transaction39_mut(self, node) }
+	fn visit_credit_transfer_transaction45_mut(&mut self, node: &mut crate::common::CreditTransferTransaction45) { walk_credit_transfer_transaction45_mut(self, node) }
+	fn visit_creditor_reference_information2_mut(&mut self, node: &mut crate::common::CreditorReferenceInformation2) { walk_creditor_reference_information2_mut(self, node) }
+	fn visit_creditor_reference_type1_choice_mut(&mut self, node: &mut crate::common::CreditorReferenceType1Choice) { walk_creditor_reference_type1_choice_mut(self, node) }
+	fn visit_creditor_reference_type2_mut(&mut self, node: &mut crate::common::CreditorReferenceType2) { walk_creditor_reference_type2_mut(self, node) }
+	fn visit_currency_exchange5_mut(&mut self, node: &mut crate::common::CurrencyExchange5) { walk_currency_exchange5_mut(self, node) }
+	fn visit_date_and_date_time2_choice_mut(&mut self, node: &mut crate::common::DateAndDateTime2Choice) { walk_date_and_date_time2_choice_mut(self, node) }
+	fn visit_date_and_place_of_birth1_mut(&mut self, node: &mut crate::common::DateAndPlaceOfBirth1) { walk_date_and_place_of_birth1_mut(self, node) }
+	fn visit_date_or_date_time_period1_choice_mut(&mut self, node: &mut crate::common::DateOrDateTimePeriod1Choice) { walk_date_or_date_time_period1_choice_mut(self, node) }
+	fn visit_date_period2_mut(&mut self, node: &mut crate::common::DatePeriod2) { walk_date_period2_mut(self, node) }
+	fn visit_date_period_details1_mut(&mut self, node: &mut crate::common::DatePeriodDetails1) { walk_date_period_details1_mut(self, node) }
+	fn visit_date_time_period1_mut(&mut self, node: &mut crate::common::DateTimePeriod1) { walk_date_time_period1_mut(self, node) }
+	fn visit_discount_amount_and_type1_mut(&mut self, node: &mut crate::common::DiscountAmountAndType1) { walk_discount_amount_and_type1_mut(self, node) }
+	fn visit_discount_amount_type1_choice_mut(&mut self, node: &mut crate::common::DiscountAmountType1Choice) { walk_discount_amount_type1_choice_mut(self, node) }
+	fn visit_display_capabilities1_mut(&mut self, node: &mut crate::common::DisplayCapabilities1) { walk_display_capabilities1_mut(self, node) }
+	fn visit_document12_mut(&mut self, node: &mut crate::common::Document12) { walk_document12_mut(self, node) }
+	fn visit_document_adjustment1_mut(&mut self, node: &mut crate::common::DocumentAdjustment1) { walk_document_adjustment1_mut(self, node) }
+	fn visit_document_format1_choice_mut(&mut self, node: &mut crate::common::DocumentFormat1Choice) { walk_document_format1_choice_mut(self, node) }
+	fn visit_document_line_identification1_mut(&mut self, node: &mut crate::common::DocumentLineIdentification1) { walk_document_line_identification1_mut(self, node) }
+	fn visit_document_line_information1_mut(&mut self, node: &mut crate::common::DocumentLineInformation1) { walk_document_line_information1_mut(self, node) }
+	fn visit_document_line_type1_mut(&mut self, node: &mut crate::common::DocumentLineType1) { walk_document_line_type1_mut(self, node) }
+	fn visit_document_line_type1_choice_mut(&mut self, node: &mut crate::common::DocumentLineType1Choice) { walk_document_line_type1_choice_mut(self, node) }
+	fn visit_document_type1_choice_mut(&mut self, node: &mut crate::common::DocumentType1Choice) { walk_document_type1_choice_mut(self, node) }
+	fn visit_document_type3_code_mut(&mut self, node: &mut crate::common::DocumentType3Code) { walk_document_type3_code_mut(self, node) }
+	fn visit_document_type6_code_mut(&mut self, node: &mut crate::common::DocumentType6Code) { walk_document_type6_code_mut(self, node) }
+	fn visit_entry_details9_mut(&mut self, node: &mut crate::common::EntryDetails9) { walk_entry_details9_mut(self, node) }
+	fn visit_entry_status1_choice_mut(&mut self, node: &mut crate::common::EntryStatus1Choice) { walk_entry_status1_choice_mut(self, node) }
+	fn visit_entry_transaction10_mut(&mut self, node: &mut crate::common::EntryTransaction10) { walk_entry_transaction10_mut(self, node) }
+	fn visit_equivalent_amount2_mut(&mut self, node: &mut crate::common::EquivalentAmount2) { walk_equivalent_amount2_mut(self, node) }
+	fn visit_event1_mut(&mut self, node: &mut crate::common::Event1) { walk_event1_mut(self, node) }
+	fn visit_event2_mut(&mut self, node: &mut crate::common::Event2) { walk_event2_mut(self, node) }
+	fn visit_financial_identification_scheme_name1_choice_mut(&mut self, node: &mut crate::common::FinancialIdentificationSchemeName1Choice) { walk_financial_identification_scheme_name1_choice_mut(self, node) }
+	fn visit_financial_institution_identification18_mut(&mut self, node: &mut crate::common::FinancialInstitutionIdentification18) { walk_financial_institution_identification18_mut(self, node) }
+	fn visit_financial_instrument_quantity1_choice_mut(&mut self, node: &mut crate::common::FinancialInstrumentQuantity1Choice) { walk_financial_instrument_quantity1_choice_mut(self, node) }
+	fn visit_floor_limit_type1_code_mut(&mut self, node: &mut crate::common::FloorLimitType1Code) { walk_floor_limit_type1_code_mut(self, node) }
+	fn visit_frequency36_choice_mut(&mut self, node: &mut crate::common::Frequency36Choice) { walk_frequency36_choice_mut(self, node) }
+	fn visit_frequency6_code_mut(&mut self, node: &mut crate::common::Frequency6Code) { walk_frequency6_code_mut(self, node) }
+	fn visit_frequency_and_moment1_mut(&mut self, node: &mut crate::common::FrequencyAndMoment1) { walk_frequency_and_moment1_mut(self, node) }
+	fn visit_frequency_period1_mut(&mut self, node: &mut crate::common::FrequencyPeriod1) { walk_frequency_period1_mut(self, node) }
+	fn visit_from_to_amount_range1_mut(&mut self, node: &mut crate::common::FromToAmountRange1) { walk_from_to_amount_range1_mut(self, node) }
+	fn visit_garnishment3_mut(&mut self, node: &mut crate::common::Garnishment3) { walk_garnishment3_mut(self, node) }
+	fn visit_garnishment_type1_mut(&mut self, node: &mut crate::common::GarnishmentType1) { walk_garnishment_type1_mut(self, node) }
+	fn visit_garnishment_type1_choice_mut(&mut self, node: &mut crate::common::GarnishmentType1Choice) { walk_garnishment_type1_choice_mut(self, node) }
+	fn visit_generic_account_identification1_mut(&mut self, node: &mut crate::common::GenericAccountIdentification1) { walk_generic_account_identification1_mut(self, node) }
+	fn visit_generic_financial_identification1_mut(&mut self, node: &mut crate::common::GenericFinancialIdentification1) { walk_generic_financial_identification1_mut(self, node) }
+	fn visit_generic_identification1_mut(&mut self, node: &mut crate::common::GenericIdentification1) { walk_generic_identification1_mut(self, node) }
+	fn visit_generic_identification3_mut(&mut self, node: &mut crate::common::GenericIdentification3) { walk_generic_identification3_mut(self, node) }
+	fn visit_generic_identification30_mut(&mut self, node: &mut crate::common::GenericIdentification30) { walk_generic_identification30_mut(self, node) }
+	fn visit_generic_identification32_mut(&mut self, node: &mut crate::common::GenericIdentification32) { walk_generic_identification32_mut(self, node) }
+	fn visit_generic_identification36_mut(&mut self, node: &mut crate::common::GenericIdentification36) { walk_generic_identification36_mut(self, node) }
+	fn visit_generic_organisation_identification1_mut(&mut self, node: &mut crate::common::GenericOrganisationIdentification1) { walk_generic_organisation_identification1_mut(self, node) }
+	fn visit_generic_person_identification1_mut(&mut self, node: &mut crate::common::GenericPersonIdentification1) { walk_generic_person_identification1_mut(self, node) }
+	fn visit_group_cancellation_status1_code_mut(&mut self, node: &mut crate::common::GroupCancellationStatus1Code) { walk_group_cancellation_status1_code_mut(self, node) }
+	fn visit_group_header77_mut(&mut self, node: &mut crate::common::GroupHeader77) { walk_group_header77_mut(self, node) }
+	fn visit_group_header78_mut(&mut self, node: &mut crate::common::GroupHeader78) { walk_group_header78_mut(self, node) }
+	fn visit_group_header81_mut(&mut self, node: &mut crate::common::GroupHeader81) { walk_group_header81_mut(self, node) }
+	fn visit_group_header87_mut(&mut self, node: &mut crate::common::GroupHeader87) { walk_group_header87_mut(self, node) }
+	fn visit_group_header90_mut(&mut self, node: &mut crate::common::GroupHeader90) { walk_group_header90_mut(self, node) }
+	fn visit_group_header91_mut(&mut self, node: &mut crate::common::GroupHeader91) { walk_group_header91_mut(self, node) }
+	fn visit_group_header93_mut(&mut self, node: &mut crate::common::GroupHeader93) { walk_group_header93_mut(self, node) }
+	fn visit_identification_source3_choice_mut(&mut self, node: &mut crate::common::IdentificationSource3Choice) { walk_identification_source3_choice_mut(self, node) }
+	fn visit_implementation_specification1_mut(&mut self, node: &mut crate::common::ImplementationSpecification1) { walk_implementation_specification1_mut(self, node) }
+	fn visit_implied_currency_amount_range1_choice_mut(&mut self, node: &mut crate::common::ImpliedCurrencyAmountRange1Choice) { walk_implied_currency_amount_range1_choice_mut(self, node) }
+	fn visit_instruction3_code_mut(&mut self, node: &mut crate::common::Instruction3Code) { walk_instruction3_code_mut(self, node) }
+	fn visit_instruction4_code_mut(&mut self, node: &mut crate::common::Instruction4Code) { walk_instruction4_code_mut(self, node) }
+	fn visit_instruction5_code_mut(&mut self, node: &mut crate::common::Instruction5Code) { walk_instruction5_code_mut(self, node) }
+	fn visit_instruction_for_creditor_agent1_mut(&mut self, node: &mut crate::common::InstructionForCreditorAgent1) { walk_instruction_for_creditor_agent1_mut(self, node) }
+	fn visit_instruction_for_creditor_agent2_mut(&mut self, node: &mut crate::common::InstructionForCreditorAgent2) { walk_instruction_for_creditor_agent2_mut(self, node) }
+	fn visit_instruction_for_creditor_agent3_mut(&mut self, node: &mut crate::common::InstructionForCreditorAgent3) { walk_instruction_for_creditor_agent3_mut(self, node) }
+	fn visit_instruction_for_next_agent1_mut(&mut self, node: &mut crate::common::InstructionForNextAgent1) { walk_instruction_for_next_agent1_mut(self, node) }
+	fn visit_interest_record2_mut(&mut self, node: &mut crate::common::InterestRecord2) { walk_interest_record2_mut(self, node) }
+	fn visit_interest_type1_choice_mut(&mut self, node: &mut crate::common::InterestType1Choice) { walk_interest_type1_choice_mut(self, node) }
+	fn visit_interest_type1_code_mut(&mut self, node: &mut crate::common::InterestType1Code) { walk_interest_type1_code_mut(self, node) }
+	fn visit_investigation_status5_choice_mut(&mut self, node: &mut crate::common::InvestigationStatus5Choice) { walk_investigation_status5_choice_mut(self, node) }
+	fn visit_limit2_mut(&mut self, node: &mut crate::common::Limit2) { walk_limit2_mut(self, node) }
+	fn visit_local_instrument2_choice_mut(&mut self, node: &mut crate::common::LocalInstrument2Choice) { walk_local_instrument2_choice_mut(self, node) }
+	fn visit_mandate_classification1_choice_mut(&mut self, node: &mut crate::common::MandateClassification1Choice) { walk_mandate_classification1_choice_mut(self, node) }
+	fn visit_mandate_classification1_code_mut(&mut self, node: &mut crate::common::MandateClassification1Code) { walk_mandate_classification1_code_mut(self, node) }
+	fn visit_mandate_related_data1_choice_mut(&mut self, node: &mut crate::common::MandateRelatedData1Choice) { walk_mandate_related_data1_choice_mut(self, node) }
+	fn visit_mandate_related_information14_mut(&mut self, node: &mut crate::common::MandateRelatedInformation14) { walk_mandate_related_information14_mut(self, node) }
+	fn visit_mandate_setup_reason1_choice_mut(&mut self, node: &mut crate::common::MandateSetupReason1Choice) { walk_mandate_setup_reason1_choice_mut(self, node) }
+	fn visit_mandate_type_information2_mut(&mut self, node: &mut crate::common::MandateTypeInformation2) { walk_mandate_type_information2_mut(self, node) }
+	fn visit_message_header10_mut(&mut self, node: &mut crate::common::MessageHeader10) { walk_message_header10_mut(self, node) }
+	fn visit_message_header7_mut(&mut self, node: &mut crate::common::MessageHeader7) { walk_message_header7_mut(self, node) }
+	fn visit_message_identification2_mut(&mut self, node: &mut crate::common::MessageIdentification2) { walk_message_identification2_mut(self, node) }
+	fn visit_message_reference_mut(&mut self, node: &mut crate::common::MessageReference) { walk_message_reference_mut(self, node) }
+	fn visit_message_reference1_mut(&mut self, node: &mut crate::common::MessageReference1) { walk_message_reference1_mut(self, node) }
+	fn visit_missing_or_incorrect_information3_mut(&mut self, node: &mut crate::common::MissingOrIncorrectInformation3) { walk_missing_or_incorrect_information3_mut(self, node) }
+	fn visit_modification_status_reason1_choice_mut(&mut self, node: &mut crate::common::ModificationStatusReason1Choice) { walk_modification_status_reason1_choice_mut(self, node) }
+	fn visit_modification_status_reason2_mut(&mut self, node: &mut crate::common::ModificationStatusReason2) { walk_modification_status_reason2_mut(self, node) }
+	fn visit_name_and_address16_mut(&mut self, node: &mut crate::common::NameAndAddress16) { walk_name_and_address16_mut(self, node) }
+	fn visit_name_and_address5_mut(&mut self, node: &mut crate::common::NameAndAddress5) { walk_name_and_address5_mut(self, node) }
+	fn visit_name_prefix2_code_mut(&mut self, node: &mut crate::common::NamePrefix2Code) { walk_name_prefix2_code_mut(self, node) }
+	fn visit_number_and_sum_of_transactions1_mut(&mut self, node: &mut crate::common::NumberAndSumOfTransactions1) { walk_number_and_sum_of_transactions1_mut(self, node) }
+	fn visit_number_and_sum_of_transactions4_mut(&mut self, node: &mut crate::common::NumberAndSumOfTransactions4) { walk_number_and_sum_of_transactions4_mut(self, node) }
+	fn visit_number_of_cancellations_per_status1_mut(&mut self, node: &mut crate::common::NumberOfCancellationsPerStatus1) { walk_number_of_cancellations_per_status1_mut(self, node) }
+	fn visit_number_of_transactions_per_status1_mut(&mut self, node: &mut crate::common::NumberOfTransactionsPerStatus1) { walk_number_of_transactions_per_status1_mut(self, node) }
+	fn visit_number_of_transactions_per_status5_mut(&mut self, node: &mut crate::common::NumberOfTransactionsPerStatus5) { walk_number_of_transactions_per_status5_mut(self, node) }
+	fn visit_on_line_capability1_code_mut(&mut self, node: &mut crate::common::OnLineCapability1Code) { walk_on_line_capability1_code_mut(self, node) }
+	fn visit_organisation_identification29_mut(&mut self, node: &mut crate::common::OrganisationIdentification29) { walk_organisation_identification29_mut(self, node) }
+	fn visit_organisation_identification_scheme_name1_choice_mut(&mut self, node: &mut crate::common::OrganisationIdentificationSchemeName1Choice) { walk_organisation_identification_scheme_name1_choice_mut(self, node) }
+	fn visit_original_and_current_quantities1_mut(&mut self, node: &mut crate::common::OriginalAndCurrentQuantities1) { walk_original_and_current_quantities1_mut(self, node) }
+	fn visit_original_business_query1_mut(&mut self, node: &mut crate::common::OriginalBusinessQuery1) { walk_original_business_query1_mut(self, node) }
+	fn visit_original_group_header14_mut(&mut self, node: &mut crate::common::OriginalGroupHeader14) { walk_original_group_header14_mut(self, node) }
+	fn visit_original_group_header15_mut(&mut self, node: &mut crate::common::OriginalGroupHeader15) { walk_original_group_header15_mut(self, node) }
+	fn visit_original_group_header17_mut(&mut self, node: &mut crate::common::OriginalGroupHeader17) { walk_original_group_header17_mut(self, node) }
+	fn visit_original_group_header18_mut(&mut self, node: &mut crate::common::OriginalGroupHeader18) { walk_original_group_header18_mut(self, node) }
+	fn visit_original_group_information27_mut(&mut self, node: &mut crate::common::OriginalGroupInformation27) { walk_original_group_information27_mut(self, node) }
+	fn visit_original_group_information29_mut(&mut self, node: &mut crate::common::OriginalGroupInformation29) { walk_original_group_information29_mut(self, node) }
+	fn visit_original_group_information30_mut(&mut self, node: &mut crate::common::OriginalGroupInformation30) { walk_original_group_information30_mut(self, node) }
+	fn visit_original_payment_instruction30_mut(&mut self, node: &mut crate::common::OriginalPaymentInstruction30) { walk_original_payment_instruction30_mut(self, node) }
+	fn visit_original_payment_instruction31_mut(&mut self, node: &mut crate::common::OriginalPaymentInstruction31) { walk_original_payment_instruction31_mut(self, node) }
+	fn visit_original_payment_instruction36_mut(&mut self, node: &mut crate::common::OriginalPaymentInstruction36) { walk_original_payment_instruction36_mut(self, node) }
+	fn visit_original_transaction_reference28_mut(&mut self, node: &mut crate::common::OriginalTransactionReference28) { walk_original_transaction_reference28_mut(self, node) }
+	fn visit_original_transaction_reference29_mut(&mut self, node: &mut crate::common::OriginalTransactionReference29) { walk_original_transaction_reference29_mut(self, node) }
+	fn visit_original_transaction_reference31_mut(&mut self, node: &mut crate::common::OriginalTransactionReference31) { walk_original_transaction_reference31_mut(self, node) }
+	fn visit_original_transaction_reference32_mut(&mut self, node: &mut crate::common::OriginalTransactionReference32) { walk_original_transaction_reference32_mut(self, node) }
+	fn visit_other_contact1_mut(&mut self, node: &mut crate::common::OtherContact1) { walk_other_contact1_mut(self, node) }
+	fn visit_other_identification1_mut(&mut self, node: &mut crate::common::OtherIdentification1) { walk_other_identification1_mut(self, node) }
+	fn visit_poi_component_type1_code_mut(&mut self, node: &mut crate::common::POIComponentType1Code) { walk_poi_component_type1_code_mut(self, node) }
+	fn visit_pagination1_mut(&mut self, node: &mut crate::common::Pagination1) { walk_pagination1_mut(self, node) }
+	fn visit_party38_choice_mut(&mut self, node: &mut crate::common::Party38Choice) { walk_party38_choice_mut(self, node) }
+	fn visit_party40_choice_mut(&mut self, node: &mut crate::common::Party40Choice) { walk_party40_choice_mut(self, node) }
+	fn visit_party44_choice_mut(&mut self, node: &mut crate::common::Party44Choice) { walk_party44_choice_mut(self, node) }
+	fn visit_party_and_signature3_mut(&mut self, node: &mut crate::common::PartyAndSignature3) { walk_party_and_signature3_mut(self, node) }
+	fn visit_party_identification120_choice_mut(&mut self, node: &mut crate::common::PartyIdentification120Choice) { walk_party_identification120_choice_mut(self, node) }
+	fn visit_party_identification135_mut(&mut self, node: &mut crate::common::PartyIdentification135) { walk_party_identification135_mut(self, node) }
+	fn visit_party_identification136_mut(&mut self, node: &mut crate::common::PartyIdentification136) { walk_party_identification136_mut(self, node) }
+	fn visit_party_type3_code_mut(&mut self, node: &mut crate::common::PartyType3Code) { walk_party_type3_code_mut(self, node) }
+	fn visit_party_type4_code_mut(&mut self, node: &mut crate::common::PartyType4Code) { walk_party_type4_code_mut(self, node) }
+	fn visit_payment_cancellation_reason5_mut(&mut self, node: &mut crate::common::PaymentCancellationReason5) { walk_payment_cancellation_reason5_mut(self, node) }
+	fn visit_payment_card4_mut(&mut self, node: &mut crate::common::PaymentCard4) { walk_payment_card4_mut(self, node) }
+	fn visit_payment_complementary_information8_mut(&mut self, node: &mut crate::common::PaymentComplementaryInformation8) { walk_payment_complementary_information8_mut(self, node) }
+	fn visit_payment_condition1_mut(&mut self, node: &mut crate::common::PaymentCondition1) { walk_payment_condition1_mut(self, node) }
+	fn visit_payment_condition_status1_mut(&mut self, node: &mut crate::common::PaymentConditionStatus1) { walk_payment_condition_status1_mut(self, node) }
+	fn visit_payment_context3_mut(&mut self, node: &mut crate::common::PaymentContext3) { walk_payment_context3_mut(self, node) }
+	fn visit_payment_identification6_mut(&mut self, node: &mut crate::common::PaymentIdentification6) { walk_payment_identification6_mut(self, node) }
+	fn visit_payment_identification7_mut(&mut self, node: &mut crate::common::PaymentIdentification7) { walk_payment_identification7_mut(self, node) }
+	fn visit_payment_instruction31_mut(&mut self, node: &mut crate::common::PaymentInstruction31) { walk_payment_instruction31_mut(self, node) }
+	fn visit_payment_method4_code_mut(&mut self, node: &mut crate::common::PaymentMethod4Code) { walk_payment_method4_code_mut(self, node) }
+	fn visit_payment_method7_code_mut(&mut self, node: &mut crate::common::PaymentMethod7Code) { walk_payment_method7_code_mut(self, node) }
+	fn visit_payment_return_reason5_mut(&mut self, node: &mut crate::common::PaymentReturnReason5) { walk_payment_return_reason5_mut(self, node) }
+	fn visit_payment_return_reason6_mut(&mut self, node: &mut crate::common::PaymentReturnReason6) { walk_payment_return_reason6_mut(self, node) }
+	fn visit_payment_transaction102_mut(&mut self, node: &mut crate::common::PaymentTransaction102) { walk_payment_transaction102_mut(self, node) }
+	fn visit_payment_transaction103_mut(&mut self, node: &mut crate::common::PaymentTransaction103) { walk_payment_transaction103_mut(self, node) }
+	fn visit_payment_transaction104_mut(&mut self, node: &mut crate::common::PaymentTransaction104) { walk_payment_transaction104_mut(self, node) }
+	fn visit_payment_transaction106_mut(&mut self, node: &mut crate::common::PaymentTransaction106) { walk_payment_transaction106_mut(self, node) }
+	fn visit_payment_transaction107_mut(&mut self, node: &mut crate::common::PaymentTransaction107) { walk_payment_transaction107_mut(self, node) }
+	fn visit_payment_transaction110_mut(&mut self, node: &mut crate::common::PaymentTransaction110) { walk_payment_transaction110_mut(self, node) }
+	fn visit_payment_transaction113_mut(&mut self, node: &mut crate::common::PaymentTransaction113) { walk_payment_transaction113_mut(self, node) }
+	fn visit_payment_transaction118_mut(&mut self, node: &mut crate::common::PaymentTransaction118) { walk_payment_transaction118_mut(self, node) }
+	fn visit_payment_transaction124_mut(&mut self, node: &mut crate::common::PaymentTransaction124) { walk_payment_transaction124_mut(self, node) }
+	fn visit_payment_type_information26_mut(&mut self, node: &mut crate::common::PaymentTypeInformation26) { walk_payment_type_information26_mut(self, node) }
+	fn visit_payment_type_information27_mut(&mut self, node: &mut crate::common::PaymentTypeInformation27) { walk_payment_type_information27_mut(self, node) }
+	fn visit_payment_type_information28_mut(&mut self, node: &mut crate::common::PaymentTypeInformation28) { walk_payment_type_information28_mut(self, node) }
+	fn visit_person_identification13_mut(&mut self, node: &mut crate::common::PersonIdentification13) { walk_person_identification13_mut(self, node) }
+	fn visit_person_identification_scheme_name1_choice_mut(&mut self, node: &mut crate::common::PersonIdentificationSchemeName1Choice) { walk_person_identification_scheme_name1_choice_mut(self, node) }
+	fn visit_plain_card_data1_mut(&mut self, node: &mut crate::common::PlainCardData1) { walk_plain_card_data1_mut(self, node) }
+	fn visit_point_of_interaction1_mut(&mut self, node: &mut crate::common::PointOfInteraction1) { walk_point_of_interaction1_mut(self, node) }
+	fn visit_point_of_interaction_capabilities1_mut(&mut self, node: &mut crate::common::PointOfInteractionCapabilities1) { walk_point_of_interaction_capabilities1_mut(self, node) }
+	fn visit_point_of_interaction_component1_mut(&mut self, node: &mut crate::common::PointOfInteractionComponent1) { walk_point_of_interaction_component1_mut(self, node) }
+	fn visit_postal_address1_mut(&mut self, node: &mut crate::common::PostalAddress1) { walk_postal_address1_mut(self, node) }
+	fn visit_postal_address24_mut(&mut self, node: &mut crate::common::PostalAddress24) { walk_postal_address24_mut(self, node) }
+	fn visit_preferred_contact_method1_code_mut(&mut self, node: &mut crate::common::PreferredContactMethod1Code) { walk_preferred_contact_method1_code_mut(self, node) }
+	fn visit_price7_mut(&mut self, node: &mut crate::common::Price7) { walk_price7_mut(self, node) }
+	fn visit_price_rate_or_amount3_choice_mut(&mut self, node: &mut crate::common::PriceRateOrAmount3Choice) { walk_price_rate_or_amount3_choice_mut(self, node) }
+	fn visit_price_value_type1_code_mut(&mut self, node: &mut crate::common::PriceValueType1Code) { walk_price_value_type1_code_mut(self, node) }
+	fn visit_priority2_code_mut(&mut self, node: &mut crate::common::Priority2Code) { walk_priority2_code_mut(self, node) }
+	fn visit_priority3_code_mut(&mut self, node: &mut crate::common::Priority3Code) { walk_priority3_code_mut(self, node) }
+	fn visit_product2_mut(&mut self, node: &mut crate::common::Product2) { walk_product2_mut(self, node) }
+	fn visit_proprietary_agent4_mut(&mut self, node: &mut crate::common::ProprietaryAgent4) { walk_proprietary_agent4_mut(self, node) }
+	fn visit_proprietary_bank_transaction_code_structure1_mut(&mut self, node: &mut crate::common::ProprietaryBankTransactionCodeStructure1) { walk_proprietary_bank_transaction_code_structure1_mut(self, node) }
+	fn visit_proprietary_data5_mut(&mut self, node: &mut crate::common::ProprietaryData5) { walk_proprietary_data5_mut(self, node) }
+	fn visit_proprietary_date3_mut(&mut self, node: &mut crate::common::ProprietaryDate3) { walk_proprietary_date3_mut(self, node) }
+	fn visit_proprietary_party5_mut(&mut self, node: &mut crate::common::ProprietaryParty5) { walk_proprietary_party5_mut(self, node) }
+	fn visit_proprietary_price2_mut(&mut self, node: &mut crate::common::ProprietaryPrice2) { walk_proprietary_price2_mut(self, node) }
+	fn visit_proprietary_quantity1_mut(&mut self, node: &mut crate::common::ProprietaryQuantity1) { walk_proprietary_quantity1_mut(self, node) }
+	fn visit_proprietary_reference1_mut(&mut self, node: &mut crate::common::ProprietaryReference1) { walk_proprietary_reference1_mut(self, node) }
+	fn visit_proxy_account_identification1_mut(&mut self, node: &mut crate::common::ProxyAccountIdentification1) { walk_proxy_account_identification1_mut(self, node) }
+	fn visit_proxy_account_type1_choice_mut(&mut self, node: &mut crate::common::ProxyAccountType1Choice) { walk_proxy_account_type1_choice_mut(self, node) }
+	fn visit_purpose2_choice_mut(&mut self, node: &mut crate::common::Purpose2Choice) { walk_purpose2_choice_mut(self, node) }
+	fn visit_query_type3_code_mut(&mut self, node: &mut crate::common::QueryType3Code) { walk_query_type3_code_mut(self, node) }
+	fn visit_rate4_mut(&mut self, node: &mut crate::common::Rate4) { walk_rate4_mut(self, node) }
+	fn visit_rate_type4_choice_mut(&mut self, node: &mut crate::common::RateType4Choice) { walk_rate_type4_choice_mut(self, node) }
+	fn visit_receipt_acknowledgement_report2_mut(&mut self, node: &mut crate::common::ReceiptAcknowledgementReport2) { walk_receipt_acknowledgement_report2_mut(self, node) }
+	fn visit_referred_document_information7_mut(&mut self, node: &mut crate::common::ReferredDocumentInformation7) { walk_referred_document_information7_mut(self, node) }
+	fn visit_referred_document_type3_choice_mut(&mut self, node: &mut crate::common::ReferredDocumentType3Choice) { walk_referred_document_type3_choice_mut(self, node) }
+	fn visit_referred_document_type4_mut(&mut self, node: &mut crate::common::ReferredDocumentType4) { walk_referred_document_type4_mut(self, node) }
+	fn visit_regulatory_authority2_mut(&mut self, node: &mut crate::common::RegulatoryAuthority2) { walk_regulatory_authority2_mut(self, node) }
+	fn visit_regulatory_reporting3_mut(&mut self, node: &mut crate::common::RegulatoryReporting3) { walk_regulatory_reporting3_mut(self, node) }
+	fn visit_regulatory_reporting_type1_code_mut(&mut self, node: &mut crate::common::RegulatoryReportingType1Code) { walk_regulatory_reporting_type1_code_mut(self, node) }
+	fn visit_rejection_reason2_mut(&mut self, node: &mut crate::common::RejectionReason2) { walk_rejection_reason2_mut(self, node) }
+	fn visit_remittance_amount2_mut(&mut self, node: &mut crate::common::RemittanceAmount2) { walk_remittance_amount2_mut(self, node) }
+	fn visit_remittance_amount3_mut(&mut self, node: &mut crate::common::RemittanceAmount3) { walk_remittance_amount3_mut(self, node) }
+	fn visit_remittance_information16_mut(&mut self, node: &mut crate::common::RemittanceInformation16) { walk_remittance_information16_mut(self, node) }
+	fn visit_remittance_information2_mut(&mut self, node: &mut crate::common::RemittanceInformation2) { walk_remittance_information2_mut(self, node) }
+	fn visit_remittance_location7_mut(&mut self, node: &mut crate::common::RemittanceLocation7) { walk_remittance_location7_mut(self, node) }
+	fn visit_remittance_location_data1_mut(&mut self, node: &mut crate::common::RemittanceLocationData1) { walk_remittance_location_data1_mut(self, node) }
+	fn visit_remittance_location_method2_code_mut(&mut self, node: &mut crate::common::RemittanceLocationMethod2Code) { walk_remittance_location_method2_code_mut(self, node) }
+	fn visit_report_entry10_mut(&mut self, node: &mut crate::common::ReportEntry10) { walk_report_entry10_mut(self, node) }
+	fn visit_reporting_period2_mut(&mut self, node: &mut crate::common::ReportingPeriod2) { walk_reporting_period2_mut(self, node) }
+	fn visit_reporting_request5_mut(&mut self, node: &mut crate::common::ReportingRequest5) { walk_reporting_request5_mut(self, node) }
+	fn visit_reporting_source1_choice_mut(&mut self, node: &mut crate::common::ReportingSource1Choice) { walk_reporting_source1_choice_mut(self, node) }
+	fn visit_request_handling2_mut(&mut self, node: &mut crate::common::RequestHandling2) { walk_request_handling2_mut(self, node) }
+	fn visit_request_type4_choice_mut(&mut self, node: &mut crate::common::RequestType4Choice) { walk_request_type4_choice_mut(self, node) }
+	fn visit_resend_search_criteria2_mut(&mut self, node: &mut crate::common::ResendSearchCriteria2) { walk_resend_search_criteria2_mut(self, node) }
+	fn visit_resolution_data1_mut(&mut self, node: &mut crate::common::ResolutionData1) { walk_resolution_data1_mut(self, node) }
+	fn visit_return_reason5_choice_mut(&mut self, node: &mut crate::common::ReturnReason5Choice) { walk_return_reason5_choice_mut(self, node) }
+	fn visit_securities_account19_mut(&mut self, node: &mut crate::common::SecuritiesAccount19) { walk_securities_account19_mut(self, node) }
+	fn visit_security_identification19_mut(&mut self, node: &mut crate::common::SecurityIdentification19) { walk_security_identification19_mut(self, node) }
+	fn visit_sequence_range1_mut(&mut self, node: &mut crate::common::SequenceRange1) { walk_sequence_range1_mut(self, node) }
+	fn visit_sequence_range1_choice_mut(&mut self, node: &mut crate::common::SequenceRange1Choice) { walk_sequence_range1_choice_mut(self, node) }
+	fn visit_sequence_type3_code_mut(&mut self, node: &mut crate::common::SequenceType3Code) { walk_sequence_type3_code_mut(self, node) }
+	fn visit_service_level8_choice_mut(&mut self, node: &mut crate::common::ServiceLevel8Choice) { walk_service_level8_choice_mut(self, node) }
+	fn visit_settlement_date_time_indication1_mut(&mut self, node: &mut crate::common::SettlementDateTimeIndication1) { walk_settlement_date_time_indication1_mut(self, node) }
+	fn visit_settlement_instruction7_mut(&mut self, node: &mut crate::common::SettlementInstruction7) { walk_settlement_instruction7_mut(self, node) }
+	fn visit_settlement_method1_code_mut(&mut self, node: &mut crate::common::SettlementMethod1Code) { walk_settlement_method1_code_mut(self, node) }
+	fn visit_settlement_time_request2_mut(&mut self, node: &mut crate::common::SettlementTimeRequest2) { walk_settlement_time_request2_mut(self, node) }
+	fn visit_signature_envelope_mut(&mut self, node: &mut crate::common::SignatureEnvelope) { walk_signature_envelope_mut(self, node) }
+	fn visit_skip_payload_mut(&mut self, node: &mut crate::common::SkipPayload) { walk_skip_payload_mut(self, node) }
+	fn visit_statement_resolution_entry4_mut(&mut self, node: &mut crate::common::StatementResolutionEntry4) { walk_statement_resolution_entry4_mut(self, node) }
+	fn visit_status_reason6_choice_mut(&mut self, node: &mut crate::common::StatusReason6Choice) { walk_status_reason6_choice_mut(self, node) }
+	fn visit_status_reason_information12_mut(&mut self, node: &mut crate::common::StatusReasonInformation12) { walk_status_reason_information12_mut(self, node) }
+	fn visit_structured_regulatory_reporting3_mut(&mut self, node: &mut crate::common::StructuredRegulatoryReporting3) { walk_structured_regulatory_reporting3_mut(self, node) }
+	fn visit_structured_remittance_information16_mut(&mut self, node: &mut crate::common::StructuredRemittanceInformation16) { walk_structured_remittance_information16_mut(self, node) }
+	fn visit_supplementary_data1_mut(&mut self, node: &mut crate::common::SupplementaryData1) { walk_supplementary_data1_mut(self, node) }
+	fn visit_supplementary_data_envelope1_mut(&mut self, node: &mut crate::common::SupplementaryDataEnvelope1) { walk_supplementary_data_envelope1_mut(self, node) }
+	fn visit_tax_amount2_mut(&mut self, node: &mut crate::common::TaxAmount2) { walk_tax_amount2_mut(self, node) }
+	fn visit_tax_amount_and_type1_mut(&mut self, node: &mut crate::common::TaxAmountAndType1) { walk_tax_amount_and_type1_mut(self, node) }
+	fn visit_tax_amount_type1_choice_mut(&mut self, node: &mut crate::common::TaxAmountType1Choice) { walk_tax_amount_type1_choice_mut(self, node) }
+	fn visit_tax_authorisation1_mut(&mut self, node: &mut crate::common::TaxAuthorisation1) { walk_tax_authorisation1_mut(self, node) }
+	fn visit_tax_charges2_mut(&mut self, node: &mut crate::common::TaxCharges2) { walk_tax_charges2_mut(self, node) }
+	fn visit_tax_information7_mut(&mut self, node: &mut crate::common::TaxInformation7) { walk_tax_information7_mut(self, node) }
+	fn visit_tax_information8_mut(&mut self, node: &mut crate::common::TaxInformation8) { walk_tax_information8_mut(self, node) }
+	fn visit_tax_party1_mut(&mut self, node: &mut crate::common::TaxParty1) { walk_tax_party1_mut(self, node) }
+	fn visit_tax_party2_mut(&mut self, node: &mut crate::common::TaxParty2) { walk_tax_party2_mut(self, node) }
+	fn visit_tax_period2_mut(&mut self, node: &mut crate::common::TaxPeriod2) { walk_tax_period2_mut(self, node) }
+	fn visit_tax_record2_mut(&mut self, node: &mut crate::common::TaxRecord2) { walk_tax_record2_mut(self, node) }
+	fn visit_tax_record_details2_mut(&mut self, node: &mut crate::common::TaxRecordDetails2) { walk_tax_record_details2_mut(self, node) }
+	fn visit_tax_record_period1_code_mut(&mut self, node: &mut crate::common::TaxRecordPeriod1Code) { walk_tax_record_period1_code_mut(self, node) }
+	fn visit_technical_input_channel1_choice_mut(&mut self, node: &mut crate::common::TechnicalInputChannel1Choice) { walk_technical_input_channel1_choice_mut(self, node) }
+	fn visit_time_period_details1_mut(&mut self, node: &mut crate::common::TimePeriodDetails1) { walk_time_period_details1_mut(self, node) }
+	fn visit_total_transactions6_mut(&mut self, node: &mut crate::common::TotalTransactions6) { walk_total_transactions6_mut(self, node) }
+	fn visit_totals_per_bank_transaction_code5_mut(&mut self, node: &mut crate::common::TotalsPerBankTransactionCode5) { walk_totals_per_bank_transaction_code5_mut(self, node) }
+	fn visit_track_data1_mut(&mut self, node: &mut crate::common::TrackData1) { walk_track_data1_mut(self, node) }
+	fn visit_transaction_agents5_mut(&mut self, node: &mut crate::common::TransactionAgents5) { walk_transaction_agents5_mut(self, node) }
+	fn visit_transaction_channel1_code_mut(&mut self, node: &mut crate::common::TransactionChannel1Code) { walk_transaction_channel1_code_mut(self, node) }
+	fn visit_transaction_dates3_mut(&mut self, node: &mut crate::common::TransactionDates3) { walk_transaction_dates3_mut(self, node) }
+	fn visit_transaction_environment1_code_mut(&mut self, node: &mut crate::common::TransactionEnvironment1Code) { walk_transaction_environment1_code_mut(self, node) }
+	fn visit_transaction_identifier1_mut(&mut self, node: &mut crate::common::TransactionIdentifier1) { walk_transaction_identifier1_mut(self, node) }
+	fn visit_transaction_individual_status1_code_mut(&mut self, node: &mut crate::common::TransactionIndividualStatus1Code) { walk_transaction_individual_status1_code_mut(self, node) }
+	fn visit_transaction_interest4_mut(&mut self, node: &mut crate::common::TransactionInterest4) { walk_transaction_interest4_mut(self, node) }
+	fn visit_transaction_parties6_mut(&mut self, node: &mut crate::common::TransactionParties6) { walk_transaction_parties6_mut(self, node) }
+	fn visit_transaction_parties8_mut(&mut self, node: &mut crate::common::TransactionParties8) { walk_transaction_parties8_mut(self, node) }
+	fn visit_transaction_price4_choice_mut(&mut self, node: &mut crate::common::TransactionPrice4Choice) { walk_transaction_price4_choice_mut(self, node) }
+	fn visit_transaction_quantities3_choice_mut(&mut self, node: &mut crate::common::TransactionQuantities3Choice) { walk_transaction_quantities3_choice_mut(self, node) }
+	fn visit_transaction_references6_mut(&mut self, node: &mut crate::common::TransactionReferences6) { walk_transaction_references6_mut(self, node) }
+	fn visit_transaction_type2_mut(&mut self, node: &mut crate::common::TransactionType2) { walk_transaction_type2_mut(self, node) }
+	fn visit_unable_to_apply_incorrect1_mut(&mut self, node: &mut crate::common::UnableToApplyIncorrect1) { walk_unable_to_apply_incorrect1_mut(self, node) }
+	fn visit_unable_to_apply_incorrect_information4_code_mut(&mut self, node: &mut crate::common::UnableToApplyIncorrectInformation4Code) { walk_unable_to_apply_incorrect_information4_code_mut(self, node) }
+	fn visit_unable_to_apply_justification3_choice_mut(&mut self, node: &mut crate::common::UnableToApplyJustification3Choice) { walk_unable_to_apply_justification3_choice_mut(self, node) }
+	fn visit_unable_to_apply_missing1_mut(&mut self, node: &mut crate::common::UnableToApplyMissing1) { walk_unable_to_apply_missing1_mut(self, node) }
+	fn visit_unable_to_apply_missing_information3_code_mut(&mut self, node: &mut crate::common::UnableToApplyMissingInformation3Code) { walk_unable_to_apply_missing_information3_code_mut(self, node) }
+	fn visit_underlying_group_information1_mut(&mut self, node: &mut crate::common::UnderlyingGroupInformation1) { walk_underlying_group_information1_mut(self, node) }
+	fn visit_underlying_payment_instruction5_mut(&mut self, node: &mut crate::common::UnderlyingPaymentInstruction5) { walk_underlying_payment_instruction5_mut(self, node) }
+	fn visit_underlying_payment_transaction4_mut(&mut self, node: &mut crate::common::UnderlyingPaymentTransaction4) { walk_underlying_payment_transaction4_mut(self, node) }
+	fn visit_underlying_statement_entry3_mut(&mut self, node: &mut crate::common::UnderlyingStatementEntry3) { walk_underlying_statement_entry3_mut(self, node) }
+	fn visit_underlying_transaction22_mut(&mut self, node: &mut crate::common::UnderlyingTransaction22) { walk_underlying_transaction22_mut(self, node) }
+	fn visit_underlying_transaction23_mut(&mut self, node: &mut crate::common::UnderlyingTransaction23) { walk_underlying_transaction23_mut(self, node) }
+	fn visit_underlying_transaction27_mut(&mut self, node: &mut crate::common::UnderlyingTransaction27) { walk_underlying_transaction27_mut(self, node) }
+	fn visit_underlying_transaction5_choice_mut(&mut self, node: &mut crate::common::UnderlyingTransaction5Choice) { walk_underlying_transaction5_choice_mut(self, node) }
+	fn visit_unit_of_measure1_code_mut(&mut self, node: &mut crate::common::UnitOfMeasure1Code) { walk_unit_of_measure1_code_mut(self, node) }
+	fn visit_user_interface2_code_mut(&mut self, node: &mut crate::common::UserInterface2Code) { walk_user_interface2_code_mut(self, node) }
+	fn visit_yielded_or_value_type1_choice_mut(&mut self, node: &mut crate::common::YieldedOrValueType1Choice) { walk_yielded_or_value_type1_choice_mut(self, node) }
+	fn visit_fed_now_message_signature_key_mut(&mut self, node: &mut crate::common::FedNowMessageSignatureKey) { walk_fed_now_message_signature_key_mut(self, node) }
+	fn visit_fed_now_message_signature_key_status_mut(&mut self, node: &mut crate::common::FedNowMessageSignatureKeyStatus) { walk_fed_now_message_signature_key_status_mut(self, node) }
+	fn visit_fed_now_public_key_response_mut(&mut self, node: &mut crate::common::FedNowPublicKeyResponse) { walk_fed_now_public_key_response_mut(self, node) }
+	fn visit_key_addition_mut(&mut self, node: &mut crate::common::KeyAddition) { walk_key_addition_mut(self, node) }
+	fn visit_key_revocation_mut(&mut self, node: &mut crate::fednow_extra::key_exchange::KeyRevocation) { walk_key_revocation_mut(self, node) }
+	fn visit_fed_now_message_signature_key_exchange_mut(&mut self, node: &mut crate::fednow_extra::key_exchange::FedNowMessageSignatureKeyExchange) { walk_fed_now_message_signature_key_exchange_mut(self, node) }
+	fn visit_fed_now_customer_message_signature_key_operation_response_mut(&mut self, node: &mut crate::fednow_extra::key_exchange::FedNowCustomerMessageSignatureKeyOperationResponse) { walk_fed_now_customer_message_signature_key_operation_response_mut(self, node) }
+	fn visit_get_all_fed_now_active_public_keys_mut(&mut self, node: &mut crate::fednow_extra::key_exchange::GetAllFedNowActivePublicKeys) { walk_get_all_fed_now_active_public_keys_mut(self, node) }
+	fn visit_get_all_customer_public_keys_mut(&mut self, node: &mut crate::fednow_extra::key_exchange::GetAllCustomerPublicKeys) { walk_get_all_customer_public_keys_mut(self, node) }
+	fn visit_fed_now_public_key_responses_mut(&mut self, node: &mut crate::fednow_extra::key_exchange::FedNowPublicKeyResponses) { walk_fed_now_public_key_responses_mut(self, node) }
+	fn visit_fed_now_incoming_mut(&mut self, node: &mut crate::fednow_incoming_external::FedNowIncoming) { walk_fed_now_incoming_mut(self, node) }
+	fn visit_fed_now_outgoing_mut(&mut self, node: &mut crate::fednow_outgoing_external::FedNowOutgoing) { walk_fed_now_outgoing_mut(self, node) }
+	fn visit_admi00200101_mut(&mut self, node: &mut crate::iso::admi_002_001_01::Admi00200101) { walk_admi00200101_mut(self, node) }
+	fn visit_system_event_notification_v02_mut(&mut self, node: &mut crate::iso::admi_004_001_02::SystemEventNotificationV02) { walk_system_event_notification_v02_mut(self, node) }
+	fn visit_resend_request_v01_mut(&mut self, node: &mut crate::iso::admi_006_001_01::ResendRequestV01) { walk_resend_request_v01_mut(self, node) }
+	fn visit_receipt_acknowledgement_v01_mut(&mut self, node: &mut crate::iso::admi_007_001_01::ReceiptAcknowledgementV01) { walk_receipt_acknowledgement_v01_mut(self, node) }
+	fn visit_system_event_acknowledgement_v01_mut(&mut self, node: &mut crate::iso::admi_011_001_01::SystemEventAcknowledgementV01) { walk_system_event_acknowledgement_v01_mut(self, node) }
+	fn visit_administration_proprietary_message_v02_mut(&mut self, node: &mut crate::iso::admi_998_001_02::AdministrationProprietaryMessageV02) { walk_administration_proprietary_message_v02_mut(self, node) }
+	fn visit_unable_to_apply_v07_mut(&mut self, node: &mut crate::iso::camt_026_001_07::UnableToApplyV07) { walk_unable_to_apply_v07_mut(self, node) }
+	fn visit_additional_payment_information_v09_mut(&mut self, node: &mut crate::iso::camt_028_001_09::AdditionalPaymentInformationV09) { walk_additional_payment_information_v09_mut(self, node) }
+	fn visit_resolution_of_investigation_v09_mut(&mut self, node: &mut crate::iso::camt_029_001_09::ResolutionOfInvestigationV09) { walk_resolution_of_investigation_v09_mut(self, node) }
+	fn visit_bank_to_customer_account_report_v08_mut(&mut self, node: &mut crate::iso::camt_052_001_08::BankToCustomerAccountReportV08) { walk_bank_to_customer_account_report_v08_mut(self, node) }
+	fn visit_bank_to_customer_debit_credit_notification_v08_mut(&mut self, node: &mut crate::iso::camt_054_001_08::BankToCustomerDebitCreditNotificationV08) { walk_bank_to_customer_debit_credit_notification_v08_mut(self, node) }
+	fn visit_customer_payment_cancellation_request_v09_mut(&mut self, node: &mut crate::iso::camt_055_001_09::CustomerPaymentCancellationRequestV09) { walk_customer_payment_cancellation_request_v09_mut(self, node) }
+	fn visit_fi_to_fi_payment_cancellation_request_v08_mut(&mut self, node: &mut crate::iso::camt_056_001_08::FIToFIPaymentCancellationRequestV08) { walk_fi_to_fi_payment_cancellation_request_v08_mut(self, node) }
+	fn visit_account_reporting_request_v05_mut(&mut self, node: &mut crate::iso::camt_060_001_05::AccountReportingRequestV05) { walk_account_reporting_request_v05_mut(self, node) }
+	fn visit_fi_to_fi_payment_status_report_v10_mut(&mut self, node: &mut crate::iso::pacs_002_001_10::FIToFIPaymentStatusReportV10) { walk_fi_to_fi_payment_status_report_v10_mut(self, node) }
+	fn visit_payment_return_v10_mut(&mut self, node: &mut crate::iso::pacs_004_001_10::PaymentReturnV10) { walk_payment_return_v10_mut(self, node) }
+	fn visit_fi_to_fi_customer_credit_transfer_v08_mut(&mut self, node: &mut crate::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08) { walk_fi_to_fi_customer_credit_transfer_v08_mut(self, node) }
+	fn visit_financial_institution_credit_transfer_v08_mut(&mut self, node: &mut crate::iso::pacs_009_001_08::FinancialInstitutionCreditTransferV08) { walk_financial_institution_credit_transfer_v08_mut(self, node) }
+	fn visit_fi_to_fi_payment_status_request_v03_mut(&mut self, node: &mut crate::iso::pacs_028_001_03::FIToFIPaymentStatusRequestV03) { walk_fi_to_fi_payment_status_request_v03_mut(self, node) }
+	fn visit_creditor_payment_activation_request_v07_mut(&mut self, node: &mut crate::iso::pain_013_001_07::CreditorPaymentActivationRequestV07) { walk_creditor_payment_activation_request_v07_mut(self, node) }
+	fn visit_creditor_payment_activation_request_status_report_v07_mut(&mut self, node: &mut crate::iso::pain_014_001_07::CreditorPaymentActivationRequestStatusReportV07) { walk_creditor_payment_activation_request_status_report_v07_mut(self, node) }
+}
+
+pub fn walk_account_debit_credit_notification<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AccountDebitCreditNotification) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c54_document.walk(visitor);
+}
+
+pub fn walk_account_debit_credit_notification_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AccountDebitCreditNotification) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c54_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_account_activity_details_report<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowAccountActivityDetailsReport) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c52_document.walk(visitor);
+}
+
+pub fn walk_fed_now_account_activity_details_report_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowAccountActivityDetailsReport) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c52_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_account_activity_totals_report<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowAccountActivityTotalsReport) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c52_document.walk(visitor);
+}
+
+pub fn walk_fed_now_account_activity_totals_report_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowAccountActivityTotalsReport) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c52_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_account_balance_report<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowAccountBalanceReport) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c52_document.walk(visitor);
+}
+
+pub fn walk_fed_now_account_balance_report_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowAccountBalanceReport) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c52_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_account_reporting_request<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowAccountReportingRequest) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c60_document.walk(visitor);
+}
+
+pub fn walk_fed_now_account_reporting_request_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowAccountReportingRequest) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c60_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_additional_payment_information<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowAdditionalPaymentInformation) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c28_document.walk(visitor);
+}
+
+pub fn walk_fed_now_additional_payment_information_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowAdditionalPaymentInformation) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c28_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_broadcast<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowBroadcast) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.a4_document.walk(visitor);
+}
+
+pub fn walk_fed_now_broadcast_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowBroadcast) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.a4_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_customer_credit_transfer<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowCustomerCreditTransfer) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.p8_document.walk(visitor);
+}
+
+pub fn walk_fed_now_customer_credit_transfer_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowCustomerCreditTransfer) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.p8_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_incoming_message<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowIncomingMessage) {
+	if let Some(ref value) = node.fed_now_message_reject { visitor.visit_fed_now_message_reject(value) }
+	if let Some(ref value) = node.fed_now_participant_broadcast { visitor.visit_fed_now_participant_broadcast(value) }
+	if let Some(ref value) = node.fed_now_retrieval_request { visitor.visit_fed_now_retrieval_request(value) }
+	if let Some(ref value) = node.fed_now_receipt_acknowledgement { visitor.visit_fed_now_receipt_acknowledgement(value) }
+	if let Some(ref value) = node.fed_now_payment_status { visitor.visit_fed_now_payment_status(value) }
+	if let Some(ref value) = node.fed_now_payment_return { visitor.visit_fed_now_payment_return(value) }
+	if let Some(ref value) = node.fed_now_customer_credit_transfer { visitor.visit_fed_now_customer_credit_transfer(value) }
+	if let Some(ref value) = node.fed_now_institution_credit_transfer { visitor.visit_fed_now_institution_credit_transfer(value) }
+	if let Some(ref value) = node.fed_now_payment_status_request { visitor.visit_fed_now_payment_status_request(value) }
+	if let Some(ref value) = node.fed_now_request_for_payment { visitor.visit_fed_now_request_for_payment(value) }
+	if let Some(ref value) = node.fed_now_request_for_payment_response { visitor.visit_fed_now_request_for_payment_response(value) }
+	if let Some(ref value) = node.fed_now_information_request { visitor.visit_fed_now_information_request(value) }
+	if let Some(ref value) = node.fed_now_additional_payment_information { visitor.visit_fed_now_additional_payment_information(value) }
+	if let Some(ref value) = node.fed_now_information_request_response { visitor.visit_fed_now_information_request_response(value) }
+	if let Some(ref value) = node.fed_now_request_for_payment_cancellation_request_response { visitor.visit_fed_now_request_for_payment_cancellation_request_response(value) }
+	if let Some(ref value) = node.fed_now_return_request_response { visitor.visit_fed_now_return_request_response(value) }
+	if let Some(ref value) = node.fed_now_request_for_payment_cancellation_request { visitor.visit_fed_now_request_for_payment_cancellation_request(value) }
+	if let Some(ref value) = node.fed_now_return_request { visitor.visit_fed_now_return_request(value) }
+	if let Some(ref value) = node.fed_now_account_reporting_request { visitor.visit_fed_now_account_reporting_request(value) }
+	if let Some(ref value) = node.fed_now_incoming_message_signature_management { visitor.visit_fed_now_incoming_message_signature_management(value) }
+}
+
+pub fn walk_fed_now_incoming_message_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowIncomingMessage) {
+	if let Some(ref mut value) = node.fed_now_message_reject { visitor.visit_fed_now_message_reject_mut(value) }
+	if let Some(ref mut value) = node.fed_now_participant_broadcast { visitor.visit_fed_now_participant_broadcast_mut(value) }
+	if let Some(ref mut value) = node.fed_now_retrieval_request { visitor.visit_fed_now_retrieval_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_receipt_acknowledgement { visitor.visit_fed_now_receipt_acknowledgement_mut(value) }
+	if let Some(ref mut value) = node.fed_now_payment_status { visitor.visit_fed_now_payment_status_mut(value) }
+	if let Some(ref mut value) = node.fed_now_payment_return { visitor.visit_fed_now_payment_return_mut(value) }
+	if let Some(ref mut value) = node.fed_now_customer_credit_transfer { visitor.visit_fed_now_customer_credit_transfer_mut(value) }
+	if let Some(ref mut value) = node.fed_now_institution_credit_transfer { visitor.visit_fed_now_institution_credit_transfer_mut(value) }
+	if let Some(ref mut value) = node.fed_now_payment_status_request { visitor.visit_fed_now_payment_status_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_request_for_payment { visitor.visit_fed_now_request_for_payment_mut(value) }
+	if let Some(ref mut value) = node.fed_now_request_for_payment_response { visitor.visit_fed_now_request_for_payment_response_mut(value) }
+	if let Some(ref mut value) = node.fed_now_information_request { visitor.visit_fed_now_information_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_additional_payment_information { visitor.visit_fed_now_additional_payment_information_mut(value) }
+	if let Some(ref mut value) = node.fed_now_information_request_response { visitor.visit_fed_now_information_request_response_mut(value) }
+	if let Some(ref mut value) = node.fed_now_request_for_payment_cancellation_request_response { visitor.visit_fed_now_request_for_payment_cancellation_request_response_mut(value) }
+	if let Some(ref mut value) = node.fed_now_return_request_response { visitor.visit_fed_now_return_request_response_mut(value) }
+	if let Some(ref mut value) = node.fed_now_request_for_payment_cancellation_request { visitor.visit_fed_now_request_for_payment_cancellation_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_return_request { visitor.visit_fed_now_return_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_account_reporting_request { visitor.visit_fed_now_account_reporting_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_incoming_message_signature_management { visitor.visit_fed_now_incoming_message_signature_management_mut(value) }
+}
+
+pub fn walk_fed_now_incoming_message_signature_management<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowIncomingMessageSignatureManagement) {
+	if let Some(ref value) = node.ke_get_all_fed_now_active_public_keys { visitor.visit_get_all_fed_now_active_public_keys(value) }
+	if let Some(ref value) = node.ke_get_all_customer_public_keys { visitor.visit_get_all_customer_public_keys(value) }
+	if let Some(ref value) = node.ke_fed_now_message_signature_key_exchange { visitor.visit_fed_now_message_signature_key_exchange(value) }
+}
+
+pub fn walk_fed_now_incoming_message_signature_management_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowIncomingMessageSignatureManagement) {
+	if let Some(ref mut value) = node.ke_get_all_fed_now_active_public_keys { visitor.visit_get_all_fed_now_active_public_keys_mut(value) }
+	if let Some(ref mut value) = node.ke_get_all_customer_public_keys { visitor.visit_get_all_customer_public_keys_mut(value) }
+	if let Some(ref mut value) = node.ke_fed_now_message_signature_key_exchange { visitor.visit_fed_now_message_signature_key_exchange_mut(value) }
+}
+
+pub fn walk_fed_now_information_request<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowInformationRequest) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c26_document.walk(visitor);
+}
+
+pub fn walk_fed_now_information_request_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowInformationRequest) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c26_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_information_request_response<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowInformationRequestResponse) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c29_document.walk(visitor);
+}
+
+pub fn walk_fed_now_information_request_response_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowInformationRequestResponse) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c29_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_institution_credit_transfer<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowInstitutionCreditTransfer) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.p9_document.walk(visitor);
+}
+
+pub fn walk_fed_now_institution_credit_transfer_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowInstitutionCreditTransfer) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.p9_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_message_reject<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowMessageReject) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.a2_document.walk(visitor);
+}
+
+pub fn walk_fed_now_message_reject_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowMessageReject) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.a2_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_outgoing_message<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowOutgoingMessage) {
+	if let Some(ref value) = node.fed_now_message_reject { visitor.visit_fed_now_message_reject(value) }
+	if let Some(ref value) = node.fed_now_broadcast { visitor.visit_fed_now_broadcast(value) }
+	if let Some(ref value) = node.fed_now_receipt_acknowledgement { visitor.visit_fed_now_receipt_acknowledgement(value) }
+	if let Some(ref value) = node.fed_now_system_response { visitor.visit_fed_now_system_response(value) }
+	if let Some(ref value) = node.fed_now_participant_file { visitor.visit_fed_now_participant_file(value) }
+	if let Some(ref value) = node.fed_now_payment_status { visitor.visit_fed_now_payment_status(value) }
+	if let Some(ref value) = node.fed_now_payment_return { visitor.visit_fed_now_payment_return(value) }
+	if let Some(ref value) = node.fed_now_customer_credit_transfer { visitor.visit_fed_now_customer_credit_transfer(value) }
+	if let Some(ref value) = node.fed_now_institution_credit_transfer { visitor.visit_fed_now_institution_credit_transfer(value) }
+	if let Some(ref value) = node.fed_now_payment_status_request { visitor.visit_fed_now_payment_status_request(value) }
+	if let Some(ref value) = node.fed_now_request_for_payment { visitor.visit_fed_now_request_for_payment(value) }
+	if let Some(ref value) = node.fed_now_request_for_payment_response { visitor.visit_fed_now_request_for_payment_response(value) }
+	if let Some(ref value) = node.fed_now_information_request { visitor.visit_fed_now_information_request(value) }
+	if let Some(ref value) = node.fed_now_additional_payment_information { visitor.visit_fed_now_additional_payment_information(value) }
+	if let Some(ref value) = node.fed_now_return_request_response { visitor.visit_fed_now_return_request_response(value) }
+	if let Some(ref value) = node.fed_now_information_request_response { visitor.visit_fed_now_information_request_response(value) }
+	if let Some(ref value) = node.fed_now_account_activity_details_report { visitor.visit_fed_now_account_activity_details_report(value) }
+	if let Some(ref value) = node.fed_now_account_activity_totals_report { visitor.visit_fed_now_account_activity_totals_report(value) }
+	if let Some(ref value) = node.fed_now_account_balance_report { visitor.visit_fed_now_account_balance_report(value) }
+	if let Some(ref value) = node.account_debit_credit_notification { visitor.visit_account_debit_credit_notification(value) }
+	if let Some(ref value) = node.fed_now_request_for_payment_cancellation_request { visitor.visit_fed_now_request_for_payment_cancellation_request(value) }
+	if let Some(ref value) = node.fed_now_request_for_payment_cancellation_request_response { visitor.visit_fed_now_request_for_payment_cancellation_request_response(value) }
+	if let Some(ref value) = node.fed_now_return_request { visitor.visit_fed_now_return_request(value) }
+	if let Some(ref value) = node.fed_now_outgoing_message_signature_management { visitor.visit_fed_now_outgoing_message_signature_management(value) }
+}
+
+pub fn walk_fed_now_outgoing_message_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowOutgoingMessage) {
+	if let Some(ref mut value) = node.fed_now_message_reject { visitor.visit_fed_now_message_reject_mut(value) }
+	if let Some(ref mut value) = node.fed_now_broadcast { visitor.visit_fed_now_broadcast_mut(value) }
+	if let Some(ref mut value) = node.fed_now_receipt_acknowledgement { visitor.visit_fed_now_receipt_acknowledgement_mut(value) }
+	if let Some(ref mut value) = node.fed_now_system_response { visitor.visit_fed_now_system_response_mut(value) }
+	if let Some(ref mut value) = node.fed_now_participant_file { visitor.visit_fed_now_participant_file_mut(value) }
+	if let Some(ref mut value) = node.fed_now_payment_status { visitor.visit_fed_now_payment_status_mut(value) }
+	if let Some(ref mut value) = node.fed_now_payment_return { visitor.visit_fed_now_payment_return_mut(value) }
+	if let Some(ref mut value) = node.fed_now_customer_credit_transfer { visitor.visit_fed_now_customer_credit_transfer_mut(value) }
+	if let Some(ref mut value) = node.fed_now_institution_credit_transfer { visitor.visit_fed_now_institution_credit_transfer_mut(value) }
+	if let Some(ref mut value) = node.fed_now_payment_status_request { visitor.visit_fed_now_payment_status_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_request_for_payment { visitor.visit_fed_now_request_for_payment_mut(value) }
+	if let Some(ref mut value) = node.fed_now_request_for_payment_response { visitor.visit_fed_now_request_for_payment_response_mut(value) }
+	if let Some(ref mut value) = node.fed_now_information_request { visitor.visit_fed_now_information_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_additional_payment_information { visitor.visit_fed_now_additional_payment_information_mut(value) }
+	if let Some(ref mut value) = node.fed_now_return_request_response { visitor.visit_fed_now_return_request_response_mut(value) }
+	if let Some(ref mut value) = node.fed_now_information_request_response { visitor.visit_fed_now_information_request_response_mut(value) }
+	if let Some(ref mut value) = node.fed_now_account_activity_details_report { visitor.visit_fed_now_account_activity_details_report_mut(value) }
+	if let Some(ref mut value) = node.fed_now_account_activity_totals_report { visitor.visit_fed_now_account_activity_totals_report_mut(value) }
+	if let Some(ref mut value) = node.fed_now_account_balance_report { visitor.visit_fed_now_account_balance_report_mut(value) }
+	if let Some(ref mut value) = node.account_debit_credit_notification { visitor.visit_account_debit_credit_notification_mut(value) }
+	if let Some(ref mut value) = node.fed_now_request_for_payment_cancellation_request { visitor.visit_fed_now_request_for_payment_cancellation_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_request_for_payment_cancellation_request_response { visitor.visit_fed_now_request_for_payment_cancellation_request_response_mut(value) }
+	if let Some(ref mut value) = node.fed_now_return_request { visitor.visit_fed_now_return_request_mut(value) }
+	if let Some(ref mut value) = node.fed_now_outgoing_message_signature_management { visitor.visit_fed_now_outgoing_message_signature_management_mut(value) }
+}
+
+pub fn walk_fed_now_outgoing_message_signature_management<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowOutgoingMessageSignatureManagement) {
+	if let Some(ref value) = node.ke_fed_now_public_key_responses { visitor.visit_fed_now_public_key_responses(value) }
+	if let Some(ref value) = node.ke_fed_now_customer_message_signature_key_operation_response { visitor.visit_fed_now_customer_message_signature_key_operation_response(value) }
+}
+
+pub fn walk_fed_now_outgoing_message_signature_management_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowOutgoingMessageSignatureManagement) {
+	if let Some(ref mut value) = node.ke_fed_now_public_key_responses { visitor.visit_fed_now_public_key_responses_mut(value) }
+	if let Some(ref mut value) = node.ke_fed_now_customer_message_signature_key_operation_response { visitor.visit_fed_now_customer_message_signature_key_operation_response_mut(value) }
+}
+
+pub fn walk_fed_now_participant_broadcast<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowParticipantBroadcast) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.a4_document.walk(visitor);
+}
+
+pub fn walk_fed_now_participant_broadcast_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowParticipantBroadcast) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.a4_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_participant_file<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowParticipantFile) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.a998_document.walk(visitor);
+}
+
+pub fn walk_fed_now_participant_file_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowParticipantFile) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.a998_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_payment_return<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowPaymentReturn) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.p4_document.walk(visitor);
+}
+
+pub fn walk_fed_now_payment_return_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowPaymentReturn) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.p4_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_payment_status<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowPaymentStatus) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.p2_document.walk(visitor);
+}
+
+pub fn walk_fed_now_payment_status_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowPaymentStatus) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.p2_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_payment_status_request<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowPaymentStatusRequest) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.p28_document.walk(visitor);
+}
+
+pub fn walk_fed_now_payment_status_request_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowPaymentStatusRequest) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.p28_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_receipt_acknowledgement<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowReceiptAcknowledgement) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.a7_document.walk(visitor);
+}
+
+pub fn walk_fed_now_receipt_acknowledgement_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowReceiptAcknowledgement) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.a7_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_request_for_payment<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowRequestForPayment) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.pain13_document.walk(visitor);
+}
+
+pub fn walk_fed_now_request_for_payment_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowRequestForPayment) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.pain13_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_request_for_payment_cancellation_request<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowRequestForPaymentCancellationRequest) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c55_document.walk(visitor);
+}
+
+pub fn walk_fed_now_request_for_payment_cancellation_request_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowRequestForPaymentCancellationRequest) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c55_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_request_for_payment_cancellation_request_response<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowRequestForPaymentCancellationRequestResponse) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c29_document.walk(visitor);
+}
+
+pub fn walk_fed_now_request_for_payment_cancellation_request_response_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowRequestForPaymentCancellationRequestResponse) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c29_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_request_for_payment_response<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowRequestForPaymentResponse) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.pain14_document.walk(visitor);
+}
+
+pub fn walk_fed_now_request_for_payment_response_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowRequestForPaymentResponse) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.pain14_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_retrieval_request<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowRetrievalRequest) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.a6_document.walk(visitor);
+}
+
+pub fn walk_fed_now_retrieval_request_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowRetrievalRequest) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.a6_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_return_request<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowReturnRequest) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c56_document.walk(visitor);
+}
+
+pub fn walk_fed_now_return_request_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowReturnRequest) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c56_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_return_request_response<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowReturnRequestResponse) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.c29_document.walk(visitor);
+}
+
+pub fn walk_fed_now_return_request_response_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowReturnRequestResponse) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.c29_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_system_response<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowSystemResponse) {
+	visitor.visit_business_application_header_v02(&node.bah_app_hdr);
+	node.a11_document.walk(visitor);
+}
+
+pub fn walk_fed_now_system_response_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowSystemResponse) {
+	visitor.visit_business_application_header_v02_mut(&mut node.bah_app_hdr);
+	node.a11_document.walk_mut(visitor);
+}
+
+pub fn walk_fed_now_technical_header<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::FedNowTechnicalHeader) {
+}
+
+pub fn walk_fed_now_technical_header_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::FedNowTechnicalHeader) {
+}
+
+pub fn walk_account_identification4_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AccountIdentification4Choice) {
+	if let Some(ref value) = node.othr { visitor.visit_generic_account_identification1(value) }
+}
+
+pub fn walk_account_identification4_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AccountIdentification4Choice) {
+	if let Some(ref mut value) = node.othr { visitor.visit_generic_account_identification1_mut(value) }
+}
+
+pub fn walk_account_interest4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AccountInterest4) {
+	if let Some(ref value) = node.tp { visitor.visit_interest_type1_choice(value) }
+	if let Some(ref vec) = node.rate { for item in vec { visitor.visit_rate4(item) } }
+	if let Some(ref value) = node.fr_to_dt { visitor.visit_date_time_period1(value) }
+	if let Some(ref value) = node.tax { visitor.visit_tax_charges2(value) }
+}
+
+pub fn walk_account_interest4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AccountInterest4) {
+	if let Some(ref mut value) = node.tp { visitor.visit_interest_type1_choice_mut(value) }
+	if let Some(ref mut vec) = node.rate { for item in vec { visitor.visit_rate4_mut(item) } }
+	if let Some(ref mut value) = node.fr_to_dt { visitor.visit_date_time_period1_mut(value) }
+	if let Some(ref mut value) = node.tax { visitor.visit_tax_charges2_mut(value) }
+}
+
+pub fn walk_account_notification17<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AccountNotification17) {
+	if let Some(ref value) = node.ntfctn_pgntn { visitor.visit_pagination1(value) }
+	if let Some(ref value) = node.rptg_seq { visitor.visit_sequence_range1_choice(value) }
+	if let Some(ref value) = node.fr_to_dt { visitor.visit_date_time_period1(value) }
+	if let Some(ref value) = node.cpy_dplct_ind { visitor.visit_copy_duplicate1_code(value) }
+	if let Some(ref value) = node.rptg_src { visitor.visit_reporting_source1_choice(value) }
+	visitor.visit_cash_account39(&node.acct);
+	if let Some(ref value) = node.rltd_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref vec) = node.intrst { for item in vec { visitor.visit_account_interest4(item) } }
+	if let Some(ref value) = node.txs_summry { visitor.visit_total_transactions6(value) }
+	if let Some(ref vec) = node.ntry { for item in vec { visitor.visit_report_entry10(item) } }
+}
+
+pub fn walk_account_notification17_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AccountNotification17) {
+	if let Some(ref mut value) = node.ntfctn_pgntn { visitor.visit_pagination1_mut(value) }
+	if let Some(ref mut value) = node.rptg_seq { visitor.visit_sequence_range1_choice_mut(value) }
+	if let Some(ref mut value) = node.fr_to_dt { visitor.visit_date_time_period1_mut(value) }
+	if let Some(ref mut value) = node.cpy_dplct_ind { visitor.visit_copy_duplicate1_code_mut(value) }
+	if let Some(ref mut value) = node.rptg_src { visitor.visit_reporting_source1_choice_mut(value) }
+	visitor.visit_cash_account39_mut(&mut node.acct);
+	if let Some(ref mut value) = node.rltd_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut vec) = node.intrst { for item in vec { visitor.visit_account_interest4_mut(item) } }
+	if let Some(ref mut value) = node.txs_summry { visitor.visit_total_transactions6_mut(value) }
+	if let Some(ref mut vec) = node.ntry { for item in vec { visitor.visit_report_entry10_mut(item) } }
+}
+
+pub fn walk_account_report25<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AccountReport25) {
+	if let Some(ref value) = node.rpt_pgntn { visitor.visit_pagination1(value) }
+	if let Some(ref value) = node.rptg_seq { visitor.visit_sequence_range1_choice(value) }
+	if let Some(ref value) = node.fr_to_dt { visitor.visit_date_time_period1(value) }
+	if let Some(ref value) = node.cpy_dplct_ind { visitor.visit_copy_duplicate1_code(value) }
+	if let Some(ref value) = node.rptg_src { visitor.visit_reporting_source1_choice(value) }
+	visitor.visit_cash_account39(&node.acct);
+	if let Some(ref value) = node.rltd_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref vec) = node.intrst { for item in vec { visitor.visit_account_interest4(item) } }
+	if let Some(ref vec) = node.bal { for item in vec { visitor.visit_cash_balance8(item) } }
+	if let Some(ref value) = node.txs_summry { visitor.visit_total_transactions6(value) }
+	if let Some(ref vec) = node.ntry { for item in vec { visitor.visit_report_entry10(item) } }
+}
+
+pub fn walk_account_report25_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AccountReport25) {
+	if let Some(ref mut value) = node.rpt_pgntn { visitor.visit_pagination1_mut(value) }
+	if let Some(ref mut value) = node.rptg_seq { visitor.visit_sequence_range1_choice_mut(value) }
+	if let Some(ref mut value) = node.fr_to_dt { visitor.visit_date_time_period1_mut(value) }
+	if let Some(ref mut value) = node.cpy_dplct_ind { visitor.visit_copy_duplicate1_code_mut(value) }
+	if let Some(ref mut value) = node.rptg_src { visitor.visit_reporting_source1_choice_mut(value) }
+	visitor.visit_cash_account39_mut(&mut node.acct);
+	if let Some(ref mut value) = node.rltd_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut vec) = node.intrst { for item in vec { visitor.visit_account_interest4_mut(item) } }
+	if let Some(ref mut vec) = node.bal { for item in vec { visitor.visit_cash_balance8_mut(item) } }
+	if let Some(ref mut value) = node.txs_summry { visitor.visit_total_transactions6_mut(value) }
+	if let Some(ref mut vec) = node.ntry { for item in vec { visitor.visit_report_entry10_mut(item) } }
+}
+
+pub fn walk_account_scheme_name1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::AccountSchemeName1Choice) {
+}
+
+pub fn walk_account_scheme_name1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::AccountSchemeName1Choice) {
+}
+
+pub fn walk_active_currency_and_amount<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ActiveCurrencyAndAmount) {
+}
+
+pub fn walk_active_currency_and_amount_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ActiveCurrencyAndAmount) {
+}
+
+pub fn walk_active_or_historic_currency_and13_decimal_amount<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ActiveOrHistoricCurrencyAnd13DecimalAmount) {
+}
+
+pub fn walk_active_or_historic_currency_and13_decimal_amount_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ActiveOrHistoricCurrencyAnd13DecimalAmount) {
+}
+
+pub fn walk_active_or_historic_currency_and_amount<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ActiveOrHistoricCurrencyAndAmount) {
+}
+
+pub fn walk_active_or_historic_currency_and_amount_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ActiveOrHistoricCurrencyAndAmount) {
+}
+
+pub fn walk_active_or_historic_currency_and_amount_range2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ActiveOrHistoricCurrencyAndAmountRange2) {
+	visitor.visit_implied_currency_amount_range1_choice(&node.amt);
+	if let Some(ref value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code(value) }
+}
+
+pub fn walk_active_or_historic_currency_and_amount_range2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ActiveOrHistoricCurrencyAndAmountRange2) {
+	visitor.visit_implied_currency_amount_range1_choice_mut(&mut node.amt);
+	if let Some(ref mut value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code_mut(value) }
+}
+
+pub fn walk_address_type2_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::AddressType2Code) {
+}
+
+pub fn walk_address_type2_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::AddressType2Code) {
+}
+
+pub fn walk_address_type3_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AddressType3Choice) {
+	if let Some(ref value) = node.cd { visitor.visit_address_type2_code(value) }
+	if let Some(ref value) = node.prtry { visitor.visit_generic_identification30(value) }
+}
+
+pub fn walk_address_type3_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AddressType3Choice) {
+	if let Some(ref mut value) = node.cd { visitor.visit_address_type2_code_mut(value) }
+	if let Some(ref mut value) = node.prtry { visitor.visit_generic_identification30_mut(value) }
+}
+
+pub fn walk_amendment_information_details13<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AmendmentInformationDetails13) {
+	if let Some(ref value) = node.orgnl_cdtr_schme_id { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.orgnl_cdtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.orgnl_cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.orgnl_dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.orgnl_dbtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.orgnl_dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.orgnl_dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.orgnl_frqcy { visitor.visit_frequency36_choice(value) }
+	if let Some(ref value) = node.orgnl_rsn { visitor.visit_mandate_setup_reason1_choice(value) }
+}
+
+pub fn walk_amendment_information_details13_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AmendmentInformationDetails13) {
+	if let Some(ref mut value) = node.orgnl_cdtr_schme_id { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.orgnl_cdtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.orgnl_cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.orgnl_dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.orgnl_dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.orgnl_dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.orgnl_dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.orgnl_frqcy { visitor.visit_frequency36_choice_mut(value) }
+	if let Some(ref mut value) = node.orgnl_rsn { visitor.visit_mandate_setup_reason1_choice_mut(value) }
+}
+
+pub fn walk_amount_and_currency_exchange3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AmountAndCurrencyExchange3) {
+	if let Some(ref value) = node.instd_amt { visitor.visit_amount_and_currency_exchange_details3(value) }
+	if let Some(ref value) = node.tx_amt { visitor.visit_amount_and_currency_exchange_details3(value) }
+	if let Some(ref value) = node.cntr_val_amt { visitor.visit_amount_and_currency_exchange_details3(value) }
+	if let Some(ref value) = node.anncd_pstng_amt { visitor.visit_amount_and_currency_exchange_details3(value) }
+	if let Some(ref vec) = node.prtry_amt { for item in vec { visitor.visit_amount_and_currency_exchange_details4(item) } }
+}
+
+pub fn walk_amount_and_currency_exchange3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AmountAndCurrencyExchange3) {
+	if let Some(ref mut value) = node.instd_amt { visitor.visit_amount_and_currency_exchange_details3_mut(value) }
+	if let Some(ref mut value) = node.tx_amt { visitor.visit_amount_and_currency_exchange_details3_mut(value) }
+	if let Some(ref mut value) = node.cntr_val_amt { visitor.visit_amount_and_currency_exchange_details3_mut(value) }
+	if let Some(ref mut value) = node.anncd_pstng_amt { visitor.visit_amount_and_currency_exchange_details3_mut(value) }
+	if let Some(ref mut vec) = node.prtry_amt { for item in vec { visitor.visit_amount_and_currency_exchange_details4_mut(item) } }
+}
+
+pub fn walk_amount_and_currency_exchange_details3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AmountAndCurrencyExchangeDetails3) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	if let Some(ref value) = node.ccy_xchg { visitor.visit_currency_exchange5(value) }
+}
+
+pub fn walk_amount_and_currency_exchange_details3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AmountAndCurrencyExchangeDetails3) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	if let Some(ref mut value) = node.ccy_xchg { visitor.visit_currency_exchange5_mut(value) }
+}
+
+pub fn walk_amount_and_currency_exchange_details4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AmountAndCurrencyExchangeDetails4) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	if let Some(ref value) = node.ccy_xchg { visitor.visit_currency_exchange5(value) }
+}
+
+pub fn walk_amount_and_currency_exchange_details4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AmountAndCurrencyExchangeDetails4) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	if let Some(ref mut value) = node.ccy_xchg { visitor.visit_currency_exchange5_mut(value) }
+}
+
+pub fn walk_amount_and_direction35<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AmountAndDirection35) {
+	visitor.visit_credit_debit_code(&node.cdt_dbt_ind);
+}
+
+pub fn walk_amount_and_direction35_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AmountAndDirection35) {
+	visitor.visit_credit_debit_code_mut(&mut node.cdt_dbt_ind);
+}
+
+pub fn walk_amount_or_rate1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AmountOrRate1Choice) {
+	if let Some(ref value) = node.amt { visitor.visit_active_currency_and_amount(value) }
+}
+
+pub fn walk_amount_or_rate1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AmountOrRate1Choice) {
+	if let Some(ref mut value) = node.amt { visitor.visit_active_currency_and_amount_mut(value) }
+}
+
+pub fn walk_amount_range_boundary1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::AmountRangeBoundary1) {
+}
+
+pub fn walk_amount_range_boundary1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::AmountRangeBoundary1) {
+}
+
+pub fn walk_amount_type4_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::AmountType4Choice) {
+	if let Some(ref value) = node.instd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.eqvt_amt { visitor.visit_equivalent_amount2(value) }
+}
+
+pub fn walk_amount_type4_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::AmountType4Choice) {
+	if let Some(ref mut value) = node.instd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.eqvt_amt { visitor.visit_equivalent_amount2_mut(value) }
+}
+
+pub fn walk_attendance_context1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::AttendanceContext1Code) {
+}
+
+pub fn walk_attendance_context1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::AttendanceContext1Code) {
+}
+
+pub fn walk_authentication_entity1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::AuthenticationEntity1Code) {
+}
+
+pub fn walk_authentication_entity1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::AuthenticationEntity1Code) {
+}
+
+pub fn walk_authentication_method1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::AuthenticationMethod1Code) {
+}
+
+pub fn walk_authentication_method1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::AuthenticationMethod1Code) {
+}
+
+pub fn walk_authorisation1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Authorisation1Choice) {
+	if let Some(ref value) = node.cd { visitor.visit_authorisation1_code(value) }
+}
+
+pub fn walk_authorisation1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Authorisation1Choice) {
+	if let Some(ref mut value) = node.cd { visitor.visit_authorisation1_code_mut(value) }
+}
+
+pub fn walk_authorisation1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Authorisation1Code) {
+}
+
+pub fn walk_authorisation1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Authorisation1Code) {
+}
+
+pub fn walk_balance_sub_type1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::BalanceSubType1Choice) {
+}
+
+pub fn walk_balance_sub_type1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::BalanceSubType1Choice) {
+}
+
+pub fn walk_balance_type10_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::BalanceType10Choice) {
+}
+
+pub fn walk_balance_type10_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::BalanceType10Choice) {
+}
+
+pub fn walk_balance_type13<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::BalanceType13) {
+	visitor.visit_balance_type10_choice(&node.cd_or_prtry);
+	if let Some(ref value) = node.sub_tp { visitor.visit_balance_sub_type1_choice(value) }
+}
+
+pub fn walk_balance_type13_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::BalanceType13) {
+	visitor.visit_balance_type10_choice_mut(&mut node.cd_or_prtry);
+	if let Some(ref mut value) = node.sub_tp { visitor.visit_balance_sub_type1_choice_mut(value) }
+}
+
+pub fn walk_bank_transaction_code_structure4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::BankTransactionCodeStructure4) {
+	if let Some(ref value) = node.domn { visitor.visit_bank_transaction_code_structure5(value) }
+	if let Some(ref value) = node.prtry { visitor.visit_proprietary_bank_transaction_code_structure1(value) }
+}
+
+pub fn walk_bank_transaction_code_structure4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::BankTransactionCodeStructure4) {
+	if let Some(ref mut value) = node.domn { visitor.visit_bank_transaction_code_structure5_mut(value) }
+	if let Some(ref mut value) = node.prtry { visitor.visit_proprietary_bank_transaction_code_structure1_mut(value) }
+}
+
+pub fn walk_bank_transaction_code_structure5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::BankTransactionCodeStructure5) {
+	visitor.visit_bank_transaction_code_structure6(&node.fmly);
+}
+
+pub fn walk_bank_transaction_code_structure5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::BankTransactionCodeStructure5) {
+	visitor.visit_bank_transaction_code_structure6_mut(&mut node.fmly);
+}
+
+pub fn walk_bank_transaction_code_structure6<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::BankTransactionCodeStructure6) {
+}
+
+pub fn walk_bank_transaction_code_structure6_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::BankTransactionCodeStructure6) {
+}
+
+pub fn walk_batch_information2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::BatchInformation2) {
+	if let Some(ref value) = node.ttl_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code(value) }
+}
+
+pub fn walk_batch_information2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::BatchInformation2) {
+	if let Some(ref mut value) = node.ttl_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code_mut(value) }
+}
+
+pub fn walk_branch_and_financial_institution_identification6<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::BranchAndFinancialInstitutionIdentification6) {
+	visitor.visit_financial_institution_identification18(&node.fin_instn_id);
+	if let Some(ref value) = node.brnch_id { visitor.visit_branch_data3(value) }
+}
+
+pub fn walk_branch_and_financial_institution_identification6_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::BranchAndFinancialInstitutionIdentification6) {
+	visitor.visit_financial_institution_identification18_mut(&mut node.fin_instn_id);
+	if let Some(ref mut value) = node.brnch_id { visitor.visit_branch_data3_mut(value) }
+}
+
+pub fn walk_branch_data3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::BranchData3) {
+	if let Some(ref value) = node.pstl_adr { visitor.visit_postal_address24(value) }
+}
+
+pub fn walk_branch_data3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::BranchData3) {
+	if let Some(ref mut value) = node.pstl_adr { visitor.visit_postal_address24_mut(value) }
+}
+
+pub fn walk_business_application_header5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::BusinessApplicationHeader5) {
+	visitor.visit_party44_choice(&node.fr);
+	visitor.visit_party44_choice(&node.to);
+	if let Some(ref value) = node.cpy_dplct { visitor.visit_copy_duplicate1_code(value) }
+	if let Some(ref value) = node.sgntr { visitor.visit_signature_envelope(value) }
+}
+
+pub fn walk_business_application_header5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::BusinessApplicationHeader5) {
+	visitor.visit_party44_choice_mut(&mut node.fr);
+	visitor.visit_party44_choice_mut(&mut node.to);
+	if let Some(ref mut value) = node.cpy_dplct { visitor.visit_copy_duplicate1_code_mut(value) }
+	if let Some(ref mut value) = node.sgntr { visitor.visit_signature_envelope_mut(value) }
+}
+
+pub fn walk_business_application_header_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::BusinessApplicationHeaderV02) {
+	visitor.visit_party44_choice(&node.fr);
+	visitor.visit_party44_choice(&node.to);
+	if let Some(ref value) = node.mkt_prctc { visitor.visit_implementation_specification1(value) }
+	if let Some(ref value) = node.cpy_dplct { visitor.visit_copy_duplicate1_code(value) }
+	if let Some(ref value) = node.sgntr { visitor.visit_signature_envelope(value) }
+	if let Some(ref vec) = node.rltd { for item in vec { visitor.visit_business_application_header5(item) } }
+}
+
+pub fn walk_business_application_header_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::BusinessApplicationHeaderV02) {
+	visitor.visit_party44_choice_mut(&mut node.fr);
+	visitor.visit_party44_choice_mut(&mut node.to);
+	if let Some(ref mut value) = node.mkt_prctc { visitor.visit_implementation_specification1_mut(value) }
+	if let Some(ref mut value) = node.cpy_dplct { visitor.visit_copy_duplicate1_code_mut(value) }
+	if let Some(ref mut value) = node.sgntr { visitor.visit_signature_envelope_mut(value) }
+	if let Some(ref mut vec) = node.rltd { for item in vec { visitor.visit_business_application_header5_mut(item) } }
+}
+
+pub fn walk_csc_management1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CSCManagement1Code) {
+}
+
+pub fn walk_csc_management1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CSCManagement1Code) {
+}
+
+pub fn walk_cancellation_individual_status1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CancellationIndividualStatus1Code) {
+}
+
+pub fn walk_cancellation_individual_status1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CancellationIndividualStatus1Code) {
+}
+
+pub fn walk_cancellation_reason33_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CancellationReason33Choice) {
+}
+
+pub fn walk_cancellation_reason33_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CancellationReason33Choice) {
+}
+
+pub fn walk_cancellation_status_reason3_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CancellationStatusReason3Choice) {
+}
+
+pub fn walk_cancellation_status_reason3_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CancellationStatusReason3Choice) {
+}
+
+pub fn walk_cancellation_status_reason4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CancellationStatusReason4) {
+	if let Some(ref value) = node.orgtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.rsn { visitor.visit_cancellation_status_reason3_choice(value) }
+}
+
+pub fn walk_cancellation_status_reason4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CancellationStatusReason4) {
+	if let Some(ref mut value) = node.orgtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.rsn { visitor.visit_cancellation_status_reason3_choice_mut(value) }
+}
+
+pub fn walk_card_aggregated2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CardAggregated2) {
+	if let Some(ref value) = node.addtl_svc { visitor.visit_card_payment_service_type2_code(value) }
+	if let Some(ref value) = node.seq_nb_rg { visitor.visit_card_sequence_number_range1(value) }
+	if let Some(ref value) = node.tx_dt_rg { visitor.visit_date_or_date_time_period1_choice(value) }
+}
+
+pub fn walk_card_aggregated2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CardAggregated2) {
+	if let Some(ref mut value) = node.addtl_svc { visitor.visit_card_payment_service_type2_code_mut(value) }
+	if let Some(ref mut value) = node.seq_nb_rg { visitor.visit_card_sequence_number_range1_mut(value) }
+	if let Some(ref mut value) = node.tx_dt_rg { visitor.visit_date_or_date_time_period1_choice_mut(value) }
+}
+
+pub fn walk_card_data_reading1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CardDataReading1Code) {
+}
+
+pub fn walk_card_data_reading1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CardDataReading1Code) {
+}
+
+pub fn walk_card_entry4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CardEntry4) {
+	if let Some(ref value) = node.card { visitor.visit_payment_card4(value) }
+	if let Some(ref value) = node.poi { visitor.visit_point_of_interaction1(value) }
+	if let Some(ref value) = node.aggtd_ntry { visitor.visit_card_aggregated2(value) }
+	if let Some(ref value) = node.pre_pd_acct { visitor.visit_cash_account38(value) }
+}
+
+pub fn walk_card_entry4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CardEntry4) {
+	if let Some(ref mut value) = node.card { visitor.visit_payment_card4_mut(value) }
+	if let Some(ref mut value) = node.poi { visitor.visit_point_of_interaction1_mut(value) }
+	if let Some(ref mut value) = node.aggtd_ntry { visitor.visit_card_aggregated2_mut(value) }
+	if let Some(ref mut value) = node.pre_pd_acct { visitor.visit_cash_account38_mut(value) }
+}
+
+pub fn walk_card_individual_transaction2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CardIndividualTransaction2) {
+	if let Some(ref value) = node.pmt_cntxt { visitor.visit_payment_context3(value) }
+	if let Some(ref value) = node.addtl_svc { visitor.visit_card_payment_service_type2_code(value) }
+	if let Some(ref value) = node.tx_id { visitor.visit_transaction_identifier1(value) }
+	if let Some(ref value) = node.pdct { visitor.visit_product2(value) }
+}
+
+pub fn walk_card_individual_transaction2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CardIndividualTransaction2) {
+	if let Some(ref mut value) = node.pmt_cntxt { visitor.visit_payment_context3_mut(value) }
+	if let Some(ref mut value) = node.addtl_svc { visitor.visit_card_payment_service_type2_code_mut(value) }
+	if let Some(ref mut value) = node.tx_id { visitor.visit_transaction_identifier1_mut(value) }
+	if let Some(ref mut value) = node.pdct { visitor.visit_product2_mut(value) }
+}
+
+pub fn walk_card_payment_service_type2_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CardPaymentServiceType2Code) {
+}
+
+pub fn walk_card_payment_service_type2_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CardPaymentServiceType2Code) {
+}
+
+pub fn walk_card_security_information1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CardSecurityInformation1) {
+	visitor.visit_csc_management1_code(&node.csc_mgmt);
+}
+
+pub fn walk_card_security_information1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CardSecurityInformation1) {
+	visitor.visit_csc_management1_code_mut(&mut node.csc_mgmt);
+}
+
+pub fn walk_card_sequence_number_range1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CardSequenceNumberRange1) {
+}
+
+pub fn walk_card_sequence_number_range1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CardSequenceNumberRange1) {
+}
+
+pub fn walk_card_transaction17<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CardTransaction17) {
+	if let Some(ref value) = node.card { visitor.visit_payment_card4(value) }
+	if let Some(ref value) = node.poi { visitor.visit_point_of_interaction1(value) }
+	if let Some(ref value) = node.tx { visitor.visit_card_transaction3_choice(value) }
+	if let Some(ref value) = node.pre_pd_acct { visitor.visit_cash_account38(value) }
+}
+
+pub fn walk_card_transaction17_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CardTransaction17) {
+	if let Some(ref mut value) = node.card { visitor.visit_payment_card4_mut(value) }
+	if let Some(ref mut value) = node.poi { visitor.visit_point_of_interaction1_mut(value) }
+	if let Some(ref mut value) = node.tx { visitor.visit_card_transaction3_choice_mut(value) }
+	if let Some(ref mut value) = node.pre_pd_acct { visitor.visit_cash_account38_mut(value) }
+}
+
+pub fn walk_card_transaction3_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CardTransaction3Choice) {
+	if let Some(ref value) = node.aggtd { visitor.visit_card_aggregated2(value) }
+	if let Some(ref value) = node.indv { visitor.visit_card_individual_transaction2(value) }
+}
+
+pub fn walk_card_transaction3_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CardTransaction3Choice) {
+	if let Some(ref mut value) = node.aggtd { visitor.visit_card_aggregated2_mut(value) }
+	if let Some(ref mut value) = node.indv { visitor.visit_card_individual_transaction2_mut(value) }
+}
+
+pub fn walk_cardholder_authentication2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CardholderAuthentication2) {
+	visitor.visit_authentication_method1_code(&node.authntcn_mtd);
+	visitor.visit_authentication_entity1_code(&node.authntcn_ntty);
+}
+
+pub fn walk_cardholder_authentication2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CardholderAuthentication2) {
+	visitor.visit_authentication_method1_code_mut(&mut node.authntcn_mtd);
+	visitor.visit_authentication_entity1_code_mut(&mut node.authntcn_ntty);
+}
+
+pub fn walk_cardholder_verification_capability1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CardholderVerificationCapability1Code) {
+}
+
+pub fn walk_cardholder_verification_capability1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CardholderVerificationCapability1Code) {
+}
+
+pub fn walk_case5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Case5) {
+	visitor.visit_party40_choice(&node.cretr);
+}
+
+pub fn walk_case5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Case5) {
+	visitor.visit_party40_choice_mut(&mut node.cretr);
+}
+
+pub fn walk_case_assignment5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CaseAssignment5) {
+	visitor.visit_party40_choice(&node.assgnr);
+	visitor.visit_party40_choice(&node.assgne);
+}
+
+pub fn walk_case_assignment5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CaseAssignment5) {
+	visitor.visit_party40_choice_mut(&mut node.assgnr);
+	visitor.visit_party40_choice_mut(&mut node.assgne);
+}
+
+pub fn walk_cash_account38<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CashAccount38) {
+	visitor.visit_account_identification4_choice(&node.id);
+	if let Some(ref value) = node.tp { visitor.visit_cash_account_type2_choice(value) }
+	if let Some(ref value) = node.prxy { visitor.visit_proxy_account_identification1(value) }
+}
+
+pub fn walk_cash_account38_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CashAccount38) {
+	visitor.visit_account_identification4_choice_mut(&mut node.id);
+	if let Some(ref mut value) = node.tp { visitor.visit_cash_account_type2_choice_mut(value) }
+	if let Some(ref mut value) = node.prxy { visitor.visit_proxy_account_identification1_mut(value) }
+}
+
+pub fn walk_cash_account39<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CashAccount39) {
+	visitor.visit_account_identification4_choice(&node.id);
+	if let Some(ref value) = node.tp { visitor.visit_cash_account_type2_choice(value) }
+	if let Some(ref value) = node.prxy { visitor.visit_proxy_account_identification1(value) }
+	if let Some(ref value) = node.ownr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.svcr { visitor.visit_branch_and_financial_institution_identification6(value) }
+}
+
+pub fn walk_cash_account39_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CashAccount39) {
+	visitor.visit_account_identification4_choice_mut(&mut node.id);
+	if let Some(ref mut value) = node.tp { visitor.visit_cash_account_type2_choice_mut(value) }
+	if let Some(ref mut value) = node.prxy { visitor.visit_proxy_account_identification1_mut(value) }
+	if let Some(ref mut value) = node.ownr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.svcr { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+}
+
+pub fn walk_cash_account_type2_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CashAccountType2Choice) {
+}
+
+pub fn walk_cash_account_type2_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CashAccountType2Choice) {
+}
+
+pub fn walk_cash_availability1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CashAvailability1) {
+	visitor.visit_cash_availability_date1_choice(&node.dt);
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	visitor.visit_credit_debit_code(&node.cdt_dbt_ind);
+}
+
+pub fn walk_cash_availability1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CashAvailability1) {
+	visitor.visit_cash_availability_date1_choice_mut(&mut node.dt);
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	visitor.visit_credit_debit_code_mut(&mut node.cdt_dbt_ind);
+}
+
+pub fn walk_cash_availability_date1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CashAvailabilityDate1Choice) {
+}
+
+pub fn walk_cash_availability_date1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CashAvailabilityDate1Choice) {
+}
+
+pub fn walk_cash_balance8<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CashBalance8) {
+	visitor.visit_balance_type13(&node.tp);
+	if let Some(ref vec) = node.cdt_line { for item in vec { visitor.visit_credit_line3(item) } }
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	visitor.visit_credit_debit_code(&node.cdt_dbt_ind);
+	visitor.visit_date_and_date_time2_choice(&node.dt);
+	if let Some(ref vec) = node.avlbty { for item in vec { visitor.visit_cash_availability1(item) } }
+}
+
+pub fn walk_cash_balance8_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CashBalance8) {
+	visitor.visit_balance_type13_mut(&mut node.tp);
+	if let Some(ref mut vec) = node.cdt_line { for item in vec { visitor.visit_credit_line3_mut(item) } }
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	visitor.visit_credit_debit_code_mut(&mut node.cdt_dbt_ind);
+	visitor.visit_date_and_date_time2_choice_mut(&mut node.dt);
+	if let Some(ref mut vec) = node.avlbty { for item in vec { visitor.visit_cash_availability1_mut(item) } }
+}
+
+pub fn walk_cash_deposit1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CashDeposit1) {
+	visitor.visit_active_currency_and_amount(&node.note_dnmtn);
+	visitor.visit_active_currency_and_amount(&node.amt);
+}
+
+pub fn walk_cash_deposit1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CashDeposit1) {
+	visitor.visit_active_currency_and_amount_mut(&mut node.note_dnmtn);
+	visitor.visit_active_currency_and_amount_mut(&mut node.amt);
+}
+
+pub fn walk_category_purpose1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CategoryPurpose1Choice) {
+}
+
+pub fn walk_category_purpose1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CategoryPurpose1Choice) {
+}
+
+pub fn walk_charge_bearer_type1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ChargeBearerType1Code) {
+}
+
+pub fn walk_charge_bearer_type1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ChargeBearerType1Code) {
+}
+
+pub fn walk_charge_type3_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ChargeType3Choice) {
+	if let Some(ref value) = node.prtry { visitor.visit_generic_identification3(value) }
+}
+
+pub fn walk_charge_type3_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ChargeType3Choice) {
+	if let Some(ref mut value) = node.prtry { visitor.visit_generic_identification3_mut(value) }
+}
+
+pub fn walk_charges6<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Charges6) {
+	if let Some(ref value) = node.ttl_chrgs_and_tax_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.rcrd { for item in vec { visitor.visit_charges_record3(item) } }
+}
+
+pub fn walk_charges6_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Charges6) {
+	if let Some(ref mut value) = node.ttl_chrgs_and_tax_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.rcrd { for item in vec { visitor.visit_charges_record3_mut(item) } }
+}
+
+pub fn walk_charges7<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Charges7) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	visitor.visit_branch_and_financial_institution_identification6(&node.agt);
+}
+
+pub fn walk_charges7_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Charges7) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.agt);
+}
+
+pub fn walk_charges_record3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ChargesRecord3) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	if let Some(ref value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code(value) }
+	if let Some(ref value) = node.tp { visitor.visit_charge_type3_choice(value) }
+	if let Some(ref value) = node.br { visitor.visit_charge_bearer_type1_code(value) }
+	if let Some(ref value) = node.agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.tax { visitor.visit_tax_charges2(value) }
+}
+
+pub fn walk_charges_record3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ChargesRecord3) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	if let Some(ref mut value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code_mut(value) }
+	if let Some(ref mut value) = node.tp { visitor.visit_charge_type3_choice_mut(value) }
+	if let Some(ref mut value) = node.br { visitor.visit_charge_bearer_type1_code_mut(value) }
+	if let Some(ref mut value) = node.agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.tax { visitor.visit_tax_charges2_mut(value) }
+}
+
+pub fn walk_cheque11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Cheque11) {
+	if let Some(ref value) = node.chq_tp { visitor.visit_cheque_type2_code(value) }
+	if let Some(ref value) = node.chq_fr { visitor.visit_name_and_address16(value) }
+	if let Some(ref value) = node.dlvry_mtd { visitor.visit_cheque_delivery_method1_choice(value) }
+	if let Some(ref value) = node.dlvr_to { visitor.visit_name_and_address16(value) }
+	if let Some(ref value) = node.instr_prty { visitor.visit_priority2_code(value) }
+}
+
+pub fn walk_cheque11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Cheque11) {
+	if let Some(ref mut value) = node.chq_tp { visitor.visit_cheque_type2_code_mut(value) }
+	if let Some(ref mut value) = node.chq_fr { visitor.visit_name_and_address16_mut(value) }
+	if let Some(ref mut value) = node.dlvry_mtd { visitor.visit_cheque_delivery_method1_choice_mut(value) }
+	if let Some(ref mut value) = node.dlvr_to { visitor.visit_name_and_address16_mut(value) }
+	if let Some(ref mut value) = node.instr_prty { visitor.visit_priority2_code_mut(value) }
+}
+
+pub fn walk_cheque_delivery1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ChequeDelivery1Code) {
+}
+
+pub fn walk_cheque_delivery1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ChequeDelivery1Code) {
+}
+
+pub fn walk_cheque_delivery_method1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ChequeDeliveryMethod1Choice) {
+	if let Some(ref value) = node.cd { visitor.visit_cheque_delivery1_code(value) }
+}
+
+pub fn walk_cheque_delivery_method1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ChequeDeliveryMethod1Choice) {
+	if let Some(ref mut value) = node.cd { visitor.visit_cheque_delivery1_code_mut(value) }
+}
+
+pub fn walk_cheque_type2_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ChequeType2Code) {
+}
+
+pub fn walk_cheque_type2_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ChequeType2Code) {
+}
+
+pub fn walk_claim_non_receipt2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ClaimNonReceipt2) {
+	if let Some(ref value) = node.orgnl_nxt_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+}
+
+pub fn walk_claim_non_receipt2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ClaimNonReceipt2) {
+	if let Some(ref mut value) = node.orgnl_nxt_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+}
+
+pub fn walk_claim_non_receipt2_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ClaimNonReceipt2Choice) {
+	if let Some(ref value) = node.accptd { visitor.visit_claim_non_receipt2(value) }
+	if let Some(ref value) = node.rjctd { visitor.visit_claim_non_receipt_reject_reason1_choice(value) }
+}
+
+pub fn walk_claim_non_receipt2_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ClaimNonReceipt2Choice) {
+	if let Some(ref mut value) = node.accptd { visitor.visit_claim_non_receipt2_mut(value) }
+	if let Some(ref mut value) = node.rjctd { visitor.visit_claim_non_receipt_reject_reason1_choice_mut(value) }
+}
+
+pub fn walk_claim_non_receipt_reject_reason1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ClaimNonReceiptRejectReason1Choice) {
+}
+
+pub fn walk_claim_non_receipt_reject_reason1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ClaimNonReceiptRejectReason1Choice) {
+}
+
+pub fn walk_clearing_channel2_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ClearingChannel2Code) {
+}
+
+pub fn walk_clearing_channel2_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ClearingChannel2Code) {
+}
+
+pub fn walk_clearing_system_identification2_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ClearingSystemIdentification2Choice) {
+}
+
+pub fn walk_clearing_system_identification2_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ClearingSystemIdentification2Choice) {
+}
+
+pub fn walk_clearing_system_identification3_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ClearingSystemIdentification3Choice) {
+}
+
+pub fn walk_clearing_system_identification3_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ClearingSystemIdentification3Choice) {
+}
+
+pub fn walk_clearing_system_member_identification2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ClearingSystemMemberIdentification2) {
+	if let Some(ref value) = node.clr_sys_id { visitor.visit_clearing_system_identification2_choice(value) }
+}
+
+pub fn walk_clearing_system_member_identification2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ClearingSystemMemberIdentification2) {
+	if let Some(ref mut value) = node.clr_sys_id { visitor.visit_clearing_system_identification2_choice_mut(value) }
+}
+
+pub fn walk_compensation2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Compensation2) {
+	visitor.visit_active_currency_and_amount(&node.amt);
+	visitor.visit_branch_and_financial_institution_identification6(&node.dbtr_agt);
+	visitor.visit_branch_and_financial_institution_identification6(&node.cdtr_agt);
+	visitor.visit_compensation_reason1_choice(&node.rsn);
+}
+
+pub fn walk_compensation2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Compensation2) {
+	visitor.visit_active_currency_and_amount_mut(&mut node.amt);
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.dbtr_agt);
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.cdtr_agt);
+	visitor.visit_compensation_reason1_choice_mut(&mut node.rsn);
+}
+
+pub fn walk_compensation_reason1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CompensationReason1Choice) {
+}
+
+pub fn walk_compensation_reason1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CompensationReason1Choice) {
+}
+
+pub fn walk_contact4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Contact4) {
+	if let Some(ref value) = node.nm_prfx { visitor.visit_name_prefix2_code(value) }
+	if let Some(ref vec) = node.othr { for item in vec { visitor.visit_other_contact1(item) } }
+	if let Some(ref value) = node.prefrd_mtd { visitor.visit_preferred_contact_method1_code(value) }
+}
+
+pub fn walk_contact4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Contact4) {
+	if let Some(ref mut value) = node.nm_prfx { visitor.visit_name_prefix2_code_mut(value) }
+	if let Some(ref mut vec) = node.othr { for item in vec { visitor.visit_other_contact1_mut(item) } }
+	if let Some(ref mut value) = node.prefrd_mtd { visitor.visit_preferred_contact_method1_code_mut(value) }
+}
+
+pub fn walk_control_data1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ControlData1) {
+}
+
+pub fn walk_control_data1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ControlData1) {
+}
+
+pub fn walk_copy_duplicate1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CopyDuplicate1Code) {
+}
+
+pub fn walk_copy_duplicate1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CopyDuplicate1Code) {
+}
+
+pub fn walk_corporate_action9<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CorporateAction9) {
+}
+
+pub fn walk_corporate_action9_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CorporateAction9) {
+}
+
+pub fn walk_corrective_group_information1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CorrectiveGroupInformation1) {
+}
+
+pub fn walk_corrective_group_information1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CorrectiveGroupInformation1) {
+}
+
+pub fn walk_corrective_interbank_transaction2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CorrectiveInterbankTransaction2) {
+	if let Some(ref value) = node.grp_hdr { visitor.visit_corrective_group_information1(value) }
+	visitor.visit_active_or_historic_currency_and_amount(&node.intr_bk_sttlm_amt);
+}
+
+pub fn walk_corrective_interbank_transaction2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CorrectiveInterbankTransaction2) {
+	if let Some(ref mut value) = node.grp_hdr { visitor.visit_corrective_group_information1_mut(value) }
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.intr_bk_sttlm_amt);
+}
+
+pub fn walk_corrective_payment_initiation4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CorrectivePaymentInitiation4) {
+	if let Some(ref value) = node.grp_hdr { visitor.visit_corrective_group_information1(value) }
+	visitor.visit_active_or_historic_currency_and_amount(&node.instd_amt);
+	if let Some(ref value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice(value) }
+}
+
+pub fn walk_corrective_payment_initiation4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CorrectivePaymentInitiation4) {
+	if let Some(ref mut value) = node.grp_hdr { visitor.visit_corrective_group_information1_mut(value) }
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.instd_amt);
+	if let Some(ref mut value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+}
+
+pub fn walk_corrective_transaction4_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CorrectiveTransaction4Choice) {
+	if let Some(ref value) = node.initn { visitor.visit_corrective_payment_initiation4(value) }
+	if let Some(ref value) = node.intr_bk { visitor.visit_corrective_interbank_transaction2(value) }
+}
+
+pub fn walk_corrective_transaction4_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CorrectiveTransaction4Choice) {
+	if let Some(ref mut value) = node.initn { visitor.visit_corrective_payment_initiation4_mut(value) }
+	if let Some(ref mut value) = node.intr_bk { visitor.visit_corrective_interbank_transaction2_mut(value) }
+}
+
+pub fn walk_credit_debit_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CreditDebitCode) {
+}
+
+pub fn walk_credit_debit_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CreditDebitCode) {
+}
+
+pub fn walk_credit_line3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditLine3) {
+	if let Some(ref value) = node.tp { visitor.visit_credit_line_type1_choice(value) }
+	if let Some(ref value) = node.amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.dt { visitor.visit_date_and_date_time2_choice(value) }
+}
+
+pub fn walk_credit_line3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditLine3) {
+	if let Some(ref mut value) = node.tp { visitor.visit_credit_line_type1_choice_mut(value) }
+	if let Some(ref mut value) = node.amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+}
+
+pub fn walk_credit_line_type1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CreditLineType1Choice) {
+}
+
+pub fn walk_credit_line_type1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CreditLineType1Choice) {
+}
+
+pub fn walk_credit_transfer_mandate_data1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditTransferMandateData1) {
+	if let Some(ref value) = node.tp { visitor.visit_mandate_type_information2(value) }
+	if let Some(ref value) = node.frqcy { visitor.visit_frequency36_choice(value) }
+	if let Some(ref value) = node.rsn { visitor.visit_mandate_setup_reason1_choice(value) }
+}
+
+pub fn walk_credit_transfer_mandate_data1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditTransferMandateData1) {
+	if let Some(ref mut value) = node.tp { visitor.visit_mandate_type_information2_mut(value) }
+	if let Some(ref mut value) = node.frqcy { visitor.visit_frequency36_choice_mut(value) }
+	if let Some(ref mut value) = node.rsn { visitor.visit_mandate_setup_reason1_choice_mut(value) }
+}
+
+pub fn walk_credit_transfer_transaction35<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditTransferTransaction35) {
+	visitor.visit_payment_identification6(&node.pmt_id);
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information26(value) }
+	if let Some(ref value) = node.pmt_cond { visitor.visit_payment_condition1(value) }
+	visitor.visit_amount_type4_choice(&node.amt);
+	visitor.visit_charge_bearer_type1_code(&node.chrg_br);
+	if let Some(ref value) = node.chq_instr { visitor.visit_cheque11(value) }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.cdtr_agt);
+	visitor.visit_party_identification135(&node.cdtr);
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party_identification135(value) }
+	if let Some(ref vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent1(item) } }
+	if let Some(ref value) = node.purp { visitor.visit_purpose2_choice(value) }
+	if let Some(ref vec) = node.rgltry_rptg { for item in vec { visitor.visit_regulatory_reporting3(item) } }
+	if let Some(ref value) = node.tax { visitor.visit_tax_information8(value) }
+	if let Some(ref vec) = node.rltd_rmt_inf { for item in vec { visitor.visit_remittance_location7(item) } }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+	if let Some(ref vec) = node.nclsd_file { for item in vec { visitor.visit_document12(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_credit_transfer_transaction35_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditTransferTransaction35) {
+	visitor.visit_payment_identification6_mut(&mut node.pmt_id);
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information26_mut(value) }
+	if let Some(ref mut value) = node.pmt_cond { visitor.visit_payment_condition1_mut(value) }
+	visitor.visit_amount_type4_choice_mut(&mut node.amt);
+	visitor.visit_charge_bearer_type1_code_mut(&mut node.chrg_br);
+	if let Some(ref mut value) = node.chq_instr { visitor.visit_cheque11_mut(value) }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.cdtr_agt);
+	visitor.visit_party_identification135_mut(&mut node.cdtr);
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent1_mut(item) } }
+	if let Some(ref mut value) = node.purp { visitor.visit_purpose2_choice_mut(value) }
+	if let Some(ref mut vec) = node.rgltry_rptg { for item in vec { visitor.visit_regulatory_reporting3_mut(item) } }
+	if let Some(ref mut value) = node.tax { visitor.visit_tax_information8_mut(value) }
+	if let Some(ref mut vec) = node.rltd_rmt_inf { for item in vec { visitor.visit_remittance_location7_mut(item) } }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+	if let Some(ref mut vec) = node.nclsd_file { for item in vec { visitor.visit_document12_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_credit_transfer_transaction36<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditTransferTransaction36) {
+	visitor.visit_payment_identification7(&node.pmt_id);
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information28(value) }
+	visitor.visit_active_currency_and_amount(&node.intr_bk_sttlm_amt);
+	if let Some(ref value) = node.sttlm_prty { visitor.visit_priority3_code(value) }
+	if let Some(ref value) = node.sttlm_tm_indctn { visitor.visit_settlement_date_time_indication1(value) }
+	if let Some(ref value) = node.sttlm_tm_req { visitor.visit_settlement_time_request2(value) }
+	if let Some(ref value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_branch_and_financial_institution_identification6(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.dbtr);
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.cdtr);
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent2(item) } }
+	if let Some(ref vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1(item) } }
+	if let Some(ref value) = node.purp { visitor.visit_purpose2_choice(value) }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information2(value) }
+	if let Some(ref value) = node.undrlyg_cstmr_cdt_trf { visitor.visit_credit_transfer_transaction37(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_credit_transfer_transaction36_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditTransferTransaction36) {
+	visitor.visit_payment_identification7_mut(&mut node.pmt_id);
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information28_mut(value) }
+	visitor.visit_active_currency_and_amount_mut(&mut node.intr_bk_sttlm_amt);
+	if let Some(ref mut value) = node.sttlm_prty { visitor.visit_priority3_code_mut(value) }
+	if let Some(ref mut value) = node.sttlm_tm_indctn { visitor.visit_settlement_date_time_indication1_mut(value) }
+	if let Some(ref mut value) = node.sttlm_tm_req { visitor.visit_settlement_time_request2_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.dbtr);
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.cdtr);
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent2_mut(item) } }
+	if let Some(ref mut vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1_mut(item) } }
+	if let Some(ref mut value) = node.purp { visitor.visit_purpose2_choice_mut(value) }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information2_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_cstmr_cdt_trf { visitor.visit_credit_transfer_transaction37_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_credit_transfer_transaction37<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditTransferTransaction37) {
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.initg_pty { visitor.visit_party_identification135(value) }
+	visitor.visit_party_identification135(&node.dbtr);
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.dbtr_agt);
+	if let Some(ref value) = node.dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt3_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.cdtr_agt);
+	if let Some(ref value) = node.cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_party_identification135(&node.cdtr);
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party_identification135(value) }
+	if let Some(ref vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent1(item) } }
+	if let Some(ref vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1(item) } }
+	if let Some(ref value) = node.tax { visitor.visit_tax_information8(value) }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+	if let Some(ref value) = node.instd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+}
+
+pub fn walk_credit_transfer_transaction37_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditTransferTransaction37) {
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.initg_pty { visitor.visit_party_identification135_mut(value) }
+	visitor.visit_party_identification135_mut(&mut node.dbtr);
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.dbtr_agt);
+	if let Some(ref mut value) = node.dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.cdtr_agt);
+	if let Some(ref mut value) = node.cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_party_identification135_mut(&mut node.cdtr);
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent1_mut(item) } }
+	if let Some(ref mut vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1_mut(item) } }
+	if let Some(ref mut value) = node.tax { visitor.visit_tax_information8_mut(value) }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+	if let Some(ref mut value) = node.instd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+}
+
+pub fn walk_credit_transfer_transaction39<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditTransferTransaction39) {
+	visitor.visit_payment_identification7(&node.pmt_id);
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information28(value) }
+	visitor.visit_active_currency_and_amount(&node.intr_bk_sttlm_amt);
+	if let Some(ref value) = node.sttlm_prty { visitor.visit_priority3_code(value) }
+	if let Some(ref value) = node.sttlm_tm_indctn { visitor.visit_settlement_date_time_indication1(value) }
+	if let Some(ref value) = node.sttlm_tm_req { visitor.visit_settlement_time_request2(value) }
+	if let Some(ref value) = node.instd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	visitor.visit_charge_bearer_type1_code(&node.chrg_br);
+	if let Some(ref vec) = node.chrgs_inf { for item in vec { visitor.visit_charges7(item) } }
+	if let Some(ref value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.initg_pty { visitor.visit_party_identification135(value) }
+	visitor.visit_party_identification135(&node.dbtr);
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.dbtr_agt);
+	if let Some(ref value) = node.dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.cdtr_agt);
+	if let Some(ref value) = node.cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_party_identification135(&node.cdtr);
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party_identification135(value) }
+	if let Some(ref vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent1(item) } }
+	if let Some(ref vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1(item) } }
+	if let Some(ref value) = node.purp { visitor.visit_purpose2_choice(value) }
+	if let Some(ref vec) = node.rgltry_rptg { for item in vec { visitor.visit_regulatory_reporting3(item) } }
+	if let Some(ref value) = node.tax { visitor.visit_tax_information8(value) }
+	if let Some(ref vec) = node.rltd_rmt_inf { for item in vec { visitor.visit_remittance_location7(item) } }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_credit_transfer_transaction39_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditTransferTransaction39) {
+	visitor.visit_payment_identification7_mut(&mut node.pmt_id);
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information28_mut(value) }
+	visitor.visit_active_currency_and_amount_mut(&mut node.intr_bk_sttlm_amt);
+	if let Some(ref mut value) = node.sttlm_prty { visitor.visit_priority3_code_mut(value) }
+	if let Some(ref mut value) = node.sttlm_tm_indctn { visitor.visit_settlement_date_time_indication1_mut(value) }
+	if let Some(ref mut value) = node.sttlm_tm_req { visitor.visit_settlement_time_request2_mut(value) }
+	if let Some(ref mut value) = node.instd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	visitor.visit_charge_bearer_type1_code_mut(&mut node.chrg_br);
+	if let Some(ref mut vec) = node.chrgs_inf { for item in vec { visitor.visit_charges7_mut(item) } }
+	if let Some(ref mut value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.initg_pty { visitor.visit_party_identification135_mut(value) }
+	visitor.visit_party_identification135_mut(&mut node.dbtr);
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.dbtr_agt);
+	if let Some(ref mut value) = node.dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.cdtr_agt);
+	if let Some(ref mut value) = node.cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_party_identification135_mut(&mut node.cdtr);
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent1_mut(item) } }
+	if let Some(ref mut vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1_mut(item) } }
+	if let Some(ref mut value) = node.purp { visitor.visit_purpose2_choice_mut(value) }
+	if let Some(ref mut vec) = node.rgltry_rptg { for item in vec { visitor.visit_regulatory_reporting3_mut(item) } }
+	if let Some(ref mut value) = node.tax { visitor.visit_tax_information8_mut(value) }
+	if let Some(ref mut vec) = node.rltd_rmt_inf { for item in vec { visitor.visit_remittance_location7_mut(item) } }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_credit_transfer_transaction45<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditTransferTransaction45) {
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.initg_pty { visitor.visit_party_identification135(value) }
+	visitor.visit_party_identification135(&node.dbtr);
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.dbtr_agt);
+	if let Some(ref value) = node.dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt3_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.cdtr_agt);
+	if let Some(ref value) = node.cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_party_identification135(&node.cdtr);
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party_identification135(value) }
+	if let Some(ref vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent3(item) } }
+	if let Some(ref vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1(item) } }
+	if let Some(ref value) = node.tax { visitor.visit_tax_information8(value) }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+	if let Some(ref value) = node.instd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+}
+
+pub fn walk_credit_transfer_transaction45_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditTransferTransaction45) {
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.initg_pty { visitor.visit_party_identification135_mut(value) }
+	visitor.visit_party_identification135_mut(&mut node.dbtr);
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.dbtr_agt);
+	if let Some(ref mut value) = node.dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.cdtr_agt);
+	if let Some(ref mut value) = node.cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_party_identification135_mut(&mut node.cdtr);
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent3_mut(item) } }
+	if let Some(ref mut vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1_mut(item) } }
+	if let Some(ref mut value) = node.tax { visitor.visit_tax_information8_mut(value) }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+	if let Some(ref mut value) = node.instd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+}
+
+pub fn walk_creditor_reference_information2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditorReferenceInformation2) {
+	if let Some(ref value) = node.tp { visitor.visit_creditor_reference_type2(value) }
+}
+
+pub fn walk_creditor_reference_information2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditorReferenceInformation2) {
+	if let Some(ref mut value) = node.tp { visitor.visit_creditor_reference_type2_mut(value) }
+}
+
+pub fn walk_creditor_reference_type1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditorReferenceType1Choice) {
+	if let Some(ref value) = node.cd { visitor.visit_document_type3_code(value) }
+}
+
+pub fn walk_creditor_reference_type1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditorReferenceType1Choice) {
+	if let Some(ref mut value) = node.cd { visitor.visit_document_type3_code_mut(value) }
+}
+
+pub fn walk_creditor_reference_type2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::CreditorReferenceType2) {
+	visitor.visit_creditor_reference_type1_choice(&node.cd_or_prtry);
+}
+
+pub fn walk_creditor_reference_type2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::CreditorReferenceType2) {
+	visitor.visit_creditor_reference_type1_choice_mut(&mut node.cd_or_prtry);
+}
+
+pub fn walk_currency_exchange5<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::CurrencyExchange5) {
+}
+
+pub fn walk_currency_exchange5_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::CurrencyExchange5) {
+}
+
+pub fn walk_date_and_date_time2_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::DateAndDateTime2Choice) {
+}
+
+pub fn walk_date_and_date_time2_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::DateAndDateTime2Choice) {
+}
+
+pub fn walk_date_and_place_of_birth1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::DateAndPlaceOfBirth1) {
+}
+
+pub fn walk_date_and_place_of_birth1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::DateAndPlaceOfBirth1) {
+}
+
+pub fn walk_date_or_date_time_period1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::DateOrDateTimePeriod1Choice) {
+	if let Some(ref value) = node.dt { visitor.visit_date_period2(value) }
+	if let Some(ref value) = node.dt_tm { visitor.visit_date_time_period1(value) }
+}
+
+pub fn walk_date_or_date_time_period1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::DateOrDateTimePeriod1Choice) {
+	if let Some(ref mut value) = node.dt { visitor.visit_date_period2_mut(value) }
+	if let Some(ref mut value) = node.dt_tm { visitor.visit_date_time_period1_mut(value) }
+}
+
+pub fn walk_date_period2<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::DatePeriod2) {
+}
+
+pub fn walk_date_period2_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::DatePeriod2) {
+}
+
+pub fn walk_date_period_details1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::DatePeriodDetails1) {
+}
+
+pub fn walk_date_period_details1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::DatePeriodDetails1) {
+}
+
+pub fn walk_date_time_period1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::DateTimePeriod1) {
+}
+
+pub fn walk_date_time_period1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::DateTimePeriod1) {
+}
+
+pub fn walk_discount_amount_and_type1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::DiscountAmountAndType1) {
+	if let Some(ref value) = node.tp { visitor.visit_discount_amount_type1_choice(value) }
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+}
+
+pub fn walk_discount_amount_and_type1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::DiscountAmountAndType1) {
+	if let Some(ref mut value) = node.tp { visitor.visit_discount_amount_type1_choice_mut(value) }
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+}
+
+pub fn walk_discount_amount_type1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::DiscountAmountType1Choice) {
+}
+
+pub fn walk_discount_amount_type1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::DiscountAmountType1Choice) {
+}
+
+pub fn walk_display_capabilities1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::DisplayCapabilities1) {
+	visitor.visit_user_interface2_code(&node.disp_tp);
+}
+
+pub fn walk_display_capabilities1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::DisplayCapabilities1) {
+	visitor.visit_user_interface2_code_mut(&mut node.disp_tp);
+}
+
+pub fn walk_document12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Document12) {
+	visitor.visit_document_type1_choice(&node.tp);
+	visitor.visit_date_and_date_time2_choice(&node.isse_dt);
+	visitor.visit_document_format1_choice(&node.frmt);
+	if let Some(ref value) = node.dgtl_sgntr { visitor.visit_party_and_signature3(value) }
+}
+
+pub fn walk_document12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Document12) {
+	visitor.visit_document_type1_choice_mut(&mut node.tp);
+	visitor.visit_date_and_date_time2_choice_mut(&mut node.isse_dt);
+	visitor.visit_document_format1_choice_mut(&mut node.frmt);
+	if let Some(ref mut value) = node.dgtl_sgntr { visitor.visit_party_and_signature3_mut(value) }
+}
+
+pub fn walk_document_adjustment1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::DocumentAdjustment1) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	if let Some(ref value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code(value) }
+}
+
+pub fn walk_document_adjustment1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::DocumentAdjustment1) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	if let Some(ref mut value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code_mut(value) }
+}
+
+pub fn walk_document_format1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::DocumentFormat1Choice) {
+	if let Some(ref value) = node.prtry { visitor.visit_generic_identification1(value) }
+}
+
+pub fn walk_document_format1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::DocumentFormat1Choice) {
+	if let Some(ref mut value) = node.prtry { visitor.visit_generic_identification1_mut(value) }
+}
+
+pub fn walk_document_line_identification1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::DocumentLineIdentification1) {
+	if let Some(ref value) = node.tp { visitor.visit_document_line_type1(value) }
+}
+
+pub fn walk_document_line_identification1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::DocumentLineIdentification1) {
+	if let Some(ref mut value) = node.tp { visitor.visit_document_line_type1_mut(value) }
+}
+
+pub fn walk_document_line_information1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::DocumentLineInformation1) {
+	for item in &node.id { visitor.visit_document_line_identification1(item) }
+	if let Some(ref value) = node.amt { visitor.visit_remittance_amount3(value) }
+}
+
+pub fn walk_document_line_information1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::DocumentLineInformation1) {
+	for item in &mut node.id { visitor.visit_document_line_identification1_mut(item) }
+	if let Some(ref mut value) = node.amt { visitor.visit_remittance_amount3_mut(value) }
+}
+
+pub fn walk_document_line_type1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::DocumentLineType1) {
+	visitor.visit_document_line_type1_choice(&node.cd_or_prtry);
+}
+
+pub fn walk_document_line_type1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::DocumentLineType1) {
+	visitor.visit_document_line_type1_choice_mut(&mut node.cd_or_prtry);
+}
+
+pub fn walk_document_line_type1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::DocumentLineType1Choice) {
+}
+
+pub fn walk_document_line_type1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::DocumentLineType1Choice) {
+}
+
+pub fn walk_document_type1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::DocumentType1Choice) {
+	if let Some(ref value) = node.prtry { visitor.visit_generic_identification1(value) }
+}
+
+pub fn walk_document_type1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::DocumentType1Choice) {
+	if let Some(ref mut value) = node.prtry { visitor.visit_generic_identification1_mut(value) }
+}
+
+pub fn walk_document_type3_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::DocumentType3Code) {
+}
+
+pub fn walk_document_type3_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::DocumentType3Code) {
+}
+
+pub fn walk_document_type6_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::DocumentType6Code) {
+}
+
+pub fn walk_document_type6_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::DocumentType6Code) {
+}
+
+pub fn walk_entry_details9<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::EntryDetails9) {
+	if let Some(ref value) = node.btch { visitor.visit_batch_information2(value) }
+	if let Some(ref vec) = node.tx_dtls { for item in vec { visitor.visit_entry_transaction10(item) } }
+}
+
+pub fn walk_entry_details9_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::EntryDetails9) {
+	if let Some(ref mut value) = node.btch { visitor.visit_batch_information2_mut(value) }
+	if let Some(ref mut vec) = node.tx_dtls { for item in vec { visitor.visit_entry_transaction10_mut(item) } }
+}
+
+pub fn walk_entry_status1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::EntryStatus1Choice) {
+}
+
+pub fn walk_entry_status1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::EntryStatus1Choice) {
+}
+
+pub fn walk_entry_transaction10<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::EntryTransaction10) {
+	if let Some(ref value) = node.refs { visitor.visit_transaction_references6(value) }
+	if let Some(ref value) = node.amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code(value) }
+	if let Some(ref value) = node.amt_dtls { visitor.visit_amount_and_currency_exchange3(value) }
+	if let Some(ref vec) = node.avlbty { for item in vec { visitor.visit_cash_availability1(item) } }
+	if let Some(ref value) = node.bk_tx_cd { visitor.visit_bank_transaction_code_structure4(value) }
+	if let Some(ref value) = node.chrgs { visitor.visit_charges6(value) }
+	if let Some(ref value) = node.intrst { visitor.visit_transaction_interest4(value) }
+	if let Some(ref value) = node.rltd_pties { visitor.visit_transaction_parties6(value) }
+	if let Some(ref value) = node.rltd_agts { visitor.visit_transaction_agents5(value) }
+	if let Some(ref value) = node.lcl_instrm { visitor.visit_local_instrument2_choice(value) }
+	if let Some(ref value) = node.purp { visitor.visit_purpose2_choice(value) }
+	if let Some(ref vec) = node.rltd_rmt_inf { for item in vec { visitor.visit_remittance_location7(item) } }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+	if let Some(ref value) = node.rltd_dts { visitor.visit_transaction_dates3(value) }
+	if let Some(ref value) = node.rltd_pric { visitor.visit_transaction_price4_choice(value) }
+	if let Some(ref vec) = node.rltd_qties { for item in vec { visitor.visit_transaction_quantities3_choice(item) } }
+	if let Some(ref value) = node.fin_instrm_id { visitor.visit_security_identification19(value) }
+	if let Some(ref value) = node.tax { visitor.visit_tax_information8(value) }
+	if let Some(ref value) = node.rtr_inf { visitor.visit_payment_return_reason5(value) }
+	if let Some(ref value) = node.corp_actn { visitor.visit_corporate_action9(value) }
+	if let Some(ref value) = node.sfkpg_acct { visitor.visit_securities_account19(value) }
+	if let Some(ref vec) = node.csh_dpst { for item in vec { visitor.visit_cash_deposit1(item) } }
+	if let Some(ref value) = node.card_tx { visitor.visit_card_transaction17(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_entry_transaction10_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::EntryTransaction10) {
+	if let Some(ref mut value) = node.refs { visitor.visit_transaction_references6_mut(value) }
+	if let Some(ref mut value) = node.amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.cdt_dbt_ind { visitor.visit_credit_debit_code_mut(value) }
+	if let Some(ref mut value) = node.amt_dtls { visitor.visit_amount_and_currency_exchange3_mut(value) }
+	if let Some(ref mut vec) = node.avlbty { for item in vec { visitor.visit_cash_availability1_mut(item) } }
+	if let Some(ref mut value) = node.bk_tx_cd { visitor.visit_bank_transaction_code_structure4_mut(value) }
+	if let Some(ref mut value) = node.chrgs { visitor.visit_charges6_mut(value) }
+	if let Some(ref mut value) = node.intrst { visitor.visit_transaction_interest4_mut(value) }
+	if let Some(ref mut value) = node.rltd_pties { visitor.visit_transaction_parties6_mut(value) }
+	if let Some(ref mut value) = node.rltd_agts { visitor.visit_transaction_agents5_mut(value) }
+	if let Some(ref mut value) = node.lcl_instrm { visitor.visit_local_instrument2_choice_mut(value) }
+	if let Some(ref mut value) = node.purp { visitor.visit_purpose2_choice_mut(value) }
+	if let Some(ref mut vec) = node.rltd_rmt_inf { for item in vec { visitor.visit_remittance_location7_mut(item) } }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+	if let Some(ref mut value) = node.rltd_dts { visitor.visit_transaction_dates3_mut(value) }
+	if let Some(ref mut value) = node.rltd_pric { visitor.visit_transaction_price4_choice_mut(value) }
+	if let Some(ref mut vec) = node.rltd_qties { for item in vec { visitor.visit_transaction_quantities3_choice_mut(item) } }
+	if let Some(ref mut value) = node.fin_instrm_id { visitor.visit_security_identification19_mut(value) }
+	if let Some(ref mut value) = node.tax { visitor.visit_tax_information8_mut(value) }
+	if let Some(ref mut value) = node.rtr_inf { visitor.visit_payment_return_reason5_mut(value) }
+	if let Some(ref mut value) = node.corp_actn { visitor.visit_corporate_action9_mut(value) }
+	if let Some(ref mut value) = node.sfkpg_acct { visitor.visit_securities_account19_mut(value) }
+	if let Some(ref mut vec) = node.csh_dpst { for item in vec { visitor.visit_cash_deposit1_mut(item) } }
+	if let Some(ref mut value) = node.card_tx { visitor.visit_card_transaction17_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_equivalent_amount2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::EquivalentAmount2) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+}
+
+pub fn walk_equivalent_amount2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::EquivalentAmount2) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+}
+
+pub fn walk_event1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Event1) {
+}
+
+pub fn walk_event1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Event1) {
+}
+
+pub fn walk_event2<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Event2) {
+}
+
+pub fn walk_event2_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Event2) {
+}
+
+pub fn walk_financial_identification_scheme_name1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::FinancialIdentificationSchemeName1Choice) {
+}
+
+pub fn walk_financial_identification_scheme_name1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::FinancialIdentificationSchemeName1Choice) {
+}
+
+pub fn walk_financial_institution_identification18<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FinancialInstitutionIdentification18) {
+	if let Some(ref value) = node.clr_sys_mmb_id { visitor.visit_clearing_system_member_identification2(value) }
+	if let Some(ref value) = node.pstl_adr { visitor.visit_postal_address24(value) }
+	if let Some(ref value) = node.othr { visitor.visit_generic_financial_identification1(value) }
+}
+
+pub fn walk_financial_institution_identification18_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FinancialInstitutionIdentification18) {
+	if let Some(ref mut value) = node.clr_sys_mmb_id { visitor.visit_clearing_system_member_identification2_mut(value) }
+	if let Some(ref mut value) = node.pstl_adr { visitor.visit_postal_address24_mut(value) }
+	if let Some(ref mut value) = node.othr { visitor.visit_generic_financial_identification1_mut(value) }
+}
+
+pub fn walk_financial_instrument_quantity1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::FinancialInstrumentQuantity1Choice) {
+}
+
+pub fn walk_financial_instrument_quantity1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::FinancialInstrumentQuantity1Choice) {
+}
+
+pub fn walk_floor_limit_type1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::FloorLimitType1Code) {
+}
+
+pub fn walk_floor_limit_type1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::FloorLimitType1Code) {
+}
+
+pub fn walk_frequency36_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Frequency36Choice) {
+	if let Some(ref value) = node.tp { visitor.visit_frequency6_code(value) }
+	if let Some(ref value) = node.prd { visitor.visit_frequency_period1(value) }
+	if let Some(ref value) = node.pt_in_tm { visitor.visit_frequency_and_moment1(value) }
+}
+
+pub fn walk_frequency36_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Frequency36Choice) {
+	if let Some(ref mut value) = node.tp { visitor.visit_frequency6_code_mut(value) }
+	if let Some(ref mut value) = node.prd { visitor.visit_frequency_period1_mut(value) }
+	if let Some(ref mut value) = node.pt_in_tm { visitor.visit_frequency_and_moment1_mut(value) }
+}
+
+pub fn walk_frequency6_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Frequency6Code) {
+}
+
+pub fn walk_frequency6_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Frequency6Code) {
+}
+
+pub fn walk_frequency_and_moment1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FrequencyAndMoment1) {
+	visitor.visit_frequency6_code(&node.tp);
+}
+
+pub fn walk_frequency_and_moment1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FrequencyAndMoment1) {
+	visitor.visit_frequency6_code_mut(&mut node.tp);
+}
+
+pub fn walk_frequency_period1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FrequencyPeriod1) {
+	visitor.visit_frequency6_code(&node.tp);
+}
+
+pub fn walk_frequency_period1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FrequencyPeriod1) {
+	visitor.visit_frequency6_code_mut(&mut node.tp);
+}
+
+pub fn walk_from_to_amount_range1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FromToAmountRange1) {
+	visitor.visit_amount_range_boundary1(&node.fr_amt);
+	visitor.visit_amount_range_boundary1(&node.to_amt);
+}
+
+pub fn walk_from_to_amount_range1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FromToAmountRange1) {
+	visitor.visit_amount_range_boundary1_mut(&mut node.fr_amt);
+	visitor.visit_amount_range_boundary1_mut(&mut node.to_amt);
+}
+
+pub fn walk_garnishment3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Garnishment3) {
+	visitor.visit_garnishment_type1(&node.tp);
+	if let Some(ref value) = node.grnshee { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.grnshmt_admstr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.rmtd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+}
+
+pub fn walk_garnishment3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Garnishment3) {
+	visitor.visit_garnishment_type1_mut(&mut node.tp);
+	if let Some(ref mut value) = node.grnshee { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.grnshmt_admstr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.rmtd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+}
+
+pub fn walk_garnishment_type1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GarnishmentType1) {
+	visitor.visit_garnishment_type1_choice(&node.cd_or_prtry);
+}
+
+pub fn walk_garnishment_type1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GarnishmentType1) {
+	visitor.visit_garnishment_type1_choice_mut(&mut node.cd_or_prtry);
+}
+
+pub fn walk_garnishment_type1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::GarnishmentType1Choice) {
+}
+
+pub fn walk_garnishment_type1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::GarnishmentType1Choice) {
+}
+
+pub fn walk_generic_account_identification1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GenericAccountIdentification1) {
+	if let Some(ref value) = node.schme_nm { visitor.visit_account_scheme_name1_choice(value) }
+}
+
+pub fn walk_generic_account_identification1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GenericAccountIdentification1) {
+	if let Some(ref mut value) = node.schme_nm { visitor.visit_account_scheme_name1_choice_mut(value) }
+}
+
+pub fn walk_generic_financial_identification1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GenericFinancialIdentification1) {
+	if let Some(ref value) = node.schme_nm { visitor.visit_financial_identification_scheme_name1_choice(value) }
+}
+
+pub fn walk_generic_financial_identification1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GenericFinancialIdentification1) {
+	if let Some(ref mut value) = node.schme_nm { visitor.visit_financial_identification_scheme_name1_choice_mut(value) }
+}
+
+pub fn walk_generic_identification1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::GenericIdentification1) {
+}
+
+pub fn walk_generic_identification1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::GenericIdentification1) {
+}
+
+pub fn walk_generic_identification3<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::GenericIdentification3) {
+}
+
+pub fn walk_generic_identification3_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::GenericIdentification3) {
+}
+
+pub fn walk_generic_identification30<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::GenericIdentification30) {
+}
+
+pub fn walk_generic_identification30_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::GenericIdentification30) {
+}
+
+pub fn walk_generic_identification32<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GenericIdentification32) {
+	if let Some(ref value) = node.tp { visitor.visit_party_type3_code(value) }
+	if let Some(ref value) = node.issr { visitor.visit_party_type4_code(value) }
+}
+
+pub fn walk_generic_identification32_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GenericIdentification32) {
+	if let Some(ref mut value) = node.tp { visitor.visit_party_type3_code_mut(value) }
+	if let Some(ref mut value) = node.issr { visitor.visit_party_type4_code_mut(value) }
+}
+
+pub fn walk_generic_identification36<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::GenericIdentification36) {
+}
+
+pub fn walk_generic_identification36_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::GenericIdentification36) {
+}
+
+pub fn walk_generic_organisation_identification1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GenericOrganisationIdentification1) {
+	if let Some(ref value) = node.schme_nm { visitor.visit_organisation_identification_scheme_name1_choice(value) }
+}
+
+pub fn walk_generic_organisation_identification1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GenericOrganisationIdentification1) {
+	if let Some(ref mut value) = node.schme_nm { visitor.visit_organisation_identification_scheme_name1_choice_mut(value) }
+}
+
+pub fn walk_generic_person_identification1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GenericPersonIdentification1) {
+	if let Some(ref value) = node.schme_nm { visitor.visit_person_identification_scheme_name1_choice(value) }
+}
+
+pub fn walk_generic_person_identification1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GenericPersonIdentification1) {
+	if let Some(ref mut value) = node.schme_nm { visitor.visit_person_identification_scheme_name1_choice_mut(value) }
+}
+
+pub fn walk_group_cancellation_status1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::GroupCancellationStatus1Code) {
+}
+
+pub fn walk_group_cancellation_status1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::GroupCancellationStatus1Code) {
+}
+
+pub fn walk_group_header77<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GroupHeader77) {
+	if let Some(ref value) = node.msg_sndr { visitor.visit_party40_choice(value) }
+}
+
+pub fn walk_group_header77_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GroupHeader77) {
+	if let Some(ref mut value) = node.msg_sndr { visitor.visit_party40_choice_mut(value) }
+}
+
+pub fn walk_group_header78<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GroupHeader78) {
+	visitor.visit_party_identification135(&node.initg_pty);
+}
+
+pub fn walk_group_header78_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GroupHeader78) {
+	visitor.visit_party_identification135_mut(&mut node.initg_pty);
+}
+
+pub fn walk_group_header81<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GroupHeader81) {
+	if let Some(ref value) = node.msg_rcpt { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.msg_pgntn { visitor.visit_pagination1(value) }
+	if let Some(ref value) = node.orgnl_biz_qry { visitor.visit_original_business_query1(value) }
+}
+
+pub fn walk_group_header81_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GroupHeader81) {
+	if let Some(ref mut value) = node.msg_rcpt { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.msg_pgntn { visitor.visit_pagination1_mut(value) }
+	if let Some(ref mut value) = node.orgnl_biz_qry { visitor.visit_original_business_query1_mut(value) }
+}
+
+pub fn walk_group_header87<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GroupHeader87) {
+	visitor.visit_party_identification135(&node.initg_pty);
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+}
+
+pub fn walk_group_header87_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GroupHeader87) {
+	visitor.visit_party_identification135_mut(&mut node.initg_pty);
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+}
+
+pub fn walk_group_header90<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GroupHeader90) {
+	if let Some(ref vec) = node.authstn { for item in vec { visitor.visit_authorisation1_choice(item) } }
+	if let Some(ref value) = node.ttl_rtrd_intr_bk_sttlm_amt { visitor.visit_active_currency_and_amount(value) }
+	visitor.visit_settlement_instruction7(&node.sttlm_inf);
+	if let Some(ref value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+}
+
+pub fn walk_group_header90_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GroupHeader90) {
+	if let Some(ref mut vec) = node.authstn { for item in vec { visitor.visit_authorisation1_choice_mut(item) } }
+	if let Some(ref mut value) = node.ttl_rtrd_intr_bk_sttlm_amt { visitor.visit_active_currency_and_amount_mut(value) }
+	visitor.visit_settlement_instruction7_mut(&mut node.sttlm_inf);
+	if let Some(ref mut value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+}
+
+pub fn walk_group_header91<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GroupHeader91) {
+	if let Some(ref value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+}
+
+pub fn walk_group_header91_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GroupHeader91) {
+	if let Some(ref mut value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+}
+
+pub fn walk_group_header93<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::GroupHeader93) {
+	if let Some(ref value) = node.ttl_intr_bk_sttlm_amt { visitor.visit_active_currency_and_amount(value) }
+	visitor.visit_settlement_instruction7(&node.sttlm_inf);
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information28(value) }
+	if let Some(ref value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+}
+
+pub fn walk_group_header93_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::GroupHeader93) {
+	if let Some(ref mut value) = node.ttl_intr_bk_sttlm_amt { visitor.visit_active_currency_and_amount_mut(value) }
+	visitor.visit_settlement_instruction7_mut(&mut node.sttlm_inf);
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information28_mut(value) }
+	if let Some(ref mut value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+}
+
+pub fn walk_identification_source3_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::IdentificationSource3Choice) {
+}
+
+pub fn walk_identification_source3_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::IdentificationSource3Choice) {
+}
+
+pub fn walk_implementation_specification1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ImplementationSpecification1) {
+}
+
+pub fn walk_implementation_specification1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ImplementationSpecification1) {
+}
+
+pub fn walk_implied_currency_amount_range1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ImpliedCurrencyAmountRange1Choice) {
+	if let Some(ref value) = node.fr_amt { visitor.visit_amount_range_boundary1(value) }
+	if let Some(ref value) = node.to_amt { visitor.visit_amount_range_boundary1(value) }
+	if let Some(ref value) = node.fr_to_amt { visitor.visit_from_to_amount_range1(value) }
+}
+
+pub fn walk_implied_currency_amount_range1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ImpliedCurrencyAmountRange1Choice) {
+	if let Some(ref mut value) = node.fr_amt { visitor.visit_amount_range_boundary1_mut(value) }
+	if let Some(ref mut value) = node.to_amt { visitor.visit_amount_range_boundary1_mut(value) }
+	if let Some(ref mut value) = node.fr_to_amt { visitor.visit_from_to_amount_range1_mut(value) }
+}
+
+pub fn walk_instruction3_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Instruction3Code) {
+}
+
+pub fn walk_instruction3_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Instruction3Code) {
+}
+
+pub fn walk_instruction4_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Instruction4Code) {
+}
+
+pub fn walk_instruction4_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Instruction4Code) {
+}
+
+pub fn walk_instruction5_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Instruction5Code) {
+}
+
+pub fn walk_instruction5_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Instruction5Code) {
+}
+
+pub fn walk_instruction_for_creditor_agent1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::InstructionForCreditorAgent1) {
+	if let Some(ref value) = node.cd { visitor.visit_instruction3_code(value) }
+}
+
+pub fn walk_instruction_for_creditor_agent1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::InstructionForCreditorAgent1) {
+	if let Some(ref mut value) = node.cd { visitor.visit_instruction3_code_mut(value) }
+}
+
+pub fn walk_instruction_for_creditor_agent2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::InstructionForCreditorAgent2) {
+	if let Some(ref value) = node.cd { visitor.visit_instruction5_code(value) }
+}
+
+pub fn walk_instruction_for_creditor_agent2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::InstructionForCreditorAgent2) {
+	if let Some(ref mut value) = node.cd { visitor.visit_instruction5_code_mut(value) }
+}
+
+pub fn walk_instruction_for_creditor_agent3<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::InstructionForCreditorAgent3) {
+}
+
+pub fn walk_instruction_for_creditor_agent3_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::InstructionForCreditorAgent3) {
+}
+
+pub fn walk_instruction_for_next_agent1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::InstructionForNextAgent1) {
+	if let Some(ref value) = node.cd { visitor.visit_instruction4_code(value) }
+}
+
+pub fn walk_instruction_for_next_agent1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::InstructionForNextAgent1) {
+	if let Some(ref mut value) = node.cd { visitor.visit_instruction4_code_mut(value) }
+}
+
+pub fn walk_interest_record2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::InterestRecord2) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	visitor.visit_credit_debit_code(&node.cdt_dbt_ind);
+	if let Some(ref value) = node.tp { visitor.visit_interest_type1_choice(value) }
+	if let Some(ref value) = node.rate { visitor.visit_rate4(value) }
+	if let Some(ref value) = node.fr_to_dt { visitor.visit_date_time_period1(value) }
+	if let Some(ref value) = node.tax { visitor.visit_tax_charges2(value) }
+}
+
+pub fn walk_interest_record2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::InterestRecord2) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	visitor.visit_credit_debit_code_mut(&mut node.cdt_dbt_ind);
+	if let Some(ref mut value) = node.tp { visitor.visit_interest_type1_choice_mut(value) }
+	if let Some(ref mut value) = node.rate { visitor.visit_rate4_mut(value) }
+	if let Some(ref mut value) = node.fr_to_dt { visitor.visit_date_time_period1_mut(value) }
+	if let Some(ref mut value) = node.tax { visitor.visit_tax_charges2_mut(value) }
+}
+
+pub fn walk_interest_type1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::InterestType1Choice) {
+	if let Some(ref value) = node.cd { visitor.visit_interest_type1_code(value) }
+}
+
+pub fn walk_interest_type1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::InterestType1Choice) {
+	if let Some(ref mut value) = node.cd { visitor.visit_interest_type1_code_mut(value) }
+}
+
+pub fn walk_interest_type1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::InterestType1Code) {
+}
+
+pub fn walk_interest_type1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::InterestType1Code) {
+}
+
+pub fn walk_investigation_status5_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::InvestigationStatus5Choice) {
+	if let Some(ref vec) = node.rjctd_mod { for item in vec { visitor.visit_modification_status_reason1_choice(item) } }
+	if let Some(ref value) = node.dplct_of { visitor.visit_case5(value) }
+}
+
+pub fn walk_investigation_status5_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::InvestigationStatus5Choice) {
+	if let Some(ref mut vec) = node.rjctd_mod { for item in vec { visitor.visit_modification_status_reason1_choice_mut(item) } }
+	if let Some(ref mut value) = node.dplct_of { visitor.visit_case5_mut(value) }
+}
+
+pub fn walk_limit2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Limit2) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	visitor.visit_floor_limit_type1_code(&node.cdt_dbt_ind);
+}
+
+pub fn walk_limit2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Limit2) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	visitor.visit_floor_limit_type1_code_mut(&mut node.cdt_dbt_ind);
+}
+
+pub fn walk_local_instrument2_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::LocalInstrument2Choice) {
+}
+
+pub fn walk_local_instrument2_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::LocalInstrument2Choice) {
+}
+
+pub fn walk_mandate_classification1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::MandateClassification1Choice) {
+	if let Some(ref value) = node.cd { visitor.visit_mandate_classification1_code(value) }
+}
+
+pub fn walk_mandate_classification1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::MandateClassification1Choice) {
+	if let Some(ref mut value) = node.cd { visitor.visit_mandate_classification1_code_mut(value) }
+}
+
+pub fn walk_mandate_classification1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::MandateClassification1Code) {
+}
+
+pub fn walk_mandate_classification1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::MandateClassification1Code) {
+}
+
+pub fn walk_mandate_related_data1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::MandateRelatedData1Choice) {
+	if let Some(ref value) = node.drct_dbt_mndt { visitor.visit_mandate_related_information14(value) }
+	if let Some(ref value) = node.cdt_trf_mndt { visitor.visit_credit_transfer_mandate_data1(value) }
+}
+
+pub fn walk_mandate_related_data1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::MandateRelatedData1Choice) {
+	if let Some(ref mut value) = node.drct_dbt_mndt { visitor.visit_mandate_related_information14_mut(value) }
+	if let Some(ref mut value) = node.cdt_trf_mndt { visitor.visit_credit_transfer_mandate_data1_mut(value) }
+}
+
+pub fn walk_mandate_related_information14<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::MandateRelatedInformation14) {
+	if let Some(ref value) = node.amdmnt_inf_dtls { visitor.visit_amendment_information_details13(value) }
+	if let Some(ref value) = node.frqcy { visitor.visit_frequency36_choice(value) }
+	if let Some(ref value) = node.rsn { visitor.visit_mandate_setup_reason1_choice(value) }
+}
+
+pub fn walk_mandate_related_information14_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::MandateRelatedInformation14) {
+	if let Some(ref mut value) = node.amdmnt_inf_dtls { visitor.visit_amendment_information_details13_mut(value) }
+	if let Some(ref mut value) = node.frqcy { visitor.visit_frequency36_choice_mut(value) }
+	if let Some(ref mut value) = node.rsn { visitor.visit_mandate_setup_reason1_choice_mut(value) }
+}
+
+pub fn walk_mandate_setup_reason1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::MandateSetupReason1Choice) {
+}
+
+pub fn walk_mandate_setup_reason1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::MandateSetupReason1Choice) {
+}
+
+pub fn walk_mandate_type_information2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::MandateTypeInformation2) {
+	if let Some(ref value) = node.svc_lvl { visitor.visit_service_level8_choice(value) }
+	if let Some(ref value) = node.lcl_instrm { visitor.visit_local_instrument2_choice(value) }
+	if let Some(ref value) = node.ctgy_purp { visitor.visit_category_purpose1_choice(value) }
+	if let Some(ref value) = node.clssfctn { visitor.visit_mandate_classification1_choice(value) }
+}
+
+pub fn walk_mandate_type_information2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::MandateTypeInformation2) {
+	if let Some(ref mut value) = node.svc_lvl { visitor.visit_service_level8_choice_mut(value) }
+	if let Some(ref mut value) = node.lcl_instrm { visitor.visit_local_instrument2_choice_mut(value) }
+	if let Some(ref mut value) = node.ctgy_purp { visitor.visit_category_purpose1_choice_mut(value) }
+	if let Some(ref mut value) = node.clssfctn { visitor.visit_mandate_classification1_choice_mut(value) }
+}
+
+pub fn walk_message_header10<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::MessageHeader10) {
+}
+
+pub fn walk_message_header10_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::MessageHeader10) {
+}
+
+pub fn walk_message_header7<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::MessageHeader7) {
+	if let Some(ref value) = node.req_tp { visitor.visit_request_type4_choice(value) }
+	if let Some(ref value) = node.orgnl_biz_qry { visitor.visit_original_business_query1(value) }
+}
+
+pub fn walk_message_header7_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::MessageHeader7) {
+	if let Some(ref mut value) = node.req_tp { visitor.visit_request_type4_choice_mut(value) }
+	if let Some(ref mut value) = node.orgnl_biz_qry { visitor.visit_original_business_query1_mut(value) }
+}
+
+pub fn walk_message_identification2<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::MessageIdentification2) {
+}
+
+pub fn walk_message_identification2_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::MessageIdentification2) {
+}
+
+pub fn walk_message_reference<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::MessageReference) {
+}
+
+pub fn walk_message_reference_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::MessageReference) {
+}
+
+pub fn walk_message_reference1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::MessageReference1) {
+	if let Some(ref value) = node.ref_issr { visitor.visit_party_identification136(value) }
+}
+
+pub fn walk_message_reference1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::MessageReference1) {
+	if let Some(ref mut value) = node.ref_issr { visitor.visit_party_identification136_mut(value) }
+}
+
+pub fn walk_missing_or_incorrect_information3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::MissingOrIncorrectInformation3) {
+	if let Some(ref vec) = node.mssng_inf { for item in vec { visitor.visit_unable_to_apply_missing1(item) } }
+	if let Some(ref vec) = node.incrrct_inf { for item in vec { visitor.visit_unable_to_apply_incorrect1(item) } }
+}
+
+pub fn walk_missing_or_incorrect_information3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::MissingOrIncorrectInformation3) {
+	if let Some(ref mut vec) = node.mssng_inf { for item in vec { visitor.visit_unable_to_apply_missing1_mut(item) } }
+	if let Some(ref mut vec) = node.incrrct_inf { for item in vec { visitor.visit_unable_to_apply_incorrect1_mut(item) } }
+}
+
+pub fn walk_modification_status_reason1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ModificationStatusReason1Choice) {
+}
+
+pub fn walk_modification_status_reason1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ModificationStatusReason1Choice) {
+}
+
+pub fn walk_modification_status_reason2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ModificationStatusReason2) {
+	if let Some(ref value) = node.orgtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.rsn { visitor.visit_modification_status_reason1_choice(value) }
+}
+
+pub fn walk_modification_status_reason2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ModificationStatusReason2) {
+	if let Some(ref mut value) = node.orgtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.rsn { visitor.visit_modification_status_reason1_choice_mut(value) }
+}
+
+pub fn walk_name_and_address16<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::NameAndAddress16) {
+	visitor.visit_postal_address24(&node.adr);
+}
+
+pub fn walk_name_and_address16_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::NameAndAddress16) {
+	visitor.visit_postal_address24_mut(&mut node.adr);
+}
+
+pub fn walk_name_and_address5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::NameAndAddress5) {
+	if let Some(ref value) = node.adr { visitor.visit_postal_address1(value) }
+}
+
+pub fn walk_name_and_address5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::NameAndAddress5) {
+	if let Some(ref mut value) = node.adr { visitor.visit_postal_address1_mut(value) }
+}
+
+pub fn walk_name_prefix2_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::NamePrefix2Code) {
+}
+
+pub fn walk_name_prefix2_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::NamePrefix2Code) {
+}
+
+pub fn walk_number_and_sum_of_transactions1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::NumberAndSumOfTransactions1) {
+}
+
+pub fn walk_number_and_sum_of_transactions1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::NumberAndSumOfTransactions1) {
+}
+
+pub fn walk_number_and_sum_of_transactions4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::NumberAndSumOfTransactions4) {
+	if let Some(ref value) = node.ttl_net_ntry { visitor.visit_amount_and_direction35(value) }
+}
+
+pub fn walk_number_and_sum_of_transactions4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::NumberAndSumOfTransactions4) {
+	if let Some(ref mut value) = node.ttl_net_ntry { visitor.visit_amount_and_direction35_mut(value) }
+}
+
+pub fn walk_number_of_cancellations_per_status1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::NumberOfCancellationsPerStatus1) {
+	visitor.visit_cancellation_individual_status1_code(&node.dtld_sts);
+}
+
+pub fn walk_number_of_cancellations_per_status1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::NumberOfCancellationsPerStatus1) {
+	visitor.visit_cancellation_individual_status1_code_mut(&mut node.dtld_sts);
+}
+
+pub fn walk_number_of_transactions_per_status1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::NumberOfTransactionsPerStatus1) {
+	visitor.visit_transaction_individual_status1_code(&node.dtld_sts);
+}
+
+pub fn walk_number_of_transactions_per_status1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::NumberOfTransactionsPerStatus1) {
+	visitor.visit_transaction_individual_status1_code_mut(&mut node.dtld_sts);
+}
+
+pub fn walk_number_of_transactions_per_status5<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::NumberOfTransactionsPerStatus5) {
+}
+
+pub fn walk_number_of_transactions_per_status5_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::NumberOfTransactionsPerStatus5) {
+}
+
+pub fn walk_on_line_capability1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::OnLineCapability1Code) {
+}
+
+pub fn walk_on_line_capability1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::OnLineCapability1Code) {
+}
+
+pub fn walk_organisation_identification29<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OrganisationIdentification29) {
+	if let Some(ref vec) = node.othr { for item in vec { visitor.visit_generic_organisation_identification1(item) } }
+}
+
+pub fn walk_organisation_identification29_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OrganisationIdentification29) {
+	if let Some(ref mut vec) = node.othr { for item in vec { visitor.visit_generic_organisation_identification1_mut(item) } }
+}
+
+pub fn walk_organisation_identification_scheme_name1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::OrganisationIdentificationSchemeName1Choice) {
+}
+
+pub fn walk_organisation_identification_scheme_name1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::OrganisationIdentificationSchemeName1Choice) {
+}
+
+pub fn walk_original_and_current_quantities1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::OriginalAndCurrentQuantities1) {
+}
+
+pub fn walk_original_and_current_quantities1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::OriginalAndCurrentQuantities1) {
+}
+
+pub fn walk_original_business_query1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::OriginalBusinessQuery1) {
+}
+
+pub fn walk_original_business_query1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::OriginalBusinessQuery1) {
+}
+
+pub fn walk_original_group_header14<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalGroupHeader14) {
+	if let Some(ref value) = node.rslvd_case { visitor.visit_case5(value) }
+	if let Some(ref value) = node.grp_cxl_sts { visitor.visit_group_cancellation_status1_code(value) }
+	if let Some(ref vec) = node.cxl_sts_rsn_inf { for item in vec { visitor.visit_cancellation_status_reason4(item) } }
+	if let Some(ref vec) = node.nb_of_txs_per_cxl_sts { for item in vec { visitor.visit_number_of_transactions_per_status1(item) } }
+}
+
+pub fn walk_original_group_header14_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalGroupHeader14) {
+	if let Some(ref mut value) = node.rslvd_case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut value) = node.grp_cxl_sts { visitor.visit_group_cancellation_status1_code_mut(value) }
+	if let Some(ref mut vec) = node.cxl_sts_rsn_inf { for item in vec { visitor.visit_cancellation_status_reason4_mut(item) } }
+	if let Some(ref mut vec) = node.nb_of_txs_per_cxl_sts { for item in vec { visitor.visit_number_of_transactions_per_status1_mut(item) } }
+}
+
+pub fn walk_original_group_header15<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalGroupHeader15) {
+	if let Some(ref value) = node.case { visitor.visit_case5(value) }
+	if let Some(ref vec) = node.cxl_rsn_inf { for item in vec { visitor.visit_payment_cancellation_reason5(item) } }
+}
+
+pub fn walk_original_group_header15_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalGroupHeader15) {
+	if let Some(ref mut value) = node.case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut vec) = node.cxl_rsn_inf { for item in vec { visitor.visit_payment_cancellation_reason5_mut(item) } }
+}
+
+pub fn walk_original_group_header17<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalGroupHeader17) {
+	if let Some(ref vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12(item) } }
+	if let Some(ref vec) = node.nb_of_txs_per_sts { for item in vec { visitor.visit_number_of_transactions_per_status5(item) } }
+}
+
+pub fn walk_original_group_header17_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalGroupHeader17) {
+	if let Some(ref mut vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12_mut(item) } }
+	if let Some(ref mut vec) = node.nb_of_txs_per_sts { for item in vec { visitor.visit_number_of_transactions_per_status5_mut(item) } }
+}
+
+pub fn walk_original_group_header18<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalGroupHeader18) {
+	if let Some(ref vec) = node.rtr_rsn_inf { for item in vec { visitor.visit_payment_return_reason6(item) } }
+}
+
+pub fn walk_original_group_header18_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalGroupHeader18) {
+	if let Some(ref mut vec) = node.rtr_rsn_inf { for item in vec { visitor.visit_payment_return_reason6_mut(item) } }
+}
+
+pub fn walk_original_group_information27<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::OriginalGroupInformation27) {
+}
+
+pub fn walk_original_group_information27_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::OriginalGroupInformation27) {
+}
+
+pub fn walk_original_group_information29<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::OriginalGroupInformation29) {
+}
+
+pub fn walk_original_group_information29_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::OriginalGroupInformation29) {
+}
+
+pub fn walk_original_group_information30<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalGroupInformation30) {
+	if let Some(ref vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12(item) } }
+	if let Some(ref vec) = node.nb_of_txs_per_sts { for item in vec { visitor.visit_number_of_transactions_per_status5(item) } }
+}
+
+pub fn walk_original_group_information30_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalGroupInformation30) {
+	if let Some(ref mut vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12_mut(item) } }
+	if let Some(ref mut vec) = node.nb_of_txs_per_sts { for item in vec { visitor.visit_number_of_transactions_per_status5_mut(item) } }
+}
+
+pub fn walk_original_payment_instruction30<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalPaymentInstruction30) {
+	if let Some(ref value) = node.rslvd_case { visitor.visit_case5(value) }
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_information29(value) }
+	if let Some(ref value) = node.pmt_inf_cxl_sts { visitor.visit_group_cancellation_status1_code(value) }
+	if let Some(ref vec) = node.cxl_sts_rsn_inf { for item in vec { visitor.visit_cancellation_status_reason4(item) } }
+	if let Some(ref vec) = node.nb_of_txs_per_cxl_sts { for item in vec { visitor.visit_number_of_cancellations_per_status1(item) } }
+	if let Some(ref vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction103(item) } }
+}
+
+pub fn walk_original_payment_instruction30_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalPaymentInstruction30) {
+	if let Some(ref mut value) = node.rslvd_case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_information29_mut(value) }
+	if let Some(ref mut value) = node.pmt_inf_cxl_sts { visitor.visit_group_cancellation_status1_code_mut(value) }
+	if let Some(ref mut vec) = node.cxl_sts_rsn_inf { for item in vec { visitor.visit_cancellation_status_reason4_mut(item) } }
+	if let Some(ref mut vec) = node.nb_of_txs_per_cxl_sts { for item in vec { visitor.visit_number_of_cancellations_per_status1_mut(item) } }
+	if let Some(ref mut vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction103_mut(item) } }
+}
+
+pub fn walk_original_payment_instruction31<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalPaymentInstruction31) {
+	if let Some(ref vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12(item) } }
+	if let Some(ref vec) = node.nb_of_txs_per_sts { for item in vec { visitor.visit_number_of_transactions_per_status5(item) } }
+	if let Some(ref vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction104(item) } }
+}
+
+pub fn walk_original_payment_instruction31_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalPaymentInstruction31) {
+	if let Some(ref mut vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12_mut(item) } }
+	if let Some(ref mut vec) = node.nb_of_txs_per_sts { for item in vec { visitor.visit_number_of_transactions_per_status5_mut(item) } }
+	if let Some(ref mut vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction104_mut(item) } }
+}
+
+pub fn walk_original_payment_instruction36<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalPaymentInstruction36) {
+	if let Some(ref value) = node.case { visitor.visit_case5(value) }
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_information29(value) }
+	if let Some(ref vec) = node.cxl_rsn_inf { for item in vec { visitor.visit_payment_cancellation_reason5(item) } }
+	if let Some(ref vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction124(item) } }
+}
+
+pub fn walk_original_payment_instruction36_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalPaymentInstruction36) {
+	if let Some(ref mut value) = node.case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_information29_mut(value) }
+	if let Some(ref mut vec) = node.cxl_rsn_inf { for item in vec { visitor.visit_payment_cancellation_reason5_mut(item) } }
+	if let Some(ref mut vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction124_mut(item) } }
+}
+
+pub fn walk_original_transaction_reference28<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalTransactionReference28) {
+	if let Some(ref value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.amt { visitor.visit_amount_type4_choice(value) }
+	if let Some(ref value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.cdtr_schme_id { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.sttlm_inf { visitor.visit_settlement_instruction7(value) }
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information27(value) }
+	if let Some(ref value) = node.pmt_mtd { visitor.visit_payment_method4_code(value) }
+	if let Some(ref value) = node.mndt_rltd_inf { visitor.visit_mandate_related_information14(value) }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.dbtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.purp { visitor.visit_purpose2_choice(value) }
+}
+
+pub fn walk_original_transaction_reference28_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalTransactionReference28) {
+	if let Some(ref mut value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.amt { visitor.visit_amount_type4_choice_mut(value) }
+	if let Some(ref mut value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.cdtr_schme_id { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.sttlm_inf { visitor.visit_settlement_instruction7_mut(value) }
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information27_mut(value) }
+	if let Some(ref mut value) = node.pmt_mtd { visitor.visit_payment_method4_code_mut(value) }
+	if let Some(ref mut value) = node.mndt_rltd_inf { visitor.visit_mandate_related_information14_mut(value) }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.dbtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.purp { visitor.visit_purpose2_choice_mut(value) }
+}
+
+pub fn walk_original_transaction_reference29<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalTransactionReference29) {
+	if let Some(ref value) = node.amt { visitor.visit_amount_type4_choice(value) }
+	if let Some(ref value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.xpry_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.pmt_cond { visitor.visit_payment_condition1(value) }
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information26(value) }
+	if let Some(ref value) = node.pmt_mtd { visitor.visit_payment_method4_code(value) }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+	if let Some(ref vec) = node.nclsd_file { for item in vec { visitor.visit_document12(item) } }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.cdtr_agt);
+	visitor.visit_party_identification135(&node.cdtr);
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party_identification135(value) }
+}
+
+pub fn walk_original_transaction_reference29_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalTransactionReference29) {
+	if let Some(ref mut value) = node.amt { visitor.visit_amount_type4_choice_mut(value) }
+	if let Some(ref mut value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.xpry_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.pmt_cond { visitor.visit_payment_condition1_mut(value) }
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information26_mut(value) }
+	if let Some(ref mut value) = node.pmt_mtd { visitor.visit_payment_method4_code_mut(value) }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+	if let Some(ref mut vec) = node.nclsd_file { for item in vec { visitor.visit_document12_mut(item) } }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.cdtr_agt);
+	visitor.visit_party_identification135_mut(&mut node.cdtr);
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party_identification135_mut(value) }
+}
+
+pub fn walk_original_transaction_reference31<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalTransactionReference31) {
+	if let Some(ref value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.amt { visitor.visit_amount_type4_choice(value) }
+	if let Some(ref value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.cdtr_schme_id { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.sttlm_inf { visitor.visit_settlement_instruction7(value) }
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information27(value) }
+	if let Some(ref value) = node.pmt_mtd { visitor.visit_payment_method4_code(value) }
+	if let Some(ref value) = node.mndt_rltd_inf { visitor.visit_mandate_related_data1_choice(value) }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.dbtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.purp { visitor.visit_purpose2_choice(value) }
+}
+
+pub fn walk_original_transaction_reference31_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalTransactionReference31) {
+	if let Some(ref mut value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.amt { visitor.visit_amount_type4_choice_mut(value) }
+	if let Some(ref mut value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.cdtr_schme_id { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.sttlm_inf { visitor.visit_settlement_instruction7_mut(value) }
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information27_mut(value) }
+	if let Some(ref mut value) = node.pmt_mtd { visitor.visit_payment_method4_code_mut(value) }
+	if let Some(ref mut value) = node.mndt_rltd_inf { visitor.visit_mandate_related_data1_choice_mut(value) }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.dbtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.purp { visitor.visit_purpose2_choice_mut(value) }
+}
+
+pub fn walk_original_transaction_reference32<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OriginalTransactionReference32) {
+	if let Some(ref value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.amt { visitor.visit_amount_type4_choice(value) }
+	if let Some(ref value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.cdtr_schme_id { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.sttlm_inf { visitor.visit_settlement_instruction7(value) }
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information27(value) }
+	if let Some(ref value) = node.pmt_mtd { visitor.visit_payment_method4_code(value) }
+	if let Some(ref value) = node.mndt_rltd_inf { visitor.visit_mandate_related_data1_choice(value) }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.dbtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.purp { visitor.visit_purpose2_choice(value) }
+	if let Some(ref value) = node.undrlyg_cstmr_cdt_trf { visitor.visit_credit_transfer_transaction45(value) }
+}
+
+pub fn walk_original_transaction_reference32_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OriginalTransactionReference32) {
+	if let Some(ref mut value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.amt { visitor.visit_amount_type4_choice_mut(value) }
+	if let Some(ref mut value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.cdtr_schme_id { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.sttlm_inf { visitor.visit_settlement_instruction7_mut(value) }
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information27_mut(value) }
+	if let Some(ref mut value) = node.pmt_mtd { visitor.visit_payment_method4_code_mut(value) }
+	if let Some(ref mut value) = node.mndt_rltd_inf { visitor.visit_mandate_related_data1_choice_mut(value) }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.dbtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.purp { visitor.visit_purpose2_choice_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_cstmr_cdt_trf { visitor.visit_credit_transfer_transaction45_mut(value) }
+}
+
+pub fn walk_other_contact1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::OtherContact1) {
+}
+
+pub fn walk_other_contact1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::OtherContact1) {
+}
+
+pub fn walk_other_identification1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::OtherIdentification1) {
+	visitor.visit_identification_source3_choice(&node.tp);
+}
+
+pub fn walk_other_identification1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::OtherIdentification1) {
+	visitor.visit_identification_source3_choice_mut(&mut node.tp);
+}
+
+pub fn walk_poi_component_type1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::POIComponentType1Code) {
+}
+
+pub fn walk_poi_component_type1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::POIComponentType1Code) {
+}
+
+pub fn walk_pagination1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Pagination1) {
+}
+
+pub fn walk_pagination1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Pagination1) {
+}
+
+pub fn walk_party38_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Party38Choice) {
+	if let Some(ref value) = node.org_id { visitor.visit_organisation_identification29(value) }
+	if let Some(ref value) = node.prvt_id { visitor.visit_person_identification13(value) }
+}
+
+pub fn walk_party38_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Party38Choice) {
+	if let Some(ref mut value) = node.org_id { visitor.visit_organisation_identification29_mut(value) }
+	if let Some(ref mut value) = node.prvt_id { visitor.visit_person_identification13_mut(value) }
+}
+
+pub fn walk_party40_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Party40Choice) {
+	if let Some(ref value) = node.pty { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+}
+
+pub fn walk_party40_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Party40Choice) {
+	if let Some(ref mut value) = node.pty { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+}
+
+pub fn walk_party44_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Party44Choice) {
+	if let Some(ref value) = node.org_id { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.fi_id { visitor.visit_branch_and_financial_institution_identification6(value) }
+}
+
+pub fn walk_party44_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Party44Choice) {
+	if let Some(ref mut value) = node.org_id { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.fi_id { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+}
+
+pub fn walk_party_and_signature3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PartyAndSignature3) {
+	visitor.visit_party_identification135(&node.pty);
+	visitor.visit_skip_payload(&node.sgntr);
+}
+
+pub fn walk_party_and_signature3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PartyAndSignature3) {
+	visitor.visit_party_identification135_mut(&mut node.pty);
+	visitor.visit_skip_payload_mut(&mut node.sgntr);
+}
+
+pub fn walk_party_identification120_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PartyIdentification120Choice) {
+	if let Some(ref value) = node.prtry_id { visitor.visit_generic_identification36(value) }
+	if let Some(ref value) = node.nm_and_adr { visitor.visit_name_and_address5(value) }
+}
+
+pub fn walk_party_identification120_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PartyIdentification120Choice) {
+	if let Some(ref mut value) = node.prtry_id { visitor.visit_generic_identification36_mut(value) }
+	if let Some(ref mut value) = node.nm_and_adr { visitor.visit_name_and_address5_mut(value) }
+}
+
+pub fn walk_party_identification135<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PartyIdentification135) {
+	if let Some(ref value) = node.pstl_adr { visitor.visit_postal_address24(value) }
+	if let Some(ref value) = node.id { visitor.visit_party38_choice(value) }
+	if let Some(ref value) = node.ctct_dtls { visitor.visit_contact4(value) }
+}
+
+pub fn walk_party_identification135_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PartyIdentification135) {
+	if let Some(ref mut value) = node.pstl_adr { visitor.visit_postal_address24_mut(value) }
+	if let Some(ref mut value) = node.id { visitor.visit_party38_choice_mut(value) }
+	if let Some(ref mut value) = node.ctct_dtls { visitor.visit_contact4_mut(value) }
+}
+
+pub fn walk_party_identification136<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PartyIdentification136) {
+	visitor.visit_party_identification120_choice(&node.id);
+}
+
+pub fn walk_party_identification136_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PartyIdentification136) {
+	visitor.visit_party_identification120_choice_mut(&mut node.id);
+}
+
+pub fn walk_party_type3_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::PartyType3Code) {
+}
+
+pub fn walk_party_type3_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::PartyType3Code) {
+}
+
+pub fn walk_party_type4_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::PartyType4Code) {
+}
+
+pub fn walk_party_type4_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::PartyType4Code) {
+}
+
+pub fn walk_payment_cancellation_reason5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentCancellationReason5) {
+	if let Some(ref value) = node.orgtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.rsn { visitor.visit_cancellation_reason33_choice(value) }
+}
+
+pub fn walk_payment_cancellation_reason5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentCancellationReason5) {
+	if let Some(ref mut value) = node.orgtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.rsn { visitor.visit_cancellation_reason33_choice_mut(value) }
+}
+
+pub fn walk_payment_card4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentCard4) {
+	if let Some(ref value) = node.plain_card_data { visitor.visit_plain_card_data1(value) }
+	if let Some(ref value) = node.card_brnd { visitor.visit_generic_identification1(value) }
+}
+
+pub fn walk_payment_card4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentCard4) {
+	if let Some(ref mut value) = node.plain_card_data { visitor.visit_plain_card_data1_mut(value) }
+	if let Some(ref mut value) = node.card_brnd { visitor.visit_generic_identification1_mut(value) }
+}
+
+pub fn walk_payment_complementary_information8<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentComplementaryInformation8) {
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information27(value) }
+	if let Some(ref value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.amt { visitor.visit_amount_type4_choice(value) }
+	if let Some(ref value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.chrg_br { visitor.visit_charge_bearer_type1_code(value) }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.sttlm_inf { visitor.visit_settlement_instruction7(value) }
+	if let Some(ref value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.purp { visitor.visit_purpose2_choice(value) }
+	if let Some(ref value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1(item) } }
+	if let Some(ref vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent1(item) } }
+	if let Some(ref value) = node.rmt_inf { visitor.visit_remittance_information16(value) }
+}
+
+pub fn walk_payment_complementary_information8_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentComplementaryInformation8) {
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information27_mut(value) }
+	if let Some(ref mut value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.amt { visitor.visit_amount_type4_choice_mut(value) }
+	if let Some(ref mut value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.chrg_br { visitor.visit_charge_bearer_type1_code_mut(value) }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.sttlm_inf { visitor.visit_settlement_instruction7_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.purp { visitor.visit_purpose2_choice_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut vec) = node.instr_for_nxt_agt { for item in vec { visitor.visit_instruction_for_next_agent1_mut(item) } }
+	if let Some(ref mut vec) = node.instr_for_cdtr_agt { for item in vec { visitor.visit_instruction_for_creditor_agent1_mut(item) } }
+	if let Some(ref mut value) = node.rmt_inf { visitor.visit_remittance_information16_mut(value) }
+}
+
+pub fn walk_payment_condition1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentCondition1) {
+	if let Some(ref value) = node.imdt_pmt_rbt { visitor.visit_amount_or_rate1_choice(value) }
+}
+
+pub fn walk_payment_condition1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentCondition1) {
+	if let Some(ref mut value) = node.imdt_pmt_rbt { visitor.visit_amount_or_rate1_choice_mut(value) }
+}
+
+pub fn walk_payment_condition_status1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentConditionStatus1) {
+	if let Some(ref value) = node.accptd_amt { visitor.visit_active_currency_and_amount(value) }
+}
+
+pub fn walk_payment_condition_status1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentConditionStatus1) {
+	if let Some(ref mut value) = node.accptd_amt { visitor.visit_active_currency_and_amount_mut(value) }
+}
+
+pub fn walk_payment_context3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentContext3) {
+	if let Some(ref value) = node.attndnc_cntxt { visitor.visit_attendance_context1_code(value) }
+	if let Some(ref value) = node.tx_envt { visitor.visit_transaction_environment1_code(value) }
+	if let Some(ref value) = node.tx_chanl { visitor.visit_transaction_channel1_code(value) }
+	visitor.visit_card_data_reading1_code(&node.card_data_ntry_md);
+	if let Some(ref value) = node.authntcn_mtd { visitor.visit_cardholder_authentication2(value) }
+}
+
+pub fn walk_payment_context3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentContext3) {
+	if let Some(ref mut value) = node.attndnc_cntxt { visitor.visit_attendance_context1_code_mut(value) }
+	if let Some(ref mut value) = node.tx_envt { visitor.visit_transaction_environment1_code_mut(value) }
+	if let Some(ref mut value) = node.tx_chanl { visitor.visit_transaction_channel1_code_mut(value) }
+	visitor.visit_card_data_reading1_code_mut(&mut node.card_data_ntry_md);
+	if let Some(ref mut value) = node.authntcn_mtd { visitor.visit_cardholder_authentication2_mut(value) }
+}
+
+pub fn walk_payment_identification6<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::PaymentIdentification6) {
+}
+
+pub fn walk_payment_identification6_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::PaymentIdentification6) {
+}
+
+pub fn walk_payment_identification7<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::PaymentIdentification7) {
+}
+
+pub fn walk_payment_identification7_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::PaymentIdentification7) {
+}
+
+pub fn walk_payment_instruction31<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentInstruction31) {
+	visitor.visit_payment_method7_code(&node.pmt_mtd);
+	if let Some(ref value) = node.pmt_tp_inf { visitor.visit_payment_type_information26(value) }
+	visitor.visit_date_and_date_time2_choice(&node.reqd_exctn_dt);
+	if let Some(ref value) = node.xpry_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.pmt_cond { visitor.visit_payment_condition1(value) }
+	visitor.visit_party_identification135(&node.dbtr);
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_branch_and_financial_institution_identification6(&node.dbtr_agt);
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.chrg_br { visitor.visit_charge_bearer_type1_code(value) }
+	for item in &node.cdt_trf_tx { visitor.visit_credit_transfer_transaction35(item) }
+}
+
+pub fn walk_payment_instruction31_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentInstruction31) {
+	visitor.visit_payment_method7_code_mut(&mut node.pmt_mtd);
+	if let Some(ref mut value) = node.pmt_tp_inf { visitor.visit_payment_type_information26_mut(value) }
+	visitor.visit_date_and_date_time2_choice_mut(&mut node.reqd_exctn_dt);
+	if let Some(ref mut value) = node.xpry_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.pmt_cond { visitor.visit_payment_condition1_mut(value) }
+	visitor.visit_party_identification135_mut(&mut node.dbtr);
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.dbtr_agt);
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.chrg_br { visitor.visit_charge_bearer_type1_code_mut(value) }
+	for item in &mut node.cdt_trf_tx { visitor.visit_credit_transfer_transaction35_mut(item) }
+}
+
+pub fn walk_payment_method4_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::PaymentMethod4Code) {
+}
+
+pub fn walk_payment_method4_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::PaymentMethod4Code) {
+}
+
+pub fn walk_payment_method7_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::PaymentMethod7Code) {
+}
+
+pub fn walk_payment_method7_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::PaymentMethod7Code) {
+}
+
+pub fn walk_payment_return_reason5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentReturnReason5) {
+	if let Some(ref value) = node.orgnl_bk_tx_cd { visitor.visit_bank_transaction_code_structure4(value) }
+	if let Some(ref value) = node.orgtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.rsn { visitor.visit_return_reason5_choice(value) }
+}
+
+pub fn walk_payment_return_reason5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentReturnReason5) {
+	if let Some(ref mut value) = node.orgnl_bk_tx_cd { visitor.visit_bank_transaction_code_structure4_mut(value) }
+	if let Some(ref mut value) = node.orgtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.rsn { visitor.visit_return_reason5_choice_mut(value) }
+}
+
+pub fn walk_payment_return_reason6<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentReturnReason6) {
+	if let Some(ref value) = node.orgtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.rsn { visitor.visit_return_reason5_choice(value) }
+}
+
+pub fn walk_payment_return_reason6_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentReturnReason6) {
+	if let Some(ref mut value) = node.orgtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.rsn { visitor.visit_return_reason5_choice_mut(value) }
+}
+
+pub fn walk_payment_transaction102<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTransaction102) {
+	if let Some(ref value) = node.rslvd_case { visitor.visit_case5(value) }
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_information29(value) }
+	if let Some(ref value) = node.tx_cxl_sts { visitor.visit_cancellation_individual_status1_code(value) }
+	if let Some(ref vec) = node.cxl_sts_rsn_inf { for item in vec { visitor.visit_cancellation_status_reason4(item) } }
+	if let Some(ref value) = node.rsltn_rltd_inf { visitor.visit_resolution_data1(value) }
+	if let Some(ref value) = node.orgnl_intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.assgnr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.assgne { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28(value) }
+}
+
+pub fn walk_payment_transaction102_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTransaction102) {
+	if let Some(ref mut value) = node.rslvd_case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_information29_mut(value) }
+	if let Some(ref mut value) = node.tx_cxl_sts { visitor.visit_cancellation_individual_status1_code_mut(value) }
+	if let Some(ref mut vec) = node.cxl_sts_rsn_inf { for item in vec { visitor.visit_cancellation_status_reason4_mut(item) } }
+	if let Some(ref mut value) = node.rsltn_rltd_inf { visitor.visit_resolution_data1_mut(value) }
+	if let Some(ref mut value) = node.orgnl_intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.assgnr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.assgne { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28_mut(value) }
+}
+
+pub fn walk_payment_transaction103<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTransaction103) {
+	if let Some(ref value) = node.rslvd_case { visitor.visit_case5(value) }
+	if let Some(ref value) = node.tx_cxl_sts { visitor.visit_cancellation_individual_status1_code(value) }
+	if let Some(ref vec) = node.cxl_sts_rsn_inf { for item in vec { visitor.visit_cancellation_status_reason4(item) } }
+	if let Some(ref value) = node.orgnl_instd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.orgnl_reqd_exctn_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28(value) }
+}
+
+pub fn walk_payment_transaction103_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTransaction103) {
+	if let Some(ref mut value) = node.rslvd_case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut value) = node.tx_cxl_sts { visitor.visit_cancellation_individual_status1_code_mut(value) }
+	if let Some(ref mut vec) = node.cxl_sts_rsn_inf { for item in vec { visitor.visit_cancellation_status_reason4_mut(item) } }
+	if let Some(ref mut value) = node.orgnl_instd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.orgnl_reqd_exctn_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28_mut(value) }
+}
+
+pub fn walk_payment_transaction104<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTransaction104) {
+	if let Some(ref vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12(item) } }
+	if let Some(ref value) = node.pmt_cond_sts { visitor.visit_payment_condition_status1(value) }
+	if let Some(ref vec) = node.chrgs_inf { for item in vec { visitor.visit_charges7(item) } }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference29(value) }
+	if let Some(ref vec) = node.nclsd_file { for item in vec { visitor.visit_document12(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_payment_transaction104_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTransaction104) {
+	if let Some(ref mut vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12_mut(item) } }
+	if let Some(ref mut value) = node.pmt_cond_sts { visitor.visit_payment_condition_status1_mut(value) }
+	if let Some(ref mut vec) = node.chrgs_inf { for item in vec { visitor.visit_charges7_mut(item) } }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference29_mut(value) }
+	if let Some(ref mut vec) = node.nclsd_file { for item in vec { visitor.visit_document12_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_payment_transaction106<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTransaction106) {
+	if let Some(ref value) = node.case { visitor.visit_case5(value) }
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_information29(value) }
+	if let Some(ref value) = node.orgnl_intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.assgnr { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.assgne { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref vec) = node.cxl_rsn_inf { for item in vec { visitor.visit_payment_cancellation_reason5(item) } }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_payment_transaction106_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTransaction106) {
+	if let Some(ref mut value) = node.case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_information29_mut(value) }
+	if let Some(ref mut value) = node.orgnl_intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.assgnr { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.assgne { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut vec) = node.cxl_rsn_inf { for item in vec { visitor.visit_payment_cancellation_reason5_mut(item) } }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_payment_transaction107<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTransaction107) {
+	if let Some(ref value) = node.rslvd_case { visitor.visit_case5(value) }
+	visitor.visit_original_group_information29(&node.orgnl_grp_inf);
+	if let Some(ref vec) = node.mod_sts_rsn_inf { for item in vec { visitor.visit_modification_status_reason2(item) } }
+	if let Some(ref value) = node.rsltn_rltd_inf { visitor.visit_resolution_data1(value) }
+	if let Some(ref value) = node.orgnl_intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.assgnr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.assgne { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28(value) }
+}
+
+pub fn walk_payment_transaction107_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTransaction107) {
+	if let Some(ref mut value) = node.rslvd_case { visitor.visit_case5_mut(value) }
+	visitor.visit_original_group_information29_mut(&mut node.orgnl_grp_inf);
+	if let Some(ref mut vec) = node.mod_sts_rsn_inf { for item in vec { visitor.visit_modification_status_reason2_mut(item) } }
+	if let Some(ref mut value) = node.rsltn_rltd_inf { visitor.visit_resolution_data1_mut(value) }
+	if let Some(ref mut value) = node.orgnl_intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.assgnr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.assgne { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28_mut(value) }
+}
+
+pub fn walk_payment_transaction110<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTransaction110) {
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_information29(value) }
+	if let Some(ref vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12(item) } }
+	if let Some(ref vec) = node.chrgs_inf { for item in vec { visitor.visit_charges7(item) } }
+	if let Some(ref value) = node.fctv_intr_bk_sttlm_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_payment_transaction110_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTransaction110) {
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_information29_mut(value) }
+	if let Some(ref mut vec) = node.sts_rsn_inf { for item in vec { visitor.visit_status_reason_information12_mut(item) } }
+	if let Some(ref mut vec) = node.chrgs_inf { for item in vec { visitor.visit_charges7_mut(item) } }
+	if let Some(ref mut value) = node.fctv_intr_bk_sttlm_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_payment_transaction113<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTransaction113) {
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_information29(value) }
+	if let Some(ref value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_payment_transaction113_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTransaction113) {
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_information29_mut(value) }
+	if let Some(ref mut value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_payment_transaction118<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTransaction118) {
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_information29(value) }
+	if let Some(ref value) = node.orgnl_intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	visitor.visit_active_currency_and_amount(&node.rtrd_intr_bk_sttlm_amt);
+	if let Some(ref value) = node.sttlm_prty { visitor.visit_priority3_code(value) }
+	if let Some(ref value) = node.sttlm_tm_indctn { visitor.visit_settlement_date_time_indication1(value) }
+	if let Some(ref value) = node.rtrd_instd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.compstn_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.chrg_br { visitor.visit_charge_bearer_type1_code(value) }
+	if let Some(ref vec) = node.chrgs_inf { for item in vec { visitor.visit_charges7(item) } }
+	if let Some(ref value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.rtr_chain { visitor.visit_transaction_parties8(value) }
+	if let Some(ref vec) = node.rtr_rsn_inf { for item in vec { visitor.visit_payment_return_reason6(item) } }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference32(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_payment_transaction118_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTransaction118) {
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_information29_mut(value) }
+	if let Some(ref mut value) = node.orgnl_intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	visitor.visit_active_currency_and_amount_mut(&mut node.rtrd_intr_bk_sttlm_amt);
+	if let Some(ref mut value) = node.sttlm_prty { visitor.visit_priority3_code_mut(value) }
+	if let Some(ref mut value) = node.sttlm_tm_indctn { visitor.visit_settlement_date_time_indication1_mut(value) }
+	if let Some(ref mut value) = node.rtrd_instd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.compstn_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.chrg_br { visitor.visit_charge_bearer_type1_code_mut(value) }
+	if let Some(ref mut vec) = node.chrgs_inf { for item in vec { visitor.visit_charges7_mut(item) } }
+	if let Some(ref mut value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.rtr_chain { visitor.visit_transaction_parties8_mut(value) }
+	if let Some(ref mut vec) = node.rtr_rsn_inf { for item in vec { visitor.visit_payment_return_reason6_mut(item) } }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference32_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_payment_transaction124<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTransaction124) {
+	if let Some(ref value) = node.case { visitor.visit_case5(value) }
+	if let Some(ref value) = node.orgnl_instd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.orgnl_reqd_exctn_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref vec) = node.cxl_rsn_inf { for item in vec { visitor.visit_payment_cancellation_reason5(item) } }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference31(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_payment_transaction124_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTransaction124) {
+	if let Some(ref mut value) = node.case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut value) = node.orgnl_instd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.orgnl_reqd_exctn_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut vec) = node.cxl_rsn_inf { for item in vec { visitor.visit_payment_cancellation_reason5_mut(item) } }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference31_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_payment_type_information26<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTypeInformation26) {
+	if let Some(ref value) = node.instr_prty { visitor.visit_priority2_code(value) }
+	if let Some(ref vec) = node.svc_lvl { for item in vec { visitor.visit_service_level8_choice(item) } }
+	if let Some(ref value) = node.lcl_instrm { visitor.visit_local_instrument2_choice(value) }
+	if let Some(ref value) = node.ctgy_purp { visitor.visit_category_purpose1_choice(value) }
+}
+
+pub fn walk_payment_type_information26_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTypeInformation26) {
+	if let Some(ref mut value) = node.instr_prty { visitor.visit_priority2_code_mut(value) }
+	if let Some(ref mut vec) = node.svc_lvl { for item in vec { visitor.visit_service_level8_choice_mut(item) } }
+	if let Some(ref mut value) = node.lcl_instrm { visitor.visit_local_instrument2_choice_mut(value) }
+	if let Some(ref mut value) = node.ctgy_purp { visitor.visit_category_purpose1_choice_mut(value) }
+}
+
+pub fn walk_payment_type_information27<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTypeInformation27) {
+	if let Some(ref value) = node.instr_prty { visitor.visit_priority2_code(value) }
+	if let Some(ref value) = node.clr_chanl { visitor.visit_clearing_channel2_code(value) }
+	if let Some(ref vec) = node.svc_lvl { for item in vec { visitor.visit_service_level8_choice(item) } }
+	if let Some(ref value) = node.lcl_instrm { visitor.visit_local_instrument2_choice(value) }
+	if let Some(ref value) = node.seq_tp { visitor.visit_sequence_type3_code(value) }
+	if let Some(ref value) = node.ctgy_purp { visitor.visit_category_purpose1_choice(value) }
+}
+
+pub fn walk_payment_type_information27_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTypeInformation27) {
+	if let Some(ref mut value) = node.instr_prty { visitor.visit_priority2_code_mut(value) }
+	if let Some(ref mut value) = node.clr_chanl { visitor.visit_clearing_channel2_code_mut(value) }
+	if let Some(ref mut vec) = node.svc_lvl { for item in vec { visitor.visit_service_level8_choice_mut(item) } }
+	if let Some(ref mut value) = node.lcl_instrm { visitor.visit_local_instrument2_choice_mut(value) }
+	if let Some(ref mut value) = node.seq_tp { visitor.visit_sequence_type3_code_mut(value) }
+	if let Some(ref mut value) = node.ctgy_purp { visitor.visit_category_purpose1_choice_mut(value) }
+}
+
+pub fn walk_payment_type_information28<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PaymentTypeInformation28) {
+	if let Some(ref value) = node.instr_prty { visitor.visit_priority2_code(value) }
+	if let Some(ref value) = node.clr_chanl { visitor.visit_clearing_channel2_code(value) }
+	if let Some(ref vec) = node.svc_lvl { for item in vec { visitor.visit_service_level8_choice(item) } }
+	if let Some(ref value) = node.lcl_instrm { visitor.visit_local_instrument2_choice(value) }
+	if let Some(ref value) = node.ctgy_purp { visitor.visit_category_purpose1_choice(value) }
+}
+
+pub fn walk_payment_type_information28_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PaymentTypeInformation28) {
+	if let Some(ref mut value) = node.instr_prty { visitor.visit_priority2_code_mut(value) }
+	if let Some(ref mut value) = node.clr_chanl { visitor.visit_clearing_channel2_code_mut(value) }
+	if let Some(ref mut vec) = node.svc_lvl { for item in vec { visitor.visit_service_level8_choice_mut(item) } }
+	if let Some(ref mut value) = node.lcl_instrm { visitor.visit_local_instrument2_choice_mut(value) }
+	if let Some(ref mut value) = node.ctgy_purp { visitor.visit_category_purpose1_choice_mut(value) }
+}
+
+pub fn walk_person_identification13<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PersonIdentification13) {
+	if let Some(ref value) = node.dt_and_plc_of_birth { visitor.visit_date_and_place_of_birth1(value) }
+	if let Some(ref vec) = node.othr { for item in vec { visitor.visit_generic_person_identification1(item) } }
+}
+
+pub fn walk_person_identification13_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PersonIdentification13) {
+	if let Some(ref mut value) = node.dt_and_plc_of_birth { visitor.visit_date_and_place_of_birth1_mut(value) }
+	if let Some(ref mut vec) = node.othr { for item in vec { visitor.visit_generic_person_identification1_mut(item) } }
+}
+
+pub fn walk_person_identification_scheme_name1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::PersonIdentificationSchemeName1Choice) {
+}
+
+pub fn walk_person_identification_scheme_name1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::PersonIdentificationSchemeName1Choice) {
+}
+
+pub fn walk_plain_card_data1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PlainCardData1) {
+	if let Some(ref vec) = node.trck_data { for item in vec { visitor.visit_track_data1(item) } }
+	if let Some(ref value) = node.card_scty_cd { visitor.visit_card_security_information1(value) }
+}
+
+pub fn walk_plain_card_data1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PlainCardData1) {
+	if let Some(ref mut vec) = node.trck_data { for item in vec { visitor.visit_track_data1_mut(item) } }
+	if let Some(ref mut value) = node.card_scty_cd { visitor.visit_card_security_information1_mut(value) }
+}
+
+pub fn walk_point_of_interaction1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PointOfInteraction1) {
+	visitor.visit_generic_identification32(&node.id);
+	if let Some(ref value) = node.cpblties { visitor.visit_point_of_interaction_capabilities1(value) }
+	if let Some(ref vec) = node.cmpnt { for item in vec { visitor.visit_point_of_interaction_component1(item) } }
+}
+
+pub fn walk_point_of_interaction1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PointOfInteraction1) {
+	visitor.visit_generic_identification32_mut(&mut node.id);
+	if let Some(ref mut value) = node.cpblties { visitor.visit_point_of_interaction_capabilities1_mut(value) }
+	if let Some(ref mut vec) = node.cmpnt { for item in vec { visitor.visit_point_of_interaction_component1_mut(item) } }
+}
+
+pub fn walk_point_of_interaction_capabilities1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PointOfInteractionCapabilities1) {
+	if let Some(ref vec) = node.card_rdng_cpblties { for item in vec { visitor.visit_card_data_reading1_code(item) } }
+	if let Some(ref vec) = node.crdhldr_vrfctn_cpblties { for item in vec { visitor.visit_cardholder_verification_capability1_code(item) } }
+	if let Some(ref value) = node.on_line_cpblties { visitor.visit_on_line_capability1_code(value) }
+	if let Some(ref vec) = node.disp_cpblties { for item in vec { visitor.visit_display_capabilities1(item) } }
+}
+
+pub fn walk_point_of_interaction_capabilities1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PointOfInteractionCapabilities1) {
+	if let Some(ref mut vec) = node.card_rdng_cpblties { for item in vec { visitor.visit_card_data_reading1_code_mut(item) } }
+	if let Some(ref mut vec) = node.crdhldr_vrfctn_cpblties { for item in vec { visitor.visit_cardholder_verification_capability1_code_mut(item) } }
+	if let Some(ref mut value) = node.on_line_cpblties { visitor.visit_on_line_capability1_code_mut(value) }
+	if let Some(ref mut vec) = node.disp_cpblties { for item in vec { visitor.visit_display_capabilities1_mut(item) } }
+}
+
+pub fn walk_point_of_interaction_component1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PointOfInteractionComponent1) {
+	visitor.visit_poi_component_type1_code(&node.poi_cmpnt_tp);
+}
+
+pub fn walk_point_of_interaction_component1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PointOfInteractionComponent1) {
+	visitor.visit_poi_component_type1_code_mut(&mut node.poi_cmpnt_tp);
+}
+
+pub fn walk_postal_address1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PostalAddress1) {
+	if let Some(ref value) = node.adr_tp { visitor.visit_address_type2_code(value) }
+}
+
+pub fn walk_postal_address1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PostalAddress1) {
+	if let Some(ref mut value) = node.adr_tp { visitor.visit_address_type2_code_mut(value) }
+}
+
+pub fn walk_postal_address24<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PostalAddress24) {
+	if let Some(ref value) = node.adr_tp { visitor.visit_address_type3_choice(value) }
+}
+
+pub fn walk_postal_address24_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PostalAddress24) {
+	if let Some(ref mut value) = node.adr_tp { visitor.visit_address_type3_choice_mut(value) }
+}
+
+pub fn walk_preferred_contact_method1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::PreferredContactMethod1Code) {
+}
+
+pub fn walk_preferred_contact_method1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::PreferredContactMethod1Code) {
+}
+
+pub fn walk_price7<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Price7) {
+	visitor.visit_yielded_or_value_type1_choice(&node.tp);
+	visitor.visit_price_rate_or_amount3_choice(&node.val);
+}
+
+pub fn walk_price7_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Price7) {
+	visitor.visit_yielded_or_value_type1_choice_mut(&mut node.tp);
+	visitor.visit_price_rate_or_amount3_choice_mut(&mut node.val);
+}
+
+pub fn walk_price_rate_or_amount3_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::PriceRateOrAmount3Choice) {
+	if let Some(ref value) = node.amt { visitor.visit_active_or_historic_currency_and13_decimal_amount(value) }
+}
+
+pub fn walk_price_rate_or_amount3_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::PriceRateOrAmount3Choice) {
+	if let Some(ref mut value) = node.amt { visitor.visit_active_or_historic_currency_and13_decimal_amount_mut(value) }
+}
+
+pub fn walk_price_value_type1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::PriceValueType1Code) {
+}
+
+pub fn walk_price_value_type1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::PriceValueType1Code) {
+}
+
+pub fn walk_priority2_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Priority2Code) {
+}
+
+pub fn walk_priority2_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Priority2Code) {
+}
+
+pub fn walk_priority3_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Priority3Code) {
+}
+
+pub fn walk_priority3_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Priority3Code) {
+}
+
+pub fn walk_product2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Product2) {
+	if let Some(ref value) = node.unit_of_measr { visitor.visit_unit_of_measure1_code(value) }
+}
+
+pub fn walk_product2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Product2) {
+	if let Some(ref mut value) = node.unit_of_measr { visitor.visit_unit_of_measure1_code_mut(value) }
+}
+
+pub fn walk_proprietary_agent4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ProprietaryAgent4) {
+	visitor.visit_branch_and_financial_institution_identification6(&node.agt);
+}
+
+pub fn walk_proprietary_agent4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ProprietaryAgent4) {
+	visitor.visit_branch_and_financial_institution_identification6_mut(&mut node.agt);
+}
+
+pub fn walk_proprietary_bank_transaction_code_structure1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ProprietaryBankTransactionCodeStructure1) {
+}
+
+pub fn walk_proprietary_bank_transaction_code_structure1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ProprietaryBankTransactionCodeStructure1) {
+}
+
+pub fn walk_proprietary_data5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ProprietaryData5) {
+	visitor.visit_supplementary_data_envelope1(&node.data);
+}
+
+pub fn walk_proprietary_data5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ProprietaryData5) {
+	visitor.visit_supplementary_data_envelope1_mut(&mut node.data);
+}
+
+pub fn walk_proprietary_date3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ProprietaryDate3) {
+	visitor.visit_date_and_date_time2_choice(&node.dt);
+}
+
+pub fn walk_proprietary_date3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ProprietaryDate3) {
+	visitor.visit_date_and_date_time2_choice_mut(&mut node.dt);
+}
+
+pub fn walk_proprietary_party5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ProprietaryParty5) {
+	visitor.visit_party40_choice(&node.pty);
+}
+
+pub fn walk_proprietary_party5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ProprietaryParty5) {
+	visitor.visit_party40_choice_mut(&mut node.pty);
+}
+
+pub fn walk_proprietary_price2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ProprietaryPrice2) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.pric);
+}
+
+pub fn walk_proprietary_price2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ProprietaryPrice2) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.pric);
+}
+
+pub fn walk_proprietary_quantity1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ProprietaryQuantity1) {
+}
+
+pub fn walk_proprietary_quantity1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ProprietaryQuantity1) {
+}
+
+pub fn walk_proprietary_reference1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ProprietaryReference1) {
+}
+
+pub fn walk_proprietary_reference1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ProprietaryReference1) {
+}
+
+pub fn walk_proxy_account_identification1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ProxyAccountIdentification1) {
+	if let Some(ref value) = node.tp { visitor.visit_proxy_account_type1_choice(value) }
+}
+
+pub fn walk_proxy_account_identification1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ProxyAccountIdentification1) {
+	if let Some(ref mut value) = node.tp { visitor.visit_proxy_account_type1_choice_mut(value) }
+}
+
+pub fn walk_proxy_account_type1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ProxyAccountType1Choice) {
+}
+
+pub fn walk_proxy_account_type1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ProxyAccountType1Choice) {
+}
+
+pub fn walk_purpose2_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::Purpose2Choice) {
+}
+
+pub fn walk_purpose2_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::Purpose2Choice) {
+}
+
+pub fn walk_query_type3_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::QueryType3Code) {
+}
+
+pub fn walk_query_type3_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::QueryType3Code) {
+}
+
+pub fn walk_rate4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::Rate4) {
+	visitor.visit_rate_type4_choice(&node.tp);
+	if let Some(ref value) = node.vldty_rg { visitor.visit_active_or_historic_currency_and_amount_range2(value) }
+}
+
+pub fn walk_rate4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::Rate4) {
+	visitor.visit_rate_type4_choice_mut(&mut node.tp);
+	if let Some(ref mut value) = node.vldty_rg { visitor.visit_active_or_historic_currency_and_amount_range2_mut(value) }
+}
+
+pub fn walk_rate_type4_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::RateType4Choice) {
+}
+
+pub fn walk_rate_type4_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::RateType4Choice) {
+}
+
+pub fn walk_receipt_acknowledgement_report2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ReceiptAcknowledgementReport2) {
+	visitor.visit_message_reference1(&node.rltd_ref);
+	visitor.visit_request_handling2(&node.req_hdlg);
+}
+
+pub fn walk_receipt_acknowledgement_report2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ReceiptAcknowledgementReport2) {
+	visitor.visit_message_reference1_mut(&mut node.rltd_ref);
+	visitor.visit_request_handling2_mut(&mut node.req_hdlg);
+}
+
+pub fn walk_referred_document_information7<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ReferredDocumentInformation7) {
+	if let Some(ref value) = node.tp { visitor.visit_referred_document_type4(value) }
+	if let Some(ref vec) = node.line_dtls { for item in vec { visitor.visit_document_line_information1(item) } }
+}
+
+pub fn walk_referred_document_information7_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ReferredDocumentInformation7) {
+	if let Some(ref mut value) = node.tp { visitor.visit_referred_document_type4_mut(value) }
+	if let Some(ref mut vec) = node.line_dtls { for item in vec { visitor.visit_document_line_information1_mut(item) } }
+}
+
+pub fn walk_referred_document_type3_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ReferredDocumentType3Choice) {
+	if let Some(ref value) = node.cd { visitor.visit_document_type6_code(value) }
+}
+
+pub fn walk_referred_document_type3_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ReferredDocumentType3Choice) {
+	if let Some(ref mut value) = node.cd { visitor.visit_document_type6_code_mut(value) }
+}
+
+pub fn walk_referred_document_type4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ReferredDocumentType4) {
+	visitor.visit_referred_document_type3_choice(&node.cd_or_prtry);
+}
+
+pub fn walk_referred_document_type4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ReferredDocumentType4) {
+	visitor.visit_referred_document_type3_choice_mut(&mut node.cd_or_prtry);
+}
+
+pub fn walk_regulatory_authority2<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::RegulatoryAuthority2) {
+}
+
+pub fn walk_regulatory_authority2_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::RegulatoryAuthority2) {
+}
+
+pub fn walk_regulatory_reporting3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::RegulatoryReporting3) {
+	if let Some(ref value) = node.dbt_cdt_rptg_ind { visitor.visit_regulatory_reporting_type1_code(value) }
+	if let Some(ref value) = node.authrty { visitor.visit_regulatory_authority2(value) }
+	if let Some(ref vec) = node.dtls { for item in vec { visitor.visit_structured_regulatory_reporting3(item) } }
+}
+
+pub fn walk_regulatory_reporting3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::RegulatoryReporting3) {
+	if let Some(ref mut value) = node.dbt_cdt_rptg_ind { visitor.visit_regulatory_reporting_type1_code_mut(value) }
+	if let Some(ref mut value) = node.authrty { visitor.visit_regulatory_authority2_mut(value) }
+	if let Some(ref mut vec) = node.dtls { for item in vec { visitor.visit_structured_regulatory_reporting3_mut(item) } }
+}
+
+pub fn walk_regulatory_reporting_type1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::RegulatoryReportingType1Code) {
+}
+
+pub fn walk_regulatory_reporting_type1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::RegulatoryReportingType1Code) {
+}
+
+pub fn walk_rejection_reason2<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::RejectionReason2) {
+}
+
+pub fn walk_rejection_reason2_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::RejectionReason2) {
+}
+
+pub fn walk_remittance_amount2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::RemittanceAmount2) {
+	if let Some(ref value) = node.due_pybl_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.dscnt_apld_amt { for item in vec { visitor.visit_discount_amount_and_type1(item) } }
+	if let Some(ref value) = node.cdt_note_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.tax_amt { for item in vec { visitor.visit_tax_amount_and_type1(item) } }
+	if let Some(ref vec) = node.adjstmnt_amt_and_rsn { for item in vec { visitor.visit_document_adjustment1(item) } }
+	if let Some(ref value) = node.rmtd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+}
+
+pub fn walk_remittance_amount2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::RemittanceAmount2) {
+	if let Some(ref mut value) = node.due_pybl_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.dscnt_apld_amt { for item in vec { visitor.visit_discount_amount_and_type1_mut(item) } }
+	if let Some(ref mut value) = node.cdt_note_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.tax_amt { for item in vec { visitor.visit_tax_amount_and_type1_mut(item) } }
+	if let Some(ref mut vec) = node.adjstmnt_amt_and_rsn { for item in vec { visitor.visit_document_adjustment1_mut(item) } }
+	if let Some(ref mut value) = node.rmtd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+}
+
+pub fn walk_remittance_amount3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::RemittanceAmount3) {
+	if let Some(ref value) = node.due_pybl_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.dscnt_apld_amt { for item in vec { visitor.visit_discount_amount_and_type1(item) } }
+	if let Some(ref value) = node.cdt_note_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.tax_amt { for item in vec { visitor.visit_tax_amount_and_type1(item) } }
+	if let Some(ref vec) = node.adjstmnt_amt_and_rsn { for item in vec { visitor.visit_document_adjustment1(item) } }
+	if let Some(ref value) = node.rmtd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+}
+
+pub fn walk_remittance_amount3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::RemittanceAmount3) {
+	if let Some(ref mut value) = node.due_pybl_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.dscnt_apld_amt { for item in vec { visitor.visit_discount_amount_and_type1_mut(item) } }
+	if let Some(ref mut value) = node.cdt_note_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.tax_amt { for item in vec { visitor.visit_tax_amount_and_type1_mut(item) } }
+	if let Some(ref mut vec) = node.adjstmnt_amt_and_rsn { for item in vec { visitor.visit_document_adjustment1_mut(item) } }
+	if let Some(ref mut value) = node.rmtd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+}
+
+pub fn walk_remittance_information16<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::RemittanceInformation16) {
+	if let Some(ref vec) = node.strd { for item in vec { visitor.visit_structured_remittance_information16(item) } }
+}
+
+pub fn walk_remittance_information16_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::RemittanceInformation16) {
+	if let Some(ref mut vec) = node.strd { for item in vec { visitor.visit_structured_remittance_information16_mut(item) } }
+}
+
+pub fn walk_remittance_information2<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::RemittanceInformation2) {
+}
+
+pub fn walk_remittance_information2_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::RemittanceInformation2) {
+}
+
+pub fn walk_remittance_location7<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::RemittanceLocation7) {
+	if let Some(ref vec) = node.rmt_lctn_dtls { for item in vec { visitor.visit_remittance_location_data1(item) } }
+}
+
+pub fn walk_remittance_location7_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::RemittanceLocation7) {
+	if let Some(ref mut vec) = node.rmt_lctn_dtls { for item in vec { visitor.visit_remittance_location_data1_mut(item) } }
+}
+
+pub fn walk_remittance_location_data1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::RemittanceLocationData1) {
+	visitor.visit_remittance_location_method2_code(&node.mtd);
+	if let Some(ref value) = node.pstl_adr { visitor.visit_name_and_address16(value) }
+}
+
+pub fn walk_remittance_location_data1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::RemittanceLocationData1) {
+	visitor.visit_remittance_location_method2_code_mut(&mut node.mtd);
+	if let Some(ref mut value) = node.pstl_adr { visitor.visit_name_and_address16_mut(value) }
+}
+
+pub fn walk_remittance_location_method2_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::RemittanceLocationMethod2Code) {
+}
+
+pub fn walk_remittance_location_method2_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::RemittanceLocationMethod2Code) {
+}
+
+pub fn walk_report_entry10<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ReportEntry10) {
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+	visitor.visit_credit_debit_code(&node.cdt_dbt_ind);
+	visitor.visit_entry_status1_choice(&node.sts);
+	if let Some(ref value) = node.bookg_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.val_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref vec) = node.avlbty { for item in vec { visitor.visit_cash_availability1(item) } }
+	visitor.visit_bank_transaction_code_structure4(&node.bk_tx_cd);
+	if let Some(ref value) = node.addtl_inf_ind { visitor.visit_message_identification2(value) }
+	if let Some(ref value) = node.amt_dtls { visitor.visit_amount_and_currency_exchange3(value) }
+	if let Some(ref value) = node.chrgs { visitor.visit_charges6(value) }
+	if let Some(ref value) = node.tech_inpt_chanl { visitor.visit_technical_input_channel1_choice(value) }
+	if let Some(ref value) = node.intrst { visitor.visit_transaction_interest4(value) }
+	if let Some(ref value) = node.card_tx { visitor.visit_card_entry4(value) }
+	if let Some(ref vec) = node.ntry_dtls { for item in vec { visitor.visit_entry_details9(item) } }
+}
+
+pub fn walk_report_entry10_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ReportEntry10) {
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+	visitor.visit_credit_debit_code_mut(&mut node.cdt_dbt_ind);
+	visitor.visit_entry_status1_choice_mut(&mut node.sts);
+	if let Some(ref mut value) = node.bookg_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.val_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut vec) = node.avlbty { for item in vec { visitor.visit_cash_availability1_mut(item) } }
+	visitor.visit_bank_transaction_code_structure4_mut(&mut node.bk_tx_cd);
+	if let Some(ref mut value) = node.addtl_inf_ind { visitor.visit_message_identification2_mut(value) }
+	if let Some(ref mut value) = node.amt_dtls { visitor.visit_amount_and_currency_exchange3_mut(value) }
+	if let Some(ref mut value) = node.chrgs { visitor.visit_charges6_mut(value) }
+	if let Some(ref mut value) = node.tech_inpt_chanl { visitor.visit_technical_input_channel1_choice_mut(value) }
+	if let Some(ref mut value) = node.intrst { visitor.visit_transaction_interest4_mut(value) }
+	if let Some(ref mut value) = node.card_tx { visitor.visit_card_entry4_mut(value) }
+	if let Some(ref mut vec) = node.ntry_dtls { for item in vec { visitor.visit_entry_details9_mut(item) } }
+}
+
+pub fn walk_reporting_period2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ReportingPeriod2) {
+	visitor.visit_date_period_details1(&node.fr_to_dt);
+	if let Some(ref value) = node.fr_to_tm { visitor.visit_time_period_details1(value) }
+	visitor.visit_query_type3_code(&node.tp);
+}
+
+pub fn walk_reporting_period2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ReportingPeriod2) {
+	visitor.visit_date_period_details1_mut(&mut node.fr_to_dt);
+	if let Some(ref mut value) = node.fr_to_tm { visitor.visit_time_period_details1_mut(value) }
+	visitor.visit_query_type3_code_mut(&mut node.tp);
+}
+
+pub fn walk_reporting_request5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ReportingRequest5) {
+	if let Some(ref value) = node.acct { visitor.visit_cash_account38(value) }
+	visitor.visit_party40_choice(&node.acct_ownr);
+	if let Some(ref value) = node.acct_svcr { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.rptg_prd { visitor.visit_reporting_period2(value) }
+	if let Some(ref value) = node.rptg_seq { visitor.visit_sequence_range1_choice(value) }
+	if let Some(ref value) = node.reqd_tx_tp { visitor.visit_transaction_type2(value) }
+	if let Some(ref vec) = node.reqd_bal_tp { for item in vec { visitor.visit_balance_type13(item) } }
+}
+
+pub fn walk_reporting_request5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ReportingRequest5) {
+	if let Some(ref mut value) = node.acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_party40_choice_mut(&mut node.acct_ownr);
+	if let Some(ref mut value) = node.acct_svcr { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.rptg_prd { visitor.visit_reporting_period2_mut(value) }
+	if let Some(ref mut value) = node.rptg_seq { visitor.visit_sequence_range1_choice_mut(value) }
+	if let Some(ref mut value) = node.reqd_tx_tp { visitor.visit_transaction_type2_mut(value) }
+	if let Some(ref mut vec) = node.reqd_bal_tp { for item in vec { visitor.visit_balance_type13_mut(item) } }
+}
+
+pub fn walk_reporting_source1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ReportingSource1Choice) {
+}
+
+pub fn walk_reporting_source1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ReportingSource1Choice) {
+}
+
+pub fn walk_request_handling2<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::RequestHandling2) {
+}
+
+pub fn walk_request_handling2_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::RequestHandling2) {
+}
+
+pub fn walk_request_type4_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::RequestType4Choice) {
+	if let Some(ref value) = node.prtry { visitor.visit_generic_identification1(value) }
+}
+
+pub fn walk_request_type4_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::RequestType4Choice) {
+	if let Some(ref mut value) = node.prtry { visitor.visit_generic_identification1_mut(value) }
+}
+
+pub fn walk_resend_search_criteria2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ResendSearchCriteria2) {
+	if let Some(ref value) = node.seq_rg { visitor.visit_sequence_range1_choice(value) }
+	visitor.visit_party_identification136(&node.rcpt);
+}
+
+pub fn walk_resend_search_criteria2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ResendSearchCriteria2) {
+	if let Some(ref mut value) = node.seq_rg { visitor.visit_sequence_range1_choice_mut(value) }
+	visitor.visit_party_identification136_mut(&mut node.rcpt);
+}
+
+pub fn walk_resolution_data1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::ResolutionData1) {
+	if let Some(ref value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.clr_chanl { visitor.visit_clearing_channel2_code(value) }
+	if let Some(ref value) = node.compstn { visitor.visit_compensation2(value) }
+	if let Some(ref vec) = node.chrgs { for item in vec { visitor.visit_charges7(item) } }
+}
+
+pub fn walk_resolution_data1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::ResolutionData1) {
+	if let Some(ref mut value) = node.intr_bk_sttlm_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.clr_chanl { visitor.visit_clearing_channel2_code_mut(value) }
+	if let Some(ref mut value) = node.compstn { visitor.visit_compensation2_mut(value) }
+	if let Some(ref mut vec) = node.chrgs { for item in vec { visitor.visit_charges7_mut(item) } }
+}
+
+pub fn walk_return_reason5_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ReturnReason5Choice) {
+}
+
+pub fn walk_return_reason5_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ReturnReason5Choice) {
+}
+
+pub fn walk_securities_account19<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::SecuritiesAccount19) {
+	if let Some(ref value) = node.tp { visitor.visit_generic_identification30(value) }
+}
+
+pub fn walk_securities_account19_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::SecuritiesAccount19) {
+	if let Some(ref mut value) = node.tp { visitor.visit_generic_identification30_mut(value) }
+}
+
+pub fn walk_security_identification19<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::SecurityIdentification19) {
+	if let Some(ref vec) = node.othr_id { for item in vec { visitor.visit_other_identification1(item) } }
+}
+
+pub fn walk_security_identification19_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::SecurityIdentification19) {
+	if let Some(ref mut vec) = node.othr_id { for item in vec { visitor.visit_other_identification1_mut(item) } }
+}
+
+pub fn walk_sequence_range1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::SequenceRange1) {
+}
+
+pub fn walk_sequence_range1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::SequenceRange1) {
+}
+
+pub fn walk_sequence_range1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::SequenceRange1Choice) {
+	if let Some(ref vec) = node.fr_to_seq { for item in vec { visitor.visit_sequence_range1(item) } }
+}
+
+pub fn walk_sequence_range1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::SequenceRange1Choice) {
+	if let Some(ref mut vec) = node.fr_to_seq { for item in vec { visitor.visit_sequence_range1_mut(item) } }
+}
+
+pub fn walk_sequence_type3_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::SequenceType3Code) {
+}
+
+pub fn walk_sequence_type3_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::SequenceType3Code) {
+}
+
+pub fn walk_service_level8_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::ServiceLevel8Choice) {
+}
+
+pub fn walk_service_level8_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::ServiceLevel8Choice) {
+}
+
+pub fn walk_settlement_date_time_indication1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::SettlementDateTimeIndication1) {
+}
+
+pub fn walk_settlement_date_time_indication1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::SettlementDateTimeIndication1) {
+}
+
+pub fn walk_settlement_instruction7<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::SettlementInstruction7) {
+	visitor.visit_settlement_method1_code(&node.sttlm_mtd);
+	if let Some(ref value) = node.sttlm_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.clr_sys { visitor.visit_clearing_system_identification3_choice(value) }
+	if let Some(ref value) = node.instg_rmbrsmnt_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instg_rmbrsmnt_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.instd_rmbrsmnt_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_rmbrsmnt_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.thrd_rmbrsmnt_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.thrd_rmbrsmnt_agt_acct { visitor.visit_cash_account38(value) }
+}
+
+pub fn walk_settlement_instruction7_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::SettlementInstruction7) {
+	visitor.visit_settlement_method1_code_mut(&mut node.sttlm_mtd);
+	if let Some(ref mut value) = node.sttlm_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.clr_sys { visitor.visit_clearing_system_identification3_choice_mut(value) }
+	if let Some(ref mut value) = node.instg_rmbrsmnt_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instg_rmbrsmnt_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.instd_rmbrsmnt_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_rmbrsmnt_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.thrd_rmbrsmnt_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.thrd_rmbrsmnt_agt_acct { visitor.visit_cash_account38_mut(value) }
+}
+
+pub fn walk_settlement_method1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::SettlementMethod1Code) {
+}
+
+pub fn walk_settlement_method1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::SettlementMethod1Code) {
+}
+
+pub fn walk_settlement_time_request2<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::SettlementTimeRequest2) {
+}
+
+pub fn walk_settlement_time_request2_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::SettlementTimeRequest2) {
+}
+
+pub fn walk_signature_envelope<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::SignatureEnvelope) {
+}
+
+pub fn walk_signature_envelope_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::SignatureEnvelope) {
+}
+
+pub fn walk_skip_payload<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::SkipPayload) {
+}
+
+pub fn walk_skip_payload_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::SkipPayload) {
+}
+
+pub fn walk_statement_resolution_entry4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::StatementResolutionEntry4) {
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_information29(value) }
+	if let Some(ref value) = node.crrctd_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.chrgs { for item in vec { visitor.visit_charges6(item) } }
+	if let Some(ref value) = node.purp { visitor.visit_purpose2_choice(value) }
+}
+
+pub fn walk_statement_resolution_entry4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::StatementResolutionEntry4) {
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_information29_mut(value) }
+	if let Some(ref mut value) = node.crrctd_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.chrgs { for item in vec { visitor.visit_charges6_mut(item) } }
+	if let Some(ref mut value) = node.purp { visitor.visit_purpose2_choice_mut(value) }
+}
+
+pub fn walk_status_reason6_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::StatusReason6Choice) {
+}
+
+pub fn walk_status_reason6_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::StatusReason6Choice) {
+}
+
+pub fn walk_status_reason_information12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::StatusReasonInformation12) {
+	if let Some(ref value) = node.orgtr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.rsn { visitor.visit_status_reason6_choice(value) }
+}
+
+pub fn walk_status_reason_information12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::StatusReasonInformation12) {
+	if let Some(ref mut value) = node.orgtr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.rsn { visitor.visit_status_reason6_choice_mut(value) }
+}
+
+pub fn walk_structured_regulatory_reporting3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::StructuredRegulatoryReporting3) {
+	if let Some(ref value) = node.amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+}
+
+pub fn walk_structured_regulatory_reporting3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::StructuredRegulatoryReporting3) {
+	if let Some(ref mut value) = node.amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+}
+
+pub fn walk_structured_remittance_information16<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::StructuredRemittanceInformation16) {
+	if let Some(ref vec) = node.rfrd_doc_inf { for item in vec { visitor.visit_referred_document_information7(item) } }
+	if let Some(ref value) = node.rfrd_doc_amt { visitor.visit_remittance_amount2(value) }
+	if let Some(ref value) = node.cdtr_ref_inf { visitor.visit_creditor_reference_information2(value) }
+	if let Some(ref value) = node.invcr { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.invcee { visitor.visit_party_identification135(value) }
+	if let Some(ref value) = node.tax_rmt { visitor.visit_tax_information7(value) }
+	if let Some(ref value) = node.grnshmt_rmt { visitor.visit_garnishment3(value) }
+}
+
+pub fn walk_structured_remittance_information16_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::StructuredRemittanceInformation16) {
+	if let Some(ref mut vec) = node.rfrd_doc_inf { for item in vec { visitor.visit_referred_document_information7_mut(item) } }
+	if let Some(ref mut value) = node.rfrd_doc_amt { visitor.visit_remittance_amount2_mut(value) }
+	if let Some(ref mut value) = node.cdtr_ref_inf { visitor.visit_creditor_reference_information2_mut(value) }
+	if let Some(ref mut value) = node.invcr { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.invcee { visitor.visit_party_identification135_mut(value) }
+	if let Some(ref mut value) = node.tax_rmt { visitor.visit_tax_information7_mut(value) }
+	if let Some(ref mut value) = node.grnshmt_rmt { visitor.visit_garnishment3_mut(value) }
+}
+
+pub fn walk_supplementary_data1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::SupplementaryData1) {
+	visitor.visit_supplementary_data_envelope1(&node.envlp);
+}
+
+pub fn walk_supplementary_data1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::SupplementaryData1) {
+	visitor.visit_supplementary_data_envelope1_mut(&mut node.envlp);
+}
+
+pub fn walk_supplementary_data_envelope1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::SupplementaryDataEnvelope1) {
+}
+
+pub fn walk_supplementary_data_envelope1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::SupplementaryDataEnvelope1) {
+}
+
+pub fn walk_tax_amount2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TaxAmount2) {
+	if let Some(ref value) = node.taxbl_base_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.ttl_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.dtls { for item in vec { visitor.visit_tax_record_details2(item) } }
+}
+
+pub fn walk_tax_amount2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TaxAmount2) {
+	if let Some(ref mut value) = node.taxbl_base_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.ttl_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.dtls { for item in vec { visitor.visit_tax_record_details2_mut(item) } }
+}
+
+pub fn walk_tax_amount_and_type1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TaxAmountAndType1) {
+	if let Some(ref value) = node.tp { visitor.visit_tax_amount_type1_choice(value) }
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+}
+
+pub fn walk_tax_amount_and_type1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TaxAmountAndType1) {
+	if let Some(ref mut value) = node.tp { visitor.visit_tax_amount_type1_choice_mut(value) }
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+}
+
+pub fn walk_tax_amount_type1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TaxAmountType1Choice) {
+}
+
+pub fn walk_tax_amount_type1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TaxAmountType1Choice) {
+}
+
+pub fn walk_tax_authorisation1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TaxAuthorisation1) {
+}
+
+pub fn walk_tax_authorisation1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TaxAuthorisation1) {
+}
+
+pub fn walk_tax_charges2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TaxCharges2) {
+	if let Some(ref value) = node.amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+}
+
+pub fn walk_tax_charges2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TaxCharges2) {
+	if let Some(ref mut value) = node.amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+}
+
+pub fn walk_tax_information7<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TaxInformation7) {
+	if let Some(ref value) = node.cdtr { visitor.visit_tax_party1(value) }
+	if let Some(ref value) = node.dbtr { visitor.visit_tax_party2(value) }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_tax_party2(value) }
+	if let Some(ref value) = node.ttl_taxbl_base_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.ttl_tax_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.rcrd { for item in vec { visitor.visit_tax_record2(item) } }
+}
+
+pub fn walk_tax_information7_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TaxInformation7) {
+	if let Some(ref mut value) = node.cdtr { visitor.visit_tax_party1_mut(value) }
+	if let Some(ref mut value) = node.dbtr { visitor.visit_tax_party2_mut(value) }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_tax_party2_mut(value) }
+	if let Some(ref mut value) = node.ttl_taxbl_base_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.ttl_tax_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.rcrd { for item in vec { visitor.visit_tax_record2_mut(item) } }
+}
+
+pub fn walk_tax_information8<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TaxInformation8) {
+	if let Some(ref value) = node.cdtr { visitor.visit_tax_party1(value) }
+	if let Some(ref value) = node.dbtr { visitor.visit_tax_party2(value) }
+	if let Some(ref value) = node.ttl_taxbl_base_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref value) = node.ttl_tax_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.rcrd { for item in vec { visitor.visit_tax_record2(item) } }
+}
+
+pub fn walk_tax_information8_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TaxInformation8) {
+	if let Some(ref mut value) = node.cdtr { visitor.visit_tax_party1_mut(value) }
+	if let Some(ref mut value) = node.dbtr { visitor.visit_tax_party2_mut(value) }
+	if let Some(ref mut value) = node.ttl_taxbl_base_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut value) = node.ttl_tax_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.rcrd { for item in vec { visitor.visit_tax_record2_mut(item) } }
+}
+
+pub fn walk_tax_party1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TaxParty1) {
+}
+
+pub fn walk_tax_party1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TaxParty1) {
+}
+
+pub fn walk_tax_party2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TaxParty2) {
+	if let Some(ref value) = node.authstn { visitor.visit_tax_authorisation1(value) }
+}
+
+pub fn walk_tax_party2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TaxParty2) {
+	if let Some(ref mut value) = node.authstn { visitor.visit_tax_authorisation1_mut(value) }
+}
+
+pub fn walk_tax_period2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TaxPeriod2) {
+	if let Some(ref value) = node.tp { visitor.visit_tax_record_period1_code(value) }
+	if let Some(ref value) = node.fr_to_dt { visitor.visit_date_period2(value) }
+}
+
+pub fn walk_tax_period2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TaxPeriod2) {
+	if let Some(ref mut value) = node.tp { visitor.visit_tax_record_period1_code_mut(value) }
+	if let Some(ref mut value) = node.fr_to_dt { visitor.visit_date_period2_mut(value) }
+}
+
+pub fn walk_tax_record2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TaxRecord2) {
+	if let Some(ref value) = node.prd { visitor.visit_tax_period2(value) }
+	if let Some(ref value) = node.tax_amt { visitor.visit_tax_amount2(value) }
+}
+
+pub fn walk_tax_record2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TaxRecord2) {
+	if let Some(ref mut value) = node.prd { visitor.visit_tax_period2_mut(value) }
+	if let Some(ref mut value) = node.tax_amt { visitor.visit_tax_amount2_mut(value) }
+}
+
+pub fn walk_tax_record_details2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TaxRecordDetails2) {
+	if let Some(ref value) = node.prd { visitor.visit_tax_period2(value) }
+	visitor.visit_active_or_historic_currency_and_amount(&node.amt);
+}
+
+pub fn walk_tax_record_details2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TaxRecordDetails2) {
+	if let Some(ref mut value) = node.prd { visitor.visit_tax_period2_mut(value) }
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.amt);
+}
+
+pub fn walk_tax_record_period1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TaxRecordPeriod1Code) {
+}
+
+pub fn walk_tax_record_period1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TaxRecordPeriod1Code) {
+}
+
+pub fn walk_technical_input_channel1_choice<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TechnicalInputChannel1Choice) {
+}
+
+pub fn walk_technical_input_channel1_choice_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TechnicalInputChannel1Choice) {
+}
+
+pub fn walk_time_period_details1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TimePeriodDetails1) {
+}
+
+pub fn walk_time_period_details1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TimePeriodDetails1) {
+}
+
+pub fn walk_total_transactions6<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TotalTransactions6) {
+	if let Some(ref value) = node.ttl_ntries { visitor.visit_number_and_sum_of_transactions4(value) }
+	if let Some(ref value) = node.ttl_cdt_ntries { visitor.visit_number_and_sum_of_transactions1(value) }
+	if let Some(ref value) = node.ttl_dbt_ntries { visitor.visit_number_and_sum_of_transactions1(value) }
+	if let Some(ref vec) = node.ttl_ntries_per_bk_tx_cd { for item in vec { visitor.visit_totals_per_bank_transaction_code5(item) } }
+}
+
+pub fn walk_total_transactions6_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TotalTransactions6) {
+	if let Some(ref mut value) = node.ttl_ntries { visitor.visit_number_and_sum_of_transactions4_mut(value) }
+	if let Some(ref mut value) = node.ttl_cdt_ntries { visitor.visit_number_and_sum_of_transactions1_mut(value) }
+	if let Some(ref mut value) = node.ttl_dbt_ntries { visitor.visit_number_and_sum_of_transactions1_mut(value) }
+	if let Some(ref mut vec) = node.ttl_ntries_per_bk_tx_cd { for item in vec { visitor.visit_totals_per_bank_transaction_code5_mut(item) } }
+}
+
+pub fn walk_totals_per_bank_transaction_code5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TotalsPerBankTransactionCode5) {
+	if let Some(ref value) = node.ttl_net_ntry { visitor.visit_amount_and_direction35(value) }
+	if let Some(ref value) = node.cdt_ntries { visitor.visit_number_and_sum_of_transactions1(value) }
+	if let Some(ref value) = node.dbt_ntries { visitor.visit_number_and_sum_of_transactions1(value) }
+	visitor.visit_bank_transaction_code_structure4(&node.bk_tx_cd);
+	if let Some(ref vec) = node.avlbty { for item in vec { visitor.visit_cash_availability1(item) } }
+	if let Some(ref value) = node.dt { visitor.visit_date_and_date_time2_choice(value) }
+}
+
+pub fn walk_totals_per_bank_transaction_code5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TotalsPerBankTransactionCode5) {
+	if let Some(ref mut value) = node.ttl_net_ntry { visitor.visit_amount_and_direction35_mut(value) }
+	if let Some(ref mut value) = node.cdt_ntries { visitor.visit_number_and_sum_of_transactions1_mut(value) }
+	if let Some(ref mut value) = node.dbt_ntries { visitor.visit_number_and_sum_of_transactions1_mut(value) }
+	visitor.visit_bank_transaction_code_structure4_mut(&mut node.bk_tx_cd);
+	if let Some(ref mut vec) = node.avlbty { for item in vec { visitor.visit_cash_availability1_mut(item) } }
+	if let Some(ref mut value) = node.dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+}
+
+pub fn walk_track_data1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TrackData1) {
+}
+
+pub fn walk_track_data1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TrackData1) {
+}
+
+pub fn walk_transaction_agents5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TransactionAgents5) {
+	if let Some(ref value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.rcvg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.dlvrg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.issg_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.sttlm_plc { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref vec) = node.prtry { for item in vec { visitor.visit_proprietary_agent4(item) } }
+}
+
+pub fn walk_transaction_agents5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TransactionAgents5) {
+	if let Some(ref mut value) = node.instg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.instd_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.rcvg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.dlvrg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.issg_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.sttlm_plc { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut vec) = node.prtry { for item in vec { visitor.visit_proprietary_agent4_mut(item) } }
+}
+
+pub fn walk_transaction_channel1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TransactionChannel1Code) {
+}
+
+pub fn walk_transaction_channel1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TransactionChannel1Code) {
+}
+
+pub fn walk_transaction_dates3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TransactionDates3) {
+	if let Some(ref vec) = node.prtry { for item in vec { visitor.visit_proprietary_date3(item) } }
+}
+
+pub fn walk_transaction_dates3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TransactionDates3) {
+	if let Some(ref mut vec) = node.prtry { for item in vec { visitor.visit_proprietary_date3_mut(item) } }
+}
+
+pub fn walk_transaction_environment1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TransactionEnvironment1Code) {
+}
+
+pub fn walk_transaction_environment1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TransactionEnvironment1Code) {
+}
+
+pub fn walk_transaction_identifier1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TransactionIdentifier1) {
+}
+
+pub fn walk_transaction_identifier1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TransactionIdentifier1) {
+}
+
+pub fn walk_transaction_individual_status1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::TransactionIndividualStatus1Code) {
+}
+
+pub fn walk_transaction_individual_status1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::TransactionIndividualStatus1Code) {
+}
+
+pub fn walk_transaction_interest4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TransactionInterest4) {
+	if let Some(ref value) = node.ttl_intrst_and_tax_amt { visitor.visit_active_or_historic_currency_and_amount(value) }
+	if let Some(ref vec) = node.rcrd { for item in vec { visitor.visit_interest_record2(item) } }
+}
+
+pub fn walk_transaction_interest4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TransactionInterest4) {
+	if let Some(ref mut value) = node.ttl_intrst_and_tax_amt { visitor.visit_active_or_historic_currency_and_amount_mut(value) }
+	if let Some(ref mut vec) = node.rcrd { for item in vec { visitor.visit_interest_record2_mut(item) } }
+}
+
+pub fn walk_transaction_parties6<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TransactionParties6) {
+	if let Some(ref value) = node.initg_pty { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.dbtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.cdtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.tradg_pty { visitor.visit_party40_choice(value) }
+	if let Some(ref vec) = node.prtry { for item in vec { visitor.visit_proprietary_party5(item) } }
+}
+
+pub fn walk_transaction_parties6_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TransactionParties6) {
+	if let Some(ref mut value) = node.initg_pty { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.dbtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.cdtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.tradg_pty { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut vec) = node.prtry { for item in vec { visitor.visit_proprietary_party5_mut(item) } }
+}
+
+pub fn walk_transaction_parties8<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TransactionParties8) {
+	if let Some(ref value) = node.ultmt_dbtr { visitor.visit_party40_choice(value) }
+	visitor.visit_party40_choice(&node.dbtr);
+	if let Some(ref value) = node.dbtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.initg_pty { visitor.visit_party40_choice(value) }
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.dbtr_agt_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt1_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt2_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.intrmy_agt3_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6(value) }
+	if let Some(ref value) = node.cdtr_agt_acct { visitor.visit_cash_account38(value) }
+	visitor.visit_party40_choice(&node.cdtr);
+	if let Some(ref value) = node.cdtr_acct { visitor.visit_cash_account38(value) }
+	if let Some(ref value) = node.ultmt_cdtr { visitor.visit_party40_choice(value) }
+}
+
+pub fn walk_transaction_parties8_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TransactionParties8) {
+	if let Some(ref mut value) = node.ultmt_dbtr { visitor.visit_party40_choice_mut(value) }
+	visitor.visit_party40_choice_mut(&mut node.dbtr);
+	if let Some(ref mut value) = node.dbtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.initg_pty { visitor.visit_party40_choice_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.prvs_instg_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt1_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt2_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3 { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.intrmy_agt3_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt { visitor.visit_branch_and_financial_institution_identification6_mut(value) }
+	if let Some(ref mut value) = node.cdtr_agt_acct { visitor.visit_cash_account38_mut(value) }
+	visitor.visit_party40_choice_mut(&mut node.cdtr);
+	if let Some(ref mut value) = node.cdtr_acct { visitor.visit_cash_account38_mut(value) }
+	if let Some(ref mut value) = node.ultmt_cdtr { visitor.visit_party40_choice_mut(value) }
+}
+
+pub fn walk_transaction_price4_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TransactionPrice4Choice) {
+	if let Some(ref value) = node.deal_pric { visitor.visit_price7(value) }
+	if let Some(ref vec) = node.prtry { for item in vec { visitor.visit_proprietary_price2(item) } }
+}
+
+pub fn walk_transaction_price4_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TransactionPrice4Choice) {
+	if let Some(ref mut value) = node.deal_pric { visitor.visit_price7_mut(value) }
+	if let Some(ref mut vec) = node.prtry { for item in vec { visitor.visit_proprietary_price2_mut(item) } }
+}
+
+pub fn walk_transaction_quantities3_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TransactionQuantities3Choice) {
+	if let Some(ref value) = node.qty { visitor.visit_financial_instrument_quantity1_choice(value) }
+	if let Some(ref value) = node.orgnl_and_cur_face_amt { visitor.visit_original_and_current_quantities1(value) }
+	if let Some(ref value) = node.prtry { visitor.visit_proprietary_quantity1(value) }
+}
+
+pub fn walk_transaction_quantities3_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TransactionQuantities3Choice) {
+	if let Some(ref mut value) = node.qty { visitor.visit_financial_instrument_quantity1_choice_mut(value) }
+	if let Some(ref mut value) = node.orgnl_and_cur_face_amt { visitor.visit_original_and_current_quantities1_mut(value) }
+	if let Some(ref mut value) = node.prtry { visitor.visit_proprietary_quantity1_mut(value) }
+}
+
+pub fn walk_transaction_references6<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TransactionReferences6) {
+	if let Some(ref vec) = node.prtry { for item in vec { visitor.visit_proprietary_reference1(item) } }
+}
+
+pub fn walk_transaction_references6_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TransactionReferences6) {
+	if let Some(ref mut vec) = node.prtry { for item in vec { visitor.visit_proprietary_reference1_mut(item) } }
+}
+
+pub fn walk_transaction_type2<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::TransactionType2) {
+	visitor.visit_entry_status1_choice(&node.sts);
+	visitor.visit_credit_debit_code(&node.cdt_dbt_ind);
+	if let Some(ref vec) = node.flr_lmt { for item in vec { visitor.visit_limit2(item) } }
+}
+
+pub fn walk_transaction_type2_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::TransactionType2) {
+	visitor.visit_entry_status1_choice_mut(&mut node.sts);
+	visitor.visit_credit_debit_code_mut(&mut node.cdt_dbt_ind);
+	if let Some(ref mut vec) = node.flr_lmt { for item in vec { visitor.visit_limit2_mut(item) } }
+}
+
+pub fn walk_unable_to_apply_incorrect1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnableToApplyIncorrect1) {
+	visitor.visit_unable_to_apply_incorrect_information4_code(&node.cd);
+}
+
+pub fn walk_unable_to_apply_incorrect1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnableToApplyIncorrect1) {
+	visitor.visit_unable_to_apply_incorrect_information4_code_mut(&mut node.cd);
+}
+
+pub fn walk_unable_to_apply_incorrect_information4_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::UnableToApplyIncorrectInformation4Code) {
+}
+
+pub fn walk_unable_to_apply_incorrect_information4_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::UnableToApplyIncorrectInformation4Code) {
+}
+
+pub fn walk_unable_to_apply_justification3_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnableToApplyJustification3Choice) {
+	if let Some(ref value) = node.mssng_or_incrrct_inf { visitor.visit_missing_or_incorrect_information3(value) }
+}
+
+pub fn walk_unable_to_apply_justification3_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnableToApplyJustification3Choice) {
+	if let Some(ref mut value) = node.mssng_or_incrrct_inf { visitor.visit_missing_or_incorrect_information3_mut(value) }
+}
+
+pub fn walk_unable_to_apply_missing1<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnableToApplyMissing1) {
+	visitor.visit_unable_to_apply_missing_information3_code(&node.cd);
+}
+
+pub fn walk_unable_to_apply_missing1_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnableToApplyMissing1) {
+	visitor.visit_unable_to_apply_missing_information3_code_mut(&mut node.cd);
+}
+
+pub fn walk_unable_to_apply_missing_information3_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::UnableToApplyMissingInformation3Code) {
+}
+
+pub fn walk_unable_to_apply_missing_information3_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::UnableToApplyMissingInformation3Code) {
+}
+
+pub fn walk_underlying_group_information1<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::UnderlyingGroupInformation1) {
+}
+
+pub fn walk_underlying_group_information1_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::UnderlyingGroupInformation1) {
+}
+
+pub fn walk_underlying_payment_instruction5<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnderlyingPaymentInstruction5) {
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_underlying_group_information1(value) }
+	visitor.visit_active_or_historic_currency_and_amount(&node.orgnl_instd_amt);
+	if let Some(ref value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice(value) }
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28(value) }
+}
+
+pub fn walk_underlying_payment_instruction5_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnderlyingPaymentInstruction5) {
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_underlying_group_information1_mut(value) }
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.orgnl_instd_amt);
+	if let Some(ref mut value) = node.reqd_exctn_dt { visitor.visit_date_and_date_time2_choice_mut(value) }
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28_mut(value) }
+}
+
+pub fn walk_underlying_payment_transaction4<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnderlyingPaymentTransaction4) {
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_underlying_group_information1(value) }
+	visitor.visit_active_or_historic_currency_and_amount(&node.orgnl_intr_bk_sttlm_amt);
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28(value) }
+}
+
+pub fn walk_underlying_payment_transaction4_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnderlyingPaymentTransaction4) {
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_underlying_group_information1_mut(value) }
+	visitor.visit_active_or_historic_currency_and_amount_mut(&mut node.orgnl_intr_bk_sttlm_amt);
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference28_mut(value) }
+}
+
+pub fn walk_underlying_statement_entry3<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnderlyingStatementEntry3) {
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_information29(value) }
+}
+
+pub fn walk_underlying_statement_entry3_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnderlyingStatementEntry3) {
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_information29_mut(value) }
+}
+
+pub fn walk_underlying_transaction22<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnderlyingTransaction22) {
+	if let Some(ref value) = node.orgnl_grp_inf_and_sts { visitor.visit_original_group_header14(value) }
+	if let Some(ref vec) = node.orgnl_pmt_inf_and_sts { for item in vec { visitor.visit_original_payment_instruction30(item) } }
+	if let Some(ref vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction102(item) } }
+}
+
+pub fn walk_underlying_transaction22_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnderlyingTransaction22) {
+	if let Some(ref mut value) = node.orgnl_grp_inf_and_sts { visitor.visit_original_group_header14_mut(value) }
+	if let Some(ref mut vec) = node.orgnl_pmt_inf_and_sts { for item in vec { visitor.visit_original_payment_instruction30_mut(item) } }
+	if let Some(ref mut vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction102_mut(item) } }
+}
+
+pub fn walk_underlying_transaction23<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnderlyingTransaction23) {
+	if let Some(ref value) = node.orgnl_grp_inf_and_cxl { visitor.visit_original_group_header15(value) }
+	if let Some(ref vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction106(item) } }
+}
+
+pub fn walk_underlying_transaction23_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnderlyingTransaction23) {
+	if let Some(ref mut value) = node.orgnl_grp_inf_and_cxl { visitor.visit_original_group_header15_mut(value) }
+	if let Some(ref mut vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction106_mut(item) } }
+}
+
+pub fn walk_underlying_transaction27<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnderlyingTransaction27) {
+	if let Some(ref value) = node.orgnl_grp_inf_and_cxl { visitor.visit_original_group_header15(value) }
+	if let Some(ref vec) = node.orgnl_pmt_inf_and_cxl { for item in vec { visitor.visit_original_payment_instruction36(item) } }
+}
+
+pub fn walk_underlying_transaction27_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnderlyingTransaction27) {
+	if let Some(ref mut value) = node.orgnl_grp_inf_and_cxl { visitor.visit_original_group_header15_mut(value) }
+	if let Some(ref mut vec) = node.orgnl_pmt_inf_and_cxl { for item in vec { visitor.visit_original_payment_instruction36_mut(item) } }
+}
+
+pub fn walk_underlying_transaction5_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::UnderlyingTransaction5Choice) {
+	if let Some(ref value) = node.initn { visitor.visit_underlying_payment_instruction5(value) }
+	if let Some(ref value) = node.intr_bk { visitor.visit_underlying_payment_transaction4(value) }
+	if let Some(ref value) = node.stmt_ntry { visitor.visit_underlying_statement_entry3(value) }
+}
+
+pub fn walk_underlying_transaction5_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::UnderlyingTransaction5Choice) {
+	if let Some(ref mut value) = node.initn { visitor.visit_underlying_payment_instruction5_mut(value) }
+	if let Some(ref mut value) = node.intr_bk { visitor.visit_underlying_payment_transaction4_mut(value) }
+	if let Some(ref mut value) = node.stmt_ntry { visitor.visit_underlying_statement_entry3_mut(value) }
+}
+
+pub fn walk_unit_of_measure1_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::UnitOfMeasure1Code) {
+}
+
+pub fn walk_unit_of_measure1_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::UnitOfMeasure1Code) {
+}
+
+pub fn walk_user_interface2_code<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::UserInterface2Code) {
+}
+
+pub fn walk_user_interface2_code_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::UserInterface2Code) {
+}
+
+pub fn walk_yielded_or_value_type1_choice<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::YieldedOrValueType1Choice) {
+	if let Some(ref value) = node.val_tp { visitor.visit_price_value_type1_code(value) }
+}
+
+pub fn walk_yielded_or_value_type1_choice_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::YieldedOrValueType1Choice) {
+	if let Some(ref mut value) = node.val_tp { visitor.visit_price_value_type1_code_mut(value) }
+}
+
+pub fn walk_fed_now_message_signature_key<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::FedNowMessageSignatureKey) {
+}
+
+pub fn walk_fed_now_message_signature_key_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::FedNowMessageSignatureKey) {
+}
+
+pub fn walk_fed_now_message_signature_key_status<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::common::FedNowMessageSignatureKeyStatus) {
+}
+
+pub fn walk_fed_now_message_signature_key_status_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::common::FedNowMessageSignatureKeyStatus) {
+}
+
+pub fn walk_fed_now_public_key_response<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::FedNowPublicKeyResponse) {
+	visitor.visit_fed_now_message_signature_key_status(&node.fed_now_message_signature_key_status);
+	visitor.visit_fed_now_message_signature_key(&node.fed_now_message_signature_key);
+}
+
+pub fn walk_fed_now_public_key_response_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::FedNowPublicKeyResponse) {
+	visitor.visit_fed_now_message_signature_key_status_mut(&mut node.fed_now_message_signature_key_status);
+	visitor.visit_fed_now_message_signature_key_mut(&mut node.fed_now_message_signature_key);
+}
+
+pub fn walk_key_addition<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::common::KeyAddition) {
+	if let Some(ref value) = node.key { visitor.visit_fed_now_message_signature_key(value) }
+}
+
+pub fn walk_key_addition_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::common::KeyAddition) {
+	if let Some(ref mut value) = node.key { visitor.visit_fed_now_message_signature_key_mut(value) }
+}
+
+pub fn walk_key_revocation<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::fednow_extra::key_exchange::KeyRevocation) {
+}
+
+pub fn walk_key_revocation_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::fednow_extra::key_exchange::KeyRevocation) {
+}
+
+pub fn walk_fed_now_message_signature_key_exchange<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::fednow_extra::key_exchange::FedNowMessageSignatureKeyExchange) {
+	if let Some(ref value) = node.key_addition { visitor.visit_key_addition(value) }
+}
+
+pub fn walk_fed_now_message_signature_key_exchange_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::fednow_extra::key_exchange::FedNowMessageSignatureKeyExchange) {
+	if let Some(ref mut value) = node.key_addition { visitor.visit_key_addition_mut(value) }
+}
+
+pub fn walk_fed_now_customer_message_signature_key_operation_response<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::fednow_extra::key_exchange::FedNowCustomerMessageSignatureKeyOperationResponse) {
+}
+
+pub fn walk_fed_now_customer_message_signature_key_operation_response_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::fednow_extra::key_exchange::FedNowCustomerMessageSignatureKeyOperationResponse) {
+}
+
+pub fn walk_get_all_fed_now_active_public_keys<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::fednow_extra::key_exchange::GetAllFedNowActivePublicKeys) {
+}
+
+pub fn walk_get_all_fed_now_active_public_keys_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::fednow_extra::key_exchange::GetAllFedNowActivePublicKeys) {
+}
+
+pub fn walk_get_all_customer_public_keys<V: Visitor + ?Sized>(_visitor: &mut V, _node: &crate::fednow_extra::key_exchange::GetAllCustomerPublicKeys) {
+}
+
+pub fn walk_get_all_customer_public_keys_mut<V: VisitorMut + ?Sized>(_visitor: &mut V, _node: &mut crate::fednow_extra::key_exchange::GetAllCustomerPublicKeys) {
+}
+
+pub fn walk_fed_now_public_key_responses<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::fednow_extra::key_exchange::FedNowPublicKeyResponses) {
+	for item in &node.public_keys { visitor.visit_fed_now_public_key_response(item) }
+}
+
+pub fn walk_fed_now_public_key_responses_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::fednow_extra::key_exchange::FedNowPublicKeyResponses) {
+	for item in &mut node.public_keys { visitor.visit_fed_now_public_key_response_mut(item) }
+}
+
+pub fn walk_fed_now_incoming<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::fednow_incoming_external::FedNowIncoming) {
+	if let Some(ref value) = node.fed_now_technical_header { visitor.visit_fed_now_technical_header(value) }
+	visitor.visit_fed_now_incoming_message(&node.fed_now_incoming_message);
+}
+
+pub fn walk_fed_now_incoming_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::fednow_incoming_external::FedNowIncoming) {
+	if let Some(ref mut value) = node.fed_now_technical_header { visitor.visit_fed_now_technical_header_mut(value) }
+	visitor.visit_fed_now_incoming_message_mut(&mut node.fed_now_incoming_message);
+}
+
+pub fn walk_fed_now_outgoing<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::fednow_outgoing_external::FedNowOutgoing) {
+	if let Some(ref value) = node.fed_now_technical_header { visitor.visit_fed_now_technical_header(value) }
+	visitor.visit_fed_now_outgoing_message(&node.fed_now_outgoing_message);
+}
+
+pub fn walk_fed_now_outgoing_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::fednow_outgoing_external::FedNowOutgoing) {
+	if let Some(ref mut value) = node.fed_now_technical_header { visitor.visit_fed_now_technical_header_mut(value) }
+	visitor.visit_fed_now_outgoing_message_mut(&mut node.fed_now_outgoing_message);
+}
+
+pub fn walk_admi00200101<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::admi_002_001_01::Admi00200101) {
+	visitor.visit_message_reference(&node.rltd_ref);
+	visitor.visit_rejection_reason2(&node.rsn);
+}
+
+pub fn walk_admi00200101_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::admi_002_001_01::Admi00200101) {
+	visitor.visit_message_reference_mut(&mut node.rltd_ref);
+	visitor.visit_rejection_reason2_mut(&mut node.rsn);
+}
+
+pub fn walk_system_event_notification_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::admi_004_001_02::SystemEventNotificationV02) {
+	visitor.visit_event2(&node.evt_inf);
+}
+
+pub fn walk_system_event_notification_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::admi_004_001_02::SystemEventNotificationV02) {
+	visitor.visit_event2_mut(&mut node.evt_inf);
+}
+
+pub fn walk_resend_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::admi_006_001_01::ResendRequestV01) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	for item in &node.rsnd_sch_crit { visitor.visit_resend_search_criteria2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_resend_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::admi_006_001_01::ResendRequestV01) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	for item in &mut node.rsnd_sch_crit { visitor.visit_resend_search_criteria2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_receipt_acknowledgement_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::admi_007_001_01::ReceiptAcknowledgementV01) {
+	visitor.visit_message_header10(&node.msg_id);
+	for item in &node.rpt { visitor.visit_receipt_acknowledgement_report2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_receipt_acknowledgement_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::admi_007_001_01::ReceiptAcknowledgementV01) {
+	visitor.visit_message_header10_mut(&mut node.msg_id);
+	for item in &mut node.rpt { visitor.visit_receipt_acknowledgement_report2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_system_event_acknowledgement_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::admi_011_001_01::SystemEventAcknowledgementV01) {
+	if let Some(ref value) = node.ack_dtls { visitor.visit_event1(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_system_event_acknowledgement_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::admi_011_001_01::SystemEventAcknowledgementV01) {
+	if let Some(ref mut value) = node.ack_dtls { visitor.visit_event1_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_administration_proprietary_message_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::admi_998_001_02::AdministrationProprietaryMessageV02) {
+	if let Some(ref value) = node.msg_id { visitor.visit_message_reference(value) }
+	if let Some(ref value) = node.rltd { visitor.visit_message_reference(value) }
+	if let Some(ref value) = node.prvs { visitor.visit_message_reference(value) }
+	if let Some(ref value) = node.othr { visitor.visit_message_reference(value) }
+	visitor.visit_proprietary_data5(&node.prtry_data);
+}
+
+pub fn walk_administration_proprietary_message_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::admi_998_001_02::AdministrationProprietaryMessageV02) {
+	if let Some(ref mut value) = node.msg_id { visitor.visit_message_reference_mut(value) }
+	if let Some(ref mut value) = node.rltd { visitor.visit_message_reference_mut(value) }
+	if let Some(ref mut value) = node.prvs { visitor.visit_message_reference_mut(value) }
+	if let Some(ref mut value) = node.othr { visitor.visit_message_reference_mut(value) }
+	visitor.visit_proprietary_data5_mut(&mut node.prtry_data);
+}
+
+pub fn walk_unable_to_apply_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::camt_026_001_07::UnableToApplyV07) {
+	visitor.visit_case_assignment5(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case5(value) }
+	visitor.visit_underlying_transaction5_choice(&node.undrlyg);
+	visitor.visit_unable_to_apply_justification3_choice(&node.justfn);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_unable_to_apply_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::camt_026_001_07::UnableToApplyV07) {
+	visitor.visit_case_assignment5_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case5_mut(value) }
+	visitor.visit_underlying_transaction5_choice_mut(&mut node.undrlyg);
+	visitor.visit_unable_to_apply_justification3_choice_mut(&mut node.justfn);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_additional_payment_information_v09<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::camt_028_001_09::AdditionalPaymentInformationV09) {
+	visitor.visit_case_assignment5(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case5(value) }
+	visitor.visit_underlying_transaction5_choice(&node.undrlyg);
+	visitor.visit_payment_complementary_information8(&node.inf);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_additional_payment_information_v09_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::camt_028_001_09::AdditionalPaymentInformationV09) {
+	visitor.visit_case_assignment5_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case5_mut(value) }
+	visitor.visit_underlying_transaction5_choice_mut(&mut node.undrlyg);
+	visitor.visit_payment_complementary_information8_mut(&mut node.inf);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_resolution_of_investigation_v09<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::camt_029_001_09::ResolutionOfInvestigationV09) {
+	visitor.visit_case_assignment5(&node.assgnmt);
+	if let Some(ref value) = node.rslvd_case { visitor.visit_case5(value) }
+	visitor.visit_investigation_status5_choice(&node.sts);
+	if let Some(ref vec) = node.cxl_dtls { for item in vec { visitor.visit_underlying_transaction22(item) } }
+	if let Some(ref value) = node.mod_dtls { visitor.visit_payment_transaction107(value) }
+	if let Some(ref value) = node.clm_non_rct_dtls { visitor.visit_claim_non_receipt2_choice(value) }
+	if let Some(ref value) = node.stmt_dtls { visitor.visit_statement_resolution_entry4(value) }
+	if let Some(ref value) = node.crrctn_tx { visitor.visit_corrective_transaction4_choice(value) }
+	if let Some(ref value) = node.rsltn_rltd_inf { visitor.visit_resolution_data1(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_resolution_of_investigation_v09_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::camt_029_001_09::ResolutionOfInvestigationV09) {
+	visitor.visit_case_assignment5_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.rslvd_case { visitor.visit_case5_mut(value) }
+	visitor.visit_investigation_status5_choice_mut(&mut node.sts);
+	if let Some(ref mut vec) = node.cxl_dtls { for item in vec { visitor.visit_underlying_transaction22_mut(item) } }
+	if let Some(ref mut value) = node.mod_dtls { visitor.visit_payment_transaction107_mut(value) }
+	if let Some(ref mut value) = node.clm_non_rct_dtls { visitor.visit_claim_non_receipt2_choice_mut(value) }
+	if let Some(ref mut value) = node.stmt_dtls { visitor.visit_statement_resolution_entry4_mut(value) }
+	if let Some(ref mut value) = node.crrctn_tx { visitor.visit_corrective_transaction4_choice_mut(value) }
+	if let Some(ref mut value) = node.rsltn_rltd_inf { visitor.visit_resolution_data1_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_bank_to_customer_account_report_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::camt_052_001_08::BankToCustomerAccountReportV08) {
+	visitor.visit_group_header81(&node.grp_hdr);
+	for item in &node.rpt { visitor.visit_account_report25(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_bank_to_customer_account_report_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::camt_052_001_08::BankToCustomerAccountReportV08) {
+	visitor.visit_group_header81_mut(&mut node.grp_hdr);
+	for item in &mut node.rpt { visitor.visit_account_report25_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_bank_to_customer_debit_credit_notification_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::camt_054_001_08::BankToCustomerDebitCreditNotificationV08) {
+	visitor.visit_group_header81(&node.grp_hdr);
+	for item in &node.ntfctn { visitor.visit_account_notification17(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_bank_to_customer_debit_credit_notification_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::camt_054_001_08::BankToCustomerDebitCreditNotificationV08) {
+	visitor.visit_group_header81_mut(&mut node.grp_hdr);
+	for item in &mut node.ntfctn { visitor.visit_account_notification17_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_customer_payment_cancellation_request_v09<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::camt_055_001_09::CustomerPaymentCancellationRequestV09) {
+	visitor.visit_case_assignment5(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case5(value) }
+	if let Some(ref value) = node.ctrl_data { visitor.visit_control_data1(value) }
+	for item in &node.undrlyg { visitor.visit_underlying_transaction27(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_customer_payment_cancellation_request_v09_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::camt_055_001_09::CustomerPaymentCancellationRequestV09) {
+	visitor.visit_case_assignment5_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut value) = node.ctrl_data { visitor.visit_control_data1_mut(value) }
+	for item in &mut node.undrlyg { visitor.visit_underlying_transaction27_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_cancellation_request_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::camt_056_001_08::FIToFIPaymentCancellationRequestV08) {
+	visitor.visit_case_assignment5(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case5(value) }
+	if let Some(ref value) = node.ctrl_data { visitor.visit_control_data1(value) }
+	for item in &node.undrlyg { visitor.visit_underlying_transaction23(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_cancellation_request_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::camt_056_001_08::FIToFIPaymentCancellationRequestV08) {
+	visitor.visit_case_assignment5_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case5_mut(value) }
+	if let Some(ref mut value) = node.ctrl_data { visitor.visit_control_data1_mut(value) }
+	for item in &mut node.undrlyg { visitor.visit_underlying_transaction23_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_reporting_request_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::camt_060_001_05::AccountReportingRequestV05) {
+	visitor.visit_group_header77(&node.grp_hdr);
+	for item in &node.rptg_req { visitor.visit_reporting_request5(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_reporting_request_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::camt_060_001_05::AccountReportingRequestV05) {
+	visitor.visit_group_header77_mut(&mut node.grp_hdr);
+	for item in &mut node.rptg_req { visitor.visit_reporting_request5_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_status_report_v10<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::pacs_002_001_10::FIToFIPaymentStatusReportV10) {
+	visitor.visit_group_header91(&node.grp_hdr);
+	if let Some(ref vec) = node.orgnl_grp_inf_and_sts { for item in vec { visitor.visit_original_group_header17(item) } }
+	if let Some(ref vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction110(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_status_report_v10_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::pacs_002_001_10::FIToFIPaymentStatusReportV10) {
+	visitor.visit_group_header91_mut(&mut node.grp_hdr);
+	if let Some(ref mut vec) = node.orgnl_grp_inf_and_sts { for item in vec { visitor.visit_original_group_header17_mut(item) } }
+	if let Some(ref mut vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction110_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_payment_return_v10<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::pacs_004_001_10::PaymentReturnV10) {
+	visitor.visit_group_header90(&node.grp_hdr);
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_header18(value) }
+	if let Some(ref vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction118(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_payment_return_v10_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::pacs_004_001_10::PaymentReturnV10) {
+	visitor.visit_group_header90_mut(&mut node.grp_hdr);
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_header18_mut(value) }
+	if let Some(ref mut vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction118_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_customer_credit_transfer_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08) {
+	visitor.visit_group_header93(&node.grp_hdr);
+	for item in &node.cdt_trf_tx_inf { visitor.visit_credit_transfer_transaction39(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_customer_credit_transfer_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08) {
+	visitor.visit_group_header93_mut(&mut node.grp_hdr);
+	for item in &mut node.cdt_trf_tx_inf { visitor.visit_credit_transfer_transaction39_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_institution_credit_transfer_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::pacs_009_001_08::FinancialInstitutionCreditTransferV08) {
+	visitor.visit_group_header93(&node.grp_hdr);
+	for item in &node.cdt_trf_tx_inf { visitor.visit_credit_transfer_transaction36(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_institution_credit_transfer_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::pacs_009_001_08::FinancialInstitutionCreditTransferV08) {
+	visitor.visit_group_header93_mut(&mut node.grp_hdr);
+	for item in &mut node.cdt_trf_tx_inf { visitor.visit_credit_transfer_transaction36_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_status_request_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::pacs_028_001_03::FIToFIPaymentStatusRequestV03) {
+	visitor.visit_group_header91(&node.grp_hdr);
+	if let Some(ref vec) = node.orgnl_grp_inf { for item in vec { visitor.visit_original_group_information27(item) } }
+	if let Some(ref vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction113(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_status_request_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::pacs_028_001_03::FIToFIPaymentStatusRequestV03) {
+	visitor.visit_group_header91_mut(&mut node.grp_hdr);
+	if let Some(ref mut vec) = node.orgnl_grp_inf { for item in vec { visitor.visit_original_group_information27_mut(item) } }
+	if let Some(ref mut vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction113_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_creditor_payment_activation_request_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::pain_013_001_07::CreditorPaymentActivationRequestV07) {
+	visitor.visit_group_header78(&node.grp_hdr);
+	for item in &node.pmt_inf { visitor.visit_payment_instruction31(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_creditor_payment_activation_request_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::pain_013_001_07::CreditorPaymentActivationRequestV07) {
+	visitor.visit_group_header78_mut(&mut node.grp_hdr);
+	for item in &mut node.pmt_inf { visitor.visit_payment_instruction31_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_creditor_payment_activation_request_status_report_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::iso::pain_014_001_07::CreditorPaymentActivationRequestStatusReportV07) {
+	visitor.visit_group_header87(&node.grp_hdr);
+	visitor.visit_original_group_information30(&node.orgnl_grp_inf_and_sts);
+	if let Some(ref vec) = node.orgnl_pmt_inf_and_sts { for item in vec { visitor.visit_original_payment_instruction31(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_creditor_payment_activation_request_status_report_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::iso::pain_014_001_07::CreditorPaymentActivationRequestStatusReportV07) {
+	visitor.visit_group_header87_mut(&mut node.grp_hdr);
+	visitor.visit_original_group_information30_mut(&mut node.orgnl_grp_inf_and_sts);
+	if let Some(ref mut vec) = node.orgnl_pmt_inf_and_sts { for item in vec { visitor.visit_original_payment_instruction31_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+impl crate::common::AccountDebitCreditNotification {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_debit_credit_notification(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_debit_credit_notification_mut(self) }
+}
+
+impl crate::common::FedNowAccountActivityDetailsReport {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_account_activity_details_report(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_account_activity_details_report_mut(self) }
+}
+
+impl crate::common::FedNowAccountActivityTotalsReport {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_account_activity_totals_report(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_account_activity_totals_report_mut(self) }
+}
+
+impl crate::common::FedNowAccountBalanceReport {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_account_balance_report(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_account_balance_report_mut(self) }
+}
+
+impl crate::common::FedNowAccountReportingRequest {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_account_reporting_request(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_account_reporting_request_mut(self) }
+}
+
+impl crate::common::FedNowAdditionalPaymentInformation {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_additional_payment_information(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_additional_payment_information_mut(self) }
+}
+
+impl crate::common::FedNowBroadcast {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_broadcast(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_broadcast_mut(self) }
+}
+
+impl crate::common::FedNowCustomerCreditTransfer {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_customer_credit_transfer(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_customer_credit_transfer_mut(self) }
+}
+
+impl crate::common::FedNowIncomingMessage {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_incoming_message(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_incoming_message_mut(self) }
+}
+
+impl crate::common::FedNowIncomingMessageSignatureManagement {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_incoming_message_signature_management(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_incoming_message_signature_management_mut(self) }
+}
+
+impl crate::common::FedNowInformationRequest {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_information_request(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_information_request_mut(self) }
+}
+
+impl crate::common::FedNowInformationRequestResponse {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_information_request_response(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_information_request_response_mut(self) }
+}
+
+impl crate::common::FedNowInstitutionCreditTransfer {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_institution_credit_transfer(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_institution_credit_transfer_mut(self) }
+}
+
+impl crate::common::FedNowMessageReject {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_message_reject(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_message_reject_mut(self) }
+}
+
+impl crate::common::FedNowOutgoingMessage {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_outgoing_message(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_outgoing_message_mut(self) }
+}
+
+impl crate::common::FedNowOutgoingMessageSignatureManagement {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_outgoing_message_signature_management(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_outgoing_message_signature_management_mut(self) }
+}
+
+impl crate::common::FedNowParticipantBroadcast {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_participant_broadcast(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_participant_broadcast_mut(self) }
+}
+
+impl crate::common::FedNowParticipantFile {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_participant_file(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_participant_file_mut(self) }
+}
+
+impl crate::common::FedNowPaymentReturn {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_payment_return(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_payment_return_mut(self) }
+}
+
+impl crate::common::FedNowPaymentStatus {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_payment_status(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_payment_status_mut(self) }
+}
+
+impl crate::common::FedNowPaymentStatusRequest {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_payment_status_request(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_payment_status_request_mut(self) }
+}
+
+impl crate::common::FedNowReceiptAcknowledgement {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_receipt_acknowledgement(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_receipt_acknowledgement_mut(self) }
+}
+
+impl crate::common::FedNowRequestForPayment {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_request_for_payment(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_request_for_payment_mut(self) }
+}
+
+impl crate::common::FedNowRequestForPaymentCancellationRequest {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_request_for_payment_cancellation_request(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_request_for_payment_cancellation_request_mut(self) }
+}
+
+impl crate::common::FedNowRequestForPaymentCancellationRequestResponse {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_request_for_payment_cancellation_request_response(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_request_for_payment_cancellation_request_response_mut(self) }
+}
+
+impl crate::common::FedNowRequestForPaymentResponse {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_request_for_payment_response(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_request_for_payment_response_mut(self) }
+}
+
+impl crate::common::FedNowRetrievalRequest {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_retrieval_request(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_retrieval_request_mut(self) }
+}
+
+impl crate::common::FedNowReturnRequest {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_return_request(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_return_request_mut(self) }
+}
+
+impl crate::common::FedNowReturnRequestResponse {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_return_request_response(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_return_request_response_mut(self) }
+}
+
+impl crate::common::FedNowSystemResponse {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_system_response(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_system_response_mut(self) }
+}
+
+impl crate::common::FedNowTechnicalHeader {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_technical_header(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_technical_header_mut(self) }
+}
+
+impl crate::common::AccountIdentification4Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_identification4_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_identification4_choice_mut(self) }
+}
+
+impl crate::common::AccountInterest4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_interest4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_interest4_mut(self) }
+}
+
+impl crate::common::AccountNotification17 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_notification17(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_notification17_mut(self) }
+}
+
+impl crate::common::AccountReport25 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_report25(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_report25_mut(self) }
+}
+
+impl crate::common::AccountSchemeName1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_scheme_name1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_scheme_name1_choice_mut(self) }
+}
+
+impl crate::common::ActiveCurrencyAndAmount {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_active_currency_and_amount(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_active_currency_and_amount_mut(self) }
+}
+
+impl crate::common::ActiveOrHistoricCurrencyAnd13DecimalAmount {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_active_or_historic_currency_and13_decimal_amount(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_active_or_historic_currency_and13_decimal_amount_mut(self) }
+}
+
+impl crate::common::ActiveOrHistoricCurrencyAndAmount {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_active_or_historic_currency_and_amount(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_active_or_historic_currency_and_amount_mut(self) }
+}
+
+impl crate::common::ActiveOrHistoricCurrencyAndAmountRange2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_active_or_historic_currency_and_amount_range2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_active_or_historic_currency_and_amount_range2_mut(self) }
+}
+
+impl crate::common::AddressType2Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_address_type2_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_address_type2_code_mut(self) }
+}
+
+impl crate::common::AddressType3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_address_type3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_address_type3_choice_mut(self) }
+}
+
+impl crate::common::AmendmentInformationDetails13 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_amendment_information_details13(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_amendment_information_details13_mut(self) }
+}
+
+impl crate::common::AmountAndCurrencyExchange3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_amount_and_currency_exchange3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_amount_and_currency_exchange3_mut(self) }
+}
+
+impl crate::common::AmountAndCurrencyExchangeDetails3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_amount_and_currency_exchange_details3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_amount_and_currency_exchange_details3_mut(self) }
+}
+
+impl crate::common::AmountAndCurrencyExchangeDetails4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_amount_and_currency_exchange_details4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_amount_and_currency_exchange_details4_mut(self) }
+}
+
+impl crate::common::AmountAndDirection35 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_amount_and_direction35(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_amount_and_direction35_mut(self) }
+}
+
+impl crate::common::AmountOrRate1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_amount_or_rate1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_amount_or_rate1_choice_mut(self) }
+}
+
+impl crate::common::AmountRangeBoundary1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_amount_range_boundary1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_amount_range_boundary1_mut(self) }
+}
+
+impl crate::common::AmountType4Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_amount_type4_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_amount_type4_choice_mut(self) }
+}
+
+impl crate::common::AttendanceContext1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_attendance_context1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_attendance_context1_code_mut(self) }
+}
+
+impl crate::common::AuthenticationEntity1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_authentication_entity1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_authentication_entity1_code_mut(self) }
+}
+
+impl crate::common::AuthenticationMethod1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_authentication_method1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_authentication_method1_code_mut(self) }
+}
+
+impl crate::common::Authorisation1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_authorisation1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_authorisation1_choice_mut(self) }
+}
+
+impl crate::common::Authorisation1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_authorisation1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_authorisation1_code_mut(self) }
+}
+
+impl crate::common::BalanceSubType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_balance_sub_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_balance_sub_type1_choice_mut(self) }
+}
+
+impl crate::common::BalanceType10Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_balance_type10_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_balance_type10_choice_mut(self) }
+}
+
+impl crate::common::BalanceType13 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_balance_type13(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_balance_type13_mut(self) }
+}
+
+impl crate::common::BankTransactionCodeStructure4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_bank_transaction_code_structure4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_bank_transaction_code_structure4_mut(self) }
+}
+
+impl crate::common::BankTransactionCodeStructure5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_bank_transaction_code_structure5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_bank_transaction_code_structure5_mut(self) }
+}
+
+impl crate::common::BankTransactionCodeStructure6 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_bank_transaction_code_structure6(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_bank_transaction_code_structure6_mut(self) }
+}
+
+impl crate::common::BatchInformation2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_batch_information2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_batch_information2_mut(self) }
+}
+
+impl crate::common::BranchAndFinancialInstitutionIdentification6 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_branch_and_financial_institution_identification6(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_branch_and_financial_institution_identification6_mut(self) }
+}
+
+impl crate::common::BranchData3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_branch_data3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_branch_data3_mut(self) }
+}
+
+impl crate::common::BusinessApplicationHeader5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_business_application_header5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_business_application_header5_mut(self) }
+}
+
+impl crate::common::BusinessApplicationHeaderV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_business_application_header_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_business_application_header_v02_mut(self) }
+}
+
+impl crate::common::CSCManagement1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_csc_management1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_csc_management1_code_mut(self) }
+}
+
+impl crate::common::CancellationIndividualStatus1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cancellation_individual_status1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cancellation_individual_status1_code_mut(self) }
+}
+
+impl crate::common::CancellationReason33Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cancellation_reason33_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cancellation_reason33_choice_mut(self) }
+}
+
+impl crate::common::CancellationStatusReason3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cancellation_status_reason3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cancellation_status_reason3_choice_mut(self) }
+}
+
+impl crate::common::CancellationStatusReason4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cancellation_status_reason4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cancellation_status_reason4_mut(self) }
+}
+
+impl crate::common::CardAggregated2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_card_aggregated2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_card_aggregated2_mut(self) }
+}
+
+impl crate::common::CardDataReading1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_card_data_reading1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_card_data_reading1_code_mut(self) }
+}
+
+impl crate::common::CardEntry4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_card_entry4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_card_entry4_mut(self) }
+}
+
+impl crate::common::CardIndividualTransaction2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_card_individual_transaction2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_card_individual_transaction2_mut(self) }
+}
+
+impl crate::common::CardPaymentServiceType2Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_card_payment_service_type2_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_card_payment_service_type2_code_mut(self) }
+}
+
+impl crate::common::CardSecurityInformation1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_card_security_information1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_card_security_information1_mut(self) }
+}
+
+impl crate::common::CardSequenceNumberRange1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_card_sequence_number_range1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_card_sequence_number_range1_mut(self) }
+}
+
+impl crate::common::CardTransaction17 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_card_transaction17(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_card_transaction17_mut(self) }
+}
+
+impl crate::common::CardTransaction3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_card_transaction3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_card_transaction3_choice_mut(self) }
+}
+
+impl crate::common::CardholderAuthentication2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cardholder_authentication2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cardholder_authentication2_mut(self) }
+}
+
+impl crate::common::CardholderVerificationCapability1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cardholder_verification_capability1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cardholder_verification_capability1_code_mut(self) }
+}
+
+impl crate::common::Case5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_case5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_case5_mut(self) }
+}
+
+impl crate::common::CaseAssignment5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_case_assignment5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_case_assignment5_mut(self) }
+}
+
+impl crate::common::CashAccount38 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cash_account38(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cash_account38_mut(self) }
+}
+
+impl crate::common::CashAccount39 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cash_account39(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cash_account39_mut(self) }
+}
+
+impl crate::common::CashAccountType2Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cash_account_type2_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cash_account_type2_choice_mut(self) }
+}
+
+impl crate::common::CashAvailability1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cash_availability1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cash_availability1_mut(self) }
+}
+
+impl crate::common::CashAvailabilityDate1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cash_availability_date1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cash_availability_date1_choice_mut(self) }
+}
+
+impl crate::common::CashBalance8 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cash_balance8(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cash_balance8_mut(self) }
+}
+
+impl crate::common::CashDeposit1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cash_deposit1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cash_deposit1_mut(self) }
+}
+
+impl crate::common::CategoryPurpose1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_category_purpose1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_category_purpose1_choice_mut(self) }
+}
+
+impl crate::common::ChargeBearerType1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_charge_bearer_type1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_charge_bearer_type1_code_mut(self) }
+}
+
+impl crate::common::ChargeType3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_charge_type3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_charge_type3_choice_mut(self) }
+}
+
+impl crate::common::Charges6 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_charges6(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_charges6_mut(self) }
+}
+
+impl crate::common::Charges7 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_charges7(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_charges7_mut(self) }
+}
+
+impl crate::common::ChargesRecord3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_charges_record3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_charges_record3_mut(self) }
+}
+
+impl crate::common::Cheque11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cheque11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cheque11_mut(self) }
+}
+
+impl crate::common::ChequeDelivery1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cheque_delivery1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cheque_delivery1_code_mut(self) }
+}
+
+impl crate::common::ChequeDeliveryMethod1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cheque_delivery_method1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cheque_delivery_method1_choice_mut(self) }
+}
+
+impl crate::common::ChequeType2Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cheque_type2_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cheque_type2_code_mut(self) }
+}
+
+impl crate::common::ClaimNonReceipt2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_claim_non_receipt2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_claim_non_receipt2_mut(self) }
+}
+
+impl crate::common::ClaimNonReceipt2Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_claim_non_receipt2_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_claim_non_receipt2_choice_mut(self) }
+}
+
+impl crate::common::ClaimNonReceiptRejectReason1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_claim_non_receipt_reject_reason1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_claim_non_receipt_reject_reason1_choice_mut(self) }
+}
+
+impl crate::common::ClearingChannel2Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_clearing_channel2_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_clearing_channel2_code_mut(self) }
+}
+
+impl crate::common::ClearingSystemIdentification2Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_clearing_system_identification2_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_clearing_system_identification2_choice_mut(self) }
+}
+
+impl crate::common::ClearingSystemIdentification3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_clearing_system_identification3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_clearing_system_identification3_choice_mut(self) }
+}
+
+impl crate::common::ClearingSystemMemberIdentification2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_clearing_system_member_identification2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_clearing_system_member_identification2_mut(self) }
+}
+
+impl crate::common::Compensation2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_compensation2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_compensation2_mut(self) }
+}
+
+impl crate::common::CompensationReason1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_compensation_reason1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_compensation_reason1_choice_mut(self) }
+}
+
+impl crate::common::Contact4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_contact4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_contact4_mut(self) }
+}
+
+impl crate::common::ControlData1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_control_data1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_control_data1_mut(self) }
+}
+
+impl crate::common::CopyDuplicate1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_copy_duplicate1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_copy_duplicate1_code_mut(self) }
+}
+
+impl crate::common::CorporateAction9 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_corporate_action9(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_corporate_action9_mut(self) }
+}
+
+impl crate::common::CorrectiveGroupInformation1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_corrective_group_information1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_corrective_group_information1_mut(self) }
+}
+
+impl crate::common::CorrectiveInterbankTransaction2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_corrective_interbank_transaction2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_corrective_interbank_transaction2_mut(self) }
+}
+
+impl crate::common::CorrectivePaymentInitiation4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_corrective_payment_initiation4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_corrective_payment_initiation4_mut(self) }
+}
+
+impl crate::common::CorrectiveTransaction4Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_corrective_transaction4_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_corrective_transaction4_choice_mut(self) }
+}
+
+impl crate::common::CreditDebitCode {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_credit_debit_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_credit_debit_code_mut(self) }
+}
+
+impl crate::common::CreditLine3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_credit_line3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_credit_line3_mut(self) }
+}
+
+impl crate::common::CreditLineType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_credit_line_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_credit_line_type1_choice_mut(self) }
+}
+
+impl crate::common::CreditTransferMandateData1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_credit_transfer_mandate_data1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_credit_transfer_mandate_data1_mut(self) }
+}
+
+impl crate::common::CreditTransferTransaction35 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_credit_transfer_transaction35(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_credit_transfer_transaction35_mut(self) }
+}
+
+impl crate::common::CreditTransferTransaction36 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_credit_transfer_transaction36(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_credit_transfer_transaction36_mut(self) }
+}
+
+impl crate::common::CreditTransferTransaction37 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_credit_transfer_transaction37(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_credit_transfer_transaction37_mut(self) }
+}
+
+impl crate::common::CreditTransferTransaction39 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_credit_transfer_transaction39(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_credit_transfer_transaction39_mut(self) }
+}
+
+impl crate::common::CreditTransferTransaction45 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_credit_transfer_transaction45(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_credit_transfer_transaction45_mut(self) }
+}
+
+impl crate::common::CreditorReferenceInformation2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_creditor_reference_information2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_creditor_reference_information2_mut(self) }
+}
+
+impl crate::common::CreditorReferenceType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_creditor_reference_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_creditor_reference_type1_choice_mut(self) }
+}
+
+impl crate::common::CreditorReferenceType2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_creditor_reference_type2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_creditor_reference_type2_mut(self) }
+}
+
+impl crate::common::CurrencyExchange5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_currency_exchange5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_currency_exchange5_mut(self) }
+}
+
+impl crate::common::DateAndDateTime2Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_date_and_date_time2_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_date_and_date_time2_choice_mut(self) }
+}
+
+impl crate::common::DateAndPlaceOfBirth1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_date_and_place_of_birth1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_date_and_place_of_birth1_mut(self) }
+}
+
+impl crate::common::DateOrDateTimePeriod1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_date_or_date_time_period1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_date_or_date_time_period1_choice_mut(self) }
+}
+
+impl crate::common::DatePeriod2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_date_period2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_date_period2_mut(self) }
+}
+
+impl crate::common::DatePeriodDetails1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_date_period_details1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_date_period_details1_mut(self) }
+}
+
+impl crate::common::DateTimePeriod1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_date_time_period1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_date_time_period1_mut(self) }
+}
+
+impl crate::common::DiscountAmountAndType1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_discount_amount_and_type1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_discount_amount_and_type1_mut(self) }
+}
+
+impl crate::common::DiscountAmountType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_discount_amount_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_discount_amount_type1_choice_mut(self) }
+}
+
+impl crate::common::DisplayCapabilities1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_display_capabilities1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_display_capabilities1_mut(self) }
+}
+
+impl crate::common::Document12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document12_mut(self) }
+}
+
+impl crate::common::DocumentAdjustment1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document_adjustment1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document_adjustment1_mut(self) }
+}
+
+impl crate::common::DocumentFormat1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document_format1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document_format1_choice_mut(self) }
+}
+
+impl crate::common::DocumentLineIdentification1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document_line_identification1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document_line_identification1_mut(self) }
+}
+
+impl crate::common::DocumentLineInformation1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document_line_information1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document_line_information1_mut(self) }
+}
+
+impl crate::common::DocumentLineType1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document_line_type1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document_line_type1_mut(self) }
+}
+
+impl crate::common::DocumentLineType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document_line_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document_line_type1_choice_mut(self) }
+}
+
+impl crate::common::DocumentType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document_type1_choice_mut(self) }
+}
+
+impl crate::common::DocumentType3Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document_type3_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document_type3_code_mut(self) }
+}
+
+impl crate::common::DocumentType6Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_document_type6_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_document_type6_code_mut(self) }
+}
+
+impl crate::common::EntryDetails9 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_entry_details9(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_entry_details9_mut(self) }
+}
+
+impl crate::common::EntryStatus1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_entry_status1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_entry_status1_choice_mut(self) }
+}
+
+impl crate::common::EntryTransaction10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_entry_transaction10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_entry_transaction10_mut(self) }
+}
+
+impl crate::common::EquivalentAmount2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_equivalent_amount2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_equivalent_amount2_mut(self) }
+}
+
+impl crate::common::Event1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_event1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_event1_mut(self) }
+}
+
+impl crate::common::Event2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_event2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_event2_mut(self) }
+}
+
+impl crate::common::FinancialIdentificationSchemeName1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_identification_scheme_name1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_identification_scheme_name1_choice_mut(self) }
+}
+
+impl crate::common::FinancialInstitutionIdentification18 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_institution_identification18(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_institution_identification18_mut(self) }
+}
+
+impl crate::common::FinancialInstrumentQuantity1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_quantity1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_quantity1_choice_mut(self) }
+}
+
+impl crate::common::FloorLimitType1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_floor_limit_type1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_floor_limit_type1_code_mut(self) }
+}
+
+impl crate::common::Frequency36Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_frequency36_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_frequency36_choice_mut(self) }
+}
+
+impl crate::common::Frequency6Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_frequency6_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_frequency6_code_mut(self) }
+}
+
+impl crate::common::FrequencyAndMoment1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_frequency_and_moment1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_frequency_and_moment1_mut(self) }
+}
+
+impl crate::common::FrequencyPeriod1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_frequency_period1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_frequency_period1_mut(self) }
+}
+
+impl crate::common::FromToAmountRange1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_from_to_amount_range1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_from_to_amount_range1_mut(self) }
+}
+
+impl crate::common::Garnishment3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_garnishment3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_garnishment3_mut(self) }
+}
+
+impl crate::common::GarnishmentType1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_garnishment_type1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_garnishment_type1_mut(self) }
+}
+
+impl crate::common::GarnishmentType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_garnishment_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_garnishment_type1_choice_mut(self) }
+}
+
+impl crate::common::GenericAccountIdentification1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_generic_account_identification1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_generic_account_identification1_mut(self) }
+}
+
+impl crate::common::GenericFinancialIdentification1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_generic_financial_identification1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_generic_financial_identification1_mut(self) }
+}
+
+impl crate::common::GenericIdentification1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_generic_identification1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_generic_identification1_mut(self) }
+}
+
+impl crate::common::GenericIdentification3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_generic_identification3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_generic_identification3_mut(self) }
+}
+
+impl crate::common::GenericIdentification30 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_generic_identification30(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_generic_identification30_mut(self) }
+}
+
+impl crate::common::GenericIdentification32 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_generic_identification32(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_generic_identification32_mut(self) }
+}
+
+impl crate::common::GenericIdentification36 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_generic_identification36(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_generic_identification36_mut(self) }
+}
+
+impl crate::common::GenericOrganisationIdentification1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_generic_organisation_identification1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_generic_organisation_identification1_mut(self) }
+}
+
+impl crate::common::GenericPersonIdentification1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_generic_person_identification1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_generic_person_identification1_mut(self) }
+}
+
+impl crate::common::GroupCancellationStatus1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_group_cancellation_status1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_group_cancellation_status1_code_mut(self) }
+}
+
+impl crate::common::GroupHeader77 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_group_header77(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_group_header77_mut(self) }
+}
+
+impl crate::common::GroupHeader78 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_group_header78(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_group_header78_mut(self) }
+}
+
+impl crate::common::GroupHeader81 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_group_header81(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_group_header81_mut(self) }
+}
+
+impl crate::common::GroupHeader87 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_group_header87(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_group_header87_mut(self) }
+}
+
+impl crate::common::GroupHeader90 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_group_header90(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_group_header90_mut(self) }
+}
+
+impl crate::common::GroupHeader91 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_group_header91(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_group_header91_mut(self) }
+}
+
+impl crate::common::GroupHeader93 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_group_header93(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_group_header93_mut(self) }
+}
+
+impl crate::common::IdentificationSource3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_identification_source3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_identification_source3_choice_mut(self) }
+}
+
+impl crate::common::ImplementationSpecification1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_implementation_specification1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_implementation_specification1_mut(self) }
+}
+
+impl crate::common::ImpliedCurrencyAmountRange1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_implied_currency_amount_range1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_implied_currency_amount_range1_choice_mut(self) }
+}
+
+impl crate::common::Instruction3Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_instruction3_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_instruction3_code_mut(self) }
+}
+
+impl crate::common::Instruction4Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_instruction4_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_instruction4_code_mut(self) }
+}
+
+impl crate::common::Instruction5Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_instruction5_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_instruction5_code_mut(self) }
+}
+
+impl crate::common::InstructionForCreditorAgent1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_instruction_for_creditor_agent1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_instruction_for_creditor_agent1_mut(self) }
+}
+
+impl crate::common::InstructionForCreditorAgent2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_instruction_for_creditor_agent2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_instruction_for_creditor_agent2_mut(self) }
+}
+
+impl crate::common::InstructionForCreditorAgent3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_instruction_for_creditor_agent3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_instruction_for_creditor_agent3_mut(self) }
+}
+
+impl crate::common::InstructionForNextAgent1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_instruction_for_next_agent1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_instruction_for_next_agent1_mut(self) }
+}
+
+impl crate::common::InterestRecord2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_interest_record2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_interest_record2_mut(self) }
+}
+
+impl crate::common::InterestType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_interest_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_interest_type1_choice_mut(self) }
+}
+
+impl crate::common::InterestType1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_interest_type1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_interest_type1_code_mut(self) }
+}
+
+impl crate::common::InvestigationStatus5Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_investigation_status5_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_investigation_status5_choice_mut(self) }
+}
+
+impl crate::common::Limit2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_limit2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_limit2_mut(self) }
+}
+
+impl crate::common::LocalInstrument2Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_local_instrument2_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_local_instrument2_choice_mut(self) }
+}
+
+impl crate::common::MandateClassification1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_classification1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_classification1_choice_mut(self) }
+}
+
+impl crate::common::MandateClassification1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_classification1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_classification1_code_mut(self) }
+}
+
+impl crate::common::MandateRelatedData1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_related_data1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_related_data1_choice_mut(self) }
+}
+
+impl crate::common::MandateRelatedInformation14 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_related_information14(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_related_information14_mut(self) }
+}
+
+impl crate::common::MandateSetupReason1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_setup_reason1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_setup_reason1_choice_mut(self) }
+}
+
+impl crate::common::MandateTypeInformation2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_type_information2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_type_information2_mut(self) }
+}
+
+impl crate::common::MessageHeader10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_message_header10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_message_header10_mut(self) }
+}
+
+impl crate::common::MessageHeader7 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_message_header7(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_message_header7_mut(self) }
+}
+
+impl crate::common::MessageIdentification2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_message_identification2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_message_identification2_mut(self) }
+}
+
+impl crate::common::MessageReference {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_message_reference(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_message_reference_mut(self) }
+}
+
+impl crate::common::MessageReference1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_message_reference1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_message_reference1_mut(self) }
+}
+
+impl crate::common::MissingOrIncorrectInformation3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_missing_or_incorrect_information3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_missing_or_incorrect_information3_mut(self) }
+}
+
+impl crate::common::ModificationStatusReason1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_modification_status_reason1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_modification_status_reason1_choice_mut(self) }
+}
+
+impl crate::common::ModificationStatusReason2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_modification_status_reason2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_modification_status_reason2_mut(self) }
+}
+
+impl crate::common::NameAndAddress16 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_name_and_address16(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_name_and_address16_mut(self) }
+}
+
+impl crate::common::NameAndAddress5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_name_and_address5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_name_and_address5_mut(self) }
+}
+
+impl crate::common::NamePrefix2Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_name_prefix2_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_name_prefix2_code_mut(self) }
+}
+
+impl crate::common::NumberAndSumOfTransactions1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_number_and_sum_of_transactions1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_number_and_sum_of_transactions1_mut(self) }
+}
+
+impl crate::common::NumberAndSumOfTransactions4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_number_and_sum_of_transactions4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_number_and_sum_of_transactions4_mut(self) }
+}
+
+impl crate::common::NumberOfCancellationsPerStatus1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_number_of_cancellations_per_status1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_number_of_cancellations_per_status1_mut(self) }
+}
+
+impl crate::common::NumberOfTransactionsPerStatus1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_number_of_transactions_per_status1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_number_of_transactions_per_status1_mut(self) }
+}
+
+impl crate::common::NumberOfTransactionsPerStatus5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_number_of_transactions_per_status5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_number_of_transactions_per_status5_mut(self) }
+}
+
+impl crate::common::OnLineCapability1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_on_line_capability1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_on_line_capability1_code_mut(self) }
+}
+
+impl crate::common::OrganisationIdentification29 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_organisation_identification29(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_organisation_identification29_mut(self) }
+}
+
+impl crate::common::OrganisationIdentificationSchemeName1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_organisation_identification_scheme_name1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_organisation_identification_scheme_name1_choice_mut(self) }
+}
+
+impl crate::common::OriginalAndCurrentQuantities1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_and_current_quantities1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_and_current_quantities1_mut(self) }
+}
+
+impl crate::common::OriginalBusinessQuery1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_business_query1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_business_query1_mut(self) }
+}
+
+impl crate::common::OriginalGroupHeader14 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_group_header14(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_group_header14_mut(self) }
+}
+
+impl crate::common::OriginalGroupHeader15 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_group_header15(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_group_header15_mut(self) }
+}
+
+impl crate::common::OriginalGroupHeader17 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_group_header17(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_group_header17_mut(self) }
+}
+
+impl crate::common::OriginalGroupHeader18 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_group_header18(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_group_header18_mut(self) }
+}
+
+impl crate::common::OriginalGroupInformation27 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_group_information27(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_group_information27_mut(self) }
+}
+
+impl crate::common::OriginalGroupInformation29 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_group_information29(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_group_information29_mut(self) }
+}
+
+impl crate::common::OriginalGroupInformation30 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_group_information30(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_group_information30_mut(self) }
+}
+
+impl crate::common::OriginalPaymentInstruction30 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_payment_instruction30(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_payment_instruction30_mut(self) }
+}
+
+impl crate::common::OriginalPaymentInstruction31 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_payment_instruction31(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_payment_instruction31_mut(self) }
+}
+
+impl crate::common::OriginalPaymentInstruction36 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_payment_instruction36(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_payment_instruction36_mut(self) }
+}
+
+impl crate::common::OriginalTransactionReference28 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_transaction_reference28(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_transaction_reference28_mut(self) }
+}
+
+impl crate::common::OriginalTransactionReference29 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_transaction_reference29(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_transaction_reference29_mut(self) }
+}
+
+impl crate::common::OriginalTransactionReference31 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_transaction_reference31(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_transaction_reference31_mut(self) }
+}
+
+impl crate::common::OriginalTransactionReference32 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_original_transaction_reference32(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_original_transaction_reference32_mut(self) }
+}
+
+impl crate::common::OtherContact1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_other_contact1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_other_contact1_mut(self) }
+}
+
+impl crate::common::OtherIdentification1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_other_identification1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_other_identification1_mut(self) }
+}
+
+impl crate::common::POIComponentType1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_poi_component_type1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_poi_component_type1_code_mut(self) }
+}
+
+impl crate::common::Pagination1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_pagination1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_pagination1_mut(self) }
+}
+
+impl crate::common::Party38Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party38_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party38_choice_mut(self) }
+}
+
+impl crate::common::Party40Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party40_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party40_choice_mut(self) }
+}
+
+impl crate::common::Party44Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party44_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party44_choice_mut(self) }
+}
+
+impl crate::common::PartyAndSignature3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_and_signature3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_and_signature3_mut(self) }
+}
+
+impl crate::common::PartyIdentification120Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_identification120_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_identification120_choice_mut(self) }
+}
+
+impl crate::common::PartyIdentification135 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_identification135(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_identification135_mut(self) }
+}
+
+impl crate::common::PartyIdentification136 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_identification136(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_identification136_mut(self) }
+}
+
+impl crate::common::PartyType3Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_type3_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_type3_code_mut(self) }
+}
+
+impl crate::common::PartyType4Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_type4_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_type4_code_mut(self) }
+}
+
+impl crate::common::PaymentCancellationReason5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_cancellation_reason5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_cancellation_reason5_mut(self) }
+}
+
+impl crate::common::PaymentCard4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_card4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_card4_mut(self) }
+}
+
+impl crate::common::PaymentComplementaryInformation8 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_complementary_information8(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_complementary_information8_mut(self) }
+}
+
+impl crate::common::PaymentCondition1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_condition1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_condition1_mut(self) }
+}
+
+impl crate::common::PaymentConditionStatus1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_condition_status1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_condition_status1_mut(self) }
+}
+
+impl crate::common::PaymentContext3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_context3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_context3_mut(self) }
+}
+
+impl crate::common::PaymentIdentification6 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_identification6(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_identification6_mut(self) }
+}
+
+impl crate::common::PaymentIdentification7 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_identification7(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_identification7_mut(self) }
+}
+
+impl crate::common::PaymentInstruction31 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_instruction31(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_instruction31_mut(self) }
+}
+
+impl crate::common::PaymentMethod4Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_method4_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_method4_code_mut(self) }
+}
+
+impl crate::common::PaymentMethod7Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_method7_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_method7_code_mut(self) }
+}
+
+impl crate::common::PaymentReturnReason5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_return_reason5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_return_reason5_mut(self) }
+}
+
+impl crate::common::PaymentReturnReason6 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_return_reason6(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_return_reason6_mut(self) }
+}
+
+impl crate::common::PaymentTransaction102 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_transaction102(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_transaction102_mut(self) }
+}
+
+impl crate::common::PaymentTransaction103 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_transaction103(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_transaction103_mut(self) }
+}
+
+impl crate::common::PaymentTransaction104 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_transaction104(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_transaction104_mut(self) }
+}
+
+impl crate::common::PaymentTransaction106 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_transaction106(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_transaction106_mut(self) }
+}
+
+impl crate::common::PaymentTransaction107 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_transaction107(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_transaction107_mut(self) }
+}
+
+impl crate::common::PaymentTransaction110 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_transaction110(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_transaction110_mut(self) }
+}
+
+impl crate::common::PaymentTransaction113 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_transaction113(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_transaction113_mut(self) }
+}
+
+impl crate::common::PaymentTransaction118 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_transaction118(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_transaction118_mut(self) }
+}
+
+impl crate::common::PaymentTransaction124 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_transaction124(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_transaction124_mut(self) }
+}
+
+impl crate::common::PaymentTypeInformation26 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_type_information26(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_type_information26_mut(self) }
+}
+
+impl crate::common::PaymentTypeInformation27 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_type_information27(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_type_information27_mut(self) }
+}
+
+impl crate::common::PaymentTypeInformation28 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_type_information28(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_type_information28_mut(self) }
+}
+
+impl crate::common::PersonIdentification13 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_person_identification13(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_person_identification13_mut(self) }
+}
+
+impl crate::common::PersonIdentificationSchemeName1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_person_identification_scheme_name1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_person_identification_scheme_name1_choice_mut(self) }
+}
+
+impl crate::common::PlainCardData1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_plain_card_data1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_plain_card_data1_mut(self) }
+}
+
+impl crate::common::PointOfInteraction1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_point_of_interaction1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_point_of_interaction1_mut(self) }
+}
+
+impl crate::common::PointOfInteractionCapabilities1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_point_of_interaction_capabilities1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_point_of_interaction_capabilities1_mut(self) }
+}
+
+impl crate::common::PointOfInteractionComponent1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_point_of_interaction_component1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_point_of_interaction_component1_mut(self) }
+}
+
+impl crate::common::PostalAddress1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_postal_address1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_postal_address1_mut(self) }
+}
+
+impl crate::common::PostalAddress24 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_postal_address24(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_postal_address24_mut(self) }
+}
+
+impl crate::common::PreferredContactMethod1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_preferred_contact_method1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_preferred_contact_method1_code_mut(self) }
+}
+
+impl crate::common::Price7 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_price7(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_price7_mut(self) }
+}
+
+impl crate::common::PriceRateOrAmount3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_price_rate_or_amount3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_price_rate_or_amount3_choice_mut(self) }
+}
+
+impl crate::common::PriceValueType1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_price_value_type1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_price_value_type1_code_mut(self) }
+}
+
+impl crate::common::Priority2Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_priority2_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_priority2_code_mut(self) }
+}
+
+impl crate::common::Priority3Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_priority3_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_priority3_code_mut(self) }
+}
+
+impl crate::common::Product2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_product2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_product2_mut(self) }
+}
+
+impl crate::common::ProprietaryAgent4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proprietary_agent4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proprietary_agent4_mut(self) }
+}
+
+impl crate::common::ProprietaryBankTransactionCodeStructure1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proprietary_bank_transaction_code_structure1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proprietary_bank_transaction_code_structure1_mut(self) }
+}
+
+impl crate::common::ProprietaryData5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proprietary_data5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proprietary_data5_mut(self) }
+}
+
+impl crate::common::ProprietaryDate3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proprietary_date3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proprietary_date3_mut(self) }
+}
+
+impl crate::common::ProprietaryParty5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proprietary_party5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proprietary_party5_mut(self) }
+}
+
+impl crate::common::ProprietaryPrice2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proprietary_price2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proprietary_price2_mut(self) }
+}
+
+impl crate::common::ProprietaryQuantity1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proprietary_quantity1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proprietary_quantity1_mut(self) }
+}
+
+impl crate::common::ProprietaryReference1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proprietary_reference1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proprietary_reference1_mut(self) }
+}
+
+impl crate::common::ProxyAccountIdentification1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proxy_account_identification1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proxy_account_identification1_mut(self) }
+}
+
+impl crate::common::ProxyAccountType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proxy_account_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proxy_account_type1_choice_mut(self) }
+}
+
+impl crate::common::Purpose2Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_purpose2_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_purpose2_choice_mut(self) }
+}
+
+impl crate::common::QueryType3Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_query_type3_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_query_type3_code_mut(self) }
+}
+
+impl crate::common::Rate4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_rate4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_rate4_mut(self) }
+}
+
+impl crate::common::RateType4Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_rate_type4_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_rate_type4_choice_mut(self) }
+}
+
+impl crate::common::ReceiptAcknowledgementReport2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_receipt_acknowledgement_report2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_receipt_acknowledgement_report2_mut(self) }
+}
+
+impl crate::common::ReferredDocumentInformation7 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_referred_document_information7(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_referred_document_information7_mut(self) }
+}
+
+impl crate::common::ReferredDocumentType3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_referred_document_type3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_referred_document_type3_choice_mut(self) }
+}
+
+impl crate::common::ReferredDocumentType4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_referred_document_type4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_referred_document_type4_mut(self) }
+}
+
+impl crate::common::RegulatoryAuthority2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_regulatory_authority2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_regulatory_authority2_mut(self) }
+}
+
+impl crate::common::RegulatoryReporting3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_regulatory_reporting3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_regulatory_reporting3_mut(self) }
+}
+
+impl crate::common::RegulatoryReportingType1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_regulatory_reporting_type1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_regulatory_reporting_type1_code_mut(self) }
+}
+
+impl crate::common::RejectionReason2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_rejection_reason2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_rejection_reason2_mut(self) }
+}
+
+impl crate::common::RemittanceAmount2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_remittance_amount2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_remittance_amount2_mut(self) }
+}
+
+impl crate::common::RemittanceAmount3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_remittance_amount3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_remittance_amount3_mut(self) }
+}
+
+impl crate::common::RemittanceInformation16 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_remittance_information16(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_remittance_information16_mut(self) }
+}
+
+impl crate::common::RemittanceInformation2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_remittance_information2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_remittance_information2_mut(self) }
+}
+
+impl crate::common::RemittanceLocation7 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_remittance_location7(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_remittance_location7_mut(self) }
+}
+
+impl crate::common::RemittanceLocationData1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_remittance_location_data1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_remittance_location_data1_mut(self) }
+}
+
+impl crate::common::RemittanceLocationMethod2Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_remittance_location_method2_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_remittance_location_method2_code_mut(self) }
+}
+
+impl crate::common::ReportEntry10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_report_entry10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_report_entry10_mut(self) }
+}
+
+impl crate::common::ReportingPeriod2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_reporting_period2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_reporting_period2_mut(self) }
+}
+
+impl crate::common::ReportingRequest5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_reporting_request5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_reporting_request5_mut(self) }
+}
+
+impl crate::common::ReportingSource1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_reporting_source1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_reporting_source1_choice_mut(self) }
+}
+
+impl crate::common::RequestHandling2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_handling2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_handling2_mut(self) }
+}
+
+impl crate::common::RequestType4Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_type4_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_type4_choice_mut(self) }
+}
+
+impl crate::common::ResendSearchCriteria2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_resend_search_criteria2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_resend_search_criteria2_mut(self) }
+}
+
+impl crate::common::ResolutionData1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_resolution_data1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_resolution_data1_mut(self) }
+}
+
+impl crate::common::ReturnReason5Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_reason5_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_reason5_choice_mut(self) }
+}
+
+impl crate::common::SecuritiesAccount19 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account19(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account19_mut(self) }
+}
+
+impl crate::common::SecurityIdentification19 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_identification19(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_identification19_mut(self) }
+}
+
+impl crate::common::SequenceRange1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_sequence_range1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_sequence_range1_mut(self) }
+}
+
+impl crate::common::SequenceRange1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_sequence_range1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_sequence_range1_choice_mut(self) }
+}
+
+impl crate::common::SequenceType3Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_sequence_type3_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_sequence_type3_code_mut(self) }
+}
+
+impl crate::common::ServiceLevel8Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_service_level8_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_service_level8_choice_mut(self) }
+}
+
+impl crate::common::SettlementDateTimeIndication1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_settlement_date_time_indication1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_settlement_date_time_indication1_mut(self) }
+}
+
+impl crate::common::SettlementInstruction7 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_settlement_instruction7(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_settlement_instruction7_mut(self) }
+}
+
+impl crate::common::SettlementMethod1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_settlement_method1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_settlement_method1_code_mut(self) }
+}
+
+impl crate::common::SettlementTimeRequest2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_settlement_time_request2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_settlement_time_request2_mut(self) }
+}
+
+impl crate::common::SignatureEnvelope {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_signature_envelope(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_signature_envelope_mut(self) }
+}
+
+impl crate::common::SkipPayload {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_skip_payload(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_skip_payload_mut(self) }
+}
+
+impl crate::common::StatementResolutionEntry4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_statement_resolution_entry4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_statement_resolution_entry4_mut(self) }
+}
+
+impl crate::common::StatusReason6Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_status_reason6_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_status_reason6_choice_mut(self) }
+}
+
+impl crate::common::StatusReasonInformation12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_status_reason_information12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_status_reason_information12_mut(self) }
+}
+
+impl crate::common::StructuredRegulatoryReporting3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_structured_regulatory_reporting3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_structured_regulatory_reporting3_mut(self) }
+}
+
+impl crate::common::StructuredRemittanceInformation16 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_structured_remittance_information16(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_structured_remittance_information16_mut(self) }
+}
+
+impl crate::common::SupplementaryData1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_supplementary_data1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_supplementary_data1_mut(self) }
+}
+
+impl crate::common::SupplementaryDataEnvelope1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_supplementary_data_envelope1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_supplementary_data_envelope1_mut(self) }
+}
+
+impl crate::common::TaxAmount2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_amount2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_amount2_mut(self) }
+}
+
+impl crate::common::TaxAmountAndType1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_amount_and_type1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_amount_and_type1_mut(self) }
+}
+
+impl crate::common::TaxAmountType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_amount_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_amount_type1_choice_mut(self) }
+}
+
+impl crate::common::TaxAuthorisation1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_authorisation1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_authorisation1_mut(self) }
+}
+
+impl crate::common::TaxCharges2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_charges2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_charges2_mut(self) }
+}
+
+impl crate::common::TaxInformation7 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_information7(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_information7_mut(self) }
+}
+
+impl crate::common::TaxInformation8 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_information8(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_information8_mut(self) }
+}
+
+impl crate::common::TaxParty1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_party1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_party1_mut(self) }
+}
+
+impl crate::common::TaxParty2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_party2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_party2_mut(self) }
+}
+
+impl crate::common::TaxPeriod2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_period2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_period2_mut(self) }
+}
+
+impl crate::common::TaxRecord2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_record2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_record2_mut(self) }
+}
+
+impl crate::common::TaxRecordDetails2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_record_details2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_record_details2_mut(self) }
+}
+
+impl crate::common::TaxRecordPeriod1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_tax_record_period1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_tax_record_period1_code_mut(self) }
+}
+
+impl crate::common::TechnicalInputChannel1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_technical_input_channel1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_technical_input_channel1_choice_mut(self) }
+}
+
+impl crate::common::TimePeriodDetails1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_time_period_details1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_time_period_details1_mut(self) }
+}
+
+impl crate::common::TotalTransactions6 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_total_transactions6(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_total_transactions6_mut(self) }
+}
+
+impl crate::common::TotalsPerBankTransactionCode5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_totals_per_bank_transaction_code5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_totals_per_bank_transaction_code5_mut(self) }
+}
+
+impl crate::common::TrackData1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_track_data1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_track_data1_mut(self) }
+}
+
+impl crate::common::TransactionAgents5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_agents5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_agents5_mut(self) }
+}
+
+impl crate::common::TransactionChannel1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_channel1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_channel1_code_mut(self) }
+}
+
+impl crate::common::TransactionDates3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_dates3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_dates3_mut(self) }
+}
+
+impl crate::common::TransactionEnvironment1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_environment1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_environment1_code_mut(self) }
+}
+
+impl crate::common::TransactionIdentifier1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_identifier1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_identifier1_mut(self) }
+}
+
+impl crate::common::TransactionIndividualStatus1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_individual_status1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_individual_status1_code_mut(self) }
+}
+
+impl crate::common::TransactionInterest4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_interest4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_interest4_mut(self) }
+}
+
+impl crate::common::TransactionParties6 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_parties6(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_parties6_mut(self) }
+}
+
+impl crate::common::TransactionParties8 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_parties8(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_parties8_mut(self) }
+}
+
+impl crate::common::TransactionPrice4Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_price4_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_price4_choice_mut(self) }
+}
+
+impl crate::common::TransactionQuantities3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_quantities3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_quantities3_choice_mut(self) }
+}
+
+impl crate::common::TransactionReferences6 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_references6(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_references6_mut(self) }
+}
+
+impl crate::common::TransactionType2 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_transaction_type2(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_transaction_type2_mut(self) }
+}
+
+impl crate::common::UnableToApplyIncorrect1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_unable_to_apply_incorrect1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_unable_to_apply_incorrect1_mut(self) }
+}
+
+impl crate::common::UnableToApplyIncorrectInformation4Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_unable_to_apply_incorrect_information4_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_unable_to_apply_incorrect_information4_code_mut(self) }
+}
+
+impl crate::common::UnableToApplyJustification3Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_unable_to_apply_justification3_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_unable_to_apply_justification3_choice_mut(self) }
+}
+
+impl crate::common::UnableToApplyMissing1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_unable_to_apply_missing1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_unable_to_apply_missing1_mut(self) }
+}
+
+impl crate::common::UnableToApplyMissingInformation3Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_unable_to_apply_missing_information3_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_unable_to_apply_missing_information3_code_mut(self) }
+}
+
+impl crate::common::UnderlyingGroupInformation1 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_underlying_group_information1(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_underlying_group_information1_mut(self) }
+}
+
+impl crate::common::UnderlyingPaymentInstruction5 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_underlying_payment_instruction5(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_underlying_payment_instruction5_mut(self) }
+}
+
+impl crate::common::UnderlyingPaymentTransaction4 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_underlying_payment_transaction4(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_underlying_payment_transaction4_mut(self) }
+}
+
+impl crate::common::UnderlyingStatementEntry3 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_underlying_statement_entry3(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_underlying_statement_entry3_mut(self) }
+}
+
+impl crate::common::UnderlyingTransaction22 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_underlying_transaction22(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_underlying_transaction22_mut(self) }
+}
+
+impl crate::common::UnderlyingTransaction23 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_underlying_transaction23(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_underlying_transaction23_mut(self) }
+}
+
+impl crate::common::UnderlyingTransaction27 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_underlying_transaction27(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_underlying_transaction27_mut(self) }
+}
+
+impl crate::common::UnderlyingTransaction5Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_underlying_transaction5_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_underlying_transaction5_choice_mut(self) }
+}
+
+impl crate::common::UnitOfMeasure1Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_unit_of_measure1_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_unit_of_measure1_code_mut(self) }
+}
+
+impl crate::common::UserInterface2Code {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_user_interface2_code(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_user_interface2_code_mut(self) }
+}
+
+impl crate::common::YieldedOrValueType1Choice {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_yielded_or_value_type1_choice(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_yielded_or_value_type1_choice_mut(self) }
+}
+
+impl crate::common::FedNowMessageSignatureKey {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_message_signature_key(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_message_signature_key_mut(self) }
+}
+
+impl crate::common::FedNowMessageSignatureKeyStatus {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_message_signature_key_status(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_message_signature_key_status_mut(self) }
+}
+
+impl crate::common::FedNowPublicKeyResponse {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_public_key_response(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_public_key_response_mut(self) }
+}
+
+impl crate::common::KeyAddition {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_key_addition(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_key_addition_mut(self) }
+}
+
+impl crate::fednow_extra::key_exchange::KeyRevocation {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_key_revocation(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_key_revocation_mut(self) }
+}
+
+impl crate::fednow_extra::key_exchange::FedNowMessageSignatureKeyExchange {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_message_signature_key_exchange(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_message_signature_key_exchange_mut(self) }
+}
+
+impl crate::fednow_extra::key_exchange::FedNowCustomerMessageSignatureKeyOperationResponse {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_customer_message_signature_key_operation_response(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_customer_message_signature_key_operation_response_mut(self) }
+}
+
+impl crate::fednow_extra::key_exchange::GetAllFedNowActivePublicKeys {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_all_fed_now_active_public_keys(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_all_fed_now_active_public_keys_mut(self) }
+}
+
+impl crate::fednow_extra::key_exchange::GetAllCustomerPublicKeys {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_all_customer_public_keys(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_all_customer_public_keys_mut(self) }
+}
+
+impl crate::fednow_extra::key_exchange::FedNowPublicKeyResponses {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_public_key_responses(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_public_key_responses_mut(self) }
+}
+
+impl crate::fednow_incoming_external::FedNowIncoming {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_incoming(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_incoming_mut(self) }
+}
+
+impl crate::fednow_outgoing_external::FedNowOutgoing {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fed_now_outgoing(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fed_now_outgoing_mut(self) }
+}
+
+impl crate::iso::admi_002_001_01::Admi00200101 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_admi00200101(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_admi00200101_mut(self) }
+}
+
+impl crate::iso::admi_004_001_02::SystemEventNotificationV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_system_event_notification_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_system_event_notification_v02_mut(self) }
+}
+
+impl crate::iso::admi_006_001_01::ResendRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_resend_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_resend_request_v01_mut(self) }
+}
+
+impl crate::iso::admi_007_001_01::ReceiptAcknowledgementV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_receipt_acknowledgement_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_receipt_acknowledgement_v01_mut(self) }
+}
+
+impl crate::iso::admi_011_001_01::SystemEventAcknowledgementV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_system_event_acknowledgement_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_system_event_acknowledgement_v01_mut(self) }
+}
+
+impl crate::iso::admi_998_001_02::AdministrationProprietaryMessageV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_administration_proprietary_message_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_administration_proprietary_message_v02_mut(self) }
+}
+
+impl crate::iso::camt_026_001_07::UnableToApplyV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_unable_to_apply_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_unable_to_apply_v07_mut(self) }
+}
+
+impl crate::iso::camt_028_001_09::AdditionalPaymentInformationV09 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_additional_payment_information_v09(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_additional_payment_information_v09_mut(self) }
+}
+
+impl crate::iso::camt_029_001_09::ResolutionOfInvestigationV09 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_resolution_of_investigation_v09(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_resolution_of_investigation_v09_mut(self) }
+}
+
+impl crate::iso::camt_052_001_08::BankToCustomerAccountReportV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_bank_to_customer_account_report_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_bank_to_customer_account_report_v08_mut(self) }
+}
+
+impl crate::iso::camt_054_001_08::BankToCustomerDebitCreditNotificationV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_bank_to_customer_debit_credit_notification_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_bank_to_customer_debit_credit_notification_v08_mut(self) }
+}
+
+impl crate::iso::camt_055_001_09::CustomerPaymentCancellationRequestV09 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_customer_payment_cancellation_request_v09(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_customer_payment_cancellation_request_v09_mut(self) }
+}
+
+impl crate::iso::camt_056_001_08::FIToFIPaymentCancellationRequestV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_cancellation_request_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_cancellation_request_v08_mut(self) }
+}
+
+impl crate::iso::camt_060_001_05::AccountReportingRequestV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_reporting_request_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_reporting_request_v05_mut(self) }
+}
+
+impl crate::iso::pacs_002_001_10::FIToFIPaymentStatusReportV10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_report_v10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_report_v10_mut(self) }
+}
+
+impl crate::iso::pacs_004_001_10::PaymentReturnV10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_return_v10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_return_v10_mut(self) }
+}
+
+impl crate::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_customer_credit_transfer_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_customer_credit_transfer_v08_mut(self) }
+}
+
+impl crate::iso::pacs_009_001_08::FinancialInstitutionCreditTransferV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_institution_credit_transfer_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_institution_credit_transfer_v08_mut(self) }
+}
+
+impl crate::iso::pacs_028_001_03::FIToFIPaymentStatusRequestV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_request_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_request_v03_mut(self) }
+}
+
+impl crate::iso::pain_013_001_07::CreditorPaymentActivationRequestV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_creditor_payment_activation_request_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_creditor_payment_activation_request_v07_mut(self) }
+}
+
+impl crate::iso::pain_014_001_07::CreditorPaymentActivationRequestStatusReportV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_creditor_payment_activation_request_status_report_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_creditor_payment_activation_request_status_report_v07_mut(self) }
+}
@@ -0,0 +1,252 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Round trip conformance of the FedNow messages in `tests/samples`: each
+// sample is parsed, validated, written back to XML and parsed again, the
+// two parsed messages must be equal and the written XML must carry the
+// same content as the sample (see `canonical`).
+//
+// serde-xml-rs can parse the FedNow envelope but not write it, so the
+// messages are written from their JSON form, with the naming of the
+// generated types: `@name` for attributes, `$value` for text content and
+// arrays for repeated elements.
+
+use open_payments_common::ValidationError;
+use open_payments_fednow::common::BusinessApplicationHeaderV02;
+use open_payments_fednow::visitor::{walk_business_application_header_v02, Visitor};
+use open_payments_fednow::FednowMessage;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use xml::reader::{EventReader, XmlEvent};
+
+/// The messages of the FedNow Service, each of which has a sample.
+const MESSAGES: [&str; 21] = [
+    "admi.002.001.01",
+    "admi.004.001.02",
+    "admi.006.001.01",
+    "admi.007.001.01",
+    "admi.011.001.01",
+    "admi.998.001.02",
+    "camt.026.001.07",
+    "camt.028.001.09",
+    "camt.029.001.09",
+    "camt.052.001.08",
+    "camt.054.001.08",
+    "camt.055.001.09",
+    "camt.056.001.08",
+    "camt.060.001.05",
+    "pacs.002.001.10",
+    "pacs.004.001.10",
+    "pacs.008.001.08",
+    "pacs.009.001.08",
+    "pacs.028.001.03",
+    "pain.013.001.07",
+    "pain.014.001.07",
+];
+
+/// Stack of the thread running the samples: deserializing the generated
+/// types takes more than the 2 MiB of a test thread in debug builds.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+#[test]
+fn samples_round_trip() {
+    std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(round_trip_samples)
+        .expect("test thread")
+        .join()
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+}
+
+fn round_trip_samples() {
+    let mut failures = Vec::new();
+    let mut covered = MessageDefinitions::default();
+    for path in samples() {
+        match round_trip(&path) {
+            Ok(message) => message.walk(&mut covered),
+            Err(failure) => failures.push(format!("{}: {}", path.display(), failure)),
+        }
+    }
+    assert!(failures.is_empty(), "{} samples failed:\n{}", failures.len(), failures.join("\n"));
+
+    let missing: Vec<&str> = MESSAGES.iter().copied().filter(|id| !covered.0.contains(*id)).collect();
+    assert!(missing.is_empty(), "no sample of {}", missing.join(", "));
+}
+
+/// The samples, in file name order.
+fn samples() -> Vec<PathBuf> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/samples");
+    let mut paths: Vec<PathBuf> = fs::read_dir(&directory)
+        .expect("tests/samples")
+        .map(|entry| entry.expect("tests/samples").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "xml"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn round_trip(path: &Path) -> Result<FednowMessage, String> {
+    let xml = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let message = parse(&xml).map_err(|e| format!("parse: {}", e))?;
+    validate(&message).map_err(|e| format!("validate: {} ({})", e.message, e.code))?;
+
+    let written = write(&message)?;
+    let reparsed = parse(&written).map_err(|e| format!("parse of the written XML: {}", e))?;
+    if to_json(&message)? != to_json(&reparsed)? {
+        return Err(format!("parsing the written XML gives another message:\n{}", written));
+    }
+
+    let (expected, actual) = (canonical(&xml)?, canonical(&written)?);
+    if let Some(line) = expected.iter().zip(actual.iter()).position(|(e, a)| e != a) {
+        return Err(format!("written XML differs at {}: expected {}", actual[line], expected[line]));
+    }
+    if expected.len() != actual.len() {
+        let line = expected.len().min(actual.len());
+        return Err(match expected.get(line) {
+            Some(missing) => format!("written XML lacks {}", missing),
+            None => format!("written XML adds {}", actual[line]),
+        });
+    }
+    Ok(message)
+}
+
+fn parse(xml: &str) -> Result<FednowMessage, String> {
+    let mut deserializer = serde_xml_rs::Deserializer::new_from_reader(xml.as_bytes());
+    serde_path_to_error::deserialize(&mut deserializer).map_err(|e| format!("{} at {}", e.inner(), e.path()))
+}
+
+fn validate(message: &FednowMessage) -> Result<(), ValidationError> {
+    match message {
+        FednowMessage::FedNowIncoming(incoming) => incoming.validate(),
+        FednowMessage::FedNowOutgoing(outgoing) => outgoing.validate(),
+        FednowMessage::UNKNOWN => Err(ValidationError::new(9999, "not a FedNow message".to_string())),
+    }
+}
+
+fn to_json(message: &FednowMessage) -> Result<Value, String> {
+    serde_json::to_value(message).map_err(|e| e.to_string())
+}
+
+/// The message as XML, from its JSON form.
+fn write(message: &FednowMessage) -> Result<String, String> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+    match to_json(message)? {
+        Value::Object(root) => root.iter().for_each(|(name, value)| write_element(&mut xml, name, value)),
+        other => return Err(format!("not a message: {}", other)),
+    }
+    Ok(xml)
+}
+
+fn write_element(xml: &mut String, name: &str, value: &Value) {
+    match value {
+        Value::Array(items) => items.iter().for_each(|item| write_element(xml, name, item)),
+        Value::Null => {}
+        Value::Object(fields) => {
+            xml.push_str(&format!("<{}", name));
+            for (attribute, value) in fields.iter().filter(|(key, _)| key.starts_with('@')) {
+                xml.push_str(&format!(" {}=\"{}\"", &attribute[1..], escape(&text(value))));
+            }
+            xml.push('>');
+            for (key, value) in fields.iter().filter(|(key, _)| !key.starts_with('@')) {
+                if key == "$value" {
+                    xml.push_str(&escape(&text(value)));
+                } else {
+                    write_element(xml, key, value);
+                }
+            }
+            xml.push_str(&format!("</{}>", name));
+        }
+        scalar => xml.push_str(&format!("<{}>{}</{}>", name, escape(&text(scalar)), name)),
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// The content of an XML document as lines of element paths, with the
+/// attributes (`path/@name=value`) and text (`path=text`) of each element,
+/// in document order. Namespace declarations, which the generated types do
+/// not keep, and whitespace between elements are left out, and decimals
+/// lose their trailing zeros (`f64` amounts are written as `10.5` or `10.0`).
+fn canonical(xml: &str) -> Result<Vec<String>, String> {
+    let mut lines = Vec::new();
+    let mut path: Vec<String> = Vec::new();
+    for event in EventReader::new(xml.as_bytes()) {
+        match event.map_err(|e| e.to_string())? {
+            XmlEvent::StartElement { name, attributes, .. } => {
+                path.push(name.local_name);
+                let element = path.join("/");
+                let mut attributes: Vec<String> = attributes
+                    .iter()
+                    .map(|attribute| format!("{}/@{}={}", element, attribute.name.local_name, decimal(&attribute.value)))
+                    .collect();
+                attributes.sort();
+                lines.push(element);
+                lines.extend(attributes);
+            }
+            XmlEvent::EndElement { .. } => {
+                path.pop();
+            }
+            XmlEvent::Characters(text) | XmlEvent::CData(text) if !text.trim().is_empty() => {
+                lines.push(format!("{}={}", path.join("/"), decimal(text.trim())));
+            }
+            _ => {}
+        }
+    }
+    Ok(lines)
+}
+
+fn decimal(text: &str) -> &str {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    match digits.split_once('.') {
+        Some((whole, fraction))
+            if !whole.is_empty() && whole.bytes().all(|b| b.is_ascii_digit()) && fraction.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            text.trim_end_matches('0').trim_end_matches('.')
+        }
+        _ => text,
+    }
+}
+
+/// The `MsgDefIdr` of the business application headers of the messages
+/// walked.
+#[derive(Default)]
+struct MessageDefinitions(BTreeSet<String>);
+
+impl Visitor for MessageDefinitions {
+    fn visit_business_application_header_v02(&mut self, node: &BusinessApplicationHeaderV02) {
+        self.0.insert(node.msg_def_idr.clone());
+        walk_business_application_header_v02(self, node);
+    }
+}
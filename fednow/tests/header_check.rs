@@ -0,0 +1,93 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Header checks of the pacs.008 sample, which a participant sends to the
+// FedNow Service: its AppHdr `To` is the service, not the instructed agent.
+
+use open_payments_common::header_check::{HeaderCheckConfig, ERR_TO_AGENT};
+use open_payments_fednow::common::FedNowCustomerCreditTransfer;
+use open_payments_fednow::header_check::{check_message, config, header_facts, AppHdrBuilder, FEDNOW_SERVICE_ROUTING_NUMBER};
+use open_payments_fednow::FednowMessage;
+use std::fs;
+use std::path::Path;
+
+/// Stack of the thread parsing the sample: deserializing the generated
+/// types takes more than the 2 MiB of a test thread in debug builds.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+fn with_sample(test: fn(FedNowCustomerCreditTransfer)) {
+    std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || test(sample()))
+        .expect("test thread")
+        .join()
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+}
+
+fn sample() -> FedNowCustomerCreditTransfer {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/samples/pacs.008.001.08.xml");
+    let xml = fs::read_to_string(path).expect("pacs.008 sample");
+    let message: FednowMessage = serde_xml_rs::from_str(&xml).expect("parse");
+    match message {
+        FednowMessage::FedNowIncoming(incoming) => incoming.fed_now_incoming_message.fed_now_customer_credit_transfer.expect("credit transfer"),
+        _ => panic!("not an incoming message"),
+    }
+}
+
+#[test]
+fn sample_addressed_to_the_service_passes() {
+    with_sample(|transfer| {
+        let errors = check_message(&transfer.bah_app_hdr, &transfer.p8_document, &config());
+        assert!(errors.is_empty(), "{:?}", errors);
+    });
+}
+
+#[test]
+fn service_is_not_the_instructed_agent_without_the_fednow_config() {
+    with_sample(|transfer| {
+        let errors = check_message(&transfer.bah_app_hdr, &transfer.p8_document, &HeaderCheckConfig::default());
+        assert_eq!(errors.iter().map(|e| e.code).collect::<Vec<_>>(), vec![ERR_TO_AGENT]);
+    });
+}
+
+#[test]
+fn builder_addresses_the_service() {
+    with_sample(|transfer| {
+        let app_hdr = AppHdrBuilder::from_document(&transfer.p8_document).unwrap().creation_date(&transfer.bah_app_hdr.cre_dt).to_fednow_service().build().unwrap();
+        assert_eq!(header_facts(&app_hdr).to.and_then(|to| to.member_id).as_deref(), Some(FEDNOW_SERVICE_ROUTING_NUMBER));
+        assert_eq!(header_facts(&app_hdr).from.and_then(|from| from.member_id), header_facts(&transfer.bah_app_hdr).from.and_then(|from| from.member_id));
+        let errors = check_message(&app_hdr, &transfer.p8_document, &config());
+        assert!(errors.is_empty(), "{:?}", errors);
+    });
+}
+
+#[test]
+fn builder_requires_the_receiver() {
+    with_sample(|transfer| {
+        let Err(error) = AppHdrBuilder::from_document(&transfer.p8_document).unwrap().build() else {
+            panic!("built a header without a receiver");
+        };
+        assert_eq!(error.code, ERR_TO_AGENT);
+    });
+}
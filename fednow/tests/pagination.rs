@@ -0,0 +1,79 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// A camt.052 report split into pages and merged back by the assembler,
+// with the pages delivered out of order.
+
+use open_payments_common::pagination::PageLimits;
+use open_payments_fednow::common::*;
+use open_payments_fednow::iso::camt_052_001_08::BankToCustomerAccountReportV08;
+use open_payments_fednow::pagination::{split_report, ReportAssembler};
+
+fn entry(reference: &str) -> ReportEntry10 {
+    ReportEntry10 { ntry_ref: Some(reference.to_string()), ..Default::default() }
+}
+
+fn report(id: &str, entries: &[&str]) -> AccountReport25 {
+    AccountReport25 {
+        id: id.to_string(),
+        txs_summry: Some(TotalTransactions6::default()),
+        ntry: Some(entries.iter().map(|r| entry(r)).collect()),
+        ..Default::default()
+    }
+}
+
+fn message() -> BankToCustomerAccountReportV08 {
+    BankToCustomerAccountReportV08 {
+        grp_hdr: GroupHeader81 { msg_id: "MSG-1".to_string(), cre_dt_tm: "2024-05-01T10:00:00".to_string(), ..Default::default() },
+        rpt: vec![report("RPT-1", &["E1", "E2", "E3"]), report("RPT-2", &["E4", "E5"])],
+        splmtry_data: None,
+    }
+}
+
+#[test]
+fn split_report_merges_back() {
+    let limits = PageLimits { max_entries: Some(2), ..Default::default() };
+    let pages = split_report(message(), &limits, |_| 1).unwrap();
+    let numbers: Vec<(String, String, bool)> = pages
+        .iter()
+        .map(|p| (p.grp_hdr.msg_id.clone(), p.grp_hdr.msg_pgntn.as_ref().unwrap().pg_nb.clone(), p.grp_hdr.msg_pgntn.as_ref().unwrap().last_pg_ind))
+        .collect();
+    assert_eq!(numbers, vec![
+        ("MSG-1-1".to_string(), "1".to_string(), false),
+        ("MSG-1-2".to_string(), "2".to_string(), false),
+        ("MSG-1-3".to_string(), "3".to_string(), true),
+    ]);
+    assert!(pages.iter().all(|p| p.rpt[0].id == "RPT-1"));
+
+    let mut assembler = ReportAssembler::new();
+    let mut merged = None;
+    for page in pages.into_iter().rev() {
+        assert!(merged.is_none());
+        merged = assembler.add(page).unwrap();
+    }
+    let merged = merged.unwrap();
+    assert_eq!(merged.rpt, message().rpt);
+    assert_eq!(merged.grp_hdr.msg_pgntn, Some(Pagination1 { pg_nb: "1".to_string(), last_pg_ind: true }));
+    assert!(assembler.pages().pending_keys().is_empty());
+}
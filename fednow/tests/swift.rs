@@ -0,0 +1,107 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// MT202 and MT202 COV to FedNow pacs.009 and back. FedNow participants are
+// identified by routing number, in the header and as agents.
+
+use open_payments_common::swift::mt202::Mt202;
+use open_payments_common::swift::FinMessage;
+use open_payments_fednow::swift::mt202::{fin_to_pacs009, pacs009_to_mt202, ERR_COVER_MISMATCH};
+
+const MT202_COV: &str = "{1:F01BANKUS33AXXX0000000000}{2:I202BANKUS44XXXXN}{3:{119:COV}{121:eb6305c9-1f7f-49de-aed0-16487c27b42d}}{4:\r\n\
+:20:COV-0001\r\n\
+:21:REF-0001\r\n\
+:32A:240501USD50000,\r\n\
+:58A:BANKUS44\r\n\
+:50K:/123456789\r\n\
+JOHN DOE\r\n\
+:52A:BANKUS33\r\n\
+:57A:BANKUS44\r\n\
+:59:/987654321\r\n\
+JANE DOE\r\n\
+:33B:USD50000,\r\n\
+-}";
+
+#[test]
+fn participants_are_identified_by_routing_number() {
+    let (header, document) = fin_to_pacs009(MT202_COV, "011104238", "021040078").unwrap();
+    let member = |party: &open_payments_fednow::common::Party44Choice| party.fi_id.as_ref().and_then(|f| f.fin_instn_id.clr_sys_mmb_id.as_ref()).map(|m| m.mmb_id.clone());
+    assert_eq!(member(&header.fr).as_deref(), Some("011104238"));
+    assert_eq!(member(&header.to).as_deref(), Some("021040078"));
+    assert_eq!(header.biz_msg_idr, "COV-0001");
+
+    let tx = &document.cdt_trf_tx_inf[0];
+    let instructing = tx.instg_agt.as_ref().and_then(|a| a.fin_instn_id.clr_sys_mmb_id.as_ref()).unwrap();
+    assert_eq!(instructing.mmb_id, "011104238");
+    assert_eq!(instructing.clr_sys_id.as_ref().and_then(|s| s.cd.as_deref()), Some("USABA"));
+    assert_eq!(tx.instd_agt.as_ref().and_then(|a| a.fin_instn_id.clr_sys_mmb_id.as_ref()).map(|m| m.mmb_id.as_str()), Some("021040078"));
+    assert!(tx.undrlyg_cstmr_cdt_trf.is_some());
+}
+
+#[test]
+fn settlement_is_through_the_fednow_clearing_system() {
+    let (_, document) = fin_to_pacs009(MT202_COV, "011104238", "021040078").unwrap();
+    let clearing_system = document.grp_hdr.sttlm_inf.clr_sys.as_ref().unwrap();
+    assert_eq!(clearing_system.cd.as_deref(), Some("FDN"));
+    assert_eq!(clearing_system.prtry, None);
+}
+
+#[test]
+fn mt202_cov_round_trip() {
+    let message = FinMessage::parse(MT202_COV).unwrap();
+    let (_, document) = fin_to_pacs009(MT202_COV, "011104238", "021040078").unwrap();
+    let messages = pacs009_to_mt202(&document, "BANKUS33", "BANKUS44").unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].validation_flag(), Some("COV"));
+    assert_eq!(messages[0].uetr(), message.uetr());
+
+    let original = Mt202::from_fin(&message).unwrap();
+    let translated = Mt202::from_fin(&messages[0]).unwrap();
+    assert_eq!(translated.transaction_reference, original.transaction_reference);
+    assert_eq!(translated.related_reference, original.related_reference);
+    assert_eq!(translated.value_date_amount, original.value_date_amount);
+    assert_eq!(translated.beneficiary_institution, original.beneficiary_institution);
+    assert_eq!(translated.underlying, original.underlying);
+}
+
+#[test]
+fn cover_must_match_the_underlying_transfer() {
+    let mismatch = MT202_COV.replace(":33B:USD50000,", ":33B:USD40000,");
+    assert_eq!(fin_to_pacs009(&mismatch, "011104238", "021040078").err().unwrap().code, ERR_COVER_MISMATCH);
+}
+
+#[test]
+fn cover_debtor_keeps_its_address_in_the_underlying_transfer() {
+    // Without 52a in sequence B the cover debtor (52D) is the underlying debtor agent.
+    let cover = MT202_COV
+        .replace(":58A:BANKUS44\r\n", ":52D:FIRST BANK\r\n1 MAIN STREET\r\nNEW YORK\r\n:58A:BANKUS44\r\n")
+        .replace("JOHN DOE\r\n:52A:BANKUS33\r\n", "JOHN DOE\r\n");
+    let (_, document) = fin_to_pacs009(&cover, "011104238", "021040078").unwrap();
+    let underlying = document.cdt_trf_tx_inf[0].undrlyg_cstmr_cdt_trf.as_ref().unwrap();
+    let debtor_agent = &underlying.dbtr_agt.fin_instn_id;
+    assert_eq!(debtor_agent.nm.as_deref(), Some("FIRST BANK"));
+    let address = debtor_agent.pstl_adr.as_ref().unwrap();
+    assert_eq!(address.adr_line.as_deref(), Some(&["1 MAIN STREET".to_string(), "NEW YORK".to_string()][..]));
+}
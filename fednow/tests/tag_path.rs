@@ -0,0 +1,98 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// Tag path access to a pacs.008 through the generated `TagPaths` impls:
+// text, amounts, attributes and codes, updates that add optional elements,
+// and the paths of a `Document`.
+
+use open_payments_common::example::Example;
+use open_payments_common::tag_path::{TagPaths, ERR_PATH_NOT_FOUND, ERR_PATH_SYNTAX, ERR_PATH_TYPE, ERR_PATH_UPDATE};
+use open_payments_fednow::common::ChargeBearerType1Code;
+use open_payments_fednow::document::Document;
+use open_payments_fednow::iso::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+
+fn payment() -> FIToFICustomerCreditTransferV08 {
+    let mut payment: FIToFICustomerCreditTransferV08 = Example::example();
+    let mut second = payment.cdt_trf_tx_inf[0].clone();
+    second.pmt_id.end_to_end_id = "E2E-2".to_string();
+    second.intr_bk_sttlm_amt.value = 25.5;
+    payment.cdt_trf_tx_inf[0].pmt_id.end_to_end_id = "E2E-1".to_string();
+    payment.cdt_trf_tx_inf.push(second);
+    payment
+}
+
+#[test]
+fn queries_read_text_amounts_attributes_and_codes() {
+    let payment = payment();
+    assert_eq!(payment.query::<String>("CdtTrfTxInf/PmtId/EndToEndId").unwrap(), vec!["E2E-1", "E2E-2"]);
+    assert_eq!(payment.get::<String>("CdtTrfTxInf[2]/PmtId/EndToEndId").unwrap(), "E2E-2");
+    assert_eq!(payment.get::<f64>("CdtTrfTxInf[2]/IntrBkSttlmAmt").unwrap(), 25.5);
+    let ccy = &payment.cdt_trf_tx_inf[0].intr_bk_sttlm_amt.ccy;
+    assert_eq!(&payment.get::<String>("CdtTrfTxInf[1]/IntrBkSttlmAmt/@Ccy").unwrap(), ccy);
+    let code = payment.get::<ChargeBearerType1Code>("CdtTrfTxInf[1]/ChrgBr").unwrap();
+    assert_eq!(code, payment.cdt_trf_tx_inf[0].chrg_br);
+    assert!(payment.get::<String>("CdtTrfTxInf[1]/ChrgBr").is_ok());
+    assert_eq!(payment.query::<String>("CdtTrfTxInf[3]/PmtId/EndToEndId").unwrap(), Vec::<String>::new());
+    assert_eq!(payment.query_first::<String>("CdtTrfTxInf/*/EndToEndId").unwrap().as_deref(), Some("E2E-1"));
+
+    assert_eq!(payment.get::<String>("GrpHdr/NoSuchTag").err().unwrap().code, ERR_PATH_NOT_FOUND);
+    assert_eq!(payment.get::<f64>("CdtTrfTxInf[1]/PmtId/EndToEndId").err().unwrap().code, ERR_PATH_TYPE);
+    assert_eq!(payment.query::<String>("CdtTrfTxInf[0]").err().unwrap().code, ERR_PATH_SYNTAX);
+}
+
+#[test]
+fn set_updates_every_match_and_adds_optional_elements() {
+    let mut payment = payment();
+    assert_eq!(payment.set("CdtTrfTxInf/IntrBkSttlmAmt/@Ccy", &"EUR".to_string()).unwrap(), 2);
+    assert!(payment.cdt_trf_tx_inf.iter().all(|tx| tx.intr_bk_sttlm_amt.ccy == "EUR"));
+    assert_eq!(payment.set("CdtTrfTxInf[2]/IntrBkSttlmAmt", &30.0).unwrap(), 1);
+    assert_eq!(payment.cdt_trf_tx_inf[1].intr_bk_sttlm_amt.value, 30.0);
+    assert_eq!(payment.set("CdtTrfTxInf[1]/ChrgBr", &"DEBT".to_string()).unwrap(), 1);
+    assert_eq!(payment.cdt_trf_tx_inf[0].chrg_br, ChargeBearerType1Code::CodeDEBT);
+
+    payment.cdt_trf_tx_inf[0].pmt_id.uetr = None;
+    let uetr = "eb6305c9-1f7f-49de-aed0-16487c27b42d".to_string();
+    assert_eq!(payment.set("CdtTrfTxInf[1]/PmtId/UETR", &uetr).unwrap(), 1);
+    assert_eq!(payment.cdt_trf_tx_inf[0].pmt_id.uetr.as_ref(), Some(&uetr));
+}
+
+#[test]
+fn failed_updates_leave_the_message_unchanged() {
+    let mut payment = payment();
+    let before = payment.clone();
+    assert_eq!(payment.set("CdtTrfTxInf/PmtId/EndToEndId", &1.0).err().unwrap().code, ERR_PATH_UPDATE);
+    assert_eq!(payment.set("CdtTrfTxInf[1]/ChrgBr", &"XXXX".to_string()).err().unwrap().code, ERR_PATH_UPDATE);
+    assert_eq!(payment.set("CdtTrfTxInf[5]/PmtId/EndToEndId", &"E2E-5".to_string()).err().unwrap().code, ERR_PATH_NOT_FOUND);
+    assert!(payment == before);
+}
+
+#[test]
+fn document_paths_start_at_the_message_element() {
+    let mut document = Document::FIToFICustomerCreditTransferV08(Box::new(payment()));
+    assert_eq!(document.get::<String>("FIToFICstmrCdtTrf/CdtTrfTxInf[2]/PmtId/EndToEndId").unwrap(), "E2E-2");
+    assert!(document.query::<String>("PmtRtr/GrpHdr/MsgId").unwrap().is_empty());
+    assert_eq!(document.set("FIToFICstmrCdtTrf/GrpHdr/MsgId", &"MSG-2".to_string()).unwrap(), 1);
+    assert_eq!(document.get::<String>("*/GrpHdr/MsgId").unwrap(), "MSG-2");
+}
@@ -0,0 +1,203 @@
+import os
+import re
+from pathlib import Path
+import argparse
+
+from generated_types import HEADER, Field, TypeDef, is_choice, scan_types
+
+CURRENCY_TAGS = {'Ccy', '@Ccy'}
+
+# Dates with a time, although their tags end in Dt (the creation date of
+# the business application header).
+DATE_TIME_TAGS = {'CreDt'}
+
+# Placeholders for the common patterns: BIC, currency, country, UETR, LEI,
+# IBAN, ISIN, phone number, counts and message identifiers.
+PATTERN_PLACEHOLDERS = {
+    '[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}': 'BANKUS33XXX',
+    '[A-Z]{3,3}': 'USD',
+    '[A-Z]{2,2}': 'US',
+    '[a-f0-9]{8}-[a-f0-9]{4}-4[a-f0-9]{3}-[89ab][a-f0-9]{3}-[a-f0-9]{12}': 'eb6305c9-1f7f-49de-aed0-16487c27b42d',
+    '[A-Z0-9]{18,18}[0-9]{2,2}': '5493001KJTIIGC8Y1R12',
+    '[A-Z]{2,2}[0-9]{2,2}[a-zA-Z0-9]{1,30}': 'GB29NWBK60161331926819',
+    '[A-Z]{2,2}[A-Z0-9]{9,9}[0-9]{1,1}': 'US0378331005',
+    '\\+[0-9]{1,3}-[0-9()+\\-]{1,30}': '+1-2125550100',
+    '[0-9]{1,15}': '1',
+    '[a-z]{4,4}[.]{1,1}[0-9]{3,3}[.]{1,1}001[.]{1,1}[0-9]{2,2}': 'pacs.008.001.08',
+    '[a-z]{2,2}': 'en',
+}
+
+def shortest_match(pattern: str) -> str:
+    """
+    The shortest text matching a pattern of the facets: the first character
+    of each class and alternative, repeated as few times as allowed.
+    """
+    position = 0
+
+    def quantifier() -> int:
+        nonlocal position
+        if position < len(pattern) and pattern[position] in '?*':
+            position += 1
+            return 0
+        if position < len(pattern) and pattern[position] == '+':
+            position += 1
+            return 1
+        if position < len(pattern) and pattern[position] == '{':
+            end = pattern.index('}', position)
+            bounds = pattern[position + 1:end].split(',')
+            position = end + 1
+            return int(bounds[0] or 0)
+        return 1
+
+    def sequence() -> str:
+        nonlocal position
+        out = ''
+        while position < len(pattern) and pattern[position] not in '|)':
+            c = pattern[position]
+            if c == '(':
+                position += 1
+                atom = sequence()
+                while pattern[position] == '|':
+                    position += 1
+                    sequence()
+                position += 1
+            elif c == '[':
+                end = position + 1
+                while pattern[end] != ']':
+                    end += 2 if pattern[end] == '\\' else 1
+                first = pattern[position + 1]
+                atom = pattern[position + 2] if first == '\\' else first
+                position = end + 1
+            elif c == '\\':
+                atom = '0' if pattern[position + 1] == 'd' else pattern[position + 1]
+                position += 2
+            else:
+                atom = c
+                position += 1
+            out += atom * quantifier()
+        return out
+
+    return sequence()
+
+def placeholder_text(field: Field) -> str:
+    """The value of a text field, checked against its facets."""
+    facets = field.facets
+    tag = field.tag.lstrip('@$')
+    if field.tag in CURRENCY_TAGS:
+        return 'USD'
+    if facets.pattern is not None:
+        # The pattern as written in the Rust string literal.
+        pattern = facets.pattern.replace('\\\\', '\\')
+        text = PATTERN_PLACEHOLDERS.get(pattern)
+        if text is None:
+            text = shortest_match(pattern)
+        if not re.fullmatch(pattern, text):
+            raise ValueError(f'{text} does not match {pattern} ({field.name})')
+        return text
+    if facets.min_length is None and facets.max_length is None:
+        if field.tag.endswith('DtTm') or field.tag.endswith('DateTime') or field.tag in DATE_TIME_TAGS:
+            return '2024-01-01T00:00:00'
+        if field.tag.endswith('Tm'):
+            return '00:00:00'
+        if field.tag.endswith('Dt') or field.tag.startswith('DtOf'):
+            return '2024-01-01'
+    text = tag[:facets.max_length] if facets.max_length is not None else tag
+    return text.ljust(facets.min_length or 0, 'X')
+
+def value_expression(field: Field) -> str:
+    """The example value of one item of the field."""
+    if field.type_name == 'String':
+        text = placeholder_text(field).replace('\\', '\\\\').replace('"', '\\"')
+        return f'"{text}".to_string()'
+    if field.type_name in ('f64', 'f32'):
+        min_value = float(field.facets.min_value) if field.facets.min_value is not None else 0.0
+        return repr(max(min_value, 1.0))
+    if field.type_name == 'bool':
+        return 'false'
+    if field.type_name == 'u64':
+        return '1'
+    return 'Example::example()'
+
+def field_expression(field: Field) -> str:
+    if field.optional:
+        return 'None'
+    value = value_expression(field)
+    if field.repeated:
+        return f'vec![{value}]'
+    return value
+
+def example_body(type_def: TypeDef) -> list:
+    if type_def.is_enum:
+        return [f'\t\tSelf::{type_def.variants[0]}']
+    chosen = None
+    if is_choice(type_def) or all(f.optional for f in type_def.fields):
+        # The first element of a choice, or of a type with only optional
+        # elements, which would be empty otherwise.
+        chosen = next(f for f in type_def.fields if f.optional)
+    lines = ['\t\tSelf {']
+    for f in type_def.fields:
+        if f is chosen:
+            value = value_expression(f)
+            lines.append(f'\t\t\t{f.name}: Some({f"vec![{value}]" if f.repeated else value}),')
+        else:
+            lines.append(f'\t\t\t{f.name}: {field_expression(f)},')
+    lines.append('\t\t}')
+    return lines
+
+def generate_example(types: list, example_crate: str) -> str:
+    lines = [HEADER]
+    lines.append('// `Example` for the generated types of this crate, generated by')
+    lines.append('// generate-example.py from their facets (see `open_payments_common::example`).')
+    lines.append('')
+    if types:
+        lines.append(f'use {example_crate}::example::Example;')
+        lines.append('')
+    for type_def in types:
+        lines.append(f'impl Example for {type_def.module}::{type_def.name} {{')
+        lines.append('\tfn example() -> Self {')
+        if type_def.is_enum or type_def.fields:
+            lines.extend(example_body(type_def))
+        else:
+            lines.append('\t\tSelf {}')
+        lines.append('\t}')
+        lines.append('}')
+        lines.append('')
+    return '\n'.join(lines)
+
+def add_module(lib_file: Path):
+    """Declare the private example_impls module in lib.rs, after the last module."""
+    with open(lib_file, 'r', encoding='utf-8') as f:
+        content = f.read()
+    if re.search(r'^(pub )?mod example_impls;$', content, re.MULTILINE):
+        return
+    modules = list(re.finditer(r'^(pub )?mod \w+;\n', content, re.MULTILINE))
+    position = modules[-1].end() if modules else len(content)
+    with open(lib_file, 'w', encoding='utf-8') as f:
+        f.write(content[:position] + 'mod example_impls;\n' + content[position:])
+
+def main():
+    parser = argparse.ArgumentParser(
+        description='Generate example_impls.rs with Example implementations of the generated types'
+    )
+    parser.add_argument('directory',
+                       help='Source directory of the crate')
+
+    args = parser.parse_args()
+
+    try:
+        directory = Path(args.directory)
+        types = scan_types(directory)
+        # The common crate defines `Example`, the others use it from there.
+        example_crate = 'crate' if (directory / 'example.rs').exists() else 'open_payments_common'
+        with open(directory / 'example_impls.rs', 'w', encoding='utf-8') as f:
+            f.write(generate_example(types, example_crate))
+        add_module(directory / 'lib.rs')
+        print(f"Generated example_impls.rs with {len(types)} types in {directory}")
+    except Exception as e:
+        print(f"Error: {e}")
+        return 1
+
+    return 0
+
+if __name__ == "__main__":
+    exit(main())
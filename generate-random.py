@@ -0,0 +1,241 @@
+import os
+import re
+from pathlib import Path
+import argparse
+
+from generated_types import HEADER, Field, TypeDef, is_choice, scan_schemas, scan_types
+
+CURRENCY_TAGS = {'Ccy', '@Ccy'}
+
+# Dates with a time, although their tags end in Dt (the creation date of
+# the business application header).
+DATE_TIME_TAGS = {'CreDt'}
+
+# Escapes of `Generator::pattern` besides escaped characters.
+PATTERN_ESCAPES = {'d'}
+
+def check_pattern(pattern: str, name: str):
+    """
+    Refuse a pattern with what `Generator::pattern` does not support (see its
+    documentation), which would give values that fail `validate`. The
+    pattern is a Rust string literal of the generated code.
+    """
+    pattern = re.sub(r'\\(.)', r'\1', pattern)
+    in_class = False
+    i = 0
+    while i < len(pattern):
+        c = pattern[i]
+        if c == '\\':
+            escaped = pattern[i + 1:i + 2]
+            if escaped.isalpha() and (in_class or escaped not in PATTERN_ESCAPES):
+                raise ValueError(f'Unsupported escape \\{escaped} in the pattern of {name}: {pattern}')
+            i += 2
+            continue
+        if c == '[' and not in_class:
+            if pattern[i + 1:i + 2] == '^':
+                raise ValueError(f'Unsupported negated class in the pattern of {name}: {pattern}')
+            in_class = True
+        elif c == ']':
+            in_class = False
+        elif c == '.' and not in_class:
+            raise ValueError(f'Unsupported . in the pattern of {name}: {pattern}')
+        i += 1
+
+def value_expression(field: Field) -> str:
+    """A value of one item of the field, from the generator `g`."""
+    facets = field.facets
+    if field.type_name == 'String':
+        if field.tag in CURRENCY_TAGS:
+            return 'g.currency()'
+        if facets.pattern is not None:
+            check_pattern(facets.pattern, field.name)
+            return f'g.pattern("{facets.pattern}")'
+        if facets.min_length is not None or facets.max_length is not None:
+            min_length = facets.min_length if facets.min_length is not None else 0
+            max_length = facets.max_length if facets.max_length is not None else 'g.max_text_length'
+            return f'g.text({min_length}, {max_length})'
+        if field.tag.endswith('DtTm') or field.tag.endswith('DateTime') or field.tag in DATE_TIME_TAGS:
+            return 'g.date_time()'
+        if field.tag.endswith('Tm'):
+            return 'g.time()'
+        if field.tag.endswith('Dt') or field.tag.startswith('DtOf'):
+            return 'g.date()'
+        return 'g.generate()'
+    if field.type_name in ('f64', 'f32'):
+        min_value = float(facets.min_value) if facets.min_value is not None else 0.0
+        if facets.total_digits is not None or facets.fraction_digits is not None:
+            fraction_digits = facets.fraction_digits if facets.fraction_digits is not None else 2
+            total_digits = facets.total_digits if facets.total_digits is not None else fraction_digits + 8
+            return f'g.digits({min_value!r}, {total_digits}, {fraction_digits})'
+        return f'g.decimal({min_value!r})'
+    if field.type_name == 'u64' and facets.total_digits is not None and facets.total_digits < 20:
+        return f'g.integer(0, {10 ** facets.total_digits - 1})'
+    if field.type_name in ('bool', 'u64'):
+        return 'g.generate()'
+    return 'g.nested()'
+
+# Most items of a bounded repetition, whatever its maxOccurs.
+MAX_OCCURS_CAP = 100
+
+def repeated_expression(field: Field, value: str) -> str:
+    """Items of a repeated field, from one up to its maxOccurs if bounded."""
+    max_occurs = field.facets.max_occurs
+    if max_occurs is not None and max_occurs < MAX_OCCURS_CAP:
+        return f'g.repeated_up_to(1, {max_occurs}, |g| {value})'
+    return f'g.repeated(1, |g| {value})'
+
+def field_expression(field: Field) -> str:
+    value = value_expression(field)
+    if field.repeated:
+        value = repeated_expression(field, value)
+    if field.optional:
+        value = f'g.optional(|g| {value})'
+    return value
+
+def random_body(type_def: TypeDef) -> list:
+    if type_def.is_enum:
+        lines = [f'\t\tmatch g.choice({len(type_def.variants)}) {{']
+        for index, variant in enumerate(type_def.variants[:-1]):
+            lines.append(f'\t\t\t{index} => Self::{variant},')
+        lines.append(f'\t\t\t_ => Self::{type_def.variants[-1]},')
+        lines.append('\t\t}')
+        return lines
+    if is_choice(type_def):
+        # Exactly one of the optional elements of a choice, next to its
+        # required ones.
+        branches = [f for f in type_def.fields if f.optional]
+        lines = [f'\t\tmatch g.choice({len(branches)}) {{']
+        for index, chosen in enumerate(branches):
+            values = []
+            for f in type_def.fields:
+                if not f.optional:
+                    values.append(f'{f.name}: {field_expression(f)}')
+                elif f is chosen:
+                    value = value_expression(f)
+                    if f.repeated:
+                        value = repeated_expression(f, value)
+                    values.append(f'{f.name}: Some({value})')
+                else:
+                    values.append(f'{f.name}: None')
+            arm = str(index) if index < len(branches) - 1 else '_'
+            lines.append(f'\t\t\t{arm} => Self {{ {", ".join(values)} }},')
+        lines.append('\t\t}')
+        return lines
+    lines = ['\t\tSelf {']
+    for f in type_def.fields:
+        lines.append(f'\t\t\t{f.name}: {field_expression(f)},')
+    lines.append('\t\t}')
+    return lines
+
+def scan_document(directory: Path) -> list:
+    """
+    The message variants of the `Document` enum of document.rs, if the crate
+    has one, each with the `#[cfg(...)]` line that gates it, if any.
+    """
+    document_file = directory / 'document.rs'
+    if not document_file.exists():
+        return []
+    with open(document_file, 'r', encoding='utf-8') as f:
+        content = f.read()
+    match = re.search(r'\npub enum Document \{\n(.*?)\n\}\n', content, re.DOTALL)
+    if not match:
+        return []
+    variants = []
+    gate = None
+    for line in match.group(1).split('\n'):
+        cfg = re.match(r'^\s*(#\[cfg\(.*\)\])$', line)
+        if cfg:
+            gate = cfg.group(1)
+            continue
+        variant = re.match(r'^\s*(\w+)\(Box<', line)
+        if variant:
+            variants.append((variant.group(1), gate))
+            gate = None
+    return variants
+
+def document_random(variants: list) -> list:
+    """`Random` for `Document`: a random message of a random kind."""
+    lines = ['impl Random for crate::document::Document {']
+    lines.append('\t/// A random message of a random kind.')
+    lines.append('\tfn random(generator: &mut Generator) -> Self {')
+    lines.append('\t\tlet messages: &[fn(&mut Generator) -> crate::document::Document] = &[')
+    for variant, gate in variants:
+        if gate:
+            lines.append(f'\t\t\t{gate}')
+        lines.append(f'\t\t\t|g| crate::document::Document::{variant}(Box::new(g.nested())),')
+    lines.append('\t\t];')
+    lines.append('\t\tlet index = generator.choice(messages.len());')
+    lines.append('\t\tmessages[index](generator)')
+    lines.append('\t}')
+    lines.append('}')
+    lines.append('')
+    return lines
+
+def generate_random(types: list, random_crate: str, document: list = ()) -> str:
+    lines = [HEADER]
+    lines.append('// `Random` for the generated types of this crate, generated by')
+    lines.append('// generate-random.py from their facets (see `open_payments_common::random`).')
+    lines.append('')
+    if types or document:
+        lines.append(f'use {random_crate}::random::{{Generator, Random}};')
+        lines.append('')
+    for type_def in types:
+        uses_generator = type_def.is_enum or type_def.fields
+        generator = 'g' if uses_generator else '_g'
+        lines.append(f'impl Random for {type_def.module}::{type_def.name} {{')
+        lines.append(f'\tfn random({generator}: &mut Generator) -> Self {{')
+        if uses_generator:
+            lines.extend(random_body(type_def))
+        else:
+            lines.append('\t\tSelf {}')
+        lines.append('\t}')
+        lines.append('}')
+        lines.append('')
+    if document:
+        lines.extend(document_random(document))
+    return '\n'.join(lines)
+
+def add_module(lib_file: Path):
+    """Declare the private random_impls module in lib.rs, after the last module."""
+    with open(lib_file, 'r', encoding='utf-8') as f:
+        content = f.read()
+    if re.search(r'^(pub )?mod random_impls;$', content, re.MULTILINE):
+        return
+    modules = list(re.finditer(r'^pub mod \w+;\n', content, re.MULTILINE))
+    position = modules[-1].end() if modules else len(content)
+    with open(lib_file, 'w', encoding='utf-8') as f:
+        f.write(content[:position] + 'mod random_impls;\n' + content[position:])
+
+def main():
+    parser = argparse.ArgumentParser(
+        description='Generate random_impls.rs with Random implementations of the generated types'
+    )
+    parser.add_argument('directory',
+                       help='Source directory of the crate')
+    parser.add_argument('--xsd', action='append', default=[],
+                       help='Directory of the XSDs of the crate, for maxOccurs, totalDigits and fractionDigits')
+
+    args = parser.parse_args()
+
+    try:
+        directory = Path(args.directory)
+        schemas = scan_schemas(args.xsd)
+        types = scan_types(directory, schemas)
+        # Without the XSDs the values would ignore maxOccurs and digits.
+        if any(t.fields for t in types) and not schemas:
+            raise ValueError(f'No XSDs for the types of {directory}, give their directory with --xsd')
+        document = scan_document(directory)
+        # The common crate defines `Random`, the others use it from there.
+        random_crate = 'crate' if (directory / 'random.rs').exists() else 'open_payments_common'
+        with open(directory / 'random_impls.rs', 'w', encoding='utf-8') as f:
+            f.write(generate_random(types, random_crate, document))
+        add_module(directory / 'lib.rs')
+        print(f"Generated random_impls.rs with {len(types)} types in {directory}")
+    except Exception as e:
+        print(f"Error: {e}")
+        return 1
+
+    return 0
+
+if __name__ == "__main__":
+    exit(main())
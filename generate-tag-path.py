@@ -0,0 +1,111 @@
+import os
+import re
+from pathlib import Path
+import argparse
+
+from generated_types import HEADER, Field, TypeDef, scan_types
+
+def child_call(field: Field, mutable: bool) -> str:
+    """The helper of `tag_path` that appends the repetitions of a field."""
+    helper = 'one'
+    if field.optional and field.repeated:
+        helper = 'optional_repeated'
+    elif field.optional:
+        helper = 'optional'
+    elif field.repeated:
+        helper = 'repeated'
+    if not mutable:
+        return f'{helper}(&self.{field.name}, step, out)'
+    if field.optional:
+        return f'{helper}_mut(&mut self.{field.name}, step, create, out)'
+    return f'{helper}_mut(&mut self.{field.name}, step, out)'
+
+def impl_body(type_def: TypeDef) -> list:
+    lines = []
+    if type_def.is_enum:
+        lines.append('\tfn code(&self) -> Option<&\'static str> {')
+        lines.append('\t\tSome(match self {')
+        for variant, code in zip(type_def.variants, type_def.codes):
+            lines.append(f'\t\t\tSelf::{variant} => "{code}",')
+        lines.append('\t\t})')
+        lines.append('\t}')
+        lines.append('')
+        lines.append('\tfn set_code(&mut self, code: &str) -> bool {')
+        lines.append('\t\t*self = match code {')
+        for variant, code in zip(type_def.variants, type_def.codes):
+            lines.append(f'\t\t\t"{code}" => Self::{variant},')
+        lines.append('\t\t\t_ => return false,')
+        lines.append('\t\t};')
+        lines.append('\t\ttrue')
+        lines.append('\t}')
+        return lines
+    lines.append('\tfn children<\'a>(&\'a self, step: &Step, out: &mut Vec<&\'a dyn TagPaths>) {')
+    for f in type_def.fields:
+        lines.append(f'\t\tif step.matches("{f.tag}") {{ {child_call(f, False)} }}')
+    lines.append('\t}')
+    lines.append('')
+    create = 'create' if any(f.optional for f in type_def.fields) else '_create'
+    lines.append(f'\tfn children_mut<\'a>(&\'a mut self, step: &Step, {create}: bool, out: &mut Vec<&\'a mut dyn TagPaths>) {{')
+    for f in type_def.fields:
+        lines.append(f'\t\tif step.matches("{f.tag}") {{ {child_call(f, True)} }}')
+    lines.append('\t}')
+    return lines
+
+def generate_tag_paths(types: list, tag_path_crate: str) -> str:
+    lines = [HEADER]
+    lines.append('// `TagPaths` for the generated types of this crate, generated by')
+    lines.append('// generate-tag-path.py from the tags of their fields (see')
+    lines.append('// `open_payments_common::tag_path`).')
+    lines.append('')
+    if types:
+        lines.append(f'use {tag_path_crate}::tag_path::*;')
+        lines.append('')
+    for type_def in types:
+        if not type_def.fields and not type_def.variants:
+            lines.append(f'impl TagPaths for {type_def.module}::{type_def.name} {{}}')
+            lines.append('')
+            continue
+        lines.append(f'impl TagPaths for {type_def.module}::{type_def.name} {{')
+        lines.extend(impl_body(type_def))
+        lines.append('}')
+        lines.append('')
+    return '\n'.join(lines)
+
+def add_module(lib_file: Path):
+    """Declare the private tag_path_impls module in lib.rs, after the last module."""
+    with open(lib_file, 'r', encoding='utf-8') as f:
+        content = f.read()
+    if re.search(r'^(pub )?mod tag_path_impls;$', content, re.MULTILINE):
+        return
+    modules = list(re.finditer(r'^(pub )?mod \w+;\n', content, re.MULTILINE))
+    position = modules[-1].end() if modules else len(content)
+    declaration = '#[cfg(feature = "derive_default")]\nmod tag_path_impls;\n'
+    with open(lib_file, 'w', encoding='utf-8') as f:
+        f.write(content[:position] + declaration + content[position:])
+
+def main():
+    parser = argparse.ArgumentParser(
+        description='Generate tag_path_impls.rs with TagPaths implementations of the generated types'
+    )
+    parser.add_argument('directory',
+                       help='Source directory of the crate')
+
+    args = parser.parse_args()
+
+    try:
+        directory = Path(args.directory)
+        types = scan_types(directory)
+        # The common crate defines `TagPaths`, the others use it from there.
+        tag_path_crate = 'crate' if (directory / 'tag_path.rs').exists() else 'open_payments_common'
+        with open(directory / 'tag_path_impls.rs', 'w', encoding='utf-8') as f:
+            f.write(generate_tag_paths(types, tag_path_crate))
+        add_module(directory / 'lib.rs')
+        print(f"Generated tag_path_impls.rs with {len(types)} types in {directory}")
+    except Exception as e:
+        print(f"Error: {e}")
+        return 1
+
+    return 0
+
+if __name__ == "__main__":
+    exit(main())
@@ -0,0 +1,216 @@
+import os
+import re
+from pathlib import Path
+from dataclasses import dataclass, field
+import argparse
+
+HEADER = '''// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+'''
+
+PRIMITIVES = {'String', 'bool', 'f64', 'f32', 'u64', 'u32', 'i64', 'i32', 'u8'}
+
+@dataclass
+class Field:
+    name: str
+    type_name: str
+    optional: bool
+    repeated: bool
+
+@dataclass
+class TypeDef:
+    name: str
+    module: str
+    fields: list = field(default_factory=list)
+
+def snake_case(name: str) -> str:
+    """FIToFICustomerCreditTransferV08 -> fi_to_fi_customer_credit_transfer_v08"""
+    name = re.sub(r'([A-Z]+)([A-Z][a-z])', r'\1_\2', name)
+    name = re.sub(r'([a-z0-9])([A-Z])', r'\1_\2', name)
+    return name.lower()
+
+def module_path(directory: Path, file_path: Path) -> str:
+    parts = list(file_path.relative_to(directory).with_suffix('').parts)
+    if parts[-1] == 'mod':
+        parts = parts[:-1]
+    return '::'.join(['crate'] + parts)
+
+def scan_types(directory: Path) -> list:
+    """
+    Find the generated structs and enums (those with the xgen derive block)
+    in the .rs files of a directory and its subdirectories.
+    """
+    type_pattern = re.compile(
+        r'\n// (\w+) \.\.\.\n'
+        r'#\[cfg_attr\(feature = "derive_debug", derive\(Debug\)\)\]\n'
+        r'#\[cfg_attr\(feature = "derive_default", derive\(Default\)\)\]\n'
+        r'#\[cfg_attr\(feature = "derive_serde", derive\(Serialize, Deserialize\)\)\]\n'
+        r'#\[cfg_attr\(feature = "derive_clone", derive\(Clone\)\)\]\n'
+        r'#\[cfg_attr\(feature = "derive_partial_eq", derive\(PartialEq\)\)\]\n'
+        r'pub (struct|enum) (\w+) \{\n((?:\t.*\n)*)\}\n'
+    )
+    field_pattern = re.compile(r'^\tpub (?:r#)?(\w+): (Option<)?(Vec<)?(\w+)>*,$', re.MULTILINE)
+
+    types = []
+    for file_path in sorted(directory.rglob('*.rs')):
+        if file_path.name in ('lib.rs', 'visitor.rs'):
+            continue
+        module = module_path(directory, file_path)
+        with open(file_path, 'r', encoding='utf-8') as f:
+            content = f.read()
+        for match in type_pattern.finditer(content):
+            type_def = TypeDef(name=match.group(3), module=module)
+            if match.group(2) == 'struct':
+                for field_match in field_pattern.finditer(match.group(4)):
+                    type_def.fields.append(Field(
+                        name=field_match.group(1),
+                        type_name=field_match.group(4),
+                        optional=field_match.group(2) is not None,
+                        repeated=field_match.group(3) is not None,
+                    ))
+            types.append(type_def)
+    return types
+
+def visit_statement(field: Field, visit: str, mutable: bool) -> str:
+    """`visit` is a format string for the call on a `{}` of the field type."""
+    ref = 'ref mut' if mutable else 'ref'
+    borrow = '&mut ' if mutable else '&'
+    if field.optional and field.repeated:
+        return f'if let Some({ref} vec) = node.{field.name} {{ for item in vec {{ {visit.format("item")} }} }}'
+    if field.optional:
+        return f'if let Some({ref} value) = node.{field.name} {{ {visit.format("value")} }}'
+    if field.repeated:
+        return f'for item in {borrow}node.{field.name} {{ {visit.format("item")} }}'
+    if visit.startswith('{}.'):
+        return visit.format(f'node.{field.name}') + ';'
+    return visit.format(f'{borrow}node.{field.name}') + ';'
+
+def generate_visitor(types: list, base_crate: str) -> str:
+    by_name = {}
+    for type_def in types:
+        if type_def.name in by_name:
+            raise ValueError(f'{type_def.name} is defined in {by_name[type_def.name].module} and {type_def.module}')
+        by_name[type_def.name] = type_def
+    methods = {}
+    for type_def in types:
+        snake = snake_case(type_def.name)
+        if snake in methods:
+            raise ValueError(f'{type_def.name} and {methods[snake]} have the same visit method')
+        methods[snake] = type_def.name
+
+    def visit(field: Field, suffix: str) -> str:
+        # Types of the base crate are visited through its traits, hand
+        # written types (like `Document`) by their own `walk` methods.
+        if field.type_name in by_name or base_crate:
+            return f'visitor.visit_{snake_case(field.type_name)}{suffix}({{}})'
+        return f'{{}}.walk{suffix}(visitor)'
+
+    hand_written = sorted({f.type_name for t in types for f in t.fields if f.type_name not in by_name and f.type_name not in PRIMITIVES})
+    if hand_written and not base_crate:
+        print(f"Types walked by their walk methods: {', '.join(hand_written)}")
+
+    lines = [HEADER]
+    lines.append('// Visitors over the generated types of this crate, generated by')
+    lines.append('// generate-visitor.py. `Visitor` has a `visit_` method per type that')
+    lines.append('// walks into its fields by default; override the methods of the types of')
+    lines.append('// interest and call the matching `walk_` function to keep going below')
+    lines.append('// them. `VisitorMut` does the same with mutable references.')
+    lines.append('')
+    if base_crate:
+        lines.append(f'use {base_crate}::visitor::{{Visitor as BaseVisitor, VisitorMut as BaseVisitorMut}};')
+        lines.append('')
+
+    for trait, mutable in (('Visitor', False), ('VisitorMut', True)):
+        supertrait = f': Base{trait}' if base_crate else ''
+        suffix = '_mut' if mutable else ''
+        borrow = '&mut ' if mutable else '&'
+        lines.append(f'pub trait {trait}{supertrait} {{')
+        for type_def in types:
+            snake = snake_case(type_def.name)
+            lines.append(f'\tfn visit_{snake}{suffix}(&mut self, node: {borrow}{type_def.module}::{type_def.name}) {{ walk_{snake}{suffix}(self, node) }}')
+        lines.append('}')
+        lines.append('')
+
+    for type_def in types:
+        snake = snake_case(type_def.name)
+        for trait, mutable in (('Visitor', False), ('VisitorMut', True)):
+            suffix = '_mut' if mutable else ''
+            borrow = '&mut ' if mutable else '&'
+            fields = [f for f in type_def.fields if f.type_name not in PRIMITIVES]
+            visitor_name, node_name = ('visitor', 'node') if fields else ('_visitor', '_node')
+            lines.append(f'pub fn walk_{snake}{suffix}<V: {trait} + ?Sized>({visitor_name}: &mut V, {node_name}: {borrow}{type_def.module}::{type_def.name}) {{')
+            for f in fields:
+                lines.append('\t' + visit_statement(f, visit(f, suffix), mutable))
+            lines.append('}')
+            lines.append('')
+
+    for type_def in types:
+        snake = snake_case(type_def.name)
+        lines.append(f'impl {type_def.module}::{type_def.name} {{')
+        lines.append(f'\tpub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {{ visitor.visit_{snake}(self) }}')
+        lines.append(f'\tpub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {{ visitor.visit_{snake}_mut(self) }}')
+        lines.append('}')
+        lines.append('')
+
+    return '\n'.join(lines)
+
+def add_module(lib_file: Path):
+    """Declare the visitor module in lib.rs, after the last module."""
+    with open(lib_file, 'r', encoding='utf-8') as f:
+        content = f.read()
+    if re.search(r'^pub mod visitor;$', content, re.MULTILINE):
+        return
+    modules = list(re.finditer(r'^pub mod \w+;\n', content, re.MULTILINE))
+    position = modules[-1].end() if modules else len(content)
+    with open(lib_file, 'w', encoding='utf-8') as f:
+        f.write(content[:position] + 'pub mod visitor;\n' + content[position:])
+
+def main():
+    parser = argparse.ArgumentParser(
+        description='Generate visitor.rs with Visitor and VisitorMut traits over the generated types'
+    )
+    parser.add_argument('directory',
+                       help='Source directory of the crate')
+    parser.add_argument('base_crate',
+                       help='Crate whose visitors the traits extend, for types not defined in this crate (e.g. open_payments_common)',
+                       default=None,
+                       nargs='?')
+
+    args = parser.parse_args()
+
+    try:
+        directory = Path(args.directory)
+        types = scan_types(directory)
+        with open(directory / 'visitor.rs', 'w', encoding='utf-8') as f:
+            f.write(generate_visitor(types, args.base_crate))
+        add_module(directory / 'lib.rs')
+        print(f"Generated visitor.rs with {len(types)} types in {directory}")
+    except Exception as e:
+        print(f"Error: {e}")
+        return 1
+
+    return 0
+
+if __name__ == "__main__":
+    exit(main())
@@ -33,6 +33,30 @@ rm open-payments-common/src/common-pain.rs
 rm open-payments-common/src/common-reda.rs
 rm open-payments-common/src/common-remt.rs
 
+# Visitors over the generated types
+python3 generate-visitor.py open-payments-common/src
+for family in acmt admi auth camt head pacs pain reda remt; do
+    python3 generate-visitor.py iso20022-$family/src open_payments_common
+done
+
+# Random and example values of the generated types, the XSDs giving the
+# maxOccurs and digits that the generated code leaves out
+python3 generate-random.py open-payments-common/src --xsd xsd/iso20022
+python3 generate-example.py open-payments-common/src
+for family in acmt admi auth camt head pacs pain reda remt; do
+    python3 generate-random.py iso20022-$family/src --xsd xsd/iso20022/$family
+    python3 generate-example.py iso20022-$family/src
+done
+
+# Random messages of the iso20022 Document, over the families
+python3 generate-random.py iso20022/src
+
+# Tag path access to the generated types
+python3 generate-tag-path.py open-payments-common/src
+for family in acmt admi auth camt head pacs pain reda remt; do
+    python3 generate-tag-path.py iso20022-$family/src
+done
+
 # FedNow XSDs
 cp common.rs fednow/src
 sh generate-fednow.sh xsd/fednow fednow/src
@@ -43,3 +67,14 @@ cat fednow/src/iso/common.rs >> fednow/src/common.rs
 rm fednow/src/iso/common.rs
 cat fednow/src/fednow_extra/common.rs >> fednow/src/common.rs
 rm fednow/src/fednow_extra/common.rs
+
+# serde-xml-rs, which parses FedNow messages, names attributes without the @ of quick-xml
+perl -pi -e 's/serde\(rename = "\@(\w+)"\)/serde(rename = "\@$1", alias = "$1")/' fednow/src/common.rs
+
+# ElctrncSeqNb and LglSeqNb are Number with fractionDigits 0 in the XSDs: sequence numbers are integers
+perl -0777 -pi -e 's/(rename = "(?:ElctrncSeqNb|LglSeqNb)"[^\n]*\n\s*pub \w+: Option<)f64>/$1u64>/g' fednow/src/common.rs
+
+python3 generate-visitor.py fednow/src
+python3 generate-random.py fednow/src --xsd xsd/fednow
+python3 generate-example.py fednow/src
+python3 generate-tag-path.py fednow/src
@@ -0,0 +1,247 @@
+# Shared by the generate-*.py scripts of the generated types: the scan of
+# the structs and enums that xgen generates, with the tags and facets of
+# their fields, completed by the facets of the XSDs that the generated code
+# leaves out.
+
+import re
+import xml.etree.ElementTree as ET
+from pathlib import Path
+from dataclasses import dataclass, field
+
+HEADER = '''// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+'''
+
+XS = '{http://www.w3.org/2001/XMLSchema}'
+
+# Files of the scripts, without generated types.
+GENERATED_FILES = {'lib.rs', 'visitor.rs', 'random_impls.rs', 'example_impls.rs', 'tag_path_impls.rs'}
+
+# Choices of the FedNow envelope, which unlike the ISO 20022 ones
+# (`...Choice`) are not named as such.
+CHOICE_TYPES = {
+    'FedNowIncomingMessage',
+    'FedNowIncomingMessageSignatureManagement',
+    'FedNowOutgoingMessage',
+    'FedNowOutgoingMessageSignatureManagement',
+}
+
+@dataclass
+class Facets:
+    min_length: int = None
+    max_length: int = None
+    min_value: str = None
+    pattern: str = None
+    # Not in the generated code, read from the XSDs.
+    max_occurs: int = None
+    total_digits: int = None
+    fraction_digits: int = None
+
+@dataclass
+class Field:
+    name: str
+    tag: str
+    type_name: str
+    optional: bool
+    repeated: bool
+    facets: Facets = field(default_factory=Facets)
+
+@dataclass
+class TypeDef:
+    name: str
+    module: str
+    is_enum: bool
+    fields: list = field(default_factory=list)
+    variants: list = field(default_factory=list)
+    # Code of each variant of an enum (its serde rename)
+    codes: list = field(default_factory=list)
+
+def module_path(directory: Path, file_path: Path) -> str:
+    parts = list(file_path.relative_to(directory).with_suffix('').parts)
+    if parts[-1] == 'mod':
+        parts = parts[:-1]
+    return '::'.join(['crate'] + parts)
+
+def scan_facets(content: str, name: str) -> dict:
+    """
+    The facets checked by the validate method of a type, by field, read back
+    from its checks and error messages.
+    """
+    match = re.search(r'\nimpl ' + name + r' \{\n\tpub fn validate\(&self\) -> Result<\(\), ValidationError> \{\n(.*?)\n\}\n', content, re.DOTALL)
+    facets = {}
+    if not match:
+        return facets
+    pattern = None
+    for line in match.group(1).split('\n'):
+        regex = re.search(r'Regex::new\("(.*)"\)\.unwrap\(\)', line)
+        if regex:
+            pattern = regex.group(1)
+            continue
+        error = re.search(r'ValidationError::new\((\d+), "(\w+) [a-z ]+?(?: of ([0-9.]+))?"', line)
+        if not error:
+            continue
+        code, field_name, bound = error.groups()
+        field_facets = facets.setdefault(field_name, Facets())
+        if code == '1001':
+            field_facets.min_length = int(bound)
+        elif code == '1002':
+            field_facets.max_length = int(bound)
+        elif code == '1003':
+            field_facets.min_value = bound
+        elif code == '1005':
+            field_facets.pattern = pattern
+        else:
+            raise ValueError(f'Unknown check {code} on {name}.{field_name}')
+    return facets
+
+def type_key(name: str) -> str:
+    """A type name of the XSDs or of the generated code, without separators."""
+    return re.sub(r'[^0-9A-Za-z]', '', name).lower()
+
+def scan_schemas(directories: list) -> dict:
+    """
+    The facets that the generated code leaves out, maxOccurs, totalDigits and
+    fractionDigits, of the elements of the complex types of the .xsd files in
+    directories and their subdirectories, by type and tag. The text of a
+    simple content has the tag `$value`.
+    """
+    simple_types = {}
+    complex_types = []
+    for directory in directories:
+        for file_path in sorted(Path(directory).rglob('*.xsd')):
+            root = ET.parse(file_path).getroot()
+            for simple_type in root.iter(XS + 'simpleType'):
+                restriction = simple_type.find(XS + 'restriction')
+                if simple_type.get('name') is None or restriction is None:
+                    continue
+                digits = {}
+                for facet in ('totalDigits', 'fractionDigits'):
+                    element = restriction.find(XS + facet)
+                    if element is not None:
+                        digits[facet] = int(element.get('value'))
+                simple_types[simple_type.get('name')] = (restriction.get('base'), digits)
+            complex_types.extend(c for c in root.iter(XS + 'complexType') if c.get('name'))
+
+    def digits(type_name: str) -> dict:
+        # Facets of the restriction chain, the nearest first.
+        found = {}
+        while type_name in simple_types:
+            base, own = simple_types[type_name]
+            found = {**own, **found}
+            type_name = base
+        return found
+
+    def facets(type_name: str, max_occurs: str = None) -> Facets:
+        type_digits = digits(type_name)
+        return Facets(
+            max_occurs=int(max_occurs) if max_occurs not in (None, 'unbounded') else None,
+            total_digits=type_digits.get('totalDigits'),
+            fraction_digits=type_digits.get('fractionDigits'),
+        )
+
+    schemas = {}
+    for complex_type in complex_types:
+        fields = schemas.setdefault(type_key(complex_type.get('name')), {})
+        content = complex_type.find(XS + 'simpleContent')
+        if content is not None:
+            for derivation in content:
+                fields['$value'] = facets(derivation.get('base'))
+        for element in complex_type.iter(XS + 'element'):
+            if element.get('name'):
+                fields[element.get('name')] = facets(element.get('type'), element.get('maxOccurs'))
+    return schemas
+
+def scan_types(directory: Path, schemas: dict = None) -> list:
+    """
+    Find the generated structs and enums (those with the xgen derive block)
+    in the .rs files of a directory and its subdirectories, with the tags
+    and facets of their fields and the codes of their variants, completed by the facets of `schemas` (see
+    `scan_schemas`).
+    """
+    type_pattern = re.compile(
+        r'\n// (\w+) \.\.\.\n'
+        r'#\[cfg_attr\(feature = "derive_debug", derive\(Debug\)\)\]\n'
+        r'#\[cfg_attr\(feature = "derive_default", derive\(Default\)\)\]\n'
+        r'#\[cfg_attr\(feature = "derive_serde", derive\(Serialize, Deserialize\)\)\]\n'
+        r'#\[cfg_attr\(feature = "derive_clone", derive\(Clone\)\)\]\n'
+        r'#\[cfg_attr\(feature = "derive_partial_eq", derive\(PartialEq\)\)\]\n'
+        r'pub (struct|enum) (\w+) \{\n((?:\t.*\n)*)\}\n'
+    )
+    field_pattern = re.compile(r'^\tpub (r#)?(\w+): (Option<)?(Vec<)?(\w+)>*,$')
+    rename_pattern = re.compile(r'serde\(rename = "([^"]+)"')
+    variant_pattern = re.compile(r'^\t(\w+),$')
+
+    types = []
+    for file_path in sorted(directory.rglob('*.rs')):
+        if file_path.name in GENERATED_FILES:
+            continue
+        module = module_path(directory, file_path)
+        with open(file_path, 'r', encoding='utf-8') as f:
+            content = f.read()
+        for match in type_pattern.finditer(content):
+            name = match.group(3)
+            type_def = TypeDef(name=name, module=module, is_enum=match.group(2) == 'enum')
+            facets = scan_facets(content, name)
+            schema = (schemas or {}).get(type_key(name), {})
+            tag = None
+            for line in match.group(4).split('\n'):
+                rename = rename_pattern.search(line)
+                if rename:
+                    tag = rename.group(1)
+                    continue
+                if type_def.is_enum:
+                    variant = variant_pattern.match(line)
+                    if variant:
+                        type_def.variants.append(variant.group(1))
+                        type_def.codes.append(tag or variant.group(1))
+                        tag = None
+                    continue
+                if not line.startswith('\tpub '):
+                    continue
+                field_match = field_pattern.match(line)
+                if not field_match:
+                    raise ValueError(f'Unsupported field in {name}: {line.strip()}')
+                field_name = field_match.group(2)
+                field_facets = facets.get(field_name, Facets())
+                schema_facets = schema.get(tag or field_name)
+                if schema_facets:
+                    field_facets.max_occurs = schema_facets.max_occurs
+                    field_facets.total_digits = schema_facets.total_digits
+                    field_facets.fraction_digits = schema_facets.fraction_digits
+                type_def.fields.append(Field(
+                    name=(field_match.group(1) or '') + field_name,
+                    tag=tag or field_name,
+                    type_name=field_match.group(5),
+                    optional=field_match.group(3) is not None,
+                    repeated=field_match.group(4) is not None,
+                    facets=field_facets,
+                ))
+                tag = None
+            types.append(type_def)
+    return types
+
+def is_choice(type_def: TypeDef) -> bool:
+    if type_def.name in CHOICE_TYPES:
+        return True
+    return type_def.name.endswith('Choice') and len(type_def.fields) > 1 and all(f.optional for f in type_def.fields)
@@ -0,0 +1,523 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `Example` for the generated types of this crate, generated by
+// generate-example.py from their facets (see `open_payments_common::example`).
+
+use open_payments_common::example::Example;
+
+impl Example for crate::acmt_001_001_08::AccountOpeningInstructionV08 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			ordr_ref: None,
+			prvs_ref: None,
+			instr_dtls: Example::example(),
+			invstmt_acct: Example::example(),
+			acct_pties: Example::example(),
+			intrmies: None,
+			plcmnt: None,
+			new_isse_allcn: None,
+			svgs_invstmt_plan: None,
+			wdrwl_invstmt_plan: None,
+			csh_sttlm: None,
+			svc_lvl_agrmt: None,
+			addtl_inf: None,
+			mkt_prctc_vrsn: None,
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_002_001_08::AccountDetailsConfirmationV08 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			ordr_ref: None,
+			rltd_ref: None,
+			conf_dtls: Example::example(),
+			invstmt_acct: None,
+			acct_pties: None,
+			intrmies: None,
+			plcmnt: None,
+			new_isse_allcn: None,
+			svgs_invstmt_plan: None,
+			wdrwl_invstmt_plan: None,
+			csh_sttlm: None,
+			svc_lvl_agrmt: None,
+			addtl_inf: None,
+			mkt_prctc_vrsn: None,
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_003_001_08::AccountModificationInstructionV08 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			prvs_ref: None,
+			instr_dtls: None,
+			invstmt_acct_selctn: Example::example(),
+			modfd_invstmt_acct: None,
+			modfd_acct_pties: None,
+			modfd_intrmies: None,
+			modfd_plcmnt: None,
+			modfd_isse_allcn: None,
+			modfd_svgs_invstmt_plan: None,
+			modfd_wdrwl_invstmt_plan: None,
+			modfd_csh_sttlm: None,
+			modfd_svc_lvl_agrmt: None,
+			modfd_addtl_inf: None,
+			mkt_prctc_vrsn: None,
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_005_001_06::RequestForAccountManagementStatusReportV06 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			req_dtls: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::acmt_006_001_07::AccountManagementStatusReportV07 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			rltd_ref: vec![Example::example()],
+			sts_rpt: Example::example(),
+			mkt_prctc_vrsn: None,
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_007_001_05::AccountOpeningRequestV05 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			acct: Example::example(),
+			ctrct_dts: None,
+			undrlyg_mstr_agrmt: None,
+			acct_svcr_id: Example::example(),
+			org: Example::example(),
+			mndt: None,
+			grp: None,
+			ref_acct: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_008_001_05::AccountOpeningAmendmentRequestV05 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			ctrct_dts: None,
+			undrlyg_mstr_agrmt: None,
+			acct: Example::example(),
+			acct_svcr_id: Example::example(),
+			org: Example::example(),
+			mndt: None,
+			grp: None,
+			ref_acct: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_009_001_04::AccountOpeningAdditionalInformationRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			org_id: Example::example(),
+			acct: Example::example(),
+			acct_svcr_id: Example::example(),
+			undrlyg_mstr_agrmt: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_010_001_04::AccountRequestAcknowledgementV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			acct_id: None,
+			org_id: Example::example(),
+			acct_svcr_id: Example::example(),
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_011_001_04::AccountRequestRejectionV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			acct_svcr_id: Example::example(),
+			acct_id: None,
+			org_id: Example::example(),
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_012_001_04::AccountAdditionalInformationRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			org_id: Example::example(),
+			acct_svcr_id: Example::example(),
+			acct_id: vec![Example::example()],
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_013_001_04::AccountReportRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			acct_id: vec![Example::example()],
+			acct_svcr_id: Example::example(),
+			org_id: Example::example(),
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_014_001_05::AccountReportV05 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			acct_svcr_id: Example::example(),
+			org: Example::example(),
+			rpt: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_015_001_04::AccountExcludedMandateMaintenanceRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			ctrct_dts: None,
+			undrlyg_mstr_agrmt: None,
+			acct: Example::example(),
+			acct_svcr_id: Example::example(),
+			org: Example::example(),
+			addtl_msg_inf: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_016_001_04::AccountExcludedMandateMaintenanceAmendmentRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			ctrct_dts: None,
+			undrlyg_mstr_agrmt: None,
+			acct: Example::example(),
+			acct_svcr_id: Example::example(),
+			org: Example::example(),
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_017_001_04::AccountMandateMaintenanceRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			ctrct_dts: None,
+			undrlyg_mstr_agrmt: None,
+			acct_id: vec![Example::example()],
+			acct_svcr_id: Example::example(),
+			org_id: Example::example(),
+			mndt: vec![Example::example()],
+			grp: None,
+			addtl_msg_inf: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_018_001_04::AccountMandateMaintenanceAmendmentRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			ctrct_dts: None,
+			undrlyg_mstr_agrmt: None,
+			acct_id: vec![Example::example()],
+			acct_svcr_id: Example::example(),
+			org_id: Example::example(),
+			mndt: None,
+			grp: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_019_001_04::AccountClosingRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			acct_id: Example::example(),
+			acct_svcr_id: Example::example(),
+			org_id: Example::example(),
+			ctrct_dts: None,
+			bal_trf_acct: None,
+			trf_acct_svcr_id: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_020_001_04::AccountClosingAmendmentRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			acct_id: Example::example(),
+			acct_svcr_id: Example::example(),
+			org_id: Example::example(),
+			ctrct_dts: None,
+			bal_trf_acct: None,
+			trf_acct_svcr_id: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_021_001_04::AccountClosingAdditionalInformationRequestV04 {
+	fn example() -> Self {
+		Self {
+			refs: Example::example(),
+			fr: None,
+			org_id: Example::example(),
+			acct_id: Example::example(),
+			acct_svcr_id: Example::example(),
+			bal_trf_acct: None,
+			trf_acct_svcr_id: None,
+			dgtl_sgntr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_022_001_04::IdentificationModificationAdviceV04 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			orgnl_tx_ref: None,
+			mod_attr: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_023_001_04::IdentificationVerificationRequestV04 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			vrfctn: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_024_001_04::IdentificationVerificationReportV04 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			orgnl_assgnmt: None,
+			rpt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_027_001_05::AccountSwitchInformationRequestV05 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			new_acct: Example::example(),
+			od_acct: Example::example(),
+			bal_trf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_028_001_05::AccountSwitchInformationResponseV05 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			new_acct: Example::example(),
+			od_acct: Example::example(),
+			pmt_instr: None,
+			drct_dbt_instr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_029_001_05::AccountSwitchCancelExistingPaymentV05 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			od_acct: Example::example(),
+			pmt_instr: None,
+			drct_dbt_instr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_030_001_04::AccountSwitchRequestRedirectionV04 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			new_acct: Example::example(),
+			od_acct: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_031_001_05::AccountSwitchRequestBalanceTransferV05 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			new_acct: Example::example(),
+			nmntd_acct: None,
+			bal_trf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_032_001_05::AccountSwitchBalanceTransferAcknowledgementV05 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			od_acct: Example::example(),
+			od_acct_bal: Example::example(),
+			bal_trf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_033_001_02::AccountSwitchNotifyAccountSwitchCompleteV02 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_034_001_05::AccountSwitchRequestPaymentV05 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			od_acct: Example::example(),
+			cdt_instr: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_035_001_02::AccountSwitchPaymentResponseV02 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_036_001_01::AccountSwitchTerminationSwitchV01 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::acmt_037_001_02::AccountSwitchTechnicalRejectionV02 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			acct_swtch_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
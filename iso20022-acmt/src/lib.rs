@@ -57,3 +57,7 @@ pub mod acmt_034_001_05;
 pub mod acmt_035_001_02;
 pub mod acmt_036_001_01;
 pub mod acmt_037_001_02;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
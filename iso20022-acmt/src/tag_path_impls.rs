@@ -0,0 +1,815 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `TagPaths` for the generated types of this crate, generated by
+// generate-tag-path.py from the tags of their fields (see
+// `open_payments_common::tag_path`).
+
+use open_payments_common::tag_path::*;
+
+impl TagPaths for crate::acmt_001_001_08::AccountOpeningInstructionV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("OrdrRef") { optional(&self.ordr_ref, step, out) }
+		if step.matches("PrvsRef") { optional(&self.prvs_ref, step, out) }
+		if step.matches("InstrDtls") { one(&self.instr_dtls, step, out) }
+		if step.matches("InvstmtAcct") { one(&self.invstmt_acct, step, out) }
+		if step.matches("AcctPties") { one(&self.acct_pties, step, out) }
+		if step.matches("Intrmies") { optional_repeated(&self.intrmies, step, out) }
+		if step.matches("Plcmnt") { optional(&self.plcmnt, step, out) }
+		if step.matches("NewIsseAllcn") { optional(&self.new_isse_allcn, step, out) }
+		if step.matches("SvgsInvstmtPlan") { optional_repeated(&self.svgs_invstmt_plan, step, out) }
+		if step.matches("WdrwlInvstmtPlan") { optional_repeated(&self.wdrwl_invstmt_plan, step, out) }
+		if step.matches("CshSttlm") { optional_repeated(&self.csh_sttlm, step, out) }
+		if step.matches("SvcLvlAgrmt") { optional_repeated(&self.svc_lvl_agrmt, step, out) }
+		if step.matches("AddtlInf") { optional_repeated(&self.addtl_inf, step, out) }
+		if step.matches("MktPrctcVrsn") { optional(&self.mkt_prctc_vrsn, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("OrdrRef") { optional_mut(&mut self.ordr_ref, step, create, out) }
+		if step.matches("PrvsRef") { optional_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("InstrDtls") { one_mut(&mut self.instr_dtls, step, out) }
+		if step.matches("InvstmtAcct") { one_mut(&mut self.invstmt_acct, step, out) }
+		if step.matches("AcctPties") { one_mut(&mut self.acct_pties, step, out) }
+		if step.matches("Intrmies") { optional_repeated_mut(&mut self.intrmies, step, create, out) }
+		if step.matches("Plcmnt") { optional_mut(&mut self.plcmnt, step, create, out) }
+		if step.matches("NewIsseAllcn") { optional_mut(&mut self.new_isse_allcn, step, create, out) }
+		if step.matches("SvgsInvstmtPlan") { optional_repeated_mut(&mut self.svgs_invstmt_plan, step, create, out) }
+		if step.matches("WdrwlInvstmtPlan") { optional_repeated_mut(&mut self.wdrwl_invstmt_plan, step, create, out) }
+		if step.matches("CshSttlm") { optional_repeated_mut(&mut self.csh_sttlm, step, create, out) }
+		if step.matches("SvcLvlAgrmt") { optional_repeated_mut(&mut self.svc_lvl_agrmt, step, create, out) }
+		if step.matches("AddtlInf") { optional_repeated_mut(&mut self.addtl_inf, step, create, out) }
+		if step.matches("MktPrctcVrsn") { optional_mut(&mut self.mkt_prctc_vrsn, step, create, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_002_001_08::AccountDetailsConfirmationV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("OrdrRef") { optional(&self.ordr_ref, step, out) }
+		if step.matches("RltdRef") { optional(&self.rltd_ref, step, out) }
+		if step.matches("ConfDtls") { one(&self.conf_dtls, step, out) }
+		if step.matches("InvstmtAcct") { optional(&self.invstmt_acct, step, out) }
+		if step.matches("AcctPties") { optional(&self.acct_pties, step, out) }
+		if step.matches("Intrmies") { optional_repeated(&self.intrmies, step, out) }
+		if step.matches("Plcmnt") { optional(&self.plcmnt, step, out) }
+		if step.matches("NewIsseAllcn") { optional(&self.new_isse_allcn, step, out) }
+		if step.matches("SvgsInvstmtPlan") { optional_repeated(&self.svgs_invstmt_plan, step, out) }
+		if step.matches("WdrwlInvstmtPlan") { optional_repeated(&self.wdrwl_invstmt_plan, step, out) }
+		if step.matches("CshSttlm") { optional_repeated(&self.csh_sttlm, step, out) }
+		if step.matches("SvcLvlAgrmt") { optional_repeated(&self.svc_lvl_agrmt, step, out) }
+		if step.matches("AddtlInf") { optional_repeated(&self.addtl_inf, step, out) }
+		if step.matches("MktPrctcVrsn") { optional(&self.mkt_prctc_vrsn, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("OrdrRef") { optional_mut(&mut self.ordr_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("ConfDtls") { one_mut(&mut self.conf_dtls, step, out) }
+		if step.matches("InvstmtAcct") { optional_mut(&mut self.invstmt_acct, step, create, out) }
+		if step.matches("AcctPties") { optional_mut(&mut self.acct_pties, step, create, out) }
+		if step.matches("Intrmies") { optional_repeated_mut(&mut self.intrmies, step, create, out) }
+		if step.matches("Plcmnt") { optional_mut(&mut self.plcmnt, step, create, out) }
+		if step.matches("NewIsseAllcn") { optional_mut(&mut self.new_isse_allcn, step, create, out) }
+		if step.matches("SvgsInvstmtPlan") { optional_repeated_mut(&mut self.svgs_invstmt_plan, step, create, out) }
+		if step.matches("WdrwlInvstmtPlan") { optional_repeated_mut(&mut self.wdrwl_invstmt_plan, step, create, out) }
+		if step.matches("CshSttlm") { optional_repeated_mut(&mut self.csh_sttlm, step, create, out) }
+		if step.matches("SvcLvlAgrmt") { optional_repeated_mut(&mut self.svc_lvl_agrmt, step, create, out) }
+		if step.matches("AddtlInf") { optional_repeated_mut(&mut self.addtl_inf, step, create, out) }
+		if step.matches("MktPrctcVrsn") { optional_mut(&mut self.mkt_prctc_vrsn, step, create, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_003_001_08::AccountModificationInstructionV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PrvsRef") { optional(&self.prvs_ref, step, out) }
+		if step.matches("InstrDtls") { optional(&self.instr_dtls, step, out) }
+		if step.matches("InvstmtAcctSelctn") { one(&self.invstmt_acct_selctn, step, out) }
+		if step.matches("ModfdInvstmtAcct") { optional(&self.modfd_invstmt_acct, step, out) }
+		if step.matches("ModfdAcctPties") { optional_repeated(&self.modfd_acct_pties, step, out) }
+		if step.matches("ModfdIntrmies") { optional_repeated(&self.modfd_intrmies, step, out) }
+		if step.matches("ModfdPlcmnt") { optional(&self.modfd_plcmnt, step, out) }
+		if step.matches("ModfdIsseAllcn") { optional(&self.modfd_isse_allcn, step, out) }
+		if step.matches("ModfdSvgsInvstmtPlan") { optional_repeated(&self.modfd_svgs_invstmt_plan, step, out) }
+		if step.matches("ModfdWdrwlInvstmtPlan") { optional_repeated(&self.modfd_wdrwl_invstmt_plan, step, out) }
+		if step.matches("ModfdCshSttlm") { optional_repeated(&self.modfd_csh_sttlm, step, out) }
+		if step.matches("ModfdSvcLvlAgrmt") { optional_repeated(&self.modfd_svc_lvl_agrmt, step, out) }
+		if step.matches("ModfdAddtlInf") { optional_repeated(&self.modfd_addtl_inf, step, out) }
+		if step.matches("MktPrctcVrsn") { optional(&self.mkt_prctc_vrsn, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PrvsRef") { optional_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("InstrDtls") { optional_mut(&mut self.instr_dtls, step, create, out) }
+		if step.matches("InvstmtAcctSelctn") { one_mut(&mut self.invstmt_acct_selctn, step, out) }
+		if step.matches("ModfdInvstmtAcct") { optional_mut(&mut self.modfd_invstmt_acct, step, create, out) }
+		if step.matches("ModfdAcctPties") { optional_repeated_mut(&mut self.modfd_acct_pties, step, create, out) }
+		if step.matches("ModfdIntrmies") { optional_repeated_mut(&mut self.modfd_intrmies, step, create, out) }
+		if step.matches("ModfdPlcmnt") { optional_mut(&mut self.modfd_plcmnt, step, create, out) }
+		if step.matches("ModfdIsseAllcn") { optional_mut(&mut self.modfd_isse_allcn, step, create, out) }
+		if step.matches("ModfdSvgsInvstmtPlan") { optional_repeated_mut(&mut self.modfd_svgs_invstmt_plan, step, create, out) }
+		if step.matches("ModfdWdrwlInvstmtPlan") { optional_repeated_mut(&mut self.modfd_wdrwl_invstmt_plan, step, create, out) }
+		if step.matches("ModfdCshSttlm") { optional_repeated_mut(&mut self.modfd_csh_sttlm, step, create, out) }
+		if step.matches("ModfdSvcLvlAgrmt") { optional_repeated_mut(&mut self.modfd_svc_lvl_agrmt, step, create, out) }
+		if step.matches("ModfdAddtlInf") { optional_repeated_mut(&mut self.modfd_addtl_inf, step, create, out) }
+		if step.matches("MktPrctcVrsn") { optional_mut(&mut self.mkt_prctc_vrsn, step, create, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_005_001_06::RequestForAccountManagementStatusReportV06 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("ReqDtls") { one(&self.req_dtls, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("ReqDtls") { one_mut(&mut self.req_dtls, step, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_006_001_07::AccountManagementStatusReportV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("RltdRef") { repeated(&self.rltd_ref, step, out) }
+		if step.matches("StsRpt") { one(&self.sts_rpt, step, out) }
+		if step.matches("MktPrctcVrsn") { optional(&self.mkt_prctc_vrsn, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("RltdRef") { repeated_mut(&mut self.rltd_ref, step, out) }
+		if step.matches("StsRpt") { one_mut(&mut self.sts_rpt, step, out) }
+		if step.matches("MktPrctcVrsn") { optional_mut(&mut self.mkt_prctc_vrsn, step, create, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_007_001_05::AccountOpeningRequestV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("Acct") { one(&self.acct, step, out) }
+		if step.matches("CtrctDts") { optional(&self.ctrct_dts, step, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional(&self.undrlyg_mstr_agrmt, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one(&self.org, step, out) }
+		if step.matches("Mndt") { optional_repeated(&self.mndt, step, out) }
+		if step.matches("Grp") { optional_repeated(&self.grp, step, out) }
+		if step.matches("RefAcct") { optional(&self.ref_acct, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("Acct") { one_mut(&mut self.acct, step, out) }
+		if step.matches("CtrctDts") { optional_mut(&mut self.ctrct_dts, step, create, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional_mut(&mut self.undrlyg_mstr_agrmt, step, create, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one_mut(&mut self.org, step, out) }
+		if step.matches("Mndt") { optional_repeated_mut(&mut self.mndt, step, create, out) }
+		if step.matches("Grp") { optional_repeated_mut(&mut self.grp, step, create, out) }
+		if step.matches("RefAcct") { optional_mut(&mut self.ref_acct, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_008_001_05::AccountOpeningAmendmentRequestV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("CtrctDts") { optional(&self.ctrct_dts, step, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional(&self.undrlyg_mstr_agrmt, step, out) }
+		if step.matches("Acct") { one(&self.acct, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one(&self.org, step, out) }
+		if step.matches("Mndt") { optional_repeated(&self.mndt, step, out) }
+		if step.matches("Grp") { optional_repeated(&self.grp, step, out) }
+		if step.matches("RefAcct") { optional(&self.ref_acct, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("CtrctDts") { optional_mut(&mut self.ctrct_dts, step, create, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional_mut(&mut self.undrlyg_mstr_agrmt, step, create, out) }
+		if step.matches("Acct") { one_mut(&mut self.acct, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one_mut(&mut self.org, step, out) }
+		if step.matches("Mndt") { optional_repeated_mut(&mut self.mndt, step, create, out) }
+		if step.matches("Grp") { optional_repeated_mut(&mut self.grp, step, create, out) }
+		if step.matches("RefAcct") { optional_mut(&mut self.ref_acct, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_009_001_04::AccountOpeningAdditionalInformationRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("Acct") { one(&self.acct, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional(&self.undrlyg_mstr_agrmt, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("Acct") { one_mut(&mut self.acct, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional_mut(&mut self.undrlyg_mstr_agrmt, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_010_001_04::AccountRequestAcknowledgementV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("AcctId") { optional_repeated(&self.acct_id, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("AcctId") { optional_repeated_mut(&mut self.acct_id, step, create, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_011_001_04::AccountRequestRejectionV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("AcctId") { optional_repeated(&self.acct_id, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("AcctId") { optional_repeated_mut(&mut self.acct_id, step, create, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_012_001_04::AccountAdditionalInformationRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("AcctId") { repeated(&self.acct_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("AcctId") { repeated_mut(&mut self.acct_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_013_001_04::AccountReportRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("AcctId") { repeated(&self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("AcctId") { repeated_mut(&mut self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_014_001_05::AccountReportV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one(&self.org, step, out) }
+		if step.matches("Rpt") { optional_repeated(&self.rpt, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one_mut(&mut self.org, step, out) }
+		if step.matches("Rpt") { optional_repeated_mut(&mut self.rpt, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_015_001_04::AccountExcludedMandateMaintenanceRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("CtrctDts") { optional(&self.ctrct_dts, step, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional(&self.undrlyg_mstr_agrmt, step, out) }
+		if step.matches("Acct") { one(&self.acct, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one(&self.org, step, out) }
+		if step.matches("AddtlMsgInf") { optional(&self.addtl_msg_inf, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("CtrctDts") { optional_mut(&mut self.ctrct_dts, step, create, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional_mut(&mut self.undrlyg_mstr_agrmt, step, create, out) }
+		if step.matches("Acct") { one_mut(&mut self.acct, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one_mut(&mut self.org, step, out) }
+		if step.matches("AddtlMsgInf") { optional_mut(&mut self.addtl_msg_inf, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_016_001_04::AccountExcludedMandateMaintenanceAmendmentRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("CtrctDts") { optional(&self.ctrct_dts, step, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional(&self.undrlyg_mstr_agrmt, step, out) }
+		if step.matches("Acct") { one(&self.acct, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one(&self.org, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("CtrctDts") { optional_mut(&mut self.ctrct_dts, step, create, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional_mut(&mut self.undrlyg_mstr_agrmt, step, create, out) }
+		if step.matches("Acct") { one_mut(&mut self.acct, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("Org") { one_mut(&mut self.org, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_017_001_04::AccountMandateMaintenanceRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("CtrctDts") { optional(&self.ctrct_dts, step, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional(&self.undrlyg_mstr_agrmt, step, out) }
+		if step.matches("AcctId") { repeated(&self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("Mndt") { repeated(&self.mndt, step, out) }
+		if step.matches("Grp") { optional_repeated(&self.grp, step, out) }
+		if step.matches("AddtlMsgInf") { optional(&self.addtl_msg_inf, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("CtrctDts") { optional_mut(&mut self.ctrct_dts, step, create, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional_mut(&mut self.undrlyg_mstr_agrmt, step, create, out) }
+		if step.matches("AcctId") { repeated_mut(&mut self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("Mndt") { repeated_mut(&mut self.mndt, step, out) }
+		if step.matches("Grp") { optional_repeated_mut(&mut self.grp, step, create, out) }
+		if step.matches("AddtlMsgInf") { optional_mut(&mut self.addtl_msg_inf, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_018_001_04::AccountMandateMaintenanceAmendmentRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("CtrctDts") { optional(&self.ctrct_dts, step, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional(&self.undrlyg_mstr_agrmt, step, out) }
+		if step.matches("AcctId") { repeated(&self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("Mndt") { optional_repeated(&self.mndt, step, out) }
+		if step.matches("Grp") { optional_repeated(&self.grp, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("CtrctDts") { optional_mut(&mut self.ctrct_dts, step, create, out) }
+		if step.matches("UndrlygMstrAgrmt") { optional_mut(&mut self.undrlyg_mstr_agrmt, step, create, out) }
+		if step.matches("AcctId") { repeated_mut(&mut self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("Mndt") { optional_repeated_mut(&mut self.mndt, step, create, out) }
+		if step.matches("Grp") { optional_repeated_mut(&mut self.grp, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_019_001_04::AccountClosingRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("AcctId") { one(&self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("CtrctDts") { optional(&self.ctrct_dts, step, out) }
+		if step.matches("BalTrfAcct") { optional(&self.bal_trf_acct, step, out) }
+		if step.matches("TrfAcctSvcrId") { optional(&self.trf_acct_svcr_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("AcctId") { one_mut(&mut self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("CtrctDts") { optional_mut(&mut self.ctrct_dts, step, create, out) }
+		if step.matches("BalTrfAcct") { optional_mut(&mut self.bal_trf_acct, step, create, out) }
+		if step.matches("TrfAcctSvcrId") { optional_mut(&mut self.trf_acct_svcr_id, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_020_001_04::AccountClosingAmendmentRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("AcctId") { one(&self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("CtrctDts") { optional(&self.ctrct_dts, step, out) }
+		if step.matches("BalTrfAcct") { optional(&self.bal_trf_acct, step, out) }
+		if step.matches("TrfAcctSvcrId") { optional(&self.trf_acct_svcr_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("AcctId") { one_mut(&mut self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("CtrctDts") { optional_mut(&mut self.ctrct_dts, step, create, out) }
+		if step.matches("BalTrfAcct") { optional_mut(&mut self.bal_trf_acct, step, create, out) }
+		if step.matches("TrfAcctSvcrId") { optional_mut(&mut self.trf_acct_svcr_id, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_021_001_04::AccountClosingAdditionalInformationRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Refs") { one(&self.refs, step, out) }
+		if step.matches("Fr") { optional(&self.fr, step, out) }
+		if step.matches("OrgId") { one(&self.org_id, step, out) }
+		if step.matches("AcctId") { one(&self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one(&self.acct_svcr_id, step, out) }
+		if step.matches("BalTrfAcct") { optional(&self.bal_trf_acct, step, out) }
+		if step.matches("TrfAcctSvcrId") { optional(&self.trf_acct_svcr_id, step, out) }
+		if step.matches("DgtlSgntr") { optional_repeated(&self.dgtl_sgntr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Refs") { one_mut(&mut self.refs, step, out) }
+		if step.matches("Fr") { optional_mut(&mut self.fr, step, create, out) }
+		if step.matches("OrgId") { one_mut(&mut self.org_id, step, out) }
+		if step.matches("AcctId") { one_mut(&mut self.acct_id, step, out) }
+		if step.matches("AcctSvcrId") { one_mut(&mut self.acct_svcr_id, step, out) }
+		if step.matches("BalTrfAcct") { optional_mut(&mut self.bal_trf_acct, step, create, out) }
+		if step.matches("TrfAcctSvcrId") { optional_mut(&mut self.trf_acct_svcr_id, step, create, out) }
+		if step.matches("DgtlSgntr") { optional_repeated_mut(&mut self.dgtl_sgntr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_022_001_04::IdentificationModificationAdviceV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("OrgnlTxRef") { optional(&self.orgnl_tx_ref, step, out) }
+		if step.matches("Mod") { repeated(&self.mod_attr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("OrgnlTxRef") { optional_mut(&mut self.orgnl_tx_ref, step, create, out) }
+		if step.matches("Mod") { repeated_mut(&mut self.mod_attr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_023_001_04::IdentificationVerificationRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Vrfctn") { repeated(&self.vrfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Vrfctn") { repeated_mut(&mut self.vrfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_024_001_04::IdentificationVerificationReportV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("OrgnlAssgnmt") { optional(&self.orgnl_assgnmt, step, out) }
+		if step.matches("Rpt") { repeated(&self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("OrgnlAssgnmt") { optional_mut(&mut self.orgnl_assgnmt, step, create, out) }
+		if step.matches("Rpt") { repeated_mut(&mut self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_027_001_05::AccountSwitchInformationRequestV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("NewAcct") { one(&self.new_acct, step, out) }
+		if step.matches("OdAcct") { one(&self.od_acct, step, out) }
+		if step.matches("BalTrf") { optional_repeated(&self.bal_trf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("NewAcct") { one_mut(&mut self.new_acct, step, out) }
+		if step.matches("OdAcct") { one_mut(&mut self.od_acct, step, out) }
+		if step.matches("BalTrf") { optional_repeated_mut(&mut self.bal_trf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_028_001_05::AccountSwitchInformationResponseV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("NewAcct") { one(&self.new_acct, step, out) }
+		if step.matches("OdAcct") { one(&self.od_acct, step, out) }
+		if step.matches("PmtInstr") { optional_repeated(&self.pmt_instr, step, out) }
+		if step.matches("DrctDbtInstr") { optional_repeated(&self.drct_dbt_instr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("NewAcct") { one_mut(&mut self.new_acct, step, out) }
+		if step.matches("OdAcct") { one_mut(&mut self.od_acct, step, out) }
+		if step.matches("PmtInstr") { optional_repeated_mut(&mut self.pmt_instr, step, create, out) }
+		if step.matches("DrctDbtInstr") { optional_repeated_mut(&mut self.drct_dbt_instr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_029_001_05::AccountSwitchCancelExistingPaymentV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("OdAcct") { one(&self.od_acct, step, out) }
+		if step.matches("PmtInstr") { optional_repeated(&self.pmt_instr, step, out) }
+		if step.matches("DrctDbtInstr") { optional_repeated(&self.drct_dbt_instr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("OdAcct") { one_mut(&mut self.od_acct, step, out) }
+		if step.matches("PmtInstr") { optional_repeated_mut(&mut self.pmt_instr, step, create, out) }
+		if step.matches("DrctDbtInstr") { optional_repeated_mut(&mut self.drct_dbt_instr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_030_001_04::AccountSwitchRequestRedirectionV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("NewAcct") { one(&self.new_acct, step, out) }
+		if step.matches("OdAcct") { one(&self.od_acct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("NewAcct") { one_mut(&mut self.new_acct, step, out) }
+		if step.matches("OdAcct") { one_mut(&mut self.od_acct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_031_001_05::AccountSwitchRequestBalanceTransferV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("NewAcct") { one(&self.new_acct, step, out) }
+		if step.matches("NmntdAcct") { optional(&self.nmntd_acct, step, out) }
+		if step.matches("BalTrf") { optional_repeated(&self.bal_trf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("NewAcct") { one_mut(&mut self.new_acct, step, out) }
+		if step.matches("NmntdAcct") { optional_mut(&mut self.nmntd_acct, step, create, out) }
+		if step.matches("BalTrf") { optional_repeated_mut(&mut self.bal_trf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_032_001_05::AccountSwitchBalanceTransferAcknowledgementV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("OdAcct") { one(&self.od_acct, step, out) }
+		if step.matches("OdAcctBal") { one(&self.od_acct_bal, step, out) }
+		if step.matches("BalTrf") { optional_repeated(&self.bal_trf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("OdAcct") { one_mut(&mut self.od_acct, step, out) }
+		if step.matches("OdAcctBal") { one_mut(&mut self.od_acct_bal, step, out) }
+		if step.matches("BalTrf") { optional_repeated_mut(&mut self.bal_trf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_033_001_02::AccountSwitchNotifyAccountSwitchCompleteV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_034_001_05::AccountSwitchRequestPaymentV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("OdAcct") { one(&self.od_acct, step, out) }
+		if step.matches("CdtInstr") { one(&self.cdt_instr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("OdAcct") { one_mut(&mut self.od_acct, step, out) }
+		if step.matches("CdtInstr") { one_mut(&mut self.cdt_instr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_035_001_02::AccountSwitchPaymentResponseV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_036_001_01::AccountSwitchTerminationSwitchV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::acmt_037_001_02::AccountSwitchTechnicalRejectionV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one(&self.acct_swtch_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("AcctSwtchDtls") { one_mut(&mut self.acct_swtch_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
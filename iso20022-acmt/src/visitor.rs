@@ -0,0 +1,993 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over the generated types of this crate, generated by
+// generate-visitor.py. `Visitor` has a `visit_` method per type that
+// walks into its fields by default; override the methods of the types of
+// interest and call the matching `walk_` function to keep going below
+// them. `VisitorMut` does the same with mutable references.
+
+use open_payments_common::visitor::{Visitor as BaseVisitor, VisitorMut as BaseVisitorMut};
+
+pub trait Visitor: BaseVisitor {
+	fn visit_account_opening_instruction_v08(&mut self, node: &crate::acmt_001_001_08::AccountOpeningInstructionV08) { walk_account_opening_instruction_v08(self, node) }
+	fn visit_account_details_confirmation_v08(&mut self, node: &crate::acmt_002_001_08::AccountDetailsConfirmationV08) { walk_account_details_confirmation_v08(self, node) }
+	fn visit_account_modification_instruction_v08(&mut self, node: &crate::acmt_003_001_08::AccountModificationInstructionV08) { walk_account_modification_instruction_v08(self, node) }
+	fn visit_request_for_account_management_status_report_v06(&mut self, node: &crate::acmt_005_001_06::RequestForAccountManagementStatusReportV06) { walk_request_for_account_management_status_report_v06(self, node) }
+	fn visit_account_management_status_report_v07(&mut self, node: &crate::acmt_006_001_07::AccountManagementStatusReportV07) { walk_account_management_status_report_v07(self, node) }
+	fn visit_account_opening_request_v05(&mut self, node: &crate::acmt_007_001_05::AccountOpeningRequestV05) { walk_account_opening_request_v05(self, node) }
+	fn visit_account_opening_amendment_request_v05(&mut self, node: &crate::acmt_008_001_05::AccountOpeningAmendmentRequestV05) { walk_account_opening_amendment_request_v05(self, node) }
+	fn visit_account_opening_additional_information_request_v04(&mut self, node: &crate::acmt_009_001_04::AccountOpeningAdditionalInformationRequestV04) { walk_account_opening_additional_information_request_v04(self, node) }
+	fn visit_account_request_acknowledgement_v04(&mut self, node: &crate::acmt_010_001_04::AccountRequestAcknowledgementV04) { walk_account_request_acknowledgement_v04(self, node) }
+	fn visit_account_request_rejection_v04(&mut self, node: &crate::acmt_011_001_04::AccountRequestRejectionV04) { walk_account_request_rejection_v04(self, node) }
+	fn visit_account_additional_information_request_v04(&mut self, node: &crate::acmt_012_001_04::AccountAdditionalInformationRequestV04) { walk_account_additional_information_request_v04(self, node) }
+	fn visit_account_report_request_v04(&mut self, node: &crate::acmt_013_001_04::AccountReportRequestV04) { walk_account_report_request_v04(self, node) }
+	fn visit_account_report_v05(&mut self, node: &crate::acmt_014_001_05::AccountReportV05) { walk_account_report_v05(self, node) }
+	fn visit_account_excluded_mandate_maintenance_request_v04(&mut self, node: &crate::acmt_015_001_04::AccountExcludedMandateMaintenanceRequestV04) { walk_account_excluded_mandate_maintenance_request_v04(self, node) }
+	fn visit_account_excluded_mandate_maintenance_amendment_request_v04(&mut self, node: &crate::acmt_016_001_04::AccountExcludedMandateMaintenanceAmendmentRequestV04) { walk_account_excluded_mandate_maintenance_amendment_request_v04(self, node) }
+	fn visit_account_mandate_maintenance_request_v04(&mut self, node: &crate::acmt_017_001_04::AccountMandateMaintenanceRequestV04) { walk_account_mandate_maintenance_request_v04(self, node) }
+	fn visit_account_mandate_maintenance_amendment_request_v04(&mut self, node: &crate::acmt_018_001_04::AccountMandateMaintenanceAmendmentRequestV04) { walk_account_mandate_maintenance_amendment_request_v04(self, node) }
+	fn visit_account_closing_request_v04(&mut self, node: &crate::acmt_019_001_04::AccountClosingRequestV04) { walk_account_closing_request_v04(self, node) }
+	fn visit_account_closing_amendment_request_v04(&mut self, node: &crate::acmt_020_001_04::AccountClosingAmendmentRequestV04) { walk_account_closing_amendment_request_v04(self, node) }
+	fn visit_account_closing_additional_information_request_v04(&mut self, node: &crate::acmt_021_001_04::AccountClosingAdditionalInformationRequestV04) { walk_account_closing_additional_information_request_v04(self, node) }
+	fn visit_identification_modification_advice_v04(&mut self, node: &crate::acmt_022_001_04::IdentificationModificationAdviceV04) { walk_identification_modification_advice_v04(self, node) }
+	fn visit_identification_verification_request_v04(&mut self, node: &crate::acmt_023_001_04::IdentificationVerificationRequestV04) { walk_identification_verification_request_v04(self, node) }
+	fn visit_identification_verification_report_v04(&mut self, node: &crate::acmt_024_001_04::IdentificationVerificationReportV04) { walk_identification_verification_report_v04(self, node) }
+	fn visit_account_switch_information_request_v05(&mut self, node: &crate::acmt_027_001_05::AccountSwitchInformationRequestV05) { walk_account_switch_information_request_v05(self, node) }
+	fn visit_account_switch_information_response_v05(&mut self, node: &crate::acmt_028_001_05::AccountSwitchInformationResponseV05) { walk_account_switch_information_response_v05(self, node) }
+	fn visit_account_switch_cancel_existing_payment_v05(&mut self, node: &crate::acmt_029_001_05::AccountSwitchCancelExistingPaymentV05) { walk_account_switch_cancel_existing_payment_v05(self, node) }
+	fn visit_account_switch_request_redirection_v04(&mut self, node: &crate::acmt_030_001_04::AccountSwitchRequestRedirectionV04) { walk_account_switch_request_redirection_v04(self, node) }
+	fn visit_account_switch_request_balance_transfer_v05(&mut self, node: &crate::acmt_031_001_05::AccountSwitchRequestBalanceTransferV05) { walk_account_switch_request_balance_transfer_v05(self, node) }
+	fn visit_account_switch_balance_transfer_acknowledgement_v05(&mut self, node: &crate::acmt_032_001_05::AccountSwitchBalanceTransferAcknowledgementV05) { walk_account_switch_balance_transfer_acknowledgement_v05(self, node) }
+	fn visit_account_switch_notify_account_switch_complete_v02(&mut self, node: &crate::acmt_033_001_02::AccountSwitchNotifyAccountSwitchCompleteV02) { walk_account_switch_notify_account_switch_complete_v02(self, node) }
+	fn visit_account_switch_request_payment_v05(&mut self, node: &crate::acmt_034_001_05::AccountSwitchRequestPaymentV05) { walk_account_switch_request_payment_v05(self, node) }
+	fn visit_account_switch_payment_response_v02(&mut self, node: &crate::acmt_035_001_02::AccountSwitchPaymentResponseV02) { walk_account_switch_payment_response_v02(self, node) }
+	fn visit_account_switch_termination_switch_v01(&mut self, node: &crate::acmt_036_001_01::AccountSwitchTerminationSwitchV01) { walk_account_switch_termination_switch_v01(self, node) }
+	fn visit_account_switch_technical_rejection_v02(&mut self, node: &crate::acmt_037_001_02::AccountSwitchTechnicalRejectionV02) { walk_account_switch_technical_rejection_v02(self, node) }
+}
+
+pub trait VisitorMut: BaseVisitorMut {
+	fn visit_account_opening_instruction_v08_mut(&mut self, node: &mut crate::acmt_001_001_08::AccountOpeningInstructionV08) { walk_account_opening_instruction_v08_mut(self, node) }
+	fn visit_account_details_confirmation_v08_mut(&mut self, node: &mut crate::acmt_002_001_08::AccountDetailsConfirmationV08) { walk_account_details_confirmation_v08_mut(self, node) }
+	fn visit_account_modification_instruction_v08_mut(&mut self, node: &mut crate::acmt_003_001_08::AccountModificationInstructionV08) { walk_account_modification_instruction_v08_mut(self, node) }
+	fn visit_request_for_account_management_status_report_v06_mut(&mut self, node: &mut crate::acmt_005_001_06::RequestForAccountManagementStatusReportV06) { walk_request_for_account_management_status_report_v06_mut(self, node) }
+	fn visit_account_management_status_report_v07_mut(&mut self, node: &mut crate::acmt_006_001_07::AccountManagementStatusReportV07) { walk_account_management_status_report_v07_mut(self, node) }
+	fn visit_account_opening_request_v05_mut(&mut self, node: &mut crate::acmt_007_001_05::AccountOpeningRequestV05) { walk_account_opening_request_v05_mut(self, node) }
+	fn visit_account_opening_amendment_request_v05_mut(&mut self, node: &mut crate::acmt_008_001_05::AccountOpeningAmendmentRequestV05) { walk_account_opening_amendment_request_v05_mut(self, node) }
+	fn visit_account_opening_additional_information_request_v04_mut(&mut self, node: &mut crate::acmt_009_001_04::AccountOpeningAdditionalInformationRequestV04) { walk_account_opening_additional_information_request_v04_mut(self, node) }
+	fn visit_account_request_acknowledgement_v04_mut(&mut self, node: &mut crate::acmt_010_001_04::AccountRequestAcknowledgementV04) { walk_account_request_acknowledgement_v04_mut(self, node) }
+	fn visit_account_request_rejection_v04_mut(&mut self, node: &mut crate::acmt_011_001_04::AccountRequestRejectionV04) { walk_account_request_rejection_v04_mut(self, node) }
+	fn visit_account_additional_information_request_v04_mut(&mut self, node: &mut crate::acmt_012_001_04::AccountAdditionalInformationRequestV04) { walk_account_additional_information_request_v04_mut(self, node) }
+	fn visit_account_report_request_v04_mut(&mut self, node: &mut crate::acmt_013_001_04::AccountReportRequestV04) { walk_account_report_request_v04_mut(self, node) }
+	fn visit_account_report_v05_mut(&mut self, node: &mut crate::acmt_014_001_05::AccountReportV05) { walk_account_report_v05_mut(self, node) }
+	fn visit_account_excluded_mandate_maintenance_request_v04_mut(&mut self, node: &mut crate::acmt_015_001_04::AccountExcludedMandateMaintenanceRequestV04) { walk_account_excluded_mandate_maintenance_request_v04_mut(self, node) }
+	fn visit_account_excluded_mandate_maintenance_amendment_request_v04_mut(&mut self, node: &mut crate::acmt_016_001_04::AccountExcludedMandateMaintenanceAmendmentRequestV04) { walk_account_excluded_mandate_maintenance_amendment_request_v04_mut(self, node) }
+	fn visit_account_mandate_maintenance_request_v04_mut(&mut self, node: &mut crate::acmt_017_001_04::AccountMandateMaintenanceRequestV04) { walk_account_mandate_maintenance_request_v04_mut(self, node) }
+	fn visit_account_mandate_maintenance_amendment_request_v04_mut(&mut self, node: &mut crate::acmt_018_001_04::AccountMandateMaintenanceAmendmentRequestV04) { walk_account_mandate_maintenance_amendment_request_v04_mut(self, node) }
+	fn visit_account_closing_request_v04_mut(&mut self, node: &mut crate::acmt_019_001_04::AccountClosingRequestV04) { walk_account_closing_request_v04_mut(self, node) }
+	fn visit_account_closing_amendment_request_v04_mut(&mut self, node: &mut crate::acmt_020_001_04::AccountClosingAmendmentRequestV04) { walk_account_closing_amendment_request_v04_mut(self, node) }
+	fn visit_account_closing_additional_information_request_v04_mut(&mut self, node: &mut crate::acmt_021_001_04::AccountClosingAdditionalInformationRequestV04) { walk_account_closing_additional_information_request_v04_mut(self, node) }
+	fn visit_identification_modification_advice_v04_mut(&mut self, node: &mut crate::acmt_022_001_04::IdentificationModificationAdviceV04) { walk_identification_modification_advice_v04_mut(self, node) }
+	fn visit_identification_verification_request_v04_mut(&mut self, node: &mut crate::acmt_023_001_04::IdentificationVerificationRequestV04) { walk_identification_verification_request_v04_mut(self, node) }
+	fn visit_identification_verification_report_v04_mut(&mut self, node: &mut crate::acmt_024_001_04::IdentificationVerificationReportV04) { walk_identification_verification_report_v04_mut(self, node) }
+	fn visit_account_switch_information_request_v05_mut(&mut self, node: &mut crate::acmt_027_001_05::AccountSwitchInformationRequestV05) { walk_account_switch_information_request_v05_mut(self, node) }
+	fn visit_account_switch_information_response_v05_mut(&mut self, node: &mut crate::acmt_028_001_05::AccountSwitchInformationResponseV05) { walk_account_switch_information_response_v05_mut(self, node) }
+	fn visit_account_switch_cancel_existing_payment_v05_mut(&mut self, node: &mut crate::acmt_029_001_05::AccountSwitchCancelExistingPaymentV05) { walk_account_switch_cancel_existing_payment_v05_mut(self, node) }
+	fn visit_account_switch_request_redirection_v04_mut(&mut self, node: &mut crate::acmt_030_001_04::AccountSwitchRequestRedirectionV04) { walk_account_switch_request_redirection_v04_mut(self, node) }
+	fn visit_account_switch_request_balance_transfer_v05_mut(&mut self, node: &mut crate::acmt_031_001_05::AccountSwitchRequestBalanceTransferV05) { walk_account_switch_request_balance_transfer_v05_mut(self, node) }
+	fn visit_account_switch_balance_transfer_acknowledgement_v05_mut(&mut self, node: &mut crate::acmt_032_001_05::AccountSwitchBalanceTransferAcknowledgementV05) { walk_account_switch_balance_transfer_acknowledgement_v05_mut(self, node) }
+	fn visit_account_switch_notify_account_switch_complete_v02_mut(&mut self, node: &mut crate::acmt_033_001_02::AccountSwitchNotifyAccountSwitchCompleteV02) { walk_account_switch_notify_account_switch_complete_v02_mut(self, node) }
+	fn visit_account_switch_request_payment_v05_mut(&mut self, node: &mut crate::acmt_034_001_05::AccountSwitchRequestPaymentV05) { walk_account_switch_request_payment_v05_mut(self, node) }
+	fn visit_account_switch_payment_response_v02_mut(&mut self, node: &mut crate::acmt_035_001_02::AccountSwitchPaymentResponseV02) { walk_account_switch_payment_response_v02_mut(self, node) }
+	fn visit_account_switch_termination_switch_v01_mut(&mut self, node: &mut crate::acmt_036_001_01::AccountSwitchTerminationSwitchV01) { walk_account_switch_termination_switch_v01_mut(self, node) }
+	fn visit_account_switch_technical_rejection_v02_mut(&mut self, node: &mut crate::acmt_037_001_02::AccountSwitchTechnicalRejectionV02) { walk_account_switch_technical_rejection_v02_mut(self, node) }
+}
+
+pub fn walk_account_opening_instruction_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_001_001_08::AccountOpeningInstructionV08) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.ordr_ref { visitor.visit_investment_fund_order4(value) }
+	if let Some(ref value) = node.prvs_ref { visitor.visit_additional_reference13(value) }
+	visitor.visit_investment_account_opening4(&node.instr_dtls);
+	visitor.visit_investment_account73(&node.invstmt_acct);
+	visitor.visit_account_parties17(&node.acct_pties);
+	if let Some(ref vec) = node.intrmies { for item in vec { visitor.visit_intermediary46(item) } }
+	if let Some(ref value) = node.plcmnt { visitor.visit_referred_agent3(value) }
+	if let Some(ref value) = node.new_isse_allcn { visitor.visit_new_issue_allocation2(value) }
+	if let Some(ref vec) = node.svgs_invstmt_plan { for item in vec { visitor.visit_investment_plan17(item) } }
+	if let Some(ref vec) = node.wdrwl_invstmt_plan { for item in vec { visitor.visit_investment_plan17(item) } }
+	if let Some(ref vec) = node.csh_sttlm { for item in vec { visitor.visit_cash_settlement3(item) } }
+	if let Some(ref vec) = node.svc_lvl_agrmt { for item in vec { visitor.visit_document_to_send4(item) } }
+	if let Some(ref vec) = node.addtl_inf { for item in vec { visitor.visit_additiononal_information13(item) } }
+	if let Some(ref value) = node.mkt_prctc_vrsn { visitor.visit_market_practice_version1(value) }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_account_opening_instruction_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_001_001_08::AccountOpeningInstructionV08) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.ordr_ref { visitor.visit_investment_fund_order4_mut(value) }
+	if let Some(ref mut value) = node.prvs_ref { visitor.visit_additional_reference13_mut(value) }
+	visitor.visit_investment_account_opening4_mut(&mut node.instr_dtls);
+	visitor.visit_investment_account73_mut(&mut node.invstmt_acct);
+	visitor.visit_account_parties17_mut(&mut node.acct_pties);
+	if let Some(ref mut vec) = node.intrmies { for item in vec { visitor.visit_intermediary46_mut(item) } }
+	if let Some(ref mut value) = node.plcmnt { visitor.visit_referred_agent3_mut(value) }
+	if let Some(ref mut value) = node.new_isse_allcn { visitor.visit_new_issue_allocation2_mut(value) }
+	if let Some(ref mut vec) = node.svgs_invstmt_plan { for item in vec { visitor.visit_investment_plan17_mut(item) } }
+	if let Some(ref mut vec) = node.wdrwl_invstmt_plan { for item in vec { visitor.visit_investment_plan17_mut(item) } }
+	if let Some(ref mut vec) = node.csh_sttlm { for item in vec { visitor.visit_cash_settlement3_mut(item) } }
+	if let Some(ref mut vec) = node.svc_lvl_agrmt { for item in vec { visitor.visit_document_to_send4_mut(item) } }
+	if let Some(ref mut vec) = node.addtl_inf { for item in vec { visitor.visit_additiononal_information13_mut(item) } }
+	if let Some(ref mut value) = node.mkt_prctc_vrsn { visitor.visit_market_practice_version1_mut(value) }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_account_details_confirmation_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_002_001_08::AccountDetailsConfirmationV08) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.ordr_ref { visitor.visit_investment_fund_order4(value) }
+	if let Some(ref value) = node.rltd_ref { visitor.visit_additional_reference13(value) }
+	visitor.visit_account_management_confirmation5(&node.conf_dtls);
+	if let Some(ref value) = node.invstmt_acct { visitor.visit_investment_account74(value) }
+	if let Some(ref value) = node.acct_pties { visitor.visit_account_parties17(value) }
+	if let Some(ref vec) = node.intrmies { for item in vec { visitor.visit_intermediary46(item) } }
+	if let Some(ref value) = node.plcmnt { visitor.visit_referred_agent3(value) }
+	if let Some(ref value) = node.new_isse_allcn { visitor.visit_new_issue_allocation2(value) }
+	if let Some(ref vec) = node.svgs_invstmt_plan { for item in vec { visitor.visit_investment_plan17(item) } }
+	if let Some(ref vec) = node.wdrwl_invstmt_plan { for item in vec { visitor.visit_investment_plan17(item) } }
+	if let Some(ref vec) = node.csh_sttlm { for item in vec { visitor.visit_cash_settlement3(item) } }
+	if let Some(ref vec) = node.svc_lvl_agrmt { for item in vec { visitor.visit_document_to_send4(item) } }
+	if let Some(ref vec) = node.addtl_inf { for item in vec { visitor.visit_additiononal_information13(item) } }
+	if let Some(ref value) = node.mkt_prctc_vrsn { visitor.visit_market_practice_version1(value) }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_account_details_confirmation_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_002_001_08::AccountDetailsConfirmationV08) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.ordr_ref { visitor.visit_investment_fund_order4_mut(value) }
+	if let Some(ref mut value) = node.rltd_ref { visitor.visit_additional_reference13_mut(value) }
+	visitor.visit_account_management_confirmation5_mut(&mut node.conf_dtls);
+	if let Some(ref mut value) = node.invstmt_acct { visitor.visit_investment_account74_mut(value) }
+	if let Some(ref mut value) = node.acct_pties { visitor.visit_account_parties17_mut(value) }
+	if let Some(ref mut vec) = node.intrmies { for item in vec { visitor.visit_intermediary46_mut(item) } }
+	if let Some(ref mut value) = node.plcmnt { visitor.visit_referred_agent3_mut(value) }
+	if let Some(ref mut value) = node.new_isse_allcn { visitor.visit_new_issue_allocation2_mut(value) }
+	if let Some(ref mut vec) = node.svgs_invstmt_plan { for item in vec { visitor.visit_investment_plan17_mut(item) } }
+	if let Some(ref mut vec) = node.wdrwl_invstmt_plan { for item in vec { visitor.visit_investment_plan17_mut(item) } }
+	if let Some(ref mut vec) = node.csh_sttlm { for item in vec { visitor.visit_cash_settlement3_mut(item) } }
+	if let Some(ref mut vec) = node.svc_lvl_agrmt { for item in vec { visitor.visit_document_to_send4_mut(item) } }
+	if let Some(ref mut vec) = node.addtl_inf { for item in vec { visitor.visit_additiononal_information13_mut(item) } }
+	if let Some(ref mut value) = node.mkt_prctc_vrsn { visitor.visit_market_practice_version1_mut(value) }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_account_modification_instruction_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_003_001_08::AccountModificationInstructionV08) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.prvs_ref { visitor.visit_additional_reference13(value) }
+	if let Some(ref value) = node.instr_dtls { visitor.visit_investment_account_modification4(value) }
+	visitor.visit_account_selection3_choice(&node.invstmt_acct_selctn);
+	if let Some(ref value) = node.modfd_invstmt_acct { visitor.visit_investment_account75(value) }
+	if let Some(ref vec) = node.modfd_acct_pties { for item in vec { visitor.visit_account_parties18(item) } }
+	if let Some(ref vec) = node.modfd_intrmies { for item in vec { visitor.visit_modification_scope40(item) } }
+	if let Some(ref value) = node.modfd_plcmnt { visitor.visit_modification_scope43(value) }
+	if let Some(ref value) = node.modfd_isse_allcn { visitor.visit_modification_scope21(value) }
+	if let Some(ref vec) = node.modfd_svgs_invstmt_plan { for item in vec { visitor.visit_modification_scope41(item) } }
+	if let Some(ref vec) = node.modfd_wdrwl_invstmt_plan { for item in vec { visitor.visit_modification_scope41(item) } }
+	if let Some(ref vec) = node.modfd_csh_sttlm { for item in vec { visitor.visit_cash_settlement4(item) } }
+	if let Some(ref vec) = node.modfd_svc_lvl_agrmt { for item in vec { visitor.visit_modification_scope44(item) } }
+	if let Some(ref vec) = node.modfd_addtl_inf { for item in vec { visitor.visit_modification_scope45(item) } }
+	if let Some(ref value) = node.mkt_prctc_vrsn { visitor.visit_market_practice_version1(value) }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_account_modification_instruction_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_003_001_08::AccountModificationInstructionV08) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.prvs_ref { visitor.visit_additional_reference13_mut(value) }
+	if let Some(ref mut value) = node.instr_dtls { visitor.visit_investment_account_modification4_mut(value) }
+	visitor.visit_account_selection3_choice_mut(&mut node.invstmt_acct_selctn);
+	if let Some(ref mut value) = node.modfd_invstmt_acct { visitor.visit_investment_account75_mut(value) }
+	if let Some(ref mut vec) = node.modfd_acct_pties { for item in vec { visitor.visit_account_parties18_mut(item) } }
+	if let Some(ref mut vec) = node.modfd_intrmies { for item in vec { visitor.visit_modification_scope40_mut(item) } }
+	if let Some(ref mut value) = node.modfd_plcmnt { visitor.visit_modification_scope43_mut(value) }
+	if let Some(ref mut value) = node.modfd_isse_allcn { visitor.visit_modification_scope21_mut(value) }
+	if let Some(ref mut vec) = node.modfd_svgs_invstmt_plan { for item in vec { visitor.visit_modification_scope41_mut(item) } }
+	if let Some(ref mut vec) = node.modfd_wdrwl_invstmt_plan { for item in vec { visitor.visit_modification_scope41_mut(item) } }
+	if let Some(ref mut vec) = node.modfd_csh_sttlm { for item in vec { visitor.visit_cash_settlement4_mut(item) } }
+	if let Some(ref mut vec) = node.modfd_svc_lvl_agrmt { for item in vec { visitor.visit_modification_scope44_mut(item) } }
+	if let Some(ref mut vec) = node.modfd_addtl_inf { for item in vec { visitor.visit_modification_scope45_mut(item) } }
+	if let Some(ref mut value) = node.mkt_prctc_vrsn { visitor.visit_market_practice_version1_mut(value) }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_request_for_account_management_status_report_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_005_001_06::RequestForAccountManagementStatusReportV06) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_management_message_reference5(&node.req_dtls);
+}
+
+pub fn walk_request_for_account_management_status_report_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_005_001_06::RequestForAccountManagementStatusReportV06) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_management_message_reference5_mut(&mut node.req_dtls);
+}
+
+pub fn walk_account_management_status_report_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_006_001_07::AccountManagementStatusReportV07) {
+	visitor.visit_message_identification1(&node.msg_id);
+	for item in &node.rltd_ref { visitor.visit_additional_reference13(item) }
+	visitor.visit_account_management_status_and_reason5(&node.sts_rpt);
+	if let Some(ref value) = node.mkt_prctc_vrsn { visitor.visit_market_practice_version1(value) }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_account_management_status_report_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_006_001_07::AccountManagementStatusReportV07) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	for item in &mut node.rltd_ref { visitor.visit_additional_reference13_mut(item) }
+	visitor.visit_account_management_status_and_reason5_mut(&mut node.sts_rpt);
+	if let Some(ref mut value) = node.mkt_prctc_vrsn { visitor.visit_market_practice_version1_mut(value) }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_account_opening_request_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_007_001_05::AccountOpeningRequestV05) {
+	visitor.visit_references4(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	visitor.visit_customer_account4(&node.acct);
+	if let Some(ref value) = node.ctrct_dts { visitor.visit_account_contract2(value) }
+	if let Some(ref value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1(value) }
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation42(&node.org);
+	if let Some(ref vec) = node.mndt { for item in vec { visitor.visit_operation_mandate7(item) } }
+	if let Some(ref vec) = node.grp { for item in vec { visitor.visit_group6(item) } }
+	if let Some(ref value) = node.ref_acct { visitor.visit_cash_account40(value) }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_opening_request_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_007_001_05::AccountOpeningRequestV05) {
+	visitor.visit_references4_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	visitor.visit_customer_account4_mut(&mut node.acct);
+	if let Some(ref mut value) = node.ctrct_dts { visitor.visit_account_contract2_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation42_mut(&mut node.org);
+	if let Some(ref mut vec) = node.mndt { for item in vec { visitor.visit_operation_mandate7_mut(item) } }
+	if let Some(ref mut vec) = node.grp { for item in vec { visitor.visit_group6_mut(item) } }
+	if let Some(ref mut value) = node.ref_acct { visitor.visit_cash_account40_mut(value) }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_opening_amendment_request_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_008_001_05::AccountOpeningAmendmentRequestV05) {
+	visitor.visit_references4(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	if let Some(ref value) = node.ctrct_dts { visitor.visit_account_contract2(value) }
+	if let Some(ref value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1(value) }
+	visitor.visit_customer_account4(&node.acct);
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation42(&node.org);
+	if let Some(ref vec) = node.mndt { for item in vec { visitor.visit_operation_mandate7(item) } }
+	if let Some(ref vec) = node.grp { for item in vec { visitor.visit_group6(item) } }
+	if let Some(ref value) = node.ref_acct { visitor.visit_cash_account40(value) }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_opening_amendment_request_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_008_001_05::AccountOpeningAmendmentRequestV05) {
+	visitor.visit_references4_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	if let Some(ref mut value) = node.ctrct_dts { visitor.visit_account_contract2_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1_mut(value) }
+	visitor.visit_customer_account4_mut(&mut node.acct);
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation42_mut(&mut node.org);
+	if let Some(ref mut vec) = node.mndt { for item in vec { visitor.visit_operation_mandate7_mut(item) } }
+	if let Some(ref mut vec) = node.grp { for item in vec { visitor.visit_group6_mut(item) } }
+	if let Some(ref mut value) = node.ref_acct { visitor.visit_cash_account40_mut(value) }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_opening_additional_information_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_009_001_04::AccountOpeningAdditionalInformationRequestV04) {
+	visitor.visit_references3(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	visitor.visit_organisation_identification39(&node.org_id);
+	visitor.visit_customer_account4(&node.acct);
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	if let Some(ref value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1(value) }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_opening_additional_information_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_009_001_04::AccountOpeningAdditionalInformationRequestV04) {
+	visitor.visit_references3_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	visitor.visit_organisation_identification39_mut(&mut node.org_id);
+	visitor.visit_customer_account4_mut(&mut node.acct);
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	if let Some(ref mut value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1_mut(value) }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_request_acknowledgement_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_010_001_04::AccountRequestAcknowledgementV04) {
+	visitor.visit_references5(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	if let Some(ref vec) = node.acct_id { for item in vec { visitor.visit_account_for_action1(item) } }
+	visitor.visit_organisation_identification39(&node.org_id);
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_request_acknowledgement_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_010_001_04::AccountRequestAcknowledgementV04) {
+	visitor.visit_references5_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	if let Some(ref mut vec) = node.acct_id { for item in vec { visitor.visit_account_for_action1_mut(item) } }
+	visitor.visit_organisation_identification39_mut(&mut node.org_id);
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_request_rejection_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_011_001_04::AccountRequestRejectionV04) {
+	visitor.visit_references6(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	if let Some(ref vec) = node.acct_id { for item in vec { visitor.visit_account_for_action1(item) } }
+	visitor.visit_organisation_identification39(&node.org_id);
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_request_rejection_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_011_001_04::AccountRequestRejectionV04) {
+	visitor.visit_references6_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	if let Some(ref mut vec) = node.acct_id { for item in vec { visitor.visit_account_for_action1_mut(item) } }
+	visitor.visit_organisation_identification39_mut(&mut node.org_id);
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_additional_information_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_012_001_04::AccountAdditionalInformationRequestV04) {
+	visitor.visit_references3(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	visitor.visit_organisation_identification39(&node.org_id);
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	for item in &node.acct_id { visitor.visit_account_for_action1(item) }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_additional_information_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_012_001_04::AccountAdditionalInformationRequestV04) {
+	visitor.visit_references3_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	visitor.visit_organisation_identification39_mut(&mut node.org_id);
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	for item in &mut node.acct_id { visitor.visit_account_for_action1_mut(item) }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_report_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_013_001_04::AccountReportRequestV04) {
+	visitor.visit_references4(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	for item in &node.acct_id { visitor.visit_account_for_action1(item) }
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation_identification39(&node.org_id);
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_report_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_013_001_04::AccountReportRequestV04) {
+	visitor.visit_references4_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	for item in &mut node.acct_id { visitor.visit_account_for_action1_mut(item) }
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation_identification39_mut(&mut node.org_id);
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_report_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_014_001_05::AccountReportV05) {
+	visitor.visit_references5(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation42(&node.org);
+	if let Some(ref vec) = node.rpt { for item in vec { visitor.visit_account_report36(item) } }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_report_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_014_001_05::AccountReportV05) {
+	visitor.visit_references5_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation42_mut(&mut node.org);
+	if let Some(ref mut vec) = node.rpt { for item in vec { visitor.visit_account_report36_mut(item) } }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_excluded_mandate_maintenance_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_015_001_04::AccountExcludedMandateMaintenanceRequestV04) {
+	visitor.visit_references4(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	if let Some(ref value) = node.ctrct_dts { visitor.visit_account_contract2(value) }
+	if let Some(ref value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1(value) }
+	visitor.visit_customer_account_modification1(&node.acct);
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation_modification3(&node.org);
+	if let Some(ref value) = node.addtl_msg_inf { visitor.visit_additional_information5(value) }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_excluded_mandate_maintenance_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_015_001_04::AccountExcludedMandateMaintenanceRequestV04) {
+	visitor.visit_references4_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	if let Some(ref mut value) = node.ctrct_dts { visitor.visit_account_contract2_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1_mut(value) }
+	visitor.visit_customer_account_modification1_mut(&mut node.acct);
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation_modification3_mut(&mut node.org);
+	if let Some(ref mut value) = node.addtl_msg_inf { visitor.visit_additional_information5_mut(value) }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_excluded_mandate_maintenance_amendment_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_016_001_04::AccountExcludedMandateMaintenanceAmendmentRequestV04) {
+	visitor.visit_references4(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	if let Some(ref value) = node.ctrct_dts { visitor.visit_account_contract2(value) }
+	if let Some(ref value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1(value) }
+	visitor.visit_customer_account_modification1(&node.acct);
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation_modification3(&node.org);
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_excluded_mandate_maintenance_amendment_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_016_001_04::AccountExcludedMandateMaintenanceAmendmentRequestV04) {
+	visitor.visit_references4_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	if let Some(ref mut value) = node.ctrct_dts { visitor.visit_account_contract2_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1_mut(value) }
+	visitor.visit_customer_account_modification1_mut(&mut node.acct);
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation_modification3_mut(&mut node.org);
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_mandate_maintenance_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_017_001_04::AccountMandateMaintenanceRequestV04) {
+	visitor.visit_references4(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	if let Some(ref value) = node.ctrct_dts { visitor.visit_account_contract2(value) }
+	if let Some(ref value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1(value) }
+	for item in &node.acct_id { visitor.visit_account_for_action1(item) }
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation44(&node.org_id);
+	for item in &node.mndt { visitor.visit_operation_mandate6(item) }
+	if let Some(ref vec) = node.grp { for item in vec { visitor.visit_group5(item) } }
+	if let Some(ref value) = node.addtl_msg_inf { visitor.visit_additional_information5(value) }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_mandate_maintenance_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_017_001_04::AccountMandateMaintenanceRequestV04) {
+	visitor.visit_references4_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	if let Some(ref mut value) = node.ctrct_dts { visitor.visit_account_contract2_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1_mut(value) }
+	for item in &mut node.acct_id { visitor.visit_account_for_action1_mut(item) }
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation44_mut(&mut node.org_id);
+	for item in &mut node.mndt { visitor.visit_operation_mandate6_mut(item) }
+	if let Some(ref mut vec) = node.grp { for item in vec { visitor.visit_group5_mut(item) } }
+	if let Some(ref mut value) = node.addtl_msg_inf { visitor.visit_additional_information5_mut(value) }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_mandate_maintenance_amendment_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_018_001_04::AccountMandateMaintenanceAmendmentRequestV04) {
+	visitor.visit_references4(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	if let Some(ref value) = node.ctrct_dts { visitor.visit_account_contract2(value) }
+	if let Some(ref value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1(value) }
+	for item in &node.acct_id { visitor.visit_account_for_action1(item) }
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation_identification39(&node.org_id);
+	if let Some(ref vec) = node.mndt { for item in vec { visitor.visit_operation_mandate6(item) } }
+	if let Some(ref vec) = node.grp { for item in vec { visitor.visit_group5(item) } }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_mandate_maintenance_amendment_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_018_001_04::AccountMandateMaintenanceAmendmentRequestV04) {
+	visitor.visit_references4_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	if let Some(ref mut value) = node.ctrct_dts { visitor.visit_account_contract2_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_mstr_agrmt { visitor.visit_contract_document1_mut(value) }
+	for item in &mut node.acct_id { visitor.visit_account_for_action1_mut(item) }
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation_identification39_mut(&mut node.org_id);
+	if let Some(ref mut vec) = node.mndt { for item in vec { visitor.visit_operation_mandate6_mut(item) } }
+	if let Some(ref mut vec) = node.grp { for item in vec { visitor.visit_group5_mut(item) } }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_closing_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_019_001_04::AccountClosingRequestV04) {
+	visitor.visit_references4(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	visitor.visit_account_for_action2(&node.acct_id);
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation44(&node.org_id);
+	if let Some(ref value) = node.ctrct_dts { visitor.visit_account_contract4(value) }
+	if let Some(ref value) = node.bal_trf_acct { visitor.visit_account_for_action1(value) }
+	if let Some(ref value) = node.trf_acct_svcr_id { visitor.visit_branch_and_financial_institution_identification8(value) }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_closing_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_019_001_04::AccountClosingRequestV04) {
+	visitor.visit_references4_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	visitor.visit_account_for_action2_mut(&mut node.acct_id);
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation44_mut(&mut node.org_id);
+	if let Some(ref mut value) = node.ctrct_dts { visitor.visit_account_contract4_mut(value) }
+	if let Some(ref mut value) = node.bal_trf_acct { visitor.visit_account_for_action1_mut(value) }
+	if let Some(ref mut value) = node.trf_acct_svcr_id { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_closing_amendment_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_020_001_04::AccountClosingAmendmentRequestV04) {
+	visitor.visit_references4(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	visitor.visit_account_for_action1(&node.acct_id);
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	visitor.visit_organisation_identification39(&node.org_id);
+	if let Some(ref value) = node.ctrct_dts { visitor.visit_account_contract4(value) }
+	if let Some(ref value) = node.bal_trf_acct { visitor.visit_account_for_action1(value) }
+	if let Some(ref value) = node.trf_acct_svcr_id { visitor.visit_branch_and_financial_institution_identification8(value) }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_closing_amendment_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_020_001_04::AccountClosingAmendmentRequestV04) {
+	visitor.visit_references4_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	visitor.visit_account_for_action1_mut(&mut node.acct_id);
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	visitor.visit_organisation_identification39_mut(&mut node.org_id);
+	if let Some(ref mut value) = node.ctrct_dts { visitor.visit_account_contract4_mut(value) }
+	if let Some(ref mut value) = node.bal_trf_acct { visitor.visit_account_for_action1_mut(value) }
+	if let Some(ref mut value) = node.trf_acct_svcr_id { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_closing_additional_information_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_021_001_04::AccountClosingAdditionalInformationRequestV04) {
+	visitor.visit_references3(&node.refs);
+	if let Some(ref value) = node.fr { visitor.visit_organisation_identification39(value) }
+	visitor.visit_organisation_identification39(&node.org_id);
+	visitor.visit_account_for_action1(&node.acct_id);
+	visitor.visit_branch_and_financial_institution_identification8(&node.acct_svcr_id);
+	if let Some(ref value) = node.bal_trf_acct { visitor.visit_account_for_action1(value) }
+	if let Some(ref value) = node.trf_acct_svcr_id { visitor.visit_branch_and_financial_institution_identification8(value) }
+	if let Some(ref vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_closing_additional_information_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_021_001_04::AccountClosingAdditionalInformationRequestV04) {
+	visitor.visit_references3_mut(&mut node.refs);
+	if let Some(ref mut value) = node.fr { visitor.visit_organisation_identification39_mut(value) }
+	visitor.visit_organisation_identification39_mut(&mut node.org_id);
+	visitor.visit_account_for_action1_mut(&mut node.acct_id);
+	visitor.visit_branch_and_financial_institution_identification8_mut(&mut node.acct_svcr_id);
+	if let Some(ref mut value) = node.bal_trf_acct { visitor.visit_account_for_action1_mut(value) }
+	if let Some(ref mut value) = node.trf_acct_svcr_id { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	if let Some(ref mut vec) = node.dgtl_sgntr { for item in vec { visitor.visit_party_and_signature4_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_identification_modification_advice_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_022_001_04::IdentificationModificationAdviceV04) {
+	visitor.visit_identification_assignment4(&node.assgnmt);
+	if let Some(ref value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference43(value) }
+	for item in &node.mod_attr { visitor.visit_identification_modification5(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_identification_modification_advice_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_022_001_04::IdentificationModificationAdviceV04) {
+	visitor.visit_identification_assignment4_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.orgnl_tx_ref { visitor.visit_original_transaction_reference43_mut(value) }
+	for item in &mut node.mod_attr { visitor.visit_identification_modification5_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_identification_verification_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_023_001_04::IdentificationVerificationRequestV04) {
+	visitor.visit_identification_assignment4(&node.assgnmt);
+	for item in &node.vrfctn { visitor.visit_identification_verification5(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_identification_verification_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_023_001_04::IdentificationVerificationRequestV04) {
+	visitor.visit_identification_assignment4_mut(&mut node.assgnmt);
+	for item in &mut node.vrfctn { visitor.visit_identification_verification5_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_identification_verification_report_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_024_001_04::IdentificationVerificationReportV04) {
+	visitor.visit_identification_assignment4(&node.assgnmt);
+	if let Some(ref value) = node.orgnl_assgnmt { visitor.visit_message_identification8(value) }
+	for item in &node.rpt { visitor.visit_verification_report5(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_identification_verification_report_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_024_001_04::IdentificationVerificationReportV04) {
+	visitor.visit_identification_assignment4_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.orgnl_assgnmt { visitor.visit_message_identification8_mut(value) }
+	for item in &mut node.rpt { visitor.visit_verification_report5_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_information_request_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_027_001_05::AccountSwitchInformationRequestV05) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	visitor.visit_new_account4(&node.new_acct);
+	visitor.visit_cash_account43(&node.od_acct);
+	if let Some(ref vec) = node.bal_trf { for item in vec { visitor.visit_balance_transfer5(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_information_request_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_027_001_05::AccountSwitchInformationRequestV05) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	visitor.visit_new_account4_mut(&mut node.new_acct);
+	visitor.visit_cash_account43_mut(&mut node.od_acct);
+	if let Some(ref mut vec) = node.bal_trf { for item in vec { visitor.visit_balance_transfer5_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_information_response_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_028_001_05::AccountSwitchInformationResponseV05) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	visitor.visit_cash_account43(&node.new_acct);
+	visitor.visit_cash_account43(&node.od_acct);
+	if let Some(ref vec) = node.pmt_instr { for item in vec { visitor.visit_payment_instruction43(item) } }
+	if let Some(ref vec) = node.drct_dbt_instr { for item in vec { visitor.visit_direct_debit_instruction_details3(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_information_response_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_028_001_05::AccountSwitchInformationResponseV05) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	visitor.visit_cash_account43_mut(&mut node.new_acct);
+	visitor.visit_cash_account43_mut(&mut node.od_acct);
+	if let Some(ref mut vec) = node.pmt_instr { for item in vec { visitor.visit_payment_instruction43_mut(item) } }
+	if let Some(ref mut vec) = node.drct_dbt_instr { for item in vec { visitor.visit_direct_debit_instruction_details3_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_cancel_existing_payment_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_029_001_05::AccountSwitchCancelExistingPaymentV05) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	visitor.visit_cash_account43(&node.od_acct);
+	if let Some(ref vec) = node.pmt_instr { for item in vec { visitor.visit_payment_instruction43(item) } }
+	if let Some(ref vec) = node.drct_dbt_instr { for item in vec { visitor.visit_direct_debit_instruction_details3(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_cancel_existing_payment_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_029_001_05::AccountSwitchCancelExistingPaymentV05) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	visitor.visit_cash_account43_mut(&mut node.od_acct);
+	if let Some(ref mut vec) = node.pmt_instr { for item in vec { visitor.visit_payment_instruction43_mut(item) } }
+	if let Some(ref mut vec) = node.drct_dbt_instr { for item in vec { visitor.visit_direct_debit_instruction_details3_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_request_redirection_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_030_001_04::AccountSwitchRequestRedirectionV04) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	visitor.visit_cash_account43(&node.new_acct);
+	visitor.visit_cash_account43(&node.od_acct);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_request_redirection_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_030_001_04::AccountSwitchRequestRedirectionV04) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	visitor.visit_cash_account43_mut(&mut node.new_acct);
+	visitor.visit_cash_account43_mut(&mut node.od_acct);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_request_balance_transfer_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_031_001_05::AccountSwitchRequestBalanceTransferV05) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	visitor.visit_cash_account43(&node.new_acct);
+	if let Some(ref value) = node.nmntd_acct { visitor.visit_cash_account43(value) }
+	if let Some(ref vec) = node.bal_trf { for item in vec { visitor.visit_balance_transfer5(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_request_balance_transfer_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_031_001_05::AccountSwitchRequestBalanceTransferV05) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	visitor.visit_cash_account43_mut(&mut node.new_acct);
+	if let Some(ref mut value) = node.nmntd_acct { visitor.visit_cash_account43_mut(value) }
+	if let Some(ref mut vec) = node.bal_trf { for item in vec { visitor.visit_balance_transfer5_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_balance_transfer_acknowledgement_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_032_001_05::AccountSwitchBalanceTransferAcknowledgementV05) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	visitor.visit_cash_account43(&node.od_acct);
+	visitor.visit_amount_and_direction5(&node.od_acct_bal);
+	if let Some(ref vec) = node.bal_trf { for item in vec { visitor.visit_balance_transfer5(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_balance_transfer_acknowledgement_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_032_001_05::AccountSwitchBalanceTransferAcknowledgementV05) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	visitor.visit_cash_account43_mut(&mut node.od_acct);
+	visitor.visit_amount_and_direction5_mut(&mut node.od_acct_bal);
+	if let Some(ref mut vec) = node.bal_trf { for item in vec { visitor.visit_balance_transfer5_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_notify_account_switch_complete_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_033_001_02::AccountSwitchNotifyAccountSwitchCompleteV02) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_notify_account_switch_complete_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_033_001_02::AccountSwitchNotifyAccountSwitchCompleteV02) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_request_payment_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_034_001_05::AccountSwitchRequestPaymentV05) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	visitor.visit_cash_account43(&node.od_acct);
+	visitor.visit_credit_transfer_transaction59(&node.cdt_instr);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_request_payment_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_034_001_05::AccountSwitchRequestPaymentV05) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	visitor.visit_cash_account43_mut(&mut node.od_acct);
+	visitor.visit_credit_transfer_transaction59_mut(&mut node.cdt_instr);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_payment_response_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_035_001_02::AccountSwitchPaymentResponseV02) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_payment_response_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_035_001_02::AccountSwitchPaymentResponseV02) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_termination_switch_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_036_001_01::AccountSwitchTerminationSwitchV01) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_termination_switch_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_036_001_01::AccountSwitchTerminationSwitchV01) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_switch_technical_rejection_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::acmt_037_001_02::AccountSwitchTechnicalRejectionV02) {
+	visitor.visit_message_identification1(&node.msg_id);
+	visitor.visit_account_switch_details1(&node.acct_swtch_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_switch_technical_rejection_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::acmt_037_001_02::AccountSwitchTechnicalRejectionV02) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	visitor.visit_account_switch_details1_mut(&mut node.acct_swtch_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+impl crate::acmt_001_001_08::AccountOpeningInstructionV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_opening_instruction_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_opening_instruction_v08_mut(self) }
+}
+
+impl crate::acmt_002_001_08::AccountDetailsConfirmationV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_details_confirmation_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_details_confirmation_v08_mut(self) }
+}
+
+impl crate::acmt_003_001_08::AccountModificationInstructionV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_modification_instruction_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_modification_instruction_v08_mut(self) }
+}
+
+impl crate::acmt_005_001_06::RequestForAccountManagementStatusReportV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_for_account_management_status_report_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_for_account_management_status_report_v06_mut(self) }
+}
+
+impl crate::acmt_006_001_07::AccountManagementStatusReportV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_management_status_report_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_management_status_report_v07_mut(self) }
+}
+
+impl crate::acmt_007_001_05::AccountOpeningRequestV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_opening_request_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_opening_request_v05_mut(self) }
+}
+
+impl crate::acmt_008_001_05::AccountOpeningAmendmentRequestV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_opening_amendment_request_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_opening_amendment_request_v05_mut(self) }
+}
+
+impl crate::acmt_009_001_04::AccountOpeningAdditionalInformationRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_opening_additional_information_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_opening_additional_information_request_v04_mut(self) }
+}
+
+impl crate::acmt_010_001_04::AccountRequestAcknowledgementV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_request_acknowledgement_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_request_acknowledgement_v04_mut(self) }
+}
+
+impl crate::acmt_011_001_04::AccountRequestRejectionV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_request_rejection_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_request_rejection_v04_mut(self) }
+}
+
+impl crate::acmt_012_001_04::AccountAdditionalInformationRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_additional_information_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_additional_information_request_v04_mut(self) }
+}
+
+impl crate::acmt_013_001_04::AccountReportRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_report_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_report_request_v04_mut(self) }
+}
+
+impl crate::acmt_014_001_05::AccountReportV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_report_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_report_v05_mut(self) }
+}
+
+impl crate::acmt_015_001_04::AccountExcludedMandateMaintenanceRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_excluded_mandate_maintenance_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_excluded_mandate_maintenance_request_v04_mut(self) }
+}
+
+impl crate::acmt_016_001_04::AccountExcludedMandateMaintenanceAmendmentRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_excluded_mandate_maintenance_amendment_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_excluded_mandate_maintenance_amendment_request_v04_mut(self) }
+}
+
+impl crate::acmt_017_001_04::AccountMandateMaintenanceRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_mandate_maintenance_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_mandate_maintenance_request_v04_mut(self) }
+}
+
+impl crate::acmt_018_001_04::AccountMandateMaintenanceAmendmentRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_mandate_maintenance_amendment_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_mandate_maintenance_amendment_request_v04_mut(self) }
+}
+
+impl crate::acmt_019_001_04::AccountClosingRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_closing_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_closing_request_v04_mut(self) }
+}
+
+impl crate::acmt_020_001_04::AccountClosingAmendmentRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_closing_amendment_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_closing_amendment_request_v04_mut(self) }
+}
+
+impl crate::acmt_021_001_04::AccountClosingAdditionalInformationRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_closing_additional_information_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_closing_additional_information_request_v04_mut(self) }
+}
+
+impl crate::acmt_022_001_04::IdentificationModificationAdviceV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_identification_modification_advice_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_identification_modification_advice_v04_mut(self) }
+}
+
+impl crate::acmt_023_001_04::IdentificationVerificationRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_identification_verification_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_identification_verification_request_v04_mut(self) }
+}
+
+impl crate::acmt_024_001_04::IdentificationVerificationReportV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_identification_verification_report_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_identification_verification_report_v04_mut(self) }
+}
+
+impl crate::acmt_027_001_05::AccountSwitchInformationRequestV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_information_request_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_information_request_v05_mut(self) }
+}
+
+impl crate::acmt_028_001_05::AccountSwitchInformationResponseV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_information_response_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_information_response_v05_mut(self) }
+}
+
+impl crate::acmt_029_001_05::AccountSwitchCancelExistingPaymentV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_cancel_existing_payment_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_cancel_existing_payment_v05_mut(self) }
+}
+
+impl crate::acmt_030_001_04::AccountSwitchRequestRedirectionV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_request_redirection_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_request_redirection_v04_mut(self) }
+}
+
+impl crate::acmt_031_001_05::AccountSwitchRequestBalanceTransferV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_request_balance_transfer_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_request_balance_transfer_v05_mut(self) }
+}
+
+impl crate::acmt_032_001_05::AccountSwitchBalanceTransferAcknowledgementV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_balance_transfer_acknowledgement_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_balance_transfer_acknowledgement_v05_mut(self) }
+}
+
+impl crate::acmt_033_001_02::AccountSwitchNotifyAccountSwitchCompleteV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_notify_account_switch_complete_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_notify_account_switch_complete_v02_mut(self) }
+}
+
+impl crate::acmt_034_001_05::AccountSwitchRequestPaymentV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_request_payment_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_request_payment_v05_mut(self) }
+}
+
+impl crate::acmt_035_001_02::AccountSwitchPaymentResponseV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_payment_response_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_payment_response_v02_mut(self) }
+}
+
+impl crate::acmt_036_001_01::AccountSwitchTerminationSwitchV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_termination_switch_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_termination_switch_v01_mut(self) }
+}
+
+impl crate::acmt_037_001_02::AccountSwitchTechnicalRejectionV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_switch_technical_rejection_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_switch_technical_rejection_v02_mut(self) }
+}
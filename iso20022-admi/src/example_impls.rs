@@ -0,0 +1,130 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `Example` for the generated types of this crate, generated by
+// generate-example.py from their facets (see `open_payments_common::example`).
+
+use open_payments_common::example::Example;
+
+impl Example for crate::admi_002_001_01::Admi00200101 {
+	fn example() -> Self {
+		Self {
+			rltd_ref: Example::example(),
+			rsn: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::admi_004_001_02::SystemEventNotificationV02 {
+	fn example() -> Self {
+		Self {
+			evt_inf: Example::example(),
+		}
+	}
+}
+
+impl Example for crate::admi_005_001_02::ReportQueryRequestV02 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_qry_crit: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::admi_006_001_01::ResendRequestV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rsnd_sch_crit: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::admi_007_001_01::ReceiptAcknowledgementV01 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			rpt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::admi_009_001_02::StaticDataRequestV02 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			sttlm_ssn_idr: None,
+			data_req_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::admi_010_001_02::StaticDataReportV02 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			sttlm_ssn_idr: None,
+			rpt_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::admi_011_001_01::SystemEventAcknowledgementV01 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			orgtr_ref: None,
+			sttlm_ssn_idr: None,
+			ack_dtls: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::admi_017_001_02::ProcessingRequestV02 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			sttlm_ssn_idr: None,
+			req: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::admi_024_001_01::NotificationOfCorrespondenceV01 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: None,
+			ntfctn_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
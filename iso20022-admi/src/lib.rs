@@ -33,3 +33,7 @@ pub mod admi_010_001_02;
 pub mod admi_011_001_01;
 pub mod admi_017_001_02;
 pub mod admi_024_001_01;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
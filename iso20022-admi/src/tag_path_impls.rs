@@ -0,0 +1,173 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `TagPaths` for the generated types of this crate, generated by
+// generate-tag-path.py from the tags of their fields (see
+// `open_payments_common::tag_path`).
+
+use open_payments_common::tag_path::*;
+
+impl TagPaths for crate::admi_002_001_01::Admi00200101 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RltdRef") { one(&self.rltd_ref, step, out) }
+		if step.matches("Rsn") { one(&self.rsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RltdRef") { one_mut(&mut self.rltd_ref, step, out) }
+		if step.matches("Rsn") { one_mut(&mut self.rsn, step, out) }
+	}
+}
+
+impl TagPaths for crate::admi_004_001_02::SystemEventNotificationV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("EvtInf") { one(&self.evt_inf, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("EvtInf") { one_mut(&mut self.evt_inf, step, out) }
+	}
+}
+
+impl TagPaths for crate::admi_005_001_02::ReportQueryRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptQryCrit") { optional_repeated(&self.rpt_qry_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptQryCrit") { optional_repeated_mut(&mut self.rpt_qry_crit, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::admi_006_001_01::ResendRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RsndSchCrit") { repeated(&self.rsnd_sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RsndSchCrit") { repeated_mut(&mut self.rsnd_sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::admi_007_001_01::ReceiptAcknowledgementV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("Rpt") { repeated(&self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("Rpt") { repeated_mut(&mut self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::admi_009_001_02::StaticDataRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("SttlmSsnIdr") { optional(&self.sttlm_ssn_idr, step, out) }
+		if step.matches("DataReqDtls") { one(&self.data_req_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("SttlmSsnIdr") { optional_mut(&mut self.sttlm_ssn_idr, step, create, out) }
+		if step.matches("DataReqDtls") { one_mut(&mut self.data_req_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::admi_010_001_02::StaticDataReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("SttlmSsnIdr") { optional(&self.sttlm_ssn_idr, step, out) }
+		if step.matches("RptDtls") { one(&self.rpt_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("SttlmSsnIdr") { optional_mut(&mut self.sttlm_ssn_idr, step, create, out) }
+		if step.matches("RptDtls") { one_mut(&mut self.rpt_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::admi_011_001_01::SystemEventAcknowledgementV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("OrgtrRef") { optional(&self.orgtr_ref, step, out) }
+		if step.matches("SttlmSsnIdr") { optional(&self.sttlm_ssn_idr, step, out) }
+		if step.matches("AckDtls") { optional(&self.ack_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("OrgtrRef") { optional_mut(&mut self.orgtr_ref, step, create, out) }
+		if step.matches("SttlmSsnIdr") { optional_mut(&mut self.sttlm_ssn_idr, step, create, out) }
+		if step.matches("AckDtls") { optional_mut(&mut self.ack_dtls, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::admi_017_001_02::ProcessingRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("SttlmSsnIdr") { optional(&self.sttlm_ssn_idr, step, out) }
+		if step.matches("Req") { one(&self.req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("SttlmSsnIdr") { optional_mut(&mut self.sttlm_ssn_idr, step, create, out) }
+		if step.matches("Req") { one_mut(&mut self.req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::admi_024_001_01::NotificationOfCorrespondenceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { optional(&self.grp_hdr, step, out) }
+		if step.matches("NtfctnData") { repeated(&self.ntfctn_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { optional_mut(&mut self.grp_hdr, step, create, out) }
+		if step.matches("NtfctnData") { repeated_mut(&mut self.ntfctn_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
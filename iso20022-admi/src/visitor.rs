@@ -0,0 +1,213 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over the generated types of this crate, generated by
+// generate-visitor.py. `Visitor` has a `visit_` method per type that
+// walks into its fields by default; override the methods of the types of
+// interest and call the matching `walk_` function to keep going below
+// them. `VisitorMut` does the same with mutable references.
+
+use open_payments_common::visitor::{Visitor as BaseVisitor, VisitorMut as BaseVisitorMut};
+
+pub trait Visitor: BaseVisitor {
+	fn visit_admi00200101(&mut self, node: &crate::admi_002_001_01::Admi00200101) { walk_admi00200101(self, node) }
+	fn visit_system_event_notification_v02(&mut self, node: &crate::admi_004_001_02::SystemEventNotificationV02) { walk_system_event_notification_v02(self, node) }
+	fn visit_report_query_request_v02(&mut self, node: &crate::admi_005_001_02::ReportQueryRequestV02) { walk_report_query_request_v02(self, node) }
+	fn visit_resend_request_v01(&mut self, node: &crate::admi_006_001_01::ResendRequestV01) { walk_resend_request_v01(self, node) }
+	fn visit_receipt_acknowledgement_v01(&mut self, node: &crate::admi_007_001_01::ReceiptAcknowledgementV01) { walk_receipt_acknowledgement_v01(self, node) }
+	fn visit_static_data_request_v02(&mut self, node: &crate::admi_009_001_02::StaticDataRequestV02) { walk_static_data_request_v02(self, node) }
+	fn visit_static_data_report_v02(&mut self, node: &crate::admi_010_001_02::StaticDataReportV02) { walk_static_data_report_v02(self, node) }
+	fn visit_system_event_acknowledgement_v01(&mut self, node: &crate::admi_011_001_01::SystemEventAcknowledgementV01) { walk_system_event_acknowledgement_v01(self, node) }
+	fn visit_processing_request_v02(&mut self, node: &crate::admi_017_001_02::ProcessingRequestV02) { walk_processing_request_v02(self, node) }
+	fn visit_notification_of_correspondence_v01(&mut self, node: &crate::admi_024_001_01::NotificationOfCorrespondenceV01) { walk_notification_of_correspondence_v01(self, node) }
+}
+
+pub trait VisitorMut: BaseVisitorMut {
+	fn visit_admi00200101_mut(&mut self, node: &mut crate::admi_002_001_01::Admi00200101) { walk_admi00200101_mut(self, node) }
+	fn visit_system_event_notification_v02_mut(&mut self, node: &mut crate::admi_004_001_02::SystemEventNotificationV02) { walk_system_event_notification_v02_mut(self, node) }
+	fn visit_report_query_request_v02_mut(&mut self, node: &mut crate::admi_005_001_02::ReportQueryRequestV02) { walk_report_query_request_v02_mut(self, node) }
+	fn visit_resend_request_v01_mut(&mut self, node: &mut crate::admi_006_001_01::ResendRequestV01) { walk_resend_request_v01_mut(self, node) }
+	fn visit_receipt_acknowledgement_v01_mut(&mut self, node: &mut crate::admi_007_001_01::ReceiptAcknowledgementV01) { walk_receipt_acknowledgement_v01_mut(self, node) }
+	fn visit_static_data_request_v02_mut(&mut self, node: &mut crate::admi_009_001_02::StaticDataRequestV02) { walk_static_data_request_v02_mut(self, node) }
+	fn visit_static_data_report_v02_mut(&mut self, node: &mut crate::admi_010_001_02::StaticDataReportV02) { walk_static_data_report_v02_mut(self, node) }
+	fn visit_system_event_acknowledgement_v01_mut(&mut self, node: &mut crate::admi_011_001_01::SystemEventAcknowledgementV01) { walk_system_event_acknowledgement_v01_mut(self, node) }
+	fn visit_processing_request_v02_mut(&mut self, node: &mut crate::admi_017_001_02::ProcessingRequestV02) { walk_processing_request_v02_mut(self, node) }
+	fn visit_notification_of_correspondence_v01_mut(&mut self, node: &mut crate::admi_024_001_01::NotificationOfCorrespondenceV01) { walk_notification_of_correspondence_v01_mut(self, node) }
+}
+
+pub fn walk_admi00200101<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_002_001_01::Admi00200101) {
+	visitor.visit_message_reference(&node.rltd_ref);
+	visitor.visit_rejection_reason2(&node.rsn);
+}
+
+pub fn walk_admi00200101_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_002_001_01::Admi00200101) {
+	visitor.visit_message_reference_mut(&mut node.rltd_ref);
+	visitor.visit_rejection_reason2_mut(&mut node.rsn);
+}
+
+pub fn walk_system_event_notification_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_004_001_02::SystemEventNotificationV02) {
+	visitor.visit_event2(&node.evt_inf);
+}
+
+pub fn walk_system_event_notification_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_004_001_02::SystemEventNotificationV02) {
+	visitor.visit_event2_mut(&mut node.evt_inf);
+}
+
+pub fn walk_report_query_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_005_001_02::ReportQueryRequestV02) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	if let Some(ref vec) = node.rpt_qry_crit { for item in vec { visitor.visit_report_query_criteria3(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_report_query_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_005_001_02::ReportQueryRequestV02) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	if let Some(ref mut vec) = node.rpt_qry_crit { for item in vec { visitor.visit_report_query_criteria3_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_resend_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_006_001_01::ResendRequestV01) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	for item in &node.rsnd_sch_crit { visitor.visit_resend_search_criteria2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_resend_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_006_001_01::ResendRequestV01) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	for item in &mut node.rsnd_sch_crit { visitor.visit_resend_search_criteria2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_receipt_acknowledgement_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_007_001_01::ReceiptAcknowledgementV01) {
+	visitor.visit_message_header10(&node.msg_id);
+	for item in &node.rpt { visitor.visit_receipt_acknowledgement_report2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_receipt_acknowledgement_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_007_001_01::ReceiptAcknowledgementV01) {
+	visitor.visit_message_header10_mut(&mut node.msg_id);
+	for item in &mut node.rpt { visitor.visit_receipt_acknowledgement_report2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_static_data_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_009_001_02::StaticDataRequestV02) {
+	visitor.visit_request_details3(&node.data_req_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_static_data_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_009_001_02::StaticDataRequestV02) {
+	visitor.visit_request_details3_mut(&mut node.data_req_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_static_data_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_010_001_02::StaticDataReportV02) {
+	visitor.visit_request_details5(&node.rpt_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_static_data_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_010_001_02::StaticDataReportV02) {
+	visitor.visit_request_details5_mut(&mut node.rpt_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_system_event_acknowledgement_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_011_001_01::SystemEventAcknowledgementV01) {
+	if let Some(ref value) = node.ack_dtls { visitor.visit_event1(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_system_event_acknowledgement_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_011_001_01::SystemEventAcknowledgementV01) {
+	if let Some(ref mut value) = node.ack_dtls { visitor.visit_event1_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_processing_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_017_001_02::ProcessingRequestV02) {
+	visitor.visit_request_details30(&node.req);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_processing_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_017_001_02::ProcessingRequestV02) {
+	visitor.visit_request_details30_mut(&mut node.req);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_notification_of_correspondence_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::admi_024_001_01::NotificationOfCorrespondenceV01) {
+	if let Some(ref value) = node.grp_hdr { visitor.visit_group_header129(value) }
+	for item in &node.ntfctn_data { visitor.visit_correspondence_notification1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_notification_of_correspondence_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::admi_024_001_01::NotificationOfCorrespondenceV01) {
+	if let Some(ref mut value) = node.grp_hdr { visitor.visit_group_header129_mut(value) }
+	for item in &mut node.ntfctn_data { visitor.visit_correspondence_notification1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+impl crate::admi_002_001_01::Admi00200101 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_admi00200101(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_admi00200101_mut(self) }
+}
+
+impl crate::admi_004_001_02::SystemEventNotificationV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_system_event_notification_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_system_event_notification_v02_mut(self) }
+}
+
+impl crate::admi_005_001_02::ReportQueryRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_report_query_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_report_query_request_v02_mut(self) }
+}
+
+impl crate::admi_006_001_01::ResendRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_resend_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_resend_request_v01_mut(self) }
+}
+
+impl crate::admi_007_001_01::ReceiptAcknowledgementV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_receipt_acknowledgement_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_receipt_acknowledgement_v01_mut(self) }
+}
+
+impl crate::admi_009_001_02::StaticDataRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_static_data_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_static_data_request_v02_mut(self) }
+}
+
+impl crate::admi_010_001_02::StaticDataReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_static_data_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_static_data_report_v02_mut(self) }
+}
+
+impl crate::admi_011_001_01::SystemEventAcknowledgementV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_system_event_acknowledgement_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_system_event_acknowledgement_v01_mut(self) }
+}
+
+impl crate::admi_017_001_02::ProcessingRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_processing_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_processing_request_v02_mut(self) }
+}
+
+impl crate::admi_024_001_01::NotificationOfCorrespondenceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_notification_of_correspondence_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_notification_of_correspondence_v01_mut(self) }
+}
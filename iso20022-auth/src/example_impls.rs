@@ -0,0 +1,848 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `Example` for the generated types of this crate, generated by
+// generate-example.py from their facets (see `open_payments_common::example`).
+
+use open_payments_common::example::Example;
+
+impl Example for crate::auth_001_001_02::InformationRequestOpeningV02 {
+	fn example() -> Self {
+		Self {
+			invstgtn_id: "InvstgtnId".to_string(),
+			lgl_mndt_bsis: Example::example(),
+			cnfdtlty_sts: false,
+			due_dt: None,
+			invstgtn_prd: Example::example(),
+			sch_crit: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_002_001_02::InformationRequestResponseV02 {
+	fn example() -> Self {
+		Self {
+			rspn_id: "RspnId".to_string(),
+			invstgtn_id: "InvstgtnId".to_string(),
+			rspn_sts: Example::example(),
+			sch_crit: Example::example(),
+			rtr_ind: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_003_001_01::InformationRequestStatusChangeNotificationV01 {
+	fn example() -> Self {
+		Self {
+			orgnl_biz_qry: "OrgnlBizQry".to_string(),
+			cnfdtlty_sts: false,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_012_001_02::MoneyMarketSecuredMarketStatisticalReportV02 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			scrd_mkt_rpt: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_013_001_02::MoneyMarketUnsecuredMarketStatisticalReportV02 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			uscrd_mkt_rpt: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_014_001_02::MoneyMarketForeignExchangeSwapsStatisticalReportV02 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			fx_swps_rpt: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_015_001_02::MoneyMarketOvernightIndexSwapsStatisticalReportV02 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			ovrnght_indx_swps_rpt: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_016_001_03::FinancialInstrumentReportingTransactionReportV03 {
+	fn example() -> Self {
+		Self {
+			tx: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_017_001_02::FinancialInstrumentReportingReferenceDataReportV02 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			ref_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_018_001_04::ContractRegistrationRequestV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			ctrct_regn: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_019_001_04::ContractRegistrationConfirmationV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			regd_ctrct: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_020_001_04::ContractRegistrationClosureRequestV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			regd_ctrct_clsr: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_021_001_04::ContractRegistrationAmendmentRequestV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			ctrct_regn_amdmnt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_022_001_04::ContractRegistrationStatementV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			stmt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_023_001_04::ContractRegistrationStatementRequestV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			stmt_req: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_024_001_04::PaymentRegulatoryInformationNotificationV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			tx_ntfctn: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_025_001_04::CurrencyControlSupportingDocumentDeliveryV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			spprtg_doc: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_026_001_04::CurrencyControlRequestOrLetterV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			req_or_lttr: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_027_001_04::CurrencyControlStatusAdviceV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			grp_sts: vec![Example::example()],
+			packg_sts: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_028_001_01::MoneyMarketStatisticalReportStatusAdviceV01 {
+	fn example() -> Self {
+		Self {
+			sts_rpt_hdr: Example::example(),
+			tx_sts: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_029_001_05::DerivativesTradeReportQueryV05 {
+	fn example() -> Self {
+		Self {
+			rqstng_authrty: Example::example(),
+			trad_qry_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_030_001_04::DerivativesTradeReportV04 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_031_001_01::FinancialInstrumentReportingStatusAdviceV01 {
+	fn example() -> Self {
+		Self {
+			sts_advc: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_032_001_01::FinancialInstrumentReportingEquityTransparencyDataReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			eqty_trnsprncy_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_033_001_03::FinancialInstrumentReportingNonEquityTransparencyDataReportV03 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			non_eqty_trnsprncy_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_034_001_01::InvoiceTaxReportV01 {
+	fn example() -> Self {
+		Self {
+			invc_tax_rpt_hdr: Example::example(),
+			tax_rpt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_035_001_01::FinancialInstrumentReportingTradingVolumeCapDataReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			vol_cap_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_036_001_03::FinancialInstrumentReportingReferenceDataDeltaReportV03 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			fin_instrm: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_038_001_01::InvoiceTaxReportStatusAdviceV01 {
+	fn example() -> Self {
+		Self {
+			sts_rpt_hdr: Example::example(),
+			tx_sts: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_039_001_01::FinancialInstrumentReportingNonWorkingDayReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			non_workg_day: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_040_001_01::FinancialInstrumentReportingEquityTradingActivityReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			eqty_trnsprncy_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_041_001_01::FinancialInstrumentReportingNonEquityTradingActivityReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			non_eqty_trnsprncy_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_042_001_02::FinancialInstrumentReportingInvalidReferenceDataReportV02 {
+	fn example() -> Self {
+		Self {
+			dt_prd: Example::example(),
+			nb_of_rcrds: None,
+			fin_instrms: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_043_001_01::FinancialInstrumentReportingReferenceDataIndexReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			indx_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_044_001_02::FinancialInstrumentReportingEquityTradingActivityResultV02 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			eqty_trnsprncy_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_045_001_03::FinancialInstrumentReportingNonEquityTradingActivityResultV03 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			non_eqty_trnsprncy_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_047_001_01::FinancialInstrumentReportingCountryCodeReportV01 {
+	fn example() -> Self {
+		Self {
+			ctry_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_048_001_01::FinancialInstrumentReportingCurrencyCodeReportV01 {
+	fn example() -> Self {
+		Self {
+			ccy_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_049_001_02::FinancialInstrumentReportingMarketIdentificationCodeReportV02 {
+	fn example() -> Self {
+		Self {
+			mkt_id: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_050_001_01::FinancialInstrumentReportingInstrumentClassificationReportV01 {
+	fn example() -> Self {
+		Self {
+			instrm_clssfctn: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_052_001_02::SecuritiesFinancingReportingTransactionReportV02 {
+	fn example() -> Self {
+		Self {
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_053_001_01::FinancialInstrumentReportingTradingVolumeCapResultReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			vol_cap_rslt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_054_001_01::CCPClearingMemberReportV01 {
+	fn example() -> Self {
+		Self {
+			clr_mmb: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_055_001_01::CCPMemberRequirementsReportV01 {
+	fn example() -> Self {
+		Self {
+			intra_day_rqrmnt_amt: vec![Example::example()],
+			intra_day_mrgn_call: None,
+			end_of_day_rqrmnt: vec![Example::example()],
+			dflt_fnd_rqrmnt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_056_001_01::CCPMemberObligationsReportV01 {
+	fn example() -> Self {
+		Self {
+			sttlm_acct: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_057_001_02::CCPPortfolioStressTestingDefinitionReportV02 {
+	fn example() -> Self {
+		Self {
+			scnro_def: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_058_001_01::CCPPortfolioStressTestingResultReportV01 {
+	fn example() -> Self {
+		Self {
+			scnro_strss_tst_rslt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_059_001_01::CCPIncomeStatementAndCapitalAdequacyReportV01 {
+	fn example() -> Self {
+		Self {
+			incm_stmt: Example::example(),
+			cptl_rqrmnts: Example::example(),
+			ttl_cptl: Example::example(),
+			lqd_fin_rsrcs: Example::example(),
+			hpthtcl_cptl_measr: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_060_001_02::CCPDailyCashFlowsReportV02 {
+	fn example() -> Self {
+		Self {
+			cncntrtn_agt: vec![Example::example()],
+			sttlm_agt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_061_001_01::CCPInvestmentsReportV01 {
+	fn example() -> Self {
+		Self {
+			invstmt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_062_001_01::CCPLiquidityStressTestingDefinitionReportV01 {
+	fn example() -> Self {
+		Self {
+			lqdty_strss_scnro_def: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_063_001_01::CCPLiquidityStressTestingResultReportV01 {
+	fn example() -> Self {
+		Self {
+			lqdty_strss_tst_rslt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_064_001_01::CCPAvailableFinancialResourcesReportV01 {
+	fn example() -> Self {
+		Self {
+			avlbl_fin_rsrcs_amt: Example::example(),
+			othr_prfndd_rsrcs: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_065_001_01::CCPBackTestingDefinitionReportV01 {
+	fn example() -> Self {
+		Self {
+			mthdlgy: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_066_001_01::CCPBackTestingResultReportV01 {
+	fn example() -> Self {
+		Self {
+			mnthly_rslt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_067_001_01::CCPCollateralReportV01 {
+	fn example() -> Self {
+		Self {
+			coll_acct_ownr: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_068_001_01::CCPAccountPositionReportV01 {
+	fn example() -> Self {
+		Self {
+			prtfl: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_069_001_01::CCPClearedProductReportV01 {
+	fn example() -> Self {
+		Self {
+			clrd_pdct: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_070_001_02::SecuritiesFinancingReportingTransactionMarginDataReportV02 {
+	fn example() -> Self {
+		Self {
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_071_001_02::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02 {
+	fn example() -> Self {
+		Self {
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_072_001_01::SettlementInternaliserReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			sttlm_intlr: Example::example(),
+			issr_csd: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_076_001_01::FinancialSupervisedPartyIdentityReportV01 {
+	fn example() -> Self {
+		Self {
+			pty_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_077_001_01::FinancialBenchmarkReportV01 {
+	fn example() -> Self {
+		Self {
+			bchmk_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_078_001_02::SecuritiesFinancingReportingPairingRequestV02 {
+	fn example() -> Self {
+		Self {
+			tx_id: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_079_001_02::SecuritiesFinancingReportingTransactionStateReportV02 {
+	fn example() -> Self {
+		Self {
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_080_001_02::SecuritiesFinancingReportingReconciliationStatusAdviceV02 {
+	fn example() -> Self {
+		Self {
+			rcncltn_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_083_001_02::SecuritiesFinancingReportingMissingCollateralRequestV02 {
+	fn example() -> Self {
+		Self {
+			tx_id: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_084_001_02::SecuritiesFinancingReportingTransactionStatusAdviceV02 {
+	fn example() -> Self {
+		Self {
+			tx_rpt_sts_and_rsn: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_085_001_02::SecuritiesFinancingReportingMarginDataTransactionStateReportV02 {
+	fn example() -> Self {
+		Self {
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_086_001_02::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02 {
+	fn example() -> Self {
+		Self {
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_090_001_02::DerivativesTradePositionSetReportV02 {
+	fn example() -> Self {
+		Self {
+			aggtd_pos: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_091_001_03::DerivativesTradeReconciliationStatisticalReportV03 {
+	fn example() -> Self {
+		Self {
+			rcncltn_sttstcs: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_092_001_04::DerivativesTradeRejectionStatisticalReportV04 {
+	fn example() -> Self {
+		Self {
+			rjctn_sttstcs: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_094_001_02::SecuritiesFinancingReportingTransactionQueryV02 {
+	fn example() -> Self {
+		Self {
+			rqstng_authrty: Example::example(),
+			trad_qry_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_100_001_01::SettlementFailsMonthlyReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			mnthly_aggt: Example::example(),
+			daly_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_101_001_01::SettlementFailsAnnualReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			anl_aggt: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_102_001_01::FinancialInstrumentReportingCancellationReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			cxl_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_105_001_01::SecuritiesFinancingReportingPositionSetReportV01 {
+	fn example() -> Self {
+		Self {
+			aggtd_poss: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_106_001_01::DerivativesTradeWarningsReportV01 {
+	fn example() -> Self {
+		Self {
+			wrnngs_sttstcs: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_107_001_02::DerivativesTradeStateReportV02 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_108_001_02::DerivativesTradeMarginDataReportV02 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_109_001_02::DerivativesTradeMarginDataTransactionStateReportV02 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			trad_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_112_001_01::CCPInteroperabilityReportV01 {
+	fn example() -> Self {
+		Self {
+			intrprblty_ccp: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::auth_113_001_01::OrderBookReportV01 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			ordr_rpt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
@@ -107,3 +107,7 @@ pub mod auth_108_001_02;
 pub mod auth_109_001_02;
 pub mod auth_112_001_01;
 pub mod auth_113_001_01;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
@@ -0,0 +1,1165 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `TagPaths` for the generated types of this crate, generated by
+// generate-tag-path.py from the tags of their fields (see
+// `open_payments_common::tag_path`).
+
+use open_payments_common::tag_path::*;
+
+impl TagPaths for crate::auth_001_001_02::InformationRequestOpeningV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InvstgtnId") { one(&self.invstgtn_id, step, out) }
+		if step.matches("LglMndtBsis") { one(&self.lgl_mndt_bsis, step, out) }
+		if step.matches("CnfdtltySts") { one(&self.cnfdtlty_sts, step, out) }
+		if step.matches("DueDt") { optional(&self.due_dt, step, out) }
+		if step.matches("InvstgtnPrd") { one(&self.invstgtn_prd, step, out) }
+		if step.matches("SchCrit") { one(&self.sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InvstgtnId") { one_mut(&mut self.invstgtn_id, step, out) }
+		if step.matches("LglMndtBsis") { one_mut(&mut self.lgl_mndt_bsis, step, out) }
+		if step.matches("CnfdtltySts") { one_mut(&mut self.cnfdtlty_sts, step, out) }
+		if step.matches("DueDt") { optional_mut(&mut self.due_dt, step, create, out) }
+		if step.matches("InvstgtnPrd") { one_mut(&mut self.invstgtn_prd, step, out) }
+		if step.matches("SchCrit") { one_mut(&mut self.sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_002_001_02::InformationRequestResponseV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RspnId") { one(&self.rspn_id, step, out) }
+		if step.matches("InvstgtnId") { one(&self.invstgtn_id, step, out) }
+		if step.matches("RspnSts") { one(&self.rspn_sts, step, out) }
+		if step.matches("SchCrit") { one(&self.sch_crit, step, out) }
+		if step.matches("RtrInd") { repeated(&self.rtr_ind, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RspnId") { one_mut(&mut self.rspn_id, step, out) }
+		if step.matches("InvstgtnId") { one_mut(&mut self.invstgtn_id, step, out) }
+		if step.matches("RspnSts") { one_mut(&mut self.rspn_sts, step, out) }
+		if step.matches("SchCrit") { one_mut(&mut self.sch_crit, step, out) }
+		if step.matches("RtrInd") { repeated_mut(&mut self.rtr_ind, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_003_001_01::InformationRequestStatusChangeNotificationV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("OrgnlBizQry") { one(&self.orgnl_biz_qry, step, out) }
+		if step.matches("CnfdtltySts") { one(&self.cnfdtlty_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("OrgnlBizQry") { one_mut(&mut self.orgnl_biz_qry, step, out) }
+		if step.matches("CnfdtltySts") { one_mut(&mut self.cnfdtlty_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_012_001_02::MoneyMarketSecuredMarketStatisticalReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("ScrdMktRpt") { one(&self.scrd_mkt_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("ScrdMktRpt") { one_mut(&mut self.scrd_mkt_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_013_001_02::MoneyMarketUnsecuredMarketStatisticalReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("UscrdMktRpt") { one(&self.uscrd_mkt_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("UscrdMktRpt") { one_mut(&mut self.uscrd_mkt_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_014_001_02::MoneyMarketForeignExchangeSwapsStatisticalReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("FXSwpsRpt") { one(&self.fx_swps_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("FXSwpsRpt") { one_mut(&mut self.fx_swps_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_015_001_02::MoneyMarketOvernightIndexSwapsStatisticalReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("OvrnghtIndxSwpsRpt") { one(&self.ovrnght_indx_swps_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("OvrnghtIndxSwpsRpt") { one_mut(&mut self.ovrnght_indx_swps_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_016_001_03::FinancialInstrumentReportingTransactionReportV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Tx") { repeated(&self.tx, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Tx") { repeated_mut(&mut self.tx, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_017_001_02::FinancialInstrumentReportingReferenceDataReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("RefData") { repeated(&self.ref_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("RefData") { repeated_mut(&mut self.ref_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_018_001_04::ContractRegistrationRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("CtrctRegn") { repeated(&self.ctrct_regn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("CtrctRegn") { repeated_mut(&mut self.ctrct_regn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_019_001_04::ContractRegistrationConfirmationV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("RegdCtrct") { repeated(&self.regd_ctrct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("RegdCtrct") { repeated_mut(&mut self.regd_ctrct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_020_001_04::ContractRegistrationClosureRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("RegdCtrctClsr") { repeated(&self.regd_ctrct_clsr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("RegdCtrctClsr") { repeated_mut(&mut self.regd_ctrct_clsr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_021_001_04::ContractRegistrationAmendmentRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("CtrctRegnAmdmnt") { repeated(&self.ctrct_regn_amdmnt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("CtrctRegnAmdmnt") { repeated_mut(&mut self.ctrct_regn_amdmnt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_022_001_04::ContractRegistrationStatementV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Stmt") { repeated(&self.stmt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Stmt") { repeated_mut(&mut self.stmt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_023_001_04::ContractRegistrationStatementRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("StmtReq") { repeated(&self.stmt_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("StmtReq") { repeated_mut(&mut self.stmt_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_024_001_04::PaymentRegulatoryInformationNotificationV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("TxNtfctn") { repeated(&self.tx_ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("TxNtfctn") { repeated_mut(&mut self.tx_ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_025_001_04::CurrencyControlSupportingDocumentDeliveryV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("SpprtgDoc") { repeated(&self.spprtg_doc, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("SpprtgDoc") { repeated_mut(&mut self.spprtg_doc, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_026_001_04::CurrencyControlRequestOrLetterV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("ReqOrLttr") { repeated(&self.req_or_lttr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("ReqOrLttr") { repeated_mut(&mut self.req_or_lttr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_027_001_04::CurrencyControlStatusAdviceV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("GrpSts") { repeated(&self.grp_sts, step, out) }
+		if step.matches("PackgSts") { optional_repeated(&self.packg_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("GrpSts") { repeated_mut(&mut self.grp_sts, step, out) }
+		if step.matches("PackgSts") { optional_repeated_mut(&mut self.packg_sts, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_028_001_01::MoneyMarketStatisticalReportStatusAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("StsRptHdr") { one(&self.sts_rpt_hdr, step, out) }
+		if step.matches("TxSts") { optional_repeated(&self.tx_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("StsRptHdr") { one_mut(&mut self.sts_rpt_hdr, step, out) }
+		if step.matches("TxSts") { optional_repeated_mut(&mut self.tx_sts, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_029_001_05::DerivativesTradeReportQueryV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RqstngAuthrty") { one(&self.rqstng_authrty, step, out) }
+		if step.matches("TradQryData") { one(&self.trad_qry_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RqstngAuthrty") { one_mut(&mut self.rqstng_authrty, step, out) }
+		if step.matches("TradQryData") { one_mut(&mut self.trad_qry_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_030_001_04::DerivativesTradeReportV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_031_001_01::FinancialInstrumentReportingStatusAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("StsAdvc") { repeated(&self.sts_advc, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("StsAdvc") { repeated_mut(&mut self.sts_advc, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_032_001_01::FinancialInstrumentReportingEquityTransparencyDataReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("EqtyTrnsprncyData") { repeated(&self.eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("EqtyTrnsprncyData") { repeated_mut(&mut self.eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_033_001_03::FinancialInstrumentReportingNonEquityTransparencyDataReportV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("NonEqtyTrnsprncyData") { repeated(&self.non_eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("NonEqtyTrnsprncyData") { repeated_mut(&mut self.non_eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_034_001_01::InvoiceTaxReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InvcTaxRptHdr") { one(&self.invc_tax_rpt_hdr, step, out) }
+		if step.matches("TaxRpt") { repeated(&self.tax_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InvcTaxRptHdr") { one_mut(&mut self.invc_tax_rpt_hdr, step, out) }
+		if step.matches("TaxRpt") { repeated_mut(&mut self.tax_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_035_001_01::FinancialInstrumentReportingTradingVolumeCapDataReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("VolCapData") { repeated(&self.vol_cap_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("VolCapData") { repeated_mut(&mut self.vol_cap_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_036_001_03::FinancialInstrumentReportingReferenceDataDeltaReportV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("FinInstrm") { optional_repeated(&self.fin_instrm, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("FinInstrm") { optional_repeated_mut(&mut self.fin_instrm, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_038_001_01::InvoiceTaxReportStatusAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("StsRptHdr") { one(&self.sts_rpt_hdr, step, out) }
+		if step.matches("TxSts") { optional_repeated(&self.tx_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("StsRptHdr") { one_mut(&mut self.sts_rpt_hdr, step, out) }
+		if step.matches("TxSts") { optional_repeated_mut(&mut self.tx_sts, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_039_001_01::FinancialInstrumentReportingNonWorkingDayReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("NonWorkgDay") { repeated(&self.non_workg_day, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("NonWorkgDay") { repeated_mut(&mut self.non_workg_day, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_040_001_01::FinancialInstrumentReportingEquityTradingActivityReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("EqtyTrnsprncyData") { repeated(&self.eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("EqtyTrnsprncyData") { repeated_mut(&mut self.eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_041_001_01::FinancialInstrumentReportingNonEquityTradingActivityReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("NonEqtyTrnsprncyData") { repeated(&self.non_eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("NonEqtyTrnsprncyData") { repeated_mut(&mut self.non_eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_042_001_02::FinancialInstrumentReportingInvalidReferenceDataReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("DtPrd") { one(&self.dt_prd, step, out) }
+		if step.matches("NbOfRcrds") { optional(&self.nb_of_rcrds, step, out) }
+		if step.matches("FinInstrms") { repeated(&self.fin_instrms, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("DtPrd") { one_mut(&mut self.dt_prd, step, out) }
+		if step.matches("NbOfRcrds") { optional_mut(&mut self.nb_of_rcrds, step, create, out) }
+		if step.matches("FinInstrms") { repeated_mut(&mut self.fin_instrms, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_043_001_01::FinancialInstrumentReportingReferenceDataIndexReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("IndxData") { repeated(&self.indx_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("IndxData") { repeated_mut(&mut self.indx_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_044_001_02::FinancialInstrumentReportingEquityTradingActivityResultV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("EqtyTrnsprncyData") { repeated(&self.eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("EqtyTrnsprncyData") { repeated_mut(&mut self.eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_045_001_03::FinancialInstrumentReportingNonEquityTradingActivityResultV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("NonEqtyTrnsprncyData") { repeated(&self.non_eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("NonEqtyTrnsprncyData") { repeated_mut(&mut self.non_eqty_trnsprncy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_047_001_01::FinancialInstrumentReportingCountryCodeReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CtryData") { repeated(&self.ctry_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CtryData") { repeated_mut(&mut self.ctry_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_048_001_01::FinancialInstrumentReportingCurrencyCodeReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CcyData") { repeated(&self.ccy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CcyData") { repeated_mut(&mut self.ccy_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_049_001_02::FinancialInstrumentReportingMarketIdentificationCodeReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MktId") { repeated(&self.mkt_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MktId") { repeated_mut(&mut self.mkt_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_050_001_01::FinancialInstrumentReportingInstrumentClassificationReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InstrmClssfctn") { repeated(&self.instrm_clssfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InstrmClssfctn") { repeated_mut(&mut self.instrm_clssfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_052_001_02::SecuritiesFinancingReportingTransactionReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_053_001_01::FinancialInstrumentReportingTradingVolumeCapResultReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("VolCapRslt") { repeated(&self.vol_cap_rslt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("VolCapRslt") { repeated_mut(&mut self.vol_cap_rslt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_054_001_01::CCPClearingMemberReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ClrMmb") { repeated(&self.clr_mmb, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ClrMmb") { repeated_mut(&mut self.clr_mmb, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_055_001_01::CCPMemberRequirementsReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("IntraDayRqrmntAmt") { repeated(&self.intra_day_rqrmnt_amt, step, out) }
+		if step.matches("IntraDayMrgnCall") { optional_repeated(&self.intra_day_mrgn_call, step, out) }
+		if step.matches("EndOfDayRqrmnt") { repeated(&self.end_of_day_rqrmnt, step, out) }
+		if step.matches("DfltFndRqrmnt") { repeated(&self.dflt_fnd_rqrmnt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("IntraDayRqrmntAmt") { repeated_mut(&mut self.intra_day_rqrmnt_amt, step, out) }
+		if step.matches("IntraDayMrgnCall") { optional_repeated_mut(&mut self.intra_day_mrgn_call, step, create, out) }
+		if step.matches("EndOfDayRqrmnt") { repeated_mut(&mut self.end_of_day_rqrmnt, step, out) }
+		if step.matches("DfltFndRqrmnt") { repeated_mut(&mut self.dflt_fnd_rqrmnt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_056_001_01::CCPMemberObligationsReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("SttlmAcct") { repeated(&self.sttlm_acct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("SttlmAcct") { repeated_mut(&mut self.sttlm_acct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_057_001_02::CCPPortfolioStressTestingDefinitionReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ScnroDef") { repeated(&self.scnro_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ScnroDef") { repeated_mut(&mut self.scnro_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_058_001_01::CCPPortfolioStressTestingResultReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ScnroStrssTstRslt") { repeated(&self.scnro_strss_tst_rslt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ScnroStrssTstRslt") { repeated_mut(&mut self.scnro_strss_tst_rslt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_059_001_01::CCPIncomeStatementAndCapitalAdequacyReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("IncmStmt") { one(&self.incm_stmt, step, out) }
+		if step.matches("CptlRqrmnts") { one(&self.cptl_rqrmnts, step, out) }
+		if step.matches("TtlCptl") { one(&self.ttl_cptl, step, out) }
+		if step.matches("LqdFinRsrcs") { one(&self.lqd_fin_rsrcs, step, out) }
+		if step.matches("HpthtclCptlMeasr") { repeated(&self.hpthtcl_cptl_measr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("IncmStmt") { one_mut(&mut self.incm_stmt, step, out) }
+		if step.matches("CptlRqrmnts") { one_mut(&mut self.cptl_rqrmnts, step, out) }
+		if step.matches("TtlCptl") { one_mut(&mut self.ttl_cptl, step, out) }
+		if step.matches("LqdFinRsrcs") { one_mut(&mut self.lqd_fin_rsrcs, step, out) }
+		if step.matches("HpthtclCptlMeasr") { repeated_mut(&mut self.hpthtcl_cptl_measr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_060_001_02::CCPDailyCashFlowsReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CncntrtnAgt") { repeated(&self.cncntrtn_agt, step, out) }
+		if step.matches("SttlmAgt") { repeated(&self.sttlm_agt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CncntrtnAgt") { repeated_mut(&mut self.cncntrtn_agt, step, out) }
+		if step.matches("SttlmAgt") { repeated_mut(&mut self.sttlm_agt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_061_001_01::CCPInvestmentsReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Invstmt") { repeated(&self.invstmt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Invstmt") { repeated_mut(&mut self.invstmt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_062_001_01::CCPLiquidityStressTestingDefinitionReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("LqdtyStrssScnroDef") { repeated(&self.lqdty_strss_scnro_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("LqdtyStrssScnroDef") { repeated_mut(&mut self.lqdty_strss_scnro_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_063_001_01::CCPLiquidityStressTestingResultReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("LqdtyStrssTstRslt") { repeated(&self.lqdty_strss_tst_rslt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("LqdtyStrssTstRslt") { repeated_mut(&mut self.lqdty_strss_tst_rslt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_064_001_01::CCPAvailableFinancialResourcesReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AvlblFinRsrcsAmt") { one(&self.avlbl_fin_rsrcs_amt, step, out) }
+		if step.matches("OthrPrfnddRsrcs") { optional(&self.othr_prfndd_rsrcs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AvlblFinRsrcsAmt") { one_mut(&mut self.avlbl_fin_rsrcs_amt, step, out) }
+		if step.matches("OthrPrfnddRsrcs") { optional_mut(&mut self.othr_prfndd_rsrcs, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_065_001_01::CCPBackTestingDefinitionReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Mthdlgy") { repeated(&self.mthdlgy, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Mthdlgy") { repeated_mut(&mut self.mthdlgy, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_066_001_01::CCPBackTestingResultReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MnthlyRslt") { repeated(&self.mnthly_rslt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MnthlyRslt") { repeated_mut(&mut self.mnthly_rslt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_067_001_01::CCPCollateralReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("CollAcctOwnr") { repeated(&self.coll_acct_ownr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("CollAcctOwnr") { repeated_mut(&mut self.coll_acct_ownr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_068_001_01::CCPAccountPositionReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Prtfl") { repeated(&self.prtfl, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Prtfl") { repeated_mut(&mut self.prtfl, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_069_001_01::CCPClearedProductReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ClrdPdct") { repeated(&self.clrd_pdct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ClrdPdct") { repeated_mut(&mut self.clrd_pdct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_070_001_02::SecuritiesFinancingReportingTransactionMarginDataReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_071_001_02::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_072_001_01::SettlementInternaliserReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("SttlmIntlr") { one(&self.sttlm_intlr, step, out) }
+		if step.matches("IssrCSD") { repeated(&self.issr_csd, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("SttlmIntlr") { one_mut(&mut self.sttlm_intlr, step, out) }
+		if step.matches("IssrCSD") { repeated_mut(&mut self.issr_csd, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_076_001_01::FinancialSupervisedPartyIdentityReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PtyData") { repeated(&self.pty_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PtyData") { repeated_mut(&mut self.pty_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_077_001_01::FinancialBenchmarkReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("BchmkData") { repeated(&self.bchmk_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("BchmkData") { repeated_mut(&mut self.bchmk_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_078_001_02::SecuritiesFinancingReportingPairingRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TxId") { repeated(&self.tx_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TxId") { repeated_mut(&mut self.tx_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_079_001_02::SecuritiesFinancingReportingTransactionStateReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_080_001_02::SecuritiesFinancingReportingReconciliationStatusAdviceV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RcncltnData") { one(&self.rcncltn_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RcncltnData") { one_mut(&mut self.rcncltn_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_083_001_02::SecuritiesFinancingReportingMissingCollateralRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TxId") { repeated(&self.tx_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TxId") { repeated_mut(&mut self.tx_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_084_001_02::SecuritiesFinancingReportingTransactionStatusAdviceV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TxRptStsAndRsn") { repeated(&self.tx_rpt_sts_and_rsn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TxRptStsAndRsn") { repeated_mut(&mut self.tx_rpt_sts_and_rsn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_085_001_02::SecuritiesFinancingReportingMarginDataTransactionStateReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_086_001_02::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_090_001_02::DerivativesTradePositionSetReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AggtdPos") { one(&self.aggtd_pos, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AggtdPos") { one_mut(&mut self.aggtd_pos, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_091_001_03::DerivativesTradeReconciliationStatisticalReportV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RcncltnSttstcs") { one(&self.rcncltn_sttstcs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RcncltnSttstcs") { one_mut(&mut self.rcncltn_sttstcs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_092_001_04::DerivativesTradeRejectionStatisticalReportV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RjctnSttstcs") { one(&self.rjctn_sttstcs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RjctnSttstcs") { one_mut(&mut self.rjctn_sttstcs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_094_001_02::SecuritiesFinancingReportingTransactionQueryV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RqstngAuthrty") { one(&self.rqstng_authrty, step, out) }
+		if step.matches("TradQryData") { one(&self.trad_qry_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RqstngAuthrty") { one_mut(&mut self.rqstng_authrty, step, out) }
+		if step.matches("TradQryData") { one_mut(&mut self.trad_qry_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_100_001_01::SettlementFailsMonthlyReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("MnthlyAggt") { one(&self.mnthly_aggt, step, out) }
+		if step.matches("DalyData") { repeated(&self.daly_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("MnthlyAggt") { one_mut(&mut self.mnthly_aggt, step, out) }
+		if step.matches("DalyData") { repeated_mut(&mut self.daly_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_101_001_01::SettlementFailsAnnualReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("AnlAggt") { one(&self.anl_aggt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("AnlAggt") { one_mut(&mut self.anl_aggt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_102_001_01::FinancialInstrumentReportingCancellationReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("CxlData") { repeated(&self.cxl_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("CxlData") { repeated_mut(&mut self.cxl_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_105_001_01::SecuritiesFinancingReportingPositionSetReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("AggtdPoss") { one(&self.aggtd_poss, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("AggtdPoss") { one_mut(&mut self.aggtd_poss, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_106_001_01::DerivativesTradeWarningsReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("WrnngsSttstcs") { one(&self.wrnngs_sttstcs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("WrnngsSttstcs") { one_mut(&mut self.wrnngs_sttstcs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_107_001_02::DerivativesTradeStateReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_108_001_02::DerivativesTradeMarginDataReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_109_001_02::DerivativesTradeMarginDataTransactionStateReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("TradData") { one(&self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("TradData") { one_mut(&mut self.trad_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_112_001_01::CCPInteroperabilityReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("IntrprbltyCCP") { repeated(&self.intrprblty_ccp, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("IntrprbltyCCP") { repeated_mut(&mut self.intrprblty_ccp, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::auth_113_001_01::OrderBookReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("OrdrRpt") { repeated(&self.ordr_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("OrdrRpt") { repeated_mut(&mut self.ordr_rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
@@ -0,0 +1,1579 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over the generated types of this crate, generated by
+// generate-visitor.py. `Visitor` has a `visit_` method per type that
+// walks into its fields by default; override the methods of the types of
+// interest and call the matching `walk_` function to keep going below
+// them. `VisitorMut` does the same with mutable references.
+
+use open_payments_common::visitor::{Visitor as BaseVisitor, VisitorMut as BaseVisitorMut};
+
+pub trait Visitor: BaseVisitor {
+	fn visit_information_request_opening_v02(&mut self, node: &crate::auth_001_001_02::InformationRequestOpeningV02) { walk_information_request_opening_v02(self, node) }
+	fn visit_information_request_response_v02(&mut self, node: &crate::auth_002_001_02::InformationRequestResponseV02) { walk_information_request_response_v02(self, node) }
+	fn visit_information_request_status_change_notification_v01(&mut self, node: &crate::auth_003_001_01::InformationRequestStatusChangeNotificationV01) { walk_information_request_status_change_notification_v01(self, node) }
+	fn visit_money_market_secured_market_statistical_report_v02(&mut self, node: &crate::auth_012_001_02::MoneyMarketSecuredMarketStatisticalReportV02) { walk_money_market_secured_market_statistical_report_v02(self, node) }
+	fn visit_money_market_unsecured_market_statistical_report_v02(&mut self, node: &crate::auth_013_001_02::MoneyMarketUnsecuredMarketStatisticalReportV02) { walk_money_market_unsecured_market_statistical_report_v02(self, node) }
+	fn visit_money_market_foreign_exchange_swaps_statistical_report_v02(&mut self, node: &crate::auth_014_001_02::MoneyMarketForeignExchangeSwapsStatisticalReportV02) { walk_money_market_foreign_exchange_swaps_statistical_report_v02(self, node) }
+	fn visit_money_market_overnight_index_swaps_statistical_report_v02(&mut self, node: &crate::auth_015_001_02::MoneyMarketOvernightIndexSwapsStatisticalReportV02) { walk_money_market_overnight_index_swaps_statistical_report_v02(self, node) }
+	fn visit_financial_instrument_reporting_transaction_report_v03(&mut self, node: &crate::auth_016_001_03::FinancialInstrumentReportingTransactionReportV03) { walk_financial_instrument_reporting_transaction_report_v03(self, node) }
+	fn visit_financial_instrument_reporting_reference_data_report_v02(&mut self, node: &crate::auth_017_001_02::FinancialInstrumentReportingReferenceDataReportV02) { walk_financial_instrument_reporting_reference_data_report_v02(self, node) }
+	fn visit_contract_registration_request_v04(&mut self, node: &crate::auth_018_001_04::ContractRegistrationRequestV04) { walk_contract_registration_request_v04(self, node) }
+	fn visit_contract_registration_confirmation_v04(&mut self, node: &crate::auth_019_001_04::ContractRegistrationConfirmationV04) { walk_contract_registration_confirmation_v04(self, node) }
+	fn visit_contract_registration_closure_request_v04(&mut self, node: &crate::auth_020_001_04::ContractRegistrationClosureRequestV04) { walk_contract_registration_closure_request_v04(self, node) }
+	fn visit_contract_registration_amendment_request_v04(&mut self, node: &crate::auth_021_001_04::ContractRegistrationAmendmentRequestV04) { walk_contract_registration_amendment_request_v04(self, node) }
+	fn visit_contract_registration_statement_v04(&mut self, node: &crate::auth_022_001_04::ContractRegistrationStatementV04) { walk_contract_registration_statement_v04(self, node) }
+	fn visit_contract_registration_statement_request_v04(&mut self, node: &crate::auth_023_001_04::ContractRegistrationStatementRequestV04) { walk_contract_registration_statement_request_v04(self, node) }
+	fn visit_payment_regulatory_information_notification_v04(&mut self, node: &crate::auth_024_001_04::PaymentRegulatoryInformationNotificationV04) { walk_payment_regulatory_information_notification_v04(self, node) }
+	fn visit_currency_control_supporting_document_delivery_v04(&mut self, node: &crate::auth_025_001_04::CurrencyControlSupportingDocumentDeliveryV04) { walk_currency_control_supporting_document_delivery_v04(self, node) }
+	fn visit_currency_control_request_or_letter_v04(&mut self, node: &crate::auth_026_001_04::CurrencyControlRequestOrLetterV04) { walk_currency_control_request_or_letter_v04(self, node) }
+	fn visit_currency_control_status_advice_v04(&mut self, node: &crate::auth_027_001_04::CurrencyControlStatusAdviceV04) { walk_currency_control_status_advice_v04(self, node) }
+	fn visit_money_market_statistical_report_status_advice_v01(&mut self, node: &crate::auth_028_001_01::MoneyMarketStatisticalReportStatusAdviceV01) { walk_money_market_statistical_report_status_advice_v01(self, node) }
+	fn visit_derivatives_trade_report_query_v05(&mut self, node: &crate::auth_029_001_05::DerivativesTradeReportQueryV05) { walk_derivatives_trade_report_query_v05(self, node) }
+	fn visit_derivatives_trade_report_v04(&mut self, node: &crate::auth_030_001_04::DerivativesTradeReportV04) { walk_derivatives_trade_report_v04(self, node) }
+	fn visit_financial_instrument_reporting_status_advice_v01(&mut self, node: &crate::auth_031_001_01::FinancialInstrumentReportingStatusAdviceV01) { walk_financial_instrument_reporting_status_advice_v01(self, node) }
+	fn visit_financial_instrument_reporting_equity_transparency_data_report_v01(&mut self, node: &crate::auth_032_001_01::FinancialInstrumentReportingEquityTransparencyDataReportV01) { walk_financial_instrument_reporting_equity_transparency_data_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_non_equity_transparency_data_report_v03(&mut self, node: &crate::auth_033_001_03::FinancialInstrumentReportingNonEquityTransparencyDataReportV03) { walk_financial_instrument_reporting_non_equity_transparency_data_report_v03(self, node) }
+	fn visit_invoice_tax_report_v01(&mut self, node: &crate::auth_034_001_01::InvoiceTaxReportV01) { walk_invoice_tax_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_trading_volume_cap_data_report_v01(&mut self, node: &crate::auth_035_001_01::FinancialInstrumentReportingTradingVolumeCapDataReportV01) { walk_financial_instrument_reporting_trading_volume_cap_data_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_reference_data_delta_report_v03(&mut self, node: &crate::auth_036_001_03::FinancialInstrumentReportingReferenceDataDeltaReportV03) { walk_financial_instrument_reporting_reference_data_delta_report_v03(self, node) }
+	fn visit_invoice_tax_report_status_advice_v01(&mut self, node: &crate::auth_038_001_01::InvoiceTaxReportStatusAdviceV01) { walk_invoice_tax_report_status_advice_v01(self, node) }
+	fn visit_financial_instrument_reporting_non_working_day_report_v01(&mut self, node: &crate::auth_039_001_01::FinancialInstrumentReportingNonWorkingDayReportV01) { walk_financial_instrument_reporting_non_working_day_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_equity_trading_activity_report_v01(&mut self, node: &crate::auth_040_001_01::FinancialInstrumentReportingEquityTradingActivityReportV01) { walk_financial_instrument_reporting_equity_trading_activity_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_non_equity_trading_activity_report_v01(&mut self, node: &crate::auth_041_001_01::FinancialInstrumentReportingNonEquityTradingActivityReportV01) { walk_financial_instrument_reporting_non_equity_trading_activity_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_invalid_reference_data_report_v02(&mut self, node: &crate::auth_042_001_02::FinancialInstrumentReportingInvalidReferenceDataReportV02) { walk_financial_instrument_reporting_invalid_reference_data_report_v02(self, node) }
+	fn visit_financial_instrument_reporting_reference_data_index_report_v01(&mut self, node: &crate::auth_043_001_01::FinancialInstrumentReportingReferenceDataIndexReportV01) { walk_financial_instrument_reporting_reference_data_index_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_equity_trading_activity_result_v02(&mut self, node: &crate::auth_044_001_02::FinancialInstrumentReportingEquityTradingActivityResultV02) { walk_financial_instrument_reporting_equity_trading_activity_result_v02(self, node) }
+	fn visit_financial_instrument_reporting_non_equity_trading_activity_result_v03(&mut self, node: &crate::auth_045_001_03::FinancialInstrumentReportingNonEquityTradingActivityResultV03) { walk_financial_instrument_reporting_non_equity_trading_activity_result_v03(self, node) }
+	fn visit_financial_instrument_reporting_country_code_report_v01(&mut self, node: &crate::auth_047_001_01::FinancialInstrumentReportingCountryCodeReportV01) { walk_financial_instrument_reporting_country_code_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_currency_code_report_v01(&mut self, node: &crate::auth_048_001_01::FinancialInstrumentReportingCurrencyCodeReportV01) { walk_financial_instrument_reporting_currency_code_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_market_identification_code_report_v02(&mut self, node: &crate::auth_049_001_02::FinancialInstrumentReportingMarketIdentificationCodeReportV02) { walk_financial_instrument_reporting_market_identification_code_report_v02(self, node) }
+	fn visit_financial_instrument_reporting_instrument_classification_report_v01(&mut self, node: &crate::auth_050_001_01::FinancialInstrumentReportingInstrumentClassificationReportV01) { walk_financial_instrument_reporting_instrument_classification_report_v01(self, node) }
+	fn visit_securities_financing_reporting_transaction_report_v02(&mut self, node: &crate::auth_052_001_02::SecuritiesFinancingReportingTransactionReportV02) { walk_securities_financing_reporting_transaction_report_v02(self, node) }
+	fn visit_financial_instrument_reporting_trading_volume_cap_result_report_v01(&mut self, node: &crate::auth_053_001_01::FinancialInstrumentReportingTradingVolumeCapResultReportV01) { walk_financial_instrument_reporting_trading_volume_cap_result_report_v01(self, node) }
+	fn visit_ccp_clearing_member_report_v01(&mut self, node: &crate::auth_054_001_01::CCPClearingMemberReportV01) { walk_ccp_clearing_member_report_v01(self, node) }
+	fn visit_ccp_member_requirements_report_v01(&mut self, node: &crate::auth_055_001_01::CCPMemberRequirementsReportV01) { walk_ccp_member_requirements_report_v01(self, node) }
+	fn visit_ccp_member_obligations_report_v01(&mut self, node: &crate::auth_056_001_01::CCPMemberObligationsReportV01) { walk_ccp_member_obligations_report_v01(self, node) }
+	fn visit_ccp_portfolio_stress_testing_definition_report_v02(&mut self, node: &crate::auth_057_001_02::CCPPortfolioStressTestingDefinitionReportV02) { walk_ccp_portfolio_stress_testing_definition_report_v02(self, node) }
+	fn visit_ccp_portfolio_stress_testing_result_report_v01(&mut self, node: &crate::auth_058_001_01::CCPPortfolioStressTestingResultReportV01) { walk_ccp_portfolio_stress_testing_result_report_v01(self, node) }
+	fn visit_ccp_income_statement_and_capital_adequacy_report_v01(&mut self, node: &crate::auth_059_001_01::CCPIncomeStatementAndCapitalAdequacyReportV01) { walk_ccp_income_statement_and_capital_adequacy_report_v01(self, node) }
+	fn visit_ccp_daily_cash_flows_report_v02(&mut self, node: &crate::auth_060_001_02::CCPDailyCashFlowsReportV02) { walk_ccp_daily_cash_flows_report_v02(self, node) }
+	fn visit_ccp_investments_report_v01(&mut self, node: &crate::auth_061_001_01::CCPInvestmentsReportV01) { walk_ccp_investments_report_v01(self, node) }
+	fn visit_ccp_liquidity_stress_testing_definition_report_v01(&mut self, node: &crate::auth_062_001_01::CCPLiquidityStressTestingDefinitionReportV01) { walk_ccp_liquidity_stress_testing_definition_report_v01(self, node) }
+	fn visit_ccp_liquidity_stress_testing_result_report_v01(&mut self, node: &crate::auth_063_001_01::CCPLiquidityStressTestingResultReportV01) { walk_ccp_liquidity_stress_testing_result_report_v01(self, node) }
+	fn visit_ccp_available_financial_resources_report_v01(&mut self, node: &crate::auth_064_001_01::CCPAvailableFinancialResourcesReportV01) { walk_ccp_available_financial_resources_report_v01(self, node) }
+	fn visit_ccp_back_testing_definition_report_v01(&mut self, node: &crate::auth_065_001_01::CCPBackTestingDefinitionReportV01) { walk_ccp_back_testing_definition_report_v01(self, node) }
+	fn visit_ccp_back_testing_result_report_v01(&mut self, node: &crate::auth_066_001_01::CCPBackTestingResultReportV01) { walk_ccp_back_testing_result_report_v01(self, node) }
+	fn visit_ccp_collateral_report_v01(&mut self, node: &crate::auth_067_001_01::CCPCollateralReportV01) { walk_ccp_collateral_report_v01(self, node) }
+	fn visit_ccp_account_position_report_v01(&mut self, node: &crate::auth_068_001_01::CCPAccountPositionReportV01) { walk_ccp_account_position_report_v01(self, node) }
+	fn visit_ccp_cleared_product_report_v01(&mut self, node: &crate::auth_069_001_01::CCPClearedProductReportV01) { walk_ccp_cleared_product_report_v01(self, node) }
+	fn visit_securities_financing_reporting_transaction_margin_data_report_v02(&mut self, node: &crate::auth_070_001_02::SecuritiesFinancingReportingTransactionMarginDataReportV02) { walk_securities_financing_reporting_transaction_margin_data_report_v02(self, node) }
+	fn visit_securities_financing_reporting_transaction_reused_collateral_data_report_v02(&mut self, node: &crate::auth_071_001_02::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02) { walk_securities_financing_reporting_transaction_reused_collateral_data_report_v02(self, node) }
+	fn visit_settlement_internaliser_report_v01(&mut self, node: &crate::auth_072_001_01::SettlementInternaliserReportV01) { walk_settlement_internaliser_report_v01(self, node) }
+	fn visit_financial_supervised_party_identity_report_v01(&mut self, node: &crate::auth_076_001_01::FinancialSupervisedPartyIdentityReportV01) { walk_financial_supervised_party_identity_report_v01(self, node) }
+	fn visit_financial_benchmark_report_v01(&mut self, node: &crate::auth_077_001_01::FinancialBenchmarkReportV01) { walk_financial_benchmark_report_v01(self, node) }
+	fn visit_securities_financing_reporting_pairing_request_v02(&mut self, node: &crate::auth_078_001_02::SecuritiesFinancingReportingPairingRequestV02) { walk_securities_financing_reporting_pairing_request_v02(self, node) }
+	fn visit_securities_financing_reporting_transaction_state_report_v02(&mut self, node: &crate::auth_079_001_02::SecuritiesFinancingReportingTransactionStateReportV02) { walk_securities_financing_reporting_transaction_state_report_v02(self, node) }
+	fn visit_securities_financing_reporting_reconciliation_status_advice_v02(&mut self, node: &crate::auth_080_001_02::SecuritiesFinancingReportingReconciliationStatusAdviceV02) { walk_securities_financing_reporting_reconciliation_status_advice_v02(self, node) }
+	fn visit_securities_financing_reporting_missing_collateral_request_v02(&mut self, node: &crate::auth_083_001_02::SecuritiesFinancingReportingMissingCollateralRequestV02) { walk_securities_financing_reporting_missing_collateral_request_v02(self, node) }
+	fn visit_securities_financing_reporting_transaction_status_advice_v02(&mut self, node: &crate::auth_084_001_02::SecuritiesFinancingReportingTransactionStatusAdviceV02) { walk_securities_financing_reporting_transaction_status_advice_v02(self, node) }
+	fn visit_securities_financing_reporting_margin_data_transaction_state_report_v02(&mut self, node: &crate::auth_085_001_02::SecuritiesFinancingReportingMarginDataTransactionStateReportV02) { walk_securities_financing_reporting_margin_data_transaction_state_report_v02(self, node) }
+	fn visit_securities_financing_reporting_reused_collateral_data_transaction_state_report_v02(&mut self, node: &crate::auth_086_001_02::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02) { walk_securities_financing_reporting_reused_collateral_data_transaction_state_report_v02(self, node) }
+	fn visit_derivatives_trade_position_set_report_v02(&mut self, node: &crate::auth_090_001_02::DerivativesTradePositionSetReportV02) { walk_derivatives_trade_position_set_report_v02(self, node) }
+	fn visit_derivatives_trade_reconciliation_statistical_report_v03(&mut self, node: &crate::auth_091_001_03::DerivativesTradeReconciliationStatisticalReportV03) { walk_derivatives_trade_reconciliation_statistical_report_v03(self, node) }
+	fn visit_derivatives_trade_rejection_statistical_report_v04(&mut self, node: &crate::auth_092_001_04::DerivativesTradeRejectionStatisticalReportV04) { walk_derivatives_trade_rejection_statistical_report_v04(self, node) }
+	fn visit_securities_financing_reporting_transaction_query_v02(&mut self, node: &crate::auth_094_001_02::SecuritiesFinancingReportingTransactionQueryV02) { walk_securities_financing_reporting_transaction_query_v02(self, node) }
+	fn visit_settlement_fails_monthly_report_v01(&mut self, node: &crate::auth_100_001_01::SettlementFailsMonthlyReportV01) { walk_settlement_fails_monthly_report_v01(self, node) }
+	fn visit_settlement_fails_annual_report_v01(&mut self, node: &crate::auth_101_001_01::SettlementFailsAnnualReportV01) { walk_settlement_fails_annual_report_v01(self, node) }
+	fn visit_financial_instrument_reporting_cancellation_report_v01(&mut self, node: &crate::auth_102_001_01::FinancialInstrumentReportingCancellationReportV01) { walk_financial_instrument_reporting_cancellation_report_v01(self, node) }
+	fn visit_securities_financing_reporting_position_set_report_v01(&mut self, node: &crate::auth_105_001_01::SecuritiesFinancingReportingPositionSetReportV01) { walk_securities_financing_reporting_position_set_report_v01(self, node) }
+	fn visit_derivatives_trade_warnings_report_v01(&mut self, node: &crate::auth_106_001_01::DerivativesTradeWarningsReportV01) { walk_derivatives_trade_warnings_report_v01(self, node) }
+	fn visit_derivatives_trade_state_report_v02(&mut self, node: &crate::auth_107_001_02::DerivativesTradeStateReportV02) { walk_derivatives_trade_state_report_v02(self, node) }
+	fn visit_derivatives_trade_margin_data_report_v02(&mut self, node: &crate::auth_108_001_02::DerivativesTradeMarginDataReportV02) { walk_derivatives_trade_margin_data_report_v02(self, node) }
+	fn visit_derivatives_trade_margin_data_transaction_state_report_v02(&mut self, node: &crate::auth_109_001_02::DerivativesTradeMarginDataTransactionStateReportV02) { walk_derivatives_trade_margin_data_transaction_state_report_v02(self, node) }
+	fn visit_ccp_interoperability_report_v01(&mut self, node: &crate::auth_112_001_01::CCPInteroperabilityReportV01) { walk_ccp_interoperability_report_v01(self, node) }
+	fn visit_order_book_report_v01(&mut self, node: &crate::auth_113_001_01::OrderBookReportV01) { walk_order_book_report_v01(self, node) }
+}
+
+pub trait VisitorMut: BaseVisitorMut {
+	fn visit_information_request_opening_v02_mut(&mut self, node: &mut crate::auth_001_001_02::InformationRequestOpeningV02) { walk_information_request_opening_v02_mut(self, node) }
+	fn visit_information_request_response_v02_mut(&mut self, node: &mut crate::auth_002_001_02::InformationRequestResponseV02) { walk_information_request_response_v02_mut(self, node) }
+	fn visit_information_request_status_change_notification_v01_mut(&mut self, node: &mut crate::auth_003_001_01::InformationRequestStatusChangeNotificationV01) { walk_information_request_status_change_notification_v01_mut(self, node) }
+	fn visit_money_market_secured_market_statistical_report_v02_mut(&mut self, node: &mut crate::auth_012_001_02::MoneyMarketSecuredMarketStatisticalReportV02) { walk_money_market_secured_market_statistical_report_v02_mut(self, node) }
+	fn visit_money_market_unsecured_market_statistical_report_v02_mut(&mut self, node: &mut crate::auth_013_001_02::MoneyMarketUnsecuredMarketStatisticalReportV02) { walk_money_market_unsecured_market_statistical_report_v02_mut(self, node) }
+	fn visit_money_market_foreign_exchange_swaps_statistical_report_v02_mut(&mut self, node: &mut crate::auth_014_001_02::MoneyMarketForeignExchangeSwapsStatisticalReportV02) { walk_money_market_foreign_exchange_swaps_statistical_report_v02_mut(self, node) }
+	fn visit_money_market_overnight_index_swaps_statistical_report_v02_mut(&mut self, node: &mut crate::auth_015_001_02::MoneyMarketOvernightIndexSwapsStatisticalReportV02) { walk_money_market_overnight_index_swaps_statistical_report_v02_mut(self, node) }
+	fn visit_financial_instrument_reporting_transaction_report_v03_mut(&mut self, node: &mut crate::auth_016_001_03::FinancialInstrumentReportingTransactionReportV03) { walk_financial_instrument_reporting_transaction_report_v03_mut(self, node) }
+	fn visit_financial_instrument_reporting_reference_data_report_v02_mut(&mut self, node: &mut crate::auth_017_001_02::FinancialInstrumentReportingReferenceDataReportV02) { walk_financial_instrument_reporting_reference_data_report_v02_mut(self, node) }
+	fn visit_contract_registration_request_v04_mut(&mut self, node: &mut crate::auth_018_001_04::ContractRegistrationRequestV04) { walk_contract_registration_request_v04_mut(self, node) }
+	fn visit_contract_registration_confirmation_v04_mut(&mut self, node: &mut crate::auth_019_001_04::ContractRegistrationConfirmationV04) { walk_contract_registration_confirmation_v04_mut(self, node) }
+	fn visit_contract_registration_closure_request_v04_mut(&mut self, node: &mut crate::auth_020_001_04::ContractRegistrationClosureRequestV04) { walk_contract_registration_closure_request_v04_mut(self, node) }
+	fn visit_contract_registration_amendment_request_v04_mut(&mut self, node: &mut crate::auth_021_001_04::ContractRegistrationAmendmentRequestV04) { walk_contract_registration_amendment_request_v04_mut(self, node) }
+	fn visit_contract_registration_statement_v04_mut(&mut self, node: &mut crate::auth_022_001_04::ContractRegistrationStatementV04) { walk_contract_registration_statement_v04_mut(self, node) }
+	fn visit_contract_registration_statement_request_v04_mut(&mut self, node: &mut crate::auth_023_001_04::ContractRegistrationStatementRequestV04) { walk_contract_registration_statement_request_v04_mut(self, node) }
+	fn visit_payment_regulatory_information_notification_v04_mut(&mut self, node: &mut crate::auth_024_001_04::PaymentRegulatoryInformationNotificationV04) { walk_payment_regulatory_information_notification_v04_mut(self, node) }
+	fn visit_currency_control_supporting_document_delivery_v04_mut(&mut self, node: &mut crate::auth_025_001_04::CurrencyControlSupportingDocumentDeliveryV04) { walk_currency_control_supporting_document_delivery_v04_mut(self, node) }
+	fn visit_currency_control_request_or_letter_v04_mut(&mut self, node: &mut crate::auth_026_001_04::CurrencyControlRequestOrLetterV04) { walk_currency_control_request_or_letter_v04_mut(self, node) }
+	fn visit_currency_control_status_advice_v04_mut(&mut self, node: &mut crate::auth_027_001_04::CurrencyControlStatusAdviceV04) { walk_currency_control_status_advice_v04_mut(self, node) }
+	fn visit_money_market_statistical_report_status_advice_v01_mut(&mut self, node: &mut crate::auth_028_001_01::MoneyMarketStatisticalReportStatusAdviceV01) { walk_money_market_statistical_report_status_advice_v01_mut(self, node) }
+	fn visit_derivatives_trade_report_query_v05_mut(&mut self, node: &mut crate::auth_029_001_05::DerivativesTradeReportQueryV05) { walk_derivatives_trade_report_query_v05_mut(self, node) }
+	fn visit_derivatives_trade_report_v04_mut(&mut self, node: &mut crate::auth_030_001_04::DerivativesTradeReportV04) { walk_derivatives_trade_report_v04_mut(self, node) }
+	fn visit_financial_instrument_reporting_status_advice_v01_mut(&mut self, node: &mut crate::auth_031_001_01::FinancialInstrumentReportingStatusAdviceV01) { walk_financial_instrument_reporting_status_advice_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_equity_transparency_data_report_v01_mut(&mut self, node: &mut crate::auth_032_001_01::FinancialInstrumentReportingEquityTransparencyDataReportV01) { walk_financial_instrument_reporting_equity_transparency_data_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_non_equity_transparency_data_report_v03_mut(&mut self, node: &mut crate::auth_033_001_03::FinancialInstrumentReportingNonEquityTransparencyDataReportV03) { walk_financial_instrument_reporting_non_equity_transparency_data_report_v03_mut(self, node) }
+	fn visit_invoice_tax_report_v01_mut(&mut self, node: &mut crate::auth_034_001_01::InvoiceTaxReportV01) { walk_invoice_tax_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_trading_volume_cap_data_report_v01_mut(&mut self, node: &mut crate::auth_035_001_01::FinancialInstrumentReportingTradingVolumeCapDataReportV01) { walk_financial_instrument_reporting_trading_volume_cap_data_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_reference_data_delta_report_v03_mut(&mut self, node: &mut crate::auth_036_001_03::FinancialInstrumentReportingReferenceDataDeltaReportV03) { walk_financial_instrument_reporting_reference_data_delta_report_v03_mut(self, node) }
+	fn visit_invoice_tax_report_status_advice_v01_mut(&mut self, node: &mut crate::auth_038_001_01::InvoiceTaxReportStatusAdviceV01) { walk_invoice_tax_report_status_advice_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_non_working_day_report_v01_mut(&mut self, node: &mut crate::auth_039_001_01::FinancialInstrumentReportingNonWorkingDayReportV01) { walk_financial_instrument_reporting_non_working_day_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_equity_trading_activity_report_v01_mut(&mut self, node: &mut crate::auth_040_001_01::FinancialInstrumentReportingEquityTradingActivityReportV01) { walk_financial_instrument_reporting_equity_trading_activity_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_non_equity_trading_activity_report_v01_mut(&mut self, node: &mut crate::auth_041_001_01::FinancialInstrumentReportingNonEquityTradingActivityReportV01) { walk_financial_instrument_reporting_non_equity_trading_activity_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_invalid_reference_data_report_v02_mut(&mut self, node: &mut crate::auth_042_001_02::FinancialInstrumentReportingInvalidReferenceDataReportV02) { walk_financial_instrument_reporting_invalid_reference_data_report_v02_mut(self, node) }
+	fn visit_financial_instrument_reporting_reference_data_index_report_v01_mut(&mut self, node: &mut crate::auth_043_001_01::FinancialInstrumentReportingReferenceDataIndexReportV01) { walk_financial_instrument_reporting_reference_data_index_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_equity_trading_activity_result_v02_mut(&mut self, node: &mut crate::auth_044_001_02::FinancialInstrumentReportingEquityTradingActivityResultV02) { walk_financial_instrument_reporting_equity_trading_activity_result_v02_mut(self, node) }
+	fn visit_financial_instrument_reporting_non_equity_trading_activity_result_v03_mut(&mut self, node: &mut crate::auth_045_001_03::FinancialInstrumentReportingNonEquityTradingActivityResultV03) { walk_financial_instrument_reporting_non_equity_trading_activity_result_v03_mut(self, node) }
+	fn visit_financial_instrument_reporting_country_code_report_v01_mut(&mut self, node: &mut crate::auth_047_001_01::FinancialInstrumentReportingCountryCodeReportV01) { walk_financial_instrument_reporting_country_code_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_currency_code_report_v01_mut(&mut self, node: &mut crate::auth_048_001_01::FinancialInstrumentReportingCurrencyCodeReportV01) { walk_financial_instrument_reporting_currency_code_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_market_identification_code_report_v02_mut(&mut self, node: &mut crate::auth_049_001_02::FinancialInstrumentReportingMarketIdentificationCodeReportV02) { walk_financial_instrument_reporting_market_identification_code_report_v02_mut(self, node) }
+	fn visit_financial_instrument_reporting_instrument_classification_report_v01_mut(&mut self, node: &mut crate::auth_050_001_01::FinancialInstrumentReportingInstrumentClassificationReportV01) { walk_financial_instrument_reporting_instrument_classification_report_v01_mut(self, node) }
+	fn visit_securities_financing_reporting_transaction_report_v02_mut(&mut self, node: &mut crate::auth_052_001_02::SecuritiesFinancingReportingTransactionReportV02) { walk_securities_financing_reporting_transaction_report_v02_mut(self, node) }
+	fn visit_financial_instrument_reporting_trading_volume_cap_result_report_v01_mut(&mut self, node: &mut crate::auth_053_001_01::FinancialInstrumentReportingTradingVolumeCapResultReportV01) { walk_financial_instrument_reporting_trading_volume_cap_result_report_v01_mut(self, node) }
+	fn visit_ccp_clearing_member_report_v01_mut(&mut self, node: &mut crate::auth_054_001_01::CCPClearingMemberReportV01) { walk_ccp_clearing_member_report_v01_mut(self, node) }
+	fn visit_ccp_member_requirements_report_v01_mut(&mut self, node: &mut crate::auth_055_001_01::CCPMemberRequirementsReportV01) { walk_ccp_member_requirements_report_v01_mut(self, node) }
+	fn visit_ccp_member_obligations_report_v01_mut(&mut self, node: &mut crate::auth_056_001_01::CCPMemberObligationsReportV01) { walk_ccp_member_obligations_report_v01_mut(self, node) }
+	fn visit_ccp_portfolio_stress_testing_definition_report_v02_mut(&mut self, node: &mut crate::auth_057_001_02::CCPPortfolioStressTestingDefinitionReportV02) { walk_ccp_portfolio_stress_testing_definition_report_v02_mut(self, node) }
+	fn visit_ccp_portfolio_stress_testing_result_report_v01_mut(&mut self, node: &mut crate::auth_058_001_01::CCPPortfolioStressTestingResultReportV01) { walk_ccp_portfolio_stress_testing_result_report_v01_mut(self, node) }
+	fn visit_ccp_income_statement_and_capital_adequacy_report_v01_mut(&mut self, node: &mut crate::auth_059_001_01::CCPIncomeStatementAndCapitalAdequacyReportV01) { walk_ccp_income_statement_and_capital_adequacy_report_v01_mut(self, node) }
+	fn visit_ccp_daily_cash_flows_report_v02_mut(&mut self, node: &mut crate::auth_060_001_02::CCPDailyCashFlowsReportV02) { walk_ccp_daily_cash_flows_report_v02_mut(self, node) }
+	fn visit_ccp_investments_report_v01_mut(&mut self, node: &mut crate::auth_061_001_01::CCPInvestmentsReportV01) { walk_ccp_investments_report_v01_mut(self, node) }
+	fn visit_ccp_liquidity_stress_testing_definition_report_v01_mut(&mut self, node: &mut crate::auth_062_001_01::CCPLiquidityStressTestingDefinitionReportV01) { walk_ccp_liquidity_stress_testing_definition_report_v01_mut(self, node) }
+	fn visit_ccp_liquidity_stress_testing_result_report_v01_mut(&mut self, node: &mut crate::auth_063_001_01::CCPLiquidityStressTestingResultReportV01) { walk_ccp_liquidity_stress_testing_result_report_v01_mut(self, node) }
+	fn visit_ccp_available_financial_resources_report_v01_mut(&mut self, node: &mut crate::auth_064_001_01::CCPAvailableFinancialResourcesReportV01) { walk_ccp_available_financial_resources_report_v01_mut(self, node) }
+	fn visit_ccp_back_testing_definition_report_v01_mut(&mut self, node: &mut crate::auth_065_001_01::CCPBackTestingDefinitionReportV01) { walk_ccp_back_testing_definition_report_v01_mut(self, node) }
+	fn visit_ccp_back_testing_result_report_v01_mut(&mut self, node: &mut crate::auth_066_001_01::CCPBackTestingResultReportV01) { walk_ccp_back_testing_result_report_v01_mut(self, node) }
+	fn visit_ccp_collateral_report_v01_mut(&mut self, node: &mut crate::auth_067_001_01::CCPCollateralReportV01) { walk_ccp_collateral_report_v01_mut(self, node) }
+	fn visit_ccp_account_position_report_v01_mut(&mut self, node: &mut crate::auth_068_001_01::CCPAccountPositionReportV01) { walk_ccp_account_position_report_v01_mut(self, node) }
+	fn visit_ccp_cleared_product_report_v01_mut(&mut self, node: &mut crate::auth_069_001_01::CCPClearedProductReportV01) { walk_ccp_cleared_product_report_v01_mut(self, node) }
+	fn visit_securities_financing_reporting_transaction_margin_data_report_v02_mut(&mut self, node: &mut crate::auth_070_001_02::SecuritiesFinancingReportingTransactionMarginDataReportV02) { walk_securities_financing_reporting_transaction_margin_data_report_v02_mut(self, node) }
+	fn visit_securities_financing_reporting_transaction_reused_collateral_data_report_v02_mut(&mut self, node: &mut crate::auth_071_001_02::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02) { walk_securities_financing_reporting_transaction_reused_collateral_data_report_v02_mut(self, node) }
+	fn visit_settlement_internaliser_report_v01_mut(&mut self, node: &mut crate::auth_072_001_01::SettlementInternaliserReportV01) { walk_settlement_internaliser_report_v01_mut(self, node) }
+	fn visit_financial_supervised_party_identity_report_v01_mut(&mut self, node: &mut crate::auth_076_001_01::FinancialSupervisedPartyIdentityReportV01) { walk_financial_supervised_party_identity_report_v01_mut(self, node) }
+	fn visit_financial_benchmark_report_v01_mut(&mut self, node: &mut crate::auth_077_001_01::FinancialBenchmarkReportV01) { walk_financial_benchmark_report_v01_mut(self, node) }
+	fn visit_securities_financing_reporting_pairing_request_v02_mut(&mut self, node: &mut crate::auth_078_001_02::SecuritiesFinancingReportingPairingRequestV02) { walk_securities_financing_reporting_pairing_request_v02_mut(self, node) }
+	fn visit_securities_financing_reporting_transaction_state_report_v02_mut(&mut self, node: &mut crate::auth_079_001_02::SecuritiesFinancingReportingTransactionStateReportV02) { walk_securities_financing_reporting_transaction_state_report_v02_mut(self, node) }
+	fn visit_securities_financing_reporting_reconciliation_status_advice_v02_mut(&mut self, node: &mut crate::auth_080_001_02::SecuritiesFinancingReportingReconciliationStatusAdviceV02) { walk_securities_financing_reporting_reconciliation_status_advice_v02_mut(self, node) }
+	fn visit_securities_financing_reporting_missing_collateral_request_v02_mut(&mut self, node: &mut crate::auth_083_001_02::SecuritiesFinancingReportingMissingCollateralRequestV02) { walk_securities_financing_reporting_missing_collateral_request_v02_mut(self, node) }
+	fn visit_securities_financing_reporting_transaction_status_advice_v02_mut(&mut self, node: &mut crate::auth_084_001_02::SecuritiesFinancingReportingTransactionStatusAdviceV02) { walk_securities_financing_reporting_transaction_status_advice_v02_mut(self, node) }
+	fn visit_securities_financing_reporting_margin_data_transaction_state_report_v02_mut(&mut self, node: &mut crate::auth_085_001_02::SecuritiesFinancingReportingMarginDataTransactionStateReportV02) { walk_securities_financing_reporting_margin_data_transaction_state_report_v02_mut(self, node) }
+	fn visit_securities_financing_reporting_reused_collateral_data_transaction_state_report_v02_mut(&mut self, node: &mut crate::auth_086_001_02::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02) { walk_securities_financing_reporting_reused_collateral_data_transaction_state_report_v02_mut(self, node) }
+	fn visit_derivatives_trade_position_set_report_v02_mut(&mut self, node: &mut crate::auth_090_001_02::DerivativesTradePositionSetReportV02) { walk_derivatives_trade_position_set_report_v02_mut(self, node) }
+	fn visit_derivatives_trade_reconciliation_statistical_report_v03_mut(&mut self, node: &mut crate::auth_091_001_03::DerivativesTradeReconciliationStatisticalReportV03) { walk_derivatives_trade_reconciliation_statistical_report_v03_mut(self, node) }
+	fn visit_derivatives_trade_rejection_statistical_report_v04_mut(&mut self, node: &mut crate::auth_092_001_04::DerivativesTradeRejectionStatisticalReportV04) { walk_derivatives_trade_rejection_statistical_report_v04_mut(self, node) }
+	fn visit_securities_financing_reporting_transaction_query_v02_mut(&mut self, node: &mut crate::auth_094_001_02::SecuritiesFinancingReportingTransactionQueryV02) { walk_securities_financing_reporting_transaction_query_v02_mut(self, node) }
+	fn visit_settlement_fails_monthly_report_v01_mut(&mut self, node: &mut crate::auth_100_001_01::SettlementFailsMonthlyReportV01) { walk_settlement_fails_monthly_report_v01_mut(self, node) }
+	fn visit_settlement_fails_annual_report_v01_mut(&mut self, node: &mut crate::auth_101_001_01::SettlementFailsAnnualReportV01) { walk_settlement_fails_annual_report_v01_mut(self, node) }
+	fn visit_financial_instrument_reporting_cancellation_report_v01_mut(&mut self, node: &mut crate::auth_102_001_01::FinancialInstrumentReportingCancellationReportV01) { walk_financial_instrument_reporting_cancellation_report_v01_mut(self, node) }
+	fn visit_securities_financing_reporting_position_set_report_v01_mut(&mut self, node: &mut crate::auth_105_001_01::SecuritiesFinancingReportingPositionSetReportV01) { walk_securities_financing_reporting_position_set_report_v01_mut(self, node) }
+	fn visit_derivatives_trade_warnings_report_v01_mut(&mut self, node: &mut crate::auth_106_001_01::DerivativesTradeWarningsReportV01) { walk_derivatives_trade_warnings_report_v01_mut(self, node) }
+	fn visit_derivatives_trade_state_report_v02_mut(&mut self, node: &mut crate::auth_107_001_02::DerivativesTradeStateReportV02) { walk_derivatives_trade_state_report_v02_mut(self, node) }
+	fn visit_derivatives_trade_margin_data_report_v02_mut(&mut self, node: &mut crate::auth_108_001_02::DerivativesTradeMarginDataReportV02) { walk_derivatives_trade_margin_data_report_v02_mut(self, node) }
+	fn visit_derivatives_trade_margin_data_transaction_state_report_v02_mut(&mut self, node: &mut crate::auth_109_001_02::DerivativesTradeMarginDataTransactionStateReportV02) { walk_derivatives_trade_margin_data_transaction_state_report_v02_mut(self, node) }
+	fn visit_ccp_interoperability_report_v01_mut(&mut self, node: &mut crate::auth_112_001_01::CCPInteroperabilityReportV01) { walk_ccp_interoperability_report_v01_mut(self, node) }
+	fn visit_order_book_report_v01_mut(&mut self, node: &mut crate::auth_113_001_01::OrderBookReportV01) { walk_order_book_report_v01_mut(self, node) }
+}
+
+pub fn walk_information_request_opening_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_001_001_02::InformationRequestOpeningV02) {
+	visitor.visit_legal_mandate1(&node.lgl_mndt_bsis);
+	if let Some(ref value) = node.due_dt { visitor.visit_due_date1(value) }
+	visitor.visit_date_or_date_time_period1_choice(&node.invstgtn_prd);
+	visitor.visit_search_criteria2_choice(&node.sch_crit);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_information_request_opening_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_001_001_02::InformationRequestOpeningV02) {
+	visitor.visit_legal_mandate1_mut(&mut node.lgl_mndt_bsis);
+	if let Some(ref mut value) = node.due_dt { visitor.visit_due_date1_mut(value) }
+	visitor.visit_date_or_date_time_period1_choice_mut(&mut node.invstgtn_prd);
+	visitor.visit_search_criteria2_choice_mut(&mut node.sch_crit);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_information_request_response_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_002_001_02::InformationRequestResponseV02) {
+	visitor.visit_status_response1_code(&node.rspn_sts);
+	visitor.visit_search_criteria2_choice(&node.sch_crit);
+	for item in &node.rtr_ind { visitor.visit_return_indicator2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_information_request_response_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_002_001_02::InformationRequestResponseV02) {
+	visitor.visit_status_response1_code_mut(&mut node.rspn_sts);
+	visitor.visit_search_criteria2_choice_mut(&mut node.sch_crit);
+	for item in &mut node.rtr_ind { visitor.visit_return_indicator2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_information_request_status_change_notification_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_003_001_01::InformationRequestStatusChangeNotificationV01) {
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_information_request_status_change_notification_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_003_001_01::InformationRequestStatusChangeNotificationV01) {
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_money_market_secured_market_statistical_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_012_001_02::MoneyMarketSecuredMarketStatisticalReportV02) {
+	visitor.visit_money_market_report_header1(&node.rpt_hdr);
+	visitor.visit_secured_market_report4_choice(&node.scrd_mkt_rpt);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_money_market_secured_market_statistical_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_012_001_02::MoneyMarketSecuredMarketStatisticalReportV02) {
+	visitor.visit_money_market_report_header1_mut(&mut node.rpt_hdr);
+	visitor.visit_secured_market_report4_choice_mut(&mut node.scrd_mkt_rpt);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_money_market_unsecured_market_statistical_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_013_001_02::MoneyMarketUnsecuredMarketStatisticalReportV02) {
+	visitor.visit_money_market_report_header1(&node.rpt_hdr);
+	visitor.visit_unsecured_market_report4_choice(&node.uscrd_mkt_rpt);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_money_market_unsecured_market_statistical_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_013_001_02::MoneyMarketUnsecuredMarketStatisticalReportV02) {
+	visitor.visit_money_market_report_header1_mut(&mut node.rpt_hdr);
+	visitor.visit_unsecured_market_report4_choice_mut(&mut node.uscrd_mkt_rpt);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_money_market_foreign_exchange_swaps_statistical_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_014_001_02::MoneyMarketForeignExchangeSwapsStatisticalReportV02) {
+	visitor.visit_money_market_report_header1(&node.rpt_hdr);
+	visitor.visit_foreign_exchange_swap3_choice(&node.fx_swps_rpt);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_money_market_foreign_exchange_swaps_statistical_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_014_001_02::MoneyMarketForeignExchangeSwapsStatisticalReportV02) {
+	visitor.visit_money_market_report_header1_mut(&mut node.rpt_hdr);
+	visitor.visit_foreign_exchange_swap3_choice_mut(&mut node.fx_swps_rpt);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_money_market_overnight_index_swaps_statistical_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_015_001_02::MoneyMarketOvernightIndexSwapsStatisticalReportV02) {
+	visitor.visit_money_market_report_header1(&node.rpt_hdr);
+	visitor.visit_overnight_index_swap4_choice(&node.ovrnght_indx_swps_rpt);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_money_market_overnight_index_swaps_statistical_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_015_001_02::MoneyMarketOvernightIndexSwapsStatisticalReportV02) {
+	visitor.visit_money_market_report_header1_mut(&mut node.rpt_hdr);
+	visitor.visit_overnight_index_swap4_choice_mut(&mut node.ovrnght_indx_swps_rpt);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_transaction_report_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_016_001_03::FinancialInstrumentReportingTransactionReportV03) {
+	for item in &node.tx { visitor.visit_reporting_transaction_type3_choice(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_transaction_report_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_016_001_03::FinancialInstrumentReportingTransactionReportV03) {
+	for item in &mut node.tx { visitor.visit_reporting_transaction_type3_choice_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_reference_data_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_017_001_02::FinancialInstrumentReportingReferenceDataReportV02) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.ref_data { visitor.visit_securities_reference_data_report6(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_reference_data_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_017_001_02::FinancialInstrumentReportingReferenceDataReportV02) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.ref_data { visitor.visit_securities_reference_data_report6_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_contract_registration_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_018_001_04::ContractRegistrationRequestV04) {
+	visitor.visit_currency_control_header8(&node.grp_hdr);
+	for item in &node.ctrct_regn { visitor.visit_contract_registration7(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_contract_registration_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_018_001_04::ContractRegistrationRequestV04) {
+	visitor.visit_currency_control_header8_mut(&mut node.grp_hdr);
+	for item in &mut node.ctrct_regn { visitor.visit_contract_registration7_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_contract_registration_confirmation_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_019_001_04::ContractRegistrationConfirmationV04) {
+	visitor.visit_currency_control_header7(&node.grp_hdr);
+	for item in &node.regd_ctrct { visitor.visit_registered_contract20(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_contract_registration_confirmation_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_019_001_04::ContractRegistrationConfirmationV04) {
+	visitor.visit_currency_control_header7_mut(&mut node.grp_hdr);
+	for item in &mut node.regd_ctrct { visitor.visit_registered_contract20_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_contract_registration_closure_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_020_001_04::ContractRegistrationClosureRequestV04) {
+	visitor.visit_currency_control_header8(&node.grp_hdr);
+	for item in &node.regd_ctrct_clsr { visitor.visit_registered_contract19(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_contract_registration_closure_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_020_001_04::ContractRegistrationClosureRequestV04) {
+	visitor.visit_currency_control_header8_mut(&mut node.grp_hdr);
+	for item in &mut node.regd_ctrct_clsr { visitor.visit_registered_contract19_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_contract_registration_amendment_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_021_001_04::ContractRegistrationAmendmentRequestV04) {
+	visitor.visit_currency_control_header8(&node.grp_hdr);
+	for item in &node.ctrct_regn_amdmnt { visitor.visit_registered_contract16(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_contract_registration_amendment_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_021_001_04::ContractRegistrationAmendmentRequestV04) {
+	visitor.visit_currency_control_header8_mut(&mut node.grp_hdr);
+	for item in &mut node.ctrct_regn_amdmnt { visitor.visit_registered_contract16_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_contract_registration_statement_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_022_001_04::ContractRegistrationStatementV04) {
+	visitor.visit_currency_control_header7(&node.grp_hdr);
+	for item in &node.stmt { visitor.visit_contract_registration_statement4(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_contract_registration_statement_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_022_001_04::ContractRegistrationStatementV04) {
+	visitor.visit_currency_control_header7_mut(&mut node.grp_hdr);
+	for item in &mut node.stmt { visitor.visit_contract_registration_statement4_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_contract_registration_statement_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_023_001_04::ContractRegistrationStatementRequestV04) {
+	visitor.visit_currency_control_header8(&node.grp_hdr);
+	for item in &node.stmt_req { visitor.visit_contract_registration_statement_request3(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_contract_registration_statement_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_023_001_04::ContractRegistrationStatementRequestV04) {
+	visitor.visit_currency_control_header8_mut(&mut node.grp_hdr);
+	for item in &mut node.stmt_req { visitor.visit_contract_registration_statement_request3_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_payment_regulatory_information_notification_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_024_001_04::PaymentRegulatoryInformationNotificationV04) {
+	visitor.visit_currency_control_header9(&node.grp_hdr);
+	for item in &node.tx_ntfctn { visitor.visit_regulatory_reporting_notification4(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_payment_regulatory_information_notification_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_024_001_04::PaymentRegulatoryInformationNotificationV04) {
+	visitor.visit_currency_control_header9_mut(&mut node.grp_hdr);
+	for item in &mut node.tx_ntfctn { visitor.visit_regulatory_reporting_notification4_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_currency_control_supporting_document_delivery_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_025_001_04::CurrencyControlSupportingDocumentDeliveryV04) {
+	visitor.visit_currency_control_header9(&node.grp_hdr);
+	for item in &node.spprtg_doc { visitor.visit_supporting_document4(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_currency_control_supporting_document_delivery_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_025_001_04::CurrencyControlSupportingDocumentDeliveryV04) {
+	visitor.visit_currency_control_header9_mut(&mut node.grp_hdr);
+	for item in &mut node.spprtg_doc { visitor.visit_supporting_document4_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_currency_control_request_or_letter_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_026_001_04::CurrencyControlRequestOrLetterV04) {
+	visitor.visit_currency_control_header9(&node.grp_hdr);
+	for item in &node.req_or_lttr { visitor.visit_supporting_document_request_or_letter4(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_currency_control_request_or_letter_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_026_001_04::CurrencyControlRequestOrLetterV04) {
+	visitor.visit_currency_control_header9_mut(&mut node.grp_hdr);
+	for item in &mut node.req_or_lttr { visitor.visit_supporting_document_request_or_letter4_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_currency_control_status_advice_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_027_001_04::CurrencyControlStatusAdviceV04) {
+	visitor.visit_currency_control_header7(&node.grp_hdr);
+	for item in &node.grp_sts { visitor.visit_currency_control_group_status3(item) }
+	if let Some(ref vec) = node.packg_sts { for item in vec { visitor.visit_currency_control_package_status3(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_currency_control_status_advice_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_027_001_04::CurrencyControlStatusAdviceV04) {
+	visitor.visit_currency_control_header7_mut(&mut node.grp_hdr);
+	for item in &mut node.grp_sts { visitor.visit_currency_control_group_status3_mut(item) }
+	if let Some(ref mut vec) = node.packg_sts { for item in vec { visitor.visit_currency_control_package_status3_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_money_market_statistical_report_status_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_028_001_01::MoneyMarketStatisticalReportStatusAdviceV01) {
+	visitor.visit_money_market_status_report_header1(&node.sts_rpt_hdr);
+	if let Some(ref vec) = node.tx_sts { for item in vec { visitor.visit_money_market_transaction_status2(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_money_market_statistical_report_status_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_028_001_01::MoneyMarketStatisticalReportStatusAdviceV01) {
+	visitor.visit_money_market_status_report_header1_mut(&mut node.sts_rpt_hdr);
+	if let Some(ref mut vec) = node.tx_sts { for item in vec { visitor.visit_money_market_transaction_status2_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_derivatives_trade_report_query_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_029_001_05::DerivativesTradeReportQueryV05) {
+	visitor.visit_party_identification121_choice(&node.rqstng_authrty);
+	visitor.visit_trade_report_query18_choice(&node.trad_qry_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_derivatives_trade_report_query_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_029_001_05::DerivativesTradeReportQueryV05) {
+	visitor.visit_party_identification121_choice_mut(&mut node.rqstng_authrty);
+	visitor.visit_trade_report_query18_choice_mut(&mut node.trad_qry_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_derivatives_trade_report_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_030_001_04::DerivativesTradeReportV04) {
+	visitor.visit_trade_report_header4(&node.rpt_hdr);
+	visitor.visit_trade_data59_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_derivatives_trade_report_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_030_001_04::DerivativesTradeReportV04) {
+	visitor.visit_trade_report_header4_mut(&mut node.rpt_hdr);
+	visitor.visit_trade_data59_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_status_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_031_001_01::FinancialInstrumentReportingStatusAdviceV01) {
+	for item in &node.sts_advc { visitor.visit_message_report_header4(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_status_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_031_001_01::FinancialInstrumentReportingStatusAdviceV01) {
+	for item in &mut node.sts_advc { visitor.visit_message_report_header4_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_equity_transparency_data_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_032_001_01::FinancialInstrumentReportingEquityTransparencyDataReportV01) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.eqty_trnsprncy_data { visitor.visit_transparency_data_report11(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_equity_transparency_data_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_032_001_01::FinancialInstrumentReportingEquityTransparencyDataReportV01) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.eqty_trnsprncy_data { visitor.visit_transparency_data_report11_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_non_equity_transparency_data_report_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_033_001_03::FinancialInstrumentReportingNonEquityTransparencyDataReportV03) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.non_eqty_trnsprncy_data { visitor.visit_transparency_data_report21(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_non_equity_transparency_data_report_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_033_001_03::FinancialInstrumentReportingNonEquityTransparencyDataReportV03) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.non_eqty_trnsprncy_data { visitor.visit_transparency_data_report21_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_invoice_tax_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_034_001_01::InvoiceTaxReportV01) {
+	visitor.visit_tax_report_header1(&node.invc_tax_rpt_hdr);
+	for item in &node.tax_rpt { visitor.visit_tax_report1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_invoice_tax_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_034_001_01::InvoiceTaxReportV01) {
+	visitor.visit_tax_report_header1_mut(&mut node.invc_tax_rpt_hdr);
+	for item in &mut node.tax_rpt { visitor.visit_tax_report1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_trading_volume_cap_data_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_035_001_01::FinancialInstrumentReportingTradingVolumeCapDataReportV01) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.vol_cap_data { visitor.visit_volume_cap_report1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_trading_volume_cap_data_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_035_001_01::FinancialInstrumentReportingTradingVolumeCapDataReportV01) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.vol_cap_data { visitor.visit_volume_cap_report1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_reference_data_delta_report_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_036_001_03::FinancialInstrumentReportingReferenceDataDeltaReportV03) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	if let Some(ref vec) = node.fin_instrm { for item in vec { visitor.visit_securities_reference_delta_status_report5_choice(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_reference_data_delta_report_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_036_001_03::FinancialInstrumentReportingReferenceDataDeltaReportV03) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	if let Some(ref mut vec) = node.fin_instrm { for item in vec { visitor.visit_securities_reference_delta_status_report5_choice_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_invoice_tax_report_status_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_038_001_01::InvoiceTaxReportStatusAdviceV01) {
+	visitor.visit_invoice_tax_status_report_header1(&node.sts_rpt_hdr);
+	if let Some(ref vec) = node.tx_sts { for item in vec { visitor.visit_invoice_tax_report_transaction_status1(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_invoice_tax_report_status_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_038_001_01::InvoiceTaxReportStatusAdviceV01) {
+	visitor.visit_invoice_tax_status_report_header1_mut(&mut node.sts_rpt_hdr);
+	if let Some(ref mut vec) = node.tx_sts { for item in vec { visitor.visit_invoice_tax_report_transaction_status1_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_non_working_day_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_039_001_01::FinancialInstrumentReportingNonWorkingDayReportV01) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.non_workg_day { visitor.visit_securities_non_trading_day_report1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_non_working_day_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_039_001_01::FinancialInstrumentReportingNonWorkingDayReportV01) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.non_workg_day { visitor.visit_securities_non_trading_day_report1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_equity_trading_activity_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_040_001_01::FinancialInstrumentReportingEquityTradingActivityReportV01) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.eqty_trnsprncy_data { visitor.visit_transparency_data_report13(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_equity_trading_activity_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_040_001_01::FinancialInstrumentReportingEquityTradingActivityReportV01) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.eqty_trnsprncy_data { visitor.visit_transparency_data_report13_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_non_equity_trading_activity_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_041_001_01::FinancialInstrumentReportingNonEquityTradingActivityReportV01) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.non_eqty_trnsprncy_data { visitor.visit_transparency_data_report15(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_non_equity_trading_activity_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_041_001_01::FinancialInstrumentReportingNonEquityTradingActivityReportV01) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.non_eqty_trnsprncy_data { visitor.visit_transparency_data_report15_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_invalid_reference_data_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_042_001_02::FinancialInstrumentReportingInvalidReferenceDataReportV02) {
+	visitor.visit_period4_choice(&node.dt_prd);
+	for item in &node.fin_instrms { visitor.visit_securities_invalid_reference_data_report4(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_invalid_reference_data_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_042_001_02::FinancialInstrumentReportingInvalidReferenceDataReportV02) {
+	visitor.visit_period4_choice_mut(&mut node.dt_prd);
+	for item in &mut node.fin_instrms { visitor.visit_securities_invalid_reference_data_report4_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_reference_data_index_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_043_001_01::FinancialInstrumentReportingReferenceDataIndexReportV01) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.indx_data { visitor.visit_securities_index_report1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_reference_data_index_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_043_001_01::FinancialInstrumentReportingReferenceDataIndexReportV01) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.indx_data { visitor.visit_securities_index_report1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_equity_trading_activity_result_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_044_001_02::FinancialInstrumentReportingEquityTradingActivityResultV02) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.eqty_trnsprncy_data { visitor.visit_transparency_data_report17(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_equity_trading_activity_result_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_044_001_02::FinancialInstrumentReportingEquityTradingActivityResultV02) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.eqty_trnsprncy_data { visitor.visit_transparency_data_report17_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_non_equity_trading_activity_result_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_045_001_03::FinancialInstrumentReportingNonEquityTradingActivityResultV03) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.non_eqty_trnsprncy_data { visitor.visit_transparency_data_report20(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_non_equity_trading_activity_result_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_045_001_03::FinancialInstrumentReportingNonEquityTradingActivityResultV03) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.non_eqty_trnsprncy_data { visitor.visit_transparency_data_report20_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_country_code_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_047_001_01::FinancialInstrumentReportingCountryCodeReportV01) {
+	for item in &node.ctry_data { visitor.visit_securities_country_identification2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_country_code_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_047_001_01::FinancialInstrumentReportingCountryCodeReportV01) {
+	for item in &mut node.ctry_data { visitor.visit_securities_country_identification2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_currency_code_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_048_001_01::FinancialInstrumentReportingCurrencyCodeReportV01) {
+	for item in &node.ccy_data { visitor.visit_securities_currency_identification2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_currency_code_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_048_001_01::FinancialInstrumentReportingCurrencyCodeReportV01) {
+	for item in &mut node.ccy_data { visitor.visit_securities_currency_identification2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_market_identification_code_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_049_001_02::FinancialInstrumentReportingMarketIdentificationCodeReportV02) {
+	for item in &node.mkt_id { visitor.visit_market_identification95(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_market_identification_code_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_049_001_02::FinancialInstrumentReportingMarketIdentificationCodeReportV02) {
+	for item in &mut node.mkt_id { visitor.visit_market_identification95_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_instrument_classification_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_050_001_01::FinancialInstrumentReportingInstrumentClassificationReportV01) {
+	for item in &node.instrm_clssfctn { visitor.visit_securities_instrument_classification2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_instrument_classification_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_050_001_01::FinancialInstrumentReportingInstrumentClassificationReportV01) {
+	for item in &mut node.instrm_clssfctn { visitor.visit_securities_instrument_classification2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_052_001_02::SecuritiesFinancingReportingTransactionReportV02) {
+	visitor.visit_trade_data40_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_052_001_02::SecuritiesFinancingReportingTransactionReportV02) {
+	visitor.visit_trade_data40_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_trading_volume_cap_result_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_053_001_01::FinancialInstrumentReportingTradingVolumeCapResultReportV01) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.vol_cap_rslt { visitor.visit_volume_cap_result1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_trading_volume_cap_result_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_053_001_01::FinancialInstrumentReportingTradingVolumeCapResultReportV01) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.vol_cap_rslt { visitor.visit_volume_cap_result1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_clearing_member_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_054_001_01::CCPClearingMemberReportV01) {
+	for item in &node.clr_mmb { visitor.visit_clearing_member1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_clearing_member_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_054_001_01::CCPClearingMemberReportV01) {
+	for item in &mut node.clr_mmb { visitor.visit_clearing_member1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_member_requirements_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_055_001_01::CCPMemberRequirementsReportV01) {
+	for item in &node.intra_day_rqrmnt_amt { visitor.visit_intra_day_requirement1(item) }
+	if let Some(ref vec) = node.intra_day_mrgn_call { for item in vec { visitor.visit_intra_day_margin_call1(item) } }
+	for item in &node.end_of_day_rqrmnt { visitor.visit_end_of_day_requirement2(item) }
+	for item in &node.dflt_fnd_rqrmnt { visitor.visit_default_fund_requirement1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_member_requirements_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_055_001_01::CCPMemberRequirementsReportV01) {
+	for item in &mut node.intra_day_rqrmnt_amt { visitor.visit_intra_day_requirement1_mut(item) }
+	if let Some(ref mut vec) = node.intra_day_mrgn_call { for item in vec { visitor.visit_intra_day_margin_call1_mut(item) } }
+	for item in &mut node.end_of_day_rqrmnt { visitor.visit_end_of_day_requirement2_mut(item) }
+	for item in &mut node.dflt_fnd_rqrmnt { visitor.visit_default_fund_requirement1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_member_obligations_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_056_001_01::CCPMemberObligationsReportV01) {
+	for item in &node.sttlm_acct { visitor.visit_settlement_account1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_member_obligations_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_056_001_01::CCPMemberObligationsReportV01) {
+	for item in &mut node.sttlm_acct { visitor.visit_settlement_account1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_portfolio_stress_testing_definition_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_057_001_02::CCPPortfolioStressTestingDefinitionReportV02) {
+	for item in &node.scnro_def { visitor.visit_scenario_definition2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_portfolio_stress_testing_definition_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_057_001_02::CCPPortfolioStressTestingDefinitionReportV02) {
+	for item in &mut node.scnro_def { visitor.visit_scenario_definition2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_portfolio_stress_testing_result_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_058_001_01::CCPPortfolioStressTestingResultReportV01) {
+	for item in &node.scnro_strss_tst_rslt { visitor.visit_scenario_stress_test_result1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_portfolio_stress_testing_result_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_058_001_01::CCPPortfolioStressTestingResultReportV01) {
+	for item in &mut node.scnro_strss_tst_rslt { visitor.visit_scenario_stress_test_result1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_income_statement_and_capital_adequacy_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_059_001_01::CCPIncomeStatementAndCapitalAdequacyReportV01) {
+	visitor.visit_income_statement1(&node.incm_stmt);
+	visitor.visit_capital_requirement1(&node.cptl_rqrmnts);
+	visitor.visit_active_currency_and_amount(&node.ttl_cptl);
+	visitor.visit_active_currency_and_amount(&node.lqd_fin_rsrcs);
+	for item in &node.hpthtcl_cptl_measr { visitor.visit_hypothetical_capital_measure1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_income_statement_and_capital_adequacy_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_059_001_01::CCPIncomeStatementAndCapitalAdequacyReportV01) {
+	visitor.visit_income_statement1_mut(&mut node.incm_stmt);
+	visitor.visit_capital_requirement1_mut(&mut node.cptl_rqrmnts);
+	visitor.visit_active_currency_and_amount_mut(&mut node.ttl_cptl);
+	visitor.visit_active_currency_and_amount_mut(&mut node.lqd_fin_rsrcs);
+	for item in &mut node.hpthtcl_cptl_measr { visitor.visit_hypothetical_capital_measure1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_daily_cash_flows_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_060_001_02::CCPDailyCashFlowsReportV02) {
+	for item in &node.cncntrtn_agt { visitor.visit_concentration_agent1(item) }
+	for item in &node.sttlm_agt { visitor.visit_settlement_agent2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_daily_cash_flows_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_060_001_02::CCPDailyCashFlowsReportV02) {
+	for item in &mut node.cncntrtn_agt { visitor.visit_concentration_agent1_mut(item) }
+	for item in &mut node.sttlm_agt { visitor.visit_settlement_agent2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_investments_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_061_001_01::CCPInvestmentsReportV01) {
+	for item in &node.invstmt { visitor.visit_investment1_choice(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_investments_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_061_001_01::CCPInvestmentsReportV01) {
+	for item in &mut node.invstmt { visitor.visit_investment1_choice_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_liquidity_stress_testing_definition_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_062_001_01::CCPLiquidityStressTestingDefinitionReportV01) {
+	for item in &node.lqdty_strss_scnro_def { visitor.visit_liquidity_stress_scenario_definition1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_liquidity_stress_testing_definition_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_062_001_01::CCPLiquidityStressTestingDefinitionReportV01) {
+	for item in &mut node.lqdty_strss_scnro_def { visitor.visit_liquidity_stress_scenario_definition1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_liquidity_stress_testing_result_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_063_001_01::CCPLiquidityStressTestingResultReportV01) {
+	for item in &node.lqdty_strss_tst_rslt { visitor.visit_liquidity_stress_test_result1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_liquidity_stress_testing_result_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_063_001_01::CCPLiquidityStressTestingResultReportV01) {
+	for item in &mut node.lqdty_strss_tst_rslt { visitor.visit_liquidity_stress_test_result1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_available_financial_resources_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_064_001_01::CCPAvailableFinancialResourcesReportV01) {
+	visitor.visit_available_financial_resources_amount1(&node.avlbl_fin_rsrcs_amt);
+	if let Some(ref value) = node.othr_prfndd_rsrcs { visitor.visit_reporting_asset_breakdown1(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_available_financial_resources_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_064_001_01::CCPAvailableFinancialResourcesReportV01) {
+	visitor.visit_available_financial_resources_amount1_mut(&mut node.avlbl_fin_rsrcs_amt);
+	if let Some(ref mut value) = node.othr_prfndd_rsrcs { visitor.visit_reporting_asset_breakdown1_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_back_testing_definition_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_065_001_01::CCPBackTestingDefinitionReportV01) {
+	for item in &node.mthdlgy { visitor.visit_back_testing_methodology1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_back_testing_definition_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_065_001_01::CCPBackTestingDefinitionReportV01) {
+	for item in &mut node.mthdlgy { visitor.visit_back_testing_methodology1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_back_testing_result_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_066_001_01::CCPBackTestingResultReportV01) {
+	for item in &node.mnthly_rslt { visitor.visit_monthly_result1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_back_testing_result_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_066_001_01::CCPBackTestingResultReportV01) {
+	for item in &mut node.mnthly_rslt { visitor.visit_monthly_result1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_collateral_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_067_001_01::CCPCollateralReportV01) {
+	for item in &node.coll_acct_ownr { visitor.visit_collateral_account4(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_collateral_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_067_001_01::CCPCollateralReportV01) {
+	for item in &mut node.coll_acct_ownr { visitor.visit_collateral_account4_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_account_position_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_068_001_01::CCPAccountPositionReportV01) {
+	for item in &node.prtfl { visitor.visit_position_account2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_account_position_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_068_001_01::CCPAccountPositionReportV01) {
+	for item in &mut node.prtfl { visitor.visit_position_account2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_cleared_product_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_069_001_01::CCPClearedProductReportV01) {
+	for item in &node.clrd_pdct { visitor.visit_cleared_product1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_cleared_product_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_069_001_01::CCPClearedProductReportV01) {
+	for item in &mut node.clrd_pdct { visitor.visit_cleared_product1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_margin_data_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_070_001_02::SecuritiesFinancingReportingTransactionMarginDataReportV02) {
+	visitor.visit_trade_data39_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_margin_data_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_070_001_02::SecuritiesFinancingReportingTransactionMarginDataReportV02) {
+	visitor.visit_trade_data39_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_reused_collateral_data_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_071_001_02::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02) {
+	visitor.visit_trade_data36_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_reused_collateral_data_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_071_001_02::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02) {
+	visitor.visit_trade_data36_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_settlement_internaliser_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_072_001_01::SettlementInternaliserReportV01) {
+	visitor.visit_settlement_internaliser_report_header1(&node.rpt_hdr);
+	visitor.visit_settlement_internaliser1(&node.sttlm_intlr);
+	for item in &node.issr_csd { visitor.visit_issuer_csd_report1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_settlement_internaliser_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_072_001_01::SettlementInternaliserReportV01) {
+	visitor.visit_settlement_internaliser_report_header1_mut(&mut node.rpt_hdr);
+	visitor.visit_settlement_internaliser1_mut(&mut node.sttlm_intlr);
+	for item in &mut node.issr_csd { visitor.visit_issuer_csd_report1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_supervised_party_identity_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_076_001_01::FinancialSupervisedPartyIdentityReportV01) {
+	for item in &node.pty_data { visitor.visit_party_report1_choice(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_supervised_party_identity_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_076_001_01::FinancialSupervisedPartyIdentityReportV01) {
+	for item in &mut node.pty_data { visitor.visit_party_report1_choice_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_benchmark_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_077_001_01::FinancialBenchmarkReportV01) {
+	for item in &node.bchmk_data { visitor.visit_benchmark_report1_choice(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_benchmark_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_077_001_01::FinancialBenchmarkReportV01) {
+	for item in &mut node.bchmk_data { visitor.visit_benchmark_report1_choice_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_pairing_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_078_001_02::SecuritiesFinancingReportingPairingRequestV02) {
+	for item in &node.tx_id { visitor.visit_trade_transaction_identification15(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_pairing_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_078_001_02::SecuritiesFinancingReportingPairingRequestV02) {
+	for item in &mut node.tx_id { visitor.visit_trade_transaction_identification15_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_state_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_079_001_02::SecuritiesFinancingReportingTransactionStateReportV02) {
+	visitor.visit_trade_state_report5_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_state_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_079_001_02::SecuritiesFinancingReportingTransactionStateReportV02) {
+	visitor.visit_trade_state_report5_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_reconciliation_status_advice_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_080_001_02::SecuritiesFinancingReportingReconciliationStatusAdviceV02) {
+	visitor.visit_trade_data34_choice(&node.rcncltn_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_reconciliation_status_advice_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_080_001_02::SecuritiesFinancingReportingReconciliationStatusAdviceV02) {
+	visitor.visit_trade_data34_choice_mut(&mut node.rcncltn_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_missing_collateral_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_083_001_02::SecuritiesFinancingReportingMissingCollateralRequestV02) {
+	for item in &node.tx_id { visitor.visit_trade_transaction_identification18(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_missing_collateral_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_083_001_02::SecuritiesFinancingReportingMissingCollateralRequestV02) {
+	for item in &mut node.tx_id { visitor.visit_trade_transaction_identification18_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_status_advice_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_084_001_02::SecuritiesFinancingReportingTransactionStatusAdviceV02) {
+	for item in &node.tx_rpt_sts_and_rsn { visitor.visit_trade_data35_choice(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_status_advice_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_084_001_02::SecuritiesFinancingReportingTransactionStatusAdviceV02) {
+	for item in &mut node.tx_rpt_sts_and_rsn { visitor.visit_trade_data35_choice_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_margin_data_transaction_state_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_085_001_02::SecuritiesFinancingReportingMarginDataTransactionStateReportV02) {
+	visitor.visit_trade_data38_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_margin_data_transaction_state_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_085_001_02::SecuritiesFinancingReportingMarginDataTransactionStateReportV02) {
+	visitor.visit_trade_data38_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_reused_collateral_data_transaction_state_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_086_001_02::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02) {
+	visitor.visit_trade_data37_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_reused_collateral_data_transaction_state_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_086_001_02::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02) {
+	visitor.visit_trade_data37_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_derivatives_trade_position_set_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_090_001_02::DerivativesTradePositionSetReportV02) {
+	visitor.visit_position_set_aggregated2_choice(&node.aggtd_pos);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_derivatives_trade_position_set_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_090_001_02::DerivativesTradePositionSetReportV02) {
+	visitor.visit_position_set_aggregated2_choice_mut(&mut node.aggtd_pos);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_derivatives_trade_reconciliation_statistical_report_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_091_001_03::DerivativesTradeReconciliationStatisticalReportV03) {
+	visitor.visit_statistics_per_counterparty19_choice(&node.rcncltn_sttstcs);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_derivatives_trade_reconciliation_statistical_report_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_091_001_03::DerivativesTradeReconciliationStatisticalReportV03) {
+	visitor.visit_statistics_per_counterparty19_choice_mut(&mut node.rcncltn_sttstcs);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_derivatives_trade_rejection_statistical_report_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_092_001_04::DerivativesTradeRejectionStatisticalReportV04) {
+	visitor.visit_statistics_per_counterparty18_choice(&node.rjctn_sttstcs);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_derivatives_trade_rejection_statistical_report_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_092_001_04::DerivativesTradeRejectionStatisticalReportV04) {
+	visitor.visit_statistics_per_counterparty18_choice_mut(&mut node.rjctn_sttstcs);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_query_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_094_001_02::SecuritiesFinancingReportingTransactionQueryV02) {
+	visitor.visit_party_identification121_choice(&node.rqstng_authrty);
+	visitor.visit_trade_report_query13_choice(&node.trad_qry_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_transaction_query_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_094_001_02::SecuritiesFinancingReportingTransactionQueryV02) {
+	visitor.visit_party_identification121_choice_mut(&mut node.rqstng_authrty);
+	visitor.visit_trade_report_query13_choice_mut(&mut node.trad_qry_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_settlement_fails_monthly_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_100_001_01::SettlementFailsMonthlyReportV01) {
+	visitor.visit_settlement_fails_report_header2(&node.rpt_hdr);
+	visitor.visit_settlement_fails_data3(&node.mnthly_aggt);
+	for item in &node.daly_data { visitor.visit_settlement_fails_daily_data3(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_settlement_fails_monthly_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_100_001_01::SettlementFailsMonthlyReportV01) {
+	visitor.visit_settlement_fails_report_header2_mut(&mut node.rpt_hdr);
+	visitor.visit_settlement_fails_data3_mut(&mut node.mnthly_aggt);
+	for item in &mut node.daly_data { visitor.visit_settlement_fails_daily_data3_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_settlement_fails_annual_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_101_001_01::SettlementFailsAnnualReportV01) {
+	visitor.visit_settlement_fails_report_header2(&node.rpt_hdr);
+	visitor.visit_settlement_fails_data4(&node.anl_aggt);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_settlement_fails_annual_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_101_001_01::SettlementFailsAnnualReportV01) {
+	visitor.visit_settlement_fails_report_header2_mut(&mut node.rpt_hdr);
+	visitor.visit_settlement_fails_data4_mut(&mut node.anl_aggt);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_cancellation_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_102_001_01::FinancialInstrumentReportingCancellationReportV01) {
+	visitor.visit_securities_market_report_header1(&node.rpt_hdr);
+	for item in &node.cxl_data { visitor.visit_securities_reference_data_report7(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_instrument_reporting_cancellation_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_102_001_01::FinancialInstrumentReportingCancellationReportV01) {
+	visitor.visit_securities_market_report_header1_mut(&mut node.rpt_hdr);
+	for item in &mut node.cxl_data { visitor.visit_securities_reference_data_report7_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_financing_reporting_position_set_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_105_001_01::SecuritiesFinancingReportingPositionSetReportV01) {
+	visitor.visit_position_set_report3_choice(&node.aggtd_poss);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_financing_reporting_position_set_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_105_001_01::SecuritiesFinancingReportingPositionSetReportV01) {
+	visitor.visit_position_set_report3_choice_mut(&mut node.aggtd_poss);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_derivatives_trade_warnings_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_106_001_01::DerivativesTradeWarningsReportV01) {
+	visitor.visit_statistics_per_counterparty16_choice(&node.wrnngs_sttstcs);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_derivatives_trade_warnings_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_106_001_01::DerivativesTradeWarningsReportV01) {
+	visitor.visit_statistics_per_counterparty16_choice_mut(&mut node.wrnngs_sttstcs);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_derivatives_trade_state_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_107_001_02::DerivativesTradeStateReportV02) {
+	visitor.visit_trade_report_header4(&node.rpt_hdr);
+	visitor.visit_trade_data60_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_derivatives_trade_state_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_107_001_02::DerivativesTradeStateReportV02) {
+	visitor.visit_trade_report_header4_mut(&mut node.rpt_hdr);
+	visitor.visit_trade_data60_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_derivatives_trade_margin_data_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_108_001_02::DerivativesTradeMarginDataReportV02) {
+	visitor.visit_trade_report_header4(&node.rpt_hdr);
+	visitor.visit_trade_data61_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_derivatives_trade_margin_data_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_108_001_02::DerivativesTradeMarginDataReportV02) {
+	visitor.visit_trade_report_header4_mut(&mut node.rpt_hdr);
+	visitor.visit_trade_data61_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_derivatives_trade_margin_data_transaction_state_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_109_001_02::DerivativesTradeMarginDataTransactionStateReportV02) {
+	visitor.visit_trade_report_header4(&node.rpt_hdr);
+	visitor.visit_trade_data62_choice(&node.trad_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_derivatives_trade_margin_data_transaction_state_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_109_001_02::DerivativesTradeMarginDataTransactionStateReportV02) {
+	visitor.visit_trade_report_header4_mut(&mut node.rpt_hdr);
+	visitor.visit_trade_data62_choice_mut(&mut node.trad_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_ccp_interoperability_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_112_001_01::CCPInteroperabilityReportV01) {
+	for item in &node.intrprblty_ccp { visitor.visit_interoperability_ccp1(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_ccp_interoperability_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_112_001_01::CCPInteroperabilityReportV01) {
+	for item in &mut node.intrprblty_ccp { visitor.visit_interoperability_ccp1_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_order_book_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::auth_113_001_01::OrderBookReportV01) {
+	visitor.visit_securities_market_report_header3(&node.rpt_hdr);
+	for item in &node.ordr_rpt { visitor.visit_order_report2_choice(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_order_book_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::auth_113_001_01::OrderBookReportV01) {
+	visitor.visit_securities_market_report_header3_mut(&mut node.rpt_hdr);
+	for item in &mut node.ordr_rpt { visitor.visit_order_report2_choice_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+impl crate::auth_001_001_02::InformationRequestOpeningV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_information_request_opening_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_information_request_opening_v02_mut(self) }
+}
+
+impl crate::auth_002_001_02::InformationRequestResponseV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_information_request_response_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_information_request_response_v02_mut(self) }
+}
+
+impl crate::auth_003_001_01::InformationRequestStatusChangeNotificationV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_information_request_status_change_notification_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_information_request_status_change_notification_v01_mut(self) }
+}
+
+impl crate::auth_012_001_02::MoneyMarketSecuredMarketStatisticalReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_money_market_secured_market_statistical_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_money_market_secured_market_statistical_report_v02_mut(self) }
+}
+
+impl crate::auth_013_001_02::MoneyMarketUnsecuredMarketStatisticalReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_money_market_unsecured_market_statistical_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_money_market_unsecured_market_statistical_report_v02_mut(self) }
+}
+
+impl crate::auth_014_001_02::MoneyMarketForeignExchangeSwapsStatisticalReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_money_market_foreign_exchange_swaps_statistical_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_money_market_foreign_exchange_swaps_statistical_report_v02_mut(self) }
+}
+
+impl crate::auth_015_001_02::MoneyMarketOvernightIndexSwapsStatisticalReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_money_market_overnight_index_swaps_statistical_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_money_market_overnight_index_swaps_statistical_report_v02_mut(self) }
+}
+
+impl crate::auth_016_001_03::FinancialInstrumentReportingTransactionReportV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_transaction_report_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_transaction_report_v03_mut(self) }
+}
+
+impl crate::auth_017_001_02::FinancialInstrumentReportingReferenceDataReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_reference_data_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_reference_data_report_v02_mut(self) }
+}
+
+impl crate::auth_018_001_04::ContractRegistrationRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_contract_registration_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_contract_registration_request_v04_mut(self) }
+}
+
+impl crate::auth_019_001_04::ContractRegistrationConfirmationV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_contract_registration_confirmation_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_contract_registration_confirmation_v04_mut(self) }
+}
+
+impl crate::auth_020_001_04::ContractRegistrationClosureRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_contract_registration_closure_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_contract_registration_closure_request_v04_mut(self) }
+}
+
+impl crate::auth_021_001_04::ContractRegistrationAmendmentRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_contract_registration_amendment_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_contract_registration_amendment_request_v04_mut(self) }
+}
+
+impl crate::auth_022_001_04::ContractRegistrationStatementV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_contract_registration_statement_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_contract_registration_statement_v04_mut(self) }
+}
+
+impl crate::auth_023_001_04::ContractRegistrationStatementRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_contract_registration_statement_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_contract_registration_statement_request_v04_mut(self) }
+}
+
+impl crate::auth_024_001_04::PaymentRegulatoryInformationNotificationV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_regulatory_information_notification_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_regulatory_information_notification_v04_mut(self) }
+}
+
+impl crate::auth_025_001_04::CurrencyControlSupportingDocumentDeliveryV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_currency_control_supporting_document_delivery_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_currency_control_supporting_document_delivery_v04_mut(self) }
+}
+
+impl crate::auth_026_001_04::CurrencyControlRequestOrLetterV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_currency_control_request_or_letter_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_currency_control_request_or_letter_v04_mut(self) }
+}
+
+impl crate::auth_027_001_04::CurrencyControlStatusAdviceV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_currency_control_status_advice_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_currency_control_status_advice_v04_mut(self) }
+}
+
+impl crate::auth_028_001_01::MoneyMarketStatisticalReportStatusAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_money_market_statistical_report_status_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_money_market_statistical_report_status_advice_v01_mut(self) }
+}
+
+impl crate::auth_029_001_05::DerivativesTradeReportQueryV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_derivatives_trade_report_query_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_derivatives_trade_report_query_v05_mut(self) }
+}
+
+impl crate::auth_030_001_04::DerivativesTradeReportV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_derivatives_trade_report_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_derivatives_trade_report_v04_mut(self) }
+}
+
+impl crate::auth_031_001_01::FinancialInstrumentReportingStatusAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_status_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_status_advice_v01_mut(self) }
+}
+
+impl crate::auth_032_001_01::FinancialInstrumentReportingEquityTransparencyDataReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_equity_transparency_data_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_equity_transparency_data_report_v01_mut(self) }
+}
+
+impl crate::auth_033_001_03::FinancialInstrumentReportingNonEquityTransparencyDataReportV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_non_equity_transparency_data_report_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_non_equity_transparency_data_report_v03_mut(self) }
+}
+
+impl crate::auth_034_001_01::InvoiceTaxReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_invoice_tax_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_invoice_tax_report_v01_mut(self) }
+}
+
+impl crate::auth_035_001_01::FinancialInstrumentReportingTradingVolumeCapDataReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_trading_volume_cap_data_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_trading_volume_cap_data_report_v01_mut(self) }
+}
+
+impl crate::auth_036_001_03::FinancialInstrumentReportingReferenceDataDeltaReportV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_reference_data_delta_report_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_reference_data_delta_report_v03_mut(self) }
+}
+
+impl crate::auth_038_001_01::InvoiceTaxReportStatusAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_invoice_tax_report_status_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_invoice_tax_report_status_advice_v01_mut(self) }
+}
+
+impl crate::auth_039_001_01::FinancialInstrumentReportingNonWorkingDayReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_non_working_day_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_non_working_day_report_v01_mut(self) }
+}
+
+impl crate::auth_040_001_01::FinancialInstrumentReportingEquityTradingActivityReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_equity_trading_activity_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_equity_trading_activity_report_v01_mut(self) }
+}
+
+impl crate::auth_041_001_01::FinancialInstrumentReportingNonEquityTradingActivityReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_non_equity_trading_activity_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_non_equity_trading_activity_report_v01_mut(self) }
+}
+
+impl crate::auth_042_001_02::FinancialInstrumentReportingInvalidReferenceDataReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_invalid_reference_data_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_invalid_reference_data_report_v02_mut(self) }
+}
+
+impl crate::auth_043_001_01::FinancialInstrumentReportingReferenceDataIndexReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_reference_data_index_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_reference_data_index_report_v01_mut(self) }
+}
+
+impl crate::auth_044_001_02::FinancialInstrumentReportingEquityTradingActivityResultV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_equity_trading_activity_result_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_equity_trading_activity_result_v02_mut(self) }
+}
+
+impl crate::auth_045_001_03::FinancialInstrumentReportingNonEquityTradingActivityResultV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_non_equity_trading_activity_result_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_non_equity_trading_activity_result_v03_mut(self) }
+}
+
+impl crate::auth_047_001_01::FinancialInstrumentReportingCountryCodeReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_country_code_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_country_code_report_v01_mut(self) }
+}
+
+impl crate::auth_048_001_01::FinancialInstrumentReportingCurrencyCodeReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_currency_code_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_currency_code_report_v01_mut(self) }
+}
+
+impl crate::auth_049_001_02::FinancialInstrumentReportingMarketIdentificationCodeReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_market_identification_code_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_market_identification_code_report_v02_mut(self) }
+}
+
+impl crate::auth_050_001_01::FinancialInstrumentReportingInstrumentClassificationReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_instrument_classification_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_instrument_classification_report_v01_mut(self) }
+}
+
+impl crate::auth_052_001_02::SecuritiesFinancingReportingTransactionReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_report_v02_mut(self) }
+}
+
+impl crate::auth_053_001_01::FinancialInstrumentReportingTradingVolumeCapResultReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_trading_volume_cap_result_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_trading_volume_cap_result_report_v01_mut(self) }
+}
+
+impl crate::auth_054_001_01::CCPClearingMemberReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_clearing_member_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_clearing_member_report_v01_mut(self) }
+}
+
+impl crate::auth_055_001_01::CCPMemberRequirementsReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_member_requirements_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_member_requirements_report_v01_mut(self) }
+}
+
+impl crate::auth_056_001_01::CCPMemberObligationsReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_member_obligations_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_member_obligations_report_v01_mut(self) }
+}
+
+impl crate::auth_057_001_02::CCPPortfolioStressTestingDefinitionReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_portfolio_stress_testing_definition_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_portfolio_stress_testing_definition_report_v02_mut(self) }
+}
+
+impl crate::auth_058_001_01::CCPPortfolioStressTestingResultReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_portfolio_stress_testing_result_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_portfolio_stress_testing_result_report_v01_mut(self) }
+}
+
+impl crate::auth_059_001_01::CCPIncomeStatementAndCapitalAdequacyReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_income_statement_and_capital_adequacy_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_income_statement_and_capital_adequacy_report_v01_mut(self) }
+}
+
+impl crate::auth_060_001_02::CCPDailyCashFlowsReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_daily_cash_flows_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_daily_cash_flows_report_v02_mut(self) }
+}
+
+impl crate::auth_061_001_01::CCPInvestmentsReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_investments_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_investments_report_v01_mut(self) }
+}
+
+impl crate::auth_062_001_01::CCPLiquidityStressTestingDefinitionReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_liquidity_stress_testing_definition_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_liquidity_stress_testing_definition_report_v01_mut(self) }
+}
+
+impl crate::auth_063_001_01::CCPLiquidityStressTestingResultReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_liquidity_stress_testing_result_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_liquidity_stress_testing_result_report_v01_mut(self) }
+}
+
+impl crate::auth_064_001_01::CCPAvailableFinancialResourcesReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_available_financial_resources_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_available_financial_resources_report_v01_mut(self) }
+}
+
+impl crate::auth_065_001_01::CCPBackTestingDefinitionReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_back_testing_definition_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_back_testing_definition_report_v01_mut(self) }
+}
+
+impl crate::auth_066_001_01::CCPBackTestingResultReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_back_testing_result_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_back_testing_result_report_v01_mut(self) }
+}
+
+impl crate::auth_067_001_01::CCPCollateralReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_collateral_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_collateral_report_v01_mut(self) }
+}
+
+impl crate::auth_068_001_01::CCPAccountPositionReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_account_position_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_account_position_report_v01_mut(self) }
+}
+
+impl crate::auth_069_001_01::CCPClearedProductReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_cleared_product_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_cleared_product_report_v01_mut(self) }
+}
+
+impl crate::auth_070_001_02::SecuritiesFinancingReportingTransactionMarginDataReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_margin_data_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_margin_data_report_v02_mut(self) }
+}
+
+impl crate::auth_071_001_02::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_reused_collateral_data_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_reused_collateral_data_report_v02_mut(self) }
+}
+
+impl crate::auth_072_001_01::SettlementInternaliserReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_settlement_internaliser_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_settlement_internaliser_report_v01_mut(self) }
+}
+
+impl crate::auth_076_001_01::FinancialSupervisedPartyIdentityReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_supervised_party_identity_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_supervised_party_identity_report_v01_mut(self) }
+}
+
+impl crate::auth_077_001_01::FinancialBenchmarkReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_benchmark_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_benchmark_report_v01_mut(self) }
+}
+
+impl crate::auth_078_001_02::SecuritiesFinancingReportingPairingRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_pairing_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_pairing_request_v02_mut(self) }
+}
+
+impl crate::auth_079_001_02::SecuritiesFinancingReportingTransactionStateReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_state_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_state_report_v02_mut(self) }
+}
+
+impl crate::auth_080_001_02::SecuritiesFinancingReportingReconciliationStatusAdviceV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_reconciliation_status_advice_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_reconciliation_status_advice_v02_mut(self) }
+}
+
+impl crate::auth_083_001_02::SecuritiesFinancingReportingMissingCollateralRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_missing_collateral_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_missing_collateral_request_v02_mut(self) }
+}
+
+impl crate::auth_084_001_02::SecuritiesFinancingReportingTransactionStatusAdviceV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_status_advice_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_status_advice_v02_mut(self) }
+}
+
+impl crate::auth_085_001_02::SecuritiesFinancingReportingMarginDataTransactionStateReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_margin_data_transaction_state_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_margin_data_transaction_state_report_v02_mut(self) }
+}
+
+impl crate::auth_086_001_02::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_reused_collateral_data_transaction_state_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_reused_collateral_data_transaction_state_report_v02_mut(self) }
+}
+
+impl crate::auth_090_001_02::DerivativesTradePositionSetReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_derivatives_trade_position_set_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_derivatives_trade_position_set_report_v02_mut(self) }
+}
+
+impl crate::auth_091_001_03::DerivativesTradeReconciliationStatisticalReportV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_derivatives_trade_reconciliation_statistical_report_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_derivatives_trade_reconciliation_statistical_report_v03_mut(self) }
+}
+
+impl crate::auth_092_001_04::DerivativesTradeRejectionStatisticalReportV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_derivatives_trade_rejection_statistical_report_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_derivatives_trade_rejection_statistical_report_v04_mut(self) }
+}
+
+impl crate::auth_094_001_02::SecuritiesFinancingReportingTransactionQueryV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_query_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_transaction_query_v02_mut(self) }
+}
+
+impl crate::auth_100_001_01::SettlementFailsMonthlyReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_settlement_fails_monthly_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_settlement_fails_monthly_report_v01_mut(self) }
+}
+
+impl crate::auth_101_001_01::SettlementFailsAnnualReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_settlement_fails_annual_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_settlement_fails_annual_report_v01_mut(self) }
+}
+
+impl crate::auth_102_001_01::FinancialInstrumentReportingCancellationReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_cancellation_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_instrument_reporting_cancellation_report_v01_mut(self) }
+}
+
+impl crate::auth_105_001_01::SecuritiesFinancingReportingPositionSetReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_financing_reporting_position_set_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_financing_reporting_position_set_report_v01_mut(self) }
+}
+
+impl crate::auth_106_001_01::DerivativesTradeWarningsReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_derivatives_trade_warnings_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_derivatives_trade_warnings_report_v01_mut(self) }
+}
+
+impl crate::auth_107_001_02::DerivativesTradeStateReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_derivatives_trade_state_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_derivatives_trade_state_report_v02_mut(self) }
+}
+
+impl crate::auth_108_001_02::DerivativesTradeMarginDataReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_derivatives_trade_margin_data_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_derivatives_trade_margin_data_report_v02_mut(self) }
+}
+
+impl crate::auth_109_001_02::DerivativesTradeMarginDataTransactionStateReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_derivatives_trade_margin_data_transaction_state_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_derivatives_trade_margin_data_transaction_state_report_v02_mut(self) }
+}
+
+impl crate::auth_112_001_01::CCPInteroperabilityReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_ccp_interoperability_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_ccp_interoperability_report_v01_mut(self) }
+}
+
+impl crate::auth_113_001_01::OrderBookReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_order_book_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_order_book_report_v01_mut(self) }
+}
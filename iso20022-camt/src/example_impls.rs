@@ -0,0 +1,1078 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `Example` for the generated types of this crate, generated by
+// generate-example.py from their facets (see `open_payments_common::example`).
+
+use open_payments_common::example::Example;
+
+impl Example for crate::camt_003_001_08::GetAccountV08 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			acct_qry_def: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_004_001_10::ReturnAccountV10 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_005_001_11::GetTransactionV11 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			tx_qry_def: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_006_001_11::ReturnTransactionV11 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_007_001_10::ModifyTransactionV10 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			mod_attr: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_008_001_11::CancelTransactionV11 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			pmt_id: Example::example(),
+			csh_acct: None,
+			cxl_rsn: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_009_001_08::GetLimitV08 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			lmt_qry_def: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_010_001_09::ReturnLimitV09 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_011_001_08::ModifyLimitV08 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			lmt_dtls: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_012_001_08::DeleteLimitV08 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			lmt_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_013_001_04::GetMemberV04 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			mmb_qry_def: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_014_001_05::ReturnMemberV05 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_015_001_04::ModifyMemberV04 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			mmb_id: Example::example(),
+			new_mmb_val_set: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_016_001_04::GetCurrencyExchangeRateV04 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			ccy_qry_def: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_017_001_05::ReturnCurrencyExchangeRateV05 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_018_001_05::GetBusinessDayInformationV05 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			biz_day_inf_qry_def: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_019_001_07::ReturnBusinessDayInformationV07 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_020_001_04::GetGeneralBusinessInformationV04 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			gnl_biz_inf_qry_def: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_021_001_06::ReturnGeneralBusinessInformationV06 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_023_001_07::BackupPaymentV07 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			orgnl_msg_id: None,
+			instr_inf: None,
+			trfd_amt: Example::example(),
+			cdtr: Example::example(),
+			cdtr_agt: None,
+			dbtr_agt: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_024_001_08::ModifyStandingOrderV08 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			stg_ordr_id: Example::example(),
+			new_stg_ordr_val_set: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_025_001_08::ReceiptV08 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rct_dtls: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_026_001_10::UnableToApplyV10 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			undrlyg: Example::example(),
+			justfn: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_027_001_10::ClaimNonReceiptV10 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			undrlyg: Example::example(),
+			cover_dtls: None,
+			instr_for_assgne: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_028_001_12::AdditionalPaymentInformationV12 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			undrlyg: Example::example(),
+			inf: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_029_001_13::ResolutionOfInvestigationV13 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			rslvd_case: None,
+			sts: Example::example(),
+			cxl_dtls: None,
+			mod_dtls: None,
+			clm_non_rct_dtls: None,
+			stmt_dtls: None,
+			crrctn_tx: None,
+			rsltn_rltd_inf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_030_001_06::NotificationOfCaseAssignmentV06 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			case: Example::example(),
+			assgnmt: Example::example(),
+			ntfctn: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_031_001_07::RejectInvestigationV07 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			justfn: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_032_001_05::CancelCaseAssignmentV05 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_033_001_07::RequestForDuplicateV07 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_034_001_07::DuplicateV07 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			dplct: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_035_001_06::ProprietaryFormatInvestigationV06 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			prtry_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_036_001_06::DebitAuthorisationResponseV06 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			conf: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_037_001_10::DebitAuthorisationRequestV10 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			undrlyg: Example::example(),
+			dtl: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_038_001_05::CaseStatusReportRequestV05 {
+	fn example() -> Self {
+		Self {
+			req_hdr: Example::example(),
+			case: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_039_001_06::CaseStatusReportV06 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			case: Example::example(),
+			sts: Example::example(),
+			new_assgnmt: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_040_001_04::FundEstimatedCashForecastReportV04 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			pool_ref: None,
+			prvs_ref: None,
+			rltd_ref: None,
+			msg_pgntn: Example::example(),
+			fnd_or_sub_fnd_dtls: None,
+			estmtd_fnd_csh_fcst_dtls: None,
+			cnsltd_net_csh_fcst: None,
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::camt_041_001_04::FundConfirmedCashForecastReportV04 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			pool_ref: None,
+			prvs_ref: None,
+			rltd_ref: None,
+			msg_pgntn: Example::example(),
+			fnd_or_sub_fnd_dtls: None,
+			fnd_csh_fcst_dtls: None,
+			cnsltd_net_csh_fcst: None,
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::camt_042_001_04::FundDetailedEstimatedCashForecastReportV04 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			pool_ref: None,
+			prvs_ref: None,
+			rltd_ref: None,
+			msg_pgntn: Example::example(),
+			fnd_or_sub_fnd_dtls: None,
+			estmtd_fnd_csh_fcst_dtls: vec![Example::example()],
+			cnsltd_net_csh_fcst: None,
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::camt_043_001_04::FundDetailedConfirmedCashForecastReportV04 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			pool_ref: None,
+			prvs_ref: None,
+			rltd_ref: None,
+			msg_pgntn: Example::example(),
+			fnd_or_sub_fnd_dtls: None,
+			fnd_csh_fcst_dtls: vec![Example::example()],
+			cnsltd_net_csh_fcst: None,
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::camt_044_001_03::FundConfirmedCashForecastReportCancellationV03 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			pool_ref: None,
+			prvs_ref: None,
+			rltd_ref: None,
+			msg_pgntn: Example::example(),
+			csh_fcst_rpt_to_be_canc: None,
+		}
+	}
+}
+
+impl Example for crate::camt_045_001_03::FundDetailedConfirmedCashForecastReportCancellationV03 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			pool_ref: None,
+			prvs_ref: None,
+			rltd_ref: None,
+			msg_pgntn: Example::example(),
+			csh_fcst_rpt_to_be_canc: None,
+		}
+	}
+}
+
+impl Example for crate::camt_046_001_08::GetReservationV08 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rsvatn_qry_def: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_047_001_08::ReturnReservationV08 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_048_001_07::ModifyReservationV07 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rsvatn_id: Example::example(),
+			new_rsvatn_val_set: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_049_001_07::DeleteReservationV07 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			cur_rsvatn: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_050_001_07::LiquidityCreditTransferV07 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			lqdty_cdt_trf: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_051_001_07::LiquidityDebitTransferV07 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			lqdty_dbt_trf: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_052_001_12::BankToCustomerAccountReportV12 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			rpt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_053_001_12::BankToCustomerStatementV12 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			stmt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_054_001_12::BankToCustomerDebitCreditNotificationV12 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			ntfctn: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_055_001_12::CustomerPaymentCancellationRequestV12 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			ctrl_data: None,
+			undrlyg: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_056_001_11::FIToFIPaymentCancellationRequestV11 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			ctrl_data: None,
+			undrlyg: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_057_001_08::NotificationToReceiveV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			ntfctn: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_058_001_09::NotificationToReceiveCancellationAdviceV09 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_ntfctn: Example::example(),
+			cxl_rsn: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_059_001_08::NotificationToReceiveStatusReportV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_ntfctn_and_sts: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_060_001_07::AccountReportingRequestV07 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			rptg_req: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_061_001_02::PayInCallV02 {
+	fn example() -> Self {
+		Self {
+			pty_id: Example::example(),
+			rpt_data: Example::example(),
+			sttlm_ssn_idr: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_062_001_03::PayInScheduleV03 {
+	fn example() -> Self {
+		Self {
+			pty_id: Example::example(),
+			rpt_data: Example::example(),
+			pay_in_schdl_lng_bal: None,
+			pay_in_schdl_itm: None,
+			pay_in_fctrs: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_063_001_02::PayInEventAcknowledgementV02 {
+	fn example() -> Self {
+		Self {
+			msg_id: "MsgId".to_string(),
+			sttlm_ssn_idr: None,
+			ack_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_066_001_02::IntraBalanceMovementInstructionV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			corp_actn_evt_id: None,
+			nb_counts: None,
+			lnkgs: None,
+			csh_acct: Example::example(),
+			csh_acct_ownr: None,
+			csh_acct_svcr: None,
+			intra_bal: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_067_001_02::IntraBalanceMovementStatusAdviceV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			tx_id: Example::example(),
+			prcg_sts: None,
+			sttlm_sts: None,
+			csh_acct: Example::example(),
+			csh_acct_ownr: None,
+			csh_acct_svcr: None,
+			undrlyg_intra_bal: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_068_001_02::IntraBalanceMovementConfirmationV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			addtl_params: None,
+			csh_acct: Example::example(),
+			csh_acct_ownr: None,
+			csh_acct_svcr: None,
+			intra_bal: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_069_001_05::GetStandingOrderV05 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			stg_ordr_qry_def: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_070_001_06::ReturnStandingOrderV06 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_071_001_05::DeleteStandingOrderV05 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			stg_ordr_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_072_001_02::IntraBalanceMovementModificationRequestV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			csh_acct: Example::example(),
+			csh_acct_ownr: None,
+			csh_acct_svcr: None,
+			req_dtls: vec![Example::example()],
+			undrlyg_intra_bal: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_073_001_02::IntraBalanceMovementModificationRequestStatusAdviceV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			req_ref: "ReqRef".to_string(),
+			csh_acct: Example::example(),
+			csh_acct_ownr: None,
+			csh_acct_svcr: None,
+			req_dtls: None,
+			prcg_sts: Example::example(),
+			undrlyg_intra_bal: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_074_001_02::IntraBalanceMovementCancellationRequestV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			tx_id: Example::example(),
+			csh_acct: Example::example(),
+			csh_acct_ownr: None,
+			csh_acct_svcr: None,
+			undrlyg_intra_bal: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_075_001_02::IntraBalanceMovementCancellationRequestStatusAdviceV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			req_ref: "ReqRef".to_string(),
+			tx_id: Example::example(),
+			prcg_sts: Example::example(),
+			csh_acct: Example::example(),
+			csh_acct_ownr: None,
+			csh_acct_svcr: None,
+			undrlyg_intra_bal: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_078_001_02::IntraBalanceMovementQueryV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			qry_def: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_079_001_02::IntraBalanceMovementQueryResponseV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			pgntn: Example::example(),
+			rpt_gnl_dtls: Example::example(),
+			rpt_or_err: None,
+		}
+	}
+}
+
+impl Example for crate::camt_080_001_02::IntraBalanceMovementModificationQueryV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			qry_def: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_081_001_02::IntraBalanceMovementModificationReportV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			pgntn: Example::example(),
+			rpt_gnl_dtls: Example::example(),
+			rpt_or_err: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_082_001_02::IntraBalanceMovementCancellationQueryV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			qry_def: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_083_001_02::IntraBalanceMovementCancellationReportV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			pgntn: Example::example(),
+			rpt_gnl_dtls: Example::example(),
+			rpt_or_err: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_084_001_02::IntraBalanceMovementPostingReportV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			pgntn: Example::example(),
+			rpt_gnl_dtls: Example::example(),
+			csh_acct: Example::example(),
+			csh_acct_ownr: None,
+			csh_acct_svcr: None,
+			sub_bal: None,
+		}
+	}
+}
+
+impl Example for crate::camt_085_001_02::IntraBalanceMovementPendingReportV02 {
+	fn example() -> Self {
+		Self {
+			id: None,
+			pgntn: Example::example(),
+			rpt_gnl_dtls: Example::example(),
+			csh_acct: Example::example(),
+			csh_acct_ownr: None,
+			csh_acct_svcr: None,
+			mvmnts: None,
+		}
+	}
+}
+
+impl Example for crate::camt_086_001_05::BankServicesBillingStatementV05 {
+	fn example() -> Self {
+		Self {
+			rpt_hdr: Example::example(),
+			bllg_stmt_grp: vec![Example::example()],
+		}
+	}
+}
+
+impl Example for crate::camt_087_001_09::RequestToModifyPaymentV09 {
+	fn example() -> Self {
+		Self {
+			assgnmt: Example::example(),
+			case: None,
+			undrlyg: Example::example(),
+			mod_attr: Example::example(),
+			instr_for_assgne: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_088_001_02::NetReportV02 {
+	fn example() -> Self {
+		Self {
+			net_rpt_data: Example::example(),
+			net_svc_ptcpt_id: Example::example(),
+			net_svc_ctr_pty_id: None,
+			net_oblgtn: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_101_001_02::CreateLimitV02 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			lmt_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_102_001_03::CreateStandingOrderV03 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			stg_ordr_id: Example::example(),
+			val_set: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_103_001_03::CreateReservationV03 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rsvatn_id: Example::example(),
+			val_set: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_104_001_01::CreateMemberV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			mmb_id: Example::example(),
+			val_set: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_105_001_02::ChargesPaymentNotificationV02 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			chrgs: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_106_001_02::ChargesPaymentRequestV02 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			chrgs: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_107_001_02::ChequePresentmentNotificationV02 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			chq: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_108_001_02::ChequeCancellationOrStopRequestV02 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			chq: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_109_001_02::ChequeCancellationOrStopReportV02 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			chq: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_110_001_01::InvestigationRequestV01 {
+	fn example() -> Self {
+		Self {
+			invstgtn_req: Example::example(),
+			invstgtn_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::camt_111_001_01::InvestigationResponseV01 {
+	fn example() -> Self {
+		Self {
+			invstgtn_rspn: Example::example(),
+			orgnl_invstgtn_req: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
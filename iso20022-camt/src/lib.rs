@@ -115,3 +115,7 @@ pub mod camt_108_001_02;
 pub mod camt_109_001_02;
 pub mod camt_110_001_01;
 pub mod camt_111_001_01;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
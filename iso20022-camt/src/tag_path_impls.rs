@@ -0,0 +1,1577 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `TagPaths` for the generated types of this crate, generated by
+// generate-tag-path.py from the tags of their fields (see
+// `open_payments_common::tag_path`).
+
+use open_payments_common::tag_path::*;
+
+impl TagPaths for crate::camt_003_001_08::GetAccountV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("AcctQryDef") { optional(&self.acct_qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("AcctQryDef") { optional_mut(&mut self.acct_qry_def, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_004_001_10::ReturnAccountV10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_005_001_11::GetTransactionV11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("TxQryDef") { optional(&self.tx_qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("TxQryDef") { optional_mut(&mut self.tx_qry_def, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_006_001_11::ReturnTransactionV11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_007_001_10::ModifyTransactionV10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("Mod") { repeated(&self.mod_attr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("Mod") { repeated_mut(&mut self.mod_attr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_008_001_11::CancelTransactionV11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("PmtId") { one(&self.pmt_id, step, out) }
+		if step.matches("CshAcct") { optional(&self.csh_acct, step, out) }
+		if step.matches("CxlRsn") { optional(&self.cxl_rsn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("PmtId") { one_mut(&mut self.pmt_id, step, out) }
+		if step.matches("CshAcct") { optional_mut(&mut self.csh_acct, step, create, out) }
+		if step.matches("CxlRsn") { optional_mut(&mut self.cxl_rsn, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_009_001_08::GetLimitV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("LmtQryDef") { optional(&self.lmt_qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("LmtQryDef") { optional_mut(&mut self.lmt_qry_def, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_010_001_09::ReturnLimitV09 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_011_001_08::ModifyLimitV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("LmtDtls") { optional_repeated(&self.lmt_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("LmtDtls") { optional_repeated_mut(&mut self.lmt_dtls, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_012_001_08::DeleteLimitV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("LmtDtls") { one(&self.lmt_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("LmtDtls") { one_mut(&mut self.lmt_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_013_001_04::GetMemberV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("MmbQryDef") { optional(&self.mmb_qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("MmbQryDef") { optional_mut(&mut self.mmb_qry_def, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_014_001_05::ReturnMemberV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_015_001_04::ModifyMemberV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("MmbId") { one(&self.mmb_id, step, out) }
+		if step.matches("NewMmbValSet") { one(&self.new_mmb_val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("MmbId") { one_mut(&mut self.mmb_id, step, out) }
+		if step.matches("NewMmbValSet") { one_mut(&mut self.new_mmb_val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_016_001_04::GetCurrencyExchangeRateV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("CcyQryDef") { optional(&self.ccy_qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("CcyQryDef") { optional_mut(&mut self.ccy_qry_def, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_017_001_05::ReturnCurrencyExchangeRateV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_018_001_05::GetBusinessDayInformationV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("BizDayInfQryDef") { optional(&self.biz_day_inf_qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("BizDayInfQryDef") { optional_mut(&mut self.biz_day_inf_qry_def, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_019_001_07::ReturnBusinessDayInformationV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_020_001_04::GetGeneralBusinessInformationV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("GnlBizInfQryDef") { optional(&self.gnl_biz_inf_qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("GnlBizInfQryDef") { optional_mut(&mut self.gnl_biz_inf_qry_def, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_021_001_06::ReturnGeneralBusinessInformationV06 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_023_001_07::BackupPaymentV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("OrgnlMsgId") { optional(&self.orgnl_msg_id, step, out) }
+		if step.matches("InstrInf") { optional(&self.instr_inf, step, out) }
+		if step.matches("TrfdAmt") { one(&self.trfd_amt, step, out) }
+		if step.matches("Cdtr") { one(&self.cdtr, step, out) }
+		if step.matches("CdtrAgt") { optional(&self.cdtr_agt, step, out) }
+		if step.matches("DbtrAgt") { optional(&self.dbtr_agt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("OrgnlMsgId") { optional_mut(&mut self.orgnl_msg_id, step, create, out) }
+		if step.matches("InstrInf") { optional_mut(&mut self.instr_inf, step, create, out) }
+		if step.matches("TrfdAmt") { one_mut(&mut self.trfd_amt, step, out) }
+		if step.matches("Cdtr") { one_mut(&mut self.cdtr, step, out) }
+		if step.matches("CdtrAgt") { optional_mut(&mut self.cdtr_agt, step, create, out) }
+		if step.matches("DbtrAgt") { optional_mut(&mut self.dbtr_agt, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_024_001_08::ModifyStandingOrderV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("StgOrdrId") { one(&self.stg_ordr_id, step, out) }
+		if step.matches("NewStgOrdrValSet") { one(&self.new_stg_ordr_val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("StgOrdrId") { one_mut(&mut self.stg_ordr_id, step, out) }
+		if step.matches("NewStgOrdrValSet") { one_mut(&mut self.new_stg_ordr_val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_025_001_08::ReceiptV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RctDtls") { repeated(&self.rct_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RctDtls") { repeated_mut(&mut self.rct_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_026_001_10::UnableToApplyV10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Undrlyg") { one(&self.undrlyg, step, out) }
+		if step.matches("Justfn") { one(&self.justfn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Undrlyg") { one_mut(&mut self.undrlyg, step, out) }
+		if step.matches("Justfn") { one_mut(&mut self.justfn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_027_001_10::ClaimNonReceiptV10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Undrlyg") { one(&self.undrlyg, step, out) }
+		if step.matches("CoverDtls") { optional(&self.cover_dtls, step, out) }
+		if step.matches("InstrForAssgne") { optional(&self.instr_for_assgne, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Undrlyg") { one_mut(&mut self.undrlyg, step, out) }
+		if step.matches("CoverDtls") { optional_mut(&mut self.cover_dtls, step, create, out) }
+		if step.matches("InstrForAssgne") { optional_mut(&mut self.instr_for_assgne, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_028_001_12::AdditionalPaymentInformationV12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Undrlyg") { one(&self.undrlyg, step, out) }
+		if step.matches("Inf") { one(&self.inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Undrlyg") { one_mut(&mut self.undrlyg, step, out) }
+		if step.matches("Inf") { one_mut(&mut self.inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_029_001_13::ResolutionOfInvestigationV13 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("RslvdCase") { optional(&self.rslvd_case, step, out) }
+		if step.matches("Sts") { one(&self.sts, step, out) }
+		if step.matches("CxlDtls") { optional_repeated(&self.cxl_dtls, step, out) }
+		if step.matches("ModDtls") { optional(&self.mod_dtls, step, out) }
+		if step.matches("ClmNonRctDtls") { optional(&self.clm_non_rct_dtls, step, out) }
+		if step.matches("StmtDtls") { optional(&self.stmt_dtls, step, out) }
+		if step.matches("CrrctnTx") { optional(&self.crrctn_tx, step, out) }
+		if step.matches("RsltnRltdInf") { optional(&self.rsltn_rltd_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("RslvdCase") { optional_mut(&mut self.rslvd_case, step, create, out) }
+		if step.matches("Sts") { one_mut(&mut self.sts, step, out) }
+		if step.matches("CxlDtls") { optional_repeated_mut(&mut self.cxl_dtls, step, create, out) }
+		if step.matches("ModDtls") { optional_mut(&mut self.mod_dtls, step, create, out) }
+		if step.matches("ClmNonRctDtls") { optional_mut(&mut self.clm_non_rct_dtls, step, create, out) }
+		if step.matches("StmtDtls") { optional_mut(&mut self.stmt_dtls, step, create, out) }
+		if step.matches("CrrctnTx") { optional_mut(&mut self.crrctn_tx, step, create, out) }
+		if step.matches("RsltnRltdInf") { optional_mut(&mut self.rsltn_rltd_inf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_030_001_06::NotificationOfCaseAssignmentV06 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("Case") { one(&self.case, step, out) }
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Ntfctn") { one(&self.ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("Case") { one_mut(&mut self.case, step, out) }
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Ntfctn") { one_mut(&mut self.ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_031_001_07::RejectInvestigationV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Justfn") { one(&self.justfn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Justfn") { one_mut(&mut self.justfn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_032_001_05::CancelCaseAssignmentV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { one(&self.case, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { one_mut(&mut self.case, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_033_001_07::RequestForDuplicateV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_034_001_07::DuplicateV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Dplct") { one(&self.dplct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Dplct") { one_mut(&mut self.dplct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_035_001_06::ProprietaryFormatInvestigationV06 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("PrtryData") { one(&self.prtry_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("PrtryData") { one_mut(&mut self.prtry_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_036_001_06::DebitAuthorisationResponseV06 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Conf") { one(&self.conf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Conf") { one_mut(&mut self.conf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_037_001_10::DebitAuthorisationRequestV10 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Undrlyg") { one(&self.undrlyg, step, out) }
+		if step.matches("Dtl") { one(&self.dtl, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Undrlyg") { one_mut(&mut self.undrlyg, step, out) }
+		if step.matches("Dtl") { one_mut(&mut self.dtl, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_038_001_05::CaseStatusReportRequestV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ReqHdr") { one(&self.req_hdr, step, out) }
+		if step.matches("Case") { one(&self.case, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ReqHdr") { one_mut(&mut self.req_hdr, step, out) }
+		if step.matches("Case") { one_mut(&mut self.case, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_039_001_06::CaseStatusReportV06 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("Case") { one(&self.case, step, out) }
+		if step.matches("Sts") { one(&self.sts, step, out) }
+		if step.matches("NewAssgnmt") { optional(&self.new_assgnmt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("Case") { one_mut(&mut self.case, step, out) }
+		if step.matches("Sts") { one_mut(&mut self.sts, step, out) }
+		if step.matches("NewAssgnmt") { optional_mut(&mut self.new_assgnmt, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_040_001_04::FundEstimatedCashForecastReportV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional(&self.pool_ref, step, out) }
+		if step.matches("PrvsRef") { optional_repeated(&self.prvs_ref, step, out) }
+		if step.matches("RltdRef") { optional_repeated(&self.rltd_ref, step, out) }
+		if step.matches("MsgPgntn") { one(&self.msg_pgntn, step, out) }
+		if step.matches("FndOrSubFndDtls") { optional_repeated(&self.fnd_or_sub_fnd_dtls, step, out) }
+		if step.matches("EstmtdFndCshFcstDtls") { optional_repeated(&self.estmtd_fnd_csh_fcst_dtls, step, out) }
+		if step.matches("CnsltdNetCshFcst") { optional(&self.cnsltd_net_csh_fcst, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional_mut(&mut self.pool_ref, step, create, out) }
+		if step.matches("PrvsRef") { optional_repeated_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_repeated_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("MsgPgntn") { one_mut(&mut self.msg_pgntn, step, out) }
+		if step.matches("FndOrSubFndDtls") { optional_repeated_mut(&mut self.fnd_or_sub_fnd_dtls, step, create, out) }
+		if step.matches("EstmtdFndCshFcstDtls") { optional_repeated_mut(&mut self.estmtd_fnd_csh_fcst_dtls, step, create, out) }
+		if step.matches("CnsltdNetCshFcst") { optional_mut(&mut self.cnsltd_net_csh_fcst, step, create, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_041_001_04::FundConfirmedCashForecastReportV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional(&self.pool_ref, step, out) }
+		if step.matches("PrvsRef") { optional_repeated(&self.prvs_ref, step, out) }
+		if step.matches("RltdRef") { optional_repeated(&self.rltd_ref, step, out) }
+		if step.matches("MsgPgntn") { one(&self.msg_pgntn, step, out) }
+		if step.matches("FndOrSubFndDtls") { optional_repeated(&self.fnd_or_sub_fnd_dtls, step, out) }
+		if step.matches("FndCshFcstDtls") { optional_repeated(&self.fnd_csh_fcst_dtls, step, out) }
+		if step.matches("CnsltdNetCshFcst") { optional(&self.cnsltd_net_csh_fcst, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional_mut(&mut self.pool_ref, step, create, out) }
+		if step.matches("PrvsRef") { optional_repeated_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_repeated_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("MsgPgntn") { one_mut(&mut self.msg_pgntn, step, out) }
+		if step.matches("FndOrSubFndDtls") { optional_repeated_mut(&mut self.fnd_or_sub_fnd_dtls, step, create, out) }
+		if step.matches("FndCshFcstDtls") { optional_repeated_mut(&mut self.fnd_csh_fcst_dtls, step, create, out) }
+		if step.matches("CnsltdNetCshFcst") { optional_mut(&mut self.cnsltd_net_csh_fcst, step, create, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_042_001_04::FundDetailedEstimatedCashForecastReportV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional(&self.pool_ref, step, out) }
+		if step.matches("PrvsRef") { optional_repeated(&self.prvs_ref, step, out) }
+		if step.matches("RltdRef") { optional_repeated(&self.rltd_ref, step, out) }
+		if step.matches("MsgPgntn") { one(&self.msg_pgntn, step, out) }
+		if step.matches("FndOrSubFndDtls") { optional(&self.fnd_or_sub_fnd_dtls, step, out) }
+		if step.matches("EstmtdFndCshFcstDtls") { repeated(&self.estmtd_fnd_csh_fcst_dtls, step, out) }
+		if step.matches("CnsltdNetCshFcst") { optional(&self.cnsltd_net_csh_fcst, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional_mut(&mut self.pool_ref, step, create, out) }
+		if step.matches("PrvsRef") { optional_repeated_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_repeated_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("MsgPgntn") { one_mut(&mut self.msg_pgntn, step, out) }
+		if step.matches("FndOrSubFndDtls") { optional_mut(&mut self.fnd_or_sub_fnd_dtls, step, create, out) }
+		if step.matches("EstmtdFndCshFcstDtls") { repeated_mut(&mut self.estmtd_fnd_csh_fcst_dtls, step, out) }
+		if step.matches("CnsltdNetCshFcst") { optional_mut(&mut self.cnsltd_net_csh_fcst, step, create, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_043_001_04::FundDetailedConfirmedCashForecastReportV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional(&self.pool_ref, step, out) }
+		if step.matches("PrvsRef") { optional_repeated(&self.prvs_ref, step, out) }
+		if step.matches("RltdRef") { optional_repeated(&self.rltd_ref, step, out) }
+		if step.matches("MsgPgntn") { one(&self.msg_pgntn, step, out) }
+		if step.matches("FndOrSubFndDtls") { optional(&self.fnd_or_sub_fnd_dtls, step, out) }
+		if step.matches("FndCshFcstDtls") { repeated(&self.fnd_csh_fcst_dtls, step, out) }
+		if step.matches("CnsltdNetCshFcst") { optional(&self.cnsltd_net_csh_fcst, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional_mut(&mut self.pool_ref, step, create, out) }
+		if step.matches("PrvsRef") { optional_repeated_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_repeated_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("MsgPgntn") { one_mut(&mut self.msg_pgntn, step, out) }
+		if step.matches("FndOrSubFndDtls") { optional_mut(&mut self.fnd_or_sub_fnd_dtls, step, create, out) }
+		if step.matches("FndCshFcstDtls") { repeated_mut(&mut self.fnd_csh_fcst_dtls, step, out) }
+		if step.matches("CnsltdNetCshFcst") { optional_mut(&mut self.cnsltd_net_csh_fcst, step, create, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_044_001_03::FundConfirmedCashForecastReportCancellationV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional(&self.pool_ref, step, out) }
+		if step.matches("PrvsRef") { optional(&self.prvs_ref, step, out) }
+		if step.matches("RltdRef") { optional_repeated(&self.rltd_ref, step, out) }
+		if step.matches("MsgPgntn") { one(&self.msg_pgntn, step, out) }
+		if step.matches("CshFcstRptToBeCanc") { optional(&self.csh_fcst_rpt_to_be_canc, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional_mut(&mut self.pool_ref, step, create, out) }
+		if step.matches("PrvsRef") { optional_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_repeated_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("MsgPgntn") { one_mut(&mut self.msg_pgntn, step, out) }
+		if step.matches("CshFcstRptToBeCanc") { optional_mut(&mut self.csh_fcst_rpt_to_be_canc, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_045_001_03::FundDetailedConfirmedCashForecastReportCancellationV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional(&self.pool_ref, step, out) }
+		if step.matches("PrvsRef") { optional(&self.prvs_ref, step, out) }
+		if step.matches("RltdRef") { optional_repeated(&self.rltd_ref, step, out) }
+		if step.matches("MsgPgntn") { one(&self.msg_pgntn, step, out) }
+		if step.matches("CshFcstRptToBeCanc") { optional(&self.csh_fcst_rpt_to_be_canc, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional_mut(&mut self.pool_ref, step, create, out) }
+		if step.matches("PrvsRef") { optional_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_repeated_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("MsgPgntn") { one_mut(&mut self.msg_pgntn, step, out) }
+		if step.matches("CshFcstRptToBeCanc") { optional_mut(&mut self.csh_fcst_rpt_to_be_canc, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_046_001_08::GetReservationV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RsvatnQryDef") { optional(&self.rsvatn_qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RsvatnQryDef") { optional_mut(&mut self.rsvatn_qry_def, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_047_001_08::ReturnReservationV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_048_001_07::ModifyReservationV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RsvatnId") { one(&self.rsvatn_id, step, out) }
+		if step.matches("NewRsvatnValSet") { one(&self.new_rsvatn_val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RsvatnId") { one_mut(&mut self.rsvatn_id, step, out) }
+		if step.matches("NewRsvatnValSet") { one_mut(&mut self.new_rsvatn_val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_049_001_07::DeleteReservationV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("CurRsvatn") { optional(&self.cur_rsvatn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("CurRsvatn") { optional_mut(&mut self.cur_rsvatn, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_050_001_07::LiquidityCreditTransferV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("LqdtyCdtTrf") { one(&self.lqdty_cdt_trf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("LqdtyCdtTrf") { one_mut(&mut self.lqdty_cdt_trf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_051_001_07::LiquidityDebitTransferV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("LqdtyDbtTrf") { one(&self.lqdty_dbt_trf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("LqdtyDbtTrf") { one_mut(&mut self.lqdty_dbt_trf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_052_001_12::BankToCustomerAccountReportV12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Rpt") { repeated(&self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Rpt") { repeated_mut(&mut self.rpt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_053_001_12::BankToCustomerStatementV12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Stmt") { repeated(&self.stmt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Stmt") { repeated_mut(&mut self.stmt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_054_001_12::BankToCustomerDebitCreditNotificationV12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Ntfctn") { repeated(&self.ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Ntfctn") { repeated_mut(&mut self.ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_055_001_12::CustomerPaymentCancellationRequestV12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("CtrlData") { optional(&self.ctrl_data, step, out) }
+		if step.matches("Undrlyg") { repeated(&self.undrlyg, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("CtrlData") { optional_mut(&mut self.ctrl_data, step, create, out) }
+		if step.matches("Undrlyg") { repeated_mut(&mut self.undrlyg, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_056_001_11::FIToFIPaymentCancellationRequestV11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("CtrlData") { optional(&self.ctrl_data, step, out) }
+		if step.matches("Undrlyg") { repeated(&self.undrlyg, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("CtrlData") { optional_mut(&mut self.ctrl_data, step, create, out) }
+		if step.matches("Undrlyg") { repeated_mut(&mut self.undrlyg, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_057_001_08::NotificationToReceiveV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Ntfctn") { one(&self.ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Ntfctn") { one_mut(&mut self.ntfctn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_058_001_09::NotificationToReceiveCancellationAdviceV09 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlNtfctn") { one(&self.orgnl_ntfctn, step, out) }
+		if step.matches("CxlRsn") { optional(&self.cxl_rsn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlNtfctn") { one_mut(&mut self.orgnl_ntfctn, step, out) }
+		if step.matches("CxlRsn") { optional_mut(&mut self.cxl_rsn, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_059_001_08::NotificationToReceiveStatusReportV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlNtfctnAndSts") { one(&self.orgnl_ntfctn_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlNtfctnAndSts") { one_mut(&mut self.orgnl_ntfctn_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_060_001_07::AccountReportingRequestV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("RptgReq") { repeated(&self.rptg_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("RptgReq") { repeated_mut(&mut self.rptg_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_061_001_02::PayInCallV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PtyId") { one(&self.pty_id, step, out) }
+		if step.matches("RptData") { one(&self.rpt_data, step, out) }
+		if step.matches("SttlmSsnIdr") { optional(&self.sttlm_ssn_idr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PtyId") { one_mut(&mut self.pty_id, step, out) }
+		if step.matches("RptData") { one_mut(&mut self.rpt_data, step, out) }
+		if step.matches("SttlmSsnIdr") { optional_mut(&mut self.sttlm_ssn_idr, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_062_001_03::PayInScheduleV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("PtyId") { one(&self.pty_id, step, out) }
+		if step.matches("RptData") { one(&self.rpt_data, step, out) }
+		if step.matches("PayInSchdlLngBal") { optional_repeated(&self.pay_in_schdl_lng_bal, step, out) }
+		if step.matches("PayInSchdlItm") { optional_repeated(&self.pay_in_schdl_itm, step, out) }
+		if step.matches("PayInFctrs") { optional(&self.pay_in_fctrs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("PtyId") { one_mut(&mut self.pty_id, step, out) }
+		if step.matches("RptData") { one_mut(&mut self.rpt_data, step, out) }
+		if step.matches("PayInSchdlLngBal") { optional_repeated_mut(&mut self.pay_in_schdl_lng_bal, step, create, out) }
+		if step.matches("PayInSchdlItm") { optional_repeated_mut(&mut self.pay_in_schdl_itm, step, create, out) }
+		if step.matches("PayInFctrs") { optional_mut(&mut self.pay_in_fctrs, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_063_001_02::PayInEventAcknowledgementV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("SttlmSsnIdr") { optional(&self.sttlm_ssn_idr, step, out) }
+		if step.matches("AckDtls") { one(&self.ack_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("SttlmSsnIdr") { optional_mut(&mut self.sttlm_ssn_idr, step, create, out) }
+		if step.matches("AckDtls") { one_mut(&mut self.ack_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_066_001_02::IntraBalanceMovementInstructionV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("CorpActnEvtId") { optional(&self.corp_actn_evt_id, step, out) }
+		if step.matches("NbCounts") { optional(&self.nb_counts, step, out) }
+		if step.matches("Lnkgs") { optional_repeated(&self.lnkgs, step, out) }
+		if step.matches("CshAcct") { one(&self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional(&self.csh_acct_ownr, step, out) }
+		if step.matches("CshAcctSvcr") { optional(&self.csh_acct_svcr, step, out) }
+		if step.matches("IntraBal") { one(&self.intra_bal, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("CorpActnEvtId") { optional_mut(&mut self.corp_actn_evt_id, step, create, out) }
+		if step.matches("NbCounts") { optional_mut(&mut self.nb_counts, step, create, out) }
+		if step.matches("Lnkgs") { optional_repeated_mut(&mut self.lnkgs, step, create, out) }
+		if step.matches("CshAcct") { one_mut(&mut self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional_mut(&mut self.csh_acct_ownr, step, create, out) }
+		if step.matches("CshAcctSvcr") { optional_mut(&mut self.csh_acct_svcr, step, create, out) }
+		if step.matches("IntraBal") { one_mut(&mut self.intra_bal, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_067_001_02::IntraBalanceMovementStatusAdviceV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("TxId") { one(&self.tx_id, step, out) }
+		if step.matches("PrcgSts") { optional(&self.prcg_sts, step, out) }
+		if step.matches("SttlmSts") { optional(&self.sttlm_sts, step, out) }
+		if step.matches("CshAcct") { one(&self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional(&self.csh_acct_ownr, step, out) }
+		if step.matches("CshAcctSvcr") { optional(&self.csh_acct_svcr, step, out) }
+		if step.matches("UndrlygIntraBal") { optional(&self.undrlyg_intra_bal, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("TxId") { one_mut(&mut self.tx_id, step, out) }
+		if step.matches("PrcgSts") { optional_mut(&mut self.prcg_sts, step, create, out) }
+		if step.matches("SttlmSts") { optional_mut(&mut self.sttlm_sts, step, create, out) }
+		if step.matches("CshAcct") { one_mut(&mut self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional_mut(&mut self.csh_acct_ownr, step, create, out) }
+		if step.matches("CshAcctSvcr") { optional_mut(&mut self.csh_acct_svcr, step, create, out) }
+		if step.matches("UndrlygIntraBal") { optional_mut(&mut self.undrlyg_intra_bal, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_068_001_02::IntraBalanceMovementConfirmationV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("AddtlParams") { optional(&self.addtl_params, step, out) }
+		if step.matches("CshAcct") { one(&self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional(&self.csh_acct_ownr, step, out) }
+		if step.matches("CshAcctSvcr") { optional(&self.csh_acct_svcr, step, out) }
+		if step.matches("IntraBal") { one(&self.intra_bal, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("AddtlParams") { optional_mut(&mut self.addtl_params, step, create, out) }
+		if step.matches("CshAcct") { one_mut(&mut self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional_mut(&mut self.csh_acct_ownr, step, create, out) }
+		if step.matches("CshAcctSvcr") { optional_mut(&mut self.csh_acct_svcr, step, create, out) }
+		if step.matches("IntraBal") { one_mut(&mut self.intra_bal, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_069_001_05::GetStandingOrderV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("StgOrdrQryDef") { optional(&self.stg_ordr_qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("StgOrdrQryDef") { optional_mut(&mut self.stg_ordr_qry_def, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_070_001_06::ReturnStandingOrderV06 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_071_001_05::DeleteStandingOrderV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("StgOrdrDtls") { one(&self.stg_ordr_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("StgOrdrDtls") { one_mut(&mut self.stg_ordr_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_072_001_02::IntraBalanceMovementModificationRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("CshAcct") { one(&self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional(&self.csh_acct_ownr, step, out) }
+		if step.matches("CshAcctSvcr") { optional(&self.csh_acct_svcr, step, out) }
+		if step.matches("ReqDtls") { repeated(&self.req_dtls, step, out) }
+		if step.matches("UndrlygIntraBal") { optional(&self.undrlyg_intra_bal, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("CshAcct") { one_mut(&mut self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional_mut(&mut self.csh_acct_ownr, step, create, out) }
+		if step.matches("CshAcctSvcr") { optional_mut(&mut self.csh_acct_svcr, step, create, out) }
+		if step.matches("ReqDtls") { repeated_mut(&mut self.req_dtls, step, out) }
+		if step.matches("UndrlygIntraBal") { optional_mut(&mut self.undrlyg_intra_bal, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_073_001_02::IntraBalanceMovementModificationRequestStatusAdviceV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("ReqRef") { one(&self.req_ref, step, out) }
+		if step.matches("CshAcct") { one(&self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional(&self.csh_acct_ownr, step, out) }
+		if step.matches("CshAcctSvcr") { optional(&self.csh_acct_svcr, step, out) }
+		if step.matches("ReqDtls") { optional(&self.req_dtls, step, out) }
+		if step.matches("PrcgSts") { one(&self.prcg_sts, step, out) }
+		if step.matches("UndrlygIntraBal") { optional(&self.undrlyg_intra_bal, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("ReqRef") { one_mut(&mut self.req_ref, step, out) }
+		if step.matches("CshAcct") { one_mut(&mut self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional_mut(&mut self.csh_acct_ownr, step, create, out) }
+		if step.matches("CshAcctSvcr") { optional_mut(&mut self.csh_acct_svcr, step, create, out) }
+		if step.matches("ReqDtls") { optional_mut(&mut self.req_dtls, step, create, out) }
+		if step.matches("PrcgSts") { one_mut(&mut self.prcg_sts, step, out) }
+		if step.matches("UndrlygIntraBal") { optional_mut(&mut self.undrlyg_intra_bal, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_074_001_02::IntraBalanceMovementCancellationRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("TxId") { one(&self.tx_id, step, out) }
+		if step.matches("CshAcct") { one(&self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional(&self.csh_acct_ownr, step, out) }
+		if step.matches("CshAcctSvcr") { optional(&self.csh_acct_svcr, step, out) }
+		if step.matches("UndrlygIntraBal") { optional(&self.undrlyg_intra_bal, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("TxId") { one_mut(&mut self.tx_id, step, out) }
+		if step.matches("CshAcct") { one_mut(&mut self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional_mut(&mut self.csh_acct_ownr, step, create, out) }
+		if step.matches("CshAcctSvcr") { optional_mut(&mut self.csh_acct_svcr, step, create, out) }
+		if step.matches("UndrlygIntraBal") { optional_mut(&mut self.undrlyg_intra_bal, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_075_001_02::IntraBalanceMovementCancellationRequestStatusAdviceV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("ReqRef") { one(&self.req_ref, step, out) }
+		if step.matches("TxId") { one(&self.tx_id, step, out) }
+		if step.matches("PrcgSts") { one(&self.prcg_sts, step, out) }
+		if step.matches("CshAcct") { one(&self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional(&self.csh_acct_ownr, step, out) }
+		if step.matches("CshAcctSvcr") { optional(&self.csh_acct_svcr, step, out) }
+		if step.matches("UndrlygIntraBal") { optional(&self.undrlyg_intra_bal, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("ReqRef") { one_mut(&mut self.req_ref, step, out) }
+		if step.matches("TxId") { one_mut(&mut self.tx_id, step, out) }
+		if step.matches("PrcgSts") { one_mut(&mut self.prcg_sts, step, out) }
+		if step.matches("CshAcct") { one_mut(&mut self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional_mut(&mut self.csh_acct_ownr, step, create, out) }
+		if step.matches("CshAcctSvcr") { optional_mut(&mut self.csh_acct_svcr, step, create, out) }
+		if step.matches("UndrlygIntraBal") { optional_mut(&mut self.undrlyg_intra_bal, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_078_001_02::IntraBalanceMovementQueryV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("QryDef") { one(&self.qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("QryDef") { one_mut(&mut self.qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_079_001_02::IntraBalanceMovementQueryResponseV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("Pgntn") { one(&self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one(&self.rpt_gnl_dtls, step, out) }
+		if step.matches("RptOrErr") { optional(&self.rpt_or_err, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("Pgntn") { one_mut(&mut self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one_mut(&mut self.rpt_gnl_dtls, step, out) }
+		if step.matches("RptOrErr") { optional_mut(&mut self.rpt_or_err, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_080_001_02::IntraBalanceMovementModificationQueryV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("QryDef") { one(&self.qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("QryDef") { one_mut(&mut self.qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_081_001_02::IntraBalanceMovementModificationReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("Pgntn") { one(&self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one(&self.rpt_gnl_dtls, step, out) }
+		if step.matches("RptOrErr") { optional(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("Pgntn") { one_mut(&mut self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one_mut(&mut self.rpt_gnl_dtls, step, out) }
+		if step.matches("RptOrErr") { optional_mut(&mut self.rpt_or_err, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_082_001_02::IntraBalanceMovementCancellationQueryV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("QryDef") { one(&self.qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("QryDef") { one_mut(&mut self.qry_def, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_083_001_02::IntraBalanceMovementCancellationReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("Pgntn") { one(&self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one(&self.rpt_gnl_dtls, step, out) }
+		if step.matches("RptOrErr") { optional(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("Pgntn") { one_mut(&mut self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one_mut(&mut self.rpt_gnl_dtls, step, out) }
+		if step.matches("RptOrErr") { optional_mut(&mut self.rpt_or_err, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_084_001_02::IntraBalanceMovementPostingReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("Pgntn") { one(&self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one(&self.rpt_gnl_dtls, step, out) }
+		if step.matches("CshAcct") { one(&self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional(&self.csh_acct_ownr, step, out) }
+		if step.matches("CshAcctSvcr") { optional(&self.csh_acct_svcr, step, out) }
+		if step.matches("SubBal") { optional_repeated(&self.sub_bal, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("Pgntn") { one_mut(&mut self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one_mut(&mut self.rpt_gnl_dtls, step, out) }
+		if step.matches("CshAcct") { one_mut(&mut self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional_mut(&mut self.csh_acct_ownr, step, create, out) }
+		if step.matches("CshAcctSvcr") { optional_mut(&mut self.csh_acct_svcr, step, create, out) }
+		if step.matches("SubBal") { optional_repeated_mut(&mut self.sub_bal, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_085_001_02::IntraBalanceMovementPendingReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Id") { optional(&self.id, step, out) }
+		if step.matches("Pgntn") { one(&self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one(&self.rpt_gnl_dtls, step, out) }
+		if step.matches("CshAcct") { one(&self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional(&self.csh_acct_ownr, step, out) }
+		if step.matches("CshAcctSvcr") { optional(&self.csh_acct_svcr, step, out) }
+		if step.matches("Mvmnts") { optional_repeated(&self.mvmnts, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Id") { optional_mut(&mut self.id, step, create, out) }
+		if step.matches("Pgntn") { one_mut(&mut self.pgntn, step, out) }
+		if step.matches("RptGnlDtls") { one_mut(&mut self.rpt_gnl_dtls, step, out) }
+		if step.matches("CshAcct") { one_mut(&mut self.csh_acct, step, out) }
+		if step.matches("CshAcctOwnr") { optional_mut(&mut self.csh_acct_ownr, step, create, out) }
+		if step.matches("CshAcctSvcr") { optional_mut(&mut self.csh_acct_svcr, step, create, out) }
+		if step.matches("Mvmnts") { optional_repeated_mut(&mut self.mvmnts, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_086_001_05::BankServicesBillingStatementV05 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptHdr") { one(&self.rpt_hdr, step, out) }
+		if step.matches("BllgStmtGrp") { repeated(&self.bllg_stmt_grp, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptHdr") { one_mut(&mut self.rpt_hdr, step, out) }
+		if step.matches("BllgStmtGrp") { repeated_mut(&mut self.bllg_stmt_grp, step, out) }
+	}
+}
+
+impl TagPaths for crate::camt_087_001_09::RequestToModifyPaymentV09 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Assgnmt") { one(&self.assgnmt, step, out) }
+		if step.matches("Case") { optional(&self.case, step, out) }
+		if step.matches("Undrlyg") { one(&self.undrlyg, step, out) }
+		if step.matches("Mod") { one(&self.mod_attr, step, out) }
+		if step.matches("InstrForAssgne") { optional(&self.instr_for_assgne, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Assgnmt") { one_mut(&mut self.assgnmt, step, out) }
+		if step.matches("Case") { optional_mut(&mut self.case, step, create, out) }
+		if step.matches("Undrlyg") { one_mut(&mut self.undrlyg, step, out) }
+		if step.matches("Mod") { one_mut(&mut self.mod_attr, step, out) }
+		if step.matches("InstrForAssgne") { optional_mut(&mut self.instr_for_assgne, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_088_001_02::NetReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("NetRptData") { one(&self.net_rpt_data, step, out) }
+		if step.matches("NetSvcPtcptId") { one(&self.net_svc_ptcpt_id, step, out) }
+		if step.matches("NetSvcCtrPtyId") { optional(&self.net_svc_ctr_pty_id, step, out) }
+		if step.matches("NetOblgtn") { repeated(&self.net_oblgtn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("NetRptData") { one_mut(&mut self.net_rpt_data, step, out) }
+		if step.matches("NetSvcPtcptId") { one_mut(&mut self.net_svc_ptcpt_id, step, out) }
+		if step.matches("NetSvcCtrPtyId") { optional_mut(&mut self.net_svc_ctr_pty_id, step, create, out) }
+		if step.matches("NetOblgtn") { repeated_mut(&mut self.net_oblgtn, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_101_001_02::CreateLimitV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("LmtData") { repeated(&self.lmt_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("LmtData") { repeated_mut(&mut self.lmt_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_102_001_03::CreateStandingOrderV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("StgOrdrId") { one(&self.stg_ordr_id, step, out) }
+		if step.matches("ValSet") { one(&self.val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("StgOrdrId") { one_mut(&mut self.stg_ordr_id, step, out) }
+		if step.matches("ValSet") { one_mut(&mut self.val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_103_001_03::CreateReservationV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RsvatnId") { one(&self.rsvatn_id, step, out) }
+		if step.matches("ValSet") { one(&self.val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RsvatnId") { one_mut(&mut self.rsvatn_id, step, out) }
+		if step.matches("ValSet") { one_mut(&mut self.val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_104_001_01::CreateMemberV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("MmbId") { one(&self.mmb_id, step, out) }
+		if step.matches("ValSet") { one(&self.val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("MmbId") { one_mut(&mut self.mmb_id, step, out) }
+		if step.matches("ValSet") { one_mut(&mut self.val_set, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_105_001_02::ChargesPaymentNotificationV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Chrgs") { one(&self.chrgs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Chrgs") { one_mut(&mut self.chrgs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_106_001_02::ChargesPaymentRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Chrgs") { one(&self.chrgs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Chrgs") { one_mut(&mut self.chrgs, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_107_001_02::ChequePresentmentNotificationV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Chq") { repeated(&self.chq, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Chq") { repeated_mut(&mut self.chq, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_108_001_02::ChequeCancellationOrStopRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Chq") { repeated(&self.chq, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Chq") { repeated_mut(&mut self.chq, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_109_001_02::ChequeCancellationOrStopReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Chq") { repeated(&self.chq, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Chq") { repeated_mut(&mut self.chq, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_110_001_01::InvestigationRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InvstgtnReq") { one(&self.invstgtn_req, step, out) }
+		if step.matches("InvstgtnData") { repeated(&self.invstgtn_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InvstgtnReq") { one_mut(&mut self.invstgtn_req, step, out) }
+		if step.matches("InvstgtnData") { repeated_mut(&mut self.invstgtn_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::camt_111_001_01::InvestigationResponseV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("InvstgtnRspn") { one(&self.invstgtn_rspn, step, out) }
+		if step.matches("OrgnlInvstgtnReq") { one(&self.orgnl_invstgtn_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("InvstgtnRspn") { one_mut(&mut self.invstgtn_rspn, step, out) }
+		if step.matches("OrgnlInvstgtnReq") { one_mut(&mut self.orgnl_invstgtn_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
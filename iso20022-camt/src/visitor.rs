@@ -0,0 +1,2033 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over the generated types of this crate, generated by
+// generate-visitor.py. `Visitor` has a `visit_` method per type that
+// walks into its fields by default; override the methods of the types of
+// interest and call the matching `walk_` function to keep going below
+// them. `VisitorMut` does the same with mutable references.
+
+use open_payments_common::visitor::{Visitor as BaseVisitor, VisitorMut as BaseVisitorMut};
+
+pub trait Visitor: BaseVisitor {
+	fn visit_get_account_v08(&mut self, node: &crate::camt_003_001_08::GetAccountV08) { walk_get_account_v08(self, node) }
+	fn visit_return_account_v10(&mut self, node: &crate::camt_004_001_10::ReturnAccountV10) { walk_return_account_v10(self, node) }
+	fn visit_get_transaction_v11(&mut self, node: &crate::camt_005_001_11::GetTransactionV11) { walk_get_transaction_v11(self, node) }
+	fn visit_return_transaction_v11(&mut self, node: &crate::camt_006_001_11::ReturnTransactionV11) { walk_return_transaction_v11(self, node) }
+	fn visit_modify_transaction_v10(&mut self, node: &crate::camt_007_001_10::ModifyTransactionV10) { walk_modify_transaction_v10(self, node) }
+	fn visit_cancel_transaction_v11(&mut self, node: &crate::camt_008_001_11::CancelTransactionV11) { walk_cancel_transaction_v11(self, node) }
+	fn visit_get_limit_v08(&mut self, node: &crate::camt_009_001_08::GetLimitV08) { walk_get_limit_v08(self, node) }
+	fn visit_return_limit_v09(&mut self, node: &crate::camt_010_001_09::ReturnLimitV09) { walk_return_limit_v09(self, node) }
+	fn visit_modify_limit_v08(&mut self, node: &crate::camt_011_001_08::ModifyLimitV08) { walk_modify_limit_v08(self, node) }
+	fn visit_delete_limit_v08(&mut self, node: &crate::camt_012_001_08::DeleteLimitV08) { walk_delete_limit_v08(self, node) }
+	fn visit_get_member_v04(&mut self, node: &crate::camt_013_001_04::GetMemberV04) { walk_get_member_v04(self, node) }
+	fn visit_return_member_v05(&mut self, node: &crate::camt_014_001_05::ReturnMemberV05) { walk_return_member_v05(self, node) }
+	fn visit_modify_member_v04(&mut self, node: &crate::camt_015_001_04::ModifyMemberV04) { walk_modify_member_v04(self, node) }
+	fn visit_get_currency_exchange_rate_v04(&mut self, node: &crate::camt_016_001_04::GetCurrencyExchangeRateV04) { walk_get_currency_exchange_rate_v04(self, node) }
+	fn visit_return_currency_exchange_rate_v05(&mut self, node: &crate::camt_017_001_05::ReturnCurrencyExchangeRateV05) { walk_return_currency_exchange_rate_v05(self, node) }
+	fn visit_get_business_day_information_v05(&mut self, node: &crate::camt_018_001_05::GetBusinessDayInformationV05) { walk_get_business_day_information_v05(self, node) }
+	fn visit_return_business_day_information_v07(&mut self, node: &crate::camt_019_001_07::ReturnBusinessDayInformationV07) { walk_return_business_day_information_v07(self, node) }
+	fn visit_get_general_business_information_v04(&mut self, node: &crate::camt_020_001_04::GetGeneralBusinessInformationV04) { walk_get_general_business_information_v04(self, node) }
+	fn visit_return_general_business_information_v06(&mut self, node: &crate::camt_021_001_06::ReturnGeneralBusinessInformationV06) { walk_return_general_business_information_v06(self, node) }
+	fn visit_backup_payment_v07(&mut self, node: &crate::camt_023_001_07::BackupPaymentV07) { walk_backup_payment_v07(self, node) }
+	fn visit_modify_standing_order_v08(&mut self, node: &crate::camt_024_001_08::ModifyStandingOrderV08) { walk_modify_standing_order_v08(self, node) }
+	fn visit_receipt_v08(&mut self, node: &crate::camt_025_001_08::ReceiptV08) { walk_receipt_v08(self, node) }
+	fn visit_unable_to_apply_v10(&mut self, node: &crate::camt_026_001_10::UnableToApplyV10) { walk_unable_to_apply_v10(self, node) }
+	fn visit_claim_non_receipt_v10(&mut self, node: &crate::camt_027_001_10::ClaimNonReceiptV10) { walk_claim_non_receipt_v10(self, node) }
+	fn visit_additional_payment_information_v12(&mut self, node: &crate::camt_028_001_12::AdditionalPaymentInformationV12) { walk_additional_payment_information_v12(self, node) }
+	fn visit_resolution_of_investigation_v13(&mut self, node: &crate::camt_029_001_13::ResolutionOfInvestigationV13) { walk_resolution_of_investigation_v13(self, node) }
+	fn visit_notification_of_case_assignment_v06(&mut self, node: &crate::camt_030_001_06::NotificationOfCaseAssignmentV06) { walk_notification_of_case_assignment_v06(self, node) }
+	fn visit_reject_investigation_v07(&mut self, node: &crate::camt_031_001_07::RejectInvestigationV07) { walk_reject_investigation_v07(self, node) }
+	fn visit_cancel_case_assignment_v05(&mut self, node: &crate::camt_032_001_05::CancelCaseAssignmentV05) { walk_cancel_case_assignment_v05(self, node) }
+	fn visit_request_for_duplicate_v07(&mut self, node: &crate::camt_033_001_07::RequestForDuplicateV07) { walk_request_for_duplicate_v07(self, node) }
+	fn visit_duplicate_v07(&mut self, node: &crate::camt_034_001_07::DuplicateV07) { walk_duplicate_v07(self, node) }
+	fn visit_proprietary_format_investigation_v06(&mut self, node: &crate::camt_035_001_06::ProprietaryFormatInvestigationV06) { walk_proprietary_format_investigation_v06(self, node) }
+	fn visit_debit_authorisation_response_v06(&mut self, node: &crate::camt_036_001_06::DebitAuthorisationResponseV06) { walk_debit_authorisation_response_v06(self, node) }
+	fn visit_debit_authorisation_request_v10(&mut self, node: &crate::camt_037_001_10::DebitAuthorisationRequestV10) { walk_debit_authorisation_request_v10(self, node) }
+	fn visit_case_status_report_request_v05(&mut self, node: &crate::camt_038_001_05::CaseStatusReportRequestV05) { walk_case_status_report_request_v05(self, node) }
+	fn visit_case_status_report_v06(&mut self, node: &crate::camt_039_001_06::CaseStatusReportV06) { walk_case_status_report_v06(self, node) }
+	fn visit_fund_estimated_cash_forecast_report_v04(&mut self, node: &crate::camt_040_001_04::FundEstimatedCashForecastReportV04) { walk_fund_estimated_cash_forecast_report_v04(self, node) }
+	fn visit_fund_confirmed_cash_forecast_report_v04(&mut self, node: &crate::camt_041_001_04::FundConfirmedCashForecastReportV04) { walk_fund_confirmed_cash_forecast_report_v04(self, node) }
+	fn visit_fund_detailed_estimated_cash_forecast_report_v04(&mut self, node: &crate::camt_042_001_04::FundDetailedEstimatedCashForecastReportV04) { walk_fund_detailed_estimated_cash_forecast_report_v04(self, node) }
+	fn visit_fund_detailed_confirmed_cash_forecast_report_v04(&mut self, node: &crate::camt_043_001_04::FundDetailedConfirmedCashForecastReportV04) { walk_fund_detailed_confirmed_cash_forecast_report_v04(self, node) }
+	fn visit_fund_confirmed_cash_forecast_report_cancellation_v03(&mut self, node: &crate::camt_044_001_03::FundConfirmedCashForecastReportCancellationV03) { walk_fund_confirmed_cash_forecast_report_cancellation_v03(self, node) }
+	fn visit_fund_detailed_confirmed_cash_forecast_report_cancellation_v03(&mut self, node: &crate::camt_045_001_03::FundDetailedConfirmedCashForecastReportCancellationV03) { walk_fund_detailed_confirmed_cash_forecast_report_cancellation_v03(self, node) }
+	fn visit_get_reservation_v08(&mut self, node: &crate::camt_046_001_08::GetReservationV08) { walk_get_reservation_v08(self, node) }
+	fn visit_return_reservation_v08(&mut self, node: &crate::camt_047_001_08::ReturnReservationV08) { walk_return_reservation_v08(self, node) }
+	fn visit_modify_reservation_v07(&mut self, node: &crate::camt_048_001_07::ModifyReservationV07) { walk_modify_reservation_v07(self, node) }
+	fn visit_delete_reservation_v07(&mut self, node: &crate::camt_049_001_07::DeleteReservationV07) { walk_delete_reservation_v07(self, node) }
+	fn visit_liquidity_credit_transfer_v07(&mut self, node: &crate::camt_050_001_07::LiquidityCreditTransferV07) { walk_liquidity_credit_transfer_v07(self, node) }
+	fn visit_liquidity_debit_transfer_v07(&mut self, node: &crate::camt_051_001_07::LiquidityDebitTransferV07) { walk_liquidity_debit_transfer_v07(self, node) }
+	fn visit_bank_to_customer_account_report_v12(&mut self, node: &crate::camt_052_001_12::BankToCustomerAccountReportV12) { walk_bank_to_customer_account_report_v12(self, node) }
+	fn visit_bank_to_customer_statement_v12(&mut self, node: &crate::camt_053_001_12::BankToCustomerStatementV12) { walk_bank_to_customer_statement_v12(self, node) }
+	fn visit_bank_to_customer_debit_credit_notification_v12(&mut self, node: &crate::camt_054_001_12::BankToCustomerDebitCreditNotificationV12) { walk_bank_to_customer_debit_credit_notification_v12(self, node) }
+	fn visit_customer_payment_cancellation_request_v12(&mut self, node: &crate::camt_055_001_12::CustomerPaymentCancellationRequestV12) { walk_customer_payment_cancellation_request_v12(self, node) }
+	fn visit_fi_to_fi_payment_cancellation_request_v11(&mut self, node: &crate::camt_056_001_11::FIToFIPaymentCancellationRequestV11) { walk_fi_to_fi_payment_cancellation_request_v11(self, node) }
+	fn visit_notification_to_receive_v08(&mut self, node: &crate::camt_057_001_08::NotificationToReceiveV08) { walk_notification_to_receive_v08(self, node) }
+	fn visit_notification_to_receive_cancellation_advice_v09(&mut self, node: &crate::camt_058_001_09::NotificationToReceiveCancellationAdviceV09) { walk_notification_to_receive_cancellation_advice_v09(self, node) }
+	fn visit_notification_to_receive_status_report_v08(&mut self, node: &crate::camt_059_001_08::NotificationToReceiveStatusReportV08) { walk_notification_to_receive_status_report_v08(self, node) }
+	fn visit_account_reporting_request_v07(&mut self, node: &crate::camt_060_001_07::AccountReportingRequestV07) { walk_account_reporting_request_v07(self, node) }
+	fn visit_pay_in_call_v02(&mut self, node: &crate::camt_061_001_02::PayInCallV02) { walk_pay_in_call_v02(self, node) }
+	fn visit_pay_in_schedule_v03(&mut self, node: &crate::camt_062_001_03::PayInScheduleV03) { walk_pay_in_schedule_v03(self, node) }
+	fn visit_pay_in_event_acknowledgement_v02(&mut self, node: &crate::camt_063_001_02::PayInEventAcknowledgementV02) { walk_pay_in_event_acknowledgement_v02(self, node) }
+	fn visit_intra_balance_movement_instruction_v02(&mut self, node: &crate::camt_066_001_02::IntraBalanceMovementInstructionV02) { walk_intra_balance_movement_instruction_v02(self, node) }
+	fn visit_intra_balance_movement_status_advice_v02(&mut self, node: &crate::camt_067_001_02::IntraBalanceMovementStatusAdviceV02) { walk_intra_balance_movement_status_advice_v02(self, node) }
+	fn visit_intra_balance_movement_confirmation_v02(&mut self, node: &crate::camt_068_001_02::IntraBalanceMovementConfirmationV02) { walk_intra_balance_movement_confirmation_v02(self, node) }
+	fn visit_get_standing_order_v05(&mut self, node: &crate::camt_069_001_05::GetStandingOrderV05) { walk_get_standing_order_v05(self, node) }
+	fn visit_return_standing_order_v06(&mut self, node: &crate::camt_070_001_06::ReturnStandingOrderV06) { walk_return_standing_order_v06(self, node) }
+	fn visit_delete_standing_order_v05(&mut self, node: &crate::camt_071_001_05::DeleteStandingOrderV05) { walk_delete_standing_order_v05(self, node) }
+	fn visit_intra_balance_movement_modification_request_v02(&mut self, node: &crate::camt_072_001_02::IntraBalanceMovementModificationRequestV02) { walk_intra_balance_movement_modification_request_v02(self, node) }
+	fn visit_intra_balance_movement_modification_request_status_advice_v02(&mut self, node: &crate::camt_073_001_02::IntraBalanceMovementModificationRequestStatusAdviceV02) { walk_intra_balance_movement_modification_request_status_advice_v02(self, node) }
+	fn visit_intra_balance_movement_cancellation_request_v02(&mut self, node: &crate::camt_074_001_02::IntraBalanceMovementCancellationRequestV02) { walk_intra_balance_movement_cancellation_request_v02(self, node) }
+	fn visit_intra_balance_movement_cancellation_request_status_advice_v02(&mut self, node: &crate::camt_075_001_02::IntraBalanceMovementCancellationRequestStatusAdviceV02) { walk_intra_balance_movement_cancellation_request_status_advice_v02(self, node) }
+	fn visit_intra_balance_movement_query_v02(&mut self, node: &crate::camt_078_001_02::IntraBalanceMovementQueryV02) { walk_intra_balance_movement_query_v02(self, node) }
+	fn visit_intra_balance_movement_query_response_v02(&mut self, node: &crate::camt_079_001_02::IntraBalanceMovementQueryResponseV02) { walk_intra_balance_movement_query_response_v02(self, node) }
+	fn visit_intra_balance_movement_modification_query_v02(&mut self, node: &crate::camt_080_001_02::IntraBalanceMovementModificationQueryV02) { walk_intra_balance_movement_modification_query_v02(self, node) }
+	fn visit_intra_balance_movement_modification_report_v02(&mut self, node: &crate::camt_081_001_02::IntraBalanceMovementModificationReportV02) { walk_intra_balance_movement_modification_report_v02(self, node) }
+	fn visit_intra_balance_movement_cancellation_query_v02(&mut self, node: &crate::camt_082_001_02::IntraBalanceMovementCancellationQueryV02) { walk_intra_balance_movement_cancellation_query_v02(self, node) }
+	fn visit_intra_balance_movement_cancellation_report_v02(&mut self, node: &crate::camt_083_001_02::IntraBalanceMovementCancellationReportV02) { walk_intra_balance_movement_cancellation_report_v02(self, node) }
+	fn visit_intra_balance_movement_posting_report_v02(&mut self, node: &crate::camt_084_001_02::IntraBalanceMovementPostingReportV02) { walk_intra_balance_movement_posting_report_v02(self, node) }
+	fn visit_intra_balance_movement_pending_report_v02(&mut self, node: &crate::camt_085_001_02::IntraBalanceMovementPendingReportV02) { walk_intra_balance_movement_pending_report_v02(self, node) }
+	fn visit_bank_services_billing_statement_v05(&mut self, node: &crate::camt_086_001_05::BankServicesBillingStatementV05) { walk_bank_services_billing_statement_v05(self, node) }
+	fn visit_request_to_modify_payment_v09(&mut self, node: &crate::camt_087_001_09::RequestToModifyPaymentV09) { walk_request_to_modify_payment_v09(self, node) }
+	fn visit_net_report_v02(&mut self, node: &crate::camt_088_001_02::NetReportV02) { walk_net_report_v02(self, node) }
+	fn visit_create_limit_v02(&mut self, node: &crate::camt_101_001_02::CreateLimitV02) { walk_create_limit_v02(self, node) }
+	fn visit_create_standing_order_v03(&mut self, node: &crate::camt_102_001_03::CreateStandingOrderV03) { walk_create_standing_order_v03(self, node) }
+	fn visit_create_reservation_v03(&mut self, node: &crate::camt_103_001_03::CreateReservationV03) { walk_create_reservation_v03(self, node) }
+	fn visit_create_member_v01(&mut self, node: &crate::camt_104_001_01::CreateMemberV01) { walk_create_member_v01(self, node) }
+	fn visit_charges_payment_notification_v02(&mut self, node: &crate::camt_105_001_02::ChargesPaymentNotificationV02) { walk_charges_payment_notification_v02(self, node) }
+	fn visit_charges_payment_request_v02(&mut self, node: &crate::camt_106_001_02::ChargesPaymentRequestV02) { walk_charges_payment_request_v02(self, node) }
+	fn visit_cheque_presentment_notification_v02(&mut self, node: &crate::camt_107_001_02::ChequePresentmentNotificationV02) { walk_cheque_presentment_notification_v02(self, node) }
+	fn visit_cheque_cancellation_or_stop_request_v02(&mut self, node: &crate::camt_108_001_02::ChequeCancellationOrStopRequestV02) { walk_cheque_cancellation_or_stop_request_v02(self, node) }
+	fn visit_cheque_cancellation_or_stop_report_v02(&mut self, node: &crate::camt_109_001_02::ChequeCancellationOrStopReportV02) { walk_cheque_cancellation_or_stop_report_v02(self, node) }
+	fn visit_investigation_request_v01(&mut self, node: &crate::camt_110_001_01::InvestigationRequestV01) { walk_investigation_request_v01(self, node) }
+	fn visit_investigation_response_v01(&mut self, node: &crate::camt_111_001_01::InvestigationResponseV01) { walk_investigation_response_v01(self, node) }
+}
+
+pub trait VisitorMut: BaseVisitorMut {
+	fn visit_get_account_v08_mut(&mut self, node: &mut crate::camt_003_001_08::GetAccountV08) { walk_get_account_v08_mut(self, node) }
+	fn visit_return_account_v10_mut(&mut self, node: &mut crate::camt_004_001_10::ReturnAccountV10) { walk_return_account_v10_mut(self, node) }
+	fn visit_get_transaction_v11_mut(&mut self, node: &mut crate::camt_005_001_11::GetTransactionV11) { walk_get_transaction_v11_mut(self, node) }
+	fn visit_return_transaction_v11_mut(&mut self, node: &mut crate::camt_006_001_11::ReturnTransactionV11) { walk_return_transaction_v11_mut(self, node) }
+	fn visit_modify_transaction_v10_mut(&mut self, node: &mut crate::camt_007_001_10::ModifyTransactionV10) { walk_modify_transaction_v10_mut(self, node) }
+	fn visit_cancel_transaction_v11_mut(&mut self, node: &mut crate::camt_008_001_11::CancelTransactionV11) { walk_cancel_transaction_v11_mut(self, node) }
+	fn visit_get_limit_v08_mut(&mut self, node: &mut crate::camt_009_001_08::GetLimitV08) { walk_get_limit_v08_mut(self, node) }
+	fn visit_return_limit_v09_mut(&mut self, node: &mut crate::camt_010_001_09::ReturnLimitV09) { walk_return_limit_v09_mut(self, node) }
+	fn visit_modify_limit_v08_mut(&mut self, node: &mut crate::camt_011_001_08::ModifyLimitV08) { walk_modify_limit_v08_mut(self, node) }
+	fn visit_delete_limit_v08_mut(&mut self, node: &mut crate::camt_012_001_08::DeleteLimitV08) { walk_delete_limit_v08_mut(self, node) }
+	fn visit_get_member_v04_mut(&mut self, node: &mut crate::camt_013_001_04::GetMemberV04) { walk_get_member_v04_mut(self, node) }
+	fn visit_return_member_v05_mut(&mut self, node: &mut crate::camt_014_001_05::ReturnMemberV05) { walk_return_member_v05_mut(self, node) }
+	fn visit_modify_member_v04_mut(&mut self, node: &mut crate::camt_015_001_04::ModifyMemberV04) { walk_modify_member_v04_mut(self, node) }
+	fn visit_get_currency_exchange_rate_v04_mut(&mut self, node: &mut crate::camt_016_001_04::GetCurrencyExchangeRateV04) { walk_get_currency_exchange_rate_v04_mut(self, node) }
+	fn visit_return_currency_exchange_rate_v05_mut(&mut self, node: &mut crate::camt_017_001_05::ReturnCurrencyExchangeRateV05) { walk_return_currency_exchange_rate_v05_mut(self, node) }
+	fn visit_get_business_day_information_v05_mut(&mut self, node: &mut crate::camt_018_001_05::GetBusinessDayInformationV05) { walk_get_business_day_information_v05_mut(self, node) }
+	fn visit_return_business_day_information_v07_mut(&mut self, node: &mut crate::camt_019_001_07::ReturnBusinessDayInformationV07) { walk_return_business_day_information_v07_mut(self, node) }
+	fn visit_get_general_business_information_v04_mut(&mut self, node: &mut crate::camt_020_001_04::GetGeneralBusinessInformationV04) { walk_get_general_business_information_v04_mut(self, node) }
+	fn visit_return_general_business_information_v06_mut(&mut self, node: &mut crate::camt_021_001_06::ReturnGeneralBusinessInformationV06) { walk_return_general_business_information_v06_mut(self, node) }
+	fn visit_backup_payment_v07_mut(&mut self, node: &mut crate::camt_023_001_07::BackupPaymentV07) { walk_backup_payment_v07_mut(self, node) }
+	fn visit_modify_standing_order_v08_mut(&mut self, node: &mut crate::camt_024_001_08::ModifyStandingOrderV08) { walk_modify_standing_order_v08_mut(self, node) }
+	fn visit_receipt_v08_mut(&mut self, node: &mut crate::camt_025_001_08::ReceiptV08) { walk_receipt_v08_mut(self, node) }
+	fn visit_unable_to_apply_v10_mut(&mut self, node: &mut crate::camt_026_001_10::UnableToApplyV10) { walk_unable_to_apply_v10_mut(self, node) }
+	fn visit_claim_non_receipt_v10_mut(&mut self, node: &mut crate::camt_027_001_10::ClaimNonReceiptV10) { walk_claim_non_receipt_v10_mut(self, node) }
+	fn visit_additional_payment_information_v12_mut(&mut self, node: &mut crate::camt_028_001_12::AdditionalPaymentInformationV12) { walk_additional_payment_information_v12_mut(self, node) }
+	fn visit_resolution_of_investigation_v13_mut(&mut self, node: &mut crate::camt_029_001_13::ResolutionOfInvestigationV13) { walk_resolution_of_investigation_v13_mut(self, node) }
+	fn visit_notification_of_case_assignment_v06_mut(&mut self, node: &mut crate::camt_030_001_06::NotificationOfCaseAssignmentV06) { walk_notification_of_case_assignment_v06_mut(self, node) }
+	fn visit_reject_investigation_v07_mut(&mut self, node: &mut crate::camt_031_001_07::RejectInvestigationV07) { walk_reject_investigation_v07_mut(self, node) }
+	fn visit_cancel_case_assignment_v05_mut(&mut self, node: &mut crate::camt_032_001_05::CancelCaseAssignmentV05) { walk_cancel_case_assignment_v05_mut(self, node) }
+	fn visit_request_for_duplicate_v07_mut(&mut self, node: &mut crate::camt_033_001_07::RequestForDuplicateV07) { walk_request_for_duplicate_v07_mut(self, node) }
+	fn visit_duplicate_v07_mut(&mut self, node: &mut crate::camt_034_001_07::DuplicateV07) { walk_duplicate_v07_mut(self, node) }
+	fn visit_proprietary_format_investigation_v06_mut(&mut self, node: &mut crate::camt_035_001_06::ProprietaryFormatInvestigationV06) { walk_proprietary_format_investigation_v06_mut(self, node) }
+	fn visit_debit_authorisation_response_v06_mut(&mut self, node: &mut crate::camt_036_001_06::DebitAuthorisationResponseV06) { walk_debit_authorisation_response_v06_mut(self, node) }
+	fn visit_debit_authorisation_request_v10_mut(&mut self, node: &mut crate::camt_037_001_10::DebitAuthorisationRequestV10) { walk_debit_authorisation_request_v10_mut(self, node) }
+	fn visit_case_status_report_request_v05_mut(&mut self, node: &mut crate::camt_038_001_05::CaseStatusReportRequestV05) { walk_case_status_report_request_v05_mut(self, node) }
+	fn visit_case_status_report_v06_mut(&mut self, node: &mut crate::camt_039_001_06::CaseStatusReportV06) { walk_case_status_report_v06_mut(self, node) }
+	fn visit_fund_estimated_cash_forecast_report_v04_mut(&mut self, node: &mut crate::camt_040_001_04::FundEstimatedCashForecastReportV04) { walk_fund_estimated_cash_forecast_report_v04_mut(self, node) }
+	fn visit_fund_confirmed_cash_forecast_report_v04_mut(&mut self, node: &mut crate::camt_041_001_04::FundConfirmedCashForecastReportV04) { walk_fund_confirmed_cash_forecast_report_v04_mut(self, node) }
+	fn visit_fund_detailed_estimated_cash_forecast_report_v04_mut(&mut self, node: &mut crate::camt_042_001_04::FundDetailedEstimatedCashForecastReportV04) { walk_fund_detailed_estimated_cash_forecast_report_v04_mut(self, node) }
+	fn visit_fund_detailed_confirmed_cash_forecast_report_v04_mut(&mut self, node: &mut crate::camt_043_001_04::FundDetailedConfirmedCashForecastReportV04) { walk_fund_detailed_confirmed_cash_forecast_report_v04_mut(self, node) }
+	fn visit_fund_confirmed_cash_forecast_report_cancellation_v03_mut(&mut self, node: &mut crate::camt_044_001_03::FundConfirmedCashForecastReportCancellationV03) { walk_fund_confirmed_cash_forecast_report_cancellation_v03_mut(self, node) }
+	fn visit_fund_detailed_confirmed_cash_forecast_report_cancellation_v03_mut(&mut self, node: &mut crate::camt_045_001_03::FundDetailedConfirmedCashForecastReportCancellationV03) { walk_fund_detailed_confirmed_cash_forecast_report_cancellation_v03_mut(self, node) }
+	fn visit_get_reservation_v08_mut(&mut self, node: &mut crate::camt_046_001_08::GetReservationV08) { walk_get_reservation_v08_mut(self, node) }
+	fn visit_return_reservation_v08_mut(&mut self, node: &mut crate::camt_047_001_08::ReturnReservationV08) { walk_return_reservation_v08_mut(self, node) }
+	fn visit_modify_reservation_v07_mut(&mut self, node: &mut crate::camt_048_001_07::ModifyReservationV07) { walk_modify_reservation_v07_mut(self, node) }
+	fn visit_delete_reservation_v07_mut(&mut self, node: &mut crate::camt_049_001_07::DeleteReservationV07) { walk_delete_reservation_v07_mut(self, node) }
+	fn visit_liquidity_credit_transfer_v07_mut(&mut self, node: &mut crate::camt_050_001_07::LiquidityCreditTransferV07) { walk_liquidity_credit_transfer_v07_mut(self, node) }
+	fn visit_liquidity_debit_transfer_v07_mut(&mut self, node: &mut crate::camt_051_001_07::LiquidityDebitTransferV07) { walk_liquidity_debit_transfer_v07_mut(self, node) }
+	fn visit_bank_to_customer_account_report_v12_mut(&mut self, node: &mut crate::camt_052_001_12::BankToCustomerAccountReportV12) { walk_bank_to_customer_account_report_v12_mut(self, node) }
+	fn visit_bank_to_customer_statement_v12_mut(&mut self, node: &mut crate::camt_053_001_12::BankToCustomerStatementV12) { walk_bank_to_customer_statement_v12_mut(self, node) }
+	fn visit_bank_to_customer_debit_credit_notification_v12_mut(&mut self, node: &mut crate::camt_054_001_12::BankToCustomerDebitCreditNotificationV12) { walk_bank_to_customer_debit_credit_notification_v12_mut(self, node) }
+	fn visit_customer_payment_cancellation_request_v12_mut(&mut self, node: &mut crate::camt_055_001_12::CustomerPaymentCancellationRequestV12) { walk_customer_payment_cancellation_request_v12_mut(self, node) }
+	fn visit_fi_to_fi_payment_cancellation_request_v11_mut(&mut self, node: &mut crate::camt_056_001_11::FIToFIPaymentCancellationRequestV11) { walk_fi_to_fi_payment_cancellation_request_v11_mut(self, node) }
+	fn visit_notification_to_receive_v08_mut(&mut self, node: &mut crate::camt_057_001_08::NotificationToReceiveV08) { walk_notification_to_receive_v08_mut(self, node) }
+	fn visit_notification_to_receive_cancellation_advice_v09_mut(&mut self, node: &mut crate::camt_058_001_09::NotificationToReceiveCancellationAdviceV09) { walk_notification_to_receive_cancellation_advice_v09_mut(self, node) }
+	fn visit_notification_to_receive_status_report_v08_mut(&mut self, node: &mut crate::camt_059_001_08::NotificationToReceiveStatusReportV08) { walk_notification_to_receive_status_report_v08_mut(self, node) }
+	fn visit_account_reporting_request_v07_mut(&mut self, node: &mut crate::camt_060_001_07::AccountReportingRequestV07) { walk_account_reporting_request_v07_mut(self, node) }
+	fn visit_pay_in_call_v02_mut(&mut self, node: &mut crate::camt_061_001_02::PayInCallV02) { walk_pay_in_call_v02_mut(self, node) }
+	fn visit_pay_in_schedule_v03_mut(&mut self, node: &mut crate::camt_062_001_03::PayInScheduleV03) { walk_pay_in_schedule_v03_mut(self, node) }
+	fn visit_pay_in_event_acknowledgement_v02_mut(&mut self, node: &mut crate::camt_063_001_02::PayInEventAcknowledgementV02) { walk_pay_in_event_acknowledgement_v02_mut(self, node) }
+	fn visit_intra_balance_movement_instruction_v02_mut(&mut self, node: &mut crate::camt_066_001_02::IntraBalanceMovementInstructionV02) { walk_intra_balance_movement_instruction_v02_mut(self, node) }
+	fn visit_intra_balance_movement_status_advice_v02_mut(&mut self, node: &mut crate::camt_067_001_02::IntraBalanceMovementStatusAdviceV02) { walk_intra_balance_movement_status_advice_v02_mut(self, node) }
+	fn visit_intra_balance_movement_confirmation_v02_mut(&mut self, node: &mut crate::camt_068_001_02::IntraBalanceMovementConfirmationV02) { walk_intra_balance_movement_confirmation_v02_mut(self, node) }
+	fn visit_get_standing_order_v05_mut(&mut self, node: &mut crate::camt_069_001_05::GetStandingOrderV05) { walk_get_standing_order_v05_mut(self, node) }
+	fn visit_return_standing_order_v06_mut(&mut self, node: &mut crate::camt_070_001_06::ReturnStandingOrderV06) { walk_return_standing_order_v06_mut(self, node) }
+	fn visit_delete_standing_order_v05_mut(&mut self, node: &mut crate::camt_071_001_05::DeleteStandingOrderV05) { walk_delete_standing_order_v05_mut(self, node) }
+	fn visit_intra_balance_movement_modification_request_v02_mut(&mut self, node: &mut crate::camt_072_001_02::IntraBalanceMovementModificationRequestV02) { walk_intra_balance_movement_modification_request_v02_mut(self, node) }
+	fn visit_intra_balance_movement_modification_request_status_advice_v02_mut(&mut self, node: &mut crate::camt_073_001_02::IntraBalanceMovementModificationRequestStatusAdviceV02) { walk_intra_balance_movement_modification_request_status_advice_v02_mut(self, node) }
+	fn visit_intra_balance_movement_cancellation_request_v02_mut(&mut self, node: &mut crate::camt_074_001_02::IntraBalanceMovementCancellationRequestV02) { walk_intra_balance_movement_cancellation_request_v02_mut(self, node) }
+	fn visit_intra_balance_movement_cancellation_request_status_advice_v02_mut(&mut self, node: &mut crate::camt_075_001_02::IntraBalanceMovementCancellationRequestStatusAdviceV02) { walk_intra_balance_movement_cancellation_request_status_advice_v02_mut(self, node) }
+	fn visit_intra_balance_movement_query_v02_mut(&mut self, node: &mut crate::camt_078_001_02::IntraBalanceMovementQueryV02) { walk_intra_balance_movement_query_v02_mut(self, node) }
+	fn visit_intra_balance_movement_query_response_v02_mut(&mut self, node: &mut crate::camt_079_001_02::IntraBalanceMovementQueryResponseV02) { walk_intra_balance_movement_query_response_v02_mut(self, node) }
+	fn visit_intra_balance_movement_modification_query_v02_mut(&mut self, node: &mut crate::camt_080_001_02::IntraBalanceMovementModificationQueryV02) { walk_intra_balance_movement_modification_query_v02_mut(self, node) }
+	fn visit_intra_balance_movement_modification_report_v02_mut(&mut self, node: &mut crate::camt_081_001_02::IntraBalanceMovementModificationReportV02) { walk_intra_balance_movement_modification_report_v02_mut(self, node) }
+	fn visit_intra_balance_movement_cancellation_query_v02_mut(&mut self, node: &mut crate::camt_082_001_02::IntraBalanceMovementCancellationQueryV02) { walk_intra_balance_movement_cancellation_query_v02_mut(self, node) }
+	fn visit_intra_balance_movement_cancellation_report_v02_mut(&mut self, node: &mut crate::camt_083_001_02::IntraBalanceMovementCancellationReportV02) { walk_intra_balance_movement_cancellation_report_v02_mut(self, node) }
+	fn visit_intra_balance_movement_posting_report_v02_mut(&mut self, node: &mut crate::camt_084_001_02::IntraBalanceMovementPostingReportV02) { walk_intra_balance_movement_posting_report_v02_mut(self, node) }
+	fn visit_intra_balance_movement_pending_report_v02_mut(&mut self, node: &mut crate::camt_085_001_02::IntraBalanceMovementPendingReportV02) { walk_intra_balance_movement_pending_report_v02_mut(self, node) }
+	fn visit_bank_services_billing_statement_v05_mut(&mut self, node: &mut crate::camt_086_001_05::BankServicesBillingStatementV05) { walk_bank_services_billing_statement_v05_mut(self, node) }
+	fn visit_request_to_modify_payment_v09_mut(&mut self, node: &mut crate::camt_087_001_09::RequestToModifyPaymentV09) { walk_request_to_modify_payment_v09_mut(self, node) }
+	fn visit_net_report_v02_mut(&mut self, node: &mut crate::camt_088_001_02::NetReportV02) { walk_net_report_v02_mut(self, node) }
+	fn visit_create_limit_v02_mut(&mut self, node: &mut crate::camt_101_001_02::CreateLimitV02) { walk_create_limit_v02_mut(self, node) }
+	fn visit_create_standing_order_v03_mut(&mut self, node: &mut crate::camt_102_001_03::CreateStandingOrderV03) { walk_create_standing_order_v03_mut(self, node) }
+	fn visit_create_reservation_v03_mut(&mut self, node: &mut crate::camt_103_001_03::CreateReservationV03) { walk_create_reservation_v03_mut(self, node) }
+	fn visit_create_member_v01_mut(&mut self, node: &mut crate::camt_104_001_01::CreateMemberV01) { walk_create_member_v01_mut(self, node) }
+	fn visit_charges_payment_notification_v02_mut(&mut self, node: &mut crate::camt_105_001_02::ChargesPaymentNotificationV02) { walk_charges_payment_notification_v02_mut(self, node) }
+	fn visit_charges_payment_request_v02_mut(&mut self, node: &mut crate::camt_106_001_02::ChargesPaymentRequestV02) { walk_charges_payment_request_v02_mut(self, node) }
+	fn visit_cheque_presentment_notification_v02_mut(&mut self, node: &mut crate::camt_107_001_02::ChequePresentmentNotificationV02) { walk_cheque_presentment_notification_v02_mut(self, node) }
+	fn visit_cheque_cancellation_or_stop_request_v02_mut(&mut self, node: &mut crate::camt_108_001_02::ChequeCancellationOrStopRequestV02) { walk_cheque_cancellation_or_stop_request_v02_mut(self, node) }
+	fn visit_cheque_cancellation_or_stop_report_v02_mut(&mut self, node: &mut crate::camt_109_001_02::ChequeCancellationOrStopReportV02) { walk_cheque_cancellation_or_stop_report_v02_mut(self, node) }
+	fn visit_investigation_request_v01_mut(&mut self, node: &mut crate::camt_110_001_01::InvestigationRequestV01) { walk_investigation_request_v01_mut(self, node) }
+	fn visit_investigation_response_v01_mut(&mut self, node: &mut crate::camt_111_001_01::InvestigationResponseV01) { walk_investigation_response_v01_mut(self, node) }
+}
+
+pub fn walk_get_account_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_003_001_08::GetAccountV08) {
+	visitor.visit_message_header9(&node.msg_hdr);
+	if let Some(ref value) = node.acct_qry_def { visitor.visit_account_query4(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_get_account_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_003_001_08::GetAccountV08) {
+	visitor.visit_message_header9_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.acct_qry_def { visitor.visit_account_query4_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_return_account_v10<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_004_001_10::ReturnAccountV10) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	visitor.visit_account_or_operational_error6_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_return_account_v10_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_004_001_10::ReturnAccountV10) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	visitor.visit_account_or_operational_error6_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_get_transaction_v11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_005_001_11::GetTransactionV11) {
+	visitor.visit_message_header9(&node.msg_hdr);
+	if let Some(ref value) = node.tx_qry_def { visitor.visit_transaction_query8(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_get_transaction_v11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_005_001_11::GetTransactionV11) {
+	visitor.visit_message_header9_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.tx_qry_def { visitor.visit_transaction_query8_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_return_transaction_v11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_006_001_11::ReturnTransactionV11) {
+	visitor.visit_message_header8(&node.msg_hdr);
+	visitor.visit_transaction_report_or_error7_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_return_transaction_v11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_006_001_11::ReturnTransactionV11) {
+	visitor.visit_message_header8_mut(&mut node.msg_hdr);
+	visitor.visit_transaction_report_or_error7_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_modify_transaction_v10<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_007_001_10::ModifyTransactionV10) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	for item in &node.mod_attr { visitor.visit_transaction_modification7(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_modify_transaction_v10_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_007_001_10::ModifyTransactionV10) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	for item in &mut node.mod_attr { visitor.visit_transaction_modification7_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_cancel_transaction_v11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_008_001_11::CancelTransactionV11) {
+	visitor.visit_message_header9(&node.msg_hdr);
+	visitor.visit_payment_identification8_choice(&node.pmt_id);
+	if let Some(ref value) = node.csh_acct { visitor.visit_cash_account40(value) }
+	if let Some(ref value) = node.cxl_rsn { visitor.visit_payment_cancellation_reason6(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_cancel_transaction_v11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_008_001_11::CancelTransactionV11) {
+	visitor.visit_message_header9_mut(&mut node.msg_hdr);
+	visitor.visit_payment_identification8_choice_mut(&mut node.pmt_id);
+	if let Some(ref mut value) = node.csh_acct { visitor.visit_cash_account40_mut(value) }
+	if let Some(ref mut value) = node.cxl_rsn { visitor.visit_payment_cancellation_reason6_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_get_limit_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_009_001_08::GetLimitV08) {
+	visitor.visit_message_header9(&node.msg_hdr);
+	if let Some(ref value) = node.lmt_qry_def { visitor.visit_limit_query5(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_get_limit_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_009_001_08::GetLimitV08) {
+	visitor.visit_message_header9_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.lmt_qry_def { visitor.visit_limit_query5_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_return_limit_v09<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_010_001_09::ReturnLimitV09) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	visitor.visit_limit_report_or_error5_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_return_limit_v09_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_010_001_09::ReturnLimitV09) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	visitor.visit_limit_report_or_error5_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_modify_limit_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_011_001_08::ModifyLimitV08) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	if let Some(ref vec) = node.lmt_dtls { for item in vec { visitor.visit_limit_structure5(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_modify_limit_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_011_001_08::ModifyLimitV08) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	if let Some(ref mut vec) = node.lmt_dtls { for item in vec { visitor.visit_limit_structure5_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_delete_limit_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_012_001_08::DeleteLimitV08) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_limit_structure3_choice(&node.lmt_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_delete_limit_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_012_001_08::DeleteLimitV08) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_limit_structure3_choice_mut(&mut node.lmt_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_get_member_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_013_001_04::GetMemberV04) {
+	visitor.visit_message_header9(&node.msg_hdr);
+	if let Some(ref value) = node.mmb_qry_def { visitor.visit_member_query_definition4(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_get_member_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_013_001_04::GetMemberV04) {
+	visitor.visit_message_header9_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.mmb_qry_def { visitor.visit_member_query_definition4_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_return_member_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_014_001_05::ReturnMemberV05) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	visitor.visit_member_report_or_error7_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_return_member_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_014_001_05::ReturnMemberV05) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	visitor.visit_member_report_or_error7_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_modify_member_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_015_001_04::ModifyMemberV04) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_member_identification3_choice(&node.mmb_id);
+	visitor.visit_member6(&node.new_mmb_val_set);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_modify_member_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_015_001_04::ModifyMemberV04) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_member_identification3_choice_mut(&mut node.mmb_id);
+	visitor.visit_member6_mut(&mut node.new_mmb_val_set);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_get_currency_exchange_rate_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_016_001_04::GetCurrencyExchangeRateV04) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	if let Some(ref value) = node.ccy_qry_def { visitor.visit_currency_query_definition3(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_get_currency_exchange_rate_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_016_001_04::GetCurrencyExchangeRateV04) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.ccy_qry_def { visitor.visit_currency_query_definition3_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_return_currency_exchange_rate_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_017_001_05::ReturnCurrencyExchangeRateV05) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	visitor.visit_exchange_rate_report_or_error3_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_return_currency_exchange_rate_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_017_001_05::ReturnCurrencyExchangeRateV05) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	visitor.visit_exchange_rate_report_or_error3_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_get_business_day_information_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_018_001_05::GetBusinessDayInformationV05) {
+	visitor.visit_message_header9(&node.msg_hdr);
+	if let Some(ref value) = node.biz_day_inf_qry_def { visitor.visit_business_day_query2(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_get_business_day_information_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_018_001_05::GetBusinessDayInformationV05) {
+	visitor.visit_message_header9_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.biz_day_inf_qry_def { visitor.visit_business_day_query2_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_return_business_day_information_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_019_001_07::ReturnBusinessDayInformationV07) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	visitor.visit_business_day_report_or_error9_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_return_business_day_information_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_019_001_07::ReturnBusinessDayInformationV07) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	visitor.visit_business_day_report_or_error9_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_get_general_business_information_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_020_001_04::GetGeneralBusinessInformationV04) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	if let Some(ref value) = node.gnl_biz_inf_qry_def { visitor.visit_business_information_query_definition3(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_get_general_business_information_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_020_001_04::GetGeneralBusinessInformationV04) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.gnl_biz_inf_qry_def { visitor.visit_business_information_query_definition3_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_return_general_business_information_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_021_001_06::ReturnGeneralBusinessInformationV06) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	visitor.visit_general_business_or_error7_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_return_general_business_information_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_021_001_06::ReturnGeneralBusinessInformationV06) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	visitor.visit_general_business_or_error7_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_backup_payment_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_023_001_07::BackupPaymentV07) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	if let Some(ref value) = node.orgnl_msg_id { visitor.visit_message_header1(value) }
+	if let Some(ref value) = node.instr_inf { visitor.visit_payment_instruction13(value) }
+	visitor.visit_amount2_choice(&node.trfd_amt);
+	visitor.visit_system_member3(&node.cdtr);
+	if let Some(ref value) = node.cdtr_agt { visitor.visit_system_member3(value) }
+	if let Some(ref value) = node.dbtr_agt { visitor.visit_system_member3(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_backup_payment_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_023_001_07::BackupPaymentV07) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.orgnl_msg_id { visitor.visit_message_header1_mut(value) }
+	if let Some(ref mut value) = node.instr_inf { visitor.visit_payment_instruction13_mut(value) }
+	visitor.visit_amount2_choice_mut(&mut node.trfd_amt);
+	visitor.visit_system_member3_mut(&mut node.cdtr);
+	if let Some(ref mut value) = node.cdtr_agt { visitor.visit_system_member3_mut(value) }
+	if let Some(ref mut value) = node.dbtr_agt { visitor.visit_system_member3_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_modify_standing_order_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_024_001_08::ModifyStandingOrderV08) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_standing_order_identification8(&node.stg_ordr_id);
+	visitor.visit_standing_order10(&node.new_stg_ordr_val_set);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_modify_standing_order_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_024_001_08::ModifyStandingOrderV08) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_standing_order_identification8_mut(&mut node.stg_ordr_id);
+	visitor.visit_standing_order10_mut(&mut node.new_stg_ordr_val_set);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_receipt_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_025_001_08::ReceiptV08) {
+	visitor.visit_message_header9(&node.msg_hdr);
+	for item in &node.rct_dtls { visitor.visit_receipt6(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_receipt_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_025_001_08::ReceiptV08) {
+	visitor.visit_message_header9_mut(&mut node.msg_hdr);
+	for item in &mut node.rct_dtls { visitor.visit_receipt6_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_unable_to_apply_v10<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_026_001_10::UnableToApplyV10) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	visitor.visit_underlying_transaction8_choice(&node.undrlyg);
+	visitor.visit_unable_to_apply_justification4_choice(&node.justfn);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_unable_to_apply_v10_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_026_001_10::UnableToApplyV10) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	visitor.visit_underlying_transaction8_choice_mut(&mut node.undrlyg);
+	visitor.visit_unable_to_apply_justification4_choice_mut(&mut node.justfn);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_claim_non_receipt_v10<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_027_001_10::ClaimNonReceiptV10) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	visitor.visit_underlying_transaction8_choice(&node.undrlyg);
+	if let Some(ref value) = node.cover_dtls { visitor.visit_missing_cover6(value) }
+	if let Some(ref value) = node.instr_for_assgne { visitor.visit_instruction_for_assignee1(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_claim_non_receipt_v10_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_027_001_10::ClaimNonReceiptV10) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	visitor.visit_underlying_transaction8_choice_mut(&mut node.undrlyg);
+	if let Some(ref mut value) = node.cover_dtls { visitor.visit_missing_cover6_mut(value) }
+	if let Some(ref mut value) = node.instr_for_assgne { visitor.visit_instruction_for_assignee1_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_additional_payment_information_v12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_028_001_12::AdditionalPaymentInformationV12) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	visitor.visit_underlying_transaction8_choice(&node.undrlyg);
+	visitor.visit_payment_complementary_information11(&node.inf);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_additional_payment_information_v12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_028_001_12::AdditionalPaymentInformationV12) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	visitor.visit_underlying_transaction8_choice_mut(&mut node.undrlyg);
+	visitor.visit_payment_complementary_information11_mut(&mut node.inf);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_resolution_of_investigation_v13<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_029_001_13::ResolutionOfInvestigationV13) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.rslvd_case { visitor.visit_case6(value) }
+	visitor.visit_investigation_status6_choice(&node.sts);
+	if let Some(ref vec) = node.cxl_dtls { for item in vec { visitor.visit_underlying_transaction32(item) } }
+	if let Some(ref value) = node.mod_dtls { visitor.visit_payment_transaction157(value) }
+	if let Some(ref value) = node.clm_non_rct_dtls { visitor.visit_claim_non_receipt3_choice(value) }
+	if let Some(ref value) = node.stmt_dtls { visitor.visit_statement_resolution_entry5(value) }
+	if let Some(ref value) = node.crrctn_tx { visitor.visit_corrective_transaction5_choice(value) }
+	if let Some(ref value) = node.rsltn_rltd_inf { visitor.visit_resolution_data5(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_resolution_of_investigation_v13_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_029_001_13::ResolutionOfInvestigationV13) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.rslvd_case { visitor.visit_case6_mut(value) }
+	visitor.visit_investigation_status6_choice_mut(&mut node.sts);
+	if let Some(ref mut vec) = node.cxl_dtls { for item in vec { visitor.visit_underlying_transaction32_mut(item) } }
+	if let Some(ref mut value) = node.mod_dtls { visitor.visit_payment_transaction157_mut(value) }
+	if let Some(ref mut value) = node.clm_non_rct_dtls { visitor.visit_claim_non_receipt3_choice_mut(value) }
+	if let Some(ref mut value) = node.stmt_dtls { visitor.visit_statement_resolution_entry5_mut(value) }
+	if let Some(ref mut value) = node.crrctn_tx { visitor.visit_corrective_transaction5_choice_mut(value) }
+	if let Some(ref mut value) = node.rsltn_rltd_inf { visitor.visit_resolution_data5_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_notification_of_case_assignment_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_030_001_06::NotificationOfCaseAssignmentV06) {
+	visitor.visit_report_header7(&node.hdr);
+	visitor.visit_case6(&node.case);
+	visitor.visit_case_assignment6(&node.assgnmt);
+	visitor.visit_case_forwarding_notification3(&node.ntfctn);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_notification_of_case_assignment_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_030_001_06::NotificationOfCaseAssignmentV06) {
+	visitor.visit_report_header7_mut(&mut node.hdr);
+	visitor.visit_case6_mut(&mut node.case);
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	visitor.visit_case_forwarding_notification3_mut(&mut node.ntfctn);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_reject_investigation_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_031_001_07::RejectInvestigationV07) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	visitor.visit_investigation_rejection_justification1(&node.justfn);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_reject_investigation_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_031_001_07::RejectInvestigationV07) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	visitor.visit_investigation_rejection_justification1_mut(&mut node.justfn);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_cancel_case_assignment_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_032_001_05::CancelCaseAssignmentV05) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	visitor.visit_case6(&node.case);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_cancel_case_assignment_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_032_001_05::CancelCaseAssignmentV05) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	visitor.visit_case6_mut(&mut node.case);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_request_for_duplicate_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_033_001_07::RequestForDuplicateV07) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_for_duplicate_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_033_001_07::RequestForDuplicateV07) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_duplicate_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_034_001_07::DuplicateV07) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	visitor.visit_proprietary_data7(&node.dplct);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_duplicate_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_034_001_07::DuplicateV07) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	visitor.visit_proprietary_data7_mut(&mut node.dplct);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_proprietary_format_investigation_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_035_001_06::ProprietaryFormatInvestigationV06) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	visitor.visit_proprietary_data7(&node.prtry_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_proprietary_format_investigation_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_035_001_06::ProprietaryFormatInvestigationV06) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	visitor.visit_proprietary_data7_mut(&mut node.prtry_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_debit_authorisation_response_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_036_001_06::DebitAuthorisationResponseV06) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	visitor.visit_debit_authorisation_confirmation2(&node.conf);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_debit_authorisation_response_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_036_001_06::DebitAuthorisationResponseV06) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	visitor.visit_debit_authorisation_confirmation2_mut(&mut node.conf);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_debit_authorisation_request_v10<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_037_001_10::DebitAuthorisationRequestV10) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	visitor.visit_underlying_transaction8_choice(&node.undrlyg);
+	visitor.visit_debit_authorisation2(&node.dtl);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_debit_authorisation_request_v10_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_037_001_10::DebitAuthorisationRequestV10) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	visitor.visit_underlying_transaction8_choice_mut(&mut node.undrlyg);
+	visitor.visit_debit_authorisation2_mut(&mut node.dtl);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_case_status_report_request_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_038_001_05::CaseStatusReportRequestV05) {
+	visitor.visit_report_header7(&node.req_hdr);
+	visitor.visit_case6(&node.case);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_case_status_report_request_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_038_001_05::CaseStatusReportRequestV05) {
+	visitor.visit_report_header7_mut(&mut node.req_hdr);
+	visitor.visit_case6_mut(&mut node.case);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_case_status_report_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_039_001_06::CaseStatusReportV06) {
+	visitor.visit_report_header7(&node.hdr);
+	visitor.visit_case6(&node.case);
+	visitor.visit_case_status2(&node.sts);
+	if let Some(ref value) = node.new_assgnmt { visitor.visit_case_assignment6(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_case_status_report_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_039_001_06::CaseStatusReportV06) {
+	visitor.visit_report_header7_mut(&mut node.hdr);
+	visitor.visit_case6_mut(&mut node.case);
+	visitor.visit_case_status2_mut(&mut node.sts);
+	if let Some(ref mut value) = node.new_assgnmt { visitor.visit_case_assignment6_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fund_estimated_cash_forecast_report_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_040_001_04::FundEstimatedCashForecastReportV04) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.pool_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	if let Some(ref vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	visitor.visit_pagination(&node.msg_pgntn);
+	if let Some(ref vec) = node.fnd_or_sub_fnd_dtls { for item in vec { visitor.visit_fund1(item) } }
+	if let Some(ref vec) = node.estmtd_fnd_csh_fcst_dtls { for item in vec { visitor.visit_estimated_fund_cash_forecast6(item) } }
+	if let Some(ref value) = node.cnsltd_net_csh_fcst { visitor.visit_net_cash_forecast3(value) }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_fund_estimated_cash_forecast_report_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_040_001_04::FundEstimatedCashForecastReportV04) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.pool_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	if let Some(ref mut vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	visitor.visit_pagination_mut(&mut node.msg_pgntn);
+	if let Some(ref mut vec) = node.fnd_or_sub_fnd_dtls { for item in vec { visitor.visit_fund1_mut(item) } }
+	if let Some(ref mut vec) = node.estmtd_fnd_csh_fcst_dtls { for item in vec { visitor.visit_estimated_fund_cash_forecast6_mut(item) } }
+	if let Some(ref mut value) = node.cnsltd_net_csh_fcst { visitor.visit_net_cash_forecast3_mut(value) }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_fund_confirmed_cash_forecast_report_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_041_001_04::FundConfirmedCashForecastReportV04) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.pool_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	if let Some(ref vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	visitor.visit_pagination(&node.msg_pgntn);
+	if let Some(ref vec) = node.fnd_or_sub_fnd_dtls { for item in vec { visitor.visit_fund2(item) } }
+	if let Some(ref vec) = node.fnd_csh_fcst_dtls { for item in vec { visitor.visit_fund_cash_forecast7(item) } }
+	if let Some(ref value) = node.cnsltd_net_csh_fcst { visitor.visit_net_cash_forecast3(value) }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_fund_confirmed_cash_forecast_report_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_041_001_04::FundConfirmedCashForecastReportV04) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.pool_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	if let Some(ref mut vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	visitor.visit_pagination_mut(&mut node.msg_pgntn);
+	if let Some(ref mut vec) = node.fnd_or_sub_fnd_dtls { for item in vec { visitor.visit_fund2_mut(item) } }
+	if let Some(ref mut vec) = node.fnd_csh_fcst_dtls { for item in vec { visitor.visit_fund_cash_forecast7_mut(item) } }
+	if let Some(ref mut value) = node.cnsltd_net_csh_fcst { visitor.visit_net_cash_forecast3_mut(value) }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_fund_detailed_estimated_cash_forecast_report_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_042_001_04::FundDetailedEstimatedCashForecastReportV04) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.pool_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	if let Some(ref vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	visitor.visit_pagination(&node.msg_pgntn);
+	if let Some(ref value) = node.fnd_or_sub_fnd_dtls { visitor.visit_fund3(value) }
+	for item in &node.estmtd_fnd_csh_fcst_dtls { visitor.visit_estimated_fund_cash_forecast5(item) }
+	if let Some(ref value) = node.cnsltd_net_csh_fcst { visitor.visit_net_cash_forecast3(value) }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_fund_detailed_estimated_cash_forecast_report_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_042_001_04::FundDetailedEstimatedCashForecastReportV04) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.pool_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	if let Some(ref mut vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	visitor.visit_pagination_mut(&mut node.msg_pgntn);
+	if let Some(ref mut value) = node.fnd_or_sub_fnd_dtls { visitor.visit_fund3_mut(value) }
+	for item in &mut node.estmtd_fnd_csh_fcst_dtls { visitor.visit_estimated_fund_cash_forecast5_mut(item) }
+	if let Some(ref mut value) = node.cnsltd_net_csh_fcst { visitor.visit_net_cash_forecast3_mut(value) }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_fund_detailed_confirmed_cash_forecast_report_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_043_001_04::FundDetailedConfirmedCashForecastReportV04) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.pool_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	if let Some(ref vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	visitor.visit_pagination(&node.msg_pgntn);
+	if let Some(ref value) = node.fnd_or_sub_fnd_dtls { visitor.visit_fund4(value) }
+	for item in &node.fnd_csh_fcst_dtls { visitor.visit_fund_cash_forecast6(item) }
+	if let Some(ref value) = node.cnsltd_net_csh_fcst { visitor.visit_net_cash_forecast3(value) }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_fund_detailed_confirmed_cash_forecast_report_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_043_001_04::FundDetailedConfirmedCashForecastReportV04) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.pool_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	if let Some(ref mut vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	visitor.visit_pagination_mut(&mut node.msg_pgntn);
+	if let Some(ref mut value) = node.fnd_or_sub_fnd_dtls { visitor.visit_fund4_mut(value) }
+	for item in &mut node.fnd_csh_fcst_dtls { visitor.visit_fund_cash_forecast6_mut(item) }
+	if let Some(ref mut value) = node.cnsltd_net_csh_fcst { visitor.visit_net_cash_forecast3_mut(value) }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_fund_confirmed_cash_forecast_report_cancellation_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_044_001_03::FundConfirmedCashForecastReportCancellationV03) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.pool_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref value) = node.prvs_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	visitor.visit_pagination(&node.msg_pgntn);
+	if let Some(ref value) = node.csh_fcst_rpt_to_be_canc { visitor.visit_fund_confirmed_cash_forecast_report3(value) }
+}
+
+pub fn walk_fund_confirmed_cash_forecast_report_cancellation_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_044_001_03::FundConfirmedCashForecastReportCancellationV03) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.pool_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut value) = node.prvs_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	visitor.visit_pagination_mut(&mut node.msg_pgntn);
+	if let Some(ref mut value) = node.csh_fcst_rpt_to_be_canc { visitor.visit_fund_confirmed_cash_forecast_report3_mut(value) }
+}
+
+pub fn walk_fund_detailed_confirmed_cash_forecast_report_cancellation_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_045_001_03::FundDetailedConfirmedCashForecastReportCancellationV03) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.pool_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref value) = node.prvs_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	visitor.visit_pagination(&node.msg_pgntn);
+	if let Some(ref value) = node.csh_fcst_rpt_to_be_canc { visitor.visit_fund_detailed_confirmed_cash_forecast_report3(value) }
+}
+
+pub fn walk_fund_detailed_confirmed_cash_forecast_report_cancellation_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_045_001_03::FundDetailedConfirmedCashForecastReportCancellationV03) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.pool_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut value) = node.prvs_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut vec) = node.rltd_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	visitor.visit_pagination_mut(&mut node.msg_pgntn);
+	if let Some(ref mut value) = node.csh_fcst_rpt_to_be_canc { visitor.visit_fund_detailed_confirmed_cash_forecast_report3_mut(value) }
+}
+
+pub fn walk_get_reservation_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_046_001_08::GetReservationV08) {
+	visitor.visit_message_header9(&node.msg_hdr);
+	if let Some(ref value) = node.rsvatn_qry_def { visitor.visit_reservation_query6(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_get_reservation_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_046_001_08::GetReservationV08) {
+	visitor.visit_message_header9_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.rsvatn_qry_def { visitor.visit_reservation_query6_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_return_reservation_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_047_001_08::ReturnReservationV08) {
+	visitor.visit_message_header7(&node.msg_hdr);
+	visitor.visit_reservation_or_error11_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_return_reservation_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_047_001_08::ReturnReservationV08) {
+	visitor.visit_message_header7_mut(&mut node.msg_hdr);
+	visitor.visit_reservation_or_error11_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_modify_reservation_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_048_001_07::ModifyReservationV07) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_current_or_default_reservation4_choice(&node.rsvatn_id);
+	visitor.visit_reservation4(&node.new_rsvatn_val_set);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_modify_reservation_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_048_001_07::ModifyReservationV07) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_current_or_default_reservation4_choice_mut(&mut node.rsvatn_id);
+	visitor.visit_reservation4_mut(&mut node.new_rsvatn_val_set);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_delete_reservation_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_049_001_07::DeleteReservationV07) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	if let Some(ref value) = node.cur_rsvatn { visitor.visit_reservation_identification4(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_delete_reservation_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_049_001_07::DeleteReservationV07) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.cur_rsvatn { visitor.visit_reservation_identification4_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_liquidity_credit_transfer_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_050_001_07::LiquidityCreditTransferV07) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_liquidity_credit_transfer4(&node.lqdty_cdt_trf);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_liquidity_credit_transfer_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_050_001_07::LiquidityCreditTransferV07) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_liquidity_credit_transfer4_mut(&mut node.lqdty_cdt_trf);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_liquidity_debit_transfer_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_051_001_07::LiquidityDebitTransferV07) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_liquidity_debit_transfer4(&node.lqdty_dbt_trf);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_liquidity_debit_transfer_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_051_001_07::LiquidityDebitTransferV07) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_liquidity_debit_transfer4_mut(&mut node.lqdty_dbt_trf);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_bank_to_customer_account_report_v12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_052_001_12::BankToCustomerAccountReportV12) {
+	visitor.visit_group_header116(&node.grp_hdr);
+	for item in &node.rpt { visitor.visit_account_report33(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_bank_to_customer_account_report_v12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_052_001_12::BankToCustomerAccountReportV12) {
+	visitor.visit_group_header116_mut(&mut node.grp_hdr);
+	for item in &mut node.rpt { visitor.visit_account_report33_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_bank_to_customer_statement_v12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_053_001_12::BankToCustomerStatementV12) {
+	visitor.visit_group_header116(&node.grp_hdr);
+	for item in &node.stmt { visitor.visit_account_statement13(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_bank_to_customer_statement_v12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_053_001_12::BankToCustomerStatementV12) {
+	visitor.visit_group_header116_mut(&mut node.grp_hdr);
+	for item in &mut node.stmt { visitor.visit_account_statement13_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_bank_to_customer_debit_credit_notification_v12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_054_001_12::BankToCustomerDebitCreditNotificationV12) {
+	visitor.visit_group_header116(&node.grp_hdr);
+	for item in &node.ntfctn { visitor.visit_account_notification22(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_bank_to_customer_debit_credit_notification_v12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_054_001_12::BankToCustomerDebitCreditNotificationV12) {
+	visitor.visit_group_header116_mut(&mut node.grp_hdr);
+	for item in &mut node.ntfctn { visitor.visit_account_notification22_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_customer_payment_cancellation_request_v12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_055_001_12::CustomerPaymentCancellationRequestV12) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	if let Some(ref value) = node.ctrl_data { visitor.visit_control_data1(value) }
+	for item in &node.undrlyg { visitor.visit_underlying_transaction33(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_customer_payment_cancellation_request_v12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_055_001_12::CustomerPaymentCancellationRequestV12) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	if let Some(ref mut value) = node.ctrl_data { visitor.visit_control_data1_mut(value) }
+	for item in &mut node.undrlyg { visitor.visit_underlying_transaction33_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_cancellation_request_v11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_056_001_11::FIToFIPaymentCancellationRequestV11) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	if let Some(ref value) = node.ctrl_data { visitor.visit_control_data1(value) }
+	for item in &node.undrlyg { visitor.visit_underlying_transaction34(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_cancellation_request_v11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_056_001_11::FIToFIPaymentCancellationRequestV11) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	if let Some(ref mut value) = node.ctrl_data { visitor.visit_control_data1_mut(value) }
+	for item in &mut node.undrlyg { visitor.visit_underlying_transaction34_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_notification_to_receive_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_057_001_08::NotificationToReceiveV08) {
+	visitor.visit_group_header117(&node.grp_hdr);
+	visitor.visit_account_notification23(&node.ntfctn);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_notification_to_receive_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_057_001_08::NotificationToReceiveV08) {
+	visitor.visit_group_header117_mut(&mut node.grp_hdr);
+	visitor.visit_account_notification23_mut(&mut node.ntfctn);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_notification_to_receive_cancellation_advice_v09<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_058_001_09::NotificationToReceiveCancellationAdviceV09) {
+	visitor.visit_group_header117(&node.grp_hdr);
+	visitor.visit_original_notification16(&node.orgnl_ntfctn);
+	if let Some(ref value) = node.cxl_rsn { visitor.visit_notification_cancellation_reason2(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_notification_to_receive_cancellation_advice_v09_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_058_001_09::NotificationToReceiveCancellationAdviceV09) {
+	visitor.visit_group_header117_mut(&mut node.grp_hdr);
+	visitor.visit_original_notification16_mut(&mut node.orgnl_ntfctn);
+	if let Some(ref mut value) = node.cxl_rsn { visitor.visit_notification_cancellation_reason2_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_notification_to_receive_status_report_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_059_001_08::NotificationToReceiveStatusReportV08) {
+	visitor.visit_group_header121(&node.grp_hdr);
+	visitor.visit_original_notification15(&node.orgnl_ntfctn_and_sts);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_notification_to_receive_status_report_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_059_001_08::NotificationToReceiveStatusReportV08) {
+	visitor.visit_group_header121_mut(&mut node.grp_hdr);
+	visitor.visit_original_notification15_mut(&mut node.orgnl_ntfctn_and_sts);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_account_reporting_request_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_060_001_07::AccountReportingRequestV07) {
+	visitor.visit_group_header117(&node.grp_hdr);
+	for item in &node.rptg_req { visitor.visit_reporting_request7(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_account_reporting_request_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_060_001_07::AccountReportingRequestV07) {
+	visitor.visit_group_header117_mut(&mut node.grp_hdr);
+	for item in &mut node.rptg_req { visitor.visit_reporting_request7_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_pay_in_call_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_061_001_02::PayInCallV02) {
+	visitor.visit_party_identification73_choice(&node.pty_id);
+	visitor.visit_report_data5(&node.rpt_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_pay_in_call_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_061_001_02::PayInCallV02) {
+	visitor.visit_party_identification73_choice_mut(&mut node.pty_id);
+	visitor.visit_report_data5_mut(&mut node.rpt_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_pay_in_schedule_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_062_001_03::PayInScheduleV03) {
+	visitor.visit_party_identification73_choice(&node.pty_id);
+	visitor.visit_report_data4(&node.rpt_data);
+	if let Some(ref vec) = node.pay_in_schdl_lng_bal { for item in vec { visitor.visit_balance_status2(item) } }
+	if let Some(ref vec) = node.pay_in_schdl_itm { for item in vec { visitor.visit_pay_in_schedule_items1(item) } }
+	if let Some(ref value) = node.pay_in_fctrs { visitor.visit_pay_in_factors1(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_pay_in_schedule_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_062_001_03::PayInScheduleV03) {
+	visitor.visit_party_identification73_choice_mut(&mut node.pty_id);
+	visitor.visit_report_data4_mut(&mut node.rpt_data);
+	if let Some(ref mut vec) = node.pay_in_schdl_lng_bal { for item in vec { visitor.visit_balance_status2_mut(item) } }
+	if let Some(ref mut vec) = node.pay_in_schdl_itm { for item in vec { visitor.visit_pay_in_schedule_items1_mut(item) } }
+	if let Some(ref mut value) = node.pay_in_fctrs { visitor.visit_pay_in_factors1_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_pay_in_event_acknowledgement_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_063_001_02::PayInEventAcknowledgementV02) {
+	visitor.visit_acknowledgement_details1_choice(&node.ack_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_pay_in_event_acknowledgement_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_063_001_02::PayInEventAcknowledgementV02) {
+	visitor.visit_acknowledgement_details1_choice_mut(&mut node.ack_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_instruction_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_066_001_02::IntraBalanceMovementInstructionV02) {
+	if let Some(ref value) = node.id { visitor.visit_transaction_and_document_identification6(value) }
+	if let Some(ref value) = node.nb_counts { visitor.visit_number_count1_choice(value) }
+	if let Some(ref vec) = node.lnkgs { for item in vec { visitor.visit_linkages57(item) } }
+	visitor.visit_cash_account40(&node.csh_acct);
+	if let Some(ref value) = node.csh_acct_ownr { visitor.visit_system_party_identification8(value) }
+	if let Some(ref value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8(value) }
+	visitor.visit_intra_balance5(&node.intra_bal);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_instruction_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_066_001_02::IntraBalanceMovementInstructionV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_transaction_and_document_identification6_mut(value) }
+	if let Some(ref mut value) = node.nb_counts { visitor.visit_number_count1_choice_mut(value) }
+	if let Some(ref mut vec) = node.lnkgs { for item in vec { visitor.visit_linkages57_mut(item) } }
+	visitor.visit_cash_account40_mut(&mut node.csh_acct);
+	if let Some(ref mut value) = node.csh_acct_ownr { visitor.visit_system_party_identification8_mut(value) }
+	if let Some(ref mut value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	visitor.visit_intra_balance5_mut(&mut node.intra_bal);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_status_advice_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_067_001_02::IntraBalanceMovementStatusAdviceV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_transaction_identifications24(&node.tx_id);
+	if let Some(ref value) = node.prcg_sts { visitor.visit_processing_status67_choice(value) }
+	if let Some(ref value) = node.sttlm_sts { visitor.visit_settlement_status16_choice(value) }
+	visitor.visit_cash_account40(&node.csh_acct);
+	if let Some(ref value) = node.csh_acct_ownr { visitor.visit_system_party_identification8(value) }
+	if let Some(ref value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8(value) }
+	if let Some(ref value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_status_advice_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_067_001_02::IntraBalanceMovementStatusAdviceV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_transaction_identifications24_mut(&mut node.tx_id);
+	if let Some(ref mut value) = node.prcg_sts { visitor.visit_processing_status67_choice_mut(value) }
+	if let Some(ref mut value) = node.sttlm_sts { visitor.visit_settlement_status16_choice_mut(value) }
+	visitor.visit_cash_account40_mut(&mut node.csh_acct);
+	if let Some(ref mut value) = node.csh_acct_ownr { visitor.visit_system_party_identification8_mut(value) }
+	if let Some(ref mut value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_confirmation_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_068_001_02::IntraBalanceMovementConfirmationV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	if let Some(ref value) = node.addtl_params { visitor.visit_additional_parameters16(value) }
+	visitor.visit_cash_account40(&node.csh_acct);
+	if let Some(ref value) = node.csh_acct_ownr { visitor.visit_system_party_identification8(value) }
+	if let Some(ref value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8(value) }
+	visitor.visit_intra_balance6(&node.intra_bal);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_confirmation_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_068_001_02::IntraBalanceMovementConfirmationV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	if let Some(ref mut value) = node.addtl_params { visitor.visit_additional_parameters16_mut(value) }
+	visitor.visit_cash_account40_mut(&mut node.csh_acct);
+	if let Some(ref mut value) = node.csh_acct_ownr { visitor.visit_system_party_identification8_mut(value) }
+	if let Some(ref mut value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	visitor.visit_intra_balance6_mut(&mut node.intra_bal);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_get_standing_order_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_069_001_05::GetStandingOrderV05) {
+	visitor.visit_message_header4(&node.msg_hdr);
+	if let Some(ref value) = node.stg_ordr_qry_def { visitor.visit_standing_order_query5(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_get_standing_order_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_069_001_05::GetStandingOrderV05) {
+	visitor.visit_message_header4_mut(&mut node.msg_hdr);
+	if let Some(ref mut value) = node.stg_ordr_qry_def { visitor.visit_standing_order_query5_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_return_standing_order_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_070_001_06::ReturnStandingOrderV06) {
+	visitor.visit_message_header6(&node.msg_hdr);
+	visitor.visit_standing_order_or_error9_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_return_standing_order_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_070_001_06::ReturnStandingOrderV06) {
+	visitor.visit_message_header6_mut(&mut node.msg_hdr);
+	visitor.visit_standing_order_or_error9_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_delete_standing_order_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_071_001_05::DeleteStandingOrderV05) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_standing_order_or_all4_choice(&node.stg_ordr_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_delete_standing_order_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_071_001_05::DeleteStandingOrderV05) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_standing_order_or_all4_choice_mut(&mut node.stg_ordr_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_modification_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_072_001_02::IntraBalanceMovementModificationRequestV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_cash_account40(&node.csh_acct);
+	if let Some(ref value) = node.csh_acct_ownr { visitor.visit_system_party_identification8(value) }
+	if let Some(ref value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8(value) }
+	for item in &node.req_dtls { visitor.visit_request_details22(item) }
+	if let Some(ref value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_modification_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_072_001_02::IntraBalanceMovementModificationRequestV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_cash_account40_mut(&mut node.csh_acct);
+	if let Some(ref mut value) = node.csh_acct_ownr { visitor.visit_system_party_identification8_mut(value) }
+	if let Some(ref mut value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	for item in &mut node.req_dtls { visitor.visit_request_details22_mut(item) }
+	if let Some(ref mut value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_modification_request_status_advice_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_073_001_02::IntraBalanceMovementModificationRequestStatusAdviceV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_cash_account40(&node.csh_acct);
+	if let Some(ref value) = node.csh_acct_ownr { visitor.visit_system_party_identification8(value) }
+	if let Some(ref value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8(value) }
+	if let Some(ref value) = node.req_dtls { visitor.visit_request_details22(value) }
+	visitor.visit_processing_status71_choice(&node.prcg_sts);
+	if let Some(ref value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_modification_request_status_advice_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_073_001_02::IntraBalanceMovementModificationRequestStatusAdviceV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_cash_account40_mut(&mut node.csh_acct);
+	if let Some(ref mut value) = node.csh_acct_ownr { visitor.visit_system_party_identification8_mut(value) }
+	if let Some(ref mut value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	if let Some(ref mut value) = node.req_dtls { visitor.visit_request_details22_mut(value) }
+	visitor.visit_processing_status71_choice_mut(&mut node.prcg_sts);
+	if let Some(ref mut value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_cancellation_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_074_001_02::IntraBalanceMovementCancellationRequestV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_references14(&node.tx_id);
+	visitor.visit_cash_account40(&node.csh_acct);
+	if let Some(ref value) = node.csh_acct_ownr { visitor.visit_system_party_identification8(value) }
+	if let Some(ref value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8(value) }
+	if let Some(ref value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_cancellation_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_074_001_02::IntraBalanceMovementCancellationRequestV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_references14_mut(&mut node.tx_id);
+	visitor.visit_cash_account40_mut(&mut node.csh_acct);
+	if let Some(ref mut value) = node.csh_acct_ownr { visitor.visit_system_party_identification8_mut(value) }
+	if let Some(ref mut value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_cancellation_request_status_advice_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_075_001_02::IntraBalanceMovementCancellationRequestStatusAdviceV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_references14(&node.tx_id);
+	visitor.visit_processing_status69_choice(&node.prcg_sts);
+	visitor.visit_cash_account40(&node.csh_acct);
+	if let Some(ref value) = node.csh_acct_ownr { visitor.visit_system_party_identification8(value) }
+	if let Some(ref value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8(value) }
+	if let Some(ref value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_cancellation_request_status_advice_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_075_001_02::IntraBalanceMovementCancellationRequestStatusAdviceV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_references14_mut(&mut node.tx_id);
+	visitor.visit_processing_status69_choice_mut(&mut node.prcg_sts);
+	visitor.visit_cash_account40_mut(&mut node.csh_acct);
+	if let Some(ref mut value) = node.csh_acct_ownr { visitor.visit_system_party_identification8_mut(value) }
+	if let Some(ref mut value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	if let Some(ref mut value) = node.undrlyg_intra_bal { visitor.visit_intra_balance5_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_query_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_078_001_02::IntraBalanceMovementQueryV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_intra_balance_query_definition11(&node.qry_def);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_query_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_078_001_02::IntraBalanceMovementQueryV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_intra_balance_query_definition11_mut(&mut node.qry_def);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_query_response_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_079_001_02::IntraBalanceMovementQueryResponseV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_pagination1(&node.pgntn);
+	visitor.visit_movement_report1(&node.rpt_gnl_dtls);
+	if let Some(ref value) = node.rpt_or_err { visitor.visit_intra_balance_or_operational_error11_choice(value) }
+}
+
+pub fn walk_intra_balance_movement_query_response_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_079_001_02::IntraBalanceMovementQueryResponseV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_pagination1_mut(&mut node.pgntn);
+	visitor.visit_movement_report1_mut(&mut node.rpt_gnl_dtls);
+	if let Some(ref mut value) = node.rpt_or_err { visitor.visit_intra_balance_or_operational_error11_choice_mut(value) }
+}
+
+pub fn walk_intra_balance_movement_modification_query_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_080_001_02::IntraBalanceMovementModificationQueryV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_intra_balance_query_definition12(&node.qry_def);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_modification_query_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_080_001_02::IntraBalanceMovementModificationQueryV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_intra_balance_query_definition12_mut(&mut node.qry_def);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_modification_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_081_001_02::IntraBalanceMovementModificationReportV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_pagination1(&node.pgntn);
+	visitor.visit_intra_balance_report5(&node.rpt_gnl_dtls);
+	if let Some(ref value) = node.rpt_or_err { visitor.visit_intra_balance_or_operational_error12_choice(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_modification_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_081_001_02::IntraBalanceMovementModificationReportV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_pagination1_mut(&mut node.pgntn);
+	visitor.visit_intra_balance_report5_mut(&mut node.rpt_gnl_dtls);
+	if let Some(ref mut value) = node.rpt_or_err { visitor.visit_intra_balance_or_operational_error12_choice_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_cancellation_query_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_082_001_02::IntraBalanceMovementCancellationQueryV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_intra_balance_query_definition10(&node.qry_def);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_cancellation_query_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_082_001_02::IntraBalanceMovementCancellationQueryV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_intra_balance_query_definition10_mut(&mut node.qry_def);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_cancellation_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_083_001_02::IntraBalanceMovementCancellationReportV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_pagination1(&node.pgntn);
+	visitor.visit_intra_balance_report5(&node.rpt_gnl_dtls);
+	if let Some(ref value) = node.rpt_or_err { visitor.visit_intra_balance_or_operational_error10_choice(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_intra_balance_movement_cancellation_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_083_001_02::IntraBalanceMovementCancellationReportV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_pagination1_mut(&mut node.pgntn);
+	visitor.visit_intra_balance_report5_mut(&mut node.rpt_gnl_dtls);
+	if let Some(ref mut value) = node.rpt_or_err { visitor.visit_intra_balance_or_operational_error10_choice_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_posting_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_084_001_02::IntraBalanceMovementPostingReportV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_pagination1(&node.pgntn);
+	visitor.visit_intra_balance_report6(&node.rpt_gnl_dtls);
+	visitor.visit_cash_account40(&node.csh_acct);
+	if let Some(ref value) = node.csh_acct_ownr { visitor.visit_system_party_identification8(value) }
+	if let Some(ref value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8(value) }
+	if let Some(ref vec) = node.sub_bal { for item in vec { visitor.visit_intra_balance_posting5(item) } }
+}
+
+pub fn walk_intra_balance_movement_posting_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_084_001_02::IntraBalanceMovementPostingReportV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_pagination1_mut(&mut node.pgntn);
+	visitor.visit_intra_balance_report6_mut(&mut node.rpt_gnl_dtls);
+	visitor.visit_cash_account40_mut(&mut node.csh_acct);
+	if let Some(ref mut value) = node.csh_acct_ownr { visitor.visit_system_party_identification8_mut(value) }
+	if let Some(ref mut value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	if let Some(ref mut vec) = node.sub_bal { for item in vec { visitor.visit_intra_balance_posting5_mut(item) } }
+}
+
+pub fn walk_intra_balance_movement_pending_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_085_001_02::IntraBalanceMovementPendingReportV02) {
+	if let Some(ref value) = node.id { visitor.visit_document_identification51(value) }
+	visitor.visit_pagination1(&node.pgntn);
+	visitor.visit_intra_balance_report6(&node.rpt_gnl_dtls);
+	visitor.visit_cash_account40(&node.csh_acct);
+	if let Some(ref value) = node.csh_acct_ownr { visitor.visit_system_party_identification8(value) }
+	if let Some(ref value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8(value) }
+	if let Some(ref vec) = node.mvmnts { for item in vec { visitor.visit_intra_balance_pending5(item) } }
+}
+
+pub fn walk_intra_balance_movement_pending_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_085_001_02::IntraBalanceMovementPendingReportV02) {
+	if let Some(ref mut value) = node.id { visitor.visit_document_identification51_mut(value) }
+	visitor.visit_pagination1_mut(&mut node.pgntn);
+	visitor.visit_intra_balance_report6_mut(&mut node.rpt_gnl_dtls);
+	visitor.visit_cash_account40_mut(&mut node.csh_acct);
+	if let Some(ref mut value) = node.csh_acct_ownr { visitor.visit_system_party_identification8_mut(value) }
+	if let Some(ref mut value) = node.csh_acct_svcr { visitor.visit_branch_and_financial_institution_identification8_mut(value) }
+	if let Some(ref mut vec) = node.mvmnts { for item in vec { visitor.visit_intra_balance_pending5_mut(item) } }
+}
+
+pub fn walk_bank_services_billing_statement_v05<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_086_001_05::BankServicesBillingStatementV05) {
+	visitor.visit_report_header6(&node.rpt_hdr);
+	for item in &node.bllg_stmt_grp { visitor.visit_statement_group5(item) }
+}
+
+pub fn walk_bank_services_billing_statement_v05_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_086_001_05::BankServicesBillingStatementV05) {
+	visitor.visit_report_header6_mut(&mut node.rpt_hdr);
+	for item in &mut node.bllg_stmt_grp { visitor.visit_statement_group5_mut(item) }
+}
+
+pub fn walk_request_to_modify_payment_v09<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_087_001_09::RequestToModifyPaymentV09) {
+	visitor.visit_case_assignment6(&node.assgnmt);
+	if let Some(ref value) = node.case { visitor.visit_case6(value) }
+	visitor.visit_underlying_transaction8_choice(&node.undrlyg);
+	visitor.visit_requested_modification11(&node.mod_attr);
+	if let Some(ref value) = node.instr_for_assgne { visitor.visit_instruction_for_assignee1(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_to_modify_payment_v09_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_087_001_09::RequestToModifyPaymentV09) {
+	visitor.visit_case_assignment6_mut(&mut node.assgnmt);
+	if let Some(ref mut value) = node.case { visitor.visit_case6_mut(value) }
+	visitor.visit_underlying_transaction8_choice_mut(&mut node.undrlyg);
+	visitor.visit_requested_modification11_mut(&mut node.mod_attr);
+	if let Some(ref mut value) = node.instr_for_assgne { visitor.visit_instruction_for_assignee1_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_net_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_088_001_02::NetReportV02) {
+	visitor.visit_net_report_data2(&node.net_rpt_data);
+	visitor.visit_party_identification242_choice(&node.net_svc_ptcpt_id);
+	if let Some(ref value) = node.net_svc_ctr_pty_id { visitor.visit_party_identification242_choice(value) }
+	for item in &node.net_oblgtn { visitor.visit_net_obligation2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_net_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_088_001_02::NetReportV02) {
+	visitor.visit_net_report_data2_mut(&mut node.net_rpt_data);
+	visitor.visit_party_identification242_choice_mut(&mut node.net_svc_ptcpt_id);
+	if let Some(ref mut value) = node.net_svc_ctr_pty_id { visitor.visit_party_identification242_choice_mut(value) }
+	for item in &mut node.net_oblgtn { visitor.visit_net_obligation2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_create_limit_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_101_001_02::CreateLimitV02) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	for item in &node.lmt_data { visitor.visit_limit_structure6(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_create_limit_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_101_001_02::CreateLimitV02) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	for item in &mut node.lmt_data { visitor.visit_limit_structure6_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_create_standing_order_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_102_001_03::CreateStandingOrderV03) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_standing_order_identification8(&node.stg_ordr_id);
+	visitor.visit_standing_order10(&node.val_set);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_create_standing_order_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_102_001_03::CreateStandingOrderV03) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_standing_order_identification8_mut(&mut node.stg_ordr_id);
+	visitor.visit_standing_order10_mut(&mut node.val_set);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_create_reservation_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_103_001_03::CreateReservationV03) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_reservation_identification4(&node.rsvatn_id);
+	visitor.visit_reservation4(&node.val_set);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_create_reservation_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_103_001_03::CreateReservationV03) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_reservation_identification4_mut(&mut node.rsvatn_id);
+	visitor.visit_reservation4_mut(&mut node.val_set);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_create_member_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_104_001_01::CreateMemberV01) {
+	visitor.visit_message_header1(&node.msg_hdr);
+	visitor.visit_member_identification3_choice(&node.mmb_id);
+	visitor.visit_member6(&node.val_set);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_create_member_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_104_001_01::CreateMemberV01) {
+	visitor.visit_message_header1_mut(&mut node.msg_hdr);
+	visitor.visit_member_identification3_choice_mut(&mut node.mmb_id);
+	visitor.visit_member6_mut(&mut node.val_set);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_charges_payment_notification_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_105_001_02::ChargesPaymentNotificationV02) {
+	visitor.visit_group_header126(&node.grp_hdr);
+	visitor.visit_charges4_choice(&node.chrgs);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_charges_payment_notification_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_105_001_02::ChargesPaymentNotificationV02) {
+	visitor.visit_group_header126_mut(&mut node.grp_hdr);
+	visitor.visit_charges4_choice_mut(&mut node.chrgs);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_charges_payment_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_106_001_02::ChargesPaymentRequestV02) {
+	visitor.visit_group_header115(&node.grp_hdr);
+	visitor.visit_charges3_choice(&node.chrgs);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_charges_payment_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_106_001_02::ChargesPaymentRequestV02) {
+	visitor.visit_group_header115_mut(&mut node.grp_hdr);
+	visitor.visit_charges3_choice_mut(&mut node.chrgs);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_cheque_presentment_notification_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_107_001_02::ChequePresentmentNotificationV02) {
+	visitor.visit_group_header103(&node.grp_hdr);
+	for item in &node.chq { visitor.visit_cheque17(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_cheque_presentment_notification_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_107_001_02::ChequePresentmentNotificationV02) {
+	visitor.visit_group_header103_mut(&mut node.grp_hdr);
+	for item in &mut node.chq { visitor.visit_cheque17_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_cheque_cancellation_or_stop_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_108_001_02::ChequeCancellationOrStopRequestV02) {
+	visitor.visit_group_header103(&node.grp_hdr);
+	for item in &node.chq { visitor.visit_cheque20(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_cheque_cancellation_or_stop_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_108_001_02::ChequeCancellationOrStopRequestV02) {
+	visitor.visit_group_header103_mut(&mut node.grp_hdr);
+	for item in &mut node.chq { visitor.visit_cheque20_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_cheque_cancellation_or_stop_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_109_001_02::ChequeCancellationOrStopReportV02) {
+	visitor.visit_group_header103(&node.grp_hdr);
+	for item in &node.chq { visitor.visit_cheque18(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_cheque_cancellation_or_stop_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_109_001_02::ChequeCancellationOrStopReportV02) {
+	visitor.visit_group_header103_mut(&mut node.grp_hdr);
+	for item in &mut node.chq { visitor.visit_cheque18_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_investigation_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_110_001_01::InvestigationRequestV01) {
+	visitor.visit_investigation_request2(&node.invstgtn_req);
+	for item in &node.invstgtn_data { visitor.visit_investigation_reason2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_investigation_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_110_001_01::InvestigationRequestV01) {
+	visitor.visit_investigation_request2_mut(&mut node.invstgtn_req);
+	for item in &mut node.invstgtn_data { visitor.visit_investigation_reason2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_investigation_response_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::camt_111_001_01::InvestigationResponseV01) {
+	visitor.visit_investigation_response3(&node.invstgtn_rspn);
+	visitor.visit_investigation_request3(&node.orgnl_invstgtn_req);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_investigation_response_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::camt_111_001_01::InvestigationResponseV01) {
+	visitor.visit_investigation_response3_mut(&mut node.invstgtn_rspn);
+	visitor.visit_investigation_request3_mut(&mut node.orgnl_invstgtn_req);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+impl crate::camt_003_001_08::GetAccountV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_account_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_account_v08_mut(self) }
+}
+
+impl crate::camt_004_001_10::ReturnAccountV10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_account_v10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_account_v10_mut(self) }
+}
+
+impl crate::camt_005_001_11::GetTransactionV11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_transaction_v11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_transaction_v11_mut(self) }
+}
+
+impl crate::camt_006_001_11::ReturnTransactionV11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_transaction_v11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_transaction_v11_mut(self) }
+}
+
+impl crate::camt_007_001_10::ModifyTransactionV10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_modify_transaction_v10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_modify_transaction_v10_mut(self) }
+}
+
+impl crate::camt_008_001_11::CancelTransactionV11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cancel_transaction_v11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cancel_transaction_v11_mut(self) }
+}
+
+impl crate::camt_009_001_08::GetLimitV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_limit_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_limit_v08_mut(self) }
+}
+
+impl crate::camt_010_001_09::ReturnLimitV09 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_limit_v09(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_limit_v09_mut(self) }
+}
+
+impl crate::camt_011_001_08::ModifyLimitV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_modify_limit_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_modify_limit_v08_mut(self) }
+}
+
+impl crate::camt_012_001_08::DeleteLimitV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_delete_limit_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_delete_limit_v08_mut(self) }
+}
+
+impl crate::camt_013_001_04::GetMemberV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_member_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_member_v04_mut(self) }
+}
+
+impl crate::camt_014_001_05::ReturnMemberV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_member_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_member_v05_mut(self) }
+}
+
+impl crate::camt_015_001_04::ModifyMemberV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_modify_member_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_modify_member_v04_mut(self) }
+}
+
+impl crate::camt_016_001_04::GetCurrencyExchangeRateV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_currency_exchange_rate_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_currency_exchange_rate_v04_mut(self) }
+}
+
+impl crate::camt_017_001_05::ReturnCurrencyExchangeRateV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_currency_exchange_rate_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_currency_exchange_rate_v05_mut(self) }
+}
+
+impl crate::camt_018_001_05::GetBusinessDayInformationV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_business_day_information_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_business_day_information_v05_mut(self) }
+}
+
+impl crate::camt_019_001_07::ReturnBusinessDayInformationV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_business_day_information_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_business_day_information_v07_mut(self) }
+}
+
+impl crate::camt_020_001_04::GetGeneralBusinessInformationV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_general_business_information_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_general_business_information_v04_mut(self) }
+}
+
+impl crate::camt_021_001_06::ReturnGeneralBusinessInformationV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_general_business_information_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_general_business_information_v06_mut(self) }
+}
+
+impl crate::camt_023_001_07::BackupPaymentV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_backup_payment_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_backup_payment_v07_mut(self) }
+}
+
+impl crate::camt_024_001_08::ModifyStandingOrderV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_modify_standing_order_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_modify_standing_order_v08_mut(self) }
+}
+
+impl crate::camt_025_001_08::ReceiptV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_receipt_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_receipt_v08_mut(self) }
+}
+
+impl crate::camt_026_001_10::UnableToApplyV10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_unable_to_apply_v10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_unable_to_apply_v10_mut(self) }
+}
+
+impl crate::camt_027_001_10::ClaimNonReceiptV10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_claim_non_receipt_v10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_claim_non_receipt_v10_mut(self) }
+}
+
+impl crate::camt_028_001_12::AdditionalPaymentInformationV12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_additional_payment_information_v12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_additional_payment_information_v12_mut(self) }
+}
+
+impl crate::camt_029_001_13::ResolutionOfInvestigationV13 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_resolution_of_investigation_v13(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_resolution_of_investigation_v13_mut(self) }
+}
+
+impl crate::camt_030_001_06::NotificationOfCaseAssignmentV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_notification_of_case_assignment_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_notification_of_case_assignment_v06_mut(self) }
+}
+
+impl crate::camt_031_001_07::RejectInvestigationV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_reject_investigation_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_reject_investigation_v07_mut(self) }
+}
+
+impl crate::camt_032_001_05::CancelCaseAssignmentV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cancel_case_assignment_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cancel_case_assignment_v05_mut(self) }
+}
+
+impl crate::camt_033_001_07::RequestForDuplicateV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_for_duplicate_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_for_duplicate_v07_mut(self) }
+}
+
+impl crate::camt_034_001_07::DuplicateV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_duplicate_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_duplicate_v07_mut(self) }
+}
+
+impl crate::camt_035_001_06::ProprietaryFormatInvestigationV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_proprietary_format_investigation_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_proprietary_format_investigation_v06_mut(self) }
+}
+
+impl crate::camt_036_001_06::DebitAuthorisationResponseV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_debit_authorisation_response_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_debit_authorisation_response_v06_mut(self) }
+}
+
+impl crate::camt_037_001_10::DebitAuthorisationRequestV10 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_debit_authorisation_request_v10(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_debit_authorisation_request_v10_mut(self) }
+}
+
+impl crate::camt_038_001_05::CaseStatusReportRequestV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_case_status_report_request_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_case_status_report_request_v05_mut(self) }
+}
+
+impl crate::camt_039_001_06::CaseStatusReportV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_case_status_report_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_case_status_report_v06_mut(self) }
+}
+
+impl crate::camt_040_001_04::FundEstimatedCashForecastReportV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fund_estimated_cash_forecast_report_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fund_estimated_cash_forecast_report_v04_mut(self) }
+}
+
+impl crate::camt_041_001_04::FundConfirmedCashForecastReportV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fund_confirmed_cash_forecast_report_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fund_confirmed_cash_forecast_report_v04_mut(self) }
+}
+
+impl crate::camt_042_001_04::FundDetailedEstimatedCashForecastReportV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fund_detailed_estimated_cash_forecast_report_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fund_detailed_estimated_cash_forecast_report_v04_mut(self) }
+}
+
+impl crate::camt_043_001_04::FundDetailedConfirmedCashForecastReportV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fund_detailed_confirmed_cash_forecast_report_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fund_detailed_confirmed_cash_forecast_report_v04_mut(self) }
+}
+
+impl crate::camt_044_001_03::FundConfirmedCashForecastReportCancellationV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fund_confirmed_cash_forecast_report_cancellation_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fund_confirmed_cash_forecast_report_cancellation_v03_mut(self) }
+}
+
+impl crate::camt_045_001_03::FundDetailedConfirmedCashForecastReportCancellationV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fund_detailed_confirmed_cash_forecast_report_cancellation_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fund_detailed_confirmed_cash_forecast_report_cancellation_v03_mut(self) }
+}
+
+impl crate::camt_046_001_08::GetReservationV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_reservation_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_reservation_v08_mut(self) }
+}
+
+impl crate::camt_047_001_08::ReturnReservationV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_reservation_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_reservation_v08_mut(self) }
+}
+
+impl crate::camt_048_001_07::ModifyReservationV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_modify_reservation_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_modify_reservation_v07_mut(self) }
+}
+
+impl crate::camt_049_001_07::DeleteReservationV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_delete_reservation_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_delete_reservation_v07_mut(self) }
+}
+
+impl crate::camt_050_001_07::LiquidityCreditTransferV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_liquidity_credit_transfer_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_liquidity_credit_transfer_v07_mut(self) }
+}
+
+impl crate::camt_051_001_07::LiquidityDebitTransferV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_liquidity_debit_transfer_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_liquidity_debit_transfer_v07_mut(self) }
+}
+
+impl crate::camt_052_001_12::BankToCustomerAccountReportV12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_bank_to_customer_account_report_v12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_bank_to_customer_account_report_v12_mut(self) }
+}
+
+impl crate::camt_053_001_12::BankToCustomerStatementV12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_bank_to_customer_statement_v12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_bank_to_customer_statement_v12_mut(self) }
+}
+
+impl crate::camt_054_001_12::BankToCustomerDebitCreditNotificationV12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_bank_to_customer_debit_credit_notification_v12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_bank_to_customer_debit_credit_notification_v12_mut(self) }
+}
+
+impl crate::camt_055_001_12::CustomerPaymentCancellationRequestV12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_customer_payment_cancellation_request_v12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_customer_payment_cancellation_request_v12_mut(self) }
+}
+
+impl crate::camt_056_001_11::FIToFIPaymentCancellationRequestV11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_cancellation_request_v11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_cancellation_request_v11_mut(self) }
+}
+
+impl crate::camt_057_001_08::NotificationToReceiveV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_notification_to_receive_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_notification_to_receive_v08_mut(self) }
+}
+
+impl crate::camt_058_001_09::NotificationToReceiveCancellationAdviceV09 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_notification_to_receive_cancellation_advice_v09(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_notification_to_receive_cancellation_advice_v09_mut(self) }
+}
+
+impl crate::camt_059_001_08::NotificationToReceiveStatusReportV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_notification_to_receive_status_report_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_notification_to_receive_status_report_v08_mut(self) }
+}
+
+impl crate::camt_060_001_07::AccountReportingRequestV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_account_reporting_request_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_account_reporting_request_v07_mut(self) }
+}
+
+impl crate::camt_061_001_02::PayInCallV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_pay_in_call_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_pay_in_call_v02_mut(self) }
+}
+
+impl crate::camt_062_001_03::PayInScheduleV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_pay_in_schedule_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_pay_in_schedule_v03_mut(self) }
+}
+
+impl crate::camt_063_001_02::PayInEventAcknowledgementV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_pay_in_event_acknowledgement_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_pay_in_event_acknowledgement_v02_mut(self) }
+}
+
+impl crate::camt_066_001_02::IntraBalanceMovementInstructionV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_instruction_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_instruction_v02_mut(self) }
+}
+
+impl crate::camt_067_001_02::IntraBalanceMovementStatusAdviceV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_status_advice_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_status_advice_v02_mut(self) }
+}
+
+impl crate::camt_068_001_02::IntraBalanceMovementConfirmationV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_confirmation_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_confirmation_v02_mut(self) }
+}
+
+impl crate::camt_069_001_05::GetStandingOrderV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_get_standing_order_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_get_standing_order_v05_mut(self) }
+}
+
+impl crate::camt_070_001_06::ReturnStandingOrderV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_return_standing_order_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_return_standing_order_v06_mut(self) }
+}
+
+impl crate::camt_071_001_05::DeleteStandingOrderV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_delete_standing_order_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_delete_standing_order_v05_mut(self) }
+}
+
+impl crate::camt_072_001_02::IntraBalanceMovementModificationRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_modification_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_modification_request_v02_mut(self) }
+}
+
+impl crate::camt_073_001_02::IntraBalanceMovementModificationRequestStatusAdviceV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_modification_request_status_advice_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_modification_request_status_advice_v02_mut(self) }
+}
+
+impl crate::camt_074_001_02::IntraBalanceMovementCancellationRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_cancellation_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_cancellation_request_v02_mut(self) }
+}
+
+impl crate::camt_075_001_02::IntraBalanceMovementCancellationRequestStatusAdviceV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_cancellation_request_status_advice_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_cancellation_request_status_advice_v02_mut(self) }
+}
+
+impl crate::camt_078_001_02::IntraBalanceMovementQueryV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_query_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_query_v02_mut(self) }
+}
+
+impl crate::camt_079_001_02::IntraBalanceMovementQueryResponseV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_query_response_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_query_response_v02_mut(self) }
+}
+
+impl crate::camt_080_001_02::IntraBalanceMovementModificationQueryV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_modification_query_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_modification_query_v02_mut(self) }
+}
+
+impl crate::camt_081_001_02::IntraBalanceMovementModificationReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_modification_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_modification_report_v02_mut(self) }
+}
+
+impl crate::camt_082_001_02::IntraBalanceMovementCancellationQueryV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_cancellation_query_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_cancellation_query_v02_mut(self) }
+}
+
+impl crate::camt_083_001_02::IntraBalanceMovementCancellationReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_cancellation_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_cancellation_report_v02_mut(self) }
+}
+
+impl crate::camt_084_001_02::IntraBalanceMovementPostingReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_posting_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_posting_report_v02_mut(self) }
+}
+
+impl crate::camt_085_001_02::IntraBalanceMovementPendingReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_intra_balance_movement_pending_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_intra_balance_movement_pending_report_v02_mut(self) }
+}
+
+impl crate::camt_086_001_05::BankServicesBillingStatementV05 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_bank_services_billing_statement_v05(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_bank_services_billing_statement_v05_mut(self) }
+}
+
+impl crate::camt_087_001_09::RequestToModifyPaymentV09 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_to_modify_payment_v09(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_to_modify_payment_v09_mut(self) }
+}
+
+impl crate::camt_088_001_02::NetReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_net_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_net_report_v02_mut(self) }
+}
+
+impl crate::camt_101_001_02::CreateLimitV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_create_limit_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_create_limit_v02_mut(self) }
+}
+
+impl crate::camt_102_001_03::CreateStandingOrderV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_create_standing_order_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_create_standing_order_v03_mut(self) }
+}
+
+impl crate::camt_103_001_03::CreateReservationV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_create_reservation_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_create_reservation_v03_mut(self) }
+}
+
+impl crate::camt_104_001_01::CreateMemberV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_create_member_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_create_member_v01_mut(self) }
+}
+
+impl crate::camt_105_001_02::ChargesPaymentNotificationV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_charges_payment_notification_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_charges_payment_notification_v02_mut(self) }
+}
+
+impl crate::camt_106_001_02::ChargesPaymentRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_charges_payment_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_charges_payment_request_v02_mut(self) }
+}
+
+impl crate::camt_107_001_02::ChequePresentmentNotificationV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cheque_presentment_notification_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cheque_presentment_notification_v02_mut(self) }
+}
+
+impl crate::camt_108_001_02::ChequeCancellationOrStopRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cheque_cancellation_or_stop_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cheque_cancellation_or_stop_request_v02_mut(self) }
+}
+
+impl crate::camt_109_001_02::ChequeCancellationOrStopReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_cheque_cancellation_or_stop_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_cheque_cancellation_or_stop_report_v02_mut(self) }
+}
+
+impl crate::camt_110_001_01::InvestigationRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_investigation_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_investigation_request_v01_mut(self) }
+}
+
+impl crate::camt_111_001_01::InvestigationResponseV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_investigation_response_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_investigation_response_v01_mut(self) }
+}
@@ -26,3 +26,7 @@
 pub mod head_001_001_02;
 pub mod head_001_001_04;
 pub mod head_002_001_01;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
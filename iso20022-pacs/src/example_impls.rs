@@ -0,0 +1,133 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `Example` for the generated types of this crate, generated by
+// generate-example.py from their facets (see `open_payments_common::example`).
+
+use open_payments_common::example::Example;
+
+impl Example for crate::pacs_002_001_12::FIToFIPaymentStatusReportV12 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf_and_sts: None,
+			tx_inf_and_sts: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pacs_002_001_14::FIToFIPaymentStatusReportV14 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf_and_sts: None,
+			tx_inf_and_sts: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pacs_003_001_11::FIToFICustomerDirectDebitV11 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			drct_dbt_tx_inf: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pacs_004_001_13::PaymentReturnV13 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf: None,
+			tx_inf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pacs_007_001_13::FIToFIPaymentReversalV13 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf: None,
+			tx_inf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pacs_008_001_12::FIToFICustomerCreditTransferV12 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			cdt_trf_tx_inf: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pacs_009_001_11::FinancialInstitutionCreditTransferV11 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			cdt_trf_tx_inf: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pacs_010_001_06::FinancialInstitutionDirectDebitV06 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			cdt_instr: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pacs_028_001_06::FIToFIPaymentStatusRequestV06 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf: None,
+			tx_inf: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pacs_029_001_02::MultilateralSettlementRequestV02 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			sttlm_req: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
@@ -33,3 +33,7 @@ pub mod pacs_009_001_11;
 pub mod pacs_010_001_06;
 pub mod pacs_028_001_06;
 pub mod pacs_029_001_02;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
@@ -0,0 +1,179 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `TagPaths` for the generated types of this crate, generated by
+// generate-tag-path.py from the tags of their fields (see
+// `open_payments_common::tag_path`).
+
+use open_payments_common::tag_path::*;
+
+impl TagPaths for crate::pacs_002_001_12::FIToFIPaymentStatusReportV12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { optional_repeated(&self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("TxInfAndSts") { optional_repeated(&self.tx_inf_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { optional_repeated_mut(&mut self.orgnl_grp_inf_and_sts, step, create, out) }
+		if step.matches("TxInfAndSts") { optional_repeated_mut(&mut self.tx_inf_and_sts, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pacs_002_001_14::FIToFIPaymentStatusReportV14 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { optional_repeated(&self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("TxInfAndSts") { optional_repeated(&self.tx_inf_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { optional_repeated_mut(&mut self.orgnl_grp_inf_and_sts, step, create, out) }
+		if step.matches("TxInfAndSts") { optional_repeated_mut(&mut self.tx_inf_and_sts, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pacs_003_001_11::FIToFICustomerDirectDebitV11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("DrctDbtTxInf") { repeated(&self.drct_dbt_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("DrctDbtTxInf") { repeated_mut(&mut self.drct_dbt_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pacs_004_001_13::PaymentReturnV13 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("TxInf") { optional_repeated(&self.tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("TxInf") { optional_repeated_mut(&mut self.tx_inf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pacs_007_001_13::FIToFIPaymentReversalV13 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional(&self.orgnl_grp_inf, step, out) }
+		if step.matches("TxInf") { optional_repeated(&self.tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("TxInf") { optional_repeated_mut(&mut self.tx_inf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pacs_008_001_12::FIToFICustomerCreditTransferV12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("CdtTrfTxInf") { repeated(&self.cdt_trf_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("CdtTrfTxInf") { repeated_mut(&mut self.cdt_trf_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pacs_009_001_11::FinancialInstitutionCreditTransferV11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("CdtTrfTxInf") { repeated(&self.cdt_trf_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("CdtTrfTxInf") { repeated_mut(&mut self.cdt_trf_tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pacs_010_001_06::FinancialInstitutionDirectDebitV06 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("CdtInstr") { repeated(&self.cdt_instr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("CdtInstr") { repeated_mut(&mut self.cdt_instr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pacs_028_001_06::FIToFIPaymentStatusRequestV06 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional_repeated(&self.orgnl_grp_inf, step, out) }
+		if step.matches("TxInf") { optional_repeated(&self.tx_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { optional_repeated_mut(&mut self.orgnl_grp_inf, step, create, out) }
+		if step.matches("TxInf") { optional_repeated_mut(&mut self.tx_inf, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pacs_029_001_02::MultilateralSettlementRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("SttlmReq") { repeated(&self.sttlm_req, step, out) }
+		if step.matches("SplmtryData") { optional(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("SttlmReq") { repeated_mut(&mut self.sttlm_req, step, out) }
+		if step.matches("SplmtryData") { optional_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
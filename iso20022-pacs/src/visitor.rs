@@ -0,0 +1,237 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over the generated types of this crate, generated by
+// generate-visitor.py. `Visitor` has a `visit_` method per type that
+// walks into its fields by default; override the methods of the types of
+// interest and call the matching `walk_` function to keep going below
+// them. `VisitorMut` does the same with mutable references.
+
+use open_payments_common::visitor::{Visitor as BaseVisitor, VisitorMut as BaseVisitorMut};
+
+pub trait Visitor: BaseVisitor {
+	fn visit_fi_to_fi_payment_status_report_v12(&mut self, node: &crate::pacs_002_001_12::FIToFIPaymentStatusReportV12) { walk_fi_to_fi_payment_status_report_v12(self, node) }
+	fn visit_fi_to_fi_payment_status_report_v14(&mut self, node: &crate::pacs_002_001_14::FIToFIPaymentStatusReportV14) { walk_fi_to_fi_payment_status_report_v14(self, node) }
+	fn visit_fi_to_fi_customer_direct_debit_v11(&mut self, node: &crate::pacs_003_001_11::FIToFICustomerDirectDebitV11) { walk_fi_to_fi_customer_direct_debit_v11(self, node) }
+	fn visit_payment_return_v13(&mut self, node: &crate::pacs_004_001_13::PaymentReturnV13) { walk_payment_return_v13(self, node) }
+	fn visit_fi_to_fi_payment_reversal_v13(&mut self, node: &crate::pacs_007_001_13::FIToFIPaymentReversalV13) { walk_fi_to_fi_payment_reversal_v13(self, node) }
+	fn visit_fi_to_fi_customer_credit_transfer_v12(&mut self, node: &crate::pacs_008_001_12::FIToFICustomerCreditTransferV12) { walk_fi_to_fi_customer_credit_transfer_v12(self, node) }
+	fn visit_financial_institution_credit_transfer_v11(&mut self, node: &crate::pacs_009_001_11::FinancialInstitutionCreditTransferV11) { walk_financial_institution_credit_transfer_v11(self, node) }
+	fn visit_financial_institution_direct_debit_v06(&mut self, node: &crate::pacs_010_001_06::FinancialInstitutionDirectDebitV06) { walk_financial_institution_direct_debit_v06(self, node) }
+	fn visit_fi_to_fi_payment_status_request_v06(&mut self, node: &crate::pacs_028_001_06::FIToFIPaymentStatusRequestV06) { walk_fi_to_fi_payment_status_request_v06(self, node) }
+	fn visit_multilateral_settlement_request_v02(&mut self, node: &crate::pacs_029_001_02::MultilateralSettlementRequestV02) { walk_multilateral_settlement_request_v02(self, node) }
+}
+
+pub trait VisitorMut: BaseVisitorMut {
+	fn visit_fi_to_fi_payment_status_report_v12_mut(&mut self, node: &mut crate::pacs_002_001_12::FIToFIPaymentStatusReportV12) { walk_fi_to_fi_payment_status_report_v12_mut(self, node) }
+	fn visit_fi_to_fi_payment_status_report_v14_mut(&mut self, node: &mut crate::pacs_002_001_14::FIToFIPaymentStatusReportV14) { walk_fi_to_fi_payment_status_report_v14_mut(self, node) }
+	fn visit_fi_to_fi_customer_direct_debit_v11_mut(&mut self, node: &mut crate::pacs_003_001_11::FIToFICustomerDirectDebitV11) { walk_fi_to_fi_customer_direct_debit_v11_mut(self, node) }
+	fn visit_payment_return_v13_mut(&mut self, node: &mut crate::pacs_004_001_13::PaymentReturnV13) { walk_payment_return_v13_mut(self, node) }
+	fn visit_fi_to_fi_payment_reversal_v13_mut(&mut self, node: &mut crate::pacs_007_001_13::FIToFIPaymentReversalV13) { walk_fi_to_fi_payment_reversal_v13_mut(self, node) }
+	fn visit_fi_to_fi_customer_credit_transfer_v12_mut(&mut self, node: &mut crate::pacs_008_001_12::FIToFICustomerCreditTransferV12) { walk_fi_to_fi_customer_credit_transfer_v12_mut(self, node) }
+	fn visit_financial_institution_credit_transfer_v11_mut(&mut self, node: &mut crate::pacs_009_001_11::FinancialInstitutionCreditTransferV11) { walk_financial_institution_credit_transfer_v11_mut(self, node) }
+	fn visit_financial_institution_direct_debit_v06_mut(&mut self, node: &mut crate::pacs_010_001_06::FinancialInstitutionDirectDebitV06) { walk_financial_institution_direct_debit_v06_mut(self, node) }
+	fn visit_fi_to_fi_payment_status_request_v06_mut(&mut self, node: &mut crate::pacs_028_001_06::FIToFIPaymentStatusRequestV06) { walk_fi_to_fi_payment_status_request_v06_mut(self, node) }
+	fn visit_multilateral_settlement_request_v02_mut(&mut self, node: &mut crate::pacs_029_001_02::MultilateralSettlementRequestV02) { walk_multilateral_settlement_request_v02_mut(self, node) }
+}
+
+pub fn walk_fi_to_fi_payment_status_report_v12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_002_001_12::FIToFIPaymentStatusReportV12) {
+	visitor.visit_group_header101(&node.grp_hdr);
+	if let Some(ref vec) = node.orgnl_grp_inf_and_sts { for item in vec { visitor.visit_original_group_header17(item) } }
+	if let Some(ref vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction130(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_status_report_v12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_002_001_12::FIToFIPaymentStatusReportV12) {
+	visitor.visit_group_header101_mut(&mut node.grp_hdr);
+	if let Some(ref mut vec) = node.orgnl_grp_inf_and_sts { for item in vec { visitor.visit_original_group_header17_mut(item) } }
+	if let Some(ref mut vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction130_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_status_report_v14<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_002_001_14::FIToFIPaymentStatusReportV14) {
+	visitor.visit_group_header120(&node.grp_hdr);
+	if let Some(ref vec) = node.orgnl_grp_inf_and_sts { for item in vec { visitor.visit_original_group_header22(item) } }
+	if let Some(ref vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction161(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_status_report_v14_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_002_001_14::FIToFIPaymentStatusReportV14) {
+	visitor.visit_group_header120_mut(&mut node.grp_hdr);
+	if let Some(ref mut vec) = node.orgnl_grp_inf_and_sts { for item in vec { visitor.visit_original_group_header22_mut(item) } }
+	if let Some(ref mut vec) = node.tx_inf_and_sts { for item in vec { visitor.visit_payment_transaction161_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_customer_direct_debit_v11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_003_001_11::FIToFICustomerDirectDebitV11) {
+	visitor.visit_group_header125(&node.grp_hdr);
+	for item in &node.drct_dbt_tx_inf { visitor.visit_direct_debit_transaction_information31(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_customer_direct_debit_v11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_003_001_11::FIToFICustomerDirectDebitV11) {
+	visitor.visit_group_header125_mut(&mut node.grp_hdr);
+	for item in &mut node.drct_dbt_tx_inf { visitor.visit_direct_debit_transaction_information31_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_payment_return_v13<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_004_001_13::PaymentReturnV13) {
+	visitor.visit_group_header123(&node.grp_hdr);
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_header19(value) }
+	if let Some(ref vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction159(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_payment_return_v13_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_004_001_13::PaymentReturnV13) {
+	visitor.visit_group_header123_mut(&mut node.grp_hdr);
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_header19_mut(value) }
+	if let Some(ref mut vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction159_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_reversal_v13<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_007_001_13::FIToFIPaymentReversalV13) {
+	visitor.visit_group_header127(&node.grp_hdr);
+	if let Some(ref value) = node.orgnl_grp_inf { visitor.visit_original_group_header20(value) }
+	if let Some(ref vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction149(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_reversal_v13_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_007_001_13::FIToFIPaymentReversalV13) {
+	visitor.visit_group_header127_mut(&mut node.grp_hdr);
+	if let Some(ref mut value) = node.orgnl_grp_inf { visitor.visit_original_group_header20_mut(value) }
+	if let Some(ref mut vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction149_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_customer_credit_transfer_v12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_008_001_12::FIToFICustomerCreditTransferV12) {
+	visitor.visit_group_header113(&node.grp_hdr);
+	for item in &node.cdt_trf_tx_inf { visitor.visit_credit_transfer_transaction64(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_customer_credit_transfer_v12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_008_001_12::FIToFICustomerCreditTransferV12) {
+	visitor.visit_group_header113_mut(&mut node.grp_hdr);
+	for item in &mut node.cdt_trf_tx_inf { visitor.visit_credit_transfer_transaction64_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_institution_credit_transfer_v11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_009_001_11::FinancialInstitutionCreditTransferV11) {
+	visitor.visit_group_header113(&node.grp_hdr);
+	for item in &node.cdt_trf_tx_inf { visitor.visit_credit_transfer_transaction62(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_institution_credit_transfer_v11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_009_001_11::FinancialInstitutionCreditTransferV11) {
+	visitor.visit_group_header113_mut(&mut node.grp_hdr);
+	for item in &mut node.cdt_trf_tx_inf { visitor.visit_credit_transfer_transaction62_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_financial_institution_direct_debit_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_010_001_06::FinancialInstitutionDirectDebitV06) {
+	visitor.visit_group_header119(&node.grp_hdr);
+	for item in &node.cdt_instr { visitor.visit_credit_transfer_transaction66(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_financial_institution_direct_debit_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_010_001_06::FinancialInstitutionDirectDebitV06) {
+	visitor.visit_group_header119_mut(&mut node.grp_hdr);
+	for item in &mut node.cdt_instr { visitor.visit_credit_transfer_transaction66_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_status_request_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_028_001_06::FIToFIPaymentStatusRequestV06) {
+	visitor.visit_group_header109(&node.grp_hdr);
+	if let Some(ref vec) = node.orgnl_grp_inf { for item in vec { visitor.visit_original_group_information27(item) } }
+	if let Some(ref vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction158(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_fi_to_fi_payment_status_request_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_028_001_06::FIToFIPaymentStatusRequestV06) {
+	visitor.visit_group_header109_mut(&mut node.grp_hdr);
+	if let Some(ref mut vec) = node.orgnl_grp_inf { for item in vec { visitor.visit_original_group_information27_mut(item) } }
+	if let Some(ref mut vec) = node.tx_inf { for item in vec { visitor.visit_payment_transaction158_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_multilateral_settlement_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pacs_029_001_02::MultilateralSettlementRequestV02) {
+	visitor.visit_group_header104(&node.grp_hdr);
+	for item in &node.sttlm_req { visitor.visit_multilateral_settlement_request3(item) }
+	if let Some(ref value) = node.splmtry_data { visitor.visit_supplementary_data1(value) }
+}
+
+pub fn walk_multilateral_settlement_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pacs_029_001_02::MultilateralSettlementRequestV02) {
+	visitor.visit_group_header104_mut(&mut node.grp_hdr);
+	for item in &mut node.sttlm_req { visitor.visit_multilateral_settlement_request3_mut(item) }
+	if let Some(ref mut value) = node.splmtry_data { visitor.visit_supplementary_data1_mut(value) }
+}
+
+impl crate::pacs_002_001_12::FIToFIPaymentStatusReportV12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_report_v12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_report_v12_mut(self) }
+}
+
+impl crate::pacs_002_001_14::FIToFIPaymentStatusReportV14 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_report_v14(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_report_v14_mut(self) }
+}
+
+impl crate::pacs_003_001_11::FIToFICustomerDirectDebitV11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_customer_direct_debit_v11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_customer_direct_debit_v11_mut(self) }
+}
+
+impl crate::pacs_004_001_13::PaymentReturnV13 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_payment_return_v13(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_payment_return_v13_mut(self) }
+}
+
+impl crate::pacs_007_001_13::FIToFIPaymentReversalV13 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_reversal_v13(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_reversal_v13_mut(self) }
+}
+
+impl crate::pacs_008_001_12::FIToFICustomerCreditTransferV12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_customer_credit_transfer_v12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_customer_credit_transfer_v12_mut(self) }
+}
+
+impl crate::pacs_009_001_11::FinancialInstitutionCreditTransferV11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_institution_credit_transfer_v11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_institution_credit_transfer_v11_mut(self) }
+}
+
+impl crate::pacs_010_001_06::FinancialInstitutionDirectDebitV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_financial_institution_direct_debit_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_financial_institution_direct_debit_v06_mut(self) }
+}
+
+impl crate::pacs_028_001_06::FIToFIPaymentStatusRequestV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_request_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fi_to_fi_payment_status_request_v06_mut(self) }
+}
+
+impl crate::pacs_029_001_02::MultilateralSettlementRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_multilateral_settlement_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_multilateral_settlement_request_v02_mut(self) }
+}
@@ -0,0 +1,151 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `Example` for the generated types of this crate, generated by
+// generate-example.py from their facets (see `open_payments_common::example`).
+
+use open_payments_common::example::Example;
+
+impl Example for crate::pain_001_001_12::CustomerCreditTransferInitiationV12 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			pmt_inf: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_002_001_14::CustomerPaymentStatusReportV14 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf_and_sts: Example::example(),
+			orgnl_pmt_inf_and_sts: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_007_001_12::CustomerPaymentReversalV12 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf: Example::example(),
+			orgnl_pmt_inf_and_rvsl: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_008_001_11::CustomerDirectDebitInitiationV11 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			pmt_inf: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_009_001_08::MandateInitiationRequestV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			mndt: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_010_001_08::MandateAmendmentRequestV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			undrlyg_amdmnt_dtls: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_011_001_08::MandateCancellationRequestV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			undrlyg_cxl_dtls: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_012_001_08::MandateAcceptanceReportV08 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			undrlyg_accptnc_dtls: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_013_001_11::CreditorPaymentActivationRequestV11 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			pmt_inf: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_014_001_11::CreditorPaymentActivationRequestStatusReportV11 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			orgnl_grp_inf_and_sts: Example::example(),
+			orgnl_pmt_inf_and_sts: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_017_001_04::MandateCopyRequestV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			undrlyg_cpy_req_dtls: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::pain_018_001_04::MandateSuspensionRequestV04 {
+	fn example() -> Self {
+		Self {
+			grp_hdr: Example::example(),
+			undrlyg_sspnsn_dtls: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
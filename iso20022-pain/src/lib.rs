@@ -35,3 +35,7 @@ pub mod pain_013_001_11;
 pub mod pain_014_001_11;
 pub mod pain_017_001_04;
 pub mod pain_018_001_04;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
@@ -0,0 +1,203 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `TagPaths` for the generated types of this crate, generated by
+// generate-tag-path.py from the tags of their fields (see
+// `open_payments_common::tag_path`).
+
+use open_payments_common::tag_path::*;
+
+impl TagPaths for crate::pain_001_001_12::CustomerCreditTransferInitiationV12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("PmtInf") { repeated(&self.pmt_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("PmtInf") { repeated_mut(&mut self.pmt_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_002_001_14::CustomerPaymentStatusReportV14 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { one(&self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("OrgnlPmtInfAndSts") { optional_repeated(&self.orgnl_pmt_inf_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { one_mut(&mut self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("OrgnlPmtInfAndSts") { optional_repeated_mut(&mut self.orgnl_pmt_inf_and_sts, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_007_001_12::CustomerPaymentReversalV12 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { one(&self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlPmtInfAndRvsl") { optional_repeated(&self.orgnl_pmt_inf_and_rvsl, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInf") { one_mut(&mut self.orgnl_grp_inf, step, out) }
+		if step.matches("OrgnlPmtInfAndRvsl") { optional_repeated_mut(&mut self.orgnl_pmt_inf_and_rvsl, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_008_001_11::CustomerDirectDebitInitiationV11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("PmtInf") { repeated(&self.pmt_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("PmtInf") { repeated_mut(&mut self.pmt_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_009_001_08::MandateInitiationRequestV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("Mndt") { repeated(&self.mndt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("Mndt") { repeated_mut(&mut self.mndt, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_010_001_08::MandateAmendmentRequestV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("UndrlygAmdmntDtls") { repeated(&self.undrlyg_amdmnt_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("UndrlygAmdmntDtls") { repeated_mut(&mut self.undrlyg_amdmnt_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_011_001_08::MandateCancellationRequestV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("UndrlygCxlDtls") { repeated(&self.undrlyg_cxl_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("UndrlygCxlDtls") { repeated_mut(&mut self.undrlyg_cxl_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_012_001_08::MandateAcceptanceReportV08 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("UndrlygAccptncDtls") { repeated(&self.undrlyg_accptnc_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("UndrlygAccptncDtls") { repeated_mut(&mut self.undrlyg_accptnc_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_013_001_11::CreditorPaymentActivationRequestV11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("PmtInf") { repeated(&self.pmt_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("PmtInf") { repeated_mut(&mut self.pmt_inf, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_014_001_11::CreditorPaymentActivationRequestStatusReportV11 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { one(&self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("OrgnlPmtInfAndSts") { optional_repeated(&self.orgnl_pmt_inf_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("OrgnlGrpInfAndSts") { one_mut(&mut self.orgnl_grp_inf_and_sts, step, out) }
+		if step.matches("OrgnlPmtInfAndSts") { optional_repeated_mut(&mut self.orgnl_pmt_inf_and_sts, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_017_001_04::MandateCopyRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("UndrlygCpyReqDtls") { repeated(&self.undrlyg_cpy_req_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("UndrlygCpyReqDtls") { repeated_mut(&mut self.undrlyg_cpy_req_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::pain_018_001_04::MandateSuspensionRequestV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("GrpHdr") { one(&self.grp_hdr, step, out) }
+		if step.matches("UndrlygSspnsnDtls") { repeated(&self.undrlyg_sspnsn_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("GrpHdr") { one_mut(&mut self.grp_hdr, step, out) }
+		if step.matches("UndrlygSspnsnDtls") { repeated_mut(&mut self.undrlyg_sspnsn_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
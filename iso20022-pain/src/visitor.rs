@@ -0,0 +1,271 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over the generated types of this crate, generated by
+// generate-visitor.py. `Visitor` has a `visit_` method per type that
+// walks into its fields by default; override the methods of the types of
+// interest and call the matching `walk_` function to keep going below
+// them. `VisitorMut` does the same with mutable references.
+
+use open_payments_common::visitor::{Visitor as BaseVisitor, VisitorMut as BaseVisitorMut};
+
+pub trait Visitor: BaseVisitor {
+	fn visit_customer_credit_transfer_initiation_v12(&mut self, node: &crate::pain_001_001_12::CustomerCreditTransferInitiationV12) { walk_customer_credit_transfer_initiation_v12(self, node) }
+	fn visit_customer_payment_status_report_v14(&mut self, node: &crate::pain_002_001_14::CustomerPaymentStatusReportV14) { walk_customer_payment_status_report_v14(self, node) }
+	fn visit_customer_payment_reversal_v12(&mut self, node: &crate::pain_007_001_12::CustomerPaymentReversalV12) { walk_customer_payment_reversal_v12(self, node) }
+	fn visit_customer_direct_debit_initiation_v11(&mut self, node: &crate::pain_008_001_11::CustomerDirectDebitInitiationV11) { walk_customer_direct_debit_initiation_v11(self, node) }
+	fn visit_mandate_initiation_request_v08(&mut self, node: &crate::pain_009_001_08::MandateInitiationRequestV08) { walk_mandate_initiation_request_v08(self, node) }
+	fn visit_mandate_amendment_request_v08(&mut self, node: &crate::pain_010_001_08::MandateAmendmentRequestV08) { walk_mandate_amendment_request_v08(self, node) }
+	fn visit_mandate_cancellation_request_v08(&mut self, node: &crate::pain_011_001_08::MandateCancellationRequestV08) { walk_mandate_cancellation_request_v08(self, node) }
+	fn visit_mandate_acceptance_report_v08(&mut self, node: &crate::pain_012_001_08::MandateAcceptanceReportV08) { walk_mandate_acceptance_report_v08(self, node) }
+	fn visit_creditor_payment_activation_request_v11(&mut self, node: &crate::pain_013_001_11::CreditorPaymentActivationRequestV11) { walk_creditor_payment_activation_request_v11(self, node) }
+	fn visit_creditor_payment_activation_request_status_report_v11(&mut self, node: &crate::pain_014_001_11::CreditorPaymentActivationRequestStatusReportV11) { walk_creditor_payment_activation_request_status_report_v11(self, node) }
+	fn visit_mandate_copy_request_v04(&mut self, node: &crate::pain_017_001_04::MandateCopyRequestV04) { walk_mandate_copy_request_v04(self, node) }
+	fn visit_mandate_suspension_request_v04(&mut self, node: &crate::pain_018_001_04::MandateSuspensionRequestV04) { walk_mandate_suspension_request_v04(self, node) }
+}
+
+pub trait VisitorMut: BaseVisitorMut {
+	fn visit_customer_credit_transfer_initiation_v12_mut(&mut self, node: &mut crate::pain_001_001_12::CustomerCreditTransferInitiationV12) { walk_customer_credit_transfer_initiation_v12_mut(self, node) }
+	fn visit_customer_payment_status_report_v14_mut(&mut self, node: &mut crate::pain_002_001_14::CustomerPaymentStatusReportV14) { walk_customer_payment_status_report_v14_mut(self, node) }
+	fn visit_customer_payment_reversal_v12_mut(&mut self, node: &mut crate::pain_007_001_12::CustomerPaymentReversalV12) { walk_customer_payment_reversal_v12_mut(self, node) }
+	fn visit_customer_direct_debit_initiation_v11_mut(&mut self, node: &mut crate::pain_008_001_11::CustomerDirectDebitInitiationV11) { walk_customer_direct_debit_initiation_v11_mut(self, node) }
+	fn visit_mandate_initiation_request_v08_mut(&mut self, node: &mut crate::pain_009_001_08::MandateInitiationRequestV08) { walk_mandate_initiation_request_v08_mut(self, node) }
+	fn visit_mandate_amendment_request_v08_mut(&mut self, node: &mut crate::pain_010_001_08::MandateAmendmentRequestV08) { walk_mandate_amendment_request_v08_mut(self, node) }
+	fn visit_mandate_cancellation_request_v08_mut(&mut self, node: &mut crate::pain_011_001_08::MandateCancellationRequestV08) { walk_mandate_cancellation_request_v08_mut(self, node) }
+	fn visit_mandate_acceptance_report_v08_mut(&mut self, node: &mut crate::pain_012_001_08::MandateAcceptanceReportV08) { walk_mandate_acceptance_report_v08_mut(self, node) }
+	fn visit_creditor_payment_activation_request_v11_mut(&mut self, node: &mut crate::pain_013_001_11::CreditorPaymentActivationRequestV11) { walk_creditor_payment_activation_request_v11_mut(self, node) }
+	fn visit_creditor_payment_activation_request_status_report_v11_mut(&mut self, node: &mut crate::pain_014_001_11::CreditorPaymentActivationRequestStatusReportV11) { walk_creditor_payment_activation_request_status_report_v11_mut(self, node) }
+	fn visit_mandate_copy_request_v04_mut(&mut self, node: &mut crate::pain_017_001_04::MandateCopyRequestV04) { walk_mandate_copy_request_v04_mut(self, node) }
+	fn visit_mandate_suspension_request_v04_mut(&mut self, node: &mut crate::pain_018_001_04::MandateSuspensionRequestV04) { walk_mandate_suspension_request_v04_mut(self, node) }
+}
+
+pub fn walk_customer_credit_transfer_initiation_v12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_001_001_12::CustomerCreditTransferInitiationV12) {
+	visitor.visit_group_header114(&node.grp_hdr);
+	for item in &node.pmt_inf { visitor.visit_payment_instruction44(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_customer_credit_transfer_initiation_v12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_001_001_12::CustomerCreditTransferInitiationV12) {
+	visitor.visit_group_header114_mut(&mut node.grp_hdr);
+	for item in &mut node.pmt_inf { visitor.visit_payment_instruction44_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_customer_payment_status_report_v14<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_002_001_14::CustomerPaymentStatusReportV14) {
+	visitor.visit_group_header128(&node.grp_hdr);
+	visitor.visit_original_group_header22(&node.orgnl_grp_inf_and_sts);
+	if let Some(ref vec) = node.orgnl_pmt_inf_and_sts { for item in vec { visitor.visit_original_payment_instruction51(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_customer_payment_status_report_v14_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_002_001_14::CustomerPaymentStatusReportV14) {
+	visitor.visit_group_header128_mut(&mut node.grp_hdr);
+	visitor.visit_original_group_header22_mut(&mut node.orgnl_grp_inf_and_sts);
+	if let Some(ref mut vec) = node.orgnl_pmt_inf_and_sts { for item in vec { visitor.visit_original_payment_instruction51_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_customer_payment_reversal_v12<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_007_001_12::CustomerPaymentReversalV12) {
+	visitor.visit_group_header124(&node.grp_hdr);
+	visitor.visit_original_group_header20(&node.orgnl_grp_inf);
+	if let Some(ref vec) = node.orgnl_pmt_inf_and_rvsl { for item in vec { visitor.visit_original_payment_instruction50(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_customer_payment_reversal_v12_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_007_001_12::CustomerPaymentReversalV12) {
+	visitor.visit_group_header124_mut(&mut node.grp_hdr);
+	visitor.visit_original_group_header20_mut(&mut node.orgnl_grp_inf);
+	if let Some(ref mut vec) = node.orgnl_pmt_inf_and_rvsl { for item in vec { visitor.visit_original_payment_instruction50_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_customer_direct_debit_initiation_v11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_008_001_11::CustomerDirectDebitInitiationV11) {
+	visitor.visit_group_header118(&node.grp_hdr);
+	for item in &node.pmt_inf { visitor.visit_payment_instruction45(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_customer_direct_debit_initiation_v11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_008_001_11::CustomerDirectDebitInitiationV11) {
+	visitor.visit_group_header118_mut(&mut node.grp_hdr);
+	for item in &mut node.pmt_inf { visitor.visit_payment_instruction45_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_mandate_initiation_request_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_009_001_08::MandateInitiationRequestV08) {
+	visitor.visit_group_header110(&node.grp_hdr);
+	for item in &node.mndt { visitor.visit_mandate23(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_mandate_initiation_request_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_009_001_08::MandateInitiationRequestV08) {
+	visitor.visit_group_header110_mut(&mut node.grp_hdr);
+	for item in &mut node.mndt { visitor.visit_mandate23_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_mandate_amendment_request_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_010_001_08::MandateAmendmentRequestV08) {
+	visitor.visit_group_header110(&node.grp_hdr);
+	for item in &node.undrlyg_amdmnt_dtls { visitor.visit_mandate_amendment8(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_mandate_amendment_request_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_010_001_08::MandateAmendmentRequestV08) {
+	visitor.visit_group_header110_mut(&mut node.grp_hdr);
+	for item in &mut node.undrlyg_amdmnt_dtls { visitor.visit_mandate_amendment8_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_mandate_cancellation_request_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_011_001_08::MandateCancellationRequestV08) {
+	visitor.visit_group_header110(&node.grp_hdr);
+	for item in &node.undrlyg_cxl_dtls { visitor.visit_mandate_cancellation8(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_mandate_cancellation_request_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_011_001_08::MandateCancellationRequestV08) {
+	visitor.visit_group_header110_mut(&mut node.grp_hdr);
+	for item in &mut node.undrlyg_cxl_dtls { visitor.visit_mandate_cancellation8_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_mandate_acceptance_report_v08<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_012_001_08::MandateAcceptanceReportV08) {
+	visitor.visit_group_header110(&node.grp_hdr);
+	for item in &node.undrlyg_accptnc_dtls { visitor.visit_mandate_acceptance8(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_mandate_acceptance_report_v08_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_012_001_08::MandateAcceptanceReportV08) {
+	visitor.visit_group_header110_mut(&mut node.grp_hdr);
+	for item in &mut node.undrlyg_accptnc_dtls { visitor.visit_mandate_acceptance8_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_creditor_payment_activation_request_v11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_013_001_11::CreditorPaymentActivationRequestV11) {
+	visitor.visit_group_header112(&node.grp_hdr);
+	for item in &node.pmt_inf { visitor.visit_payment_instruction46(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_creditor_payment_activation_request_v11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_013_001_11::CreditorPaymentActivationRequestV11) {
+	visitor.visit_group_header112_mut(&mut node.grp_hdr);
+	for item in &mut node.pmt_inf { visitor.visit_payment_instruction46_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_creditor_payment_activation_request_status_report_v11<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_014_001_11::CreditorPaymentActivationRequestStatusReportV11) {
+	visitor.visit_group_header111(&node.grp_hdr);
+	visitor.visit_original_group_information32(&node.orgnl_grp_inf_and_sts);
+	if let Some(ref vec) = node.orgnl_pmt_inf_and_sts { for item in vec { visitor.visit_original_payment_instruction47(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_creditor_payment_activation_request_status_report_v11_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_014_001_11::CreditorPaymentActivationRequestStatusReportV11) {
+	visitor.visit_group_header111_mut(&mut node.grp_hdr);
+	visitor.visit_original_group_information32_mut(&mut node.orgnl_grp_inf_and_sts);
+	if let Some(ref mut vec) = node.orgnl_pmt_inf_and_sts { for item in vec { visitor.visit_original_payment_instruction47_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_mandate_copy_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_017_001_04::MandateCopyRequestV04) {
+	visitor.visit_group_header110(&node.grp_hdr);
+	for item in &node.undrlyg_cpy_req_dtls { visitor.visit_mandate_copy4(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_mandate_copy_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_017_001_04::MandateCopyRequestV04) {
+	visitor.visit_group_header110_mut(&mut node.grp_hdr);
+	for item in &mut node.undrlyg_cpy_req_dtls { visitor.visit_mandate_copy4_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_mandate_suspension_request_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::pain_018_001_04::MandateSuspensionRequestV04) {
+	visitor.visit_group_header110(&node.grp_hdr);
+	for item in &node.undrlyg_sspnsn_dtls { visitor.visit_mandate_suspension4(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_mandate_suspension_request_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::pain_018_001_04::MandateSuspensionRequestV04) {
+	visitor.visit_group_header110_mut(&mut node.grp_hdr);
+	for item in &mut node.undrlyg_sspnsn_dtls { visitor.visit_mandate_suspension4_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+impl crate::pain_001_001_12::CustomerCreditTransferInitiationV12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_customer_credit_transfer_initiation_v12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_customer_credit_transfer_initiation_v12_mut(self) }
+}
+
+impl crate::pain_002_001_14::CustomerPaymentStatusReportV14 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_customer_payment_status_report_v14(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_customer_payment_status_report_v14_mut(self) }
+}
+
+impl crate::pain_007_001_12::CustomerPaymentReversalV12 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_customer_payment_reversal_v12(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_customer_payment_reversal_v12_mut(self) }
+}
+
+impl crate::pain_008_001_11::CustomerDirectDebitInitiationV11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_customer_direct_debit_initiation_v11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_customer_direct_debit_initiation_v11_mut(self) }
+}
+
+impl crate::pain_009_001_08::MandateInitiationRequestV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_initiation_request_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_initiation_request_v08_mut(self) }
+}
+
+impl crate::pain_010_001_08::MandateAmendmentRequestV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_amendment_request_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_amendment_request_v08_mut(self) }
+}
+
+impl crate::pain_011_001_08::MandateCancellationRequestV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_cancellation_request_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_cancellation_request_v08_mut(self) }
+}
+
+impl crate::pain_012_001_08::MandateAcceptanceReportV08 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_acceptance_report_v08(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_acceptance_report_v08_mut(self) }
+}
+
+impl crate::pain_013_001_11::CreditorPaymentActivationRequestV11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_creditor_payment_activation_request_v11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_creditor_payment_activation_request_v11_mut(self) }
+}
+
+impl crate::pain_014_001_11::CreditorPaymentActivationRequestStatusReportV11 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_creditor_payment_activation_request_status_report_v11(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_creditor_payment_activation_request_status_report_v11_mut(self) }
+}
+
+impl crate::pain_017_001_04::MandateCopyRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_copy_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_copy_request_v04_mut(self) }
+}
+
+impl crate::pain_018_001_04::MandateSuspensionRequestV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_mandate_suspension_request_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_mandate_suspension_request_v04_mut(self) }
+}
@@ -0,0 +1,568 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `Example` for the generated types of this crate, generated by
+// generate-example.py from their facets (see `open_payments_common::example`).
+
+use open_payments_common::example::Example;
+
+impl Example for crate::reda_001_001_04::PriceReportV04 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			pool_ref: None,
+			prvs_ref: None,
+			rltd_ref: None,
+			msg_pgntn: Example::example(),
+			pric_rpt_id: "PricRptId".to_string(),
+			fctn: Example::example(),
+			cxl_id: None,
+			pric_valtn_dtls: vec![Example::example()],
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::reda_002_001_04::PriceReportCancellationV04 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			pool_ref: None,
+			prvs_ref: None,
+			msg_pgntn: Example::example(),
+			pric_rpt_id: "PricRptId".to_string(),
+			cxl_id: "CxlId".to_string(),
+			cxl_rsn: None,
+			xpctd_pric_crrctn_dt: None,
+			cmplt_pric_cxl: false,
+			canc_pric_valtn_dtls: None,
+			xtnsn: None,
+		}
+	}
+}
+
+impl Example for crate::reda_004_001_07::FundReferenceDataReportV07 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			prvs_ref: None,
+			rltd_ref: None,
+			fnd_ref_data_rpt_id: None,
+			rpt: vec![Example::example()],
+		}
+	}
+}
+
+impl Example for crate::reda_005_001_03::InvestmentFundReportRequestV03 {
+	fn example() -> Self {
+		Self {
+			msg_id: Example::example(),
+			prvs_ref: None,
+			rltd_ref: None,
+			rpt_req: vec![Example::example()],
+		}
+	}
+}
+
+impl Example for crate::reda_006_001_01::SecurityCreationRequestV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			scty: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_007_001_01::SecurityMaintenanceRequestV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			upd_tp: Example::example(),
+			upd_rsn: None,
+			fin_instrm_id: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_008_001_01::SecurityCreationStatusAdviceV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			fin_instrm_id: None,
+			prcg_sts: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_009_001_01::SecurityActivityAdviceV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			pgntn: Example::example(),
+			scty_actvty: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_010_001_01::SecurityQueryV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			req_tp: None,
+			sch_crit: Example::example(),
+			sml_set_rtr_crit: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_012_001_01::SecurityReportV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			pgntn: Example::example(),
+			scty_rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_013_001_01::SecurityDeletionRequestV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			fin_instrm_id: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_014_001_02::PartyCreationRequestV02 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			pty: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_015_001_01::PartyQueryV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			sch_crit: Example::example(),
+			rtr_crit: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_016_001_01::PartyStatusAdviceV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			pty_sts: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_017_001_02::PartyReportV02 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_018_001_01::SecuritiesAccountCreationRequestV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			scties_acct: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_019_001_01::SecuritiesAccountQueryV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			sch_crit: Example::example(),
+			rtr_crit: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_020_001_01::SecuritiesAccountStatusAdviceV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			scties_acct_sts: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_021_001_01::SecuritiesAccountReportV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			pgntn: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_022_001_02::PartyModificationRequestV02 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			sys_pty_id: Example::example(),
+			mod_attr: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_023_001_01::SecuritiesAccountModificationRequestV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			acct_id: Example::example(),
+			mod_attr: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_029_001_01::SecurityMaintenanceStatusAdviceV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			fin_instrm_id: None,
+			prcg_sts: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_030_001_01::SecurityDeletionStatusAdviceV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			fin_instrm_id: None,
+			prcg_sts: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_031_001_01::PartyDeletionRequestV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			sys_pty_id: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_032_001_01::SecuritiesAccountDeletionRequestV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			acct_id: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_033_001_01::SecuritiesAuditTrailQueryV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			sch_crit: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_034_001_01::SecuritiesAuditTrailReportV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_035_001_01::SecuritiesAccountActivityAdviceV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			pgntn: Example::example(),
+			scties_acct_actvty: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_036_001_01::SecuritiesAccountAuditTrailQueryV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			sch_crit: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_037_001_01::SecuritiesAccountAuditTrailReportV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_041_001_02::PartyActivityAdviceV02 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			pty_actvty: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_042_001_01::PartyAuditTrailQueryV01 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			sch_crit: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_043_001_02::PartyAuditTrailReportV02 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: None,
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_056_001_01::StandingSettlementInstructionV01 {
+	fn example() -> Self {
+		Self {
+			msg_ref_id: "MsgRefId".to_string(),
+			fctv_dt_dtls: None,
+			acct_id: vec![Example::example()],
+			mkt_id: Example::example(),
+			sttlm_ccy: None,
+			sttlm_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_057_001_01::StandingSettlementInstructionDeletionV01 {
+	fn example() -> Self {
+		Self {
+			msg_ref_id: "MsgRefId".to_string(),
+			fctv_dt_dtls: None,
+			acct_id: vec![Example::example()],
+			mkt_id: Example::example(),
+			sttlm_dtls: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_058_001_01::StandingSettlementInstructionStatusAdviceV01 {
+	fn example() -> Self {
+		Self {
+			fctv_dt_dtls: None,
+			acct_id: vec![Example::example()],
+			mkt_id: Example::example(),
+			sttlm_dtls: Example::example(),
+			rltd_msg_ref: "RltdMsgRef".to_string(),
+			prcg_sts: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_059_001_01::StandingSettlementInstructionCancellationV01 {
+	fn example() -> Self {
+		Self {
+			msg_ref_id: "MsgRefId".to_string(),
+			fctv_dt_dtls: None,
+			acct_id: vec![Example::example()],
+			mkt_id: Example::example(),
+			sttlm_dtls: Example::example(),
+			prvs_msg_ref: "PrvsMsgRef".to_string(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_060_001_02::NettingCutOffReferenceDataUpdateRequestV02 {
+	fn example() -> Self {
+		Self {
+			req_data: Example::example(),
+			netg_cut_off_req: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_061_001_02::NettingCutOffReferenceDataReportV02 {
+	fn example() -> Self {
+		Self {
+			rpt_data: Example::example(),
+			ptcpt_netg_cut_off_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_064_001_02::CalendarQueryV02 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			sch_crit: None,
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_065_001_02::CalendarReportV02 {
+	fn example() -> Self {
+		Self {
+			msg_hdr: Example::example(),
+			rpt_or_err: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_066_001_02::RequestToPayCreditorEnrolmentRequestV02 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			cdtr_enrlmnt: vec![Example::example()],
+			actvtn_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_067_001_02::RequestToPayCreditorEnrolmentAmendmentRequestV02 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			amdmnt_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_068_001_02::RequestToPayCreditorEnrolmentCancellationRequestV02 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			cxl_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_069_001_02::RequestToPayCreditorEnrolmentStatusReportV02 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			orgnl_enrlmnt_and_sts: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_070_001_02::RequestToPayDebtorActivationRequestV02 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			dbtr_actvtn: vec![Example::example()],
+			elctrnc_invc_data: Example::example(),
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_071_001_02::RequestToPayDebtorActivationAmendmentRequestV02 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			amdmnt_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_072_001_02::RequestToPayDebtorActivationCancellationRequestV02 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			cxl_data: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
+
+impl Example for crate::reda_073_001_02::RequestToPayDebtorActivationStatusReportV02 {
+	fn example() -> Self {
+		Self {
+			hdr: Example::example(),
+			orgnl_actvtn_and_sts: vec![Example::example()],
+			splmtry_data: None,
+		}
+	}
+}
@@ -72,3 +72,7 @@ pub mod reda_070_001_02;
 pub mod reda_071_001_02;
 pub mod reda_072_001_02;
 pub mod reda_073_001_02;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
@@ -0,0 +1,815 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// `TagPaths` for the generated types of this crate, generated by
+// generate-tag-path.py from the tags of their fields (see
+// `open_payments_common::tag_path`).
+
+use open_payments_common::tag_path::*;
+
+impl TagPaths for crate::reda_001_001_04::PriceReportV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional(&self.pool_ref, step, out) }
+		if step.matches("PrvsRef") { optional_repeated(&self.prvs_ref, step, out) }
+		if step.matches("RltdRef") { optional(&self.rltd_ref, step, out) }
+		if step.matches("MsgPgntn") { one(&self.msg_pgntn, step, out) }
+		if step.matches("PricRptId") { one(&self.pric_rpt_id, step, out) }
+		if step.matches("Fctn") { one(&self.fctn, step, out) }
+		if step.matches("CxlId") { optional(&self.cxl_id, step, out) }
+		if step.matches("PricValtnDtls") { repeated(&self.pric_valtn_dtls, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional_mut(&mut self.pool_ref, step, create, out) }
+		if step.matches("PrvsRef") { optional_repeated_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("MsgPgntn") { one_mut(&mut self.msg_pgntn, step, out) }
+		if step.matches("PricRptId") { one_mut(&mut self.pric_rpt_id, step, out) }
+		if step.matches("Fctn") { one_mut(&mut self.fctn, step, out) }
+		if step.matches("CxlId") { optional_mut(&mut self.cxl_id, step, create, out) }
+		if step.matches("PricValtnDtls") { repeated_mut(&mut self.pric_valtn_dtls, step, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_002_001_04::PriceReportCancellationV04 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional(&self.pool_ref, step, out) }
+		if step.matches("PrvsRef") { optional(&self.prvs_ref, step, out) }
+		if step.matches("MsgPgntn") { one(&self.msg_pgntn, step, out) }
+		if step.matches("PricRptId") { one(&self.pric_rpt_id, step, out) }
+		if step.matches("CxlId") { one(&self.cxl_id, step, out) }
+		if step.matches("CxlRsn") { optional(&self.cxl_rsn, step, out) }
+		if step.matches("XpctdPricCrrctnDt") { optional(&self.xpctd_pric_crrctn_dt, step, out) }
+		if step.matches("CmpltPricCxl") { one(&self.cmplt_pric_cxl, step, out) }
+		if step.matches("CancPricValtnDtls") { optional_repeated(&self.canc_pric_valtn_dtls, step, out) }
+		if step.matches("Xtnsn") { optional_repeated(&self.xtnsn, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PoolRef") { optional_mut(&mut self.pool_ref, step, create, out) }
+		if step.matches("PrvsRef") { optional_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("MsgPgntn") { one_mut(&mut self.msg_pgntn, step, out) }
+		if step.matches("PricRptId") { one_mut(&mut self.pric_rpt_id, step, out) }
+		if step.matches("CxlId") { one_mut(&mut self.cxl_id, step, out) }
+		if step.matches("CxlRsn") { optional_mut(&mut self.cxl_rsn, step, create, out) }
+		if step.matches("XpctdPricCrrctnDt") { optional_mut(&mut self.xpctd_pric_crrctn_dt, step, create, out) }
+		if step.matches("CmpltPricCxl") { one_mut(&mut self.cmplt_pric_cxl, step, out) }
+		if step.matches("CancPricValtnDtls") { optional_repeated_mut(&mut self.canc_pric_valtn_dtls, step, create, out) }
+		if step.matches("Xtnsn") { optional_repeated_mut(&mut self.xtnsn, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_004_001_07::FundReferenceDataReportV07 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PrvsRef") { optional_repeated(&self.prvs_ref, step, out) }
+		if step.matches("RltdRef") { optional(&self.rltd_ref, step, out) }
+		if step.matches("FndRefDataRptId") { optional(&self.fnd_ref_data_rpt_id, step, out) }
+		if step.matches("Rpt") { repeated(&self.rpt, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PrvsRef") { optional_repeated_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("FndRefDataRptId") { optional_mut(&mut self.fnd_ref_data_rpt_id, step, create, out) }
+		if step.matches("Rpt") { repeated_mut(&mut self.rpt, step, out) }
+	}
+}
+
+impl TagPaths for crate::reda_005_001_03::InvestmentFundReportRequestV03 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgId") { one(&self.msg_id, step, out) }
+		if step.matches("PrvsRef") { optional(&self.prvs_ref, step, out) }
+		if step.matches("RltdRef") { optional(&self.rltd_ref, step, out) }
+		if step.matches("RptReq") { repeated(&self.rpt_req, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgId") { one_mut(&mut self.msg_id, step, out) }
+		if step.matches("PrvsRef") { optional_mut(&mut self.prvs_ref, step, create, out) }
+		if step.matches("RltdRef") { optional_mut(&mut self.rltd_ref, step, create, out) }
+		if step.matches("RptReq") { repeated_mut(&mut self.rpt_req, step, out) }
+	}
+}
+
+impl TagPaths for crate::reda_006_001_01::SecurityCreationRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("Scty") { one(&self.scty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("Scty") { one_mut(&mut self.scty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_007_001_01::SecurityMaintenanceRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("UpdTp") { one(&self.upd_tp, step, out) }
+		if step.matches("UpdRsn") { optional(&self.upd_rsn, step, out) }
+		if step.matches("FinInstrmId") { one(&self.fin_instrm_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("UpdTp") { one_mut(&mut self.upd_tp, step, out) }
+		if step.matches("UpdRsn") { optional_mut(&mut self.upd_rsn, step, create, out) }
+		if step.matches("FinInstrmId") { one_mut(&mut self.fin_instrm_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_008_001_01::SecurityCreationStatusAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("FinInstrmId") { optional(&self.fin_instrm_id, step, out) }
+		if step.matches("PrcgSts") { one(&self.prcg_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("FinInstrmId") { optional_mut(&mut self.fin_instrm_id, step, create, out) }
+		if step.matches("PrcgSts") { one_mut(&mut self.prcg_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_009_001_01::SecurityActivityAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("Pgntn") { one(&self.pgntn, step, out) }
+		if step.matches("SctyActvty") { one(&self.scty_actvty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("Pgntn") { one_mut(&mut self.pgntn, step, out) }
+		if step.matches("SctyActvty") { one_mut(&mut self.scty_actvty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_010_001_01::SecurityQueryV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("ReqTp") { optional(&self.req_tp, step, out) }
+		if step.matches("SchCrit") { one(&self.sch_crit, step, out) }
+		if step.matches("SmlSetRtrCrit") { optional(&self.sml_set_rtr_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("ReqTp") { optional_mut(&mut self.req_tp, step, create, out) }
+		if step.matches("SchCrit") { one_mut(&mut self.sch_crit, step, out) }
+		if step.matches("SmlSetRtrCrit") { optional_mut(&mut self.sml_set_rtr_crit, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_012_001_01::SecurityReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("Pgntn") { one(&self.pgntn, step, out) }
+		if step.matches("SctyRptOrErr") { one(&self.scty_rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("Pgntn") { one_mut(&mut self.pgntn, step, out) }
+		if step.matches("SctyRptOrErr") { one_mut(&mut self.scty_rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_013_001_01::SecurityDeletionRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("FinInstrmId") { one(&self.fin_instrm_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("FinInstrmId") { one_mut(&mut self.fin_instrm_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_014_001_02::PartyCreationRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("Pty") { one(&self.pty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("Pty") { one_mut(&mut self.pty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_015_001_01::PartyQueryV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("SchCrit") { one(&self.sch_crit, step, out) }
+		if step.matches("RtrCrit") { optional(&self.rtr_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("SchCrit") { one_mut(&mut self.sch_crit, step, out) }
+		if step.matches("RtrCrit") { optional_mut(&mut self.rtr_crit, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_016_001_01::PartyStatusAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("PtySts") { one(&self.pty_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("PtySts") { one_mut(&mut self.pty_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_017_001_02::PartyReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_018_001_01::SecuritiesAccountCreationRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("SctiesAcct") { one(&self.scties_acct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("SctiesAcct") { one_mut(&mut self.scties_acct, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_019_001_01::SecuritiesAccountQueryV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("SchCrit") { one(&self.sch_crit, step, out) }
+		if step.matches("RtrCrit") { optional(&self.rtr_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("SchCrit") { one_mut(&mut self.sch_crit, step, out) }
+		if step.matches("RtrCrit") { optional_mut(&mut self.rtr_crit, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_020_001_01::SecuritiesAccountStatusAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("SctiesAcctSts") { one(&self.scties_acct_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("SctiesAcctSts") { one_mut(&mut self.scties_acct_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_021_001_01::SecuritiesAccountReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("Pgntn") { one(&self.pgntn, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("Pgntn") { one_mut(&mut self.pgntn, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_022_001_02::PartyModificationRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("SysPtyId") { one(&self.sys_pty_id, step, out) }
+		if step.matches("Mod") { repeated(&self.mod_attr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("SysPtyId") { one_mut(&mut self.sys_pty_id, step, out) }
+		if step.matches("Mod") { repeated_mut(&mut self.mod_attr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_023_001_01::SecuritiesAccountModificationRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("AcctId") { one(&self.acct_id, step, out) }
+		if step.matches("Mod") { repeated(&self.mod_attr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("AcctId") { one_mut(&mut self.acct_id, step, out) }
+		if step.matches("Mod") { repeated_mut(&mut self.mod_attr, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_029_001_01::SecurityMaintenanceStatusAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("FinInstrmId") { optional(&self.fin_instrm_id, step, out) }
+		if step.matches("PrcgSts") { one(&self.prcg_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("FinInstrmId") { optional_mut(&mut self.fin_instrm_id, step, create, out) }
+		if step.matches("PrcgSts") { one_mut(&mut self.prcg_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_030_001_01::SecurityDeletionStatusAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("FinInstrmId") { optional(&self.fin_instrm_id, step, out) }
+		if step.matches("PrcgSts") { one(&self.prcg_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("FinInstrmId") { optional_mut(&mut self.fin_instrm_id, step, create, out) }
+		if step.matches("PrcgSts") { one_mut(&mut self.prcg_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_031_001_01::PartyDeletionRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("SysPtyId") { one(&self.sys_pty_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("SysPtyId") { one_mut(&mut self.sys_pty_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_032_001_01::SecuritiesAccountDeletionRequestV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("AcctId") { one(&self.acct_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("AcctId") { one_mut(&mut self.acct_id, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_033_001_01::SecuritiesAuditTrailQueryV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("SchCrit") { one(&self.sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("SchCrit") { one_mut(&mut self.sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_034_001_01::SecuritiesAuditTrailReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_035_001_01::SecuritiesAccountActivityAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("Pgntn") { one(&self.pgntn, step, out) }
+		if step.matches("SctiesAcctActvty") { one(&self.scties_acct_actvty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("Pgntn") { one_mut(&mut self.pgntn, step, out) }
+		if step.matches("SctiesAcctActvty") { one_mut(&mut self.scties_acct_actvty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_036_001_01::SecuritiesAccountAuditTrailQueryV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("SchCrit") { one(&self.sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("SchCrit") { one_mut(&mut self.sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_037_001_01::SecuritiesAccountAuditTrailReportV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_041_001_02::PartyActivityAdviceV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("PtyActvty") { one(&self.pty_actvty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("PtyActvty") { one_mut(&mut self.pty_actvty, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_042_001_01::PartyAuditTrailQueryV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("SchCrit") { one(&self.sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("SchCrit") { one_mut(&mut self.sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_043_001_02::PartyAuditTrailReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { optional_mut(&mut self.msg_hdr, step, create, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_056_001_01::StandingSettlementInstructionV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgRefId") { one(&self.msg_ref_id, step, out) }
+		if step.matches("FctvDtDtls") { optional(&self.fctv_dt_dtls, step, out) }
+		if step.matches("AcctId") { repeated(&self.acct_id, step, out) }
+		if step.matches("MktId") { one(&self.mkt_id, step, out) }
+		if step.matches("SttlmCcy") { optional(&self.sttlm_ccy, step, out) }
+		if step.matches("SttlmDtls") { one(&self.sttlm_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgRefId") { one_mut(&mut self.msg_ref_id, step, out) }
+		if step.matches("FctvDtDtls") { optional_mut(&mut self.fctv_dt_dtls, step, create, out) }
+		if step.matches("AcctId") { repeated_mut(&mut self.acct_id, step, out) }
+		if step.matches("MktId") { one_mut(&mut self.mkt_id, step, out) }
+		if step.matches("SttlmCcy") { optional_mut(&mut self.sttlm_ccy, step, create, out) }
+		if step.matches("SttlmDtls") { one_mut(&mut self.sttlm_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_057_001_01::StandingSettlementInstructionDeletionV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgRefId") { one(&self.msg_ref_id, step, out) }
+		if step.matches("FctvDtDtls") { optional(&self.fctv_dt_dtls, step, out) }
+		if step.matches("AcctId") { repeated(&self.acct_id, step, out) }
+		if step.matches("MktId") { one(&self.mkt_id, step, out) }
+		if step.matches("SttlmDtls") { one(&self.sttlm_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgRefId") { one_mut(&mut self.msg_ref_id, step, out) }
+		if step.matches("FctvDtDtls") { optional_mut(&mut self.fctv_dt_dtls, step, create, out) }
+		if step.matches("AcctId") { repeated_mut(&mut self.acct_id, step, out) }
+		if step.matches("MktId") { one_mut(&mut self.mkt_id, step, out) }
+		if step.matches("SttlmDtls") { one_mut(&mut self.sttlm_dtls, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_058_001_01::StandingSettlementInstructionStatusAdviceV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("FctvDtDtls") { optional(&self.fctv_dt_dtls, step, out) }
+		if step.matches("AcctId") { repeated(&self.acct_id, step, out) }
+		if step.matches("MktId") { one(&self.mkt_id, step, out) }
+		if step.matches("SttlmDtls") { one(&self.sttlm_dtls, step, out) }
+		if step.matches("RltdMsgRef") { one(&self.rltd_msg_ref, step, out) }
+		if step.matches("PrcgSts") { one(&self.prcg_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("FctvDtDtls") { optional_mut(&mut self.fctv_dt_dtls, step, create, out) }
+		if step.matches("AcctId") { repeated_mut(&mut self.acct_id, step, out) }
+		if step.matches("MktId") { one_mut(&mut self.mkt_id, step, out) }
+		if step.matches("SttlmDtls") { one_mut(&mut self.sttlm_dtls, step, out) }
+		if step.matches("RltdMsgRef") { one_mut(&mut self.rltd_msg_ref, step, out) }
+		if step.matches("PrcgSts") { one_mut(&mut self.prcg_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_059_001_01::StandingSettlementInstructionCancellationV01 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgRefId") { one(&self.msg_ref_id, step, out) }
+		if step.matches("FctvDtDtls") { optional(&self.fctv_dt_dtls, step, out) }
+		if step.matches("AcctId") { repeated(&self.acct_id, step, out) }
+		if step.matches("MktId") { one(&self.mkt_id, step, out) }
+		if step.matches("SttlmDtls") { one(&self.sttlm_dtls, step, out) }
+		if step.matches("PrvsMsgRef") { one(&self.prvs_msg_ref, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgRefId") { one_mut(&mut self.msg_ref_id, step, out) }
+		if step.matches("FctvDtDtls") { optional_mut(&mut self.fctv_dt_dtls, step, create, out) }
+		if step.matches("AcctId") { repeated_mut(&mut self.acct_id, step, out) }
+		if step.matches("MktId") { one_mut(&mut self.mkt_id, step, out) }
+		if step.matches("SttlmDtls") { one_mut(&mut self.sttlm_dtls, step, out) }
+		if step.matches("PrvsMsgRef") { one_mut(&mut self.prvs_msg_ref, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_060_001_02::NettingCutOffReferenceDataUpdateRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("ReqData") { one(&self.req_data, step, out) }
+		if step.matches("NetgCutOffReq") { repeated(&self.netg_cut_off_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("ReqData") { one_mut(&mut self.req_data, step, out) }
+		if step.matches("NetgCutOffReq") { repeated_mut(&mut self.netg_cut_off_req, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_061_001_02::NettingCutOffReferenceDataReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("RptData") { one(&self.rpt_data, step, out) }
+		if step.matches("PtcptNetgCutOffData") { repeated(&self.ptcpt_netg_cut_off_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("RptData") { one_mut(&mut self.rpt_data, step, out) }
+		if step.matches("PtcptNetgCutOffData") { repeated_mut(&mut self.ptcpt_netg_cut_off_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_064_001_02::CalendarQueryV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("SchCrit") { optional_repeated(&self.sch_crit, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("SchCrit") { optional_repeated_mut(&mut self.sch_crit, step, create, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_065_001_02::CalendarReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("MsgHdr") { one(&self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one(&self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("MsgHdr") { one_mut(&mut self.msg_hdr, step, out) }
+		if step.matches("RptOrErr") { one_mut(&mut self.rpt_or_err, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_066_001_02::RequestToPayCreditorEnrolmentRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("CdtrEnrlmnt") { repeated(&self.cdtr_enrlmnt, step, out) }
+		if step.matches("ActvtnData") { one(&self.actvtn_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("CdtrEnrlmnt") { repeated_mut(&mut self.cdtr_enrlmnt, step, out) }
+		if step.matches("ActvtnData") { one_mut(&mut self.actvtn_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_067_001_02::RequestToPayCreditorEnrolmentAmendmentRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("AmdmntData") { repeated(&self.amdmnt_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("AmdmntData") { repeated_mut(&mut self.amdmnt_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_068_001_02::RequestToPayCreditorEnrolmentCancellationRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("CxlData") { repeated(&self.cxl_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("CxlData") { repeated_mut(&mut self.cxl_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_069_001_02::RequestToPayCreditorEnrolmentStatusReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("OrgnlEnrlmntAndSts") { repeated(&self.orgnl_enrlmnt_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("OrgnlEnrlmntAndSts") { repeated_mut(&mut self.orgnl_enrlmnt_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_070_001_02::RequestToPayDebtorActivationRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("DbtrActvtn") { repeated(&self.dbtr_actvtn, step, out) }
+		if step.matches("ElctrncInvcData") { one(&self.elctrnc_invc_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("DbtrActvtn") { repeated_mut(&mut self.dbtr_actvtn, step, out) }
+		if step.matches("ElctrncInvcData") { one_mut(&mut self.elctrnc_invc_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_071_001_02::RequestToPayDebtorActivationAmendmentRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("AmdmntData") { repeated(&self.amdmnt_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("AmdmntData") { repeated_mut(&mut self.amdmnt_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_072_001_02::RequestToPayDebtorActivationCancellationRequestV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("CxlData") { repeated(&self.cxl_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("CxlData") { repeated_mut(&mut self.cxl_data, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
+
+impl TagPaths for crate::reda_073_001_02::RequestToPayDebtorActivationStatusReportV02 {
+	fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+		if step.matches("Hdr") { one(&self.hdr, step, out) }
+		if step.matches("OrgnlActvtnAndSts") { repeated(&self.orgnl_actvtn_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated(&self.splmtry_data, step, out) }
+	}
+
+	fn children_mut<'a>(&'a mut self, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+		if step.matches("Hdr") { one_mut(&mut self.hdr, step, out) }
+		if step.matches("OrgnlActvtnAndSts") { repeated_mut(&mut self.orgnl_actvtn_and_sts, step, out) }
+		if step.matches("SplmtryData") { optional_repeated_mut(&mut self.splmtry_data, step, create, out) }
+	}
+}
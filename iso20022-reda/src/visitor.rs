@@ -0,0 +1,1042 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over the generated types of this crate, generated by
+// generate-visitor.py. `Visitor` has a `visit_` method per type that
+// walks into its fields by default; override the methods of the types of
+// interest and call the matching `walk_` function to keep going below
+// them. `VisitorMut` does the same with mutable references.
+
+use open_payments_common::visitor::{Visitor as BaseVisitor, VisitorMut as BaseVisitorMut};
+
+pub trait Visitor: BaseVisitor {
+	fn visit_price_report_v04(&mut self, node: &crate::reda_001_001_04::PriceReportV04) { walk_price_report_v04(self, node) }
+	fn visit_price_report_cancellation_v04(&mut self, node: &crate::reda_002_001_04::PriceReportCancellationV04) { walk_price_report_cancellation_v04(self, node) }
+	fn visit_fund_reference_data_report_v07(&mut self, node: &crate::reda_004_001_07::FundReferenceDataReportV07) { walk_fund_reference_data_report_v07(self, node) }
+	fn visit_investment_fund_report_request_v03(&mut self, node: &crate::reda_005_001_03::InvestmentFundReportRequestV03) { walk_investment_fund_report_request_v03(self, node) }
+	fn visit_security_creation_request_v01(&mut self, node: &crate::reda_006_001_01::SecurityCreationRequestV01) { walk_security_creation_request_v01(self, node) }
+	fn visit_security_maintenance_request_v01(&mut self, node: &crate::reda_007_001_01::SecurityMaintenanceRequestV01) { walk_security_maintenance_request_v01(self, node) }
+	fn visit_security_creation_status_advice_v01(&mut self, node: &crate::reda_008_001_01::SecurityCreationStatusAdviceV01) { walk_security_creation_status_advice_v01(self, node) }
+	fn visit_security_activity_advice_v01(&mut self, node: &crate::reda_009_001_01::SecurityActivityAdviceV01) { walk_security_activity_advice_v01(self, node) }
+	fn visit_security_query_v01(&mut self, node: &crate::reda_010_001_01::SecurityQueryV01) { walk_security_query_v01(self, node) }
+	fn visit_security_report_v01(&mut self, node: &crate::reda_012_001_01::SecurityReportV01) { walk_security_report_v01(self, node) }
+	fn visit_security_deletion_request_v01(&mut self, node: &crate::reda_013_001_01::SecurityDeletionRequestV01) { walk_security_deletion_request_v01(self, node) }
+	fn visit_party_creation_request_v02(&mut self, node: &crate::reda_014_001_02::PartyCreationRequestV02) { walk_party_creation_request_v02(self, node) }
+	fn visit_party_query_v01(&mut self, node: &crate::reda_015_001_01::PartyQueryV01) { walk_party_query_v01(self, node) }
+	fn visit_party_status_advice_v01(&mut self, node: &crate::reda_016_001_01::PartyStatusAdviceV01) { walk_party_status_advice_v01(self, node) }
+	fn visit_party_report_v02(&mut self, node: &crate::reda_017_001_02::PartyReportV02) { walk_party_report_v02(self, node) }
+	fn visit_securities_account_creation_request_v01(&mut self, node: &crate::reda_018_001_01::SecuritiesAccountCreationRequestV01) { walk_securities_account_creation_request_v01(self, node) }
+	fn visit_securities_account_query_v01(&mut self, node: &crate::reda_019_001_01::SecuritiesAccountQueryV01) { walk_securities_account_query_v01(self, node) }
+	fn visit_securities_account_status_advice_v01(&mut self, node: &crate::reda_020_001_01::SecuritiesAccountStatusAdviceV01) { walk_securities_account_status_advice_v01(self, node) }
+	fn visit_securities_account_report_v01(&mut self, node: &crate::reda_021_001_01::SecuritiesAccountReportV01) { walk_securities_account_report_v01(self, node) }
+	fn visit_party_modification_request_v02(&mut self, node: &crate::reda_022_001_02::PartyModificationRequestV02) { walk_party_modification_request_v02(self, node) }
+	fn visit_securities_account_modification_request_v01(&mut self, node: &crate::reda_023_001_01::SecuritiesAccountModificationRequestV01) { walk_securities_account_modification_request_v01(self, node) }
+	fn visit_security_maintenance_status_advice_v01(&mut self, node: &crate::reda_029_001_01::SecurityMaintenanceStatusAdviceV01) { walk_security_maintenance_status_advice_v01(self, node) }
+	fn visit_security_deletion_status_advice_v01(&mut self, node: &crate::reda_030_001_01::SecurityDeletionStatusAdviceV01) { walk_security_deletion_status_advice_v01(self, node) }
+	fn visit_party_deletion_request_v01(&mut self, node: &crate::reda_031_001_01::PartyDeletionRequestV01) { walk_party_deletion_request_v01(self, node) }
+	fn visit_securities_account_deletion_request_v01(&mut self, node: &crate::reda_032_001_01::SecuritiesAccountDeletionRequestV01) { walk_securities_account_deletion_request_v01(self, node) }
+	fn visit_securities_audit_trail_query_v01(&mut self, node: &crate::reda_033_001_01::SecuritiesAuditTrailQueryV01) { walk_securities_audit_trail_query_v01(self, node) }
+	fn visit_securities_audit_trail_report_v01(&mut self, node: &crate::reda_034_001_01::SecuritiesAuditTrailReportV01) { walk_securities_audit_trail_report_v01(self, node) }
+	fn visit_securities_account_activity_advice_v01(&mut self, node: &crate::reda_035_001_01::SecuritiesAccountActivityAdviceV01) { walk_securities_account_activity_advice_v01(self, node) }
+	fn visit_securities_account_audit_trail_query_v01(&mut self, node: &crate::reda_036_001_01::SecuritiesAccountAuditTrailQueryV01) { walk_securities_account_audit_trail_query_v01(self, node) }
+	fn visit_securities_account_audit_trail_report_v01(&mut self, node: &crate::reda_037_001_01::SecuritiesAccountAuditTrailReportV01) { walk_securities_account_audit_trail_report_v01(self, node) }
+	fn visit_party_activity_advice_v02(&mut self, node: &crate::reda_041_001_02::PartyActivityAdviceV02) { walk_party_activity_advice_v02(self, node) }
+	fn visit_party_audit_trail_query_v01(&mut self, node: &crate::reda_042_001_01::PartyAuditTrailQueryV01) { walk_party_audit_trail_query_v01(self, node) }
+	fn visit_party_audit_trail_report_v02(&mut self, node: &crate::reda_043_001_02::PartyAuditTrailReportV02) { walk_party_audit_trail_report_v02(self, node) }
+	fn visit_standing_settlement_instruction_v01(&mut self, node: &crate::reda_056_001_01::StandingSettlementInstructionV01) { walk_standing_settlement_instruction_v01(self, node) }
+	fn visit_standing_settlement_instruction_deletion_v01(&mut self, node: &crate::reda_057_001_01::StandingSettlementInstructionDeletionV01) { walk_standing_settlement_instruction_deletion_v01(self, node) }
+	fn visit_standing_settlement_instruction_status_advice_v01(&mut self, node: &crate::reda_058_001_01::StandingSettlementInstructionStatusAdviceV01) { walk_standing_settlement_instruction_status_advice_v01(self, node) }
+	fn visit_standing_settlement_instruction_cancellation_v01(&mut self, node: &crate::reda_059_001_01::StandingSettlementInstructionCancellationV01) { walk_standing_settlement_instruction_cancellation_v01(self, node) }
+	fn visit_netting_cut_off_reference_data_update_request_v02(&mut self, node: &crate::reda_060_001_02::NettingCutOffReferenceDataUpdateRequestV02) { walk_netting_cut_off_reference_data_update_request_v02(self, node) }
+	fn visit_netting_cut_off_reference_data_report_v02(&mut self, node: &crate::reda_061_001_02::NettingCutOffReferenceDataReportV02) { walk_netting_cut_off_reference_data_report_v02(self, node) }
+	fn visit_calendar_query_v02(&mut self, node: &crate::reda_064_001_02::CalendarQueryV02) { walk_calendar_query_v02(self, node) }
+	fn visit_calendar_report_v02(&mut self, node: &crate::reda_065_001_02::CalendarReportV02) { walk_calendar_report_v02(self, node) }
+	fn visit_request_to_pay_creditor_enrolment_request_v02(&mut self, node: &crate::reda_066_001_02::RequestToPayCreditorEnrolmentRequestV02) { walk_request_to_pay_creditor_enrolment_request_v02(self, node) }
+	fn visit_request_to_pay_creditor_enrolment_amendment_request_v02(&mut self, node: &crate::reda_067_001_02::RequestToPayCreditorEnrolmentAmendmentRequestV02) { walk_request_to_pay_creditor_enrolment_amendment_request_v02(self, node) }
+	fn visit_request_to_pay_creditor_enrolment_cancellation_request_v02(&mut self, node: &crate::reda_068_001_02::RequestToPayCreditorEnrolmentCancellationRequestV02) { walk_request_to_pay_creditor_enrolment_cancellation_request_v02(self, node) }
+	fn visit_request_to_pay_creditor_enrolment_status_report_v02(&mut self, node: &crate::reda_069_001_02::RequestToPayCreditorEnrolmentStatusReportV02) { walk_request_to_pay_creditor_enrolment_status_report_v02(self, node) }
+	fn visit_request_to_pay_debtor_activation_request_v02(&mut self, node: &crate::reda_070_001_02::RequestToPayDebtorActivationRequestV02) { walk_request_to_pay_debtor_activation_request_v02(self, node) }
+	fn visit_request_to_pay_debtor_activation_amendment_request_v02(&mut self, node: &crate::reda_071_001_02::RequestToPayDebtorActivationAmendmentRequestV02) { walk_request_to_pay_debtor_activation_amendment_request_v02(self, node) }
+	fn visit_request_to_pay_debtor_activation_cancellation_request_v02(&mut self, node: &crate::reda_072_001_02::RequestToPayDebtorActivationCancellationRequestV02) { walk_request_to_pay_debtor_activation_cancellation_request_v02(self, node) }
+	fn visit_request_to_pay_debtor_activation_status_report_v02(&mut self, node: &crate::reda_073_001_02::RequestToPayDebtorActivationStatusReportV02) { walk_request_to_pay_debtor_activation_status_report_v02(self, node) }
+}
+
+pub trait VisitorMut: BaseVisitorMut {
+	fn visit_price_report_v04_mut(&mut self, node: &mut crate::reda_001_001_04::PriceReportV04) { walk_price_report_v04_mut(self, node) }
+	fn visit_price_report_cancellation_v04_mut(&mut self, node: &mut crate::reda_002_001_04::PriceReportCancellationV04) { walk_price_report_cancellation_v04_mut(self, node) }
+	fn visit_fund_reference_data_report_v07_mut(&mut self, node: &mut crate::reda_004_001_07::FundReferenceDataReportV07) { walk_fund_reference_data_report_v07_mut(self, node) }
+	fn visit_investment_fund_report_request_v03_mut(&mut self, node: &mut crate::reda_005_001_03::InvestmentFundReportRequestV03) { walk_investment_fund_report_request_v03_mut(self, node) }
+	fn visit_security_creation_request_v01_mut(&mut self, node: &mut crate::reda_006_001_01::SecurityCreationRequestV01) { walk_security_creation_request_v01_mut(self, node) }
+	fn visit_security_maintenance_request_v01_mut(&mut self, node: &mut crate::reda_007_001_01::SecurityMaintenanceRequestV01) { walk_security_maintenance_request_v01_mut(self, node) }
+	fn visit_security_creation_status_advice_v01_mut(&mut self, node: &mut crate::reda_008_001_01::SecurityCreationStatusAdviceV01) { walk_security_creation_status_advice_v01_mut(self, node) }
+	fn visit_security_activity_advice_v01_mut(&mut self, node: &mut crate::reda_009_001_01::SecurityActivityAdviceV01) { walk_security_activity_advice_v01_mut(self, node) }
+	fn visit_security_query_v01_mut(&mut self, node: &mut crate::reda_010_001_01::SecurityQueryV01) { walk_security_query_v01_mut(self, node) }
+	fn visit_security_report_v01_mut(&mut self, node: &mut crate::reda_012_001_01::SecurityReportV01) { walk_security_report_v01_mut(self, node) }
+	fn visit_security_deletion_request_v01_mut(&mut self, node: &mut crate::reda_013_001_01::SecurityDeletionRequestV01) { walk_security_deletion_request_v01_mut(self, node) }
+	fn visit_party_creation_request_v02_mut(&mut self, node: &mut crate::reda_014_001_02::PartyCreationRequestV02) { walk_party_creation_request_v02_mut(self, node) }
+	fn visit_party_query_v01_mut(&mut self, node: &mut crate::reda_015_001_01::PartyQueryV01) { walk_party_query_v01_mut(self, node) }
+	fn visit_party_status_advice_v01_mut(&mut self, node: &mut crate::reda_016_001_01::PartyStatusAdviceV01) { walk_party_status_advice_v01_mut(self, node) }
+	fn visit_party_report_v02_mut(&mut self, node: &mut crate::reda_017_001_02::PartyReportV02) { walk_party_report_v02_mut(self, node) }
+	fn visit_securities_account_creation_request_v01_mut(&mut self, node: &mut crate::reda_018_001_01::SecuritiesAccountCreationRequestV01) { walk_securities_account_creation_request_v01_mut(self, node) }
+	fn visit_securities_account_query_v01_mut(&mut self, node: &mut crate::reda_019_001_01::SecuritiesAccountQueryV01) { walk_securities_account_query_v01_mut(self, node) }
+	fn visit_securities_account_status_advice_v01_mut(&mut self, node: &mut crate::reda_020_001_01::SecuritiesAccountStatusAdviceV01) { walk_securities_account_status_advice_v01_mut(self, node) }
+	fn visit_securities_account_report_v01_mut(&mut self, node: &mut crate::reda_021_001_01::SecuritiesAccountReportV01) { walk_securities_account_report_v01_mut(self, node) }
+	fn visit_party_modification_request_v02_mut(&mut self, node: &mut crate::reda_022_001_02::PartyModificationRequestV02) { walk_party_modification_request_v02_mut(self, node) }
+	fn visit_securities_account_modification_request_v01_mut(&mut self, node: &mut crate::reda_023_001_01::SecuritiesAccountModificationRequestV01) { walk_securities_account_modification_request_v01_mut(self, node) }
+	fn visit_security_maintenance_status_advice_v01_mut(&mut self, node: &mut crate::reda_029_001_01::SecurityMaintenanceStatusAdviceV01) { walk_security_maintenance_status_advice_v01_mut(self, node) }
+	fn visit_security_deletion_status_advice_v01_mut(&mut self, node: &mut crate::reda_030_001_01::SecurityDeletionStatusAdviceV01) { walk_security_deletion_status_advice_v01_mut(self, node) }
+	fn visit_party_deletion_request_v01_mut(&mut self, node: &mut crate::reda_031_001_01::PartyDeletionRequestV01) { walk_party_deletion_request_v01_mut(self, node) }
+	fn visit_securities_account_deletion_request_v01_mut(&mut self, node: &mut crate::reda_032_001_01::SecuritiesAccountDeletionRequestV01) { walk_securities_account_deletion_request_v01_mut(self, node) }
+	fn visit_securities_audit_trail_query_v01_mut(&mut self, node: &mut crate::reda_033_001_01::SecuritiesAuditTrailQueryV01) { walk_securities_audit_trail_query_v01_mut(self, node) }
+	fn visit_securities_audit_trail_report_v01_mut(&mut self, node: &mut crate::reda_034_001_01::SecuritiesAuditTrailReportV01) { walk_securities_audit_trail_report_v01_mut(self, node) }
+	fn visit_securities_account_activity_advice_v01_mut(&mut self, node: &mut crate::reda_035_001_01::SecuritiesAccountActivityAdviceV01) { walk_securities_account_activity_advice_v01_mut(self, node) }
+	fn visit_securities_account_audit_trail_query_v01_mut(&mut self, node: &mut crate::reda_036_001_01::SecuritiesAccountAuditTrailQueryV01) { walk_securities_account_audit_trail_query_v01_mut(self, node) }
+	fn visit_securities_account_audit_trail_report_v01_mut(&mut self, node: &mut crate::reda_037_001_01::SecuritiesAccountAuditTrailReportV01) { walk_securities_account_audit_trail_report_v01_mut(self, node) }
+	fn visit_party_activity_advice_v02_mut(&mut self, node: &mut crate::reda_041_001_02::PartyActivityAdviceV02) { walk_party_activity_advice_v02_mut(self, node) }
+	fn visit_party_audit_trail_query_v01_mut(&mut self, node: &mut crate::reda_042_001_01::PartyAuditTrailQueryV01) { walk_party_audit_trail_query_v01_mut(self, node) }
+	fn visit_party_audit_trail_report_v02_mut(&mut self, node: &mut crate::reda_043_001_02::PartyAuditTrailReportV02) { walk_party_audit_trail_report_v02_mut(self, node) }
+	fn visit_standing_settlement_instruction_v01_mut(&mut self, node: &mut crate::reda_056_001_01::StandingSettlementInstructionV01) { walk_standing_settlement_instruction_v01_mut(self, node) }
+	fn visit_standing_settlement_instruction_deletion_v01_mut(&mut self, node: &mut crate::reda_057_001_01::StandingSettlementInstructionDeletionV01) { walk_standing_settlement_instruction_deletion_v01_mut(self, node) }
+	fn visit_standing_settlement_instruction_status_advice_v01_mut(&mut self, node: &mut crate::reda_058_001_01::StandingSettlementInstructionStatusAdviceV01) { walk_standing_settlement_instruction_status_advice_v01_mut(self, node) }
+	fn visit_standing_settlement_instruction_cancellation_v01_mut(&mut self, node: &mut crate::reda_059_001_01::StandingSettlementInstructionCancellationV01) { walk_standing_settlement_instruction_cancellation_v01_mut(self, node) }
+	fn visit_netting_cut_off_reference_data_update_request_v02_mut(&mut self, node: &mut crate::reda_060_001_02::NettingCutOffReferenceDataUpdateRequestV02) { walk_netting_cut_off_reference_data_update_request_v02_mut(self, node) }
+	fn visit_netting_cut_off_reference_data_report_v02_mut(&mut self, node: &mut crate::reda_061_001_02::NettingCutOffReferenceDataReportV02) { walk_netting_cut_off_reference_data_report_v02_mut(self, node) }
+	fn visit_calendar_query_v02_mut(&mut self, node: &mut crate::reda_064_001_02::CalendarQueryV02) { walk_calendar_query_v02_mut(self, node) }
+	fn visit_calendar_report_v02_mut(&mut self, node: &mut crate::reda_065_001_02::CalendarReportV02) { walk_calendar_report_v02_mut(self, node) }
+	fn visit_request_to_pay_creditor_enrolment_request_v02_mut(&mut self, node: &mut crate::reda_066_001_02::RequestToPayCreditorEnrolmentRequestV02) { walk_request_to_pay_creditor_enrolment_request_v02_mut(self, node) }
+	fn visit_request_to_pay_creditor_enrolment_amendment_request_v02_mut(&mut self, node: &mut crate::reda_067_001_02::RequestToPayCreditorEnrolmentAmendmentRequestV02) { walk_request_to_pay_creditor_enrolment_amendment_request_v02_mut(self, node) }
+	fn visit_request_to_pay_creditor_enrolment_cancellation_request_v02_mut(&mut self, node: &mut crate::reda_068_001_02::RequestToPayCreditorEnrolmentCancellationRequestV02) { walk_request_to_pay_creditor_enrolment_cancellation_request_v02_mut(self, node) }
+	fn visit_request_to_pay_creditor_enrolment_status_report_v02_mut(&mut self, node: &mut crate::reda_069_001_02::RequestToPayCreditorEnrolmentStatusReportV02) { walk_request_to_pay_creditor_enrolment_status_report_v02_mut(self, node) }
+	fn visit_request_to_pay_debtor_activation_request_v02_mut(&mut self, node: &mut crate::reda_070_001_02::RequestToPayDebtorActivationRequestV02) { walk_request_to_pay_debtor_activation_request_v02_mut(self, node) }
+	fn visit_request_to_pay_debtor_activation_amendment_request_v02_mut(&mut self, node: &mut crate::reda_071_001_02::RequestToPayDebtorActivationAmendmentRequestV02) { walk_request_to_pay_debtor_activation_amendment_request_v02_mut(self, node) }
+	fn visit_request_to_pay_debtor_activation_cancellation_request_v02_mut(&mut self, node: &mut crate::reda_072_001_02::RequestToPayDebtorActivationCancellationRequestV02) { walk_request_to_pay_debtor_activation_cancellation_request_v02_mut(self, node) }
+	fn visit_request_to_pay_debtor_activation_status_report_v02_mut(&mut self, node: &mut crate::reda_073_001_02::RequestToPayDebtorActivationStatusReportV02) { walk_request_to_pay_debtor_activation_status_report_v02_mut(self, node) }
+}
+
+pub fn walk_price_report_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_001_001_04::PriceReportV04) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.pool_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3(item) } }
+	if let Some(ref value) = node.rltd_ref { visitor.visit_additional_reference3(value) }
+	visitor.visit_pagination(&node.msg_pgntn);
+	visitor.visit_price_report_function1_code(&node.fctn);
+	for item in &node.pric_valtn_dtls { visitor.visit_price_valuation4(item) }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_price_report_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_001_001_04::PriceReportV04) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.pool_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference3_mut(item) } }
+	if let Some(ref mut value) = node.rltd_ref { visitor.visit_additional_reference3_mut(value) }
+	visitor.visit_pagination_mut(&mut node.msg_pgntn);
+	visitor.visit_price_report_function1_code_mut(&mut node.fctn);
+	for item in &mut node.pric_valtn_dtls { visitor.visit_price_valuation4_mut(item) }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_price_report_cancellation_v04<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_002_001_04::PriceReportCancellationV04) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.pool_ref { visitor.visit_additional_reference3(value) }
+	if let Some(ref value) = node.prvs_ref { visitor.visit_additional_reference3(value) }
+	visitor.visit_pagination(&node.msg_pgntn);
+	if let Some(ref value) = node.xpctd_pric_crrctn_dt { visitor.visit_date_and_date_time1_choice(value) }
+	if let Some(ref vec) = node.canc_pric_valtn_dtls { for item in vec { visitor.visit_price_report3(item) } }
+	if let Some(ref vec) = node.xtnsn { for item in vec { visitor.visit_extension1(item) } }
+}
+
+pub fn walk_price_report_cancellation_v04_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_002_001_04::PriceReportCancellationV04) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.pool_ref { visitor.visit_additional_reference3_mut(value) }
+	if let Some(ref mut value) = node.prvs_ref { visitor.visit_additional_reference3_mut(value) }
+	visitor.visit_pagination_mut(&mut node.msg_pgntn);
+	if let Some(ref mut value) = node.xpctd_pric_crrctn_dt { visitor.visit_date_and_date_time1_choice_mut(value) }
+	if let Some(ref mut vec) = node.canc_pric_valtn_dtls { for item in vec { visitor.visit_price_report3_mut(item) } }
+	if let Some(ref mut vec) = node.xtnsn { for item in vec { visitor.visit_extension1_mut(item) } }
+}
+
+pub fn walk_fund_reference_data_report_v07<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_004_001_07::FundReferenceDataReportV07) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference10(item) } }
+	if let Some(ref value) = node.rltd_ref { visitor.visit_additional_reference10(value) }
+	for item in &node.rpt { visitor.visit_fund_reference_data_report5(item) }
+}
+
+pub fn walk_fund_reference_data_report_v07_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_004_001_07::FundReferenceDataReportV07) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut vec) = node.prvs_ref { for item in vec { visitor.visit_additional_reference10_mut(item) } }
+	if let Some(ref mut value) = node.rltd_ref { visitor.visit_additional_reference10_mut(value) }
+	for item in &mut node.rpt { visitor.visit_fund_reference_data_report5_mut(item) }
+}
+
+pub fn walk_investment_fund_report_request_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_005_001_03::InvestmentFundReportRequestV03) {
+	visitor.visit_message_identification1(&node.msg_id);
+	if let Some(ref value) = node.prvs_ref { visitor.visit_additional_reference10(value) }
+	if let Some(ref value) = node.rltd_ref { visitor.visit_additional_reference10(value) }
+	for item in &node.rpt_req { visitor.visit_fund_parameters4_choice(item) }
+}
+
+pub fn walk_investment_fund_report_request_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_005_001_03::InvestmentFundReportRequestV03) {
+	visitor.visit_message_identification1_mut(&mut node.msg_id);
+	if let Some(ref mut value) = node.prvs_ref { visitor.visit_additional_reference10_mut(value) }
+	if let Some(ref mut value) = node.rltd_ref { visitor.visit_additional_reference10_mut(value) }
+	for item in &mut node.rpt_req { visitor.visit_fund_parameters4_choice_mut(item) }
+}
+
+pub fn walk_security_creation_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_006_001_01::SecurityCreationRequestV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_security_attributes10(&node.scty);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_security_creation_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_006_001_01::SecurityCreationRequestV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_security_attributes10_mut(&mut node.scty);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_security_maintenance_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_007_001_01::SecurityMaintenanceRequestV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_update_type36_choice(&node.upd_tp);
+	if let Some(ref value) = node.upd_rsn { visitor.visit_securities_update_reason1_choice(value) }
+	visitor.visit_security_identification39(&node.fin_instrm_id);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_security_maintenance_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_007_001_01::SecurityMaintenanceRequestV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_update_type36_choice_mut(&mut node.upd_tp);
+	if let Some(ref mut value) = node.upd_rsn { visitor.visit_securities_update_reason1_choice_mut(value) }
+	visitor.visit_security_identification39_mut(&mut node.fin_instrm_id);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_security_creation_status_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_008_001_01::SecurityCreationStatusAdviceV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header12(value) }
+	if let Some(ref value) = node.fin_instrm_id { visitor.visit_security_identification39(value) }
+	visitor.visit_processing_status72_choice(&node.prcg_sts);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_security_creation_status_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_008_001_01::SecurityCreationStatusAdviceV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header12_mut(value) }
+	if let Some(ref mut value) = node.fin_instrm_id { visitor.visit_security_identification39_mut(value) }
+	visitor.visit_processing_status72_choice_mut(&mut node.prcg_sts);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_security_activity_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_009_001_01::SecurityActivityAdviceV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_pagination1(&node.pgntn);
+	visitor.visit_security_statement3(&node.scty_actvty);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_security_activity_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_009_001_01::SecurityActivityAdviceV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_pagination1_mut(&mut node.pgntn);
+	visitor.visit_security_statement3_mut(&mut node.scty_actvty);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_security_query_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_010_001_01::SecurityQueryV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	if let Some(ref value) = node.req_tp { visitor.visit_generic_identification1(value) }
+	visitor.visit_securities_search_criteria4(&node.sch_crit);
+	if let Some(ref value) = node.sml_set_rtr_crit { visitor.visit_securities_return_criteria1(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_security_query_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_010_001_01::SecurityQueryV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	if let Some(ref mut value) = node.req_tp { visitor.visit_generic_identification1_mut(value) }
+	visitor.visit_securities_search_criteria4_mut(&mut node.sch_crit);
+	if let Some(ref mut value) = node.sml_set_rtr_crit { visitor.visit_securities_return_criteria1_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_security_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_012_001_01::SecurityReportV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header12(value) }
+	visitor.visit_pagination1(&node.pgntn);
+	visitor.visit_security_or_operational_error4_choice(&node.scty_rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_security_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_012_001_01::SecurityReportV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header12_mut(value) }
+	visitor.visit_pagination1_mut(&mut node.pgntn);
+	visitor.visit_security_or_operational_error4_choice_mut(&mut node.scty_rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_security_deletion_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_013_001_01::SecurityDeletionRequestV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_security_identification39(&node.fin_instrm_id);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_security_deletion_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_013_001_01::SecurityDeletionRequestV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_security_identification39_mut(&mut node.fin_instrm_id);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_party_creation_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_014_001_02::PartyCreationRequestV02) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_system_party7(&node.pty);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_party_creation_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_014_001_02::PartyCreationRequestV02) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_system_party7_mut(&mut node.pty);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_party_query_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_015_001_01::PartyQueryV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header2(value) }
+	visitor.visit_party_data_search_criteria2(&node.sch_crit);
+	if let Some(ref value) = node.rtr_crit { visitor.visit_party_data_return_criteria2(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_party_query_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_015_001_01::PartyQueryV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header2_mut(value) }
+	visitor.visit_party_data_search_criteria2_mut(&mut node.sch_crit);
+	if let Some(ref mut value) = node.rtr_crit { visitor.visit_party_data_return_criteria2_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_party_status_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_016_001_01::PartyStatusAdviceV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header12(value) }
+	visitor.visit_party_status2(&node.pty_sts);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_party_status_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_016_001_01::PartyStatusAdviceV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header12_mut(value) }
+	visitor.visit_party_status2_mut(&mut node.pty_sts);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_party_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_017_001_02::PartyReportV02) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header3(value) }
+	visitor.visit_party_or_operational_error4_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_party_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_017_001_02::PartyReportV02) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header3_mut(value) }
+	visitor.visit_party_or_operational_error4_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_account_creation_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_018_001_01::SecuritiesAccountCreationRequestV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_system_securities_account7(&node.scties_acct);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_account_creation_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_018_001_01::SecuritiesAccountCreationRequestV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_system_securities_account7_mut(&mut node.scties_acct);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_account_query_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_019_001_01::SecuritiesAccountQueryV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header2(value) }
+	visitor.visit_securities_account_search_criteria2(&node.sch_crit);
+	if let Some(ref value) = node.rtr_crit { visitor.visit_securities_account_return_criteria1(value) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_account_query_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_019_001_01::SecuritiesAccountQueryV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header2_mut(value) }
+	visitor.visit_securities_account_search_criteria2_mut(&mut node.sch_crit);
+	if let Some(ref mut value) = node.rtr_crit { visitor.visit_securities_account_return_criteria1_mut(value) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_account_status_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_020_001_01::SecuritiesAccountStatusAdviceV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header12(value) }
+	visitor.visit_securities_account_status2(&node.scties_acct_sts);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_account_status_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_020_001_01::SecuritiesAccountStatusAdviceV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header12_mut(value) }
+	visitor.visit_securities_account_status2_mut(&mut node.scties_acct_sts);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_account_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_021_001_01::SecuritiesAccountReportV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header3(value) }
+	visitor.visit_pagination1(&node.pgntn);
+	visitor.visit_securities_account_or_operational_error3_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_account_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_021_001_01::SecuritiesAccountReportV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header3_mut(value) }
+	visitor.visit_pagination1_mut(&mut node.pgntn);
+	visitor.visit_securities_account_or_operational_error3_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_party_modification_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_022_001_02::PartyModificationRequestV02) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_system_party_identification8(&node.sys_pty_id);
+	for item in &node.mod_attr { visitor.visit_system_party_modification3(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_party_modification_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_022_001_02::PartyModificationRequestV02) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_system_party_identification8_mut(&mut node.sys_pty_id);
+	for item in &mut node.mod_attr { visitor.visit_system_party_modification3_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_account_modification_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_023_001_01::SecuritiesAccountModificationRequestV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_securities_account19(&node.acct_id);
+	for item in &node.mod_attr { visitor.visit_securities_account_modification2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_account_modification_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_023_001_01::SecuritiesAccountModificationRequestV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_securities_account19_mut(&mut node.acct_id);
+	for item in &mut node.mod_attr { visitor.visit_securities_account_modification2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_security_maintenance_status_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_029_001_01::SecurityMaintenanceStatusAdviceV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header12(value) }
+	if let Some(ref value) = node.fin_instrm_id { visitor.visit_security_identification39(value) }
+	visitor.visit_processing_status72_choice(&node.prcg_sts);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_security_maintenance_status_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_029_001_01::SecurityMaintenanceStatusAdviceV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header12_mut(value) }
+	if let Some(ref mut value) = node.fin_instrm_id { visitor.visit_security_identification39_mut(value) }
+	visitor.visit_processing_status72_choice_mut(&mut node.prcg_sts);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_security_deletion_status_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_030_001_01::SecurityDeletionStatusAdviceV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header12(value) }
+	if let Some(ref value) = node.fin_instrm_id { visitor.visit_security_identification39(value) }
+	visitor.visit_processing_status72_choice(&node.prcg_sts);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_security_deletion_status_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_030_001_01::SecurityDeletionStatusAdviceV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header12_mut(value) }
+	if let Some(ref mut value) = node.fin_instrm_id { visitor.visit_security_identification39_mut(value) }
+	visitor.visit_processing_status72_choice_mut(&mut node.prcg_sts);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_party_deletion_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_031_001_01::PartyDeletionRequestV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_system_party_identification8(&node.sys_pty_id);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_party_deletion_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_031_001_01::PartyDeletionRequestV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_system_party_identification8_mut(&mut node.sys_pty_id);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_account_deletion_request_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_032_001_01::SecuritiesAccountDeletionRequestV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_securities_account19(&node.acct_id);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_account_deletion_request_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_032_001_01::SecuritiesAccountDeletionRequestV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_securities_account19_mut(&mut node.acct_id);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_audit_trail_query_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_033_001_01::SecuritiesAuditTrailQueryV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_securities_audit_trail_search_criteria4(&node.sch_crit);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_audit_trail_query_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_033_001_01::SecuritiesAuditTrailQueryV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_securities_audit_trail_search_criteria4_mut(&mut node.sch_crit);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_audit_trail_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_034_001_01::SecuritiesAuditTrailReportV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header12(value) }
+	visitor.visit_securities_audit_trail_or_operational_error4_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_audit_trail_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_034_001_01::SecuritiesAuditTrailReportV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header12_mut(value) }
+	visitor.visit_securities_audit_trail_or_operational_error4_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_account_activity_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_035_001_01::SecuritiesAccountActivityAdviceV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_pagination1(&node.pgntn);
+	visitor.visit_securities_account_statement2(&node.scties_acct_actvty);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_account_activity_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_035_001_01::SecuritiesAccountActivityAdviceV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_pagination1_mut(&mut node.pgntn);
+	visitor.visit_securities_account_statement2_mut(&mut node.scties_acct_actvty);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_account_audit_trail_query_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_036_001_01::SecuritiesAccountAuditTrailQueryV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_securities_account_audit_trail_search_criteria3(&node.sch_crit);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_account_audit_trail_query_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_036_001_01::SecuritiesAccountAuditTrailQueryV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_securities_account_audit_trail_search_criteria3_mut(&mut node.sch_crit);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_securities_account_audit_trail_report_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_037_001_01::SecuritiesAccountAuditTrailReportV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header12(value) }
+	visitor.visit_securities_account_audit_trail_or_operational_error3_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_securities_account_audit_trail_report_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_037_001_01::SecuritiesAccountAuditTrailReportV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header12_mut(value) }
+	visitor.visit_securities_account_audit_trail_or_operational_error3_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_party_activity_advice_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_041_001_02::PartyActivityAdviceV02) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_party_statement3(&node.pty_actvty);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_party_activity_advice_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_041_001_02::PartyActivityAdviceV02) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_party_statement3_mut(&mut node.pty_actvty);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_party_audit_trail_query_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_042_001_01::PartyAuditTrailQueryV01) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header1(value) }
+	visitor.visit_party_audit_trail_search_criteria2(&node.sch_crit);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_party_audit_trail_query_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_042_001_01::PartyAuditTrailQueryV01) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header1_mut(value) }
+	visitor.visit_party_audit_trail_search_criteria2_mut(&mut node.sch_crit);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_party_audit_trail_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_043_001_02::PartyAuditTrailReportV02) {
+	if let Some(ref value) = node.msg_hdr { visitor.visit_message_header12(value) }
+	visitor.visit_party_audit_trail_or_error3_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_party_audit_trail_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_043_001_02::PartyAuditTrailReportV02) {
+	if let Some(ref mut value) = node.msg_hdr { visitor.visit_message_header12_mut(value) }
+	visitor.visit_party_audit_trail_or_error3_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_standing_settlement_instruction_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_056_001_01::StandingSettlementInstructionV01) {
+	if let Some(ref value) = node.fctv_dt_dtls { visitor.visit_effective_date1(value) }
+	for item in &node.acct_id { visitor.visit_account_identification26(item) }
+	visitor.visit_market_identification_or_cash_purpose1_choice(&node.mkt_id);
+	visitor.visit_securities_or_cash1_choice(&node.sttlm_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_standing_settlement_instruction_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_056_001_01::StandingSettlementInstructionV01) {
+	if let Some(ref mut value) = node.fctv_dt_dtls { visitor.visit_effective_date1_mut(value) }
+	for item in &mut node.acct_id { visitor.visit_account_identification26_mut(item) }
+	visitor.visit_market_identification_or_cash_purpose1_choice_mut(&mut node.mkt_id);
+	visitor.visit_securities_or_cash1_choice_mut(&mut node.sttlm_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_standing_settlement_instruction_deletion_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_057_001_01::StandingSettlementInstructionDeletionV01) {
+	if let Some(ref value) = node.fctv_dt_dtls { visitor.visit_effective_date1(value) }
+	for item in &node.acct_id { visitor.visit_account_identification26(item) }
+	visitor.visit_market_identification_or_cash_purpose1_choice(&node.mkt_id);
+	visitor.visit_party_or_currency1_choice(&node.sttlm_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_standing_settlement_instruction_deletion_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_057_001_01::StandingSettlementInstructionDeletionV01) {
+	if let Some(ref mut value) = node.fctv_dt_dtls { visitor.visit_effective_date1_mut(value) }
+	for item in &mut node.acct_id { visitor.visit_account_identification26_mut(item) }
+	visitor.visit_market_identification_or_cash_purpose1_choice_mut(&mut node.mkt_id);
+	visitor.visit_party_or_currency1_choice_mut(&mut node.sttlm_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_standing_settlement_instruction_status_advice_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_058_001_01::StandingSettlementInstructionStatusAdviceV01) {
+	if let Some(ref value) = node.fctv_dt_dtls { visitor.visit_effective_date1(value) }
+	for item in &node.acct_id { visitor.visit_account_identification26(item) }
+	visitor.visit_market_identification_or_cash_purpose1_choice(&node.mkt_id);
+	visitor.visit_party_or_currency1_choice(&node.sttlm_dtls);
+	visitor.visit_processing_status43_choice(&node.prcg_sts);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_standing_settlement_instruction_status_advice_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_058_001_01::StandingSettlementInstructionStatusAdviceV01) {
+	if let Some(ref mut value) = node.fctv_dt_dtls { visitor.visit_effective_date1_mut(value) }
+	for item in &mut node.acct_id { visitor.visit_account_identification26_mut(item) }
+	visitor.visit_market_identification_or_cash_purpose1_choice_mut(&mut node.mkt_id);
+	visitor.visit_party_or_currency1_choice_mut(&mut node.sttlm_dtls);
+	visitor.visit_processing_status43_choice_mut(&mut node.prcg_sts);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_standing_settlement_instruction_cancellation_v01<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_059_001_01::StandingSettlementInstructionCancellationV01) {
+	if let Some(ref value) = node.fctv_dt_dtls { visitor.visit_effective_date1(value) }
+	for item in &node.acct_id { visitor.visit_account_identification26(item) }
+	visitor.visit_market_identification_or_cash_purpose1_choice(&node.mkt_id);
+	visitor.visit_party_or_currency1_choice(&node.sttlm_dtls);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_standing_settlement_instruction_cancellation_v01_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_059_001_01::StandingSettlementInstructionCancellationV01) {
+	if let Some(ref mut value) = node.fctv_dt_dtls { visitor.visit_effective_date1_mut(value) }
+	for item in &mut node.acct_id { visitor.visit_account_identification26_mut(item) }
+	visitor.visit_market_identification_or_cash_purpose1_choice_mut(&mut node.mkt_id);
+	visitor.visit_party_or_currency1_choice_mut(&mut node.sttlm_dtls);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_netting_cut_off_reference_data_update_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_060_001_02::NettingCutOffReferenceDataUpdateRequestV02) {
+	visitor.visit_request_data2(&node.req_data);
+	for item in &node.netg_cut_off_req { visitor.visit_netting_cut_off2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_netting_cut_off_reference_data_update_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_060_001_02::NettingCutOffReferenceDataUpdateRequestV02) {
+	visitor.visit_request_data2_mut(&mut node.req_data);
+	for item in &mut node.netg_cut_off_req { visitor.visit_netting_cut_off2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_netting_cut_off_reference_data_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_061_001_02::NettingCutOffReferenceDataReportV02) {
+	visitor.visit_netting_cut_off_report_data2(&node.rpt_data);
+	for item in &node.ptcpt_netg_cut_off_data { visitor.visit_cut_off_data2(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_netting_cut_off_reference_data_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_061_001_02::NettingCutOffReferenceDataReportV02) {
+	visitor.visit_netting_cut_off_report_data2_mut(&mut node.rpt_data);
+	for item in &mut node.ptcpt_netg_cut_off_data { visitor.visit_cut_off_data2_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_calendar_query_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_064_001_02::CalendarQueryV02) {
+	visitor.visit_message_header9(&node.msg_hdr);
+	if let Some(ref vec) = node.sch_crit { for item in vec { visitor.visit_calendar_search_criteria1(item) } }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_calendar_query_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_064_001_02::CalendarQueryV02) {
+	visitor.visit_message_header9_mut(&mut node.msg_hdr);
+	if let Some(ref mut vec) = node.sch_crit { for item in vec { visitor.visit_calendar_search_criteria1_mut(item) } }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_calendar_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_065_001_02::CalendarReportV02) {
+	visitor.visit_message_header11(&node.msg_hdr);
+	visitor.visit_calendar_report_or_error1_choice(&node.rpt_or_err);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_calendar_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_065_001_02::CalendarReportV02) {
+	visitor.visit_message_header11_mut(&mut node.msg_hdr);
+	visitor.visit_calendar_report_or_error1_choice_mut(&mut node.rpt_or_err);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_request_to_pay_creditor_enrolment_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_066_001_02::RequestToPayCreditorEnrolmentRequestV02) {
+	visitor.visit_enrolment_header3(&node.hdr);
+	for item in &node.cdtr_enrlmnt { visitor.visit_creditor_enrolment5(item) }
+	visitor.visit_creditor_invoice6(&node.actvtn_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_to_pay_creditor_enrolment_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_066_001_02::RequestToPayCreditorEnrolmentRequestV02) {
+	visitor.visit_enrolment_header3_mut(&mut node.hdr);
+	for item in &mut node.cdtr_enrlmnt { visitor.visit_creditor_enrolment5_mut(item) }
+	visitor.visit_creditor_invoice6_mut(&mut node.actvtn_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_request_to_pay_creditor_enrolment_amendment_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_067_001_02::RequestToPayCreditorEnrolmentAmendmentRequestV02) {
+	visitor.visit_enrolment_header3(&node.hdr);
+	for item in &node.amdmnt_data { visitor.visit_creditor_enrolment_amendment5(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_to_pay_creditor_enrolment_amendment_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_067_001_02::RequestToPayCreditorEnrolmentAmendmentRequestV02) {
+	visitor.visit_enrolment_header3_mut(&mut node.hdr);
+	for item in &mut node.amdmnt_data { visitor.visit_creditor_enrolment_amendment5_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_request_to_pay_creditor_enrolment_cancellation_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_068_001_02::RequestToPayCreditorEnrolmentCancellationRequestV02) {
+	visitor.visit_enrolment_header3(&node.hdr);
+	for item in &node.cxl_data { visitor.visit_creditor_enrolment_cancellation3(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_to_pay_creditor_enrolment_cancellation_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_068_001_02::RequestToPayCreditorEnrolmentCancellationRequestV02) {
+	visitor.visit_enrolment_header3_mut(&mut node.hdr);
+	for item in &mut node.cxl_data { visitor.visit_creditor_enrolment_cancellation3_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_request_to_pay_creditor_enrolment_status_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_069_001_02::RequestToPayCreditorEnrolmentStatusReportV02) {
+	visitor.visit_enrolment_header3(&node.hdr);
+	for item in &node.orgnl_enrlmnt_and_sts { visitor.visit_enrolment_status3(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_to_pay_creditor_enrolment_status_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_069_001_02::RequestToPayCreditorEnrolmentStatusReportV02) {
+	visitor.visit_enrolment_header3_mut(&mut node.hdr);
+	for item in &mut node.orgnl_enrlmnt_and_sts { visitor.visit_enrolment_status3_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_request_to_pay_debtor_activation_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_070_001_02::RequestToPayDebtorActivationRequestV02) {
+	visitor.visit_activation_header3(&node.hdr);
+	for item in &node.dbtr_actvtn { visitor.visit_debtor_activation5(item) }
+	visitor.visit_electronic_invoice1(&node.elctrnc_invc_data);
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_to_pay_debtor_activation_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_070_001_02::RequestToPayDebtorActivationRequestV02) {
+	visitor.visit_activation_header3_mut(&mut node.hdr);
+	for item in &mut node.dbtr_actvtn { visitor.visit_debtor_activation5_mut(item) }
+	visitor.visit_electronic_invoice1_mut(&mut node.elctrnc_invc_data);
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_request_to_pay_debtor_activation_amendment_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_071_001_02::RequestToPayDebtorActivationAmendmentRequestV02) {
+	visitor.visit_activation_header3(&node.hdr);
+	for item in &node.amdmnt_data { visitor.visit_debtor_activation_amendment5(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_to_pay_debtor_activation_amendment_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_071_001_02::RequestToPayDebtorActivationAmendmentRequestV02) {
+	visitor.visit_activation_header3_mut(&mut node.hdr);
+	for item in &mut node.amdmnt_data { visitor.visit_debtor_activation_amendment5_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_request_to_pay_debtor_activation_cancellation_request_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_072_001_02::RequestToPayDebtorActivationCancellationRequestV02) {
+	visitor.visit_activation_header3(&node.hdr);
+	for item in &node.cxl_data { visitor.visit_debtor_activation_cancellation3(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_to_pay_debtor_activation_cancellation_request_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_072_001_02::RequestToPayDebtorActivationCancellationRequestV02) {
+	visitor.visit_activation_header3_mut(&mut node.hdr);
+	for item in &mut node.cxl_data { visitor.visit_debtor_activation_cancellation3_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_request_to_pay_debtor_activation_status_report_v02<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::reda_073_001_02::RequestToPayDebtorActivationStatusReportV02) {
+	visitor.visit_activation_header3(&node.hdr);
+	for item in &node.orgnl_actvtn_and_sts { visitor.visit_activation_status3(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_request_to_pay_debtor_activation_status_report_v02_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::reda_073_001_02::RequestToPayDebtorActivationStatusReportV02) {
+	visitor.visit_activation_header3_mut(&mut node.hdr);
+	for item in &mut node.orgnl_actvtn_and_sts { visitor.visit_activation_status3_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+impl crate::reda_001_001_04::PriceReportV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_price_report_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_price_report_v04_mut(self) }
+}
+
+impl crate::reda_002_001_04::PriceReportCancellationV04 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_price_report_cancellation_v04(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_price_report_cancellation_v04_mut(self) }
+}
+
+impl crate::reda_004_001_07::FundReferenceDataReportV07 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_fund_reference_data_report_v07(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_fund_reference_data_report_v07_mut(self) }
+}
+
+impl crate::reda_005_001_03::InvestmentFundReportRequestV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_investment_fund_report_request_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_investment_fund_report_request_v03_mut(self) }
+}
+
+impl crate::reda_006_001_01::SecurityCreationRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_creation_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_creation_request_v01_mut(self) }
+}
+
+impl crate::reda_007_001_01::SecurityMaintenanceRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_maintenance_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_maintenance_request_v01_mut(self) }
+}
+
+impl crate::reda_008_001_01::SecurityCreationStatusAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_creation_status_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_creation_status_advice_v01_mut(self) }
+}
+
+impl crate::reda_009_001_01::SecurityActivityAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_activity_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_activity_advice_v01_mut(self) }
+}
+
+impl crate::reda_010_001_01::SecurityQueryV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_query_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_query_v01_mut(self) }
+}
+
+impl crate::reda_012_001_01::SecurityReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_report_v01_mut(self) }
+}
+
+impl crate::reda_013_001_01::SecurityDeletionRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_deletion_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_deletion_request_v01_mut(self) }
+}
+
+impl crate::reda_014_001_02::PartyCreationRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_creation_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_creation_request_v02_mut(self) }
+}
+
+impl crate::reda_015_001_01::PartyQueryV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_query_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_query_v01_mut(self) }
+}
+
+impl crate::reda_016_001_01::PartyStatusAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_status_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_status_advice_v01_mut(self) }
+}
+
+impl crate::reda_017_001_02::PartyReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_report_v02_mut(self) }
+}
+
+impl crate::reda_018_001_01::SecuritiesAccountCreationRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account_creation_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account_creation_request_v01_mut(self) }
+}
+
+impl crate::reda_019_001_01::SecuritiesAccountQueryV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account_query_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account_query_v01_mut(self) }
+}
+
+impl crate::reda_020_001_01::SecuritiesAccountStatusAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account_status_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account_status_advice_v01_mut(self) }
+}
+
+impl crate::reda_021_001_01::SecuritiesAccountReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account_report_v01_mut(self) }
+}
+
+impl crate::reda_022_001_02::PartyModificationRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_modification_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_modification_request_v02_mut(self) }
+}
+
+impl crate::reda_023_001_01::SecuritiesAccountModificationRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account_modification_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account_modification_request_v01_mut(self) }
+}
+
+impl crate::reda_029_001_01::SecurityMaintenanceStatusAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_maintenance_status_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_maintenance_status_advice_v01_mut(self) }
+}
+
+impl crate::reda_030_001_01::SecurityDeletionStatusAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_security_deletion_status_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_security_deletion_status_advice_v01_mut(self) }
+}
+
+impl crate::reda_031_001_01::PartyDeletionRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_deletion_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_deletion_request_v01_mut(self) }
+}
+
+impl crate::reda_032_001_01::SecuritiesAccountDeletionRequestV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account_deletion_request_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account_deletion_request_v01_mut(self) }
+}
+
+impl crate::reda_033_001_01::SecuritiesAuditTrailQueryV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_audit_trail_query_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_audit_trail_query_v01_mut(self) }
+}
+
+impl crate::reda_034_001_01::SecuritiesAuditTrailReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_audit_trail_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_audit_trail_report_v01_mut(self) }
+}
+
+impl crate::reda_035_001_01::SecuritiesAccountActivityAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account_activity_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account_activity_advice_v01_mut(self) }
+}
+
+impl crate::reda_036_001_01::SecuritiesAccountAuditTrailQueryV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account_audit_trail_query_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account_audit_trail_query_v01_mut(self) }
+}
+
+impl crate::reda_037_001_01::SecuritiesAccountAuditTrailReportV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_securities_account_audit_trail_report_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_securities_account_audit_trail_report_v01_mut(self) }
+}
+
+impl crate::reda_041_001_02::PartyActivityAdviceV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_activity_advice_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_activity_advice_v02_mut(self) }
+}
+
+impl crate::reda_042_001_01::PartyAuditTrailQueryV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_audit_trail_query_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_audit_trail_query_v01_mut(self) }
+}
+
+impl crate::reda_043_001_02::PartyAuditTrailReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_party_audit_trail_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_party_audit_trail_report_v02_mut(self) }
+}
+
+impl crate::reda_056_001_01::StandingSettlementInstructionV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_standing_settlement_instruction_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_standing_settlement_instruction_v01_mut(self) }
+}
+
+impl crate::reda_057_001_01::StandingSettlementInstructionDeletionV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_standing_settlement_instruction_deletion_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_standing_settlement_instruction_deletion_v01_mut(self) }
+}
+
+impl crate::reda_058_001_01::StandingSettlementInstructionStatusAdviceV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_standing_settlement_instruction_status_advice_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_standing_settlement_instruction_status_advice_v01_mut(self) }
+}
+
+impl crate::reda_059_001_01::StandingSettlementInstructionCancellationV01 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_standing_settlement_instruction_cancellation_v01(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_standing_settlement_instruction_cancellation_v01_mut(self) }
+}
+
+impl crate::reda_060_001_02::NettingCutOffReferenceDataUpdateRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_netting_cut_off_reference_data_update_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_netting_cut_off_reference_data_update_request_v02_mut(self) }
+}
+
+impl crate::reda_061_001_02::NettingCutOffReferenceDataReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_netting_cut_off_reference_data_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_netting_cut_off_reference_data_report_v02_mut(self) }
+}
+
+impl crate::reda_064_001_02::CalendarQueryV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_calendar_query_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_calendar_query_v02_mut(self) }
+}
+
+impl crate::reda_065_001_02::CalendarReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_calendar_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_calendar_report_v02_mut(self) }
+}
+
+impl crate::reda_066_001_02::RequestToPayCreditorEnrolmentRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_to_pay_creditor_enrolment_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_to_pay_creditor_enrolment_request_v02_mut(self) }
+}
+
+impl crate::reda_067_001_02::RequestToPayCreditorEnrolmentAmendmentRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_to_pay_creditor_enrolment_amendment_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_to_pay_creditor_enrolment_amendment_request_v02_mut(self) }
+}
+
+impl crate::reda_068_001_02::RequestToPayCreditorEnrolmentCancellationRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_to_pay_creditor_enrolment_cancellation_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_to_pay_creditor_enrolment_cancellation_request_v02_mut(self) }
+}
+
+impl crate::reda_069_001_02::RequestToPayCreditorEnrolmentStatusReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_to_pay_creditor_enrolment_status_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_to_pay_creditor_enrolment_status_report_v02_mut(self) }
+}
+
+impl crate::reda_070_001_02::RequestToPayDebtorActivationRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_to_pay_debtor_activation_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_to_pay_debtor_activation_request_v02_mut(self) }
+}
+
+impl crate::reda_071_001_02::RequestToPayDebtorActivationAmendmentRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_to_pay_debtor_activation_amendment_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_to_pay_debtor_activation_amendment_request_v02_mut(self) }
+}
+
+impl crate::reda_072_001_02::RequestToPayDebtorActivationCancellationRequestV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_to_pay_debtor_activation_cancellation_request_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_to_pay_debtor_activation_cancellation_request_v02_mut(self) }
+}
+
+impl crate::reda_073_001_02::RequestToPayDebtorActivationStatusReportV02 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_request_to_pay_debtor_activation_status_report_v02(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_request_to_pay_debtor_activation_status_report_v02_mut(self) }
+}
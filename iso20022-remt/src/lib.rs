@@ -25,3 +25,7 @@
 
 pub mod remt_001_001_06;
 pub mod remt_002_001_03;
+pub mod visitor;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
@@ -0,0 +1,75 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over the generated types of this crate, generated by
+// generate-visitor.py. `Visitor` has a `visit_` method per type that
+// walks into its fields by default; override the methods of the types of
+// interest and call the matching `walk_` function to keep going below
+// them. `VisitorMut` does the same with mutable references.
+
+use open_payments_common::visitor::{Visitor as BaseVisitor, VisitorMut as BaseVisitorMut};
+
+pub trait Visitor: BaseVisitor {
+	fn visit_remittance_advice_v06(&mut self, node: &crate::remt_001_001_06::RemittanceAdviceV06) { walk_remittance_advice_v06(self, node) }
+	fn visit_remittance_location_advice_v03(&mut self, node: &crate::remt_002_001_03::RemittanceLocationAdviceV03) { walk_remittance_location_advice_v03(self, node) }
+}
+
+pub trait VisitorMut: BaseVisitorMut {
+	fn visit_remittance_advice_v06_mut(&mut self, node: &mut crate::remt_001_001_06::RemittanceAdviceV06) { walk_remittance_advice_v06_mut(self, node) }
+	fn visit_remittance_location_advice_v03_mut(&mut self, node: &mut crate::remt_002_001_03::RemittanceLocationAdviceV03) { walk_remittance_location_advice_v03_mut(self, node) }
+}
+
+pub fn walk_remittance_advice_v06<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::remt_001_001_06::RemittanceAdviceV06) {
+	visitor.visit_group_header122(&node.grp_hdr);
+	for item in &node.rmt_inf { visitor.visit_remittance_information23(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_remittance_advice_v06_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::remt_001_001_06::RemittanceAdviceV06) {
+	visitor.visit_group_header122_mut(&mut node.grp_hdr);
+	for item in &mut node.rmt_inf { visitor.visit_remittance_information23_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+pub fn walk_remittance_location_advice_v03<V: Visitor + ?Sized>(visitor: &mut V, node: &crate::remt_002_001_03::RemittanceLocationAdviceV03) {
+	visitor.visit_group_header122(&node.grp_hdr);
+	for item in &node.rmt_lctn { visitor.visit_remittance_location10(item) }
+	if let Some(ref vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1(item) } }
+}
+
+pub fn walk_remittance_location_advice_v03_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut crate::remt_002_001_03::RemittanceLocationAdviceV03) {
+	visitor.visit_group_header122_mut(&mut node.grp_hdr);
+	for item in &mut node.rmt_lctn { visitor.visit_remittance_location10_mut(item) }
+	if let Some(ref mut vec) = node.splmtry_data { for item in vec { visitor.visit_supplementary_data1_mut(item) } }
+}
+
+impl crate::remt_001_001_06::RemittanceAdviceV06 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_remittance_advice_v06(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_remittance_advice_v06_mut(self) }
+}
+
+impl crate::remt_002_001_03::RemittanceLocationAdviceV03 {
+	pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) { visitor.visit_remittance_location_advice_v03(self) }
+	pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) { visitor.visit_remittance_location_advice_v03_mut(self) }
+}
@@ -0,0 +1,261 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Business files (head.002.001.01): a `PyldDesc` payload description
+// followed by any number of `Pyld` elements, each carrying a business
+// application header (head.001.001.02) and a `Document`.
+//
+// The file itself is the generated `BusinessFileHeaderV01`. Its payload
+// elements are open content in the schema, so the file is also scanned as
+// XML and every AppHdr and Document is deserialized on its own, the
+// Document into the matching `Document` variant.
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+
+use crate::document::Document;
+use open_payments_common::common::{BusinessApplicationHeaderV02, BusinessFileHeaderV01, PayloadDescription2};
+use open_payments_common::ValidationError;
+
+pub const ERR_FILE_STRUCTURE: u32 = 2701;
+pub const ERR_MESSAGE_SYNTAX: u32 = 2702;
+pub const ERR_DOCUMENT_COUNT: u32 = 2703;
+pub const ERR_SERIALIZATION: u32 = 2704;
+
+pub const FILE_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:head.002.001.01";
+pub const HEADER_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:head.001.001.02";
+const DOCUMENT_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:";
+
+/// One message of a business file.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct BusinessMessage {
+    pub app_hdr: BusinessApplicationHeaderV02,
+    pub document: Document,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Document")]
+struct DocumentXml {
+    #[serde(rename = "$value")]
+    document: Document,
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Document")]
+struct DocumentXmlRef<'a> {
+    #[serde(rename = "@xmlns")]
+    xmlns: String,
+    #[serde(rename = "$value")]
+    document: &'a Document,
+}
+
+fn structure_error(message: String) -> ValidationError {
+    ValidationError::new(ERR_FILE_STRUCTURE, message)
+}
+
+fn local_name(name: &[u8]) -> &[u8] {
+    match name.iter().position(|b| *b == b':') {
+        Some(i) => &name[i + 1..],
+        None => name,
+    }
+}
+
+/// `ApplSpcfcInf/TtlNbOfDocs` of a payload description.
+fn total_number_of_documents(description: &PayloadDescription2) -> Option<u64> {
+    description.appl_spcfc_inf.as_ref().map(|info| info.ttl_nb_of_docs as u64)
+}
+
+/// The AppHdr and Document elements of one `Pyld`, as XML text.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct RawPayload<'a> {
+    app_hdr: Option<&'a str>,
+    document: Option<&'a str>,
+}
+
+/// Reads a business file. The header, for its payload description, and the
+/// position of the payloads are read up front; the messages are deserialized
+/// as they are iterated.
+pub struct BusinessFileReader<'a> {
+    description: PayloadDescription2,
+    payloads: Vec<RawPayload<'a>>,
+    next: usize,
+}
+
+impl<'a> BusinessFileReader<'a> {
+    pub fn new(xml: &'a str) -> Result<Self, ValidationError> {
+        let file: BusinessFileHeaderV01 = quick_xml::de::from_str(xml).map_err(|e| structure_error(format!("invalid business file: {}", e)))?;
+        let mut reader = Reader::from_str(xml);
+        let mut payloads = Vec::new();
+        let mut path: Vec<Vec<u8>> = Vec::new();
+        loop {
+            let start = reader.buffer_position() as usize;
+            let event = reader.read_event().map_err(|e| structure_error(format!("invalid XML at {}: {}", reader.error_position(), e)))?;
+            match event {
+                Event::Start(e) => {
+                    let name = local_name(e.name().as_ref()).to_vec();
+                    let in_payload = path.last().is_some_and(|p| p.as_slice() == b"Pyld");
+                    if in_payload && (name.as_slice() == b"AppHdr" || name.as_slice() == b"Document") {
+                        reader.read_to_end(e.name()).map_err(|e| structure_error(format!("invalid XML at {}: {}", reader.error_position(), e)))?;
+                        let element = &xml[start..reader.buffer_position() as usize];
+                        let payload: &mut RawPayload = payloads.last_mut().ok_or_else(|| structure_error("payload outside of Pyld".to_string()))?;
+                        let slot = if name.as_slice() == b"AppHdr" { &mut payload.app_hdr } else { &mut payload.document };
+                        if slot.replace(element).is_some() {
+                            return Err(structure_error(format!("Pyld {} has more than one {}", payloads.len(), String::from_utf8_lossy(&name))));
+                        }
+                        continue;
+                    }
+                    if name.as_slice() == b"Pyld" {
+                        payloads.push(RawPayload::default());
+                    }
+                    path.push(name);
+                }
+                Event::Empty(e) if local_name(e.name().as_ref()) == b"Pyld" => payloads.push(RawPayload::default()),
+                Event::End(_) => {
+                    path.pop();
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(BusinessFileReader { description: file.pyld_desc, payloads, next: 0 })
+    }
+
+    pub fn description(&self) -> &PayloadDescription2 {
+        &self.description
+    }
+
+    /// Number of `Pyld` elements in the file.
+    pub fn len(&self) -> usize {
+        self.payloads.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.payloads.is_empty()
+    }
+
+    /// Checks the declared `TtlNbOfDocs` against the payloads and that every
+    /// payload carries an AppHdr and a Document.
+    pub fn validate_counts(&self) -> Result<(), ValidationError> {
+        if let Some(declared) = total_number_of_documents(&self.description) {
+            if declared != self.payloads.len() as u64 {
+                return Err(ValidationError::new(ERR_DOCUMENT_COUNT, format!("TtlNbOfDocs is {}, the file has {} payloads", declared, self.payloads.len())));
+            }
+        }
+        for (i, payload) in self.payloads.iter().enumerate() {
+            if payload.app_hdr.is_none() || payload.document.is_none() {
+                let missing = if payload.app_hdr.is_none() { "AppHdr" } else { "Document" };
+                return Err(ValidationError::new(ERR_DOCUMENT_COUNT, format!("Pyld {} has no {}", i + 1, missing)));
+            }
+        }
+        Ok(())
+    }
+
+    fn message(&self, index: usize) -> Result<BusinessMessage, ValidationError> {
+        let payload = &self.payloads[index];
+        let syntax_error = |element: &str, e: quick_xml::DeError| ValidationError::new(ERR_MESSAGE_SYNTAX, format!("Pyld {} {}: {}", index + 1, element, e));
+        let app_hdr = payload.app_hdr.ok_or_else(|| ValidationError::new(ERR_DOCUMENT_COUNT, format!("Pyld {} has no AppHdr", index + 1)))?;
+        let document = payload.document.ok_or_else(|| ValidationError::new(ERR_DOCUMENT_COUNT, format!("Pyld {} has no Document", index + 1)))?;
+        let app_hdr: BusinessApplicationHeaderV02 = quick_xml::de::from_str(app_hdr).map_err(|e| syntax_error("AppHdr", e))?;
+        let document: DocumentXml = quick_xml::de::from_str(document).map_err(|e| syntax_error("Document", e))?;
+        Ok(BusinessMessage { app_hdr, document: document.document })
+    }
+}
+
+impl Iterator for BusinessFileReader<'_> {
+    type Item = Result<BusinessMessage, ValidationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.payloads.len() {
+            return None;
+        }
+        self.next += 1;
+        Some(self.message(self.next - 1))
+    }
+}
+
+/// A business file with its messages.
+#[cfg_attr(feature = "derive_debug", derive(Debug))]
+#[cfg_attr(feature = "derive_clone", derive(Clone))]
+#[cfg_attr(feature = "derive_partial_eq", derive(PartialEq))]
+pub struct BusinessFile {
+    pub description: PayloadDescription2,
+    pub messages: Vec<BusinessMessage>,
+}
+
+impl BusinessFile {
+    /// Reads a business file, checks the declared counts and deserializes
+    /// every message.
+    pub fn from_xml(xml: &str) -> Result<Self, ValidationError> {
+        let mut reader = BusinessFileReader::new(xml)?;
+        reader.validate_counts()?;
+        let description = std::mem::take(&mut reader.description);
+        let messages = reader.collect::<Result<Vec<_>, _>>()?;
+        Ok(BusinessFile { description, messages })
+    }
+
+    /// Writes the file: the `PyldDesc` as it is, then one `Pyld` per message
+    /// with each Document in the namespace of the `MsgDefIdr` of its AppHdr.
+    pub fn to_xml(&self) -> Result<String, ValidationError> {
+        let serialization_error = |e: quick_xml::SeError| ValidationError::new(ERR_SERIALIZATION, e.to_string());
+        let description = quick_xml::se::to_string_with_root("PyldDesc", &self.description).map_err(serialization_error)?;
+        let mut xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Xchg xmlns=\"{}\">\n{}\n", FILE_NAMESPACE, description);
+        for message in &self.messages {
+            let app_hdr = quick_xml::se::to_string_with_root("AppHdr", &message.app_hdr).map_err(serialization_error)?;
+            let app_hdr = app_hdr.replacen("<AppHdr", &format!("<AppHdr xmlns=\"{}\"", HEADER_NAMESPACE), 1);
+            let document = DocumentXmlRef { xmlns: format!("{}{}", DOCUMENT_NAMESPACE, message.app_hdr.msg_def_idr), document: &message.document };
+            let document = quick_xml::se::to_string(&document).map_err(serialization_error)?;
+            xml.push_str(&format!("<Pyld>{}{}</Pyld>\n", app_hdr, document));
+        }
+        xml.push_str("</Xchg>\n");
+        Ok(xml)
+    }
+}
+
+/// Packages messages into a business file.
+pub struct BusinessFileBuilder {
+    description: PayloadDescription2,
+    messages: Vec<BusinessMessage>,
+}
+
+impl BusinessFileBuilder {
+    pub fn new(description: PayloadDescription2) -> Self {
+        BusinessFileBuilder { description, messages: Vec::new() }
+    }
+
+    pub fn message(mut self, app_hdr: BusinessApplicationHeaderV02, document: Document) -> Self {
+        self.messages.push(BusinessMessage { app_hdr, document });
+        self
+    }
+
+    /// The file, with `ApplSpcfcInf/TtlNbOfDocs` set to the number of
+    /// messages.
+    pub fn build(self) -> BusinessFile {
+        let mut description = self.description;
+        description.appl_spcfc_inf.get_or_insert_with(Default::default).ttl_nb_of_docs = self.messages.len() as f64;
+        BusinessFile { description, messages: self.messages }
+    }
+}
@@ -25,6 +25,11 @@
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 use open_payments_common::ValidationError;
+use crate::visitor::{Visitor, VisitorMut};
+#[cfg(feature = "derive_default")]
+use open_payments_common::tag_path::{Step, TagPaths};
+#[cfg(feature = "derive_serde")]
+use open_payments_common::fingerprint::{fingerprint, Fingerprint, FingerprintOptions};
 
 #[cfg(feature = "acmt")] use open_payments_iso20022_acmt::acmt_001_001_08::*;
 #[cfg(feature = "acmt")] use open_payments_iso20022_acmt::acmt_002_001_08::*;
@@ -2104,4 +2109,2990 @@ impl Document {
             }
         }
     }
-}
\ No newline at end of file
+
+    /// Message definition identifier (`MsgDefIdr`) of the message, e.g.
+    /// `pacs.008.001.08`.
+    pub fn message_definition_identifier(&self) -> Option<&'static str> {
+        match self {
+			#[cfg(feature = "admi")]
+			Document::Admi00200101(_) => Some("admi.002.001.01"),
+			#[cfg(feature = "admi")]
+			Document::SystemEventNotificationV02(_) => Some("admi.004.001.02"),
+			#[cfg(feature = "admi")]
+			Document::ReportQueryRequestV02(_) => Some("admi.005.001.02"),
+			#[cfg(feature = "admi")]
+			Document::ResendRequestV01(_) => Some("admi.006.001.01"),
+			#[cfg(feature = "admi")]
+			Document::ReceiptAcknowledgementV01(_) => Some("admi.007.001.01"),
+			#[cfg(feature = "admi")]
+			Document::StaticDataRequestV02(_) => Some("admi.009.001.02"),
+			#[cfg(feature = "admi")]
+			Document::StaticDataReportV02(_) => Some("admi.010.001.02"),
+			#[cfg(feature = "admi")]
+			Document::SystemEventAcknowledgementV01(_) => Some("admi.011.001.01"),
+			#[cfg(feature = "admi")]
+			Document::ProcessingRequestV02(_) => Some("admi.017.001.02"),
+			#[cfg(feature = "admi")]
+			Document::NotificationOfCorrespondenceV01(_) => Some("admi.024.001.01"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV12(_) => Some("pacs.002.001.12"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV14(_) => Some("pacs.002.001.14"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerDirectDebitV11(_) => Some("pacs.003.001.11"),
+			#[cfg(feature = "pacs")]
+			Document::PaymentReturnV13(_) => Some("pacs.004.001.13"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentReversalV13(_) => Some("pacs.007.001.13"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerCreditTransferV12(_) => Some("pacs.008.001.12"),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionCreditTransferV11(_) => Some("pacs.009.001.11"),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionDirectDebitV06(_) => Some("pacs.010.001.06"),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusRequestV06(_) => Some("pacs.028.001.06"),
+			#[cfg(feature = "pacs")]
+			Document::MultilateralSettlementRequestV02(_) => Some("pacs.029.001.02"),
+			#[cfg(feature = "pain")]
+			Document::CustomerCreditTransferInitiationV12(_) => Some("pain.001.001.12"),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentStatusReportV14(_) => Some("pain.002.001.14"),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentReversalV12(_) => Some("pain.007.001.12"),
+			#[cfg(feature = "pain")]
+			Document::CustomerDirectDebitInitiationV11(_) => Some("pain.008.001.11"),
+			#[cfg(feature = "pain")]
+			Document::MandateInitiationRequestV08(_) => Some("pain.009.001.08"),
+			#[cfg(feature = "pain")]
+			Document::MandateAmendmentRequestV08(_) => Some("pain.010.001.08"),
+			#[cfg(feature = "pain")]
+			Document::MandateCancellationRequestV08(_) => Some("pain.011.001.08"),
+			#[cfg(feature = "pain")]
+			Document::MandateAcceptanceReportV08(_) => Some("pain.012.001.08"),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestV11(_) => Some("pain.013.001.11"),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestStatusReportV11(_) => Some("pain.014.001.11"),
+			#[cfg(feature = "pain")]
+			Document::MandateCopyRequestV04(_) => Some("pain.017.001.04"),
+			#[cfg(feature = "pain")]
+			Document::MandateSuspensionRequestV04(_) => Some("pain.018.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningInstructionV08(_) => Some("acmt.001.001.08"),
+			#[cfg(feature = "acmt")]
+			Document::AccountDetailsConfirmationV08(_) => Some("acmt.002.001.08"),
+			#[cfg(feature = "acmt")]
+			Document::AccountModificationInstructionV08(_) => Some("acmt.003.001.08"),
+			#[cfg(feature = "acmt")]
+			Document::RequestForAccountManagementStatusReportV06(_) => Some("acmt.005.001.06"),
+			#[cfg(feature = "acmt")]
+			Document::AccountManagementStatusReportV07(_) => Some("acmt.006.001.07"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningRequestV05(_) => Some("acmt.007.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAmendmentRequestV05(_) => Some("acmt.008.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAdditionalInformationRequestV04(_) => Some("acmt.009.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestAcknowledgementV04(_) => Some("acmt.010.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestRejectionV04(_) => Some("acmt.011.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountAdditionalInformationRequestV04(_) => Some("acmt.012.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportRequestV04(_) => Some("acmt.013.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportV05(_) => Some("acmt.014.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceRequestV04(_) => Some("acmt.015.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(_) => Some("acmt.016.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceRequestV04(_) => Some("acmt.017.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceAmendmentRequestV04(_) => Some("acmt.018.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingRequestV04(_) => Some("acmt.019.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAmendmentRequestV04(_) => Some("acmt.020.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAdditionalInformationRequestV04(_) => Some("acmt.021.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationModificationAdviceV04(_) => Some("acmt.022.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationRequestV04(_) => Some("acmt.023.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationReportV04(_) => Some("acmt.024.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationRequestV05(_) => Some("acmt.027.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationResponseV05(_) => Some("acmt.028.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchCancelExistingPaymentV05(_) => Some("acmt.029.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestRedirectionV04(_) => Some("acmt.030.001.04"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestBalanceTransferV05(_) => Some("acmt.031.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchBalanceTransferAcknowledgementV05(_) => Some("acmt.032.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchNotifyAccountSwitchCompleteV02(_) => Some("acmt.033.001.02"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestPaymentV05(_) => Some("acmt.034.001.05"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchPaymentResponseV02(_) => Some("acmt.035.001.02"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTerminationSwitchV01(_) => Some("acmt.036.001.01"),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTechnicalRejectionV02(_) => Some("acmt.037.001.02"),
+			#[cfg(feature = "reda")]
+			Document::PriceReportV04(_) => Some("reda.001.001.04"),
+			#[cfg(feature = "reda")]
+			Document::PriceReportCancellationV04(_) => Some("reda.002.001.04"),
+			#[cfg(feature = "reda")]
+			Document::FundReferenceDataReportV07(_) => Some("reda.004.001.07"),
+			#[cfg(feature = "reda")]
+			Document::InvestmentFundReportRequestV03(_) => Some("reda.005.001.03"),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationRequestV01(_) => Some("reda.006.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceRequestV01(_) => Some("reda.007.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationStatusAdviceV01(_) => Some("reda.008.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityActivityAdviceV01(_) => Some("reda.009.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityQueryV01(_) => Some("reda.010.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityReportV01(_) => Some("reda.012.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionRequestV01(_) => Some("reda.013.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyCreationRequestV02(_) => Some("reda.014.001.02"),
+			#[cfg(feature = "reda")]
+			Document::PartyQueryV01(_) => Some("reda.015.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyStatusAdviceV01(_) => Some("reda.016.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyReportV02(_) => Some("reda.017.001.02"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountCreationRequestV01(_) => Some("reda.018.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountQueryV01(_) => Some("reda.019.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountStatusAdviceV01(_) => Some("reda.020.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountReportV01(_) => Some("reda.021.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyModificationRequestV02(_) => Some("reda.022.001.02"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountModificationRequestV01(_) => Some("reda.023.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceStatusAdviceV01(_) => Some("reda.029.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionStatusAdviceV01(_) => Some("reda.030.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyDeletionRequestV01(_) => Some("reda.031.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountDeletionRequestV01(_) => Some("reda.032.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailQueryV01(_) => Some("reda.033.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailReportV01(_) => Some("reda.034.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountActivityAdviceV01(_) => Some("reda.035.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailQueryV01(_) => Some("reda.036.001.01"),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailReportV01(_) => Some("reda.037.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyActivityAdviceV02(_) => Some("reda.041.001.02"),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailQueryV01(_) => Some("reda.042.001.01"),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailReportV02(_) => Some("reda.043.001.02"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionV01(_) => Some("reda.056.001.01"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionDeletionV01(_) => Some("reda.057.001.01"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionStatusAdviceV01(_) => Some("reda.058.001.01"),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionCancellationV01(_) => Some("reda.059.001.01"),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataUpdateRequestV02(_) => Some("reda.060.001.02"),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataReportV02(_) => Some("reda.061.001.02"),
+			#[cfg(feature = "reda")]
+			Document::CalendarQueryV02(_) => Some("reda.064.001.02"),
+			#[cfg(feature = "reda")]
+			Document::CalendarReportV02(_) => Some("reda.065.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentRequestV02(_) => Some("reda.066.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(_) => Some("reda.067.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(_) => Some("reda.068.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentStatusReportV02(_) => Some("reda.069.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationRequestV02(_) => Some("reda.070.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationAmendmentRequestV02(_) => Some("reda.071.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationCancellationRequestV02(_) => Some("reda.072.001.02"),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationStatusReportV02(_) => Some("reda.073.001.02"),
+			#[cfg(feature = "remt")]
+			Document::RemittanceAdviceV06(_) => Some("remt.001.001.06"),
+			#[cfg(feature = "remt")]
+			Document::RemittanceLocationAdviceV03(_) => Some("remt.002.001.03"),
+			#[cfg(feature = "camt")]
+			Document::GetAccountV08(_) => Some("camt.003.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ReturnAccountV10(_) => Some("camt.004.001.10"),
+			#[cfg(feature = "camt")]
+			Document::GetTransactionV11(_) => Some("camt.005.001.11"),
+			#[cfg(feature = "camt")]
+			Document::ReturnTransactionV11(_) => Some("camt.006.001.11"),
+			#[cfg(feature = "camt")]
+			Document::ModifyTransactionV10(_) => Some("camt.007.001.10"),
+			#[cfg(feature = "camt")]
+			Document::CancelTransactionV11(_) => Some("camt.008.001.11"),
+			#[cfg(feature = "camt")]
+			Document::GetLimitV08(_) => Some("camt.009.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ReturnLimitV09(_) => Some("camt.010.001.09"),
+			#[cfg(feature = "camt")]
+			Document::ModifyLimitV08(_) => Some("camt.011.001.08"),
+			#[cfg(feature = "camt")]
+			Document::DeleteLimitV08(_) => Some("camt.012.001.08"),
+			#[cfg(feature = "camt")]
+			Document::GetMemberV04(_) => Some("camt.013.001.04"),
+			#[cfg(feature = "camt")]
+			Document::ReturnMemberV05(_) => Some("camt.014.001.05"),
+			#[cfg(feature = "camt")]
+			Document::ModifyMemberV04(_) => Some("camt.015.001.04"),
+			#[cfg(feature = "camt")]
+			Document::GetCurrencyExchangeRateV04(_) => Some("camt.016.001.04"),
+			#[cfg(feature = "camt")]
+			Document::ReturnCurrencyExchangeRateV05(_) => Some("camt.017.001.05"),
+			#[cfg(feature = "camt")]
+			Document::GetBusinessDayInformationV05(_) => Some("camt.018.001.05"),
+			#[cfg(feature = "camt")]
+			Document::ReturnBusinessDayInformationV07(_) => Some("camt.019.001.07"),
+			#[cfg(feature = "camt")]
+			Document::GetGeneralBusinessInformationV04(_) => Some("camt.020.001.04"),
+			#[cfg(feature = "camt")]
+			Document::ReturnGeneralBusinessInformationV06(_) => Some("camt.021.001.06"),
+			#[cfg(feature = "camt")]
+			Document::BackupPaymentV07(_) => Some("camt.023.001.07"),
+			#[cfg(feature = "camt")]
+			Document::ModifyStandingOrderV08(_) => Some("camt.024.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ReceiptV08(_) => Some("camt.025.001.08"),
+			#[cfg(feature = "camt")]
+			Document::UnableToApplyV10(_) => Some("camt.026.001.10"),
+			#[cfg(feature = "camt")]
+			Document::ClaimNonReceiptV10(_) => Some("camt.027.001.10"),
+			#[cfg(feature = "camt")]
+			Document::AdditionalPaymentInformationV12(_) => Some("camt.028.001.12"),
+			#[cfg(feature = "camt")]
+			Document::ResolutionOfInvestigationV13(_) => Some("camt.029.001.13"),
+			#[cfg(feature = "camt")]
+			Document::NotificationOfCaseAssignmentV06(_) => Some("camt.030.001.06"),
+			#[cfg(feature = "camt")]
+			Document::RejectInvestigationV07(_) => Some("camt.031.001.07"),
+			#[cfg(feature = "camt")]
+			Document::CancelCaseAssignmentV05(_) => Some("camt.032.001.05"),
+			#[cfg(feature = "camt")]
+			Document::RequestForDuplicateV07(_) => Some("camt.033.001.07"),
+			#[cfg(feature = "camt")]
+			Document::DuplicateV07(_) => Some("camt.034.001.07"),
+			#[cfg(feature = "camt")]
+			Document::ProprietaryFormatInvestigationV06(_) => Some("camt.035.001.06"),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationResponseV06(_) => Some("camt.036.001.06"),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationRequestV10(_) => Some("camt.037.001.10"),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportRequestV05(_) => Some("camt.038.001.05"),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportV06(_) => Some("camt.039.001.06"),
+			#[cfg(feature = "camt")]
+			Document::FundEstimatedCashForecastReportV04(_) => Some("camt.040.001.04"),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportV04(_) => Some("camt.041.001.04"),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedEstimatedCashForecastReportV04(_) => Some("camt.042.001.04"),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportV04(_) => Some("camt.043.001.04"),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportCancellationV03(_) => Some("camt.044.001.03"),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportCancellationV03(_) => Some("camt.045.001.03"),
+			#[cfg(feature = "camt")]
+			Document::GetReservationV08(_) => Some("camt.046.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ReturnReservationV08(_) => Some("camt.047.001.08"),
+			#[cfg(feature = "camt")]
+			Document::ModifyReservationV07(_) => Some("camt.048.001.07"),
+			#[cfg(feature = "camt")]
+			Document::DeleteReservationV07(_) => Some("camt.049.001.07"),
+			#[cfg(feature = "camt")]
+			Document::LiquidityCreditTransferV07(_) => Some("camt.050.001.07"),
+			#[cfg(feature = "camt")]
+			Document::LiquidityDebitTransferV07(_) => Some("camt.051.001.07"),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerAccountReportV12(_) => Some("camt.052.001.12"),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerStatementV12(_) => Some("camt.053.001.12"),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerDebitCreditNotificationV12(_) => Some("camt.054.001.12"),
+			#[cfg(feature = "camt")]
+			Document::CustomerPaymentCancellationRequestV12(_) => Some("camt.055.001.12"),
+			#[cfg(feature = "camt")]
+			Document::FIToFIPaymentCancellationRequestV11(_) => Some("camt.056.001.11"),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveV08(_) => Some("camt.057.001.08"),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveCancellationAdviceV09(_) => Some("camt.058.001.09"),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveStatusReportV08(_) => Some("camt.059.001.08"),
+			#[cfg(feature = "camt")]
+			Document::AccountReportingRequestV07(_) => Some("camt.060.001.07"),
+			#[cfg(feature = "camt")]
+			Document::PayInCallV02(_) => Some("camt.061.001.02"),
+			#[cfg(feature = "camt")]
+			Document::PayInScheduleV03(_) => Some("camt.062.001.03"),
+			#[cfg(feature = "camt")]
+			Document::PayInEventAcknowledgementV02(_) => Some("camt.063.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementInstructionV02(_) => Some("camt.066.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementStatusAdviceV02(_) => Some("camt.067.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementConfirmationV02(_) => Some("camt.068.001.02"),
+			#[cfg(feature = "camt")]
+			Document::GetStandingOrderV05(_) => Some("camt.069.001.05"),
+			#[cfg(feature = "camt")]
+			Document::ReturnStandingOrderV06(_) => Some("camt.070.001.06"),
+			#[cfg(feature = "camt")]
+			Document::DeleteStandingOrderV05(_) => Some("camt.071.001.05"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestV02(_) => Some("camt.072.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(_) => Some("camt.073.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestV02(_) => Some("camt.074.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(_) => Some("camt.075.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryV02(_) => Some("camt.078.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryResponseV02(_) => Some("camt.079.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationQueryV02(_) => Some("camt.080.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationReportV02(_) => Some("camt.081.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationQueryV02(_) => Some("camt.082.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationReportV02(_) => Some("camt.083.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPostingReportV02(_) => Some("camt.084.001.02"),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPendingReportV02(_) => Some("camt.085.001.02"),
+			#[cfg(feature = "camt")]
+			Document::BankServicesBillingStatementV05(_) => Some("camt.086.001.05"),
+			#[cfg(feature = "camt")]
+			Document::RequestToModifyPaymentV09(_) => Some("camt.087.001.09"),
+			#[cfg(feature = "camt")]
+			Document::NetReportV02(_) => Some("camt.088.001.02"),
+			#[cfg(feature = "camt")]
+			Document::CreateLimitV02(_) => Some("camt.101.001.02"),
+			#[cfg(feature = "camt")]
+			Document::CreateStandingOrderV03(_) => Some("camt.102.001.03"),
+			#[cfg(feature = "camt")]
+			Document::CreateReservationV03(_) => Some("camt.103.001.03"),
+			#[cfg(feature = "camt")]
+			Document::CreateMemberV01(_) => Some("camt.104.001.01"),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentNotificationV02(_) => Some("camt.105.001.02"),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentRequestV02(_) => Some("camt.106.001.02"),
+			#[cfg(feature = "camt")]
+			Document::ChequePresentmentNotificationV02(_) => Some("camt.107.001.02"),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopRequestV02(_) => Some("camt.108.001.02"),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopReportV02(_) => Some("camt.109.001.02"),
+			#[cfg(feature = "camt")]
+			Document::InvestigationRequestV01(_) => Some("camt.110.001.01"),
+			#[cfg(feature = "camt")]
+			Document::InvestigationResponseV01(_) => Some("camt.111.001.01"),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestOpeningV02(_) => Some("auth.001.001.02"),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestResponseV02(_) => Some("auth.002.001.02"),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestStatusChangeNotificationV01(_) => Some("auth.003.001.01"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketSecuredMarketStatisticalReportV02(_) => Some("auth.012.001.02"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(_) => Some("auth.013.001.02"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(_) => Some("auth.014.001.02"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(_) => Some("auth.015.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTransactionReportV03(_) => Some("auth.016.001.03"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataReportV02(_) => Some("auth.017.001.02"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationRequestV04(_) => Some("auth.018.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationConfirmationV04(_) => Some("auth.019.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationClosureRequestV04(_) => Some("auth.020.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationAmendmentRequestV04(_) => Some("auth.021.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementV04(_) => Some("auth.022.001.04"),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementRequestV04(_) => Some("auth.023.001.04"),
+			#[cfg(feature = "auth")]
+			Document::PaymentRegulatoryInformationNotificationV04(_) => Some("auth.024.001.04"),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlSupportingDocumentDeliveryV04(_) => Some("auth.025.001.04"),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlRequestOrLetterV04(_) => Some("auth.026.001.04"),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlStatusAdviceV04(_) => Some("auth.027.001.04"),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketStatisticalReportStatusAdviceV01(_) => Some("auth.028.001.01"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportQueryV05(_) => Some("auth.029.001.05"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportV04(_) => Some("auth.030.001.04"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingStatusAdviceV01(_) => Some("auth.031.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(_) => Some("auth.032.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(_) => Some("auth.033.001.03"),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportV01(_) => Some("auth.034.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(_) => Some("auth.035.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(_) => Some("auth.036.001.03"),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportStatusAdviceV01(_) => Some("auth.038.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonWorkingDayReportV01(_) => Some("auth.039.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(_) => Some("auth.040.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(_) => Some("auth.041.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(_) => Some("auth.042.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(_) => Some("auth.043.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(_) => Some("auth.044.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(_) => Some("auth.045.001.03"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCountryCodeReportV01(_) => Some("auth.047.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCurrencyCodeReportV01(_) => Some("auth.048.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(_) => Some("auth.049.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(_) => Some("auth.050.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReportV02(_) => Some("auth.052.001.02"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(_) => Some("auth.053.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPClearingMemberReportV01(_) => Some("auth.054.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberRequirementsReportV01(_) => Some("auth.055.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberObligationsReportV01(_) => Some("auth.056.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingDefinitionReportV02(_) => Some("auth.057.001.02"),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingResultReportV01(_) => Some("auth.058.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(_) => Some("auth.059.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPDailyCashFlowsReportV02(_) => Some("auth.060.001.02"),
+			#[cfg(feature = "auth")]
+			Document::CCPInvestmentsReportV01(_) => Some("auth.061.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingDefinitionReportV01(_) => Some("auth.062.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingResultReportV01(_) => Some("auth.063.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPAvailableFinancialResourcesReportV01(_) => Some("auth.064.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingDefinitionReportV01(_) => Some("auth.065.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingResultReportV01(_) => Some("auth.066.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPCollateralReportV01(_) => Some("auth.067.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPAccountPositionReportV01(_) => Some("auth.068.001.01"),
+			#[cfg(feature = "auth")]
+			Document::CCPClearedProductReportV01(_) => Some("auth.069.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(_) => Some("auth.070.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(_) => Some("auth.071.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SettlementInternaliserReportV01(_) => Some("auth.072.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialSupervisedPartyIdentityReportV01(_) => Some("auth.076.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialBenchmarkReportV01(_) => Some("auth.077.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPairingRequestV02(_) => Some("auth.078.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStateReportV02(_) => Some("auth.079.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(_) => Some("auth.080.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(_) => Some("auth.083.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(_) => Some("auth.084.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(_) => Some("auth.085.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(_) => Some("auth.086.001.02"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradePositionSetReportV02(_) => Some("auth.090.001.02"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReconciliationStatisticalReportV03(_) => Some("auth.091.001.03"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeRejectionStatisticalReportV04(_) => Some("auth.092.001.04"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionQueryV02(_) => Some("auth.094.001.02"),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsMonthlyReportV01(_) => Some("auth.100.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsAnnualReportV01(_) => Some("auth.101.001.01"),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCancellationReportV01(_) => Some("auth.102.001.01"),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPositionSetReportV01(_) => Some("auth.105.001.01"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeWarningsReportV01(_) => Some("auth.106.001.01"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeStateReportV02(_) => Some("auth.107.001.02"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataReportV02(_) => Some("auth.108.001.02"),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataTransactionStateReportV02(_) => Some("auth.109.001.02"),
+			#[cfg(feature = "auth")]
+			Document::CCPInteroperabilityReportV01(_) => Some("auth.112.001.01"),
+			#[cfg(feature = "auth")]
+			Document::OrderBookReportV01(_) => Some("auth.113.001.01"),
+			Document::UNKNOWN => None,
+        }
+    }
+
+    /// Canonical fingerprint of the message
+    /// (`open_payments_common::fingerprint`), `None` for an unknown document.
+    #[cfg(feature = "derive_serde")]
+    pub fn fingerprint(&self, options: &FingerprintOptions) -> Option<Fingerprint> {
+        let msg_def_idr = self.message_definition_identifier()?;
+        let value = serde_json::to_value(self).ok()?;
+        Some(fingerprint(msg_def_idr, &value, options))
+    }
+
+    /// Walks the message with a visitor (`crate::visitor`).
+    pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+			#[cfg(feature = "admi")]
+			Document::Admi00200101(value) => value.walk(visitor),
+			#[cfg(feature = "admi")]
+			Document::SystemEventNotificationV02(value) => value.walk(visitor),
+			#[cfg(feature = "admi")]
+			Document::ReportQueryRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "admi")]
+			Document::ResendRequestV01(value) => value.walk(visitor),
+			#[cfg(feature = "admi")]
+			Document::ReceiptAcknowledgementV01(value) => value.walk(visitor),
+			#[cfg(feature = "admi")]
+			Document::StaticDataRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "admi")]
+			Document::StaticDataReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "admi")]
+			Document::SystemEventAcknowledgementV01(value) => value.walk(visitor),
+			#[cfg(feature = "admi")]
+			Document::ProcessingRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "admi")]
+			Document::NotificationOfCorrespondenceV01(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV12(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV14(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerDirectDebitV11(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::PaymentReturnV13(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentReversalV13(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerCreditTransferV12(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionCreditTransferV11(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionDirectDebitV06(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusRequestV06(value) => value.walk(visitor),
+			#[cfg(feature = "pacs")]
+			Document::MultilateralSettlementRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::CustomerCreditTransferInitiationV12(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentStatusReportV14(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentReversalV12(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::CustomerDirectDebitInitiationV11(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateInitiationRequestV08(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateAmendmentRequestV08(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateCancellationRequestV08(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateAcceptanceReportV08(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestV11(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestStatusReportV11(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateCopyRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateSuspensionRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningInstructionV08(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountDetailsConfirmationV08(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountModificationInstructionV08(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::RequestForAccountManagementStatusReportV06(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountManagementStatusReportV07(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningRequestV05(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAmendmentRequestV05(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAdditionalInformationRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestAcknowledgementV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestRejectionV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountAdditionalInformationRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportV05(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceAmendmentRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAmendmentRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAdditionalInformationRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationModificationAdviceV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationReportV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationRequestV05(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationResponseV05(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchCancelExistingPaymentV05(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestRedirectionV04(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestBalanceTransferV05(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchBalanceTransferAcknowledgementV05(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchNotifyAccountSwitchCompleteV02(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestPaymentV05(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchPaymentResponseV02(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTerminationSwitchV01(value) => value.walk(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTechnicalRejectionV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PriceReportV04(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PriceReportCancellationV04(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::FundReferenceDataReportV07(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::InvestmentFundReportRequestV03(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationRequestV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceRequestV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationStatusAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityActivityAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityQueryV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionRequestV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyCreationRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyQueryV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyStatusAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountCreationRequestV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountQueryV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountStatusAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyModificationRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountModificationRequestV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceStatusAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionStatusAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyDeletionRequestV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountDeletionRequestV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailQueryV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountActivityAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailQueryV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyActivityAdviceV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailQueryV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionDeletionV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionStatusAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionCancellationV01(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataUpdateRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::CalendarQueryV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::CalendarReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentStatusReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationAmendmentRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationCancellationRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationStatusReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "remt")]
+			Document::RemittanceAdviceV06(value) => value.walk(visitor),
+			#[cfg(feature = "remt")]
+			Document::RemittanceLocationAdviceV03(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetAccountV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnAccountV10(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetTransactionV11(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnTransactionV11(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyTransactionV10(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::CancelTransactionV11(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetLimitV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnLimitV09(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyLimitV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::DeleteLimitV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetMemberV04(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnMemberV05(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyMemberV04(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetCurrencyExchangeRateV04(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnCurrencyExchangeRateV05(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetBusinessDayInformationV05(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnBusinessDayInformationV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetGeneralBusinessInformationV04(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnGeneralBusinessInformationV06(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::BackupPaymentV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyStandingOrderV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReceiptV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::UnableToApplyV10(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ClaimNonReceiptV10(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::AdditionalPaymentInformationV12(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ResolutionOfInvestigationV13(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::NotificationOfCaseAssignmentV06(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::RejectInvestigationV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::CancelCaseAssignmentV05(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::RequestForDuplicateV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::DuplicateV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ProprietaryFormatInvestigationV06(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationResponseV06(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationRequestV10(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportRequestV05(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportV06(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundEstimatedCashForecastReportV04(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportV04(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedEstimatedCashForecastReportV04(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportV04(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportCancellationV03(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportCancellationV03(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetReservationV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnReservationV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyReservationV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::DeleteReservationV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::LiquidityCreditTransferV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::LiquidityDebitTransferV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerAccountReportV12(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerStatementV12(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerDebitCreditNotificationV12(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::CustomerPaymentCancellationRequestV12(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::FIToFIPaymentCancellationRequestV11(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveCancellationAdviceV09(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveStatusReportV08(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::AccountReportingRequestV07(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::PayInCallV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::PayInScheduleV03(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::PayInEventAcknowledgementV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementInstructionV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementStatusAdviceV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementConfirmationV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetStandingOrderV05(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnStandingOrderV06(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::DeleteStandingOrderV05(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryResponseV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationQueryV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationQueryV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPostingReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPendingReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::BankServicesBillingStatementV05(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::RequestToModifyPaymentV09(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::NetReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::CreateLimitV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::CreateStandingOrderV03(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::CreateReservationV03(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::CreateMemberV01(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentNotificationV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChequePresentmentNotificationV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::InvestigationRequestV01(value) => value.walk(visitor),
+			#[cfg(feature = "camt")]
+			Document::InvestigationResponseV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestOpeningV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestResponseV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestStatusChangeNotificationV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketSecuredMarketStatisticalReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTransactionReportV03(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationConfirmationV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationClosureRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationAmendmentRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementRequestV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::PaymentRegulatoryInformationNotificationV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlSupportingDocumentDeliveryV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlRequestOrLetterV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlStatusAdviceV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketStatisticalReportStatusAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportQueryV05(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingStatusAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportStatusAdviceV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonWorkingDayReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCountryCodeReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCurrencyCodeReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPClearingMemberReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberRequirementsReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberObligationsReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingDefinitionReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingResultReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPDailyCashFlowsReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPInvestmentsReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingDefinitionReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingResultReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPAvailableFinancialResourcesReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingDefinitionReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingResultReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPCollateralReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPAccountPositionReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPClearedProductReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SettlementInternaliserReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialSupervisedPartyIdentityReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialBenchmarkReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPairingRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStateReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradePositionSetReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReconciliationStatisticalReportV03(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeRejectionStatisticalReportV04(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionQueryV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsMonthlyReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsAnnualReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCancellationReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPositionSetReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeWarningsReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeStateReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataTransactionStateReportV02(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPInteroperabilityReportV01(value) => value.walk(visitor),
+			#[cfg(feature = "auth")]
+			Document::OrderBookReportV01(value) => value.walk(visitor),
+			Document::UNKNOWN => {}
+        }
+    }
+
+    pub fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+			#[cfg(feature = "admi")]
+			Document::Admi00200101(value) => value.walk_mut(visitor),
+			#[cfg(feature = "admi")]
+			Document::SystemEventNotificationV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "admi")]
+			Document::ReportQueryRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "admi")]
+			Document::ResendRequestV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "admi")]
+			Document::ReceiptAcknowledgementV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "admi")]
+			Document::StaticDataRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "admi")]
+			Document::StaticDataReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "admi")]
+			Document::SystemEventAcknowledgementV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "admi")]
+			Document::ProcessingRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "admi")]
+			Document::NotificationOfCorrespondenceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV12(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV14(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerDirectDebitV11(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::PaymentReturnV13(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentReversalV13(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerCreditTransferV12(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionCreditTransferV11(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionDirectDebitV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusRequestV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pacs")]
+			Document::MultilateralSettlementRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::CustomerCreditTransferInitiationV12(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentStatusReportV14(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentReversalV12(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::CustomerDirectDebitInitiationV11(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateInitiationRequestV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateAmendmentRequestV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateCancellationRequestV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateAcceptanceReportV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestV11(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestStatusReportV11(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateCopyRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "pain")]
+			Document::MandateSuspensionRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningInstructionV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountDetailsConfirmationV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountModificationInstructionV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::RequestForAccountManagementStatusReportV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountManagementStatusReportV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningRequestV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAmendmentRequestV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAdditionalInformationRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestAcknowledgementV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestRejectionV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountAdditionalInformationRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceAmendmentRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAmendmentRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAdditionalInformationRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationModificationAdviceV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationReportV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationRequestV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationResponseV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchCancelExistingPaymentV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestRedirectionV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestBalanceTransferV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchBalanceTransferAcknowledgementV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchNotifyAccountSwitchCompleteV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestPaymentV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchPaymentResponseV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTerminationSwitchV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTechnicalRejectionV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PriceReportV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PriceReportCancellationV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::FundReferenceDataReportV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::InvestmentFundReportRequestV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationRequestV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceRequestV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationStatusAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityActivityAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityQueryV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionRequestV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyCreationRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyQueryV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyStatusAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountCreationRequestV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountQueryV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountStatusAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyModificationRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountModificationRequestV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceStatusAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionStatusAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyDeletionRequestV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountDeletionRequestV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailQueryV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountActivityAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailQueryV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyActivityAdviceV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailQueryV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionDeletionV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionStatusAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionCancellationV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataUpdateRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::CalendarQueryV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::CalendarReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentStatusReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationAmendmentRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationCancellationRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationStatusReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "remt")]
+			Document::RemittanceAdviceV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "remt")]
+			Document::RemittanceLocationAdviceV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetAccountV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnAccountV10(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetTransactionV11(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnTransactionV11(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyTransactionV10(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::CancelTransactionV11(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetLimitV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnLimitV09(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyLimitV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::DeleteLimitV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetMemberV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnMemberV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyMemberV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetCurrencyExchangeRateV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnCurrencyExchangeRateV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetBusinessDayInformationV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnBusinessDayInformationV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetGeneralBusinessInformationV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnGeneralBusinessInformationV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::BackupPaymentV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyStandingOrderV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReceiptV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::UnableToApplyV10(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ClaimNonReceiptV10(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::AdditionalPaymentInformationV12(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ResolutionOfInvestigationV13(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::NotificationOfCaseAssignmentV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::RejectInvestigationV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::CancelCaseAssignmentV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::RequestForDuplicateV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::DuplicateV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ProprietaryFormatInvestigationV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationResponseV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationRequestV10(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportRequestV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundEstimatedCashForecastReportV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedEstimatedCashForecastReportV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportCancellationV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportCancellationV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetReservationV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnReservationV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ModifyReservationV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::DeleteReservationV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::LiquidityCreditTransferV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::LiquidityDebitTransferV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerAccountReportV12(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerStatementV12(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerDebitCreditNotificationV12(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::CustomerPaymentCancellationRequestV12(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::FIToFIPaymentCancellationRequestV11(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveCancellationAdviceV09(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveStatusReportV08(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::AccountReportingRequestV07(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::PayInCallV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::PayInScheduleV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::PayInEventAcknowledgementV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementInstructionV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementStatusAdviceV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementConfirmationV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::GetStandingOrderV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ReturnStandingOrderV06(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::DeleteStandingOrderV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryResponseV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationQueryV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationQueryV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPostingReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPendingReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::BankServicesBillingStatementV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::RequestToModifyPaymentV09(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::NetReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::CreateLimitV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::CreateStandingOrderV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::CreateReservationV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::CreateMemberV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentNotificationV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChequePresentmentNotificationV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::InvestigationRequestV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "camt")]
+			Document::InvestigationResponseV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestOpeningV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestResponseV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestStatusChangeNotificationV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketSecuredMarketStatisticalReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTransactionReportV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationConfirmationV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationClosureRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationAmendmentRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementRequestV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::PaymentRegulatoryInformationNotificationV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlSupportingDocumentDeliveryV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlRequestOrLetterV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlStatusAdviceV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketStatisticalReportStatusAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportQueryV05(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingStatusAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportStatusAdviceV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonWorkingDayReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCountryCodeReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCurrencyCodeReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPClearingMemberReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberRequirementsReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberObligationsReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingDefinitionReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingResultReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPDailyCashFlowsReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPInvestmentsReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingDefinitionReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingResultReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPAvailableFinancialResourcesReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingDefinitionReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingResultReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPCollateralReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPAccountPositionReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPClearedProductReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SettlementInternaliserReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialSupervisedPartyIdentityReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialBenchmarkReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPairingRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStateReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradePositionSetReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReconciliationStatisticalReportV03(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeRejectionStatisticalReportV04(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionQueryV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsMonthlyReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsAnnualReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCancellationReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPositionSetReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeWarningsReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeStateReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataTransactionStateReportV02(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::CCPInteroperabilityReportV01(value) => value.walk_mut(visitor),
+			#[cfg(feature = "auth")]
+			Document::OrderBookReportV01(value) => value.walk_mut(visitor),
+			Document::UNKNOWN => {}
+        }
+    }
+}
+
+/// Paths of a document start at its message element.
+#[cfg(feature = "derive_default")]
+impl TagPaths for Document {
+    fn children<'a>(&'a self, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+        let message: Option<(&str, &'a dyn TagPaths)> = match self {
+			#[cfg(feature = "admi")]
+			Document::Admi00200101(value) => Some(("admi.002.001.01", &**value)),
+			#[cfg(feature = "admi")]
+			Document::SystemEventNotificationV02(value) => Some(("SysEvtNtfctn", &**value)),
+			#[cfg(feature = "admi")]
+			Document::ReportQueryRequestV02(value) => Some(("RptQryReq", &**value)),
+			#[cfg(feature = "admi")]
+			Document::ResendRequestV01(value) => Some(("RsndReq", &**value)),
+			#[cfg(feature = "admi")]
+			Document::ReceiptAcknowledgementV01(value) => Some(("RctAck", &**value)),
+			#[cfg(feature = "admi")]
+			Document::StaticDataRequestV02(value) => Some(("StatcDataReq", &**value)),
+			#[cfg(feature = "admi")]
+			Document::StaticDataReportV02(value) => Some(("StatcDataRpt", &**value)),
+			#[cfg(feature = "admi")]
+			Document::SystemEventAcknowledgementV01(value) => Some(("SysEvtAck", &**value)),
+			#[cfg(feature = "admi")]
+			Document::ProcessingRequestV02(value) => Some(("PrcgReq", &**value)),
+			#[cfg(feature = "admi")]
+			Document::NotificationOfCorrespondenceV01(value) => Some(("NtfctnOfCrspdc", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV12(value) => Some(("FIToFIPmtStsRpt", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV14(value) => Some(("FIToFIPmtStsRpt", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerDirectDebitV11(value) => Some(("FIToFICstmrDrctDbt", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::PaymentReturnV13(value) => Some(("PmtRtr", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentReversalV13(value) => Some(("FIToFIPmtRvsl", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerCreditTransferV12(value) => Some(("FIToFICstmrCdtTrf", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionCreditTransferV11(value) => Some(("FICdtTrf", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionDirectDebitV06(value) => Some(("FIDrctDbt", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusRequestV06(value) => Some(("FIToFIPmtStsReq", &**value)),
+			#[cfg(feature = "pacs")]
+			Document::MultilateralSettlementRequestV02(value) => Some(("MulSttlmReq", &**value)),
+			#[cfg(feature = "pain")]
+			Document::CustomerCreditTransferInitiationV12(value) => Some(("CstmrCdtTrfInitn", &**value)),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentStatusReportV14(value) => Some(("CstmrPmtStsRpt", &**value)),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentReversalV12(value) => Some(("CstmrPmtRvsl", &**value)),
+			#[cfg(feature = "pain")]
+			Document::CustomerDirectDebitInitiationV11(value) => Some(("CstmrDrctDbtInitn", &**value)),
+			#[cfg(feature = "pain")]
+			Document::MandateInitiationRequestV08(value) => Some(("MndtInitnReq", &**value)),
+			#[cfg(feature = "pain")]
+			Document::MandateAmendmentRequestV08(value) => Some(("MndtAmdmntReq", &**value)),
+			#[cfg(feature = "pain")]
+			Document::MandateCancellationRequestV08(value) => Some(("MndtCxlReq", &**value)),
+			#[cfg(feature = "pain")]
+			Document::MandateAcceptanceReportV08(value) => Some(("MndtAccptncRpt", &**value)),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestV11(value) => Some(("CdtrPmtActvtnReq", &**value)),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestStatusReportV11(value) => Some(("CdtrPmtActvtnReqStsRpt", &**value)),
+			#[cfg(feature = "pain")]
+			Document::MandateCopyRequestV04(value) => Some(("MndtCpyReq", &**value)),
+			#[cfg(feature = "pain")]
+			Document::MandateSuspensionRequestV04(value) => Some(("MndtSspnsnReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningInstructionV08(value) => Some(("AcctOpngInstr", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountDetailsConfirmationV08(value) => Some(("AcctDtlsConf", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountModificationInstructionV08(value) => Some(("AcctModInstr", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::RequestForAccountManagementStatusReportV06(value) => Some(("ReqForAcctMgmtStsRpt", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountManagementStatusReportV07(value) => Some(("AcctMgmtStsRpt", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningRequestV05(value) => Some(("AcctOpngReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAmendmentRequestV05(value) => Some(("AcctOpngAmdmntReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAdditionalInformationRequestV04(value) => Some(("AcctOpngAddtlInfReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestAcknowledgementV04(value) => Some(("AcctReqAck", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestRejectionV04(value) => Some(("AcctReqRjctn", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountAdditionalInformationRequestV04(value) => Some(("AcctAddtlInfReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportRequestV04(value) => Some(("AcctRptReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportV05(value) => Some(("AcctRpt", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceRequestV04(value) => Some(("AcctExcldMndtMntncReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(value) => Some(("AcctExcldMndtMntncAmdmntReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceRequestV04(value) => Some(("AcctMndtMntncReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceAmendmentRequestV04(value) => Some(("AcctMndtMntncAmdmntReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingRequestV04(value) => Some(("AcctClsgReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAmendmentRequestV04(value) => Some(("AcctClsgAmdmntReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAdditionalInformationRequestV04(value) => Some(("AcctClsgAddtlInfReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationModificationAdviceV04(value) => Some(("IdModAdvc", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationRequestV04(value) => Some(("IdVrfctnReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationReportV04(value) => Some(("IdVrfctnRpt", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationRequestV05(value) => Some(("AcctSwtchInfReq", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationResponseV05(value) => Some(("AcctSwtchInfRspn", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchCancelExistingPaymentV05(value) => Some(("AcctSwtchCclExstgPmt", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestRedirectionV04(value) => Some(("AcctSwtchReqRdrctn", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestBalanceTransferV05(value) => Some(("AcctSwtchReqBalTrf", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchBalanceTransferAcknowledgementV05(value) => Some(("AcctSwtchBalTrfAck", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchNotifyAccountSwitchCompleteV02(value) => Some(("AcctSwtchNtfyAcctSwtchCmplt", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestPaymentV05(value) => Some(("AcctSwtchReqPmt", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchPaymentResponseV02(value) => Some(("AcctSwtchPmtRspn", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTerminationSwitchV01(value) => Some(("AcctSwtchTermntnSwtch", &**value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTechnicalRejectionV02(value) => Some(("AcctSwtchTechRjctn", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PriceReportV04(value) => Some(("PricRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PriceReportCancellationV04(value) => Some(("PricRptCxl", &**value)),
+			#[cfg(feature = "reda")]
+			Document::FundReferenceDataReportV07(value) => Some(("FndRefDataRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::InvestmentFundReportRequestV03(value) => Some(("InvstmtFndRptReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationRequestV01(value) => Some(("SctyCreReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceRequestV01(value) => Some(("SctyMntncReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationStatusAdviceV01(value) => Some(("SctyCreStsAdvc", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityActivityAdviceV01(value) => Some(("SctyActvtyAdvc", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityQueryV01(value) => Some(("SctyQry", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityReportV01(value) => Some(("SctyRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionRequestV01(value) => Some(("SctyDeltnReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PartyCreationRequestV02(value) => Some(("PtyCreReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PartyQueryV01(value) => Some(("PtyQry", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PartyStatusAdviceV01(value) => Some(("PtyStsAdvc", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PartyReportV02(value) => Some(("PtyRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountCreationRequestV01(value) => Some(("SctiesAcctCreReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountQueryV01(value) => Some(("SctiesAcctQry", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountStatusAdviceV01(value) => Some(("SctiesAcctStsAdvc", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountReportV01(value) => Some(("SctiesAcctRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PartyModificationRequestV02(value) => Some(("PtyModReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountModificationRequestV01(value) => Some(("SctiesAcctModReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceStatusAdviceV01(value) => Some(("SctyMntncStsAdvc", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionStatusAdviceV01(value) => Some(("SctyDeltnStsAdvc", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PartyDeletionRequestV01(value) => Some(("PtyDeltnReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountDeletionRequestV01(value) => Some(("SctiesAcctDeltnReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailQueryV01(value) => Some(("SctiesAudtTrlQry", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailReportV01(value) => Some(("SctiesAudtTrlRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountActivityAdviceV01(value) => Some(("SctiesAcctActvtyAdvc", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailQueryV01(value) => Some(("SctiesAcctAudtTrlQry", &**value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailReportV01(value) => Some(("SctiesAcctAudtTrlRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PartyActivityAdviceV02(value) => Some(("PtyActvtyAdvc", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailQueryV01(value) => Some(("PtyAudtTrlQry", &**value)),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailReportV02(value) => Some(("PtyAudtTrlRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionV01(value) => Some(("StgSttlmInstr", &**value)),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionDeletionV01(value) => Some(("StgSttlmInstrDeltn", &**value)),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionStatusAdviceV01(value) => Some(("StgSttlmInstrStsAdvc", &**value)),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionCancellationV01(value) => Some(("StgSttlmInstrCxl", &**value)),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataUpdateRequestV02(value) => Some(("NetgCutOffRefDataUpdReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataReportV02(value) => Some(("NetgCutOffRefDataRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::CalendarQueryV02(value) => Some(("CalQry", &**value)),
+			#[cfg(feature = "reda")]
+			Document::CalendarReportV02(value) => Some(("CalRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentRequestV02(value) => Some(("ReqToPayCdtrEnrlmntReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(value) => Some(("ReqToPayCdtrEnrlmntAmdmntReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(value) => Some(("ReqToPayCdtrEnrlmntCxlReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentStatusReportV02(value) => Some(("ReqToPayCdtrEnrlmntStsRpt", &**value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationRequestV02(value) => Some(("ReqToPayDbtrActvtnReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationAmendmentRequestV02(value) => Some(("ReqToPayDbtrActvtnAmdmntReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationCancellationRequestV02(value) => Some(("ReqToPayDbtrActvtnCxlReq", &**value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationStatusReportV02(value) => Some(("ReqToPayDbtrActvtnStsRpt", &**value)),
+			#[cfg(feature = "remt")]
+			Document::RemittanceAdviceV06(value) => Some(("RmtAdvc", &**value)),
+			#[cfg(feature = "remt")]
+			Document::RemittanceLocationAdviceV03(value) => Some(("RmtLctnAdvc", &**value)),
+			#[cfg(feature = "camt")]
+			Document::GetAccountV08(value) => Some(("GetAcct", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnAccountV10(value) => Some(("RtrAcct", &**value)),
+			#[cfg(feature = "camt")]
+			Document::GetTransactionV11(value) => Some(("GetTx", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnTransactionV11(value) => Some(("RtrTx", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyTransactionV10(value) => Some(("ModfyTx", &**value)),
+			#[cfg(feature = "camt")]
+			Document::CancelTransactionV11(value) => Some(("CclTx", &**value)),
+			#[cfg(feature = "camt")]
+			Document::GetLimitV08(value) => Some(("GetLmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnLimitV09(value) => Some(("RtrLmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyLimitV08(value) => Some(("ModfyLmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::DeleteLimitV08(value) => Some(("DelLmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::GetMemberV04(value) => Some(("GetMmb", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnMemberV05(value) => Some(("RtrMmb", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyMemberV04(value) => Some(("ModfyMmb", &**value)),
+			#[cfg(feature = "camt")]
+			Document::GetCurrencyExchangeRateV04(value) => Some(("GetCcyXchgRate", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnCurrencyExchangeRateV05(value) => Some(("RtrCcyXchgRate", &**value)),
+			#[cfg(feature = "camt")]
+			Document::GetBusinessDayInformationV05(value) => Some(("GetBizDayInf", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnBusinessDayInformationV07(value) => Some(("RtrBizDayInf", &**value)),
+			#[cfg(feature = "camt")]
+			Document::GetGeneralBusinessInformationV04(value) => Some(("GetGnlBizInf", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnGeneralBusinessInformationV06(value) => Some(("RtrGnlBizInf", &**value)),
+			#[cfg(feature = "camt")]
+			Document::BackupPaymentV07(value) => Some(("BckpPmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyStandingOrderV08(value) => Some(("ModfyStgOrdr", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReceiptV08(value) => Some(("Rct", &**value)),
+			#[cfg(feature = "camt")]
+			Document::UnableToApplyV10(value) => Some(("UblToApply", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ClaimNonReceiptV10(value) => Some(("ClmNonRct", &**value)),
+			#[cfg(feature = "camt")]
+			Document::AdditionalPaymentInformationV12(value) => Some(("AddtlPmtInf", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ResolutionOfInvestigationV13(value) => Some(("RsltnOfInvstgtn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::NotificationOfCaseAssignmentV06(value) => Some(("NtfctnOfCaseAssgnmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::RejectInvestigationV07(value) => Some(("RjctInvstgtn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::CancelCaseAssignmentV05(value) => Some(("CclCaseAssgnmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::RequestForDuplicateV07(value) => Some(("ReqForDplct", &**value)),
+			#[cfg(feature = "camt")]
+			Document::DuplicateV07(value) => Some(("Dplct", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ProprietaryFormatInvestigationV06(value) => Some(("PrtryFrmtInvstgtn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationResponseV06(value) => Some(("DbtAuthstnRspn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationRequestV10(value) => Some(("DbtAuthstnReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportRequestV05(value) => Some(("CaseStsRptReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportV06(value) => Some(("CaseStsRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::FundEstimatedCashForecastReportV04(value) => Some(("FndEstmtdCshFcstRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportV04(value) => Some(("FndConfdCshFcstRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedEstimatedCashForecastReportV04(value) => Some(("FndDtldEstmtdCshFcstRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportV04(value) => Some(("FndDtldConfdCshFcstRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportCancellationV03(value) => Some(("FndConfdCshFcstRptCxl", &**value)),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportCancellationV03(value) => Some(("FndDtldConfdCshFcstRptCxl", &**value)),
+			#[cfg(feature = "camt")]
+			Document::GetReservationV08(value) => Some(("GetRsvatn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnReservationV08(value) => Some(("RtrRsvatn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyReservationV07(value) => Some(("ModfyRsvatn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::DeleteReservationV07(value) => Some(("DelRsvatn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::LiquidityCreditTransferV07(value) => Some(("LqdtyCdtTrf", &**value)),
+			#[cfg(feature = "camt")]
+			Document::LiquidityDebitTransferV07(value) => Some(("LqdtyDbtTrf", &**value)),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerAccountReportV12(value) => Some(("BkToCstmrAcctRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerStatementV12(value) => Some(("BkToCstmrStmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerDebitCreditNotificationV12(value) => Some(("BkToCstmrDbtCdtNtfctn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::CustomerPaymentCancellationRequestV12(value) => Some(("CstmrPmtCxlReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::FIToFIPaymentCancellationRequestV11(value) => Some(("FIToFIPmtCxlReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveV08(value) => Some(("NtfctnToRcv", &**value)),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveCancellationAdviceV09(value) => Some(("NtfctnToRcvCxlAdvc", &**value)),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveStatusReportV08(value) => Some(("NtfctnToRcvStsRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::AccountReportingRequestV07(value) => Some(("AcctRptgReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::PayInCallV02(value) => Some(("PayInCall", &**value)),
+			#[cfg(feature = "camt")]
+			Document::PayInScheduleV03(value) => Some(("PayInSchdl", &**value)),
+			#[cfg(feature = "camt")]
+			Document::PayInEventAcknowledgementV02(value) => Some(("PayInEvtAck", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementInstructionV02(value) => Some(("IntraBalMvmntInstr", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementStatusAdviceV02(value) => Some(("IntraBalMvmntStsAdvc", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementConfirmationV02(value) => Some(("IntraBalMvmntConf", &**value)),
+			#[cfg(feature = "camt")]
+			Document::GetStandingOrderV05(value) => Some(("GetStgOrdr", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnStandingOrderV06(value) => Some(("RtrStgOrdr", &**value)),
+			#[cfg(feature = "camt")]
+			Document::DeleteStandingOrderV05(value) => Some(("DelStgOrdr", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestV02(value) => Some(("IntraBalMvmntModReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(value) => Some(("IntraBalMvmntModReqStsAdvc", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestV02(value) => Some(("IntraBalMvmntCxlReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(value) => Some(("IntraBalMvmntCxlReqStsAdvc", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryV02(value) => Some(("IntraBalMvmntQry", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryResponseV02(value) => Some(("IntraBalMvmntQryRspn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationQueryV02(value) => Some(("IntraBalMvmntModQry", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationReportV02(value) => Some(("IntraBalMvmntModRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationQueryV02(value) => Some(("IntraBalMvmntCxlQry", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationReportV02(value) => Some(("IntraBalMvmntCxlRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPostingReportV02(value) => Some(("IntraBalMvmntPstngRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPendingReportV02(value) => Some(("IntraBalMvmntPdgRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::BankServicesBillingStatementV05(value) => Some(("BkSvcsBllgStmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::RequestToModifyPaymentV09(value) => Some(("ReqToModfyPmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::NetReportV02(value) => Some(("NetRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::CreateLimitV02(value) => Some(("CretLmt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::CreateStandingOrderV03(value) => Some(("CretStgOrdr", &**value)),
+			#[cfg(feature = "camt")]
+			Document::CreateReservationV03(value) => Some(("CretRsvatn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::CreateMemberV01(value) => Some(("CretMmb", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentNotificationV02(value) => Some(("ChrgsPmtNtfctn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentRequestV02(value) => Some(("ChrgsPmtReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ChequePresentmentNotificationV02(value) => Some(("ChqPresntmntNtfctn", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopRequestV02(value) => Some(("ChqCxlOrStopReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopReportV02(value) => Some(("ChqCxlOrStopRpt", &**value)),
+			#[cfg(feature = "camt")]
+			Document::InvestigationRequestV01(value) => Some(("InvstgtnReq", &**value)),
+			#[cfg(feature = "camt")]
+			Document::InvestigationResponseV01(value) => Some(("InvstgtnRspn", &**value)),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestOpeningV02(value) => Some(("InfReqOpng", &**value)),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestResponseV02(value) => Some(("InfReqRspn", &**value)),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestStatusChangeNotificationV01(value) => Some(("InfReqStsChngNtfctn", &**value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketSecuredMarketStatisticalReportV02(value) => Some(("MnyMktScrdMktSttstclRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(value) => Some(("MnyMktUscrdMktSttstclRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(value) => Some(("MnyMktFXSwpsSttstclRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(value) => Some(("MnyMktOvrnghtIndxSwpsSttstclRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTransactionReportV03(value) => Some(("FinInstrmRptgTxRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataReportV02(value) => Some(("FinInstrmRptgRefDataRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationRequestV04(value) => Some(("CtrctRegnReq", &**value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationConfirmationV04(value) => Some(("CtrctRegnConf", &**value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationClosureRequestV04(value) => Some(("CtrctRegnClsrReq", &**value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationAmendmentRequestV04(value) => Some(("CtrctRegnAmdmntReq", &**value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementV04(value) => Some(("CtrctRegnStmt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementRequestV04(value) => Some(("CtrctRegnStmtReq", &**value)),
+			#[cfg(feature = "auth")]
+			Document::PaymentRegulatoryInformationNotificationV04(value) => Some(("PmtRgltryInfNtfctn", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlSupportingDocumentDeliveryV04(value) => Some(("CcyCtrlSpprtgDocDlvry", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlRequestOrLetterV04(value) => Some(("CcyCtrlReqOrLttr", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlStatusAdviceV04(value) => Some(("CcyCtrlStsAdvc", &**value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketStatisticalReportStatusAdviceV01(value) => Some(("MnyMktSttstclRptStsAdvc", &**value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportQueryV05(value) => Some(("DerivsTradRptQry", &**value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportV04(value) => Some(("DerivsTradRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingStatusAdviceV01(value) => Some(("FinInstrmRptgStsAdvc", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(value) => Some(("FinInstrmRptgEqtyTrnsprncyDataRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(value) => Some(("FinInstrmRptgNonEqtyTrnsprncyDataRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportV01(value) => Some(("InvcTaxRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(value) => Some(("FinInstrmRptgTradgVolCapDataRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(value) => Some(("FinInstrmRptgRefDataDltaRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportStatusAdviceV01(value) => Some(("InvcTaxRptStsAdvc", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonWorkingDayReportV01(value) => Some(("FinInstrmRptgNonWorkgDayRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(value) => Some(("FinInstrmRptgEqtyTradgActvtyRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(value) => Some(("FinInstrmRptgNonEqtyTradgActvtyRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(value) => Some(("FinInstrmRptgInvldRefDataRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(value) => Some(("FinInstrmRptgRefDataIndxRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(value) => Some(("FinInstrmRptgEqtyTradgActvtyRslt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(value) => Some(("FinInstrmRptgNonEqtyTradgActvtyRslt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCountryCodeReportV01(value) => Some(("FinInstrmRptgCtryCdRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCurrencyCodeReportV01(value) => Some(("FinInstrmRptgCcyCdRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(value) => Some(("FinInstrmRptgMktIdCdRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(value) => Some(("FinInstrmRptgInstrmClssfctnRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReportV02(value) => Some(("SctiesFincgRptgTxRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(value) => Some(("FinInstrmRptgTradgVolCapRsltRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPClearingMemberReportV01(value) => Some(("CCPClrMmbRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberRequirementsReportV01(value) => Some(("CCPMmbRqrmntsRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberObligationsReportV01(value) => Some(("CCPMmbOblgtnsRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingDefinitionReportV02(value) => Some(("CCPPrtflStrssTstgDefRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingResultReportV01(value) => Some(("CCPPrtflStrssTstgRsltRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(value) => Some(("CCPIncmStmtAndCptlAdqcyRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPDailyCashFlowsReportV02(value) => Some(("CCPDalyCshFlowsRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPInvestmentsReportV01(value) => Some(("CCPInvstmtsRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingDefinitionReportV01(value) => Some(("CCPLqdtyStrssTstgDefRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingResultReportV01(value) => Some(("CCPLqdtyStrssTstgRsltRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPAvailableFinancialResourcesReportV01(value) => Some(("CCPAvlblFinRsrcsRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingDefinitionReportV01(value) => Some(("CCPBckTstgDefRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingResultReportV01(value) => Some(("CCPBckTstgRsltRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPCollateralReportV01(value) => Some(("CCPCollRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPAccountPositionReportV01(value) => Some(("CCPAcctPosRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPClearedProductReportV01(value) => Some(("CCPClrdPdctRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(value) => Some(("SctiesFincgRptgTxMrgnDataRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(value) => Some(("SctiesFincgRptgTxReusdCollDataRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SettlementInternaliserReportV01(value) => Some(("SttlmIntlrRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialSupervisedPartyIdentityReportV01(value) => Some(("FinSprvsdPtyIdntyRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialBenchmarkReportV01(value) => Some(("FinBchmkRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPairingRequestV02(value) => Some(("SctiesFincgRptgPairgReq", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStateReportV02(value) => Some(("SctiesFincgRptgTxStatRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(value) => Some(("SctiesFincgRptgRcncltnStsAdvc", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(value) => Some(("SctiesFincgRptgMssngCollReq", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(value) => Some(("SctiesFincgRptgTxStsAdvc", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(value) => Some(("SctiesFincgRptgMrgnDataTxStatRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(value) => Some(("SctiesFincgRptgReusdCollDataTxStatRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradePositionSetReportV02(value) => Some(("DerivsTradPosSetRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReconciliationStatisticalReportV03(value) => Some(("DerivsTradRcncltnSttstclRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeRejectionStatisticalReportV04(value) => Some(("DerivsTradRjctnSttstclRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionQueryV02(value) => Some(("SctiesFincgRptgTxQry", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsMonthlyReportV01(value) => Some(("SttlmFlsMnthlyRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsAnnualReportV01(value) => Some(("SttlmFlsAnlRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCancellationReportV01(value) => Some(("FinInstrmRptgCxlRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPositionSetReportV01(value) => Some(("SctiesFincgRptgPosSetRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeWarningsReportV01(value) => Some(("DerivsTradWrnngsRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeStateReportV02(value) => Some(("DerivsTradStatRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataReportV02(value) => Some(("DerivsTradMrgnDataRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataTransactionStateReportV02(value) => Some(("DerivsTradMrgnDataTxStatRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::CCPInteroperabilityReportV01(value) => Some(("CCPIntrprbltyRpt", &**value)),
+			#[cfg(feature = "auth")]
+			Document::OrderBookReportV01(value) => Some(("OrdrBookRpt", &**value)),
+			Document::UNKNOWN => None,
+        };
+        if let Some((tag, message)) = message {
+            if step.matches(tag) && step.first() {
+                out.push(message);
+            }
+        }
+    }
+
+    fn children_mut<'a>(&'a mut self, step: &Step, _create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+        let message: Option<(&str, &'a mut dyn TagPaths)> = match self {
+			#[cfg(feature = "admi")]
+			Document::Admi00200101(value) => Some(("admi.002.001.01", &mut **value)),
+			#[cfg(feature = "admi")]
+			Document::SystemEventNotificationV02(value) => Some(("SysEvtNtfctn", &mut **value)),
+			#[cfg(feature = "admi")]
+			Document::ReportQueryRequestV02(value) => Some(("RptQryReq", &mut **value)),
+			#[cfg(feature = "admi")]
+			Document::ResendRequestV01(value) => Some(("RsndReq", &mut **value)),
+			#[cfg(feature = "admi")]
+			Document::ReceiptAcknowledgementV01(value) => Some(("RctAck", &mut **value)),
+			#[cfg(feature = "admi")]
+			Document::StaticDataRequestV02(value) => Some(("StatcDataReq", &mut **value)),
+			#[cfg(feature = "admi")]
+			Document::StaticDataReportV02(value) => Some(("StatcDataRpt", &mut **value)),
+			#[cfg(feature = "admi")]
+			Document::SystemEventAcknowledgementV01(value) => Some(("SysEvtAck", &mut **value)),
+			#[cfg(feature = "admi")]
+			Document::ProcessingRequestV02(value) => Some(("PrcgReq", &mut **value)),
+			#[cfg(feature = "admi")]
+			Document::NotificationOfCorrespondenceV01(value) => Some(("NtfctnOfCrspdc", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV12(value) => Some(("FIToFIPmtStsRpt", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusReportV14(value) => Some(("FIToFIPmtStsRpt", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerDirectDebitV11(value) => Some(("FIToFICstmrDrctDbt", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::PaymentReturnV13(value) => Some(("PmtRtr", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentReversalV13(value) => Some(("FIToFIPmtRvsl", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFICustomerCreditTransferV12(value) => Some(("FIToFICstmrCdtTrf", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionCreditTransferV11(value) => Some(("FICdtTrf", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::FinancialInstitutionDirectDebitV06(value) => Some(("FIDrctDbt", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::FIToFIPaymentStatusRequestV06(value) => Some(("FIToFIPmtStsReq", &mut **value)),
+			#[cfg(feature = "pacs")]
+			Document::MultilateralSettlementRequestV02(value) => Some(("MulSttlmReq", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::CustomerCreditTransferInitiationV12(value) => Some(("CstmrCdtTrfInitn", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentStatusReportV14(value) => Some(("CstmrPmtStsRpt", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::CustomerPaymentReversalV12(value) => Some(("CstmrPmtRvsl", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::CustomerDirectDebitInitiationV11(value) => Some(("CstmrDrctDbtInitn", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::MandateInitiationRequestV08(value) => Some(("MndtInitnReq", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::MandateAmendmentRequestV08(value) => Some(("MndtAmdmntReq", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::MandateCancellationRequestV08(value) => Some(("MndtCxlReq", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::MandateAcceptanceReportV08(value) => Some(("MndtAccptncRpt", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestV11(value) => Some(("CdtrPmtActvtnReq", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::CreditorPaymentActivationRequestStatusReportV11(value) => Some(("CdtrPmtActvtnReqStsRpt", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::MandateCopyRequestV04(value) => Some(("MndtCpyReq", &mut **value)),
+			#[cfg(feature = "pain")]
+			Document::MandateSuspensionRequestV04(value) => Some(("MndtSspnsnReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningInstructionV08(value) => Some(("AcctOpngInstr", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountDetailsConfirmationV08(value) => Some(("AcctDtlsConf", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountModificationInstructionV08(value) => Some(("AcctModInstr", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::RequestForAccountManagementStatusReportV06(value) => Some(("ReqForAcctMgmtStsRpt", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountManagementStatusReportV07(value) => Some(("AcctMgmtStsRpt", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningRequestV05(value) => Some(("AcctOpngReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAmendmentRequestV05(value) => Some(("AcctOpngAmdmntReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountOpeningAdditionalInformationRequestV04(value) => Some(("AcctOpngAddtlInfReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestAcknowledgementV04(value) => Some(("AcctReqAck", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountRequestRejectionV04(value) => Some(("AcctReqRjctn", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountAdditionalInformationRequestV04(value) => Some(("AcctAddtlInfReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportRequestV04(value) => Some(("AcctRptReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountReportV05(value) => Some(("AcctRpt", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceRequestV04(value) => Some(("AcctExcldMndtMntncReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountExcludedMandateMaintenanceAmendmentRequestV04(value) => Some(("AcctExcldMndtMntncAmdmntReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceRequestV04(value) => Some(("AcctMndtMntncReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountMandateMaintenanceAmendmentRequestV04(value) => Some(("AcctMndtMntncAmdmntReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingRequestV04(value) => Some(("AcctClsgReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAmendmentRequestV04(value) => Some(("AcctClsgAmdmntReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountClosingAdditionalInformationRequestV04(value) => Some(("AcctClsgAddtlInfReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationModificationAdviceV04(value) => Some(("IdModAdvc", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationRequestV04(value) => Some(("IdVrfctnReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::IdentificationVerificationReportV04(value) => Some(("IdVrfctnRpt", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationRequestV05(value) => Some(("AcctSwtchInfReq", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchInformationResponseV05(value) => Some(("AcctSwtchInfRspn", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchCancelExistingPaymentV05(value) => Some(("AcctSwtchCclExstgPmt", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestRedirectionV04(value) => Some(("AcctSwtchReqRdrctn", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestBalanceTransferV05(value) => Some(("AcctSwtchReqBalTrf", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchBalanceTransferAcknowledgementV05(value) => Some(("AcctSwtchBalTrfAck", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchNotifyAccountSwitchCompleteV02(value) => Some(("AcctSwtchNtfyAcctSwtchCmplt", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchRequestPaymentV05(value) => Some(("AcctSwtchReqPmt", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchPaymentResponseV02(value) => Some(("AcctSwtchPmtRspn", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTerminationSwitchV01(value) => Some(("AcctSwtchTermntnSwtch", &mut **value)),
+			#[cfg(feature = "acmt")]
+			Document::AccountSwitchTechnicalRejectionV02(value) => Some(("AcctSwtchTechRjctn", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PriceReportV04(value) => Some(("PricRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PriceReportCancellationV04(value) => Some(("PricRptCxl", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::FundReferenceDataReportV07(value) => Some(("FndRefDataRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::InvestmentFundReportRequestV03(value) => Some(("InvstmtFndRptReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationRequestV01(value) => Some(("SctyCreReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceRequestV01(value) => Some(("SctyMntncReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityCreationStatusAdviceV01(value) => Some(("SctyCreStsAdvc", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityActivityAdviceV01(value) => Some(("SctyActvtyAdvc", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityQueryV01(value) => Some(("SctyQry", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityReportV01(value) => Some(("SctyRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionRequestV01(value) => Some(("SctyDeltnReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PartyCreationRequestV02(value) => Some(("PtyCreReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PartyQueryV01(value) => Some(("PtyQry", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PartyStatusAdviceV01(value) => Some(("PtyStsAdvc", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PartyReportV02(value) => Some(("PtyRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountCreationRequestV01(value) => Some(("SctiesAcctCreReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountQueryV01(value) => Some(("SctiesAcctQry", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountStatusAdviceV01(value) => Some(("SctiesAcctStsAdvc", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountReportV01(value) => Some(("SctiesAcctRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PartyModificationRequestV02(value) => Some(("PtyModReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountModificationRequestV01(value) => Some(("SctiesAcctModReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityMaintenanceStatusAdviceV01(value) => Some(("SctyMntncStsAdvc", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecurityDeletionStatusAdviceV01(value) => Some(("SctyDeltnStsAdvc", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PartyDeletionRequestV01(value) => Some(("PtyDeltnReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountDeletionRequestV01(value) => Some(("SctiesAcctDeltnReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailQueryV01(value) => Some(("SctiesAudtTrlQry", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAuditTrailReportV01(value) => Some(("SctiesAudtTrlRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountActivityAdviceV01(value) => Some(("SctiesAcctActvtyAdvc", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailQueryV01(value) => Some(("SctiesAcctAudtTrlQry", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::SecuritiesAccountAuditTrailReportV01(value) => Some(("SctiesAcctAudtTrlRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PartyActivityAdviceV02(value) => Some(("PtyActvtyAdvc", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailQueryV01(value) => Some(("PtyAudtTrlQry", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::PartyAuditTrailReportV02(value) => Some(("PtyAudtTrlRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionV01(value) => Some(("StgSttlmInstr", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionDeletionV01(value) => Some(("StgSttlmInstrDeltn", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionStatusAdviceV01(value) => Some(("StgSttlmInstrStsAdvc", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::StandingSettlementInstructionCancellationV01(value) => Some(("StgSttlmInstrCxl", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataUpdateRequestV02(value) => Some(("NetgCutOffRefDataUpdReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::NettingCutOffReferenceDataReportV02(value) => Some(("NetgCutOffRefDataRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::CalendarQueryV02(value) => Some(("CalQry", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::CalendarReportV02(value) => Some(("CalRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentRequestV02(value) => Some(("ReqToPayCdtrEnrlmntReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentAmendmentRequestV02(value) => Some(("ReqToPayCdtrEnrlmntAmdmntReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentCancellationRequestV02(value) => Some(("ReqToPayCdtrEnrlmntCxlReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayCreditorEnrolmentStatusReportV02(value) => Some(("ReqToPayCdtrEnrlmntStsRpt", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationRequestV02(value) => Some(("ReqToPayDbtrActvtnReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationAmendmentRequestV02(value) => Some(("ReqToPayDbtrActvtnAmdmntReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationCancellationRequestV02(value) => Some(("ReqToPayDbtrActvtnCxlReq", &mut **value)),
+			#[cfg(feature = "reda")]
+			Document::RequestToPayDebtorActivationStatusReportV02(value) => Some(("ReqToPayDbtrActvtnStsRpt", &mut **value)),
+			#[cfg(feature = "remt")]
+			Document::RemittanceAdviceV06(value) => Some(("RmtAdvc", &mut **value)),
+			#[cfg(feature = "remt")]
+			Document::RemittanceLocationAdviceV03(value) => Some(("RmtLctnAdvc", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::GetAccountV08(value) => Some(("GetAcct", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnAccountV10(value) => Some(("RtrAcct", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::GetTransactionV11(value) => Some(("GetTx", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnTransactionV11(value) => Some(("RtrTx", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyTransactionV10(value) => Some(("ModfyTx", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::CancelTransactionV11(value) => Some(("CclTx", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::GetLimitV08(value) => Some(("GetLmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnLimitV09(value) => Some(("RtrLmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyLimitV08(value) => Some(("ModfyLmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::DeleteLimitV08(value) => Some(("DelLmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::GetMemberV04(value) => Some(("GetMmb", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnMemberV05(value) => Some(("RtrMmb", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyMemberV04(value) => Some(("ModfyMmb", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::GetCurrencyExchangeRateV04(value) => Some(("GetCcyXchgRate", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnCurrencyExchangeRateV05(value) => Some(("RtrCcyXchgRate", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::GetBusinessDayInformationV05(value) => Some(("GetBizDayInf", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnBusinessDayInformationV07(value) => Some(("RtrBizDayInf", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::GetGeneralBusinessInformationV04(value) => Some(("GetGnlBizInf", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnGeneralBusinessInformationV06(value) => Some(("RtrGnlBizInf", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::BackupPaymentV07(value) => Some(("BckpPmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyStandingOrderV08(value) => Some(("ModfyStgOrdr", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReceiptV08(value) => Some(("Rct", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::UnableToApplyV10(value) => Some(("UblToApply", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ClaimNonReceiptV10(value) => Some(("ClmNonRct", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::AdditionalPaymentInformationV12(value) => Some(("AddtlPmtInf", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ResolutionOfInvestigationV13(value) => Some(("RsltnOfInvstgtn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::NotificationOfCaseAssignmentV06(value) => Some(("NtfctnOfCaseAssgnmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::RejectInvestigationV07(value) => Some(("RjctInvstgtn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::CancelCaseAssignmentV05(value) => Some(("CclCaseAssgnmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::RequestForDuplicateV07(value) => Some(("ReqForDplct", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::DuplicateV07(value) => Some(("Dplct", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ProprietaryFormatInvestigationV06(value) => Some(("PrtryFrmtInvstgtn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationResponseV06(value) => Some(("DbtAuthstnRspn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::DebitAuthorisationRequestV10(value) => Some(("DbtAuthstnReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportRequestV05(value) => Some(("CaseStsRptReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::CaseStatusReportV06(value) => Some(("CaseStsRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::FundEstimatedCashForecastReportV04(value) => Some(("FndEstmtdCshFcstRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportV04(value) => Some(("FndConfdCshFcstRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedEstimatedCashForecastReportV04(value) => Some(("FndDtldEstmtdCshFcstRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportV04(value) => Some(("FndDtldConfdCshFcstRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::FundConfirmedCashForecastReportCancellationV03(value) => Some(("FndConfdCshFcstRptCxl", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::FundDetailedConfirmedCashForecastReportCancellationV03(value) => Some(("FndDtldConfdCshFcstRptCxl", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::GetReservationV08(value) => Some(("GetRsvatn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnReservationV08(value) => Some(("RtrRsvatn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ModifyReservationV07(value) => Some(("ModfyRsvatn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::DeleteReservationV07(value) => Some(("DelRsvatn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::LiquidityCreditTransferV07(value) => Some(("LqdtyCdtTrf", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::LiquidityDebitTransferV07(value) => Some(("LqdtyDbtTrf", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerAccountReportV12(value) => Some(("BkToCstmrAcctRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerStatementV12(value) => Some(("BkToCstmrStmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::BankToCustomerDebitCreditNotificationV12(value) => Some(("BkToCstmrDbtCdtNtfctn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::CustomerPaymentCancellationRequestV12(value) => Some(("CstmrPmtCxlReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::FIToFIPaymentCancellationRequestV11(value) => Some(("FIToFIPmtCxlReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveV08(value) => Some(("NtfctnToRcv", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveCancellationAdviceV09(value) => Some(("NtfctnToRcvCxlAdvc", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::NotificationToReceiveStatusReportV08(value) => Some(("NtfctnToRcvStsRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::AccountReportingRequestV07(value) => Some(("AcctRptgReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::PayInCallV02(value) => Some(("PayInCall", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::PayInScheduleV03(value) => Some(("PayInSchdl", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::PayInEventAcknowledgementV02(value) => Some(("PayInEvtAck", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementInstructionV02(value) => Some(("IntraBalMvmntInstr", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementStatusAdviceV02(value) => Some(("IntraBalMvmntStsAdvc", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementConfirmationV02(value) => Some(("IntraBalMvmntConf", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::GetStandingOrderV05(value) => Some(("GetStgOrdr", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ReturnStandingOrderV06(value) => Some(("RtrStgOrdr", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::DeleteStandingOrderV05(value) => Some(("DelStgOrdr", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestV02(value) => Some(("IntraBalMvmntModReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationRequestStatusAdviceV02(value) => Some(("IntraBalMvmntModReqStsAdvc", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestV02(value) => Some(("IntraBalMvmntCxlReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationRequestStatusAdviceV02(value) => Some(("IntraBalMvmntCxlReqStsAdvc", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryV02(value) => Some(("IntraBalMvmntQry", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementQueryResponseV02(value) => Some(("IntraBalMvmntQryRspn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationQueryV02(value) => Some(("IntraBalMvmntModQry", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementModificationReportV02(value) => Some(("IntraBalMvmntModRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationQueryV02(value) => Some(("IntraBalMvmntCxlQry", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementCancellationReportV02(value) => Some(("IntraBalMvmntCxlRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPostingReportV02(value) => Some(("IntraBalMvmntPstngRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::IntraBalanceMovementPendingReportV02(value) => Some(("IntraBalMvmntPdgRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::BankServicesBillingStatementV05(value) => Some(("BkSvcsBllgStmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::RequestToModifyPaymentV09(value) => Some(("ReqToModfyPmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::NetReportV02(value) => Some(("NetRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::CreateLimitV02(value) => Some(("CretLmt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::CreateStandingOrderV03(value) => Some(("CretStgOrdr", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::CreateReservationV03(value) => Some(("CretRsvatn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::CreateMemberV01(value) => Some(("CretMmb", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentNotificationV02(value) => Some(("ChrgsPmtNtfctn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ChargesPaymentRequestV02(value) => Some(("ChrgsPmtReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ChequePresentmentNotificationV02(value) => Some(("ChqPresntmntNtfctn", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopRequestV02(value) => Some(("ChqCxlOrStopReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::ChequeCancellationOrStopReportV02(value) => Some(("ChqCxlOrStopRpt", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::InvestigationRequestV01(value) => Some(("InvstgtnReq", &mut **value)),
+			#[cfg(feature = "camt")]
+			Document::InvestigationResponseV01(value) => Some(("InvstgtnRspn", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestOpeningV02(value) => Some(("InfReqOpng", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestResponseV02(value) => Some(("InfReqRspn", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::InformationRequestStatusChangeNotificationV01(value) => Some(("InfReqStsChngNtfctn", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketSecuredMarketStatisticalReportV02(value) => Some(("MnyMktScrdMktSttstclRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketUnsecuredMarketStatisticalReportV02(value) => Some(("MnyMktUscrdMktSttstclRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketForeignExchangeSwapsStatisticalReportV02(value) => Some(("MnyMktFXSwpsSttstclRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketOvernightIndexSwapsStatisticalReportV02(value) => Some(("MnyMktOvrnghtIndxSwpsSttstclRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTransactionReportV03(value) => Some(("FinInstrmRptgTxRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataReportV02(value) => Some(("FinInstrmRptgRefDataRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationRequestV04(value) => Some(("CtrctRegnReq", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationConfirmationV04(value) => Some(("CtrctRegnConf", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationClosureRequestV04(value) => Some(("CtrctRegnClsrReq", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationAmendmentRequestV04(value) => Some(("CtrctRegnAmdmntReq", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementV04(value) => Some(("CtrctRegnStmt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::ContractRegistrationStatementRequestV04(value) => Some(("CtrctRegnStmtReq", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::PaymentRegulatoryInformationNotificationV04(value) => Some(("PmtRgltryInfNtfctn", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlSupportingDocumentDeliveryV04(value) => Some(("CcyCtrlSpprtgDocDlvry", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlRequestOrLetterV04(value) => Some(("CcyCtrlReqOrLttr", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CurrencyControlStatusAdviceV04(value) => Some(("CcyCtrlStsAdvc", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::MoneyMarketStatisticalReportStatusAdviceV01(value) => Some(("MnyMktSttstclRptStsAdvc", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportQueryV05(value) => Some(("DerivsTradRptQry", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReportV04(value) => Some(("DerivsTradRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingStatusAdviceV01(value) => Some(("FinInstrmRptgStsAdvc", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTransparencyDataReportV01(value) => Some(("FinInstrmRptgEqtyTrnsprncyDataRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTransparencyDataReportV03(value) => Some(("FinInstrmRptgNonEqtyTrnsprncyDataRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportV01(value) => Some(("InvcTaxRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapDataReportV01(value) => Some(("FinInstrmRptgTradgVolCapDataRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataDeltaReportV03(value) => Some(("FinInstrmRptgRefDataDltaRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::InvoiceTaxReportStatusAdviceV01(value) => Some(("InvcTaxRptStsAdvc", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonWorkingDayReportV01(value) => Some(("FinInstrmRptgNonWorkgDayRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityReportV01(value) => Some(("FinInstrmRptgEqtyTradgActvtyRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityReportV01(value) => Some(("FinInstrmRptgNonEqtyTradgActvtyRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInvalidReferenceDataReportV02(value) => Some(("FinInstrmRptgInvldRefDataRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingReferenceDataIndexReportV01(value) => Some(("FinInstrmRptgRefDataIndxRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingEquityTradingActivityResultV02(value) => Some(("FinInstrmRptgEqtyTradgActvtyRslt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingNonEquityTradingActivityResultV03(value) => Some(("FinInstrmRptgNonEqtyTradgActvtyRslt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCountryCodeReportV01(value) => Some(("FinInstrmRptgCtryCdRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCurrencyCodeReportV01(value) => Some(("FinInstrmRptgCcyCdRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingMarketIdentificationCodeReportV02(value) => Some(("FinInstrmRptgMktIdCdRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingInstrumentClassificationReportV01(value) => Some(("FinInstrmRptgInstrmClssfctnRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReportV02(value) => Some(("SctiesFincgRptgTxRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingTradingVolumeCapResultReportV01(value) => Some(("FinInstrmRptgTradgVolCapRsltRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPClearingMemberReportV01(value) => Some(("CCPClrMmbRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberRequirementsReportV01(value) => Some(("CCPMmbRqrmntsRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPMemberObligationsReportV01(value) => Some(("CCPMmbOblgtnsRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingDefinitionReportV02(value) => Some(("CCPPrtflStrssTstgDefRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPPortfolioStressTestingResultReportV01(value) => Some(("CCPPrtflStrssTstgRsltRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPIncomeStatementAndCapitalAdequacyReportV01(value) => Some(("CCPIncmStmtAndCptlAdqcyRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPDailyCashFlowsReportV02(value) => Some(("CCPDalyCshFlowsRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPInvestmentsReportV01(value) => Some(("CCPInvstmtsRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingDefinitionReportV01(value) => Some(("CCPLqdtyStrssTstgDefRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPLiquidityStressTestingResultReportV01(value) => Some(("CCPLqdtyStrssTstgRsltRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPAvailableFinancialResourcesReportV01(value) => Some(("CCPAvlblFinRsrcsRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingDefinitionReportV01(value) => Some(("CCPBckTstgDefRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPBackTestingResultReportV01(value) => Some(("CCPBckTstgRsltRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPCollateralReportV01(value) => Some(("CCPCollRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPAccountPositionReportV01(value) => Some(("CCPAcctPosRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPClearedProductReportV01(value) => Some(("CCPClrdPdctRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionMarginDataReportV02(value) => Some(("SctiesFincgRptgTxMrgnDataRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionReusedCollateralDataReportV02(value) => Some(("SctiesFincgRptgTxReusdCollDataRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SettlementInternaliserReportV01(value) => Some(("SttlmIntlrRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialSupervisedPartyIdentityReportV01(value) => Some(("FinSprvsdPtyIdntyRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialBenchmarkReportV01(value) => Some(("FinBchmkRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPairingRequestV02(value) => Some(("SctiesFincgRptgPairgReq", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStateReportV02(value) => Some(("SctiesFincgRptgTxStatRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReconciliationStatusAdviceV02(value) => Some(("SctiesFincgRptgRcncltnStsAdvc", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMissingCollateralRequestV02(value) => Some(("SctiesFincgRptgMssngCollReq", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionStatusAdviceV02(value) => Some(("SctiesFincgRptgTxStsAdvc", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingMarginDataTransactionStateReportV02(value) => Some(("SctiesFincgRptgMrgnDataTxStatRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingReusedCollateralDataTransactionStateReportV02(value) => Some(("SctiesFincgRptgReusdCollDataTxStatRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradePositionSetReportV02(value) => Some(("DerivsTradPosSetRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeReconciliationStatisticalReportV03(value) => Some(("DerivsTradRcncltnSttstclRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeRejectionStatisticalReportV04(value) => Some(("DerivsTradRjctnSttstclRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingTransactionQueryV02(value) => Some(("SctiesFincgRptgTxQry", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsMonthlyReportV01(value) => Some(("SttlmFlsMnthlyRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SettlementFailsAnnualReportV01(value) => Some(("SttlmFlsAnlRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::FinancialInstrumentReportingCancellationReportV01(value) => Some(("FinInstrmRptgCxlRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::SecuritiesFinancingReportingPositionSetReportV01(value) => Some(("SctiesFincgRptgPosSetRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeWarningsReportV01(value) => Some(("DerivsTradWrnngsRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeStateReportV02(value) => Some(("DerivsTradStatRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataReportV02(value) => Some(("DerivsTradMrgnDataRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::DerivativesTradeMarginDataTransactionStateReportV02(value) => Some(("DerivsTradMrgnDataTxStatRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::CCPInteroperabilityReportV01(value) => Some(("CCPIntrprbltyRpt", &mut **value)),
+			#[cfg(feature = "auth")]
+			Document::OrderBookReportV01(value) => Some(("OrdrBookRpt", &mut **value)),
+			Document::UNKNOWN => None,
+        };
+        if let Some((tag, message)) = message {
+            if step.matches(tag) && step.first() {
+                out.push(message);
+            }
+        }
+    }
+}
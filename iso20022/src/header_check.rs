@@ -0,0 +1,177 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Consistency checks between the business application header
+// (head.001.001.02) and the ISO 20022 Document it carries
+// (`open_payments_common::header_check`), and a builder that fills the
+// header from the Document.
+
+use crate::document::Document;
+use open_payments_common::common::*;
+#[cfg(feature = "derive_default")]
+use open_payments_common::datetime::now_iso_datetime;
+use open_payments_common::header_check::{check_header, payload_facts, AgentId, HeaderCheckConfig, HeaderFacts, PayloadFacts};
+#[cfg(feature = "derive_default")]
+use open_payments_common::header_check::{ERR_BUSINESS_MESSAGE_ID, ERR_FROM_AGENT, ERR_MESSAGE_DEFINITION, ERR_TO_AGENT};
+use open_payments_common::ValidationError;
+
+/// Identification of a financial institution.
+pub fn agent_id(agent: &BranchAndFinancialInstitutionIdentification6) -> Option<AgentId> {
+    let id = &agent.fin_instn_id;
+    let member = id.clr_sys_mmb_id.as_ref();
+    let agent = AgentId {
+        bic: id.bicfi.clone(),
+        member_id: member.map(|m| m.mmb_id.clone()),
+        clearing_system: member.and_then(|m| m.clr_sys_id.as_ref()).and_then(|c| c.cd.clone().or_else(|| c.prtry.clone())),
+        lei: id.lei.clone(),
+    };
+    if agent.is_empty() { None } else { Some(agent) }
+}
+
+fn copy_duplicate_code(code: &CopyDuplicate1Code) -> &'static str {
+    match code {
+        CopyDuplicate1Code::CodeCODU => "CODU",
+        CopyDuplicate1Code::CodeCOPY => "COPY",
+        CopyDuplicate1Code::CodeDUPL => "DUPL",
+    }
+}
+
+pub fn header_facts(app_hdr: &BusinessApplicationHeaderV02) -> HeaderFacts {
+    HeaderFacts {
+        msg_def_idr: app_hdr.msg_def_idr.clone(),
+        biz_msg_idr: app_hdr.biz_msg_idr.clone(),
+        from: app_hdr.fr.fi_id.as_ref().and_then(agent_id),
+        to: app_hdr.to.fi_id.as_ref().and_then(agent_id),
+        cre_dt: app_hdr.cre_dt.clone(),
+        copy_duplicate: app_hdr.cpy_dplct.as_ref().map(|c| copy_duplicate_code(c).to_string()),
+        has_related: app_hdr.rltd.as_ref().is_some_and(|r| !r.is_empty()),
+    }
+}
+
+pub fn document_facts(document: &Document) -> PayloadFacts {
+    let mut facts = serde_json::to_value(document).map(|value| payload_facts(&value)).unwrap_or_default();
+    facts.msg_def_idr = document.message_definition_identifier().map(str::to_string);
+    facts
+}
+
+/// Checks the header of a message against its Document.
+pub fn check_message(app_hdr: &BusinessApplicationHeaderV02, document: &Document, config: &HeaderCheckConfig) -> Vec<ValidationError> {
+    check_header(&header_facts(app_hdr), &document_facts(document), config)
+}
+
+#[cfg(feature = "derive_default")]
+fn party(agent: &AgentId) -> Party44Choice {
+    Party44Choice {
+        fi_id: Some(BranchAndFinancialInstitutionIdentification6 {
+            fin_instn_id: FinancialInstitutionIdentification18 {
+                bicfi: agent.bic.clone(),
+                clr_sys_mmb_id: agent.member_id.as_ref().map(|member| ClearingSystemMemberIdentification2 {
+                    clr_sys_id: agent.clearing_system.as_ref().map(|code| ClearingSystemIdentification2Choice { cd: Some(code.clone()), ..Default::default() }),
+                    mmb_id: member.clone(),
+                }),
+                lei: agent.lei.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Builds the business application header of a Document: `MsgDefIdr` from
+/// the message, `BizMsgIdr` and `CreDt` from its `MsgId` and `CreDtTm`, and
+/// `Fr` and `To` from the instructing and instructed agents. Anything the
+/// Document does not carry is set on the builder.
+#[cfg(feature = "derive_default")]
+pub struct AppHdrBuilder {
+    header: BusinessApplicationHeaderV02,
+    from: Option<AgentId>,
+    to: Option<AgentId>,
+}
+
+#[cfg(feature = "derive_default")]
+impl AppHdrBuilder {
+    pub fn from_document(document: &Document) -> Result<Self, ValidationError> {
+        let facts = document_facts(document);
+        let msg_def_idr = facts.msg_def_idr.ok_or_else(|| ValidationError::new(ERR_MESSAGE_DEFINITION, "unknown document type".to_string()))?;
+        Ok(AppHdrBuilder {
+            header: BusinessApplicationHeaderV02 {
+                biz_msg_idr: facts.msg_id.unwrap_or_default(),
+                msg_def_idr,
+                cre_dt: facts.cre_dt_tm.unwrap_or_else(now_iso_datetime),
+                ..Default::default()
+            },
+            from: facts.instructing_agent,
+            to: facts.instructed_agent,
+        })
+    }
+
+    pub fn from(mut self, agent: AgentId) -> Self {
+        self.from = Some(agent);
+        self
+    }
+
+    pub fn to(mut self, agent: AgentId) -> Self {
+        self.to = Some(agent);
+        self
+    }
+
+    pub fn business_message_id(mut self, biz_msg_idr: &str) -> Self {
+        self.header.biz_msg_idr = biz_msg_idr.to_string();
+        self
+    }
+
+    pub fn business_service(mut self, biz_svc: &str) -> Self {
+        self.header.biz_svc = Some(biz_svc.to_string());
+        self
+    }
+
+    pub fn creation_date(mut self, cre_dt: &str) -> Self {
+        self.header.cre_dt = cre_dt.to_string();
+        self
+    }
+
+    pub fn possible_duplicate(mut self, possible_duplicate: bool) -> Self {
+        self.header.pssbl_dplct = Some(possible_duplicate);
+        self
+    }
+
+    /// Marks the message as a copy or duplicate of the message with the
+    /// given header.
+    pub fn related(mut self, code: CopyDuplicate1Code, related: BusinessApplicationHeader5) -> Self {
+        self.header.cpy_dplct = Some(code);
+        self.header.rltd.get_or_insert_with(Vec::new).push(related);
+        self
+    }
+
+    pub fn build(self) -> Result<BusinessApplicationHeaderV02, ValidationError> {
+        let mut header = self.header;
+        if header.biz_msg_idr.is_empty() {
+            return Err(ValidationError::new(ERR_BUSINESS_MESSAGE_ID, format!("{} has no MsgId, set the business message identifier", header.msg_def_idr)));
+        }
+        header.fr = party(&self.from.ok_or_else(|| ValidationError::new(ERR_FROM_AGENT, format!("{} has no instructing agent, set the sender", header.msg_def_idr)))?);
+        header.to = party(&self.to.ok_or_else(|| ValidationError::new(ERR_TO_AGENT, format!("{} has no instructed agent, set the receiver", header.msg_def_idr)))?);
+        Ok(header)
+    }
+}
@@ -25,3 +25,23 @@
 
 pub mod document;
 pub mod common;
+
+#[cfg(feature = "derive_default")]
+pub mod swift;
+#[cfg(all(feature = "derive_default", feature = "camt"))]
+pub mod bai2;
+#[cfg(all(feature = "derive_default", any(feature = "pain", feature = "pacs")))]
+pub mod nacha;
+#[cfg(any(feature = "camt", feature = "pacs", feature = "pain"))]
+pub mod reconciliation;
+#[cfg(feature = "camt")]
+pub mod statement_check;
+#[cfg(feature = "camt")]
+pub mod pagination;
+#[cfg(all(feature = "head", feature = "derive_serde", feature = "derive_default"))]
+pub mod business_file;
+#[cfg(feature = "derive_serde")]
+pub mod header_check;
+#[cfg(feature = "derive_serde")]
+pub mod duplicate;
+pub mod visitor;
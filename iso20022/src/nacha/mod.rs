@@ -0,0 +1,282 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Translation between NACHA ACH files and the ISO 20022 messages of this
+// crate. The NACHA reader and writer live in `open_payments_common::nacha`.
+//
+// Batches carry the originating company (debtor), its ODFI (debtor agent),
+// the SEC code (local instrument) and the company entry description
+// (proprietary category purpose). Entries carry the receiver (creditor), its
+// RDFI (creditor agent) and account, the trace number and the
+// identification number (end-to-end identification); addenda become
+// unstructured remittance information.
+
+#[cfg(feature = "pacs")]
+pub mod pacs008;
+#[cfg(feature = "pain")]
+pub mod pain001;
+
+use open_payments_common::common::*;
+use open_payments_common::datetime::date_part;
+use open_payments_common::nacha::{routing_number, Addenda, BatchHeader, EntryDetail, FileHeader, SecCode, ERR_CONTROL_TOTAL, ERR_UNMAPPABLE};
+use open_payments_common::ValidationError;
+
+use crate::swift::{account_identifier, cash_account};
+
+/// Clearing system of ABA routing numbers.
+pub const ABA_CLEARING_SYSTEM: &str = "USABA";
+
+pub fn unmappable(message: String) -> ValidationError {
+    ValidationError::new(ERR_UNMAPPABLE, message)
+}
+
+/// Financial institution identified by its ABA routing number.
+pub fn aba_agent(routing: &str) -> BranchAndFinancialInstitutionIdentification8 {
+    BranchAndFinancialInstitutionIdentification8 {
+        fin_instn_id: FinancialInstitutionIdentification23 {
+            clr_sys_mmb_id: Some(ClearingSystemMemberIdentification2 {
+                clr_sys_id: Some(ClearingSystemIdentification2Choice { cd: Some(ABA_CLEARING_SYSTEM.to_string()), ..Default::default() }),
+                mmb_id: routing.to_string(),
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// ABA routing number of an agent.
+pub fn agent_routing_number(agent: &BranchAndFinancialInstitutionIdentification8) -> Option<&str> {
+    let member = agent.fin_instn_id.clr_sys_mmb_id.as_ref()?;
+    let system = member.clr_sys_id.as_ref().and_then(|s| s.cd.as_deref());
+    if system.is_some_and(|s| s != ABA_CLEARING_SYSTEM) || member.mmb_id.len() != 9 || !member.mmb_id.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(&member.mmb_id)
+}
+
+/// Originating company of a batch: company name and identification.
+pub fn company_party(header: &BatchHeader) -> PartyIdentification272 {
+    PartyIdentification272 {
+        nm: Some(header.company_name.clone()),
+        id: Some(Party52Choice {
+            org_id: Some(OrganisationIdentification39 {
+                othr: Some(vec![GenericOrganisationIdentification3 { id: header.company_identification.clone(), ..Default::default() }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Company identification of an originator: its first organisation identifier.
+pub fn company_identification(party: &PartyIdentification272) -> Option<&str> {
+    party.id.as_ref()?.org_id.as_ref()?.othr.as_ref()?.first().map(|o| o.id.as_str())
+}
+
+pub fn receiver_party(entry: &EntryDetail) -> PartyIdentification272 {
+    PartyIdentification272 { nm: Some(entry.receiver_name.clone()), ..Default::default() }
+}
+
+/// Receiver account with its type: CACC for checking, SVGS for savings and
+/// LOAN for loan accounts.
+pub fn receiver_account(entry: &EntryDetail) -> CashAccount40 {
+    use open_payments_common::nacha::AccountType;
+    let mut account = cash_account(&entry.dfi_account_number);
+    let code = match entry.account_type() {
+        Some(AccountType::Savings) => Some("SVGS"),
+        Some(AccountType::Loan) => Some("LOAN"),
+        Some(AccountType::Checking) => Some("CACC"),
+        _ => None,
+    };
+    account.tp = code.map(|c| CashAccountType2Choice { cd: Some(c.to_string()), ..Default::default() });
+    account
+}
+
+/// Credit transaction code for a receiver account: 32 for savings, 52 for
+/// loan, 22 (checking) otherwise.
+pub fn credit_transaction_code(account: Option<&CashAccount40>) -> u8 {
+    match account.and_then(|a| a.tp.as_ref()).and_then(|t| t.cd.as_deref()) {
+        Some("SVGS") => 32,
+        Some("LOAN") => 52,
+        _ => 22,
+    }
+}
+
+pub fn local_instrument(sec_code: &SecCode) -> LocalInstrument2Choice {
+    LocalInstrument2Choice { cd: Some(sec_code.code().to_string()), ..Default::default() }
+}
+
+/// SEC code from a local instrument, CCD when there is none.
+pub fn sec_code(instrument: Option<&LocalInstrument2Choice>) -> SecCode {
+    instrument.and_then(|i| i.cd.as_deref().or(i.prtry.as_deref())).map(SecCode::parse).unwrap_or(SecCode::Ccd)
+}
+
+/// Addenda payment related information as unstructured remittance lines.
+pub fn remittance(entry: &EntryDetail) -> Option<RemittanceInformation22> {
+    let text = entry.payment_related_information();
+    if text.is_empty() {
+        return None;
+    }
+    let chars: Vec<char> = text.chars().collect();
+    Some(RemittanceInformation22 { ustrd: Some(chars.chunks(140).map(|c| c.iter().collect()).collect()), ..Default::default() })
+}
+
+/// Addenda for unstructured remittance information: one addenda of 80
+/// characters for PPD and CCD, as many as needed for CTX.
+pub fn remittance_addenda(remittance: Option<&RemittanceInformation22>, sec_code: &SecCode, reference: &str) -> Result<Vec<Addenda>, ValidationError> {
+    let text: String = remittance.and_then(|r| r.ustrd.as_ref()).map(|lines| lines.concat()).unwrap_or_default();
+    if remittance.is_some_and(|r| r.strd.is_some()) {
+        return Err(unmappable(format!("{}: structured remittance information has no NACHA counterpart", reference)));
+    }
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let chunks: Vec<String> = chars.chunks(80).map(|c| c.iter().collect()).collect();
+    if chunks.len() > sec_code.max_addenda() {
+        return Err(unmappable(format!("{}: remittance information exceeds the addenda of a {} entry", reference, sec_code.code())));
+    }
+    Ok(chunks.into_iter().map(|text| Addenda { type_code: "05".to_string(), payment_related_information: text.trim_end().to_string(), ..Default::default() }).collect())
+}
+
+/// Trace number: the ODFI identification and a seven digit sequence number.
+pub fn trace_number(odfi: &str, sequence: usize) -> String {
+    format!("{}{:07}", odfi, sequence % 10_000_000)
+}
+
+/// Trace number carried by an ISO reference, if it is one of this ODFI.
+pub fn carried_trace_number(reference: Option<&str>, odfi: &str) -> Option<String> {
+    reference.filter(|r| r.len() == 15 && r.starts_with(odfi) && r.chars().all(|c| c.is_ascii_digit())).map(|r| r.to_string())
+}
+
+pub fn creation_date_time(header: &FileHeader) -> String {
+    format!("{}T{}:00", header.creation_date, header.creation_time.as_deref().unwrap_or("00:00"))
+}
+
+/// File header for an originated file: the ODFI is the immediate
+/// destination unless a forwarding agent is given.
+pub fn file_header(cre_dt_tm: &str, destination: &str, origin: &str, origin_name: &str) -> FileHeader {
+    FileHeader {
+        immediate_destination: format!(" {}", destination),
+        immediate_origin: origin.to_string(),
+        creation_date: date_part(cre_dt_tm).to_string(),
+        creation_time: cre_dt_tm.get(11..16).map(|t| t.to_string()),
+        file_id_modifier: 'A',
+        immediate_destination_name: String::new(),
+        immediate_origin_name: origin_name.to_string(),
+        reference_code: String::new(),
+    }
+}
+
+/// Entry detail for a credit of `amount` cents to the creditor. The
+/// EndToEndId goes to the 15 character identification number and is not
+/// truncated: a longer one cannot be carried.
+#[allow(clippy::too_many_arguments)]
+pub fn credit_entry(
+    reference: &str,
+    creditor_agent: Option<&BranchAndFinancialInstitutionIdentification8>,
+    creditor: Option<&PartyIdentification272>,
+    creditor_account: Option<&CashAccount40>,
+    end_to_end_id: &str,
+    amount: i64,
+    trace: String,
+    addenda: Vec<Addenda>,
+) -> Result<EntryDetail, ValidationError> {
+    let routing = creditor_agent
+        .and_then(agent_routing_number)
+        .ok_or_else(|| unmappable(format!("{}: creditor agent must be identified by ABA routing number", reference)))?;
+    let account = creditor_account
+        .and_then(account_identifier)
+        .ok_or_else(|| unmappable(format!("{}: creditor account is required", reference)))?;
+    let identification_number = if end_to_end_id == "NOTPROVIDED" { String::new() } else { end_to_end_id.to_string() };
+    if identification_number.chars().count() > 15 {
+        return Err(unmappable(format!("{}: EndToEndId {} is longer than the 15 characters of the identification number", reference, end_to_end_id)));
+    }
+    Ok(EntryDetail {
+        transaction_code: credit_transaction_code(creditor_account),
+        receiving_dfi_identification: routing[..8].to_string(),
+        check_digit: routing.as_bytes()[8] - b'0',
+        dfi_account_number: account,
+        amount,
+        identification_number,
+        receiver_name: creditor.and_then(|c| c.nm.clone()).unwrap_or_default(),
+        discretionary_data: String::new(),
+        trace_number: trace,
+        addenda,
+    })
+}
+
+/// Amount in cents; NACHA files carry US dollars only.
+pub fn cents(reference: &str, currency: &str, amount: f64) -> Result<i64, ValidationError> {
+    if currency != "USD" {
+        return Err(unmappable(format!("{}: NACHA entries are in USD, not {}", reference, currency)));
+    }
+    Ok((amount * 100.0).round() as i64)
+}
+
+/// Checks a declared number of transactions (`NbOfTxs`) and control sum
+/// (`CtrlSum`) against the transactions.
+pub fn check_totals(what: &str, nb_of_txs: Option<&str>, ctrl_sum: Option<f64>, count: usize, sum: i64) -> Result<(), ValidationError> {
+    if let Some(declared) = nb_of_txs {
+        if declared.parse::<usize>().ok() != Some(count) {
+            return Err(ValidationError::new(ERR_CONTROL_TOTAL, format!("{}: NbOfTxs is {} but there are {} transactions", what, declared, count)));
+        }
+    }
+    if let Some(declared) = ctrl_sum {
+        if (declared * 100.0).round() as i64 != sum {
+            return Err(ValidationError::new(
+                ERR_CONTROL_TOTAL,
+                format!("{}: CtrlSum is {} but the transactions add up to {:.2}", what, declared, sum as f64 / 100.0),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that an entry can be sent as a credit transfer.
+pub fn check_credit(entry: &EntryDetail) -> Result<(), ValidationError> {
+    if !entry.is_credit() {
+        return Err(unmappable(format!("entry {}: debit entries are not credit transfers", entry.trace_number)));
+    }
+    // Live credits end in 2; 1 is a return or notification of change, 3 a
+    // prenotification and 4 a zero dollar remittance entry.
+    if entry.transaction_code % 10 != 2 {
+        return Err(unmappable(format!("entry {}: transaction code {} is not a live credit", entry.trace_number, entry.transaction_code)));
+    }
+    Ok(())
+}
+
+/// Nine digit routing number of the ODFI of a batch.
+pub fn odfi_routing_number(header: &BatchHeader) -> String {
+    routing_number(&header.originating_dfi_identification)
+}
+
+/// Category purpose carrying the company entry description.
+pub fn entry_description(header: &BatchHeader) -> Option<CategoryPurpose1Choice> {
+    if header.company_entry_description.is_empty() {
+        return None;
+    }
+    Some(CategoryPurpose1Choice { prtry: Some(header.company_entry_description.clone()), ..Default::default() })
+}
@@ -0,0 +1,165 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// NACHA credit entries <-> pacs.008.001.12 (FIToFICustomerCreditTransferV12).
+
+use open_payments_common::common::*;
+use open_payments_common::datetime::date_part;
+use open_payments_common::nacha::{Batch, BatchHeader, NachaFile};
+use open_payments_common::ValidationError;
+use open_payments_iso20022_pacs::pacs_008_001_12::FIToFICustomerCreditTransferV12;
+
+use crate::nacha::*;
+
+/// Clearing system proprietary code for settlement through the ACH.
+pub const ACH_CLEARING_SYSTEM: &str = "ACH";
+
+/// Parses a NACHA file and translates it, see `file_to_pacs008`.
+pub fn nacha_to_pacs008(input: &str) -> Result<FIToFICustomerCreditTransferV12, ValidationError> {
+    file_to_pacs008(&NachaFile::parse(input)?)
+}
+
+/// Translates the credit entries of a NACHA file into a pacs.008 with one
+/// transaction per entry, cleared through the ACH and settled on the
+/// effective entry date of its batch. The ODFI instructs and the RDFI is
+/// instructed. Debits, prenotifications and returns are rejected.
+pub fn file_to_pacs008(file: &NachaFile) -> Result<FIToFICustomerCreditTransferV12, ValidationError> {
+    let header = &file.header;
+    let mut transactions = Vec::new();
+    let mut sum = 0;
+    for batch in &file.batches {
+        let h = &batch.header;
+        let odfi = odfi_routing_number(h);
+        for entry in &batch.entries {
+            check_credit(entry)?;
+            let rdfi = entry.routing_number();
+            transactions.push(CreditTransferTransaction64 {
+                pmt_id: PaymentIdentification13 {
+                    instr_id: Some(entry.trace_number.clone()),
+                    end_to_end_id: if entry.identification_number.is_empty() { "NOTPROVIDED".to_string() } else { entry.identification_number.clone() },
+                    tx_id: Some(entry.trace_number.clone()),
+                    ..Default::default()
+                },
+                pmt_tp_inf: Some(PaymentTypeInformation28 {
+                    lcl_instrm: Some(local_instrument(&h.sec_code)),
+                    ctgy_purp: entry_description(h),
+                    ..Default::default()
+                }),
+                intr_bk_sttlm_amt: ActiveCurrencyAndAmount { ccy: "USD".to_string(), value: entry.amount as f64 / 100.0 },
+                intr_bk_sttlm_dt: Some(h.effective_entry_date.clone()),
+                chrg_br: ChargeBearerType1Code::CodeSLEV,
+                instg_agt: Some(aba_agent(&odfi)),
+                instd_agt: Some(aba_agent(&rdfi)),
+                dbtr: company_party(h),
+                dbtr_agt: aba_agent(&odfi),
+                cdtr_agt: aba_agent(&rdfi),
+                cdtr: receiver_party(entry),
+                cdtr_acct: Some(receiver_account(entry)),
+                rmt_inf: remittance(entry),
+                ..Default::default()
+            });
+            sum += entry.amount;
+        }
+    }
+    Ok(FIToFICustomerCreditTransferV12 {
+        grp_hdr: GroupHeader113 {
+            msg_id: format!("{}{}{}", header.immediate_origin, header.creation_date.replace('-', ""), header.file_id_modifier),
+            cre_dt_tm: creation_date_time(header),
+            nb_of_txs: transactions.len().to_string(),
+            ctrl_sum: Some(sum as f64 / 100.0),
+            ttl_intr_bk_sttlm_amt: Some(ActiveCurrencyAndAmount { ccy: "USD".to_string(), value: sum as f64 / 100.0 }),
+            sttlm_inf: SettlementInstruction15 {
+                sttlm_mtd: SettlementMethod1Code::CodeCLRG,
+                clr_sys: Some(ClearingSystemIdentification3Choice { prtry: Some(ACH_CLEARING_SYSTEM.to_string()), ..Default::default() }),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        cdt_trf_tx_inf: transactions,
+        splmtry_data: None,
+    })
+}
+
+/// Translates a pacs.008 into NACHA text, see `pacs008_to_file`.
+pub fn pacs008_to_nacha(document: &FIToFICustomerCreditTransferV12) -> Result<String, ValidationError> {
+    Ok(pacs008_to_file(document)?.to_nacha())
+}
+
+/// Translates a pacs.008 into a NACHA file. Transactions of the same
+/// debtor, debtor agent, local instrument, category purpose and settlement
+/// date share a credits only (220) batch. `NbOfTxs`, `CtrlSum` and
+/// `TtlIntrBkSttlmAmt` are checked against the transactions first; the batch
+/// and file control records are computed.
+pub fn pacs008_to_file(document: &FIToFICustomerCreditTransferV12) -> Result<NachaFile, ValidationError> {
+    let group = &document.grp_hdr;
+    let mut batches: Vec<Batch> = Vec::new();
+    let mut sum = 0;
+    for tx in &document.cdt_trf_tx_inf {
+        let reference = &tx.pmt_id.end_to_end_id;
+        let odfi = agent_routing_number(&tx.dbtr_agt).ok_or_else(|| unmappable(format!("{}: debtor agent must be identified by ABA routing number", reference)))?;
+        let company_id = company_identification(&tx.dbtr).ok_or_else(|| unmappable(format!("{}: debtor must carry the ACH company identification", reference)))?;
+        let payment_type = tx.pmt_tp_inf.as_ref().or(group.pmt_tp_inf.as_ref());
+        let settlement_date = tx
+            .intr_bk_sttlm_dt
+            .as_deref()
+            .or(group.intr_bk_sttlm_dt.as_deref())
+            .map(|d| date_part(d).to_string())
+            .ok_or_else(|| unmappable(format!("{}: interbank settlement date is required", reference)))?;
+        let header = BatchHeader {
+            service_class_code: 220,
+            company_name: tx.dbtr.nm.clone().unwrap_or_default(),
+            company_identification: company_id.to_string(),
+            sec_code: sec_code(payment_type.and_then(|p| p.lcl_instrm.as_ref())),
+            company_entry_description: payment_type.and_then(|p| p.ctgy_purp.as_ref()).and_then(|c| c.prtry.clone().or(c.cd.clone())).unwrap_or_else(|| "PAYMENT".to_string()),
+            effective_entry_date: settlement_date,
+            originator_status_code: '1',
+            originating_dfi_identification: odfi[..8].to_string(),
+            ..Default::default()
+        };
+        let index = match batches.iter().position(|b| BatchHeader { batch_number: 0, ..b.header.clone() } == header) {
+            Some(index) => index,
+            None => {
+                batches.push(Batch { header: BatchHeader { batch_number: batches.len() as u32 + 1, ..header }, ..Default::default() });
+                batches.len() - 1
+            }
+        };
+        let batch = &mut batches[index];
+        let amount = cents(reference, &tx.intr_bk_sttlm_amt.ccy, tx.intr_bk_sttlm_amt.value)?;
+        let trace = carried_trace_number(tx.pmt_id.instr_id.as_deref().or(tx.pmt_id.tx_id.as_deref()), &batch.header.originating_dfi_identification)
+            .unwrap_or_else(|| trace_number(&batch.header.originating_dfi_identification, batch.entries.len() + 1));
+        let addenda = remittance_addenda(tx.rmt_inf.as_ref(), &batch.header.sec_code, reference)?;
+        batch.entries.push(credit_entry(reference, Some(&tx.cdtr_agt), Some(&tx.cdtr), tx.cdtr_acct.as_ref(), reference, amount, trace, addenda)?);
+        sum += amount;
+    }
+    check_totals(&group.msg_id, Some(&group.nb_of_txs), group.ctrl_sum, document.cdt_trf_tx_inf.len(), sum)?;
+    if let Some(ref total) = group.ttl_intr_bk_sttlm_amt {
+        check_totals(&group.msg_id, None, Some(total.value), document.cdt_trf_tx_inf.len(), sum)?;
+    }
+    let first_odfi = batches.first().map(|b| odfi_routing_number(&b.header)).unwrap_or_default();
+    let destination = group.instd_agt.as_ref().and_then(agent_routing_number).map(|r| r.to_string()).unwrap_or_else(|| first_odfi.clone());
+    let origin = group.instg_agt.as_ref().and_then(agent_routing_number).map(|r| r.to_string()).unwrap_or(first_odfi);
+    let mut file = NachaFile { header: file_header(&group.cre_dt_tm, &destination, &origin, ""), batches, ..Default::default() };
+    file.update_control_totals();
+    Ok(file)
+}
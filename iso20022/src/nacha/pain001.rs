@@ -0,0 +1,190 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// NACHA credit batches <-> pain.001.001.12 (CustomerCreditTransferInitiationV12).
+
+use open_payments_common::common::*;
+use open_payments_common::nacha::{Batch, BatchHeader, NachaFile};
+use open_payments_common::ValidationError;
+use open_payments_iso20022_pain::pain_001_001_12::CustomerCreditTransferInitiationV12;
+
+use crate::nacha::*;
+
+/// Parses a NACHA file and translates it, see `file_to_pain001`.
+pub fn nacha_to_pain001(input: &str) -> Result<CustomerCreditTransferInitiationV12, ValidationError> {
+    file_to_pain001(&NachaFile::parse(input)?)
+}
+
+/// Translates the credit batches of a NACHA file into a pain.001 with one
+/// payment information block per batch and one transaction per entry. The
+/// immediate origin initiates the message and the immediate destination is
+/// the forwarding agent. Debits, prenotifications and returns are rejected;
+/// company discretionary data and descriptive date have no counterpart.
+pub fn file_to_pain001(file: &NachaFile) -> Result<CustomerCreditTransferInitiationV12, ValidationError> {
+    let header = &file.header;
+    let mut payments = Vec::with_capacity(file.batches.len());
+    let (mut count, mut sum) = (0, 0);
+    for batch in &file.batches {
+        let h = &batch.header;
+        let mut transactions = Vec::with_capacity(batch.entries.len());
+        for entry in &batch.entries {
+            check_credit(entry)?;
+            transactions.push(CreditTransferTransaction61 {
+                pmt_id: PaymentIdentification6 {
+                    instr_id: Some(entry.trace_number.clone()),
+                    end_to_end_id: if entry.identification_number.is_empty() { "NOTPROVIDED".to_string() } else { entry.identification_number.clone() },
+                    ..Default::default()
+                },
+                amt: AmountType4Choice {
+                    instd_amt: Some(ActiveOrHistoricCurrencyAndAmount { ccy: "USD".to_string(), value: entry.amount as f64 / 100.0 }),
+                    ..Default::default()
+                },
+                cdtr_agt: Some(aba_agent(&entry.routing_number())),
+                cdtr: Some(receiver_party(entry)),
+                cdtr_acct: Some(receiver_account(entry)),
+                rmt_inf: remittance(entry),
+                ..Default::default()
+            });
+        }
+        count += batch.entries.len();
+        sum += batch.total_credit();
+        payments.push(PaymentInstruction44 {
+            pmt_inf_id: format!("{}-{}", h.company_identification, h.batch_number),
+            pmt_mtd: PaymentMethod3Code::CodeTRF,
+            btch_bookg: Some(true),
+            nb_of_txs: Some(batch.entries.len().to_string()),
+            ctrl_sum: Some(batch.total_credit() as f64 / 100.0),
+            pmt_tp_inf: Some(PaymentTypeInformation26 {
+                lcl_instrm: Some(local_instrument(&h.sec_code)),
+                ctgy_purp: entry_description(h),
+                ..Default::default()
+            }),
+            reqd_exctn_dt: DateAndDateTime2Choice { dt: Some(h.effective_entry_date.clone()), ..Default::default() },
+            dbtr: company_party(h),
+            dbtr_agt: aba_agent(&odfi_routing_number(h)),
+            chrg_br: Some(ChargeBearerType1Code::CodeSLEV),
+            cdt_trf_tx_inf: transactions,
+            ..Default::default()
+        });
+    }
+    let destination = header.immediate_destination.trim();
+    Ok(CustomerCreditTransferInitiationV12 {
+        grp_hdr: GroupHeader114 {
+            msg_id: format!("{}{}{}", header.immediate_origin, header.creation_date.replace('-', ""), header.file_id_modifier),
+            cre_dt_tm: creation_date_time(header),
+            nb_of_txs: count.to_string(),
+            ctrl_sum: Some(sum as f64 / 100.0),
+            initg_pty: PartyIdentification272 {
+                nm: Some(header.immediate_origin_name.clone()).filter(|n| !n.is_empty()),
+                id: Some(Party52Choice {
+                    org_id: Some(OrganisationIdentification39 {
+                        othr: Some(vec![GenericOrganisationIdentification3 { id: header.immediate_origin.clone(), ..Default::default() }]),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            fwdg_agt: if destination.is_empty() { None } else { Some(aba_agent(destination)) },
+            ..Default::default()
+        },
+        pmt_inf: payments,
+        splmtry_data: None,
+    })
+}
+
+/// Translates a pain.001 into NACHA text, see `pain001_to_file`.
+pub fn pain001_to_nacha(document: &CustomerCreditTransferInitiationV12) -> Result<String, ValidationError> {
+    Ok(pain001_to_file(document)?.to_nacha())
+}
+
+/// Translates a pain.001 into a NACHA file with one credits only (220)
+/// batch per payment information block. `NbOfTxs` and `CtrlSum` of the
+/// group header and of every block are checked against the transactions
+/// first; the batch and file control records are computed.
+pub fn pain001_to_file(document: &CustomerCreditTransferInitiationV12) -> Result<NachaFile, ValidationError> {
+    let group = &document.grp_hdr;
+    let mut batches = Vec::with_capacity(document.pmt_inf.len());
+    let (mut count, mut sum) = (0, 0);
+    for (index, payment) in document.pmt_inf.iter().enumerate() {
+        let reference = &payment.pmt_inf_id;
+        if !matches!(payment.pmt_mtd, PaymentMethod3Code::CodeTRF) {
+            return Err(unmappable(format!("{}: only credit transfers (TRF) are sent as ACH credits", reference)));
+        }
+        let odfi = agent_routing_number(&payment.dbtr_agt).ok_or_else(|| unmappable(format!("{}: debtor agent must be identified by ABA routing number", reference)))?;
+        let company_id = company_identification(&payment.dbtr).ok_or_else(|| unmappable(format!("{}: debtor must carry the ACH company identification", reference)))?;
+        let payment_type = payment.pmt_tp_inf.as_ref();
+        let sec_code = sec_code(payment_type.and_then(|p| p.lcl_instrm.as_ref()));
+        let effective_date = payment
+            .reqd_exctn_dt
+            .dt
+            .clone()
+            .or_else(|| payment.reqd_exctn_dt.dt_tm.as_deref().map(|d| d[..10.min(d.len())].to_string()))
+            .ok_or_else(|| unmappable(format!("{}: requested execution date is required", reference)))?;
+        let header = BatchHeader {
+            service_class_code: 220,
+            company_name: payment.dbtr.nm.clone().unwrap_or_default(),
+            company_identification: company_id.to_string(),
+            company_entry_description: payment_type.and_then(|p| p.ctgy_purp.as_ref()).and_then(|c| c.prtry.clone().or(c.cd.clone())).unwrap_or_else(|| "PAYMENT".to_string()),
+            sec_code,
+            effective_entry_date: effective_date,
+            originator_status_code: '1',
+            originating_dfi_identification: odfi[..8].to_string(),
+            batch_number: index as u32 + 1,
+            ..Default::default()
+        };
+        let mut entries = Vec::with_capacity(payment.cdt_trf_tx_inf.len());
+        for (sequence, tx) in payment.cdt_trf_tx_inf.iter().enumerate() {
+            let amount = tx.amt.instd_amt.as_ref().ok_or_else(|| unmappable(format!("{}: instructed amount is required", tx.pmt_id.end_to_end_id)))?;
+            let trace = carried_trace_number(tx.pmt_id.instr_id.as_deref(), &header.originating_dfi_identification)
+                .unwrap_or_else(|| trace_number(&header.originating_dfi_identification, sequence + 1));
+            entries.push(credit_entry(
+                &tx.pmt_id.end_to_end_id,
+                tx.cdtr_agt.as_ref(),
+                tx.cdtr.as_ref(),
+                tx.cdtr_acct.as_ref(),
+                &tx.pmt_id.end_to_end_id,
+                cents(&tx.pmt_id.end_to_end_id, &amount.ccy, amount.value)?,
+                trace,
+                remittance_addenda(tx.rmt_inf.as_ref(), &header.sec_code, &tx.pmt_id.end_to_end_id)?,
+            )?);
+        }
+        let batch = Batch { header, entries, ..Default::default() };
+        check_totals(reference, payment.nb_of_txs.as_deref(), payment.ctrl_sum, batch.entries.len(), batch.total_credit())?;
+        count += batch.entries.len();
+        sum += batch.total_credit();
+        batches.push(batch);
+    }
+    check_totals(&group.msg_id, Some(&group.nb_of_txs), group.ctrl_sum, count, sum)?;
+    let first_odfi = batches.first().map(|b| odfi_routing_number(&b.header)).unwrap_or_default();
+    let destination = group.fwdg_agt.as_ref().and_then(agent_routing_number).map(|r| r.to_string()).unwrap_or(first_odfi);
+    let origin = company_identification(&group.initg_pty).map(|o| o.to_string()).unwrap_or_else(|| destination.clone());
+    let mut file = NachaFile {
+        header: file_header(&group.cre_dt_tm, &destination, &origin, group.initg_pty.nm.as_deref().unwrap_or_default()),
+        batches,
+        ..Default::default()
+    };
+    file.update_control_totals();
+    Ok(file)
+}
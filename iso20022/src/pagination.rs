@@ -0,0 +1,322 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Reassembly and splitting of camt.052.001.12 reports and camt.053.001.12
+// statements paginated with `GrpHdr/MsgPgntn`
+// (`open_payments_common::pagination`).
+//
+// Pages are matched by the `Id` of their first report or statement. Merging
+// appends the entries of the reports and statements with the same `Id` and
+// keeps each balance (type and date) once; the merged message is page 1 and
+// the last page.
+
+use open_payments_common::common::*;
+use open_payments_common::pagination::{page_number, PageAssembler};
+#[cfg(feature = "derive_clone")]
+use open_payments_common::pagination::{page_ranges, PageLimits};
+use open_payments_common::ValidationError;
+use open_payments_iso20022_camt::camt_052_001_12::BankToCustomerAccountReportV12;
+use open_payments_iso20022_camt::camt_053_001_12::BankToCustomerStatementV12;
+
+fn balance_key(balance: &CashBalance8) -> (Option<&str>, Option<&str>, Option<&str>) {
+    let tp = &balance.tp.cd_or_prtry;
+    (tp.cd.as_deref().or(tp.prtry.as_deref()), balance.dt.dt.as_deref(), balance.dt.dt_tm.as_deref())
+}
+
+/// Appends the balances not yet present, by type and date.
+fn merge_balances(target: &mut Vec<CashBalance8>, source: Vec<CashBalance8>) {
+    for balance in source {
+        if !target.iter().any(|b| balance_key(b) == balance_key(&balance)) {
+            target.push(balance);
+        }
+    }
+}
+
+fn merge_entries(target: &mut Option<Vec<ReportEntry14>>, source: Option<Vec<ReportEntry14>>) {
+    if let Some(entries) = source {
+        target.get_or_insert_with(Vec::new).extend(entries);
+    }
+}
+
+fn single_page() -> Option<Pagination1> {
+    Some(Pagination1 { pg_nb: "1".to_string(), last_pg_ind: true })
+}
+
+fn pagination(header: &GroupHeader116) -> Result<(u64, bool), ValidationError> {
+    match header.msg_pgntn {
+        Some(ref p) => Ok((page_number(&p.pg_nb)?, p.last_pg_ind)),
+        None => Ok((1, true)),
+    }
+}
+
+/// Merges the pages of a report, in page order, into one message.
+pub fn merge_reports(pages: Vec<BankToCustomerAccountReportV12>) -> Option<BankToCustomerAccountReportV12> {
+    let mut pages = pages.into_iter();
+    let mut merged = pages.next()?;
+    for page in pages {
+        for report in page.rpt {
+            match merged.rpt.iter_mut().find(|r| r.id == report.id) {
+                Some(target) => {
+                    if let Some(balances) = report.bal {
+                        merge_balances(target.bal.get_or_insert_with(Vec::new), balances);
+                    }
+                    if target.txs_summry.is_none() {
+                        target.txs_summry = report.txs_summry;
+                    }
+                    merge_entries(&mut target.ntry, report.ntry);
+                    target.rpt_pgntn = None;
+                }
+                None => merged.rpt.push(report),
+            }
+        }
+        if let Some(data) = page.splmtry_data {
+            merged.splmtry_data.get_or_insert_with(Vec::new).extend(data);
+        }
+    }
+    merged.grp_hdr.msg_pgntn = single_page();
+    Some(merged)
+}
+
+/// Merges the pages of a statement, in page order, into one message.
+pub fn merge_statements(pages: Vec<BankToCustomerStatementV12>) -> Option<BankToCustomerStatementV12> {
+    let mut pages = pages.into_iter();
+    let mut merged = pages.next()?;
+    for page in pages {
+        for statement in page.stmt {
+            match merged.stmt.iter_mut().find(|s| s.id == statement.id) {
+                Some(target) => {
+                    merge_balances(&mut target.bal, statement.bal);
+                    if target.txs_summry.is_none() {
+                        target.txs_summry = statement.txs_summry;
+                    }
+                    merge_entries(&mut target.ntry, statement.ntry);
+                    target.stmt_pgntn = None;
+                }
+                None => merged.stmt.push(statement),
+            }
+        }
+        if let Some(data) = page.splmtry_data {
+            merged.splmtry_data.get_or_insert_with(Vec::new).extend(data);
+        }
+    }
+    merged.grp_hdr.msg_pgntn = single_page();
+    Some(merged)
+}
+
+/// Collects camt.052 pages and yields the merged report once every page
+/// has arrived.
+#[derive(Default)]
+pub struct ReportAssembler {
+    pages: PageAssembler<BankToCustomerAccountReportV12>,
+}
+
+impl ReportAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a page, keyed by the `Id` of its first report. A message without
+    /// `MsgPgntn` is complete on its own.
+    pub fn add(&mut self, page: BankToCustomerAccountReportV12) -> Result<Option<BankToCustomerAccountReportV12>, ValidationError> {
+        let key = page.rpt.first().map(|r| r.id.clone()).unwrap_or_else(|| page.grp_hdr.msg_id.clone());
+        self.add_with_key(&key, page)
+    }
+
+    /// Adds a page under a key chosen by the caller, e.g. the original
+    /// business query.
+    pub fn add_with_key(&mut self, key: &str, page: BankToCustomerAccountReportV12) -> Result<Option<BankToCustomerAccountReportV12>, ValidationError> {
+        let (number, last) = pagination(&page.grp_hdr)?;
+        Ok(self.pages.add(key, number, last, page)?.and_then(merge_reports))
+    }
+
+    pub fn pages(&self) -> &PageAssembler<BankToCustomerAccountReportV12> {
+        &self.pages
+    }
+
+    pub fn pages_mut(&mut self) -> &mut PageAssembler<BankToCustomerAccountReportV12> {
+        &mut self.pages
+    }
+}
+
+/// Collects camt.053 pages and yields the merged statement once every page
+/// has arrived.
+#[derive(Default)]
+pub struct StatementAssembler {
+    pages: PageAssembler<BankToCustomerStatementV12>,
+}
+
+impl StatementAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a page, keyed by the `Id` of its first statement. A message
+    /// without `MsgPgntn` is complete on its own.
+    pub fn add(&mut self, page: BankToCustomerStatementV12) -> Result<Option<BankToCustomerStatementV12>, ValidationError> {
+        let key = page.stmt.first().map(|s| s.id.clone()).unwrap_or_else(|| page.grp_hdr.msg_id.clone());
+        self.add_with_key(&key, page)
+    }
+
+    /// Adds a page under a key chosen by the caller.
+    pub fn add_with_key(&mut self, key: &str, page: BankToCustomerStatementV12) -> Result<Option<BankToCustomerStatementV12>, ValidationError> {
+        let (number, last) = pagination(&page.grp_hdr)?;
+        Ok(self.pages.add(key, number, last, page)?.and_then(merge_statements))
+    }
+
+    pub fn pages(&self) -> &PageAssembler<BankToCustomerStatementV12> {
+        &self.pages
+    }
+
+    pub fn pages_mut(&mut self) -> &mut PageAssembler<BankToCustomerStatementV12> {
+        &mut self.pages
+    }
+}
+
+/// `MsgId` of a page: the original identification with the page number
+/// appended, within the 35 characters of Max35Text.
+#[cfg(feature = "derive_clone")]
+fn page_message_id(msg_id: &str, page: usize) -> String {
+    let suffix = format!("-{}", page);
+    let base: String = msg_id.chars().take(35 - suffix.len()).collect();
+    format!("{}{}", base, suffix)
+}
+
+/// Pages of a message whose reports or statements were flattened to one
+/// item per entry (or one item without entry for those without entries).
+#[cfg(feature = "derive_clone")]
+fn paginate<R: Clone>(
+    headers: &[R],
+    items: Vec<(usize, Option<ReportEntry14>)>,
+    limits: &PageLimits,
+    entry_size: impl Fn(&ReportEntry14) -> usize,
+    continued: impl Fn(&mut R),
+    entries: impl Fn(&mut R) -> &mut Option<Vec<ReportEntry14>>,
+) -> Result<Vec<Vec<R>>, ValidationError> {
+    let sizes: Vec<usize> = items.iter().map(|(_, e)| e.as_ref().map(&entry_size).unwrap_or(0)).collect();
+    let ranges = page_ranges(&sizes, limits)?;
+    let mut items = items.into_iter();
+    let mut started = vec![false; headers.len()];
+    let mut pages = Vec::new();
+    for range in ranges {
+        let mut page: Vec<(usize, R)> = Vec::new();
+        for (index, entry) in items.by_ref().take(range.len()) {
+            if page.last().is_none_or(|(i, _)| *i != index) {
+                let mut header = headers[index].clone();
+                if started[index] {
+                    continued(&mut header);
+                }
+                started[index] = true;
+                page.push((index, header));
+            }
+            if let (Some(entry), Some((_, header))) = (entry, page.last_mut()) {
+                entries(header).get_or_insert_with(Vec::new).push(entry);
+            }
+        }
+        // Every page starts with the first report or statement so that the
+        // pages can be matched by its `Id`.
+        if !headers.is_empty() && page.first().is_none_or(|(i, _)| *i != 0) {
+            let mut header = headers[0].clone();
+            continued(&mut header);
+            page.insert(0, (0, header));
+        }
+        pages.push(page.into_iter().map(|(_, r)| r).collect());
+    }
+    Ok(pages)
+}
+
+/// Splits a camt.052 report into pages under the limits. `entry_size`
+/// measures an entry for `PageLimits::max_size`, e.g. its serialized length.
+/// Entries stay in order; every page repeats the report headers and
+/// balances, `TxsSummry` is only on the first page of each report.
+#[cfg(feature = "derive_clone")]
+pub fn split_report(
+    report: BankToCustomerAccountReportV12,
+    limits: &PageLimits,
+    entry_size: impl Fn(&ReportEntry14) -> usize,
+) -> Result<Vec<BankToCustomerAccountReportV12>, ValidationError> {
+    let BankToCustomerAccountReportV12 { grp_hdr, rpt, splmtry_data } = report;
+    let mut headers = Vec::new();
+    let mut items = Vec::new();
+    for mut r in rpt {
+        let entries = r.ntry.take().unwrap_or_default();
+        if entries.is_empty() {
+            items.push((headers.len(), None));
+        }
+        items.extend(entries.into_iter().map(|e| (headers.len(), Some(e))));
+        r.rpt_pgntn = None;
+        headers.push(r);
+    }
+    let pages = paginate(&headers, items, limits, entry_size, |r| r.txs_summry = None, |r| &mut r.ntry)?;
+    let total = pages.len();
+    let mut splmtry_data = splmtry_data;
+    Ok(pages
+        .into_iter()
+        .enumerate()
+        .map(|(i, rpt)| {
+            let mut grp_hdr = grp_hdr.clone();
+            if total > 1 {
+                grp_hdr.msg_id = page_message_id(&grp_hdr.msg_id, i + 1);
+            }
+            grp_hdr.msg_pgntn = Some(Pagination1 { pg_nb: (i + 1).to_string(), last_pg_ind: i + 1 == total });
+            BankToCustomerAccountReportV12 { grp_hdr, rpt, splmtry_data: splmtry_data.take() }
+        })
+        .collect())
+}
+
+/// Splits a camt.053 statement into pages under the limits, like
+/// `split_report`.
+#[cfg(feature = "derive_clone")]
+pub fn split_statement(
+    statement: BankToCustomerStatementV12,
+    limits: &PageLimits,
+    entry_size: impl Fn(&ReportEntry14) -> usize,
+) -> Result<Vec<BankToCustomerStatementV12>, ValidationError> {
+    let BankToCustomerStatementV12 { grp_hdr, stmt, splmtry_data } = statement;
+    let mut headers = Vec::new();
+    let mut items = Vec::new();
+    for mut s in stmt {
+        let entries = s.ntry.take().unwrap_or_default();
+        if entries.is_empty() {
+            items.push((headers.len(), None));
+        }
+        items.extend(entries.into_iter().map(|e| (headers.len(), Some(e))));
+        s.stmt_pgntn = None;
+        headers.push(s);
+    }
+    let pages = paginate(&headers, items, limits, entry_size, |s| s.txs_summry = None, |s| &mut s.ntry)?;
+    let total = pages.len();
+    let mut splmtry_data = splmtry_data;
+    Ok(pages
+        .into_iter()
+        .enumerate()
+        .map(|(i, stmt)| {
+            let mut grp_hdr = grp_hdr.clone();
+            if total > 1 {
+                grp_hdr.msg_id = page_message_id(&grp_hdr.msg_id, i + 1);
+            }
+            grp_hdr.msg_pgntn = Some(Pagination1 { pg_nb: (i + 1).to_string(), last_pg_ind: i + 1 == total });
+            BankToCustomerStatementV12 { grp_hdr, stmt, splmtry_data: splmtry_data.take() }
+        })
+        .collect())
+}
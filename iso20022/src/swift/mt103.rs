@@ -0,0 +1,200 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// MT103 -> pacs.008.001.12 (FIToFICustomerCreditTransferV12).
+
+use open_payments_common::common::*;
+use open_payments_common::swift::mt103::Mt103;
+use open_payments_common::swift::FinMessage;
+use open_payments_common::ValidationError;
+use open_payments_iso20022_pacs::pacs_008_001_12::FIToFICustomerCreditTransferV12;
+
+use crate::swift::*;
+
+pub const PACS_008_MSG_DEF_IDR: &str = "pacs.008.001.12";
+
+// 23E codes addressed to the creditor agent, the others go to the next agent.
+const CREDITOR_AGENT_CODES: [&str; 4] = ["CHQB", "HOLD", "PHOB", "TELB"];
+
+fn charge_bearer(code: &str) -> ChargeBearerType1Code {
+    match code {
+        "OUR" => ChargeBearerType1Code::CodeDEBT,
+        "BEN" => ChargeBearerType1Code::CodeCRED,
+        _ => ChargeBearerType1Code::CodeSHAR,
+    }
+}
+
+/// Parses MT103 FIN text and translates it, see `mt103_to_pacs008`.
+pub fn fin_to_pacs008(input: &str) -> Result<(BusinessApplicationHeaderV02, FIToFICustomerCreditTransferV12), ValidationError> {
+    mt103_to_pacs008(&FinMessage::parse(input)?)
+}
+
+/// Translates an MT103 into a pacs.008 with its business application header.
+///
+/// The UETR comes from block 3 field 121, the end-to-end identification
+/// from the `/ROC/` code word of field 70 (`NOTPROVIDED` otherwise). Sender
+/// and receiver become the instructing and instructed agents; when 52a or
+/// 57a are absent they also act as debtor and creditor agent.
+pub fn mt103_to_pacs008(message: &FinMessage) -> Result<(BusinessApplicationHeaderV02, FIToFICustomerCreditTransferV12), ValidationError> {
+    let mt = Mt103::from_fin(message)?;
+    let sender = message.sender_bic();
+    let receiver = message.receiver_bic();
+    let cre_dt_tm = creation_date_time(message);
+
+    let mut tx = CreditTransferTransaction64 {
+        pmt_id: PaymentIdentification13 {
+            instr_id: Some(mt.senders_reference.clone()),
+            end_to_end_id: mt.end_to_end_reference().unwrap_or("NOTPROVIDED").to_string(),
+            tx_id: Some(mt.senders_reference.clone()),
+            uetr: message.uetr().map(|u| u.to_string()),
+            ..Default::default()
+        },
+        intr_bk_sttlm_amt: ActiveCurrencyAndAmount {
+            ccy: mt.value_date_amount.currency.clone(),
+            value: mt.value_date_amount.amount,
+        },
+        intr_bk_sttlm_dt: Some(mt.value_date_amount.date.clone()),
+        instd_amt: mt.instructed_amount.as_ref().map(|a| ActiveOrHistoricCurrencyAndAmount { ccy: a.currency.clone(), value: a.amount }),
+        xchg_rate: mt.exchange_rate,
+        chrg_br: charge_bearer(&mt.details_of_charges),
+        instg_agt: Some(bic_agent(&sender)),
+        instd_agt: Some(bic_agent(&receiver)),
+        dbtr: party_to_customer(&mt.ordering_customer),
+        dbtr_acct: party_to_customer_account(&mt.ordering_customer),
+        dbtr_agt: mt.ordering_institution.as_ref().map(party_to_agent).unwrap_or_else(|| bic_agent(&sender)),
+        dbtr_agt_acct: mt.ordering_institution.as_ref().and_then(party_to_agent_account),
+        cdtr_agt: mt.account_with_institution.as_ref().map(party_to_agent).unwrap_or_else(|| bic_agent(&receiver)),
+        cdtr_agt_acct: mt.account_with_institution.as_ref().and_then(party_to_agent_account),
+        cdtr: party_to_customer(&mt.beneficiary_customer),
+        cdtr_acct: party_to_customer_account(&mt.beneficiary_customer),
+        ..Default::default()
+    };
+
+    if let Some(ref sending) = mt.sending_institution {
+        tx.prvs_instg_agt1 = Some(party_to_agent(sending));
+    }
+    if let Some(ref intermediary) = mt.intermediary_institution {
+        tx.intrmy_agt1 = Some(party_to_agent(intermediary));
+        tx.intrmy_agt1_acct = party_to_agent_account(intermediary);
+    }
+
+    let mut payment_type = PaymentTypeInformation28::default();
+    if mt.bank_operation_code == "SPRI" || mt.bank_operation_code == "SSTD" || mt.bank_operation_code == "SPAY" {
+        payment_type.svc_lvl = Some(vec![ServiceLevel8Choice { prtry: Some(mt.bank_operation_code.clone()), ..Default::default() }]);
+    }
+    if let Some(ref code) = mt.transaction_type_code {
+        payment_type.ctgy_purp = Some(CategoryPurpose1Choice { prtry: Some(code.clone()), ..Default::default() });
+    }
+    if payment_type.svc_lvl.is_some() || payment_type.ctgy_purp.is_some() {
+        tx.pmt_tp_inf = Some(payment_type);
+    }
+
+    let mut creditor_agent_instructions = Vec::new();
+    let mut next_agent_instructions = Vec::new();
+    for instruction in &mt.instruction_codes {
+        let text = instruction.to_field_value();
+        if CREDITOR_AGENT_CODES.contains(&instruction.code.as_str()) {
+            creditor_agent_instructions.push(InstructionForCreditorAgent3 { instr_inf: Some(text), ..Default::default() });
+        } else {
+            next_agent_instructions.push(InstructionForNextAgent1 { instr_inf: Some(text), ..Default::default() });
+        }
+    }
+    for line in &mt.sender_to_receiver_information {
+        next_agent_instructions.push(InstructionForNextAgent1 { instr_inf: Some(line.clone()), ..Default::default() });
+    }
+    if !creditor_agent_instructions.is_empty() {
+        tx.instr_for_cdtr_agt = Some(creditor_agent_instructions);
+    }
+    if !next_agent_instructions.is_empty() {
+        tx.instr_for_nxt_agt = Some(next_agent_instructions);
+    }
+
+    let mut charges = Vec::new();
+    for charge in &mt.senders_charges {
+        charges.push(Charges16 {
+            amt: ActiveOrHistoricCurrencyAndAmount { ccy: charge.currency.clone(), value: charge.amount },
+            agt: bic_agent(&sender),
+            ..Default::default()
+        });
+    }
+    if let Some(ref charge) = mt.receivers_charges {
+        charges.push(Charges16 {
+            amt: ActiveOrHistoricCurrencyAndAmount { ccy: charge.currency.clone(), value: charge.amount },
+            agt: bic_agent(&receiver),
+            ..Default::default()
+        });
+    }
+    if !charges.is_empty() {
+        tx.chrgs_inf = Some(charges);
+    }
+
+    let remittance = mt.remittance_text();
+    if !remittance.is_empty() {
+        tx.rmt_inf = Some(RemittanceInformation22 {
+            ustrd: Some(vec![remittance.chars().take(140).collect()]),
+            ..Default::default()
+        });
+    }
+
+    let settlement_method = if mt.senders_correspondent.is_some() || mt.receivers_correspondent.is_some() {
+        SettlementMethod1Code::CodeCOVE
+    } else {
+        SettlementMethod1Code::CodeINDA
+    };
+    let mut settlement = SettlementInstruction15 { sttlm_mtd: settlement_method, ..Default::default() };
+    if let Some(ref correspondent) = mt.senders_correspondent {
+        if correspondent.option == Some('B') || (correspondent.identifier_code.is_none() && correspondent.name_and_address.is_empty()) {
+            // 53B with an account only: the account serviced for the sender.
+            settlement.sttlm_acct = party_to_agent_account(correspondent);
+            if settlement.sttlm_acct.is_some() {
+                settlement.sttlm_mtd = SettlementMethod1Code::CodeINDA;
+            }
+        } else {
+            settlement.instg_rmbrsmnt_agt = Some(party_to_agent(correspondent));
+            settlement.instg_rmbrsmnt_agt_acct = party_to_agent_account(correspondent);
+        }
+    }
+    if let Some(ref correspondent) = mt.receivers_correspondent {
+        settlement.instd_rmbrsmnt_agt = Some(party_to_agent(correspondent));
+        settlement.instd_rmbrsmnt_agt_acct = party_to_agent_account(correspondent);
+    }
+    if let Some(ref reimbursement) = mt.third_reimbursement_institution {
+        settlement.thrd_rmbrsmnt_agt = Some(party_to_agent(reimbursement));
+        settlement.thrd_rmbrsmnt_agt_acct = party_to_agent_account(reimbursement);
+    }
+
+    let document = FIToFICustomerCreditTransferV12 {
+        grp_hdr: GroupHeader113 {
+            msg_id: mt.senders_reference.clone(),
+            cre_dt_tm: cre_dt_tm.clone(),
+            nb_of_txs: "1".to_string(),
+            sttlm_inf: settlement,
+            ..Default::default()
+        },
+        cdt_trf_tx_inf: vec![tx],
+        splmtry_data: None,
+    };
+    let header = business_application_header(message, &mt.senders_reference, PACS_008_MSG_DEF_IDR, &cre_dt_tm);
+    Ok((header, document))
+}
@@ -0,0 +1,491 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// MT940 / MT950 <-> camt.053.001.12 (BankToCustomerStatementV12) and
+// MT942 <-> camt.052.001.12 (BankToCustomerAccountReportV12).
+
+use open_payments_common::common::*;
+use open_payments_common::datetime::date_part;
+use open_payments_common::swift::fields::wrap_text;
+use open_payments_common::swift::mt940::{Balance, DebitCreditMark, EntrySummary, FloorLimit, Statement, StatementEntry, StatementLine, StatementType};
+use open_payments_common::swift::{to_x_charset, FinMessage};
+use open_payments_common::ValidationError;
+use open_payments_iso20022_camt::camt_052_001_12::BankToCustomerAccountReportV12;
+use open_payments_iso20022_camt::camt_053_001_12::BankToCustomerStatementV12;
+
+use crate::swift::*;
+
+pub const CAMT_052_MSG_DEF_IDR: &str = "camt.052.001.12";
+pub const CAMT_053_MSG_DEF_IDR: &str = "camt.053.001.12";
+
+fn date_choice(date: &str) -> DateAndDateTime2Choice {
+    DateAndDateTime2Choice { dt: Some(date.to_string()), ..Default::default() }
+}
+
+fn choice_date(choice: &DateAndDateTime2Choice) -> Option<String> {
+    choice.dt.clone().or_else(|| choice.dt_tm.as_deref().map(|d| date_part(d).to_string()))
+}
+
+fn credit_debit(credit: bool) -> CreditDebitCode {
+    if credit { CreditDebitCode::CodeCRDT } else { CreditDebitCode::CodeDBIT }
+}
+
+fn balance_to_iso(balance: &Balance, code: &str) -> CashBalance8 {
+    CashBalance8 {
+        tp: BalanceType13 {
+            cd_or_prtry: BalanceType10Choice { cd: Some(code.to_string()), ..Default::default() },
+            ..Default::default()
+        },
+        amt: ActiveOrHistoricCurrencyAndAmount { ccy: balance.currency.clone(), value: balance.amount },
+        cdt_dbt_ind: credit_debit(balance.credit),
+        dt: date_choice(&balance.date),
+        ..Default::default()
+    }
+}
+
+/// Balances in statement order: 60F is the opening booked balance (OPBD),
+/// 62F the closing booked balance (CLBD), intermediate 60M/62M balances are
+/// interim booked balances (ITBD), 64 is CLAV and 65 is FWAV.
+fn balances_to_iso(statement: &Statement) -> Vec<CashBalance8> {
+    let mut balances = Vec::new();
+    if let Some(ref balance) = statement.opening_balance {
+        balances.push(balance_to_iso(balance, if balance.intermediate { "ITBD" } else { "OPBD" }));
+    }
+    if let Some(ref balance) = statement.closing_balance {
+        balances.push(balance_to_iso(balance, if balance.intermediate { "ITBD" } else { "CLBD" }));
+    }
+    if let Some(ref balance) = statement.closing_available_balance {
+        balances.push(balance_to_iso(balance, "CLAV"));
+    }
+    balances.extend(statement.forward_available_balances.iter().map(|b| balance_to_iso(b, "FWAV")));
+    balances
+}
+
+fn entry_to_iso(entry: &StatementEntry, currency: &str) -> ReportEntry14 {
+    let line = &entry.line;
+    let reference = Some(line.account_owner_reference.clone()).filter(|r| r != "NONREF");
+    let details = if reference.is_some() || line.supplementary_details.is_some() {
+        Some(vec![EntryDetails13 {
+            tx_dtls: Some(vec![EntryTransaction14 {
+                refs: reference.map(|r| TransactionReferences6 { end_to_end_id: Some(r), ..Default::default() }),
+                addtl_tx_inf: line.supplementary_details.clone(),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }])
+    } else {
+        None
+    };
+    let information: String = entry.information.join("");
+    ReportEntry14 {
+        amt: ActiveOrHistoricCurrencyAndAmount { ccy: currency.to_string(), value: line.amount },
+        cdt_dbt_ind: credit_debit(line.mark.is_credit()),
+        rvsl_ind: if line.mark.is_reversal() { Some(true) } else { None },
+        sts: EntryStatus1Choice { cd: Some("BOOK".to_string()), ..Default::default() },
+        bookg_dt: line.entry_date.as_deref().map(date_choice),
+        val_dt: Some(date_choice(&line.value_date)),
+        acct_svcr_ref: line.servicing_institution_reference.clone(),
+        bk_tx_cd: BankTransactionCodeStructure4 {
+            prtry: Some(ProprietaryBankTransactionCodeStructure1 { cd: line.transaction_type.clone(), issr: Some("SWIFT".to_string()) }),
+            ..Default::default()
+        },
+        ntry_dtls: details,
+        addtl_ntry_inf: if information.is_empty() { None } else { Some(information.chars().take(500).collect()) },
+        ..Default::default()
+    }
+}
+
+fn account_to_iso(statement: &Statement, servicer: &str) -> CashAccount43 {
+    CashAccount43 {
+        id: Some(account_identification(&statement.account)),
+        ccy: statement.currency(),
+        ownr: statement.account_owner_bic.as_deref().map(any_bic_party),
+        svcr: Some(bic_agent(servicer)),
+        ..Default::default()
+    }
+}
+
+fn any_bic_party(bic: &str) -> PartyIdentification272 {
+    PartyIdentification272 {
+        id: Some(Party52Choice {
+            org_id: Some(OrganisationIdentification39 { any_bic: Some(bic.to_string()), ..Default::default() }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn party_any_bic(party: &PartyIdentification272) -> Option<&str> {
+    party.id.as_ref()?.org_id.as_ref()?.any_bic.as_deref()
+}
+
+fn group_header(statement: &Statement, message: &FinMessage, cre_dt_tm: &str) -> GroupHeader116 {
+    GroupHeader116 {
+        msg_id: statement.transaction_reference.clone(),
+        cre_dt_tm: cre_dt_tm.to_string(),
+        msg_rcpt: Some(any_bic_party(&message.receiver_bic())),
+        // Field 21 refers to the MT920 request that triggered the statement.
+        orgnl_biz_qry: statement.related_reference.as_ref().map(|r| OriginalBusinessQuery1 { msg_id: r.clone(), ..Default::default() }),
+        ..Default::default()
+    }
+}
+
+/// Parses MT940/MT950 FIN text and translates it, see `mt940_to_camt053`.
+pub fn fin_to_camt053(input: &str) -> Result<(BusinessApplicationHeaderV02, BankToCustomerStatementV12), ValidationError> {
+    mt940_to_camt053(&FinMessage::parse(input)?)
+}
+
+/// Translates an MT940 or MT950 into a camt.053 with one statement. The
+/// statement number (28C) becomes the legal and electronic sequence number,
+/// the sequence number the page number and a final closing balance (62F)
+/// marks the last page. Statement lines are booked entries.
+pub fn mt940_to_camt053(message: &FinMessage) -> Result<(BusinessApplicationHeaderV02, BankToCustomerStatementV12), ValidationError> {
+    let statement = Statement::from_fin(message)?;
+    if statement.statement_type == StatementType::Mt942 {
+        return Err(ValidationError::new(ERR_UNMAPPABLE, "an MT942 translates into a camt.052, see mt942_to_camt052".to_string()));
+    }
+    let currency = statement.currency().unwrap_or_default();
+    let cre_dt_tm = creation_date_time(message);
+    let fr_to_dt = match (&statement.opening_balance, &statement.closing_balance) {
+        (Some(opening), Some(closing)) => Some(DateTimePeriod1 {
+            fr_dt_tm: format!("{}T00:00:00", opening.date),
+            to_dt_tm: format!("{}T23:59:59", closing.date),
+        }),
+        _ => None,
+    };
+    let entries: Vec<ReportEntry14> = statement.entries.iter().map(|e| entry_to_iso(e, &currency)).collect();
+    let information = statement.information.join("");
+    let document = BankToCustomerStatementV12 {
+        grp_hdr: group_header(&statement, message, &cre_dt_tm),
+        stmt: vec![AccountStatement13 {
+            id: statement.transaction_reference.clone(),
+            stmt_pgntn: Some(Pagination1 {
+                pg_nb: statement.sequence_number.unwrap_or(1).to_string(),
+                last_pg_ind: !statement.closing_balance.as_ref().is_some_and(|b| b.intermediate),
+            }),
+            elctrnc_seq_nb: Some(statement.statement_number as f64),
+            lgl_seq_nb: Some(statement.statement_number as f64),
+            cre_dt_tm: Some(cre_dt_tm.clone()),
+            fr_to_dt,
+            acct: account_to_iso(&statement, &message.sender_bic()),
+            bal: balances_to_iso(&statement),
+            ntry: if entries.is_empty() { None } else { Some(entries) },
+            addtl_stmt_inf: if information.is_empty() { None } else { Some(information.chars().take(500).collect()) },
+            ..Default::default()
+        }],
+        splmtry_data: None,
+    };
+    let header = business_application_header(message, &statement.transaction_reference, CAMT_053_MSG_DEF_IDR, &cre_dt_tm);
+    Ok((header, document))
+}
+
+fn summary_to_iso(summary: &EntrySummary) -> NumberAndSumOfTransactions1 {
+    NumberAndSumOfTransactions1 { nb_of_ntries: Some(summary.count.to_string()), sum: Some(summary.amount) }
+}
+
+/// Parses MT942 FIN text and translates it, see `mt942_to_camt052`.
+pub fn fin_to_camt052(input: &str) -> Result<(BusinessApplicationHeaderV02, BankToCustomerAccountReportV12), ValidationError> {
+    mt942_to_camt052(&FinMessage::parse(input)?)
+}
+
+/// Translates an MT942 into a camt.052 with one report. The date/time
+/// indication (13D) is the creation time of the report and the 90D/90C
+/// totals become the transaction summary. Floor limits (34F) have no
+/// counterpart and are dropped.
+pub fn mt942_to_camt052(message: &FinMessage) -> Result<(BusinessApplicationHeaderV02, BankToCustomerAccountReportV12), ValidationError> {
+    let statement = Statement::from_fin(message)?;
+    if statement.statement_type != StatementType::Mt942 {
+        return Err(ValidationError::new(ERR_UNMAPPABLE, "MT940 and MT950 translate into a camt.053, see mt940_to_camt053".to_string()));
+    }
+    let currency = statement.currency().unwrap_or_default();
+    let cre_dt_tm = statement.date_time_indication.clone().unwrap_or_else(|| creation_date_time(message));
+    let entries: Vec<ReportEntry14> = statement.entries.iter().map(|e| entry_to_iso(e, &currency)).collect();
+    let balances = balances_to_iso(&statement);
+    let summary = if statement.debit_summary.is_some() || statement.credit_summary.is_some() {
+        Some(TotalTransactions6 {
+            ttl_dbt_ntries: statement.debit_summary.as_ref().map(summary_to_iso),
+            ttl_cdt_ntries: statement.credit_summary.as_ref().map(summary_to_iso),
+            ..Default::default()
+        })
+    } else {
+        None
+    };
+    let information = statement.information.join("");
+    let document = BankToCustomerAccountReportV12 {
+        grp_hdr: group_header(&statement, message, &cre_dt_tm),
+        rpt: vec![AccountReport33 {
+            id: statement.transaction_reference.clone(),
+            rpt_pgntn: statement.sequence_number.map(|sequence| Pagination1 { pg_nb: sequence.to_string(), last_pg_ind: true }),
+            elctrnc_seq_nb: Some(statement.statement_number as f64),
+            lgl_seq_nb: Some(statement.statement_number as f64),
+            cre_dt_tm: Some(cre_dt_tm.clone()),
+            acct: account_to_iso(&statement, &message.sender_bic()),
+            bal: if balances.is_empty() { None } else { Some(balances) },
+            txs_summry: summary,
+            ntry: if entries.is_empty() { None } else { Some(entries) },
+            addtl_rpt_inf: if information.is_empty() { None } else { Some(information.chars().take(500).collect()) },
+            ..Default::default()
+        }],
+        splmtry_data: None,
+    };
+    let header = business_application_header(message, &statement.transaction_reference, CAMT_052_MSG_DEF_IDR, &cre_dt_tm);
+    Ok((header, document))
+}
+
+fn unmappable(message: &str) -> ValidationError {
+    ValidationError::new(ERR_UNMAPPABLE, message.to_string())
+}
+
+fn balance_from_iso(balance: &CashBalance8, intermediate: bool) -> Result<Balance, ValidationError> {
+    Ok(Balance {
+        intermediate,
+        credit: matches!(balance.cdt_dbt_ind, CreditDebitCode::CodeCRDT),
+        date: choice_date(&balance.dt).ok_or_else(|| unmappable("balance date is required"))?,
+        currency: balance.amt.ccy.clone(),
+        amount: balance.amt.value,
+    })
+}
+
+fn balance_code(balance: &CashBalance8) -> Option<&str> {
+    balance.tp.cd_or_prtry.cd.as_deref()
+}
+
+/// Opening (60a), closing (62a), closing available (64) and forward
+/// available (65) balances. OPBD and PRCD open and CLBD closes a final
+/// statement; otherwise the first and last ITBD are the intermediate
+/// opening and closing balances.
+#[allow(clippy::type_complexity)]
+fn balances_from_iso(balances: &[CashBalance8]) -> Result<(Option<Balance>, Option<Balance>, Option<Balance>, Vec<Balance>), ValidationError> {
+    let first_interim = balances.iter().position(|b| balance_code(b) == Some("ITBD"));
+    let last_interim = balances.iter().rposition(|b| balance_code(b) == Some("ITBD"));
+    let opening = match balances.iter().find(|b| matches!(balance_code(b), Some("OPBD") | Some("PRCD"))) {
+        Some(balance) => Some(balance_from_iso(balance, false)?),
+        None => first_interim.map(|i| balance_from_iso(&balances[i], true)).transpose()?,
+    };
+    let closing = match balances.iter().find(|b| balance_code(b) == Some("CLBD")) {
+        Some(balance) => Some(balance_from_iso(balance, false)?),
+        None => match last_interim {
+            // A single ITBD already serves as the opening balance when OPBD/PRCD is missing.
+            Some(i) if opening.as_ref().is_some_and(|o| !o.intermediate) || Some(i) != first_interim => Some(balance_from_iso(&balances[i], true)?),
+            _ => None,
+        },
+    };
+    let available = balances.iter().find(|b| balance_code(b) == Some("CLAV")).map(|b| balance_from_iso(b, false)).transpose()?;
+    let forward = balances.iter().filter(|b| balance_code(b) == Some("FWAV")).map(|b| balance_from_iso(b, false)).collect::<Result<Vec<_>, _>>()?;
+    Ok((opening, closing, available, forward))
+}
+
+fn is_transaction_type(code: &str) -> bool {
+    code.len() == 4 && code.starts_with(['N', 'S', 'F']) && code.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+fn entry_reference(entry: &ReportEntry14) -> String {
+    let details = entry.ntry_dtls.iter().flatten();
+    let transaction = details.clone().flat_map(|d| d.tx_dtls.iter().flatten()).find_map(|t| {
+        let refs = t.refs.as_ref()?;
+        refs.end_to_end_id
+            .clone()
+            .filter(|r| r != "NOTPROVIDED")
+            .or_else(|| refs.instr_id.clone())
+            .or_else(|| refs.acct_ownr_tx_id.clone())
+            .or_else(|| refs.pmt_inf_id.clone())
+    });
+    let batch = || details.clone().find_map(|d| d.btch.as_ref().and_then(|b| b.pmt_inf_id.clone().or_else(|| b.msg_id.clone())));
+    transaction.or_else(batch).map(|r| mt_reference(&r)).unwrap_or_else(|| "NONREF".to_string())
+}
+
+fn entry_from_iso(entry: &ReportEntry14, currency: &str) -> Result<StatementEntry, ValidationError> {
+    if entry.amt.ccy != currency {
+        return Err(unmappable(&format!("entry in {} cannot be reported on a statement in {}", entry.amt.ccy, currency)));
+    }
+    let value_date = entry
+        .val_dt
+        .as_ref()
+        .or(entry.bookg_dt.as_ref())
+        .and_then(choice_date)
+        .ok_or_else(|| unmappable("entry value date or booking date is required for field 61"))?;
+    let transaction_type = entry
+        .bk_tx_cd
+        .prtry
+        .as_ref()
+        .map(|p| p.cd.clone())
+        .filter(|c| is_transaction_type(c))
+        .unwrap_or_else(|| "NMSC".to_string());
+    let supplementary = entry
+        .ntry_dtls
+        .iter()
+        .flatten()
+        .flat_map(|d| d.tx_dtls.iter().flatten())
+        .find_map(|t| t.addtl_tx_inf.as_deref())
+        .map(|s| to_x_charset(s).chars().take(34).collect::<String>());
+    let information = entry.addtl_ntry_inf.as_deref().map(|i| wrap_text(&to_x_charset(i), 65, 6)).unwrap_or_default();
+    Ok(StatementEntry {
+        line: StatementLine {
+            value_date,
+            entry_date: entry.bookg_dt.as_ref().and_then(choice_date),
+            mark: DebitCreditMark::from_entry(matches!(entry.cdt_dbt_ind, CreditDebitCode::CodeCRDT), entry.rvsl_ind == Some(true)),
+            funds_code: None,
+            amount: entry.amt.value,
+            transaction_type,
+            account_owner_reference: entry_reference(entry),
+            servicing_institution_reference: entry.acct_svcr_ref.as_deref().map(|r| to_x_charset(r).chars().take(16).collect()),
+            supplementary_details: supplementary,
+        },
+        information,
+    })
+}
+
+fn is_booked(entry: &ReportEntry14) -> bool {
+    entry.sts.cd.as_deref() == Some("BOOK")
+}
+
+/// Sender (account servicer) and receiver BICs of the MT statement.
+fn addressing<'a>(header: &'a GroupHeader116, account: &'a CashAccount43) -> Result<(&'a str, &'a str), ValidationError> {
+    let sender = account
+        .svcr
+        .as_ref()
+        .and_then(agent_bic)
+        .ok_or_else(|| unmappable("account servicer must be identified by BIC to send an MT statement"))?;
+    let receiver = header
+        .msg_rcpt
+        .as_ref()
+        .and_then(party_any_bic)
+        .or_else(|| account.ownr.as_ref().and_then(party_any_bic))
+        .ok_or_else(|| unmappable("message recipient or account owner must be identified by AnyBIC to receive an MT statement"))?;
+    Ok((sender, receiver))
+}
+
+fn statement_number(legal: Option<f64>, electronic: Option<f64>) -> u32 {
+    legal.or(electronic).map(|n| n as u32).unwrap_or(1)
+}
+
+fn account_currency(account: &CashAccount43, balances: &[CashBalance8], entries: &[ReportEntry14]) -> Result<String, ValidationError> {
+    account
+        .ccy
+        .clone()
+        .or_else(|| balances.first().map(|b| b.amt.ccy.clone()))
+        .or_else(|| entries.first().map(|e| e.amt.ccy.clone()))
+        .ok_or_else(|| unmappable("account currency is unknown"))
+}
+
+fn camt053_to_statements(document: &BankToCustomerStatementV12, statement_type: StatementType) -> Result<Vec<FinMessage>, ValidationError> {
+    let header = &document.grp_hdr;
+    let mut messages = Vec::new();
+    for stmt in &document.stmt {
+        let (sender, receiver) = addressing(header, &stmt.acct)?;
+        let entries: Vec<&ReportEntry14> = stmt.ntry.iter().flatten().filter(|e| is_booked(e)).collect();
+        let currency = account_currency(&stmt.acct, &stmt.bal, stmt.ntry.as_deref().unwrap_or_default())?;
+        let (opening, closing, available, forward) = balances_from_iso(&stmt.bal)?;
+        if opening.is_none() || closing.is_none() {
+            return Err(unmappable("an MT statement requires an opening (OPBD, PRCD or ITBD) and a closing (CLBD or ITBD) booked balance"));
+        }
+        let information = stmt.addtl_stmt_inf.as_deref().map(|i| wrap_text(&to_x_charset(i), 65, 6)).unwrap_or_default();
+        let statement = Statement {
+            statement_type,
+            transaction_reference: mt_reference(&stmt.id),
+            related_reference: header.orgnl_biz_qry.as_ref().map(|q| mt_reference(&q.msg_id)),
+            account: stmt.acct.id.as_ref().and_then(identification_to_account).ok_or_else(|| unmappable("account identification is required for field 25"))?,
+            account_owner_bic: stmt.acct.ownr.as_ref().and_then(party_any_bic).map(|b| b.to_string()),
+            statement_number: statement_number(stmt.lgl_seq_nb, stmt.elctrnc_seq_nb),
+            sequence_number: stmt.stmt_pgntn.as_ref().and_then(|p| p.pg_nb.parse().ok()),
+            opening_balance: opening,
+            entries: entries.iter().map(|e| entry_from_iso(e, &currency)).collect::<Result<Vec<_>, _>>()?,
+            closing_balance: closing,
+            closing_available_balance: available,
+            forward_available_balances: forward,
+            information: if statement_type == StatementType::Mt950 { Vec::new() } else { information },
+            ..Default::default()
+        };
+        messages.push(statement.to_fin(sender, receiver));
+    }
+    Ok(messages)
+}
+
+/// Translates every statement of a camt.053 into an MT940, sent by the
+/// account servicer to the message recipient (or account owner). Only
+/// booked entries are reported; pending and information-only entries have
+/// no place in a booked statement.
+pub fn camt053_to_mt940(document: &BankToCustomerStatementV12) -> Result<Vec<FinMessage>, ValidationError> {
+    camt053_to_statements(document, StatementType::Mt940)
+}
+
+/// Same as `camt053_to_mt940` for an MT950, which carries no 86 information.
+pub fn camt053_to_mt950(document: &BankToCustomerStatementV12) -> Result<Vec<FinMessage>, ValidationError> {
+    camt053_to_statements(document, StatementType::Mt950)
+}
+
+fn summary_from_iso(summary: Option<&NumberAndSumOfTransactions1>, entries: &[StatementEntry], credit: bool, currency: &str) -> Option<EntrySummary> {
+    match summary {
+        Some(summary) => Some(EntrySummary {
+            count: summary.nb_of_ntries.as_deref().and_then(|n| n.parse().ok()).unwrap_or(0),
+            currency: currency.to_string(),
+            amount: summary.sum.unwrap_or(0.0),
+        }),
+        None => {
+            let matching: Vec<&StatementEntry> = entries.iter().filter(|e| e.line.mark.is_credit() == credit).collect();
+            if matching.is_empty() {
+                return None;
+            }
+            Some(EntrySummary {
+                count: matching.len() as u32,
+                currency: currency.to_string(),
+                amount: matching.iter().map(|e| e.line.amount).sum(),
+            })
+        }
+    }
+}
+
+/// Translates every report of a camt.052 into an MT942. Only booked entries
+/// are reported. The floor limit (34F) is set to zero in the account
+/// currency, meaning all entries are reported, and 90D/90C come from the
+/// transaction summary or are computed from the entries.
+pub fn camt052_to_mt942(document: &BankToCustomerAccountReportV12) -> Result<Vec<FinMessage>, ValidationError> {
+    let header = &document.grp_hdr;
+    let mut messages = Vec::new();
+    for rpt in &document.rpt {
+        let (sender, receiver) = addressing(header, &rpt.acct)?;
+        let booked: Vec<&ReportEntry14> = rpt.ntry.iter().flatten().filter(|e| is_booked(e)).collect();
+        let currency = account_currency(&rpt.acct, rpt.bal.as_deref().unwrap_or_default(), rpt.ntry.as_deref().unwrap_or_default())?;
+        let entries = booked.iter().map(|e| entry_from_iso(e, &currency)).collect::<Result<Vec<_>, _>>()?;
+        let summary = rpt.txs_summry.as_ref();
+        let statement = Statement {
+            statement_type: StatementType::Mt942,
+            transaction_reference: mt_reference(&rpt.id),
+            related_reference: header.orgnl_biz_qry.as_ref().map(|q| mt_reference(&q.msg_id)),
+            account: rpt.acct.id.as_ref().and_then(identification_to_account).ok_or_else(|| unmappable("account identification is required for field 25"))?,
+            account_owner_bic: rpt.acct.ownr.as_ref().and_then(party_any_bic).map(|b| b.to_string()),
+            statement_number: statement_number(rpt.lgl_seq_nb, rpt.elctrnc_seq_nb),
+            sequence_number: rpt.rpt_pgntn.as_ref().and_then(|p| p.pg_nb.parse().ok()),
+            floor_limits: vec![FloorLimit { currency: currency.clone(), mark: None, amount: 0.0 }],
+            date_time_indication: Some(rpt.cre_dt_tm.clone().unwrap_or_else(|| header.cre_dt_tm.clone())),
+            debit_summary: summary_from_iso(summary.and_then(|s| s.ttl_dbt_ntries.as_ref()), &entries, false, &currency),
+            credit_summary: summary_from_iso(summary.and_then(|s| s.ttl_cdt_ntries.as_ref()), &entries, true, &currency),
+            entries,
+            information: rpt.addtl_rpt_inf.as_deref().map(|i| wrap_text(&to_x_charset(i), 65, 6)).unwrap_or_default(),
+            ..Default::default()
+        };
+        messages.push(statement.to_fin(sender, receiver));
+    }
+    Ok(messages)
+}
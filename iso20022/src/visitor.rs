@@ -0,0 +1,63 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Visitors over every message of the enabled families. `Visitor` combines
+// the generated visitors of `open_payments_common` and of the family
+// crates, so a visitor written once walks any `Document`; the traits of the
+// disabled families are empty and implemented by every type.
+
+macro_rules! family_visitors {
+    ($($feature:literal => $family:ident, $visitor:ident, $visitor_mut:ident;)*) => {
+        $(
+            #[cfg(feature = $feature)]
+            pub use $family::visitor::{Visitor as $visitor, VisitorMut as $visitor_mut};
+            #[cfg(not(feature = $feature))]
+            pub trait $visitor {}
+            #[cfg(not(feature = $feature))]
+            impl<T: ?Sized> $visitor for T {}
+            #[cfg(not(feature = $feature))]
+            pub trait $visitor_mut {}
+            #[cfg(not(feature = $feature))]
+            impl<T: ?Sized> $visitor_mut for T {}
+        )*
+
+        pub trait Visitor: open_payments_common::visitor::Visitor $(+ $visitor)* {}
+        impl<T: ?Sized + open_payments_common::visitor::Visitor $(+ $visitor)*> Visitor for T {}
+
+        pub trait VisitorMut: open_payments_common::visitor::VisitorMut $(+ $visitor_mut)* {}
+        impl<T: ?Sized + open_payments_common::visitor::VisitorMut $(+ $visitor_mut)*> VisitorMut for T {}
+    };
+}
+
+family_visitors! {
+    "acmt" => open_payments_iso20022_acmt, AcmtVisitor, AcmtVisitorMut;
+    "admi" => open_payments_iso20022_admi, AdmiVisitor, AdmiVisitorMut;
+    "auth" => open_payments_iso20022_auth, AuthVisitor, AuthVisitorMut;
+    "camt" => open_payments_iso20022_camt, CamtVisitor, CamtVisitorMut;
+    "head" => open_payments_iso20022_head, HeadVisitor, HeadVisitorMut;
+    "pacs" => open_payments_iso20022_pacs, PacsVisitor, PacsVisitorMut;
+    "pain" => open_payments_iso20022_pain, PainVisitor, PainVisitorMut;
+    "reda" => open_payments_iso20022_reda, RedaVisitor, RedaVisitorMut;
+    "remt" => open_payments_iso20022_remt, RemtVisitor, RemtVisitorMut;
+}
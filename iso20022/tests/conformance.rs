@@ -0,0 +1,250 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications,
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Round trip conformance of the ISO 20022 messages in `tests/samples`:
+// each sample is parsed, validated, written back to XML with quick-xml and
+// parsed again, the two parsed messages must be equal and the written XML
+// must carry the same content as the sample (see `canonical`).
+//
+// A sample is named after its message definition identifier and is either
+// a `Document` or, for head.001, an `AppHdr`.
+
+use open_payments_common::common::BusinessApplicationHeaderV02;
+use open_payments_common::ValidationError;
+use open_payments_iso20022::document::Document;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The business areas, each of which has at least one sample.
+const AREAS: [&str; 8] = ["acmt", "admi", "auth", "camt", "head", "pacs", "pain", "reda"];
+
+const DOCUMENT_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:";
+
+/// Stack of the thread running the samples: deserializing the generated
+/// types takes more than the 2 MiB of a test thread in debug builds.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+#[derive(Deserialize)]
+#[serde(rename = "Document")]
+struct DocumentXml {
+    #[serde(rename = "$value")]
+    document: Document,
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Document")]
+struct DocumentXmlRef<'a> {
+    #[serde(rename = "@xmlns")]
+    xmlns: String,
+    #[serde(rename = "$value")]
+    document: &'a Document,
+}
+
+/// A parsed sample.
+enum Message {
+    AppHdr(Box<BusinessApplicationHeaderV02>),
+    Document(Box<Document>),
+}
+
+#[test]
+fn samples_round_trip() {
+    std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(round_trip_samples)
+        .expect("test thread")
+        .join()
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+}
+
+fn round_trip_samples() {
+    let mut failures = Vec::new();
+    let mut covered = BTreeSet::new();
+    for path in samples() {
+        let id = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default().to_string();
+        match round_trip(&path, &id) {
+            Ok(()) => {
+                covered.insert(id.split('.').next().unwrap_or_default().to_string());
+            }
+            Err(failure) => failures.push(format!("{}: {}", path.display(), failure)),
+        }
+    }
+    assert!(failures.is_empty(), "{} samples failed:\n{}", failures.len(), failures.join("\n"));
+
+    let missing: Vec<&str> = AREAS.iter().copied().filter(|area| !covered.contains(*area)).collect();
+    assert!(missing.is_empty(), "no sample of {}", missing.join(", "));
+}
+
+/// The samples, in file name order.
+fn samples() -> Vec<PathBuf> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/samples");
+    let mut paths: Vec<PathBuf> = fs::read_dir(&directory)
+        .expect("tests/samples")
+        .map(|entry| entry.expect("tests/samples").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "xml"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn round_trip(path: &Path, id: &str) -> Result<(), String> {
+    let xml = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let message = parse(&xml).map_err(|e| format!("parse: {}", e))?;
+    if let Message::Document(ref document) = message {
+        if document.message_definition_identifier() != Some(id) {
+            return Err(format!("parsed as {:?}", document.message_definition_identifier()));
+        }
+    }
+    validate(&message).map_err(|e| format!("validate: {} ({})", e.message, e.code))?;
+
+    let written = write(&message, id)?;
+    let reparsed = parse(&written).map_err(|e| format!("parse of the written XML: {}", e))?;
+    if to_json(&message)? != to_json(&reparsed)? {
+        return Err(format!("parsing the written XML gives another message:\n{}", written));
+    }
+
+    let (expected, actual) = (canonical(&xml)?, canonical(&written)?);
+    if let Some(line) = expected.iter().zip(actual.iter()).position(|(e, a)| e != a) {
+        return Err(format!("written XML differs at {}: expected {}", actual[line], expected[line]));
+    }
+    if expected.len() != actual.len() {
+        let line = expected.len().min(actual.len());
+        return Err(match expected.get(line) {
+            Some(missing) => format!("written XML lacks {}", missing),
+            None => format!("written XML adds {}", actual[line]),
+        });
+    }
+    Ok(())
+}
+
+fn parse(xml: &str) -> Result<Message, String> {
+    if canonical(xml)?.first().is_some_and(|root| root == "AppHdr") {
+        let header = quick_xml::de::from_str(xml).map_err(|e| e.to_string())?;
+        Ok(Message::AppHdr(Box::new(header)))
+    } else {
+        let document: DocumentXml = quick_xml::de::from_str(xml).map_err(|e| e.to_string())?;
+        Ok(Message::Document(Box::new(document.document)))
+    }
+}
+
+fn validate(message: &Message) -> Result<(), ValidationError> {
+    match message {
+        Message::AppHdr(header) => header.validate(),
+        Message::Document(document) => document.validate(),
+    }
+}
+
+fn to_json(message: &Message) -> Result<Value, String> {
+    match message {
+        Message::AppHdr(header) => serde_json::to_value(header),
+        Message::Document(document) => serde_json::to_value(document),
+    }
+    .map_err(|e| e.to_string())
+}
+
+fn write(message: &Message, id: &str) -> Result<String, String> {
+    match message {
+        Message::AppHdr(header) => quick_xml::se::to_string_with_root("AppHdr", header),
+        Message::Document(document) => {
+            let document = DocumentXmlRef { xmlns: format!("{}{}", DOCUMENT_NAMESPACE, id), document };
+            quick_xml::se::to_string(&document)
+        }
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// The content of an XML document as lines of element paths, with the
+/// attributes (`path/@name=value`) and text (`path=text`) of each element,
+/// in document order. Namespace declarations, which the generated types do
+/// not keep, and whitespace between elements are left out, and decimals
+/// lose their trailing zeros (`f64` amounts are written as `10.5` or `10`).
+fn canonical(xml: &str) -> Result<Vec<String>, String> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+    let mut lines = Vec::new();
+    let mut path: Vec<String> = Vec::new();
+    loop {
+        match reader.read_event().map_err(|e| e.to_string())? {
+            Event::Start(start) => open(&mut path, &start, &mut lines)?,
+            Event::Empty(start) => {
+                open(&mut path, &start, &mut lines)?;
+                path.pop();
+            }
+            Event::End(_) => {
+                path.pop();
+            }
+            Event::Text(text) => {
+                let text = text.unescape().map_err(|e| e.to_string())?;
+                if !text.trim().is_empty() {
+                    lines.push(format!("{}={}", path.join("/"), decimal(text.trim())));
+                }
+            }
+            Event::CData(text) => {
+                let text = String::from_utf8_lossy(&text).into_owned();
+                if !text.trim().is_empty() {
+                    lines.push(format!("{}={}", path.join("/"), decimal(text.trim())));
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(lines)
+}
+
+/// Pushes the element onto the path and adds its lines.
+fn open(path: &mut Vec<String>, start: &BytesStart, lines: &mut Vec<String>) -> Result<(), String> {
+    path.push(String::from_utf8_lossy(start.local_name().as_ref()).into_owned());
+    let element = path.join("/");
+    let mut attributes = Vec::new();
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(|e| e.to_string())?;
+        if attribute.key.as_namespace_binding().is_some() {
+            continue;
+        }
+        let name = String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned();
+        let value = attribute.unescape_value().map_err(|e| e.to_string())?;
+        attributes.push(format!("{}/@{}={}", element, name, decimal(&value)));
+    }
+    attributes.sort();
+    lines.push(element);
+    lines.extend(attributes);
+    Ok(())
+}
+
+fn decimal(text: &str) -> &str {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    match digits.split_once('.') {
+        Some((whole, fraction))
+            if !whole.is_empty() && whole.bytes().all(|b| b.is_ascii_digit()) && fraction.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            text.trim_end_matches('0').trim_end_matches('.')
+        }
+        _ => text,
+    }
+}
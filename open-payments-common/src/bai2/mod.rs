@@ -0,0 +1,640 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// BAI2 cash management balance reporting files.
+//
+// A file (01/99) holds groups (02/98) of accounts (03/49) with their
+// transaction details (16). Continuation records (88) are merged into the
+// record they continue. Amounts are kept in minor units as in the file; the
+// ISO 20022 and FedNow conversions live next to the generated messages.
+
+pub mod type_codes;
+
+use crate::datetime::{format_iso_date, parse_iso_date};
+use crate::swift::fields::currency_decimals;
+use crate::ValidationError;
+
+// Error codes raised while reading and converting BAI2 files.
+pub const ERR_RECORD_STRUCTURE: u32 = 2201;
+pub const ERR_FIELD_SYNTAX: u32 = 2202;
+pub const ERR_CONTROL_TOTAL: u32 = 2203;
+// Raised by the conversions when a message has no BAI2 counterpart.
+pub const ERR_UNMAPPABLE: u32 = 2204;
+
+fn structure_error(line: usize, message: &str) -> ValidationError {
+    ValidationError::new(ERR_RECORD_STRUCTURE, format!("line {}: {}", line, message))
+}
+
+fn field_error(line: usize, field: &str, value: &str) -> ValidationError {
+    ValidationError::new(ERR_FIELD_SYNTAX, format!("line {}: invalid {} {:?}", line, field, value))
+}
+
+/// Converts a `YYMMDD` date into an ISODate.
+pub fn parse_date(value: &str) -> Option<String> {
+    if value.len() != 6 || !value.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let year = 2000 + value[0..2].parse::<i64>().ok()?;
+    let date = format_iso_date(year, value[2..4].parse().ok()?, value[4..6].parse().ok()?);
+    parse_iso_date(&date).map(|_| date)
+}
+
+/// Converts an ISODate into `YYMMDD`.
+pub fn format_date(date: &str) -> String {
+    parse_iso_date(date).map(|(y, m, d)| format!("{:02}{:02}{:02}", y % 100, m, d)).unwrap_or_default()
+}
+
+/// Converts a `HHMM` time (`2400`/`9999` stand for end of day) into `hh:mm`.
+pub fn parse_time(value: &str) -> Option<String> {
+    if value.len() != 4 || !value.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    match value {
+        "2400" | "9999" => Some("23:59".to_string()),
+        _ => Some(format!("{}:{}", &value[0..2], &value[2..4])),
+    }
+}
+
+pub fn format_time(time: &str) -> String {
+    time.replace(':', "").chars().take(4).collect()
+}
+
+/// Converts an amount in minor units into a decimal amount of the currency.
+pub fn to_decimal(amount: i64, currency: &str) -> f64 {
+    amount as f64 / 10f64.powi(currency_decimals(currency) as i32)
+}
+
+/// Converts a decimal amount into minor units of the currency.
+pub fn to_minor_units(amount: f64, currency: &str) -> i64 {
+    (amount * 10f64.powi(currency_decimals(currency) as i32)).round() as i64
+}
+
+/// Funds type of a summary or detail amount, with its availability fields.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum FundsType {
+    /// `Z` or blank: availability unknown.
+    #[default]
+    Unknown,
+    /// `0`
+    Immediate,
+    /// `1`
+    OneDay,
+    /// `2`
+    TwoOrMoreDays,
+    /// `S`: amounts available immediately, in one day and in two or more days.
+    Distributed { immediate: i64, one_day: i64, two_or_more_days: i64 },
+    /// `V`: value date and optional time.
+    ValueDated { date: String, time: Option<String> },
+    /// `D`: amounts by number of days.
+    DistributedByDays(Vec<(u32, i64)>),
+}
+
+impl FundsType {
+    fn parse(reader: &mut FieldReader, line: usize) -> Result<Self, ValidationError> {
+        let code = reader.next();
+        let amount = |reader: &mut FieldReader| -> Result<i64, ValidationError> {
+            let value = reader.next();
+            parse_amount(value).ok_or_else(|| field_error(line, "availability amount", value))
+        };
+        Ok(match code {
+            "" | "Z" => FundsType::Unknown,
+            "0" => FundsType::Immediate,
+            "1" => FundsType::OneDay,
+            "2" => FundsType::TwoOrMoreDays,
+            "S" => FundsType::Distributed { immediate: amount(reader)?, one_day: amount(reader)?, two_or_more_days: amount(reader)? },
+            "V" => {
+                let date = reader.next();
+                let date = parse_date(date).ok_or_else(|| field_error(line, "value date", date))?;
+                let time = reader.next();
+                let time = if time.is_empty() { None } else { Some(parse_time(time).ok_or_else(|| field_error(line, "value time", time))?) };
+                FundsType::ValueDated { date, time }
+            }
+            "D" => {
+                let count = reader.next();
+                let count: usize = count.parse().map_err(|_| field_error(line, "number of distributions", count))?;
+                let mut distributions = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let days = reader.next();
+                    let days = days.parse().map_err(|_| field_error(line, "availability days", days))?;
+                    distributions.push((days, amount(reader)?));
+                }
+                FundsType::DistributedByDays(distributions)
+            }
+            other => return Err(field_error(line, "funds type", other)),
+        })
+    }
+
+    fn write(&self, out: &mut Vec<String>) {
+        match self {
+            FundsType::Unknown => out.push(String::new()),
+            FundsType::Immediate => out.push("0".to_string()),
+            FundsType::OneDay => out.push("1".to_string()),
+            FundsType::TwoOrMoreDays => out.push("2".to_string()),
+            FundsType::Distributed { immediate, one_day, two_or_more_days } => {
+                out.extend(["S".to_string(), immediate.to_string(), one_day.to_string(), two_or_more_days.to_string()])
+            }
+            FundsType::ValueDated { date, time } => {
+                out.extend(["V".to_string(), format_date(date), time.as_deref().map(format_time).unwrap_or_default()])
+            }
+            FundsType::DistributedByDays(distributions) => {
+                out.push("D".to_string());
+                out.push(distributions.len().to_string());
+                for (days, amount) in distributions {
+                    out.push(days.to_string());
+                    out.push(amount.to_string());
+                }
+            }
+        }
+    }
+}
+
+fn parse_amount(value: &str) -> Option<i64> {
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+fn optional(value: &str) -> Option<String> {
+    if value.is_empty() { None } else { Some(value.to_string()) }
+}
+
+/// 01 File Header with the 99 File Trailer totals.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FileHeader {
+    pub sender_id: String,
+    pub receiver_id: String,
+    /// File creation date as ISODate.
+    pub creation_date: String,
+    /// File creation time as `hh:mm`.
+    pub creation_time: Option<String>,
+    pub file_id: String,
+    pub physical_record_length: Option<u32>,
+    pub block_size: Option<u32>,
+    /// Version number, always `2` for BAI2.
+    pub version: String,
+}
+
+/// 03 summary or status amount.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Summary {
+    pub type_code: String,
+    pub amount: Option<i64>,
+    pub item_count: Option<u32>,
+    pub funds_type: FundsType,
+}
+
+/// 16 Transaction Detail.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Transaction {
+    pub type_code: String,
+    pub amount: i64,
+    pub funds_type: FundsType,
+    pub bank_reference: Option<String>,
+    pub customer_reference: Option<String>,
+    /// Free text, with the text of continuation records appended.
+    pub text: Option<String>,
+}
+
+/// 03 Account Identifier and Summary/Status, its details and 49 Account Trailer.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Account {
+    pub account_number: String,
+    /// Currency of the account when it differs from the group currency.
+    pub currency: Option<String>,
+    pub summaries: Vec<Summary>,
+    pub transactions: Vec<Transaction>,
+    /// 49 account control total: sum of all 03 and 16 amounts.
+    pub control_total: i64,
+    /// 49 number of records, 03 to 49 including continuations.
+    pub number_of_records: u32,
+}
+
+/// 02 Group Header, its accounts and 98 Group Trailer.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Group {
+    pub ultimate_receiver_id: String,
+    pub originator_id: String,
+    /// 1 update, 2 deletion, 3 correction, 4 test only.
+    pub status: u8,
+    pub as_of_date: String,
+    pub as_of_time: Option<String>,
+    pub currency: Option<String>,
+    /// 1 interim previous-day, 2 final previous-day, 3 interim same-day, 4 final same-day.
+    pub as_of_date_modifier: Option<u8>,
+    pub accounts: Vec<Account>,
+    pub control_total: i64,
+    pub number_of_accounts: u32,
+    pub number_of_records: u32,
+}
+
+impl Group {
+    /// Currency of an account, defaulting to the group currency and USD.
+    pub fn account_currency(&self, account: &Account) -> String {
+        account.currency.clone().or_else(|| self.currency.clone()).unwrap_or_else(|| "USD".to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Bai2File {
+    pub header: FileHeader,
+    pub groups: Vec<Group>,
+    pub control_total: i64,
+    pub number_of_groups: u32,
+    pub number_of_records: u32,
+}
+
+/// Reads the comma separated fields of a record; the last field loses the
+/// `/` record delimiter.
+struct FieldReader<'a> {
+    rest: Option<&'a str>,
+}
+
+impl<'a> FieldReader<'a> {
+    fn new(content: &'a str) -> Self {
+        FieldReader { rest: Some(content) }
+    }
+
+    fn next(&mut self) -> &'a str {
+        match self.rest {
+            Some(rest) => match rest.find(',') {
+                Some(i) => {
+                    self.rest = Some(&rest[i + 1..]);
+                    &rest[..i]
+                }
+                None => {
+                    self.rest = None;
+                    rest.trim_end_matches('/')
+                }
+            },
+            None => "",
+        }
+    }
+
+    /// Remaining content of the record (the text field of a 16 record).
+    fn rest(&mut self) -> &'a str {
+        self.rest.take().unwrap_or("")
+    }
+
+    fn is_empty(&self) -> bool {
+        self.rest.is_none_or(|r| r.trim_end_matches('/').is_empty())
+    }
+}
+
+/// A logical record: the record and the continuation records merged into it.
+struct Record {
+    line: usize,
+    code: String,
+    content: String,
+    physical_records: u32,
+}
+
+fn split_records(input: &str) -> Result<Vec<Record>, ValidationError> {
+    let mut records: Vec<Record> = Vec::new();
+    for (index, raw) in input.lines().enumerate() {
+        let line = index + 1;
+        let raw = raw.trim_end();
+        if raw.is_empty() {
+            continue;
+        }
+        let (code, content) = raw.split_once(',').unwrap_or((raw.trim_end_matches('/'), ""));
+        match code {
+            "88" => {
+                let previous = records.last_mut().ok_or_else(|| structure_error(line, "continuation record without a record to continue"))?;
+                // Detail text continues as text, other records continue with their next field.
+                if previous.code == "16" {
+                    // Without text the detail record ends in ",/": the slash only
+                    // ends the record and the text starts on the continuation.
+                    let trimmed = previous.content.trim_end();
+                    if trimmed.ends_with(",/") {
+                        let len = trimmed.len() - 1;
+                        previous.content.truncate(len);
+                    } else {
+                        previous.content.push(' ');
+                    }
+                } else {
+                    let trimmed = previous.content.trim_end().trim_end_matches('/').len();
+                    previous.content.truncate(trimmed);
+                    previous.content.push(',');
+                }
+                previous.content.push_str(content);
+                previous.physical_records += 1;
+            }
+            "01" | "02" | "03" | "16" | "49" | "98" | "99" => {
+                records.push(Record { line, code: code.to_string(), content: content.to_string(), physical_records: 1 })
+            }
+            other => return Err(structure_error(line, &format!("unknown record type {:?}", other))),
+        }
+    }
+    Ok(records)
+}
+
+fn parse_count(value: &str, line: usize, field: &str) -> Result<u32, ValidationError> {
+    value.parse().map_err(|_| field_error(line, field, value))
+}
+
+fn parse_total(value: &str, line: usize, field: &str) -> Result<i64, ValidationError> {
+    parse_amount(value).ok_or_else(|| field_error(line, field, value))
+}
+
+fn parse_summaries(reader: &mut FieldReader, line: usize) -> Result<Vec<Summary>, ValidationError> {
+    let mut summaries = Vec::new();
+    while !reader.is_empty() {
+        let type_code = reader.next();
+        if type_code.is_empty() {
+            break;
+        }
+        if type_code.len() != 3 || !type_code.chars().all(|c| c.is_ascii_digit()) {
+            return Err(field_error(line, "type code", type_code));
+        }
+        let amount = reader.next();
+        let item_count = reader.next();
+        summaries.push(Summary {
+            type_code: type_code.to_string(),
+            amount: if amount.is_empty() { None } else { Some(parse_amount(amount).ok_or_else(|| field_error(line, "amount", amount))?) },
+            item_count: if item_count.is_empty() { None } else { Some(parse_count(item_count, line, "item count")?) },
+            funds_type: FundsType::parse(reader, line)?,
+        });
+    }
+    Ok(summaries)
+}
+
+fn parse_transaction(content: &str, line: usize) -> Result<Transaction, ValidationError> {
+    let mut reader = FieldReader::new(content);
+    let type_code = reader.next();
+    if type_code.len() != 3 || !type_code.chars().all(|c| c.is_ascii_digit()) {
+        return Err(field_error(line, "type code", type_code));
+    }
+    let amount = reader.next();
+    let amount = if amount.is_empty() { 0 } else { parse_amount(amount).ok_or_else(|| field_error(line, "amount", amount))? };
+    let funds_type = FundsType::parse(&mut reader, line)?;
+    let bank_reference = optional(reader.next());
+    let customer_reference = optional(reader.next());
+    let text = reader.rest().trim();
+    let text = text.strip_suffix('/').unwrap_or(text).trim_end();
+    Ok(Transaction { type_code: type_code.to_string(), amount, funds_type, bank_reference, customer_reference, text: optional(text) })
+}
+
+impl Account {
+    /// Sum of all 03 and 16 amounts, the value of the 49 account control total.
+    pub fn computed_control_total(&self) -> i64 {
+        self.summaries.iter().filter_map(|s| s.amount).sum::<i64>() + self.transactions.iter().map(|t| t.amount).sum::<i64>()
+    }
+}
+
+impl Group {
+    pub fn computed_control_total(&self) -> i64 {
+        self.accounts.iter().map(|a| a.control_total).sum()
+    }
+}
+
+impl Bai2File {
+    /// Parses a BAI2 file and verifies the control totals, the number of
+    /// accounts and groups and the record counts of every trailer.
+    pub fn parse(input: &str) -> Result<Self, ValidationError> {
+        let records = split_records(input)?;
+        let mut file = Bai2File::default();
+        let mut iter = records.iter().peekable();
+        let first = iter.next().ok_or_else(|| structure_error(1, "file is empty"))?;
+        if first.code != "01" {
+            return Err(structure_error(first.line, "file must start with a 01 file header"));
+        }
+        let mut reader = FieldReader::new(&first.content);
+        let sender_id = reader.next().to_string();
+        let receiver_id = reader.next().to_string();
+        let date = reader.next();
+        let time = reader.next();
+        let file_id = reader.next().to_string();
+        let record_length = reader.next();
+        let block_size = reader.next();
+        let version = reader.next().to_string();
+        if version != "2" {
+            return Err(field_error(first.line, "version number (expected 2)", &version));
+        }
+        file.header = FileHeader {
+            sender_id,
+            receiver_id,
+            creation_date: parse_date(date).ok_or_else(|| field_error(first.line, "file creation date", date))?,
+            creation_time: if time.is_empty() { None } else { Some(parse_time(time).ok_or_else(|| field_error(first.line, "file creation time", time))?) },
+            file_id,
+            physical_record_length: if record_length.is_empty() { None } else { Some(parse_count(record_length, first.line, "physical record length")?) },
+            block_size: if block_size.is_empty() { None } else { Some(parse_count(block_size, first.line, "block size")?) },
+            version,
+        };
+        let mut file_records = first.physical_records;
+        let mut closed = false;
+
+        while let Some(record) = iter.next() {
+            file_records += record.physical_records;
+            match record.code.as_str() {
+                "02" => {
+                    let mut reader = FieldReader::new(&record.content);
+                    let ultimate_receiver_id = reader.next().to_string();
+                    let originator_id = reader.next().to_string();
+                    let status = reader.next();
+                    let date = reader.next();
+                    let time = reader.next();
+                    let currency = optional(reader.next());
+                    let modifier = reader.next();
+                    let mut group = Group {
+                        ultimate_receiver_id,
+                        originator_id,
+                        status: status.parse().ok().filter(|s| (1..=4).contains(s)).ok_or_else(|| field_error(record.line, "group status", status))?,
+                        as_of_date: parse_date(date).ok_or_else(|| field_error(record.line, "as-of-date", date))?,
+                        as_of_time: if time.is_empty() { None } else { Some(parse_time(time).ok_or_else(|| field_error(record.line, "as-of-time", time))?) },
+                        currency,
+                        as_of_date_modifier: if modifier.is_empty() {
+                            None
+                        } else {
+                            Some(modifier.parse().ok().filter(|m| (1..=4).contains(m)).ok_or_else(|| field_error(record.line, "as-of-date modifier", modifier))?)
+                        },
+                        ..Default::default()
+                    };
+                    let mut group_records = record.physical_records;
+                    loop {
+                        let record = iter.next().ok_or_else(|| structure_error(record.line, "group is not closed by a 98 group trailer"))?;
+                        file_records += record.physical_records;
+                        group_records += record.physical_records;
+                        match record.code.as_str() {
+                            "03" => {
+                                let mut reader = FieldReader::new(&record.content);
+                                let mut account = Account {
+                                    account_number: reader.next().to_string(),
+                                    currency: optional(reader.next()),
+                                    summaries: parse_summaries(&mut reader, record.line)?,
+                                    ..Default::default()
+                                };
+                                let mut account_records = record.physical_records;
+                                while let Some(detail) = iter.next_if(|r| r.code == "16") {
+                                    file_records += detail.physical_records;
+                                    group_records += detail.physical_records;
+                                    account_records += detail.physical_records;
+                                    account.transactions.push(parse_transaction(&detail.content, detail.line)?);
+                                }
+                                let trailer = iter.next().filter(|r| r.code == "49").ok_or_else(|| structure_error(record.line, "account is not closed by a 49 account trailer"))?;
+                                file_records += trailer.physical_records;
+                                group_records += trailer.physical_records;
+                                account_records += trailer.physical_records;
+                                let mut reader = FieldReader::new(&trailer.content);
+                                account.control_total = parse_total(reader.next(), trailer.line, "account control total")?;
+                                account.number_of_records = parse_count(reader.next(), trailer.line, "number of records")?;
+                                verify(trailer.line, "account control total", account.control_total, account.computed_control_total())?;
+                                verify(trailer.line, "account number of records", account.number_of_records as i64, account_records as i64)?;
+                                group.accounts.push(account);
+                            }
+                            "98" => {
+                                let mut reader = FieldReader::new(&record.content);
+                                group.control_total = parse_total(reader.next(), record.line, "group control total")?;
+                                group.number_of_accounts = parse_count(reader.next(), record.line, "number of accounts")?;
+                                group.number_of_records = parse_count(reader.next(), record.line, "number of records")?;
+                                verify(record.line, "group control total", group.control_total, group.computed_control_total())?;
+                                verify(record.line, "group number of accounts", group.number_of_accounts as i64, group.accounts.len() as i64)?;
+                                verify(record.line, "group number of records", group.number_of_records as i64, group_records as i64)?;
+                                break;
+                            }
+                            other => return Err(structure_error(record.line, &format!("unexpected {} record inside a group", other))),
+                        }
+                    }
+                    file.groups.push(group);
+                }
+                "99" => {
+                    let mut reader = FieldReader::new(&record.content);
+                    file.control_total = parse_total(reader.next(), record.line, "file control total")?;
+                    file.number_of_groups = parse_count(reader.next(), record.line, "number of groups")?;
+                    file.number_of_records = parse_count(reader.next(), record.line, "number of records")?;
+                    verify(record.line, "file control total", file.control_total, file.groups.iter().map(|g| g.control_total).sum())?;
+                    verify(record.line, "file number of groups", file.number_of_groups as i64, file.groups.len() as i64)?;
+                    verify(record.line, "file number of records", file.number_of_records as i64, file_records as i64)?;
+                    closed = true;
+                    if let Some(extra) = iter.next() {
+                        return Err(structure_error(extra.line, "records after the 99 file trailer"));
+                    }
+                }
+                other => return Err(structure_error(record.line, &format!("unexpected {} record outside of a group", other))),
+            }
+        }
+        if !closed {
+            return Err(structure_error(records.last().map(|r| r.line).unwrap_or(1), "file is not closed by a 99 file trailer"));
+        }
+        Ok(file)
+    }
+
+    /// Recomputes every control total and record count from the content,
+    /// as needed before writing a file that was built programmatically.
+    /// Each record is counted once, continuation records are not used when writing.
+    pub fn update_control_totals(&mut self) {
+        let mut file_records = 2;
+        for group in &mut self.groups {
+            let mut group_records = 2;
+            for account in &mut group.accounts {
+                account.control_total = account.computed_control_total();
+                account.number_of_records = 2 + account.transactions.len() as u32;
+                group_records += account.number_of_records;
+            }
+            group.control_total = group.computed_control_total();
+            group.number_of_accounts = group.accounts.len() as u32;
+            group.number_of_records = group_records;
+            file_records += group_records;
+        }
+        self.control_total = self.groups.iter().map(|g| g.control_total).sum();
+        self.number_of_groups = self.groups.len() as u32;
+        self.number_of_records = file_records;
+    }
+
+    /// Writes the file with one physical record per logical record, using the
+    /// stored control totals (see `update_control_totals`).
+    pub fn to_bai2(&self) -> String {
+        let header = &self.header;
+        let mut lines = vec![record(
+            "01",
+            vec![
+                header.sender_id.clone(),
+                header.receiver_id.clone(),
+                format_date(&header.creation_date),
+                header.creation_time.as_deref().map(format_time).unwrap_or_default(),
+                header.file_id.clone(),
+                header.physical_record_length.map(|v| v.to_string()).unwrap_or_default(),
+                header.block_size.map(|v| v.to_string()).unwrap_or_default(),
+                header.version.clone(),
+            ],
+        )];
+        for group in &self.groups {
+            lines.push(record(
+                "02",
+                vec![
+                    group.ultimate_receiver_id.clone(),
+                    group.originator_id.clone(),
+                    group.status.to_string(),
+                    format_date(&group.as_of_date),
+                    group.as_of_time.as_deref().map(format_time).unwrap_or_default(),
+                    group.currency.clone().unwrap_or_default(),
+                    group.as_of_date_modifier.map(|m| m.to_string()).unwrap_or_default(),
+                ],
+            ));
+            for account in &group.accounts {
+                let mut fields = vec![account.account_number.clone(), account.currency.clone().unwrap_or_default()];
+                for summary in &account.summaries {
+                    fields.push(summary.type_code.clone());
+                    fields.push(summary.amount.map(|a| a.to_string()).unwrap_or_default());
+                    fields.push(summary.item_count.map(|c| c.to_string()).unwrap_or_default());
+                    summary.funds_type.write(&mut fields);
+                }
+                lines.push(record("03", fields));
+                for transaction in &account.transactions {
+                    let mut fields = vec![transaction.type_code.clone(), transaction.amount.to_string()];
+                    transaction.funds_type.write(&mut fields);
+                    fields.push(transaction.bank_reference.clone().unwrap_or_default());
+                    fields.push(transaction.customer_reference.clone().unwrap_or_default());
+                    // The text runs to the end of the record; without text the record ends with a slash.
+                    match transaction.text {
+                        Some(ref text) => lines.push(format!("16,{},{}", fields.join(","), text)),
+                        None => lines.push(format!("16,{},/", fields.join(","))),
+                    }
+                }
+                lines.push(record("49", vec![account.control_total.to_string(), account.number_of_records.to_string()]));
+            }
+            lines.push(record(
+                "98",
+                vec![group.control_total.to_string(), group.number_of_accounts.to_string(), group.number_of_records.to_string()],
+            ));
+        }
+        lines.push(record("99", vec![self.control_total.to_string(), self.number_of_groups.to_string(), self.number_of_records.to_string()]));
+        let mut out = lines.join("\n");
+        out.push('\n');
+        out
+    }
+}
+
+fn record(code: &str, fields: Vec<String>) -> String {
+    format!("{},{}/", code, fields.join(","))
+}
+
+fn verify(line: usize, what: &str, declared: i64, computed: i64) -> Result<(), ValidationError> {
+    if declared == computed {
+        Ok(())
+    } else {
+        Err(ValidationError::new(ERR_CONTROL_TOTAL, format!("line {}: {} is {} but the content adds up to {}", line, what, declared, computed)))
+    }
+}
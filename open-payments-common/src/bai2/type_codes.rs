@@ -0,0 +1,158 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// BAI2 type codes. The table covers the status, summary and detail codes in
+// common use; codes outside of it are classified by their range.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeCodeClass {
+    /// Account status (balances, float).
+    Status,
+    CreditSummary,
+    CreditDetail,
+    DebitSummary,
+    DebitDetail,
+}
+
+impl TypeCodeClass {
+    pub fn is_credit(&self) -> bool {
+        matches!(self, TypeCodeClass::CreditSummary | TypeCodeClass::CreditDetail)
+    }
+
+    pub fn is_debit(&self) -> bool {
+        matches!(self, TypeCodeClass::DebitSummary | TypeCodeClass::DebitDetail)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeCode {
+    pub code: &'static str,
+    pub class: TypeCodeClass,
+    pub description: &'static str,
+    /// ISO 20022 balance type (`BalTp/CdOrPrtry/Cd`) of a status code.
+    pub balance_type: Option<&'static str>,
+    /// ISO 20022 bank transaction code (domain, family, sub-family) of a detail code.
+    pub bank_transaction_code: Option<(&'static str, &'static str, &'static str)>,
+}
+
+const fn status(code: &'static str, description: &'static str, balance_type: Option<&'static str>) -> TypeCode {
+    TypeCode { code, class: TypeCodeClass::Status, description, balance_type, bank_transaction_code: None }
+}
+
+const fn summary(code: &'static str, class: TypeCodeClass, description: &'static str) -> TypeCode {
+    TypeCode { code, class, description, balance_type: None, bank_transaction_code: None }
+}
+
+const fn detail(code: &'static str, class: TypeCodeClass, description: &'static str, btc: Option<(&'static str, &'static str, &'static str)>) -> TypeCode {
+    TypeCode { code, class, description, balance_type: None, bank_transaction_code: btc }
+}
+
+use TypeCodeClass::{CreditDetail, CreditSummary, DebitDetail, DebitSummary};
+
+pub const TYPE_CODES: &[TypeCode] = &[
+    status("010", "Opening Ledger", Some("OPBD")),
+    status("011", "Average Opening Ledger MTD", None),
+    status("012", "Average Opening Ledger YTD", None),
+    status("015", "Closing Ledger", Some("CLBD")),
+    status("020", "Average Closing Ledger MTD", None),
+    status("025", "Average Closing Ledger YTD", None),
+    status("030", "Current Ledger", Some("ITBD")),
+    status("040", "Opening Available", Some("OPAV")),
+    status("041", "Average Opening Available MTD", None),
+    status("045", "Closing Available", Some("CLAV")),
+    status("050", "Average Closing Available MTD", None),
+    status("055", "Average Closing Available YTD", None),
+    status("057", "Investment Sweep Position", None),
+    status("060", "Current Available", Some("ITAV")),
+    status("072", "One Day Float", None),
+    status("074", "Two or More Days Float", None),
+    summary("100", CreditSummary, "Total Credits"),
+    summary("101", CreditSummary, "Total Credit Amount MTD"),
+    summary("105", CreditSummary, "Credits Not Detailed"),
+    detail("108", CreditDetail, "Credit (Any Type)", None),
+    summary("110", CreditSummary, "Total Lockbox Deposits"),
+    detail("115", CreditDetail, "Lockbox Deposit", Some(("PMNT", "LBDP", "OTHR"))),
+    summary("140", CreditSummary, "Total ACH Credits"),
+    detail("142", CreditDetail, "ACH Credit Received", Some(("PMNT", "RCDT", "ESCT"))),
+    detail("145", CreditDetail, "ACH Concentration Credit", Some(("PMNT", "RCDT", "ESCT"))),
+    detail("165", CreditDetail, "Preauthorized ACH Credit", Some(("PMNT", "RCDT", "ESCT"))),
+    detail("169", CreditDetail, "Miscellaneous ACH Credit", Some(("PMNT", "RCDT", "ESCT"))),
+    detail("175", CreditDetail, "Check Deposit Package", Some(("PMNT", "RCHQ", "OTHR"))),
+    summary("190", CreditSummary, "Total Incoming Money Transfers"),
+    detail("191", CreditDetail, "Individual Incoming Internal Money Transfer", Some(("PMNT", "RCDT", "BOOK"))),
+    detail("195", CreditDetail, "Incoming Money Transfer", Some(("PMNT", "RCDT", "DMCT"))),
+    detail("196", CreditDetail, "Money Transfer Adjustment", None),
+    detail("201", CreditDetail, "Individual Automatic Transfer Credit", Some(("PMNT", "RCDT", "BOOK"))),
+    detail("206", CreditDetail, "Book Transfer Credit", Some(("PMNT", "RCDT", "BOOK"))),
+    detail("208", CreditDetail, "Individual International Money Transfer Credit", Some(("PMNT", "RCDT", "XBCT"))),
+    detail("275", CreditDetail, "ZBA Credit", Some(("CAMT", "ACCB", "ZABA"))),
+    detail("301", CreditDetail, "Commercial Deposit", None),
+    detail("399", CreditDetail, "Miscellaneous Credit", None),
+    summary("400", DebitSummary, "Total Debits"),
+    summary("401", DebitSummary, "Total Debit Amount MTD"),
+    detail("408", DebitDetail, "Float Adjustment", None),
+    detail("409", DebitDetail, "Debit (Any Type)", None),
+    summary("450", DebitSummary, "Total ACH Debits"),
+    detail("451", DebitDetail, "ACH Debit Received", Some(("PMNT", "IDDT", "ESDD"))),
+    detail("455", DebitDetail, "Preauthorized ACH Debit", Some(("PMNT", "IDDT", "ESDD"))),
+    detail("469", DebitDetail, "Miscellaneous ACH Debit", Some(("PMNT", "IDDT", "ESDD"))),
+    summary("470", DebitSummary, "Total Check Paid"),
+    detail("475", DebitDetail, "Check Paid", Some(("PMNT", "ICHQ", "CCHQ"))),
+    summary("490", DebitSummary, "Total Outgoing Money Transfers"),
+    detail("495", DebitDetail, "Outgoing Money Transfer", Some(("PMNT", "ICDT", "DMCT"))),
+    detail("496", DebitDetail, "Money Transfer Adjustment", None),
+    detail("501", DebitDetail, "Individual Automatic Transfer Debit", Some(("PMNT", "ICDT", "BOOK"))),
+    detail("506", DebitDetail, "Book Transfer Debit", Some(("PMNT", "ICDT", "BOOK"))),
+    detail("508", DebitDetail, "Individual International Money Transfer Debit", Some(("PMNT", "ICDT", "XBCT"))),
+    detail("555", DebitDetail, "Deposited Item Returned", Some(("PMNT", "RCHQ", "UPCQ"))),
+    detail("575", DebitDetail, "ZBA Debit", Some(("CAMT", "ACCB", "ZABA"))),
+    detail("698", DebitDetail, "Miscellaneous Fee", Some(("ACMT", "MDOP", "CHRG"))),
+    detail("699", DebitDetail, "Miscellaneous Debit", None),
+];
+
+/// Looks up a type code in the table.
+pub fn type_code(code: &str) -> Option<&'static TypeCode> {
+    TYPE_CODES.iter().find(|t| t.code == code)
+}
+
+/// Class of a type code: from the table when listed, otherwise from the
+/// ranges reserved by the specification (001-099 and 900-919 status, 100-399
+/// and 920-959 credits, 400-699 and 960-999 debits).
+pub fn classify(code: &str) -> Option<TypeCodeClass> {
+    if let Some(entry) = type_code(code) {
+        return Some(entry.class);
+    }
+    let value: u32 = code.parse().ok()?;
+    match value {
+        1..=99 | 700..=799 | 900..=919 => Some(TypeCodeClass::Status),
+        100..=399 | 920..=959 => Some(TypeCodeClass::CreditDetail),
+        400..=699 | 960..=999 => Some(TypeCodeClass::DebitDetail),
+        _ => None,
+    }
+}
+
+/// Status code for an ISO 20022 balance type.
+pub fn status_code_for_balance(balance_type: &str) -> Option<&'static str> {
+    TYPE_CODES.iter().find(|t| t.balance_type == Some(balance_type)).map(|t| t.code)
+}
@@ -0,0 +1,163 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Calendar helpers shared by the format converters. The generated types carry
+// ISODate and ISODateTime values as plain strings, so these helpers only deal
+// with the lexical forms (YYYY-MM-DD, YYYY-MM-DDThh:mm:ss) and UTC arithmetic.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Days since 1970-01-01 for the given proleptic Gregorian date.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Inverse of `days_from_civil`.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        _ => 28,
+    }
+}
+
+/// Parses an ISODate (`YYYY-MM-DD`) into its components. Anything else,
+/// including non-ASCII text, gives `None`.
+pub fn parse_iso_date(value: &str) -> Option<(i64, u32, u32)> {
+    let bytes = value.as_bytes();
+    if bytes.len() < 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = value.get(0..4)?.parse::<i64>().ok()?;
+    let month = value.get(5..7)?.parse::<u32>().ok()?;
+    let day = value.get(8..10)?.parse::<u32>().ok()?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+/// Formats a date as ISODate.
+pub fn format_iso_date(year: i64, month: u32, day: u32) -> String {
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Converts an ISODate to days since the epoch.
+pub fn iso_date_to_days(value: &str) -> Option<i64> {
+    parse_iso_date(value).map(|(y, m, d)| days_from_civil(y, m, d))
+}
+
+/// Converts days since the epoch to an ISODate.
+pub fn days_to_iso_date(days: i64) -> String {
+    let (y, m, d) = civil_from_days(days);
+    format_iso_date(y, m, d)
+}
+
+/// Adds (or subtracts) a number of days to an ISODate.
+pub fn add_days(value: &str, days: i64) -> Option<String> {
+    iso_date_to_days(value).map(|d| days_to_iso_date(d + days))
+}
+
+/// Day of week for an ISODate, 1 = Monday .. 7 = Sunday.
+pub fn weekday(value: &str) -> Option<u32> {
+    iso_date_to_days(value).map(|d| ((d + 3).rem_euclid(7) + 1) as u32)
+}
+
+/// Returns the date part of an ISODate or ISODateTime value.
+pub fn date_part(value: &str) -> &str {
+    value.get(0..10).unwrap_or(value)
+}
+
+/// Parses an ISODateTime into seconds since the epoch. A trailing `Z` or
+/// `+hh:mm`/`-hh:mm` offset is honoured, local times are taken as UTC and
+/// fractional seconds are ignored.
+pub fn iso_datetime_to_seconds(value: &str) -> Option<i64> {
+    let days = iso_date_to_days(value)?;
+    let rest = value.get(10..)?;
+    if rest.is_empty() {
+        return Some(days * 86400);
+    }
+    let rest = rest.strip_prefix('T')?;
+    if rest.get(2..3)? != ":" || rest.get(5..6)? != ":" {
+        return None;
+    }
+    let hh = rest.get(0..2)?.parse::<i64>().ok()?;
+    let mm = rest.get(3..5)?.parse::<i64>().ok()?;
+    let ss = rest.get(6..8)?.parse::<i64>().ok()?;
+    let mut seconds = days * 86400 + hh * 3600 + mm * 60 + ss;
+    let tail = rest.get(8..)?.trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let offset = match tail.chars().next() {
+        Some('+') => Some((1, &tail[1..])),
+        Some('-') => Some((-1, &tail[1..])),
+        _ => None,
+    };
+    if let Some((sign, o)) = offset {
+        let oh = o.get(0..2)?.parse::<i64>().ok()?;
+        let om = o.get(3..5)?.parse::<i64>().ok()?;
+        seconds -= sign * (oh * 3600 + om * 60);
+    }
+    Some(seconds)
+}
+
+/// Formats seconds since the epoch as a UTC ISODateTime.
+pub fn seconds_to_iso_datetime(seconds: i64) -> String {
+    let days = seconds.div_euclid(86400);
+    let secs = seconds.rem_euclid(86400);
+    format!("{}T{:02}:{:02}:{:02}Z", days_to_iso_date(days), secs / 3600, (secs % 3600) / 60, secs % 60)
+}
+
+/// Current time as seconds since the epoch.
+pub fn now_seconds() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).unwrap_or(0)
+}
+
+/// Current UTC time as ISODateTime.
+pub fn now_iso_datetime() -> String {
+    seconds_to_iso_datetime(now_seconds())
+}
+
+/// Current UTC date as ISODate.
+pub fn today_iso_date() -> String {
+    days_to_iso_date(now_seconds().div_euclid(86400))
+}
@@ -0,0 +1,207 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Structural differences between two messages, e.g. an original and its
+// resend. Both are compared as serialized with serde_json, element by
+// element, and each difference is a `Change` at an ISO tag path
+// (`/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/IntrBkSttlmAmt/@Ccy`), with
+// repetitions numbered from 1. Repetitions are compared by position, and
+// numbers by value (`10` and `10.0` are the same amount).
+
+use crate::fingerprint::{child_path, excluded, VOLATILE_ELEMENTS};
+use crate::ValidationError;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fmt;
+
+pub const ERR_DIFF: u32 = 3501;
+
+/// Header elements that differ between a message and its resend.
+pub const RESEND_ELEMENTS: &[&str] = &["PssblDplct", "CpyDplct", "Rltd"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl ChangeKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Modified => "modified",
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            ChangeKind::Added => '+',
+            ChangeKind::Removed => '-',
+            ChangeKind::Modified => '~',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub path: String,
+    /// Value in the first message, `None` when added.
+    pub old: Option<Value>,
+    /// Value in the second message, `None` when removed.
+    pub new: Option<Value>,
+}
+
+/// `~ /GrpHdr/MsgId: "A" -> "B"`, `+ /GrpHdr/CtrlSum: 10` or
+/// `- /GrpHdr/CtrlSum: 10`.
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: ", self.kind.symbol(), self.path)?;
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(f, "{} -> {}", old, new),
+            (Some(value), None) | (None, Some(value)) => write!(f, "{}", value),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DiffOptions {
+    /// Elements left out: a tag excludes the element wherever it occurs, a
+    /// path starting with `/` excludes that element only. Repetition
+    /// numbers are ignored when matching paths.
+    pub exclude: Vec<String>,
+}
+
+impl DiffOptions {
+    pub fn exclude(mut self, element: &str) -> Self {
+        self.exclude.push(element.to_string());
+        self
+    }
+
+    /// Leaves out the creation times (`fingerprint::VOLATILE_ELEMENTS`) and
+    /// the duplicate markers of the header (`RESEND_ELEMENTS`).
+    pub fn ignore_volatile(mut self) -> Self {
+        self.exclude.extend(VOLATILE_ELEMENTS.iter().chain(RESEND_ELEMENTS).map(|e| e.to_string()));
+        self
+    }
+
+    /// Whether the element `tag` at `path` is left out.
+    pub fn excludes(&self, tag: &str, path: &str) -> bool {
+        excluded(&self.exclude, tag, path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The changes as a JSON array of `{"op", "path", "old", "new"}`.
+    pub fn to_json(&self) -> Value {
+        Value::Array(self.changes.iter().map(|change| {
+            let mut object = json!({ "op": change.kind.name(), "path": change.path });
+            if let Some(old) = &change.old {
+                object["old"] = old.clone();
+            }
+            if let Some(new) = &change.new {
+                object["new"] = new.clone();
+            }
+            object
+        }).collect())
+    }
+}
+
+/// One change per line.
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+fn same_leaf(old: &Value, new: &Value) -> bool {
+    match (old, new) {
+        (Value::Number(a), Value::Number(b)) => a == b || a.as_f64() == b.as_f64(),
+        (a, b) => a == b,
+    }
+}
+
+fn change(kind: ChangeKind, path: &str, old: Option<&Value>, new: Option<&Value>) -> Change {
+    Change { kind, path: path.to_string(), old: old.cloned(), new: new.cloned() }
+}
+
+fn diff_values(old: Option<&Value>, new: Option<&Value>, path: &str, options: &DiffOptions, changes: &mut Vec<Change>) {
+    let old = old.filter(|v| !v.is_null());
+    let new = new.filter(|v| !v.is_null());
+    match (old, new) {
+        (None, None) => {}
+        (Some(old), None) => changes.push(change(ChangeKind::Removed, path, Some(old), None)),
+        (None, Some(new)) => changes.push(change(ChangeKind::Added, path, None, Some(new))),
+        (Some(Value::Object(a)), Some(Value::Object(b))) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let (tag, child_path) = child_path(path, key);
+                if options.excludes(tag, &child_path) {
+                    continue;
+                }
+                diff_values(a.get(key), b.get(key), &child_path, options, changes);
+            }
+        }
+        (Some(Value::Array(a)), Some(Value::Array(b))) => {
+            for i in 0..a.len().max(b.len()) {
+                diff_values(a.get(i), b.get(i), &format!("{}[{}]", path, i + 1), options, changes);
+            }
+        }
+        (Some(old), Some(new)) => {
+            if !same_leaf(old, new) {
+                changes.push(change(ChangeKind::Modified, path, Some(old), Some(new)));
+            }
+        }
+    }
+}
+
+/// Differences between two messages serialized with serde_json.
+pub fn diff_json(old: &Value, new: &Value, options: &DiffOptions) -> Diff {
+    let mut changes = vec![];
+    diff_values(Some(old), Some(new), "", options, &mut changes);
+    Diff { changes }
+}
+
+/// Differences between two messages of the same type, or two Documents.
+pub fn diff<M: Serialize>(old: &M, new: &M, options: &DiffOptions) -> Result<Diff, ValidationError> {
+    let to_value = |message: &M| serde_json::to_value(message).map_err(|e| ValidationError::new(ERR_DIFF, e.to_string()));
+    Ok(diff_json(&to_value(old)?, &to_value(new)?, options))
+}
@@ -0,0 +1,308 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Duplicate detection. A message is known by its keys (`BizMsgIdr` of the
+// business application header, `MsgId`, and the `UETR`s and `EndToEndId`s
+// of its transactions) and by a hash of its Document. Transaction keys are
+// scoped by the message definition, since reports (camt.053, camt.054) and
+// other messages about a payment repeat its references. Against the
+// messages seen before, a message is:
+//
+// - new when none of its keys was seen;
+// - a resend when a key was seen with the same content and the message says
+//   it may be a duplicate (`PssblDplct`, `CpyDplct` DUPL or CODU, or an
+//   answer to a resend or retrieval request);
+// - a possible duplicate when a key was seen with the same content but the
+//   message does not say so;
+// - a conflicting duplicate when a key was seen with different content.
+//
+// Seen keys are kept in a `DuplicateStore`, in memory by default.
+
+#[cfg(feature = "derive_serde")]
+use crate::header_check::payload_facts;
+#[cfg(feature = "derive_serde")]
+use crate::fingerprint::{fingerprint, FingerprintOptions};
+use crate::reconciliation::NOT_PROVIDED;
+use crate::ValidationError;
+#[cfg(feature = "derive_serde")]
+use serde_json::Value;
+use std::collections::HashMap;
+
+pub const ERR_DUPLICATE: u32 = 3201;
+pub const ERR_CONFLICTING_DUPLICATE: u32 = 3202;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DuplicateKey {
+    BizMsgIdr(String),
+    MsgId(String),
+    /// Lower case, within a message definition.
+    Uetr { msg_def_idr: String, uetr: String },
+    EndToEndId { msg_def_idr: String, end_to_end_id: String },
+}
+
+impl std::fmt::Display for DuplicateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DuplicateKey::BizMsgIdr(id) => write!(f, "BizMsgIdr {}", id),
+            DuplicateKey::MsgId(id) => write!(f, "MsgId {}", id),
+            DuplicateKey::Uetr { msg_def_idr, uetr } => write!(f, "UETR {} of {}", uetr, msg_def_idr),
+            DuplicateKey::EndToEndId { msg_def_idr, end_to_end_id } => write!(f, "EndToEndId {} of {}", end_to_end_id, msg_def_idr),
+        }
+    }
+}
+
+/// What the detector knows of a message.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MessageKeys {
+    /// Message definition identifier of the Document, e.g. pacs.008.001.08.
+    pub msg_def_idr: Option<String>,
+    pub biz_msg_idr: Option<String>,
+    pub msg_id: Option<String>,
+    pub uetrs: Vec<String>,
+    pub end_to_end_ids: Vec<String>,
+    /// Hash of the Document.
+    pub content_hash: String,
+    /// The message says it may be a duplicate.
+    pub possible_duplicate: bool,
+}
+
+/// A message as remembered by the store.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SeenMessage {
+    /// `BizMsgIdr`, or `MsgId` without a header.
+    pub reference: String,
+    pub content_hash: String,
+}
+
+/// Where seen keys are kept.
+pub trait DuplicateStore {
+    fn get(&self, key: &DuplicateKey) -> Option<SeenMessage>;
+    fn insert(&mut self, key: DuplicateKey, message: SeenMessage);
+}
+
+/// Keeps seen keys in memory, for the life of the process.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MemoryStore {
+    seen: HashMap<DuplicateKey, SeenMessage>,
+}
+
+impl MemoryStore {
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+}
+
+impl DuplicateStore for MemoryStore {
+    fn get(&self, key: &DuplicateKey) -> Option<SeenMessage> {
+        self.seen.get(key).cloned()
+    }
+
+    fn insert(&mut self, key: DuplicateKey, message: SeenMessage) {
+        self.seen.entry(key).or_insert(message);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    New,
+    Resend,
+    PossibleDuplicate,
+    ConflictingDuplicate,
+}
+
+/// Classification of a message and the keys it shares with messages seen
+/// before.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Verdict {
+    pub classification: Classification,
+    pub matches: Vec<(DuplicateKey, SeenMessage)>,
+}
+
+impl Verdict {
+    /// The verdict as an error for anything but new messages and resends.
+    pub fn to_error(&self) -> Option<ValidationError> {
+        let (key, seen) = self.matches.first()?;
+        match self.classification {
+            Classification::New | Classification::Resend => None,
+            Classification::PossibleDuplicate => Some(ValidationError::new(ERR_DUPLICATE, format!(
+                "{} was already received in {} with the same content", key, seen.reference
+            ))),
+            Classification::ConflictingDuplicate => Some(ValidationError::new(ERR_CONFLICTING_DUPLICATE, format!(
+                "{} was already received in {} with different content", key, seen.reference
+            ))),
+        }
+    }
+}
+
+/// Which keys identify a message. `EndToEndId`s are assigned by the
+/// initiating party and only unique per party; leave them out when messages
+/// of different parties are checked together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateConfig {
+    pub biz_msg_idr: bool,
+    pub msg_id: bool,
+    pub uetr: bool,
+    pub end_to_end_id: bool,
+}
+
+impl Default for DuplicateConfig {
+    fn default() -> Self {
+        DuplicateConfig { biz_msg_idr: true, msg_id: true, uetr: true, end_to_end_id: true }
+    }
+}
+
+pub struct DuplicateDetector<S: DuplicateStore = MemoryStore> {
+    store: S,
+    config: DuplicateConfig,
+}
+
+impl DuplicateDetector<MemoryStore> {
+    pub fn new() -> Self {
+        DuplicateDetector { store: MemoryStore::default(), config: DuplicateConfig::default() }
+    }
+}
+
+impl Default for DuplicateDetector<MemoryStore> {
+    fn default() -> Self {
+        DuplicateDetector::new()
+    }
+}
+
+impl<S: DuplicateStore> DuplicateDetector<S> {
+    pub fn with_store(store: S, config: DuplicateConfig) -> Self {
+        DuplicateDetector { store, config }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    fn keys(&self, message: &MessageKeys) -> Vec<DuplicateKey> {
+        let mut keys = vec![];
+        let mut add = |key: DuplicateKey| {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        };
+        if self.config.biz_msg_idr {
+            message.biz_msg_idr.iter().for_each(|id| add(DuplicateKey::BizMsgIdr(id.trim().to_string())));
+        }
+        if self.config.msg_id {
+            message.msg_id.iter().for_each(|id| add(DuplicateKey::MsgId(id.trim().to_string())));
+        }
+        let msg_def_idr = message.msg_def_idr.as_deref().unwrap_or_default().trim();
+        if self.config.uetr {
+            message.uetrs.iter().for_each(|id| add(DuplicateKey::Uetr {
+                msg_def_idr: msg_def_idr.to_string(),
+                uetr: id.trim().to_ascii_lowercase(),
+            }));
+        }
+        if self.config.end_to_end_id {
+            message.end_to_end_ids.iter()
+                .filter(|id| id.trim() != NOT_PROVIDED)
+                .for_each(|id| add(DuplicateKey::EndToEndId {
+                    msg_def_idr: msg_def_idr.to_string(),
+                    end_to_end_id: id.trim().to_string(),
+                }));
+        }
+        keys
+    }
+
+    /// Classifies a message without remembering it.
+    pub fn classify(&self, message: &MessageKeys) -> Verdict {
+        let matches: Vec<(DuplicateKey, SeenMessage)> = self.keys(message).into_iter()
+            .filter_map(|key| self.store.get(&key).map(|seen| (key, seen)))
+            .collect();
+        let classification = if matches.is_empty() {
+            Classification::New
+        } else if matches.iter().any(|(_, seen)| seen.content_hash != message.content_hash) {
+            Classification::ConflictingDuplicate
+        } else if message.possible_duplicate {
+            Classification::Resend
+        } else {
+            Classification::PossibleDuplicate
+        };
+        Verdict { classification, matches }
+    }
+
+    /// Classifies a message and remembers the keys of new messages.
+    pub fn check(&mut self, message: &MessageKeys) -> Verdict {
+        let verdict = self.classify(message);
+        if verdict.classification == Classification::New {
+            let seen = SeenMessage {
+                reference: message.biz_msg_idr.clone().or_else(|| message.msg_id.clone()).unwrap_or_default(),
+                content_hash: message.content_hash.clone(),
+            };
+            for key in self.keys(message) {
+                self.store.insert(key, seen.clone());
+            }
+        }
+        verdict
+    }
+}
+
+/// Canonical SHA-256 fingerprint of a Document serialized with serde_json
+/// (`fingerprint`), so the hash does not depend on how the message was
+/// formatted.
+#[cfg(feature = "derive_serde")]
+pub fn content_hash(document: &Value) -> String {
+    fingerprint("", document, &FingerprintOptions::default()).to_string()
+}
+
+/// `UETR`s and `EndToEndId`s of a message, leaving out the references to
+/// other messages (`Orgnl...` and `Rltd...` elements).
+#[cfg(feature = "derive_serde")]
+fn transaction_references(value: &Value, uetrs: &mut Vec<String>, end_to_end_ids: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                match (key.as_str(), child) {
+                    ("UETR", Value::String(id)) => uetrs.push(id.clone()),
+                    ("EndToEndId", Value::String(id)) => end_to_end_ids.push(id.clone()),
+                    (key, _) if key.starts_with("Orgnl") || key.starts_with("Rltd") => {}
+                    _ => transaction_references(child, uetrs, end_to_end_ids),
+                }
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| transaction_references(item, uetrs, end_to_end_ids)),
+        _ => {}
+    }
+}
+
+/// Keys and content hash of a Document serialized with serde_json. The
+/// message definition and the header keys are filled in by the caller.
+#[cfg(feature = "derive_serde")]
+pub fn document_keys(document: &Value) -> MessageKeys {
+    let mut keys = MessageKeys {
+        msg_id: payload_facts(document).msg_id,
+        content_hash: content_hash(document),
+        ..Default::default()
+    };
+    transaction_references(document, &mut keys.uetrs, &mut keys.end_to_end_ids);
+    keys
+}
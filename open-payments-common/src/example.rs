@@ -0,0 +1,62 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Example messages for documentation, tests and API stubs. Unlike `Default`,
+// which leaves text empty and amounts at zero, `Example` builds the smallest
+// instance that passes `validate`: the required elements only (and the first
+// element of a choice, or of a type whose elements are all optional), with
+// placeholder values that respect the facets. Pattern-restricted text gets a
+// real-looking value (BIC `BANKUS33XXX`, currency `USD`, UETR, LEI, IBAN)
+// and other text its ISO tag, e.g. a `MsgId` of `"MsgId"`.
+//
+// The implementations are generated by `generate-example.py`.
+
+pub trait Example: Sized {
+    /// The minimal valid instance, with placeholder values.
+    fn example() -> Self;
+}
+
+impl Example for String {
+    fn example() -> Self {
+        "Example".to_string()
+    }
+}
+
+impl Example for bool {
+    fn example() -> Self {
+        false
+    }
+}
+
+impl Example for f64 {
+    fn example() -> Self {
+        1.0
+    }
+}
+
+impl Example for u64 {
+    fn example() -> Self {
+        1
+    }
+}
@@ -0,0 +1,480 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+// Fedwire Funds Service messages in the FAIM tag format.
+//
+// A message is a sequence of `{nnnn}` tags, each followed by its value.
+// Fixed length elements are written as is, variable length elements are
+// terminated by `*`. `FaimMessage` is the raw tag list, `FundsTransfer` the
+// typed view of the tags used by customer (CTR) and bank (BTR) transfers;
+// tags it does not model are kept in `other_tags`. The ISO 20022
+// translations live next to the FedNow messages.
+
+use crate::datetime::{format_iso_date, parse_iso_date};
+use crate::swift::fields::{format_amount, parse_amount};
+use crate::ValidationError;
+
+// Error codes raised while reading and converting FAIM messages.
+pub const ERR_TAG_STRUCTURE: u32 = 2401;
+pub const ERR_FIELD_SYNTAX: u32 = 2402;
+pub const ERR_MISSING_TAG: u32 = 2403;
+// Raised by the conversions when a message has no FAIM counterpart.
+pub const ERR_UNMAPPABLE: u32 = 2404;
+
+/// Current FAIM format version written in tag {1500}.
+pub const FORMAT_VERSION: &str = "30";
+
+pub const BUSINESS_FUNCTION_CUSTOMER_TRANSFER: &str = "CTR";
+pub const BUSINESS_FUNCTION_BANK_TRANSFER: &str = "BTR";
+
+fn syntax_error(tag: &str, message: &str) -> ValidationError {
+    ValidationError::new(ERR_FIELD_SYNTAX, format!("tag {{{}}}: {}", tag, message))
+}
+
+/// A tag and its raw value.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tag {
+    pub tag: String,
+    pub value: String,
+}
+
+/// A tag or an element that has no counterpart on the other side of a
+/// translation and was left out of the result.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Unrepresentable {
+    /// `{nnnn}` for a FAIM tag, the ISO 20022 path (`CdtTrfTxInf/UltmtDbtr`)
+    /// for a message element.
+    pub element: String,
+    pub reason: String,
+}
+
+impl Unrepresentable {
+    pub fn new(element: &str, reason: &str) -> Self {
+        Unrepresentable { element: element.to_string(), reason: reason.to_string() }
+    }
+}
+
+/// Result of a translation together with what could not be carried over.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Translation<T> {
+    pub message: T,
+    pub unrepresentable: Vec<Unrepresentable>,
+}
+
+/// Splits a value into its `*` terminated elements.
+pub fn elements(value: &str) -> Vec<String> {
+    let mut elements: Vec<String> = value.split('*').map(|e| e.to_string()).collect();
+    if value.ends_with('*') {
+        elements.pop();
+    }
+    elements
+}
+
+/// Writes `*` terminated elements, dropping trailing empty ones.
+pub fn join_elements(elements: &[String]) -> String {
+    let used = elements.iter().rposition(|e| !e.is_empty()).map(|p| p + 1).unwrap_or(0);
+    elements[..used].iter().map(|e| format!("{}*", e)).collect()
+}
+
+/// Keeps the characters allowed in FAIM text and cuts the value to `max`.
+pub fn faim_text(value: &str, max: usize) -> String {
+    value.chars().map(|c| if c == '*' || c == '{' || c == '}' || !c.is_ascii() || c.is_ascii_control() { ' ' } else { c }).take(max).collect::<String>().trim_end().to_string()
+}
+
+/// Raw FAIM message: the tags in the order they were received.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FaimMessage {
+    pub tags: Vec<Tag>,
+}
+
+impl FaimMessage {
+    /// Reads the tags of a message. Line breaks between tags are ignored; a
+    /// tag may appear only once.
+    pub fn parse(input: &str) -> Result<Self, ValidationError> {
+        let input = input.trim_start_matches('\u{feff}').trim();
+        if !input.starts_with('{') {
+            return Err(ValidationError::new(ERR_TAG_STRUCTURE, "message does not start with a tag".to_string()));
+        }
+        let mut tags: Vec<Tag> = Vec::new();
+        for chunk in input.split('{').skip(1) {
+            let (tag, value) = chunk.split_once('}').ok_or_else(|| ValidationError::new(ERR_TAG_STRUCTURE, format!("unterminated tag {{{}", chunk)))?;
+            if tag.len() != 4 || !tag.chars().all(|c| c.is_ascii_digit()) {
+                return Err(ValidationError::new(ERR_TAG_STRUCTURE, format!("invalid tag {{{}}}", tag)));
+            }
+            if tags.iter().any(|t| t.tag == tag) {
+                return Err(ValidationError::new(ERR_TAG_STRUCTURE, format!("tag {{{}}} appears more than once", tag)));
+            }
+            tags.push(Tag { tag: tag.to_string(), value: value.trim_end_matches(['\r', '\n']).to_string() });
+        }
+        Ok(FaimMessage { tags })
+    }
+
+    pub fn get(&self, tag: &str) -> Option<&str> {
+        self.tags.iter().find(|t| t.tag == tag).map(|t| t.value.as_str())
+    }
+
+    fn required(&self, tag: &str) -> Result<&str, ValidationError> {
+        self.get(tag).ok_or_else(|| ValidationError::new(ERR_MISSING_TAG, format!("missing tag {{{}}}", tag)))
+    }
+
+    pub fn push(&mut self, tag: &str, value: String) {
+        self.tags.push(Tag { tag: tag.to_string(), value });
+    }
+
+    /// Writes the tags in tag number order.
+    pub fn to_faim(&self) -> String {
+        let mut tags: Vec<&Tag> = self.tags.iter().collect();
+        tags.sort_by(|a, b| a.tag.cmp(&b.tag));
+        tags.iter().map(|t| format!("{{{}}}{}", t.tag, t.value)).collect()
+    }
+}
+
+/// Input Message Accountability Data, {1520} and {3500}: input cycle date,
+/// input source and input sequence number.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Imad {
+    /// ISODate of the input cycle.
+    pub cycle_date: String,
+    pub source: String,
+    pub sequence_number: String,
+}
+
+impl Imad {
+    pub fn parse(value: &str) -> Option<Self> {
+        if value.len() != 22 || !value.is_ascii() || !value[0..8].chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let cycle_date = format_iso_date(value[0..4].parse().ok()?, value[4..6].parse().ok()?, value[6..8].parse().ok()?);
+        parse_iso_date(&cycle_date)?;
+        Some(Imad { cycle_date, source: value[8..16].to_string(), sequence_number: value[16..22].to_string() })
+    }
+
+    /// The 22 character form, `CCYYMMDD` followed by the source and sequence number.
+    pub fn value(&self) -> String {
+        format!("{}{:<8.8}{:0>6.6}", self.cycle_date.replace('-', ""), self.source, self.sequence_number)
+    }
+}
+
+/// Depository institution of {3100} (sender) and {3400} (receiver).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DepositoryInstitution {
+    pub routing_number: String,
+    pub short_name: Option<String>,
+}
+
+impl DepositoryInstitution {
+    fn parse(tag: &str, value: &str) -> Result<Self, ValidationError> {
+        if value.len() < 9 || !value.is_char_boundary(9) || !value[0..9].chars().all(|c| c.is_ascii_digit()) {
+            return Err(syntax_error(tag, "invalid ABA routing number"));
+        }
+        let short_name = elements(&value[9..]).into_iter().next().filter(|n| !n.is_empty());
+        Ok(DepositoryInstitution { routing_number: value[0..9].to_string(), short_name })
+    }
+
+    fn value(&self) -> String {
+        format!("{}{}", self.routing_number, join_elements(&[faim_text(self.short_name.as_deref().unwrap_or_default(), 18)]))
+    }
+}
+
+/// Party tags {4000} to {5200}: an identification code with its identifier,
+/// a name and up to three address lines.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FaimParty {
+    /// `B` BIC, `C` CHIPS participant, `D` account, `F` ABA routing number,
+    /// `T` BIC and account, `U` CHIPS identifier; for originators and
+    /// beneficiaries also `1` passport, `2` tax identification, `3`
+    /// driver's license, `4` alien registration, `5` corporate
+    /// identification and `9` other.
+    pub id_code: Option<char>,
+    pub identifier: Option<String>,
+    pub name: Option<String>,
+    pub address: Vec<String>,
+}
+
+impl FaimParty {
+    fn parse(value: &str) -> Self {
+        let mut parts = elements(value).into_iter();
+        let id = parts.next().unwrap_or_default();
+        let mut chars = id.chars();
+        let id_code = chars.next();
+        let identifier = Some(chars.as_str().to_string()).filter(|i| !i.is_empty());
+        let name = parts.next().filter(|n| !n.is_empty());
+        let address = parts.filter(|l| !l.is_empty()).take(3).collect();
+        FaimParty { id_code, identifier, name, address }
+    }
+
+    fn value(&self) -> String {
+        let mut parts = vec![format!("{}{}", self.id_code.map(String::from).unwrap_or_default(), faim_text(self.identifier.as_deref().unwrap_or_default(), 34))];
+        parts.push(faim_text(self.name.as_deref().unwrap_or_default(), 35));
+        parts.extend(self.address.iter().take(3).map(|l| faim_text(l, 35)));
+        join_elements(&parts)
+    }
+}
+
+/// Details of charges, {3700}.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Charges {
+    /// `B` charges borne by the beneficiary, `S` shared.
+    pub details: char,
+    /// Up to four charges of the senders, currency and amount.
+    pub senders_charges: Vec<(String, f64)>,
+}
+
+/// A Fedwire funds transfer.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FundsTransfer {
+    /// {1500} user request correlation.
+    pub user_request_correlation: String,
+    /// {1500} `T` test or `P` production.
+    pub test_production_code: char,
+    /// {1500} `P` for a possible duplicate, blank otherwise.
+    pub message_duplication_code: char,
+    /// {1510} type code, `10` funds transfer.
+    pub type_code: String,
+    /// {1510} subtype code, `00` basic funds transfer.
+    pub subtype_code: String,
+    pub imad: Imad,
+    /// {2000} amount in cents.
+    pub amount: i64,
+    pub sender: DepositoryInstitution,
+    /// {3320}
+    pub sender_reference: Option<String>,
+    pub receiver: DepositoryInstitution,
+    /// {3500}
+    pub previous_imad: Option<Imad>,
+    /// {3600} `CTR`, `BTR`, ...
+    pub business_function_code: String,
+    pub transaction_type_code: Option<String>,
+    /// {3610} code (`ANSI`, `COVS`, `PROP`, ...) and proprietary code.
+    pub local_instrument: Option<(String, Option<String>)>,
+    /// {3700}
+    pub charges: Option<Charges>,
+    /// {3710} currency and amount.
+    pub instructed_amount: Option<(String, f64)>,
+    /// {3720}
+    pub exchange_rate: Option<f64>,
+    /// {4000}
+    pub intermediary_fi: Option<FaimParty>,
+    /// {4100}
+    pub beneficiary_fi: Option<FaimParty>,
+    /// {4200}
+    pub beneficiary: Option<FaimParty>,
+    /// {4320}
+    pub reference_for_beneficiary: Option<String>,
+    /// {5000}
+    pub originator: Option<FaimParty>,
+    /// {5100}
+    pub originator_fi: Option<FaimParty>,
+    /// {5200}
+    pub instructing_fi: Option<FaimParty>,
+    /// {6000} originator to beneficiary information, up to four lines.
+    pub originator_to_beneficiary: Vec<String>,
+    /// {6100} receiver FI information, up to six lines.
+    pub receiver_fi_information: Vec<String>,
+    /// {6300} beneficiary's FI information, up to six lines.
+    pub beneficiary_fi_information: Vec<String>,
+    /// Tags not modelled above, e.g. message disposition, advices, cover
+    /// payment and remittance tags.
+    pub other_tags: Vec<Tag>,
+}
+
+const MODELLED_TAGS: &[&str] = &[
+    "1500", "1510", "1520", "2000", "3100", "3320", "3400", "3500", "3600", "3610", "3700", "3710", "3720", "4000", "4100", "4200", "4320", "5000", "5100", "5200",
+    "6000", "6100", "6300",
+];
+
+fn reference(value: Option<&str>) -> Option<String> {
+    value.and_then(|v| elements(v).into_iter().next()).filter(|r| !r.is_empty())
+}
+
+fn text_lines(value: Option<&str>, max_lines: usize) -> Vec<String> {
+    value.map(|v| elements(v).into_iter().take(max_lines).collect::<Vec<_>>()).unwrap_or_default()
+}
+
+fn parse_currency_amount(tag: &str, value: &str) -> Result<(String, f64), ValidationError> {
+    if value.len() < 4 || !value.is_char_boundary(3) || !value[0..3].chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(syntax_error(tag, "invalid currency"));
+    }
+    let amount = parse_amount(&value[3..]).ok_or_else(|| syntax_error(tag, "invalid amount"))?;
+    Ok((value[0..3].to_string(), amount))
+}
+
+impl FundsTransfer {
+    /// Parses a FAIM message into a funds transfer, see `from_faim`.
+    pub fn parse(input: &str) -> Result<Self, ValidationError> {
+        Self::from_faim(&FaimMessage::parse(input)?)
+    }
+
+    /// Reads the tags of a funds transfer. {1500}, {1510}, {1520}, {2000},
+    /// {3100}, {3400} and {3600} are mandatory.
+    pub fn from_faim(message: &FaimMessage) -> Result<Self, ValidationError> {
+        let mut transfer = FundsTransfer::default();
+
+        let sender_supplied = message.required("1500")?;
+        if sender_supplied.len() != 12 || !sender_supplied.is_ascii() {
+            return Err(syntax_error("1500", "sender supplied information must be 12 characters"));
+        }
+        if &sender_supplied[0..2] != FORMAT_VERSION {
+            return Err(syntax_error("1500", &format!("unsupported format version {}", &sender_supplied[0..2])));
+        }
+        transfer.user_request_correlation = sender_supplied[2..10].trim_end().to_string();
+        transfer.test_production_code = sender_supplied.as_bytes()[10] as char;
+        transfer.message_duplication_code = sender_supplied.as_bytes()[11] as char;
+        if !matches!(transfer.test_production_code, 'T' | 'P') || !matches!(transfer.message_duplication_code, ' ' | 'P') {
+            return Err(syntax_error("1500", "invalid test/production or duplication code"));
+        }
+
+        let type_subtype = message.required("1510")?;
+        if type_subtype.len() != 4 || !type_subtype.chars().all(|c| c.is_ascii_digit()) {
+            return Err(syntax_error("1510", "type and subtype must be four digits"));
+        }
+        transfer.type_code = type_subtype[0..2].to_string();
+        transfer.subtype_code = type_subtype[2..4].to_string();
+
+        transfer.imad = Imad::parse(message.required("1520")?).ok_or_else(|| syntax_error("1520", "invalid IMAD"))?;
+
+        let amount = message.required("2000")?;
+        if amount.len() != 12 || !amount.chars().all(|c| c.is_ascii_digit()) {
+            return Err(syntax_error("2000", "amount must be twelve digits"));
+        }
+        transfer.amount = amount.parse().map_err(|_| syntax_error("2000", "invalid amount"))?;
+
+        transfer.sender = DepositoryInstitution::parse("3100", message.required("3100")?)?;
+        transfer.sender_reference = reference(message.get("3320"));
+        transfer.receiver = DepositoryInstitution::parse("3400", message.required("3400")?)?;
+        if let Some(value) = message.get("3500") {
+            transfer.previous_imad = Some(Imad::parse(value).ok_or_else(|| syntax_error("3500", "invalid IMAD"))?);
+        }
+
+        let business_function = message.required("3600")?;
+        if business_function.len() < 3 || !business_function.is_char_boundary(3) {
+            return Err(syntax_error("3600", "invalid business function code"));
+        }
+        transfer.business_function_code = business_function[0..3].to_string();
+        transfer.transaction_type_code = reference(Some(&business_function[3..])).map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+
+        if let Some(value) = message.get("3610") {
+            if value.len() < 4 || !value.is_char_boundary(4) {
+                return Err(syntax_error("3610", "invalid local instrument code"));
+            }
+            transfer.local_instrument = Some((value[0..4].to_string(), reference(Some(&value[4..]))));
+        }
+        if let Some(value) = message.get("3700") {
+            let details = value.chars().next().filter(|c| matches!(c, 'B' | 'S')).ok_or_else(|| syntax_error("3700", "invalid details of charges"))?;
+            let senders_charges = elements(&value[1..])
+                .iter()
+                .filter(|c| !c.is_empty())
+                .map(|c| parse_currency_amount("3700", c))
+                .collect::<Result<Vec<_>, _>>()?;
+            transfer.charges = Some(Charges { details, senders_charges });
+        }
+        if let Some(value) = message.get("3710") {
+            transfer.instructed_amount = Some(parse_currency_amount("3710", &reference(Some(value)).unwrap_or_default())?);
+        }
+        if let Some(value) = message.get("3720") {
+            let rate = reference(Some(value)).unwrap_or_default();
+            transfer.exchange_rate = Some(parse_amount(&rate).ok_or_else(|| syntax_error("3720", "invalid exchange rate"))?);
+        }
+
+        transfer.intermediary_fi = message.get("4000").map(FaimParty::parse);
+        transfer.beneficiary_fi = message.get("4100").map(FaimParty::parse);
+        transfer.beneficiary = message.get("4200").map(FaimParty::parse);
+        transfer.reference_for_beneficiary = reference(message.get("4320"));
+        transfer.originator = message.get("5000").map(FaimParty::parse);
+        transfer.originator_fi = message.get("5100").map(FaimParty::parse);
+        transfer.instructing_fi = message.get("5200").map(FaimParty::parse);
+        transfer.originator_to_beneficiary = text_lines(message.get("6000"), 4);
+        transfer.receiver_fi_information = text_lines(message.get("6100"), 6);
+        transfer.beneficiary_fi_information = text_lines(message.get("6300"), 6);
+
+        transfer.other_tags = message.tags.iter().filter(|t| !MODELLED_TAGS.contains(&t.tag.as_str())).cloned().collect();
+        Ok(transfer)
+    }
+
+    pub fn is_possible_duplicate(&self) -> bool {
+        self.message_duplication_code == 'P'
+    }
+
+    pub fn to_faim_message(&self) -> FaimMessage {
+        let mut message = FaimMessage::default();
+        let test_production = if self.test_production_code == 'T' { 'T' } else { 'P' };
+        let duplication = if self.is_possible_duplicate() { 'P' } else { ' ' };
+        message.push("1500", format!("{}{:<8.8}{}{}", FORMAT_VERSION, self.user_request_correlation, test_production, duplication));
+        message.push("1510", format!("{:0>2.2}{:0>2.2}", self.type_code, self.subtype_code));
+        message.push("1520", self.imad.value());
+        message.push("2000", format!("{:012}", self.amount));
+        message.push("3100", self.sender.value());
+        if let Some(ref r) = self.sender_reference {
+            message.push("3320", join_elements(&[faim_text(r, 16)]));
+        }
+        message.push("3400", self.receiver.value());
+        if let Some(ref imad) = self.previous_imad {
+            message.push("3500", imad.value());
+        }
+        let transaction_type = self.transaction_type_code.as_deref().map(|t| join_elements(&[faim_text(t, 3)])).unwrap_or_default();
+        message.push("3600", format!("{:<3.3}{}", self.business_function_code, transaction_type));
+        if let Some((ref code, ref proprietary)) = self.local_instrument {
+            message.push("3610", format!("{:<4.4}{}", code, join_elements(&[faim_text(proprietary.as_deref().unwrap_or_default(), 35)])));
+        }
+        if let Some(ref charges) = self.charges {
+            let amounts: Vec<String> = charges.senders_charges.iter().take(4).map(|(ccy, amount)| format!("{}{}", ccy, format_amount(*amount, ccy))).collect();
+            message.push("3700", format!("{}{}", charges.details, join_elements(&amounts)));
+        }
+        if let Some((ref ccy, amount)) = self.instructed_amount {
+            message.push("3710", join_elements(&[format!("{}{}", ccy, format_amount(amount, ccy))]));
+        }
+        if let Some(rate) = self.exchange_rate {
+            let rate = format!("{:.6}", rate).trim_end_matches('0').replace('.', ",");
+            message.push("3720", join_elements(&[rate.chars().take(12).collect()]));
+        }
+        let parties = [
+            ("4000", &self.intermediary_fi),
+            ("4100", &self.beneficiary_fi),
+            ("4200", &self.beneficiary),
+            ("5000", &self.originator),
+            ("5100", &self.originator_fi),
+            ("5200", &self.instructing_fi),
+        ];
+        for (tag, party) in parties {
+            if let Some(party) = party {
+                message.push(tag, party.value());
+            }
+        }
+        if let Some(ref r) = self.reference_for_beneficiary {
+            message.push("4320", join_elements(&[faim_text(r, 16)]));
+        }
+        let texts = [("6000", &self.originator_to_beneficiary, 4), ("6100", &self.receiver_fi_information, 6), ("6300", &self.beneficiary_fi_information, 6)];
+        for (tag, lines, max_lines) in texts {
+            if !lines.is_empty() {
+                let lines: Vec<String> = lines.iter().take(max_lines).map(|l| faim_text(l, 35)).collect();
+                message.push(tag, join_elements(&lines));
+            }
+        }
+        message.tags.extend(self.other_tags.iter().cloned());
+        message
+    }
+
+    pub fn to_faim(&self) -> String {
+        self.to_faim_message().to_faim()
+    }
+}
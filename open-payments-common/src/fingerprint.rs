@@ -0,0 +1,316 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Stable fingerprints of parsed messages, for audit trails and duplicate
+// detection.
+//
+// The fingerprint is a digest of a canonical text of the typed tree. It
+// does not depend on the XML the message was read from (whitespace between
+// elements, namespace prefixes, attribute order), only on its content:
+//
+// - the first line is `MsgDefIdr=` and the message definition identifier;
+// - then one line per value, `path=value`, with the path made of ISO 20022
+//   tags (`/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/IntrBkSttlmAmt/@Ccy=USD`),
+//   children in byte order of their tags and repetitions numbered from 1;
+// - text values are trimmed, numbers are written without exponent and
+//   without trailing zeros, absent elements have no line;
+// - `\`, line feed and carriage return in values are written `\\`, `\n`
+//   and `\r`, so a value cannot spill into the next line;
+// - every line ends with `\n`, and the text is encoded in UTF-8.
+//
+// The canonical text is part of the format: a fingerprint stored today is
+// reproduced by later versions of the library. A change to the rules above
+// comes with a new `CANONICAL_VERSION`.
+
+use std::fmt;
+#[cfg(feature = "derive_serde")]
+use serde_json::Value;
+
+/// Version of the canonical text, written in every fingerprint.
+pub const CANONICAL_VERSION: u32 = 1;
+
+/// Elements that change on every retransmission of the same content.
+pub const VOLATILE_ELEMENTS: &[&str] = &["CreDtTm", "CreDt"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Digest {
+    #[default]
+    Sha256,
+    /// 64-bit FNV-1a, for in-memory indexes where collisions are cheap.
+    Fnv1a64,
+}
+
+impl Digest {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Digest::Sha256 => "sha256",
+            Digest::Fnv1a64 => "fnv1a64",
+        }
+    }
+
+    pub fn digest(&self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            Digest::Sha256 => sha256(bytes).to_vec(),
+            Digest::Fnv1a64 => fnv1a64(bytes).to_be_bytes().to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FingerprintOptions {
+    pub digest: Digest,
+    /// Elements left out: a tag excludes the element wherever it occurs, a
+    /// path starting with `/` excludes that element only. Repetition
+    /// numbers are ignored when matching paths.
+    pub exclude: Vec<String>,
+}
+
+impl FingerprintOptions {
+    pub fn new(digest: Digest) -> Self {
+        FingerprintOptions { digest, exclude: vec![] }
+    }
+
+    pub fn exclude(mut self, element: &str) -> Self {
+        self.exclude.push(element.to_string());
+        self
+    }
+
+    /// Leaves out the `VOLATILE_ELEMENTS`.
+    pub fn exclude_volatile(mut self) -> Self {
+        self.exclude.extend(VOLATILE_ELEMENTS.iter().map(|e| e.to_string()));
+        self
+    }
+
+    /// Whether the element `tag` at `path` is left out.
+    pub fn excludes(&self, tag: &str, path: &str) -> bool {
+        excluded(&self.exclude, tag, path)
+    }
+}
+
+/// Whether the element `tag` at `path` is one of `exclude`: a tag excludes
+/// the element wherever it occurs, a path starting with `/` excludes that
+/// element only, whatever its repetition numbers.
+pub(crate) fn excluded(exclude: &[String], tag: &str, path: &str) -> bool {
+    exclude.iter().any(|e| {
+        if e.starts_with('/') { strip_indexes(path) == *e } else { e == tag }
+    })
+}
+
+/// Tag and path of the child `key` of the element at `path`, in a value
+/// serialized with serde_json. The text of an element (`$value` or
+/// `$text`) has the path of the element; attributes are excluded by their
+/// name without `@`.
+#[cfg(feature = "derive_serde")]
+pub(crate) fn child_path<'a>(path: &str, key: &'a str) -> (&'a str, String) {
+    if key == "$value" || key == "$text" {
+        (key, path.to_string())
+    } else {
+        (key.trim_start_matches('@'), format!("{}/{}", path, key))
+    }
+}
+
+fn strip_indexes(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    let mut in_index = false;
+    for c in path.chars() {
+        match c {
+            '[' => in_index = true,
+            ']' => in_index = false,
+            c if !in_index => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fingerprint {
+    pub digest: Digest,
+    pub version: u32,
+    pub bytes: Vec<u8>,
+}
+
+impl Fingerprint {
+    pub fn hex(&self) -> String {
+        self.bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+}
+
+/// `sha256:v1:` followed by the digest in lower case hex.
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:v{}:{}", self.digest.name(), self.version, self.hex())
+    }
+}
+
+/// Fingerprint of a canonical text.
+pub fn fingerprint_text(canonical: &str, digest: Digest) -> Fingerprint {
+    Fingerprint { digest, version: CANONICAL_VERSION, bytes: digest.digest(canonical.as_bytes()) }
+}
+
+#[cfg(feature = "derive_serde")]
+fn canonical_number(number: &serde_json::Number) -> String {
+    if let Some(i) = number.as_i64() {
+        return i.to_string();
+    }
+    if let Some(u) = number.as_u64() {
+        return u.to_string();
+    }
+    let value = number.as_f64().unwrap_or_default();
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        return format!("{}", value as i64);
+    }
+    let text = format!("{}", value);
+    if text.contains('.') { text.trim_end_matches('0').trim_end_matches('.').to_string() } else { text }
+}
+
+/// A value with `\`, line feed and carriage return escaped.
+#[cfg(feature = "derive_serde")]
+fn escape_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(feature = "derive_serde")]
+fn canonical_lines(value: &Value, path: &str, options: &FingerprintOptions, out: &mut String) {
+    match value {
+        Value::Null => {}
+        Value::Bool(b) => out.push_str(&format!("{}={}\n", path, b)),
+        Value::Number(n) => out.push_str(&format!("{}={}\n", path, canonical_number(n))),
+        Value::String(s) => {
+            let text = s.trim();
+            if !text.is_empty() {
+                out.push_str(&format!("{}={}\n", path, escape_value(text)));
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                canonical_lines(item, &format!("{}[{}]", path, i + 1), options, out);
+            }
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            for key in keys {
+                let (tag, child_path) = child_path(path, key);
+                if options.excludes(tag, &child_path) {
+                    continue;
+                }
+                canonical_lines(&map[key], &child_path, options, out);
+            }
+        }
+    }
+}
+
+/// Canonical text of a Document serialized with serde_json.
+#[cfg(feature = "derive_serde")]
+pub fn canonical_text(msg_def_idr: &str, document: &Value, options: &FingerprintOptions) -> String {
+    let mut out = format!("MsgDefIdr={}\n", msg_def_idr);
+    canonical_lines(document, "", options, &mut out);
+    out
+}
+
+/// Fingerprint of a Document serialized with serde_json.
+#[cfg(feature = "derive_serde")]
+pub fn fingerprint(msg_def_idr: &str, document: &Value, options: &FingerprintOptions) -> Fingerprint {
+    fingerprint_text(&canonical_text(msg_def_idr, document, options), options.digest)
+}
+
+pub fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 (FIPS 180-4).
+pub fn sha256(bytes: &[u8]) -> [u8; 32] {
+    let mut h: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+    let mut message = bytes.to_vec();
+    let bit_length = (bytes.len() as u64).wrapping_mul(8);
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&bit_length.to_be_bytes());
+    for block in message.chunks(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = hh.wrapping_add(s1).wrapping_add(ch).wrapping_add(SHA256_K[i]).wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            hh = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (state, value) in h.iter_mut().zip([a, b, c, d, e, f, g, hh]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+    let mut out = [0u8; 32];
+    for (i, word) in h.iter().enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
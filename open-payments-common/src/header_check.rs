@@ -0,0 +1,249 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Consistency of a business application header (head.001) with the
+// Document it carries:
+//
+// - `MsgDefIdr` names the payload message;
+// - `BizMsgIdr` is the `MsgId` of the payload (`Assgnmt/Id` for
+//   investigations);
+// - `Fr` and `To` identify the instructing and instructed agents (or the
+//   assigner and assignee), or a service the message is exchanged with,
+//   such as the FedNow Service between participants;
+// - `CreDt` is close to the `CreDtTm` of the payload;
+// - `Rltd` is present when the message is a copy or a duplicate.
+//
+// Header and payload are reduced to `HeaderFacts` and `PayloadFacts` by the
+// conversions next to the messages. Checks are only made where both sides
+// carry the information.
+
+use crate::datetime::iso_datetime_to_seconds;
+#[cfg(feature = "derive_serde")]
+use serde_json::Value;
+use crate::ValidationError;
+
+pub const ERR_MESSAGE_DEFINITION: u32 = 2801;
+pub const ERR_BUSINESS_MESSAGE_ID: u32 = 2802;
+pub const ERR_FROM_AGENT: u32 = 2803;
+pub const ERR_TO_AGENT: u32 = 2804;
+pub const ERR_CREATION_DATE: u32 = 2805;
+pub const ERR_MISSING_RELATED: u32 = 2806;
+
+/// Identification of a financial institution.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AgentId {
+    pub bic: Option<String>,
+    pub member_id: Option<String>,
+    /// Clearing system of the member identification, e.g. USABA.
+    pub clearing_system: Option<String>,
+    pub lei: Option<String>,
+}
+
+/// BIC without the primary office branch code `XXX`.
+fn normalized_bic(bic: &str) -> String {
+    let bic = bic.trim().to_ascii_uppercase();
+    match bic.strip_suffix("XXX") {
+        Some(head) if bic.len() == 11 => head.to_string(),
+        _ => bic,
+    }
+}
+
+impl AgentId {
+    pub fn is_empty(&self) -> bool {
+        self.bic.is_none() && self.member_id.is_none() && self.lei.is_none()
+    }
+
+    /// Whether both identify the same institution. `None` when they have no
+    /// kind of identification in common.
+    pub fn agrees_with(&self, other: &AgentId) -> Option<bool> {
+        if let (Some(a), Some(b)) = (&self.bic, &other.bic) {
+            return Some(normalized_bic(a) == normalized_bic(b));
+        }
+        if let (Some(a), Some(b)) = (&self.member_id, &other.member_id) {
+            return Some(a.trim() == b.trim());
+        }
+        if let (Some(a), Some(b)) = (&self.lei, &other.lei) {
+            return Some(a.trim().eq_ignore_ascii_case(b.trim()));
+        }
+        None
+    }
+}
+
+impl std::fmt::Display for AgentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.bic, &self.member_id, &self.lei) {
+            (Some(bic), _, _) => write!(f, "{}", bic),
+            (None, Some(member), _) => write!(f, "{}", member),
+            (None, None, Some(lei)) => write!(f, "{}", lei),
+            (None, None, None) => write!(f, "(none)"),
+        }
+    }
+}
+
+/// A business application header as seen by the checks.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HeaderFacts {
+    pub msg_def_idr: String,
+    pub biz_msg_idr: String,
+    pub from: Option<AgentId>,
+    pub to: Option<AgentId>,
+    pub cre_dt: String,
+    /// `CpyDplct` code (COPY, CODU or DUPL).
+    pub copy_duplicate: Option<String>,
+    pub has_related: bool,
+}
+
+/// The parts of a Document the header is checked against.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PayloadFacts {
+    /// Message definition identifier of the Document, e.g. pacs.008.001.08.
+    pub msg_def_idr: Option<String>,
+    pub msg_id: Option<String>,
+    pub cre_dt_tm: Option<String>,
+    pub instructing_agent: Option<AgentId>,
+    pub instructed_agent: Option<AgentId>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeaderCheckConfig {
+    /// Largest accepted difference between `CreDt` and the payload
+    /// `CreDtTm`, in seconds.
+    pub creation_tolerance_seconds: i64,
+    /// Agents accepted as `Fr` or `To` whatever the payload agents, for
+    /// services that sit between the instructing and instructed agents.
+    pub service_agents: Vec<AgentId>,
+}
+
+impl Default for HeaderCheckConfig {
+    fn default() -> Self {
+        HeaderCheckConfig { creation_tolerance_seconds: 300, service_agents: Vec::new() }
+    }
+}
+
+fn check_agent(code: u32, element: &str, header: &Option<AgentId>, payload: &Option<AgentId>, config: &HeaderCheckConfig, errors: &mut Vec<ValidationError>) {
+    if let (Some(header), Some(payload)) = (header, payload) {
+        if config.service_agents.iter().any(|service| header.agrees_with(service) == Some(true)) {
+            return;
+        }
+        if header.agrees_with(payload) == Some(false) {
+            errors.push(ValidationError::new(code, format!("{} {} does not match the payload agent {}", element, header, payload)));
+        }
+    }
+}
+
+/// Checks a header against its payload and reports every inconsistency.
+pub fn check_header(header: &HeaderFacts, payload: &PayloadFacts, config: &HeaderCheckConfig) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    match payload.msg_def_idr {
+        Some(ref msg_def_idr) if *msg_def_idr != header.msg_def_idr => {
+            errors.push(ValidationError::new(ERR_MESSAGE_DEFINITION, format!("MsgDefIdr {} does not match the payload message {}", header.msg_def_idr, msg_def_idr)));
+        }
+        None => {
+            errors.push(ValidationError::new(ERR_MESSAGE_DEFINITION, format!("MsgDefIdr {}: unknown payload message", header.msg_def_idr)));
+        }
+        _ => {}
+    }
+    if let Some(ref msg_id) = payload.msg_id {
+        if *msg_id != header.biz_msg_idr {
+            errors.push(ValidationError::new(ERR_BUSINESS_MESSAGE_ID, format!("BizMsgIdr {} does not match MsgId {}", header.biz_msg_idr, msg_id)));
+        }
+    }
+    check_agent(ERR_FROM_AGENT, "Fr", &header.from, &payload.instructing_agent, config, &mut errors);
+    check_agent(ERR_TO_AGENT, "To", &header.to, &payload.instructed_agent, config, &mut errors);
+    if let Some(ref cre_dt_tm) = payload.cre_dt_tm {
+        if let (Some(header_time), Some(payload_time)) = (iso_datetime_to_seconds(&header.cre_dt), iso_datetime_to_seconds(cre_dt_tm)) {
+            if (header_time - payload_time).abs() > config.creation_tolerance_seconds {
+                errors.push(ValidationError::new(ERR_CREATION_DATE, format!("CreDt {} is more than {} seconds from CreDtTm {}", header.cre_dt, config.creation_tolerance_seconds, cre_dt_tm)));
+            }
+        }
+    }
+    if let Some(ref code) = header.copy_duplicate {
+        if !header.has_related {
+            errors.push(ValidationError::new(ERR_MISSING_RELATED, format!("CpyDplct {} without Rltd", code)));
+        }
+    }
+    errors
+}
+
+#[cfg(feature = "derive_serde")]
+fn text(value: &Value, path: &[&str]) -> Option<String> {
+    let mut value = value;
+    for key in path {
+        value = value.get(key)?;
+    }
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Agent identification of a BranchAndFinancialInstitutionIdentification.
+#[cfg(feature = "derive_serde")]
+pub fn agent_id(agent: &Value) -> Option<AgentId> {
+    let id = agent.get("FinInstnId")?;
+    let agent = AgentId {
+        bic: text(id, &["BICFI"]),
+        member_id: text(id, &["ClrSysMmbId", "MmbId"]),
+        clearing_system: text(id, &["ClrSysMmbId", "ClrSysId", "Cd"]).or_else(|| text(id, &["ClrSysMmbId", "ClrSysId", "Prtry"])),
+        lei: text(id, &["LEI"]),
+    };
+    if agent.is_empty() { None } else { Some(agent) }
+}
+
+/// Agents of the first element of a transaction level array carrying
+/// them, for messages with agents per transaction only.
+#[cfg(feature = "derive_serde")]
+fn transaction_agents(message: &Value) -> (Option<AgentId>, Option<AgentId>) {
+    let transactions = message.as_object().into_iter().flat_map(|m| m.values()).filter_map(|v| v.as_array()).filter_map(|a| a.first());
+    for transaction in transactions {
+        let instructing = transaction.get("InstgAgt").and_then(agent_id);
+        let instructed = transaction.get("InstdAgt").and_then(agent_id);
+        if instructing.is_some() || instructed.is_some() {
+            return (instructing, instructed);
+        }
+    }
+    (None, None)
+}
+
+/// Payload facts of a Document serialized with serde_json, i.e. an
+/// object with the message root element as its single key. The message
+/// identification comes from `GrpHdr`, `Assgnmt`, `MsgHdr` or `Hdr`.
+#[cfg(feature = "derive_serde")]
+pub fn payload_facts(document: &Value) -> PayloadFacts {
+    let mut facts = PayloadFacts::default();
+    let Some(message) = document.as_object().and_then(|o| o.values().next()) else {
+        return facts;
+    };
+    if let Some(header) = ["GrpHdr", "Assgnmt", "MsgHdr", "Hdr"].iter().find_map(|key| message.get(key)) {
+        facts.msg_id = text(header, &["MsgId"]).or_else(|| text(header, &["Id"]));
+        facts.cre_dt_tm = text(header, &["CreDtTm"]);
+        facts.instructing_agent = header.get("InstgAgt").or_else(|| header.get("Assgnr").and_then(|a| a.get("Agt"))).and_then(agent_id);
+        facts.instructed_agent = header.get("InstdAgt").or_else(|| header.get("Assgne").and_then(|a| a.get("Agt"))).and_then(agent_id);
+    }
+    if facts.instructing_agent.is_none() && facts.instructed_agent.is_none() {
+        (facts.instructing_agent, facts.instructed_agent) = transaction_agents(message);
+    }
+    facts
+}
@@ -22,7 +22,34 @@
 // You may obtain a copy of this library at
 // https://github.com/Open-Payments/messages
 
+pub mod bai2;
 pub mod common;
+pub mod datetime;
+#[cfg(feature = "derive_serde")]
+pub mod diff;
+pub mod duplicate;
+pub mod example;
+pub mod fedwire;
+pub mod fingerprint;
+pub mod header_check;
+pub mod nacha;
+pub mod pagination;
+pub mod random;
+pub mod reconciliation;
+#[cfg(feature = "derive_serde")]
+pub mod redact;
+pub mod reject;
+pub mod rfp;
+pub mod schema;
+pub mod statement_check;
+pub mod swift;
+pub mod system_event;
+pub mod tag_path;
+pub mod visitor;
+mod random_impls;
+mod example_impls;
+#[cfg(feature = "derive_default")]
+mod tag_path_impls;
 
 pub use common::*;
 
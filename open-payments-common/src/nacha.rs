@@ -0,0 +1,779 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// NACHA ACH files.
+//
+// A file is made of fixed width 94 character records: the file header (1),
+// batches of a batch header (5), entry details (6) with their addenda (7)
+// and a batch control (8), then the file control (9). The file is padded
+// with all-9 records to a multiple of the blocking factor. Amounts are kept
+// in cents as in the file; the ISO 20022 conversions live next to the
+// generated messages.
+
+use crate::ValidationError;
+
+// NACHA dates are `YYMMDD` like BAI2 dates.
+pub use crate::bai2::{format_date, parse_date};
+
+// Error codes raised while reading and converting NACHA files.
+pub const ERR_RECORD_STRUCTURE: u32 = 2301;
+pub const ERR_FIELD_SYNTAX: u32 = 2302;
+pub const ERR_CONTROL_TOTAL: u32 = 2303;
+// Raised by the conversions when a message has no NACHA counterpart.
+pub const ERR_UNMAPPABLE: u32 = 2304;
+
+pub const RECORD_LENGTH: usize = 94;
+pub const BLOCKING_FACTOR: usize = 10;
+
+fn structure_error(line: usize, message: &str) -> ValidationError {
+    ValidationError::new(ERR_RECORD_STRUCTURE, format!("record {}: {}", line, message))
+}
+
+fn field_error(line: usize, field: &str, value: &str) -> ValidationError {
+    ValidationError::new(ERR_FIELD_SYNTAX, format!("record {}: invalid {} {:?}", line, field, value))
+}
+
+fn control_error(line: usize, what: &str, declared: i64, computed: i64) -> ValidationError {
+    ValidationError::new(ERR_CONTROL_TOTAL, format!("record {}: {} is {} but the content adds up to {}", line, what, declared, computed))
+}
+
+/// Check digit of an ABA routing number from its first eight digits.
+pub fn check_digit(routing: &str) -> Option<u8> {
+    if routing.len() < 8 || !routing[..8].chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let sum: u32 = routing[..8].bytes().zip([3, 7, 1, 3, 7, 1, 3, 7]).map(|(b, w)| (b - b'0') as u32 * w).sum();
+    Some(((10 - sum % 10) % 10) as u8)
+}
+
+/// Nine digit ABA routing number from the eight digit DFI identification.
+pub fn routing_number(dfi_identification: &str) -> String {
+    match check_digit(dfi_identification) {
+        Some(digit) => format!("{}{}", &dfi_identification[..8], digit),
+        None => dfi_identification.to_string(),
+    }
+}
+
+/// Whether a nine digit routing number carries a valid check digit.
+pub fn is_routing_number(value: &str) -> bool {
+    value.len() == 9 && value.chars().all(|c| c.is_ascii_digit()) && check_digit(value) == Some(value.as_bytes()[8] - b'0')
+}
+
+/// Standard Entry Class code of a batch.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum SecCode {
+    /// Prearranged Payment and Deposit, consumer accounts.
+    #[default]
+    Ppd,
+    /// Corporate Credit or Debit, one addenda with payment related information.
+    Ccd,
+    /// Corporate Trade Exchange, up to 9,999 addenda carrying an ANSI X12 or
+    /// UN/EDIFACT remittance.
+    Ctx,
+    Other(String),
+}
+
+impl SecCode {
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "PPD" => SecCode::Ppd,
+            "CCD" => SecCode::Ccd,
+            "CTX" => SecCode::Ctx,
+            other => SecCode::Other(other.to_string()),
+        }
+    }
+
+    pub fn code(&self) -> &str {
+        match self {
+            SecCode::Ppd => "PPD",
+            SecCode::Ccd => "CCD",
+            SecCode::Ctx => "CTX",
+            SecCode::Other(code) => code,
+        }
+    }
+
+    /// Maximum number of addenda per entry.
+    pub fn max_addenda(&self) -> usize {
+        match self {
+            SecCode::Ctx => 9999,
+            _ => 1,
+        }
+    }
+}
+
+/// Account type of an entry, from the first digit of the transaction code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountType {
+    Checking,
+    Savings,
+    GeneralLedger,
+    Loan,
+}
+
+/// 1 File Header record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileHeader {
+    /// Immediate destination, the receiving point's routing number.
+    pub immediate_destination: String,
+    /// Immediate origin, a routing number or a ten character identifier.
+    pub immediate_origin: String,
+    /// File creation date as ISODate.
+    pub creation_date: String,
+    /// File creation time as `hh:mm`.
+    pub creation_time: Option<String>,
+    /// `A` to `Z` or `0` to `9`, distinguishes files created on the same date.
+    pub file_id_modifier: char,
+    pub immediate_destination_name: String,
+    pub immediate_origin_name: String,
+    pub reference_code: String,
+}
+
+impl Default for FileHeader {
+    /// The first file of the day has file ID modifier `A`.
+    fn default() -> Self {
+        FileHeader {
+            immediate_destination: String::new(),
+            immediate_origin: String::new(),
+            creation_date: String::new(),
+            creation_time: None,
+            file_id_modifier: 'A',
+            immediate_destination_name: String::new(),
+            immediate_origin_name: String::new(),
+            reference_code: String::new(),
+        }
+    }
+}
+
+/// 5 Company/Batch Header record.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BatchHeader {
+    /// 200 mixed debits and credits, 220 credits only, 225 debits only.
+    pub service_class_code: u16,
+    pub company_name: String,
+    pub company_discretionary_data: String,
+    pub company_identification: String,
+    pub sec_code: SecCode,
+    pub company_entry_description: String,
+    pub company_descriptive_date: String,
+    /// Effective entry date as ISODate.
+    pub effective_entry_date: String,
+    /// Julian settlement day, inserted by the ACH operator.
+    pub settlement_date: String,
+    pub originator_status_code: char,
+    /// First eight digits of the originating DFI routing number.
+    pub originating_dfi_identification: String,
+    pub batch_number: u32,
+}
+
+/// 7 Addenda record (type 05).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Addenda {
+    pub type_code: String,
+    pub payment_related_information: String,
+    pub sequence_number: u32,
+    /// Last seven digits of the trace number of the entry.
+    pub entry_detail_sequence_number: u32,
+}
+
+/// 6 Entry Detail record with its addenda.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EntryDetail {
+    pub transaction_code: u8,
+    /// First eight digits of the receiving DFI routing number.
+    pub receiving_dfi_identification: String,
+    pub check_digit: u8,
+    pub dfi_account_number: String,
+    /// Amount in cents.
+    pub amount: i64,
+    /// Individual identification number (PPD) or identification number (CCD, CTX).
+    pub identification_number: String,
+    /// Individual name (PPD) or receiving company name (CCD, CTX).
+    pub receiver_name: String,
+    pub discretionary_data: String,
+    pub trace_number: String,
+    pub addenda: Vec<Addenda>,
+}
+
+impl EntryDetail {
+    /// Credits use transaction codes ending in 1 to 4, debits 5 to 9.
+    pub fn is_credit(&self) -> bool {
+        matches!(self.transaction_code % 10, 1..=4)
+    }
+
+    /// Prenotifications (codes ending in 3 or 8) carry no amount.
+    pub fn is_prenote(&self) -> bool {
+        matches!(self.transaction_code % 10, 3 | 8)
+    }
+
+    pub fn account_type(&self) -> Option<AccountType> {
+        match self.transaction_code / 10 {
+            2 => Some(AccountType::Checking),
+            3 => Some(AccountType::Savings),
+            4 => Some(AccountType::GeneralLedger),
+            5 => Some(AccountType::Loan),
+            _ => None,
+        }
+    }
+
+    /// Nine digit routing number of the receiving DFI.
+    pub fn routing_number(&self) -> String {
+        format!("{}{}", self.receiving_dfi_identification, self.check_digit)
+    }
+
+    /// Payment related information of all addenda, in sequence. The CTX
+    /// remittance is one stream split over the 80 character fields, so each
+    /// field keeps its trailing blanks except the last.
+    pub fn payment_related_information(&self) -> String {
+        let text: String = self.addenda.iter().map(|a| format!("{:<80}", a.payment_related_information)).collect();
+        text.trim_end().to_string()
+    }
+
+    fn hash(&self) -> i64 {
+        self.receiving_dfi_identification.parse().unwrap_or(0)
+    }
+}
+
+/// 8 Company/Batch Control record.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BatchControl {
+    pub service_class_code: u16,
+    pub entry_addenda_count: u32,
+    /// Sum of the receiving DFI identifications, rightmost ten digits.
+    pub entry_hash: i64,
+    pub total_debit: i64,
+    pub total_credit: i64,
+    pub company_identification: String,
+    pub message_authentication_code: String,
+    pub originating_dfi_identification: String,
+    pub batch_number: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Batch {
+    pub header: BatchHeader,
+    pub entries: Vec<EntryDetail>,
+    pub control: BatchControl,
+}
+
+impl Batch {
+    pub fn entry_addenda_count(&self) -> u32 {
+        self.entries.iter().map(|e| 1 + e.addenda.len() as u32).sum()
+    }
+
+    pub fn entry_hash(&self) -> i64 {
+        self.entries.iter().map(|e| e.hash()).sum::<i64>() % 10_000_000_000
+    }
+
+    pub fn total_debit(&self) -> i64 {
+        self.entries.iter().filter(|e| !e.is_credit()).map(|e| e.amount).sum()
+    }
+
+    pub fn total_credit(&self) -> i64 {
+        self.entries.iter().filter(|e| e.is_credit()).map(|e| e.amount).sum()
+    }
+}
+
+/// 9 File Control record.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FileControl {
+    pub batch_count: u32,
+    pub block_count: u32,
+    pub entry_addenda_count: u32,
+    pub entry_hash: i64,
+    pub total_debit: i64,
+    pub total_credit: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NachaFile {
+    pub header: FileHeader,
+    pub batches: Vec<Batch>,
+    pub control: FileControl,
+}
+
+/// Fixed width fields of a record, by 1-based position as in the NACHA rules.
+struct Record<'a> {
+    number: usize,
+    content: &'a str,
+}
+
+impl<'a> Record<'a> {
+    fn text(&self, from: usize, to: usize) -> &'a str {
+        &self.content[from - 1..to]
+    }
+
+    fn alpha(&self, from: usize, to: usize) -> String {
+        self.text(from, to).trim().to_string()
+    }
+
+    fn number<T: std::str::FromStr>(&self, from: usize, to: usize, field: &str) -> Result<T, ValidationError> {
+        let value = self.text(from, to);
+        value.trim().parse().map_err(|_| field_error(self.number, field, value))
+    }
+
+    fn date(&self, from: usize, to: usize, field: &str) -> Result<String, ValidationError> {
+        let value = self.text(from, to);
+        parse_date(value).ok_or_else(|| field_error(self.number, field, value))
+    }
+}
+
+/// Splits the input into records. Files without line breaks are cut into
+/// 94 character records; records shortened by trailing blanks are padded.
+fn split_records(input: &str) -> Result<Vec<String>, ValidationError> {
+    let input = input.trim_end_matches(['\r', '\n']);
+    if !input.is_ascii() {
+        return Err(structure_error(1, "file must contain ASCII characters only"));
+    }
+    let lines: Vec<&str> = if !input.contains('\n') && input.len() > RECORD_LENGTH {
+        (0..input.len()).step_by(RECORD_LENGTH).map(|i| &input[i..(i + RECORD_LENGTH).min(input.len())]).collect()
+    } else {
+        input.lines().map(|l| l.trim_end_matches('\r')).collect()
+    };
+    let mut records = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        if line.len() > RECORD_LENGTH {
+            return Err(structure_error(i + 1, "record is longer than 94 characters"));
+        }
+        if line.is_empty() {
+            return Err(structure_error(i + 1, "empty record"));
+        }
+        records.push(format!("{:<width$}", line, width = RECORD_LENGTH));
+    }
+    Ok(records)
+}
+
+fn parse_file_header(record: &Record) -> Result<FileHeader, ValidationError> {
+    if record.text(2, 3) != "01" {
+        return Err(field_error(record.number, "priority code", record.text(2, 3)));
+    }
+    if record.text(35, 37) != "094" {
+        return Err(field_error(record.number, "record size", record.text(35, 37)));
+    }
+    if record.text(38, 39) != "10" {
+        return Err(field_error(record.number, "blocking factor", record.text(38, 39)));
+    }
+    let time = record.text(30, 33);
+    let creation_time = if time.trim().is_empty() {
+        None
+    } else if time.chars().all(|c| c.is_ascii_digit()) {
+        Some(format!("{}:{}", &time[0..2], &time[2..4]))
+    } else {
+        return Err(field_error(record.number, "file creation time", time));
+    };
+    let modifier = record.text(34, 34).chars().next().unwrap_or(' ');
+    if !modifier.is_ascii_uppercase() && !modifier.is_ascii_digit() {
+        return Err(field_error(record.number, "file ID modifier", record.text(34, 34)));
+    }
+    Ok(FileHeader {
+        immediate_destination: record.alpha(4, 13),
+        immediate_origin: record.alpha(14, 23),
+        creation_date: record.date(24, 29, "file creation date")?,
+        creation_time,
+        file_id_modifier: modifier,
+        immediate_destination_name: record.alpha(41, 63),
+        immediate_origin_name: record.alpha(64, 86),
+        reference_code: record.alpha(87, 94),
+    })
+}
+
+fn parse_batch_header(record: &Record) -> Result<BatchHeader, ValidationError> {
+    Ok(BatchHeader {
+        service_class_code: record.number(2, 4, "service class code")?,
+        company_name: record.alpha(5, 20),
+        company_discretionary_data: record.alpha(21, 40),
+        company_identification: record.alpha(41, 50),
+        sec_code: SecCode::parse(record.text(51, 53)),
+        company_entry_description: record.alpha(54, 63),
+        company_descriptive_date: record.alpha(64, 69),
+        effective_entry_date: record.date(70, 75, "effective entry date")?,
+        settlement_date: record.alpha(76, 78),
+        originator_status_code: record.text(79, 79).chars().next().unwrap_or(' '),
+        originating_dfi_identification: record.alpha(80, 87),
+        batch_number: record.number(88, 94, "batch number")?,
+    })
+}
+
+fn parse_entry(record: &Record, sec_code: &SecCode) -> Result<(EntryDetail, bool, Option<u32>), ValidationError> {
+    let routing = record.text(4, 12);
+    if !routing.chars().all(|c| c.is_ascii_digit()) {
+        return Err(field_error(record.number, "receiving DFI identification", routing));
+    }
+    if !is_routing_number(routing) {
+        return Err(field_error(record.number, "check digit", routing));
+    }
+    let (receiver_name, addenda_count) = match sec_code {
+        SecCode::Ctx => (record.alpha(59, 74), Some(record.number(55, 58, "number of addenda records")?)),
+        _ => (record.alpha(55, 76), None),
+    };
+    let indicator = match record.text(79, 79) {
+        "0" => false,
+        "1" => true,
+        other => return Err(field_error(record.number, "addenda record indicator", other)),
+    };
+    let amount = record.text(30, 39);
+    if !amount.chars().all(|c| c.is_ascii_digit()) {
+        return Err(field_error(record.number, "amount", amount));
+    }
+    let entry = EntryDetail {
+        transaction_code: record.number(2, 3, "transaction code")?,
+        receiving_dfi_identification: routing[..8].to_string(),
+        check_digit: routing.as_bytes()[8] - b'0',
+        dfi_account_number: record.alpha(13, 29),
+        amount: amount.parse().unwrap_or(0),
+        identification_number: record.alpha(40, 54),
+        receiver_name,
+        discretionary_data: record.alpha(77, 78),
+        trace_number: record.alpha(80, 94),
+        addenda: Vec::new(),
+    };
+    Ok((entry, indicator, addenda_count))
+}
+
+fn parse_addenda(record: &Record) -> Result<Addenda, ValidationError> {
+    Ok(Addenda {
+        type_code: record.text(2, 3).to_string(),
+        payment_related_information: record.text(4, 83).trim_end().to_string(),
+        sequence_number: record.number(84, 87, "addenda sequence number")?,
+        entry_detail_sequence_number: record.number(88, 94, "entry detail sequence number")?,
+    })
+}
+
+fn parse_batch_control(record: &Record) -> Result<BatchControl, ValidationError> {
+    Ok(BatchControl {
+        service_class_code: record.number(2, 4, "service class code")?,
+        entry_addenda_count: record.number(5, 10, "entry/addenda count")?,
+        entry_hash: record.number(11, 20, "entry hash")?,
+        total_debit: record.number(21, 32, "total debit entry dollar amount")?,
+        total_credit: record.number(33, 44, "total credit entry dollar amount")?,
+        company_identification: record.alpha(45, 54),
+        message_authentication_code: record.alpha(55, 73),
+        originating_dfi_identification: record.alpha(80, 87),
+        batch_number: record.number(88, 94, "batch number")?,
+    })
+}
+
+fn parse_file_control(record: &Record) -> Result<FileControl, ValidationError> {
+    Ok(FileControl {
+        batch_count: record.number(2, 7, "batch count")?,
+        block_count: record.number(8, 13, "block count")?,
+        entry_addenda_count: record.number(14, 21, "entry/addenda count")?,
+        entry_hash: record.number(22, 31, "entry hash")?,
+        total_debit: record.number(32, 43, "total debit entry dollar amount")?,
+        total_credit: record.number(44, 55, "total credit entry dollar amount")?,
+    })
+}
+
+/// Checks a batch control record against the batch content.
+fn verify_batch(batch: &Batch, line: usize) -> Result<(), ValidationError> {
+    let (header, control) = (&batch.header, &batch.control);
+    if control.service_class_code != header.service_class_code {
+        return Err(structure_error(line, "service class code differs from the batch header"));
+    }
+    if control.company_identification != header.company_identification {
+        return Err(structure_error(line, "company identification differs from the batch header"));
+    }
+    if control.originating_dfi_identification != header.originating_dfi_identification {
+        return Err(structure_error(line, "originating DFI identification differs from the batch header"));
+    }
+    if control.batch_number != header.batch_number {
+        return Err(structure_error(line, "batch number differs from the batch header"));
+    }
+    let checks = [
+        ("entry/addenda count", control.entry_addenda_count as i64, batch.entry_addenda_count() as i64),
+        ("entry hash", control.entry_hash, batch.entry_hash()),
+        ("total debit entry dollar amount", control.total_debit, batch.total_debit()),
+        ("total credit entry dollar amount", control.total_credit, batch.total_credit()),
+    ];
+    for (what, declared, computed) in checks {
+        if declared != computed {
+            return Err(control_error(line, what, declared, computed));
+        }
+    }
+    match header.service_class_code {
+        220 if batch.entries.iter().any(|e| !e.is_credit()) => Err(structure_error(line, "credits only batch (220) contains debits")),
+        225 if batch.entries.iter().any(|e| e.is_credit()) => Err(structure_error(line, "debits only batch (225) contains credits")),
+        _ => Ok(()),
+    }
+}
+
+impl NachaFile {
+    /// Parses a NACHA file and verifies every batch and file control record:
+    /// entry/addenda counts, entry hash, debit and credit totals, the batch
+    /// count and the block count.
+    pub fn parse(input: &str) -> Result<Self, ValidationError> {
+        let lines = split_records(input)?;
+        let records: Vec<Record> = lines.iter().enumerate().map(|(i, l)| Record { number: i + 1, content: l }).collect();
+        let mut iter = records.iter();
+        let first = iter.next().ok_or_else(|| structure_error(1, "file is empty"))?;
+        if first.text(1, 1) != "1" {
+            return Err(structure_error(first.number, "file must start with a file header record (1)"));
+        }
+        let mut file = NachaFile { header: parse_file_header(first)?, ..Default::default() };
+        let mut batch: Option<Batch> = None;
+        // Addenda expected for the current entry: indicator and CTX count.
+        let mut expected_addenda: Option<(usize, Option<u32>)> = None;
+        let mut control_line = None;
+        for record in iter.by_ref() {
+            let code = record.text(1, 1);
+            if code != "7" {
+                if let (Some(b), Some((line, count))) = (batch.as_ref(), expected_addenda.take()) {
+                    let entry = b.entries.last().unwrap();
+                    if count.is_some_and(|c| c as usize != entry.addenda.len()) {
+                        return Err(structure_error(line, "number of addenda records differs from the addenda that follow"));
+                    }
+                    if entry.addenda.is_empty() {
+                        return Err(structure_error(line, "addenda record indicator is set but no addenda follows"));
+                    }
+                }
+            }
+            match (code, batch.as_mut()) {
+                ("5", None) => batch = Some(Batch { header: parse_batch_header(record)?, ..Default::default() }),
+                ("6", Some(b)) => {
+                    let (entry, indicator, count) = parse_entry(record, &b.header.sec_code)?;
+                    if indicator {
+                        expected_addenda = Some((record.number, count));
+                    }
+                    b.entries.push(entry);
+                }
+                ("7", Some(b)) => {
+                    let max = b.header.sec_code.max_addenda();
+                    let entry = match (b.entries.last_mut(), expected_addenda.is_some()) {
+                        (Some(entry), true) => entry,
+                        _ => return Err(structure_error(record.number, "addenda record without an entry expecting addenda")),
+                    };
+                    if entry.addenda.len() >= max {
+                        return Err(structure_error(record.number, &format!("{} entries take at most {} addenda", b.header.sec_code.code(), max)));
+                    }
+                    let addenda = parse_addenda(record)?;
+                    if addenda.sequence_number as usize != entry.addenda.len() + 1 {
+                        return Err(field_error(record.number, "addenda sequence number", record.text(84, 87)));
+                    }
+                    if entry.trace_number.len() < 7 || entry.trace_number[entry.trace_number.len() - 7..].parse::<u32>().ok() != Some(addenda.entry_detail_sequence_number) {
+                        return Err(field_error(record.number, "entry detail sequence number", record.text(88, 94)));
+                    }
+                    entry.addenda.push(addenda);
+                }
+                ("8", Some(_)) => {
+                    let mut b = batch.take().unwrap();
+                    b.control = parse_batch_control(record)?;
+                    verify_batch(&b, record.number)?;
+                    file.batches.push(b);
+                }
+                ("9", None) => {
+                    file.control = parse_file_control(record)?;
+                    control_line = Some(record.number);
+                    break;
+                }
+                ("5", Some(_)) => return Err(structure_error(record.number, "batch header before the previous batch control")),
+                ("6" | "7" | "8", None) => return Err(structure_error(record.number, "record outside of a batch")),
+                ("9", Some(_)) => return Err(structure_error(record.number, "file control before the batch control")),
+                (other, _) => return Err(structure_error(record.number, &format!("unknown record type {:?}", other))),
+            }
+        }
+        let line = control_line.ok_or_else(|| structure_error(records.len(), "file control record (9) is missing"))?;
+        for record in iter {
+            if record.content.bytes().any(|b| b != b'9') {
+                return Err(structure_error(record.number, "only padding records may follow the file control"));
+            }
+        }
+        let control = &file.control;
+        let checks = [
+            ("batch count", control.batch_count as i64, file.batches.len() as i64),
+            ("block count", control.block_count as i64, line.div_ceil(BLOCKING_FACTOR) as i64),
+            ("entry/addenda count", control.entry_addenda_count as i64, file.batches.iter().map(|b| b.entry_addenda_count() as i64).sum()),
+            ("entry hash", control.entry_hash, file.batches.iter().map(|b| b.entry_hash()).sum::<i64>() % 10_000_000_000),
+            ("total debit entry dollar amount", control.total_debit, file.batches.iter().map(|b| b.total_debit()).sum()),
+            ("total credit entry dollar amount", control.total_credit, file.batches.iter().map(|b| b.total_credit()).sum()),
+        ];
+        for (what, declared, computed) in checks {
+            if declared != computed {
+                return Err(control_error(line, what, declared, computed));
+            }
+        }
+        Ok(file)
+    }
+
+    /// Recomputes the batch and file control records, the addenda
+    /// indicators and sequence numbers from the content, as needed before
+    /// writing a file that was built programmatically.
+    pub fn update_control_totals(&mut self) {
+        let mut records = 2;
+        for batch in &mut self.batches {
+            for entry in &mut batch.entries {
+                let sequence = entry.trace_number.get(entry.trace_number.len().saturating_sub(7)..).and_then(|s| s.parse().ok()).unwrap_or(0);
+                for (i, addenda) in entry.addenda.iter_mut().enumerate() {
+                    if addenda.type_code.is_empty() {
+                        addenda.type_code = "05".to_string();
+                    }
+                    addenda.sequence_number = i as u32 + 1;
+                    addenda.entry_detail_sequence_number = sequence;
+                }
+            }
+            batch.control = BatchControl {
+                service_class_code: batch.header.service_class_code,
+                entry_addenda_count: batch.entry_addenda_count(),
+                entry_hash: batch.entry_hash(),
+                total_debit: batch.total_debit(),
+                total_credit: batch.total_credit(),
+                company_identification: batch.header.company_identification.clone(),
+                message_authentication_code: batch.control.message_authentication_code.clone(),
+                originating_dfi_identification: batch.header.originating_dfi_identification.clone(),
+                batch_number: batch.header.batch_number,
+            };
+            records += 2 + batch.control.entry_addenda_count as usize;
+        }
+        self.control = FileControl {
+            batch_count: self.batches.len() as u32,
+            block_count: records.div_ceil(BLOCKING_FACTOR) as u32,
+            entry_addenda_count: self.batches.iter().map(|b| b.control.entry_addenda_count).sum(),
+            entry_hash: self.batches.iter().map(|b| b.control.entry_hash).sum::<i64>() % 10_000_000_000,
+            total_debit: self.batches.iter().map(|b| b.control.total_debit).sum(),
+            total_credit: self.batches.iter().map(|b| b.control.total_credit).sum(),
+        };
+    }
+
+    /// Writes the file using the stored control records (see
+    /// `update_control_totals`), padded with all-9 records to full blocks.
+    pub fn to_nacha(&self) -> String {
+        let header = &self.header;
+        let mut records = vec![[
+            "101".to_string(),
+            alpha(&format!("{:>10}", header.immediate_destination), 10),
+            alpha(&format!("{:>10}", header.immediate_origin), 10),
+            format_date(&header.creation_date),
+            header.creation_time.as_deref().map(|t| t.replace(':', "")).unwrap_or_else(|| "    ".to_string()),
+            header.file_id_modifier.to_string(),
+            "094101".to_string(),
+            alpha(&header.immediate_destination_name, 23),
+            alpha(&header.immediate_origin_name, 23),
+            alpha(&header.reference_code, 8),
+        ]
+        .concat()];
+        for batch in &self.batches {
+            let h = &batch.header;
+            records.push(
+                [
+                    "5".to_string(),
+                    numeric(h.service_class_code as i64, 3),
+                    alpha(&h.company_name, 16),
+                    alpha(&h.company_discretionary_data, 20),
+                    alpha(&h.company_identification, 10),
+                    alpha(h.sec_code.code(), 3),
+                    alpha(&h.company_entry_description, 10),
+                    alpha(&h.company_descriptive_date, 6),
+                    format_date(&h.effective_entry_date),
+                    alpha(&h.settlement_date, 3),
+                    h.originator_status_code.to_string(),
+                    alpha(&h.originating_dfi_identification, 8),
+                    numeric(h.batch_number as i64, 7),
+                ]
+                .concat(),
+            );
+            for entry in &batch.entries {
+                let name = match h.sec_code {
+                    SecCode::Ctx => format!("{}{}  ", numeric(entry.addenda.len() as i64, 4), alpha(&entry.receiver_name, 16)),
+                    _ => alpha(&entry.receiver_name, 22),
+                };
+                records.push(
+                    [
+                        "6".to_string(),
+                        numeric(entry.transaction_code as i64, 2),
+                        alpha(&entry.receiving_dfi_identification, 8),
+                        entry.check_digit.to_string(),
+                        alpha(&entry.dfi_account_number, 17),
+                        numeric(entry.amount, 10),
+                        alpha(&entry.identification_number, 15),
+                        name,
+                        alpha(&entry.discretionary_data, 2),
+                        if entry.addenda.is_empty() { "0" } else { "1" }.to_string(),
+                        alpha(&entry.trace_number, 15),
+                    ]
+                    .concat(),
+                );
+                for addenda in &entry.addenda {
+                    records.push(
+                        [
+                            "7".to_string(),
+                            alpha(&addenda.type_code, 2),
+                            alpha(&addenda.payment_related_information, 80),
+                            numeric(addenda.sequence_number as i64, 4),
+                            numeric(addenda.entry_detail_sequence_number as i64, 7),
+                        ]
+                        .concat(),
+                    );
+                }
+            }
+            let c = &batch.control;
+            records.push(
+                [
+                    "8".to_string(),
+                    numeric(c.service_class_code as i64, 3),
+                    numeric(c.entry_addenda_count as i64, 6),
+                    numeric(c.entry_hash, 10),
+                    numeric(c.total_debit, 12),
+                    numeric(c.total_credit, 12),
+                    alpha(&c.company_identification, 10),
+                    alpha(&c.message_authentication_code, 19),
+                    " ".repeat(6),
+                    alpha(&c.originating_dfi_identification, 8),
+                    numeric(c.batch_number as i64, 7),
+                ]
+                .concat(),
+            );
+        }
+        let c = &self.control;
+        records.push(
+            [
+                "9".to_string(),
+                numeric(c.batch_count as i64, 6),
+                numeric(c.block_count as i64, 6),
+                numeric(c.entry_addenda_count as i64, 8),
+                numeric(c.entry_hash, 10),
+                numeric(c.total_debit, 12),
+                numeric(c.total_credit, 12),
+                " ".repeat(39),
+            ]
+            .concat(),
+        );
+        while records.len() % BLOCKING_FACTOR != 0 {
+            records.push("9".repeat(RECORD_LENGTH));
+        }
+        let mut out = records.join("\n");
+        out.push('\n');
+        out
+    }
+}
+
+/// Alphanumeric field: left justified and blank filled, characters outside
+/// of printable ASCII become blanks.
+fn alpha(value: &str, width: usize) -> String {
+    let value: String = value.chars().map(|c| if c.is_ascii() && !c.is_ascii_control() { c } else { ' ' }).take(width).collect();
+    format!("{:<width$}", value, width = width)
+}
+
+/// Numeric field: right justified and zero filled, keeping the rightmost digits.
+fn numeric(value: i64, width: usize) -> String {
+    let digits = format!("{:0width$}", value.max(0), width = width);
+    digits[digits.len() - width..].to_string()
+}
@@ -0,0 +1,202 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Pagination of messages split across several pages with `MsgPgntn`
+// (`PgNb`, `LastPgInd`), as used by camt.052 and camt.053.
+//
+// `PageAssembler` collects the pages of a logical message under a key and
+// hands them back in page order once the last page and every page before it
+// have arrived. `page_ranges` does the reverse: it cuts a list of items into
+// pages under an entry count and size limit.
+
+use std::ops::Range;
+
+use crate::ValidationError;
+
+pub const ERR_DUPLICATE_PAGE: u32 = 2601;
+pub const ERR_MISSING_PAGE: u32 = 2602;
+pub const ERR_PAGE_NUMBER: u32 = 2603;
+pub const ERR_PAGE_LIMIT: u32 = 2604;
+
+/// Largest page number `PgNb` (Max5NumericText) can carry.
+pub const MAX_PAGE_NUMBER: u64 = 99999;
+
+/// Parses a `PgNb` page number. Pages are numbered from 1.
+pub fn page_number(pg_nb: &str) -> Result<u64, ValidationError> {
+    match pg_nb.parse::<u64>() {
+        Ok(number) if (1..=MAX_PAGE_NUMBER).contains(&number) && pg_nb.chars().all(|c| c.is_ascii_digit()) => Ok(number),
+        _ => Err(ValidationError::new(ERR_PAGE_NUMBER, format!("invalid page number '{}'", pg_nb))),
+    }
+}
+
+#[derive(Debug)]
+struct PendingMessage<T> {
+    key: String,
+    pages: Vec<(u64, T)>,
+    last_page: Option<u64>,
+}
+
+impl<T> PendingMessage<T> {
+    fn missing_pages(&self) -> Vec<u64> {
+        let highest = self.pages.iter().map(|(n, _)| *n).max().unwrap_or(0);
+        let last = self.last_page.unwrap_or(highest);
+        (1..=last).filter(|n| !self.pages.iter().any(|(p, _)| p == n)).collect()
+    }
+
+    fn is_complete(&self) -> bool {
+        self.last_page.is_some() && self.missing_pages().is_empty()
+    }
+}
+
+/// Collects the pages of paginated messages, keyed by the identification of
+/// the logical message (for reports, the report `Id`).
+#[derive(Debug)]
+pub struct PageAssembler<T> {
+    pending: Vec<PendingMessage<T>>,
+}
+
+impl<T> Default for PageAssembler<T> {
+    fn default() -> Self {
+        PageAssembler { pending: Vec::new() }
+    }
+}
+
+impl<T> PageAssembler<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a page. Returns the pages of the message in page order once the
+    /// last page and every page before it have been added; the message is
+    /// then no longer pending. A page number seen twice, a second last page
+    /// or a page after the last page is rejected and leaves the pending
+    /// pages untouched.
+    pub fn add(&mut self, key: &str, page_number: u64, last_page: bool, page: T) -> Result<Option<Vec<T>>, ValidationError> {
+        if page_number == 0 || page_number > MAX_PAGE_NUMBER {
+            return Err(ValidationError::new(ERR_PAGE_NUMBER, format!("{}: invalid page number {}", key, page_number)));
+        }
+        let index = match self.pending.iter().position(|m| m.key == key) {
+            Some(index) => index,
+            None => {
+                self.pending.push(PendingMessage { key: key.to_string(), pages: Vec::new(), last_page: None });
+                self.pending.len() - 1
+            }
+        };
+        let message = &mut self.pending[index];
+        if message.pages.iter().any(|(n, _)| *n == page_number) {
+            return Err(ValidationError::new(ERR_DUPLICATE_PAGE, format!("{}: page {} received twice", key, page_number)));
+        }
+        if let Some(last) = message.last_page {
+            if last_page {
+                return Err(ValidationError::new(ERR_PAGE_NUMBER, format!("{}: page {} marked last after last page {}", key, page_number, last)));
+            }
+            if page_number > last {
+                return Err(ValidationError::new(ERR_PAGE_NUMBER, format!("{}: page {} after last page {}", key, page_number, last)));
+            }
+        }
+        if last_page {
+            if let Some(highest) = message.pages.iter().map(|(n, _)| *n).filter(|n| *n > page_number).max() {
+                return Err(ValidationError::new(ERR_PAGE_NUMBER, format!("{}: last page {} before page {}", key, page_number, highest)));
+            }
+            message.last_page = Some(page_number);
+        }
+        message.pages.push((page_number, page));
+        if !message.is_complete() {
+            return Ok(None);
+        }
+        let mut message = self.pending.remove(index);
+        message.pages.sort_by_key(|(n, _)| *n);
+        Ok(Some(message.pages.into_iter().map(|(_, page)| page).collect()))
+    }
+
+    /// Keys of the messages still waiting for pages.
+    pub fn pending_keys(&self) -> Vec<&str> {
+        self.pending.iter().map(|m| m.key.as_str()).collect()
+    }
+
+    /// Page numbers not yet received for a pending message, up to the last
+    /// page if it arrived and up to the highest page received otherwise.
+    pub fn missing_pages(&self, key: &str) -> Vec<u64> {
+        self.pending.iter().find(|m| m.key == key).map(|m| m.missing_pages()).unwrap_or_default()
+    }
+
+    /// Reports every pending message as incomplete, e.g. at the end of a
+    /// business day.
+    pub fn check_complete(&self) -> Vec<ValidationError> {
+        self.pending
+            .iter()
+            .map(|m| {
+                let missing = m.missing_pages().iter().map(|n| n.to_string()).collect::<Vec<_>>();
+                let last = if m.last_page.is_some() { "" } else { ", last page not received" };
+                ValidationError::new(ERR_MISSING_PAGE, format!("{}: missing pages [{}]{}", m.key, missing.join(", "), last))
+            })
+            .collect()
+    }
+
+    /// Removes a pending message and returns the pages received so far, in
+    /// page order.
+    pub fn discard(&mut self, key: &str) -> Option<Vec<T>> {
+        let index = self.pending.iter().position(|m| m.key == key)?;
+        let mut message = self.pending.remove(index);
+        message.pages.sort_by_key(|(n, _)| *n);
+        Some(message.pages.into_iter().map(|(_, page)| page).collect())
+    }
+}
+
+/// Limits of a single page. Without limits everything goes on one page.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PageLimits {
+    /// Maximum number of entries on a page.
+    pub max_entries: Option<usize>,
+    /// Maximum size of the entries on a page, in the unit of the sizes
+    /// passed to `page_ranges` (usually serialized bytes).
+    pub max_size: Option<usize>,
+}
+
+/// Cuts items of the given sizes into consecutive pages under the limits.
+/// There is always at least one page, empty if there are no items. An item
+/// larger than `max_size` on its own is an error.
+pub fn page_ranges(sizes: &[usize], limits: &PageLimits) -> Result<Vec<Range<usize>>, ValidationError> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut size = 0;
+    for (i, item) in sizes.iter().enumerate() {
+        if limits.max_size.is_some_and(|max| *item > max) {
+            return Err(ValidationError::new(ERR_PAGE_LIMIT, format!("item {} of size {} exceeds the page size limit", i + 1, item)));
+        }
+        let entries_full = limits.max_entries.is_some_and(|max| i - start >= max);
+        let size_full = limits.max_size.is_some_and(|max| size + item > max);
+        if i > start && (entries_full || size_full) {
+            ranges.push(start..i);
+            start = i;
+            size = 0;
+        }
+        size += item;
+    }
+    ranges.push(start..sizes.len());
+    if ranges.len() as u64 > MAX_PAGE_NUMBER {
+        return Err(ValidationError::new(ERR_PAGE_LIMIT, format!("{} pages exceed the maximum page number", ranges.len())));
+    }
+    Ok(ranges)
+}
@@ -0,0 +1,377 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Random messages for testing. `Random` is implemented for every generated
+// type (see `generate-random.py`) and produces values that pass `validate`:
+// text within its length facets, text matching its pattern, numbers above
+// their minimum and within their digits, a variant of each code list, one
+// branch of each choice (`...Choice`) and from one item up to the maxOccurs
+// of each required repetition. Dates,
+// times and currencies, which have no facets in the generated code, are
+// recognized by their tags (`...Dt`, `...DtTm`, `...Tm`, `Ccy`).
+//
+// A `Generator` is seeded, so the same seed gives the same messages with
+// the same version of the library and of `rand`.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const ALPHANUMERIC: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+const CURRENCIES: &[&str] = &["USD", "EUR", "GBP", "JPY", "CHF", "CAD", "AUD"];
+
+pub trait Random: Sized {
+    fn random(generator: &mut Generator) -> Self;
+}
+
+/// A random value of `T` from `seed`, with the default settings.
+pub fn random<T: Random>(seed: u64) -> T {
+    T::random(&mut Generator::new(seed))
+}
+
+#[derive(Debug, Clone)]
+pub struct Generator {
+    rng: StdRng,
+    depth: usize,
+    /// Probability that an optional element is present.
+    pub optional_density: f64,
+    /// Most items of a repetition.
+    pub max_repeats: usize,
+    /// Elements deeper than this have no optional elements and the fewest
+    /// repetitions.
+    pub max_depth: usize,
+    /// Longest text without a maximum length, and longest free text.
+    pub max_text_length: usize,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        Generator {
+            rng: StdRng::seed_from_u64(seed),
+            depth: 0,
+            optional_density: 0.5,
+            max_repeats: 3,
+            max_depth: 12,
+            max_text_length: 35,
+        }
+    }
+
+    pub fn optional_density(mut self, density: f64) -> Self {
+        self.optional_density = density.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn max_repeats(mut self, max_repeats: usize) -> Self {
+        self.max_repeats = max_repeats.max(1);
+        self
+    }
+
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn max_text_length(mut self, max_text_length: usize) -> Self {
+        self.max_text_length = max_text_length.max(1);
+        self
+    }
+
+    pub fn generate<T: Random>(&mut self) -> T {
+        T::random(self)
+    }
+
+    /// A child element, one level deeper.
+    pub fn nested<T: Random>(&mut self) -> T {
+        self.depth += 1;
+        let value = T::random(self);
+        self.depth -= 1;
+        value
+    }
+
+    pub fn optional<T>(&mut self, value: impl FnOnce(&mut Self) -> T) -> Option<T> {
+        if self.depth < self.max_depth && self.rng.gen_bool(self.optional_density) {
+            Some(value(self))
+        } else {
+            None
+        }
+    }
+
+    /// At least `min` items.
+    pub fn repeated<T>(&mut self, min: usize, item: impl FnMut(&mut Self) -> T) -> Vec<T> {
+        self.repeated_up_to(min, usize::MAX, item)
+    }
+
+    /// At least `min` and at most `max` items, for a bounded maxOccurs.
+    pub fn repeated_up_to<T>(&mut self, min: usize, max: usize, mut item: impl FnMut(&mut Self) -> T) -> Vec<T> {
+        let max = if self.depth < self.max_depth { self.max_repeats.min(max).max(min) } else { min };
+        let count = self.rng.gen_range(min..=max);
+        (0..count).map(|_| item(self)).collect()
+    }
+
+    /// One of `count` branches.
+    pub fn choice(&mut self, count: usize) -> usize {
+        self.rng.gen_range(0..count.max(1))
+    }
+
+    pub fn boolean(&mut self) -> bool {
+        self.rng.gen()
+    }
+
+    pub fn integer(&mut self, min: u64, max: u64) -> u64 {
+        self.rng.gen_range(min..=max.max(min))
+    }
+
+    /// An amount of at least `min` with 2 decimals.
+    pub fn decimal(&mut self, min: f64) -> f64 {
+        let cents = self.rng.gen_range(0..100_000_000u64);
+        (min.max(0.0) * 100.0).ceil() / 100.0 + cents as f64 / 100.0
+    }
+
+    /// A number of at least `min` with at most `total_digits` digits, of
+    /// which at most `fraction_digits` after the point. It has at most 2
+    /// decimals and 8 digits before the point, which f64 holds exactly.
+    pub fn digits(&mut self, min: f64, total_digits: u32, fraction_digits: u32) -> f64 {
+        let scale = fraction_digits.min(total_digits).min(2);
+        let limit = 10u64.pow((total_digits - scale).min(8) + scale);
+        let step = 10u64.pow(scale) as f64;
+        let low = (min.max(0.0) * step).ceil() as u64;
+        let units = self.rng.gen_range(low..limit.max(low + 1));
+        units as f64 / step
+    }
+
+    /// Alphanumeric text of `min` to `max` characters, at most
+    /// `max_text_length` unless `min` is longer.
+    pub fn text(&mut self, min: usize, max: usize) -> String {
+        let max = max.min(self.max_text_length).max(min);
+        let length = self.rng.gen_range(min..=max);
+        (0..length).map(|_| ALPHANUMERIC[self.rng.gen_range(0..ALPHANUMERIC.len())] as char).collect()
+    }
+
+    /// `YYYY-MM-DD`.
+    pub fn date(&mut self) -> String {
+        format!("{:04}-{:02}-{:02}", self.rng.gen_range(2000..=2030), self.rng.gen_range(1..=12), self.rng.gen_range(1..=28))
+    }
+
+    /// `hh:mm:ss`.
+    pub fn time(&mut self) -> String {
+        format!("{:02}:{:02}:{:02}", self.rng.gen_range(0..24), self.rng.gen_range(0..60), self.rng.gen_range(0..60))
+    }
+
+    /// `YYYY-MM-DDThh:mm:ss`.
+    pub fn date_time(&mut self) -> String {
+        let date = self.date();
+        let time = self.time();
+        format!("{}T{}", date, time)
+    }
+
+    pub fn currency(&mut self) -> String {
+        CURRENCIES[self.rng.gen_range(0..CURRENCIES.len())].to_string()
+    }
+
+    /// Text matching `pattern`, a regular expression of the XSD facets:
+    /// characters, escaped characters, `\d`, classes of characters and
+    /// ranges, groups, `|` and the quantifiers `?`, `*`, `+`, `{n}` and
+    /// `{n,m}`. Negated classes, `.` and the other class escapes (`\s`,
+    /// `\w`, ...) are not supported: generate-random.py refuses the patterns
+    /// that use them.
+    pub fn pattern(&mut self, pattern: &str) -> String {
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut position = 0;
+        let mut out = String::new();
+        self.alternatives(&chars, &mut position, &mut out);
+        out
+    }
+
+    fn alternatives(&mut self, chars: &[char], position: &mut usize, out: &mut String) {
+        let mut branches = vec![*position];
+        let mut depth = 0;
+        let mut i = *position;
+        let mut in_class = false;
+        while i < chars.len() {
+            match chars[i] {
+                '\\' => i += 1,
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '(' if !in_class => depth += 1,
+                ')' if !in_class && depth == 0 => break,
+                ')' if !in_class => depth -= 1,
+                '|' if !in_class && depth == 0 => branches.push(i + 1),
+                _ => {}
+            }
+            i += 1;
+        }
+        let end = i;
+        *position = branches[self.rng.gen_range(0..branches.len())];
+        while *position < end && chars[*position] != '|' {
+            self.atom(chars, position, out);
+        }
+        *position = end;
+    }
+
+    fn atom(&mut self, chars: &[char], position: &mut usize, out: &mut String) {
+        let start = *position;
+        let mut atom = String::new();
+        match chars[*position] {
+            '(' => {
+                let group_start = start + 1;
+                let group_end = Self::closing(chars, group_start);
+                let (min, max) = self.quantifier(chars, group_end + 1);
+                for _ in 0..self.rng.gen_range(min..=max) {
+                    let mut inner = group_start;
+                    self.alternatives(chars, &mut inner, &mut atom);
+                }
+                out.push_str(&atom);
+                *position = self.after_quantifier(chars, group_end + 1);
+                return;
+            }
+            '[' => {
+                let (class, end) = Self::class(chars, *position + 1);
+                let (min, max) = self.quantifier(chars, end + 1);
+                for _ in 0..self.rng.gen_range(min..=max) {
+                    atom.push(class[self.rng.gen_range(0..class.len())]);
+                }
+                *position = self.after_quantifier(chars, end + 1);
+            }
+            '\\' => {
+                let escaped = chars.get(start + 1).copied().unwrap_or('\\');
+                let (min, max) = self.quantifier(chars, start + 2);
+                for _ in 0..self.rng.gen_range(min..=max) {
+                    atom.push(if escaped == 'd' { char::from(b'0' + self.rng.gen_range(0..10)) } else { escaped });
+                }
+                *position = self.after_quantifier(chars, start + 2);
+            }
+            c => {
+                let (min, max) = self.quantifier(chars, start + 1);
+                for _ in 0..self.rng.gen_range(min..=max) {
+                    atom.push(c);
+                }
+                *position = self.after_quantifier(chars, start + 1);
+            }
+        }
+        out.push_str(&atom);
+    }
+
+    /// Position of the `)` closing the group starting at `i`.
+    fn closing(chars: &[char], mut i: usize) -> usize {
+        let mut depth = 0;
+        let mut in_class = false;
+        while i < chars.len() {
+            match chars[i] {
+                '\\' => i += 1,
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '(' if !in_class => depth += 1,
+                ')' if !in_class && depth == 0 => return i,
+                ')' if !in_class => depth -= 1,
+                _ => {}
+            }
+            i += 1;
+        }
+        i
+    }
+
+    /// Characters of the class starting after `[`, and the position of `]`.
+    fn class(chars: &[char], mut i: usize) -> (Vec<char>, usize) {
+        let mut class = vec![];
+        while i < chars.len() && chars[i] != ']' {
+            let c = if chars[i] == '\\' {
+                i += 1;
+                chars.get(i).copied().unwrap_or('\\')
+            } else {
+                chars[i]
+            };
+            if chars.get(i + 1) == Some(&'-') && chars.get(i + 2).is_some_and(|c| *c != ']') {
+                let to = chars[i + 2];
+                class.extend(c..=to);
+                i += 3;
+            } else {
+                class.push(c);
+                i += 1;
+            }
+        }
+        if class.is_empty() {
+            class.push('0');
+        }
+        (class, i)
+    }
+
+    /// Repetitions of the quantifier at `i`, if any.
+    fn quantifier(&self, chars: &[char], i: usize) -> (usize, usize) {
+        let open = self.max_repeats.max(1);
+        match chars.get(i) {
+            Some('?') => (0, 1),
+            Some('*') => (0, open),
+            Some('+') => (1, open),
+            Some('{') => {
+                let end = chars[i..].iter().position(|c| *c == '}').map(|p| i + p).unwrap_or(chars.len());
+                let bounds: String = chars[i + 1..end].iter().collect();
+                match bounds.split_once(',') {
+                    Some((min, "")) => {
+                        let min = min.parse().unwrap_or(0);
+                        (min, min + open)
+                    }
+                    Some((min, max)) => (min.parse().unwrap_or(0), max.parse().unwrap_or(0)),
+                    None => {
+                        let n = bounds.parse().unwrap_or(1);
+                        (n, n)
+                    }
+                }
+            }
+            _ => (1, 1),
+        }
+    }
+
+    fn after_quantifier(&self, chars: &[char], i: usize) -> usize {
+        match chars.get(i) {
+            Some('?' | '*' | '+') => i + 1,
+            Some('{') => chars[i..].iter().position(|c| *c == '}').map(|p| i + p + 1).unwrap_or(chars.len()),
+            _ => i,
+        }
+    }
+}
+
+impl Random for String {
+    fn random(generator: &mut Generator) -> Self {
+        let max = generator.max_text_length;
+        generator.text(1, max)
+    }
+}
+
+impl Random for bool {
+    fn random(generator: &mut Generator) -> Self {
+        generator.boolean()
+    }
+}
+
+impl Random for f64 {
+    fn random(generator: &mut Generator) -> Self {
+        generator.decimal(0.0)
+    }
+}
+
+impl Random for u64 {
+    fn random(generator: &mut Generator) -> Self {
+        generator.integer(0, u64::MAX)
+    }
+}
@@ -0,0 +1,362 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+// Reconciliation of booked statement entries against outgoing instructions.
+//
+// Statements and notifications (camt.053, camt.054) and instructions
+// (pacs.008, pain.001) are first reduced to `StatementEntry` and
+// `Instruction` records by the conversions next to the messages, so the
+// matching works the same for every message version. Matching runs in three
+// passes:
+//
+// 1. references: UETR, then EndToEndId, then the account servicer reference,
+//    per entry transaction (`NtryDtls/TxDtls`) or per entry;
+// 2. batches: entries booked as a batch (`NtryDtls/Btch`) whose transactions
+//    were not matched by reference are matched against all instructions of
+//    the batch's payment information or message identification;
+// 3. amount and date: what is left is matched on currency, direction,
+//    amount and date within the configured tolerance.
+//
+// A reference or batch match whose amounts disagree is reported as partial.
+
+use crate::datetime::{date_part, iso_date_to_days};
+
+/// EndToEndId used when the initiating party gave none; never matched on.
+pub const NOT_PROVIDED: &str = "NOTPROVIDED";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconciliationConfig {
+    /// Largest accepted difference between booked and instructed amounts.
+    pub amount_tolerance: f64,
+    /// Largest accepted number of days between the booking (or value) date
+    /// and the requested execution or settlement date.
+    pub date_tolerance_days: i64,
+    /// Whether entries without a matching reference are matched on amount and date.
+    pub match_by_amount_and_date: bool,
+}
+
+impl Default for ReconciliationConfig {
+    fn default() -> Self {
+        ReconciliationConfig { amount_tolerance: 0.0, date_tolerance_days: 2, match_by_amount_and_date: true }
+    }
+}
+
+/// Payment references of an entry, an entry transaction or an instruction.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct References {
+    pub message_id: Option<String>,
+    pub payment_information_id: Option<String>,
+    pub instruction_id: Option<String>,
+    pub end_to_end_id: Option<String>,
+    pub uetr: Option<String>,
+    pub account_servicer_reference: Option<String>,
+}
+
+/// `NtryDtls/Btch` of a batch booked entry.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EntryBatch {
+    pub message_id: Option<String>,
+    pub payment_information_id: Option<String>,
+    pub number_of_transactions: Option<u64>,
+    pub total_amount: Option<f64>,
+}
+
+/// `NtryDtls/TxDtls` of an entry.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EntryTransaction {
+    pub references: References,
+    pub amount: Option<f64>,
+    pub currency: Option<String>,
+}
+
+/// A booked entry of a statement or notification.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StatementEntry {
+    pub account: Option<String>,
+    pub entry_reference: Option<String>,
+    /// Entry level references, usually only the account servicer reference.
+    pub references: References,
+    pub amount: f64,
+    pub currency: String,
+    pub credit: bool,
+    pub booking_date: Option<String>,
+    pub value_date: Option<String>,
+    pub batch: Option<EntryBatch>,
+    pub transactions: Vec<EntryTransaction>,
+}
+
+/// An instruction expected to show up on the statement.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Instruction {
+    pub references: References,
+    pub amount: f64,
+    pub currency: String,
+    /// Whether the instruction credits the reconciled account; outgoing
+    /// credit transfers debit it.
+    pub credit: bool,
+    /// Requested execution or interbank settlement date.
+    pub date: Option<String>,
+}
+
+/// Matching rules, in order of precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchRule {
+    Uetr,
+    EndToEndId,
+    AccountServicerReference,
+    Batch,
+    AmountAndDate,
+}
+
+/// An entry, or a transaction of an entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntryPosition {
+    pub entry: usize,
+    pub transaction: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconciliationMatch {
+    pub rule: MatchRule,
+    pub position: EntryPosition,
+    /// Indexes of the matched instructions.
+    pub instructions: Vec<usize>,
+    pub booked_amount: Option<f64>,
+    pub instructed_amount: f64,
+    /// Why a partial match did not fully agree.
+    pub discrepancy: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Reconciliation {
+    pub matched: Vec<ReconciliationMatch>,
+    /// Matched by reference or batch but with disagreeing amounts, currency
+    /// or number of transactions.
+    pub partial: Vec<ReconciliationMatch>,
+    pub unmatched_entries: Vec<EntryPosition>,
+    pub unmatched_instructions: Vec<usize>,
+}
+
+/// What is matched in the reference and amount passes: a transaction of an
+/// entry, or the entry when it carries no transaction details.
+struct Item<'a> {
+    position: EntryPosition,
+    references: References,
+    amount: Option<f64>,
+    currency: &'a str,
+    credit: bool,
+    date: Option<&'a str>,
+}
+
+fn items(entries: &[StatementEntry]) -> Vec<Item<'_>> {
+    let mut items = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let date = entry.booking_date.as_deref().or(entry.value_date.as_deref()).map(date_part);
+        if entry.transactions.is_empty() {
+            items.push(Item {
+                position: EntryPosition { entry: index, transaction: None },
+                references: entry.references.clone(),
+                amount: Some(entry.amount),
+                currency: &entry.currency,
+                credit: entry.credit,
+                date,
+            });
+            continue;
+        }
+        let single = entry.transactions.len() == 1;
+        for (position, tx) in entry.transactions.iter().enumerate() {
+            let mut references = tx.references.clone();
+            if references.account_servicer_reference.is_none() && single {
+                references.account_servicer_reference = entry.references.account_servicer_reference.clone();
+            }
+            items.push(Item {
+                position: EntryPosition { entry: index, transaction: Some(position) },
+                references,
+                // A single transaction books the entry amount; in a batch
+                // the share of a transaction is only known from TxDtls/Amt.
+                amount: tx.amount.or(if single { Some(entry.amount) } else { None }),
+                currency: tx.currency.as_deref().unwrap_or(&entry.currency),
+                credit: entry.credit,
+                date,
+            });
+        }
+    }
+    items
+}
+
+fn same(a: &Option<String>, b: &Option<String>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => !a.is_empty() && a.eq_ignore_ascii_case(b),
+        _ => false,
+    }
+}
+
+fn reference_rule(item: &References, instruction: &References) -> Option<MatchRule> {
+    if same(&item.uetr, &instruction.uetr) {
+        return Some(MatchRule::Uetr);
+    }
+    if item.end_to_end_id.as_deref() != Some(NOT_PROVIDED) && same(&item.end_to_end_id, &instruction.end_to_end_id) {
+        return Some(MatchRule::EndToEndId);
+    }
+    if same(&item.account_servicer_reference, &instruction.account_servicer_reference) {
+        return Some(MatchRule::AccountServicerReference);
+    }
+    None
+}
+
+fn days_between(a: Option<&str>, b: Option<&str>) -> Option<i64> {
+    Some((iso_date_to_days(date_part(a?))? - iso_date_to_days(date_part(b?))?).abs())
+}
+
+fn amounts_agree(booked: f64, instructed: f64, config: &ReconciliationConfig) -> bool {
+    (booked - instructed).abs() <= config.amount_tolerance + 1e-9
+}
+
+fn discrepancy(booked: Option<f64>, currency: &str, instructed: f64, instructed_currency: &str, config: &ReconciliationConfig) -> Option<String> {
+    if !currency.eq_ignore_ascii_case(instructed_currency) {
+        return Some(format!("booked in {} but instructed in {}", currency, instructed_currency));
+    }
+    match booked {
+        Some(booked) if !amounts_agree(booked, instructed, config) => Some(format!("booked {} but instructed {}", booked, instructed)),
+        _ => None,
+    }
+}
+
+/// Reconciles statement entries against instructions.
+pub fn reconcile(entries: &[StatementEntry], instructions: &[Instruction], config: &ReconciliationConfig) -> Reconciliation {
+    let items = items(entries);
+    let mut result = Reconciliation::default();
+    let mut item_done = vec![false; items.len()];
+    let mut used = vec![false; instructions.len()];
+
+    // References.
+    for (i, item) in items.iter().enumerate() {
+        let best = instructions
+            .iter()
+            .enumerate()
+            .filter(|(k, _)| !used[*k])
+            .filter_map(|(k, instruction)| reference_rule(&item.references, &instruction.references).map(|rule| (rule, k)))
+            .min();
+        if let Some((rule, k)) = best {
+            let instruction = &instructions[k];
+            used[k] = true;
+            item_done[i] = true;
+            let found = ReconciliationMatch {
+                rule,
+                position: item.position,
+                instructions: vec![k],
+                booked_amount: item.amount,
+                instructed_amount: instruction.amount,
+                discrepancy: discrepancy(item.amount, item.currency, instruction.amount, &instruction.currency, config),
+            };
+            if found.discrepancy.is_some() { result.partial.push(found) } else { result.matched.push(found) }
+        }
+    }
+
+    // Batches: entries with NtryDtls/Btch none of whose transactions matched.
+    for (index, entry) in entries.iter().enumerate() {
+        let Some(ref batch) = entry.batch else { continue };
+        let entry_items: Vec<usize> = (0..items.len()).filter(|i| items[*i].position.entry == index).collect();
+        if entry_items.iter().any(|i| item_done[*i]) {
+            continue;
+        }
+        let members: Vec<usize> = instructions
+            .iter()
+            .enumerate()
+            .filter(|(k, instruction)| {
+                !used[*k]
+                    && instruction.credit == entry.credit
+                    && (same(&batch.payment_information_id, &instruction.references.payment_information_id)
+                        || (batch.payment_information_id.is_none() && same(&batch.message_id, &instruction.references.message_id)))
+            })
+            .map(|(k, _)| k)
+            .collect();
+        if members.is_empty() {
+            continue;
+        }
+        let total: f64 = members.iter().map(|k| instructions[*k].amount).sum();
+        let booked = batch.total_amount.unwrap_or(entry.amount);
+        let mut problems = Vec::new();
+        if let Some(k) = members.iter().find(|k| !instructions[**k].currency.eq_ignore_ascii_case(&entry.currency)) {
+            problems.push(format!("booked in {} but instructed in {}", entry.currency, instructions[*k].currency));
+        }
+        if !amounts_agree(booked, total, config) {
+            problems.push(format!("booked {} but the batch instructions add up to {}", booked, total));
+        }
+        if let Some(count) = batch.number_of_transactions.filter(|c| *c != members.len() as u64) {
+            problems.push(format!("{} transactions booked but {} instructed", count, members.len()));
+        }
+        for k in &members {
+            used[*k] = true;
+        }
+        for i in entry_items {
+            item_done[i] = true;
+        }
+        let found = ReconciliationMatch {
+            rule: MatchRule::Batch,
+            position: EntryPosition { entry: index, transaction: None },
+            instructions: members,
+            booked_amount: Some(booked),
+            instructed_amount: total,
+            discrepancy: if problems.is_empty() { None } else { Some(problems.join("; ")) },
+        };
+        if found.discrepancy.is_some() { result.partial.push(found) } else { result.matched.push(found) }
+    }
+
+    // Amount and date.
+    if config.match_by_amount_and_date {
+        for (i, item) in items.iter().enumerate() {
+            let Some(amount) = item.amount.filter(|_| !item_done[i]) else { continue };
+            let best = instructions
+                .iter()
+                .enumerate()
+                .filter(|(k, instruction)| {
+                    !used[*k]
+                        && instruction.credit == item.credit
+                        && instruction.currency.eq_ignore_ascii_case(item.currency)
+                        && amounts_agree(amount, instruction.amount, config)
+                })
+                .filter_map(|(k, instruction)| match days_between(item.date, instruction.date.as_deref()) {
+                    Some(days) if days > config.date_tolerance_days => None,
+                    days => Some((days.unwrap_or(0), k)),
+                })
+                .min();
+            if let Some((_, k)) = best {
+                used[k] = true;
+                item_done[i] = true;
+                result.matched.push(ReconciliationMatch {
+                    rule: MatchRule::AmountAndDate,
+                    position: item.position,
+                    instructions: vec![k],
+                    booked_amount: Some(amount),
+                    instructed_amount: instructions[k].amount,
+                    discrepancy: None,
+                });
+            }
+        }
+    }
+
+    result.unmatched_entries = items.iter().zip(&item_done).filter(|(_, done)| !**done).map(|(item, _)| item.position).collect();
+    result.unmatched_instructions = (0..instructions.len()).filter(|k| !used[*k]).collect();
+    result
+}
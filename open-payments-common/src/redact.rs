@@ -0,0 +1,298 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Redaction of personal data, for logging messages in production.
+//
+// Personal data is found by the ISO 20022 tags of the elements and of
+// their parents, whatever the message and its version:
+//
+// - names: `Nm` of parties and accounts;
+// - addresses: `PstlAdr` and `AdrLine`;
+// - account identifiers: `IBAN`, the `Othr/Id` of accounts (`Acct`,
+//   `DbtrAcct`, `CdtrAcct`, ...) and the `Id` of proxies (`Prxy`);
+// - birth details: `DtAndPlcOfBirth` and `BirthDt`;
+// - person identifiers: the `Othr/Id` of private identifications (`PrvtId`);
+// - contact details: `CtctDtls`;
+// - remittance information: `RmtInf`.
+//
+// The identification of financial institutions (`FinInstnId`) is not
+// personal data and is left as is, and neither are the codes of code lists
+// (`Cd`, `AdrTp`, `NmPrfx`, `PrefrdMtd`) within masked elements. A
+// `RedactionPolicy` says what to do with each category; the innermost
+// category of an element applies.
+
+use crate::ValidationError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+pub const ERR_REDACTION: u32 = 3401;
+
+/// Replacement of masked text.
+pub const MASK: &str = "***";
+
+/// Elements holding codes, left as is within masked elements.
+pub const CODE_ELEMENTS: &[&str] = &["Cd", "AdrTp", "NmPrfx", "PrefrdMtd"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    Name,
+    Address,
+    AccountId,
+    BirthDetails,
+    PersonId,
+    ContactDetails,
+    RemittanceInformation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Treatment {
+    #[default]
+    Keep,
+    /// Replaces the text with `MASK`.
+    Mask,
+    /// Replaces all but the last characters with `MASK`.
+    KeepLast(usize),
+    /// Leaves the element out.
+    Drop,
+}
+
+impl Treatment {
+    fn apply(&self, text: &str) -> String {
+        match self {
+            Treatment::Keep => text.to_string(),
+            Treatment::KeepLast(n) => {
+                let chars: Vec<char> = text.chars().collect();
+                if chars.len() <= *n {
+                    MASK.to_string()
+                } else {
+                    format!("{}{}", MASK, chars[chars.len() - n..].iter().collect::<String>())
+                }
+            }
+            Treatment::Mask | Treatment::Drop => MASK.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedactionPolicy {
+    pub names: Treatment,
+    pub addresses: Treatment,
+    pub account_ids: Treatment,
+    pub birth_details: Treatment,
+    pub person_ids: Treatment,
+    pub contact_details: Treatment,
+    pub remittance_information: Treatment,
+    /// Additional tags and their categories, taking precedence over the
+    /// built-in ones.
+    pub tags: Vec<(String, Category)>,
+}
+
+/// Masks names and person identifiers, keeps the last 4 characters of
+/// account identifiers, drops addresses, birth and contact details and
+/// keeps remittance information.
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        RedactionPolicy {
+            names: Treatment::Mask,
+            addresses: Treatment::Drop,
+            account_ids: Treatment::KeepLast(4),
+            birth_details: Treatment::Drop,
+            person_ids: Treatment::Mask,
+            contact_details: Treatment::Drop,
+            remittance_information: Treatment::Keep,
+            tags: vec![],
+        }
+    }
+}
+
+impl RedactionPolicy {
+    /// A policy that keeps everything, to start from.
+    pub fn keep_all() -> Self {
+        RedactionPolicy {
+            names: Treatment::Keep,
+            addresses: Treatment::Keep,
+            account_ids: Treatment::Keep,
+            birth_details: Treatment::Keep,
+            person_ids: Treatment::Keep,
+            contact_details: Treatment::Keep,
+            remittance_information: Treatment::Keep,
+            tags: vec![],
+        }
+    }
+
+    pub fn names(mut self, treatment: Treatment) -> Self {
+        self.names = treatment;
+        self
+    }
+
+    pub fn addresses(mut self, treatment: Treatment) -> Self {
+        self.addresses = treatment;
+        self
+    }
+
+    pub fn account_ids(mut self, treatment: Treatment) -> Self {
+        self.account_ids = treatment;
+        self
+    }
+
+    pub fn birth_details(mut self, treatment: Treatment) -> Self {
+        self.birth_details = treatment;
+        self
+    }
+
+    pub fn person_ids(mut self, treatment: Treatment) -> Self {
+        self.person_ids = treatment;
+        self
+    }
+
+    pub fn contact_details(mut self, treatment: Treatment) -> Self {
+        self.contact_details = treatment;
+        self
+    }
+
+    pub fn remittance_information(mut self, treatment: Treatment) -> Self {
+        self.remittance_information = treatment;
+        self
+    }
+
+    /// Puts the elements with `tag` in `category`.
+    pub fn tag(mut self, tag: &str, category: Category) -> Self {
+        self.tags.push((tag.to_string(), category));
+        self
+    }
+
+    pub fn treatment(&self, category: Category) -> Treatment {
+        match category {
+            Category::Name => self.names,
+            Category::Address => self.addresses,
+            Category::AccountId => self.account_ids,
+            Category::BirthDetails => self.birth_details,
+            Category::PersonId => self.person_ids,
+            Category::ContactDetails => self.contact_details,
+            Category::RemittanceInformation => self.remittance_information,
+        }
+    }
+
+    /// Category of the element `tag` within the elements `ancestors`,
+    /// outermost first.
+    pub fn category(&self, tag: &str, ancestors: &[&str]) -> Option<Category> {
+        if let Some((_, category)) = self.tags.iter().find(|(t, _)| t == tag) {
+            return Some(*category);
+        }
+        let ancestor = |n: usize| ancestors.len().checked_sub(n).map(|i| ancestors[i]).unwrap_or_default();
+        match tag {
+            "Nm" => Some(Category::Name),
+            "PstlAdr" | "AdrLine" => Some(Category::Address),
+            "IBAN" => Some(Category::AccountId),
+            "Id" if ancestor(1) == "Prxy" => Some(Category::AccountId),
+            "Id" if ancestor(1) == "Othr" && ancestor(2) == "Id" && ancestor(3).ends_with("Acct") => Some(Category::AccountId),
+            "Id" if ancestor(1) == "Othr" && ancestor(2) == "PrvtId" => Some(Category::PersonId),
+            "DtAndPlcOfBirth" | "BirthDt" => Some(Category::BirthDetails),
+            "CtctDtls" => Some(Category::ContactDetails),
+            "RmtInf" => Some(Category::RemittanceInformation),
+            _ => None,
+        }
+    }
+}
+
+fn redact_value<'a>(value: &'a mut Value, ancestors: &[&'a str], treatment: Treatment, policy: &RedactionPolicy) {
+    match value {
+        Value::String(text) => *text = treatment.apply(text),
+        Value::Array(items) => items.iter_mut().for_each(|item| redact_value(item, ancestors, treatment, policy)),
+        Value::Object(map) => {
+            let tag = ancestors.last().copied().unwrap_or_default();
+            if tag == "FinInstnId" {
+                return;
+            }
+            map.retain(|key, _| {
+                key.starts_with(['@', '$']) || policy.category(key, ancestors).map(|category| policy.treatment(category)) != Some(Treatment::Drop)
+            });
+            for (key, child) in map.iter_mut() {
+                let child_treatment = if key.starts_with(['@', '$']) {
+                    treatment
+                } else if let Some(category) = policy.category(key, ancestors) {
+                    policy.treatment(category)
+                } else if CODE_ELEMENTS.contains(&key.as_str()) {
+                    Treatment::Keep
+                } else {
+                    treatment
+                };
+                if key.starts_with('$') {
+                    redact_value(child, ancestors, child_treatment, policy);
+                } else {
+                    let mut child_ancestors = ancestors.to_vec();
+                    child_ancestors.push(key.trim_start_matches('@'));
+                    redact_value(child, &child_ancestors, child_treatment, policy);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Redacts a message serialized with serde_json.
+pub fn redact_json(value: &mut Value, policy: &RedactionPolicy) {
+    redact_value(value, &[], Treatment::Keep, policy)
+}
+
+/// A redacted copy of a message. Masked text does not meet the facets of
+/// the message, so the copy is for logging and not for sending; an error
+/// means a masked element cannot be represented in the message (a code
+/// list, for instance) and the policy should drop or keep it instead.
+pub fn redact<M: Serialize + DeserializeOwned>(message: &M, policy: &RedactionPolicy) -> Result<M, ValidationError> {
+    let mut value = serde_json::to_value(message).map_err(|e| ValidationError::new(ERR_REDACTION, e.to_string()))?;
+    redact_json(&mut value, policy);
+    serde_json::from_value(value).map_err(|e| ValidationError::new(ERR_REDACTION, format!("redacted message is not valid: {}", e)))
+}
+
+/// Displays a message as redacted JSON, for logging:
+/// `info!("{}", Redacted::new(&document, &policy))`.
+pub struct Redacted<'a, M> {
+    pub message: &'a M,
+    pub policy: &'a RedactionPolicy,
+}
+
+impl<'a, M> Redacted<'a, M> {
+    pub fn new(message: &'a M, policy: &'a RedactionPolicy) -> Self {
+        Redacted { message, policy }
+    }
+}
+
+impl<M: Serialize> fmt::Display for Redacted<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = serde_json::to_value(self.message).map_err(|_| fmt::Error)?;
+        redact_json(&mut value, self.policy);
+        write!(f, "{}", value)
+    }
+}
+
+/// `Debug` shows the redacted message too, so a `Redacted` can stand in for
+/// the message in structs that derive `Debug`.
+impl<M: Serialize> fmt::Debug for Redacted<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,253 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Reasons for rejecting an inbound message with a message reject (admi.002):
+// the reject reason code, the location of the offending element and the
+// `BizMsgIdr` of the rejected message with the sender it goes back to.
+//
+// Reason codes are ISO 20022 external status reason codes. Parse failures
+// are `FF01` (invalid format) or `CH21` (mandatory element missing),
+// failed facets of the generated `validate` methods (codes 1001 to 1005)
+// are `CH16` (element content incorrect), and any other `ValidationError`
+// is `NARR` with its message as the description. Schema violations are
+// `CH21` for missing elements, `CH16` for invalid values and `FF01`
+// otherwise.
+
+use crate::header_check::AgentId;
+#[cfg(feature = "derive_serde")]
+use crate::header_check::agent_id;
+use crate::schema::{self, SchemaViolation};
+use crate::ValidationError;
+use regex::Regex;
+use std::sync::LazyLock;
+#[cfg(feature = "derive_serde")]
+use serde_json::Value;
+
+pub const REASON_INVALID_FORMAT: &str = "FF01";
+pub const REASON_MISSING_ELEMENT: &str = "CH21";
+pub const REASON_INVALID_CONTENT: &str = "CH16";
+pub const REASON_NARRATIVE: &str = "NARR";
+
+/// Codes of the facets checked by the generated `validate` methods. Their
+/// messages start with the field name and end with the limit of the facet.
+pub const ERR_MIN_LENGTH: u32 = 1001;
+pub const ERR_MAX_LENGTH: u32 = 1002;
+pub const ERR_MIN_VALUE: u32 = 1003;
+pub const ERR_MAX_VALUE: u32 = 1004;
+pub const ERR_PATTERN: u32 = 1005;
+
+static LEAF_ELEMENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<(?:[\w.-]+:)?([\w.-]+)(?:\s[^>]*)?>\s*([^<]*?)\s*</").unwrap());
+static FROM_ELEMENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<(?:[\w.-]+:)?Fr(?:\s[^>]*)?>(.*?)</(?:[\w.-]+:)?Fr>").unwrap());
+
+/// Why an inbound message is rejected.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Rejection {
+    /// `RjctgPtyRsn`.
+    pub reason: String,
+    /// `RsnDesc`.
+    pub description: String,
+    /// `ErrLctn`, the path of the offending element, e.g.
+    /// `/Document/FIToFICstmrCdtTrf/GrpHdr/MsgId`.
+    pub location: Option<String>,
+    /// `BizMsgIdr` of the rejected message, for `RltdRef/Ref`.
+    pub biz_msg_idr: Option<String>,
+    /// `Fr` of the rejected message, the receiver of the reject.
+    pub sender: Option<AgentId>,
+    /// Code of the `ValidationError`, if any.
+    pub error_code: Option<u32>,
+}
+
+/// Reason code of a `ValidationError` code.
+pub fn validation_reason(code: u32) -> &'static str {
+    match code {
+        ERR_MIN_LENGTH..=ERR_PATTERN | schema::ERR_VALUE => REASON_INVALID_CONTENT,
+        schema::ERR_MISSING_ELEMENT => REASON_MISSING_ELEMENT,
+        schema::ERR_XML_SYNTAX..=schema::ERR_TEXT => REASON_INVALID_FORMAT,
+        _ => REASON_NARRATIVE,
+    }
+}
+
+impl Rejection {
+    /// A message that could not be parsed; `description` is the message of
+    /// the parser.
+    pub fn syntax(description: &str, location: Option<String>) -> Self {
+        let reason = if description.contains("missing field") { REASON_MISSING_ELEMENT } else { REASON_INVALID_FORMAT };
+        Rejection {
+            reason: reason.to_string(),
+            description: description.to_string(),
+            location,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_validation_error(error: &ValidationError) -> Self {
+        Rejection {
+            reason: validation_reason(error.code).to_string(),
+            description: error.message.clone(),
+            error_code: Some(error.code),
+            ..Default::default()
+        }
+    }
+
+    /// A message that does not follow its schema; the description starts
+    /// with the line and column of the violation.
+    pub fn from_schema_violation(violation: &SchemaViolation) -> Self {
+        Rejection {
+            reason: validation_reason(violation.code).to_string(),
+            description: format!("{}:{} {}", violation.line, violation.column, violation.message),
+            location: Some(violation.path.clone()),
+            error_code: Some(violation.code),
+            ..Default::default()
+        }
+    }
+
+    /// Fills `biz_msg_idr` and `sender` from the business application
+    /// header of the raw message, when they are not known yet.
+    pub fn with_header_references(mut self, xml: &str) -> Self {
+        let (biz_msg_idr, sender) = header_references(xml);
+        self.biz_msg_idr = self.biz_msg_idr.or(biz_msg_idr);
+        self.sender = self.sender.or(sender);
+        self
+    }
+}
+
+/// Text of the first element with the given local name that holds text.
+fn element_text(xml: &str, element: &str) -> Option<String> {
+    LEAF_ELEMENT.captures_iter(xml).find(|c| &c[1] == element).map(|c| c[2].to_string()).filter(|s| !s.is_empty())
+}
+
+/// `BizMsgIdr` and `Fr` agent of the first business application header of
+/// a raw message. Works on messages that cannot be parsed, as long as the
+/// header elements are readable.
+pub fn header_references(xml: &str) -> (Option<String>, Option<AgentId>) {
+    let biz_msg_idr = element_text(xml, "BizMsgIdr");
+    let sender = FROM_ELEMENT.captures(xml).map(|c| c[1].to_string()).and_then(|fr| {
+        let agent = AgentId {
+            bic: element_text(&fr, "BICFI"),
+            member_id: element_text(&fr, "MmbId"),
+            clearing_system: element_text(&fr, "Cd").or_else(|| element_text(&fr, "Prtry")),
+            lei: element_text(&fr, "LEI"),
+        };
+        if agent.is_empty() { None } else { Some(agent) }
+    });
+    (biz_msg_idr, sender)
+}
+
+/// `BizMsgIdr` and `Fr` agent of the first `AppHdr` of a message
+/// serialized with serde_json.
+#[cfg(feature = "derive_serde")]
+pub fn message_references(message: &Value) -> (Option<String>, Option<AgentId>) {
+    fn find<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+        match value {
+            Value::Object(map) => map.get(key).or_else(|| map.values().find_map(|v| find(v, key))),
+            Value::Array(items) => items.iter().find_map(|v| find(v, key)),
+            _ => None,
+        }
+    }
+    let Some(header) = find(message, "AppHdr") else {
+        return (None, None);
+    };
+    let biz_msg_idr = header.get("BizMsgIdr").and_then(|v| v.as_str()).map(str::to_string);
+    let sender = header.get("Fr").and_then(|fr| fr.get("FIId")).and_then(agent_id);
+    (biz_msg_idr, sender)
+}
+
+/// What a failed facet says about the offending value.
+#[cfg(feature = "derive_serde")]
+enum Facet {
+    MinLength(usize),
+    MaxLength(usize),
+    MinValue(f64),
+    MaxValue(f64),
+    Any,
+}
+
+#[cfg(feature = "derive_serde")]
+impl Facet {
+    fn from_error(error: &ValidationError) -> Facet {
+        let limit = error.message.rsplit(' ').next().unwrap_or("");
+        let facet = match error.code {
+            ERR_MIN_LENGTH => limit.parse().ok().map(Facet::MinLength),
+            ERR_MAX_LENGTH => limit.parse().ok().map(Facet::MaxLength),
+            ERR_MIN_VALUE => limit.parse().ok().map(Facet::MinValue),
+            ERR_MAX_VALUE => limit.parse().ok().map(Facet::MaxValue),
+            _ => None,
+        };
+        facet.unwrap_or(Facet::Any)
+    }
+
+    fn fails(&self, value: &Value) -> bool {
+        let text = match value {
+            Value::String(s) => s.clone(),
+            Value::Number(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+            _ => return false,
+        };
+        let number = || text.parse::<f64>().ok();
+        match self {
+            Facet::MinLength(n) => text.chars().count() < *n,
+            Facet::MaxLength(n) => text.chars().count() > *n,
+            Facet::MinValue(v) => number().is_some_and(|x| x < *v),
+            Facet::MaxValue(v) => number().is_some_and(|x| x > *v),
+            Facet::Any => true,
+        }
+    }
+}
+
+/// Path of the element a `ValidationError` of a generated `validate`
+/// method is about, found in the message serialized with serde_json. The
+/// error names the field, and for length and value facets, told apart by
+/// the error code, the first value of that field that fails the facet is
+/// taken; for patterns the first value of the field. `None` for errors of
+/// other codes.
+#[cfg(feature = "derive_serde")]
+pub fn error_location(error: &ValidationError, message: &Value) -> Option<String> {
+    if !(ERR_MIN_LENGTH..=ERR_PATTERN).contains(&error.code) {
+        return None;
+    }
+    let field = error.message.split(' ').next()?.replace('_', "");
+    let facet = Facet::from_error(error);
+    fn search(value: &Value, path: &str, field: &str, facet: &Facet) -> Option<String> {
+        match value {
+            Value::Object(map) => map.iter().find_map(|(key, child)| {
+                let name = key.trim_start_matches(['@', '$']);
+                let child_path = if key == "$value" { path.to_string() } else { format!("{}/{}", path, key) };
+                if !name.eq_ignore_ascii_case(field) {
+                    return search(child, &child_path, field, facet);
+                }
+                match child {
+                    Value::Array(items) => items.iter().position(|item| facet.fails(item))
+                        .map(|i| format!("{}[{}]", child_path, i + 1))
+                        .or_else(|| search(child, &child_path, field, facet)),
+                    Value::Object(inner) if inner.get("$value").is_some_and(|v| facet.fails(v)) => Some(child_path),
+                    other if facet.fails(other) => Some(child_path),
+                    other => search(other, &child_path, field, facet),
+                }
+            }),
+            Value::Array(items) => items.iter().enumerate().find_map(|(i, item)| search(item, &format!("{}[{}]", path, i + 1), field, facet)),
+            _ => None,
+        }
+    }
+    search(message, "", &field, &facet)
+}
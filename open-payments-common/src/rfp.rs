@@ -0,0 +1,362 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Request-for-payment lifecycle. A creditor requests a payment with a
+// pain.013, the debtor side answers with pain.014 status reports, the
+// creditor may withdraw the request with a camt.055 answered by a camt.029,
+// and an accepted request is settled by a pacs.008 that carries the
+// references of the request:
+//
+// - `PmtId/EndToEndId` is the `EndToEndId` of the request;
+// - `PmtId/InstrId` is the `PmtInfId` of the request;
+// - `PmtId/UETR` is the `UETR` of the request when it has one.
+//
+// Requests are reduced to `RequestForPayment` records by the conversions
+// next to the messages and tracked by `EndToEndId` in an `RfpTracker`.
+
+use crate::datetime::{iso_date_to_days, iso_datetime_to_seconds};
+use crate::ValidationError;
+use rand::Rng;
+
+pub const ERR_RFP_UNKNOWN: u32 = 3001;
+pub const ERR_RFP_DUPLICATE: u32 = 3002;
+pub const ERR_RFP_STATE: u32 = 3003;
+pub const ERR_RFP_EXPIRED: u32 = 3004;
+pub const ERR_RFP_LINKAGE: u32 = 3005;
+pub const ERR_RFP_AMOUNT: u32 = 3006;
+pub const ERR_RFP_REQUEST: u32 = 3007;
+
+/// Where a request for payment stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RfpStatus {
+    /// Sent, no status report yet.
+    Requested,
+    /// Received or pending with the debtor (ACTC, RCVD, PDNG).
+    Presented,
+    /// Accepted by the debtor (ACCP, ACSP, ACWC, ACSC).
+    Accepted,
+    /// Rejected by the debtor (RJCT).
+    Rejected,
+    /// Settled by a credit transfer.
+    Paid,
+    /// A camt.055 is outstanding.
+    CancellationRequested,
+    /// Withdrawn by the creditor.
+    Cancelled,
+    /// Past its expiry date without a payment.
+    Expired,
+}
+
+impl RfpStatus {
+    /// Status of a pain.014 `TxSts`, `PmtInfSts` or `GrpSts` code.
+    pub fn from_status_code(code: &str) -> Option<RfpStatus> {
+        match code {
+            "RCVD" | "ACTC" | "PDNG" => Some(RfpStatus::Presented),
+            "ACCP" | "ACSP" | "ACWC" | "ACSC" => Some(RfpStatus::Accepted),
+            "RJCT" => Some(RfpStatus::Rejected),
+            _ => None,
+        }
+    }
+
+    /// No further transition is possible.
+    pub fn is_final(&self) -> bool {
+        matches!(self, RfpStatus::Rejected | RfpStatus::Paid | RfpStatus::Cancelled | RfpStatus::Expired)
+    }
+}
+
+/// One transaction of a pain.013.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RequestForPayment {
+    pub msg_id: String,
+    pub cre_dt_tm: String,
+    pub pmt_inf_id: String,
+    pub end_to_end_id: String,
+    pub uetr: Option<String>,
+    pub amount: f64,
+    pub currency: String,
+    /// `PmtCond/AmtModAllwd`: the debtor may pay a different amount.
+    pub amount_modification_allowed: bool,
+    /// `ReqdExctnDt`, ISODate or ISODateTime.
+    pub requested_execution_date: Option<String>,
+    /// `XpryDt`, ISODate or ISODateTime.
+    pub expiry: Option<String>,
+    pub creditor_agent: Option<String>,
+    pub debtor_agent: Option<String>,
+}
+
+/// References and amount of the credit transfer paying a request.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PaymentReferences {
+    pub msg_id: String,
+    pub instr_id: Option<String>,
+    pub end_to_end_id: String,
+    pub uetr: Option<String>,
+    pub amount: f64,
+    pub currency: String,
+}
+
+/// Amount in cents, to compare amounts without floating point noise.
+pub fn cents(amount: f64) -> i64 {
+    (amount * 100.0).round() as i64
+}
+
+/// Seconds since the epoch at which an ISODate or ISODateTime expiry
+/// passes. A date expires at the end of the day.
+fn expiry_seconds(expiry: &str) -> Option<i64> {
+    if expiry.len() == 10 {
+        iso_date_to_days(expiry).map(|days| (days + 1) * 86400)
+    } else {
+        iso_datetime_to_seconds(expiry)
+    }
+}
+
+/// A random version 4 UUID for `PmtId/UETR`.
+pub fn new_uetr() -> String {
+    let mut bytes: [u8; 16] = rand::thread_rng().gen();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32])
+}
+
+impl RequestForPayment {
+    /// Whether the request has expired at `now` (ISODateTime). Requests
+    /// without an expiry, or with one that cannot be read, never expire.
+    pub fn is_expired(&self, now: &str) -> bool {
+        match (self.expiry.as_deref().and_then(expiry_seconds), iso_datetime_to_seconds(now)) {
+            (Some(expiry), Some(now)) => now >= expiry,
+            _ => false,
+        }
+    }
+
+    /// Checks that a credit transfer carries the references of the request
+    /// and an amount the request allows.
+    pub fn check_payment(&self, payment: &PaymentReferences) -> Vec<ValidationError> {
+        let mut errors = vec![];
+        if payment.end_to_end_id != self.end_to_end_id {
+            errors.push(ValidationError::new(ERR_RFP_LINKAGE, format!(
+                "EndToEndId {} of payment {} is not the EndToEndId {} of the request",
+                payment.end_to_end_id, payment.msg_id, self.end_to_end_id
+            )));
+        }
+        if payment.instr_id.as_deref() != Some(self.pmt_inf_id.as_str()) {
+            errors.push(ValidationError::new(ERR_RFP_LINKAGE, format!(
+                "InstrId {} of payment {} is not the PmtInfId {} of the request",
+                payment.instr_id.as_deref().unwrap_or("(none)"), payment.msg_id, self.pmt_inf_id
+            )));
+        }
+        if let Some(uetr) = &self.uetr {
+            if !payment.uetr.as_deref().is_some_and(|u| u.eq_ignore_ascii_case(uetr)) {
+                errors.push(ValidationError::new(ERR_RFP_LINKAGE, format!(
+                    "UETR {} of payment {} is not the UETR {} of the request",
+                    payment.uetr.as_deref().unwrap_or("(none)"), payment.msg_id, uetr
+                )));
+            }
+        }
+        if payment.currency != self.currency {
+            errors.push(ValidationError::new(ERR_RFP_AMOUNT, format!(
+                "payment {} is in {}, the request {} is in {}",
+                payment.msg_id, payment.currency, self.end_to_end_id, self.currency
+            )));
+        } else if !self.amount_modification_allowed && cents(payment.amount) != cents(self.amount) {
+            errors.push(ValidationError::new(ERR_RFP_AMOUNT, format!(
+                "payment {} of {:.2} does not match the amount {:.2} of request {}, which does not allow modification",
+                payment.msg_id, payment.amount, self.amount, self.end_to_end_id
+            )));
+        } else if cents(payment.amount) <= 0 {
+            errors.push(ValidationError::new(ERR_RFP_AMOUNT, format!("payment {} has no amount", payment.msg_id)));
+        }
+        errors
+    }
+}
+
+/// A request and where it stands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackedRequest {
+    pub request: RequestForPayment,
+    pub status: RfpStatus,
+    /// Reason code of the last status report or cancellation.
+    pub reason: Option<String>,
+    /// `MsgId` of the credit transfer that paid the request.
+    pub payment_msg_id: Option<String>,
+    /// Status to return to when a cancellation is refused.
+    before_cancellation: Option<RfpStatus>,
+}
+
+/// Requests for payment by `EndToEndId`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RfpTracker {
+    requests: Vec<TrackedRequest>,
+}
+
+fn unknown(end_to_end_id: &str) -> ValidationError {
+    ValidationError::new(ERR_RFP_UNKNOWN, format!("no request for payment with EndToEndId {}", end_to_end_id))
+}
+
+impl RfpTracker {
+    pub fn new() -> Self {
+        RfpTracker::default()
+    }
+
+    /// Starts tracking a request that has been sent.
+    pub fn track(&mut self, request: RequestForPayment) -> Result<(), ValidationError> {
+        if self.get(&request.end_to_end_id).is_some() {
+            return Err(ValidationError::new(ERR_RFP_DUPLICATE, format!(
+                "request for payment with EndToEndId {} is already tracked", request.end_to_end_id
+            )));
+        }
+        self.requests.push(TrackedRequest {
+            request,
+            status: RfpStatus::Requested,
+            reason: None,
+            payment_msg_id: None,
+            before_cancellation: None,
+        });
+        Ok(())
+    }
+
+    pub fn get(&self, end_to_end_id: &str) -> Option<&TrackedRequest> {
+        self.requests.iter().find(|r| r.request.end_to_end_id == end_to_end_id)
+    }
+
+    pub fn requests(&self) -> &[TrackedRequest] {
+        &self.requests
+    }
+
+    /// Requests that are not yet rejected, paid, cancelled or expired.
+    pub fn open_requests(&self) -> impl Iterator<Item = &TrackedRequest> {
+        self.requests.iter().filter(|r| !r.status.is_final())
+    }
+
+    fn open(&mut self, end_to_end_id: &str) -> Result<&mut TrackedRequest, ValidationError> {
+        let tracked = self.requests.iter_mut()
+            .find(|r| r.request.end_to_end_id == end_to_end_id)
+            .ok_or_else(|| unknown(end_to_end_id))?;
+        if tracked.status.is_final() {
+            return Err(ValidationError::new(ERR_RFP_STATE, format!(
+                "request for payment {} is already {:?}", end_to_end_id, tracked.status
+            )));
+        }
+        Ok(tracked)
+    }
+
+    /// Applies a pain.014 status code with its reason code.
+    pub fn apply_status(&mut self, end_to_end_id: &str, code: &str, reason: Option<&str>) -> Result<RfpStatus, ValidationError> {
+        let status = RfpStatus::from_status_code(code).ok_or_else(|| ValidationError::new(ERR_RFP_STATE, format!(
+            "unknown status {} for request for payment {}", code, end_to_end_id
+        )))?;
+        let tracked = self.open(end_to_end_id)?;
+        if tracked.status == RfpStatus::Accepted && status == RfpStatus::Presented {
+            return Err(ValidationError::new(ERR_RFP_STATE, format!(
+                "status {} after acceptance of request for payment {}", code, end_to_end_id
+            )));
+        }
+        if tracked.status == RfpStatus::CancellationRequested {
+            tracked.before_cancellation = Some(status);
+        } else {
+            tracked.status = status;
+        }
+        tracked.reason = reason.map(|r| r.to_string());
+        Ok(status)
+    }
+
+    /// Checks that a request can be paid at `now` with the given credit
+    /// transfer, without recording the payment.
+    pub fn check_payment(&self, payment: &PaymentReferences, now: &str) -> Result<(), Vec<ValidationError>> {
+        let tracked = self.get(&payment.end_to_end_id).ok_or_else(|| vec![unknown(&payment.end_to_end_id)])?;
+        if tracked.status.is_final() || tracked.status == RfpStatus::CancellationRequested {
+            return Err(vec![ValidationError::new(ERR_RFP_STATE, format!(
+                "request for payment {} cannot be paid, it is {:?}", payment.end_to_end_id, tracked.status
+            ))]);
+        }
+        if tracked.request.is_expired(now) {
+            return Err(vec![ValidationError::new(ERR_RFP_EXPIRED, format!(
+                "request for payment {} expired at {}",
+                payment.end_to_end_id, tracked.request.expiry.as_deref().unwrap_or_default()
+            ))]);
+        }
+        let errors = tracked.request.check_payment(payment);
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Records the credit transfer that paid a request after checking it
+    /// with `check_payment`.
+    pub fn mark_paid(&mut self, payment: &PaymentReferences, now: &str) -> Result<(), Vec<ValidationError>> {
+        self.check_payment(payment, now)?;
+        let tracked = self.open(&payment.end_to_end_id).map_err(|e| vec![e])?;
+        tracked.status = RfpStatus::Paid;
+        tracked.payment_msg_id = Some(payment.msg_id.clone());
+        Ok(())
+    }
+
+    /// Records that a camt.055 was sent for a request.
+    pub fn request_cancellation(&mut self, end_to_end_id: &str, reason: Option<&str>) -> Result<(), ValidationError> {
+        let tracked = self.open(end_to_end_id)?;
+        if tracked.status == RfpStatus::CancellationRequested {
+            return Err(ValidationError::new(ERR_RFP_STATE, format!(
+                "cancellation of request for payment {} is already requested", end_to_end_id
+            )));
+        }
+        tracked.before_cancellation = Some(tracked.status);
+        tracked.status = RfpStatus::CancellationRequested;
+        tracked.reason = reason.map(|r| r.to_string());
+        Ok(())
+    }
+
+    /// Applies the camt.029 cancellation status of a request: ACCR cancels
+    /// it, RJCR restores the status it had, PDCR leaves it pending.
+    pub fn apply_cancellation_status(&mut self, end_to_end_id: &str, code: &str) -> Result<RfpStatus, ValidationError> {
+        let tracked = self.open(end_to_end_id)?;
+        if tracked.status != RfpStatus::CancellationRequested {
+            return Err(ValidationError::new(ERR_RFP_STATE, format!(
+                "no cancellation requested for request for payment {}", end_to_end_id
+            )));
+        }
+        match code {
+            "ACCR" | "CNCL" => {
+                tracked.status = RfpStatus::Cancelled;
+                tracked.before_cancellation = None;
+            }
+            "RJCR" => tracked.status = tracked.before_cancellation.take().unwrap_or(RfpStatus::Requested),
+            "PDCR" => {}
+            _ => return Err(ValidationError::new(ERR_RFP_STATE, format!(
+                "unknown cancellation status {} for request for payment {}", code, end_to_end_id
+            ))),
+        }
+        Ok(tracked.status)
+    }
+
+    /// Marks the open requests that have expired at `now` (ISODateTime)
+    /// and returns their `EndToEndId`s.
+    pub fn expire(&mut self, now: &str) -> Vec<String> {
+        let mut expired = vec![];
+        for tracked in self.requests.iter_mut() {
+            if !tracked.status.is_final() && tracked.request.is_expired(now) {
+                tracked.status = RfpStatus::Expired;
+                expired.push(tracked.request.end_to_end_id.clone());
+            }
+        }
+        expired
+    }
+}
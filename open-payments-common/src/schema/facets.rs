@@ -0,0 +1,409 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// Checks of text and attribute values against simple types: the lexical
+// form of the built-in type at the root of the derivation, then the facets
+// of every restriction step.
+
+use super::model::{Components, Facets, SimpleType, TypeDef, TypeRef};
+use std::cmp::Ordering;
+
+/// How the facets of a built-in type are applied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Family {
+    /// Lengths in characters, bounds in code point order.
+    Text,
+    /// Bounds and digits of decimal numbers.
+    Decimal,
+    Float,
+    /// Lengths in octets.
+    Base64,
+    Hex,
+    /// Lengths in items.
+    List,
+    /// Dates and times, compared in lexical order.
+    Temporal,
+    Other,
+}
+
+fn family(builtin: &str) -> Family {
+    match builtin {
+        "string" | "normalizedString" | "token" | "language" | "Name" | "NCName" | "NMTOKEN" | "ID" | "IDREF" | "ENTITY" | "anyURI" | "QName" => Family::Text,
+        "decimal" | "integer" | "long" | "int" | "short" | "byte" | "nonNegativeInteger" | "positiveInteger" | "nonPositiveInteger" | "negativeInteger"
+        | "unsignedLong" | "unsignedInt" | "unsignedShort" | "unsignedByte" => Family::Decimal,
+        "float" | "double" => Family::Float,
+        "base64Binary" => Family::Base64,
+        "hexBinary" => Family::Hex,
+        "NMTOKENS" | "IDREFS" | "ENTITIES" => Family::List,
+        "date" | "dateTime" | "time" | "gYear" | "gYearMonth" | "gMonth" | "gMonthDay" | "gDay" | "duration" => Family::Temporal,
+        _ => Family::Other,
+    }
+}
+
+/// Whether the whitespace of a value is kept as it is, rather than
+/// collapsed.
+fn preserves_whitespace(builtin: &str) -> bool {
+    matches!(builtin, "string" | "normalizedString" | "anySimpleType")
+}
+
+impl Components {
+    /// The built-in type a type is derived from, `None` for unions and
+    /// undeclared types.
+    fn builtin<'a>(&'a self, type_ref: &'a TypeRef) -> Option<&'a str> {
+        match type_ref {
+            TypeRef::Named(name) => match name.builtin() {
+                Some(builtin) => Some(builtin),
+                None => match self.types.get(name)? {
+                    TypeDef::Simple(simple) => self.simple_builtin(simple),
+                    TypeDef::Complex(complex) => self.builtin(self.simple_content(complex)?),
+                },
+            },
+            TypeRef::Simple(simple) => self.simple_builtin(simple),
+            TypeRef::Complex(complex) => self.builtin(self.simple_content(complex)?),
+        }
+    }
+
+    fn simple_builtin<'a>(&'a self, simple: &'a SimpleType) -> Option<&'a str> {
+        match simple {
+            SimpleType::Restriction { base, .. } => self.builtin(base),
+            SimpleType::List(_) => Some("NMTOKENS"),
+            SimpleType::Union(_) => None,
+        }
+    }
+
+    /// Checks a value against a simple type, or the simple content of a
+    /// complex type. The error describes the first failure.
+    pub fn check_value(&self, type_ref: &TypeRef, value: &str) -> Result<(), String> {
+        match type_ref {
+            TypeRef::Named(name) => match name.builtin() {
+                Some(builtin) => check_builtin(builtin, value),
+                None => match self.types.get(name) {
+                    Some(TypeDef::Simple(simple)) => self.check_simple(simple, value),
+                    Some(TypeDef::Complex(complex)) => match self.simple_content(complex) {
+                        Some(content) => self.check_value(content, value),
+                        None => Ok(()),
+                    },
+                    None => Err(format!("type {} is not declared", name)),
+                },
+            },
+            TypeRef::Simple(simple) => self.check_simple(simple, value),
+            TypeRef::Complex(complex) => match self.simple_content(complex) {
+                Some(content) => self.check_value(content, value),
+                None => Ok(()),
+            },
+        }
+    }
+
+    fn check_simple(&self, simple: &SimpleType, value: &str) -> Result<(), String> {
+        match simple {
+            SimpleType::Restriction { base, facets } => {
+                self.check_value(base, value)?;
+                let builtin = self.builtin(base).unwrap_or("anySimpleType");
+                if preserves_whitespace(builtin) {
+                    facets.check(family(builtin), value)
+                } else {
+                    facets.check(family(builtin), &collapse(value))
+                }
+            }
+            SimpleType::List(item) => value.split_whitespace().try_for_each(|item_value| self.check_value(item, item_value)),
+            SimpleType::Union(members) => {
+                if members.iter().any(|member| self.check_value(member, value).is_ok()) {
+                    Ok(())
+                } else {
+                    Err(format!("{} is not valid for any member type of the union", value))
+                }
+            }
+        }
+    }
+}
+
+fn collapse(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl Facets {
+    fn check(&self, family: Family, value: &str) -> Result<(), String> {
+        if !self.enumeration.is_empty() && !self.enumeration.iter().any(|code| self.same(family, code, value)) {
+            return Err(format!("{} is not one of the codes {}", value, self.enumeration.join(", ")));
+        }
+        if !self.patterns.is_empty() && !self.patterns.iter().any(|(_, pattern)| pattern.is_match(value)) {
+            let patterns: Vec<&str> = self.patterns.iter().map(|(source, _)| source.as_str()).collect();
+            return Err(format!("{} does not match the required pattern {}", value, patterns.join(" | ")));
+        }
+        let length = match family {
+            Family::Base64 => value.chars().filter(|c| !c.is_whitespace()).count() / 4 * 3 - value.trim_end().bytes().rev().take_while(|b| *b == b'=').count(),
+            Family::Hex => value.len() / 2,
+            Family::List => value.split_whitespace().count(),
+            _ => value.chars().count(),
+        };
+        if let Some(expected) = self.length {
+            if length != expected {
+                return Err(format!("{} does not have the length of {}", value, expected));
+            }
+        }
+        if let Some(min) = self.min_length {
+            if length < min {
+                return Err(format!("{} is shorter than the minimum length of {}", value, min));
+            }
+        }
+        if let Some(max) = self.max_length {
+            if length > max {
+                return Err(format!("{} exceeds the maximum length of {}", value, max));
+            }
+        }
+        let bounds = [
+            (&self.min_inclusive, [Ordering::Greater, Ordering::Equal], "less than the minimum value of"),
+            (&self.max_inclusive, [Ordering::Less, Ordering::Equal], "greater than the maximum value of"),
+            (&self.min_exclusive, [Ordering::Greater, Ordering::Greater], "not greater than"),
+            (&self.max_exclusive, [Ordering::Less, Ordering::Less], "not less than"),
+        ];
+        for (bound, allowed, description) in bounds {
+            if let Some(bound) = bound {
+                if !compare(family, value, bound).is_some_and(|ordering| allowed.contains(&ordering)) {
+                    return Err(format!("{} is {} {}", value, description, bound));
+                }
+            }
+        }
+        if family == Family::Decimal {
+            let (_, whole, fraction) = decimal_parts(value).unwrap_or((false, "", ""));
+            if let Some(total) = self.total_digits {
+                if whole.len() + fraction.len() > total {
+                    return Err(format!("{} has more than {} digits", value, total));
+                }
+            }
+            if let Some(digits) = self.fraction_digits {
+                if fraction.len() > digits {
+                    return Err(format!("{} has more than {} fraction digits", value, digits));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether a value is an enumerated code: equal as text, or as a number
+    /// for numeric types.
+    fn same(&self, family: Family, code: &str, value: &str) -> bool {
+        match family {
+            Family::Decimal | Family::Float => compare(family, value, code) == Some(Ordering::Equal),
+            _ => code == value,
+        }
+    }
+}
+
+fn compare(family: Family, value: &str, bound: &str) -> Option<Ordering> {
+    match family {
+        Family::Decimal => compare_decimals(value, bound),
+        Family::Float => value.parse::<f64>().ok()?.partial_cmp(&bound.parse::<f64>().ok()?),
+        _ => Some(value.cmp(bound)),
+    }
+}
+
+/// Sign, integer digits without leading zeros and fraction digits without
+/// trailing zeros of a decimal.
+fn decimal_parts(value: &str) -> Option<(bool, &str, &str)> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() && fraction.is_empty() || !whole.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let whole = whole.trim_start_matches('0');
+    let fraction = fraction.trim_end_matches('0');
+    Some((negative && !(whole.is_empty() && fraction.is_empty()), whole, fraction))
+}
+
+fn compare_decimals(a: &str, b: &str) -> Option<Ordering> {
+    let (a_negative, a_whole, a_fraction) = decimal_parts(a)?;
+    let (b_negative, b_whole, b_fraction) = decimal_parts(b)?;
+    let magnitude = a_whole.len().cmp(&b_whole.len()).then_with(|| a_whole.cmp(b_whole)).then_with(|| a_fraction.cmp(b_fraction));
+    Some(match (a_negative, b_negative) {
+        (false, false) => magnitude,
+        (true, true) => magnitude.reverse(),
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    })
+}
+
+/// Checks the lexical form of a value of a built-in type.
+fn check_builtin(builtin: &str, value: &str) -> Result<(), String> {
+    let collapsed = if preserves_whitespace(builtin) { value.to_string() } else { collapse(value) };
+    let value = collapsed.as_str();
+    let valid = match builtin {
+        "boolean" => matches!(value, "true" | "false" | "1" | "0"),
+        "decimal" => decimal_parts(value).is_some(),
+        "float" | "double" => matches!(value, "INF" | "-INF" | "NaN") || (value.parse::<f64>().is_ok() && !value.contains(['i', 'I', 'n', 'N'])),
+        "integer" | "long" | "int" | "short" | "byte" | "nonNegativeInteger" | "positiveInteger" | "nonPositiveInteger" | "negativeInteger"
+        | "unsignedLong" | "unsignedInt" | "unsignedShort" | "unsignedByte" => return check_integer(builtin, value),
+        "date" => date(value).and_then(timezone) == Some(""),
+        "dateTime" => date(value).and_then(|rest| rest.strip_prefix('T')).and_then(time).and_then(timezone) == Some(""),
+        "time" => time(value).and_then(timezone) == Some(""),
+        "gYear" => year(value).and_then(timezone) == Some(""),
+        "gYearMonth" => year(value).and_then(|rest| two_digits(rest.strip_prefix('-')?, 1, 12)).and_then(timezone) == Some(""),
+        "gMonth" => value.strip_prefix("--").and_then(|rest| two_digits(rest, 1, 12)).and_then(timezone) == Some(""),
+        "gMonthDay" => value.strip_prefix("--").and_then(|rest| two_digits(rest, 1, 12)).and_then(|rest| two_digits(rest.strip_prefix('-')?, 1, 31)).and_then(timezone) == Some(""),
+        "gDay" => value.strip_prefix("---").and_then(|rest| two_digits(rest, 1, 31)).and_then(timezone) == Some(""),
+        "duration" => duration(value),
+        "base64Binary" => base64(value),
+        "hexBinary" => value.len().is_multiple_of(2) && value.bytes().all(|b| b.is_ascii_hexdigit()),
+        "language" => value.split('-').enumerate().all(|(i, part)| (1..=8).contains(&part.len()) && part.bytes().all(|b| b.is_ascii_alphabetic() || (i > 0 && b.is_ascii_digit()))),
+        "Name" | "NCName" | "ID" | "IDREF" | "ENTITY" => name(value, builtin == "Name"),
+        "NMTOKEN" => !value.is_empty() && value.chars().all(name_char),
+        _ => true,
+    };
+    if valid { Ok(()) } else { Err(format!("{} is not a valid {}", value, builtin)) }
+}
+
+fn check_integer(builtin: &str, value: &str) -> Result<(), String> {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("{} is not a valid {}", value, builtin));
+    }
+    let (min, max): (i128, i128) = match builtin {
+        "long" => (i64::MIN.into(), i64::MAX.into()),
+        "int" => (i32::MIN.into(), i32::MAX.into()),
+        "short" => (i16::MIN.into(), i16::MAX.into()),
+        "byte" => (i8::MIN.into(), i8::MAX.into()),
+        "unsignedLong" => (0, u64::MAX.into()),
+        "unsignedInt" => (0, u32::MAX.into()),
+        "unsignedShort" => (0, u16::MAX.into()),
+        "unsignedByte" => (0, u8::MAX.into()),
+        "nonNegativeInteger" => (0, i128::MAX),
+        "positiveInteger" => (1, i128::MAX),
+        "nonPositiveInteger" => (i128::MIN, 0),
+        "negativeInteger" => (i128::MIN, -1),
+        _ => return Ok(()),
+    };
+    match value.trim_start_matches('+').parse::<i128>() {
+        Ok(number) if (min..=max).contains(&number) => Ok(()),
+        _ => Err(format!("{} is out of the range of {}", value, builtin)),
+    }
+}
+
+/// The rest of the value after two digits between `min` and `max`.
+fn two_digits(value: &str, min: u32, max: u32) -> Option<&str> {
+    let digits = value.get(..2)?;
+    let number: u32 = digits.bytes().all(|b| b.is_ascii_digit()).then(|| digits.parse().ok())??;
+    (min..=max).contains(&number).then(|| &value[2..])
+}
+
+/// A year of at least four digits, with an optional minus sign.
+fn year(value: &str) -> Option<&str> {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    let length = digits.bytes().take_while(|b| b.is_ascii_digit()).count();
+    (length >= 4 && (length == 4 || !digits.starts_with('0'))).then(|| &digits[length..])
+}
+
+fn date(value: &str) -> Option<&str> {
+    let rest = year(value)?;
+    let year: i64 = value[..value.len() - rest.len()].parse().ok()?;
+    let after_month = two_digits(rest.strip_prefix('-')?, 1, 12)?;
+    let month: u32 = rest[1..3].parse().ok()?;
+    let after_day = two_digits(after_month.strip_prefix('-')?, 1, 31)?;
+    let day: u32 = after_month[1..3].parse().ok()?;
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    (day <= days).then_some(after_day)
+}
+
+fn time(value: &str) -> Option<&str> {
+    if let Some(rest) = value.strip_prefix("24:00:00") {
+        let rest = rest.strip_prefix('.').map_or(rest, |fraction| fraction.trim_start_matches('0'));
+        return Some(rest);
+    }
+    let rest = two_digits(value, 0, 23)?;
+    let rest = two_digits(rest.strip_prefix(':')?, 0, 59)?;
+    let rest = two_digits(rest.strip_prefix(':')?, 0, 59)?;
+    match rest.strip_prefix('.') {
+        Some(fraction) => {
+            let digits = fraction.bytes().take_while(|b| b.is_ascii_digit()).count();
+            (digits > 0).then(|| &fraction[digits..])
+        }
+        None => Some(rest),
+    }
+}
+
+/// The rest of the value after an optional timezone.
+fn timezone(value: &str) -> Option<&str> {
+    if let Some(rest) = value.strip_prefix('Z') {
+        return Some(rest);
+    }
+    match value.strip_prefix(['+', '-']) {
+        Some(offset) => {
+            let rest = two_digits(offset, 0, 14)?;
+            two_digits(rest.strip_prefix(':')?, 0, 59)
+        }
+        None => Some(value),
+    }
+}
+
+fn duration(value: &str) -> bool {
+    let Some(rest) = value.strip_prefix('-').unwrap_or(value).strip_prefix('P') else {
+        return false;
+    };
+    let (date_part, time_part) = match rest.split_once('T') {
+        Some((date_part, time_part)) if !time_part.is_empty() => (date_part, Some(time_part)),
+        Some(_) => return false,
+        None => (rest, None),
+    };
+    fn components(mut part: &str, designators: &str) -> bool {
+        let mut designators = designators.chars();
+        while !part.is_empty() {
+            let number = part.bytes().take_while(|b| b.is_ascii_digit() || *b == b'.').count();
+            let Some(designator) = part[number..].chars().next() else {
+                return false;
+            };
+            let digits = &part[..number];
+            let decimal = digits.contains('.') && designator == 'S';
+            if digits.is_empty() || digits.starts_with('.') || digits.ends_with('.') || (digits.contains('.') && !decimal) || !designators.any(|d| d == designator) {
+                return false;
+            }
+            part = &part[number + 1..];
+        }
+        true
+    }
+    (!date_part.is_empty() || time_part.is_some()) && components(date_part, "YMD") && time_part.is_none_or(|part| components(part, "HMS"))
+}
+
+fn base64(value: &str) -> bool {
+    let text: Vec<u8> = value.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    let padding = text.iter().rev().take_while(|b| **b == b'=').count();
+    text.len().is_multiple_of(4) && padding <= 2 && text[..text.len() - padding].iter().all(|b| b.is_ascii_alphanumeric() || *b == b'+' || *b == b'/')
+}
+
+fn name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '-' | '_' | ':')
+}
+
+fn name(value: &str, colons: bool) -> bool {
+    let mut chars = value.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_' || (colons && c == ':')) && chars.all(|c| name_char(c) && (colons || c != ':'))
+}
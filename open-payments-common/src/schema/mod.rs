@@ -0,0 +1,167 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// Validation of raw XML against the XSDs of the messages, before it is
+// deserialized.
+//
+// The generated `validate` methods check the facets that survive the
+// mapping to Rust types. The XSDs say more: the order and number of the
+// elements (minOccurs, maxOccurs and choices), the exact codes of an
+// enumeration, `totalDigits`, and elements the generated types have no
+// field for, which serde silently drops. A `SchemaSet` holds the XSDs of
+// the messages and validates a message against the declaration of its
+// root element, found by namespace and name: the XSD of pacs.008.001.08
+// validates a `Document` in `urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08`.
+// Elements no XSD declares, such as a FedNow envelope when only the ISO
+// 20022 XSDs are loaded, or the wrapper of a business file, are looked
+// through and the declared elements inside them (`AppHdr`, `Document`)
+// are validated.
+//
+// Every violation carries the line and column of the offending element
+// and its path. The crates do not ship the XSDs; they are read at run
+// time, e.g. with `SchemaSet::load_dir("xsd/fednow")`.
+//
+// The supported XSD subset is the one of the ISO 20022 and FedNow schemas:
+// global and local elements and element references, named and anonymous
+// types, sequences, choices, `all`, groups, wildcards, simple and complex
+// content by extension or restriction, attributes and attribute groups,
+// and restrictions, lists and unions of the built-in types with all their
+// facets but `whiteSpace`. Identity constraints, substitution groups and
+// `xsi:type` are ignored.
+
+mod facets;
+mod model;
+mod validator;
+
+use crate::ValidationError;
+use model::Components;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+pub const ERR_SCHEMA: u32 = 3601;
+pub const ERR_XML_SYNTAX: u32 = 3602;
+pub const ERR_NO_SCHEMA: u32 = 3603;
+pub const ERR_UNEXPECTED_ELEMENT: u32 = 3604;
+pub const ERR_MISSING_ELEMENT: u32 = 3605;
+pub const ERR_OCCURRENCES: u32 = 3606;
+pub const ERR_ATTRIBUTE: u32 = 3607;
+pub const ERR_VALUE: u32 = 3608;
+pub const ERR_TEXT: u32 = 3609;
+
+/// A place where a message does not follow its schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaViolation {
+    /// One of the `ERR_` codes of this module.
+    pub code: u32,
+    /// Line and column (both from 1) of the start tag of the offending
+    /// element, or of the error for XML syntax errors.
+    pub line: u64,
+    pub column: u64,
+    /// Path of the offending element, e.g.
+    /// `/Document/FIToFICstmrCdtTrf/GrpHdr/MsgId`, or attribute
+    /// (`.../IntrBkSttlmAmt/@Ccy`).
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{} {}: {}", self.line, self.column, self.path, self.message)
+    }
+}
+
+impl From<SchemaViolation> for ValidationError {
+    fn from(violation: SchemaViolation) -> Self {
+        ValidationError::new(violation.code, violation.to_string())
+    }
+}
+
+/// The XSDs messages are validated against.
+#[derive(Debug, Default)]
+pub struct SchemaSet {
+    components: Components,
+    namespaces: BTreeSet<String>,
+}
+
+impl SchemaSet {
+    pub fn new() -> Self {
+        SchemaSet::default()
+    }
+
+    /// Reads every `.xsd` file in a directory and its subdirectories.
+    pub fn load_dir<P: AsRef<Path>>(directory: P) -> Result<Self, ValidationError> {
+        let mut schemas = SchemaSet::new();
+        schemas.add_dir(directory.as_ref())?;
+        Ok(schemas)
+    }
+
+    fn add_dir(&mut self, directory: &Path) -> Result<(), ValidationError> {
+        let error = |e: std::io::Error| ValidationError::new(ERR_SCHEMA, format!("{}: {}", directory.display(), e));
+        let mut paths: Vec<_> = fs::read_dir(directory).map_err(error)?.map(|entry| entry.map(|e| e.path())).collect::<Result<_, _>>().map_err(error)?;
+        paths.sort();
+        for path in paths {
+            if path.is_dir() {
+                self.add_dir(&path)?;
+            } else if path.extension().is_some_and(|extension| extension == "xsd") {
+                let xsd = fs::read_to_string(&path).map_err(|e| ValidationError::new(ERR_SCHEMA, format!("{}: {}", path.display(), e)))?;
+                self.add(&xsd).map_err(|e| ValidationError::new(e.code, format!("{}: {}", path.display(), e.message)))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the declarations of a schema document. A declaration with the
+    /// name of one already read replaces it.
+    pub fn add(&mut self, xsd: &str) -> Result<(), ValidationError> {
+        let namespace = self.components.read(xsd)?;
+        self.namespaces.insert(namespace);
+        Ok(())
+    }
+
+    /// Target namespaces of the schemas read, e.g.
+    /// `urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08`.
+    pub fn namespaces(&self) -> impl Iterator<Item = &str> {
+        self.namespaces.iter().map(String::as_str)
+    }
+
+    /// Validates a message and returns every violation found, by position.
+    /// A message that is not well-formed stops at the syntax error; one
+    /// without any declared element is a single `ERR_NO_SCHEMA` violation.
+    pub fn validate(&self, xml: &str) -> Vec<SchemaViolation> {
+        let mut violations = validator::validate(&self.components, xml);
+        violations.sort_by_key(|violation| (violation.line, violation.column));
+        violations
+    }
+
+    /// Validates a message and fails with its first violation.
+    pub fn check(&self, xml: &str) -> Result<(), ValidationError> {
+        match self.validate(xml).into_iter().next() {
+            Some(violation) => Err(violation.into()),
+            None => Ok(()),
+        }
+    }
+}
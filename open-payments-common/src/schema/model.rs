@@ -0,0 +1,584 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// The components of the XSDs (element, type, group and attribute
+// declarations) and how they are read from a schema document.
+//
+// References between components are kept as names and looked up when a
+// message is validated, so the XSDs of a message can be added in any order
+// and imports and includes need no resolution: every XSD a message uses
+// has to be added to the set.
+
+use super::ERR_SCHEMA;
+use crate::ValidationError;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use xml::attribute::OwnedAttribute;
+use xml::name::OwnedName;
+use xml::namespace::{Namespace, NS_NO_PREFIX};
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
+
+pub(crate) const XSD_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema";
+
+/// An expanded name: namespace (empty for none) and local name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct QName {
+    pub namespace: String,
+    pub local: String,
+}
+
+impl QName {
+    pub fn new(namespace: &str, local: &str) -> Self {
+        QName { namespace: namespace.to_string(), local: local.to_string() }
+    }
+
+    pub fn of(name: &OwnedName) -> Self {
+        QName::new(name.namespace.as_deref().unwrap_or(""), &name.local_name)
+    }
+
+    /// A built-in type, e.g. `xs:string`.
+    pub fn builtin(&self) -> Option<&str> {
+        if self.namespace == XSD_NAMESPACE { Some(&self.local) } else { None }
+    }
+}
+
+impl fmt::Display for QName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.local)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ElementDecl {
+    pub name: QName,
+    pub type_ref: TypeRef,
+}
+
+/// The type of an element or attribute: a named type, built-in or
+/// declared, or an anonymous one.
+#[derive(Debug)]
+pub(crate) enum TypeRef {
+    Named(QName),
+    Simple(Box<SimpleType>),
+    Complex(Box<ComplexType>),
+}
+
+#[derive(Debug)]
+pub(crate) enum TypeDef {
+    Simple(SimpleType),
+    Complex(ComplexType),
+}
+
+#[derive(Debug)]
+pub(crate) enum SimpleType {
+    Restriction { base: TypeRef, facets: Box<Facets> },
+    List(TypeRef),
+    Union(Vec<TypeRef>),
+}
+
+/// The facets of one restriction step. Enumerations and patterns of a step
+/// are alternatives; the steps of a derivation chain all apply.
+#[derive(Debug, Default)]
+pub(crate) struct Facets {
+    pub enumeration: Vec<String>,
+    pub patterns: Vec<(String, Regex)>,
+    pub length: Option<usize>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub min_inclusive: Option<String>,
+    pub max_inclusive: Option<String>,
+    pub min_exclusive: Option<String>,
+    pub max_exclusive: Option<String>,
+    pub total_digits: Option<usize>,
+    pub fraction_digits: Option<usize>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ComplexType {
+    /// Base type and whether it is extended (rather than restricted).
+    pub base: Option<(QName, bool)>,
+    pub content: Content,
+    pub attributes: Vec<AttributeDecl>,
+    pub attribute_groups: Vec<QName>,
+    pub any_attribute: bool,
+}
+
+#[derive(Debug)]
+pub(crate) enum Content {
+    Empty,
+    Elements { particle: Particle, mixed: bool },
+    /// Text of a simple type (simple content).
+    Simple(TypeRef),
+}
+
+#[derive(Debug)]
+pub(crate) struct AttributeDecl {
+    pub name: QName,
+    /// `None` for a reference to a global attribute.
+    pub type_ref: Option<TypeRef>,
+    pub required: bool,
+    pub prohibited: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct AttributeGroup {
+    pub attributes: Vec<AttributeDecl>,
+    pub attribute_groups: Vec<QName>,
+    pub any_attribute: bool,
+}
+
+/// A term with its minOccurs and maxOccurs (`None` for unbounded).
+#[derive(Debug)]
+pub(crate) struct Particle {
+    pub min: usize,
+    pub max: Option<usize>,
+    pub term: Term,
+}
+
+#[derive(Debug)]
+pub(crate) enum Term {
+    Element(ElementDecl),
+    /// Reference to a global element.
+    Ref(QName),
+    Any(Wildcard),
+    Group(QName),
+    Sequence(Vec<Particle>),
+    Choice(Vec<Particle>),
+    All(Vec<Particle>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Process {
+    Strict,
+    Lax,
+    Skip,
+}
+
+#[derive(Debug)]
+pub(crate) enum Namespaces {
+    Any,
+    /// Any namespace but this one and no namespace (`##other`).
+    Other(String),
+    List(Vec<String>),
+}
+
+#[derive(Debug)]
+pub(crate) struct Wildcard {
+    pub namespaces: Namespaces,
+    pub process: Process,
+}
+
+impl Wildcard {
+    pub fn allows(&self, namespace: &str) -> bool {
+        match self.namespaces {
+            Namespaces::Any => true,
+            Namespaces::Other(ref target) => !namespace.is_empty() && namespace != target,
+            Namespaces::List(ref namespaces) => namespaces.iter().any(|n| n == namespace),
+        }
+    }
+}
+
+/// The global components of all the XSDs read.
+#[derive(Debug, Default)]
+pub(crate) struct Components {
+    pub elements: HashMap<QName, ElementDecl>,
+    pub types: HashMap<QName, TypeDef>,
+    pub groups: HashMap<QName, Particle>,
+    pub attributes: HashMap<QName, AttributeDecl>,
+    pub attribute_groups: HashMap<QName, AttributeGroup>,
+}
+
+/// An element of a schema document.
+struct Node {
+    name: OwnedName,
+    attributes: Vec<OwnedAttribute>,
+    namespace: Namespace,
+    line: u64,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|a| a.name.namespace.is_none() && a.name.local_name == name).map(|a| a.value.as_str())
+    }
+
+    /// The XSD elements in this element, without annotations.
+    fn children(&self) -> impl Iterator<Item = &Node> {
+        self.children.iter().filter(|c| c.name.namespace.as_deref() == Some(XSD_NAMESPACE) && c.name.local_name != "annotation")
+    }
+
+    fn child(&self, name: &str) -> Option<&Node> {
+        self.children().find(|c| c.name.local_name == name)
+    }
+
+    fn error(&self, message: String) -> ValidationError {
+        ValidationError::new(ERR_SCHEMA, format!("line {}: xs:{}: {}", self.line, self.name.local_name, message))
+    }
+
+    fn required(&self, name: &str) -> Result<&str, ValidationError> {
+        self.attribute(name).ok_or_else(|| self.error(format!("missing attribute {}", name)))
+    }
+
+    /// The QName of a prefixed value, e.g. `type="xs:string"`.
+    fn qname(&self, value: &str) -> Result<QName, ValidationError> {
+        let (prefix, local) = value.split_once(':').unwrap_or((NS_NO_PREFIX, value));
+        match self.namespace.get(prefix) {
+            Some(namespace) => Ok(QName::new(namespace, local)),
+            None if prefix.is_empty() => Ok(QName::new("", local)),
+            None => Err(self.error(format!("undeclared prefix {} in {}", prefix, value))),
+        }
+    }
+
+    fn occurs(&self) -> Result<(usize, Option<usize>), ValidationError> {
+        let count = |name: &str, default: usize| match self.attribute(name) {
+            None => Ok(Some(default)),
+            Some("unbounded") => Ok(None),
+            Some(value) => value.trim().parse().map(Some).map_err(|_| self.error(format!("{} {} is not a number", name, value))),
+        };
+        let min = count("minOccurs", 1)?.ok_or_else(|| self.error("minOccurs cannot be unbounded".to_string()))?;
+        Ok((min, count("maxOccurs", 1)?))
+    }
+}
+
+fn read_tree(xsd: &str) -> Result<Node, ValidationError> {
+    use xml::common::Position;
+    let config = ParserConfig::new().trim_whitespace(true).ignore_comments(true);
+    let mut reader = EventReader::new_with_config(xsd.as_bytes(), config);
+    let mut stack: Vec<Node> = Vec::new();
+    loop {
+        let event = reader.next().map_err(|e| ValidationError::new(ERR_SCHEMA, format!("{}:{} {}", e.position().row + 1, e.position().column + 1, e.msg())))?;
+        match event {
+            XmlEvent::StartElement { name, attributes, namespace } => {
+                let line = reader.position().row + 1;
+                stack.push(Node { name, attributes, namespace, line, children: Vec::new() });
+            }
+            XmlEvent::EndElement { .. } => {
+                let node = stack.pop().expect("an end tag closes an open element");
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => return Ok(node),
+                }
+            }
+            XmlEvent::EndDocument => return Err(ValidationError::new(ERR_SCHEMA, "empty schema document".to_string())),
+            _ => {}
+        }
+    }
+}
+
+/// Translates an XSD regular expression, which is implicitly anchored, to
+/// the syntax of the `regex` crate.
+fn pattern(node: &Node, value: &str) -> Result<Regex, ValidationError> {
+    let mut translated = String::from("^(?:");
+    let mut class_start = false;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('i') => translated.push_str(r"[\p{L}_:]"),
+                Some('I') => translated.push_str(r"[^\p{L}_:]"),
+                Some('c') => translated.push_str(r"[\p{L}\p{N}._:\-]"),
+                Some('C') => translated.push_str(r"[^\p{L}\p{N}._:\-]"),
+                Some(escaped) => {
+                    translated.push('\\');
+                    translated.push(escaped);
+                }
+                None => translated.push_str(r"\\"),
+            },
+            // Anchors are plain characters in XSD, `^` negates only at the
+            // start of a class.
+            '^' if class_start => translated.push(c),
+            '^' | '$' => {
+                translated.push('\\');
+                translated.push(c);
+            }
+            _ => translated.push(c),
+        }
+        class_start = c == '[';
+    }
+    translated.push_str(")$");
+    Regex::new(&translated).map_err(|e| node.error(format!("pattern {}: {}", value, e)))
+}
+
+/// Reads one schema document.
+struct SchemaReader {
+    target: String,
+    qualified_elements: bool,
+    qualified_attributes: bool,
+}
+
+impl SchemaReader {
+    fn name(&self, node: &Node, qualified: bool) -> Result<QName, ValidationError> {
+        let namespace = if qualified { self.target.as_str() } else { "" };
+        Ok(QName::new(namespace, node.required("name")?))
+    }
+
+    fn element(&self, node: &Node, global: bool) -> Result<ElementDecl, ValidationError> {
+        let qualified = global || node.attribute("form").map_or(self.qualified_elements, |form| form == "qualified");
+        let name = self.name(node, qualified)?;
+        let type_ref = match node.attribute("type") {
+            Some(type_name) => TypeRef::Named(node.qname(type_name)?),
+            None => self.anonymous_type(node)?.unwrap_or_else(|| TypeRef::Named(QName::new(XSD_NAMESPACE, "anyType"))),
+        };
+        Ok(ElementDecl { name, type_ref })
+    }
+
+    fn anonymous_type(&self, node: &Node) -> Result<Option<TypeRef>, ValidationError> {
+        if let Some(simple) = node.child("simpleType") {
+            return Ok(Some(TypeRef::Simple(Box::new(self.simple_type(simple)?))));
+        }
+        if let Some(complex) = node.child("complexType") {
+            return Ok(Some(TypeRef::Complex(Box::new(self.complex_type(complex)?))));
+        }
+        Ok(None)
+    }
+
+    fn particle(&self, node: &Node) -> Result<Option<Particle>, ValidationError> {
+        let term = match node.name.local_name.as_str() {
+            "element" => match node.attribute("ref") {
+                Some(name) => Term::Ref(node.qname(name)?),
+                None => Term::Element(self.element(node, false)?),
+            },
+            "any" => Term::Any(self.wildcard(node)),
+            "group" => Term::Group(node.qname(node.required("ref")?)?),
+            "sequence" | "choice" | "all" => {
+                let mut particles = Vec::new();
+                for child in node.children() {
+                    particles.extend(self.particle(child)?);
+                }
+                match node.name.local_name.as_str() {
+                    "sequence" => Term::Sequence(particles),
+                    "choice" => Term::Choice(particles),
+                    _ => Term::All(particles),
+                }
+            }
+            _ => return Ok(None),
+        };
+        let (min, max) = node.occurs()?;
+        Ok(Some(Particle { min, max, term }))
+    }
+
+    fn wildcard(&self, node: &Node) -> Wildcard {
+        let namespaces = match node.attribute("namespace").unwrap_or("##any").trim() {
+            "##any" => Namespaces::Any,
+            "##other" => Namespaces::Other(self.target.clone()),
+            list => Namespaces::List(
+                list.split_whitespace()
+                    .map(|n| match n {
+                        "##targetNamespace" => self.target.clone(),
+                        "##local" => String::new(),
+                        other => other.to_string(),
+                    })
+                    .collect(),
+            ),
+        };
+        let process = match node.attribute("processContents") {
+            Some("lax") => Process::Lax,
+            Some("skip") => Process::Skip,
+            _ => Process::Strict,
+        };
+        Wildcard { namespaces, process }
+    }
+
+    fn attribute(&self, node: &Node, global: bool) -> Result<AttributeDecl, ValidationError> {
+        let required = node.attribute("use") == Some("required");
+        let prohibited = node.attribute("use") == Some("prohibited");
+        if let Some(name) = node.attribute("ref") {
+            return Ok(AttributeDecl { name: node.qname(name)?, type_ref: None, required, prohibited });
+        }
+        let qualified = global || node.attribute("form").map_or(self.qualified_attributes, |form| form == "qualified");
+        let name = self.name(node, qualified)?;
+        let type_ref = match (node.attribute("type"), node.child("simpleType")) {
+            (Some(type_name), _) => TypeRef::Named(node.qname(type_name)?),
+            (None, Some(simple)) => TypeRef::Simple(Box::new(self.simple_type(simple)?)),
+            (None, None) => TypeRef::Named(QName::new(XSD_NAMESPACE, "anySimpleType")),
+        };
+        Ok(AttributeDecl { name, type_ref: Some(type_ref), required, prohibited })
+    }
+
+    /// Attributes, attribute group references and attribute wildcards of
+    /// a complex type, extension, restriction or attribute group.
+    fn attributes(&self, node: &Node, group: &mut AttributeGroup) -> Result<(), ValidationError> {
+        for child in node.children() {
+            match child.name.local_name.as_str() {
+                "attribute" => group.attributes.push(self.attribute(child, false)?),
+                "attributeGroup" => group.attribute_groups.push(child.qname(child.required("ref")?)?),
+                "anyAttribute" => group.any_attribute = true,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn complex_type(&self, node: &Node) -> Result<ComplexType, ValidationError> {
+        let mut mixed = node.attribute("mixed") == Some("true");
+        let mut base = None;
+        let mut content = None;
+        let mut attributes = AttributeGroup::default();
+        let mut body = node;
+        if let Some(simple) = node.child("simpleContent") {
+            let derivation = simple.children().next().ok_or_else(|| simple.error("missing extension or restriction".to_string()))?;
+            let base_name = derivation.qname(derivation.required("base")?)?;
+            let extension = derivation.name.local_name == "extension";
+            content = Some(if extension {
+                Content::Simple(TypeRef::Named(base_name.clone()))
+            } else {
+                let facets = self.facets(derivation)?;
+                Content::Simple(TypeRef::Simple(Box::new(SimpleType::Restriction { base: TypeRef::Named(base_name.clone()), facets: Box::new(facets) })))
+            });
+            base = Some((base_name, extension));
+            body = derivation;
+        } else if let Some(complex) = node.child("complexContent") {
+            mixed = mixed || complex.attribute("mixed") == Some("true");
+            let derivation = complex.children().next().ok_or_else(|| complex.error("missing extension or restriction".to_string()))?;
+            base = Some((derivation.qname(derivation.required("base")?)?, derivation.name.local_name == "extension"));
+            body = derivation;
+        }
+        if content.is_none() {
+            for child in body.children() {
+                if let Some(particle) = self.particle(child)? {
+                    content = Some(Content::Elements { particle, mixed });
+                }
+            }
+        }
+        self.attributes(body, &mut attributes)?;
+        let content = content.unwrap_or(if mixed {
+            Content::Elements { particle: Particle { min: 1, max: Some(1), term: Term::Sequence(Vec::new()) }, mixed }
+        } else {
+            Content::Empty
+        });
+        Ok(ComplexType {
+            base,
+            content,
+            attributes: attributes.attributes,
+            attribute_groups: attributes.attribute_groups,
+            any_attribute: attributes.any_attribute,
+        })
+    }
+
+    fn simple_type(&self, node: &Node) -> Result<SimpleType, ValidationError> {
+        if let Some(restriction) = node.child("restriction") {
+            let base = match restriction.attribute("base") {
+                Some(base) => TypeRef::Named(restriction.qname(base)?),
+                None => match restriction.child("simpleType") {
+                    Some(simple) => TypeRef::Simple(Box::new(self.simple_type(simple)?)),
+                    None => return Err(restriction.error("missing base".to_string())),
+                },
+            };
+            return Ok(SimpleType::Restriction { base, facets: Box::new(self.facets(restriction)?) });
+        }
+        if let Some(list) = node.child("list") {
+            let item = match (list.attribute("itemType"), list.child("simpleType")) {
+                (Some(item), _) => TypeRef::Named(list.qname(item)?),
+                (None, Some(simple)) => TypeRef::Simple(Box::new(self.simple_type(simple)?)),
+                (None, None) => return Err(list.error("missing itemType".to_string())),
+            };
+            return Ok(SimpleType::List(item));
+        }
+        if let Some(union) = node.child("union") {
+            let mut members = Vec::new();
+            for member in union.attribute("memberTypes").unwrap_or("").split_whitespace() {
+                members.push(TypeRef::Named(union.qname(member)?));
+            }
+            for simple in union.children().filter(|c| c.name.local_name == "simpleType") {
+                members.push(TypeRef::Simple(Box::new(self.simple_type(simple)?)));
+            }
+            return Ok(SimpleType::Union(members));
+        }
+        Err(node.error("missing restriction, list or union".to_string()))
+    }
+
+    fn facets(&self, node: &Node) -> Result<Facets, ValidationError> {
+        let mut facets = Facets::default();
+        for child in node.children() {
+            let facet = child.name.local_name.as_str();
+            let Some(value) = child.attribute("value") else {
+                continue;
+            };
+            let count = || value.trim().parse::<usize>().map_err(|_| child.error(format!("{} is not a number", value)));
+            match facet {
+                "enumeration" => facets.enumeration.push(value.to_string()),
+                "pattern" => facets.patterns.push((value.to_string(), pattern(child, value)?)),
+                "length" => facets.length = Some(count()?),
+                "minLength" => facets.min_length = Some(count()?),
+                "maxLength" => facets.max_length = Some(count()?),
+                "totalDigits" => facets.total_digits = Some(count()?),
+                "fractionDigits" => facets.fraction_digits = Some(count()?),
+                "minInclusive" => facets.min_inclusive = Some(value.trim().to_string()),
+                "maxInclusive" => facets.max_inclusive = Some(value.trim().to_string()),
+                "minExclusive" => facets.min_exclusive = Some(value.trim().to_string()),
+                "maxExclusive" => facets.max_exclusive = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+        Ok(facets)
+    }
+}
+
+impl Components {
+    /// Adds the global components of a schema document and returns its
+    /// target namespace.
+    pub fn read(&mut self, xsd: &str) -> Result<String, ValidationError> {
+        let root = read_tree(xsd)?;
+        if root.name.namespace.as_deref() != Some(XSD_NAMESPACE) || root.name.local_name != "schema" {
+            return Err(root.error("not an XML schema".to_string()));
+        }
+        let reader = SchemaReader {
+            target: root.attribute("targetNamespace").unwrap_or("").to_string(),
+            qualified_elements: root.attribute("elementFormDefault") == Some("qualified"),
+            qualified_attributes: root.attribute("attributeFormDefault") == Some("qualified"),
+        };
+        for node in root.children() {
+            match node.name.local_name.as_str() {
+                "element" => {
+                    let element = reader.element(node, true)?;
+                    self.elements.insert(element.name.clone(), element);
+                }
+                "complexType" => {
+                    self.types.insert(reader.name(node, true)?, TypeDef::Complex(reader.complex_type(node)?));
+                }
+                "simpleType" => {
+                    self.types.insert(reader.name(node, true)?, TypeDef::Simple(reader.simple_type(node)?));
+                }
+                "group" => {
+                    let particle = node.children().find_map(|child| reader.particle(child).transpose()).transpose()?;
+                    let particle = particle.ok_or_else(|| node.error("missing sequence, choice or all".to_string()))?;
+                    self.groups.insert(reader.name(node, true)?, particle);
+                }
+                "attribute" => {
+                    let attribute = reader.attribute(node, true)?;
+                    self.attributes.insert(attribute.name.clone(), attribute);
+                }
+                "attributeGroup" => {
+                    let mut group = AttributeGroup::default();
+                    reader.attributes(node, &mut group)?;
+                    self.attribute_groups.insert(reader.name(node, true)?, group);
+                }
+                _ => {}
+            }
+        }
+        Ok(reader.target)
+    }
+}
@@ -0,0 +1,566 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// Validation of a message as it is read. Each element is checked when it
+// starts (is it allowed in its parent, are its attributes declared and
+// valid) and when it ends (does its text fit its simple type, do its child
+// elements follow the content model of its type).
+//
+// Child elements are matched against the content model greedily, which is
+// exact for schemas obeying the XSD rule that a child element always has a
+// single particle it can match (unique particle attribution).
+
+use super::model::{AttributeDecl, Components, ComplexType, Content, ElementDecl, Particle, Process, QName, Term, TypeDef, TypeRef, Wildcard};
+use super::{SchemaViolation, ERR_ATTRIBUTE, ERR_MISSING_ELEMENT, ERR_NO_SCHEMA, ERR_OCCURRENCES, ERR_SCHEMA, ERR_TEXT, ERR_UNEXPECTED_ELEMENT, ERR_VALUE, ERR_XML_SYNTAX};
+use xml::attribute::OwnedAttribute;
+use xml::common::Position;
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
+
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// What the type of an element allows inside it, with its extensions
+/// resolved.
+enum Allowed<'s> {
+    Empty,
+    /// Elements following the particles in turn.
+    Elements { particles: Vec<&'s Particle>, mixed: bool },
+    Text(&'s TypeRef),
+    /// `xs:anyType`: any attributes, text and elements.
+    Anything,
+}
+
+/// An attribute of a type: the use (`required`, `prohibited`) of its
+/// declaration in the type and the type of the global declaration it may
+/// refer to.
+struct Attribute<'s> {
+    name: &'s QName,
+    type_ref: &'s TypeRef,
+    required: bool,
+    prohibited: bool,
+}
+
+struct Effective<'s> {
+    allowed: Allowed<'s>,
+    attributes: Vec<Attribute<'s>>,
+    any_attribute: bool,
+}
+
+/// A child element as seen by the content model.
+struct Child {
+    name: QName,
+    line: u64,
+    column: u64,
+}
+
+/// An element being read.
+enum Frame<'s> {
+    /// An element the schemas do not declare, or content of a lax
+    /// wildcard: declared elements inside it are validated.
+    LookThrough,
+    /// Content of a skip wildcard or of an element already reported.
+    Skip,
+    Element(Box<ElementFrame<'s>>),
+}
+
+struct ElementFrame<'s> {
+    name: QName,
+    line: u64,
+    column: u64,
+    allowed: Allowed<'s>,
+    children: Vec<Child>,
+    text: String,
+    text_reported: bool,
+}
+
+/// What to do with an element that starts.
+enum Next<'s> {
+    Validate(&'s ElementDecl),
+    Frame(Frame<'s>),
+    /// The element is not allowed where it is.
+    Report(String),
+}
+
+impl<'s> Next<'s> {
+    /// An element outside of validated content: validated if the schemas
+    /// declare it, looked through otherwise.
+    fn global(components: &'s Components, name: &QName) -> Self {
+        match components.elements.get(name) {
+            Some(element) => Next::Validate(element),
+            None => Next::Frame(Frame::LookThrough),
+        }
+    }
+}
+
+/// Where a child element is declared in the content model of its parent.
+enum Declaration<'s> {
+    Element(&'s ElementDecl),
+    Wildcard(&'s Wildcard),
+}
+
+impl Components {
+    /// The simple type of the content of a complex type with simple
+    /// content.
+    pub(crate) fn simple_content<'s>(&'s self, complex: &'s ComplexType) -> Option<&'s TypeRef> {
+        match complex.content {
+            Content::Simple(ref type_ref) => Some(type_ref),
+            _ => None,
+        }
+    }
+
+    fn effective<'s>(&'s self, type_ref: &'s TypeRef) -> Result<Effective<'s>, String> {
+        match type_ref {
+            TypeRef::Named(name) if name.builtin() == Some("anyType") => Ok(Effective { allowed: Allowed::Anything, attributes: Vec::new(), any_attribute: true }),
+            TypeRef::Named(name) if name.builtin().is_some() => Ok(Effective { allowed: Allowed::Text(type_ref), attributes: Vec::new(), any_attribute: false }),
+            TypeRef::Named(name) => match self.types.get(name) {
+                Some(TypeDef::Simple(_)) => Ok(Effective { allowed: Allowed::Text(type_ref), attributes: Vec::new(), any_attribute: false }),
+                Some(TypeDef::Complex(complex)) => self.effective_complex(complex),
+                None => Err(format!("type {} is not declared", name)),
+            },
+            TypeRef::Simple(_) => Ok(Effective { allowed: Allowed::Text(type_ref), attributes: Vec::new(), any_attribute: false }),
+            TypeRef::Complex(complex) => self.effective_complex(complex),
+        }
+    }
+
+    fn effective_complex<'s>(&'s self, complex: &'s ComplexType) -> Result<Effective<'s>, String> {
+        let mut attributes = Vec::new();
+        let mut any_attribute = complex.any_attribute;
+        self.add_attributes(&complex.attributes, &complex.attribute_groups, &mut attributes, &mut any_attribute)?;
+        let mut allowed = match complex.content {
+            Content::Empty => Allowed::Empty,
+            Content::Elements { ref particle, mixed } => Allowed::Elements { particles: vec![particle], mixed },
+            Content::Simple(ref type_ref) => Allowed::Text(type_ref),
+        };
+        // Only a complex base has attributes and elements to inherit.
+        if let Some((ref base, extension)) = complex.base {
+            match self.types.get(base) {
+                Some(TypeDef::Complex(base)) => {
+                    let base = self.effective_complex(base)?;
+                    for attribute in base.attributes {
+                        if !attributes.iter().any(|a| a.name == attribute.name) {
+                            attributes.push(attribute);
+                        }
+                    }
+                    any_attribute = any_attribute || (extension && base.any_attribute);
+                    allowed = match (extension, base.allowed, allowed) {
+                        (true, Allowed::Elements { particles: mut inherited, .. }, Allowed::Elements { particles, mixed }) => {
+                            inherited.extend(particles);
+                            Allowed::Elements { particles: inherited, mixed }
+                        }
+                        (true, inherited, Allowed::Empty) => inherited,
+                        (_, _, own) => own,
+                    };
+                }
+                Some(TypeDef::Simple(_)) => {}
+                None if base.builtin().is_some() => {}
+                None => return Err(format!("type {} is not declared", base)),
+            }
+        }
+        attributes.retain(|a| !a.prohibited);
+        Ok(Effective { allowed, attributes, any_attribute })
+    }
+
+    fn add_attributes<'s>(&'s self, declared: &'s [AttributeDecl], groups: &'s [QName], attributes: &mut Vec<Attribute<'s>>, any_attribute: &mut bool) -> Result<(), String> {
+        for attribute in declared {
+            let type_ref = match attribute.type_ref {
+                Some(ref type_ref) => type_ref,
+                None => {
+                    let global = self.attributes.get(&attribute.name).ok_or_else(|| format!("attribute {} is not declared", attribute.name))?;
+                    global.type_ref.as_ref().expect("global attributes have a type")
+                }
+            };
+            attributes.push(Attribute { name: &attribute.name, type_ref, required: attribute.required, prohibited: attribute.prohibited });
+        }
+        for name in groups {
+            let group = self.attribute_groups.get(name).ok_or_else(|| format!("attribute group {} is not declared", name))?;
+            *any_attribute = *any_attribute || group.any_attribute;
+            self.add_attributes(&group.attributes, &group.attribute_groups, attributes, any_attribute)?;
+        }
+        Ok(())
+    }
+
+    /// The declaration of a child element in a content model.
+    fn declaration<'s>(&'s self, particles: &[&'s Particle], name: &QName) -> Option<Declaration<'s>> {
+        let mut wildcard = None;
+        for particle in particles {
+            match self.find(&particle.term, name, &mut wildcard) {
+                Some(declaration) => return Some(Declaration::Element(declaration)),
+                None => continue,
+            }
+        }
+        wildcard.map(Declaration::Wildcard)
+    }
+
+    fn find<'s>(&'s self, term: &'s Term, name: &QName, wildcard: &mut Option<&'s Wildcard>) -> Option<&'s ElementDecl> {
+        match term {
+            Term::Element(element) => (element.name == *name).then_some(element),
+            Term::Ref(reference) if reference == name => self.elements.get(reference),
+            Term::Ref(_) => None,
+            Term::Any(any) => {
+                if wildcard.is_none() && any.allows(&name.namespace) {
+                    *wildcard = Some(any);
+                }
+                None
+            }
+            Term::Group(group) => self.find(&self.groups.get(group)?.term, name, wildcard),
+            Term::Sequence(particles) | Term::Choice(particles) | Term::All(particles) => particles.iter().find_map(|p| self.find(&p.term, name, wildcard)),
+        }
+    }
+
+    /// maxOccurs of the particle of a child element in a content model.
+    fn max_occurs(&self, particles: &[&Particle], name: &QName) -> Option<usize> {
+        fn search(components: &Components, particle: &Particle, name: &QName) -> Option<Option<usize>> {
+            match particle.term {
+                Term::Element(ref element) if element.name == *name => Some(particle.max),
+                Term::Ref(ref reference) if reference == name => Some(particle.max),
+                Term::Group(ref group) => search(components, components.groups.get(group)?, name),
+                Term::Sequence(ref particles) | Term::Choice(ref particles) | Term::All(ref particles) => particles.iter().find_map(|p| search(components, p, name)),
+                _ => None,
+            }
+        }
+        particles.iter().find_map(|p| search(self, p, name)).flatten()
+    }
+}
+
+/// Matches the children of an element against its content model and keeps
+/// the elements expected where matching got furthest.
+struct Matcher<'s, 'c> {
+    components: &'s Components,
+    children: &'c [Child],
+    furthest: usize,
+    expected: Vec<String>,
+}
+
+impl Matcher<'_, '_> {
+    fn expect(&mut self, at: usize, name: String) {
+        if at > self.furthest || self.expected.is_empty() {
+            self.furthest = at;
+            self.expected = vec![name];
+        } else if at == self.furthest && !self.expected.contains(&name) {
+            self.expected.push(name);
+        }
+    }
+
+    /// The position after the occurrences of a particle from `pos`, or the
+    /// position of the mismatch.
+    fn particle(&mut self, particle: &Particle, mut pos: usize) -> Result<usize, usize> {
+        let mut count = 0;
+        while particle.max.is_none_or(|max| count < max) {
+            match self.term(&particle.term, pos) {
+                Ok(next) if next > pos => {
+                    pos = next;
+                    count += 1;
+                }
+                // An empty occurrence stands for all the missing ones.
+                Ok(_) => break,
+                Err(at) if at == pos && count >= particle.min => break,
+                Err(at) => return Err(at),
+            }
+        }
+        Ok(pos)
+    }
+
+    fn term(&mut self, term: &Term, pos: usize) -> Result<usize, usize> {
+        match term {
+            Term::Element(element) => self.element(&element.name, pos),
+            Term::Ref(name) => self.element(name, pos),
+            Term::Any(wildcard) => match self.children.get(pos) {
+                Some(child) if wildcard.allows(&child.name.namespace) => Ok(pos + 1),
+                _ => {
+                    self.expect(pos, "any element".to_string());
+                    Err(pos)
+                }
+            },
+            Term::Group(name) => match self.components.groups.get(name) {
+                Some(particle) => self.particle(particle, pos),
+                None => Err(pos),
+            },
+            Term::Sequence(particles) => particles.iter().try_fold(pos, |pos, particle| self.particle(particle, pos)),
+            Term::Choice(particles) => {
+                let mut empty = false;
+                for particle in particles {
+                    match self.particle(particle, pos) {
+                        Ok(next) if next > pos => return Ok(next),
+                        Ok(_) => empty = true,
+                        Err(at) if at > pos => return Err(at),
+                        Err(_) => {}
+                    }
+                }
+                if empty { Ok(pos) } else { Err(pos) }
+            }
+            Term::All(particles) => {
+                let mut seen = vec![false; particles.len()];
+                let mut pos = pos;
+                'next: while pos < self.children.len() {
+                    for (i, particle) in particles.iter().enumerate() {
+                        if seen[i] {
+                            continue;
+                        }
+                        if let Ok(next) = self.term(&particle.term, pos) {
+                            if next > pos {
+                                seen[i] = true;
+                                pos = next;
+                                continue 'next;
+                            }
+                        }
+                    }
+                    break;
+                }
+                let mut complete = true;
+                for (i, particle) in particles.iter().enumerate() {
+                    if !seen[i] && particle.min > 0 {
+                        // Records the missing element as expected.
+                        self.term(&particle.term, pos).ok();
+                        complete = false;
+                    }
+                }
+                if complete { Ok(pos) } else { Err(pos) }
+            }
+        }
+    }
+
+    fn element(&mut self, name: &QName, pos: usize) -> Result<usize, usize> {
+        match self.children.get(pos) {
+            Some(child) if child.name == *name => Ok(pos + 1),
+            _ => {
+                self.expect(pos, name.local.clone());
+                Err(pos)
+            }
+        }
+    }
+}
+
+/// `A`, `A or B`, `A, B or C`.
+fn alternatives(names: &[String]) -> String {
+    match names.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
+
+/// Validates a message as it is read.
+struct Validator<'s> {
+    components: &'s Components,
+    frames: Vec<Frame<'s>>,
+    path: Vec<String>,
+    violations: Vec<SchemaViolation>,
+    validated: bool,
+}
+
+impl<'s> Validator<'s> {
+    fn report(&mut self, code: u32, line: u64, column: u64, path: String, message: String) {
+        self.violations.push(SchemaViolation { code, line, column, path, message });
+    }
+
+    fn path(&self) -> String {
+        format!("/{}", self.path.join("/"))
+    }
+
+    fn start(&mut self, name: QName, attributes: &[OwnedAttribute], line: u64, column: u64) {
+        self.path.push(name.local.clone());
+        let components = self.components;
+        let next = match self.frames.last_mut() {
+            None | Some(Frame::LookThrough) => Next::global(components, &name),
+            Some(Frame::Skip) => Next::Frame(Frame::Skip),
+            Some(Frame::Element(parent)) => match parent.allowed {
+                Allowed::Anything => Next::global(components, &name),
+                Allowed::Elements { ref particles, .. } => match components.declaration(particles, &name) {
+                    Some(declaration) => {
+                        parent.children.push(Child { name: name.clone(), line, column });
+                        match declaration {
+                            Declaration::Element(element) => Next::Validate(element),
+                            Declaration::Wildcard(wildcard) => match (wildcard.process, components.elements.get(&name)) {
+                                (Process::Skip, _) => Next::Frame(Frame::Skip),
+                                (_, Some(element)) => Next::Validate(element),
+                                (Process::Lax, None) => Next::Frame(Frame::LookThrough),
+                                (Process::Strict, None) => Next::Report(format!("{} in namespace {} is not declared", name, name.namespace)),
+                            },
+                        }
+                    }
+                    None => Next::Report(format!("{} is not allowed in {}", name, parent.name)),
+                },
+                Allowed::Empty => Next::Report(format!("{} is not allowed in {}, whose content is empty", name, parent.name)),
+                Allowed::Text(_) => Next::Report(format!("{} is not allowed in {}, whose content is text only", name, parent.name)),
+            },
+        };
+        let frame = match next {
+            Next::Validate(element) => self.element_frame(element, attributes, line, column),
+            Next::Frame(frame) => frame,
+            Next::Report(message) => {
+                let path = self.path();
+                self.report(ERR_UNEXPECTED_ELEMENT, line, column, path, message);
+                Frame::Skip
+            }
+        };
+        self.frames.push(frame);
+    }
+
+    fn element_frame(&mut self, element: &'s ElementDecl, attributes: &[OwnedAttribute], line: u64, column: u64) -> Frame<'s> {
+        self.validated = true;
+        let path = self.path();
+        let effective = match self.components.effective(&element.type_ref) {
+            Ok(effective) => effective,
+            Err(message) => {
+                self.report(ERR_SCHEMA, line, column, path, message);
+                return Frame::Skip;
+            }
+        };
+        for attribute in attributes {
+            let name = QName::of(&attribute.name);
+            if name.namespace == XSI_NAMESPACE {
+                continue;
+            }
+            match effective.attributes.iter().find(|a| *a.name == name) {
+                Some(declaration) => {
+                    if let Err(message) = self.components.check_value(declaration.type_ref, &attribute.value) {
+                        self.report(ERR_VALUE, line, column, format!("{}/@{}", path, name), message);
+                    }
+                }
+                None if effective.any_attribute => {}
+                None => self.report(ERR_ATTRIBUTE, line, column, path.clone(), format!("attribute {} is not allowed", name)),
+            }
+        }
+        for declaration in effective.attributes.iter().filter(|a| a.required) {
+            if !attributes.iter().any(|a| QName::of(&a.name) == *declaration.name) {
+                self.report(ERR_ATTRIBUTE, line, column, path.clone(), format!("missing attribute {}", declaration.name));
+            }
+        }
+        Frame::Element(Box::new(ElementFrame {
+            name: element.name.clone(),
+            line,
+            column,
+            allowed: effective.allowed,
+            children: Vec::new(),
+            text: String::new(),
+            text_reported: false,
+        }))
+    }
+
+    fn text(&mut self, text: &str, line: u64, column: u64) {
+        let Some(Frame::Element(frame)) = self.frames.last_mut() else {
+            return;
+        };
+        match frame.allowed {
+            Allowed::Text(_) => frame.text.push_str(text),
+            Allowed::Elements { mixed: true, .. } | Allowed::Anything => {}
+            Allowed::Empty | Allowed::Elements { .. } => {
+                if !text.trim().is_empty() && !frame.text_reported {
+                    frame.text_reported = true;
+                    let message = format!("text is not allowed in {}", frame.name);
+                    let path = self.path();
+                    self.report(ERR_TEXT, line, column, path, message);
+                }
+            }
+        }
+    }
+
+    fn end(&mut self) {
+        if let Some(Frame::Element(frame)) = self.frames.pop() {
+            let path = self.path();
+            match frame.allowed {
+                Allowed::Text(type_ref) => {
+                    if let Err(message) = self.components.check_value(type_ref, &frame.text) {
+                        self.report(ERR_VALUE, frame.line, frame.column, path, message);
+                    }
+                }
+                Allowed::Elements { ref particles, .. } => self.content(&frame, particles, path),
+                Allowed::Empty | Allowed::Anything => {}
+            }
+        }
+        self.path.pop();
+    }
+
+    /// Checks the children of an element against its content model.
+    fn content(&mut self, frame: &ElementFrame<'s>, particles: &[&'s Particle], path: String) {
+        let mut matcher = Matcher { components: self.components, children: &frame.children, furthest: 0, expected: Vec::new() };
+        let (end, failed) = match particles.iter().try_fold(0, |pos, particle| matcher.particle(particle, pos)) {
+            Ok(end) => (end, false),
+            Err(at) => (at, true),
+        };
+        if !failed && end == frame.children.len() {
+            return;
+        }
+        let expected = if matcher.furthest == end { alternatives(&matcher.expected) } else { String::new() };
+        let Some(child) = frame.children.get(end) else {
+            let message = if expected.is_empty() { format!("{} is incomplete", frame.name) } else { format!("{} is missing {}", frame.name, expected) };
+            self.report(ERR_MISSING_ELEMENT, frame.line, frame.column, path, message);
+            return;
+        };
+        let path = format!("{}/{}", path, child.name);
+        let repeated = end > 0 && frame.children[end - 1].name == child.name;
+        let message = match self.components.max_occurs(particles, &child.name) {
+            Some(max) if repeated => {
+                let message = format!("{} occurs more than {} times", child.name, max);
+                return self.report(ERR_OCCURRENCES, child.line, child.column, path, message);
+            }
+            _ if expected.is_empty() => format!("{} is not expected here", child.name),
+            _ => format!("{} is not expected here, expected {}", child.name, expected),
+        };
+        self.report(ERR_UNEXPECTED_ELEMENT, child.line, child.column, path, message);
+    }
+}
+
+/// Validates a message against the components; see `SchemaSet::validate`.
+pub(crate) fn validate(components: &Components, xml: &str) -> Vec<SchemaViolation> {
+    let config = ParserConfig::new().whitespace_to_characters(true).cdata_to_characters(true).ignore_comments(true);
+    let mut reader = EventReader::new_with_config(xml.as_bytes(), config);
+    let mut validator = Validator { components, frames: Vec::new(), path: Vec::new(), violations: Vec::new(), validated: false };
+    let mut root = None;
+    loop {
+        let event = match reader.next() {
+            Ok(event) => event,
+            Err(error) => {
+                let position = error.position();
+                let path = validator.path();
+                validator.report(ERR_XML_SYNTAX, position.row + 1, position.column + 1, path, error.msg().to_string());
+                return validator.violations;
+            }
+        };
+        let position = reader.position();
+        let (line, column) = (position.row + 1, position.column + 1);
+        match event {
+            XmlEvent::StartElement { name, attributes, .. } => {
+                let name = QName::of(&name);
+                root.get_or_insert_with(|| name.clone());
+                validator.start(name, &attributes, line, column);
+            }
+            XmlEvent::EndElement { .. } => validator.end(),
+            XmlEvent::Characters(text) => validator.text(&text, line, column),
+            XmlEvent::EndDocument => break,
+            _ => {}
+        }
+    }
+    if !validator.validated {
+        if let Some(root) = root {
+            let message = if root.namespace.is_empty() {
+                format!("no schema declares {} or an element in it", root)
+            } else {
+                format!("no schema declares {} in namespace {} or an element in it", root, root.namespace)
+            };
+            validator.report(ERR_NO_SCHEMA, 1, 1, format!("/{}", root), message);
+        }
+    }
+    validator.violations
+}
@@ -0,0 +1,243 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+// Integrity checks for account reports and statements (camt.052, camt.053)
+// and notifications (camt.054).
+//
+// The messages are reduced to `CheckedStatement` records by the conversions
+// next to the messages. The checks report every finding instead of stopping
+// at the first one:
+//
+// - the opening booked balance (OPBD, or PRCD) plus the booked credits
+//   minus the booked debits equals the closing booked balance (CLBD);
+// - `TxsSummry` agrees with the booked entries;
+// - per account, consecutive statements carry consecutive electronic and
+//   legal sequence numbers and the closing booked balance of one is the
+//   opening booked balance of the next.
+
+use crate::swift::fields::currency_decimals;
+use crate::ValidationError;
+
+pub const ERR_BALANCE_MISMATCH: u32 = 2501;
+pub const ERR_SUMMARY_MISMATCH: u32 = 2502;
+pub const ERR_SEQUENCE_GAP: u32 = 2503;
+pub const ERR_SEQUENCE_ORDER: u32 = 2504;
+pub const ERR_BALANCE_CONTINUITY: u32 = 2505;
+pub const ERR_MISSING_BALANCE: u32 = 2506;
+
+/// Balance type codes of the opening and closing booked balances.
+pub const OPENING_BOOKED: &[&str] = &["OPBD", "PRCD"];
+pub const CLOSING_BOOKED: &str = "CLBD";
+
+/// Number and sum of entries declared in `TxsSummry`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DeclaredTotals {
+    pub number_of_entries: Option<u64>,
+    pub sum: Option<f64>,
+    /// `TtlNetNtry`, negative for a net debit.
+    pub net_amount: Option<f64>,
+}
+
+/// A statement, report or notification as seen by the checks. Balances are
+/// signed, negative for debit balances.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CheckedStatement {
+    pub id: String,
+    pub account: Option<String>,
+    pub currency: String,
+    pub electronic_sequence_number: Option<u64>,
+    pub legal_sequence_number: Option<u64>,
+    /// Page number and last page indicator of a paginated statement.
+    pub page: Option<(u64, bool)>,
+    pub opening_booked: Option<f64>,
+    pub closing_booked: Option<f64>,
+    /// Amounts of the booked credit entries.
+    pub credits: Vec<f64>,
+    /// Amounts of the booked debit entries.
+    pub debits: Vec<f64>,
+    pub total_entries: Option<DeclaredTotals>,
+    pub total_credit_entries: Option<DeclaredTotals>,
+    pub total_debit_entries: Option<DeclaredTotals>,
+    /// Statements (camt.053) must carry both booked balances, reports and
+    /// notifications need not.
+    pub balances_required: bool,
+}
+
+impl CheckedStatement {
+    fn minor_units(&self, amount: f64) -> i64 {
+        (amount * 10f64.powi(currency_decimals(&self.currency) as i32)).round() as i64
+    }
+
+    fn format(&self, units: i64) -> String {
+        let decimals = currency_decimals(&self.currency);
+        format!("{:.*}", decimals, units as f64 / 10f64.powi(decimals as i32))
+    }
+
+    fn is_single_page(&self) -> bool {
+        matches!(self.page, None | Some((1, true)))
+    }
+}
+
+/// Converts a sequence number read as a decimal into an integer.
+pub fn sequence_number(value: Option<f64>) -> Option<u64> {
+    value.filter(|v| *v >= 0.0 && v.fract() == 0.0).map(|v| v as u64)
+}
+
+/// Opening booked plus credits minus debits against the closing booked balance.
+pub fn check_balances(statement: &CheckedStatement) -> Vec<ValidationError> {
+    let mut findings = Vec::new();
+    let (opening, closing) = match (statement.opening_booked, statement.closing_booked) {
+        (Some(opening), Some(closing)) => (opening, closing),
+        (opening, _) => {
+            // Pages of a paginated statement may carry only one of the balances.
+            if statement.balances_required && statement.is_single_page() {
+                let missing = if opening.is_none() { "opening booked balance (OPBD or PRCD)" } else { "closing booked balance (CLBD)" };
+                findings.push(ValidationError::new(ERR_MISSING_BALANCE, format!("{}: no {}", statement.id, missing)));
+            }
+            return findings;
+        }
+    };
+    if !statement.is_single_page() {
+        return findings;
+    }
+    let credits: i64 = statement.credits.iter().map(|a| statement.minor_units(*a)).sum();
+    let debits: i64 = statement.debits.iter().map(|a| statement.minor_units(*a)).sum();
+    let computed = statement.minor_units(opening) + credits - debits;
+    if computed != statement.minor_units(closing) {
+        findings.push(ValidationError::new(
+            ERR_BALANCE_MISMATCH,
+            format!(
+                "{}: opening booked balance {} plus credits {} minus debits {} is {}, but the closing booked balance is {}",
+                statement.id,
+                statement.format(statement.minor_units(opening)),
+                statement.format(credits),
+                statement.format(debits),
+                statement.format(computed),
+                statement.format(statement.minor_units(closing)),
+            ),
+        ));
+    }
+    findings
+}
+
+fn check_totals(statement: &CheckedStatement, element: &str, declared: &DeclaredTotals, amounts: &[f64], findings: &mut Vec<ValidationError>) {
+    if let Some(number) = declared.number_of_entries.filter(|n| *n != amounts.len() as u64) {
+        findings.push(ValidationError::new(ERR_SUMMARY_MISMATCH, format!("{}: {}/NbOfNtries is {} but there are {} booked entries", statement.id, element, number, amounts.len())));
+    }
+    if let Some(sum) = declared.sum {
+        let computed: i64 = amounts.iter().map(|a| statement.minor_units(*a)).sum();
+        if statement.minor_units(sum) != computed {
+            findings.push(ValidationError::new(
+                ERR_SUMMARY_MISMATCH,
+                format!("{}: {}/Sum is {} but the booked entries add up to {}", statement.id, element, statement.format(statement.minor_units(sum)), statement.format(computed)),
+            ));
+        }
+    }
+}
+
+/// `TxsSummry` totals against the booked entries.
+pub fn check_summary(statement: &CheckedStatement) -> Vec<ValidationError> {
+    let mut findings = Vec::new();
+    let all: Vec<f64> = statement.credits.iter().chain(&statement.debits).cloned().collect();
+    if let Some(ref totals) = statement.total_entries {
+        check_totals(statement, "TxsSummry/TtlNtries", totals, &all, &mut findings);
+        if let Some(net) = totals.net_amount {
+            let computed: i64 = statement.credits.iter().map(|a| statement.minor_units(*a)).sum::<i64>() - statement.debits.iter().map(|a| statement.minor_units(*a)).sum::<i64>();
+            if statement.minor_units(net) != computed {
+                findings.push(ValidationError::new(
+                    ERR_SUMMARY_MISMATCH,
+                    format!("{}: TxsSummry/TtlNtries/TtlNetNtry is {} but the booked entries net to {}", statement.id, statement.format(statement.minor_units(net)), statement.format(computed)),
+                ));
+            }
+        }
+    }
+    if let Some(ref totals) = statement.total_credit_entries {
+        check_totals(statement, "TxsSummry/TtlCdtNtries", totals, &statement.credits, &mut findings);
+    }
+    if let Some(ref totals) = statement.total_debit_entries {
+        check_totals(statement, "TxsSummry/TtlDbtNtries", totals, &statement.debits, &mut findings);
+    }
+    findings
+}
+
+/// Whether `current` is the page following `previous` of the same statement.
+fn is_next_page(previous: &CheckedStatement, current: &CheckedStatement) -> bool {
+    previous.electronic_sequence_number == current.electronic_sequence_number
+        && previous.legal_sequence_number == current.legal_sequence_number
+        && matches!((previous.page, current.page), (Some((a, false)), Some((b, _))) if b == a + 1)
+}
+
+fn check_numbers(previous: &CheckedStatement, current: &CheckedStatement, element: &str, number: fn(&CheckedStatement) -> Option<u64>, findings: &mut Vec<ValidationError>) {
+    let (Some(before), Some(after)) = (number(previous), number(current)) else { return };
+    if after <= before {
+        findings.push(ValidationError::new(ERR_SEQUENCE_ORDER, format!("{}: {} {} does not follow {} of {}", current.id, element, after, before, previous.id)));
+    } else if after > before + 1 {
+        let missing = if after == before + 2 { format!("{}", before + 1) } else { format!("{} to {}", before + 1, after - 1) };
+        findings.push(ValidationError::new(ERR_SEQUENCE_GAP, format!("{}: {} {} missing between {} and {}", current.id, element, missing, previous.id, current.id)));
+    }
+}
+
+/// Sequence numbers and balance continuity of consecutive statements of
+/// the same account and currency, in the order given.
+pub fn check_sequence(statements: &[CheckedStatement]) -> Vec<ValidationError> {
+    let mut findings = Vec::new();
+    let mut last: Vec<&CheckedStatement> = Vec::new();
+    for statement in statements {
+        let previous = last.iter_mut().find(|p| p.account == statement.account && p.currency == statement.currency);
+        match previous {
+            Some(previous) if is_next_page(previous, statement) => *previous = statement,
+            Some(previous) => {
+                check_numbers(previous, statement, "ElctrncSeqNb", |s| s.electronic_sequence_number, &mut findings);
+                check_numbers(previous, statement, "LglSeqNb", |s| s.legal_sequence_number, &mut findings);
+                if let (Some(closing), Some(opening)) = (previous.closing_booked, statement.opening_booked) {
+                    if previous.minor_units(closing) != statement.minor_units(opening) {
+                        findings.push(ValidationError::new(
+                            ERR_BALANCE_CONTINUITY,
+                            format!(
+                                "{}: opening booked balance {} differs from the closing booked balance {} of {}",
+                                statement.id,
+                                statement.format(statement.minor_units(opening)),
+                                previous.format(previous.minor_units(closing)),
+                                previous.id
+                            ),
+                        ));
+                    }
+                }
+                *previous = statement;
+            }
+            None => last.push(statement),
+        }
+    }
+    findings
+}
+
+/// All checks: balances and summary of every statement, then the sequence.
+pub fn check_statements(statements: &[CheckedStatement]) -> Vec<ValidationError> {
+    let mut findings = Vec::new();
+    for statement in statements {
+        findings.extend(check_balances(statement));
+        findings.extend(check_summary(statement));
+    }
+    findings.extend(check_sequence(statements));
+    findings
+}
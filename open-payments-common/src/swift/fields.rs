@@ -0,0 +1,362 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Typed views over the generic FIN field formats shared by several MTs.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+use crate::datetime::{format_iso_date, parse_iso_date};
+use crate::swift::{Field, ERR_FIELD_OPTION, ERR_FIELD_SYNTAX};
+use crate::ValidationError;
+
+static BIC: LazyLock<Regex> = LazyLock::new(|| Regex::new("^[A-Z0-9]{4}[A-Z]{2}[A-Z0-9]{2}([A-Z0-9]{3})?$").unwrap());
+
+fn syntax_error(field: &Field, what: &str) -> ValidationError {
+    ValidationError::new(ERR_FIELD_SYNTAX, format!("field {}: {} in {:?}", field.tag, what, field.value))
+}
+
+/// Checks the option letter of a field against the options allowed by the
+/// message definition (`None` stands for the letterless variant, e.g. `59`).
+pub fn check_option(field: &Field, allowed: &[Option<char>]) -> Result<(), ValidationError> {
+    if allowed.contains(&field.option()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new(ERR_FIELD_OPTION, format!("option {} is not allowed for field {}", field.tag, field.number())))
+    }
+}
+
+/// Converts a `YYMMDD` date into an ISODate.
+pub fn parse_date6(value: &str) -> Option<String> {
+    if value.len() != 6 || !value.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let yy = value[0..2].parse::<i64>().ok()?;
+    let year = if yy < 80 { 2000 + yy } else { 1900 + yy };
+    let date = format_iso_date(year, value[2..4].parse().ok()?, value[4..6].parse().ok()?);
+    parse_iso_date(&date).map(|_| date)
+}
+
+/// Converts an ISODate into `YYMMDD`.
+pub fn format_date6(date: &str) -> Option<String> {
+    let (year, month, day) = parse_iso_date(date)?;
+    Some(format!("{:02}{:02}{:02}", year % 100, month, day))
+}
+
+/// Parses a SWIFT amount (`1234,56`, decimal comma mandatory).
+pub fn parse_amount(value: &str) -> Option<f64> {
+    if value.is_empty() || !value.contains(',') || value.len() > 15 || !value.chars().all(|c| c.is_ascii_digit() || c == ',') {
+        return None;
+    }
+    value.replacen(',', ".", 1).parse::<f64>().ok()
+}
+
+/// Number of decimals used when writing an amount in the given currency.
+pub fn currency_decimals(currency: &str) -> usize {
+    match currency {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+/// Formats an amount the SWIFT way: decimal comma, no thousands separator and
+/// no trailing zero decimals beyond the comma (`1000,`, `12,5`).
+pub fn format_amount(amount: f64, currency: &str) -> String {
+    let formatted = format!("{:.*}", currency_decimals(currency), amount);
+    let formatted = if formatted.contains('.') { formatted.trim_end_matches('0').to_string() } else { format!("{}.", formatted) };
+    formatted.replace('.', ",")
+}
+
+/// Formats an exchange rate (`12d`, field 36) at full precision: decimal
+/// comma and at most 12 characters, rounded only when the rate has more
+/// digits than fit (`0,9123456789`, `1,5`, `2,`).
+pub fn format_rate(rate: f64) -> String {
+    let shortest = rate.to_string();
+    let formatted = if shortest.len() <= 12 {
+        shortest
+    } else {
+        let whole = format!("{:.0}", rate.trunc()).len();
+        format!("{:.*}", 11usize.saturating_sub(whole), rate)
+    };
+    let formatted = if formatted.contains('.') { formatted.trim_end_matches('0').to_string() } else { format!("{}.", formatted) };
+    formatted.replace('.', ",")
+}
+
+/// `3!a15d`, e.g. field 33B, 71F, 71G.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CurrencyAmount {
+    pub currency: String,
+    pub amount: f64,
+}
+
+impl CurrencyAmount {
+    pub fn parse(field: &Field) -> Result<Self, ValidationError> {
+        let value = field.value.as_str();
+        if value.len() < 5 || !value.is_char_boundary(3) || !value[0..3].chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(syntax_error(field, "invalid currency"));
+        }
+        let amount = parse_amount(&value[3..]).ok_or_else(|| syntax_error(field, "invalid amount"))?;
+        Ok(CurrencyAmount { currency: value[0..3].to_string(), amount })
+    }
+
+    pub fn to_field_value(&self) -> String {
+        format!("{}{}", self.currency, format_amount(self.amount, &self.currency))
+    }
+}
+
+/// `6!n3!a15d`, field 32A.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DateCurrencyAmount {
+    pub date: String,
+    pub currency: String,
+    pub amount: f64,
+}
+
+impl DateCurrencyAmount {
+    pub fn parse(field: &Field) -> Result<Self, ValidationError> {
+        let value = field.value.as_str();
+        if value.len() < 11 || !value.is_ascii() {
+            return Err(syntax_error(field, "value too short"));
+        }
+        let date = parse_date6(&value[0..6]).ok_or_else(|| syntax_error(field, "invalid date"))?;
+        let rest = CurrencyAmount::parse(&Field::new(&field.tag, &value[6..]))?;
+        Ok(DateCurrencyAmount { date, currency: rest.currency, amount: rest.amount })
+    }
+
+    pub fn to_field_value(&self) -> String {
+        format!(
+            "{}{}{}",
+            format_date6(&self.date).unwrap_or_default(),
+            self.currency,
+            format_amount(self.amount, &self.currency)
+        )
+    }
+}
+
+/// `4!c[/30x]`, e.g. field 23E.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CodeWithInformation {
+    pub code: String,
+    pub information: Option<String>,
+}
+
+impl CodeWithInformation {
+    pub fn parse(field: &Field) -> Result<Self, ValidationError> {
+        let (code, information) = match field.value.split_once('/') {
+            Some((code, info)) => (code, Some(info.to_string())),
+            None => (field.value.as_str(), None),
+        };
+        if code.len() != 4 || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(syntax_error(field, "invalid code"));
+        }
+        Ok(CodeWithInformation { code: code.to_string(), information })
+    }
+
+    pub fn to_field_value(&self) -> String {
+        match &self.information {
+            Some(info) => format!("{}/{}", self.code, info),
+            None => self.code.clone(),
+        }
+    }
+}
+
+/// Party and institution fields (50a to 59a). All options share this shape:
+/// an optional party identifier line followed by either an identifier code
+/// (option A), a location (option B) or name and address lines.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PartyField {
+    pub option: Option<char>,
+    pub party_identifier: Option<String>,
+    pub identifier_code: Option<String>,
+    pub location: Option<String>,
+    pub name_and_address: Vec<String>,
+}
+
+pub fn is_bic(value: &str) -> bool {
+    BIC.is_match(value)
+}
+
+impl PartyField {
+    pub fn parse(field: &Field) -> Result<Self, ValidationError> {
+        let option = field.option();
+        let lines: Vec<&str> = field.lines();
+        let mut party = PartyField { option, ..PartyField::default() };
+        let mut rest = &lines[..];
+        match option {
+            Some('F') => {
+                // 50F/59F: "35x" party identifier then "1!n/33x" lines.
+                if field.number() == "50" || lines[0].starts_with('/') {
+                    party.party_identifier = Some(lines[0].to_string());
+                    rest = &lines[1..];
+                }
+                for line in rest {
+                    let bytes = line.as_bytes();
+                    if bytes.len() < 3 || !bytes[0].is_ascii_digit() || bytes[1] != b'/' {
+                        return Err(syntax_error(field, "structured line must be of the form n/text"));
+                    }
+                }
+                party.name_and_address = rest.iter().map(|l| l.to_string()).collect();
+            }
+            Some('C') => {
+                if !lines[0].starts_with('/') || lines.len() != 1 {
+                    return Err(syntax_error(field, "option C must contain a single /34x line"));
+                }
+                party.party_identifier = Some(lines[0].to_string());
+            }
+            _ => {
+                // The optional "[/1!a][/34x]" line. For field 50A/50K and
+                // 59A/59 the account is always written as "/34x".
+                if lines[0].starts_with('/') && (lines.len() > 1 || option.is_none()) {
+                    party.party_identifier = Some(lines[0].to_string());
+                    rest = &lines[1..];
+                }
+                match option {
+                    Some('A') => {
+                        let bic = rest.first().ok_or_else(|| syntax_error(field, "missing identifier code"))?;
+                        if !is_bic(bic) || rest.len() != 1 {
+                            return Err(syntax_error(field, "invalid identifier code"));
+                        }
+                        party.identifier_code = Some(bic.to_string());
+                    }
+                    Some('B') => party.location = rest.first().map(|l| l.to_string()),
+                    _ => party.name_and_address = rest.iter().map(|l| l.to_string()).collect(),
+                }
+            }
+        }
+        if party.party_identifier.is_none() && party.identifier_code.is_none() && party.location.is_none() && party.name_and_address.is_empty() {
+            return Err(syntax_error(field, "party field is empty"));
+        }
+        Ok(party)
+    }
+
+    /// The account from a `/34x` or `/1!a/34x` party identifier.
+    pub fn account(&self) -> Option<String> {
+        let id = self.party_identifier.as_deref()?.strip_prefix('/')?;
+        let id = match id.as_bytes() {
+            [c, b'/', ..] if c.is_ascii_uppercase() => &id[2..],
+            _ => id,
+        };
+        Some(id.to_string())
+    }
+
+    /// Debit/credit mark of a `/1!a/34x` party identifier (`/C/`, `/D/`).
+    pub fn account_mark(&self) -> Option<char> {
+        let id = self.party_identifier.as_deref()?.as_bytes();
+        match id {
+            [b'/', c, b'/', ..] if c.is_ascii_uppercase() => Some(*c as char),
+            _ => None,
+        }
+    }
+
+    /// Numbered lines of option F, e.g. `structured_lines(1)` gives the names.
+    pub fn structured_lines(&self, number: u8) -> Vec<String> {
+        let prefix = format!("{}/", number);
+        self.name_and_address.iter().filter_map(|l| l.strip_prefix(&prefix).map(|s| s.to_string())).collect()
+    }
+
+    /// Name for options without a structured layout (first line of K, D, 59).
+    pub fn name(&self) -> Option<String> {
+        if self.option == Some('F') {
+            let names = self.structured_lines(1);
+            return if names.is_empty() { None } else { Some(names.join(" ")) };
+        }
+        self.name_and_address.first().cloned()
+    }
+
+    /// Address lines for unstructured options.
+    pub fn address_lines(&self) -> Vec<String> {
+        if self.option == Some('F') {
+            return self.structured_lines(2);
+        }
+        self.name_and_address.iter().skip(1).cloned().collect()
+    }
+
+    /// Country and town from option F line 3 (`3/CC/Town`).
+    pub fn country_and_town(&self) -> Option<(String, Option<String>)> {
+        let line = self.structured_lines(3).into_iter().next()?;
+        let (country, town) = match line.split_once('/') {
+            Some((c, t)) => (c.to_string(), Some(t.to_string())),
+            None => (line, None),
+        };
+        Some((country, town))
+    }
+
+    pub fn with_identifier_code(bic: &str, account: Option<&str>) -> Self {
+        PartyField {
+            option: Some('A'),
+            party_identifier: account.map(|a| format!("/{}", a)),
+            identifier_code: Some(bic.to_string()),
+            ..PartyField::default()
+        }
+    }
+
+    pub fn with_name_and_address(option: Option<char>, account: Option<&str>, lines: Vec<String>) -> Self {
+        PartyField {
+            option,
+            party_identifier: account.map(|a| format!("/{}", a)),
+            name_and_address: lines,
+            ..PartyField::default()
+        }
+    }
+
+    /// Writes the party back as a field with the given number.
+    pub fn to_field(&self, number: &str) -> Field {
+        let mut lines: Vec<String> = Vec::new();
+        if let Some(ref id) = self.party_identifier {
+            lines.push(id.clone());
+        }
+        if let Some(ref bic) = self.identifier_code {
+            lines.push(bic.clone());
+        }
+        if let Some(ref location) = self.location {
+            lines.push(location.clone());
+        }
+        lines.extend(self.name_and_address.iter().cloned());
+        let tag = match self.option {
+            Some(c) => format!("{}{}", number, c),
+            None => number.to_string(),
+        };
+        Field { tag, value: lines.join("\n") }
+    }
+}
+
+/// Splits free text into lines of at most `width` characters, limited to
+/// `max_lines` lines, as required by the `n*35x` formats.
+pub fn wrap_text(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    chars
+        .chunks(width)
+        .take(max_lines)
+        .map(|c| c.iter().collect::<String>())
+        .filter(|l| !l.trim().is_empty())
+        .collect()
+}
+
+/// Extracts the value of a `/CODE/` entry from code-word fields such as 70, 72
+/// and 86 (e.g. `/ROC/` in field 70).
+pub fn code_word<'a>(lines: &'a [String], code: &str) -> Option<&'a str> {
+    let prefix = format!("/{}/", code);
+    lines.iter().find_map(|l| l.strip_prefix(prefix.as_str()))
+}
@@ -0,0 +1,424 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// Mapping between MT party fields and ISO 20022 parties, shared by the MT
+// translations of the `open-payments-iso20022` and `open-payments-fednow`
+// crates. The two crates generate different versions of the party types
+// (BranchAndFinancialInstitutionIdentification8 and 6, PartyIdentification272
+// and 135, ...), so the rules live here on the version neutral `Institution`,
+// `Customer` and `Address` and each crate only copies them into its types.
+
+use crate::datetime;
+use crate::swift::fields::{parse_date6, wrap_text, CurrencyAmount, PartyField};
+use crate::swift::{to_x_charset, ApplicationHeader, FinMessage};
+use crate::ValidationError;
+
+// Error code raised when a message cannot be expressed in the other format.
+pub const ERR_UNMAPPABLE: u32 = 2101;
+// Error code raised when a cover and its underlying transfer disagree.
+pub const ERR_COVER_MISMATCH: u32 = 2102;
+
+/// Postal address, either as address lines or structured.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Address {
+    pub lines: Vec<String>,
+    pub street_name: Option<String>,
+    pub building_number: Option<String>,
+    pub post_code: Option<String>,
+    pub town_name: Option<String>,
+    pub country: Option<String>,
+}
+
+impl Address {
+    /// Address lines for `4*35x` fields: the address lines when present,
+    /// otherwise street and town lines built from the structured address.
+    pub fn to_lines(&self) -> Vec<String> {
+        if !self.lines.is_empty() {
+            return self.lines.iter().map(|l| to_x_charset(l).chars().take(35).collect()).collect();
+        }
+        let street = join(&[self.street_name.as_deref(), self.building_number.as_deref()]);
+        let town = join(&[self.post_code.as_deref(), self.town_name.as_deref(), self.country.as_deref()]);
+        [street, town].into_iter().filter(|l| !l.is_empty()).map(|l| to_x_charset(&l).chars().take(35).collect()).collect()
+    }
+}
+
+fn join(parts: &[Option<&str>]) -> String {
+    parts.iter().flatten().cloned().collect::<Vec<_>>().join(" ")
+}
+
+/// Member of a clearing system, with the external clearing system code
+/// (`USABA` for a US routing number).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ClearingMember {
+    pub system: Option<String>,
+    pub member_id: String,
+}
+
+/// Financial institution of an institution field (52a to 58a).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Institution {
+    pub bic: Option<String>,
+    pub clearing_member: Option<ClearingMember>,
+    pub lei: Option<String>,
+    pub name: Option<String>,
+    pub address: Option<Address>,
+}
+
+impl Institution {
+    /// Financial institution identified by BIC.
+    pub fn with_bic(bic: &str) -> Self {
+        Institution { bic: Some(bic.to_string()), ..Default::default() }
+    }
+
+    /// Financial institution identified by US routing number.
+    pub fn with_routing_number(routing_number: &str) -> Self {
+        Institution {
+            clearing_member: Some(ClearingMember { system: Some("USABA".to_string()), member_id: routing_number.to_string() }),
+            ..Default::default()
+        }
+    }
+
+    /// Whether two institutions are the same: by BIC (first 8 characters)
+    /// when both have one, by clearing member otherwise, `None` when they
+    /// cannot be compared.
+    pub fn same_as(&self, other: &Institution) -> Option<bool> {
+        if let (Some(a), Some(b)) = (&self.bic, &other.bic) {
+            return Some(a.chars().take(8).eq(b.chars().take(8)));
+        }
+        match (&self.clearing_member, &other.clearing_member) {
+            (Some(a), Some(b)) => Some(a.member_id == b.member_id),
+            _ => None,
+        }
+    }
+}
+
+/// Generic organisation identification of a 50F party identifier.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OtherIdentification {
+    pub id: String,
+    pub scheme_code: Option<String>,
+    pub issuer: Option<String>,
+}
+
+/// Customer of a customer field (50a, 59a).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Customer {
+    pub name: Option<String>,
+    pub any_bic: Option<String>,
+    pub other_id: Option<OtherIdentification>,
+    pub address: Option<Address>,
+}
+
+/// Account identification of an MT party identifier.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccountId {
+    Iban(String),
+    Other(String),
+}
+
+impl AccountId {
+    /// IBANs go to `IBAN`, anything else to `Othr/Id`.
+    pub fn parse(account: &str) -> Self {
+        let is_iban = account.len() > 4
+            && account.len() <= 34
+            && account[0..2].chars().all(|c| c.is_ascii_uppercase())
+            && account[2..4].chars().all(|c| c.is_ascii_digit())
+            && account.chars().all(|c| c.is_ascii_alphanumeric());
+        if is_iban {
+            AccountId::Iban(account.to_string())
+        } else {
+            AccountId::Other(account.to_string())
+        }
+    }
+}
+
+/// Institution fields (52a to 58a): option A gives the BIC, option C/D
+/// the clearing code and name and address.
+pub fn party_to_institution(party: &PartyField) -> Institution {
+    let mut institution = Institution { bic: party.identifier_code.clone(), ..Default::default() };
+    // "//FW123456789" style national clearing codes.
+    if let Some(code) = party.party_identifier.as_deref().and_then(|id| id.strip_prefix("//")) {
+        let (system, member) = code.split_at(2.min(code.len()));
+        institution.clearing_member = Some(ClearingMember { system: Some(clearing_system_code(system)), member_id: member.to_string() });
+    }
+    if party.identifier_code.is_none() {
+        if let Some(name) = party.name() {
+            institution.name = Some(name);
+            let lines = party.address_lines();
+            if !lines.is_empty() {
+                institution.address = Some(Address { lines, ..Default::default() });
+            }
+        }
+        if let Some(ref location) = party.location {
+            institution.address = Some(Address { town_name: Some(location.clone()), ..Default::default() });
+        }
+    }
+    institution
+}
+
+/// Account of an institution field (`/34x` line), if present and not a clearing code.
+pub fn party_to_institution_account(party: &PartyField) -> Option<AccountId> {
+    if party.party_identifier.as_deref().is_some_and(|id| id.starts_with("//")) {
+        return None;
+    }
+    party.account().map(|a| AccountId::parse(&a))
+}
+
+/// Customer fields (50a, 59a).
+pub fn party_to_customer(party: &PartyField) -> Customer {
+    let mut customer = Customer { name: party.name(), any_bic: party.identifier_code.clone(), ..Default::default() };
+    let lines = party.address_lines();
+    let country_town = party.country_and_town();
+    if !lines.is_empty() || country_town.is_some() {
+        let mut address = Address { lines, ..Default::default() };
+        if let Some((country, town)) = country_town {
+            address.country = Some(country);
+            address.town_name = town;
+        }
+        customer.address = Some(address);
+    }
+    // 50F party identifier in the "CODE/CC/Identifier" form.
+    if party.option == Some('F') {
+        if let Some(id) = party.party_identifier.as_deref().filter(|id| !id.starts_with('/')) {
+            let mut parts = id.splitn(3, '/');
+            let (code, country, value) = (parts.next(), parts.next(), parts.next());
+            if let (Some(code), Some(value)) = (code, value) {
+                customer.any_bic = None;
+                customer.other_id = Some(OtherIdentification {
+                    id: value.to_string(),
+                    scheme_code: Some(code.to_string()),
+                    issuer: country.map(|c| c.to_string()),
+                });
+            }
+        }
+    }
+    customer
+}
+
+/// Account of a customer field.
+pub fn party_to_customer_account(party: &PartyField) -> Option<AccountId> {
+    if party.option == Some('F') && party.party_identifier.as_deref().is_some_and(|id| !id.starts_with('/')) {
+        return None;
+    }
+    party.account().map(|a| AccountId::parse(&a))
+}
+
+/// Institution field for an institution: option A when a BIC is known,
+/// option D with the clearing code and name and address otherwise.
+pub fn institution_to_party(institution: &Institution, account: Option<&str>) -> PartyField {
+    if let Some(ref bic) = institution.bic {
+        return PartyField::with_identifier_code(bic, account);
+    }
+    let clearing = institution.clearing_member.as_ref().map(|m| format!("/{}{}", clearing_system_prefix(m.system.as_deref().unwrap_or_default()), m.member_id));
+    let mut lines: Vec<String> = Vec::new();
+    if let Some(ref name) = institution.name {
+        lines.extend(wrap_text(&to_x_charset(name), 35, 2));
+    }
+    if let Some(ref address) = institution.address {
+        lines.extend(address.to_lines());
+    }
+    lines.truncate(4);
+    PartyField {
+        option: Some('D'),
+        party_identifier: clearing.or(account.map(|a| format!("/{}", a))),
+        name_and_address: lines,
+        ..Default::default()
+    }
+}
+
+/// Customer field for a customer: option A for a party identified by AnyBIC
+/// only, option F when a structured address is available and the
+/// letterless/K form with name and address lines otherwise.
+pub fn customer_to_party(customer: &Customer, account: Option<&str>, letterless_option: Option<char>) -> PartyField {
+    if let (Some(bic), None) = (customer.any_bic.as_deref(), customer.name.as_ref()) {
+        return PartyField::with_identifier_code(bic, account);
+    }
+    let name = customer.name.as_deref().map(to_x_charset).unwrap_or_else(|| "NOTPROVIDED".to_string());
+    if let Some(ref address) = customer.address {
+        if address.lines.is_empty() && address.country.is_some() {
+            let mut lines: Vec<String> = wrap_text(&name, 33, 2).into_iter().map(|l| format!("1/{}", l)).collect();
+            let street = join(&[address.street_name.as_deref(), address.building_number.as_deref()]);
+            if !street.is_empty() {
+                lines.push(format!("2/{}", to_x_charset(&street).chars().take(33).collect::<String>()));
+            }
+            let town = join(&[address.post_code.as_deref(), address.town_name.as_deref()]);
+            let country = address.country.clone().unwrap_or_default();
+            lines.push(format!("3/{}/{}", country, to_x_charset(&town)).chars().take(35).collect());
+            return PartyField {
+                option: Some('F'),
+                party_identifier: Some(format!("/{}", account.unwrap_or("NOTPROVIDED"))),
+                name_and_address: lines,
+                ..Default::default()
+            };
+        }
+    }
+    let mut lines = wrap_text(&name, 35, 1);
+    if let Some(ref address) = customer.address {
+        lines.extend(address.to_lines());
+    }
+    lines.truncate(4);
+    PartyField::with_name_and_address(letterless_option, account, lines)
+}
+
+/// Maps the two letter MT clearing system prefix onto the external clearing
+/// system identification code.
+pub fn clearing_system_code(prefix: &str) -> String {
+    match prefix {
+        "FW" => "USABA",
+        "CH" => "USPID",
+        "BL" => "DEBLZ",
+        "SC" => "GBDSC",
+        "CC" => "CACPA",
+        "AT" => "ATBLZ",
+        "AU" => "AUBSB",
+        "ES" => "ESNCC",
+        "IT" => "ITNCC",
+        "CP" => "USPID",
+        other => other,
+    }
+    .to_string()
+}
+
+/// Inverse of `clearing_system_code`.
+pub fn clearing_system_prefix(code: &str) -> String {
+    match code {
+        "USABA" => "FW",
+        "USPID" => "CH",
+        "DEBLZ" => "BL",
+        "GBDSC" => "SC",
+        "CACPA" => "CC",
+        "ATBLZ" => "AT",
+        "AUBSB" => "AU",
+        "ESNCC" => "ES",
+        "ITNCC" => "IT",
+        other => other,
+    }
+    .to_string()
+}
+
+/// Creation date and time of a FIN message: the output date and time for
+/// received messages, the current time otherwise.
+pub fn creation_date_time(message: &FinMessage) -> String {
+    if let ApplicationHeader::Output { output_date, output_time, .. } = &message.application_header {
+        if let Some(date) = parse_date6(output_date) {
+            if output_time.len() == 4 {
+                return format!("{}T{}:{}:00", date, &output_time[0..2], &output_time[2..4]);
+            }
+        }
+    }
+    datetime::now_iso_datetime()
+}
+
+/// Whether the PDE/PDM trailers mark the message as a possible duplicate.
+pub fn possible_duplicate(message: &FinMessage) -> bool {
+    message.trailer.iter().any(|t| t.name == "PDE" || t.name == "PDM")
+}
+
+/// Free text for `n*35x` fields.
+pub fn text_lines(text: &str, max_lines: usize) -> Vec<String> {
+    wrap_text(&to_x_charset(text), 35, max_lines)
+}
+
+/// Sender to receiver information (field 72, `6*35x`) of the instructions
+/// for the next agent.
+pub fn instruction_lines<'a>(instructions: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    instructions.into_iter().flat_map(|i| text_lines(i, 6)).take(6).collect()
+}
+
+/// Unstructured remittance information (`140x`) of the field 70 lines.
+pub fn remittance_text(lines: &[String]) -> Option<String> {
+    let text = lines.join("");
+    if text.is_empty() { None } else { Some(text.chars().take(140).collect()) }
+}
+
+/// Field 70 lines (`4*35x`) of unstructured remittance information.
+pub fn remittance_lines(unstructured: &[String]) -> Vec<String> {
+    text_lines(&unstructured.join(" "), 4)
+}
+
+/// A cover (pacs.009 COV transaction) and its underlying customer credit
+/// transfer, as far as `check_cover` compares them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Cover {
+    pub debtor: Institution,
+    pub creditor: Institution,
+    pub amount: CurrencyAmount,
+    pub underlying_debtor_agent: Institution,
+    pub underlying_intermediary: Option<Institution>,
+    pub underlying_creditor_agent: Institution,
+    pub underlying_instructed_amount: Option<CurrencyAmount>,
+    /// Whether the underlying debtor has a name or an identifier.
+    pub underlying_debtor_identified: bool,
+    /// Whether the underlying creditor has a name or an identifier.
+    pub underlying_creditor_identified: bool,
+}
+
+/// Consistency between a cover and its underlying customer credit transfer:
+///
+/// - the cover creditor (beneficiary institution) must be the underlying
+///   creditor agent or the agent before it in the chain,
+/// - the cover debtor (ordering institution) must be the underlying debtor
+///   agent,
+/// - the underlying instructed amount must be in the settlement currency
+///   and must not be smaller than the cover amount.
+///
+/// Agents are compared by BIC or clearing member, see `Institution::same_as`;
+/// agents that cannot be compared are skipped.
+pub fn check_cover(cover: &Cover) -> Result<(), ValidationError> {
+    let mismatch = |message: &str| Err(ValidationError::new(ERR_COVER_MISMATCH, message.to_string()));
+    let creditor_matches = cover.creditor.same_as(&cover.underlying_creditor_agent);
+    let intermediary_matches = cover.underlying_intermediary.as_ref().and_then(|agent| cover.creditor.same_as(agent));
+    if creditor_matches == Some(false) && intermediary_matches != Some(true) {
+        return mismatch("cover creditor does not match the creditor agent of the underlying customer credit transfer");
+    }
+    if cover.debtor.same_as(&cover.underlying_debtor_agent) == Some(false) {
+        return mismatch("cover debtor does not match the debtor agent of the underlying customer credit transfer");
+    }
+    if let Some(ref instructed) = cover.underlying_instructed_amount {
+        if instructed.currency == cover.amount.currency && instructed.amount + 0.005 < cover.amount.amount {
+            return mismatch("cover amount exceeds the instructed amount of the underlying customer credit transfer");
+        }
+    }
+    if !cover.underlying_debtor_identified {
+        return mismatch("underlying debtor must be identified by name or identifier");
+    }
+    if !cover.underlying_creditor_identified {
+        return mismatch("underlying creditor must be identified by name or identifier");
+    }
+    Ok(())
+}
+
+/// References are `16x` and must not start or end with `/` or contain `//`.
+pub fn mt_reference(value: &str) -> String {
+    let mut collapsed = String::new();
+    for c in to_x_charset(value).chars() {
+        if c != '/' || !collapsed.ends_with('/') {
+            collapsed.push(c);
+        }
+    }
+    let cleaned: String = collapsed.trim_matches('/').chars().take(16).collect();
+    let cleaned = cleaned.trim_end_matches('/').to_string();
+    if cleaned.is_empty() { "NONREF".to_string() } else { cleaned }
+}
@@ -0,0 +1,579 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// SWIFT FIN (MT) message support.
+//
+// `FinMessage` splits a FIN message into its five blocks and keeps the text
+// block as an ordered list of raw fields. The typed messages (`mt103`, ...)
+// are built on top of it. The ISO 20022 translations live next to the
+// generated message types in the `open-payments-iso20022` and
+// `open-payments-fednow` crates, on top of the party mapping in `mapping`.
+
+pub mod fields;
+pub mod mapping;
+pub mod mt103;
+pub mod mt202;
+pub mod mt940;
+
+use crate::ValidationError;
+
+// Error codes raised while reading FIN messages.
+pub const ERR_BLOCK_STRUCTURE: u32 = 2001;
+pub const ERR_HEADER: u32 = 2002;
+pub const ERR_FIELD_SYNTAX: u32 = 2003;
+pub const ERR_CHARACTER_SET: u32 = 2004;
+pub const ERR_LINE_FORMAT: u32 = 2005;
+pub const ERR_MISSING_FIELD: u32 = 2006;
+pub const ERR_FIELD_OPTION: u32 = 2007;
+pub const ERR_MESSAGE_TYPE: u32 = 2008;
+
+/// Block 1: basic header, e.g. `F01BANKBEBBAXXX0000000000`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BasicHeader {
+    pub app_id: String,
+    pub service_id: String,
+    pub lt_address: String,
+    pub session_number: String,
+    pub sequence_number: String,
+}
+
+impl BasicHeader {
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        if value.len() != 25 || !value.is_ascii() {
+            return Err(ValidationError::new(ERR_HEADER, format!("basic header must be 25 characters, found {:?}", value)));
+        }
+        Ok(BasicHeader {
+            app_id: value[0..1].to_string(),
+            service_id: value[1..3].to_string(),
+            lt_address: value[3..15].to_string(),
+            session_number: value[15..19].to_string(),
+            sequence_number: value[19..25].to_string(),
+        })
+    }
+
+    pub fn to_fin(&self) -> String {
+        format!("{}{}{}{}{}", self.app_id, self.service_id, self.lt_address, self.session_number, self.sequence_number)
+    }
+}
+
+/// Block 2: application header, either the input (sent) or output (received) form.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApplicationHeader {
+    Input {
+        message_type: String,
+        receiver_address: String,
+        priority: Option<String>,
+        delivery_monitoring: Option<String>,
+        obsolescence_period: Option<String>,
+    },
+    Output {
+        message_type: String,
+        input_time: String,
+        mir: String,
+        output_date: String,
+        output_time: String,
+        priority: Option<String>,
+    },
+}
+
+impl Default for ApplicationHeader {
+    fn default() -> Self {
+        ApplicationHeader::Input {
+            message_type: String::new(),
+            receiver_address: String::new(),
+            priority: None,
+            delivery_monitoring: None,
+            obsolescence_period: None,
+        }
+    }
+}
+
+impl ApplicationHeader {
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        if !value.is_ascii() || value.len() < 4 {
+            return Err(ValidationError::new(ERR_HEADER, format!("invalid application header {:?}", value)));
+        }
+        let opt = |from: usize, to: usize| value.get(from..to.min(value.len())).filter(|s| !s.is_empty()).map(|s| s.to_string());
+        match &value[0..1] {
+            "I" => {
+                if value.len() < 16 {
+                    return Err(ValidationError::new(ERR_HEADER, format!("input application header too short: {:?}", value)));
+                }
+                Ok(ApplicationHeader::Input {
+                    message_type: value[1..4].to_string(),
+                    receiver_address: value[4..16].to_string(),
+                    priority: opt(16, 17),
+                    delivery_monitoring: opt(17, 18),
+                    obsolescence_period: opt(18, 21),
+                })
+            }
+            "O" => {
+                if value.len() < 46 {
+                    return Err(ValidationError::new(ERR_HEADER, format!("output application header too short: {:?}", value)));
+                }
+                Ok(ApplicationHeader::Output {
+                    message_type: value[1..4].to_string(),
+                    input_time: value[4..8].to_string(),
+                    mir: value[8..36].to_string(),
+                    output_date: value[36..42].to_string(),
+                    output_time: value[42..46].to_string(),
+                    priority: opt(46, 47),
+                })
+            }
+            _ => Err(ValidationError::new(ERR_HEADER, format!("application header must start with I or O: {:?}", value))),
+        }
+    }
+
+    pub fn to_fin(&self) -> String {
+        match self {
+            ApplicationHeader::Input { message_type, receiver_address, priority, delivery_monitoring, obsolescence_period } => format!(
+                "I{}{}{}{}{}",
+                message_type,
+                receiver_address,
+                priority.as_deref().unwrap_or(""),
+                delivery_monitoring.as_deref().unwrap_or(""),
+                obsolescence_period.as_deref().unwrap_or("")
+            ),
+            ApplicationHeader::Output { message_type, input_time, mir, output_date, output_time, priority } => format!(
+                "O{}{}{}{}{}{}",
+                message_type,
+                input_time,
+                mir,
+                output_date,
+                output_time,
+                priority.as_deref().unwrap_or("")
+            ),
+        }
+    }
+
+    pub fn message_type(&self) -> &str {
+        match self {
+            ApplicationHeader::Input { message_type, .. } => message_type,
+            ApplicationHeader::Output { message_type, .. } => message_type,
+        }
+    }
+}
+
+/// A `{tag:value}` entry of the user header (block 3) or trailer (block 5).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tag {
+    pub name: String,
+    pub value: String,
+}
+
+/// A field of the text block, e.g. tag `50K` with its (multi-line) value.
+/// Lines are separated by `\n` regardless of the line ending used on the wire.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Field {
+    pub tag: String,
+    pub value: String,
+}
+
+impl Field {
+    pub fn new(tag: &str, value: &str) -> Self {
+        Field { tag: tag.to_string(), value: value.to_string() }
+    }
+
+    /// Field number without the option letter (`50K` -> `50`).
+    pub fn number(&self) -> &str {
+        self.tag.trim_end_matches(|c: char| c.is_ascii_alphabetic())
+    }
+
+    /// Option letter, if any (`50K` -> `Some('K')`).
+    pub fn option(&self) -> Option<char> {
+        self.tag.chars().last().filter(|c| c.is_ascii_alphabetic())
+    }
+
+    pub fn matches(&self, tag: &str) -> bool {
+        if tag.ends_with(|c: char| c.is_ascii_alphabetic()) {
+            self.tag == tag
+        } else {
+            self.number() == tag
+        }
+    }
+
+    pub fn lines(&self) -> Vec<&str> {
+        self.value.split('\n').collect()
+    }
+}
+
+/// A complete FIN message.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FinMessage {
+    pub basic_header: BasicHeader,
+    pub application_header: ApplicationHeader,
+    pub user_header: Vec<Tag>,
+    pub text: Vec<Field>,
+    pub trailer: Vec<Tag>,
+}
+
+impl FinMessage {
+    /// An input (sent) message from `sender_bic` to `receiver_bic` with
+    /// normal priority and empty session and sequence numbers.
+    pub fn new_input(message_type: &str, sender_bic: &str, receiver_bic: &str, text: Vec<Field>) -> Self {
+        FinMessage {
+            basic_header: BasicHeader {
+                app_id: "F".to_string(),
+                service_id: "01".to_string(),
+                lt_address: bic_to_lt(sender_bic),
+                session_number: "0000".to_string(),
+                sequence_number: "000000".to_string(),
+            },
+            application_header: ApplicationHeader::Input {
+                message_type: message_type.to_string(),
+                receiver_address: bic_to_lt(receiver_bic),
+                priority: Some("N".to_string()),
+                delivery_monitoring: None,
+                obsolescence_period: None,
+            },
+            user_header: Vec::new(),
+            text,
+            trailer: Vec::new(),
+        }
+    }
+
+    /// Sets (or replaces) a block 3 tag, keeping the tags in insertion order.
+    pub fn set_user_header_tag(&mut self, name: &str, value: &str) {
+        match self.user_header.iter_mut().find(|t| t.name == name) {
+            Some(tag) => tag.value = value.to_string(),
+            None => self.user_header.push(Tag { name: name.to_string(), value: value.to_string() }),
+        }
+    }
+
+    /// Parses a FIN message. Blocks 1, 2 and 4 are mandatory, every text
+    /// block field is checked against the SWIFT X character set and the
+    /// line rules of its format.
+    pub fn parse(input: &str) -> Result<Self, ValidationError> {
+        let mut message = FinMessage::default();
+        let mut seen = [false; 6];
+        for (id, content) in split_blocks(input.trim())? {
+            let index = match id.as_str() {
+                "1" => 1,
+                "2" => 2,
+                "3" => 3,
+                "4" => 4,
+                "5" => 5,
+                _ => return Err(ValidationError::new(ERR_BLOCK_STRUCTURE, format!("unknown block {{{}:", id))),
+            };
+            if seen[index] {
+                return Err(ValidationError::new(ERR_BLOCK_STRUCTURE, format!("block {} appears more than once", id)));
+            }
+            seen[index] = true;
+            match index {
+                1 => message.basic_header = BasicHeader::parse(&content)?,
+                2 => message.application_header = ApplicationHeader::parse(&content)?,
+                3 => message.user_header = parse_tags(&content)?,
+                4 => message.text = parse_text_block(&content)?,
+                _ => message.trailer = parse_tags(&content)?,
+            }
+        }
+        for (index, name) in [(1, "basic header"), (2, "application header"), (4, "text")] {
+            if !seen[index] {
+                return Err(ValidationError::new(ERR_BLOCK_STRUCTURE, format!("{} block is missing", name)));
+            }
+        }
+        Ok(message)
+    }
+
+    /// Serialises the message back to FIN, using CRLF inside the text block.
+    pub fn to_fin(&self) -> String {
+        let mut out = format!("{{1:{}}}{{2:{}}}", self.basic_header.to_fin(), self.application_header.to_fin());
+        if !self.user_header.is_empty() {
+            out.push_str(&format!("{{3:{}}}", format_tags(&self.user_header)));
+        }
+        out.push_str("{4:\r\n");
+        for field in &self.text {
+            out.push_str(&format!(":{}:{}\r\n", field.tag, field.value.replace('\n', "\r\n")));
+        }
+        out.push_str("-}");
+        if !self.trailer.is_empty() {
+            out.push_str(&format!("{{5:{}}}", format_tags(&self.trailer)));
+        }
+        out
+    }
+
+    pub fn message_type(&self) -> &str {
+        self.application_header.message_type()
+    }
+
+    /// Validation flag of block 3 field 119 (e.g. `COV` for MT202 COV, `STP`).
+    pub fn validation_flag(&self) -> Option<&str> {
+        self.user_header_tag("119")
+    }
+
+    /// Unique end-to-end transaction reference from block 3 field 121.
+    pub fn uetr(&self) -> Option<&str> {
+        self.user_header_tag("121")
+    }
+
+    pub fn user_header_tag(&self, name: &str) -> Option<&str> {
+        self.user_header.iter().find(|t| t.name == name).map(|t| t.value.as_str())
+    }
+
+    pub fn trailer_tag(&self, name: &str) -> Option<&str> {
+        self.trailer.iter().find(|t| t.name == name).map(|t| t.value.as_str())
+    }
+
+    /// BIC of the sender: the LT address of block 1 for input messages and
+    /// the LT address inside the MIR for output messages.
+    pub fn sender_bic(&self) -> String {
+        match &self.application_header {
+            ApplicationHeader::Input { .. } => lt_to_bic(&self.basic_header.lt_address),
+            ApplicationHeader::Output { mir, .. } => lt_to_bic(mir.get(6..18).unwrap_or("")),
+        }
+    }
+
+    /// BIC of the receiver.
+    pub fn receiver_bic(&self) -> String {
+        match &self.application_header {
+            ApplicationHeader::Input { receiver_address, .. } => lt_to_bic(receiver_address),
+            ApplicationHeader::Output { .. } => lt_to_bic(&self.basic_header.lt_address),
+        }
+    }
+
+    /// First field matching `tag`: a bare number (`50`) matches any option
+    /// letter, a full tag (`71A`) matches exactly.
+    pub fn field(&self, tag: &str) -> Option<&Field> {
+        self.text.iter().find(|f| f.matches(tag))
+    }
+
+    /// All fields matching `tag`, in message order.
+    pub fn fields(&self, tag: &str) -> Vec<&Field> {
+        self.text.iter().filter(|f| f.matches(tag)).collect()
+    }
+
+    pub fn require(&self, tag: &str) -> Result<&Field, ValidationError> {
+        self.field(tag)
+            .ok_or_else(|| ValidationError::new(ERR_MISSING_FIELD, format!("mandatory field {} is missing", tag)))
+    }
+
+    /// Splits the text block into sequences: a new sequence starts at every
+    /// occurrence of `marker` (e.g. `21` for the transaction sequences of MT940).
+    pub fn sequences(&self, marker: &str) -> (Vec<&Field>, Vec<Vec<&Field>>) {
+        let mut head = Vec::new();
+        let mut sequences: Vec<Vec<&Field>> = Vec::new();
+        for field in &self.text {
+            if field.matches(marker) {
+                sequences.push(vec![field]);
+            } else if let Some(last) = sequences.last_mut() {
+                last.push(field);
+            } else {
+                head.push(field);
+            }
+        }
+        (head, sequences)
+    }
+}
+
+/// Turns a 12 character logical terminal address into a BIC11 (the terminal
+/// code at position 9 is dropped).
+pub fn lt_to_bic(lt: &str) -> String {
+    if lt.len() == 12 && lt.is_ascii() {
+        format!("{}{}", &lt[0..8], &lt[9..12])
+    } else {
+        lt.to_string()
+    }
+}
+
+/// Turns a BIC8 or BIC11 into a logical terminal address with terminal code `X`.
+pub fn bic_to_lt(bic: &str) -> String {
+    let bic = if bic.len() == 8 { format!("{}XXX", bic) } else { bic.to_string() };
+    if bic.len() == 11 && bic.is_ascii() {
+        format!("{}X{}", &bic[0..8], &bic[8..11])
+    } else {
+        bic
+    }
+}
+
+fn split_blocks(input: &str) -> Result<Vec<(String, String)>, ValidationError> {
+    let mut blocks = Vec::new();
+    let chars: Vec<char> = input.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if chars[i] != '{' {
+            return Err(ValidationError::new(ERR_BLOCK_STRUCTURE, format!("unexpected character {:?} outside of a block", chars[i])));
+        }
+        let colon = chars[i..].iter().position(|c| *c == ':')
+            .ok_or_else(|| ValidationError::new(ERR_BLOCK_STRUCTURE, "block identifier is not terminated".to_string()))?;
+        let id: String = chars[i + 1..i + colon].iter().collect();
+        let mut depth = 1;
+        let mut j = i + colon + 1;
+        while j < chars.len() && depth > 0 {
+            match chars[j] {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            j += 1;
+        }
+        if depth != 0 {
+            return Err(ValidationError::new(ERR_BLOCK_STRUCTURE, format!("block {} is not terminated", id)));
+        }
+        blocks.push((id, chars[i + colon + 1..j - 1].iter().collect()));
+        i = j;
+    }
+    Ok(blocks)
+}
+
+fn parse_tags(content: &str) -> Result<Vec<Tag>, ValidationError> {
+    let mut tags = Vec::new();
+    for (name, value) in split_blocks(content)? {
+        tags.push(Tag { name, value });
+    }
+    Ok(tags)
+}
+
+fn format_tags(tags: &[Tag]) -> String {
+    tags.iter().map(|t| format!("{{{}:{}}}", t.name, t.value)).collect()
+}
+
+fn is_field_start(line: &str) -> Option<(String, String)> {
+    let rest = line.strip_prefix(':')?;
+    let end = rest.find(':')?;
+    let tag = &rest[..end];
+    let bytes = tag.as_bytes();
+    let valid = (2..=3).contains(&bytes.len())
+        && bytes[0].is_ascii_digit()
+        && bytes[1].is_ascii_digit()
+        && (bytes.len() == 2 || bytes[2].is_ascii_uppercase());
+    if valid {
+        Some((tag.to_string(), rest[end + 1..].to_string()))
+    } else {
+        None
+    }
+}
+
+fn parse_text_block(content: &str) -> Result<Vec<Field>, ValidationError> {
+    let normalized = content.replace("\r\n", "\n");
+    let body = normalized.trim_start_matches('\n');
+    let body = body
+        .strip_suffix("\n-")
+        .or_else(|| if body == "-" { Some("") } else { None })
+        .ok_or_else(|| ValidationError::new(ERR_BLOCK_STRUCTURE, "text block must end with CrLf-".to_string()))?;
+    let mut fields: Vec<Field> = Vec::new();
+    for line in body.split('\n') {
+        if let Some((tag, value)) = is_field_start(line) {
+            fields.push(Field { tag, value });
+        } else if let Some(last) = fields.last_mut() {
+            last.value.push('\n');
+            last.value.push_str(line);
+        } else if !line.is_empty() {
+            return Err(ValidationError::new(ERR_FIELD_SYNTAX, format!("text block does not start with a field: {:?}", line)));
+        }
+    }
+    for field in &fields {
+        check_field(field)?;
+    }
+    Ok(fields)
+}
+
+/// SWIFT X character set.
+pub fn is_x_character(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "/-?:().,'+ \n".contains(c)
+}
+
+/// Replaces characters outside the X character set, used when writing
+/// free text coming from ISO 20022 messages into MT fields.
+pub fn to_x_charset(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            'À'..='Å' | 'à'..='å' => 'A',
+            'È'..='Ë' | 'è'..='ë' => 'E',
+            'Ì'..='Ï' | 'ì'..='ï' => 'I',
+            'Ò'..='Ö' | 'ò'..='ö' => 'O',
+            'Ù'..='Ü' | 'ù'..='ü' => 'U',
+            'Ç' | 'ç' => 'C',
+            'Ñ' | 'ñ' => 'N',
+            '&' => '+',
+            c if is_x_character(c) && c != '\n' => c,
+            _ => '.',
+        })
+        .collect()
+}
+
+/// Maximum number of lines and line length of the free format fields.
+fn line_rules(tag: &str) -> Option<(usize, usize)> {
+    match tag {
+        "20" | "21" => Some((1, 16)),
+        "13C" => Some((1, 18)),
+        "13D" => Some((1, 15)),
+        "23B" | "26T" => Some((1, 4)),
+        "23E" => Some((1, 35)),
+        "25" | "25P" => Some((2, 35)),
+        "28C" => Some((1, 11)),
+        "32A" | "33B" | "71F" | "71G" | "32B" | "34F" => Some((1, 24)),
+        "36" => Some((1, 12)),
+        "60F" | "60M" | "62F" | "62M" | "64" | "65" => Some((1, 25)),
+        "61" => Some((2, 66)),
+        "90C" | "90D" => Some((1, 23)),
+        "50K" | "59" | "50F" | "59F" | "52D" | "53D" | "54D" | "55D" | "56D" | "57D" | "58D" => Some((5, 35)),
+        "50A" | "51A" | "52A" | "53A" | "54A" | "55A" | "56A" | "57A" | "58A" | "59A" => Some((2, 35)),
+        "53B" | "54B" | "55B" | "57B" => Some((2, 35)),
+        "56C" | "57C" => Some((1, 35)),
+        "70" => Some((4, 35)),
+        "71A" => Some((1, 3)),
+        "72" => Some((6, 35)),
+        "77B" => Some((3, 35)),
+        "86" => Some((6, 65)),
+        _ => None,
+    }
+}
+
+fn is_party_field(tag: &str) -> bool {
+    matches!(&tag[..2.min(tag.len())], "50" | "51" | "52" | "53" | "54" | "55" | "56" | "57" | "58" | "59")
+}
+
+fn check_field(field: &Field) -> Result<(), ValidationError> {
+    if let Some(c) = field.value.chars().find(|c| !is_x_character(*c)) {
+        return Err(ValidationError::new(ERR_CHARACTER_SET, format!("field {} contains character {:?} outside the X character set", field.tag, c)));
+    }
+    if let Some((max_lines, max_len)) = line_rules(&field.tag) {
+        let lines = field.lines();
+        if lines.len() > max_lines {
+            return Err(ValidationError::new(ERR_LINE_FORMAT, format!("field {} has {} lines, at most {} allowed", field.tag, lines.len(), max_lines)));
+        }
+        for (index, line) in lines.iter().enumerate() {
+            // Party fields may start with a "[/1!a][/34x]" party identifier line.
+            let limit = if index == 0 && line.starts_with('/') && is_party_field(&field.tag) { 37 } else { max_len };
+            if line.chars().count() > limit {
+                return Err(ValidationError::new(ERR_LINE_FORMAT, format!("field {} line {:?} exceeds {} characters", field.tag, line, limit)));
+            }
+        }
+    }
+    if field.value.is_empty() {
+        return Err(ValidationError::new(ERR_FIELD_SYNTAX, format!("field {} is empty", field.tag)));
+    }
+    if field.number() == "20" || field.number() == "21" {
+        let value = field.value.as_str();
+        if value.starts_with('/') || value.ends_with('/') || value.contains("//") {
+            return Err(ValidationError::new(ERR_FIELD_SYNTAX, format!("field {} must not start or end with '/' or contain '//'", field.tag)));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,240 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// MT103 Single Customer Credit Transfer.
+
+use crate::swift::fields::{check_option, CodeWithInformation, CurrencyAmount, DateCurrencyAmount, PartyField};
+use crate::swift::{Field, FinMessage, ERR_FIELD_SYNTAX, ERR_MESSAGE_TYPE};
+use crate::ValidationError;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Mt103 {
+    /// :20: Sender's Reference
+    pub senders_reference: String,
+    /// :13C: Time Indication
+    pub time_indications: Vec<String>,
+    /// :23B: Bank Operation Code
+    pub bank_operation_code: String,
+    /// :23E: Instruction Code
+    pub instruction_codes: Vec<CodeWithInformation>,
+    /// :26T: Transaction Type Code
+    pub transaction_type_code: Option<String>,
+    /// :32A: Value Date/Currency/Interbank Settled Amount
+    pub value_date_amount: DateCurrencyAmount,
+    /// :33B: Currency/Instructed Amount
+    pub instructed_amount: Option<CurrencyAmount>,
+    /// :36: Exchange Rate
+    pub exchange_rate: Option<f64>,
+    /// :50a: Ordering Customer (A, F or K)
+    pub ordering_customer: PartyField,
+    /// :51A: Sending Institution
+    pub sending_institution: Option<PartyField>,
+    /// :52a: Ordering Institution (A or D)
+    pub ordering_institution: Option<PartyField>,
+    /// :53a: Sender's Correspondent (A, B or D)
+    pub senders_correspondent: Option<PartyField>,
+    /// :54a: Receiver's Correspondent (A, B or D)
+    pub receivers_correspondent: Option<PartyField>,
+    /// :55a: Third Reimbursement Institution (A, B or D)
+    pub third_reimbursement_institution: Option<PartyField>,
+    /// :56a: Intermediary Institution (A, C or D)
+    pub intermediary_institution: Option<PartyField>,
+    /// :57a: Account With Institution (A, B, C or D)
+    pub account_with_institution: Option<PartyField>,
+    /// :59a: Beneficiary Customer (no letter, A or F)
+    pub beneficiary_customer: PartyField,
+    /// :70: Remittance Information
+    pub remittance_information: Vec<String>,
+    /// :71A: Details of Charges (BEN, OUR or SHA)
+    pub details_of_charges: String,
+    /// :71F: Sender's Charges
+    pub senders_charges: Vec<CurrencyAmount>,
+    /// :71G: Receiver's Charges
+    pub receivers_charges: Option<CurrencyAmount>,
+    /// :72: Sender to Receiver Information
+    pub sender_to_receiver_information: Vec<String>,
+    /// :77B: Regulatory Reporting
+    pub regulatory_reporting: Vec<String>,
+    /// :77T: Envelope Contents
+    pub envelope_contents: Option<String>,
+}
+
+fn optional_party(message: &FinMessage, number: &str, allowed: &[Option<char>]) -> Result<Option<PartyField>, ValidationError> {
+    match message.field(number) {
+        Some(field) => {
+            check_option(field, allowed)?;
+            Ok(Some(PartyField::parse(field)?))
+        }
+        None => Ok(None),
+    }
+}
+
+fn lines_of(message: &FinMessage, number: &str) -> Vec<String> {
+    message.field(number).map(|f| f.lines().iter().map(|l| l.to_string()).collect()).unwrap_or_default()
+}
+
+impl Mt103 {
+    /// Builds the typed view of an MT103 (including the STP and REMIT
+    /// variants) and checks mandatory fields, field options and the network
+    /// validated rules that do not need reference data.
+    pub fn from_fin(message: &FinMessage) -> Result<Self, ValidationError> {
+        if message.message_type() != "103" {
+            return Err(ValidationError::new(ERR_MESSAGE_TYPE, format!("expected MT103, found MT{}", message.message_type())));
+        }
+        let exchange_rate = match message.field("36") {
+            Some(field) => Some(
+                crate::swift::fields::parse_amount(&field.value)
+                    .ok_or_else(|| ValidationError::new(ERR_FIELD_SYNTAX, format!("field 36: invalid rate {:?}", field.value)))?,
+            ),
+            None => None,
+        };
+        let ordering_customer = message.require("50")?;
+        check_option(ordering_customer, &[Some('A'), Some('F'), Some('K')])?;
+        let beneficiary = message.require("59")?;
+        check_option(beneficiary, &[None, Some('A'), Some('F')])?;
+        let value_date_amount = message.require("32A")?;
+        let mt = Mt103 {
+            senders_reference: message.require("20")?.value.clone(),
+            time_indications: message.fields("13C").iter().map(|f| f.value.clone()).collect(),
+            bank_operation_code: message.require("23B")?.value.clone(),
+            instruction_codes: message.fields("23E").into_iter().map(CodeWithInformation::parse).collect::<Result<_, _>>()?,
+            transaction_type_code: message.field("26T").map(|f| f.value.clone()),
+            value_date_amount: DateCurrencyAmount::parse(value_date_amount)?,
+            instructed_amount: message.field("33B").map(CurrencyAmount::parse).transpose()?,
+            exchange_rate,
+            ordering_customer: PartyField::parse(ordering_customer)?,
+            sending_institution: optional_party(message, "51", &[Some('A')])?,
+            ordering_institution: optional_party(message, "52", &[Some('A'), Some('D')])?,
+            senders_correspondent: optional_party(message, "53", &[Some('A'), Some('B'), Some('D')])?,
+            receivers_correspondent: optional_party(message, "54", &[Some('A'), Some('B'), Some('D')])?,
+            third_reimbursement_institution: optional_party(message, "55", &[Some('A'), Some('B'), Some('D')])?,
+            intermediary_institution: optional_party(message, "56", &[Some('A'), Some('C'), Some('D')])?,
+            account_with_institution: optional_party(message, "57", &[Some('A'), Some('B'), Some('C'), Some('D')])?,
+            beneficiary_customer: PartyField::parse(beneficiary)?,
+            remittance_information: lines_of(message, "70"),
+            details_of_charges: message.require("71A")?.value.clone(),
+            senders_charges: message.fields("71F").into_iter().map(CurrencyAmount::parse).collect::<Result<_, _>>()?,
+            receivers_charges: message.field("71G").map(CurrencyAmount::parse).transpose()?,
+            sender_to_receiver_information: lines_of(message, "72"),
+            regulatory_reporting: lines_of(message, "77B"),
+            envelope_contents: message.field("77T").map(|f| f.value.clone()),
+        };
+        mt.check_rules()?;
+        Ok(mt)
+    }
+
+    fn check_rules(&self) -> Result<(), ValidationError> {
+        let rule = |ok: bool, message: &str| {
+            if ok { Ok(()) } else { Err(ValidationError::new(ERR_FIELD_SYNTAX, message.to_string())) }
+        };
+        rule(
+            ["CRED", "CRTS", "SPAY", "SPRI", "SSTD"].contains(&self.bank_operation_code.as_str()),
+            "field 23B must be one of CRED, CRTS, SPAY, SPRI or SSTD",
+        )?;
+        rule(
+            ["BEN", "OUR", "SHA"].contains(&self.details_of_charges.as_str()),
+            "field 71A must be one of BEN, OUR or SHA",
+        )?;
+        // C1: if 33B differs from 32A in currency, 36 must be present.
+        if let Some(ref instructed) = self.instructed_amount {
+            rule(
+                instructed.currency == self.value_date_amount.currency || self.exchange_rate.is_some(),
+                "field 36 is mandatory when 33B and 32A currencies differ",
+            )?;
+        }
+        // E13/D50: with OUR, 71F is not allowed; with BEN, at least one 71F is required.
+        rule(self.details_of_charges != "OUR" || self.senders_charges.is_empty(), "field 71F is not allowed when 71A is OUR")?;
+        rule(self.details_of_charges != "BEN" || !self.senders_charges.is_empty(), "field 71F is mandatory when 71A is BEN")?;
+        rule(self.details_of_charges == "OUR" || self.receivers_charges.is_none(), "field 71G is only allowed when 71A is OUR")?;
+        // C9: 56a requires 57a.
+        rule(self.intermediary_institution.is_none() || self.account_with_institution.is_some(), "field 57a is mandatory when 56a is present")?;
+        // C4: 55a requires 53a and 54a.
+        rule(
+            self.third_reimbursement_institution.is_none() || (self.senders_correspondent.is_some() && self.receivers_correspondent.is_some()),
+            "fields 53a and 54a are mandatory when 55a is present",
+        )?;
+        Ok(())
+    }
+
+    /// End-to-end reference carried in field 70 as `/ROC/`.
+    pub fn end_to_end_reference(&self) -> Option<&str> {
+        crate::swift::fields::code_word(&self.remittance_information, "ROC")
+    }
+
+    /// Field 70 without the `/ROC/` reference, as unstructured remittance text.
+    pub fn remittance_text(&self) -> String {
+        self.remittance_information.iter().filter(|l| !l.starts_with("/ROC/")).cloned().collect::<Vec<_>>().join("")
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![Field::new("20", &self.senders_reference)];
+        fields.extend(self.time_indications.iter().map(|t| Field::new("13C", t)));
+        fields.push(Field::new("23B", &self.bank_operation_code));
+        fields.extend(self.instruction_codes.iter().map(|c| Field::new("23E", &c.to_field_value())));
+        if let Some(ref code) = self.transaction_type_code {
+            fields.push(Field::new("26T", code));
+        }
+        fields.push(Field::new("32A", &self.value_date_amount.to_field_value()));
+        if let Some(ref amount) = self.instructed_amount {
+            fields.push(Field::new("33B", &amount.to_field_value()));
+        }
+        if let Some(rate) = self.exchange_rate {
+            fields.push(Field::new("36", &crate::swift::fields::format_rate(rate)));
+        }
+        fields.push(self.ordering_customer.to_field("50"));
+        let institutions = [
+            ("51", &self.sending_institution),
+            ("52", &self.ordering_institution),
+            ("53", &self.senders_correspondent),
+            ("54", &self.receivers_correspondent),
+            ("55", &self.third_reimbursement_institution),
+            ("56", &self.intermediary_institution),
+            ("57", &self.account_with_institution),
+        ];
+        for (number, party) in institutions {
+            if let Some(party) = party {
+                fields.push(party.to_field(number));
+            }
+        }
+        fields.push(self.beneficiary_customer.to_field("59"));
+        if !self.remittance_information.is_empty() {
+            fields.push(Field::new("70", &self.remittance_information.join("\n")));
+        }
+        fields.push(Field::new("71A", &self.details_of_charges));
+        fields.extend(self.senders_charges.iter().map(|c| Field::new("71F", &c.to_field_value())));
+        if let Some(ref charges) = self.receivers_charges {
+            fields.push(Field::new("71G", &charges.to_field_value()));
+        }
+        if !self.sender_to_receiver_information.is_empty() {
+            fields.push(Field::new("72", &self.sender_to_receiver_information.join("\n")));
+        }
+        if !self.regulatory_reporting.is_empty() {
+            fields.push(Field::new("77B", &self.regulatory_reporting.join("\n")));
+        }
+        if let Some(ref envelope) = self.envelope_contents {
+            fields.push(Field::new("77T", envelope));
+        }
+        fields
+    }
+}
@@ -0,0 +1,221 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// MT202 General Financial Institution Transfer and MT202 COV.
+
+use crate::swift::fields::{check_option, CurrencyAmount, DateCurrencyAmount, PartyField};
+use crate::swift::{Field, FinMessage, ERR_FIELD_SYNTAX, ERR_MESSAGE_TYPE, ERR_MISSING_FIELD};
+use crate::ValidationError;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Mt202 {
+    /// :20: Transaction Reference Number
+    pub transaction_reference: String,
+    /// :21: Related Reference
+    pub related_reference: String,
+    /// :13C: Time Indication
+    pub time_indications: Vec<String>,
+    /// :32A: Value Date, Currency Code, Amount
+    pub value_date_amount: DateCurrencyAmount,
+    /// :52a: Ordering Institution (A or D)
+    pub ordering_institution: Option<PartyField>,
+    /// :53a: Sender's Correspondent (A, B or D)
+    pub senders_correspondent: Option<PartyField>,
+    /// :54a: Receiver's Correspondent (A, B or D)
+    pub receivers_correspondent: Option<PartyField>,
+    /// :56a: Intermediary (A or D)
+    pub intermediary: Option<PartyField>,
+    /// :57a: Account With Institution (A, B or D)
+    pub account_with_institution: Option<PartyField>,
+    /// :58a: Beneficiary Institution (A or D)
+    pub beneficiary_institution: PartyField,
+    /// :72: Sender to Receiver Information
+    pub sender_to_receiver_information: Vec<String>,
+    /// Sequence B of the MT202 COV.
+    pub underlying: Option<UnderlyingCustomerCreditTransfer>,
+}
+
+/// Sequence B (Underlying Customer Credit Transfer) of the MT202 COV.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UnderlyingCustomerCreditTransfer {
+    /// :50a: Ordering Customer (A, F or K)
+    pub ordering_customer: PartyField,
+    /// :52a: Ordering Institution (A or D)
+    pub ordering_institution: Option<PartyField>,
+    /// :56a: Intermediary Institution (A, C or D)
+    pub intermediary_institution: Option<PartyField>,
+    /// :57a: Account With Institution (A, B, C or D)
+    pub account_with_institution: Option<PartyField>,
+    /// :59a: Beneficiary Customer (no letter, A or F)
+    pub beneficiary_customer: PartyField,
+    /// :70: Remittance Information
+    pub remittance_information: Vec<String>,
+    /// :72: Sender to Receiver Information
+    pub sender_to_receiver_information: Vec<String>,
+    /// :33B: Currency/Instructed Amount
+    pub instructed_amount: Option<CurrencyAmount>,
+}
+
+fn find<'a>(fields: &[&'a Field], tag: &str) -> Option<&'a Field> {
+    fields.iter().find(|f| f.matches(tag)).copied()
+}
+
+fn require<'a>(fields: &[&'a Field], tag: &str, sequence: &str) -> Result<&'a Field, ValidationError> {
+    find(fields, tag).ok_or_else(|| ValidationError::new(ERR_MISSING_FIELD, format!("mandatory field {} is missing in sequence {}", tag, sequence)))
+}
+
+fn party(fields: &[&Field], number: &str, allowed: &[Option<char>]) -> Result<Option<PartyField>, ValidationError> {
+    match find(fields, number) {
+        Some(field) => {
+            check_option(field, allowed)?;
+            Ok(Some(PartyField::parse(field)?))
+        }
+        None => Ok(None),
+    }
+}
+
+fn lines(fields: &[&Field], tag: &str) -> Vec<String> {
+    find(fields, tag).map(|f| f.lines().iter().map(|l| l.to_string()).collect()).unwrap_or_default()
+}
+
+fn push_party(fields: &mut Vec<Field>, number: &str, party: &Option<PartyField>) {
+    if let Some(party) = party {
+        fields.push(party.to_field(number));
+    }
+}
+
+impl Mt202 {
+    /// Builds the typed view of an MT202 or MT202 COV. Sequence B starts at
+    /// the ordering customer (50a); it is mandatory when block 3 carries the
+    /// `COV` validation flag and not allowed otherwise.
+    pub fn from_fin(message: &FinMessage) -> Result<Self, ValidationError> {
+        if message.message_type() != "202" {
+            return Err(ValidationError::new(ERR_MESSAGE_TYPE, format!("expected MT202, found MT{}", message.message_type())));
+        }
+        let split = message.text.iter().position(|f| f.number() == "50").unwrap_or(message.text.len());
+        let sequence_a: Vec<&Field> = message.text[..split].iter().collect();
+        let sequence_b: Vec<&Field> = message.text[split..].iter().collect();
+        let is_cov = message.validation_flag() == Some("COV");
+        if is_cov && sequence_b.is_empty() {
+            return Err(ValidationError::new(ERR_MISSING_FIELD, "MT202 COV requires sequence B".to_string()));
+        }
+        if !is_cov && !sequence_b.is_empty() {
+            return Err(ValidationError::new(ERR_FIELD_SYNTAX, "sequence B is only allowed in MT202 COV (field 119 COV)".to_string()));
+        }
+
+        let beneficiary = require(&sequence_a, "58", "A")?;
+        check_option(beneficiary, &[Some('A'), Some('D')])?;
+        let underlying = if is_cov {
+            let ordering_customer = require(&sequence_b, "50", "B")?;
+            check_option(ordering_customer, &[Some('A'), Some('F'), Some('K')])?;
+            let beneficiary_customer = require(&sequence_b, "59", "B")?;
+            check_option(beneficiary_customer, &[None, Some('A'), Some('F')])?;
+            Some(UnderlyingCustomerCreditTransfer {
+                ordering_customer: PartyField::parse(ordering_customer)?,
+                ordering_institution: party(&sequence_b, "52", &[Some('A'), Some('D')])?,
+                intermediary_institution: party(&sequence_b, "56", &[Some('A'), Some('C'), Some('D')])?,
+                account_with_institution: party(&sequence_b, "57", &[Some('A'), Some('B'), Some('C'), Some('D')])?,
+                beneficiary_customer: PartyField::parse(beneficiary_customer)?,
+                remittance_information: lines(&sequence_b, "70"),
+                sender_to_receiver_information: lines(&sequence_b, "72"),
+                instructed_amount: find(&sequence_b, "33B").map(CurrencyAmount::parse).transpose()?,
+            })
+        } else {
+            None
+        };
+
+        let mt = Mt202 {
+            transaction_reference: require(&sequence_a, "20", "A")?.value.clone(),
+            related_reference: require(&sequence_a, "21", "A")?.value.clone(),
+            time_indications: sequence_a.iter().filter(|f| f.matches("13C")).map(|f| f.value.clone()).collect(),
+            value_date_amount: DateCurrencyAmount::parse(require(&sequence_a, "32A", "A")?)?,
+            ordering_institution: party(&sequence_a, "52", &[Some('A'), Some('D')])?,
+            senders_correspondent: party(&sequence_a, "53", &[Some('A'), Some('B'), Some('D')])?,
+            receivers_correspondent: party(&sequence_a, "54", &[Some('A'), Some('B'), Some('D')])?,
+            intermediary: party(&sequence_a, "56", &[Some('A'), Some('D')])?,
+            account_with_institution: party(&sequence_a, "57", &[Some('A'), Some('B'), Some('D')])?,
+            beneficiary_institution: PartyField::parse(beneficiary)?,
+            sender_to_receiver_information: lines(&sequence_a, "72"),
+            underlying,
+        };
+        // C1: 56a requires 57a.
+        if mt.intermediary.is_some() && mt.account_with_institution.is_none() {
+            return Err(ValidationError::new(ERR_FIELD_SYNTAX, "field 57a is mandatory when 56a is present".to_string()));
+        }
+        if let Some(ref underlying) = mt.underlying {
+            if underlying.intermediary_institution.is_some() && underlying.account_with_institution.is_none() {
+                return Err(ValidationError::new(ERR_FIELD_SYNTAX, "field 57a is mandatory in sequence B when 56a is present".to_string()));
+            }
+        }
+        Ok(mt)
+    }
+
+    pub fn is_cov(&self) -> bool {
+        self.underlying.is_some()
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![Field::new("20", &self.transaction_reference), Field::new("21", &self.related_reference)];
+        fields.extend(self.time_indications.iter().map(|t| Field::new("13C", t)));
+        fields.push(Field::new("32A", &self.value_date_amount.to_field_value()));
+        push_party(&mut fields, "52", &self.ordering_institution);
+        push_party(&mut fields, "53", &self.senders_correspondent);
+        push_party(&mut fields, "54", &self.receivers_correspondent);
+        push_party(&mut fields, "56", &self.intermediary);
+        push_party(&mut fields, "57", &self.account_with_institution);
+        fields.push(self.beneficiary_institution.to_field("58"));
+        if !self.sender_to_receiver_information.is_empty() {
+            fields.push(Field::new("72", &self.sender_to_receiver_information.join("\n")));
+        }
+        if let Some(ref underlying) = self.underlying {
+            fields.push(underlying.ordering_customer.to_field("50"));
+            push_party(&mut fields, "52", &underlying.ordering_institution);
+            push_party(&mut fields, "56", &underlying.intermediary_institution);
+            push_party(&mut fields, "57", &underlying.account_with_institution);
+            fields.push(underlying.beneficiary_customer.to_field("59"));
+            if !underlying.remittance_information.is_empty() {
+                fields.push(Field::new("70", &underlying.remittance_information.join("\n")));
+            }
+            if !underlying.sender_to_receiver_information.is_empty() {
+                fields.push(Field::new("72", &underlying.sender_to_receiver_information.join("\n")));
+            }
+            if let Some(ref amount) = underlying.instructed_amount {
+                fields.push(Field::new("33B", &amount.to_field_value()));
+            }
+        }
+        fields
+    }
+
+    /// Writes the message as an input MT202 (or MT202 COV, flagged in block 3).
+    pub fn to_fin(&self, sender_bic: &str, receiver_bic: &str, uetr: Option<&str>) -> FinMessage {
+        let mut message = FinMessage::new_input("202", sender_bic, receiver_bic, self.fields());
+        if self.is_cov() {
+            message.set_user_header_tag("119", "COV");
+        }
+        if let Some(uetr) = uetr {
+            message.set_user_header_tag("121", uetr);
+        }
+        message
+    }
+}
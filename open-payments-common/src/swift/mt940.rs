@@ -0,0 +1,549 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// MT940 Customer Statement Message, MT942 Interim Transaction Report and
+// MT950 Statement Message. The three messages share their field formats and
+// are read into the same `Statement` view.
+
+use regex::Regex;
+
+use crate::datetime::{format_iso_date, iso_date_to_days, parse_iso_date};
+use crate::swift::fields::{format_amount, format_date6, parse_amount, parse_date6};
+use crate::swift::{Field, FinMessage, ERR_FIELD_SYNTAX, ERR_MESSAGE_TYPE, ERR_MISSING_FIELD};
+use crate::ValidationError;
+
+fn syntax_error(field: &Field, what: &str) -> ValidationError {
+    ValidationError::new(ERR_FIELD_SYNTAX, format!("field {}: {} in {:?}", field.tag, what, field.value))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatementType {
+    #[default]
+    Mt940,
+    Mt942,
+    Mt950,
+}
+
+impl StatementType {
+    pub fn message_type(&self) -> &'static str {
+        match self {
+            StatementType::Mt940 => "940",
+            StatementType::Mt942 => "942",
+            StatementType::Mt950 => "950",
+        }
+    }
+}
+
+/// `1!a6!n3!a15d`, balance fields 60a, 62a, 64 and 65.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Balance {
+    /// Option M (intermediate) rather than F (final) for 60a and 62a.
+    pub intermediate: bool,
+    pub credit: bool,
+    pub date: String,
+    pub currency: String,
+    pub amount: f64,
+}
+
+impl Balance {
+    pub fn parse(field: &Field) -> Result<Self, ValidationError> {
+        let value = field.value.as_str();
+        if value.len() < 12 || !value.is_ascii() {
+            return Err(syntax_error(field, "value too short"));
+        }
+        let credit = match &value[0..1] {
+            "C" => true,
+            "D" => false,
+            _ => return Err(syntax_error(field, "invalid debit/credit mark")),
+        };
+        let date = parse_date6(&value[1..7]).ok_or_else(|| syntax_error(field, "invalid date"))?;
+        let currency = &value[7..10];
+        if !currency.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(syntax_error(field, "invalid currency"));
+        }
+        let amount = parse_amount(&value[10..]).ok_or_else(|| syntax_error(field, "invalid amount"))?;
+        Ok(Balance { intermediate: field.option() == Some('M'), credit, date, currency: currency.to_string(), amount })
+    }
+
+    pub fn to_field_value(&self) -> String {
+        format!(
+            "{}{}{}{}",
+            if self.credit { "C" } else { "D" },
+            format_date6(&self.date).unwrap_or_default(),
+            self.currency,
+            format_amount(self.amount, &self.currency)
+        )
+    }
+
+    /// Signed amount, debit balances are negative.
+    pub fn signed_amount(&self) -> f64 {
+        if self.credit { self.amount } else { -self.amount }
+    }
+
+    fn to_field(&self, number: &str) -> Field {
+        let tag = match number {
+            "60" | "62" => format!("{}{}", number, if self.intermediate { 'M' } else { 'F' }),
+            _ => number.to_string(),
+        };
+        Field::new(&tag, &self.to_field_value())
+    }
+}
+
+/// Debit/credit mark of a statement line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DebitCreditMark {
+    #[default]
+    Credit,
+    Debit,
+    /// `RC`, a debit entry reversing an earlier credit.
+    ReversalOfCredit,
+    /// `RD`, a credit entry reversing an earlier debit.
+    ReversalOfDebit,
+}
+
+impl DebitCreditMark {
+    pub fn code(&self) -> &'static str {
+        match self {
+            DebitCreditMark::Credit => "C",
+            DebitCreditMark::Debit => "D",
+            DebitCreditMark::ReversalOfCredit => "RC",
+            DebitCreditMark::ReversalOfDebit => "RD",
+        }
+    }
+
+    /// Whether the entry credits the account.
+    pub fn is_credit(&self) -> bool {
+        matches!(self, DebitCreditMark::Credit | DebitCreditMark::ReversalOfDebit)
+    }
+
+    pub fn is_reversal(&self) -> bool {
+        matches!(self, DebitCreditMark::ReversalOfCredit | DebitCreditMark::ReversalOfDebit)
+    }
+
+    pub fn from_entry(credit: bool, reversal: bool) -> Self {
+        match (credit, reversal) {
+            (true, false) => DebitCreditMark::Credit,
+            (false, false) => DebitCreditMark::Debit,
+            (false, true) => DebitCreditMark::ReversalOfCredit,
+            (true, true) => DebitCreditMark::ReversalOfDebit,
+        }
+    }
+}
+
+/// Field 61 Statement Line:
+/// `6!n[4!n]2a[1!a]15d1!a3!c16x[//16x][34x]`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StatementLine {
+    pub value_date: String,
+    /// Entry (booking) date; the year is taken from the value date.
+    pub entry_date: Option<String>,
+    pub mark: DebitCreditMark,
+    /// Third character of the currency code, if it differs from the account currency.
+    pub funds_code: Option<char>,
+    pub amount: f64,
+    /// Transaction type identification code, e.g. `NTRF` or `S103`.
+    pub transaction_type: String,
+    /// Reference for the account owner, `NONREF` when there is none.
+    pub account_owner_reference: String,
+    pub servicing_institution_reference: Option<String>,
+    pub supplementary_details: Option<String>,
+}
+
+/// Year of an `MMDD` date close to the given ISODate (entry dates may fall
+/// in the year before or after the value date around the new year).
+fn date_near(mmdd: &str, reference: &str) -> Option<String> {
+    let (year, _, _) = parse_iso_date(reference)?;
+    let month = mmdd[0..2].parse::<u32>().ok()?;
+    let day = mmdd[2..4].parse::<u32>().ok()?;
+    let reference_days = iso_date_to_days(reference)?;
+    [year - 1, year, year + 1]
+        .iter()
+        .map(|y| format_iso_date(*y, month, day))
+        .filter_map(|d| iso_date_to_days(&d).map(|days| (d, days)))
+        .min_by_key(|(_, days)| (days - reference_days).abs())
+        .map(|(d, _)| d)
+}
+
+impl StatementLine {
+    pub fn parse(field: &Field) -> Result<Self, ValidationError> {
+        let pattern = Regex::new(r"^(\d{6})(\d{4})?(RC|RD|C|D)([A-Z])?(\d[\d,]{0,14})([NSF][A-Z0-9]{3})(.*?)(?://(.*))?$").unwrap();
+        let lines = field.lines();
+        let captures = pattern.captures(lines[0]).ok_or_else(|| syntax_error(field, "invalid statement line"))?;
+        let value_date = parse_date6(&captures[1]).ok_or_else(|| syntax_error(field, "invalid value date"))?;
+        let entry_date = match captures.get(2) {
+            Some(m) => Some(date_near(m.as_str(), &value_date).ok_or_else(|| syntax_error(field, "invalid entry date"))?),
+            None => None,
+        };
+        let mark = match &captures[3] {
+            "C" => DebitCreditMark::Credit,
+            "D" => DebitCreditMark::Debit,
+            "RC" => DebitCreditMark::ReversalOfCredit,
+            _ => DebitCreditMark::ReversalOfDebit,
+        };
+        let amount = parse_amount(&captures[5]).ok_or_else(|| syntax_error(field, "invalid amount"))?;
+        let reference = &captures[7];
+        if reference.is_empty() || reference.chars().count() > 16 {
+            return Err(syntax_error(field, "reference for the account owner must be 1 to 16 characters"));
+        }
+        let servicing = captures.get(8).map(|m| m.as_str().to_string());
+        if servicing.as_ref().is_some_and(|s| s.is_empty() || s.chars().count() > 16) {
+            return Err(syntax_error(field, "account servicing institution's reference must be 1 to 16 characters"));
+        }
+        let supplementary = lines.get(1).map(|l| l.to_string());
+        if supplementary.as_ref().is_some_and(|s| s.chars().count() > 34) {
+            return Err(syntax_error(field, "supplementary details exceed 34 characters"));
+        }
+        Ok(StatementLine {
+            value_date,
+            entry_date,
+            mark,
+            funds_code: captures.get(4).and_then(|m| m.as_str().chars().next()),
+            amount,
+            transaction_type: captures[6].to_string(),
+            account_owner_reference: reference.to_string(),
+            servicing_institution_reference: servicing,
+            supplementary_details: supplementary,
+        })
+    }
+
+    pub fn to_field_value(&self, currency: &str) -> String {
+        let mut value = format_date6(&self.value_date).unwrap_or_default();
+        if let Some(entry) = self.entry_date.as_deref().and_then(format_date6) {
+            value.push_str(&entry[2..]);
+        }
+        value.push_str(self.mark.code());
+        if let Some(code) = self.funds_code {
+            value.push(code);
+        }
+        value.push_str(&format_amount(self.amount, currency));
+        value.push_str(&self.transaction_type);
+        value.push_str(&self.account_owner_reference);
+        if let Some(ref servicing) = self.servicing_institution_reference {
+            value.push_str("//");
+            value.push_str(servicing);
+        }
+        if let Some(ref details) = self.supplementary_details {
+            value.push('\n');
+            value.push_str(details);
+        }
+        value
+    }
+}
+
+/// A statement line with the information to account owner (86) that follows it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StatementEntry {
+    pub line: StatementLine,
+    pub information: Vec<String>,
+}
+
+/// `3!a[1!a]15d`, field 34F of the MT942.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FloorLimit {
+    pub currency: String,
+    /// `D` or `C` when debit and credit limits differ.
+    pub mark: Option<char>,
+    pub amount: f64,
+}
+
+impl FloorLimit {
+    pub fn parse(field: &Field) -> Result<Self, ValidationError> {
+        let value = field.value.as_str();
+        if value.len() < 5 || !value.is_ascii() || !value[0..3].chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(syntax_error(field, "invalid currency"));
+        }
+        let (mark, rest) = match value[3..].chars().next() {
+            Some(c @ ('D' | 'C')) => (Some(c), &value[4..]),
+            _ => (None, &value[3..]),
+        };
+        let amount = parse_amount(rest).ok_or_else(|| syntax_error(field, "invalid amount"))?;
+        Ok(FloorLimit { currency: value[0..3].to_string(), mark, amount })
+    }
+
+    pub fn to_field_value(&self) -> String {
+        let mark = self.mark.map(|c| c.to_string()).unwrap_or_default();
+        format!("{}{}{}", self.currency, mark, format_amount(self.amount, &self.currency))
+    }
+}
+
+/// `5n3!a15d`, fields 90D and 90C of the MT942.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EntrySummary {
+    pub count: u32,
+    pub currency: String,
+    pub amount: f64,
+}
+
+impl EntrySummary {
+    pub fn parse(field: &Field) -> Result<Self, ValidationError> {
+        let value = field.value.as_str();
+        let digits = value.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 || digits > 5 || value.len() < digits + 5 || !value.is_ascii() {
+            return Err(syntax_error(field, "invalid number of entries"));
+        }
+        let currency = &value[digits..digits + 3];
+        if !currency.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(syntax_error(field, "invalid currency"));
+        }
+        Ok(EntrySummary {
+            count: value[..digits].parse().map_err(|_| syntax_error(field, "invalid number of entries"))?,
+            currency: currency.to_string(),
+            amount: parse_amount(&value[digits + 3..]).ok_or_else(|| syntax_error(field, "invalid amount"))?,
+        })
+    }
+
+    pub fn to_field_value(&self) -> String {
+        format!("{}{}{}", self.count, self.currency, format_amount(self.amount, &self.currency))
+    }
+}
+
+/// Converts a `6!n4!n1!x4!n` date time indication (13D) into an ISODateTime.
+pub fn parse_date_time_indication(value: &str) -> Option<String> {
+    if value.len() != 15 || !value.is_ascii() {
+        return None;
+    }
+    let date = parse_date6(&value[0..6])?;
+    let (time, sign, offset) = (&value[6..10], &value[10..11], &value[11..15]);
+    if (sign != "+" && sign != "-") || !time.chars().chain(offset.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("{}T{}:{}:00{}{}:{}", date, &time[0..2], &time[2..4], sign, &offset[0..2], &offset[2..4]))
+}
+
+/// Inverse of `parse_date_time_indication`; times without offset are taken as UTC.
+pub fn format_date_time_indication(value: &str) -> Option<String> {
+    let date = format_date6(value.get(0..10)?)?;
+    let time = value.get(11..16)?.replace(':', "");
+    let offset = match value.get(19..).map(|t| t.trim_start_matches(|c: char| c == '.' || c.is_ascii_digit())) {
+        Some(t) if t.len() >= 6 && (t.starts_with('+') || t.starts_with('-')) => format!("{}{}", &t[0..1], t[1..6].replace(':', "")),
+        _ => "+0000".to_string(),
+    };
+    Some(format!("{}{}{}", date, time, offset))
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Statement {
+    pub statement_type: StatementType,
+    /// :20: Transaction Reference Number
+    pub transaction_reference: String,
+    /// :21: Related Reference
+    pub related_reference: Option<String>,
+    /// :25: / :25P: Account Identification
+    pub account: String,
+    /// Identifier code of the account owner (25P).
+    pub account_owner_bic: Option<String>,
+    /// :28C: Statement Number
+    pub statement_number: u32,
+    /// :28C: Sequence Number
+    pub sequence_number: Option<u32>,
+    /// :34F: Floor Limit Indicator (MT942)
+    pub floor_limits: Vec<FloorLimit>,
+    /// :13D: Date/Time Indication (MT942), as ISODateTime
+    pub date_time_indication: Option<String>,
+    /// :60a: Opening Balance
+    pub opening_balance: Option<Balance>,
+    /// :61: Statement Lines with their :86:
+    pub entries: Vec<StatementEntry>,
+    /// :62a: Closing Balance (Booked Funds)
+    pub closing_balance: Option<Balance>,
+    /// :64: Closing Available Balance
+    pub closing_available_balance: Option<Balance>,
+    /// :65: Forward Available Balance
+    pub forward_available_balances: Vec<Balance>,
+    /// :90D: Number and Sum of Debit Entries (MT942)
+    pub debit_summary: Option<EntrySummary>,
+    /// :90C: Number and Sum of Credit Entries (MT942)
+    pub credit_summary: Option<EntrySummary>,
+    /// :86: Information to Account Owner, at message level
+    pub information: Vec<String>,
+}
+
+impl Statement {
+    /// Builds the typed view of an MT940, MT942 or MT950 and checks the
+    /// mandatory fields and the currency rule shared by the balances.
+    pub fn from_fin(message: &FinMessage) -> Result<Self, ValidationError> {
+        let statement_type = match message.message_type() {
+            "940" => StatementType::Mt940,
+            "942" => StatementType::Mt942,
+            "950" => StatementType::Mt950,
+            other => return Err(ValidationError::new(ERR_MESSAGE_TYPE, format!("expected MT940, MT942 or MT950, found MT{}", other))),
+        };
+        let mut statement = Statement { statement_type, ..Default::default() };
+        let mut seen_28c = false;
+        let mut after_line = false;
+        for field in &message.text {
+            let mut is_line = false;
+            match field.tag.as_str() {
+                "20" => statement.transaction_reference = field.value.clone(),
+                "21" => statement.related_reference = Some(field.value.clone()),
+                "25" | "25P" => {
+                    let lines = field.lines();
+                    statement.account = lines[0].to_string();
+                    if field.tag == "25P" {
+                        statement.account_owner_bic = Some(lines.get(1).ok_or_else(|| syntax_error(field, "identifier code is missing"))?.to_string());
+                    }
+                }
+                "28C" => {
+                    let (number, sequence) = match field.value.split_once('/') {
+                        Some((n, s)) => (n, Some(s)),
+                        None => (field.value.as_str(), None),
+                    };
+                    statement.statement_number = number.parse().map_err(|_| syntax_error(field, "invalid statement number"))?;
+                    statement.sequence_number = sequence.map(|s| s.parse().map_err(|_| syntax_error(field, "invalid sequence number"))).transpose()?;
+                    seen_28c = true;
+                }
+                "34F" => statement.floor_limits.push(FloorLimit::parse(field)?),
+                "13D" => {
+                    statement.date_time_indication = Some(parse_date_time_indication(&field.value).ok_or_else(|| syntax_error(field, "invalid date/time indication"))?)
+                }
+                "60F" | "60M" => statement.opening_balance = Some(Balance::parse(field)?),
+                "61" => {
+                    statement.entries.push(StatementEntry { line: StatementLine::parse(field)?, information: Vec::new() });
+                    is_line = true;
+                }
+                "86" if statement_type != StatementType::Mt950 => {
+                    let lines = field.lines().iter().map(|l| l.to_string()).collect();
+                    match statement.entries.last_mut() {
+                        Some(entry) if after_line => entry.information = lines,
+                        _ => statement.information = lines,
+                    }
+                }
+                "62F" | "62M" => statement.closing_balance = Some(Balance::parse(field)?),
+                "64" => statement.closing_available_balance = Some(Balance::parse(field)?),
+                "65" => statement.forward_available_balances.push(Balance::parse(field)?),
+                "90D" => statement.debit_summary = Some(EntrySummary::parse(field)?),
+                "90C" => statement.credit_summary = Some(EntrySummary::parse(field)?),
+                _ => {
+                    return Err(ValidationError::new(
+                        ERR_FIELD_SYNTAX,
+                        format!("field {} is not allowed in MT{}", field.tag, statement_type.message_type()),
+                    ))
+                }
+            }
+            after_line = is_line;
+        }
+
+        let missing = |tag: &str| Err(ValidationError::new(ERR_MISSING_FIELD, format!("mandatory field {} is missing", tag)));
+        if statement.transaction_reference.is_empty() {
+            return missing("20");
+        }
+        if statement.account.is_empty() {
+            return missing("25");
+        }
+        if !seen_28c {
+            return missing("28C");
+        }
+        if statement_type == StatementType::Mt942 {
+            if statement.floor_limits.is_empty() {
+                return missing("34F");
+            }
+            if statement.date_time_indication.is_none() {
+                return missing("13D");
+            }
+            // C2: with two floor limits the first is the debit and the second the credit limit.
+            if statement.floor_limits.len() > 2
+                || (statement.floor_limits.len() == 2 && (statement.floor_limits[0].mark != Some('D') || statement.floor_limits[1].mark != Some('C')))
+            {
+                return Err(ValidationError::new(ERR_FIELD_SYNTAX, "field 34F must be a single limit or a debit (D) and a credit (C) limit".to_string()));
+            }
+        } else {
+            if statement.opening_balance.is_none() {
+                return missing("60a");
+            }
+            if statement.closing_balance.is_none() {
+                return missing("62a");
+            }
+        }
+        // C1: the balances must share the same currency.
+        let currency = statement.currency();
+        let balances = statement.opening_balance.iter().chain(statement.closing_balance.iter()).chain(statement.closing_available_balance.iter()).chain(statement.forward_available_balances.iter());
+        if balances.map(|b| b.currency.as_str()).chain(statement.floor_limits.iter().map(|f| f.currency.as_str())).any(|c| Some(c) != currency.as_deref()) {
+            return Err(ValidationError::new(ERR_FIELD_SYNTAX, "the balances of a statement must all be in the same currency".to_string()));
+        }
+        Ok(statement)
+    }
+
+    /// Account currency, taken from the first balance (or floor limit).
+    pub fn currency(&self) -> Option<String> {
+        self.opening_balance
+            .as_ref()
+            .or(self.closing_balance.as_ref())
+            .map(|b| b.currency.clone())
+            .or_else(|| self.floor_limits.first().map(|f| f.currency.clone()))
+            .or_else(|| self.debit_summary.as_ref().or(self.credit_summary.as_ref()).map(|s| s.currency.clone()))
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let currency = self.currency().unwrap_or_default();
+        let mut fields = vec![Field::new("20", &self.transaction_reference)];
+        if let Some(ref related) = self.related_reference {
+            fields.push(Field::new("21", related));
+        }
+        match self.account_owner_bic {
+            Some(ref bic) => fields.push(Field::new("25P", &format!("{}\n{}", self.account, bic))),
+            None => fields.push(Field::new("25", &self.account)),
+        }
+        let number = match self.sequence_number {
+            Some(sequence) => format!("{}/{}", self.statement_number, sequence),
+            None => self.statement_number.to_string(),
+        };
+        fields.push(Field::new("28C", &number));
+        fields.extend(self.floor_limits.iter().map(|f| Field::new("34F", &f.to_field_value())));
+        if let Some(indication) = self.date_time_indication.as_deref().and_then(format_date_time_indication) {
+            fields.push(Field::new("13D", &indication));
+        }
+        if let Some(ref balance) = self.opening_balance {
+            fields.push(balance.to_field("60"));
+        }
+        for entry in &self.entries {
+            fields.push(Field::new("61", &entry.line.to_field_value(&currency)));
+            if !entry.information.is_empty() && self.statement_type != StatementType::Mt950 {
+                fields.push(Field::new("86", &entry.information.join("\n")));
+            }
+        }
+        if let Some(ref balance) = self.closing_balance {
+            fields.push(balance.to_field("62"));
+        }
+        if let Some(ref balance) = self.closing_available_balance {
+            fields.push(balance.to_field("64"));
+        }
+        fields.extend(self.forward_available_balances.iter().map(|b| b.to_field("65")));
+        if let Some(ref summary) = self.debit_summary {
+            fields.push(Field::new("90D", &summary.to_field_value()));
+        }
+        if let Some(ref summary) = self.credit_summary {
+            fields.push(Field::new("90C", &summary.to_field_value()));
+        }
+        if !self.information.is_empty() && self.statement_type != StatementType::Mt950 {
+            fields.push(Field::new("86", &self.information.join("\n")));
+        }
+        fields
+    }
+
+    /// Writes the statement as an input message from the account servicer
+    /// (`sender_bic`) to the account owner (`receiver_bic`).
+    pub fn to_fin(&self, sender_bic: &str, receiver_bic: &str) -> FinMessage {
+        FinMessage::new_input(self.statement_type.message_type(), sender_bic, receiver_bic, self.fields())
+    }
+}
@@ -0,0 +1,285 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// System events broadcast with admi.004 (SystemEventNotification) and the
+// business day and service status they imply.
+//
+// Event codes are mapped to `SystemEvent`s with an `EventCodes` table. Its
+// default holds the codes of the FedNow Service; other services, or a
+// changed specification, use their own table. Events the table does not
+// know are kept as `SystemEvent::Other`.
+//
+// `BusinessDayTracker` follows the events in the order they were sent: it
+// knows the current business day, whether the service is open and which
+// participants have signed off. `BusinessDayCalendar` tells which dates
+// are business days (weekdays other than the listed holidays).
+
+use crate::datetime::{add_days, iso_datetime_to_seconds, parse_iso_date, weekday};
+use crate::ValidationError;
+use std::collections::BTreeSet;
+
+pub const ERR_EVENT_PARAMETER: u32 = 3101;
+pub const ERR_BUSINESS_DAY_ORDER: u32 = 3102;
+pub const ERR_EVENT_ORDER: u32 = 3103;
+
+/// What a system event announces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SystemEvent {
+    /// The service accepts messages again.
+    ServiceOpen,
+    /// The service stops accepting messages.
+    ServiceClose,
+    /// A new business day starts; the first parameter is its date.
+    BusinessDayChange,
+    /// A participant, the first parameter, signs on.
+    ParticipantSignOn,
+    /// A participant, the first parameter, signs off.
+    ParticipantSignOff,
+    /// Free text broadcast to the participants.
+    AdHoc,
+    Other(String),
+}
+
+/// Event codes of the events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventCodes {
+    pub service_open: String,
+    pub service_close: String,
+    pub business_day_change: String,
+    pub participant_sign_on: String,
+    pub participant_sign_off: String,
+    pub ad_hoc: String,
+}
+
+impl Default for EventCodes {
+    fn default() -> Self {
+        EventCodes {
+            service_open: "OPEN".to_string(),
+            service_close: "CLSD".to_string(),
+            business_day_change: "BDAY".to_string(),
+            participant_sign_on: "SGON".to_string(),
+            participant_sign_off: "SGOF".to_string(),
+            ad_hoc: "ADHC".to_string(),
+        }
+    }
+}
+
+impl EventCodes {
+    pub fn event(&self, code: &str) -> SystemEvent {
+        let code = code.trim();
+        if code == self.service_open {
+            SystemEvent::ServiceOpen
+        } else if code == self.service_close {
+            SystemEvent::ServiceClose
+        } else if code == self.business_day_change {
+            SystemEvent::BusinessDayChange
+        } else if code == self.participant_sign_on {
+            SystemEvent::ParticipantSignOn
+        } else if code == self.participant_sign_off {
+            SystemEvent::ParticipantSignOff
+        } else if code == self.ad_hoc {
+            SystemEvent::AdHoc
+        } else {
+            SystemEvent::Other(code.to_string())
+        }
+    }
+
+    pub fn code<'a>(&'a self, event: &'a SystemEvent) -> &'a str {
+        match event {
+            SystemEvent::ServiceOpen => &self.service_open,
+            SystemEvent::ServiceClose => &self.service_close,
+            SystemEvent::BusinessDayChange => &self.business_day_change,
+            SystemEvent::ParticipantSignOn => &self.participant_sign_on,
+            SystemEvent::ParticipantSignOff => &self.participant_sign_off,
+            SystemEvent::AdHoc => &self.ad_hoc,
+            SystemEvent::Other(code) => code,
+        }
+    }
+}
+
+/// An admi.004 `EvtInf`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EventRecord {
+    pub code: String,
+    pub parameters: Vec<String>,
+    pub description: Option<String>,
+    /// `EvtTm`, ISODateTime.
+    pub time: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ServiceStatus {
+    /// No open or close event seen yet.
+    #[default]
+    Unknown,
+    Open,
+    Closed,
+}
+
+/// Business days: weekdays other than the holidays.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BusinessDayCalendar {
+    holidays: BTreeSet<String>,
+}
+
+impl BusinessDayCalendar {
+    pub fn new() -> Self {
+        BusinessDayCalendar::default()
+    }
+
+    /// Calendar with the given holidays (ISODate).
+    pub fn with_holidays<'a>(holidays: impl IntoIterator<Item = &'a str>) -> Self {
+        BusinessDayCalendar { holidays: holidays.into_iter().map(|d| d.to_string()).collect() }
+    }
+
+    pub fn add_holiday(&mut self, date: &str) {
+        self.holidays.insert(date.to_string());
+    }
+
+    pub fn is_business_day(&self, date: &str) -> bool {
+        weekday(date).is_some_and(|d| d <= 5) && !self.holidays.contains(date)
+    }
+
+    /// The first business day after `date`.
+    pub fn next_business_day(&self, date: &str) -> Option<String> {
+        let mut next = add_days(date, 1)?;
+        while !self.is_business_day(&next) {
+            next = add_days(&next, 1)?;
+        }
+        Some(next)
+    }
+
+    /// Business day of `date`: the date itself, or the next business day
+    /// for weekends and holidays.
+    pub fn business_day_of(&self, date: &str) -> Option<String> {
+        if self.is_business_day(date) { Some(date.to_string()) } else { self.next_business_day(date) }
+    }
+}
+
+/// Business day and service status built from system events.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BusinessDayTracker {
+    codes: EventCodes,
+    calendar: BusinessDayCalendar,
+    business_day: Option<String>,
+    status: ServiceStatus,
+    last_event_time: Option<String>,
+    signed_off: BTreeSet<String>,
+}
+
+fn parameter<'a>(event: &'a EventRecord, what: &str) -> Result<&'a str, ValidationError> {
+    event.parameters.first().map(|p| p.trim()).filter(|p| !p.is_empty()).ok_or_else(|| ValidationError::new(ERR_EVENT_PARAMETER, format!(
+        "event {} has no {} parameter", event.code, what
+    )))
+}
+
+impl BusinessDayTracker {
+    pub fn new(codes: EventCodes, calendar: BusinessDayCalendar) -> Self {
+        BusinessDayTracker { codes, calendar, ..Default::default() }
+    }
+
+    pub fn codes(&self) -> &EventCodes {
+        &self.codes
+    }
+
+    pub fn calendar(&self) -> &BusinessDayCalendar {
+        &self.calendar
+    }
+
+    /// The current business day, once a business day change was seen.
+    pub fn business_day(&self) -> Option<&str> {
+        self.business_day.as_deref()
+    }
+
+    /// The business day expected after the current one.
+    pub fn expected_next_business_day(&self) -> Option<String> {
+        self.calendar.next_business_day(self.business_day.as_deref()?)
+    }
+
+    pub fn status(&self) -> ServiceStatus {
+        self.status
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.status == ServiceStatus::Open
+    }
+
+    /// Whether a participant, by routing number, has signed off.
+    pub fn is_signed_off(&self, participant: &str) -> bool {
+        self.signed_off.contains(participant)
+    }
+
+    pub fn signed_off(&self) -> impl Iterator<Item = &str> {
+        self.signed_off.iter().map(String::as_str)
+    }
+
+    /// Applies an event and returns what it announced. Events older than
+    /// the last one applied, and business days earlier than the current
+    /// one, are refused and change nothing. A repeated business day change
+    /// is accepted.
+    pub fn apply(&mut self, event: &EventRecord) -> Result<SystemEvent, ValidationError> {
+        let time = event.time.as_deref().and_then(iso_datetime_to_seconds);
+        let last = self.last_event_time.as_deref().and_then(iso_datetime_to_seconds);
+        if let (Some(time), Some(last)) = (time, last) {
+            if time < last {
+                return Err(ValidationError::new(ERR_EVENT_ORDER, format!(
+                    "event {} at {} is older than the last event at {}",
+                    event.code, event.time.as_deref().unwrap_or_default(), self.last_event_time.as_deref().unwrap_or_default()
+                )));
+            }
+        }
+        let kind = self.codes.event(&event.code);
+        match kind {
+            SystemEvent::ServiceOpen => self.status = ServiceStatus::Open,
+            SystemEvent::ServiceClose => self.status = ServiceStatus::Closed,
+            SystemEvent::BusinessDayChange => {
+                let day = parameter(event, "business day")?;
+                if parse_iso_date(day).is_none() {
+                    return Err(ValidationError::new(ERR_EVENT_PARAMETER, format!("business day {} of event {} is not an ISODate", day, event.code)));
+                }
+                if let Some(current) = &self.business_day {
+                    if day < current.as_str() {
+                        return Err(ValidationError::new(ERR_BUSINESS_DAY_ORDER, format!(
+                            "business day {} is earlier than the current business day {}", day, current
+                        )));
+                    }
+                }
+                self.business_day = Some(day.to_string());
+            }
+            SystemEvent::ParticipantSignOn => {
+                let participant = parameter(event, "participant")?;
+                self.signed_off.remove(participant);
+            }
+            SystemEvent::ParticipantSignOff => {
+                let participant = parameter(event, "participant")?.to_string();
+                self.signed_off.insert(participant);
+            }
+            SystemEvent::AdHoc | SystemEvent::Other(_) => {}
+        }
+        if time.is_some() {
+            self.last_event_time = event.time.clone();
+        }
+        Ok(kind)
+    }
+}
@@ -0,0 +1,320 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Reading and writing values of any generated message by ISO tag path, e.g.
+// `CdtTrfTxInf[*]/CdtrAgt/FinInstnId/ClrSysMmbId/MmbId`.
+//
+// A path is a list of steps separated by `/`. A step is an XML tag
+// (`GrpHdr`), an attribute (`@Ccy`) or `*` for any child element, followed
+// by an optional repetition: `[n]` counting from 1, or `[*]` for all of
+// them. A step without repetition also matches all repetitions, so
+// `CdtTrfTxInf/PmtId/EndToEndId` reads the `EndToEndId` of every
+// transaction. Paths are relative to the value they are applied to: the
+// fields of a message start at its top level elements (`GrpHdr`), those of
+// a `Document` at the message element (`FIToFICstmrCdtTrf/GrpHdr`).
+//
+// `TagPaths` is implemented for every generated type by
+// generate-tag-path.py (`tag_path_impls.rs` of each crate, feature
+// `derive_default`), which lists the tag of each field, and for the text
+// values they hold. The values are read as their own type: a `String`, an
+// `f64`, a generated code enum or a whole generated struct. An element with
+// text and attributes, like an amount with its `Ccy`, reads as its text
+// unless the requested type is that of the whole element, and a code reads
+// as a `String` too.
+
+use crate::ValidationError;
+use std::any::{type_name, Any};
+use std::fmt;
+
+pub const ERR_PATH_SYNTAX: u32 = 3301;
+pub const ERR_PATH_NOT_FOUND: u32 = 3302;
+pub const ERR_PATH_TYPE: u32 = 3303;
+pub const ERR_PATH_UPDATE: u32 = 3304;
+
+/// Tag of the text of an element with attributes.
+pub const TEXT: &str = "$value";
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Step {
+    /// Tag or `@` and attribute name, `None` for `*`.
+    pub tag: Option<String>,
+    /// Repetition counting from 1, `None` for all of them.
+    pub index: Option<usize>,
+}
+
+impl Step {
+    fn text() -> Step {
+        Step { tag: Some(TEXT.to_string()), index: None }
+    }
+
+    /// Whether the step names the element or attribute `tag`. `*` names
+    /// every element, but not attributes or text.
+    pub fn matches(&self, tag: &str) -> bool {
+        match &self.tag {
+            Some(step_tag) => step_tag == tag,
+            None => !tag.starts_with(['@', '$']),
+        }
+    }
+
+    /// Whether the step selects the first repetition.
+    pub fn first(&self) -> bool {
+        self.index.is_none_or(|n| n == 1)
+    }
+
+    /// Whether a missing element named by the step is added on the way to
+    /// a value being set.
+    fn creates(&self, create: bool) -> bool {
+        create && self.tag.is_some() && self.first()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TagPath {
+    pub steps: Vec<Step>,
+}
+
+impl TagPath {
+    pub fn parse(path: &str) -> Result<Self, ValidationError> {
+        let syntax = |reason: &str| ValidationError::new(ERR_PATH_SYNTAX, format!("invalid path {}: {}", path, reason));
+        let mut steps = vec![];
+        for step in path.trim_start_matches('/').split('/') {
+            let (tag, index) = match step.split_once('[') {
+                Some((tag, rest)) => {
+                    let index = rest.strip_suffix(']').ok_or_else(|| syntax("unclosed ["))?;
+                    let index = match index {
+                        "*" => None,
+                        n => match n.parse::<usize>() {
+                            Ok(n) if n > 0 => Some(n),
+                            _ => return Err(syntax("repetitions are counted from 1")),
+                        },
+                    };
+                    (tag, index)
+                }
+                None => (step, None),
+            };
+            let name = tag.trim_start_matches('@');
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '*') || (name.contains('*') && tag != "*") {
+                return Err(syntax(&format!("invalid step {}", step)));
+            }
+            steps.push(Step { tag: if tag == "*" { None } else { Some(tag.to_string()) }, index });
+        }
+        Ok(TagPath { steps })
+    }
+}
+
+impl fmt::Display for TagPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, step) in self.steps.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+            write!(f, "{}", step.tag.as_deref().unwrap_or("*"))?;
+            if let Some(index) = step.index {
+                write!(f, "[{}]", index)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Path based access to the fields of a message.
+pub trait TagPaths: Any {
+    /// Appends the children matching `step`, one per repetition.
+    fn children<'a>(&'a self, _step: &Step, _out: &mut Vec<&'a dyn TagPaths>) {}
+
+    /// Appends the children matching `step` for an update. With `create`, a
+    /// missing optional element named by the step is added when the step
+    /// selects at most its first repetition.
+    fn children_mut<'a>(&'a mut self, _step: &Step, _create: bool, _out: &mut Vec<&'a mut dyn TagPaths>) {}
+
+    /// The code of a code enum.
+    fn code(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Sets a code enum from its code; false for other types and unknown
+    /// codes.
+    fn set_code(&mut self, _code: &str) -> bool {
+        false
+    }
+
+    /// All values at `path`, in document order.
+    fn query<T: Clone + 'static>(&self, path: &str) -> Result<Vec<T>, ValidationError> where Self: Sized {
+        let tag_path = TagPath::parse(path)?;
+        select(self, &tag_path).into_iter().map(|node| read(node, path)).collect()
+    }
+
+    /// The first value at `path`, if any.
+    fn query_first<T: Clone + 'static>(&self, path: &str) -> Result<Option<T>, ValidationError> where Self: Sized {
+        let tag_path = TagPath::parse(path)?;
+        select(self, &tag_path).first().map(|node| read(*node, path)).transpose()
+    }
+
+    /// The value at `path`; an error if there is none.
+    fn get<T: Clone + 'static>(&self, path: &str) -> Result<T, ValidationError> where Self: Sized {
+        self.query_first(path)?
+            .ok_or_else(|| ValidationError::new(ERR_PATH_NOT_FOUND, format!("{} not found", path)))
+    }
+
+    /// Sets every value at `path` and returns how many were set. The
+    /// message is left unchanged if nothing is found or if the new value
+    /// does not fit the message.
+    fn set<T: Clone + 'static>(&mut self, path: &str, new_value: &T) -> Result<usize, ValidationError> where Self: Sized + Clone {
+        let tag_path = TagPath::parse(path)?;
+        let mut updated = self.clone();
+        match assign(&mut updated, &tag_path.steps, new_value) {
+            Some(0) => Err(ValidationError::new(ERR_PATH_NOT_FOUND, format!("{} not found", path))),
+            Some(count) => {
+                *self = updated;
+                Ok(count)
+            }
+            None => Err(ValidationError::new(ERR_PATH_UPDATE, format!("{} cannot be set to a {}", path, type_name::<T>()))),
+        }
+    }
+}
+
+macro_rules! text_values {
+    ($($t:ty),*) => { $(impl TagPaths for $t {})* };
+}
+
+text_values!(String, bool, f64, f32, u64, u32, i64, i32, u8);
+
+/// Values at `path` below a node.
+pub fn select<'a>(node: &'a dyn TagPaths, path: &TagPath) -> Vec<&'a dyn TagPaths> {
+    path.steps.iter().fold(vec![node], |nodes, step| {
+        let mut children = vec![];
+        for node in nodes {
+            node.children(step, &mut children);
+        }
+        children
+    })
+}
+
+/// Sets the values at `steps` below a node, adding missing optional
+/// elements on the way, and returns how many were set; `None` if one of
+/// them does not take the new value.
+fn assign<T: Clone + 'static>(node: &mut dyn TagPaths, steps: &[Step], new_value: &T) -> Option<usize> {
+    let Some((step, rest)) = steps.split_first() else {
+        return write(node, new_value).then_some(1);
+    };
+    let mut children = vec![];
+    node.children_mut(step, true, &mut children);
+    children.into_iter().map(|child| assign(child, rest, new_value)).sum()
+}
+
+fn read<T: Clone + 'static>(node: &dyn TagPaths, path: &str) -> Result<T, ValidationError> {
+    let any: &dyn Any = node;
+    if let Some(value) = any.downcast_ref::<T>() {
+        return Ok(value.clone());
+    }
+    let mut text = vec![];
+    node.children(&Step::text(), &mut text);
+    if let Some(value) = text.first().and_then(|text| (*text as &dyn Any).downcast_ref::<T>()) {
+        return Ok(value.clone());
+    }
+    let code = node.code().map(|code| code.to_string());
+    if let Some(value) = code.as_ref().and_then(|code| (code as &dyn Any).downcast_ref::<T>()) {
+        return Ok(value.clone());
+    }
+    Err(ValidationError::new(ERR_PATH_TYPE, format!("{} is not a {}", path, type_name::<T>())))
+}
+
+fn write<T: Clone + 'static>(node: &mut dyn TagPaths, new_value: &T) -> bool {
+    if let Some(value) = (node as &mut dyn Any).downcast_mut::<T>() {
+        *value = new_value.clone();
+        return true;
+    }
+    {
+        let mut text = vec![];
+        node.children_mut(&Step::text(), false, &mut text);
+        if let Some(value) = text.into_iter().next().and_then(|text| (text as &mut dyn Any).downcast_mut::<T>()) {
+            *value = new_value.clone();
+            return true;
+        }
+    }
+    match (new_value as &dyn Any).downcast_ref::<String>() {
+        Some(code) => node.set_code(code),
+        None => false,
+    }
+}
+
+/// Children of an element field.
+pub fn one<'a, T: TagPaths>(value: &'a T, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+    if step.first() {
+        out.push(value);
+    }
+}
+
+/// Children of an optional element field.
+pub fn optional<'a, T: TagPaths>(value: &'a Option<T>, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+    if let Some(value) = value {
+        one(value, step, out);
+    }
+}
+
+/// Children of a repeated element field.
+pub fn repeated<'a, T: TagPaths>(values: &'a [T], step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+    match step.index {
+        Some(n) => out.extend(values.get(n - 1).map(|value| value as &dyn TagPaths)),
+        None => out.extend(values.iter().map(|value| value as &dyn TagPaths)),
+    }
+}
+
+/// Children of an optional repeated element field.
+pub fn optional_repeated<'a, T: TagPaths>(values: &'a Option<Vec<T>>, step: &Step, out: &mut Vec<&'a dyn TagPaths>) {
+    if let Some(values) = values {
+        repeated(values, step, out);
+    }
+}
+
+pub fn one_mut<'a, T: TagPaths>(value: &'a mut T, step: &Step, out: &mut Vec<&'a mut dyn TagPaths>) {
+    if step.first() {
+        out.push(value);
+    }
+}
+
+pub fn optional_mut<'a, T: TagPaths + Default>(value: &'a mut Option<T>, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+    if value.is_none() && step.creates(create) {
+        *value = Some(T::default());
+    }
+    if let Some(value) = value {
+        one_mut(value, step, out);
+    }
+}
+
+pub fn repeated_mut<'a, T: TagPaths>(values: &'a mut [T], step: &Step, out: &mut Vec<&'a mut dyn TagPaths>) {
+    match step.index {
+        Some(n) => out.extend(values.get_mut(n - 1).map(|value| value as &mut dyn TagPaths)),
+        None => out.extend(values.iter_mut().map(|value| value as &mut dyn TagPaths)),
+    }
+}
+
+pub fn optional_repeated_mut<'a, T: TagPaths + Default>(values: &'a mut Option<Vec<T>>, step: &Step, create: bool, out: &mut Vec<&'a mut dyn TagPaths>) {
+    if values.is_none() && step.creates(create) {
+        *values = Some(vec![T::default()]);
+    }
+    if let Some(values) = values {
+        repeated_mut(values, step, out);
+    }
+}
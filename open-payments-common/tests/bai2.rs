@@ -0,0 +1,92 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// BAI2 files: continuation records, control totals and record counts, and
+// round trips through `to_bai2`.
+
+use open_payments_common::bai2::{Bai2File, FundsType, ERR_CONTROL_TOTAL, ERR_RECORD_STRUCTURE};
+
+const FILE: &str = "01,SENDR1,RECVR1,240115,0800,FILE1,80,10,2/
+02,RECVR1,SENDR1,1,240114,2400,USD,2/
+03,123456789,USD,010,500000,,/
+88,015,750000,,/
+16,165,150000,0,BANKREF1,CUSTREF1,/
+88,INCOMING WIRE FROM ACME
+88,INVOICE 2024-001
+16,475,50000,V,240116,,BANKREF2,,CHECK 1001/A
+49,1450000,7/
+98,1450000,1,9/
+99,1450000,1,11/
+";
+
+#[test]
+fn continuation_records_are_merged() {
+    let file = Bai2File::parse(FILE).unwrap();
+    let account = &file.groups[0].accounts[0];
+    assert_eq!(account.summaries.len(), 2);
+    assert_eq!(account.summaries[1].type_code, "015");
+    assert_eq!(account.summaries[1].amount, Some(750000));
+    assert_eq!(account.transactions[0].text.as_deref(), Some("INCOMING WIRE FROM ACME INVOICE 2024-001"));
+    assert_eq!(account.transactions[1].text.as_deref(), Some("CHECK 1001/A"));
+    assert_eq!(account.transactions[1].funds_type, FundsType::ValueDated { date: "2024-01-16".to_string(), time: None });
+    assert_eq!(file.groups[0].as_of_time.as_deref(), Some("23:59"));
+}
+
+#[test]
+fn control_totals_and_record_counts() {
+    let file = Bai2File::parse(FILE).unwrap();
+    let account = &file.groups[0].accounts[0];
+    assert_eq!(account.control_total, account.computed_control_total());
+    assert_eq!(account.number_of_records, 7);
+    assert_eq!(file.groups[0].number_of_records, 9);
+    assert_eq!(file.number_of_records, 11);
+
+    for (from, to) in [("49,1450000,7/", "49,1450001,7/"), ("49,1450000,7/", "49,1450000,6/"), ("98,1450000,1,9/", "98,1450000,2,9/"), ("99,1450000,1,11/", "99,1450000,1,10/")] {
+        assert_eq!(Bai2File::parse(&FILE.replace(from, to)).unwrap_err().code, ERR_CONTROL_TOTAL, "{}", to);
+    }
+    assert_eq!(Bai2File::parse(&FILE.replace("99,1450000,1,11/\n", "")).unwrap_err().code, ERR_RECORD_STRUCTURE);
+}
+
+#[test]
+fn round_trip() {
+    let mut file = Bai2File::parse(FILE).unwrap();
+    // Continuations are not written, so the record counts change.
+    file.update_control_totals();
+    assert_eq!(file.groups[0].accounts[0].number_of_records, 4);
+    assert_eq!(file.number_of_records, 8);
+    let written = file.to_bai2();
+    assert!(written.lines().all(|l| !l.starts_with("88")));
+    assert_eq!(Bai2File::parse(&written).unwrap(), file);
+}
+
+#[test]
+fn detail_without_text_is_written_with_a_slash() {
+    let mut file = Bai2File::parse(FILE).unwrap();
+    file.groups[0].accounts[0].transactions[0].text = None;
+    file.update_control_totals();
+    let written = file.to_bai2();
+    assert!(written.contains("16,165,150000,0,BANKREF1,CUSTREF1,/\n"));
+    assert_eq!(Bai2File::parse(&written).unwrap().groups[0].accounts[0].transactions[0].text, None);
+}
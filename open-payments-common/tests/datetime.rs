@@ -0,0 +1,51 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Parsing of ISODate and ISODateTime values, including input that is not
+// ASCII, which must be rejected rather than sliced inside a character.
+
+use open_payments_common::datetime::{iso_datetime_to_seconds, parse_iso_date, seconds_to_iso_datetime};
+
+#[test]
+fn dates_and_times_parse() {
+    assert_eq!(parse_iso_date("2024-02-29"), Some((2024, 2, 29)));
+    assert_eq!(parse_iso_date("2023-02-29"), None);
+    assert_eq!(iso_datetime_to_seconds("2024-05-01"), Some(1_714_521_600));
+    assert_eq!(iso_datetime_to_seconds("2024-05-01T10:15:30.123-04:00").map(seconds_to_iso_datetime).as_deref(), Some("2024-05-01T14:15:30Z"));
+}
+
+#[test]
+fn non_ascii_input_is_rejected() {
+    for value in ["2024-01-1é", "2024-é1-01", "€024-01-01", "2024-01-01Té0:00:00", "2024-01-01T10:00:0é", "2024-01-01T10:00:00+0é:00", "2024-01-01é"] {
+        assert_eq!(iso_datetime_to_seconds(value), None, "{}", value);
+    }
+    assert_eq!(parse_iso_date("2024-01-1é"), None);
+}
+
+#[test]
+fn truncated_times_are_rejected() {
+    for value in ["2024-01-01T", "2024-01-01T10:00", "2024-01-01T10:00:00+04"] {
+        assert_eq!(iso_datetime_to_seconds(value), None, "{}", value);
+    }
+}
@@ -0,0 +1,101 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Differences between two versions of a credit transfer: added, removed and
+// modified elements with their tag paths, and what `ignore_volatile` and
+// `exclude` leave out.
+
+use open_payments_common::diff::{diff, diff_json, ChangeKind, DiffOptions};
+use serde_json::{json, Value};
+
+fn original() -> Value {
+    json!({
+        "AppHdr": {"BizMsgIdr": "MSG-1", "CreDt": "2024-05-01T10:15:30Z"},
+        "Document": {"FIToFICstmrCdtTrf": {
+            "GrpHdr": {"MsgId": "MSG-1", "CreDtTm": "2024-05-01T10:15:30Z", "NbOfTxs": "1", "CtrlSum": 10},
+            "CdtTrfTxInf": [{
+                "PmtId": {"EndToEndId": "E2E-1"},
+                "IntrBkSttlmAmt": {"@Ccy": "USD", "$value": 10},
+                "RmtInf": {"Ustrd": ["Invoice 42"]}
+            }]
+        }}
+    })
+}
+
+fn resend() -> Value {
+    json!({
+        "AppHdr": {"BizMsgIdr": "MSG-1", "CreDt": "2024-05-01T10:20:00Z", "PssblDplct": true},
+        "Document": {"FIToFICstmrCdtTrf": {
+            "GrpHdr": {"MsgId": "MSG-1", "CreDtTm": "2024-05-01T10:20:00Z", "NbOfTxs": "2", "CtrlSum": 10.0},
+            "CdtTrfTxInf": [
+                {
+                    "PmtId": {"EndToEndId": "E2E-1"},
+                    "IntrBkSttlmAmt": {"@Ccy": "CAD", "$value": 10.0}
+                },
+                {"PmtId": {"EndToEndId": "E2E-2"}}
+            ]
+        }}
+    })
+}
+
+#[test]
+fn changes_between_two_messages() {
+    let changes = diff_json(&original(), &resend(), &DiffOptions::default());
+    assert_eq!(changes.to_string(), [
+        "~ /AppHdr/CreDt: \"2024-05-01T10:15:30Z\" -> \"2024-05-01T10:20:00Z\"",
+        "+ /AppHdr/PssblDplct: true",
+        "~ /Document/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/IntrBkSttlmAmt/@Ccy: \"USD\" -> \"CAD\"",
+        "- /Document/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/RmtInf: {\"Ustrd\":[\"Invoice 42\"]}",
+        "+ /Document/FIToFICstmrCdtTrf/CdtTrfTxInf[2]: {\"PmtId\":{\"EndToEndId\":\"E2E-2\"}}",
+        "~ /Document/FIToFICstmrCdtTrf/GrpHdr/CreDtTm: \"2024-05-01T10:15:30Z\" -> \"2024-05-01T10:20:00Z\"",
+        "~ /Document/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs: \"1\" -> \"2\"",
+        "",
+    ].join("\n"));
+    let kinds: Vec<ChangeKind> = changes.changes.iter().map(|c| c.kind).collect();
+    assert_eq!(kinds[..3], [ChangeKind::Modified, ChangeKind::Added, ChangeKind::Modified]);
+}
+
+#[test]
+fn volatile_and_excluded_elements_are_left_out() {
+    let options = DiffOptions::default().ignore_volatile().exclude("/Document/FIToFICstmrCdtTrf/CdtTrfTxInf").exclude("NbOfTxs");
+    assert!(diff_json(&original(), &resend(), &options).is_empty());
+
+    let options = DiffOptions::default().ignore_volatile().exclude("/Document/FIToFICstmrCdtTrf/CdtTrfTxInf/RmtInf");
+    let paths: Vec<String> = diff_json(&original(), &resend(), &options).changes.into_iter().map(|c| c.path).collect();
+    assert_eq!(paths, [
+        "/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/IntrBkSttlmAmt/@Ccy",
+        "/Document/FIToFICstmrCdtTrf/CdtTrfTxInf[2]",
+        "/Document/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs",
+    ]);
+}
+
+#[test]
+fn changes_as_json() {
+    let options = DiffOptions::default().ignore_volatile().exclude("CdtTrfTxInf");
+    let changes = diff(&original(), &resend(), &options).unwrap();
+    assert_eq!(changes.to_json(), json!([
+        {"op": "modified", "path": "/Document/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs", "old": "1", "new": "2"},
+    ]));
+    assert!(diff(&original(), &original(), &DiffOptions::default()).unwrap().is_empty());
+}
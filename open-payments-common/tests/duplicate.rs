@@ -0,0 +1,111 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Duplicate classification of messages by their keys: new, resend,
+// possible duplicate and conflicting duplicate, and transaction keys kept
+// apart by message definition.
+
+use open_payments_common::duplicate::{Classification, DuplicateConfig, DuplicateDetector, DuplicateKey, MemoryStore, MessageKeys, ERR_CONFLICTING_DUPLICATE, ERR_DUPLICATE};
+
+const UETR: &str = "8a562c67-ca16-48ba-b074-65581be6f011";
+
+fn credit_transfer(biz_msg_idr: &str, content_hash: &str) -> MessageKeys {
+    MessageKeys {
+        msg_def_idr: Some("pacs.008.001.08".to_string()),
+        biz_msg_idr: Some(biz_msg_idr.to_string()),
+        msg_id: Some(biz_msg_idr.to_string()),
+        uetrs: vec![UETR.to_string()],
+        end_to_end_ids: vec!["E2E-1".to_string()],
+        content_hash: content_hash.to_string(),
+        possible_duplicate: false,
+    }
+}
+
+fn detector_with(message: &MessageKeys) -> DuplicateDetector {
+    let mut detector = DuplicateDetector::new();
+    assert_eq!(detector.check(message).classification, Classification::New);
+    detector
+}
+
+#[test]
+fn first_message_is_new_and_remembered() {
+    let mut detector = DuplicateDetector::new();
+    let verdict = detector.check(&credit_transfer("M1", "h1"));
+    assert_eq!(verdict.classification, Classification::New);
+    assert!(verdict.matches.is_empty());
+    assert!(verdict.to_error().is_none());
+    assert_eq!(detector.store().len(), 4);
+    assert_eq!(detector.check(&credit_transfer("M2", "h2")).classification, Classification::ConflictingDuplicate);
+}
+
+#[test]
+fn same_content_marked_as_duplicate_is_a_resend() {
+    let detector = detector_with(&credit_transfer("M1", "h1"));
+    let resend = MessageKeys { possible_duplicate: true, ..credit_transfer("M1", "h1") };
+    let verdict = detector.classify(&resend);
+    assert_eq!(verdict.classification, Classification::Resend);
+    assert_eq!(verdict.matches.len(), 4);
+    assert!(verdict.to_error().is_none());
+}
+
+#[test]
+fn same_content_not_marked_is_a_possible_duplicate() {
+    let detector = detector_with(&credit_transfer("M1", "h1"));
+    let verdict = detector.classify(&credit_transfer("M1", "h1"));
+    assert_eq!(verdict.classification, Classification::PossibleDuplicate);
+    let error = verdict.to_error().unwrap();
+    assert_eq!(error.code, ERR_DUPLICATE);
+    assert_eq!(error.message, "BizMsgIdr M1 was already received in M1 with the same content");
+}
+
+#[test]
+fn different_content_is_a_conflicting_duplicate() {
+    let detector = detector_with(&credit_transfer("M1", "h1"));
+    let other = MessageKeys { uetrs: vec![UETR.to_ascii_uppercase()], ..credit_transfer("M2", "h2") };
+    let verdict = detector.classify(&other);
+    assert_eq!(verdict.classification, Classification::ConflictingDuplicate);
+    assert_eq!(verdict.matches.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>(), vec![
+        DuplicateKey::Uetr { msg_def_idr: "pacs.008.001.08".to_string(), uetr: UETR.to_string() },
+        DuplicateKey::EndToEndId { msg_def_idr: "pacs.008.001.08".to_string(), end_to_end_id: "E2E-1".to_string() },
+    ]);
+    let error = verdict.to_error().unwrap();
+    assert_eq!(error.code, ERR_CONFLICTING_DUPLICATE);
+    assert_eq!(error.message, format!("UETR {} of pacs.008.001.08 was already received in M1 with different content", UETR));
+}
+
+#[test]
+fn report_of_a_payment_is_not_a_duplicate_of_it() {
+    let detector = detector_with(&credit_transfer("M1", "h1"));
+    let notification = MessageKeys { msg_def_idr: Some("camt.054.001.08".to_string()), ..credit_transfer("N1", "h2") };
+    assert_eq!(detector.classify(&notification).classification, Classification::New);
+}
+
+#[test]
+fn end_to_end_ids_can_be_left_out() {
+    let config = DuplicateConfig { end_to_end_id: false, ..Default::default() };
+    let mut detector = DuplicateDetector::with_store(MemoryStore::default(), config);
+    detector.check(&credit_transfer("M1", "h1"));
+    let other = MessageKeys { uetrs: vec![], ..credit_transfer("M2", "h2") };
+    assert_eq!(detector.classify(&other).classification, Classification::New);
+}
@@ -0,0 +1,96 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// Fedwire FAIM messages: tag structure, fixed length elements, `*`
+// terminated elements cut to their maximum length, and round trips through
+// `to_faim`.
+
+use open_payments_common::fedwire::{FaimMessage, FaimParty, FundsTransfer, Imad, ERR_FIELD_SYNTAX, ERR_MISSING_TAG, ERR_TAG_STRUCTURE};
+
+const CTR: &str = "{1500}30USERREQ1P {1510}1000{1520}20240115QMGFT015000001{2000}000000150000\
+{3100}011000015BANK OF BOSTON*{3320}SENDERREF01*{3400}021000021JPMORGAN CHASE*{3600}CTR\
+{3710}USD1500,*{4200}D123456789*ACME CORP*1 MAIN STREET*NEW YORK NY*\
+{5000}D987654321*JOHN DOE*2 ELM STREET*BOSTON MA*{6000}INVOICE 2024-001*\
+{8200}UNMODELLED TAG*";
+
+#[test]
+fn round_trip() {
+    let transfer = FundsTransfer::parse(CTR).unwrap();
+    assert_eq!(transfer.amount, 150000);
+    assert_eq!(transfer.imad, Imad { cycle_date: "2024-01-15".to_string(), source: "QMGFT015".to_string(), sequence_number: "000001".to_string() });
+    assert_eq!(transfer.sender.routing_number, "011000015");
+    assert_eq!(transfer.sender_reference.as_deref(), Some("SENDERREF01"));
+    assert_eq!(
+        transfer.beneficiary,
+        Some(FaimParty {
+            id_code: Some('D'),
+            identifier: Some("123456789".to_string()),
+            name: Some("ACME CORP".to_string()),
+            address: vec!["1 MAIN STREET".to_string(), "NEW YORK NY".to_string()],
+        })
+    );
+    assert_eq!(transfer.other_tags.len(), 1);
+    assert_eq!(transfer.to_faim(), CTR);
+    assert_eq!(FundsTransfer::parse(&transfer.to_faim()).unwrap(), transfer);
+}
+
+#[test]
+fn tags_are_written_in_order_with_fixed_lengths() {
+    let mut transfer = FundsTransfer::parse(CTR).unwrap();
+    transfer.amount = 7;
+    transfer.user_request_correlation = "ABC".to_string();
+    transfer.message_duplication_code = 'P';
+    let message = transfer.to_faim_message();
+    assert_eq!(message.get("2000"), Some("000000000007"));
+    assert_eq!(message.get("1500"), Some("30ABC     PP"));
+    let written = transfer.to_faim();
+    let tags: Vec<&str> = written.split('{').skip(1).map(|t| &t[..4]).collect();
+    let mut sorted = tags.clone();
+    sorted.sort();
+    assert_eq!(tags, sorted);
+}
+
+#[test]
+fn elements_are_cut_to_their_maximum_length() {
+    let mut transfer = FundsTransfer::parse(CTR).unwrap();
+    let beneficiary = transfer.beneficiary.as_mut().unwrap();
+    beneficiary.name = Some("A VERY LONG BENEFICIARY NAME THAT DOES NOT FIT".to_string());
+    beneficiary.address = vec!["1".to_string(), "2".to_string(), "3".to_string(), "4".to_string()];
+    transfer.sender_reference = Some("A*REFERENCE{LONGER}THAN SIXTEEN".to_string());
+    let message = transfer.to_faim_message();
+    assert_eq!(message.get("4200"), Some("D123456789*A VERY LONG BENEFICIARY NAME THAT D*1*2*3*"));
+    assert_eq!(message.get("3320"), Some("A REFERENCE LONG*"));
+}
+
+#[test]
+fn malformed_messages_are_rejected() {
+    assert_eq!(FaimMessage::parse("1500}30").unwrap_err().code, ERR_TAG_STRUCTURE);
+    assert_eq!(FaimMessage::parse(&format!("{}{{2000}}000000000001", CTR)).unwrap_err().code, ERR_TAG_STRUCTURE);
+    assert_eq!(FaimMessage::parse("{15A0}30").unwrap_err().code, ERR_TAG_STRUCTURE);
+    assert_eq!(FundsTransfer::parse(&CTR.replace("{3400}021000021JPMORGAN CHASE*", "")).unwrap_err().code, ERR_MISSING_TAG);
+    assert_eq!(FundsTransfer::parse(&CTR.replace("000000150000", "150000")).unwrap_err().code, ERR_FIELD_SYNTAX);
+    assert_eq!(FundsTransfer::parse(&CTR.replace("20240115QMGFT", "20241315QMGFT")).unwrap_err().code, ERR_FIELD_SYNTAX);
+    assert_eq!(FundsTransfer::parse(&CTR.replace("{1500}30", "{1500}29")).unwrap_err().code, ERR_FIELD_SYNTAX);
+}
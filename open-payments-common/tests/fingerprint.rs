@@ -0,0 +1,106 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// Fingerprints: SHA-256 against the FIPS 180-4 examples, FNV-1a, and a
+// canonical text and digest pinned for a known message, so that a change
+// to the canonical rules does not go unnoticed.
+
+use open_payments_common::fingerprint::{canonical_text, fingerprint, fnv1a64, sha256, Digest, FingerprintOptions, CANONICAL_VERSION};
+use serde_json::{json, Value};
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn payment() -> Value {
+    json!({
+        "FIToFICstmrCdtTrf": {
+            "GrpHdr": { "MsgId": " MSG-1 ", "CreDtTm": "2026-10-18T10:00:00", "NbOfTxs": "1" },
+            "CdtTrfTxInf": [
+                { "PmtId": { "EndToEndId": "E2E-1" }, "IntrBkSttlmAmt": { "@Ccy": "USD", "$value": 1500.50 } }
+            ]
+        }
+    })
+}
+
+const PAYMENT_CANONICAL_TEXT: &str = "MsgDefIdr=pacs.008.001.08
+/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/IntrBkSttlmAmt=1500.5
+/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/IntrBkSttlmAmt/@Ccy=USD
+/FIToFICstmrCdtTrf/CdtTrfTxInf[1]/PmtId/EndToEndId=E2E-1
+/FIToFICstmrCdtTrf/GrpHdr/CreDtTm=2026-10-18T10:00:00
+/FIToFICstmrCdtTrf/GrpHdr/MsgId=MSG-1
+/FIToFICstmrCdtTrf/GrpHdr/NbOfTxs=1
+";
+
+#[test]
+fn sha256_matches_the_fips_examples() {
+    assert_eq!(hex(&sha256(b"")), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    assert_eq!(hex(&sha256(b"abc")), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    assert_eq!(
+        hex(&sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+    );
+    assert_eq!(hex(&sha256(&vec![b'a'; 1_000_000])), "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0");
+}
+
+#[test]
+fn fnv1a64_matches_the_reference_values() {
+    assert_eq!(fnv1a64(b""), 0xcbf29ce484222325);
+    assert_eq!(fnv1a64(b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(fnv1a64(b"foobar"), 0x85944171f73967e8);
+}
+
+#[test]
+fn canonical_text_and_digest_are_pinned() {
+    let options = FingerprintOptions::default();
+    assert_eq!(canonical_text("pacs.008.001.08", &payment(), &options), PAYMENT_CANONICAL_TEXT);
+    let fingerprint = fingerprint("pacs.008.001.08", &payment(), &options);
+    assert_eq!(fingerprint.version, CANONICAL_VERSION);
+    assert_eq!(fingerprint.to_string(), "sha256:v1:8314550ccdaabdfdbadb10a7f6e20a9aac06cc374d5757c32d742345be260a31");
+}
+
+#[test]
+fn excluded_elements_leave_the_canonical_text() {
+    let options = FingerprintOptions::new(Digest::Sha256).exclude_volatile().exclude("/FIToFICstmrCdtTrf/CdtTrfTxInf/PmtId");
+    let text = canonical_text("pacs.008.001.08", &payment(), &options);
+    assert!(!text.contains("CreDtTm"));
+    assert!(!text.contains("EndToEndId"));
+    assert!(text.contains("/FIToFICstmrCdtTrf/GrpHdr/MsgId=MSG-1\n"));
+
+    let mut retransmitted = payment();
+    retransmitted["FIToFICstmrCdtTrf"]["GrpHdr"]["CreDtTm"] = json!("2026-10-19T08:30:00");
+    let volatile = FingerprintOptions::default().exclude_volatile();
+    assert_eq!(fingerprint("pacs.008.001.08", &payment(), &volatile), fingerprint("pacs.008.001.08", &retransmitted, &volatile));
+    assert_ne!(fingerprint("pacs.008.001.08", &payment(), &FingerprintOptions::default()), fingerprint("pacs.008.001.08", &retransmitted, &FingerprintOptions::default()));
+}
+
+#[test]
+fn line_breaks_in_values_are_escaped() {
+    let options = FingerprintOptions::default();
+    let multiline = json!({"Ustrd": {"A": "x\n/Ustrd/B=y", "C": "back\\slash\rend"}});
+    assert_eq!(canonical_text("pacs.008.001.08", &multiline, &options), "MsgDefIdr=pacs.008.001.08\n/Ustrd/A=x\\n/Ustrd/B=y\n/Ustrd/C=back\\\\slash\\rend\n");
+    let split = json!({"Ustrd": {"A": "x", "B": "y", "C": "back\\slash"}});
+    assert_ne!(fingerprint("pacs.008.001.08", &multiline, &options), fingerprint("pacs.008.001.08", &split, &options));
+}
@@ -0,0 +1,148 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// NACHA files: fixed width records, blocking with all-9 filler records,
+// batch and file control totals, and round trips through `to_nacha`.
+
+use open_payments_common::nacha::{
+    check_digit, Addenda, Batch, BatchHeader, EntryDetail, FileHeader, NachaFile, SecCode, BLOCKING_FACTOR, ERR_CONTROL_TOTAL, ERR_FIELD_SYNTAX, ERR_RECORD_STRUCTURE, RECORD_LENGTH,
+};
+
+fn entry(routing: &str, transaction_code: u8, amount: i64, trace: &str, addenda: &[&str]) -> EntryDetail {
+    EntryDetail {
+        transaction_code,
+        receiving_dfi_identification: routing[..8].to_string(),
+        check_digit: check_digit(routing).unwrap(),
+        dfi_account_number: "123456789".to_string(),
+        amount,
+        identification_number: "INV-0001".to_string(),
+        receiver_name: "ACME CORP".to_string(),
+        trace_number: trace.to_string(),
+        addenda: addenda.iter().map(|text| Addenda { payment_related_information: text.to_string(), ..Default::default() }).collect(),
+        ..Default::default()
+    }
+}
+
+fn sample() -> NachaFile {
+    let mut file = NachaFile {
+        header: FileHeader {
+            immediate_destination: "021000021".to_string(),
+            immediate_origin: "1234567890".to_string(),
+            creation_date: "2024-01-15".to_string(),
+            creation_time: Some("08:30".to_string()),
+            immediate_destination_name: "JPMORGAN CHASE".to_string(),
+            immediate_origin_name: "ACME PAYROLL".to_string(),
+            ..Default::default()
+        },
+        batches: vec![Batch {
+            header: BatchHeader {
+                service_class_code: 200,
+                company_name: "ACME PAYROLL".to_string(),
+                company_identification: "1234567890".to_string(),
+                sec_code: SecCode::Ccd,
+                company_entry_description: "PAYMENT".to_string(),
+                effective_entry_date: "2024-01-16".to_string(),
+                originator_status_code: '1',
+                originating_dfi_identification: "02100002".to_string(),
+                batch_number: 1,
+                ..Default::default()
+            },
+            entries: vec![
+                entry("021000021", 22, 150000, "021000020000001", &["RMR*IV*INV-0001*PI*1500.00\\"]),
+                entry("011000015", 32, 2500, "021000020000002", &[]),
+                entry("091000019", 27, 1000, "021000020000003", &[]),
+            ],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    file.update_control_totals();
+    file
+}
+
+#[test]
+fn file_id_modifier_defaults_to_a() {
+    assert_eq!(FileHeader::default().file_id_modifier, 'A');
+}
+
+#[test]
+fn records_are_94_characters_in_blocks_of_ten() {
+    let written = sample().to_nacha();
+    let records: Vec<&str> = written.lines().collect();
+    assert!(records.iter().all(|r| r.len() == RECORD_LENGTH));
+    assert_eq!(records.len() % BLOCKING_FACTOR, 0);
+    // 1, 5, three 6 with one 7, 8 and 9 make 8 records, padded to a block of 10.
+    assert_eq!(records.len(), 10);
+    assert!(records[8..].iter().all(|r| r.chars().all(|c| c == '9')));
+    assert!(records[0].starts_with("101 021000021123456789024011508"));
+    assert_eq!(&records[0][33..40], "A094101");
+    // Amounts are zero filled on the left, names blank filled on the right.
+    assert_eq!(&records[2][29..39], "0000150000");
+    assert_eq!(&records[2][54..76], "ACME CORP             ");
+    assert_eq!(&records[3][0..3], "705");
+    assert_eq!(&records[3][83..94], "00010000001");
+}
+
+#[test]
+fn control_totals() {
+    let file = sample();
+    let batch = &file.batches[0];
+    assert_eq!(batch.control.entry_addenda_count, 4);
+    assert_eq!(batch.control.entry_hash, 2100002 + 1100001 + 9100001);
+    assert_eq!(batch.control.total_credit, 152500);
+    assert_eq!(batch.control.total_debit, 1000);
+    assert_eq!(file.control.block_count, 1);
+    assert_eq!(file.control.entry_hash, batch.control.entry_hash);
+
+    let written = file.to_nacha();
+    let batch_control = written.lines().nth(6).unwrap();
+    assert!(batch_control.starts_with("8200000004"));
+    let wrong_hash = written.replacen(&batch_control[10..20], "0000000001", 1);
+    assert_eq!(NachaFile::parse(&wrong_hash).unwrap_err().code, ERR_CONTROL_TOTAL);
+    let file_control = written.lines().nth(7).unwrap();
+    let wrong_blocks = written.replace(file_control, &format!("9000001000002{}", &file_control[13..]));
+    assert_eq!(NachaFile::parse(&wrong_blocks).unwrap_err().code, ERR_CONTROL_TOTAL);
+}
+
+#[test]
+fn round_trip() {
+    let file = sample();
+    let written = file.to_nacha();
+    let parsed = NachaFile::parse(&written).unwrap();
+    assert_eq!(parsed, file);
+    assert_eq!(parsed.batches[0].entries[0].payment_related_information(), "RMR*IV*INV-0001*PI*1500.00\\");
+    assert_eq!(parsed.to_nacha(), written);
+    // Files without line breaks are cut into records.
+    assert_eq!(NachaFile::parse(&written.replace('\n', "")).unwrap(), file);
+}
+
+#[test]
+fn malformed_records_are_rejected() {
+    let written = sample().to_nacha();
+    let long = written.replacen('\n', " \n", 1);
+    assert_eq!(NachaFile::parse(&long).unwrap_err().code, ERR_RECORD_STRUCTURE);
+    let check_digit = written.replacen("622021000021", "622021000029", 1);
+    assert_eq!(NachaFile::parse(&check_digit).unwrap_err().code, ERR_FIELD_SYNTAX);
+}
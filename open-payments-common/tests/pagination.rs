@@ -0,0 +1,102 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Reassembly of paginated messages: pages out of order, twice or missing,
+// and cutting items into pages that reassemble to the original items.
+
+use open_payments_common::pagination::{page_number, page_ranges, PageAssembler, PageLimits, ERR_DUPLICATE_PAGE, ERR_MISSING_PAGE, ERR_PAGE_LIMIT, ERR_PAGE_NUMBER};
+
+#[test]
+fn pages_out_of_order_come_back_in_page_order() {
+    let mut assembler = PageAssembler::new();
+    assert_eq!(assembler.add("RPT-1", 3, true, "c").unwrap(), None);
+    assert_eq!(assembler.add("RPT-2", 1, true, "x").unwrap(), Some(vec!["x"]));
+    assert_eq!(assembler.add("RPT-1", 1, false, "a").unwrap(), None);
+    assert_eq!(assembler.missing_pages("RPT-1"), vec![2]);
+    assert_eq!(assembler.add("RPT-1", 2, false, "b").unwrap(), Some(vec!["a", "b", "c"]));
+    assert!(assembler.pending_keys().is_empty());
+}
+
+#[test]
+fn page_received_twice_is_rejected() {
+    let mut assembler = PageAssembler::new();
+    assembler.add("RPT-1", 1, false, "a").unwrap();
+    assert_eq!(assembler.add("RPT-1", 1, false, "a").unwrap_err().code, ERR_DUPLICATE_PAGE);
+    assert_eq!(assembler.add("RPT-1", 2, true, "b").unwrap(), Some(vec!["a", "b"]));
+}
+
+#[test]
+fn pages_around_the_last_page_are_rejected() {
+    let mut assembler = PageAssembler::new();
+    assembler.add("RPT-1", 2, true, "b").unwrap();
+    assert_eq!(assembler.add("RPT-1", 3, false, "c").unwrap_err().code, ERR_PAGE_NUMBER);
+    assert_eq!(assembler.add("RPT-1", 1, true, "a").unwrap_err().code, ERR_PAGE_NUMBER);
+    assert_eq!(assembler.add("RPT-1", 0, false, "z").unwrap_err().code, ERR_PAGE_NUMBER);
+    assert_eq!(assembler.pending_keys(), vec!["RPT-1"]);
+}
+
+#[test]
+fn missing_pages_are_reported() {
+    let mut assembler = PageAssembler::new();
+    assembler.add("RPT-1", 2, false, "b").unwrap();
+    assembler.add("RPT-2", 1, false, "x").unwrap();
+    assembler.add("RPT-2", 4, true, "z").unwrap();
+    let errors = assembler.check_complete();
+    assert_eq!(errors.iter().map(|e| e.code).collect::<Vec<_>>(), vec![ERR_MISSING_PAGE, ERR_MISSING_PAGE]);
+    assert_eq!(errors[0].message, "RPT-1: missing pages [1], last page not received");
+    assert_eq!(errors[1].message, "RPT-2: missing pages [2, 3]");
+    assert_eq!(assembler.discard("RPT-2"), Some(vec!["x", "z"]));
+    assert_eq!(assembler.pending_keys(), vec!["RPT-1"]);
+}
+
+#[test]
+fn page_numbers_are_max5_numeric_text() {
+    assert_eq!(page_number("1").unwrap(), 1);
+    assert_eq!(page_number("99999").unwrap(), 99999);
+    for pg_nb in ["0", "100000", "+1", " 1", ""] {
+        assert_eq!(page_number(pg_nb).unwrap_err().code, ERR_PAGE_NUMBER, "{:?}", pg_nb);
+    }
+}
+
+#[test]
+fn items_split_into_pages_reassemble() {
+    let items: Vec<usize> = vec![4, 3, 2, 5, 1, 1, 1, 6];
+    let limits = PageLimits { max_entries: Some(3), max_size: Some(7) };
+    let ranges = page_ranges(&items, &limits).unwrap();
+    assert_eq!(ranges, vec![0..2, 2..4, 4..7, 7..8]);
+
+    let mut assembler = PageAssembler::new();
+    let pages: Vec<(u64, bool, Vec<usize>)> = ranges.iter().enumerate().map(|(i, range)| (i as u64 + 1, i + 1 == ranges.len(), items[range.clone()].to_vec())).collect();
+    let mut merged = None;
+    for (number, last, page) in pages.into_iter().rev() {
+        merged = assembler.add("RPT-1", number, last, page).unwrap();
+    }
+    assert_eq!(merged.map(|pages| pages.concat()), Some(items));
+}
+
+#[test]
+fn page_limits_are_enforced() {
+    assert_eq!(page_ranges(&[], &PageLimits::default()).unwrap(), vec![0..0]);
+    assert_eq!(page_ranges(&[3, 8], &PageLimits { max_size: Some(7), ..Default::default() }).unwrap_err().code, ERR_PAGE_LIMIT);
+}
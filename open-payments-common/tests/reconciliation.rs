@@ -0,0 +1,135 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Reconciliation of statement entries against instructions: the reference,
+// batch and amount and date passes, partial matches and the tolerances.
+
+use open_payments_common::reconciliation::{
+    reconcile, EntryBatch, EntryPosition, EntryTransaction, Instruction, MatchRule, ReconciliationConfig, References, StatementEntry, NOT_PROVIDED,
+};
+
+fn entry(amount: f64, booking_date: &str) -> StatementEntry {
+    StatementEntry { amount, currency: "USD".to_string(), booking_date: Some(booking_date.to_string()), ..Default::default() }
+}
+
+fn instruction(amount: f64, date: &str) -> Instruction {
+    Instruction { amount, currency: "USD".to_string(), date: Some(date.to_string()), ..Default::default() }
+}
+
+fn transaction(references: References, amount: f64) -> EntryTransaction {
+    EntryTransaction { references, amount: Some(amount), currency: None }
+}
+
+fn at(entry: usize, transaction: Option<usize>) -> EntryPosition {
+    EntryPosition { entry, transaction }
+}
+
+#[test]
+fn references_match_uetr_before_end_to_end_id() {
+    let mut booked = entry(100.0, "2024-05-01");
+    booked.transactions = vec![transaction(References { uetr: Some("ABC".to_string()), end_to_end_id: Some("E2E-1".to_string()), ..Default::default() }, 100.0)];
+    let by_end_to_end_id = Instruction { references: References { end_to_end_id: Some("E2E-1".to_string()), ..Default::default() }, ..instruction(100.0, "2024-05-01") };
+    let by_uetr = Instruction { references: References { uetr: Some("abc".to_string()), ..Default::default() }, ..instruction(100.0, "2024-05-01") };
+
+    let result = reconcile(&[booked], &[by_end_to_end_id, by_uetr], &ReconciliationConfig::default());
+    assert_eq!(result.matched.len(), 1);
+    assert_eq!((result.matched[0].rule, result.matched[0].position, result.matched[0].instructions.clone()), (MatchRule::Uetr, at(0, Some(0)), vec![1]));
+    assert_eq!(result.unmatched_instructions, vec![0]);
+    assert!(result.unmatched_entries.is_empty());
+}
+
+#[test]
+fn reference_match_with_another_amount_is_partial() {
+    let booked = StatementEntry { references: References { account_servicer_reference: Some("ASR-1".to_string()), ..Default::default() }, ..entry(99.0, "2024-05-01") };
+    let instructed = Instruction { references: References { account_servicer_reference: Some("ASR-1".to_string()), ..Default::default() }, ..instruction(100.0, "2024-05-01") };
+
+    let result = reconcile(&[booked], &[instructed], &ReconciliationConfig::default());
+    assert!(result.matched.is_empty());
+    assert_eq!(result.partial.len(), 1);
+    assert_eq!(result.partial[0].rule, MatchRule::AccountServicerReference);
+    assert_eq!(result.partial[0].discrepancy.as_deref(), Some("booked 99 but instructed 100"));
+}
+
+#[test]
+fn not_provided_end_to_end_ids_are_not_matched() {
+    let mut booked = entry(100.0, "2024-05-01");
+    booked.transactions = vec![transaction(References { end_to_end_id: Some(NOT_PROVIDED.to_string()), ..Default::default() }, 100.0)];
+    let instructed = Instruction { references: References { end_to_end_id: Some(NOT_PROVIDED.to_string()), ..Default::default() }, ..instruction(100.0, "2024-05-01") };
+
+    let config = ReconciliationConfig { match_by_amount_and_date: false, ..Default::default() };
+    let result = reconcile(&[booked], &[instructed], &config);
+    assert!(result.matched.is_empty());
+    assert_eq!(result.unmatched_entries, vec![at(0, Some(0))]);
+    assert_eq!(result.unmatched_instructions, vec![0]);
+}
+
+#[test]
+fn batch_entry_matches_the_instructions_of_its_payment_information() {
+    let batch = EntryBatch { payment_information_id: Some("PMT-1".to_string()), number_of_transactions: Some(2), ..Default::default() };
+    let booked = StatementEntry { batch: Some(batch), ..entry(300.0, "2024-05-01") };
+    let member = |amount| Instruction { references: References { payment_information_id: Some("PMT-1".to_string()), ..Default::default() }, ..instruction(amount, "2024-05-01") };
+    let instructions = [member(100.0), instruction(300.0, "2024-05-01"), member(200.0)];
+
+    let result = reconcile(std::slice::from_ref(&booked), &instructions, &ReconciliationConfig::default());
+    assert_eq!(result.matched.len(), 1);
+    assert_eq!((result.matched[0].rule, result.matched[0].instructions.clone()), (MatchRule::Batch, vec![0, 2]));
+    assert_eq!(result.unmatched_instructions, vec![1]);
+
+    let short = [member(100.0), member(150.0)];
+    let result = reconcile(&[booked], &short, &ReconciliationConfig::default());
+    assert_eq!(result.partial.len(), 1);
+    assert_eq!(result.partial[0].discrepancy.as_deref(), Some("booked 300 but the batch instructions add up to 250"));
+}
+
+#[test]
+fn remaining_entries_match_on_amount_and_the_closest_date() {
+    let entries = [entry(100.0, "2024-05-03")];
+    let instructions = [instruction(100.0, "2024-05-01"), instruction(100.0, "2024-05-02"), instruction(100.0, "2024-04-20")];
+
+    let result = reconcile(&entries, &instructions, &ReconciliationConfig::default());
+    assert_eq!(result.matched.len(), 1);
+    assert_eq!((result.matched[0].rule, result.matched[0].instructions.clone()), (MatchRule::AmountAndDate, vec![1]));
+    assert_eq!(result.unmatched_instructions, vec![0, 2]);
+}
+
+#[test]
+fn tolerances_bound_the_amount_and_date_pass() {
+    let entries = [entry(100.05, "2024-05-01")];
+    let instructions = [instruction(100.0, "2024-05-04")];
+
+    let result = reconcile(&entries, &instructions, &ReconciliationConfig::default());
+    assert!(result.matched.is_empty());
+    assert_eq!(result.unmatched_entries, vec![at(0, None)]);
+
+    let config = ReconciliationConfig { amount_tolerance: 0.1, date_tolerance_days: 3, ..Default::default() };
+    let result = reconcile(&entries, &instructions, &config);
+    assert_eq!(result.matched.len(), 1);
+    assert_eq!(result.matched[0].booked_amount, Some(100.05));
+
+    let config = ReconciliationConfig { amount_tolerance: 0.1, date_tolerance_days: 2, ..Default::default() };
+    assert!(reconcile(&entries, &instructions, &config).matched.is_empty());
+
+    let config = ReconciliationConfig { amount_tolerance: 0.1, date_tolerance_days: 3, match_by_amount_and_date: false };
+    assert!(reconcile(&entries, &instructions, &config).matched.is_empty());
+}
@@ -0,0 +1,116 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Redaction of a credit transfer: each treatment, the categories found by
+// tag and parent, the codes kept in masked elements and the financial
+// institution identification passed through.
+
+use open_payments_common::redact::{redact_json, Category, Redacted, RedactionPolicy, Treatment, MASK};
+use serde_json::{json, Value};
+
+fn transaction() -> Value {
+    json!({
+        "CdtTrfTxInf": [{
+            "DbtrAgt": {"FinInstnId": {"Nm": "First Bank", "PstlAdr": {"TwnNm": "Boston"}, "ClrSysMmbId": {"MmbId": "011104238"}}},
+            "Dbtr": {
+                "Nm": "Jane Doe",
+                "PstlAdr": {"AdrTp": {"Cd": "HOME"}, "AdrLine": ["1 Main St"]},
+                "Id": {"PrvtId": {
+                    "DtAndPlcOfBirth": {"BirthDt": "1980-01-01"},
+                    "Othr": [{"Id": "123-45-6789", "SchmeNm": {"Cd": "SOSE"}}]
+                }},
+                "CtctDtls": {"NmPrfx": "MIST", "EmailAdr": "jane@example.com"}
+            },
+            "DbtrAcct": {"Id": {"Othr": {"Id": "1234567890"}}},
+            "CdtrAcct": {"Id": {"IBAN": "DE89370400440532013000"}, "Prxy": {"Id": "+15555550100"}},
+            "RmtInf": {"Ustrd": ["Invoice 42"]},
+            "IntrBkSttlmAmt": {"@Ccy": "USD", "$value": 10.5}
+        }]
+    })
+}
+
+fn redacted(policy: &RedactionPolicy) -> Value {
+    let mut value = transaction();
+    redact_json(&mut value, policy);
+    value["CdtTrfTxInf"][0].take()
+}
+
+#[test]
+fn default_policy() {
+    let tx = redacted(&RedactionPolicy::default());
+    assert_eq!(tx["Dbtr"]["Nm"], json!(MASK));
+    assert!(tx["Dbtr"].get("PstlAdr").is_none());
+    assert!(tx["Dbtr"].get("CtctDtls").is_none());
+    assert!(tx["Dbtr"]["Id"]["PrvtId"].get("DtAndPlcOfBirth").is_none());
+    assert_eq!(tx["Dbtr"]["Id"]["PrvtId"]["Othr"][0], json!({"Id": MASK, "SchmeNm": {"Cd": "SOSE"}}));
+    assert_eq!(tx["DbtrAcct"]["Id"]["Othr"]["Id"], json!("***7890"));
+    assert_eq!(tx["CdtrAcct"], json!({"Id": {"IBAN": "***3000"}, "Prxy": {"Id": "***0100"}}));
+    assert_eq!(tx["RmtInf"], json!({"Ustrd": ["Invoice 42"]}));
+    assert_eq!(tx["IntrBkSttlmAmt"], json!({"@Ccy": "USD", "$value": 10.5}));
+}
+
+#[test]
+fn financial_institution_identification_is_passed_through() {
+    let policy = RedactionPolicy::default().names(Treatment::Drop);
+    let tx = redacted(&policy);
+    assert_eq!(tx["DbtrAgt"], transaction()["CdtTrfTxInf"][0]["DbtrAgt"]);
+    assert!(tx["Dbtr"].get("Nm").is_none());
+}
+
+#[test]
+fn each_treatment() {
+    let tx = redacted(&RedactionPolicy::keep_all());
+    assert_eq!(tx, transaction()["CdtTrfTxInf"][0]);
+
+    let tx = redacted(&RedactionPolicy::keep_all().addresses(Treatment::Mask).contact_details(Treatment::Mask));
+    assert_eq!(tx["Dbtr"]["PstlAdr"], json!({"AdrTp": {"Cd": "HOME"}, "AdrLine": [MASK]}));
+    assert_eq!(tx["Dbtr"]["CtctDtls"], json!({"NmPrfx": "MIST", "EmailAdr": MASK}));
+
+    let tx = redacted(&RedactionPolicy::keep_all().account_ids(Treatment::KeepLast(10)).names(Treatment::KeepLast(3)));
+    assert_eq!(tx["DbtrAcct"]["Id"]["Othr"]["Id"], json!(MASK));
+    assert_eq!(tx["CdtrAcct"]["Id"]["IBAN"], json!("***0532013000"));
+    assert_eq!(tx["Dbtr"]["Nm"], json!("***Doe"));
+
+    let tx = redacted(&RedactionPolicy::keep_all().birth_details(Treatment::Mask).person_ids(Treatment::Drop).remittance_information(Treatment::Drop));
+    assert_eq!(tx["Dbtr"]["Id"]["PrvtId"], json!({"DtAndPlcOfBirth": {"BirthDt": MASK}, "Othr": [{"SchmeNm": {"Cd": "SOSE"}}]}));
+    assert!(tx.get("RmtInf").is_none());
+}
+
+#[test]
+fn policy_tags_take_precedence() {
+    let policy = RedactionPolicy::keep_all().tag("MmbId", Category::AccountId).account_ids(Treatment::Mask).tag("Ustrd", Category::Name).names(Treatment::Drop);
+    let tx = redacted(&policy);
+    assert_eq!(tx["DbtrAgt"]["FinInstnId"]["ClrSysMmbId"]["MmbId"], json!("011104238"));
+    assert_eq!(tx["RmtInf"], json!({}));
+    assert_eq!(policy.category("Id", &["CdtTrfTxInf", "DbtrAcct", "Id", "Othr"]), Some(Category::AccountId));
+    assert_eq!(policy.category("Id", &["CdtTrfTxInf", "Dbtr", "Id", "OrgId", "Othr"]), None);
+}
+
+#[test]
+fn redacted_display_is_json() {
+    let value = json!({"Dbtr": {"Nm": "Jane Doe"}});
+    let policy = RedactionPolicy::default();
+    assert_eq!(Redacted::new(&value, &policy).to_string(), r#"{"Dbtr":{"Nm":"***"}}"#);
+    assert_eq!(format!("{:?}", Redacted::new(&value, &policy)), r#"{"Dbtr":{"Nm":"***"}}"#);
+}
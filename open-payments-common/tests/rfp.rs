@@ -0,0 +1,147 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// The request-for-payment state machine: the transitions the tracker
+// refuses, cancellation, expiry and the checks of the paying transfer.
+
+use open_payments_common::rfp::{
+    new_uetr, PaymentReferences, RequestForPayment, RfpStatus, RfpTracker, ERR_RFP_AMOUNT, ERR_RFP_DUPLICATE, ERR_RFP_EXPIRED, ERR_RFP_LINKAGE, ERR_RFP_STATE,
+    ERR_RFP_UNKNOWN,
+};
+use open_payments_common::ValidationError;
+
+fn request(end_to_end_id: &str) -> RequestForPayment {
+    RequestForPayment {
+        msg_id: "RFP-MSG-1".to_string(),
+        cre_dt_tm: "2024-05-01T09:00:00Z".to_string(),
+        pmt_inf_id: "PMT-1".to_string(),
+        end_to_end_id: end_to_end_id.to_string(),
+        uetr: Some("8a562c67-ca16-48ba-b074-65581be6f011".to_string()),
+        amount: 125.5,
+        currency: "USD".to_string(),
+        expiry: Some("2024-05-03".to_string()),
+        ..Default::default()
+    }
+}
+
+fn payment(end_to_end_id: &str) -> PaymentReferences {
+    PaymentReferences {
+        msg_id: "PAY-1".to_string(),
+        instr_id: Some("PMT-1".to_string()),
+        end_to_end_id: end_to_end_id.to_string(),
+        uetr: Some("8A562C67-CA16-48BA-B074-65581BE6F011".to_string()),
+        amount: 125.50,
+        currency: "USD".to_string(),
+    }
+}
+
+fn tracker() -> RfpTracker {
+    let mut tracker = RfpTracker::new();
+    tracker.track(request("E2E-1")).unwrap();
+    tracker
+}
+
+fn codes(errors: Vec<ValidationError>) -> Vec<u32> {
+    errors.iter().map(|e| e.code).collect()
+}
+
+#[test]
+fn accepted_request_is_paid() {
+    let mut tracker = tracker();
+    assert_eq!(tracker.apply_status("E2E-1", "ACTC", None).unwrap(), RfpStatus::Presented);
+    assert_eq!(tracker.apply_status("E2E-1", "ACCP", None).unwrap(), RfpStatus::Accepted);
+    tracker.mark_paid(&payment("E2E-1"), "2024-05-02T12:00:00Z").unwrap();
+    let tracked = tracker.get("E2E-1").unwrap();
+    assert_eq!((tracked.status, tracked.payment_msg_id.as_deref()), (RfpStatus::Paid, Some("PAY-1")));
+    assert_eq!(tracker.open_requests().count(), 0);
+}
+
+#[test]
+fn illegal_transitions_are_refused() {
+    let mut tracker = tracker();
+    assert_eq!(tracker.track(request("E2E-1")).unwrap_err().code, ERR_RFP_DUPLICATE);
+    assert_eq!(tracker.apply_status("E2E-2", "ACCP", None).unwrap_err().code, ERR_RFP_UNKNOWN);
+    assert_eq!(tracker.apply_status("E2E-1", "XXXX", None).unwrap_err().code, ERR_RFP_STATE);
+
+    tracker.apply_status("E2E-1", "ACCP", None).unwrap();
+    assert_eq!(tracker.apply_status("E2E-1", "PDNG", None).unwrap_err().code, ERR_RFP_STATE);
+    assert_eq!(tracker.apply_cancellation_status("E2E-1", "ACCR").unwrap_err().code, ERR_RFP_STATE);
+
+    tracker.apply_status("E2E-1", "RJCT", Some("AC04")).unwrap();
+    assert_eq!(tracker.get("E2E-1").unwrap().reason.as_deref(), Some("AC04"));
+    assert_eq!(tracker.apply_status("E2E-1", "ACCP", None).unwrap_err().code, ERR_RFP_STATE);
+    assert_eq!(tracker.request_cancellation("E2E-1", None).unwrap_err().code, ERR_RFP_STATE);
+    assert_eq!(codes(tracker.mark_paid(&payment("E2E-1"), "2024-05-02T12:00:00Z").unwrap_err()), vec![ERR_RFP_STATE]);
+}
+
+#[test]
+fn refused_cancellation_restores_the_status() {
+    let mut tracker = tracker();
+    tracker.apply_status("E2E-1", "ACCP", None).unwrap();
+    tracker.request_cancellation("E2E-1", Some("CUST")).unwrap();
+    assert_eq!(tracker.request_cancellation("E2E-1", None).unwrap_err().code, ERR_RFP_STATE);
+    assert_eq!(codes(tracker.check_payment(&payment("E2E-1"), "2024-05-02T12:00:00Z").unwrap_err()), vec![ERR_RFP_STATE]);
+    assert_eq!(tracker.apply_cancellation_status("E2E-1", "PDCR").unwrap(), RfpStatus::CancellationRequested);
+    assert_eq!(tracker.apply_cancellation_status("E2E-1", "RJCR").unwrap(), RfpStatus::Accepted);
+
+    tracker.request_cancellation("E2E-1", None).unwrap();
+    assert_eq!(tracker.apply_cancellation_status("E2E-1", "ACCR").unwrap(), RfpStatus::Cancelled);
+    assert_eq!(tracker.apply_cancellation_status("E2E-1", "RJCR").unwrap_err().code, ERR_RFP_STATE);
+}
+
+#[test]
+fn requests_expire_at_the_end_of_their_expiry_date() {
+    let mut tracker = tracker();
+    tracker.track(RequestForPayment { expiry: Some("2024-05-02T15:00:00-04:00".to_string()), ..request("E2E-2") }).unwrap();
+    tracker.track(RequestForPayment { expiry: None, ..request("E2E-3") }).unwrap();
+
+    assert_eq!(codes(tracker.check_payment(&payment("E2E-2"), "2024-05-02T19:00:00Z").unwrap_err()), vec![ERR_RFP_EXPIRED]);
+    assert!(tracker.check_payment(&payment("E2E-1"), "2024-05-03T23:59:59Z").is_ok());
+    assert_eq!(tracker.expire("2024-05-03T23:59:59Z"), vec!["E2E-2"]);
+    assert_eq!(tracker.expire("2024-05-04T00:00:00Z"), vec!["E2E-1"]);
+    assert_eq!(tracker.get("E2E-1").unwrap().status, RfpStatus::Expired);
+    assert_eq!(tracker.apply_status("E2E-1", "ACCP", None).unwrap_err().code, ERR_RFP_STATE);
+    assert_eq!(tracker.open_requests().map(|r| r.request.end_to_end_id.as_str()).collect::<Vec<_>>(), vec!["E2E-3"]);
+}
+
+#[test]
+fn payment_must_carry_the_references_and_amount() {
+    let tracker = tracker();
+    let unlinked = PaymentReferences { instr_id: None, uetr: None, ..payment("E2E-1") };
+    assert_eq!(codes(tracker.check_payment(&unlinked, "2024-05-02T12:00:00Z").unwrap_err()), vec![ERR_RFP_LINKAGE, ERR_RFP_LINKAGE]);
+    let partial = PaymentReferences { amount: 100.0, ..payment("E2E-1") };
+    assert_eq!(codes(tracker.check_payment(&partial, "2024-05-02T12:00:00Z").unwrap_err()), vec![ERR_RFP_AMOUNT]);
+    assert_eq!(codes(tracker.check_payment(&payment("E2E-9"), "2024-05-02T12:00:00Z").unwrap_err()), vec![ERR_RFP_UNKNOWN]);
+
+    let modifiable = RequestForPayment { amount_modification_allowed: true, ..request("E2E-1") };
+    assert!(modifiable.check_payment(&partial).is_empty());
+}
+
+#[test]
+fn uetrs_are_version_4_uuids() {
+    let uetr = new_uetr();
+    assert_eq!(uetr.len(), 36);
+    assert_eq!(&uetr[14..15], "4");
+    assert!("89ab".contains(&uetr[19..20]), "{}", uetr);
+}
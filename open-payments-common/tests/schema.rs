@@ -0,0 +1,217 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+// Schema validation against a small inline XSD: the code, line, column and
+// path of each kind of violation, so that a change to where violations are
+// reported does not go unnoticed.
+
+use open_payments_common::schema::{
+    SchemaSet, SchemaViolation, ERR_ATTRIBUTE, ERR_MISSING_ELEMENT, ERR_NO_SCHEMA, ERR_OCCURRENCES, ERR_UNEXPECTED_ELEMENT, ERR_VALUE, ERR_XML_SYNTAX,
+};
+
+const XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns="urn:test:pmt" targetNamespace="urn:test:pmt" elementFormDefault="qualified">
+  <xs:element name="Document" type="Document"/>
+  <xs:complexType name="Document">
+    <xs:sequence>
+      <xs:element name="MsgId" type="Max5Text"/>
+      <xs:element name="Amt" type="Amount"/>
+      <xs:element name="Nm" type="Max5Text" minOccurs="0" maxOccurs="2"/>
+      <xs:element name="Sts" type="Status"/>
+    </xs:sequence>
+  </xs:complexType>
+  <xs:simpleType name="Max5Text">
+    <xs:restriction base="xs:string">
+      <xs:minLength value="1"/>
+      <xs:maxLength value="5"/>
+    </xs:restriction>
+  </xs:simpleType>
+  <xs:simpleType name="Status">
+    <xs:restriction base="xs:string">
+      <xs:enumeration value="ACCP"/>
+      <xs:enumeration value="RJCT"/>
+    </xs:restriction>
+  </xs:simpleType>
+  <xs:simpleType name="Amount_SimpleType">
+    <xs:restriction base="xs:decimal">
+      <xs:totalDigits value="5"/>
+      <xs:fractionDigits value="2"/>
+    </xs:restriction>
+  </xs:simpleType>
+  <xs:complexType name="Amount">
+    <xs:simpleContent>
+      <xs:extension base="Amount_SimpleType">
+        <xs:attribute name="Ccy" type="Ccy" use="required"/>
+      </xs:extension>
+    </xs:simpleContent>
+  </xs:complexType>
+  <xs:simpleType name="Ccy">
+    <xs:restriction base="xs:string">
+      <xs:pattern value="[A-Z]{3}"/>
+    </xs:restriction>
+  </xs:simpleType>
+</xs:schema>
+"#;
+
+fn schemas() -> SchemaSet {
+    let mut schemas = SchemaSet::new();
+    schemas.add(XSD).unwrap();
+    schemas
+}
+
+/// A message of the test schema with `body` inside `Document`, from line 3.
+fn message(body: &str) -> String {
+    format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Document xmlns=\"urn:test:pmt\">\n{}</Document>\n", body)
+}
+
+/// Code, line, column and path of each violation.
+fn located(violations: &[SchemaViolation]) -> Vec<(u32, u64, u64, &str)> {
+    violations.iter().map(|v| (v.code, v.line, v.column, v.path.as_str())).collect()
+}
+
+#[test]
+fn valid_message_has_no_violations() {
+    let xml = message("  <MsgId>M1</MsgId>\n  <Amt Ccy=\"USD\">10.50</Amt>\n  <Nm>A</Nm>\n  <Nm>B</Nm>\n  <Sts>ACCP</Sts>\n");
+    assert_eq!(schemas().validate(&xml), vec![]);
+    assert!(schemas().check(&xml).is_ok());
+    assert_eq!(schemas().namespaces().collect::<Vec<_>>(), vec!["urn:test:pmt"]);
+}
+
+#[test]
+fn syntax_error_is_reported_where_the_parser_stops() {
+    let xml = message("  <MsgId>M1</MsgId>\n  <Amt Ccy=\"USD\">10.50</Amount>\n  <Sts>ACCP</Sts>\n");
+    let violations = schemas().validate(&xml);
+    assert_eq!(located(&violations), vec![(ERR_XML_SYNTAX, 4, 31, "/Document/Amt")]);
+}
+
+#[test]
+fn undeclared_root_has_no_schema() {
+    let xml = "<Document xmlns=\"urn:test:other\">\n  <MsgId>M1</MsgId>\n</Document>\n";
+    let violations = schemas().validate(xml);
+    assert_eq!(located(&violations), vec![(ERR_NO_SCHEMA, 1, 1, "/Document")]);
+    assert!(violations[0].message.contains("urn:test:other"));
+}
+
+#[test]
+fn unexpected_element_is_reported_at_its_start_tag() {
+    let xml = message("  <MsgId>M1</MsgId>\n  <Amt Ccy=\"USD\">10.50</Amt>\n  <Xtra>1</Xtra>\n  <Sts>ACCP</Sts>\n");
+    let violations = schemas().validate(&xml);
+    assert_eq!(located(&violations), vec![(ERR_UNEXPECTED_ELEMENT, 5, 3, "/Document/Xtra")]);
+}
+
+#[test]
+fn element_out_of_order_is_unexpected() {
+    let xml = message("  <Amt Ccy=\"USD\">10.50</Amt>\n  <MsgId>M1</MsgId>\n  <Sts>ACCP</Sts>\n");
+    let violations = schemas().validate(&xml);
+    assert_eq!(located(&violations), vec![(ERR_UNEXPECTED_ELEMENT, 3, 3, "/Document/Amt")]);
+    assert!(violations[0].message.contains("MsgId"), "{}", violations[0].message);
+}
+
+#[test]
+fn missing_element_is_reported_at_its_parent() {
+    let xml = message("  <MsgId>M1</MsgId>\n  <Amt Ccy=\"USD\">10.50</Amt>\n");
+    let violations = schemas().validate(&xml);
+    assert_eq!(located(&violations), vec![(ERR_MISSING_ELEMENT, 2, 1, "/Document")]);
+    assert!(violations[0].message.contains("Sts"), "{}", violations[0].message);
+}
+
+#[test]
+fn repetition_beyond_max_occurs_is_reported_at_the_extra_item() {
+    let xml = message("  <MsgId>M1</MsgId>\n  <Amt Ccy=\"USD\">10.50</Amt>\n  <Nm>A</Nm>\n  <Nm>B</Nm>\n  <Nm>C</Nm>\n  <Sts>ACCP</Sts>\n");
+    let violations = schemas().validate(&xml);
+    assert_eq!(located(&violations), vec![(ERR_OCCURRENCES, 7, 3, "/Document/Nm")]);
+    assert!(violations[0].message.contains("2 times"), "{}", violations[0].message);
+}
+
+#[test]
+fn attributes_missing_or_not_declared() {
+    let xml = message("  <MsgId Id=\"1\">M1</MsgId>\n  <Amt>10.50</Amt>\n  <Sts>ACCP</Sts>\n");
+    let violations = schemas().validate(&xml);
+    assert_eq!(
+        located(&violations),
+        vec![(ERR_ATTRIBUTE, 3, 3, "/Document/MsgId"), (ERR_ATTRIBUTE, 4, 3, "/Document/Amt")],
+    );
+    assert!(violations[0].message.contains("Id is not allowed"), "{}", violations[0].message);
+    assert!(violations[1].message.contains("missing attribute Ccy"), "{}", violations[1].message);
+}
+
+#[test]
+fn values_breaking_their_facets() {
+    let xml = message("  <MsgId>TOOLONG</MsgId>\n  <Amt Ccy=\"usd\">1000.505</Amt>\n  <Sts>PDNG</Sts>\n");
+    let violations = schemas().validate(&xml);
+    assert_eq!(
+        located(&violations),
+        vec![
+            (ERR_VALUE, 3, 3, "/Document/MsgId"),
+            (ERR_VALUE, 4, 3, "/Document/Amt/@Ccy"),
+            (ERR_VALUE, 4, 3, "/Document/Amt"),
+            (ERR_VALUE, 5, 3, "/Document/Sts"),
+        ],
+    );
+}
+
+#[test]
+fn check_fails_with_the_first_violation() {
+    let xml = message("  <MsgId>TOOLONG</MsgId>\n  <Amt Ccy=\"USD\">10.50</Amt>\n  <Sts>RJCT</Sts>\n");
+    let error = schemas().check(&xml).unwrap_err();
+    assert_eq!(error.code, ERR_VALUE);
+    assert!(error.message.starts_with("3:3 /Document/MsgId: "), "{}", error.message);
+}
+
+/// Attributes declared globally and used by reference, with the use given
+/// at the reference.
+const ATTRIBUTE_REF_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns="urn:test:ref" targetNamespace="urn:test:ref" elementFormDefault="qualified">
+  <xs:attribute name="Ccy" type="xs:string"/>
+  <xs:attribute name="Src" type="xs:string"/>
+  <xs:element name="Document" type="Document"/>
+  <xs:complexType name="Document">
+    <xs:sequence>
+      <xs:element name="Amt" type="Amount"/>
+    </xs:sequence>
+  </xs:complexType>
+  <xs:complexType name="Amount">
+    <xs:simpleContent>
+      <xs:extension base="xs:decimal">
+        <xs:attribute ref="Ccy" use="required"/>
+        <xs:attribute ref="Src" use="prohibited"/>
+      </xs:extension>
+    </xs:simpleContent>
+  </xs:complexType>
+</xs:schema>
+"#;
+
+#[test]
+fn referenced_attributes_keep_the_use_of_the_reference() {
+    let mut schemas = SchemaSet::new();
+    schemas.add(ATTRIBUTE_REF_XSD).unwrap();
+    let valid = "<Document xmlns=\"urn:test:ref\" xmlns:r=\"urn:test:ref\">\n  <Amt r:Ccy=\"USD\">1</Amt>\n</Document>\n";
+    assert_eq!(schemas.validate(valid), vec![]);
+
+    let invalid = "<Document xmlns=\"urn:test:ref\" xmlns:r=\"urn:test:ref\">\n  <Amt r:Src=\"X\">1</Amt>\n</Document>\n";
+    let violations = schemas.validate(invalid);
+    assert_eq!(located(&violations), vec![(ERR_ATTRIBUTE, 2, 3, "/Document/Amt"), (ERR_ATTRIBUTE, 2, 3, "/Document/Amt")]);
+    assert!(violations[0].message.contains("Src is not allowed"), "{}", violations[0].message);
+    assert!(violations[1].message.contains("missing attribute"), "{}", violations[1].message);
+}
@@ -0,0 +1,164 @@
+// Open Payment Message Parsing Library
+// https://github.com/Open-Payments/messages
+//
+// This library is designed to parse message formats based on the ISO 20022 standards,
+// including but not limited to FedNow messages. It supports various financial message types,
+// such as customer credit transfers, payment status reports, administrative notifications, 
+// and other ISO 20022 messages, using Serde for efficient serialization and deserialization.
+//
+// Copyright (c) 2024 Open Payments by Harishankar Narayanan
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/Open-Payments/messages
+
+
+// SWIFT FIN round trips: each sample is parsed, read into its typed view,
+// written back from that view and parsed again, and the two views must be
+// equal. The field tests pin the line formats of block 4.
+
+use open_payments_common::swift::fields::{format_rate, is_bic};
+use open_payments_common::swift::mapping::mt_reference;
+use open_payments_common::swift::mt103::Mt103;
+use open_payments_common::swift::mt202::Mt202;
+use open_payments_common::swift::mt940::Statement;
+use open_payments_common::swift::{Field, FinMessage, ERR_CHARACTER_SET, ERR_LINE_FORMAT};
+
+const MT103: &str = "{1:F01BANKBEBBAXXX0000000000}{2:I103BANKDEFFXXXXN}{4:\r\n\
+:20:REF-0001\r\n\
+:23B:CRED\r\n\
+:32A:240115EUR1000,5\r\n\
+:33B:USD1096,12\r\n\
+:36:0,9123456789\r\n\
+:50K:/BE68539007547034\r\n\
+JOHN DOE\r\n\
+RUE DE LA LOI 1\r\n\
+:59:/DE89370400440532013000\r\n\
+MAX MUSTERMANN\r\n\
+:70:INVOICE 123\r\n\
+:71A:SHA\r\n\
+-}";
+
+const MT202_COV: &str = "{1:F01BANKBEBBAXXX0000000000}{2:I202BANKDEFFXXXXN}{3:{119:COV}{121:eb6305c9-1f7f-49de-aed0-16487c27b42d}}{4:\r\n\
+:20:COV-0001\r\n\
+:21:REF-0001\r\n\
+:32A:240115EUR1000,5\r\n\
+:58A:BANKDEFF\r\n\
+:50K:/BE68539007547034\r\n\
+JOHN DOE\r\n\
+:59:/DE89370400440532013000\r\n\
+MAX MUSTERMANN\r\n\
+:33B:EUR1000,5\r\n\
+-}";
+
+const MT940: &str = "{1:F01BANKBEBBAXXX0000000000}{2:I940BANKDEFFXXXXN}{4:\r\n\
+:20:STMT-0001\r\n\
+:25:BE68539007547034\r\n\
+:28C:1/1\r\n\
+:60F:C240114EUR1000,\r\n\
+:61:2401150115C500,25NTRFNONREF//B123\r\n\
+:86:PAYMENT RECEIVED\r\n\
+:61:2401150115D200,NTRFREF-0002\r\n\
+:62F:C240115EUR1300,25\r\n\
+-}";
+
+/// Parses the FIN text, writes the fields of its typed view back into a
+/// new message with the same headers and parses that message again.
+fn round_trip(input: &str, fields: impl Fn(&FinMessage) -> Vec<Field>) -> (FinMessage, FinMessage) {
+    let message = FinMessage::parse(input).unwrap();
+    let mut written = message.clone();
+    written.text = fields(&message);
+    let reparsed = FinMessage::parse(&written.to_fin()).unwrap();
+    (message, reparsed)
+}
+
+#[test]
+fn fin_message_round_trip() {
+    let message = FinMessage::parse(MT103).unwrap();
+    assert_eq!(message.message_type(), "103");
+    assert_eq!(message.sender_bic(), "BANKBEBBXXX");
+    assert_eq!(message.to_fin(), MT103);
+}
+
+#[test]
+fn mt103_round_trip() {
+    let (message, reparsed) = round_trip(MT103, |m| Mt103::from_fin(m).unwrap().fields());
+    let mt103 = Mt103::from_fin(&message).unwrap();
+    assert_eq!(mt103, Mt103::from_fin(&reparsed).unwrap());
+    assert_eq!(mt103.exchange_rate, Some(0.9123456789));
+    assert_eq!(reparsed.field("36").unwrap().value, "0,9123456789");
+    assert_eq!(reparsed.field("32A").unwrap().value, "240115EUR1000,5");
+}
+
+#[test]
+fn mt202_cov_round_trip() {
+    let (message, reparsed) = round_trip(MT202_COV, |m| Mt202::from_fin(m).unwrap().fields());
+    let mt202 = Mt202::from_fin(&message).unwrap();
+    assert!(mt202.is_cov());
+    assert_eq!(mt202, Mt202::from_fin(&reparsed).unwrap());
+
+    let written = FinMessage::parse(&mt202.to_fin("BANKBEBB", "BANKDEFF", message.uetr()).to_fin()).unwrap();
+    assert_eq!(written.validation_flag(), Some("COV"));
+    assert_eq!(written.uetr(), message.uetr());
+    assert_eq!(Mt202::from_fin(&written).unwrap(), mt202);
+}
+
+#[test]
+fn mt940_round_trip() {
+    let (message, reparsed) = round_trip(MT940, |m| Statement::from_fin(m).unwrap().fields());
+    let statement = Statement::from_fin(&message).unwrap();
+    assert_eq!(statement.entries.len(), 2);
+    assert_eq!(statement.entries[0].information, vec!["PAYMENT RECEIVED".to_string()]);
+    assert_eq!(statement, Statement::from_fin(&reparsed).unwrap());
+}
+
+#[test]
+fn exchange_rate_keeps_its_precision() {
+    assert_eq!(format_rate(0.9123456789), "0,9123456789");
+    assert_eq!(format_rate(1.5), "1,5");
+    assert_eq!(format_rate(2.0), "2,");
+    assert_eq!(format_rate(123456.123456789), "123456,12346");
+    assert!(format_rate(1.0 / 3.0).len() <= 12);
+}
+
+#[test]
+fn field_line_formats_are_checked() {
+    let too_long = MT103.replace(":20:REF-0001", ":20:REF-0001-0002-0003");
+    assert_eq!(FinMessage::parse(&too_long).unwrap_err().code, ERR_LINE_FORMAT);
+
+    let too_many_lines = MT103.replace(":70:INVOICE 123", ":70:1\r\n2\r\n3\r\n4\r\n5");
+    assert_eq!(FinMessage::parse(&too_many_lines).unwrap_err().code, ERR_LINE_FORMAT);
+
+    let slash = MT103.replace(":20:REF-0001", ":20:REF//0001");
+    assert!(FinMessage::parse(&slash).is_err());
+
+    let character_set = MT103.replace("JOHN DOE", "JOHN DOE & CO");
+    assert_eq!(FinMessage::parse(&character_set).unwrap_err().code, ERR_CHARACTER_SET);
+}
+
+#[test]
+fn references_have_no_empty_slash_segments() {
+    assert_eq!(mt_reference("A///B"), "A/B");
+    assert_eq!(mt_reference("//A//B//"), "A/B");
+    assert_eq!(mt_reference("ABCDEFGHIJKLMNO/PQ"), "ABCDEFGHIJKLMNO");
+    assert_eq!(mt_reference("///"), "NONREF");
+}
+
+#[test]
+fn bics_have_eight_or_eleven_characters() {
+    assert!(is_bic("CHASUS33"));
+    assert!(is_bic("CHASUS33XXX"));
+    assert!(!is_bic("CHASUS3"));
+    assert!(!is_bic("chasus33"));
+    assert!(!is_bic("CHAS1S33"));
+}